@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Line counting benchmarks for the cloc check.
+//!
+//! Compares the byte-level counter (mmap + memchr, no UTF-8 validation)
+//! against the previous `String`-based counting on a generated 1M-line file.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use std::path::Path;
+
+use quench::cloc::count_bytes;
+use quench::file_reader::FileBytes;
+
+const LINES: usize = 1_000_000;
+
+/// Generate source-like content: code, blank lines, and some non-ASCII.
+fn generate_content(lines: usize) -> String {
+    (0..lines)
+        .map(|i| match i % 10 {
+            0 => "\n".to_string(),
+            5 => format!("    // commentaire n°{}\n", i),
+            _ => format!("    let value_{} = compute({});\n", i, i),
+        })
+        .collect()
+}
+
+/// Previous implementation: read into a `String`, count with `str::lines`.
+fn count_string(path: &Path) -> (usize, usize, usize) {
+    let content = std::fs::read(path).unwrap();
+    let text = String::from_utf8(content)
+        .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned());
+    let lines = text.lines().count();
+    let nonblank = text.lines().filter(|l| !l.trim().is_empty()).count();
+    let tokens = text.chars().count() / 4;
+    (lines, nonblank, tokens)
+}
+
+/// Current implementation: mmap and scan bytes.
+fn count_mmap(path: &Path) -> (usize, usize, usize) {
+    let content = FileBytes::read(path).unwrap();
+    let m = count_bytes(content.as_bytes());
+    (m.lines, m.nonblank(), m.tokens)
+}
+
+fn bench_line_counting(c: &mut Criterion) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("large.rs");
+    let content = generate_content(LINES);
+    std::fs::write(&path, &content).unwrap();
+
+    // Both implementations must agree before timing them
+    assert_eq!(count_string(&path), count_mmap(&path));
+
+    let mut group = c.benchmark_group("cloc_line_counting");
+    group.sample_size(20);
+    group.throughput(Throughput::Bytes(content.len() as u64));
+
+    group.bench_with_input(BenchmarkId::new("string", "1M_lines"), &path, |b, path| {
+        b.iter(|| black_box(count_string(path)))
+    });
+    group.bench_with_input(
+        BenchmarkId::new("mmap_memchr", "1M_lines"),
+        &path,
+        |b, path| b.iter(|| black_box(count_mmap(path))),
+    );
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_line_counting);
+criterion_main!(benches);
@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Core component benchmarks for quench's own performance gate.
+//!
+//! Library-level measurements (no process spawn) of the hot paths:
+//! - Walker throughput on a generated tree
+//! - Escapes scan over walked files
+//! - Cache persist/load round-trip
+//! - JSON formatting of check output
+//!
+//! Run in CI by `.github/workflows/bench.yml`; see docs/specs/20-performance.md#self-benchmarks.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicUsize;
+
+use quench::cache::{CachedViolation, FileCache, FileCacheKey};
+use quench::check::{Check, CheckContext, CheckResult, Violation};
+use quench::checks::escapes::EscapesCheck;
+use quench::config::Config;
+use quench::diagnostics::Diagnostics;
+use quench::file_reader::DecodeLog;
+use quench::output::json::{JsonFormatter, create_output};
+use quench::walker::{FileWalker, WalkedFile, WalkerConfig};
+
+const FILES: usize = 1_000;
+
+/// Generate a Rust project with `files` source files across nested modules.
+fn generate_project(root: &Path, files: usize) {
+    std::fs::write(
+        root.join("Cargo.toml"),
+        "[package]\nname = \"bench\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    for i in 0..files {
+        let dir = root.join(format!("src/mod_{}/sub_{}", i % 20, i % 7));
+        std::fs::create_dir_all(&dir).unwrap();
+        let body: String = (0..100)
+            .map(|line| match line % 25 {
+                3 => "    let v = maybe().unwrap();\n".to_string(),
+                11 => "    // TODO: tidy this up\n".to_string(),
+                _ => format!("    let x_{line} = compute({line});\n"),
+            })
+            .collect();
+        std::fs::write(
+            dir.join(format!("file_{}.rs", i)),
+            format!("pub fn f_{}() {{\n{}}}\n", i, body),
+        )
+        .unwrap();
+    }
+}
+
+fn walk(root: &Path) -> Vec<WalkedFile> {
+    FileWalker::new(WalkerConfig::default())
+        .walk_collect(root)
+        .0
+}
+
+fn bench_walker_throughput(c: &mut Criterion) {
+    let dir = tempfile::tempdir().unwrap();
+    generate_project(dir.path(), FILES);
+
+    let mut group = c.benchmark_group("core_walker");
+    group.throughput(Throughput::Elements(FILES as u64));
+    group.bench_function(BenchmarkId::new("walk_collect", FILES), |b| {
+        b.iter(|| black_box(walk(dir.path())))
+    });
+    group.finish();
+}
+
+fn bench_escapes_scan(c: &mut Criterion) {
+    let dir = tempfile::tempdir().unwrap();
+    generate_project(dir.path(), FILES);
+    let files = walk(dir.path());
+    let config = Config::default();
+
+    let mut group = c.benchmark_group("core_escapes");
+    group.sample_size(20);
+    group.throughput(Throughput::Elements(files.len() as u64));
+    group.bench_function(BenchmarkId::new("scan", FILES), |b| {
+        b.iter(|| {
+            let violation_count = AtomicUsize::new(0);
+            let decode_log = DecodeLog::default();
+            let diagnostics = Diagnostics::silent();
+            let ctx = CheckContext {
+                root: dir.path(),
+                files: &files,
+                config: &config,
+                limit: None,
+                violation_count: &violation_count,
+                changed_files: None,
+                fix: false,
+                run_fixers: false,
+                dry_run: false,
+                patch: None,
+                ci_mode: false,
+                base_branch: None,
+                staged: false,
+                staged_content: None,
+                verbose: false,
+                decode_log: &decode_log,
+                diagnostics: &diagnostics,
+            };
+            black_box(EscapesCheck.run(&ctx))
+        })
+    });
+    group.finish();
+}
+
+/// Build a cache with `files` entries, each holding a few violations.
+fn populated_cache(files: usize) -> FileCache {
+    let cache = FileCache::new(Default::default());
+    for i in 0..files {
+        let path = PathBuf::from(format!("src/mod_{}/file_{}.rs", i % 20, i));
+        let violations = (0..3)
+            .map(|line| {
+                let v = Violation::file(&path, line + 1, "forbidden", "Remove it.");
+                CachedViolation::from_violation(&v, "escapes")
+            })
+            .collect();
+        let key = FileCacheKey {
+            mtime_secs: i as i64,
+            mtime_nanos: 0,
+            size: 4096,
+        };
+        cache.insert(path, key, i as u64, violations);
+    }
+    cache
+}
+
+fn bench_cache_persist_load(c: &mut Criterion) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cache.bin");
+    let cache = populated_cache(FILES * 10);
+
+    let mut group = c.benchmark_group("core_cache");
+    group.bench_function(BenchmarkId::new("persist", FILES * 10), |b| {
+        b.iter(|| cache.persist(&path).unwrap())
+    });
+    cache.persist(&path).unwrap();
+    group.bench_function(BenchmarkId::new("load", FILES * 10), |b| {
+        b.iter(|| black_box(FileCache::from_persistent(&path, Default::default()).unwrap()))
+    });
+    group.finish();
+}
+
+fn bench_json_formatting(c: &mut Criterion) {
+    let violations: Vec<Violation> = (0..FILES)
+        .map(|i| {
+            Violation::file(
+                format!("src/file_{}.rs", i),
+                i as u32 + 1,
+                "forbidden",
+                "Remove it.",
+            )
+        })
+        .collect();
+    let results = vec![
+        CheckResult::failed("escapes", violations),
+        CheckResult::passed("cloc"),
+    ];
+
+    let mut group = c.benchmark_group("core_json");
+    group.bench_function(BenchmarkId::new("format", FILES), |b| {
+        b.iter(|| {
+            let output = create_output(results.clone());
+            let mut buf = Vec::with_capacity(256 * 1024);
+            JsonFormatter::new(&mut buf).write(&output).unwrap();
+            black_box(buf)
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_walker_throughput,
+    bench_escapes_scan,
+    bench_cache_persist_load,
+    bench_json_formatting
+);
+criterion_main!(benches);
@@ -17,7 +17,7 @@ use std::path::{Path, PathBuf};
 
 use quench::Baseline;
 use quench::cli::{OutputFormat, ReportArgs};
-use quench::report::{format_report, format_report_to, format_report_with_options};
+use quench::report::{Insights, format_report, format_report_to, format_report_with_options};
 
 fn fixture_path(name: &str) -> PathBuf {
     Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -153,6 +153,7 @@ fn bench_streaming_vs_buffered(c: &mut Criterion) {
                 Some(black_box(&baseline)),
                 &args,
                 false,
+                &Insights::default(),
             )
             .unwrap();
         })
@@ -176,6 +177,7 @@ fn bench_streaming_vs_buffered(c: &mut Criterion) {
                 Some(black_box(&baseline)),
                 &args,
                 false,
+                &Insights::default(),
             )
             .unwrap();
         })
@@ -199,13 +201,25 @@ fn bench_compact_json(c: &mut Criterion) {
 
     group.bench_function("pretty", |b| {
         b.iter(|| {
-            format_report_with_options(OutputFormat::Json, Some(black_box(&baseline)), &args, false)
+            format_report_with_options(
+                OutputFormat::Json,
+                Some(black_box(&baseline)),
+                &args,
+                false,
+                &Insights::default(),
+            )
         })
     });
 
     group.bench_function("compact", |b| {
         b.iter(|| {
-            format_report_with_options(OutputFormat::Json, Some(black_box(&baseline)), &args, true)
+            format_report_with_options(
+                OutputFormat::Json,
+                Some(black_box(&baseline)),
+                &args,
+                true,
+                &Insights::default(),
+            )
         })
     });
 
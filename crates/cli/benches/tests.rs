@@ -45,6 +45,12 @@ fn rust_correlation_config() -> CorrelationConfig {
             "**/lib.rs".to_string(),
             "**/main.rs".to_string(),
         ],
+        mechanical_patterns: vec![
+            "**/Cargo.lock".to_string(),
+            "**/__snapshots__/**".to_string(),
+            "**/*.snap".to_string(),
+            "**/*.generated.*".to_string(),
+        ],
     }
 }
 
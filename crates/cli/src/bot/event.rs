@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! GitHub `pull_request` webhook payloads.
+
+use serde::Deserialize;
+
+/// Actions that change what a pull request would merge.
+pub const CHECKED_ACTIONS: &[&str] = &["opened", "reopened", "synchronize"];
+
+/// The parts of a `pull_request` event the bot uses.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PullRequestEvent {
+    pub action: String,
+    pub number: u64,
+    pub pull_request: PullRequest,
+    /// The base repository (where statuses and comments go).
+    pub repository: Repository,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PullRequest {
+    pub head: Branch,
+    pub base: Branch,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Branch {
+    /// Branch name (e.g., "main").
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    pub sha: String,
+    /// None when the head fork has been deleted.
+    pub repo: Option<Repository>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Repository {
+    /// "owner/name".
+    pub full_name: String,
+    pub clone_url: String,
+}
+
+impl Repository {
+    /// The owning user or organization.
+    pub fn owner(&self) -> &str {
+        self.full_name
+            .split_once('/')
+            .map_or(self.full_name.as_str(), |(owner, _)| owner)
+    }
+}
+
+impl PullRequestEvent {
+    /// Parse a webhook payload.
+    pub fn parse(payload: &str) -> Result<Self, String> {
+        serde_json::from_str(payload).map_err(|e| format!("not a pull_request event: {}", e))
+    }
+
+    /// Whether this event should trigger a check.
+    pub fn wants_check(&self) -> bool {
+        CHECKED_ACTIONS.contains(&self.action.as_str())
+    }
+
+    /// Where to fetch the head branch from (the fork, for fork PRs).
+    pub fn head_clone_url(&self) -> &str {
+        self.pull_request
+            .head
+            .repo
+            .as_ref()
+            .unwrap_or(&self.repository)
+            .clone_url
+            .as_str()
+    }
+
+    /// The fork the head branch comes from, or `None` when the pull
+    /// request is from a branch of the base repository (or the fork is
+    /// gone, and the head is fetched from the base repository).
+    pub fn fork(&self) -> Option<&Repository> {
+        self.pull_request
+            .head
+            .repo
+            .as_ref()
+            .filter(|repo| repo.full_name != self.repository.full_name)
+    }
+
+    /// "owner/name#number", for logs.
+    pub fn label(&self) -> String {
+        format!("{}#{}", self.repository.full_name, self.number)
+    }
+}
+
+#[cfg(test)]
+#[path = "event_tests.rs"]
+mod tests;
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn payload(action: &str, head_repo: &str) -> String {
+    format!(
+        r#"{{
+            "action": "{action}",
+            "number": 42,
+            "pull_request": {{
+                "head": {{ "ref": "feature", "sha": "abc123", "repo": {head_repo} }},
+                "base": {{
+                    "ref": "main",
+                    "sha": "def456",
+                    "repo": {{ "full_name": "acme/app", "clone_url": "https://github.com/acme/app.git" }}
+                }}
+            }},
+            "repository": {{ "full_name": "acme/app", "clone_url": "https://github.com/acme/app.git" }},
+            "sender": {{ "login": "octocat" }}
+        }}"#
+    )
+}
+
+const FORK: &str = r#"{ "full_name": "fork/app", "clone_url": "https://github.com/fork/app.git" }"#;
+
+#[test]
+fn parses_pull_request_payload() {
+    let event = PullRequestEvent::parse(&payload("opened", FORK)).unwrap();
+
+    assert_eq!(event.number, 42);
+    assert_eq!(event.pull_request.head.ref_name, "feature");
+    assert_eq!(event.pull_request.head.sha, "abc123");
+    assert_eq!(event.pull_request.base.sha, "def456");
+    assert_eq!(event.label(), "acme/app#42");
+}
+
+#[test]
+fn checks_opened_reopened_and_synchronized_pull_requests() {
+    for action in ["opened", "reopened", "synchronize"] {
+        let event = PullRequestEvent::parse(&payload(action, FORK)).unwrap();
+        assert!(event.wants_check(), "{action}");
+    }
+    for action in ["closed", "labeled", "edited"] {
+        let event = PullRequestEvent::parse(&payload(action, FORK)).unwrap();
+        assert!(!event.wants_check(), "{action}");
+    }
+}
+
+#[test]
+fn head_is_fetched_from_fork() {
+    let event = PullRequestEvent::parse(&payload("opened", FORK)).unwrap();
+
+    assert_eq!(event.head_clone_url(), "https://github.com/fork/app.git");
+}
+
+#[test]
+fn head_falls_back_to_base_repository_when_fork_is_gone() {
+    let event = PullRequestEvent::parse(&payload("opened", "null")).unwrap();
+
+    assert_eq!(event.head_clone_url(), "https://github.com/acme/app.git");
+}
+
+#[test]
+fn rejects_other_events() {
+    let err = PullRequestEvent::parse(r#"{"zen": "Keep it logically awesome."}"#).unwrap_err();
+
+    assert!(err.starts_with("not a pull_request event"), "{err}");
+}
+
+#[test]
+fn fork_is_head_repository_other_than_base() {
+    let event = PullRequestEvent::parse(&payload("opened", FORK)).unwrap();
+    let fork = event.fork().unwrap();
+    assert_eq!(fork.full_name, "fork/app");
+    assert_eq!(fork.owner(), "fork");
+
+    let same = r#"{ "full_name": "acme/app", "clone_url": "https://github.com/acme/app.git" }"#;
+    for head_repo in [same, "null"] {
+        let event = PullRequestEvent::parse(&payload("opened", head_repo)).unwrap();
+        assert!(event.fork().is_none(), "{head_repo}");
+    }
+}
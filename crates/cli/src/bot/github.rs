@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! GitHub REST calls for commit statuses and PR comments.
+//!
+//! Like telemetry uploads, requests go through `curl` so quench needs no
+//! HTTP stack. The token never goes on a command line, where `ps` would
+//! show it: curl reads it from a config on stdin, and git from a credential
+//! helper that reads the environment.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use serde_json::{Value as JsonValue, json};
+
+/// API base URL unless `GITHUB_API_URL` says otherwise.
+pub const DEFAULT_API_URL: &str = "https://api.github.com";
+
+/// Commit status context shown on pull requests.
+pub const STATUS_CONTEXT: &str = "quench";
+
+/// Marks the bot's PR comment so later runs update it instead of adding more.
+pub const COMMENT_MARKER: &str = "<!-- quench -->";
+
+/// GitHub allows at most this many characters in a status description.
+const MAX_DESCRIPTION: usize = 140;
+
+/// Git config answering HTTPS credential requests with the token in
+/// `GITHUB_TOKEN`. Passed after an empty `credential.helper=`, which drops
+/// any helpers configured on the host.
+pub const CREDENTIAL_HELPER: &str = "credential.helper=!f() { test \"$1\" = get && echo username=x-access-token && echo \"password=$GITHUB_TOKEN\"; }; f";
+
+/// Commit status state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Pending,
+    Success,
+    Failure,
+    /// Checks couldn't run (bad config, failed checkout).
+    Error,
+}
+
+impl State {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            State::Pending => "pending",
+            State::Success => "success",
+            State::Failure => "failure",
+            State::Error => "error",
+        }
+    }
+}
+
+/// An authenticated GitHub API client.
+#[derive(Debug, Clone)]
+pub struct GitHub {
+    api_url: String,
+    token: String,
+}
+
+impl GitHub {
+    pub fn new(api_url: impl Into<String>, token: impl Into<String>) -> Self {
+        Self {
+            api_url: api_url.into().trim_end_matches('/').to_string(),
+            token: token.into(),
+        }
+    }
+
+    /// Token for fetching private repositories.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Set the `quench` status on `sha`.
+    pub fn set_status(
+        &self,
+        repo: &str,
+        sha: &str,
+        state: State,
+        description: &str,
+    ) -> Result<(), String> {
+        let body = json!({
+            "state": state.as_str(),
+            "context": STATUS_CONTEXT,
+            "description": truncate(description, MAX_DESCRIPTION),
+        });
+        self.request(
+            "POST",
+            &format!("/repos/{}/statuses/{}", repo, sha),
+            Some(&body),
+        )
+        .map(drop)
+    }
+
+    /// Post `body` on pull request `number`, replacing the bot's earlier
+    /// comment if there is one.
+    pub fn upsert_comment(&self, repo: &str, number: u64, body: &str) -> Result<(), String> {
+        let comments = self.request(
+            "GET",
+            &format!("/repos/{}/issues/{}/comments?per_page=100", repo, number),
+            None,
+        )?;
+        let existing = comments.as_array().and_then(|comments| {
+            comments.iter().find_map(|c| {
+                let ours = c["body"]
+                    .as_str()
+                    .is_some_and(|b| b.contains(COMMENT_MARKER));
+                if ours { c["id"].as_u64() } else { None }
+            })
+        });
+        let body = json!({ "body": body });
+        match existing {
+            Some(id) => self.request(
+                "PATCH",
+                &format!("/repos/{}/issues/comments/{}", repo, id),
+                Some(&body),
+            ),
+            None => self.request(
+                "POST",
+                &format!("/repos/{}/issues/{}/comments", repo, number),
+                Some(&body),
+            ),
+        }
+        .map(drop)
+    }
+
+    /// Send a request, returning the parsed response (Null if empty).
+    fn request(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&JsonValue>,
+    ) -> Result<JsonValue, String> {
+        let mut cmd = Command::new("curl");
+        cmd.args(["--silent", "--show-error", "--fail", "--max-time", "30"])
+            .args(["--request", method])
+            .args(["--header", "Accept: application/vnd.github+json"])
+            .args(["--config", "-"])
+            .arg(format!("{}{}", self.api_url, path))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if body.is_some() {
+            cmd.args(["--header", "Content-Type: application/json"]);
+        }
+        let mut child = cmd.spawn().map_err(|e| format!("cannot run curl: {}", e))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(curl_config(&self.token, body).as_bytes())
+                .map_err(|e| e.to_string())?;
+        }
+        let output = child.wait_with_output().map_err(|e| e.to_string())?;
+        if !output.status.success() {
+            return Err(format!(
+                "{} {}: {}",
+                method,
+                path,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        if output.stdout.iter().all(u8::is_ascii_whitespace) {
+            return Ok(JsonValue::Null);
+        }
+        serde_json::from_slice(&output.stdout).map_err(|e| format!("{} {}: {}", method, path, e))
+    }
+}
+
+/// curl config with the authorization header and the request body, if any.
+fn curl_config(token: &str, body: Option<&JsonValue>) -> String {
+    let mut config = format!(
+        "header = {}\n",
+        quote_config(&format!("Authorization: Bearer {}", token))
+    );
+    if let Some(body) = body {
+        config.push_str(&format!(
+            "data-binary = {}\n",
+            quote_config(&body.to_string())
+        ));
+    }
+    config
+}
+
+/// `value` as a double-quoted curl config string.
+fn quote_config(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// `text` cut to `max` characters, ending in "..." if cut.
+pub fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(max.saturating_sub(3)).collect();
+    format!("{}...", kept)
+}
+
+#[cfg(test)]
+#[path = "github_tests.rs"]
+mod tests;
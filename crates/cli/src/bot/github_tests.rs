@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use crate::bot::server::read_request;
+
+#[test]
+fn credential_helper_answers_with_the_token() {
+    let mut child = Command::new("git")
+        .args(["-c", "credential.helper=", "-c", CREDENTIAL_HELPER])
+        .args(["credential", "fill"])
+        .env("GITHUB_TOKEN", "t0k")
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"protocol=https\nhost=github.com\n\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("username=x-access-token\n"), "{stdout}");
+    assert!(stdout.contains("password=t0k\n"), "{stdout}");
+}
+
+#[test]
+fn requests_send_token_and_body_through_curl_config() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let api_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let request = read_request(&mut std::io::BufReader::new(&stream)).unwrap();
+        let mut stream = &stream;
+        write!(
+            stream,
+            "HTTP/1.1 201 Created\r\nContent-Length: 2\r\n\r\n{{}}"
+        )
+        .unwrap();
+        request
+    });
+
+    GitHub::new(api_url, "t0k")
+        .set_status("acme/app", "abc", State::Success, "say \"hi\" \\ bye")
+        .unwrap();
+
+    let request = server.join().unwrap();
+    assert_eq!(request.path, "/repos/acme/app/statuses/abc");
+    assert_eq!(request.header("Authorization"), Some("Bearer t0k"));
+    let body: JsonValue = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(body["description"], "say \"hi\" \\ bye");
+}
+
+#[test]
+fn curl_config_without_body_has_only_the_header() {
+    assert_eq!(
+        curl_config("t0k", None),
+        "header = \"Authorization: Bearer t0k\"\n"
+    );
+}
+
+#[test]
+fn truncate_keeps_short_text() {
+    assert_eq!(truncate("All checks passed", 140), "All checks passed");
+}
+
+#[test]
+fn truncate_cuts_long_text_with_ellipsis() {
+    let cut = truncate(&"x".repeat(200), 140);
+
+    assert_eq!(cut.chars().count(), 140);
+    assert!(cut.ends_with("..."));
+}
+
+#[test]
+fn api_url_trailing_slash_is_dropped() {
+    let github = GitHub::new("https://ghe.example.com/api/v3/", "t0k");
+
+    assert_eq!(github.api_url, "https://ghe.example.com/api/v3");
+}
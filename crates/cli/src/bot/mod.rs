@@ -0,0 +1,266 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench bot`: a pull request quality gate driven by GitHub webhooks.
+//!
+//! For each opened or updated pull request the bot fetches the base and
+//! head into a local checkout, runs `quench check --base <base sha>` on the
+//! head, and posts a `quench` commit status plus a markdown comment with
+//! the check results and ratchet deltas.
+//!
+//! Pull requests run code on the bot's host, so fork pull requests are
+//! refused unless their owner is allowed, and the checks run with the base
+//! commit's `quench.toml` files unless the pull request's are trusted.
+
+pub mod event;
+pub mod github;
+pub mod report;
+pub mod server;
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::env::names;
+
+use event::{PullRequestEvent, Repository};
+use github::{GitHub, State};
+use report::CheckRun;
+
+/// Config files the bot takes from the base commit unless trusted.
+const CONFIG_FILE: &str = "quench.toml";
+
+/// Which pull requests the bot checks, and with whose configuration.
+#[derive(Debug, Clone, Default)]
+pub struct Policy {
+    /// Fork owners (`owner`) or forks (`owner/name`) whose pull requests
+    /// are checked. Other forks are refused.
+    pub allow_forks: Vec<String>,
+    /// Check with the pull request's own `quench.toml` files, and so run
+    /// the commands they configure, instead of the base commit's.
+    pub trust_config: bool,
+}
+
+impl Policy {
+    /// Whether pull requests from `fork` may be checked.
+    pub fn allows_fork(&self, fork: &Repository) -> bool {
+        self.allow_forks
+            .iter()
+            .any(|allowed| allowed == fork.owner() || *allowed == fork.full_name)
+    }
+}
+
+/// Checks pull requests and publishes the results.
+pub struct Bot {
+    /// Checkouts live under `work_dir/<owner>/<repo>`.
+    work_dir: PathBuf,
+    /// None prints statuses and comments instead of posting them (`--dry-run`).
+    github: Option<GitHub>,
+    /// The quench binary that runs the checks.
+    quench: PathBuf,
+    /// Pass `--ci` to `quench check`.
+    ci: bool,
+    policy: Policy,
+}
+
+impl Bot {
+    pub fn new(
+        work_dir: PathBuf,
+        github: Option<GitHub>,
+        quench: PathBuf,
+        ci: bool,
+        policy: Policy,
+    ) -> Self {
+        Self {
+            work_dir,
+            github,
+            quench,
+            ci,
+            policy,
+        }
+    }
+
+    /// Check a pull request and publish the status and comment.
+    ///
+    /// Returns the published state; errors mean the result couldn't be
+    /// published at all.
+    pub fn handle(&self, event: &PullRequestEvent) -> Result<State, String> {
+        let repo = &event.repository.full_name;
+        let head = &event.pull_request.head.sha;
+        let base = &event.pull_request.base.sha;
+        if let Some(fork) = event.fork().filter(|fork| !self.policy.allows_fork(fork)) {
+            let description = format!("Not checked: {} is not an allowed fork", fork.full_name);
+            self.publish_status(repo, head, State::Error, &description)?;
+            return Ok(State::Error);
+        }
+        self.publish_status(repo, head, State::Pending, "Running quench check")?;
+
+        let run = match self
+            .checkout(event)
+            .and_then(|dir| self.run_checks(&dir, base))
+        {
+            Ok(run) => run,
+            Err(e) => {
+                self.publish_status(repo, head, State::Error, &e)?;
+                return Ok(State::Error);
+            }
+        };
+        let (state, description) = report::status(&run);
+        self.publish_comment(repo, event.number, &report::render(&run, head, base))?;
+        self.publish_status(repo, head, state, &description)?;
+        Ok(state)
+    }
+
+    /// Fetch the base and head branches and check out the head commit.
+    fn checkout(&self, event: &PullRequestEvent) -> Result<PathBuf, String> {
+        let dir = self.work_dir.join(&event.repository.full_name);
+        if !dir.join(".git").exists() {
+            std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+            self.git(&dir, &["init", "--quiet"])?;
+        }
+        let pr = &event.pull_request;
+        let base_url = &event.repository.clone_url;
+        let head_url = event.head_clone_url();
+
+        self.git(
+            &dir,
+            &[
+                "fetch",
+                "--quiet",
+                base_url,
+                &format!(
+                    "+refs/heads/{}:refs/remotes/base/{}",
+                    pr.base.ref_name, pr.base.ref_name
+                ),
+            ],
+        )?;
+        // Baselines live in git notes; without them there's just no ratchet
+        let _ = self.git(
+            &dir,
+            &[
+                "fetch",
+                "--quiet",
+                base_url,
+                "+refs/notes/quench:refs/notes/quench",
+            ],
+        );
+        self.git(
+            &dir,
+            &[
+                "fetch",
+                "--quiet",
+                head_url,
+                &format!(
+                    "+refs/heads/{}:refs/remotes/pr/{}",
+                    pr.head.ref_name, event.number
+                ),
+            ],
+        )?;
+        self.git(
+            &dir,
+            &["checkout", "--quiet", "--force", "--detach", &pr.head.sha],
+        )
+        .map_err(|e| format!("head {} not fetched: {}", pr.head.sha, e))?;
+        self.git(&dir, &["clean", "--quiet", "-fdx"])?;
+        if !self.policy.trust_config {
+            self.pin_config(&dir, &pr.base.sha, &pr.head.sha)?;
+        }
+        Ok(dir)
+    }
+
+    /// Put back the base commit's `quench.toml` files wherever the head
+    /// changed them, so the pull request can't configure what runs.
+    fn pin_config(&self, dir: &Path, base: &str, head: &str) -> Result<(), String> {
+        let changed = self.git_output(dir, &["diff", "--name-only", "-z", base, head])?;
+        for path in changed
+            .split('\0')
+            .filter(|path| Path::new(path).file_name() == Some(std::ffi::OsStr::new(CONFIG_FILE)))
+        {
+            let in_base = format!("{}:{}", base, path);
+            if self.git(dir, &["cat-file", "-e", &in_base]).is_ok() {
+                self.git(dir, &["checkout", "--quiet", base, "--", path])?;
+            } else {
+                std::fs::remove_file(dir.join(path)).map_err(|e| e.to_string())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Run `quench check` in `dir` against `base`.
+    fn run_checks(&self, dir: &Path, base: &str) -> Result<CheckRun, String> {
+        let mut cmd = Command::new(&self.quench);
+        cmd.args(["check", "--base", base, "-o", "json"]);
+        if self.ci {
+            cmd.arg("--ci");
+        }
+        // The checked code (test suites, builds) must not see the bot's secrets
+        cmd.current_dir(dir)
+            .env_remove(names::GITHUB_TOKEN)
+            .env_remove(names::QUENCH_WEBHOOK_SECRET);
+        let output = cmd
+            .output()
+            .map_err(|e| format!("cannot run {}: {}", self.quench.display(), e))?;
+        serde_json::from_slice(&output.stdout).map_err(|_| {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let reason = stderr.lines().last().unwrap_or("no output");
+            format!("quench check failed: {}", reason)
+        })
+    }
+
+    fn publish_status(
+        &self,
+        repo: &str,
+        sha: &str,
+        state: State,
+        description: &str,
+    ) -> Result<(), String> {
+        match &self.github {
+            Some(github) => github.set_status(repo, sha, state, description),
+            None => {
+                println!("status: {} - {}", state.as_str(), description);
+                Ok(())
+            }
+        }
+    }
+
+    fn publish_comment(&self, repo: &str, number: u64, body: &str) -> Result<(), String> {
+        match &self.github {
+            Some(github) => github.upsert_comment(repo, number, body),
+            None => {
+                print!("{}", body);
+                Ok(())
+            }
+        }
+    }
+
+    /// Run git in `dir`, keeping the token out of error messages.
+    fn git(&self, dir: &Path, args: &[&str]) -> Result<(), String> {
+        self.git_output(dir, args).map(|_| ())
+    }
+
+    /// Run git in `dir` and return its stdout.
+    fn git_output(&self, dir: &Path, args: &[&str]) -> Result<String, String> {
+        let mut cmd = Command::new("git");
+        if let Some(github) = &self.github {
+            // Fetches authenticate through the helper, never the URL
+            cmd.args(["-c", "credential.helper=", "-c", github::CREDENTIAL_HELPER])
+                .env(names::GITHUB_TOKEN, github.token());
+        }
+        let output = cmd
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .map_err(|e| format!("cannot run git: {}", e))?;
+        if output.status.success() {
+            return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        Err(match &self.github {
+            Some(github) => stderr.replace(github.token(), "***"),
+            None => stderr,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn fork(full_name: &str) -> Repository {
+    Repository {
+        full_name: full_name.to_string(),
+        clone_url: format!("https://github.com/{}.git", full_name),
+    }
+}
+
+#[test]
+fn forks_are_refused_by_default() {
+    assert!(!Policy::default().allows_fork(&fork("fork/app")));
+}
+
+#[test]
+fn forks_are_allowed_by_owner_or_full_name() {
+    let policy = Policy {
+        allow_forks: vec!["trusted".to_string(), "partner/app".to_string()],
+        ..Default::default()
+    };
+
+    assert!(policy.allows_fork(&fork("trusted/app")));
+    assert!(policy.allows_fork(&fork("partner/app")));
+    assert!(!policy.allows_fork(&fork("partner/other")));
+    assert!(!policy.allows_fork(&fork("stranger/app")));
+}
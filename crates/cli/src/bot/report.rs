@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Status descriptions and the markdown PR comment for a check run.
+
+use std::fmt::Write;
+
+use serde::Deserialize;
+
+use crate::check::CheckResult;
+
+use super::github::{COMMENT_MARKER, State};
+
+/// Violations listed in the comment before the rest are summarized.
+const MAX_LISTED_VIOLATIONS: usize = 50;
+
+/// `quench check -o json` output, as far as the report needs it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckRun {
+    pub passed: bool,
+    pub checks: Vec<CheckResult>,
+    #[serde(default)]
+    pub ratchet: Option<RatchetRun>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RatchetRun {
+    pub passed: bool,
+    pub comparisons: Vec<Comparison>,
+}
+
+/// A ratcheted metric: head value against the base baseline.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Comparison {
+    pub name: String,
+    pub current: f64,
+    pub baseline: f64,
+    pub passed: bool,
+    pub improved: bool,
+}
+
+impl CheckRun {
+    fn failed_checks(&self) -> Vec<&str> {
+        self.checks
+            .iter()
+            .filter(|c| !c.passed && !c.skipped)
+            .map(|c| c.name.as_str())
+            .collect()
+    }
+
+    fn regressions(&self) -> Vec<&Comparison> {
+        self.ratchet
+            .iter()
+            .flat_map(|r| r.comparisons.iter().filter(|c| !c.passed))
+            .collect()
+    }
+}
+
+/// Commit status state and description for a run.
+pub fn status(run: &CheckRun) -> (State, String) {
+    if run.passed {
+        return (State::Success, "All checks passed".to_string());
+    }
+    let mut problems = Vec::new();
+    let failed = run.failed_checks();
+    if !failed.is_empty() {
+        let ran = run.checks.iter().filter(|c| !c.skipped).count();
+        problems.push(format!(
+            "{} of {} checks failed: {}",
+            failed.len(),
+            ran,
+            failed.join(", ")
+        ));
+    }
+    let regressions = run.regressions();
+    if !regressions.is_empty() {
+        let names: Vec<&str> = regressions.iter().map(|c| c.name.as_str()).collect();
+        problems.push(format!("ratchet regressed: {}", names.join(", ")));
+    }
+    (State::Failure, problems.join("; "))
+}
+
+/// Markdown comment comparing `head` against `base`.
+pub fn render(run: &CheckRun, head: &str, base: &str) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{}", COMMENT_MARKER);
+    let _ = writeln!(
+        out,
+        "### quench: {}\n",
+        if run.passed { "PASS" } else { "FAIL" }
+    );
+    let (_, description) = status(run);
+    let _ = writeln!(
+        out,
+        "{} on `{}` (base `{}`).\n",
+        description,
+        short(head),
+        short(base)
+    );
+
+    let _ = writeln!(out, "| Check | Result | Violations |");
+    let _ = writeln!(out, "|-------|--------|-----------:|");
+    for check in &run.checks {
+        let result = if check.skipped {
+            "SKIP"
+        } else if check.passed {
+            "PASS"
+        } else {
+            "FAIL"
+        };
+        let _ = writeln!(
+            out,
+            "| {} | {} | {} |",
+            check.name,
+            result,
+            check.violations.len()
+        );
+    }
+
+    if let Some(ratchet) = run.ratchet.as_ref().filter(|r| !r.comparisons.is_empty()) {
+        let _ = writeln!(out, "\n#### Ratchet\n");
+        let _ = writeln!(out, "| Metric | Base | Head | |");
+        let _ = writeln!(out, "|--------|-----:|-----:|---|");
+        for c in &ratchet.comparisons {
+            let change = if !c.passed {
+                "regressed"
+            } else if c.improved {
+                "improved"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {} |",
+                c.name,
+                number(c.baseline),
+                number(c.current),
+                change
+            );
+        }
+    }
+
+    render_violations(&mut out, run);
+    out
+}
+
+fn render_violations(out: &mut String, run: &CheckRun) {
+    let violations: Vec<_> = run
+        .checks
+        .iter()
+        .flat_map(|c| c.violations.iter().map(move |v| (c.name.as_str(), v)))
+        .collect();
+    if violations.is_empty() {
+        return;
+    }
+    let _ = writeln!(
+        out,
+        "\n<details><summary>Violations ({})</summary>\n",
+        violations.len()
+    );
+    for (check, v) in violations.iter().take(MAX_LISTED_VIOLATIONS) {
+        let location = match (&v.file, v.line) {
+            (Some(file), Some(line)) => format!("`{}:{}` ", file.display(), line),
+            (Some(file), None) => format!("`{}` ", file.display()),
+            _ => String::new(),
+        };
+        let _ = writeln!(
+            out,
+            "- {}{} **{}**: {}",
+            location,
+            check,
+            v.violation_type,
+            v.advice.lines().next().unwrap_or_default()
+        );
+    }
+    if violations.len() > MAX_LISTED_VIOLATIONS {
+        let _ = writeln!(
+            out,
+            "- ... and {} more",
+            violations.len() - MAX_LISTED_VIOLATIONS
+        );
+    }
+    let _ = writeln!(out, "\n</details>");
+}
+
+/// Abbreviated commit SHA.
+fn short(sha: &str) -> &str {
+    &sha[..7.min(sha.len())]
+}
+
+/// Metric value without trailing zeros (e.g., 12, 81.25).
+fn number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        let s = format!("{:.2}", value);
+        s.trim_end_matches('0').to_string()
+    }
+}
+
+#[cfg(test)]
+#[path = "report_tests.rs"]
+mod tests;
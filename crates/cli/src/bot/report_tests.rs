@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn run(json: &str) -> CheckRun {
+    serde_json::from_str(json).unwrap()
+}
+
+const FAILING: &str = r#"{
+    "timestamp": "2026-01-01T00:00:00Z",
+    "passed": false,
+    "checks": [
+        {
+            "name": "cloc",
+            "passed": false,
+            "violations": [
+                {
+                    "file": "src/big.rs",
+                    "line": 1,
+                    "type": "file_too_large",
+                    "advice": "Split into smaller modules.\nMore detail.",
+                    "value": 900,
+                    "threshold": 750
+                }
+            ]
+        },
+        { "name": "escapes", "passed": true, "violations": [] },
+        { "name": "tests", "passed": true, "skipped": true, "error": "no suites", "violations": [] }
+    ],
+    "ratchet": {
+        "passed": false,
+        "comparisons": [
+            { "name": "coverage.total", "current": 78.25, "baseline": 80.0, "passed": false, "improved": false },
+            { "name": "escapes.unsafe", "current": 2.0, "baseline": 3.0, "passed": true, "improved": true }
+        ]
+    }
+}"#;
+
+#[test]
+fn status_of_passing_run_is_success() {
+    let run =
+        run(r#"{"passed": true, "checks": [{"name": "cloc", "passed": true, "violations": []}]}"#);
+
+    assert_eq!(
+        status(&run),
+        (State::Success, "All checks passed".to_string())
+    );
+}
+
+#[test]
+fn status_names_failed_checks_and_regressions() {
+    let (state, description) = status(&run(FAILING));
+
+    assert_eq!(state, State::Failure);
+    assert_eq!(
+        description,
+        "1 of 2 checks failed: cloc; ratchet regressed: coverage.total"
+    );
+}
+
+#[test]
+fn render_starts_with_marker_and_compares_head_to_base() {
+    let body = render(&run(FAILING), "abc1234def", "0123456789");
+
+    assert!(body.starts_with(COMMENT_MARKER));
+    assert!(body.contains("### quench: FAIL"));
+    assert!(body.contains("on `abc1234` (base `0123456`)"));
+}
+
+#[test]
+fn render_tables_checks_and_ratchet_deltas() {
+    let body = render(&run(FAILING), "abc1234", "def5678");
+
+    assert!(body.contains("| cloc | FAIL | 1 |"));
+    assert!(body.contains("| escapes | PASS | 0 |"));
+    assert!(body.contains("| tests | SKIP | 0 |"));
+    assert!(body.contains("| coverage.total | 80 | 78.25 | regressed |"));
+    assert!(body.contains("| escapes.unsafe | 3 | 2 | improved |"));
+}
+
+#[test]
+fn render_lists_violations_with_first_advice_line() {
+    let body = render(&run(FAILING), "abc1234", "def5678");
+
+    assert!(body.contains("<details><summary>Violations (1)</summary>"));
+    assert!(
+        body.contains("- `src/big.rs:1` cloc **file_too_large**: Split into smaller modules.\n")
+    );
+}
+
+#[test]
+fn render_caps_listed_violations() {
+    let violations: Vec<String> = (0..60)
+        .map(|i| format!(r#"{{"file": "f{i}.rs", "type": "t", "advice": "a"}}"#))
+        .collect();
+    let json = format!(
+        r#"{{"passed": false, "checks": [{{"name": "cloc", "passed": false, "violations": [{}]}}]}}"#,
+        violations.join(",")
+    );
+
+    let body = render(&run(&json), "abc1234", "def5678");
+
+    assert!(body.contains("- ... and 10 more"));
+    assert!(!body.contains("f55.rs"));
+}
@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Minimal HTTP listener for GitHub webhook deliveries.
+//!
+//! GitHub expects a reply within 10 seconds, so each connection is read on
+//! its own thread, and deliveries are verified, acknowledged, and queued;
+//! checks run on a separate worker. Threads, request heads, and bodies are
+//! all capped, so a flood of connections can't exhaust memory.
+
+use std::io::{BufRead, BufReader, Read, Take, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use super::event::PullRequestEvent;
+
+/// Largest payload GitHub delivers.
+pub const MAX_BODY: usize = 25 * 1024 * 1024;
+
+/// Largest request line and headers, together.
+pub const MAX_HEAD: usize = 64 * 1024;
+
+/// Connections read at once; more are turned away with a 503.
+pub const MAX_CONNECTIONS: usize = 16;
+
+/// An HTTP request.
+#[derive(Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// Value of header `name` (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Status code and message to reply with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reply {
+    pub status: u16,
+    pub message: &'static str,
+}
+
+impl Reply {
+    const fn new(status: u16, message: &'static str) -> Self {
+        Self { status, message }
+    }
+}
+
+/// Read one HTTP/1.1 request.
+pub fn read_request(reader: &mut impl BufRead) -> Result<Request, String> {
+    let mut head = Read::take(&mut *reader, MAX_HEAD as u64);
+    let mut line = String::new();
+    read_head_line(&mut head, &mut line)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(format!("malformed request line {:?}", line.trim_end()));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        read_head_line(&mut head, &mut line)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header
+            .split_once(':')
+            .ok_or_else(|| format!("malformed header {:?}", header))?;
+        headers.push((name.trim().to_string(), value.trim().to_string()));
+    }
+
+    let mut request = Request {
+        method,
+        path,
+        headers,
+        body: Vec::new(),
+    };
+    let length: usize = match request.header("Content-Length") {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("invalid Content-Length {:?}", value))?,
+        None => 0,
+    };
+    if length > MAX_BODY {
+        return Err(format!("body of {} bytes is too large", length));
+    }
+    request.body.resize(length, 0);
+    reader
+        .read_exact(&mut request.body)
+        .map_err(|e| e.to_string())?;
+    Ok(request)
+}
+
+/// Read one line of the request head, which must end within [`MAX_HEAD`].
+fn read_head_line(head: &mut Take<impl BufRead>, line: &mut String) -> Result<(), String> {
+    head.read_line(line).map_err(|e| e.to_string())?;
+    if line.ends_with('\n') {
+        Ok(())
+    } else if head.limit() == 0 {
+        Err(format!("request head is over {} bytes", MAX_HEAD))
+    } else {
+        Err("request ended before its headers did".to_string())
+    }
+}
+
+/// Whether `signature` (`X-Hub-Signature-256`) is the HMAC of `body`.
+pub fn verify_signature(secret: &[u8], body: &[u8], signature: Option<&str>) -> bool {
+    let Some(expected) = signature
+        .and_then(|s| s.strip_prefix("sha256="))
+        .and_then(decode_hex)
+    else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret) else {
+        return false;
+    };
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// Decide how to answer a delivery, and the event to check if any.
+pub fn route(request: &Request, secret: &[u8]) -> (Reply, Option<PullRequestEvent>) {
+    if request.method != "POST" {
+        return (Reply::new(405, "POST webhook deliveries only"), None);
+    }
+    let signature = request.header("X-Hub-Signature-256");
+    if !verify_signature(secret, &request.body, signature) {
+        return (Reply::new(401, "invalid signature"), None);
+    }
+    match request.header("X-GitHub-Event") {
+        Some("ping") => (Reply::new(200, "pong"), None),
+        Some("pull_request") => {
+            let payload = String::from_utf8_lossy(&request.body);
+            match PullRequestEvent::parse(&payload) {
+                Ok(event) if event.wants_check() => (Reply::new(202, "queued"), Some(event)),
+                Ok(_) => (Reply::new(200, "ignored"), None),
+                Err(_) => (Reply::new(400, "malformed pull_request payload"), None),
+            }
+        }
+        _ => (Reply::new(200, "ignored"), None),
+    }
+}
+
+/// Answer deliveries on `listener` forever, sending events to `queue`.
+///
+/// A slow client only holds up its own connection. Past
+/// [`MAX_CONNECTIONS`] open at once, new ones are answered 503 unread.
+pub fn serve(listener: TcpListener, secret: &[u8], queue: &Sender<PullRequestEvent>) {
+    let open = AtomicUsize::new(0);
+    std::thread::scope(|scope| {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { continue };
+            if open.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+                open.fetch_sub(1, Ordering::SeqCst);
+                respond(&stream, Reply::new(503, "too many connections"));
+                continue;
+            }
+            let open = &open;
+            scope.spawn(move || {
+                if let Some(event) = answer(stream, secret) {
+                    let _ = queue.send(event);
+                }
+                open.fetch_sub(1, Ordering::SeqCst);
+            });
+        }
+    });
+}
+
+fn answer(stream: TcpStream, secret: &[u8]) -> Option<PullRequestEvent> {
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(10)));
+    let mut reader = BufReader::new(&stream);
+    let (reply, event) = match read_request(&mut reader) {
+        Ok(request) => route(&request, secret),
+        Err(_) => (Reply::new(400, "malformed request"), None),
+    };
+    respond(&stream, reply);
+    event
+}
+
+fn respond(mut stream: &TcpStream, reply: Reply) {
+    let _ = stream.set_write_timeout(Some(Duration::from_secs(10)));
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        reply.status,
+        reason(reply.status),
+        reply.message.len(),
+        reply.message
+    );
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        202 => "Accepted",
+        401 => "Unauthorized",
+        405 => "Method Not Allowed",
+        503 => "Service Unavailable",
+        _ => "Bad Request",
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "server_tests.rs"]
+mod tests;
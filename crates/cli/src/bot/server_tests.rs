@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+const SECRET: &[u8] = b"It's a Secret to Everybody";
+
+/// Signature from GitHub's webhook validation docs for `SECRET`.
+const HELLO_SIGNATURE: &str =
+    "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+const OPENED: &str = r#"{
+    "action": "opened",
+    "number": 1,
+    "pull_request": {
+        "head": { "ref": "feature", "sha": "abc", "repo": null },
+        "base": { "ref": "main", "sha": "def", "repo": null }
+    },
+    "repository": { "full_name": "acme/app", "clone_url": "https://github.com/acme/app.git" }
+}"#;
+
+fn sign(body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(SECRET).unwrap();
+    mac.update(body);
+    let hex: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    format!("sha256={}", hex)
+}
+
+fn delivery(event: &str, body: &str, signature: Option<String>) -> Request {
+    let mut headers = vec![("X-GitHub-Event".to_string(), event.to_string())];
+    if let Some(signature) = signature {
+        headers.push(("X-Hub-Signature-256".to_string(), signature));
+    }
+    Request {
+        method: "POST".to_string(),
+        path: "/".to_string(),
+        headers,
+        body: body.as_bytes().to_vec(),
+    }
+}
+
+#[test]
+fn reads_request_line_headers_and_body() {
+    let raw = b"POST /webhook HTTP/1.1\r\nHost: bot\r\ncontent-length: 5\r\nX-GitHub-Event: ping\r\n\r\nhello";
+
+    let request = read_request(&mut &raw[..]).unwrap();
+
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/webhook");
+    assert_eq!(request.header("X-GitHub-Event"), Some("ping"));
+    assert_eq!(request.body, b"hello");
+}
+
+#[test]
+fn rejects_oversized_body() {
+    let raw = format!(
+        "POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+        MAX_BODY + 1
+    );
+
+    let err = read_request(&mut raw.as_bytes()).unwrap_err();
+
+    assert!(err.contains("too large"), "{err}");
+}
+
+#[test]
+fn rejects_oversized_request_line() {
+    let raw = format!("POST /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_HEAD));
+
+    let err = read_request(&mut raw.as_bytes()).unwrap_err();
+
+    assert!(err.contains("request head is over"), "{err}");
+}
+
+#[test]
+fn rejects_oversized_headers() {
+    let header = format!("X-Padding: {}\r\n", "a".repeat(1024));
+    let raw = format!("POST / HTTP/1.1\r\n{}\r\n", header.repeat(MAX_HEAD / 1024));
+
+    let err = read_request(&mut raw.as_bytes()).unwrap_err();
+
+    assert!(err.contains("request head is over"), "{err}");
+}
+
+#[test]
+fn rejects_request_ending_mid_headers() {
+    let raw = b"POST / HTTP/1.1\r\nHost: bot";
+
+    let err = read_request(&mut &raw[..]).unwrap_err();
+
+    assert!(err.contains("ended before its headers"), "{err}");
+}
+
+#[test]
+fn verifies_github_signature() {
+    assert!(verify_signature(
+        SECRET,
+        b"Hello, World!",
+        Some(HELLO_SIGNATURE)
+    ));
+    assert!(!verify_signature(
+        SECRET,
+        b"Hello, World?",
+        Some(HELLO_SIGNATURE)
+    ));
+    assert!(!verify_signature(SECRET, b"Hello, World!", None));
+    assert!(!verify_signature(
+        SECRET,
+        b"Hello, World!",
+        Some("sha256=zz")
+    ));
+}
+
+#[test]
+fn queues_signed_pull_request() {
+    let request = delivery("pull_request", OPENED, Some(sign(OPENED.as_bytes())));
+
+    let (reply, event) = route(&request, SECRET);
+
+    assert_eq!(reply.status, 202);
+    assert_eq!(event.unwrap().label(), "acme/app#1");
+}
+
+#[test]
+fn rejects_unsigned_delivery() {
+    let request = delivery("pull_request", OPENED, None);
+
+    let (reply, event) = route(&request, SECRET);
+
+    assert_eq!(reply.status, 401);
+    assert!(event.is_none());
+}
+
+#[test]
+fn answers_ping_and_ignores_other_events() {
+    let ping = delivery("ping", "{}", Some(sign(b"{}")));
+    assert_eq!(route(&ping, SECRET).0, Reply::new(200, "pong"));
+
+    let push = delivery("push", "{}", Some(sign(b"{}")));
+    assert_eq!(route(&push, SECRET).0, Reply::new(200, "ignored"));
+
+    let closed = OPENED.replace("opened", "closed");
+    let request = delivery("pull_request", &closed, Some(sign(closed.as_bytes())));
+    let (reply, event) = route(&request, SECRET);
+    assert_eq!(reply, Reply::new(200, "ignored"));
+    assert!(event.is_none());
+}
+
+#[test]
+fn rejects_non_post_requests() {
+    let mut request = delivery("ping", "", None);
+    request.method = "GET".to_string();
+
+    assert_eq!(route(&request, SECRET).0.status, 405);
+}
+
+#[test]
+fn slow_client_does_not_hold_up_other_deliveries() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || serve(listener, SECRET, &tx));
+
+    // Connects, then never finishes its request
+    let _slow = TcpStream::connect(addr).unwrap();
+
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    write!(
+        stream,
+        "POST / HTTP/1.1\r\nX-GitHub-Event: pull_request\r\nX-Hub-Signature-256: {}\r\nContent-Length: {}\r\n\r\n{}",
+        sign(OPENED.as_bytes()),
+        OPENED.len(),
+        OPENED
+    )
+    .unwrap();
+    let mut reply = String::new();
+    std::io::Read::read_to_string(&mut stream, &mut reply).unwrap();
+
+    assert!(reply.starts_with("HTTP/1.1 202 Accepted"), "{reply}");
+    let event = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(event.label(), "acme/app#1");
+}
+
+#[test]
+fn turns_away_connections_past_the_limit() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, _rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || serve(listener, SECRET, &tx));
+
+    // Each connects, then never sends its request
+    let _idle: Vec<_> = (0..MAX_CONNECTIONS)
+        .map(|_| TcpStream::connect(addr).unwrap())
+        .collect();
+
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let mut reply = String::new();
+    std::io::Read::read_to_string(&mut stream, &mut reply).unwrap();
+
+    assert!(
+        reply.starts_with("HTTP/1.1 503 Service Unavailable"),
+        "{reply}"
+    );
+}
@@ -1,633 +0,0 @@
-// SPDX-License-Identifier: MIT
-// Copyright (c) 2026 Alfred Jean LLC
-
-//! Escapes (escape hatches) check.
-//!
-//! Detects patterns that bypass type safety or error handling.
-//! See docs/specs/checks/escape-hatches.md.
-
-mod comment;
-mod go_suppress;
-mod javascript_suppress;
-mod lint_policy;
-mod metrics;
-mod patterns;
-mod python_suppress;
-mod ruby_suppress;
-mod shell_suppress;
-mod suppress_common;
-mod violations;
-
-use std::collections::HashSet;
-use std::path::Path;
-
-use globset::GlobSet;
-
-use crate::adapter::glob::build_glob_set;
-use crate::adapter::{CfgTestInfo, FileKind, GenericAdapter, parse_suppress_attrs};
-use crate::check::{Check, CheckContext, CheckResult, Violation};
-use crate::config::{CheckLevel, EscapeAction, SuppressConfig, SuppressLevel};
-use crate::file_reader::FileContent;
-use go_suppress::check_go_suppress_violations;
-use javascript_suppress::check_javascript_suppress_violations;
-use python_suppress::check_python_suppress_violations;
-use ruby_suppress::check_ruby_suppress_violations;
-use shell_suppress::check_shell_suppress_violations;
-use suppress_common::{
-    SuppressAttrInfo, SuppressCheckParams, SuppressViolationKind, check_suppress_attr,
-};
-
-use comment::{has_justification_comment, is_match_in_comment};
-use metrics::EscapesMetrics;
-use patterns::{
-    compile_merged_patterns, default_test_patterns, get_adapter_escape_patterns, merge_patterns,
-};
-use violations::{create_threshold_violation, format_comment_advice, try_create_violation};
-
-/// The escapes check detects escape hatch patterns.
-pub struct EscapesCheck;
-
-impl Check for EscapesCheck {
-    fn name(&self) -> &'static str {
-        "escapes"
-    }
-
-    fn description(&self) -> &'static str {
-        "Escape hatch detection"
-    }
-
-    fn run(&self, ctx: &CheckContext) -> CheckResult {
-        let config = &ctx.config.check.escapes;
-
-        if config.check == CheckLevel::Off {
-            return CheckResult::passed(self.name());
-        }
-
-        // Check lint policy for language-specific projects (only when --base is provided)
-        let policy_result = lint_policy::check_lint_policy(ctx);
-
-        // Get adapter default patterns for the detected language
-        let adapter_patterns = get_adapter_escape_patterns(ctx.root);
-
-        // Merge patterns: config patterns override adapter defaults by name
-        let merged_patterns = merge_patterns(&config.patterns, &adapter_patterns);
-
-        // No patterns to check = pass
-        if merged_patterns.is_empty() {
-            return CheckResult::passed(self.name());
-        }
-
-        // Compile patterns once
-        let patterns = match compile_merged_patterns(&merged_patterns) {
-            Ok(p) => p,
-            Err(e) => return CheckResult::skipped(self.name(), e.to_string()),
-        };
-
-        // Collect pattern names for metrics output
-        let pattern_names: Vec<String> = patterns.iter().map(|p| p.name.clone()).collect();
-
-        // Get packages for by_package tracking
-        let packages = &ctx.config.project.packages;
-
-        // Use project test patterns or defaults
-        let test_patterns = if ctx.config.project.tests.is_empty() {
-            default_test_patterns()
-        } else {
-            ctx.config.project.tests.clone()
-        };
-
-        // Create adapter once for file classification (optimization: avoid per-file allocation)
-        let file_adapter = GenericAdapter::new(&[], &test_patterns);
-
-        // Build exclude matcher
-        let exclude_matcher = ExcludeMatcher::new(&config.exclude);
-
-        let mut violations = Vec::new();
-        let mut metrics = EscapesMetrics::new();
-        let mut limit_reached = false;
-
-        for file in ctx.files {
-            if limit_reached {
-                break;
-            }
-
-            // Skip non-source files (configs, docs, etc.)
-            if !is_source_file(&file.path) {
-                continue;
-            }
-
-            // Skip excluded files
-            if exclude_matcher.is_excluded(&file.path, ctx.root) {
-                continue;
-            }
-
-            // Read file content (uses mmap for large files per performance spec)
-            let file_content = match FileContent::read(&file.path) {
-                Ok(c) => c,
-                Err(_) => continue,
-            };
-            let Some(content) = file_content.as_str() else {
-                continue; // Skip non-UTF-8 files
-            };
-
-            let relative = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
-
-            // Classify file as source or test
-            let is_test_file = classify_file(&file_adapter, &file.path, ctx.root) == FileKind::Test;
-            let package = find_package(&file.path, ctx.root, packages);
-
-            // Parse cfg(test) info for Rust files (reuse for suppress + escape checking)
-            let cfg_info = if has_extension(&file.path, &["rs"]) {
-                Some(CfgTestInfo::parse(content))
-            } else {
-                None
-            };
-
-            // Check for Rust suppress attribute violations
-            if let Some(ref info) = cfg_info {
-                let suppress_violations = check_suppress_violations(
-                    ctx,
-                    relative,
-                    content,
-                    &ctx.config.rust.suppress,
-                    is_test_file,
-                    info,
-                    &mut limit_reached,
-                );
-                violations.extend(suppress_violations);
-
-                if limit_reached {
-                    break;
-                }
-            }
-
-            // Check for Shell shellcheck suppress directive violations
-            if has_extension(&file.path, &["sh", "bash", "bats"]) {
-                let shell_violations = check_shell_suppress_violations(
-                    ctx,
-                    relative,
-                    content,
-                    &ctx.config.shell.suppress,
-                    is_test_file,
-                    &mut limit_reached,
-                );
-                violations.extend(shell_violations);
-
-                if limit_reached {
-                    break;
-                }
-            }
-
-            // Check for Go nolint directive violations
-            if has_extension(&file.path, &["go"]) {
-                let go_violations = check_go_suppress_violations(
-                    ctx,
-                    relative,
-                    content,
-                    &ctx.config.golang.suppress,
-                    is_test_file,
-                    &mut limit_reached,
-                );
-                violations.extend(go_violations);
-
-                if limit_reached {
-                    break;
-                }
-            }
-
-            // Check for JavaScript/TypeScript suppress directive violations
-            if has_extension(&file.path, &["js", "jsx", "ts", "tsx", "mjs", "mts"]) {
-                let js_violations = check_javascript_suppress_violations(
-                    ctx,
-                    relative,
-                    content,
-                    &ctx.config.javascript.suppress,
-                    is_test_file,
-                    &mut limit_reached,
-                );
-                violations.extend(js_violations);
-
-                if limit_reached {
-                    break;
-                }
-            }
-
-            // Check for Ruby RuboCop/Standard suppress directive violations
-            if has_extension(&file.path, &["rb", "rake"]) {
-                let ruby_violations = check_ruby_suppress_violations(
-                    ctx,
-                    relative,
-                    content,
-                    &ctx.config.ruby.suppress,
-                    is_test_file,
-                    &mut limit_reached,
-                );
-                violations.extend(ruby_violations);
-
-                if limit_reached {
-                    break;
-                }
-            }
-
-            // Check for Python suppress directive violations (noqa, type: ignore, pylint)
-            if has_extension(&file.path, &["py"]) {
-                let python_violations = check_python_suppress_violations(
-                    ctx,
-                    relative,
-                    content,
-                    &ctx.config.python.suppress,
-                    is_test_file,
-                    &mut limit_reached,
-                );
-                violations.extend(python_violations);
-
-                if limit_reached {
-                    break;
-                }
-            }
-
-            // Find matches for each pattern
-            for pattern in &patterns {
-                let matches = pattern.matcher.find_all_with_lines(content);
-
-                // Deduplicate matches by line - keep only first match per line
-                // This prevents duplicate violations when pattern appears multiple
-                // times on same line (e.g., in code AND in a comment)
-                let mut seen_lines = HashSet::new();
-                let unique_matches: Vec<_> = matches
-                    .into_iter()
-                    .filter(|m| seen_lines.insert(m.line))
-                    .collect();
-
-                for m in unique_matches {
-                    // Calculate offset of match within the line
-                    let line_start = content[..m.offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
-                    let offset_in_line = m.offset - line_start;
-
-                    // For comment and forbid actions, skip matches that appear only in comments.
-                    // This prevents false positives like "don't use eval" in explanatory comments.
-                    // Count action patterns (like TODO/FIXME) are often legitimately in comments.
-                    let skip_comment_matches =
-                        matches!(pattern.action, EscapeAction::Comment | EscapeAction::Forbid);
-                    if skip_comment_matches && is_match_in_comment(&m.line_content, offset_in_line)
-                    {
-                        continue;
-                    }
-
-                    // Check if line is in test code (file-level OR inline #[cfg(test)])
-                    // Note: m.line is 1-indexed, but is_test_line expects 0-indexed
-                    let is_test_code = is_test_file
-                        || cfg_info.as_ref().is_some_and(|info| {
-                            info.is_test_line(m.line.saturating_sub(1) as usize)
-                        });
-
-                    // Always track metrics (both source and test)
-                    metrics.increment(&pattern.name, is_test_code);
-                    if let Some(ref pkg) = package {
-                        metrics.increment_package(pkg, &pattern.name, is_test_code);
-                    }
-
-                    // Handle test code based on pattern's in_tests setting
-                    if is_test_code {
-                        // Determine effective action for test code
-                        let test_action = match pattern.in_tests.as_deref() {
-                            Some("allow") => None, // Skip violations
-                            Some("forbid") => Some(EscapeAction::Forbid),
-                            Some("comment") => Some(EscapeAction::Comment),
-                            // Default: all patterns are allowed in tests (original behavior)
-                            // To make a pattern forbidden in tests, set in_tests = "forbid"
-                            None => None,
-                            _ => None, // Unknown value -> allow
-                        };
-
-                        // Skip if no violations needed for test code
-                        if test_action.is_none() {
-                            continue;
-                        }
-                    }
-
-                    // Source code: apply action logic
-                    match pattern.action {
-                        EscapeAction::Count => {
-                            // Just count - threshold check happens after all files
-                        }
-                        EscapeAction::Comment => {
-                            let comment_pattern =
-                                pattern.comment.as_deref().unwrap_or("// JUSTIFIED:");
-
-                            if !has_justification_comment(content, m.line, comment_pattern) {
-                                let advice =
-                                    format_comment_advice(&pattern.advice, comment_pattern);
-                                if let Some(v) = try_create_violation(
-                                    ctx,
-                                    relative,
-                                    m.line,
-                                    "missing_comment",
-                                    &advice,
-                                    &pattern.name,
-                                ) {
-                                    violations.push(v);
-                                } else {
-                                    limit_reached = true;
-                                    break;
-                                }
-                            }
-                        }
-                        EscapeAction::Forbid => {
-                            if let Some(v) = try_create_violation(
-                                ctx,
-                                relative,
-                                m.line,
-                                "forbidden",
-                                &pattern.advice,
-                                &pattern.name,
-                            ) {
-                                violations.push(v);
-                            } else {
-                                limit_reached = true;
-                                break;
-                            }
-                        }
-                    }
-                }
-
-                if limit_reached {
-                    break;
-                }
-            }
-        }
-
-        // Check count thresholds after scanning all files (uses metrics)
-        for pattern in &patterns {
-            if pattern.action == EscapeAction::Count {
-                let count = metrics.source_count(&pattern.name);
-                if count > pattern.threshold
-                    && let Some(v) = create_threshold_violation(
-                        ctx,
-                        &pattern.name,
-                        count,
-                        pattern.threshold,
-                        &pattern.advice,
-                    )
-                {
-                    violations.push(v);
-                }
-            }
-        }
-
-        // Handle policy violations based on their check level
-        let has_escape_violations = !violations.is_empty();
-        let policy_is_warning = policy_result.check_level == CheckLevel::Warn;
-        let policy_violations = policy_result.violations;
-
-        // Build result with metrics
-        let result = if has_escape_violations {
-            // Escape violations always cause failure, include policy violations too
-            violations.extend(policy_violations);
-            CheckResult::failed(self.name(), violations)
-        } else if !policy_violations.is_empty() {
-            // Only policy violations
-            if policy_is_warning {
-                // Warn level: report but don't fail
-                CheckResult::passed_with_warnings(self.name(), policy_violations)
-            } else {
-                // Error level: fail
-                CheckResult::failed(self.name(), policy_violations)
-            }
-        } else {
-            CheckResult::passed(self.name())
-        };
-
-        // Add metrics to result
-        let result = result.with_metrics(metrics.to_json(&pattern_names));
-
-        // Add by_package if workspace configured
-        if let Some(by_package) = metrics.to_by_package(&pattern_names) {
-            result.with_by_package(by_package)
-        } else {
-            result
-        }
-    }
-
-    fn default_enabled(&self) -> bool {
-        true
-    }
-}
-
-/// Classify file as source or test using a pre-built adapter.
-fn classify_file(adapter: &GenericAdapter, path: &Path, root: &Path) -> FileKind {
-    use crate::adapter::Adapter;
-    let relative = path.strip_prefix(root).unwrap_or(path);
-    adapter.classify(relative)
-}
-
-/// Find which package a file belongs to, if any.
-fn find_package(path: &Path, root: &Path, packages: &[String]) -> Option<String> {
-    let relative = path.strip_prefix(root).ok()?;
-    let relative_str = relative.to_string_lossy();
-
-    // Check if file is under any package directory
-    for pkg in packages {
-        // Handle wildcard patterns like "crates/*"
-        let prefix = pkg.trim_end_matches("/*");
-        if relative_str.starts_with(prefix) {
-            // Extract package name from path
-            let rest = relative_str.strip_prefix(prefix)?.trim_start_matches('/');
-            let parts: Vec<&str> = rest.split('/').collect();
-
-            if pkg.ends_with("/*") {
-                // Wildcard: first component after prefix is package name
-                if let Some(name) = parts.first()
-                    && !name.is_empty()
-                {
-                    return Some((*name).to_string());
-                }
-            } else {
-                // Exact path: use the last component of the pattern
-                return Some(pkg.split('/').next_back().unwrap_or(pkg).to_string());
-            }
-        }
-    }
-
-    None
-}
-
-/// Check if a file is a source code file (for escape pattern checking).
-/// Excludes configuration files, documentation, and data files.
-fn is_source_file(path: &Path) -> bool {
-    let ext = path
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_lowercase();
-
-    matches!(
-        ext.as_str(),
-        // Systems languages
-        "rs" | "c" | "cpp" | "h" | "hpp" | "go"
-        // JVM languages
-        | "java" | "kt" | "scala"
-        // Dynamic languages
-        | "py" | "rb" | "php" | "lua" | "pl" | "pm" | "r"
-        // JavaScript/TypeScript
-        | "js" | "ts" | "jsx" | "tsx"
-        // Apple platforms
-        | "swift" | "m" | "mm"
-        // .NET
-        | "cs"
-        // Shell scripts
-        | "sh" | "bash" | "zsh"
-        // Web
-        | "html" | "css" | "vue" | "svelte"
-        // Other
-        | "sql" | "ex" | "exs" | "erl" | "clj" | "hs" | "ml"
-    )
-}
-
-/// Check if a file has one of the given extensions (case-insensitive).
-fn has_extension(path: &Path, extensions: &[&str]) -> bool {
-    path.extension()
-        .and_then(|e| e.to_str())
-        .map(|e| extensions.iter().any(|ext| e.eq_ignore_ascii_case(ext)))
-        .unwrap_or(false)
-}
-
-/// Check suppress attributes in a Rust file.
-fn check_suppress_violations(
-    ctx: &CheckContext,
-    path: &Path,
-    content: &str,
-    config: &SuppressConfig,
-    is_test_file: bool,
-    cfg_info: &CfgTestInfo,
-    limit_reached: &mut bool,
-) -> Vec<Violation> {
-    let mut violations = Vec::new();
-
-    // Determine effective check level based on source vs test
-    let effective_check = if is_test_file {
-        config.test.check.unwrap_or(SuppressLevel::Allow)
-    } else {
-        config.source.check.unwrap_or(config.check)
-    };
-
-    // If allow, no checking needed
-    if effective_check == SuppressLevel::Allow {
-        return violations;
-    }
-
-    // Parse suppress attributes (don't filter by global pattern - let checker handle per-lint patterns)
-    let attrs = parse_suppress_attrs(content, None);
-
-    for attr in attrs {
-        if *limit_reached {
-            break;
-        }
-
-        // Check if this line is in test code (inline #[cfg(test)])
-        let is_test_line = cfg_info.is_test_line(attr.line);
-
-        if is_test_line {
-            // Use test policy
-            let test_check = config.test.check.unwrap_or(SuppressLevel::Allow);
-            if test_check == SuppressLevel::Allow {
-                continue;
-            }
-        }
-
-        // Get scope config and check level
-        let (scope_config, scope_check) = if is_test_file || is_test_line {
-            (
-                &config.test,
-                config.test.check.unwrap_or(SuppressLevel::Allow),
-            )
-        } else {
-            (&config.source, config.source.check.unwrap_or(config.check))
-        };
-
-        // Build params for shared checking logic
-        let params = SuppressCheckParams {
-            scope_config,
-            scope_check,
-            global_comment: config.comment.as_deref(),
-        };
-
-        let attr_info = SuppressAttrInfo {
-            codes: &attr.codes,
-            has_comment: attr.has_comment,
-            comment_text: attr.comment_text.as_deref(),
-        };
-
-        // Use shared checking logic
-        if let Some(violation_kind) = check_suppress_attr(&params, &attr_info) {
-            // Build pattern string for violation (use first code for display)
-            let code = attr.codes.first().map(|s| s.as_str()).unwrap_or("unknown");
-            let pattern = format!("#[{}({})]", attr.kind, code);
-
-            let (violation_type, advice) = match violation_kind {
-                SuppressViolationKind::Forbidden { ref code } => {
-                    let advice = format!(
-                        "Suppressing `{}` is forbidden. Remove the suppression or address the issue.",
-                        code
-                    );
-                    ("suppress_forbidden", advice)
-                }
-                SuppressViolationKind::MissingComment {
-                    ref lint_code,
-                    ref required_patterns,
-                } => {
-                    let advice = suppress_common::build_suppress_missing_comment_advice(
-                        "rust",
-                        lint_code.as_deref(),
-                        required_patterns,
-                    );
-                    ("suppress_missing_comment", advice)
-                }
-                SuppressViolationKind::AllForbidden => {
-                    let advice =
-                        "Lint suppressions are forbidden. Remove and fix the underlying issue.";
-                    ("suppress_forbidden", advice.to_string())
-                }
-            };
-
-            if let Some(v) = try_create_violation(
-                ctx,
-                path,
-                (attr.line + 1) as u32,
-                violation_type,
-                &advice,
-                &pattern,
-            ) {
-                violations.push(v);
-            } else {
-                *limit_reached = true;
-            }
-        }
-    }
-
-    violations
-}
-
-/// Pattern matcher for exclude patterns.
-struct ExcludeMatcher {
-    exclude_patterns: GlobSet,
-}
-
-impl ExcludeMatcher {
-    /// Create a new exclude matcher from config patterns.
-    fn new(exclude_patterns: &[String]) -> Self {
-        Self {
-            exclude_patterns: build_glob_set(exclude_patterns),
-        }
-    }
-
-    /// Check if a file should be excluded from escape checks.
-    fn is_excluded(&self, path: &Path, root: &Path) -> bool {
-        let relative = path.strip_prefix(root).unwrap_or(path);
-        self.exclude_patterns.is_match(relative)
-    }
-}
-
-#[cfg(test)]
-#[path = "mod_tests.rs"]
-mod tests;
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Arguments for `quench ratchet` and `quench baseline`.
+
+use std::path::PathBuf;
+
+use super::OutputFormat;
+
+#[derive(clap::Args)]
+pub struct RatchetArgs {
+    #[command(subcommand)]
+    pub command: RatchetCommand,
+}
+
+#[derive(clap::Subcommand)]
+pub enum RatchetCommand {
+    /// Re-run CI checks and update the baseline (for scheduled jobs)
+    Refresh(RefreshArgs),
+}
+
+#[derive(clap::Args)]
+pub struct RefreshArgs {
+    /// Only update when metrics improved or the baseline is older than stale_days
+    #[arg(long)]
+    pub if_stale: bool,
+
+    /// Output format
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args)]
+pub struct BaselineArgs {
+    #[command(subcommand)]
+    pub command: BaselineCommand,
+}
+
+#[derive(clap::Subcommand)]
+pub enum BaselineCommand {
+    /// Print the current baseline or a snapshot from its history
+    Show(BaselineShowArgs),
+    /// Re-run CI checks and save the baseline, or restore a snapshot
+    Update(BaselineUpdateArgs),
+    /// List baseline snapshots and the metrics each one moved
+    History(BaselineHistoryArgs),
+    /// Delete old baseline snapshots
+    Prune(BaselinePruneArgs),
+    /// Print the baseline stored on another branch as JSON
+    Export(BaselineExportArgs),
+    /// Replace the local baseline with one from a branch or file
+    Import(BaselineImportArgs),
+}
+
+#[derive(clap::Args)]
+pub struct BaselineShowArgs {
+    /// Snapshot ID from `quench baseline history` [default: current baseline]
+    #[arg(value_name = "SNAPSHOT")]
+    pub snapshot: Option<String>,
+
+    /// Output format (text, json)
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args)]
+pub struct BaselineUpdateArgs {
+    /// Restore this snapshot instead of re-running checks
+    #[arg(long, value_name = "SNAPSHOT")]
+    pub from: Option<String>,
+}
+
+#[derive(clap::Args)]
+pub struct BaselineHistoryArgs {
+    /// Only list snapshots that moved this metric (e.g., escapes.source.unwrap)
+    #[arg(long, value_name = "METRIC")]
+    pub metric: Option<String>,
+
+    /// Output format (text, json)
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args)]
+#[group(required = true, multiple = true)]
+pub struct BaselinePruneArgs {
+    /// Keep the newest N snapshots
+    #[arg(long, value_name = "N")]
+    pub keep: Option<usize>,
+
+    /// Delete snapshots saved more than DAYS days ago
+    #[arg(long, value_name = "DAYS")]
+    pub older_than: Option<u32>,
+}
+
+#[derive(clap::Args)]
+pub struct BaselineExportArgs {
+    /// Branch, tag, or commit to read [default: main or master]
+    #[arg(long = "ref", value_name = "REF")]
+    pub git_ref: Option<String>,
+}
+
+#[derive(clap::Args)]
+#[group(required = true, multiple = false)]
+pub struct BaselineImportArgs {
+    /// Baseline JSON file (from `quench baseline export`)
+    #[arg(value_name = "FILE")]
+    pub file: Option<PathBuf>,
+
+    /// Branch, tag, or commit to read the baseline from
+    #[arg(long = "ref", value_name = "REF")]
+    pub git_ref: Option<String>,
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Arguments for `quench bisect`.
+
+use super::OutputFormat;
+
+#[derive(clap::Args)]
+pub struct BisectArgs {
+    /// Baseline metric to track (e.g., escapes.source.unwrap, coverage.total)
+    #[arg(long, value_name = "NAME")]
+    pub metric: String,
+
+    /// Known-good starting point (tag, branch, or commit)
+    #[arg(long, value_name = "REF")]
+    pub from: String,
+
+    /// Endpoint where the metric has crossed
+    #[arg(long, value_name = "REF", default_value = "HEAD")]
+    pub to: String,
+
+    /// Find where the metric first exceeded N [default: its value at --from]
+    #[arg(long, value_name = "N", conflicts_with = "below")]
+    pub above: Option<f64>,
+
+    /// Find where the metric first dropped under N
+    #[arg(long, value_name = "N")]
+    pub below: Option<f64>,
+
+    /// Measure commits in CI mode (slow checks, test suites, builds)
+    #[arg(long)]
+    pub ci: bool,
+
+    /// Output format (text, json)
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
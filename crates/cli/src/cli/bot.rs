@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Arguments for `quench bot`.
+
+use std::path::PathBuf;
+
+#[derive(clap::Args)]
+pub struct BotArgs {
+    /// Address to listen on for webhook deliveries
+    #[arg(long, value_name = "ADDR", default_value = "127.0.0.1:8080")]
+    pub listen: String,
+
+    /// Handle one pull_request payload from FILE ("-" for stdin) and exit
+    #[arg(long, value_name = "FILE")]
+    pub event: Option<PathBuf>,
+
+    /// Directory for repository checkouts [default: <tmp>/quench-bot]
+    #[arg(long, value_name = "DIR")]
+    pub work_dir: Option<PathBuf>,
+
+    /// Run checks in CI mode (slow checks, test suites, builds)
+    #[arg(long)]
+    pub ci: bool,
+
+    /// Print statuses and reports instead of posting them to GitHub
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Check pull requests from forks owned by OWNER, or from the fork
+    /// OWNER/NAME (repeatable); other forks are refused
+    #[arg(long, value_name = "OWNER")]
+    pub allow_fork: Vec<String>,
+
+    /// Check with the pull request's quench.toml files instead of the base
+    /// commit's, running the commands they configure
+    #[arg(long)]
+    pub trust_config: bool,
+}
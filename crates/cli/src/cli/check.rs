@@ -0,0 +1,380 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Arguments for `quench check` and the commands that wrap it.
+
+use std::path::PathBuf;
+
+use crate::check::CiMode;
+
+use super::{BaseStrategy, CheckFilter, OutputFormat, parse_tag};
+
+#[derive(clap::Args, Clone)]
+pub struct CheckArgs {
+    /// Files or directories to check
+    #[arg(value_name = "PATH")]
+    pub paths: Vec<PathBuf>,
+
+    /// Output format
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+
+    /// Maximum violations to display (default: 15)
+    #[arg(long, default_value_t = 15, value_name = "N")]
+    pub limit: usize,
+
+    /// Show all violations (no limit)
+    #[arg(long)]
+    pub no_limit: bool,
+
+    /// Maximum directory depth to traverse
+    #[arg(long, default_value_t = 100)]
+    pub max_depth: usize,
+
+    /// Compare against a git base ref (e.g., main, HEAD~1)
+    #[arg(long, value_name = "REF")]
+    pub base: Option<String>,
+
+    /// How the base commit is found from the base ref [default: [git] base_strategy]
+    #[arg(long, value_enum, value_name = "STRATEGY")]
+    pub base_strategy: Option<BaseStrategy>,
+
+    /// Check only staged changes (pre-commit hook)
+    #[arg(long)]
+    pub staged: bool,
+
+    /// Check only files owned by this CODEOWNERS owner (e.g., @org/team)
+    #[arg(long, value_name = "OWNER")]
+    pub owner: Option<String>,
+
+    /// Bypass the cache (force fresh check)
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Wait for another quench run to finish instead of failing
+    #[arg(long)]
+    pub wait: bool,
+
+    /// Run without taking the .quench/lock run lock
+    #[arg(long, conflicts_with = "wait")]
+    pub no_lock: bool,
+
+    /// Worker threads for checks and file scans (default: one per CPU)
+    #[arg(
+        short,
+        long,
+        value_name = "N",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    pub jobs: Option<usize>,
+
+    /// Run only the checks that fit this time budget (e.g., 10s), cheapest first
+    // Timing history lives in the cache, so without it there's nothing to plan with
+    #[arg(long, value_name = "DURATION", conflicts_with_all = ["no_cache", "check_determinism"])]
+    pub budget: Option<String>,
+
+    /// Automatically fix violations when possible
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Run check fixers over violations too (set by `quench fix`)
+    #[arg(skip)]
+    pub run_fixers: bool,
+
+    /// Show what --fix would change without changing it
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Write what --fix would change to FILE as a unified diff instead
+    #[arg(long, value_name = "FILE")]
+    pub emit_patch: Option<PathBuf>,
+
+    /// CI mode: run slow checks, auto-detect base branch (default when CI is set)
+    #[arg(long)]
+    pub ci: bool,
+
+    /// Don't turn on CI mode from CI environment variables
+    #[arg(long, conflicts_with = "ci")]
+    pub no_ci: bool,
+
+    /// Also check release readiness (changelog, versions, markers); requires --ci
+    #[arg(long, requires = "ci")]
+    pub release_mode: bool,
+
+    /// Show verbose diagnostic output (always enabled in --ci mode)
+    #[arg(long)]
+    pub verbose: bool,
+
+    /// Show timing breakdown (phases, per-check, cache stats)
+    #[arg(long)]
+    pub timing: bool,
+
+    /// Show a health score for the metrics this run measured (text output)
+    #[arg(long)]
+    pub health: bool,
+
+    /// Run checks twice and fail if the results differ (implies --no-limit, --no-cache)
+    #[arg(long)]
+    pub check_determinism: bool,
+
+    /// Save metrics to file (CI mode)
+    #[arg(long, value_name = "FILE")]
+    pub save: Option<std::path::PathBuf>,
+
+    /// Tag this run's metrics (e.g., env=ci); repeatable
+    #[arg(long = "tag", value_name = "KEY=VALUE", value_parser = parse_tag)]
+    pub tags: Vec<(String, String)>,
+
+    /// Report only these rules (e.g., cloc/file-too-large)
+    #[arg(long = "only-rule", value_name = "RULE", value_delimiter = ',')]
+    pub only_rules: Vec<String>,
+
+    /// Don't report these rules (e.g., escapes/forbidden)
+    #[arg(long = "skip-rule", value_name = "RULE", value_delimiter = ',')]
+    pub skip_rules: Vec<String>,
+
+    // Check enable flags (run only these checks)
+    /// Run only the cloc check
+    #[arg(long)]
+    pub cloc: bool,
+
+    /// Run only the escapes check
+    #[arg(long)]
+    pub escapes: bool,
+
+    /// Run only the agents check
+    #[arg(long)]
+    pub agents: bool,
+
+    /// Run only the docs check
+    #[arg(long)]
+    pub docs: bool,
+
+    /// Run only the tests check
+    #[arg(long = "tests")]
+    pub tests_check: bool,
+
+    /// Run only the git check
+    #[arg(long)]
+    pub git: bool,
+
+    /// Run only the build check
+    #[arg(long)]
+    pub build: bool,
+
+    /// Run only the license check
+    #[arg(long)]
+    pub license: bool,
+
+    /// Run only the whitespace check
+    #[arg(long)]
+    pub whitespace: bool,
+
+    /// Run only the format check
+    #[arg(long)]
+    pub format: bool,
+
+    /// Run only the lint check
+    #[arg(long)]
+    pub lint: bool,
+
+    /// Run only the snapshots check
+    #[arg(long)]
+    pub snapshots: bool,
+
+    /// Run only the deadcode check
+    #[arg(long)]
+    pub deadcode: bool,
+
+    /// Run only the deps check
+    #[arg(long)]
+    pub deps: bool,
+
+    // Check disable flags (skip these checks)
+    /// Skip the cloc check
+    #[arg(long)]
+    pub no_cloc: bool,
+
+    /// Skip the escapes check
+    #[arg(long)]
+    pub no_escapes: bool,
+
+    /// Skip the agents check
+    #[arg(long)]
+    pub no_agents: bool,
+
+    /// Skip the docs check
+    #[arg(long)]
+    pub no_docs: bool,
+
+    /// Skip the tests check
+    #[arg(long)]
+    pub no_tests: bool,
+
+    /// Skip the git check
+    #[arg(long)]
+    pub no_git: bool,
+
+    /// Skip the build check
+    #[arg(long)]
+    pub no_build: bool,
+
+    /// Skip the license check
+    #[arg(long)]
+    pub no_license: bool,
+
+    /// Skip the whitespace check
+    #[arg(long)]
+    pub no_whitespace: bool,
+
+    /// Skip the format check
+    #[arg(long)]
+    pub no_format: bool,
+
+    /// Skip the lint check
+    #[arg(long)]
+    pub no_lint: bool,
+
+    /// Skip the snapshots check
+    #[arg(long)]
+    pub no_snapshots: bool,
+
+    /// Skip the deadcode check
+    #[arg(long)]
+    pub no_deadcode: bool,
+
+    /// Skip the deps check
+    #[arg(long)]
+    pub no_deps: bool,
+}
+
+/// Collect check names from boolean flags.
+macro_rules! collect_checks {
+    ($self:expr, $($flag:ident => $name:expr),+ $(,)?) => {{
+        let mut checks = Vec::new();
+        $(
+            if $self.$flag {
+                checks.push($name.to_string());
+            }
+        )+
+        checks
+    }};
+}
+
+pub(super) use collect_checks;
+
+impl CheckFilter for CheckArgs {
+    fn enabled_checks(&self) -> Vec<String> {
+        collect_checks!(self,
+            cloc => "cloc",
+            escapes => "escapes",
+            agents => "agents",
+            docs => "docs",
+            tests_check => "tests",
+            git => "git",
+            build => "build",
+            license => "license",
+            whitespace => "whitespace",
+            format => "format",
+            lint => "lint",
+            snapshots => "snapshots",
+            deadcode => "deadcode",
+            deps => "deps",
+        )
+    }
+
+    fn disabled_checks(&self) -> Vec<String> {
+        collect_checks!(self,
+            no_cloc => "cloc",
+            no_escapes => "escapes",
+            no_agents => "agents",
+            no_docs => "docs",
+            no_tests => "tests",
+            no_git => "git",
+            no_build => "build",
+            no_license => "license",
+            no_whitespace => "whitespace",
+            no_format => "format",
+            no_lint => "lint",
+            no_snapshots => "snapshots",
+            no_deadcode => "deadcode",
+            no_deps => "deps",
+        )
+    }
+}
+
+impl CheckArgs {
+    /// Turn on CI mode when a CI variable is set, unless `--no-ci` was
+    /// passed. Returns how CI mode was turned on, and the variable if inferred.
+    pub fn resolve_ci(&mut self) -> Option<(CiMode, Option<&'static str>)> {
+        if self.ci {
+            return Some((CiMode::Explicit, None));
+        }
+        if self.no_ci {
+            return None;
+        }
+        let var = crate::env::detect_ci()?;
+        self.ci = true;
+        Some((CiMode::Inferred, Some(var)))
+    }
+
+    /// Narrow this run to the checks `keep` accepts, as if the others had
+    /// been passed as `--no-<check>`. False if no check would be left.
+    pub fn retain_checks(&mut self, keep: impl Fn(&str) -> bool) -> bool {
+        let enabled = self.enabled_checks();
+        let left = if enabled.is_empty() {
+            let disabled = self.disabled_checks();
+            crate::checks::CHECK_NAMES
+                .iter()
+                .any(|name| !disabled.iter().any(|d| d == name) && keep(name))
+        } else {
+            enabled.iter().any(|name| keep(name))
+        };
+        if !left {
+            return false;
+        }
+        for (name, enable, disable) in self.check_flags() {
+            if !keep(name) {
+                *enable = false;
+                *disable = true;
+            }
+        }
+        true
+    }
+
+    /// Each check's enable and disable flags.
+    fn check_flags(&mut self) -> [(&'static str, &mut bool, &mut bool); 14] {
+        [
+            ("cloc", &mut self.cloc, &mut self.no_cloc),
+            ("escapes", &mut self.escapes, &mut self.no_escapes),
+            ("agents", &mut self.agents, &mut self.no_agents),
+            ("docs", &mut self.docs, &mut self.no_docs),
+            ("tests", &mut self.tests_check, &mut self.no_tests),
+            ("git", &mut self.git, &mut self.no_git),
+            ("build", &mut self.build, &mut self.no_build),
+            ("license", &mut self.license, &mut self.no_license),
+            ("whitespace", &mut self.whitespace, &mut self.no_whitespace),
+            ("format", &mut self.format, &mut self.no_format),
+            ("lint", &mut self.lint, &mut self.no_lint),
+            ("snapshots", &mut self.snapshots, &mut self.no_snapshots),
+            ("deadcode", &mut self.deadcode, &mut self.no_deadcode),
+            ("deps", &mut self.deps, &mut self.no_deps),
+        ]
+    }
+}
+
+#[derive(clap::Args)]
+pub struct WatchArgs {
+    /// Wait this long after the last change before re-running (e.g., 500ms)
+    #[arg(long, default_value = "300ms", value_name = "DURATION")]
+    pub debounce: String,
+
+    #[command(flatten)]
+    pub check: CheckArgs,
+}
+
+#[derive(clap::Args)]
+pub struct FixArgs {
+    #[command(flatten)]
+    pub check: CheckArgs,
+}
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Arguments for `quench init`.
+
+#[derive(clap::Args)]
+pub struct InitArgs {
+    /// Overwrite existing config
+    #[arg(long)]
+    pub force: bool,
+
+    /// Profile(s) to include (e.g., rust, shell, claude, copilot)
+    #[arg(long = "with", value_delimiter = ',')]
+    pub with_profiles: Vec<String>,
+
+    /// Also generate a CI workflow (github, gitlab, circle)
+    #[arg(long, value_name = "PROVIDER")]
+    pub ci: Option<CiProvider>,
+
+    /// Existing tool config(s) to translate (e.g., .eslintrc,clippy.toml,codecov.yml)
+    #[arg(long, value_delimiter = ',', value_name = "FILE")]
+    pub import: Vec<String>,
+
+    /// Also install git pre-commit and commit-msg hooks that run quench
+    #[arg(long)]
+    pub hooks: bool,
+
+    /// Remove the git hooks installed by --hooks, restoring any they replaced
+    #[arg(long, conflicts_with_all = ["force", "with_profiles", "ci", "import", "hooks"])]
+    pub remove_hooks: bool,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+pub enum CiProvider {
+    /// GitHub Actions
+    Github,
+    /// GitLab CI/CD
+    Gitlab,
+    /// CircleCI
+    Circle,
+}
+
+impl From<CiProvider> for crate::ci_workflow::CiProvider {
+    fn from(provider: CiProvider) -> Self {
+        match provider {
+            CiProvider::Github => Self::GitHub,
+            CiProvider::Gitlab => Self::GitLab,
+            CiProvider::Circle => Self::Circle,
+        }
+    }
+}
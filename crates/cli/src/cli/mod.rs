@@ -0,0 +1,344 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! CLI argument parsing with clap derive.
+
+mod baseline;
+mod bisect;
+mod bot;
+mod check;
+mod init;
+mod query;
+mod report;
+mod suppressions;
+
+pub use baseline::{
+    BaselineArgs, BaselineCommand, BaselineExportArgs, BaselineHistoryArgs, BaselineImportArgs,
+    BaselinePruneArgs, BaselineShowArgs, BaselineUpdateArgs, RatchetArgs, RatchetCommand,
+    RefreshArgs,
+};
+pub use bisect::BisectArgs;
+pub use bot::BotArgs;
+pub use check::{CheckArgs, FixArgs, WatchArgs};
+pub use init::{CiProvider, InitArgs};
+pub use query::QueryArgs;
+pub use report::ReportArgs;
+pub use suppressions::{SuppressionsArgs, SuppressionsCommand, SuppressionsListArgs};
+
+use std::path::PathBuf;
+
+use crate::help;
+use clap::{Parser, Subcommand};
+use clap_complete::Shell;
+
+/// A fast linting tool for AI agents that measures quality signals
+#[derive(Parser)]
+#[command(name = "quench")]
+#[command(version, about, long_about = None, disable_version_flag = true)]
+#[command(propagate_version = true)]
+#[command(styles = help::styles())]
+pub struct Cli {
+    /// Print version
+    #[arg(short = 'v', long = "version", global = true, action = clap::ArgAction::Version)]
+    version: (),
+
+    /// Hidden alias for backwards compatibility
+    #[arg(short = 'V', global = true, hide = true, action = clap::ArgAction::Version)]
+    version_compat: (),
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run quality checks
+    Check(CheckArgs),
+    /// Validate a commit message file (for git's commit-msg hook)
+    CheckCommitMsg(CheckCommitMsgArgs),
+    /// Apply automatic fixes from every check (quench check --fix)
+    Fix(FixArgs),
+    /// Count lines of code by language
+    Cloc(ClocArgs),
+    /// Report lines of code by language and package
+    Loc(LocArgs),
+    /// Generate reports from stored metrics
+    Report(ReportArgs),
+    /// Initialize quench configuration
+    Init(InitArgs),
+    /// Read configuration reference documentation
+    Config(ConfigArgs),
+    /// Manage the ratchet baseline
+    Ratchet(RatchetArgs),
+    /// Show, update, and audit the ratchet baseline
+    Baseline(BaselineArgs),
+    /// Attribute violations to authors and commits via git blame
+    Annotate(AnnotateArgs),
+    /// Suggest tests to run for a diff from recorded test impact
+    SuggestTests(SuggestTestsArgs),
+    /// Render the workspace dependency graph with quality metrics
+    Graph(GraphArgs),
+    /// Generate a software bill of materials from lockfiles
+    Sbom(SbomArgs),
+    /// Verify pinned tool versions and suggest install commands
+    Doctor(DoctorArgs),
+    /// Explain a violation type: what it means, why, and how to fix it
+    Explain(ExplainArgs),
+    /// Check pull requests from GitHub webhooks and post statuses and reports
+    Bot(BotArgs),
+    /// Find the commit where a metric crossed a threshold
+    Bisect(BisectArgs),
+    /// Query run history recorded in the [store] database
+    Query(QueryArgs),
+    /// Re-run checks as files change
+    Watch(WatchArgs),
+    /// Review inline ignores, allow lists, and other suppressions
+    Suppressions(SuppressionsArgs),
+    /// Generate shell completions
+    Completions(CompletionsArgs),
+}
+
+#[derive(clap::Args)]
+pub struct ClocArgs {
+    /// Files or directories to count
+    #[arg(value_name = "PATH")]
+    pub paths: Vec<PathBuf>,
+
+    /// Maximum directory depth to traverse
+    #[arg(long, default_value_t = 100)]
+    pub max_depth: usize,
+
+    /// Output format
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args)]
+pub struct LocArgs {
+    /// Directory to count
+    #[arg(value_name = "PATH")]
+    pub paths: Vec<PathBuf>,
+
+    /// Maximum directory depth to traverse
+    #[arg(long, default_value_t = 100)]
+    pub max_depth: usize,
+
+    /// Output format (text, json)
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+/// Trait for filtering checks/metrics by name.
+///
+/// Both `CheckArgs` and `ReportArgs` implement this trait to provide
+/// consistent filtering behavior for check enable/disable flags.
+pub trait CheckFilter {
+    /// Get list of explicitly enabled checks.
+    fn enabled_checks(&self) -> Vec<String>;
+
+    /// Get list of explicitly disabled checks.
+    fn disabled_checks(&self) -> Vec<String>;
+
+    /// Check if a metric/check should be included based on filters.
+    ///
+    /// If any checks are explicitly enabled, only those are included.
+    /// Otherwise, all checks are included except those explicitly disabled.
+    fn should_include(&self, check_name: &str) -> bool {
+        let enabled = self.enabled_checks();
+        let disabled = self.disabled_checks();
+
+        if !enabled.is_empty() {
+            // Explicit enable mode: only show specified checks
+            enabled.iter().any(|e| e == check_name)
+        } else {
+            // Default mode: show all except disabled
+            !disabled.iter().any(|d| d == check_name)
+        }
+    }
+
+    /// Get list of packages to include in per-package metrics.
+    fn packages(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Check if a package should be included based on filters.
+    ///
+    /// All packages are included unless some are explicitly selected.
+    fn should_include_package(&self, package: &str) -> bool {
+        let packages = self.packages();
+        packages.is_empty() || packages.iter().any(|p| p == package)
+    }
+}
+
+#[derive(clap::Args)]
+pub struct AnnotateArgs {
+    /// Check output JSON to annotate (default: .quench/latest.json)
+    #[arg(long, value_name = "FILE")]
+    pub input: Option<PathBuf>,
+
+    /// Output format (text, json, markdown)
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args)]
+pub struct SuggestTestsArgs {
+    /// Git base ref to diff against (default: auto-detected main branch)
+    #[arg(long, value_name = "REF")]
+    pub base: Option<String>,
+
+    /// How the base commit is found from the base ref [default: [git] base_strategy]
+    #[arg(long, value_enum, value_name = "STRATEGY")]
+    pub base_strategy: Option<BaseStrategy>,
+
+    /// Output format (text, json)
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args)]
+pub struct GraphArgs {
+    /// Graph format (dot, mermaid)
+    #[arg(short, long, default_value = "dot")]
+    pub output: GraphFormat,
+}
+
+#[derive(clap::Args)]
+pub struct SbomArgs {
+    /// SBOM format (cyclonedx, spdx)
+    #[arg(short, long, default_value = "cyclonedx")]
+    pub output: SbomFormat,
+}
+
+#[derive(clap::Args)]
+pub struct DoctorArgs {
+    /// Output format (text, json)
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args)]
+pub struct ExplainArgs {
+    /// Violation type or rule ID (e.g., missing_comment, cloc/file-too-large);
+    /// omit to list all
+    #[arg(value_name = "TYPE")]
+    pub violation_type: Option<String>,
+
+    /// Output format (text, json)
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args)]
+pub struct ConfigArgs {
+    /// Feature to show configuration for (e.g., rust, tests, cloc)
+    #[arg(value_name = "FEATURE")]
+    pub feature: Option<String>,
+
+    /// With `docs`, generate the full config reference (markdown, json)
+    #[arg(short, long)]
+    pub output: Option<OutputFormat>,
+}
+
+#[derive(clap::Args)]
+pub struct CheckCommitMsgArgs {
+    /// Commit message file (e.g., .git/COMMIT_EDITMSG)
+    #[arg(value_name = "FILE")]
+    pub file: PathBuf,
+}
+
+#[derive(clap::Args)]
+pub struct CompletionsArgs {
+    /// Shell to generate completions for
+    #[arg(value_enum)]
+    pub shell: Shell,
+}
+
+#[derive(Clone, Copy, Default, clap::ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Html,
+    #[value(alias = "md")]
+    Markdown,
+    /// Newline-delimited JSON events, streamed as checks run (`quench check` only)
+    Ndjson,
+    /// Health badge (`quench report -o badge.svg` only)
+    #[value(skip)]
+    Svg,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+pub enum BaseStrategy {
+    /// Where the branch and the ref's tip diverged
+    MergeBase,
+    /// Where the branch forked, per the ref's reflog
+    ForkPoint,
+    /// The ref itself
+    Exact,
+}
+
+impl From<BaseStrategy> for crate::config::BaseStrategy {
+    fn from(strategy: BaseStrategy) -> Self {
+        match strategy {
+            BaseStrategy::MergeBase => Self::MergeBase,
+            BaseStrategy::ForkPoint => Self::ForkPoint,
+            BaseStrategy::Exact => Self::Exact,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, clap::ValueEnum)]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    #[default]
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+#[derive(Clone, Copy, Default, clap::ValueEnum)]
+pub enum SbomFormat {
+    /// CycloneDX 1.5 JSON
+    #[default]
+    Cyclonedx,
+    /// SPDX 2.3 JSON
+    Spdx,
+}
+
+/// Parse a `--tag KEY=VALUE` argument.
+///
+/// Keys may contain letters, digits, `_`, `-`, and `.`; values are free-form.
+pub fn parse_tag(s: &str) -> Result<(String, String), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, found {:?}", s))?;
+    let key = key.trim();
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    {
+        return Err(format!(
+            "invalid tag key {:?}: use letters, digits, '_', '-', or '.'",
+            key
+        ));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
+// Re-export profile-related items from the profiles module for backward compatibility
+pub use crate::profiles::{
+    ProfileRegistry, agents_detected_section, agents_section, claude_profile_defaults,
+    cursor_profile_defaults, default_template, default_template_base, default_template_suffix,
+    golang_detected_section, golang_landing_items, golang_profile_defaults,
+    javascript_detected_section, javascript_landing_items, javascript_landing_items_for,
+    javascript_profile_defaults, python_landing_items, python_landing_items_for,
+    rust_detected_section, rust_landing_items, rust_profile_defaults, shell_detected_section,
+    shell_landing_items, shell_profile_defaults,
+};
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
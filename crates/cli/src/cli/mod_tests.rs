@@ -144,3 +144,108 @@ fn default_template_no_duplicate_agents_section() {
     let count = template.matches("[check.agents]").count();
     assert_eq!(count, 1, "should have exactly one [check.agents] section");
 }
+
+#[test]
+fn parse_tag_splits_on_first_equals() {
+    assert_eq!(
+        parse_tag("query=a=b").unwrap(),
+        ("query".to_string(), "a=b".to_string())
+    );
+    assert_eq!(
+        parse_tag("env=").unwrap(),
+        ("env".to_string(), String::new())
+    );
+}
+
+#[test]
+fn parse_tag_rejects_bad_input() {
+    assert!(parse_tag("env").is_err());
+    assert!(parse_tag("=ci").is_err());
+    assert!(parse_tag("my env=ci").is_err());
+}
+
+#[test]
+fn check_collects_repeated_tags() {
+    let cli = Cli::parse_from(["quench", "check", "--tag", "env=ci", "--tag", "branch=main"]);
+    if let Some(Command::Check(args)) = cli.command {
+        assert_eq!(
+            args.tags,
+            vec![
+                ("env".to_string(), "ci".to_string()),
+                ("branch".to_string(), "main".to_string())
+            ]
+        );
+    } else {
+        panic!("expected check command");
+    }
+}
+
+// =============================================================================
+// Narrowing checks
+// =============================================================================
+
+fn check_args(args: &[&str]) -> CheckArgs {
+    let argv = ["quench", "check"].iter().chain(args);
+    match Cli::parse_from(argv).command {
+        Some(Command::Check(args)) => args,
+        _ => panic!("expected check command"),
+    }
+}
+
+#[test]
+fn retain_checks_disables_the_rest() {
+    let mut args = check_args(&["--no-tests"]);
+    assert!(args.retain_checks(|name| name == "cloc" || name == "tests"));
+    assert!(args.enabled_checks().is_empty());
+    assert!(args.disabled_checks().contains(&"docs".to_string()));
+    assert!(args.disabled_checks().contains(&"tests".to_string()));
+    assert!(args.should_include("cloc"));
+}
+
+#[test]
+fn retain_checks_keeps_explicit_enables() {
+    let mut args = check_args(&["--docs", "--cloc"]);
+    assert!(args.retain_checks(|name| name != "docs"));
+    assert_eq!(args.enabled_checks(), ["cloc"]);
+
+    let mut args = check_args(&["--docs"]);
+    assert!(!args.retain_checks(|name| name != "docs"));
+    assert_eq!(args.enabled_checks(), ["docs"]);
+}
+
+// =============================================================================
+// Report filters
+// =============================================================================
+
+fn report_args(args: &[&str]) -> ReportArgs {
+    let argv = ["quench", "report"].iter().chain(args);
+    match Cli::parse_from(argv).command {
+        Some(Command::Report(args)) => args,
+        _ => panic!("expected report command"),
+    }
+}
+
+#[test]
+fn report_check_list_enables_checks() {
+    let args = report_args(&["--check", "escapes,tests", "--cloc"]);
+    assert_eq!(args.enabled_checks(), ["cloc", "escapes", "tests"]);
+    assert!(args.should_include("tests"));
+    assert!(!args.should_include("build"));
+}
+
+#[test]
+fn report_check_list_rejects_unknown_checks() {
+    let argv = ["quench", "report", "--check", "escapes,typo"];
+    assert!(Cli::try_parse_from(argv).is_err());
+}
+
+#[test]
+fn report_packages_resolve_paths_to_names() {
+    let mut args = report_args(&["--package", "crates/cli/,core"]);
+    assert!(!args.should_include_package("quench"));
+    let names = [("crates/cli".to_string(), "quench".to_string())].into();
+    args.resolve_packages(&names);
+    assert_eq!(args.packages(), ["quench", "core"]);
+    assert!(args.should_include_package("core"));
+    assert!(!args.should_include_package("web"));
+}
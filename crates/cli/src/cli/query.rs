@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Arguments for `quench query`.
+
+use super::OutputFormat;
+
+#[derive(clap::Args)]
+pub struct QueryArgs {
+    /// Recorded values of a baseline metric (e.g., escapes.source.unwrap)
+    #[arg(long, value_name = "METRIC", required_unless_present = "file")]
+    pub history: Option<String>,
+
+    /// Recorded violation counts for a file, by check
+    #[arg(long, value_name = "PATH", conflicts_with = "history")]
+    pub file: Option<String>,
+
+    /// Newest entries to show
+    #[arg(long, default_value_t = 20, value_name = "N")]
+    pub limit: usize,
+
+    /// Output format (text, json)
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Arguments for `quench report`.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::check::collect_checks;
+use super::{CheckFilter, OutputFormat};
+
+#[derive(clap::Args, Clone, Default)]
+pub struct ReportArgs {
+    /// Compare against a git ref or JSON file (e.g., main, HEAD~1, baseline.json)
+    #[arg(long)]
+    pub base: Option<String>,
+
+    /// Mark metrics that changed since a git ref or JSON baseline (HTML and Markdown)
+    #[arg(long, value_name = "REF|FILE")]
+    pub compare: Option<String>,
+
+    /// Output format or file path (e.g., text, json, html, report.html, badge.svg)
+    #[arg(short, long, default_value = "text")]
+    pub output: String,
+
+    /// Output compact JSON (no whitespace, single line)
+    #[arg(long)]
+    pub compact: bool,
+
+    /// Rank files by technical debt instead of reporting metrics
+    #[arg(long, group = "ranking")]
+    pub debt: bool,
+
+    /// List churn hotspots (large files that change often) instead of reporting metrics
+    #[arg(long, group = "ranking")]
+    pub hotspots: bool,
+
+    /// Number of files in the debt or hotspot report
+    #[arg(long, default_value_t = 20, requires = "ranking")]
+    pub top: usize,
+
+    /// Show only these checks' metrics (e.g., escapes,tests)
+    #[arg(
+        long = "check",
+        value_name = "CHECK",
+        value_delimiter = ',',
+        value_parser = clap::builder::PossibleValuesParser::new(crate::checks::CHECK_NAMES),
+        hide_possible_values = true
+    )]
+    pub checks: Vec<String>,
+
+    /// Show only these packages, by name or path (e.g., crates/cli)
+    #[arg(long = "package", value_name = "PACKAGE", value_delimiter = ',')]
+    pub packages: Vec<String>,
+
+    // Check enable flags (show only these metrics)
+    /// Show only cloc metrics
+    #[arg(long)]
+    pub cloc: bool,
+
+    /// Show only escapes metrics
+    #[arg(long)]
+    pub escapes: bool,
+
+    /// Show only agents metrics
+    #[arg(long)]
+    pub agents: bool,
+
+    /// Show only docs metrics
+    #[arg(long)]
+    pub docs: bool,
+
+    /// Show only tests metrics
+    #[arg(long = "tests")]
+    pub tests_check: bool,
+
+    /// Show only git metrics
+    #[arg(long)]
+    pub git: bool,
+
+    /// Show only build metrics
+    #[arg(long)]
+    pub build: bool,
+
+    /// Show only license metrics
+    #[arg(long)]
+    pub license: bool,
+
+    /// Show only whitespace metrics
+    #[arg(long)]
+    pub whitespace: bool,
+
+    /// Show only format metrics
+    #[arg(long)]
+    pub format: bool,
+
+    /// Show only lint metrics
+    #[arg(long)]
+    pub lint: bool,
+
+    /// Show only snapshots metrics
+    #[arg(long)]
+    pub snapshots: bool,
+
+    /// Show only deadcode metrics
+    #[arg(long)]
+    pub deadcode: bool,
+
+    /// Show only deps metrics
+    #[arg(long)]
+    pub deps: bool,
+
+    // Check disable flags (skip these metrics)
+    /// Skip cloc metrics
+    #[arg(long)]
+    pub no_cloc: bool,
+
+    /// Skip escapes metrics
+    #[arg(long)]
+    pub no_escapes: bool,
+
+    /// Skip agents metrics
+    #[arg(long)]
+    pub no_agents: bool,
+
+    /// Skip docs metrics
+    #[arg(long)]
+    pub no_docs: bool,
+
+    /// Skip tests metrics
+    #[arg(long)]
+    pub no_tests: bool,
+
+    /// Skip git metrics
+    #[arg(long)]
+    pub no_git: bool,
+
+    /// Skip build metrics
+    #[arg(long)]
+    pub no_build: bool,
+
+    /// Skip license metrics
+    #[arg(long)]
+    pub no_license: bool,
+
+    /// Skip whitespace metrics
+    #[arg(long)]
+    pub no_whitespace: bool,
+
+    /// Skip format metrics
+    #[arg(long)]
+    pub no_format: bool,
+
+    /// Skip lint metrics
+    #[arg(long)]
+    pub no_lint: bool,
+
+    /// Skip snapshots metrics
+    #[arg(long)]
+    pub no_snapshots: bool,
+
+    /// Skip deadcode metrics
+    #[arg(long)]
+    pub no_deadcode: bool,
+
+    /// Skip deps metrics
+    #[arg(long)]
+    pub no_deps: bool,
+}
+
+impl ReportArgs {
+    /// Parse output argument into format and optional file path.
+    pub fn output_target(&self) -> (OutputFormat, Option<PathBuf>) {
+        let val = self.output.to_lowercase();
+
+        // Check for file extension
+        if val.ends_with(".html") {
+            (OutputFormat::Html, Some(PathBuf::from(&self.output)))
+        } else if val.ends_with(".json") {
+            (OutputFormat::Json, Some(PathBuf::from(&self.output)))
+        } else if val.ends_with(".md") {
+            (OutputFormat::Markdown, Some(PathBuf::from(&self.output)))
+        } else if val.ends_with(".txt") {
+            (OutputFormat::Text, Some(PathBuf::from(&self.output)))
+        } else if val.ends_with(".svg") {
+            (OutputFormat::Svg, Some(PathBuf::from(&self.output)))
+        } else {
+            // Parse as format name
+            let format = match val.as_str() {
+                "json" => OutputFormat::Json,
+                "html" => OutputFormat::Html,
+                "md" | "markdown" => OutputFormat::Markdown,
+                "svg" | "badge" => OutputFormat::Svg,
+                _ => OutputFormat::Text,
+            };
+            (format, None)
+        }
+    }
+
+    /// Replace package paths in `--package` with the package names that
+    /// baselines record, e.g. `crates/cli` with `quench`.
+    pub fn resolve_packages(&mut self, package_names: &HashMap<String, String>) {
+        for package in &mut self.packages {
+            let path = package.trim_end_matches('/');
+            if let Some(name) = package_names.get(path) {
+                *package = name.clone();
+            }
+        }
+    }
+}
+
+impl CheckFilter for ReportArgs {
+    fn enabled_checks(&self) -> Vec<String> {
+        let mut checks = collect_checks!(self,
+            cloc => "cloc",
+            escapes => "escapes",
+            agents => "agents",
+            docs => "docs",
+            tests_check => "tests",
+            git => "git",
+            build => "build",
+            license => "license",
+            whitespace => "whitespace",
+            format => "format",
+            lint => "lint",
+            snapshots => "snapshots",
+            deadcode => "deadcode",
+            deps => "deps",
+        );
+        checks.extend(self.checks.iter().cloned());
+        checks
+    }
+
+    fn disabled_checks(&self) -> Vec<String> {
+        collect_checks!(self,
+            no_cloc => "cloc",
+            no_escapes => "escapes",
+            no_agents => "agents",
+            no_docs => "docs",
+            no_tests => "tests",
+            no_git => "git",
+            no_build => "build",
+            no_license => "license",
+            no_whitespace => "whitespace",
+            no_format => "format",
+            no_lint => "lint",
+            no_snapshots => "snapshots",
+            no_deadcode => "deadcode",
+            no_deps => "deps",
+        )
+    }
+
+    fn packages(&self) -> Vec<String> {
+        self.packages.clone()
+    }
+}
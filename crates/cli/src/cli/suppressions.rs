@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Arguments for `quench suppressions`.
+
+use super::OutputFormat;
+
+#[derive(clap::Args)]
+pub struct SuppressionsArgs {
+    #[command(subcommand)]
+    pub command: SuppressionsCommand,
+}
+
+#[derive(clap::Subcommand)]
+pub enum SuppressionsCommand {
+    /// List suppressions with their age and expiry
+    List(SuppressionsListArgs),
+}
+
+#[derive(clap::Args)]
+pub struct SuppressionsListArgs {
+    /// Only show suppressions expired or expiring within DAYS [default: 30]
+    #[arg(long, value_name = "DAYS", num_args = 0..=1, default_missing_value = "30")]
+    pub expiring: Option<u32>,
+
+    /// Output format (text, json)
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
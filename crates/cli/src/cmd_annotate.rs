@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench annotate` command implementation.
+
+use quench::annotate::annotate;
+use quench::check::CheckOutput;
+use quench::cli::{AnnotateArgs, OutputFormat};
+use quench::error::ExitCode;
+use quench::git::is_git_repo;
+use quench::latest::LatestMetrics;
+
+/// Run the `quench annotate` command.
+pub fn run(args: &AnnotateArgs) -> anyhow::Result<ExitCode> {
+    let root = std::env::current_dir()?;
+    if !is_git_repo(&root) {
+        eprintln!("quench: annotate requires a git repository");
+        return Ok(ExitCode::ConfigError);
+    }
+
+    let output = match &args.input {
+        Some(path) => {
+            let content = std::fs::read_to_string(path)
+                .map_err(|e| anyhow::anyhow!("failed to read {}: {}", path.display(), e))?;
+            serde_json::from_str::<CheckOutput>(&content)
+                .map_err(|e| anyhow::anyhow!("invalid check output {}: {}", path.display(), e))?
+        }
+        None => match LatestMetrics::load(&root.join(".quench/latest.json"))? {
+            Some(latest) => latest.output,
+            None => {
+                eprintln!("quench: no recorded violations; run `quench check` first");
+                return Ok(ExitCode::ConfigError);
+            }
+        },
+    };
+
+    let report = annotate(&root, &output)?;
+    match args.output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        OutputFormat::Ndjson => println!("{}", serde_json::to_string(&report)?),
+        OutputFormat::Markdown => print!("{}", report.format_markdown()),
+        OutputFormat::Text | OutputFormat::Html | OutputFormat::Svg => {
+            print!("{}", report.format_text())
+        }
+    }
+    Ok(ExitCode::Success)
+}
@@ -0,0 +1,262 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench baseline` command implementation.
+
+use std::path::Path;
+
+use anyhow::{Context, bail};
+
+use quench::baseline::Baseline;
+use quench::baseline_history::{self, HISTORY_DIR, MetricMove};
+use quench::cli::{
+    BaselineArgs, BaselineCommand, BaselineExportArgs, BaselineHistoryArgs, BaselineImportArgs,
+    BaselinePruneArgs, BaselineShowArgs, BaselineUpdateArgs, OutputFormat, RefreshArgs,
+};
+use quench::config::{self, Config};
+use quench::discovery;
+use quench::error::{Error, ExitCode};
+use quench::git::{self, save_to_git_notes};
+
+use crate::cmd_ratchet;
+
+/// Run the `quench baseline` command.
+pub fn run(args: &BaselineArgs) -> anyhow::Result<ExitCode> {
+    let root = std::env::current_dir()?;
+    let config = match discovery::find_config(&root) {
+        Some(path) => config::load_with_warnings(&path)?,
+        None => Config::default(),
+    };
+
+    match &args.command {
+        BaselineCommand::Show(args) => run_show(&root, &config, args),
+        BaselineCommand::Update(args) => run_update(&root, &config, args),
+        BaselineCommand::History(args) => run_history(&root, args),
+        BaselineCommand::Prune(args) => run_prune(&root, args),
+        BaselineCommand::Export(args) => run_export(&root, &config, args),
+        BaselineCommand::Import(args) => run_import(&root, &config, args),
+    }
+}
+
+/// Print the current baseline, or a snapshot from its history.
+fn run_show(root: &Path, config: &Config, args: &BaselineShowArgs) -> anyhow::Result<ExitCode> {
+    let baseline = match &args.snapshot {
+        Some(id) => load_snapshot(root, id)?,
+        None => {
+            let loaded = match config.git.baseline_path() {
+                Some(path) => Baseline::load(&root.join(path)),
+                None => Baseline::load_from_notes(root, "HEAD"),
+            };
+            match loaded? {
+                Some(baseline) => baseline,
+                None => {
+                    eprintln!("quench: no baseline; run `quench check --fix` to create one");
+                    return Ok(ExitCode::ConfigError);
+                }
+            }
+        }
+    };
+
+    match args.output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&baseline)?),
+        _ => {
+            let commit = baseline
+                .commit
+                .as_deref()
+                .map(|commit| format!(" (commit {})", commit))
+                .unwrap_or_default();
+            println!(
+                "Baseline updated {}{}",
+                baseline.updated.format("%Y-%m-%d %H:%M:%S UTC"),
+                commit
+            );
+            for (name, value) in baseline.metrics.flatten() {
+                println!("  {} = {}", name, value);
+            }
+        }
+    }
+    Ok(ExitCode::Success)
+}
+
+/// Re-run CI checks and save the baseline, or restore a snapshot.
+fn run_update(root: &Path, config: &Config, args: &BaselineUpdateArgs) -> anyhow::Result<ExitCode> {
+    let Some(id) = &args.from else {
+        return cmd_ratchet::run_refresh(&RefreshArgs {
+            if_stale: false,
+            output: OutputFormat::Text,
+        });
+    };
+
+    let baseline = load_snapshot(root, id)?;
+    let target = write_baseline(root, config, &baseline)?;
+    println!("Restored baseline snapshot {} into {}", id, target);
+    Ok(ExitCode::Success)
+}
+
+/// List baseline snapshots, oldest first, with the metrics each one moved.
+fn run_history(root: &Path, args: &BaselineHistoryArgs) -> anyhow::Result<ExitCode> {
+    let snapshots = baseline_history::list(root);
+    let empty = Baseline::new();
+    let entries: Vec<_> = snapshots
+        .iter()
+        .enumerate()
+        .map(|(i, snapshot)| {
+            let previous = i.checked_sub(1).map_or(&empty, |i| &snapshots[i].baseline);
+            let moves: Vec<MetricMove> = baseline_history::moves(previous, &snapshot.baseline)
+                .into_iter()
+                .filter(|m| args.metric.as_ref().is_none_or(|metric| &m.name == metric))
+                .collect();
+            (i == 0, snapshot, moves)
+        })
+        .filter(|(_, _, moves)| args.metric.is_none() || !moves.is_empty())
+        .collect();
+
+    match args.output {
+        OutputFormat::Json => {
+            let items: Vec<_> = entries
+                .iter()
+                .map(|(_, snapshot, moves)| {
+                    serde_json::json!({
+                        "id": snapshot.id,
+                        "saved": snapshot.saved,
+                        "commit": snapshot.baseline.commit,
+                        "moves": moves,
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&items)?);
+        }
+        _ if snapshots.is_empty() => println!("No baseline snapshots in {}/.", HISTORY_DIR),
+        _ => {
+            for (first, snapshot, moves) in &entries {
+                let commit = snapshot
+                    .baseline
+                    .commit
+                    .as_deref()
+                    .map(|commit| format!("  commit {}", commit))
+                    .unwrap_or_default();
+                println!("{}{}", snapshot.id, commit);
+                if *first && args.metric.is_none() {
+                    println!("  first snapshot: {} metrics", moves.len());
+                } else if moves.is_empty() {
+                    println!("  no metric changes");
+                } else {
+                    for m in moves {
+                        println!(
+                            "  {}: {} -> {}",
+                            m.name,
+                            format_value(m.from),
+                            format_value(m.to)
+                        );
+                    }
+                }
+            }
+        }
+    }
+    Ok(ExitCode::Success)
+}
+
+/// Delete old baseline snapshots.
+fn run_prune(root: &Path, args: &BaselinePruneArgs) -> anyhow::Result<ExitCode> {
+    let removed = baseline_history::prune(root, args.keep, args.older_than)?;
+    let kept = baseline_history::list(root).len();
+    for id in &removed {
+        println!("deleted {}", id);
+    }
+    println!(
+        "Deleted {} baseline snapshots, kept {}",
+        removed.len(),
+        kept
+    );
+    Ok(ExitCode::Success)
+}
+
+/// Print the baseline stored at a ref.
+fn run_export(root: &Path, config: &Config, args: &BaselineExportArgs) -> anyhow::Result<ExitCode> {
+    let git_ref = match &args.git_ref {
+        Some(git_ref) => git_ref.clone(),
+        None => git::detect_base_branch(root)
+            .ok_or_else(|| Error::Argument("no main or master branch; pass --ref".to_string()))?,
+    };
+    let baseline = load_at_ref(root, config, &git_ref)?;
+    println!("{}", serde_json::to_string_pretty(&baseline)?);
+    Ok(ExitCode::Success)
+}
+
+/// Replace the configured baseline with one from a ref or file.
+fn run_import(root: &Path, config: &Config, args: &BaselineImportArgs) -> anyhow::Result<ExitCode> {
+    let (baseline, source) = match (&args.git_ref, &args.file) {
+        (Some(git_ref), _) => (load_at_ref(root, config, git_ref)?, git_ref.clone()),
+        (None, Some(file)) => {
+            let content = std::fs::read_to_string(file)
+                .with_context(|| format!("failed to read {}", file.display()))?;
+            let baseline = Baseline::parse(&content)
+                .map_err(|e| Error::Argument(format!("{}: {}", file.display(), e)))?;
+            (baseline, file.display().to_string())
+        }
+        (None, None) => bail!(Error::Argument("pass FILE or --ref".to_string())),
+    };
+
+    let target = write_baseline(root, config, &baseline)?;
+
+    let from_commit = baseline
+        .commit
+        .as_deref()
+        .map(|commit| format!(" (commit {})", commit))
+        .unwrap_or_default();
+    println!(
+        "Imported baseline from {}{} into {}",
+        source, from_commit, target
+    );
+    Ok(ExitCode::Success)
+}
+
+/// Write `baseline` where the config stores it and record it in the
+/// history, returning where it was written.
+fn write_baseline(root: &Path, config: &Config, baseline: &Baseline) -> anyhow::Result<String> {
+    let target = match config.git.baseline_path() {
+        Some(path) => {
+            baseline.save(&root.join(path))?;
+            path.to_string()
+        }
+        None => {
+            save_to_git_notes(root, &serde_json::to_string_pretty(baseline)?)?;
+            "git notes for HEAD".to_string()
+        }
+    };
+    baseline_history::record(root, baseline)?;
+    Ok(target)
+}
+
+/// Read a snapshot from the baseline history.
+fn load_snapshot(root: &Path, id: &str) -> anyhow::Result<Baseline> {
+    baseline_history::load(root, id)
+        .map_err(|e| Error::Argument(format!("{}: {}", id, e)))?
+        .ok_or_else(|| {
+            Error::Argument(format!(
+                "no baseline snapshot {}; see `quench baseline history`",
+                id
+            ))
+            .into()
+        })
+}
+
+/// A metric value, or `-` when absent.
+fn format_value(value: Option<f64>) -> String {
+    value.map_or_else(|| "-".to_string(), |v| v.to_string())
+}
+
+/// Read the baseline at `git_ref`: its note, or the committed baseline file.
+fn load_at_ref(root: &Path, config: &Config, git_ref: &str) -> anyhow::Result<Baseline> {
+    let (loaded, location) = match config.git.baseline_path() {
+        Some(path) => (Baseline::load_from_ref(root, git_ref, path), path),
+        None => (
+            Baseline::load_from_notes(root, git_ref),
+            "refs/notes/quench",
+        ),
+    };
+    // A bad ref is a usage error, not an internal one
+    let baseline = loaded.map_err(|e| Error::Argument(format!("{}: {}", git_ref, e)))?;
+    baseline
+        .ok_or_else(|| Error::Argument(format!("no baseline at {} ({})", git_ref, location)).into())
+}
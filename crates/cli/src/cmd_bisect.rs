@@ -0,0 +1,315 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench bisect` command implementation.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, bail};
+
+use quench::baseline::Baseline;
+use quench::bisect::{self, Threshold};
+use quench::check::CheckOutput;
+use quench::cli::{BisectArgs, OutputFormat};
+use quench::config::{self, Config};
+use quench::discovery;
+use quench::error::{Error, ExitCode};
+use quench::git::{self, Commit};
+use quench::ratchet::{self, CurrentMetrics};
+
+use crate::store::ResultStore;
+
+/// Run the `quench bisect` command.
+///
+/// Reports the first commit after `--from` where the metric is past the
+/// threshold, measuring only the commits the search needs.
+pub fn run(args: &BisectArgs) -> anyhow::Result<ExitCode> {
+    let root = std::env::current_dir()?;
+    let config = match discovery::find_config(&root) {
+        Some(path) => config::load_with_warnings(&path)?,
+        None => Config::default(),
+    };
+    let store = ResultStore::open(&root, &config)?;
+    let commits = git::get_first_parent_commits(&root, &args.from, &args.to)?;
+    let mut sampler = Sampler::new(&root, &commits, &args.metric, args.ci, store)?;
+
+    let last = commits.len() - 1;
+    let Some(start) = sampler.value(0)? else {
+        bail!(Error::Argument(format!(
+            "cannot measure {} at {}",
+            args.metric, args.from
+        )));
+    };
+    let threshold = match (args.above, args.below) {
+        (Some(limit), _) => Threshold::Above(limit),
+        (_, Some(limit)) => Threshold::Below(limit),
+        _ => Threshold::worse_than(&args.metric, start),
+    };
+    if threshold.crossed(start) {
+        bail!(Error::Argument(format!(
+            "{} is already {} at {} ({})",
+            args.metric, threshold, args.from, start
+        )));
+    }
+    let end = match sampler.value(last)? {
+        Some(end) => end,
+        None => bail!(Error::Argument(format!(
+            "cannot measure {} at {}",
+            args.metric, args.to
+        ))),
+    };
+    if !threshold.crossed(end) {
+        match args.output {
+            OutputFormat::Json => {
+                let report = serde_json::json!({
+                    "metric": args.metric,
+                    "threshold": threshold.to_string(),
+                    "found": false,
+                });
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            }
+            _ => println!(
+                "{} never went {} between {} and {}",
+                args.metric, threshold, args.from, args.to
+            ),
+        }
+        return Ok(ExitCode::Success);
+    }
+
+    let found = bisect::first_crossing(commits.len(), |i| {
+        Ok::<_, anyhow::Error>(sampler.value(i)?.map(|v| threshold.crossed(v)))
+    })?;
+    let before = sampler.values[&found.last_good];
+    let after = sampler.values[&found.first_bad];
+    let culprit = &commits[found.first_bad];
+    let skipped: Vec<&str> = commits[found.last_good + 1..found.first_bad]
+        .iter()
+        .map(|c| c.hash.as_str())
+        .collect();
+
+    match args.output {
+        OutputFormat::Json => {
+            let report = serde_json::json!({
+                "metric": args.metric,
+                "threshold": threshold.to_string(),
+                "found": true,
+                "commit": culprit.hash,
+                "message": culprit.message,
+                "previous": before,
+                "value": after,
+                "unmeasured": skipped,
+            });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        _ => {
+            println!(
+                "{} went {} at {}: {}",
+                args.metric, threshold, culprit.hash, culprit.message
+            );
+            println!("  {} -> {}", before, after);
+            if found.is_ambiguous() {
+                println!(
+                    "  could not measure {} before it; any of them may be first",
+                    skipped.join(", ")
+                );
+            }
+        }
+    }
+    Ok(ExitCode::Success)
+}
+
+/// Where a commit's metrics came from.
+#[derive(Clone, Copy)]
+enum Source {
+    Notes,
+    Cache,
+    Measured,
+}
+
+impl Source {
+    fn as_str(self) -> &'static str {
+        match self {
+            Source::Notes => "notes",
+            Source::Cache => "cache",
+            Source::Measured => "measured",
+        }
+    }
+}
+
+/// Looks up a metric per commit: git notes first, then results cached by
+/// earlier bisects (the `[store]` database, or `.quench/bisect/`), then a
+/// fresh `quench check` in a scratch worktree.
+struct Sampler<'a> {
+    root: &'a Path,
+    commits: &'a [Commit],
+    metric: &'a str,
+    ci: bool,
+    store: Option<ResultStore>,
+    quench: PathBuf,
+    worktree: Option<Worktree>,
+    values: HashMap<usize, f64>,
+}
+
+impl<'a> Sampler<'a> {
+    fn new(
+        root: &'a Path,
+        commits: &'a [Commit],
+        metric: &'a str,
+        ci: bool,
+        store: Option<ResultStore>,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            root,
+            commits,
+            metric,
+            ci,
+            store,
+            quench: std::env::current_exe()?,
+            worktree: None,
+            values: HashMap::new(),
+        })
+    }
+
+    /// Metric value at commit `index`, or None if it can't be measured.
+    fn value(&mut self, index: usize) -> anyhow::Result<Option<f64>> {
+        if let Some(&value) = self.values.get(&index) {
+            return Ok(Some(value));
+        }
+        let commit = &self.commits[index];
+        let value = self.lookup(&commit.hash)?;
+        match value {
+            Some((value, source)) => {
+                eprintln!("  {}  {}  ({})", commit.hash, value, source.as_str());
+                self.values.insert(index, value);
+                Ok(Some(value))
+            }
+            None => {
+                eprintln!("  {}  skipped (not measurable)", commit.hash);
+                Ok(None)
+            }
+        }
+    }
+
+    fn lookup(&mut self, hash: &str) -> anyhow::Result<Option<(f64, Source)>> {
+        let from_notes = Baseline::load_from_notes(self.root, hash)
+            .ok()
+            .flatten()
+            .and_then(|baseline| baseline.metrics.value(self.metric));
+        if let Some(value) = from_notes {
+            return Ok(Some((value, Source::Notes)));
+        }
+
+        if let Some(store) = &self.store {
+            if let Some(value) = store.metric_at(hash, self.ci, self.metric)? {
+                return Ok(value.map(|value| (value, Source::Cache)));
+            }
+        } else if let Ok(Some(cached)) = Baseline::load(&self.cache_path(hash)) {
+            return Ok(cached
+                .metrics
+                .value(self.metric)
+                .map(|value| (value, Source::Cache)));
+        }
+
+        let Some(check) = self.measure(hash)? else {
+            return Ok(None);
+        };
+        let mut cached = Baseline::new();
+        cached.commit = Some(hash.to_string());
+        ratchet::update_baseline(&mut cached, &CurrentMetrics::from_output(&check));
+        // A failed cache write only costs a re-measure next time
+        match &mut self.store {
+            Some(store) => {
+                let _ = store.record_run(Some(hash), self.ci, &check);
+            }
+            None => {
+                let _ = cached.save(&self.cache_path(hash));
+            }
+        }
+        Ok(cached
+            .metrics
+            .value(self.metric)
+            .map(|value| (value, Source::Measured)))
+    }
+
+    fn cache_path(&self, hash: &str) -> PathBuf {
+        let name = if self.ci {
+            format!("{}-ci.json", hash)
+        } else {
+            format!("{}.json", hash)
+        };
+        self.root.join(".quench/bisect").join(name)
+    }
+
+    /// Check out `hash` in the scratch worktree and run the checks there.
+    fn measure(&mut self, hash: &str) -> anyhow::Result<Option<CheckOutput>> {
+        let dir = match &self.worktree {
+            Some(worktree) => {
+                git_in(&worktree.dir, &["checkout", "--detach", "--force", hash])?;
+                // Keep ignored files so build output carries over between probes
+                git_in(&worktree.dir, &["clean", "-fd"])?;
+                worktree.dir.clone()
+            }
+            None => {
+                let worktree = Worktree::add(self.root, hash)?;
+                let dir = worktree.dir.clone();
+                self.worktree = Some(worktree);
+                dir
+            }
+        };
+
+        let mut cmd = Command::new(&self.quench);
+        cmd.args(["check", "-o", "json"]);
+        if self.ci {
+            cmd.arg("--ci");
+        }
+        let output = cmd
+            .current_dir(&dir)
+            .output()
+            .with_context(|| format!("cannot run {}", self.quench.display()))?;
+        // Failing checks still report metrics; only unreadable output is a skip
+        Ok(serde_json::from_slice::<CheckOutput>(&output.stdout).ok())
+    }
+}
+
+/// A detached `git worktree`, removed on drop.
+struct Worktree {
+    root: PathBuf,
+    dir: PathBuf,
+}
+
+impl Worktree {
+    fn add(root: &Path, hash: &str) -> anyhow::Result<Self> {
+        let dir = std::env::temp_dir().join(format!("quench-bisect-{}", std::process::id()));
+        let dir_arg = dir.to_string_lossy();
+        git_in(root, &["worktree", "add", "--detach", &dir_arg, hash])?;
+        Ok(Self {
+            root: root.to_path_buf(),
+            dir,
+        })
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let dir_arg = self.dir.to_string_lossy();
+        let _ = git_in(&self.root, &["worktree", "remove", "--force", &dir_arg]);
+    }
+}
+
+fn git_in(dir: &Path, args: &[&str]) -> anyhow::Result<()> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .context("cannot run git")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
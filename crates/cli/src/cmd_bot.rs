@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench bot` command implementation.
+
+use std::io::Read;
+use std::net::TcpListener;
+use std::sync::mpsc;
+
+use anyhow::{Context, anyhow};
+
+use quench::bot::event::PullRequestEvent;
+use quench::bot::github::{self, GitHub, State};
+use quench::bot::server;
+use quench::bot::{Bot, Policy};
+use quench::cli::BotArgs;
+use quench::env;
+use quench::error::ExitCode;
+
+/// Run the `quench bot` command.
+///
+/// With `--event`, checks one pull request and exits with failure if its
+/// checks failed; otherwise serves webhook deliveries until killed.
+pub fn run(args: &BotArgs) -> anyhow::Result<ExitCode> {
+    let github = if args.dry_run {
+        None
+    } else {
+        let token = env::github_token()
+            .context("GITHUB_TOKEN must be set to post statuses (or use --dry-run)")?;
+        let api_url = env::github_api_url().unwrap_or_else(|| github::DEFAULT_API_URL.to_string());
+        Some(GitHub::new(api_url, token))
+    };
+    let work_dir = args
+        .work_dir
+        .clone()
+        .unwrap_or_else(|| std::env::temp_dir().join("quench-bot"));
+    let policy = Policy {
+        allow_forks: args.allow_fork.clone(),
+        trust_config: args.trust_config,
+    };
+    let bot = Bot::new(work_dir, github, std::env::current_exe()?, args.ci, policy);
+
+    match &args.event {
+        Some(path) => handle_event(&bot, path),
+        None => serve(bot, &args.listen),
+    }
+}
+
+/// Check the pull request in one payload file.
+fn handle_event(bot: &Bot, path: &std::path::Path) -> anyhow::Result<ExitCode> {
+    let payload = if path.as_os_str() == "-" {
+        let mut payload = String::new();
+        std::io::stdin().read_to_string(&mut payload)?;
+        payload
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?
+    };
+    let event = PullRequestEvent::parse(&payload).map_err(|e| anyhow!(e))?;
+    if !event.wants_check() {
+        eprintln!(
+            "quench bot: ignoring '{}' on {}",
+            event.action,
+            event.label()
+        );
+        return Ok(ExitCode::Success);
+    }
+    match bot.handle(&event).map_err(|e| anyhow!(e))? {
+        State::Success => Ok(ExitCode::Success),
+        State::Error => Ok(ExitCode::InternalError),
+        State::Pending | State::Failure => Ok(ExitCode::CheckFailed),
+    }
+}
+
+/// Serve webhook deliveries, answering each at once and checking pull
+/// requests one at a time.
+fn serve(bot: Bot, listen: &str) -> anyhow::Result<ExitCode> {
+    let secret = env::quench_webhook_secret()
+        .context("QUENCH_WEBHOOK_SECRET must be set to verify webhook deliveries")?;
+    let listener =
+        TcpListener::bind(listen).with_context(|| format!("failed to listen on {}", listen))?;
+    eprintln!("quench bot: listening on {}", listener.local_addr()?);
+
+    // One worker, so checks of the same repository never share a checkout
+    let (tx, rx) = mpsc::channel::<PullRequestEvent>();
+    std::thread::spawn(move || {
+        for event in rx {
+            eprintln!("quench bot: {}: checking", event.label());
+            match bot.handle(&event) {
+                Ok(state) => eprintln!("quench bot: {}: {}", event.label(), state.as_str()),
+                Err(e) => eprintln!("quench bot: {}: {}", event.label(), e),
+            }
+        }
+    });
+
+    server::serve(listener, secret.as_bytes(), &tx);
+    Ok(ExitCode::Success)
+}
@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Fix helpers for the check command: patches and baseline updates.
+
+use anyhow::Context;
+
+use quench::baseline::Baseline;
+use quench::baseline_history;
+use quench::config;
+use quench::diagnostics::{Code, Diagnostics};
+use quench::git::{is_git_repo, save_to_git_notes};
+use quench::patch::FixPatch;
+use quench::ratchet::{self, CurrentMetrics, SelfTimeCurrent};
+
+/// Write the fixes collected by `--emit-patch`, leaving the working tree as is.
+pub(super) fn write_patch(
+    patch: &FixPatch,
+    path: &std::path::Path,
+    root: &std::path::Path,
+) -> anyhow::Result<()> {
+    let diff = patch.render(root)?;
+    std::fs::write(path, diff)
+        .with_context(|| format!("failed to write patch {}", path.display()))?;
+    eprintln!(
+        "Wrote fixes for {} file(s) to {}; working tree unchanged",
+        patch.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+/// Show the fixes a `--dry-run` collected, as a unified diff on stderr.
+pub(super) fn preview_patch(patch: &FixPatch, root: &std::path::Path) -> anyhow::Result<()> {
+    if patch.is_empty() {
+        return Ok(());
+    }
+    eprintln!("fix: would change {} file(s)", patch.len());
+    eprint!("{}", patch.render(root)?);
+    Ok(())
+}
+
+/// Save current metrics to the baseline, returning the saved baseline.
+/// `self_time` is quench's own run time, when this run's duration is
+/// representative.
+pub(crate) fn save_baseline(
+    config: &config::Config,
+    output: &quench::check::CheckOutput,
+    ratchet_result: &Option<ratchet::RatchetResult>,
+    baseline: Option<Baseline>,
+    root: &std::path::Path,
+    self_time: Option<SelfTimeCurrent>,
+    diagnostics: &Diagnostics,
+) -> Baseline {
+    let use_notes = config.git.uses_notes() && is_git_repo(root);
+    let baseline = updated_baseline(output, baseline, root, self_time);
+    let mut saved = false;
+
+    if use_notes {
+        let json = match serde_json::to_string_pretty(&baseline) {
+            Ok(j) => j,
+            Err(e) => {
+                diagnostics.emit(
+                    Code::BaselineSaveFailed,
+                    format!("failed to serialize baseline: {}", e),
+                );
+                return baseline;
+            }
+        };
+        match save_to_git_notes(root, &json) {
+            Ok(()) => {
+                saved = true;
+                report_baseline_update(ratchet_result, "git notes");
+            }
+            Err(e) => diagnostics.emit(
+                Code::BaselineSaveFailed,
+                format!("failed to save to git notes: {}", e),
+            ),
+        }
+    }
+
+    if let Some(path) = config.git.baseline_path() {
+        let baseline_path = root.join(path);
+        let baseline_existed = baseline_path.exists();
+        if let Err(e) = baseline.save(&baseline_path) {
+            diagnostics.emit(
+                Code::BaselineSaveFailed,
+                format!("failed to save baseline: {}", e),
+            );
+        } else {
+            saved = true;
+            if !use_notes {
+                report_baseline_update_file(ratchet_result, &baseline_path, baseline_existed);
+            }
+        }
+    }
+
+    if saved {
+        record_history(root, &baseline, diagnostics);
+    }
+
+    baseline
+}
+
+/// Keep a snapshot of a saved baseline in `.quench/history/`.
+fn record_history(root: &std::path::Path, baseline: &Baseline, diagnostics: &Diagnostics) {
+    if let Err(e) = baseline_history::record(root, baseline) {
+        diagnostics.emit(
+            Code::BaselineSaveFailed,
+            format!("failed to record baseline history: {}", e),
+        );
+    }
+}
+
+/// The baseline updated with this run's metrics.
+fn updated_baseline(
+    output: &quench::check::CheckOutput,
+    baseline: Option<Baseline>,
+    root: &std::path::Path,
+    self_time: Option<SelfTimeCurrent>,
+) -> Baseline {
+    let current = CurrentMetrics {
+        self_time,
+        ..CurrentMetrics::from_output(output)
+    };
+    let mut baseline = baseline
+        .map(|b| b.with_commit(root))
+        .unwrap_or_else(|| Baseline::new().with_commit(root));
+
+    // Set first, so the history sample this update records keeps the run's tags
+    baseline.tags = output.tags.clone();
+    ratchet::update_baseline(&mut baseline, &current);
+    baseline
+}
+
+/// Show the metrics `--fix` would move in the baseline, without saving.
+pub(super) fn preview_baseline(
+    config: &config::Config,
+    output: &quench::check::CheckOutput,
+    baseline: Option<Baseline>,
+    root: &std::path::Path,
+    self_time: Option<SelfTimeCurrent>,
+) {
+    let target = if config.git.uses_notes() && is_git_repo(root) {
+        "git notes".to_string()
+    } else if let Some(path) = config.git.baseline_path() {
+        path.to_string()
+    } else {
+        return;
+    };
+    let current = baseline
+        .as_ref()
+        .map(|b| b.metrics.clone())
+        .unwrap_or_default();
+    let updated = updated_baseline(output, baseline, root, self_time);
+    let changes = ratchet::baseline_changes(&current, &updated.metrics);
+
+    if changes.is_empty() {
+        eprintln!("ratchet: baseline unchanged ({})", target);
+    } else {
+        eprintln!("ratchet: would update baseline ({})", target);
+        eprint!("{}", ratchet::format_baseline_diff(&target, &changes));
+    }
+}
+
+/// Report baseline update to stderr (for git notes mode).
+fn report_baseline_update(ratchet_result: &Option<ratchet::RatchetResult>, target: &str) {
+    if let Some(result) = ratchet_result {
+        if result.improvements.is_empty() {
+            eprintln!("ratchet: baseline synced ({})", target);
+        } else {
+            eprintln!("ratchet: updated baseline ({})", target);
+            print_improvements(&result.improvements);
+        }
+    } else {
+        eprintln!("ratchet: created initial baseline ({})", target);
+    }
+}
+
+/// Report baseline update to stderr (for file mode).
+fn report_baseline_update_file(
+    ratchet_result: &Option<ratchet::RatchetResult>,
+    path: &std::path::Path,
+    existed: bool,
+) {
+    if !existed {
+        eprintln!("ratchet: created initial baseline at {}", path.display());
+    } else if let Some(result) = ratchet_result {
+        if result.improvements.is_empty() {
+            eprintln!("ratchet: baseline synced");
+        } else {
+            eprintln!("ratchet: updated baseline at {}", path.display());
+            print_improvements(&result.improvements);
+        }
+    } else {
+        eprintln!("ratchet: baseline synced");
+    }
+}
+
+fn print_improvements(improvements: &[ratchet::MetricImprovement]) {
+    for improvement in improvements {
+        let ratchet_label = if improvement.name.starts_with("coverage.") {
+            "new floor"
+        } else {
+            "new ceiling"
+        };
+        eprintln!(
+            "  {}: {} -> {} ({})",
+            improvement.name,
+            improvement.format_value(improvement.old_value),
+            improvement.format_value(improvement.new_value),
+            ratchet_label,
+        );
+    }
+}
@@ -3,38 +3,60 @@
 
 //! Check command implementation.
 
+mod fix;
+mod output;
+mod ratchet;
+mod setup;
+mod stream;
 mod verbose;
 
 use std::sync::Arc;
 use std::time::Instant;
 
 use quench::adapter::project::apply_language_defaults;
+use quench::advice::AdviceCatalog;
 use quench::baseline::Baseline;
-use quench::cache::{self, CACHE_FILE_NAME, FileCache};
-use quench::checks;
-use quench::cli::{CheckArgs, CheckFilter, Cli, OutputFormat};
-use quench::color::resolve_color;
-use quench::config::{self, CheckLevel};
-use quench::discovery;
+use quench::cache::{CACHE_FILE_NAME, FileCache};
+use quench::check::{Check, CheckResult};
+use quench::checks::{self, git::dirty, release::ReleaseCheck};
+use quench::cli::{CheckArgs, CheckFilter, Cli};
+use quench::codeowners::CodeOwners;
+use quench::coherence;
+use quench::config::{self, AllowDirty};
+use quench::determinism;
+use quench::diagnostics::{Code, Diagnostics};
 use quench::error::ExitCode;
-use quench::git::{
-    detect_base_branch, find_ratchet_base, get_changed_files, get_staged_files, is_git_repo,
-    save_to_git_notes,
-};
+use quench::git::{detect_base_branch, is_git_repo, resolve_base};
 use quench::latest::{LatestMetrics, get_head_commit};
-use quench::output::FormatOptions;
-use quench::output::json::{self, JsonFormatter};
-use quench::output::text::TextFormatter;
-use quench::ratchet::{self, CurrentMetrics};
-use quench::runner::{CheckRunner, RunnerConfig};
-use quench::timing::{PhaseTiming, TimingInfo};
+use quench::output::json;
+use quench::patch::FixPatch;
+use quench::quarantine::{Quarantine, expiry_warning};
+use quench::ratchet::SelfTimeCurrent;
+use quench::rules::RuleFilter;
+use quench::runner::{CheckEvent, CheckRunner, RunnerConfig};
+use quench::sparse::SparseState;
+use quench::telemetry::{self, RunInfo};
+use quench::tolerance::parse_duration;
 use quench::verbose::VerboseLogger;
-use quench::walker::{FileWalker, WalkerConfig};
+use quench::walker::WalkerConfig;
 
-/// Check if debug files mode is enabled via QUENCH_DEBUG_FILES env var.
-fn debug_files() -> bool {
-    quench::env::quench_debug_files()
-}
+use fix::{preview_baseline, preview_patch, write_patch};
+use output::{
+    build_timing_info, determine_exit_code, format_output, output_sink, print_timing,
+    save_metrics_to_file,
+};
+use ratchet::{attribute_regressions, notify_ratchet_webhook, warn_self_time};
+use setup::{
+    dirty_files, legacy_files, load_config, resolve_base_branch, resolve_base_commit,
+    resolve_changed_files, resolve_staged_content, run_discovery, run_limit, runner_config,
+    sample_seed, setup_cache, setup_verbose, take_lock, tool_mismatches, validate_flags,
+};
+use stream::{Adjustments, Stream};
+
+pub(crate) use fix::save_baseline;
+pub(crate) use ratchet::run_ratchet_check;
+
+use crate::store::ResultStore;
 
 /// Run the check command.
 pub fn run(_cli: &Cli, args: &CheckArgs) -> anyhow::Result<ExitCode> {
@@ -45,9 +67,20 @@ pub fn run(_cli: &Cli, args: &CheckArgs) -> anyhow::Result<ExitCode> {
         return Ok(exit);
     }
 
+    let mut args = args.clone();
+    let ci = args.resolve_ci();
+    let args = &args;
+
     let verbose = setup_verbose(args);
+    if let Some((_, Some(var))) = ci {
+        verbose.log(&format!("CI mode: {} is set (--no-ci to opt out)", var));
+    }
     let cwd = std::env::current_dir()?;
     let root = resolve_root(&cwd, args);
+    let _lock = match take_lock(args, &root) {
+        Ok(lock) => lock,
+        Err(exit) => return Ok(exit),
+    };
 
     // === Configuration Phase ===
     let (mut config, config_path) = load_config(&root)?;
@@ -57,93 +90,348 @@ pub fn run(_cli: &Cli, args: &CheckArgs) -> anyhow::Result<ExitCode> {
     let walker_config = WalkerConfig {
         max_depth: Some(args.max_depth),
         exclude_patterns,
-        ..Default::default()
+        ..WalkerConfig::for_project(&config.project)
     };
 
     // === Discovery Phase ===
     let discovery_start = Instant::now();
     let (files, stats) = run_discovery(&root, walker_config, &verbose)?;
-    let Some(files) = files else {
+    let Some(mut files) = files else {
         return Ok(ExitCode::Success); // debug_files mode handled
     };
+    if let Some(ref owner) = args.owner {
+        let Some((path, owners)) = CodeOwners::find(&root) else {
+            eprintln!("quench: --owner requires a CODEOWNERS file");
+            eprintln!("  Looked in .github/CODEOWNERS, CODEOWNERS, and docs/CODEOWNERS.");
+            return Ok(ExitCode::ConfigError);
+        };
+        files.retain(|f| owners.is_owned_by(f.path.strip_prefix(&root).unwrap_or(&f.path), owner));
+        verbose.log(&format!(
+            "Owner: {} owns {} files (from {})",
+            owner,
+            files.len(),
+            path.display()
+        ));
+    }
     let discovery_ms = discovery_start.elapsed().as_millis() as u64;
 
     verbose::discovery(&verbose, args, &files, &stats);
 
     // === Setup Phase ===
-    let checks_list = checks::filter_checks(&args.enabled_checks(), &args.disabled_checks());
-    let base_branch = resolve_base_branch(args, &root);
-    let changed_files = resolve_changed_files(args, &root, &base_branch, &verbose);
+    let rule_filter = match RuleFilter::new(&args.only_rules, &args.skip_rules, &config.rules) {
+        Ok(filter) => filter,
+        Err(e) => {
+            eprintln!("quench: {}", e);
+            return Ok(ExitCode::ConfigError);
+        }
+    };
+    let advice = match AdviceCatalog::new(&config.advice, &root) {
+        Ok(advice) => advice,
+        Err(e) => {
+            eprintln!("quench: {}", e);
+            return Ok(ExitCode::ConfigError);
+        }
+    };
+    let budget = match args.budget.as_deref().map(parse_duration).transpose() {
+        Ok(budget) => budget,
+        Err(e) => {
+            eprintln!("quench: invalid --budget: {}", e);
+            return Ok(ExitCode::ConfigError);
+        }
+    };
+    let mut checks_list = checks::instance::with_instances(
+        checks::filter_checks(&args.enabled_checks(), &args.disabled_checks()),
+        &config,
+    );
+    let diagnostics = Arc::new(Diagnostics::default());
+    let base_ref = resolve_base_branch(args, &root);
+    let base_branch = resolve_base_commit(args, &config, &root, &base_ref, &verbose);
+    let changed_files = resolve_changed_files(args, &root, &base_branch, &verbose, &diagnostics);
+    let staged_content = resolve_staged_content(args, &root, &changed_files, &diagnostics);
+
+    let sample_seed = sample_seed(args, &root, &changed_files);
 
     verbose::suites(&verbose, &config);
     verbose::commits(&verbose, &root, &base_branch);
+    verbose::sampling(&verbose, &config, &checks_list, &sample_seed);
 
-    let limit = effective_limit(args);
+    // Fixes are collected rather than written for --emit-patch and --dry-run
+    let patch = (args.emit_patch.is_some() || (args.fix && args.dry_run))
+        .then(|| Arc::new(FixPatch::new()));
     let mut runner = CheckRunner::new(RunnerConfig {
-        limit,
-        changed_files,
-        fix: args.fix,
-        dry_run: args.dry_run,
-        ci_mode: args.ci,
-        base_branch: base_branch.clone(),
-        staged: args.staged,
-        verbose: verbose.is_enabled(),
-    });
+        limit: run_limit(args, &rule_filter),
+        ..runner_config(
+            args,
+            changed_files.clone(),
+            staged_content.clone(),
+            &base_branch,
+            &sample_seed,
+            patch.clone(),
+            &verbose,
+        )
+    })
+    .with_diagnostics(Arc::clone(&diagnostics));
 
     let cache = setup_cache(args, &root, &config)?;
+    let mut store = ResultStore::open(&root, &config)?;
     if let Some(ref cache) = cache {
         runner = runner.with_cache(Arc::clone(cache));
     }
 
+    let mut skipped_results = Vec::new();
+    let sparse = SparseState::detect(&root);
+    if sparse.is_active() {
+        verbose::sparse(&verbose, &sparse);
+        let (skipped, run): (Vec<_>, Vec<_>) = checks_list
+            .into_iter()
+            .partition(|c| sparse.skips(c.name()));
+        skipped_results.extend(
+            skipped
+                .iter()
+                .map(|c| CheckResult::skipped(c.name(), sparse.skip_reason())),
+        );
+        checks_list = run;
+    }
+
+    let tool_mismatches = tool_mismatches(&config, &verbose);
+    if let Some(tools) = &tool_mismatches {
+        let (skipped, run): (Vec<_>, Vec<_>) = checks_list
+            .into_iter()
+            .partition(|c| tools.skip_reason(c.name()).is_some());
+        skipped_results.extend(skipped.iter().filter_map(|c| {
+            let reason = tools.skip_reason(c.name())?;
+            Some(CheckResult::skipped(c.name(), reason))
+        }));
+        checks_list = run;
+    }
+
+    if let (Some(budget), Some(cache)) = (budget, &cache) {
+        let plan = quench::budget::plan(checks_list, budget, |name| cache.check_duration(name));
+        verbose::budget(&verbose, budget, &plan);
+        skipped_results.extend(plan.skipped_results(budget));
+        checks_list = plan.run;
+    }
+    verbose::scheduling(&verbose, &root, &files, &checks_list, &cache);
+
+    // Before checks run, since --fix edits files
+    let dirty_files = dirty_files(args, &config, &root);
+
+    let quarantine = Quarantine::new(&config.quarantine, chrono::Local::now().date_naive());
+    for entry in quarantine.expired() {
+        diagnostics.emit(Code::QuarantineExpired, expiry_warning(entry));
+    }
+    for diagnostic in coherence::validate(&config, &root) {
+        diagnostics.report(diagnostic);
+    }
+    let legacy = legacy_files(&config, &root, &verbose, &diagnostics);
+    let adjust = Adjustments {
+        root: &root,
+        dirty: dirty_files
+            .as_deref()
+            .map(|files| (dirty::violation(files), config.git.allow_dirty)),
+        tools: tool_mismatches.as_ref(),
+        rules: &rule_filter,
+        advice: &advice,
+        quarantine: &quarantine,
+        legacy: legacy.as_ref(),
+    };
+    let stream = output_sink(args).map(|sink| {
+        let mut names: Vec<String> = checks_list.iter().map(|c| c.name().to_string()).collect();
+        names.extend(skipped_results.iter().map(|r| r.name.clone()));
+        if args.release_mode {
+            names.push(ReleaseCheck.name().to_string());
+        }
+        Stream::new(sink, &adjust, names)
+    });
+    // A nondeterministic run prints nothing, so stream only once verified
+    let live = stream.as_ref().filter(|_| !args.check_determinism);
+    let on_event = |event: CheckEvent| match (live, event) {
+        (Some(stream), CheckEvent::Started(name)) => stream.started(name),
+        (Some(stream), CheckEvent::Finished(result)) => stream.push(result),
+        (None, _) => {}
+    };
+    if let Some(stream) = live {
+        skipped_results.iter().for_each(|r| stream.push(r));
+    }
+
     // === Checking Phase ===
     let checking_start = Instant::now();
-    let check_results = runner.run(checks_list, &files, &config, &root);
+    let rerun_checks = args.check_determinism.then(|| checks_list.clone());
+    let mut check_results = runner.run_with(checks_list, &files, &config, &root, &on_event);
+    let mut lossy_files = runner.lossy_files();
+    if args.release_mode {
+        // Project-wide check: scan every file, not just cache misses
+        let release = CheckRunner::new(RunnerConfig {
+            limit: run_limit(args, &rule_filter),
+            ..runner_config(
+                args,
+                changed_files.clone(),
+                staged_content.clone(),
+                &base_branch,
+                &sample_seed,
+                None,
+                &verbose,
+            )
+        })
+        .with_diagnostics(Arc::clone(&diagnostics));
+        let release_check: Arc<dyn Check> = Arc::new(ReleaseCheck);
+        check_results.extend(release.run_with(
+            vec![release_check],
+            &files,
+            &config,
+            &root,
+            &on_event,
+        ));
+        lossy_files.extend(release.lossy_files());
+        lossy_files.sort();
+        lossy_files.dedup();
+    }
+    for path in &lossy_files {
+        let relative = path.strip_prefix(&root).unwrap_or(path);
+        diagnostics.emit(
+            Code::UndecodableFile,
+            format!("{}: not valid UTF-8, decoded lossily", relative.display()),
+        );
+    }
     let checking_ms = checking_start.elapsed().as_millis() as u64;
+    if let Some(patch) = &patch {
+        match &args.emit_patch {
+            Some(path) => write_patch(patch, path, &root)?,
+            None => preview_patch(patch, &root)?,
+        }
+    }
+    // Partial results are reported but don't update the baseline or history
+    let interrupted = quench::interrupt::requested();
+
+    if let Some(checks_list) = rerun_checks.filter(|_| !interrupted) {
+        // Diagnostics were already reported by the first run
+        let rerun = CheckRunner::new(runner_config(
+            args,
+            changed_files,
+            staged_content,
+            &base_branch,
+            &sample_seed,
+            None,
+            &verbose,
+        ))
+        .with_diagnostics(Arc::new(Diagnostics::silent()));
+        let second = rerun.run(checks_list, &files, &config, &root);
+        if let Some(diff) = determinism::diff_runs(&check_results, &second) {
+            eprintln!("quench: nondeterministic output between two identical runs");
+            eprint!("{}", diff);
+            return Ok(ExitCode::InternalError);
+        }
+        verbose.log("Determinism: two runs produced identical results");
+        if let Some(stream) = &stream {
+            check_results
+                .iter()
+                .chain(&skipped_results)
+                .for_each(|r| stream.push(r));
+        }
+    }
+
+    if !skipped_results.is_empty() {
+        check_results.extend(skipped_results);
+        check_results.sort_by_key(|r| checks::order_key(&r.name));
+    }
+    adjust.apply(&mut check_results);
+    quench::runner::correlate_violations(&mut check_results);
 
     let cache_handle = persist_cache_async(args, &cache, &root);
     verbose::cache(&verbose, &cache);
 
-    let output = json::create_output(check_results);
+    let mut output = json::create_output(check_results)
+        .with_tags(args.tags.iter().cloned().collect())
+        .with_undecodable_files(lossy_files.len())
+        .with_ci(ci.map(|(mode, _)| mode))
+        .with_interrupted(interrupted);
 
     // === Ratchet Phase ===
     let use_notes = config.git.uses_notes() && is_git_repo(&root);
-    let (ratchet_result, baseline) =
-        run_ratchet_check(&config, &verbose, &output, use_notes, &root, &base_branch);
+    // Owner-scoped metrics aren't comparable with the project-wide baseline
+    let (mut ratchet_result, baseline) = if args.owner.is_some() {
+        verbose.log("Ratchet: skipped for --owner run");
+        (None, None)
+    } else if interrupted {
+        verbose.log("Ratchet: skipped for interrupted run");
+        (None, None)
+    } else {
+        run_ratchet_check(
+            &config,
+            &verbose,
+            &output,
+            use_notes,
+            &root,
+            &base_ref,
+            &diagnostics,
+        )
+    };
+    if args.ci
+        && let Some(result) = ratchet_result.as_mut().filter(|r| !r.passed)
+        && let Some(base) = baseline.as_ref().and_then(|b| b.commit.as_deref())
+    {
+        attribute_regressions(result, &config, &root, base, &verbose);
+    }
+    let self_time = SelfTimeCurrent {
+        ci: args.ci,
+        elapsed: total_start.elapsed(),
+    };
+    if let Some(baseline) = &baseline {
+        warn_self_time(&self_time, baseline, &config, &diagnostics);
+    }
 
-    if args.fix {
-        save_baseline(
+    let fix = args.fix && !interrupted;
+    if fix && dirty_files.is_some() && config.git.allow_dirty == AllowDirty::Deny {
+        diagnostics.emit(
+            Code::BaselineNotUpdated,
+            "not updating baseline: working tree has uncommitted changes",
+        );
+    } else if fix && (args.dry_run || args.emit_patch.is_some()) && args.owner.is_none() {
+        preview_baseline(&config, &output, baseline, &root, Some(self_time));
+    } else if fix && args.owner.is_none() {
+        let saved = save_baseline(
             &config,
             &output,
             &ratchet_result,
             baseline,
-            use_notes,
             &root,
+            Some(self_time),
+            &diagnostics,
         );
+        if let Some(store) = &store {
+            record_baseline(store, &saved, &diagnostics);
+        }
     }
 
-    save_latest(&root, &output, &verbose);
+    if let Some(store) = store.as_mut().filter(|_| !interrupted) {
+        record_run(store, args, &root, &output, &diagnostics);
+    }
+
+    output.diagnostics = diagnostics.all();
+    if !interrupted {
+        save_latest(&root, &output, &verbose);
+    }
 
     // === Output Phase ===
-    let color_choice = resolve_color();
-    let options = FormatOptions {
-        limit: effective_limit(args),
-    };
     let timing_info = build_timing_info(args, &cache, &output, &files, discovery_ms, checking_ms);
 
     let output_start = Instant::now();
+    let sink = stream.map(Stream::finish).transpose()?;
     format_output(
+        sink,
         args,
         &output,
         &ratchet_result,
         &config,
-        color_choice,
-        options,
         timing_info.as_ref(),
     )?;
 
     if let Some(ref save_path) = args.save {
         if let Err(e) = save_metrics_to_file(save_path, &output) {
-            eprintln!("quench: warning: failed to save metrics: {}", e);
+            diagnostics.emit(
+                Code::MetricsSaveFailed,
+                format!("failed to save metrics: {}", e),
+            );
         } else if verbose.is_enabled() {
             verbose.log(&format!("Saved metrics to {}", save_path.display()));
         }
@@ -155,6 +443,20 @@ pub fn run(_cli: &Cli, args: &CheckArgs) -> anyhow::Result<ExitCode> {
     print_timing(args, timing_info, &output, &cache, output_ms, total_ms);
     verbose::summary(&verbose, total_ms);
 
+    let run = RunInfo {
+        files: files.len(),
+        total_ms,
+        ci: args.ci,
+    };
+    if !interrupted {
+        send_telemetry(&config.telemetry, &output, run, &verbose);
+    }
+    if args.ci
+        && let Some(result) = ratchet_result.as_ref().filter(|r| !r.passed)
+    {
+        notify_ratchet_webhook(&config.ratchet, result, &verbose);
+    }
+
     // Wait for cache persistence
     if let Some(handle) = cache_handle
         && let Err(e) = handle.join().unwrap_or(Ok(()))
@@ -165,32 +467,47 @@ pub fn run(_cli: &Cli, args: &CheckArgs) -> anyhow::Result<ExitCode> {
     Ok(determine_exit_code(args, &output, &ratchet_result, &config))
 }
 
+/// Run every check in CI mode without cache or output, for commands that
+/// need fresh metrics (e.g., `quench ratchet refresh`).
+pub(crate) fn run_ci_checks(
+    root: &std::path::Path,
+) -> anyhow::Result<(config::Config, quench::check::CheckOutput)> {
+    let (mut config, _) = load_config(root)?;
+    let exclude_patterns = apply_language_defaults(root, &mut config);
+    let walker_config = WalkerConfig {
+        exclude_patterns,
+        ..WalkerConfig::for_project(&config.project)
+    };
+    let quiet = VerboseLogger::new(false);
+    let (files, _) = run_discovery(root, walker_config, &quiet)?;
+    let files = files.unwrap_or_default();
+
+    let runner = CheckRunner::new(RunnerConfig {
+        limit: None,
+        changed_files: None,
+        fix: false,
+        run_fixers: false,
+        dry_run: false,
+        patch: None,
+        ci_mode: true,
+        base_branch: detect_base_branch(root)
+            .map(|base| resolve_base(root, &base, config.git.base_strategy).unwrap_or(base)),
+        staged: false,
+        staged_content: None,
+        verbose: false,
+        sample_seed: None,
+        jobs: None,
+    });
+    let checks_list = checks::instance::with_instances(checks::filter_checks(&[], &[]), &config);
+    let results = runner.run(checks_list, &files, &config, root);
+    Ok((config, json::create_output(results)))
+}
+
 // =============================================================================
 // Phase helpers
 // =============================================================================
 
-fn validate_flags(args: &CheckArgs) -> Option<ExitCode> {
-    if args.dry_run && !args.fix {
-        eprintln!("--dry-run only works with --fix");
-        eprintln!(
-            "  The --dry-run flag lets you preview what --fix would change without applying changes."
-        );
-        eprintln!("  Use: quench check --fix --dry-run");
-        return Some(ExitCode::ConfigError);
-    }
-    if args.staged && args.base.is_some() {
-        eprintln!("--staged and --base cannot be used together");
-        return Some(ExitCode::ConfigError);
-    }
-    None
-}
-
-fn setup_verbose(args: &CheckArgs) -> VerboseLogger {
-    let verbose_enabled = args.ci || args.verbose || quench::env::quench_debug();
-    VerboseLogger::new(verbose_enabled)
-}
-
-fn resolve_root(cwd: &std::path::Path, args: &CheckArgs) -> std::path::PathBuf {
+pub fn resolve_root(cwd: &std::path::Path, args: &CheckArgs) -> std::path::PathBuf {
     if args.paths.is_empty() {
         cwd.to_path_buf()
     } else {
@@ -203,136 +520,26 @@ fn resolve_root(cwd: &std::path::Path, args: &CheckArgs) -> std::path::PathBuf {
     }
 }
 
-fn load_config(
-    root: &std::path::Path,
-) -> anyhow::Result<(config::Config, Option<std::path::PathBuf>)> {
-    let config_path = discovery::find_config(root);
-    let config = match &config_path {
-        Some(path) => {
-            tracing::debug!("loading config from {}", path.display());
-            config::load_with_warnings(path)?
-        }
-        None => {
-            tracing::debug!("no config found, using defaults");
-            config::Config::default()
-        }
-    };
-    tracing::trace!("check command starting");
-    Ok((config, config_path))
-}
-
-/// Run file discovery. Returns None for files if debug_files mode handled output.
-fn run_discovery(
-    root: &std::path::Path,
-    walker_config: WalkerConfig,
-    verbose: &VerboseLogger,
-) -> anyhow::Result<(
-    Option<Vec<quench::walker::WalkedFile>>,
-    quench::walker::WalkStats,
-)> {
-    let walker = FileWalker::new(walker_config);
-    let (rx, handle) = walker.walk(root);
-
-    if debug_files() {
-        for file in rx {
-            let display_path = file.path.strip_prefix(root).unwrap_or(&file.path);
-            println!("{}", display_path.display());
-        }
-        let stats = handle.join();
-        if verbose.is_enabled() {
-            eprintln!(
-                "Scanned {} files, {} errors, {} symlink loops",
-                stats.files_found, stats.errors, stats.symlink_loops
-            );
-        }
-        return Ok((None, stats));
-    }
-
-    let files: Vec<_> = rx.iter().collect();
-    let stats = handle.join();
-    Ok((Some(files), stats))
-}
-
-fn resolve_base_branch(args: &CheckArgs, root: &std::path::Path) -> Option<String> {
-    if let Some(ref base) = args.base {
-        Some(base.clone())
-    } else if args.ci {
-        detect_base_branch(root)
-    } else {
-        None
-    }
-}
-
-fn resolve_changed_files(
-    args: &CheckArgs,
-    root: &std::path::Path,
-    base_branch: &Option<String>,
+/// Upload the anonymized run summary when `[telemetry]` opts in.
+///
+/// Failures are only logged: telemetry never changes a run's outcome.
+fn send_telemetry(
+    config: &config::TelemetryConfig,
+    output: &quench::check::CheckOutput,
+    run: RunInfo,
     verbose: &VerboseLogger,
-) -> Option<Vec<std::path::PathBuf>> {
-    if args.staged {
-        match get_staged_files(root) {
-            Ok(files) => {
-                if verbose.is_enabled() {
-                    verbose.log(&format!("Checking staged files ({} files)", files.len()));
-                }
-                Some(files)
-            }
-            Err(e) => {
-                eprintln!("quench: warning: could not get staged files: {}", e);
-                None
-            }
-        }
-    } else if let Some(base) = base_branch {
-        match get_changed_files(root, base) {
-            Ok(files) => {
-                if verbose.is_enabled() {
-                    verbose.log(&format!(
-                        "Comparing against base: {} ({} files changed)",
-                        base,
-                        files.len()
-                    ));
-                }
-                Some(files)
-            }
-            Err(e) => {
-                if args.base.is_some() {
-                    eprintln!("quench: warning: could not get changed files: {}", e);
-                }
-                None
-            }
-        }
-    } else {
-        None
-    }
-}
-
-fn effective_limit(args: &CheckArgs) -> Option<usize> {
-    if args.no_limit || args.ci {
-        None
-    } else {
-        Some(args.limit)
+) {
+    let Some(endpoint) = config.endpoint.as_deref() else {
+        return;
+    };
+    if quench::env::no_telemetry() {
+        verbose.log("Telemetry: disabled by QUENCH_NO_TELEMETRY");
+        return;
     }
-}
-
-fn setup_cache(
-    args: &CheckArgs,
-    root: &std::path::Path,
-    config: &config::Config,
-) -> anyhow::Result<Option<Arc<FileCache>>> {
-    if args.no_cache {
-        return Ok(None);
-    }
-    let cache_path = root.join(".quench").join(CACHE_FILE_NAME);
-    let config_hash = cache::hash_config(config);
-    match FileCache::from_persistent(&cache_path, config_hash) {
-        Ok(cache) => {
-            tracing::debug!("loaded cache from {}", cache_path.display());
-            Ok(Some(Arc::new(cache)))
-        }
-        Err(e) => {
-            tracing::debug!("cache not loaded ({}), starting fresh", e);
-            Ok(Some(Arc::new(FileCache::new(config_hash))))
-        }
+    let summary = telemetry::summary(output, run);
+    match telemetry::send(endpoint, config.timeout(), &summary) {
+        Ok(()) => verbose.log(&format!("Telemetry: sent run summary to {}", endpoint)),
+        Err(e) => tracing::warn!("telemetry upload to {} failed: {}", endpoint, e),
     }
 }
 
@@ -352,180 +559,36 @@ fn persist_cache_async(
     Some(cache.persist_async(cache_path))
 }
 
-fn run_ratchet_check(
-    config: &config::Config,
-    verbose: &VerboseLogger,
-    output: &quench::check::CheckOutput,
-    use_notes: bool,
-    root: &std::path::Path,
-    base_branch: &Option<String>,
-) -> (Option<ratchet::RatchetResult>, Option<Baseline>) {
-    if config.ratchet.check == CheckLevel::Off {
-        if verbose.is_enabled() {
-            verbose.section("Ratchet");
-            verbose.log("Ratchet check: off");
-        }
-        return (None, None);
-    }
-
-    if verbose.is_enabled() {
-        verbose.section("Ratchet");
-        verbose.log(&format!(
-            "Mode: {}",
-            if use_notes { "git notes" } else { "file" }
-        ));
-        if let Some(base) = base_branch {
-            verbose.log(&format!("Base branch: {}", base));
-        }
-    }
-
-    if use_notes {
-        ratchet_from_notes(config, verbose, output, root, base_branch)
-    } else if let Some(path) = config.git.baseline_path() {
-        ratchet_from_file(config, verbose, output, root, path)
-    } else {
-        if verbose.is_enabled() {
-            verbose.log("Ratchet check: off (not in git repo with notes mode)");
-        }
-        (None, None)
-    }
-}
-
-fn ratchet_from_notes(
-    config: &config::Config,
-    verbose: &VerboseLogger,
-    output: &quench::check::CheckOutput,
+/// Record a whole-project run in the `[store]` database.
+fn record_run(
+    store: &mut ResultStore,
+    args: &CheckArgs,
     root: &std::path::Path,
-    base_branch: &Option<String>,
-) -> (Option<ratchet::RatchetResult>, Option<Baseline>) {
-    match find_ratchet_base(root, base_branch.as_deref()) {
-        Ok(base_commit) => {
-            if verbose.is_enabled() {
-                verbose.log(&format!(
-                    "Ratchet base: {}",
-                    &base_commit[..7.min(base_commit.len())]
-                ));
-            }
-            match Baseline::load_from_notes(root, &base_commit) {
-                Ok(Some(baseline)) => {
-                    if verbose.is_enabled() {
-                        verbose.log(&format!(
-                            "Baseline: loaded from git notes for {}",
-                            &base_commit[..7.min(base_commit.len())]
-                        ));
-                    }
-                    warn_stale_baseline(&baseline, config);
-                    let current = CurrentMetrics::from_output(output);
-                    let result = ratchet::compare(&current, &baseline.metrics, &config.ratchet);
-                    (Some(result), Some(baseline))
-                }
-                Ok(None) => {
-                    if verbose.is_enabled() {
-                        verbose.log(&format!(
-                            "Baseline: not found (searched: refs/notes/quench for {})",
-                            &base_commit[..7.min(base_commit.len())]
-                        ));
-                    }
-                    (None, None)
-                }
-                Err(e) => {
-                    eprintln!("quench: warning: failed to load baseline from notes: {}", e);
-                    (None, None)
-                }
-            }
-        }
-        Err(e) => {
-            if verbose.is_enabled() {
-                verbose.log(&format!("Ratchet base: not found ({})", e));
-            }
-            (None, None)
-        }
-    }
-}
-
-fn ratchet_from_file(
-    config: &config::Config,
-    verbose: &VerboseLogger,
     output: &quench::check::CheckOutput,
-    root: &std::path::Path,
-    path: &str,
-) -> (Option<ratchet::RatchetResult>, Option<Baseline>) {
-    let baseline_path = root.join(path);
-    match Baseline::load(&baseline_path) {
-        Ok(Some(baseline)) => {
-            if verbose.is_enabled() {
-                verbose.log(&format!(
-                    "Baseline: loaded from {}",
-                    baseline_path.display()
-                ));
-            }
-            warn_stale_baseline(&baseline, config);
-            let current = CurrentMetrics::from_output(output);
-            let result = ratchet::compare(&current, &baseline.metrics, &config.ratchet);
-            (Some(result), Some(baseline))
-        }
-        Ok(None) => {
-            if verbose.is_enabled() {
-                verbose.log(&format!(
-                    "No baseline found at {}. Run with --fix to create.",
-                    baseline_path.display()
-                ));
-            }
-            (None, None)
-        }
-        Err(e) => {
-            eprintln!("quench: warning: failed to load baseline: {}", e);
-            (None, None)
-        }
-    }
-}
-
-fn warn_stale_baseline(baseline: &Baseline, config: &config::Config) {
-    if baseline.is_stale(config.ratchet.stale_days) {
-        eprintln!(
-            "warning: baseline is {} days old. Consider refreshing with --fix.",
-            baseline.age_days()
+    diagnostics: &Diagnostics,
+) {
+    // Runs scoped to some paths, owners, staged files, or checks (as in
+    // `quench watch`) don't measure the whole project
+    let narrowed = !args.enabled_checks().is_empty() || !args.disabled_checks().is_empty();
+    if !args.paths.is_empty() || args.owner.is_some() || args.staged || narrowed {
+        return;
+    }
+    let commit = get_head_commit(root).ok();
+    if let Err(e) = store.record_run(commit.as_deref(), args.ci, output) {
+        diagnostics.emit(
+            Code::StoreWriteFailed,
+            format!("failed to record run: {}", e),
         );
     }
 }
 
-fn save_baseline(
-    config: &config::Config,
-    output: &quench::check::CheckOutput,
-    ratchet_result: &Option<ratchet::RatchetResult>,
-    baseline: Option<Baseline>,
-    use_notes: bool,
-    root: &std::path::Path,
-) {
-    let current = CurrentMetrics::from_output(output);
-    let mut baseline = baseline
-        .map(|b| b.with_commit(root))
-        .unwrap_or_else(|| Baseline::new().with_commit(root));
-
-    ratchet::update_baseline(&mut baseline, &current);
-
-    if use_notes {
-        let json = match serde_json::to_string_pretty(&baseline) {
-            Ok(j) => j,
-            Err(e) => {
-                eprintln!("quench: warning: failed to serialize baseline: {}", e);
-                return;
-            }
-        };
-        match save_to_git_notes(root, &json) {
-            Ok(()) => report_baseline_update(ratchet_result, "git notes"),
-            Err(e) => eprintln!("quench: warning: failed to save to git notes: {}", e),
-        }
-    }
-
-    if let Some(path) = config.git.baseline_path() {
-        let baseline_path = root.join(path);
-        let baseline_existed = baseline_path.exists();
-        if let Err(e) = baseline.save(&baseline_path) {
-            eprintln!("quench: warning: failed to save baseline: {}", e);
-        } else if !use_notes {
-            report_baseline_update_file(ratchet_result, &baseline_path, baseline_existed);
-        }
+/// Keep a copy of a saved baseline in the `[store]` database.
+pub(crate) fn record_baseline(store: &ResultStore, baseline: &Baseline, diagnostics: &Diagnostics) {
+    if let Err(e) = store.record_baseline(baseline) {
+        diagnostics.emit(
+            Code::StoreWriteFailed,
+            format!("failed to record baseline: {}", e),
+        );
     }
 }
 
@@ -546,173 +609,3 @@ fn save_latest(
         verbose.log(&format!("Failed to write latest.json: {}", e));
     }
 }
-
-fn build_timing_info(
-    args: &CheckArgs,
-    cache: &Option<Arc<FileCache>>,
-    output: &quench::check::CheckOutput,
-    files: &[quench::walker::WalkedFile],
-    discovery_ms: u64,
-    checking_ms: u64,
-) -> Option<TimingInfo> {
-    if !args.timing {
-        return None;
-    }
-    let stats = cache.as_ref().map(|c| c.stats());
-    Some(TimingInfo {
-        phases: PhaseTiming {
-            discovery_ms,
-            checking_ms,
-            output_ms: 0,
-            total_ms: 0,
-        },
-        files: files.len(),
-        cache_hits: stats.as_ref().map(|s| s.hits).unwrap_or(0),
-        checks: output
-            .checks
-            .iter()
-            .filter_map(|r| r.duration_ms.map(|d| (r.name.clone(), d)))
-            .collect(),
-    })
-}
-
-fn format_output(
-    args: &CheckArgs,
-    output: &quench::check::CheckOutput,
-    ratchet_result: &Option<ratchet::RatchetResult>,
-    config: &config::Config,
-    color_choice: termcolor::ColorChoice,
-    options: FormatOptions,
-    timing_info: Option<&TimingInfo>,
-) -> anyhow::Result<()> {
-    let total_violations = output.total_violations();
-    match args.output {
-        OutputFormat::Text | OutputFormat::Html | OutputFormat::Markdown => {
-            let mut formatter = TextFormatter::new(color_choice, options);
-            for result in &output.checks {
-                formatter.write_check(result)?;
-            }
-            if let Some(result) = ratchet_result {
-                formatter.write_ratchet(result, config.ratchet.check)?;
-            }
-            formatter.write_summary(output)?;
-            if formatter.was_truncated() {
-                formatter.write_truncation_message(total_violations)?;
-            }
-        }
-        OutputFormat::Json => {
-            let mut formatter = JsonFormatter::new(std::io::stdout());
-            formatter.write_with_timing(output, ratchet_result.as_ref(), timing_info)?;
-        }
-    }
-    Ok(())
-}
-
-fn print_timing(
-    args: &CheckArgs,
-    timing_info: Option<TimingInfo>,
-    output: &quench::check::CheckOutput,
-    cache: &Option<Arc<FileCache>>,
-    output_ms: u64,
-    total_ms: u64,
-) {
-    if let Some(mut info) = timing_info {
-        info.phases.output_ms = output_ms;
-        info.phases.total_ms = total_ms;
-        if !matches!(args.output, OutputFormat::Json) {
-            eprintln!("{}", info.phases.format_text());
-            for result in &output.checks {
-                if let Some(ms) = result.duration_ms {
-                    eprintln!("{}: {}ms", result.name, ms);
-                }
-            }
-            eprintln!("files: {}", info.files);
-            let misses = cache.as_ref().map(|c| c.stats().misses).unwrap_or(0);
-            eprintln!("{}", info.format_cache(misses));
-        }
-    }
-}
-
-fn determine_exit_code(
-    args: &CheckArgs,
-    output: &quench::check::CheckOutput,
-    ratchet_result: &Option<ratchet::RatchetResult>,
-    config: &config::Config,
-) -> ExitCode {
-    let ratchet_failed = ratchet_result
-        .as_ref()
-        .is_some_and(|r| !r.passed && config.ratchet.check == CheckLevel::Error);
-    if args.dry_run {
-        ExitCode::Success
-    } else if !output.passed || ratchet_failed {
-        ExitCode::CheckFailed
-    } else {
-        ExitCode::Success
-    }
-}
-
-/// Save metrics output to a JSON file.
-fn save_metrics_to_file(
-    path: &std::path::Path,
-    output: &quench::check::CheckOutput,
-) -> anyhow::Result<()> {
-    if let Some(parent) = path.parent()
-        && !parent.as_os_str().is_empty()
-    {
-        std::fs::create_dir_all(parent)?;
-    }
-    let json = serde_json::to_string_pretty(output)?;
-    std::fs::write(path, json)?;
-    Ok(())
-}
-
-/// Report baseline update to stderr (for git notes mode).
-fn report_baseline_update(ratchet_result: &Option<ratchet::RatchetResult>, target: &str) {
-    if let Some(result) = ratchet_result {
-        if result.improvements.is_empty() {
-            eprintln!("ratchet: baseline synced ({})", target);
-        } else {
-            eprintln!("ratchet: updated baseline ({})", target);
-            print_improvements(&result.improvements);
-        }
-    } else {
-        eprintln!("ratchet: created initial baseline ({})", target);
-    }
-}
-
-/// Report baseline update to stderr (for file mode).
-fn report_baseline_update_file(
-    ratchet_result: &Option<ratchet::RatchetResult>,
-    path: &std::path::Path,
-    existed: bool,
-) {
-    if !existed {
-        eprintln!("ratchet: created initial baseline at {}", path.display());
-    } else if let Some(result) = ratchet_result {
-        if result.improvements.is_empty() {
-            eprintln!("ratchet: baseline synced");
-        } else {
-            eprintln!("ratchet: updated baseline at {}", path.display());
-            print_improvements(&result.improvements);
-        }
-    } else {
-        eprintln!("ratchet: baseline synced");
-    }
-}
-
-fn print_improvements(improvements: &[ratchet::MetricImprovement]) {
-    for improvement in improvements {
-        let ratchet_label = if improvement.name.starts_with("coverage.") {
-            "new floor"
-        } else {
-            "new ceiling"
-        };
-        eprintln!(
-            "  {}: {} -> {} ({})",
-            improvement.name,
-            improvement.format_value(improvement.old_value),
-            improvement.format_value(improvement.new_value),
-            ratchet_label,
-        );
-    }
-}
@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Output helpers for the check command: formatting, timing, and the
+//! exit code.
+
+use std::sync::Arc;
+
+use quench::baseline::Baseline;
+use quench::cache::FileCache;
+use quench::cli::{CheckArgs, OutputFormat};
+use quench::color::resolve_color;
+use quench::config::{self, CheckLevel};
+use quench::error::ExitCode;
+use quench::health::HealthScore;
+use quench::output::FormatOptions;
+use quench::output::json::JsonFormatter;
+use quench::output::ndjson::NdjsonFormatter;
+use quench::output::text::TextFormatter;
+use quench::ratchet::{self, CurrentMetrics};
+use quench::timing::{PhaseTiming, TimingInfo};
+
+use super::setup::effective_limit;
+use super::stream::Sink;
+
+pub(super) fn build_timing_info(
+    args: &CheckArgs,
+    cache: &Option<Arc<FileCache>>,
+    output: &quench::check::CheckOutput,
+    files: &[quench::walker::WalkedFile],
+    discovery_ms: u64,
+    checking_ms: u64,
+) -> Option<TimingInfo> {
+    if !args.timing {
+        return None;
+    }
+    let stats = cache.as_ref().map(|c| c.stats());
+    Some(TimingInfo {
+        phases: PhaseTiming {
+            discovery_ms,
+            checking_ms,
+            output_ms: 0,
+            total_ms: 0,
+        },
+        files: files.len(),
+        cache_hits: stats.as_ref().map(|s| s.hits).unwrap_or(0),
+        checks: output
+            .checks
+            .iter()
+            .filter_map(|r| r.duration_ms.map(|d| (r.name.clone(), d)))
+            .collect(),
+    })
+}
+
+/// Where check results go as they complete, or `None` for JSON, which is
+/// written whole at the end.
+pub(super) fn output_sink(args: &CheckArgs) -> Option<Sink> {
+    match args.output {
+        // SVG is refused by `validate_flags`
+        OutputFormat::Json | OutputFormat::Svg => None,
+        OutputFormat::Ndjson => Some(Sink::Ndjson(NdjsonFormatter::new(std::io::stdout()))),
+        OutputFormat::Text | OutputFormat::Html | OutputFormat::Markdown => {
+            let options = FormatOptions {
+                limit: effective_limit(args),
+            };
+            Some(Sink::Text(TextFormatter::new(resolve_color(), options)))
+        }
+    }
+}
+
+/// Finish output after the checks themselves have streamed to `sink`.
+pub(super) fn format_output(
+    sink: Option<Sink>,
+    args: &CheckArgs,
+    output: &quench::check::CheckOutput,
+    ratchet_result: &Option<ratchet::RatchetResult>,
+    config: &config::Config,
+    timing_info: Option<&TimingInfo>,
+) -> anyhow::Result<()> {
+    match sink {
+        Some(Sink::Text(mut formatter)) => {
+            if let Some(result) = ratchet_result {
+                formatter.write_ratchet(result, config.ratchet.check)?;
+            }
+            formatter.write_summary(output)?;
+            if let Some(health) = health_score(output, config).filter(|_| args.health) {
+                formatter.write_health(&health)?;
+            }
+            if formatter.was_truncated() {
+                formatter.write_truncation_message(output.total_violations())?;
+            }
+        }
+        Some(Sink::Ndjson(mut formatter)) => {
+            formatter.write_summary(output, ratchet_result.as_ref(), timing_info)?;
+        }
+        None => {
+            let mut formatter = JsonFormatter::new(std::io::stdout());
+            formatter.write_with_timing(output, ratchet_result.as_ref(), timing_info)?;
+        }
+    }
+    Ok(())
+}
+
+/// Score the metrics this run measured, capped at the share of checks
+/// that passed so a failing run never grades well.
+fn health_score(
+    output: &quench::check::CheckOutput,
+    config: &config::Config,
+) -> Option<HealthScore> {
+    let mut measured = Baseline::new();
+    ratchet::update_baseline(&mut measured, &CurrentMetrics::from_output(output));
+    let score = HealthScore::from_metrics(&measured.metrics, &config.health)?;
+    let ran = output.checks.iter().filter(|c| !c.skipped).count();
+    let passed = output
+        .checks
+        .iter()
+        .filter(|c| c.passed && !c.skipped)
+        .count();
+    Some(match ran {
+        0 => score,
+        _ => score.capped((100 * passed / ran) as u32),
+    })
+}
+
+pub(super) fn print_timing(
+    args: &CheckArgs,
+    timing_info: Option<TimingInfo>,
+    output: &quench::check::CheckOutput,
+    cache: &Option<Arc<FileCache>>,
+    output_ms: u64,
+    total_ms: u64,
+) {
+    if let Some(mut info) = timing_info {
+        info.phases.output_ms = output_ms;
+        info.phases.total_ms = total_ms;
+        if !matches!(args.output, OutputFormat::Json) {
+            eprintln!("{}", info.phases.format_text());
+            for result in &output.checks {
+                if let Some(ms) = result.duration_ms {
+                    eprintln!("{}: {}ms", result.name, ms);
+                }
+            }
+            eprintln!("files: {}", info.files);
+            let misses = cache.as_ref().map(|c| c.stats().misses).unwrap_or(0);
+            eprintln!("{}", info.format_cache(misses));
+        }
+    }
+}
+
+pub(super) fn determine_exit_code(
+    args: &CheckArgs,
+    output: &quench::check::CheckOutput,
+    ratchet_result: &Option<ratchet::RatchetResult>,
+    config: &config::Config,
+) -> ExitCode {
+    let ratchet_failed = ratchet_result
+        .as_ref()
+        .is_some_and(|r| !r.passed && config.ratchet.check == CheckLevel::Error);
+    if output.interrupted {
+        ExitCode::Interrupted
+    } else if args.dry_run || args.emit_patch.is_some() {
+        // Fixes weren't applied, so the violations they'd fix remain
+        ExitCode::Success
+    } else if !output.passed || ratchet_failed {
+        ExitCode::CheckFailed
+    } else {
+        ExitCode::Success
+    }
+}
+
+/// Save metrics output to a JSON file.
+pub(super) fn save_metrics_to_file(
+    path: &std::path::Path,
+    output: &quench::check::CheckOutput,
+) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(output)?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
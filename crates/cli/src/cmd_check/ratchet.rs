@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Ratchet helpers for the check command: comparing against the
+//! baseline and reporting regressions.
+
+use quench::atomic;
+use quench::baseline::Baseline;
+use quench::checks::escapes::EscapeMatcher;
+use quench::config::{self, CheckLevel};
+use quench::delta_owners;
+use quench::diagnostics::{Code, Diagnostics};
+use quench::git::find_ratchet_base;
+use quench::ratchet::{self, CurrentMetrics, SelfTimeCurrent};
+use quench::telemetry;
+use quench::verbose::VerboseLogger;
+
+/// Fill in the branch commits behind each ratchet regression.
+pub(super) fn attribute_regressions(
+    result: &mut ratchet::RatchetResult,
+    config: &config::Config,
+    root: &std::path::Path,
+    base: &str,
+    verbose: &VerboseLogger,
+) {
+    let escapes = match EscapeMatcher::new(root, config) {
+        Ok(escapes) => escapes,
+        Err(e) => {
+            tracing::warn!("cannot attribute ratchet regressions: {}", e);
+            return;
+        }
+    };
+    match delta_owners::attribute(root, base, result, &escapes) {
+        Ok(owners) => {
+            verbose.log(&format!(
+                "Ratchet: attributed {} regressed metrics since {}",
+                owners.len(),
+                base
+            ));
+            result.owners = owners;
+        }
+        Err(e) => tracing::warn!("cannot attribute ratchet regressions: {}", e),
+    }
+}
+
+/// POST ratchet regressions and their owners to `[ratchet] webhook`.
+pub(super) fn notify_ratchet_webhook(
+    config: &config::RatchetConfig,
+    result: &ratchet::RatchetResult,
+    verbose: &VerboseLogger,
+) {
+    let Some(url) = config.webhook.as_deref() else {
+        return;
+    };
+    let payload = delta_owners::webhook_payload(result);
+    match telemetry::send(url, config::RatchetConfig::WEBHOOK_TIMEOUT, &payload) {
+        Ok(()) => verbose.log(&format!("Ratchet: sent regression webhook to {}", url)),
+        Err(e) => tracing::warn!("ratchet webhook to {} failed: {}", url, e),
+    }
+}
+
+pub(crate) fn run_ratchet_check(
+    config: &config::Config,
+    verbose: &VerboseLogger,
+    output: &quench::check::CheckOutput,
+    use_notes: bool,
+    root: &std::path::Path,
+    base_branch: &Option<String>,
+    diagnostics: &Diagnostics,
+) -> (Option<ratchet::RatchetResult>, Option<Baseline>) {
+    if config.ratchet.check == CheckLevel::Off {
+        if verbose.is_enabled() {
+            verbose.section("Ratchet");
+            verbose.log("Ratchet check: off");
+        }
+        return (None, None);
+    }
+
+    if verbose.is_enabled() {
+        verbose.section("Ratchet");
+        verbose.log(&format!(
+            "Mode: {}",
+            if use_notes { "git notes" } else { "file" }
+        ));
+        if let Some(base) = base_branch {
+            verbose.log(&format!("Base branch: {}", base));
+        }
+    }
+
+    if use_notes {
+        ratchet_from_notes(config, verbose, output, root, base_branch, diagnostics)
+    } else if let Some(path) = config.git.baseline_path() {
+        ratchet_from_file(config, verbose, output, root, path, diagnostics)
+    } else {
+        if verbose.is_enabled() {
+            verbose.log("Ratchet check: off (not in git repo with notes mode)");
+        }
+        (None, None)
+    }
+}
+
+fn ratchet_from_notes(
+    config: &config::Config,
+    verbose: &VerboseLogger,
+    output: &quench::check::CheckOutput,
+    root: &std::path::Path,
+    base_branch: &Option<String>,
+    diagnostics: &Diagnostics,
+) -> (Option<ratchet::RatchetResult>, Option<Baseline>) {
+    match find_ratchet_base(root, base_branch.as_deref()) {
+        Ok(base_commit) => {
+            if verbose.is_enabled() {
+                verbose.log(&format!(
+                    "Ratchet base: {}",
+                    &base_commit[..7.min(base_commit.len())]
+                ));
+            }
+            match Baseline::load_from_notes(root, &base_commit) {
+                Ok(Some(baseline)) => {
+                    if verbose.is_enabled() {
+                        verbose.log(&format!(
+                            "Baseline: loaded from git notes for {}",
+                            &base_commit[..7.min(base_commit.len())]
+                        ));
+                    }
+                    warn_stale_baseline(&baseline, config, diagnostics);
+                    let current = CurrentMetrics::from_output(output);
+                    let result = ratchet::compare(&current, &baseline.metrics, &config.ratchet);
+                    (Some(result), Some(baseline))
+                }
+                Ok(None) => {
+                    if verbose.is_enabled() {
+                        verbose.log(&format!(
+                            "Baseline: not found (searched: refs/notes/quench for {})",
+                            &base_commit[..7.min(base_commit.len())]
+                        ));
+                    }
+                    (None, None)
+                }
+                Err(e) => {
+                    diagnostics.emit(
+                        Code::BaselineLoadFailed,
+                        format!("failed to load baseline from notes: {}", e),
+                    );
+                    (None, None)
+                }
+            }
+        }
+        Err(e) => {
+            if verbose.is_enabled() {
+                verbose.log(&format!("Ratchet base: not found ({})", e));
+            }
+            (None, None)
+        }
+    }
+}
+
+fn ratchet_from_file(
+    config: &config::Config,
+    verbose: &VerboseLogger,
+    output: &quench::check::CheckOutput,
+    root: &std::path::Path,
+    path: &str,
+    diagnostics: &Diagnostics,
+) -> (Option<ratchet::RatchetResult>, Option<Baseline>) {
+    let baseline_path = root.join(path);
+    let loaded = Baseline::load(&baseline_path).or_else(|e| {
+        // A damaged baseline falls back to the copy kept by the last save
+        let backup = atomic::backup_path(&baseline_path);
+        match Baseline::load(&backup) {
+            Ok(Some(baseline)) => {
+                diagnostics.emit(
+                    Code::BaselineLoadFailed,
+                    format!(
+                        "failed to load baseline: {}; using backup {}",
+                        e,
+                        backup.strip_prefix(root).unwrap_or(&backup).display()
+                    ),
+                );
+                Ok(Some(baseline))
+            }
+            _ => Err(e),
+        }
+    });
+    match loaded {
+        Ok(Some(baseline)) => {
+            if verbose.is_enabled() {
+                verbose.log(&format!(
+                    "Baseline: loaded from {}",
+                    baseline_path.display()
+                ));
+            }
+            warn_stale_baseline(&baseline, config, diagnostics);
+            let current = CurrentMetrics::from_output(output);
+            let result = ratchet::compare(&current, &baseline.metrics, &config.ratchet);
+            (Some(result), Some(baseline))
+        }
+        Ok(None) => {
+            if verbose.is_enabled() {
+                verbose.log(&format!(
+                    "No baseline found at {}. Run with --fix to create.",
+                    baseline_path.display()
+                ));
+            }
+            (None, None)
+        }
+        Err(e) => {
+            diagnostics.emit(
+                Code::BaselineLoadFailed,
+                format!("failed to load baseline: {}", e),
+            );
+            (None, None)
+        }
+    }
+}
+
+fn warn_stale_baseline(baseline: &Baseline, config: &config::Config, diagnostics: &Diagnostics) {
+    if baseline.is_stale(config.ratchet.stale_days) {
+        diagnostics.emit(
+            Code::BaselineStale,
+            format!("baseline is {} days old", baseline.age_days()),
+        );
+    }
+}
+
+/// Warn when quench itself ran slower than the baseline allows.
+///
+/// Only a warning: a slower tool is no reason to fail the project's build.
+pub(super) fn warn_self_time(
+    self_time: &SelfTimeCurrent,
+    baseline: &Baseline,
+    config: &config::Config,
+    diagnostics: &Diagnostics,
+) {
+    if let Some(comparison) =
+        ratchet::compare_self_time(self_time, &baseline.metrics, &config.ratchet)
+        && !comparison.passed
+    {
+        diagnostics.emit(
+            Code::SelfTimeRegressed,
+            format!(
+                "{}: {} (max: {} from baseline {})",
+                comparison.name,
+                comparison.format_value(comparison.current),
+                comparison.format_value(comparison.threshold),
+                comparison.format_value(comparison.baseline),
+            ),
+        );
+    }
+}
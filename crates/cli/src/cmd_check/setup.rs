@@ -0,0 +1,436 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Setup helpers for the check command: flags, lock, configuration,
+//! discovery, and what the run covers.
+
+use std::sync::Arc;
+
+use quench::cache::{self, CACHE_FILE_NAME, FileCache};
+use quench::checks::git::dirty;
+use quench::cli::{CheckArgs, OutputFormat};
+use quench::config::{self, AllowDirty};
+use quench::diagnostics::{Code, Diagnostics};
+use quench::discovery;
+use quench::error::ExitCode;
+use quench::file_reader::StagedContent;
+use quench::git::{
+    detect_base_branch, get_changed_files, get_dirty_files, get_staged_blobs, get_staged_files,
+    is_git_repo, resolve_base,
+};
+use quench::latest::get_head_commit;
+use quench::lock::{LockError, RunLock, describe_holder};
+use quench::new_code::{self, FileDates, LegacyFiles};
+use quench::patch::FixPatch;
+use quench::rules::RuleFilter;
+use quench::runner::RunnerConfig;
+use quench::tools::{self, ToolMismatches};
+use quench::verbose::VerboseLogger;
+use quench::walker::{FileWalker, WalkerConfig};
+
+/// Check if debug files mode is enabled via QUENCH_DEBUG_FILES env var.
+fn debug_files() -> bool {
+    quench::env::quench_debug_files()
+}
+
+pub(super) fn validate_flags(args: &CheckArgs) -> Option<ExitCode> {
+    if matches!(args.output, OutputFormat::Svg) {
+        eprintln!("-o svg only works with quench report");
+        eprintln!("  Use: quench report -o badge.svg");
+        return Some(ExitCode::ConfigError);
+    }
+    if args.dry_run && !args.fix {
+        eprintln!("--dry-run only works with --fix");
+        eprintln!(
+            "  The --dry-run flag lets you preview what --fix would change without applying changes."
+        );
+        eprintln!("  Use: quench check --fix --dry-run");
+        return Some(ExitCode::ConfigError);
+    }
+    if args.emit_patch.is_some() && !args.fix {
+        eprintln!("--emit-patch only works with --fix");
+        eprintln!("  Use: quench check --fix --emit-patch fixes.patch");
+        return Some(ExitCode::ConfigError);
+    }
+    if args.emit_patch.is_some() && args.dry_run {
+        eprintln!("--emit-patch and --dry-run cannot be used together");
+        return Some(ExitCode::ConfigError);
+    }
+    if args.check_determinism && args.fix {
+        eprintln!("--check-determinism and --fix cannot be used together");
+        return Some(ExitCode::ConfigError);
+    }
+    if args.staged && args.base.is_some() {
+        eprintln!("--staged and --base cannot be used together");
+        return Some(ExitCode::ConfigError);
+    }
+    None
+}
+
+/// Take `.quench/lock` so concurrent runs don't interleave cache and
+/// baseline writes. Runs that write neither (`--no-cache` without `--fix`)
+/// skip it, and runs go unlocked if the lock file can't be written.
+pub(super) fn take_lock(
+    args: &CheckArgs,
+    root: &std::path::Path,
+) -> Result<Option<RunLock>, ExitCode> {
+    if args.no_lock || (args.no_cache && !args.fix) {
+        return Ok(None);
+    }
+    let result = if args.wait {
+        RunLock::wait(root, |pid| {
+            eprintln!("quench: waiting for {} to finish", describe_holder(pid));
+        })
+    } else {
+        RunLock::acquire(root)
+    };
+    match result {
+        Ok(lock) => Ok(Some(lock)),
+        Err(LockError::Held { .. }) if quench::interrupt::requested() => Err(ExitCode::Interrupted),
+        Err(LockError::Held { pid, path }) => {
+            let relative = path.strip_prefix(root).unwrap_or(&path);
+            eprintln!(
+                "quench: {} holds {}",
+                describe_holder(pid),
+                relative.display()
+            );
+            eprintln!("  Use --wait to wait for it, or --no-lock to run anyway.");
+            Err(ExitCode::InternalError)
+        }
+        Err(e @ LockError::Io { .. }) => {
+            tracing::warn!("running without a lock: {}", e);
+            Ok(None)
+        }
+    }
+}
+
+pub(super) fn setup_verbose(args: &CheckArgs) -> VerboseLogger {
+    let verbose_enabled = args.ci || args.verbose || quench::env::quench_debug();
+    VerboseLogger::new(verbose_enabled)
+}
+
+pub(super) fn load_config(
+    root: &std::path::Path,
+) -> anyhow::Result<(config::Config, Option<std::path::PathBuf>)> {
+    let config_path = discovery::find_config(root);
+    let config = match &config_path {
+        Some(path) => {
+            tracing::debug!("loading config from {}", path.display());
+            config::load_with_warnings(path)?
+        }
+        None => {
+            tracing::debug!("no config found, using defaults");
+            config::Config::default()
+        }
+    };
+    tracing::trace!("check command starting");
+    Ok((config, config_path))
+}
+
+/// Run file discovery. Returns None for files if debug_files mode handled output.
+pub(super) fn run_discovery(
+    root: &std::path::Path,
+    walker_config: WalkerConfig,
+    verbose: &VerboseLogger,
+) -> anyhow::Result<(
+    Option<Vec<quench::walker::WalkedFile>>,
+    quench::walker::WalkStats,
+)> {
+    let walker = FileWalker::new(walker_config);
+    let (rx, handle) = walker.walk(root);
+
+    if debug_files() {
+        for file in rx {
+            let display_path = file.path.strip_prefix(root).unwrap_or(&file.path);
+            println!("{}", display_path.display());
+        }
+        let stats = handle.join();
+        if verbose.is_enabled() {
+            eprintln!(
+                "Scanned {} files, {} errors, {} symlink loops",
+                stats.files_found, stats.errors, stats.symlink_loops
+            );
+        }
+        return Ok((None, stats));
+    }
+
+    let mut files: Vec<_> = rx.iter().collect();
+    let stats = handle.join();
+    // Walker threads deliver files in arbitrary order; fix it for stable output.
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok((Some(files), stats))
+}
+
+pub(super) fn resolve_base_branch(args: &CheckArgs, root: &std::path::Path) -> Option<String> {
+    if let Some(ref base) = args.base {
+        Some(base.clone())
+    } else if args.ci {
+        detect_base_branch(root)
+    } else {
+        None
+    }
+}
+
+/// Commit that changes are compared against, found from the base ref with
+/// `--base-strategy` or `[git] base_strategy`.
+///
+/// Falls back to the ref itself when the strategy finds no commit (e.g., a
+/// shallow clone missing the common ancestor).
+pub(super) fn resolve_base_commit(
+    args: &CheckArgs,
+    config: &config::Config,
+    root: &std::path::Path,
+    base_ref: &Option<String>,
+    verbose: &VerboseLogger,
+) -> Option<String> {
+    let base = base_ref.as_deref()?;
+    let strategy = args
+        .base_strategy
+        .map_or(config.git.base_strategy, Into::into);
+    match resolve_base(root, base, strategy) {
+        Ok(commit) => {
+            if verbose.is_enabled() {
+                verbose.log(&format!(
+                    "Base commit: {} ({} of {})",
+                    &commit[..7],
+                    strategy.as_str(),
+                    base
+                ));
+            }
+            Some(commit)
+        }
+        Err(e) => {
+            if verbose.is_enabled() {
+                verbose.log(&format!("Base commit: {} ({:#})", base, e));
+            }
+            Some(base.to_string())
+        }
+    }
+}
+
+pub(super) fn resolve_changed_files(
+    args: &CheckArgs,
+    root: &std::path::Path,
+    base_branch: &Option<String>,
+    verbose: &VerboseLogger,
+    diagnostics: &Diagnostics,
+) -> Option<Vec<std::path::PathBuf>> {
+    if args.staged {
+        match get_staged_files(root) {
+            Ok(files) => {
+                if verbose.is_enabled() {
+                    verbose.log(&format!("Checking staged files ({} files)", files.len()));
+                }
+                Some(files)
+            }
+            Err(e) => {
+                diagnostics.emit(
+                    Code::StagedFilesUnavailable,
+                    format!("could not get staged files: {}", e),
+                );
+                None
+            }
+        }
+    } else if let Some(base) = base_branch {
+        match get_changed_files(root, base) {
+            Ok(files) => {
+                if verbose.is_enabled() {
+                    verbose.log(&format!(
+                        "Comparing against base: {} ({} files changed)",
+                        base,
+                        files.len()
+                    ));
+                }
+                Some(files)
+            }
+            Err(e) => {
+                if args.base.is_some() {
+                    diagnostics.emit(
+                        Code::ChangedFilesUnavailable,
+                        format!("could not get changed files: {}", e),
+                    );
+                }
+                None
+            }
+        }
+    } else {
+        None
+    }
+}
+
+/// Index content of partially staged files, which `--staged` checks in
+/// place of the working tree.
+pub(super) fn resolve_staged_content(
+    args: &CheckArgs,
+    root: &std::path::Path,
+    changed_files: &Option<Vec<std::path::PathBuf>>,
+    diagnostics: &Diagnostics,
+) -> Option<Arc<StagedContent>> {
+    let files = changed_files.as_deref().filter(|_| args.staged)?;
+    match get_staged_blobs(root, files) {
+        Ok(blobs) => Some(Arc::new(StagedContent::new(blobs))),
+        Err(e) => {
+            diagnostics.emit(
+                Code::StagedFilesUnavailable,
+                format!("could not read staged content: {}", e),
+            );
+            None
+        }
+    }
+}
+
+pub(super) fn runner_config(
+    args: &CheckArgs,
+    changed_files: Option<Vec<std::path::PathBuf>>,
+    staged_content: Option<Arc<StagedContent>>,
+    base_branch: &Option<String>,
+    sample_seed: &Option<String>,
+    patch: Option<Arc<FixPatch>>,
+    verbose: &VerboseLogger,
+) -> RunnerConfig {
+    RunnerConfig {
+        limit: effective_limit(args),
+        changed_files,
+        fix: args.fix,
+        run_fixers: args.fix && args.run_fixers,
+        dry_run: args.dry_run,
+        patch,
+        ci_mode: args.ci,
+        base_branch: base_branch.clone(),
+        staged: args.staged,
+        staged_content,
+        verbose: verbose.is_enabled(),
+        sample_seed: sample_seed.clone(),
+        jobs: args.jobs,
+    }
+}
+
+/// Seed for checks with `sample`. Only full fast-mode runs sample; CI and
+/// changed-file runs check every file.
+pub(super) fn sample_seed(
+    args: &CheckArgs,
+    root: &std::path::Path,
+    changed_files: &Option<Vec<std::path::PathBuf>>,
+) -> Option<String> {
+    if args.ci || changed_files.is_some() {
+        return None;
+    }
+    Some(get_head_commit(root).unwrap_or_default())
+}
+
+pub(super) fn effective_limit(args: &CheckArgs) -> Option<usize> {
+    if args.no_limit || args.ci || args.check_determinism {
+        None
+    } else {
+        Some(args.limit)
+    }
+}
+
+/// Violation limit for the checks themselves. With a rule filter they run
+/// unlimited, since violations the filter drops would otherwise use up the
+/// limit before the ones it keeps; output still stops at the limit.
+pub(super) fn run_limit(args: &CheckArgs, rules: &RuleFilter) -> Option<usize> {
+    effective_limit(args).filter(|_| rules.is_empty())
+}
+
+pub(super) fn setup_cache(
+    args: &CheckArgs,
+    root: &std::path::Path,
+    config: &config::Config,
+) -> anyhow::Result<Option<Arc<FileCache>>> {
+    if args.no_cache || args.check_determinism {
+        return Ok(None);
+    }
+    let cache_path = root.join(".quench").join(CACHE_FILE_NAME);
+    let config_hashes = cache::hash_config(config);
+    match FileCache::from_persistent(&cache_path, config_hashes.clone()) {
+        Ok(cache) => {
+            tracing::debug!("loaded cache from {}", cache_path.display());
+            Ok(Some(Arc::new(cache)))
+        }
+        Err(e) => {
+            tracing::debug!("cache not loaded ({}), starting fresh", e);
+            Ok(Some(Arc::new(FileCache::new(config_hashes))))
+        }
+    }
+}
+
+/// Uncommitted files, when `[git] allow_dirty` gates this run.
+///
+/// Only CI runs and baseline updates (`--fix`) are gated.
+pub(super) fn dirty_files(
+    args: &CheckArgs,
+    config: &config::Config,
+    root: &std::path::Path,
+) -> Option<Vec<std::path::PathBuf>> {
+    if config.git.allow_dirty == AllowDirty::Allow || !(args.ci || args.fix) || !is_git_repo(root) {
+        return None;
+    }
+    match get_dirty_files(root) {
+        Ok(files) => {
+            // git reports paths under the canonical working tree
+            let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+            let files = dirty::relevant_files(&root, &files, config.git.baseline_path());
+            (!files.is_empty()).then_some(files)
+        }
+        Err(e) => {
+            tracing::warn!("failed to read working tree status: {}", e);
+            None
+        }
+    }
+}
+
+/// Files outside the `[new_code]` window, whose violations only warn.
+pub(super) fn legacy_files(
+    config: &config::Config,
+    root: &std::path::Path,
+    verbose: &VerboseLogger,
+    diagnostics: &Diagnostics,
+) -> Option<LegacyFiles> {
+    let since = config.new_code.since?;
+    if !is_git_repo(root) {
+        diagnostics.emit(
+            Code::NewCodeNeedsGit,
+            "[new_code] needs a git repository; all files are checked",
+        );
+        return None;
+    }
+    let cutoff = FileDates::refresh(root, diagnostics).and_then(|dates| {
+        let cutoff = new_code::cutoff(since, root, &config.git, &dates)?;
+        Ok(cutoff.map(|cutoff| (dates, cutoff)))
+    });
+    match cutoff {
+        Ok(Some((dates, cutoff))) => Some(LegacyFiles::new(&dates, cutoff)),
+        Ok(None) => {
+            verbose.log("New code: no baseline yet, all files are checked");
+            None
+        }
+        Err(e) => {
+            diagnostics.emit(
+                Code::FileHistoryUnavailable,
+                format!("failed to read file history: {}", e),
+            );
+            None
+        }
+    }
+}
+
+/// Pinned `[tools]` that don't match the installed versions.
+pub(super) fn tool_mismatches(
+    config: &config::Config,
+    verbose: &VerboseLogger,
+) -> Option<ToolMismatches> {
+    if config.tools.versions.is_empty() {
+        return None;
+    }
+    let statuses = tools::verify(&config.tools);
+    for status in &statuses {
+        let found = status.found.as_deref().unwrap_or("not found");
+        verbose.log(&format!(
+            "Tools: {} {} (pinned {})",
+            status.tool.name, found, status.expected
+        ));
+    }
+    let mismatches = ToolMismatches::new(statuses, config.tools.on_mismatch);
+    (!mismatches.is_empty()).then_some(mismatches)
+}
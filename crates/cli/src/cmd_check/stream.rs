@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Streaming check output.
+//!
+//! Results print as checks complete instead of after the whole run, so
+//! early failures show up on long CI runs. Output keeps canonical check
+//! order: a result waits until every check before it has printed.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Stdout;
+use std::path::Path;
+use std::sync::Mutex;
+
+use quench::advice::AdviceCatalog;
+use quench::check::{CheckResult, Violation};
+use quench::checks::{self, git::dirty};
+use quench::config::AllowDirty;
+use quench::new_code::LegacyFiles;
+use quench::output::ndjson::NdjsonFormatter;
+use quench::output::text::TextFormatter;
+use quench::quarantine::Quarantine;
+use quench::rules::RuleFilter;
+use quench::runner::correlate_violations;
+use quench::tools::ToolMismatches;
+
+/// Adjustments made to check results after they run, shared by streamed
+/// and final output so both report the same violations.
+pub(super) struct Adjustments<'a> {
+    pub root: &'a Path,
+    /// The dirty working tree violation and how strictly to apply it.
+    pub dirty: Option<(Violation, AllowDirty)>,
+    pub tools: Option<&'a ToolMismatches>,
+    pub rules: &'a RuleFilter,
+    pub advice: &'a AdviceCatalog,
+    pub quarantine: &'a Quarantine<'a>,
+    pub legacy: Option<&'a LegacyFiles>,
+}
+
+impl Adjustments<'_> {
+    /// Adjust a full run's results.
+    pub fn apply(&self, results: &mut Vec<CheckResult>) {
+        if let Some((violation, level)) = &self.dirty {
+            dirty::apply(results, violation.clone(), *level);
+        }
+        self.apply_each(results);
+    }
+
+    /// The adjustments that look at one result at a time.
+    fn apply_each(&self, results: &mut [CheckResult]) {
+        if let Some(tools) = self.tools {
+            tools.apply(results);
+        }
+        self.rules.apply(results);
+        self.advice.apply(results);
+        self.quarantine.apply(self.root, results);
+        if let Some(legacy) = self.legacy {
+            legacy.apply(self.root, results);
+        }
+    }
+}
+
+/// Where streamed results are written.
+pub(super) enum Sink {
+    Text(TextFormatter),
+    Ndjson(NdjsonFormatter<Stdout>),
+}
+
+/// Prints results in check order as they arrive from worker threads.
+pub(super) struct Stream<'a> {
+    adjust: &'a Adjustments<'a>,
+    state: Mutex<State>,
+}
+
+struct State {
+    /// Check names in output order.
+    order: Vec<String>,
+    /// Index into `order` of the next check to print.
+    next: usize,
+    /// Results waiting on an earlier check.
+    ready: HashMap<String, CheckResult>,
+    /// Printed results, kept to group violations across checks.
+    printed: Vec<CheckResult>,
+    /// No git result will arrive, so the dirty violation gets its own.
+    synthesize_git: bool,
+    /// Whether `run_started` has been written.
+    announced: bool,
+    /// Checks with a `check_started` event written.
+    started: HashSet<String>,
+    sink: Sink,
+    error: Option<std::io::Error>,
+}
+
+impl<'a> Stream<'a> {
+    /// A stream expecting results for `names`, in any order.
+    pub fn new(sink: Sink, adjust: &'a Adjustments<'a>, names: Vec<String>) -> Self {
+        let mut order = names;
+        let synthesize_git = adjust.dirty.is_some() && !order.iter().any(|n| n == "git");
+        if synthesize_git {
+            order.push("git".to_string());
+        }
+        order.sort_by_key(|name| checks::order_key(name));
+        Self {
+            adjust,
+            state: Mutex::new(State {
+                order,
+                next: 0,
+                ready: HashMap::new(),
+                printed: Vec::new(),
+                synthesize_git,
+                announced: false,
+                started: HashSet::new(),
+                sink,
+                error: None,
+            }),
+        }
+    }
+
+    /// Note that a check began running. Only the event stream reports this,
+    /// as soon as it happens.
+    pub fn started(&self, name: &str) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.start(name);
+    }
+
+    /// Accept a result, printing it and any results it was holding back.
+    pub fn push(&self, result: &CheckResult) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.ready.insert(result.name.clone(), result.clone());
+        state.release(self.adjust);
+    }
+
+    /// Print anything left and hand back the sink for the summary.
+    pub fn finish(self) -> anyhow::Result<Sink> {
+        let mut state = self.state.into_inner().unwrap_or_else(|e| e.into_inner());
+        // Results from checks that weren't announced go last, in check order
+        let mut extra: Vec<String> = state.ready.keys().cloned().collect();
+        extra.sort_by_key(|name| checks::order_key(name));
+        state.order.extend(extra);
+        state.release(self.adjust);
+        state.announce();
+        match state.error {
+            Some(e) => Err(e.into()),
+            None => Ok(state.sink),
+        }
+    }
+}
+
+impl State {
+    /// Write `run_started` before the first event.
+    fn announce(&mut self) {
+        if self.announced {
+            return;
+        }
+        self.announced = true;
+        let timestamp = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+        if let (Sink::Ndjson(formatter), None) = (&mut self.sink, &self.error) {
+            self.error = formatter.write_run_started(&timestamp, &self.order).err();
+        }
+    }
+
+    /// Write `check_started` once per check.
+    fn start(&mut self, name: &str) {
+        self.announce();
+        if self.started.insert(name.to_string())
+            && let (Sink::Ndjson(formatter), None) = (&mut self.sink, &self.error)
+        {
+            self.error = formatter.write_check_started(name).err();
+        }
+    }
+
+    /// Print results in order until one is still running.
+    fn release(&mut self, adjust: &Adjustments) {
+        while let Some(name) = self.order.get(self.next).cloned() {
+            let mut results = match self.ready.remove(&name) {
+                Some(result) => vec![result],
+                None if name == "git" && self.synthesize_git => Vec::new(),
+                None => break,
+            };
+            self.next += 1;
+            if name == "git"
+                && let Some((violation, level)) = &adjust.dirty
+            {
+                dirty::apply(&mut results, violation.clone(), *level);
+            }
+            adjust.apply_each(&mut results);
+
+            for result in results {
+                // Checks that never ran, or ran before streaming, start here
+                self.start(&result.name);
+                self.printed.push(result);
+                correlate_violations(&mut self.printed);
+                let Some(result) = self.printed.last() else {
+                    continue;
+                };
+                if self.error.is_none() {
+                    self.error = match &mut self.sink {
+                        Sink::Text(formatter) => formatter.write_check(result).map(|_| ()),
+                        Sink::Ndjson(formatter) => formatter.write_check(result),
+                    }
+                    .err();
+                }
+            }
+        }
+    }
+}
@@ -10,8 +10,10 @@ use quench::adapter::{
     resolve_project_patterns,
 };
 use quench::cache::FileCache;
+use quench::check::Check;
 use quench::cli::CheckArgs;
 use quench::config;
+use quench::file_size::human_size;
 use quench::git::get_commits_since;
 use quench::verbose::VerboseLogger;
 
@@ -108,6 +110,33 @@ pub(super) fn commits(
     }
 }
 
+pub(super) fn sampling(
+    verbose: &VerboseLogger,
+    config: &config::Config,
+    checks: &[Arc<dyn Check>],
+    seed: &Option<String>,
+) {
+    let Some(seed) = seed else {
+        return;
+    };
+    let sampled: Vec<String> = checks
+        .iter()
+        .filter_map(|c| {
+            let sample = config.check.sample(c.name())?;
+            Some(format!("{} {}%", c.name(), sample.percent()))
+        })
+        .collect();
+    if sampled.is_empty() {
+        return;
+    }
+    let seed = if seed.is_empty() { "no commit" } else { seed };
+    verbose.log(&format!(
+        "Sampling: {} (seed {}; --ci checks every file)",
+        sampled.join(", "),
+        seed
+    ));
+}
+
 pub(super) fn cache(verbose: &VerboseLogger, cache: &Option<Arc<FileCache>>) {
     if !verbose.is_enabled() {
         return;
@@ -121,6 +150,45 @@ pub(super) fn cache(verbose: &VerboseLogger, cache: &Option<Arc<FileCache>>) {
     }
 }
 
+pub(super) fn scheduling(
+    verbose: &VerboseLogger,
+    root: &std::path::Path,
+    files: &[quench::walker::WalkedFile],
+    checks: &[Arc<dyn Check>],
+    cache: &Option<Arc<FileCache>>,
+) {
+    if !verbose.is_enabled() {
+        return;
+    }
+    verbose.section("Scheduling");
+    let stats = quench::schedule::ScheduleStats::new(files);
+    verbose.log(&format!(
+        "Files: {} ({} total), largest first",
+        stats.files,
+        human_size(stats.total_bytes, true)
+    ));
+    if let Some((path, size)) = &stats.largest {
+        verbose.log(&format!(
+            "Largest: {} ({}); top 1% of files hold {:.0}% of bytes",
+            path.strip_prefix(root).unwrap_or(path).display(),
+            human_size(*size, true),
+            stats.top_share * 100.0
+        ));
+    }
+
+    let duration = |name: &str| cache.as_ref().and_then(|c| c.check_duration(name));
+    let mut ordered = checks.to_vec();
+    quench::schedule::longest_first(&mut ordered, duration);
+    let order: Vec<String> = ordered
+        .iter()
+        .map(|c| match duration(c.name()) {
+            Some(ms) => format!("{} ({}ms)", c.name(), ms),
+            None => c.name().to_string(),
+        })
+        .collect();
+    verbose.log(&format!("Check order: {}", order.join(", ")));
+}
+
 pub(super) fn summary(verbose: &VerboseLogger, total_ms: u64) {
     if verbose.is_enabled() {
         verbose.section("Summary");
@@ -138,3 +206,37 @@ pub(super) fn patterns(verbose: &VerboseLogger, label: &str, patterns: &[String]
         verbose.log(&format!("{label}: {val}"));
     }
 }
+
+pub(super) fn sparse(verbose: &VerboseLogger, state: &quench::sparse::SparseState) {
+    if !verbose.is_enabled() {
+        return;
+    }
+    verbose.section("Checkout");
+    verbose.log(&format!(
+        "Detected {}: skipping checks that need absent files",
+        state.label()
+    ));
+}
+
+pub(super) fn budget(
+    verbose: &VerboseLogger,
+    budget: std::time::Duration,
+    plan: &quench::budget::BudgetPlan,
+) {
+    if !verbose.is_enabled() {
+        return;
+    }
+    verbose.section("Budget");
+    verbose.log(&format!(
+        "Budget: {}ms ({} checks run, {} skipped)",
+        budget.as_millis(),
+        plan.run.len(),
+        plan.skipped.len()
+    ));
+    for skipped in &plan.skipped {
+        verbose.log(&format!(
+            "  skipped {} (last run {}ms)",
+            skipped.name, skipped.estimate_ms
+        ));
+    }
+}
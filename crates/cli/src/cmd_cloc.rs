@@ -7,7 +7,7 @@
 //! and source vs test classification.
 
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use quench::adapter::project::apply_language_defaults;
 use quench::adapter::{AdapterRegistry, FileKind, RustAdapter, patterns::LanguageDefaults};
@@ -22,21 +22,57 @@ use quench::walker::{FileWalker, WalkerConfig};
 
 /// Accumulated statistics for a (language, kind) bucket.
 #[derive(Default)]
-struct LangStats {
-    files: usize,
-    blank: usize,
-    comment: usize,
-    code: usize,
+pub(crate) struct LangStats {
+    pub(crate) files: usize,
+    pub(crate) blank: usize,
+    pub(crate) comment: usize,
+    pub(crate) code: usize,
+}
+
+/// Line counts for a project, shared by `quench cloc` and `quench loc`.
+pub(crate) struct Counts {
+    /// (language_name, FileKind) -> LangStats
+    pub(crate) stats: HashMap<(String, FileKind), LangStats>,
+    /// (package_path, language_name, FileKind) -> LangStats
+    pub(crate) package_lang_stats: HashMap<(String, String, FileKind), LangStats>,
+    /// (package_path or "", language_name) -> file count. Files split by
+    /// cfg_test appear in both the source and test stats but count once here.
+    pub(crate) files: HashMap<(String, String), usize>,
+    /// Package path -> display name
+    pub(crate) package_names: HashMap<String, String>,
 }
 
 /// Run the `quench cloc` command.
 pub fn run(args: &ClocArgs) -> anyhow::Result<ExitCode> {
+    let Counts {
+        stats,
+        package_lang_stats,
+        package_names,
+        ..
+    } = count(&args.paths, args.max_depth)?;
+
+    let use_color = matches!(
+        color::resolve_color(),
+        termcolor::ColorChoice::Always | termcolor::ColorChoice::Auto
+    );
+
+    match args.output {
+        OutputFormat::Json => print_json(&stats, &package_lang_stats, &package_names)?,
+        _ => print_text(&stats, &package_lang_stats, &package_names, use_color),
+    }
+
+    Ok(ExitCode::Success)
+}
+
+/// Walk the project (or the first of `paths`) and count lines by language,
+/// source vs test, and package.
+pub(crate) fn count(paths: &[PathBuf], max_depth: usize) -> anyhow::Result<Counts> {
     let cwd = std::env::current_dir()?;
 
-    let root = if args.paths.is_empty() {
+    let root = if paths.is_empty() {
         cwd.clone()
     } else {
-        let path = &args.paths[0];
+        let path = &paths[0];
         if path.is_absolute() {
             path.clone()
         } else {
@@ -62,9 +98,9 @@ pub fn run(args: &ClocArgs) -> anyhow::Result<ExitCode> {
 
     // Set up walker
     let walker_config = WalkerConfig {
-        max_depth: Some(args.max_depth),
+        max_depth: Some(max_depth),
         exclude_patterns,
-        ..Default::default()
+        ..WalkerConfig::for_project(&config.project)
     };
     let walker = FileWalker::new(walker_config);
     let (rx, handle) = walker.walk(&root);
@@ -108,6 +144,7 @@ pub fn run(args: &ClocArgs) -> anyhow::Result<ExitCode> {
     let mut stats: HashMap<(String, FileKind), LangStats> = HashMap::new();
     // Per-package stats: (package_path, language_name, FileKind) -> LangStats
     let mut package_lang_stats: HashMap<(String, String, FileKind), LangStats> = HashMap::new();
+    let mut files: HashMap<(String, String), usize> = HashMap::new();
     let packages = &config.project.packages;
 
     for file in rx {
@@ -139,6 +176,11 @@ pub fn run(args: &ClocArgs) -> anyhow::Result<ExitCode> {
         let lang = cloc::language_name(&ext).to_string();
         let metrics = cloc::count_file_metrics(text, &ext);
 
+        *files.entry((String::new(), lang.clone())).or_default() += 1;
+        if let Some(pkg) = file_package(relative_path, packages) {
+            *files.entry((pkg, lang.clone())).or_default() += 1;
+        }
+
         // Handle Rust cfg_test splitting
         if let Some(adapter) = rust_adapter.as_ref()
             && ext == "rs"
@@ -220,18 +262,12 @@ pub fn run(args: &ClocArgs) -> anyhow::Result<ExitCode> {
     // Wait for walker to finish
     let _walk_stats = handle.join();
 
-    let package_names = &config.project.package_names;
-    let use_color = matches!(
-        color::resolve_color(),
-        termcolor::ColorChoice::Always | termcolor::ColorChoice::Auto
-    );
-
-    match args.output {
-        OutputFormat::Json => print_json(&stats, &package_lang_stats, package_names)?,
-        _ => print_text(&stats, &package_lang_stats, package_names, use_color),
-    }
-
-    Ok(ExitCode::Success)
+    Ok(Counts {
+        stats,
+        package_lang_stats,
+        files,
+        package_names: config.project.package_names,
+    })
 }
 
 /// Print the cloc report in text table format.
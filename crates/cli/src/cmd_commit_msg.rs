@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench check-commit-msg` command implementation.
+//!
+//! Validates the message file git passes to the `commit-msg` hook, so a bad
+//! message is rejected before the commit is created.
+
+use std::path::{Path, PathBuf};
+
+use quench::adapter::project::apply_language_defaults;
+use quench::check::{Field, Violation};
+use quench::checks::git::message::{check_message, check_message_scope};
+use quench::checks::git::scope::{ScopeMap, relative_to};
+use quench::cli::CheckCommitMsgArgs;
+use quench::config::{self, Config, InferScope};
+use quench::discovery;
+use quench::error::ExitCode;
+use quench::git::get_staged_files;
+
+/// Run the `quench check-commit-msg` command.
+pub fn run(args: &CheckCommitMsgArgs) -> anyhow::Result<ExitCode> {
+    let cwd = std::env::current_dir()?;
+    let (root, mut config) = match discovery::find_config(&cwd) {
+        Some(path) => {
+            let root = path.parent().map_or_else(|| cwd.clone(), Path::to_path_buf);
+            (root, config::load_with_warnings(&path)?)
+        }
+        None => (cwd.clone(), Config::default()),
+    };
+    let commit_config = config.git.commit.clone();
+    if commit_config.check.as_deref() == Some("off") || commit_config.effective_format() == "none" {
+        return Ok(ExitCode::Success);
+    }
+
+    let raw = std::fs::read_to_string(&args.file)
+        .map_err(|e| anyhow::anyhow!("failed to read {}: {}", args.file.display(), e))?;
+    let mut violations = check_message(&raw, &commit_config);
+    let mut warnings = Vec::new();
+    if commit_config.infer_scope != InferScope::Off
+        && let Some(violation) = staged_scope_violation(&root, &mut config, &raw)
+    {
+        match commit_config.infer_scope {
+            InferScope::Enforce => violations.push(violation),
+            _ => warnings.push(violation),
+        }
+    }
+    for warning in &warnings {
+        eprintln!("quench: warning: {}", format_violation(&args.file, warning));
+    }
+    if violations.is_empty() {
+        return Ok(ExitCode::Success);
+    }
+
+    let warn_only = commit_config.check.as_deref() == Some("warn");
+    eprintln!(
+        "quench: commit message {} [git.commit] rules:",
+        if warn_only {
+            "does not follow"
+        } else {
+            "rejected by"
+        }
+    );
+    for violation in &violations {
+        eprintln!("{}", format_violation(&args.file, violation));
+    }
+
+    if warn_only {
+        Ok(ExitCode::Success)
+    } else {
+        eprintln!(
+            "Fix the message and commit again (it is saved in {}).",
+            args.file.display()
+        );
+        Ok(ExitCode::CheckFailed)
+    }
+}
+
+/// Scope violation for the message against the packages touched by staged files.
+fn staged_scope_violation(root: &Path, config: &mut Config, raw: &str) -> Option<Violation> {
+    apply_language_defaults(root, config);
+    let commit_config = &config.git.commit;
+    let scope_map = ScopeMap::new(&commit_config.scope_paths, &config.project.packages);
+    if scope_map.is_empty() {
+        return None;
+    }
+    let staged = get_staged_files(root).ok()?;
+    let paths = relative_to(root, &staged);
+    let touched = scope_map.infer(paths.iter().map(PathBuf::as_path));
+    check_message_scope(raw, &touched)
+}
+
+/// `file:line: type: "subject"` followed by indented advice, so editors and
+/// terminals can jump to the line.
+fn format_violation(file: &std::path::Path, violation: &Violation) -> String {
+    format!(
+        "{}:{}: {}: \"{}\"\n  {}",
+        file.display(),
+        violation.line.unwrap_or(1),
+        violation.violation_type,
+        violation.text(Field::Message).unwrap_or_default(),
+        violation.advice
+    )
+}
@@ -8,8 +8,9 @@ use std::io::Write;
 use anyhow::{Context, Result, bail};
 use clap::CommandFactory;
 
-use quench::cli::{Cli, ConfigArgs};
+use quench::cli::{Cli, ConfigArgs, OutputFormat};
 use quench::color;
+use quench::config::reference;
 use quench::error::ExitCode;
 use quench::help::format_help;
 
@@ -27,6 +28,14 @@ const TEMPLATES: &[(&str, &str)] = &[
         "cloc",
         include_str!("../../../docs/specs/templates/guide.cloc.md"),
     ),
+    (
+        "deadcode",
+        include_str!("../../../docs/specs/templates/guide.deadcode.md"),
+    ),
+    (
+        "deps",
+        include_str!("../../../docs/specs/templates/guide.deps.md"),
+    ),
     (
         "docs",
         include_str!("../../../docs/specs/templates/guide.docs.md"),
@@ -35,6 +44,10 @@ const TEMPLATES: &[(&str, &str)] = &[
         "escapes",
         include_str!("../../../docs/specs/templates/guide.escapes.md"),
     ),
+    (
+        "format",
+        include_str!("../../../docs/specs/templates/guide.format.md"),
+    ),
     (
         "git",
         include_str!("../../../docs/specs/templates/guide.git.md"),
@@ -67,6 +80,10 @@ const TEMPLATES: &[(&str, &str)] = &[
         "license",
         include_str!("../../../docs/specs/templates/guide.license.md"),
     ),
+    (
+        "lint",
+        include_str!("../../../docs/specs/templates/guide.lint.md"),
+    ),
     (
         "python",
         include_str!("../../../docs/specs/templates/guide.python.md"),
@@ -103,12 +120,41 @@ const TEMPLATES: &[(&str, &str)] = &[
         "bash",
         include_str!("../../../docs/specs/templates/guide.shell.md"),
     ),
+    (
+        "snapshots",
+        include_str!("../../../docs/specs/templates/guide.snapshots.md"),
+    ),
     (
         "tests",
         include_str!("../../../docs/specs/templates/guide.tests.md"),
     ),
+    (
+        "whitespace",
+        include_str!("../../../docs/specs/templates/guide.whitespace.md"),
+    ),
 ];
 
+/// Print the reference for every config key, generated from the config structs.
+fn reference(feature: &str, format: OutputFormat) -> Result<ExitCode> {
+    if feature != "docs" {
+        bail!("--output is only supported with `quench config docs`");
+    }
+    let content = match format {
+        OutputFormat::Markdown => reference::markdown(),
+        OutputFormat::Json => {
+            let mut json = serde_json::to_string_pretty(&reference::schema())?;
+            json.push('\n');
+            json
+        }
+        _ => bail!("`quench config docs` supports --output markdown (md) or json"),
+    };
+    std::io::stdout()
+        .lock()
+        .write_all(content.as_bytes())
+        .context("Failed to write config reference to stdout")?;
+    Ok(ExitCode::Success)
+}
+
 pub fn run(args: &ConfigArgs) -> Result<ExitCode> {
     let feature = match &args.feature {
         Some(f) => f.to_lowercase(),
@@ -122,7 +168,9 @@ pub fn run(args: &ConfigArgs) -> Result<ExitCode> {
             println!("{}", color::header("Available features:"));
             println!(
                 "  Checks:    {}",
-                color::literal("agents, build, cloc, docs, escapes, git, license, tests")
+                color::literal(
+                    "agents, build, cloc, deadcode, deps, docs, escapes, format, git, license, lint, snapshots, tests, whitespace"
+                )
             );
             println!(
                 "  Languages: {}",
@@ -132,10 +180,19 @@ pub fn run(args: &ConfigArgs) -> Result<ExitCode> {
                 "             {}",
                 color::literal("rust (rs), shell (sh/bash)")
             );
+            println!();
+            println!(
+                "Full reference for every key: {}",
+                color::literal("quench config docs --output md")
+            );
             return Ok(ExitCode::Success);
         }
     };
 
+    if let Some(format) = args.output {
+        return reference(&feature, format);
+    }
+
     // Find the template
     let template = TEMPLATES
         .iter()
@@ -156,7 +213,7 @@ pub fn run(args: &ConfigArgs) -> Result<ExitCode> {
             bail!(
                 "Unknown feature '{}'\n\n\
                 Available features:\n\
-                  Checks:  agents, build, cloc, docs, escapes, git, license, tests\n\
+                  Checks:  agents, build, cloc, deadcode, deps, docs, escapes, format, git, license, lint, snapshots, tests, whitespace\n\
                   Languages: golang (go), javascript (js/ts/typescript), python (py), ruby (rb), rust (rs), shell (sh/bash)",
                 feature
             );
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench doctor` command implementation.
+
+use quench::cli::{DoctorArgs, OutputFormat};
+use quench::config;
+use quench::discovery;
+use quench::error::ExitCode;
+use quench::tools::{self, TOOLS};
+
+/// Run the `quench doctor` command.
+///
+/// Exits with failure if any `[tools]` pin doesn't match.
+pub fn run(args: &DoctorArgs) -> anyhow::Result<ExitCode> {
+    let root = std::env::current_dir()?;
+    let config = match discovery::find_config(&root) {
+        Some(path) => config::load_with_warnings(&path)?,
+        None => config::Config::default(),
+    };
+
+    let statuses = tools::verify(&config.tools);
+    let all_ok = statuses.iter().all(|s| s.ok());
+
+    match args.output {
+        OutputFormat::Json => {
+            let tools: Vec<_> = statuses
+                .iter()
+                .map(|s| {
+                    serde_json::json!({
+                        "name": s.tool.name,
+                        "expected": s.expected,
+                        "found": s.found,
+                        "ok": s.ok(),
+                        "install": (!s.ok()).then(|| s.tool.install_command(&s.expected)),
+                    })
+                })
+                .collect();
+            let report = serde_json::json!({ "passed": all_ok, "tools": tools });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        _ if statuses.is_empty() => print_unpinned(),
+        _ => {
+            for status in &statuses {
+                if status.ok() {
+                    println!(
+                        "ok    {} {} (pinned {})",
+                        status.tool.name,
+                        status.found.as_deref().unwrap_or_default(),
+                        status.expected
+                    );
+                } else {
+                    println!("FAIL  {}", status.reason());
+                    println!(
+                        "      install: {}",
+                        status.tool.install_command(&status.expected)
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(if all_ok {
+        ExitCode::Success
+    } else {
+        ExitCode::CheckFailed
+    })
+}
+
+/// Suggest a `[tools]` section pinning the installed versions.
+fn print_unpinned() {
+    let installed: Vec<(&str, String)> = TOOLS
+        .iter()
+        .filter_map(|tool| {
+            let version = tool.detect()?;
+            // Pin major.minor so patch releases don't count as drift
+            let pin: Vec<&str> = version.split('.').take(2).collect();
+            Some((tool.name, pin.join(".")))
+        })
+        .collect();
+
+    println!("No tool versions pinned.");
+    if installed.is_empty() {
+        return;
+    }
+    println!("To pin the installed versions, add to quench.toml:");
+    println!();
+    println!("[tools]");
+    for (name, version) in installed {
+        println!("{} = \"{}\"", name, version);
+    }
+}
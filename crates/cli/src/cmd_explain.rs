@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench explain` command implementation.
+
+use quench::cli::{ExplainArgs, OutputFormat};
+use quench::error::ExitCode;
+use quench::explain::{self, Explanation};
+
+/// Run the `quench explain` command.
+///
+/// Without a violation type, lists every type quench reports.
+pub fn run(args: &ExplainArgs) -> anyhow::Result<ExitCode> {
+    let explanations: Vec<&Explanation> = match &args.violation_type {
+        Some(query) => {
+            let found = explain::explain(query);
+            if found.is_empty() {
+                eprintln!("quench: unknown violation type: {}", query);
+                eprintln!("Run `quench explain` to list violation types.");
+                return Ok(ExitCode::ConfigError);
+            }
+            found
+        }
+        None => explain::explanations().collect(),
+    };
+
+    match args.output {
+        OutputFormat::Json => {
+            let items: Vec<_> = explanations
+                .iter()
+                .map(|e| {
+                    serde_json::json!({
+                        "violation_type": e.violation_type,
+                        "checks": e.checks,
+                        "rule_ids": e.rule_ids(),
+                        "description": e.description,
+                        "rationale": e.rationale,
+                        "config": e.config,
+                        "fixes": e.fixes,
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&items)?);
+        }
+        _ if args.violation_type.is_none() => {
+            for e in &explanations {
+                println!("{}  {}", e.rule_ids().join(", "), e.description);
+            }
+        }
+        _ => {
+            for (i, e) in explanations.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                print_explanation(e);
+            }
+        }
+    }
+
+    Ok(ExitCode::Success)
+}
+
+fn print_explanation(e: &Explanation) {
+    println!("{} ({})", e.violation_type, e.rule_ids().join(", "));
+    println!();
+    println!("{}", e.description);
+    println!();
+    println!("Why: {}", e.rationale);
+    if !e.config.is_empty() {
+        println!();
+        println!("Configuration:");
+        for key in e.config {
+            println!("  {}", key);
+        }
+    }
+    println!();
+    println!("Fixes:");
+    for fix in e.fixes {
+        println!("  - {}", fix);
+    }
+}
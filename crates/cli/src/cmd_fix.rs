@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench fix` command implementation.
+
+use quench::cli::{Cli, FixArgs};
+use quench::error::ExitCode;
+
+use crate::cmd_check;
+
+/// Run the `quench fix` command.
+///
+/// Runs `quench check --fix` with check fixers: every check's fixes are
+/// applied, or with `--dry-run` shown as a diff, and the baseline is updated.
+pub fn run(cli: &Cli, args: &FixArgs) -> anyhow::Result<ExitCode> {
+    let mut check = args.check.clone();
+    check.fix = true;
+    check.run_fixers = true;
+    cmd_check::run(cli, &check)
+}
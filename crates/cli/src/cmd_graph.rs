@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench graph` command implementation.
+
+use quench::adapter::project::apply_language_defaults;
+use quench::cli::{GraphArgs, GraphFormat};
+use quench::config;
+use quench::discovery;
+use quench::error::ExitCode;
+use quench::graph::Graph;
+use quench::latest::LatestMetrics;
+
+/// Run the `quench graph` command.
+pub fn run(args: &GraphArgs) -> anyhow::Result<ExitCode> {
+    let root = std::env::current_dir()?;
+
+    let mut config = match discovery::find_config(&root) {
+        Some(path) => config::load_with_warnings(&path)?,
+        None => config::Config::default(),
+    };
+    // Auto-detects workspace packages
+    apply_language_defaults(&root, &mut config);
+
+    let packages = if config.project.packages.is_empty() {
+        vec![".".to_string()]
+    } else {
+        config.project.packages.clone()
+    };
+    let mut graph = Graph::from_packages(&root, &packages, &config.project.package_names);
+    if let Some(latest) = LatestMetrics::load(&root.join(".quench/latest.json"))? {
+        graph.overlay(&latest.output);
+    }
+
+    match args.output {
+        GraphFormat::Dot => print!("{}", graph.to_dot()),
+        GraphFormat::Mermaid => print!("{}", graph.to_mermaid()),
+    }
+    Ok(ExitCode::Success)
+}
@@ -7,10 +7,15 @@ use std::path::Path;
 
 use anyhow::Result;
 
+use crate::ci_workflow::{CiPlan, CiProvider};
 use crate::cli::InitArgs;
 use crate::completions;
-use crate::error::ExitCode;
-use crate::init::{DetectedAgent, DetectedLanguage, detect_agents, detect_languages};
+use crate::diagnostics::{Code, Diagnostics};
+use crate::error::{Error, ExitCode};
+use crate::git::{detect_base_branch, is_git_repo};
+use crate::hooks::{self, Hook, HookAction};
+use crate::init::{DetectedLanguage, detect_agents, detect_languages};
+use crate::migrate::Imported;
 use crate::profiles::{
     ProfileRegistry, agents_section, default_template_base, default_template_suffix,
     golang_detected_section, javascript_detected_section, python_detected_section,
@@ -64,38 +69,136 @@ fn ensure_gitignored(root: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Header shared by every generated workflow file.
+const GENERATED_WORKFLOW_HEADER: &str = "# Generated by `quench init --ci";
+
+/// Language named by a `--with` profile, if it is a language profile.
+fn profile_language(profile: &str) -> Option<DetectedLanguage> {
+    match profile.to_lowercase().as_str() {
+        "rust" => Some(DetectedLanguage::Rust),
+        "golang" | "go" => Some(DetectedLanguage::Golang),
+        "javascript" | "js" | "typescript" | "ts" => Some(DetectedLanguage::JavaScript),
+        "shell" => Some(DetectedLanguage::Shell),
+        "ruby" | "rb" => Some(DetectedLanguage::Ruby),
+        "python" | "py" => Some(DetectedLanguage::Python),
+        _ => None,
+    }
+}
+
+/// Write the CI workflow for `provider`, returning what was created.
+///
+/// Returns None (after a warning) if the workflow file exists and `force`
+/// isn't set. GitLab pipelines the user wrote themselves are left alone:
+/// the workflow goes into an includable file instead.
+fn write_ci_workflow(
+    root: &Path,
+    provider: CiProvider,
+    languages: &[DetectedLanguage],
+    force: bool,
+) -> Result<Option<String>> {
+    let branch = detect_base_branch(root)
+        .map(|b| b.trim_start_matches("origin/").to_string())
+        .unwrap_or_else(|| "main".to_string());
+    let plan = CiPlan::detect(root, languages, &branch);
+
+    let main_path = provider.workflow_path();
+    let user_owned = std::fs::read_to_string(root.join(main_path))
+        .is_ok_and(|content| !content.starts_with(GENERATED_WORKFLOW_HEADER));
+    let path = match provider.include_path() {
+        Some(include) if user_owned => include,
+        _ => main_path,
+    };
+    let target = root.join(path);
+    if target.exists() && !force {
+        eprintln!(
+            "quench: warning: {} already exists, skipping CI workflow (use --force to overwrite)",
+            path
+        );
+        return Ok(None);
+    }
+
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&target, plan.render(provider))?;
+
+    let mut message = format!("Created {}", path);
+    if path != main_path {
+        message.push_str(&format!(" (add `include: {}` to {})", path, main_path));
+    }
+    Ok(Some(message))
+}
+
+/// Install quench's git hooks, or remove them with `remove`, printing
+/// what happened to each.
+fn update_hooks(root: &Path, remove: bool) -> Result<()> {
+    if !is_git_repo(root) {
+        let flag = if remove { "--remove-hooks" } else { "--hooks" };
+        anyhow::bail!(Error::Argument(format!("{} needs a git repository", flag)));
+    }
+    let dir = hooks::hooks_dir(root)?;
+    let shown = dir.strip_prefix(root).unwrap_or(&dir);
+    for hook in Hook::ALL {
+        let path = shown.join(hook.name());
+        let path = path.display();
+        let action = if remove {
+            hooks::uninstall(&dir, hook)?
+        } else {
+            hooks::install(&dir, hook)?
+        };
+        match action {
+            HookAction::Installed => println!("Installed {}", path),
+            HookAction::Updated => println!("Updated {}", path),
+            HookAction::Chained => println!(
+                "Installed {} (runs the existing hook first, moved to {}.pre-quench)",
+                path, path
+            ),
+            HookAction::Removed { restored: true } => {
+                println!("Removed {} (restored the hook it replaced)", path)
+            }
+            HookAction::Removed { restored: false } => println!("Removed {}", path),
+            HookAction::NotOurs => eprintln!(
+                "quench: warning: {} was not installed by quench, leaving it",
+                path
+            ),
+            HookAction::Missing => {}
+        }
+    }
+    Ok(())
+}
+
 /// Run the `init` command to create a quench.toml configuration file.
 pub fn run(args: &InitArgs) -> Result<ExitCode> {
     let cwd = std::env::current_dir()?;
     let config_path = cwd.join("quench.toml");
 
+    if args.remove_hooks {
+        update_hooks(&cwd, true)?;
+        return Ok(ExitCode::Success);
+    }
+
     if config_path.exists() && !args.force {
+        // Hooks can be added to an existing setup
+        if args.hooks {
+            update_hooks(&cwd, false)?;
+            return Ok(ExitCode::Success);
+        }
         eprintln!("quench.toml already exists. Use --force to overwrite.");
         return Ok(ExitCode::ConfigError);
     }
 
     // Determine what to include
-    let (config, message) = if !args.with_profiles.is_empty() {
+    let (config, message, languages) = if !args.with_profiles.is_empty() {
         // --with specified: use full profiles, skip detection
         // Separate agent profiles from language profiles since agents replace agents section
         let mut agent_required: Vec<&str> = Vec::new();
         let mut lang_config = String::new();
 
         for profile in &args.with_profiles {
-            if ProfileRegistry::is_agent_profile(profile) {
+            if let Some(file) = ProfileRegistry::agent_file(profile) {
                 // Agent profile: collect required files
-                match profile.to_lowercase().as_str() {
-                    "claude" => {
-                        if !agent_required.contains(&"CLAUDE.md") {
-                            agent_required.push("CLAUDE.md");
-                        }
-                    }
-                    "cursor" => {
-                        if !agent_required.contains(&".cursorrules") {
-                            agent_required.push(".cursorrules");
-                        }
-                    }
-                    _ => {}
+                if !agent_required.contains(&file) {
+                    agent_required.push(file);
                 }
             } else if let Some(content) = ProfileRegistry::get(profile) {
                 // Language profile: append to config
@@ -131,7 +234,12 @@ pub fn run(args: &InitArgs) -> Result<ExitCode> {
             "Created quench.toml with profile(s): {}",
             args.with_profiles.join(", ")
         );
-        (cfg, msg)
+        let langs = args
+            .with_profiles
+            .iter()
+            .filter_map(|p| profile_language(p))
+            .collect();
+        (cfg, msg, langs)
     } else {
         // No --with: run auto-detection for both languages and agents
         let detected_langs = detect_languages(&cwd);
@@ -168,10 +276,7 @@ pub fn run(args: &InitArgs) -> Result<ExitCode> {
             });
         }
         for agent in &detected_agents {
-            detected_names.push(match agent {
-                DetectedAgent::Claude => "claude",
-                DetectedAgent::Cursor(_) => "cursor",
-            });
+            detected_names.push(agent.name());
         }
 
         let msg = if detected_names.is_empty() {
@@ -182,10 +287,23 @@ pub fn run(args: &InitArgs) -> Result<ExitCode> {
                 detected_names.join(", ")
             )
         };
-        (cfg, msg)
+        (cfg, msg, detected_langs)
     };
 
-    std::fs::write(&config_path, config)?;
+    // Carry over settings from existing tool configs
+    let mut imported = Imported::default();
+    let diagnostics = Diagnostics::default();
+    for file in &args.import {
+        if let Err(e) = imported.import(&cwd, file) {
+            diagnostics.emit(Code::ImportFailed, format!("cannot import {}", e));
+        }
+    }
+    std::fs::write(&config_path, imported.apply(&config))?;
+
+    let workflow_message = match args.ci {
+        Some(provider) => write_ci_workflow(&cwd, provider.into(), &languages, args.force)?,
+        None => None,
+    };
 
     // Ensure .quench/ is in .gitignore
     if let Err(e) = ensure_gitignored(&cwd) {
@@ -201,5 +319,14 @@ pub fn run(args: &InitArgs) -> Result<ExitCode> {
     }
 
     println!("{}", message);
+    if !imported.sources.is_empty() {
+        println!("Imported settings from {}", imported.sources.join(", "));
+    }
+    if let Some(workflow_message) = workflow_message {
+        println!("{}", workflow_message);
+    }
+    if args.hooks {
+        update_hooks(&cwd, false)?;
+    }
     Ok(ExitCode::Success)
 }
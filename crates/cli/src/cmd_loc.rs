@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench loc` command implementation.
+//!
+//! Reports the cloc counts as a tokei-style table (one row per language and
+//! per package), without any pass/fail judgment.
+
+use std::collections::BTreeMap;
+
+use quench::adapter::FileKind;
+use quench::cli::{LocArgs, OutputFormat};
+use quench::color::{self, codes};
+use quench::error::ExitCode;
+
+use crate::cmd_cloc::{Counts, LangStats, count};
+
+/// Named rows, in display order.
+type Rows = Vec<(String, Row)>;
+
+/// Totals for one row of the report.
+#[derive(Default)]
+struct Row {
+    files: usize,
+    code: usize,
+    comments: usize,
+    blanks: usize,
+    /// Code lines in test files (included in `code`).
+    tests: usize,
+}
+
+impl Row {
+    fn lines(&self) -> usize {
+        self.code + self.comments + self.blanks
+    }
+
+    fn add(&mut self, other: &Row) {
+        self.files += other.files;
+        self.code += other.code;
+        self.comments += other.comments;
+        self.blanks += other.blanks;
+        self.tests += other.tests;
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "files": self.files,
+            "lines": self.lines(),
+            "code": self.code,
+            "comments": self.comments,
+            "blanks": self.blanks,
+            "tests": self.tests,
+        })
+    }
+}
+
+/// Run the `quench loc` command.
+pub fn run(args: &LocArgs) -> anyhow::Result<ExitCode> {
+    let counts = count(&args.paths, args.max_depth)?;
+    let (languages, packages) = rows(&counts);
+
+    let mut total = Row::default();
+    for (_, row) in &languages {
+        total.add(row);
+    }
+
+    match args.output {
+        OutputFormat::Json => {
+            let named = |label: &str, rows: &Rows| -> Vec<serde_json::Value> {
+                rows.iter()
+                    .map(|(name, row)| {
+                        let mut entry = row.to_json();
+                        entry[label] = serde_json::json!(name);
+                        entry
+                    })
+                    .collect()
+            };
+            let mut output = serde_json::json!({
+                "languages": named("language", &languages),
+                "total": total.to_json(),
+            });
+            if !packages.is_empty() {
+                output["packages"] = serde_json::json!(named("name", &packages));
+            }
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+        _ => print_text(&languages, &packages, &total),
+    }
+
+    Ok(ExitCode::Success)
+}
+
+/// Per-language rows (most code first) and per-package rows (by name).
+fn rows(counts: &Counts) -> (Rows, Rows) {
+    let mut languages: BTreeMap<String, Row> = BTreeMap::new();
+    let mut packages: BTreeMap<String, Row> = BTreeMap::new();
+
+    let add = |row: &mut Row, kind: FileKind, stats: &LangStats| {
+        row.code += stats.code;
+        row.comments += stats.comment;
+        row.blanks += stats.blank;
+        if kind == FileKind::Test {
+            row.tests += stats.code;
+        }
+    };
+    for ((lang, kind), stats) in &counts.stats {
+        add(languages.entry(lang.clone()).or_default(), *kind, stats);
+    }
+    for ((pkg, _, kind), stats) in &counts.package_lang_stats {
+        add(packages.entry(pkg.clone()).or_default(), *kind, stats);
+    }
+    for ((pkg, lang), files) in &counts.files {
+        let row = if pkg.is_empty() {
+            languages.get_mut(lang)
+        } else {
+            packages.get_mut(pkg)
+        };
+        if let Some(row) = row {
+            row.files += files;
+        }
+    }
+
+    let mut languages: Vec<_> = languages.into_iter().collect();
+    languages.sort_by(|a, b| b.1.code.cmp(&a.1.code).then_with(|| a.0.cmp(&b.0)));
+
+    let mut packages: Vec<_> = packages
+        .into_iter()
+        .map(|(path, row)| {
+            let name = counts.package_names.get(&path).cloned().unwrap_or(path);
+            (name, row)
+        })
+        .collect();
+    packages.sort_by(|a, b| a.0.cmp(&b.0));
+
+    (languages, packages)
+}
+
+/// Print the report as a text table.
+fn print_text(languages: &Rows, packages: &Rows, total: &Row) {
+    if languages.is_empty() {
+        println!("No source files found.");
+        return;
+    }
+
+    let use_color = matches!(
+        color::resolve_color(),
+        termcolor::ColorChoice::Always | termcolor::ColorChoice::Auto
+    );
+    let fg = |code: u8, text: &str| -> String {
+        if use_color {
+            format!("{}{}{}", color::fg256(code), text, color::RESET)
+        } else {
+            text.to_string()
+        }
+    };
+    let separator = "\u{2500}".repeat(76);
+    let header = |label: &str| {
+        println!("{}", fg(codes::CONTEXT, &separator));
+        println!(
+            "{}",
+            fg(
+                codes::HEADER,
+                &format!(
+                    "{:<20} {:>7} {:>9} {:>9} {:>9} {:>9} {:>9}",
+                    label, "Files", "Lines", "Code", "Comments", "Blanks", "Tests"
+                ),
+            )
+        );
+        println!("{}", fg(codes::CONTEXT, &separator));
+    };
+    let print_row = |name: &str, row: &Row| {
+        println!(
+            "{:<20} {:>7} {:>9} {:>9} {:>9} {:>9} {:>9}",
+            name,
+            row.files,
+            row.lines(),
+            row.code,
+            row.comments,
+            row.blanks,
+            row.tests
+        );
+    };
+
+    header("Language");
+    for (name, row) in languages {
+        print_row(name, row);
+    }
+    if !packages.is_empty() {
+        header("Package");
+        for (name, row) in packages {
+            print_row(name, row);
+        }
+    }
+    println!("{}", fg(codes::CONTEXT, &separator));
+    print_row("Total", total);
+    println!("{}", fg(codes::CONTEXT, &separator));
+}
@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench query` command implementation.
+
+use anyhow::bail;
+
+use quench::cli::QueryArgs;
+use quench::config::{self, Config};
+use quench::discovery;
+use quench::error::{Error, ExitCode};
+
+use crate::store::ResultStore;
+
+/// Run the `quench query` command.
+///
+/// Reads run history from the `[store]` database, newest `--limit` entries,
+/// oldest first.
+pub fn run(args: &QueryArgs) -> anyhow::Result<ExitCode> {
+    let root = std::env::current_dir()?;
+    let config = match discovery::find_config(&root) {
+        Some(path) => config::load_with_warnings(&path)?,
+        None => Config::default(),
+    };
+    let Some(store) = ResultStore::open(&root, &config)? else {
+        bail!(Error::Config {
+            message: "quench query reads the [store] database; set [store] backend = \"sqlite\""
+                .to_string(),
+            path: None,
+        });
+    };
+    query(&store, args)
+}
+
+#[cfg(feature = "sqlite")]
+fn query(store: &ResultStore, args: &QueryArgs) -> anyhow::Result<ExitCode> {
+    use quench::cli::OutputFormat;
+
+    let store = store.inner();
+    let json = matches!(args.output, OutputFormat::Json);
+    let commit = |commit: &Option<String>| commit.clone().unwrap_or_else(|| "-".to_string());
+    let date = |recorded: &chrono::DateTime<chrono::Utc>| recorded.format("%Y-%m-%d %H:%M");
+
+    if let Some(metric) = &args.history {
+        let points = store.metric_history(metric, args.limit)?;
+        if json {
+            let history: Vec<_> = points
+                .iter()
+                .map(|p| {
+                    serde_json::json!({
+                        "recorded": p.recorded.to_rfc3339(),
+                        "commit": p.commit,
+                        "ci": p.ci,
+                        "value": p.value,
+                    })
+                })
+                .collect();
+            let report = serde_json::json!({ "metric": metric, "history": history });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else if points.is_empty() {
+            println!("no recorded values for {}", metric);
+        } else {
+            println!("{}", metric);
+            for p in &points {
+                let mode = if p.ci { "  (ci)" } else { "" };
+                println!(
+                    "  {}  {}  {}{}",
+                    date(&p.recorded),
+                    commit(&p.commit),
+                    p.value,
+                    mode
+                );
+            }
+        }
+    } else if let Some(file) = &args.file {
+        let results = store.file_history(file, args.limit)?;
+        if json {
+            let history: Vec<_> = results
+                .iter()
+                .map(|r| {
+                    serde_json::json!({
+                        "recorded": r.recorded.to_rfc3339(),
+                        "commit": r.commit,
+                        "check": r.check,
+                        "violations": r.violations,
+                    })
+                })
+                .collect();
+            let report = serde_json::json!({ "file": file, "history": history });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else if results.is_empty() {
+            println!("no recorded violations for {}", file);
+        } else {
+            println!("{}", file);
+            for r in &results {
+                println!(
+                    "  {}  {}  {}: {}",
+                    date(&r.recorded),
+                    commit(&r.commit),
+                    r.check,
+                    r.violations
+                );
+            }
+        }
+    }
+    Ok(ExitCode::Success)
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn query(store: &ResultStore, _args: &QueryArgs) -> anyhow::Result<ExitCode> {
+    store.unreachable()
+}
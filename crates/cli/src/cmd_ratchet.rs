@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench ratchet` command implementation.
+
+use quench::cli::{OutputFormat, RatchetArgs, RatchetCommand, RefreshArgs};
+use quench::config::CheckLevel;
+use quench::diagnostics::Diagnostics;
+use quench::error::ExitCode;
+use quench::git::is_git_repo;
+use quench::latest::get_head_commit;
+use quench::refresh::{self, RefreshSummary};
+use quench::verbose::VerboseLogger;
+
+use crate::cmd_check;
+use crate::store::ResultStore;
+
+/// Run the `quench ratchet` command.
+pub fn run(args: &RatchetArgs) -> anyhow::Result<ExitCode> {
+    match &args.command {
+        RatchetCommand::Refresh(args) => run_refresh(args),
+    }
+}
+
+/// Re-run CI checks and update the baseline when warranted.
+pub(crate) fn run_refresh(args: &RefreshArgs) -> anyhow::Result<ExitCode> {
+    let root = std::env::current_dir()?;
+    let (config, output) = cmd_check::run_ci_checks(&root)?;
+    // Partial results would refresh the baseline with skipped checks missing
+    if quench::interrupt::requested() {
+        eprintln!("quench: interrupted; baseline not refreshed");
+        return Ok(ExitCode::Interrupted);
+    }
+
+    if config.ratchet.check == CheckLevel::Off {
+        eprintln!("quench: ratchet is off ([ratchet] check = \"off\"); nothing to refresh");
+        return Ok(ExitCode::ConfigError);
+    }
+
+    let use_notes = config.git.uses_notes() && is_git_repo(&root);
+    let target = match config.git.baseline_path() {
+        _ if use_notes => "git notes".to_string(),
+        Some(path) => path.to_string(),
+        None => {
+            eprintln!("quench: git notes baseline requires a git repository");
+            return Ok(ExitCode::ConfigError);
+        }
+    };
+
+    let store = ResultStore::open(&root, &config)?;
+    let quiet = VerboseLogger::new(false);
+    let diagnostics = Diagnostics::default();
+    let (ratchet_result, baseline) = cmd_check::run_ratchet_check(
+        &config,
+        &quiet,
+        &output,
+        use_notes,
+        &root,
+        &None,
+        &diagnostics,
+    );
+
+    let reason = refresh::decide(
+        baseline.as_ref(),
+        ratchet_result.as_ref(),
+        config.ratchet.stale_days,
+        args.if_stale,
+    );
+    let summary = RefreshSummary::new(
+        reason,
+        target,
+        baseline.as_ref(),
+        ratchet_result.as_ref(),
+        get_head_commit(&root).ok(),
+    );
+
+    if reason.is_some() {
+        let saved = cmd_check::save_baseline(
+            &config,
+            &output,
+            &ratchet_result,
+            baseline,
+            &root,
+            None,
+            &diagnostics,
+        );
+        if let Some(store) = &store {
+            cmd_check::record_baseline(store, &saved, &diagnostics);
+        }
+    }
+
+    match args.output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&summary)?),
+        _ => println!("{}", summary.format_text()),
+    }
+
+    if summary.updated || summary.regressions.is_empty() {
+        Ok(ExitCode::Success)
+    } else {
+        Ok(ExitCode::CheckFailed)
+    }
+}
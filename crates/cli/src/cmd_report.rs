@@ -8,21 +8,31 @@ use std::path::Path;
 
 use anyhow::Context;
 
+use quench::adapter::project::apply_language_defaults;
 use quench::baseline::Baseline;
-use quench::cli::{Cli, OutputFormat, ReportArgs};
+use quench::cli::{CheckFilter, Cli, OutputFormat, ReportArgs};
 use quench::config::{self, Config};
+use quench::debt;
+use quench::diagnostics::{Code, Diagnostics};
 use quench::discovery;
 use quench::git::is_git_repo;
+use quench::goals;
+use quench::health::HealthScore;
+use quench::hotspots;
 use quench::latest::LatestMetrics;
 use quench::report;
+use quench::suppressions::{self, SuppressionSummary};
+
+use crate::store::ResultStore;
 
 /// Run the report command.
 pub fn run(_cli: &Cli, args: &ReportArgs) -> anyhow::Result<()> {
     let cwd = std::env::current_dir()?;
 
     // Find and load config
-    let config = if let Some(path) = discovery::find_config(&cwd) {
-        config::load_with_warnings(&path)?
+    let config_path = discovery::find_config(&cwd);
+    let mut config = if let Some(path) = &config_path {
+        config::load_with_warnings(path)?
     } else {
         config::Config::default()
     };
@@ -35,40 +45,82 @@ pub fn run(_cli: &Cli, args: &ReportArgs) -> anyhow::Result<()> {
         eprintln!("warning: --compact only applies to JSON output, ignoring");
     }
 
-    // Load baseline from the best available source
-    let baseline: Option<Baseline> = if let Some(ref base) = args.base {
-        if base.ends_with(".json") {
-            // Direct file load (e.g., --base baseline.json)
-            let path = std::path::Path::new(base);
-            let loaded = Baseline::load(&cwd.join(path))
-                .with_context(|| format!("failed to load baseline from {}", path.display()))?;
-            if loaded.is_none() {
-                eprintln!("warning: baseline not found at {}", path.display());
-            }
-            loaded
-        } else {
-            // Git ref (e.g., --base main, --base HEAD~1)
-            load_baseline_for_ref(&cwd, &config, base)?
-        }
-    } else {
-        // No --base specified: use HEAD
-        load_baseline_for_ref(&cwd, &config, "HEAD")?
+    if args.debt || args.hotspots {
+        return run_ranking(&cwd, config, args, format, file_path.as_deref());
+    }
+
+    // Baselines record package names; `--package` also takes paths
+    let mut args = args.clone();
+    if !args.packages.is_empty() {
+        apply_language_defaults(&cwd, &mut config);
+        args.resolve_packages(&config.project.package_names);
+    }
+    let args = &args;
+
+    // Load baseline from the best available source (HEAD without --base)
+    let diagnostics = Diagnostics::default();
+    let head = args.base.as_deref().unwrap_or("HEAD");
+    let mut baseline = load_baseline(&cwd, &config, head, &diagnostics)?;
+    let compared = match args.compare.as_deref() {
+        Some(target) => load_baseline(&cwd, &config, target, &diagnostics)?,
+        None => None,
     };
 
+    // The store keeps every baseline update, not just the newest samples
+    if let Some(store) = ResultStore::open(&cwd, &config)?
+        && let Some(b) = baseline.as_mut()
+    {
+        let samples = store.samples()?;
+        if !samples.is_empty() {
+            b.metrics.history = Some(samples);
+        }
+    }
+
+    // Suppressions come from the working tree, not the baseline
+    let suppressions = (baseline.is_some() && args.should_include("escapes"))
+        .then(|| suppression_summary(&cwd, &mut config, config_path.as_deref()));
+    let insights = baseline
+        .as_ref()
+        .map(|b| report::Insights {
+            health: HealthScore::from_metrics(&b.metrics, &config.health),
+            goals: goals::track(&b.metrics, &config.goals),
+            suppressions,
+            comparison: compared.map(|earlier| report::Comparison {
+                health: HealthScore::from_metrics(&earlier.metrics, &config.health),
+                max_tokens: root_max_tokens(&config),
+                baseline: earlier,
+            }),
+        })
+        .unwrap_or_default();
+
     // Write output using streaming when possible
     match file_path {
         Some(path) => {
             // File output: use buffered writer for efficiency
             let file = std::fs::File::create(&path)?;
             let mut writer = std::io::BufWriter::new(file);
-            report::format_report_to(&mut writer, format, baseline.as_ref(), args, args.compact)?;
+            report::format_report_to(
+                &mut writer,
+                format,
+                baseline.as_ref(),
+                args,
+                args.compact,
+                &insights,
+            )?;
             writer.flush()?;
         }
         None => {
             // Stdout: use stdout lock for efficiency
             let stdout = std::io::stdout();
             let mut handle = stdout.lock();
-            report::format_report_to(&mut handle, format, baseline.as_ref(), args, args.compact)?;
+            report::format_report_to(
+                &mut handle,
+                format,
+                baseline.as_ref(),
+                args,
+                args.compact,
+                &insights,
+            )?;
             // Add trailing newline for JSON output
             if matches!(format, OutputFormat::Json) {
                 writeln!(handle)?;
@@ -78,6 +130,74 @@ pub fn run(_cli: &Cli, args: &ReportArgs) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Rank files by technical debt or churn and write the top `--top`.
+fn run_ranking(
+    root: &Path,
+    mut config: Config,
+    args: &ReportArgs,
+    format: OutputFormat,
+    file_path: Option<&Path>,
+) -> anyhow::Result<()> {
+    let files = debt::collect(root, &mut config)?;
+    let output = if args.hotspots {
+        let mut found = hotspots::find(&files, &config.hotspots);
+        found.truncate(args.top);
+        report::hotspots::format(format, &found, &config.hotspots, args.compact)?
+    } else {
+        let ranked = debt::rank(&files, config.check.cloc.max_lines, args.top);
+        report::debt::format(format, &ranked, args.compact)?
+    };
+    match file_path {
+        Some(path) => std::fs::write(path, output)?,
+        None if output.ends_with('\n') => print!("{}", output),
+        None => println!("{}", output),
+    }
+    Ok(())
+}
+
+/// Per-file token limit for root-scope agent files, the files baselines record.
+fn root_max_tokens(config: &Config) -> Option<usize> {
+    let agents = &config.check.agents;
+    agents
+        .root
+        .as_ref()
+        .and_then(|root| root.max_tokens)
+        .or(agents.max_tokens)
+}
+
+/// Count the working tree's suppressions for the report.
+fn suppression_summary(
+    root: &Path,
+    config: &mut Config,
+    config_path: Option<&Path>,
+) -> SuppressionSummary {
+    let found = suppressions::collect(root, config, config_path);
+    SuppressionSummary::new(&found, chrono::Local::now().date_naive())
+}
+
+/// Load a baseline from a JSON file (e.g., baseline.json) or a git ref.
+fn load_baseline(
+    root: &Path,
+    config: &Config,
+    target: &str,
+    diagnostics: &Diagnostics,
+) -> anyhow::Result<Option<Baseline>> {
+    if !target.ends_with(".json") {
+        // Git ref (e.g., main, HEAD~1)
+        return load_baseline_for_ref(root, config, target, diagnostics);
+    }
+    let path = Path::new(target);
+    let loaded = Baseline::load(&root.join(path))
+        .with_context(|| format!("failed to load baseline from {}", path.display()))?;
+    if loaded.is_none() {
+        diagnostics.emit(
+            Code::BaselineLoadFailed,
+            format!("baseline not found at {}", path.display()),
+        );
+    }
+    Ok(loaded)
+}
+
 /// Load baseline for a git reference using configured baseline source.
 ///
 /// Strategy:
@@ -90,6 +210,7 @@ fn load_baseline_for_ref(
     root: &Path,
     config: &Config,
     git_ref: &str,
+    diagnostics: &Diagnostics,
 ) -> anyhow::Result<Option<Baseline>> {
     // For HEAD, try latest.json cache first (fast path)
     if git_ref == "HEAD" {
@@ -99,6 +220,7 @@ fn load_baseline_for_ref(
                 version: quench::baseline::BASELINE_VERSION,
                 updated: latest.updated,
                 commit: latest.commit,
+                tags: latest.output.tags.clone(),
                 metrics: extract_baseline_metrics(&latest.output),
             }));
         }
@@ -110,9 +232,12 @@ fn load_baseline_for_ref(
         match Baseline::load_from_notes(root, git_ref) {
             Ok(baseline) => Ok(baseline),
             Err(e) => {
-                eprintln!(
-                    "warning: failed to load baseline from git notes for {}: {}",
-                    git_ref, e
+                diagnostics.emit(
+                    Code::BaselineLoadFailed,
+                    format!(
+                        "failed to load baseline from git notes for {}: {}",
+                        git_ref, e
+                    ),
                 );
                 Ok(None)
             }
@@ -122,7 +247,10 @@ fn load_baseline_for_ref(
         match Baseline::load(&root.join(path)) {
             Ok(baseline) => Ok(baseline),
             Err(e) => {
-                eprintln!("warning: failed to load baseline from {}: {}", path, e);
+                diagnostics.emit(
+                    Code::BaselineLoadFailed,
+                    format!("failed to load baseline from {}: {}", path, e),
+                );
                 Ok(None)
             }
         }
@@ -135,8 +263,8 @@ fn load_baseline_for_ref(
 fn extract_baseline_metrics(
     output: &quench::check::CheckOutput,
 ) -> quench::baseline::BaselineMetrics {
-    use quench::baseline::{BaselineMetrics, EscapesMetrics};
-    use std::collections::HashMap;
+    use quench::baseline::{AgentsMetrics, BaselineMetrics, EscapesMetrics};
+    use std::collections::BTreeMap;
 
     let mut metrics = BaselineMetrics::default();
 
@@ -144,7 +272,7 @@ fn extract_baseline_metrics(
         if check.name == "escapes"
             && let Some(check_metrics) = &check.metrics
         {
-            let mut source: HashMap<String, usize> = HashMap::new();
+            let mut source: BTreeMap<String, usize> = BTreeMap::new();
 
             if let Some(source_obj) = check_metrics.get("source").and_then(|s| s.as_object()) {
                 for (key, value) in source_obj {
@@ -155,9 +283,34 @@ fn extract_baseline_metrics(
             }
 
             if !source.is_empty() {
-                metrics.escapes = Some(EscapesMetrics { source, test: None });
+                metrics.escapes = Some(EscapesMetrics {
+                    source,
+                    test: None,
+                    // Kept for the health score's escape density
+                    source_lines: check_metrics
+                        .get("source_lines")
+                        .and_then(|v| v.as_u64())
+                        .map(|n| n as usize),
+                    by_package: None,
+                });
             }
         }
+        if check.name == "agents"
+            && let Some(tokens) = check
+                .metrics
+                .as_ref()
+                .and_then(|m| m.get("tokens"))
+                .and_then(|t| t.as_object())
+        {
+            let files: BTreeMap<String, usize> = tokens
+                .iter()
+                .filter_map(|(k, v)| v.as_u64().map(|n| (k.clone(), n as usize)))
+                .collect();
+            metrics.agents = Some(AgentsMetrics {
+                total_tokens: files.values().sum(),
+                files,
+            });
+        }
         // Add other metric types as needed (coverage, build_time, etc.)
     }
 
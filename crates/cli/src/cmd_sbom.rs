@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench sbom` command implementation.
+
+use std::path::PathBuf;
+
+use quench::adapter::glob::build_glob_set;
+use quench::adapter::project::apply_language_defaults;
+use quench::cli::{SbomArgs, SbomFormat};
+use quench::config;
+use quench::diagnostics::{Code, Diagnostics};
+use quench::discovery;
+use quench::error::ExitCode;
+use quench::sbom::Sbom;
+use quench::walker::{FileWalker, WalkerConfig};
+
+/// Run the `quench sbom` command.
+pub fn run(args: &SbomArgs) -> anyhow::Result<ExitCode> {
+    let root = std::env::current_dir()?;
+
+    let mut config = match discovery::find_config(&root) {
+        Some(path) => config::load_with_warnings(&path)?,
+        None => config::Config::default(),
+    };
+    let exclude_patterns = apply_language_defaults(&root, &mut config);
+
+    let walker = FileWalker::new(WalkerConfig {
+        exclude_patterns,
+        ..WalkerConfig::for_project(&config.project)
+    });
+    let (files, _) = walker.walk_collect(&root);
+    let paths: Vec<PathBuf> = files.into_iter().map(|file| file.path).collect();
+
+    // Named after the project, or its directory
+    let name = config.project.name.clone().unwrap_or_else(|| {
+        root.file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "project".to_string())
+    });
+    let exclude = build_glob_set(&config.check.deps.exclude);
+    let sbom = Sbom::read(&name, &root, &paths, &exclude);
+
+    let diagnostics = Diagnostics::default();
+    for (path, reason) in &sbom.skipped {
+        diagnostics.emit(
+            Code::SbomIncomplete,
+            format!("skipping {}: {}", path.display(), reason),
+        );
+    }
+    for ecosystem in &sbom.unlocked {
+        diagnostics.emit(
+            Code::SbomIncomplete,
+            format!(
+                "{} manifests have no lockfile; their dependencies are not listed",
+                ecosystem.name()
+            ),
+        );
+    }
+
+    let now = chrono::Utc::now();
+    let document = match args.output {
+        SbomFormat::Cyclonedx => sbom.to_cyclonedx(now),
+        SbomFormat::Spdx => sbom.to_spdx(now),
+    };
+    println!("{}", serde_json::to_string_pretty(&document)?);
+    Ok(ExitCode::Success)
+}
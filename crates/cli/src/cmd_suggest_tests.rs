@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench suggest-tests` command implementation.
+
+use quench::cli::{OutputFormat, SuggestTestsArgs};
+use quench::config;
+use quench::discovery;
+use quench::error::ExitCode;
+use quench::git::{detect_base_branch, get_changed_files, is_git_repo, resolve_base};
+use quench::impact::ImpactMap;
+
+/// Run the `quench suggest-tests` command.
+pub fn run(args: &SuggestTestsArgs) -> anyhow::Result<ExitCode> {
+    let root = std::env::current_dir()?;
+    if !is_git_repo(&root) {
+        eprintln!("quench: suggest-tests requires a git repository");
+        return Ok(ExitCode::ConfigError);
+    }
+    let Some(base) = args.base.clone().or_else(|| detect_base_branch(&root)) else {
+        eprintln!("quench: no base branch found; pass --base <REF>");
+        return Ok(ExitCode::ConfigError);
+    };
+
+    let Some(map) = ImpactMap::load(&ImpactMap::path(&root))? else {
+        eprintln!(
+            "quench: no test impact recorded; set [check.tests.coverage] impact = true \
+             and run `quench check --ci`"
+        );
+        return Ok(ExitCode::ConfigError);
+    };
+
+    let config = match discovery::find_config(&root) {
+        Some(path) => config::load_with_warnings(&path)?,
+        None => config::Config::default(),
+    };
+    let strategy = args
+        .base_strategy
+        .map_or(config.git.base_strategy, Into::into);
+    let base_commit = resolve_base(&root, &base, strategy).unwrap_or_else(|_| base.clone());
+
+    let mut changed: Vec<String> = get_changed_files(&root, &base_commit)?
+        .iter()
+        .map(|path| {
+            path.strip_prefix(&root)
+                .unwrap_or(path)
+                .display()
+                .to_string()
+        })
+        .collect();
+    changed.sort();
+    let suggestion = map.suggest(&changed);
+
+    match args.output {
+        OutputFormat::Json => {
+            let report = serde_json::json!({
+                "base": base,
+                "changed": changed,
+                "tests": suggestion.tests,
+                "unmapped": suggestion.unmapped,
+            });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        _ => {
+            for test in &suggestion.tests {
+                println!("{}", test);
+            }
+            if !suggestion.unmapped.is_empty() {
+                eprintln!(
+                    "quench: {} changed files have no recorded tests:",
+                    suggestion.unmapped.len()
+                );
+                for file in &suggestion.unmapped {
+                    eprintln!("  {}", file);
+                }
+            }
+        }
+    }
+    Ok(ExitCode::Success)
+}
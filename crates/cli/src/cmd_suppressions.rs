@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench suppressions` command implementation.
+
+use chrono::NaiveDate;
+
+use quench::cli::{OutputFormat, SuppressionsArgs, SuppressionsCommand, SuppressionsListArgs};
+use quench::config::{self, Config};
+use quench::discovery;
+use quench::error::ExitCode;
+use quench::suppressions::{self, Suppression, SuppressionSummary};
+
+/// Run the `quench suppressions` command.
+pub fn run(args: &SuppressionsArgs) -> anyhow::Result<ExitCode> {
+    match &args.command {
+        SuppressionsCommand::List(args) => run_list(args),
+    }
+}
+
+/// List suppressions, oldest expiry first with `--expiring`.
+fn run_list(args: &SuppressionsListArgs) -> anyhow::Result<ExitCode> {
+    let root = std::env::current_dir()?;
+    let config_path = discovery::find_config(&root);
+    let mut config = match &config_path {
+        Some(path) => config::load_with_warnings(path)?,
+        None => Config::default(),
+    };
+    let today = chrono::Local::now().date_naive();
+
+    let mut list = suppressions::collect(&root, &mut config, config_path.as_deref());
+    if let Some(days) = args.expiring {
+        list.retain(|s| s.expires_within(today, days));
+        list.sort_by_key(|s| s.expires);
+    }
+    let summary = SuppressionSummary::new(&list, today);
+
+    if matches!(args.output, OutputFormat::Json) {
+        let entries: Vec<_> = list
+            .iter()
+            .map(|s| {
+                let mut entry = serde_json::to_value(s)?;
+                entry["age_days"] = s.age_days(today).into();
+                entry["expired"] = s.is_expired(today).into();
+                Ok(entry)
+            })
+            .collect::<serde_json::Result<_>>()?;
+        let report = serde_json::json!({ "suppressions": entries, "summary": summary });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(ExitCode::Success);
+    }
+
+    if list.is_empty() {
+        match args.expiring {
+            Some(days) => println!("no suppressions expired or expiring within {} days", days),
+            None => println!("no suppressions"),
+        }
+        return Ok(ExitCode::Success);
+    }
+    for s in &list {
+        println!("{}:{}: {}", s.file.display(), s.line, s.pattern);
+        println!("  {}", describe(s, today));
+    }
+    println!();
+    println!(
+        "{} suppressions ({} expired, {} expiring within {} days)",
+        summary.total,
+        summary.expired,
+        summary.expiring,
+        suppressions::DEFAULT_EXPIRING_DAYS
+    );
+    Ok(ExitCode::Success)
+}
+
+/// Kind, age, and expiry, e.g. "inline, added 2026-01-05 (40d ago), expires 2026-03-01".
+fn describe(s: &Suppression, today: NaiveDate) -> String {
+    let mut parts = vec![s.kind.as_str().to_string()];
+    match (s.added, s.age_days(today)) {
+        (Some(added), Some(age)) => parts.push(format!("added {} ({}d ago)", added, age)),
+        _ => parts.push("uncommitted".to_string()),
+    }
+    match s.expires {
+        Some(expires) if s.is_expired(today) => parts.push(format!("expired {}", expires)),
+        Some(expires) => parts.push(format!("expires {}", expires)),
+        None => parts.push("no expiry".to_string()),
+    }
+    parts.join(", ")
+}
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench watch` command implementation.
+
+use quench::cli::{Cli, WatchArgs};
+use quench::config::{self, Config};
+use quench::discovery;
+use quench::error::ExitCode;
+use quench::tolerance::parse_duration;
+use quench::watcher::{self, Watcher};
+
+use crate::cmd_check;
+
+/// Run the `quench watch` command.
+///
+/// Runs `quench check` once, then again each time files change, narrowed
+/// to the checks the changes can affect. Unchanged files come from the
+/// file cache, so only changed files are rechecked. Runs until interrupted:
+/// Ctrl-C ends the current run with partial results, then stops watching.
+pub fn run(cli: &Cli, args: &WatchArgs) -> anyhow::Result<ExitCode> {
+    let debounce = match parse_duration(&args.debounce) {
+        Ok(debounce) => debounce,
+        Err(e) => {
+            eprintln!("quench: invalid --debounce: {}", e);
+            return Ok(ExitCode::ConfigError);
+        }
+    };
+    for (set, flag) in [
+        (args.check.staged, "--staged"),
+        (args.check.save.is_some(), "--save"),
+        (args.check.check_determinism, "--check-determinism"),
+    ] {
+        if set {
+            eprintln!("{} cannot be used with quench watch", flag);
+            return Ok(ExitCode::ConfigError);
+        }
+    }
+
+    // A run started by hand delays the next watch run instead of failing it
+    let mut base = args.check.clone();
+    base.wait = !base.no_lock;
+
+    let root = cmd_check::resolve_root(&std::env::current_dir()?, &base);
+    // Before the first run, so changes made during it aren't missed
+    let watcher = Watcher::new(&root)?;
+
+    eprintln!("quench: watching {} (Ctrl-C to stop)", root.display());
+    report(cmd_check::run(cli, &base));
+
+    while let Some(changes) = watcher.next_batch(debounce)? {
+        let config = match discovery::find_config(&root) {
+            Some(path) => config::load(&path).unwrap_or_default(),
+            None => Config::default(),
+        };
+        let relevant = watcher::relevant_checks(&changes, &root, &config);
+        let mut check = base.clone();
+        eprintln!();
+        if !check.retain_checks(|name| relevant.contains(&name)) {
+            eprintln!("quench: changed {}; no checks affected", changes.summary());
+            continue;
+        }
+        eprintln!("quench: changed {}", changes.summary());
+        report(cmd_check::run(cli, &check));
+    }
+    if quench::interrupt::requested() {
+        return Ok(ExitCode::Interrupted);
+    }
+    Ok(ExitCode::Success)
+}
+
+/// Report a run that couldn't finish (e.g., a config mid-edit) and keep watching.
+fn report(run: anyhow::Result<ExitCode>) {
+    if let Err(e) = run {
+        eprintln!("quench: {}", e);
+    }
+}
@@ -175,7 +175,20 @@ fn format_help_with_real_command() {
 
     // Verify all check toggles consolidated
     for check in [
-        "cloc", "escapes", "agents", "docs", "tests", "git", "build", "license",
+        "cloc",
+        "escapes",
+        "agents",
+        "docs",
+        "tests",
+        "git",
+        "build",
+        "license",
+        "whitespace",
+        "format",
+        "lint",
+        "snapshots",
+        "deadcode",
+        "deps",
     ] {
         assert!(
             stripped.contains(&format!("--[no-]{check}")),
@@ -1,40 +1,32 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-pub mod adapter;
-pub mod baseline;
-pub mod cache;
-pub mod check;
-pub mod checks;
+//! The `quench` command line.
+//!
+//! Argument parsing, help, shell completions, and `quench report` live here;
+//! the check engine is [`quench_core`], re-exported below so `quench::config`
+//! and friends keep working.
+
+#[cfg(feature = "sqlite")]
+pub use quench_core::store;
+pub use quench_core::{
+    adapter, advice, annotate, anomaly, atomic, baseline, baseline_history, bisect, blocks, budget,
+    cache, check, checks, ci_workflow, cloc, codeowners, coherence, color, config, container, debt,
+    delta_owners, determinism, diagnostics, discovery, env, error, explain, file_reader, file_size,
+    fix, git, goals, graph, health, hooks, hotspots, impact, init, interrupt, latest, lock,
+    metrics, migrate, new_code, output, patch, pattern, profiles, quarantine, ratchet, refresh,
+    rules, runner, sbom, schedule, scope, sparse, suppressions, telemetry, testkit, timing,
+    tolerance, tools, verbose, walker,
+};
+
+pub mod bot;
 pub mod cli;
-pub mod cloc;
 pub mod cmd_init;
-pub mod color;
 pub mod completions;
-pub mod config;
-pub mod discovery;
-pub mod env;
-pub mod error;
-pub mod file_reader;
-pub mod file_size;
-pub mod git;
 pub mod help;
-pub mod init;
-pub mod latest;
-pub mod output;
-pub mod pattern;
-pub mod profiles;
-pub mod ratchet;
 pub mod report;
-pub mod runner;
-pub mod timing;
-pub mod tolerance;
-pub mod verbose;
-pub mod walker;
+pub mod watcher;
 
 pub use baseline::Baseline;
 pub use cli::{Cli, Command};
 pub use error::{Error, ExitCode};
-
-#[cfg(test)]
-pub mod test_utils;
@@ -13,10 +13,27 @@ use quench::cli::{Cli, Command};
 use quench::error::ExitCode;
 use quench::help::format_help;
 
+mod cmd_annotate;
+mod cmd_baseline;
+mod cmd_bisect;
+mod cmd_bot;
 mod cmd_check;
 mod cmd_cloc;
+mod cmd_commit_msg;
 mod cmd_config;
+mod cmd_doctor;
+mod cmd_explain;
+mod cmd_fix;
+mod cmd_graph;
+mod cmd_loc;
+mod cmd_query;
+mod cmd_ratchet;
 mod cmd_report;
+mod cmd_sbom;
+mod cmd_suggest_tests;
+mod cmd_suppressions;
+mod cmd_watch;
+mod store;
 
 fn init_logging() {
     let filter = EnvFilter::try_from_env(quench::env::quench_log_var())
@@ -77,14 +94,44 @@ fn run() -> anyhow::Result<ExitCode> {
             println!();
             Ok(ExitCode::Success)
         }
-        Some(Command::Check(args)) => cmd_check::run(&cli, args),
+        Some(Command::Check(args)) => {
+            // Ctrl-C ends the run with partial results instead of killing it
+            quench::interrupt::install();
+            cmd_check::run(&cli, args)
+        }
+        Some(Command::CheckCommitMsg(args)) => cmd_commit_msg::run(args),
+        Some(Command::Fix(args)) => {
+            quench::interrupt::install();
+            cmd_fix::run(&cli, args)
+        }
         Some(Command::Cloc(args)) => cmd_cloc::run(args),
+        Some(Command::Loc(args)) => cmd_loc::run(args),
         Some(Command::Report(args)) => {
             cmd_report::run(&cli, args)?;
             Ok(ExitCode::Success)
         }
         Some(Command::Init(args)) => quench::cmd_init::run(args),
         Some(Command::Config(args)) => cmd_config::run(args),
+        Some(Command::Ratchet(args)) => {
+            // Refresh runs checks, and its test suites must not outlive it
+            quench::interrupt::install();
+            cmd_ratchet::run(args)
+        }
+        Some(Command::Baseline(args)) => cmd_baseline::run(args),
+        Some(Command::Annotate(args)) => cmd_annotate::run(args),
+        Some(Command::SuggestTests(args)) => cmd_suggest_tests::run(args),
+        Some(Command::Graph(args)) => cmd_graph::run(args),
+        Some(Command::Sbom(args)) => cmd_sbom::run(args),
+        Some(Command::Doctor(args)) => cmd_doctor::run(args),
+        Some(Command::Explain(args)) => cmd_explain::run(args),
+        Some(Command::Bot(args)) => cmd_bot::run(args),
+        Some(Command::Bisect(args)) => cmd_bisect::run(args),
+        Some(Command::Query(args)) => cmd_query::run(args),
+        Some(Command::Watch(args)) => {
+            quench::interrupt::install();
+            cmd_watch::run(&cli, args)
+        }
+        Some(Command::Suppressions(args)) => cmd_suppressions::run(args),
         Some(Command::Completions(args)) => {
             let mut cmd = Cli::command();
             generate(args.shell, &mut cmd, "quench", &mut io::stdout());
@@ -106,11 +153,26 @@ fn print_custom_help(args: &[String]) {
                 print!("{}", format_help(subcmd));
             }
         }
+        Some("check-commit-msg") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("check-commit-msg") {
+                print!("{}", format_help(subcmd));
+            }
+        }
+        Some("fix") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("fix") {
+                print!("{}", format_help(subcmd));
+            }
+        }
         Some("cloc") => {
             if let Some(subcmd) = cmd.find_subcommand_mut("cloc") {
                 print!("{}", format_help(subcmd));
             }
         }
+        Some("loc") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("loc") {
+                print!("{}", format_help(subcmd));
+            }
+        }
         Some("report") => {
             if let Some(subcmd) = cmd.find_subcommand_mut("report") {
                 print!("{}", format_help(subcmd));
@@ -126,6 +188,86 @@ fn print_custom_help(args: &[String]) {
                 print!("{}", format_help(subcmd));
             }
         }
+        Some("ratchet") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("ratchet") {
+                // `quench ratchet refresh --help` shows the nested command's help
+                let nested = args.iter().skip(2).find(|arg| !arg.starts_with('-'));
+                match nested.and_then(|name| subcmd.find_subcommand_mut(name)) {
+                    Some(nested) => print!("{}", format_help(nested)),
+                    None => print!("{}", format_help(subcmd)),
+                }
+            }
+        }
+        Some("baseline") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("baseline") {
+                // `quench baseline import --help` shows the nested command's help
+                let nested = args.iter().skip(2).find(|arg| !arg.starts_with('-'));
+                match nested.and_then(|name| subcmd.find_subcommand_mut(name)) {
+                    Some(nested) => print!("{}", format_help(nested)),
+                    None => print!("{}", format_help(subcmd)),
+                }
+            }
+        }
+        Some("annotate") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("annotate") {
+                print!("{}", format_help(subcmd));
+            }
+        }
+        Some("suggest-tests") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("suggest-tests") {
+                print!("{}", format_help(subcmd));
+            }
+        }
+        Some("graph") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("graph") {
+                print!("{}", format_help(subcmd));
+            }
+        }
+        Some("sbom") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("sbom") {
+                print!("{}", format_help(subcmd));
+            }
+        }
+        Some("doctor") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("doctor") {
+                print!("{}", format_help(subcmd));
+            }
+        }
+        Some("explain") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("explain") {
+                print!("{}", format_help(subcmd));
+            }
+        }
+        Some("bot") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("bot") {
+                print!("{}", format_help(subcmd));
+            }
+        }
+        Some("bisect") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("bisect") {
+                print!("{}", format_help(subcmd));
+            }
+        }
+        Some("query") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("query") {
+                print!("{}", format_help(subcmd));
+            }
+        }
+        Some("watch") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("watch") {
+                print!("{}", format_help(subcmd));
+            }
+        }
+        Some("suppressions") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("suppressions") {
+                // `quench suppressions list --help` shows the nested command's help
+                let nested = args.iter().skip(2).find(|arg| !arg.starts_with('-'));
+                match nested.and_then(|name| subcmd.find_subcommand_mut(name)) {
+                    Some(nested) => print!("{}", format_help(nested)),
+                    None => print!("{}", format_help(subcmd)),
+                }
+            }
+        }
         Some("completions") => {
             if let Some(subcmd) = cmd.find_subcommand_mut("completions") {
                 print!("{}", format_help(subcmd));
@@ -140,6 +282,16 @@ fn print_custom_help(args: &[String]) {
                         print!("{}", format_help(subcmd));
                     }
                 }
+                Some("fix") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("fix") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("loc") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("loc") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
                 Some("report") => {
                     if let Some(subcmd) = cmd.find_subcommand_mut("report") {
                         print!("{}", format_help(subcmd));
@@ -155,6 +307,71 @@ fn print_custom_help(args: &[String]) {
                         print!("{}", format_help(subcmd));
                     }
                 }
+                Some("ratchet") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("ratchet") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("baseline") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("baseline") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("annotate") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("annotate") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("suggest-tests") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("suggest-tests") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("graph") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("graph") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("sbom") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("sbom") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("doctor") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("doctor") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("explain") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("explain") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("bot") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("bot") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("bisect") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("bisect") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("query") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("query") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("watch") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("watch") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
+                Some("suppressions") => {
+                    if let Some(subcmd) = cmd.find_subcommand_mut("suppressions") {
+                        print!("{}", format_help(subcmd));
+                    }
+                }
                 Some("completions") => {
                     if let Some(subcmd) = cmd.find_subcommand_mut("completions") {
                         print!("{}", format_help(subcmd));
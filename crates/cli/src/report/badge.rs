@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! SVG health badge output.
+
+use crate::baseline::Baseline;
+use crate::cli::CheckFilter;
+use crate::health::HealthScore;
+
+use super::ReportFormatter;
+
+/// Health badge formatter, in the flat style of shields.io badges.
+#[derive(Default)]
+pub struct BadgeFormatter {
+    health: Option<HealthScore>,
+}
+
+impl BadgeFormatter {
+    /// Create a badge formatter for a health score.
+    pub fn new(health: Option<HealthScore>) -> Self {
+        Self { health }
+    }
+}
+
+const LABEL: &str = "health";
+
+/// Badge color per grade; grey when there's no score.
+fn color(grade: Option<char>) -> &'static str {
+    match grade {
+        Some('A') => "#4c1",
+        Some('B') => "#97ca00",
+        Some('C') => "#dfb317",
+        Some('D') => "#fe7d37",
+        Some(_) => "#e05d44",
+        None => "#9f9f9f",
+    }
+}
+
+/// Approximate rendered width of badge text (11px Verdana).
+fn text_width(text: &str) -> usize {
+    text.chars().count() * 7 + 10
+}
+
+/// Render a two-part badge: grey label, colored value.
+fn render(health: Option<&HealthScore>) -> String {
+    let value = health.map_or_else(
+        || "unknown".to_string(),
+        |h| format!("{} {}", h.score, h.grade),
+    );
+    let fill = color(health.map(|h| h.grade));
+    let label_width = text_width(LABEL);
+    let value_width = text_width(&value);
+    let width = label_width + value_width;
+    let label_x = label_width / 2;
+    let value_x = label_width + value_width / 2;
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{LABEL}: {value}">
+  <title>{LABEL}: {value}</title>
+  <linearGradient id="s" x2="0" y2="100%">
+    <stop offset="0" stop-color="#bbb" stop-opacity=".1"/>
+    <stop offset="1" stop-opacity=".1"/>
+  </linearGradient>
+  <clipPath id="r"><rect width="{width}" height="20" rx="3" fill="#fff"/></clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{label_width}" height="20" fill="#555"/>
+    <rect x="{label_width}" width="{value_width}" height="20" fill="{fill}"/>
+    <rect width="{width}" height="20" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="{label_x}" y="14">{LABEL}</text>
+    <text x="{value_x}" y="14">{value}</text>
+  </g>
+</svg>
+"##
+    )
+}
+
+impl ReportFormatter for BadgeFormatter {
+    fn format(&self, _baseline: &Baseline, _filter: &dyn CheckFilter) -> anyhow::Result<String> {
+        Ok(render(self.health.as_ref()))
+    }
+
+    fn format_to(
+        &self,
+        writer: &mut dyn std::io::Write,
+        _baseline: &Baseline,
+        _filter: &dyn CheckFilter,
+    ) -> anyhow::Result<()> {
+        writer.write_all(render(self.health.as_ref()).as_bytes())?;
+        Ok(())
+    }
+
+    fn format_empty(&self) -> String {
+        render(None)
+    }
+}
+
+#[cfg(test)]
+#[path = "badge_tests.rs"]
+mod tests;
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::report::test_support::{AllChecks, assert_buffered_matches_streamed};
+
+fn score(score: u32) -> HealthScore {
+    HealthScore {
+        score,
+        grade: crate::health::grade(score),
+        metrics: Default::default(),
+        packages: Default::default(),
+    }
+}
+
+#[test]
+fn badge_shows_score_and_grade() {
+    let formatter = BadgeFormatter::new(Some(score(87)));
+    let output = formatter.format(&Baseline::default(), &AllChecks).unwrap();
+    assert!(output.starts_with("<svg"));
+    assert!(output.contains(">health</text>"));
+    assert!(output.contains(">87 B</text>"));
+    assert!(output.contains(r##"fill="#97ca00""##));
+}
+
+#[test]
+fn badge_color_follows_grade() {
+    let failing = BadgeFormatter::new(Some(score(42)))
+        .format(&Baseline::default(), &AllChecks)
+        .unwrap();
+    assert!(failing.contains(r##"fill="#e05d44""##));
+
+    let top = BadgeFormatter::new(Some(score(95)))
+        .format(&Baseline::default(), &AllChecks)
+        .unwrap();
+    assert!(top.contains(r##"fill="#4c1""##));
+}
+
+#[test]
+fn badge_without_score_is_unknown() {
+    let formatter = BadgeFormatter::default();
+    assert!(formatter.format_empty().contains(">unknown</text>"));
+    let output = formatter.format(&Baseline::default(), &AllChecks).unwrap();
+    assert!(output.contains(r##"fill="#9f9f9f""##));
+}
+
+#[test]
+fn badge_buffered_matches_streamed() {
+    let formatter = BadgeFormatter::new(Some(score(73)));
+    assert_buffered_matches_streamed(&formatter, &Baseline::default(), &AllChecks);
+}
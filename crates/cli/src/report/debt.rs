@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Debt report output for `quench report --debt`.
+//!
+//! A ranked table of the files with the most technical debt, worst first.
+
+use std::fmt::Write;
+
+use serde_json::json;
+
+use crate::cli::OutputFormat;
+use crate::debt::FileDebt;
+
+use super::html::{CSS, escape};
+
+/// Format a debt ranking in the given output format.
+pub fn format(format: OutputFormat, files: &[FileDebt], compact: bool) -> anyhow::Result<String> {
+    match format {
+        OutputFormat::Text => Ok(text(files)),
+        OutputFormat::Markdown => Ok(markdown(files)),
+        OutputFormat::Html => Ok(html(files)),
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            let value = json!({ "files": files });
+            Ok(if compact {
+                serde_json::to_string(&value)?
+            } else {
+                serde_json::to_string_pretty(&value)?
+            })
+        }
+        OutputFormat::Svg => anyhow::bail!("--debt does not support SVG output"),
+    }
+}
+
+fn tests(file: &FileDebt) -> &'static str {
+    if file.has_test { "yes" } else { "no" }
+}
+
+fn coverage(file: &FileDebt) -> String {
+    file.coverage
+        .map_or_else(|| "-".to_string(), |pct| format!("{:.1}%", pct))
+}
+
+fn text(files: &[FileDebt]) -> String {
+    let mut out = String::from("Debt report\n");
+    if files.is_empty() {
+        out.push_str("No files with debt.\n");
+        return out;
+    }
+    for (rank, file) in files.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "{:>3}. {:>5.1}  {}  ({} lines, {} escapes, tests: {}, coverage: {}, churn: {})",
+            rank + 1,
+            file.score,
+            file.path,
+            file.lines,
+            file.escapes,
+            tests(file),
+            coverage(file),
+            file.churn
+        );
+    }
+    out
+}
+
+fn markdown(files: &[FileDebt]) -> String {
+    let mut out = String::from("# Debt Report\n\n");
+    if files.is_empty() {
+        out.push_str("No files with debt.\n");
+        return out;
+    }
+    out.push_str("| # | File | Score | Lines | Escapes | Tests | Coverage | Churn |\n");
+    out.push_str("|--:|------|------:|------:|--------:|-------|---------:|------:|\n");
+    for (rank, file) in files.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "| {} | `{}` | {:.1} | {} | {} | {} | {} | {} |",
+            rank + 1,
+            file.path,
+            file.score,
+            file.lines,
+            file.escapes,
+            tests(file),
+            coverage(file),
+            file.churn
+        );
+    }
+    out
+}
+
+fn html(files: &[FileDebt]) -> String {
+    let rows: Vec<Vec<String>> = files
+        .iter()
+        .enumerate()
+        .map(|(rank, file)| {
+            vec![
+                (rank + 1).to_string(),
+                escape(&file.path),
+                file.lines.to_string(),
+                file.escapes.to_string(),
+                tests(file).to_string(),
+                coverage(file),
+                file.churn.to_string(),
+                format!("{:.1}", file.score),
+            ]
+        })
+        .collect();
+    html_page(
+        "Quench Debt Report",
+        &format!("{} files ranked by debt score", files.len()),
+        &[
+            "#", "File", "Lines", "Escapes", "Tests", "Coverage", "Churn", "Score",
+        ],
+        &rows,
+    )
+}
+
+/// A standalone HTML page with one table, styled like the metrics report.
+/// Cells are written as given, so callers escape text.
+pub(super) fn html_page(title: &str, meta: &str, headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut out = format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="UTF-8">
+  <meta name="viewport" content="width=device-width, initial-scale=1.0">
+  <title>{title}</title>
+  <style>
+    {CSS}
+  </style>
+</head>
+<body>
+  <div class="container">
+    <header>
+      <h1>{title}</h1>
+      <div class="meta">{meta}</div>
+    </header>
+    <section>
+      <table>
+        <thead>
+          <tr>"#
+    );
+    for header in headers {
+        let _ = write!(out, "<th>{}</th>", header);
+    }
+    out.push_str(
+        r#"</tr>
+        </thead>
+        <tbody>
+"#,
+    );
+    for row in rows {
+        out.push_str("          <tr>");
+        for cell in row {
+            let _ = write!(out, "<td>{}</td>", cell);
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str(
+        r#"        </tbody>
+      </table>
+    </section>
+  </div>
+</body>
+</html>"#,
+    );
+    out
+}
+
+#[cfg(test)]
+#[path = "debt_tests.rs"]
+mod tests;
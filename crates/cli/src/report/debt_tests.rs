@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::BTreeMap;
+
+use super::*;
+
+fn debt(path: &str, score: f64) -> FileDebt {
+    FileDebt {
+        path: path.to_string(),
+        score,
+        lines: 900,
+        escapes: 3,
+        has_test: false,
+        coverage: Some(42.5),
+        churn: 7,
+        factors: BTreeMap::from([("tests", 1.0)]),
+    }
+}
+
+#[test]
+fn text_ranks_files() {
+    let output = format(
+        OutputFormat::Text,
+        &[debt("src/a.rs", 62.0), debt("src/b.rs", 20.0)],
+        false,
+    )
+    .unwrap();
+    assert!(output.starts_with("Debt report\n"));
+    assert!(output.contains(
+        "  1.  62.0  src/a.rs  (900 lines, 3 escapes, tests: no, coverage: 42.5%, churn: 7)"
+    ));
+    assert!(output.contains("  2.  20.0  src/b.rs"));
+}
+
+#[test]
+fn markdown_table_has_row_per_file() {
+    let output = format(OutputFormat::Markdown, &[debt("src/a.rs", 62.0)], false).unwrap();
+    assert!(output.starts_with("# Debt Report\n"));
+    assert!(output.contains("| # | File | Score | Lines | Escapes | Tests | Coverage | Churn |"));
+    assert!(output.contains("| 1 | `src/a.rs` | 62.0 | 900 | 3 | no | 42.5% | 7 |"));
+}
+
+#[test]
+fn html_escapes_paths() {
+    let output = format(OutputFormat::Html, &[debt("src/<a>.rs", 62.0)], false).unwrap();
+    assert!(output.contains("<title>Quench Debt Report</title>"));
+    assert!(output.contains("<td>src/&lt;a&gt;.rs</td>"));
+}
+
+#[test]
+fn json_lists_files_with_factors() {
+    let output = format(OutputFormat::Json, &[debt("src/a.rs", 62.0)], true).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(value["files"][0]["path"], "src/a.rs");
+    assert_eq!(value["files"][0]["factors"]["tests"], 1.0);
+    assert!(!output.contains('\n'));
+}
+
+#[test]
+fn empty_ranking_says_so() {
+    let output = format(OutputFormat::Markdown, &[], false).unwrap();
+    assert!(output.contains("No files with debt."));
+}
+
+#[test]
+fn svg_is_unsupported() {
+    assert!(format(OutputFormat::Svg, &[], false).is_err());
+}
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Hotspot report output for `quench report --hotspots`.
+//!
+//! Files that are both large and changed often, hottest first.
+
+use std::fmt::Write;
+
+use serde_json::json;
+
+use crate::cli::OutputFormat;
+use crate::config::HotspotsConfig;
+use crate::hotspots::Hotspot;
+
+use super::debt::html_page;
+use super::html::escape;
+
+/// Format hotspots in the given output format.
+pub fn format(
+    format: OutputFormat,
+    hotspots: &[Hotspot],
+    config: &HotspotsConfig,
+    compact: bool,
+) -> anyhow::Result<String> {
+    match format {
+        OutputFormat::Text => Ok(text(hotspots, config)),
+        OutputFormat::Markdown => Ok(markdown(hotspots, config)),
+        OutputFormat::Html => Ok(html(hotspots, config)),
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            let value = json!({ "window": config.window, "hotspots": hotspots });
+            Ok(if compact {
+                serde_json::to_string(&value)?
+            } else {
+                serde_json::to_string_pretty(&value)?
+            })
+        }
+        OutputFormat::Svg => anyhow::bail!("--hotspots does not support SVG output"),
+    }
+}
+
+/// `Files with 10+ commits in 90 days and 400+ lines`.
+fn criteria(config: &HotspotsConfig) -> String {
+    format!(
+        "Files with {}+ commits in {} days and {}+ lines",
+        config.churn, config.window, config.lines
+    )
+}
+
+fn text(hotspots: &[Hotspot], config: &HotspotsConfig) -> String {
+    let mut out = format!("Hotspots: {}\n", criteria(config).to_lowercase());
+    if hotspots.is_empty() {
+        out.push_str("No hotspots.\n");
+        return out;
+    }
+    for (rank, hotspot) in hotspots.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "{:>3}. {:>5.1}  {}  ({} commits, {} lines)",
+            rank + 1,
+            hotspot.score,
+            hotspot.path,
+            hotspot.churn,
+            hotspot.lines
+        );
+    }
+    out
+}
+
+fn markdown(hotspots: &[Hotspot], config: &HotspotsConfig) -> String {
+    let mut out = format!("# Hotspots\n\n{}.\n\n", criteria(config));
+    if hotspots.is_empty() {
+        out.push_str("No hotspots.\n");
+        return out;
+    }
+    out.push_str("| # | File | Commits | Lines | Score |\n");
+    out.push_str("|--:|------|--------:|------:|------:|\n");
+    for (rank, hotspot) in hotspots.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "| {} | `{}` | {} | {} | {:.1} |",
+            rank + 1,
+            hotspot.path,
+            hotspot.churn,
+            hotspot.lines,
+            hotspot.score
+        );
+    }
+    out
+}
+
+fn html(hotspots: &[Hotspot], config: &HotspotsConfig) -> String {
+    let rows: Vec<Vec<String>> = hotspots
+        .iter()
+        .enumerate()
+        .map(|(rank, hotspot)| {
+            vec![
+                (rank + 1).to_string(),
+                escape(&hotspot.path),
+                hotspot.churn.to_string(),
+                hotspot.lines.to_string(),
+                format!("{:.1}", hotspot.score),
+            ]
+        })
+        .collect();
+    html_page(
+        "Quench Hotspots",
+        &criteria(config),
+        &["#", "File", "Commits", "Lines", "Score"],
+        &rows,
+    )
+}
+
+#[cfg(test)]
+#[path = "hotspots_tests.rs"]
+mod tests;
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn hotspot(path: &str, churn: usize, lines: usize, score: f64) -> Hotspot {
+    Hotspot {
+        path: path.to_string(),
+        churn,
+        lines,
+        score,
+    }
+}
+
+#[test]
+fn text_ranks_hotspots_with_criteria() {
+    let output = format(
+        OutputFormat::Text,
+        &[hotspot("src/a.rs", 30, 1200, 9.0)],
+        &HotspotsConfig::default(),
+        false,
+    )
+    .unwrap();
+    assert!(output.starts_with("Hotspots: files with 10+ commits in 90 days and 400+ lines\n"));
+    assert!(output.contains("  1.   9.0  src/a.rs  (30 commits, 1200 lines)"));
+}
+
+#[test]
+fn markdown_table_has_row_per_hotspot() {
+    let output = format(
+        OutputFormat::Markdown,
+        &[hotspot("src/a.rs", 30, 1200, 9.0)],
+        &HotspotsConfig::default(),
+        false,
+    )
+    .unwrap();
+    assert!(output.starts_with("# Hotspots\n"));
+    assert!(output.contains("| 1 | `src/a.rs` | 30 | 1200 | 9.0 |"));
+}
+
+#[test]
+fn html_escapes_paths() {
+    let output = format(
+        OutputFormat::Html,
+        &[hotspot("src/<a>.rs", 30, 1200, 9.0)],
+        &HotspotsConfig::default(),
+        false,
+    )
+    .unwrap();
+    assert!(output.contains("<title>Quench Hotspots</title>"));
+    assert!(output.contains("<td>src/&lt;a&gt;.rs</td>"));
+}
+
+#[test]
+fn json_includes_window() {
+    let output = format(OutputFormat::Json, &[], &HotspotsConfig::default(), true).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(value["window"], 90);
+    assert_eq!(value["hotspots"], serde_json::json!([]));
+}
+
+#[test]
+fn no_hotspots_says_so() {
+    let output = format(OutputFormat::Text, &[], &HotspotsConfig::default(), false).unwrap();
+    assert!(output.contains("No hotspots."));
+}
@@ -6,10 +6,20 @@
 use crate::baseline::Baseline;
 use crate::cli::CheckFilter;
 
-use super::{FilteredMetrics, ReportFormatter, human_bytes};
+use super::{FilteredMetrics, Insights, ReportFormatter, goal_value, human_bytes, slow_test_time};
 
 /// HTML format report formatter.
-pub struct HtmlFormatter;
+#[derive(Default)]
+pub struct HtmlFormatter {
+    insights: Insights,
+}
+
+impl HtmlFormatter {
+    /// Create an HTML formatter that shows the health score and goals.
+    pub fn new(insights: Insights) -> Self {
+        Self { insights }
+    }
+}
 
 /// Size estimation constants for pre-allocation.
 const HTML_BASE_SIZE: usize = 1500; // Template + CSS
@@ -17,7 +27,7 @@ const HTML_CARD_SIZE: usize = 200;
 const HTML_ROW_SIZE: usize = 80;
 
 /// CSS styles for the report.
-const CSS: &str = r#":root {
+pub(super) const CSS: &str = r#":root {
       --bg: #1a1a2e;
       --card-bg: #16213e;
       --text: #eef;
@@ -52,7 +62,9 @@ const CSS: &str = r#":root {
       border-radius: 8px;
       border-left: 4px solid var(--accent);
     }
+    .card.health { border-color: #f472b6; }
     .card.escapes { border-color: #f59e0b; }
+    .card.agents { border-color: #38bdf8; }
     .card.build { border-color: #8b5cf6; }
     .card.tests { border-color: #10b981; }
     .card-title { color: var(--muted); font-size: 0.75rem; text-transform: uppercase; }
@@ -67,7 +79,12 @@ const CSS: &str = r#":root {
     th, td { padding: 0.75rem 1rem; text-align: left; }
     th { background: rgba(0,0,0,0.2); color: var(--muted); font-size: 0.75rem; text-transform: uppercase; }
     tr:not(:last-child) td { border-bottom: 1px solid var(--bg); }
-    td:last-child { text-align: right; font-family: monospace; }"#;
+    td:last-child { text-align: right; font-family: monospace; }
+    td.regressed, li.regressed { color: #f87171; }
+    td.improved { color: #34d399; }
+    .changes { margin-top: 2rem; }
+    .changes h2 { color: var(--accent); font-size: 1.125rem; margin-bottom: 0.5rem; }
+    .changes ul { list-style: none; }"#;
 
 /// Write a metric card inline.
 macro_rules! write_card {
@@ -94,12 +111,41 @@ macro_rules! write_row {
     };
 }
 
+/// Write a table row, marking the value cell if the metric regressed or
+/// improved since the compared baseline.
+macro_rules! write_trend_row {
+    ($writer:expr, $filtered:expr, $metric:expr, $value:expr) => {
+        let metric = $metric;
+        match $filtered.trend(&metric) {
+            Some(trend) => {
+                writeln!(
+                    $writer,
+                    r#"        <tr><td>{}</td><td class="{}">{}</td></tr>"#,
+                    escape(&metric),
+                    trend.as_str(),
+                    $value
+                )?;
+            }
+            None => {
+                write_row!($writer, escape(&metric), $value);
+            }
+        }
+    };
+}
+
 /// Write HTML report content. This macro handles the common formatting logic
 /// for both fmt::Write (String) and io::Write (stdout, files).
 macro_rules! write_html_report {
     ($writer:expr, $baseline:expr, $filtered:expr) => {{
         let commit = $baseline.commit.as_deref().unwrap_or("unknown");
         let date = $baseline.updated.format("%Y-%m-%d %H:%M UTC");
+        let compared = match $filtered.compared() {
+            Some(earlier) => format!(
+                " &middot; Compared to: {}",
+                earlier.commit.as_deref().unwrap_or("unknown")
+            ),
+            None => String::new(),
+        };
 
         // Write document header
         write!(
@@ -118,13 +164,17 @@ macro_rules! write_html_report {
   <div class="container">
     <header>
       <h1>Quench Report</h1>
-      <div class="meta">Baseline: {commit} &middot; {date}</div>
+      <div class="meta">Baseline: {commit} &middot; {date}{compared}</div>
     </header>
     <section class="cards">
 "#
         )?;
 
         // Write cards section
+        if let Some(health) = $filtered.health() {
+            write_card!($writer, "Health", health.summary(), "health");
+        }
+
         if let Some(coverage) = $filtered.coverage() {
             write_card!(
                 $writer,
@@ -140,6 +190,10 @@ macro_rules! write_html_report {
             }
         }
 
+        if let Some(agents) = $filtered.agents() {
+            write_card!($writer, "Agent Tokens", agents.total_tokens, "agents");
+        }
+
         if let Some(build) = $filtered.build_time() {
             write_card!(
                 $writer,
@@ -187,13 +241,31 @@ macro_rules! write_html_report {
         )?;
 
         // Write table rows
+        if let Some(health) = $filtered.health() {
+            write_trend_row!($writer, $filtered, "health".to_string(), health.summary());
+            for (name, package) in $filtered.health_packages() {
+                write_trend_row!(
+                    $writer,
+                    $filtered,
+                    format!("health.{}", name),
+                    package.summary()
+                );
+            }
+        }
+
         if let Some(coverage) = $filtered.coverage() {
-            write_row!($writer, "coverage", format!("{:.1}%", coverage.total));
+            write_trend_row!(
+                $writer,
+                $filtered,
+                "coverage".to_string(),
+                format!("{:.1}%", coverage.total)
+            );
 
             if let Some(packages) = $filtered.sorted_package_coverage() {
                 for (name, pct) in packages {
-                    write_row!(
+                    write_trend_row!(
                         $writer,
+                        $filtered,
                         format!("coverage.{}", name),
                         format!("{:.1}%", pct)
                     );
@@ -203,49 +275,169 @@ macro_rules! write_html_report {
 
         if let Some(items) = $filtered.sorted_escapes() {
             for (name, count) in items {
-                write_row!($writer, format!("escapes.{}", name), count);
+                write_trend_row!($writer, $filtered, format!("escapes.{}", name), count);
             }
         }
 
         if let Some(items) = $filtered.sorted_test_escapes() {
             for (name, count) in items {
-                write_row!($writer, format!("escapes.test.{}", name), count);
+                write_trend_row!($writer, $filtered, format!("escapes.test.{}", name), count);
+            }
+        }
+
+        if let Some(agents) = $filtered.agents() {
+            write_trend_row!(
+                $writer,
+                $filtered,
+                "agents.total_tokens".to_string(),
+                agents.total_tokens
+            );
+        }
+
+        if let Some(files) = $filtered.sorted_agent_tokens() {
+            for (name, tokens, share) in files {
+                write_trend_row!(
+                    $writer,
+                    $filtered,
+                    format!("agents.files.{}", name),
+                    format!("{} ({:.0}%)", tokens, share)
+                );
             }
         }
 
         if let Some(build) = $filtered.build_time() {
-            write_row!($writer, "build_time.cold", format!("{:.1}s", build.cold));
-            write_row!($writer, "build_time.hot", format!("{:.1}s", build.hot));
+            write_trend_row!(
+                $writer,
+                $filtered,
+                "build_time.cold".to_string(),
+                format!("{:.1}s", build.cold)
+            );
+            write_trend_row!(
+                $writer,
+                $filtered,
+                "build_time.hot".to_string(),
+                format!("{:.1}s", build.hot)
+            );
         }
 
         if let Some(items) = $filtered.sorted_binary_sizes() {
             for (name, size) in items {
-                write_row!($writer, format!("binary_size.{}", name), human_bytes(size));
+                write_trend_row!(
+                    $writer,
+                    $filtered,
+                    format!("binary_size.{}", name),
+                    human_bytes(size)
+                );
             }
         }
 
         if let Some(tests) = $filtered.test_time() {
-            write_row!($writer, "test_time.total", format!("{:.1}s", tests.total));
+            write_trend_row!(
+                $writer,
+                $filtered,
+                "test_time.total".to_string(),
+                format!("{:.1}s", tests.total)
+            );
+        }
+
+        for goal in $filtered.goals() {
+            write_row!(
+                $writer,
+                escape(&format!("goal.{}", goal.metric)),
+                format!(
+                    r#"{} &rarr; {} <progress value="{:.0}" max="100"></progress> {:.0}% ({})"#,
+                    goal_value(goal, goal.current),
+                    goal_value(goal, goal.target),
+                    goal.progress,
+                    goal.progress,
+                    goal.eta()
+                )
+            );
+        }
+
+        if let Some(suppressions) = $filtered.suppressions() {
+            write_row!($writer, "suppressions", suppressions.summary());
+            for (kind, count) in &suppressions.by_kind {
+                write_row!($writer, format!("suppressions.{}", kind), count);
+            }
+        }
+
+        if let Some(suites) = $filtered.slow_tests() {
+            for (suite, tests) in suites {
+                for test in tests {
+                    write_row!(
+                        $writer,
+                        escape(&format!("slow_tests.{}.{}", suite, test.name)),
+                        slow_test_time(test)
+                    );
+                }
+            }
         }
 
-        // Write document footer
         write!(
             $writer,
             r#"        </tbody>
       </table>
     </section>
-  </div>
+"#
+        )?;
+
+        // Write what changed since the compared baseline
+        if let (Some(earlier), Some(changes)) = ($filtered.compared(), $filtered.changes()) {
+            writeln!(
+                $writer,
+                r#"    <section class="changes">
+      <h2>Changes since {} &middot; {}</h2>"#,
+                earlier.commit.as_deref().unwrap_or("unknown"),
+                earlier.updated.format("%Y-%m-%d")
+            )?;
+            if changes.is_empty() {
+                writeln!($writer, r#"      <p class="meta">No new violations.</p>"#)?;
+            } else {
+                writeln!($writer, "      <ul>")?;
+                for (metric, count) in &changes.new_violations {
+                    writeln!(
+                        $writer,
+                        r#"        <li class="regressed">New violation: {} ({})</li>"#,
+                        escape(metric),
+                        count
+                    )?;
+                }
+                for (file, tokens) in &changes.over_limit {
+                    writeln!(
+                        $writer,
+                        r#"        <li class="regressed">Over limit: {} ({} tokens)</li>"#,
+                        escape(file),
+                        tokens
+                    )?;
+                }
+                writeln!($writer, "      </ul>")?;
+            }
+            writeln!($writer, "    </section>")?;
+        }
+
+        // Write document footer
+        write!(
+            $writer,
+            r#"  </div>
 </body>
 </html>"#
         )?;
     }};
 }
 
+/// Escape text (e.g., test names) for HTML element content.
+pub(super) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 impl ReportFormatter for HtmlFormatter {
     fn format(&self, baseline: &Baseline, filter: &dyn CheckFilter) -> anyhow::Result<String> {
         use std::fmt::Write;
 
-        let filtered = FilteredMetrics::new(baseline, filter);
+        let filtered = FilteredMetrics::new(baseline, filter).with_insights(&self.insights);
         let capacity = HTML_BASE_SIZE + filtered.count() * (HTML_CARD_SIZE + HTML_ROW_SIZE);
         let mut output = String::with_capacity(capacity);
         write_html_report!(&mut output, baseline, &filtered);
@@ -258,7 +450,7 @@ impl ReportFormatter for HtmlFormatter {
         baseline: &Baseline,
         filter: &dyn CheckFilter,
     ) -> anyhow::Result<()> {
-        let filtered = FilteredMetrics::new(baseline, filter);
+        let filtered = FilteredMetrics::new(baseline, filter).with_insights(&self.insights);
         write_html_report!(writer, baseline, &filtered);
         Ok(())
     }
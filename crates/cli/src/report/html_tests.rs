@@ -3,12 +3,13 @@
 
 use super::*;
 use crate::report::test_support::{
-    AllChecks, assert_buffered_matches_streamed, create_test_baseline,
+    AllChecks, assert_buffered_matches_streamed, create_agents_baseline,
+    create_slow_tests_baseline, create_test_baseline, create_test_comparison, create_test_insights,
 };
 
 #[test]
 fn html_format_empty_baseline() {
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     let empty = formatter.format_empty();
     assert!(empty.contains("<!DOCTYPE html>"));
     assert!(empty.contains("No baseline found"));
@@ -17,7 +18,7 @@ fn html_format_empty_baseline() {
 #[test]
 fn html_format_includes_doctype() {
     let baseline = create_test_baseline();
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.starts_with("<!DOCTYPE html>"));
 }
@@ -25,7 +26,7 @@ fn html_format_includes_doctype() {
 #[test]
 fn html_format_includes_title() {
     let baseline = create_test_baseline();
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("<title>Quench Report</title>"));
 }
@@ -33,7 +34,7 @@ fn html_format_includes_title() {
 #[test]
 fn html_format_includes_css() {
     let baseline = create_test_baseline();
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("<style>"));
     assert!(output.contains("</style>"));
@@ -42,7 +43,7 @@ fn html_format_includes_css() {
 #[test]
 fn html_format_includes_header() {
     let baseline = create_test_baseline();
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("<h1>Quench Report</h1>"));
 }
@@ -50,7 +51,7 @@ fn html_format_includes_header() {
 #[test]
 fn html_format_includes_commit() {
     let baseline = create_test_baseline();
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("abc1234"));
 }
@@ -58,7 +59,7 @@ fn html_format_includes_commit() {
 #[test]
 fn html_format_includes_coverage_card() {
     let baseline = create_test_baseline();
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("Coverage"));
     assert!(output.contains("85.5%"));
@@ -67,7 +68,7 @@ fn html_format_includes_coverage_card() {
 #[test]
 fn html_format_includes_escapes_card() {
     let baseline = create_test_baseline();
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("Escapes"));
     assert!(output.contains("10"));
@@ -76,7 +77,7 @@ fn html_format_includes_escapes_card() {
 #[test]
 fn html_format_includes_table() {
     let baseline = create_test_baseline();
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("<table>"));
     assert!(output.contains("<thead>"));
@@ -86,7 +87,7 @@ fn html_format_includes_table() {
 #[test]
 fn html_format_includes_build_metrics() {
     let baseline = create_test_baseline();
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("build_time.cold"));
     assert!(output.contains("45.0s"));
@@ -97,7 +98,7 @@ fn html_format_includes_build_metrics() {
 #[test]
 fn html_format_includes_binary_size() {
     let baseline = create_test_baseline();
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("binary_size.quench"));
     assert!(output.contains("5.0 MB"));
@@ -106,13 +107,13 @@ fn html_format_includes_binary_size() {
 #[test]
 fn html_format_to_matches_format() {
     let baseline = create_test_baseline();
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     assert_buffered_matches_streamed(&formatter, &baseline, &AllChecks);
 }
 
 #[test]
 fn html_format_empty_to_matches_format_empty() {
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
 
     let buffered = formatter.format_empty();
 
@@ -126,7 +127,7 @@ fn html_format_empty_to_matches_format_empty() {
 #[test]
 fn html_format_closes_all_tags() {
     let baseline = create_test_baseline();
-    let formatter = HtmlFormatter;
+    let formatter = HtmlFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
 
     // Verify key closing tags are present
@@ -135,3 +136,98 @@ fn html_format_closes_all_tags() {
     assert!(output.contains("</body>"));
     assert!(output.contains("</table>"));
 }
+
+#[test]
+fn html_format_escapes_slow_test_names() {
+    let mut baseline = create_slow_tests_baseline();
+    if let Some(suites) = baseline.metrics.slow_tests.as_mut() {
+        suites.get_mut("unit").unwrap()[0].name = "renders <App>".to_string();
+    }
+    let output = HtmlFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains("slow_tests.unit.renders &lt;App&gt;"));
+}
+
+#[test]
+fn html_format_includes_health_card() {
+    let baseline = create_test_baseline();
+    let output = HtmlFormatter::new(create_test_insights())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains(r#"<div class="card health">"#));
+    assert!(output.contains("<tr><td>health.core</td><td>92/100 (A)</td></tr>"));
+}
+
+#[test]
+fn html_format_includes_goal_progress() {
+    let baseline = create_test_baseline();
+    let output = HtmlFormatter::new(create_test_insights())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains(
+        r#"<tr><td>goal.coverage</td><td>85.5% &rarr; 90.0% <progress value="50" max="100"></progress> 50% (~9 days)</td></tr>"#
+    ));
+}
+
+#[test]
+fn html_format_includes_suppressions() {
+    let baseline = create_test_baseline();
+    let output = HtmlFormatter::new(create_test_insights())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains(
+        "<tr><td>suppressions</td><td>3 (1 expired, 0 expiring, oldest 120 days)</td></tr>"
+    ));
+    assert!(output.contains("<tr><td>suppressions.inline</td><td>2</td></tr>"));
+}
+
+#[test]
+fn html_format_includes_agent_tokens() {
+    let baseline = create_agents_baseline();
+    let output = HtmlFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains("Agent Tokens"));
+    assert!(output.contains("<td>agents.total_tokens</td><td>4000</td>"));
+    assert!(output.contains("<td>agents.files.CLAUDE.md</td><td>2500 (62%)</td>"));
+}
+
+#[test]
+fn html_format_marks_regressed_and_improved_cells() {
+    let baseline = create_test_baseline();
+    let output = HtmlFormatter::new(create_test_comparison())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains("Compared to: 0ff1ce0"));
+    assert!(output.contains(r#"<tr><td>coverage</td><td class="regressed">85.5%</td></tr>"#));
+    assert!(output.contains(r#"<tr><td>escapes.unwrap</td><td class="improved">10</td></tr>"#));
+    assert!(output.contains("<tr><td>build_time.cold</td><td>45.0s</td></tr>"));
+}
+
+#[test]
+fn html_format_lists_changes_since_comparison() {
+    let baseline = create_test_baseline();
+    let output = HtmlFormatter::new(create_test_comparison())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains("<h2>Changes since 0ff1ce0"));
+    assert!(output.contains(r#"<li class="regressed">New violation: escapes.expect (5)</li>"#));
+}
+
+#[test]
+fn html_format_omits_changes_without_comparison() {
+    let baseline = create_test_baseline();
+    let output = HtmlFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(!output.contains("Changes since"));
+    assert!(!output.contains(r#"class="regressed""#));
+}
+
+#[test]
+fn html_format_buffered_matches_streamed_with_comparison() {
+    let baseline = create_test_baseline();
+    let formatter = HtmlFormatter::new(create_test_comparison());
+    assert_buffered_matches_streamed(&formatter, &baseline, &AllChecks);
+}
@@ -7,11 +7,12 @@ use crate::baseline::Baseline;
 use crate::cli::CheckFilter;
 use serde_json::json;
 
-use super::{FilteredMetrics, ReportFormatter};
+use super::{FilteredMetrics, Insights, ReportFormatter};
 
 /// JSON format report formatter.
 pub struct JsonFormatter {
     compact: bool,
+    insights: Insights,
 }
 
 impl JsonFormatter {
@@ -19,12 +20,21 @@ impl JsonFormatter {
     ///
     /// If `compact` is true, outputs single-line JSON without whitespace.
     pub fn new(compact: bool) -> Self {
-        Self { compact }
+        Self {
+            compact,
+            insights: Insights::default(),
+        }
+    }
+
+    /// Include the health score and goal progress in the output.
+    pub fn with_insights(mut self, insights: Insights) -> Self {
+        self.insights = insights;
+        self
     }
 
     /// Build the JSON value from baseline and filter.
     fn build_json(&self, baseline: &Baseline, filter: &dyn CheckFilter) -> serde_json::Value {
-        let filtered = FilteredMetrics::new(baseline, filter);
+        let filtered = FilteredMetrics::new(baseline, filter).with_insights(&self.insights);
 
         let mut output = serde_json::Map::new();
 
@@ -33,6 +43,23 @@ impl JsonFormatter {
         if let Some(ref commit) = baseline.commit {
             output.insert("commit".to_string(), json!(commit));
         }
+        if !baseline.tags.is_empty() {
+            output.insert("tags".to_string(), json!(baseline.tags));
+        }
+        if let Some(health) = filtered.health() {
+            let mut health = json!(health);
+            if let Some(packages) = health.get_mut("packages").and_then(|p| p.as_object_mut()) {
+                packages.retain(|name, _| filter.should_include_package(name));
+            }
+            output.insert("health".to_string(), health);
+        }
+        let goals = filtered.goals();
+        if !goals.is_empty() {
+            output.insert("goals".to_string(), json!(goals));
+        }
+        if let Some(suppressions) = filtered.suppressions() {
+            output.insert("suppressions".to_string(), json!(suppressions));
+        }
 
         // Filtered metrics
         let mut metrics = serde_json::Map::new();
@@ -41,8 +68,22 @@ impl JsonFormatter {
             metrics.insert("coverage".to_string(), json!({ "total": coverage.total }));
         }
 
-        if let Some(escapes) = filtered.escapes() {
-            metrics.insert("escapes".to_string(), json!({ "source": escapes.source }));
+        if let Some(escapes) = filtered.sorted_escapes() {
+            let source: serde_json::Map<_, _> = escapes
+                .into_iter()
+                .map(|(name, count)| (name.to_string(), json!(count)))
+                .collect();
+            metrics.insert("escapes".to_string(), json!({ "source": source }));
+        }
+
+        if let Some(agents) = filtered.agents() {
+            metrics.insert(
+                "agents".to_string(),
+                json!({
+                    "total_tokens": agents.total_tokens,
+                    "files": agents.files,
+                }),
+            );
         }
 
         if let Some(build) = filtered.build_time() {
@@ -70,6 +111,10 @@ impl JsonFormatter {
             );
         }
 
+        if let Some(slow) = filtered.slow_tests() {
+            metrics.insert("slow_tests".to_string(), json!(slow));
+        }
+
         output.insert("metrics".to_string(), serde_json::Value::Object(metrics));
 
         serde_json::Value::Object(output)
@@ -6,7 +6,8 @@
 use super::*;
 use crate::baseline::EscapesMetrics;
 use crate::report::test_support::{
-    AllChecks, ExcludeChecks, assert_buffered_matches_streamed, create_test_baseline,
+    AllChecks, ExcludeChecks, assert_buffered_matches_streamed, create_agents_baseline,
+    create_slow_tests_baseline, create_test_baseline, create_test_insights,
 };
 
 // =============================================================================
@@ -236,6 +237,8 @@ fn json_format_escapes_includes_all_patterns() {
         .into_iter()
         .collect(),
         test: None,
+        source_lines: None,
+        by_package: None,
     });
 
     let formatter = JsonFormatter::default();
@@ -247,3 +250,82 @@ fn json_format_escapes_includes_all_patterns() {
     assert_eq!(escapes["expect"], 5);
     assert_eq!(escapes["panic"], 2);
 }
+
+#[test]
+fn json_format_includes_slow_tests() {
+    let baseline = create_slow_tests_baseline();
+    let output = JsonFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let big = &json["metrics"]["slow_tests"]["unit"][0];
+    assert_eq!(big["name"], "tests::big");
+    assert_eq!(big["ms"], 2100);
+    assert_eq!(big["previous"], serde_json::json!([1200, 1400]));
+}
+
+#[test]
+fn json_format_includes_tags() {
+    let mut baseline = create_test_baseline();
+    baseline.tags.insert("env".to_string(), "ci".to_string());
+    let output = JsonFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["tags"], serde_json::json!({ "env": "ci" }));
+}
+
+#[test]
+fn json_format_includes_health() {
+    let baseline = create_test_baseline();
+    let output = JsonFormatter::default()
+        .with_insights(create_test_insights())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert!(json["health"]["score"].is_u64());
+    assert!(json["health"]["metrics"]["coverage"].is_f64());
+    assert_eq!(json["health"]["packages"]["core"]["grade"], "A");
+}
+
+#[test]
+fn json_format_includes_goals() {
+    let baseline = create_test_baseline();
+    let output = JsonFormatter::default()
+        .with_insights(create_test_insights())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    let goals = json["goals"].as_array().unwrap();
+    assert_eq!(goals[0]["metric"], "coverage");
+    assert_eq!(goals[0]["target"], 90.0);
+    assert_eq!(goals[0]["progress"], 50.0);
+    assert_eq!(goals[0]["eta_days"], 9);
+    assert_eq!(goals[1]["reached"], false);
+    assert!(goals[1].get("eta_days").is_none());
+}
+
+#[test]
+fn json_format_includes_suppressions() {
+    let baseline = create_test_baseline();
+    let output = JsonFormatter::default()
+        .with_insights(create_test_insights())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["suppressions"]["total"], 3);
+    assert_eq!(json["suppressions"]["by_kind"]["inline"], 2);
+    assert_eq!(json["suppressions"]["expired"], 1);
+    assert_eq!(json["suppressions"]["oldest_days"], 120);
+}
+
+#[test]
+fn json_format_includes_agent_tokens() {
+    let baseline = create_agents_baseline();
+    let output = JsonFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["metrics"]["agents"]["total_tokens"], 4000);
+    assert_eq!(json["metrics"]["agents"]["files"]["CLAUDE.md"], 2500);
+}
@@ -6,10 +6,32 @@
 use crate::baseline::Baseline;
 use crate::cli::CheckFilter;
 
-use super::{FilteredMetrics, ReportFormatter, human_bytes};
+use super::{
+    FilteredMetrics, Insights, ReportFormatter, Trend, goal_progress, goal_value, human_bytes,
+    slow_test_time,
+};
 
 /// Markdown format report formatter.
-pub struct MarkdownFormatter;
+#[derive(Default)]
+pub struct MarkdownFormatter {
+    insights: Insights,
+}
+
+impl MarkdownFormatter {
+    /// Create a Markdown formatter that shows the health score and goals.
+    pub fn new(insights: Insights) -> Self {
+        Self { insights }
+    }
+}
+
+/// Mark a cell whose metric moved since the compared baseline,
+/// e.g. `78.0% (regressed)`.
+fn marked(value: impl std::fmt::Display, trend: Option<Trend>) -> String {
+    match trend {
+        Some(trend) => format!("{} ({})", value, trend.as_str()),
+        None => value.to_string(),
+    }
+}
 
 /// Write markdown report content. This macro handles the common formatting logic
 /// for both fmt::Write (String) and io::Write (stdout, files).
@@ -21,46 +43,201 @@ macro_rules! write_markdown_report {
             let date = $baseline.updated.format("%Y-%m-%d");
             writeln!($writer, "**Baseline:** {} ({})\n", commit, date)?;
         }
+        if let Some(earlier) = $filtered.compared() {
+            writeln!(
+                $writer,
+                "**Compared to:** {} ({})\n",
+                earlier.commit.as_deref().unwrap_or("unknown"),
+                earlier.updated.format("%Y-%m-%d")
+            )?;
+        }
+        if let Some(health) = $filtered.health() {
+            let trend = $filtered.trend("health");
+            writeln!($writer, "**Health:** {}\n", marked(health.summary(), trend))?;
+        }
 
         // Summary table
         writeln!($writer, "| Metric | Value |")?;
         writeln!($writer, "|--------|------:|")?;
 
         if let Some(coverage) = $filtered.coverage() {
-            writeln!($writer, "| Coverage | {:.1}% |", coverage.total)?;
+            let value = format!("{:.1}%", coverage.total);
+            writeln!(
+                $writer,
+                "| Coverage | {} |",
+                marked(value, $filtered.trend("coverage"))
+            )?;
 
             if let Some(packages) = $filtered.sorted_package_coverage() {
                 for (name, pct) in packages {
-                    writeln!($writer, "| Coverage ({}) | {:.1}% |", name, pct)?;
+                    let trend = $filtered.trend(&format!("coverage.{}", name));
+                    let value = format!("{:.1}%", pct);
+                    writeln!(
+                        $writer,
+                        "| Coverage ({}) | {} |",
+                        name,
+                        marked(value, trend)
+                    )?;
                 }
             }
         }
 
         if let Some(items) = $filtered.sorted_escapes() {
             for (name, count) in items {
-                writeln!($writer, "| Escapes ({}) | {} |", name, count)?;
+                let trend = $filtered.trend(&format!("escapes.{}", name));
+                writeln!($writer, "| Escapes ({}) | {} |", name, marked(count, trend))?;
             }
         }
 
         // Test escapes (if present)
         if let Some(items) = $filtered.sorted_test_escapes() {
             for (name, count) in items {
-                writeln!($writer, "| Escapes test ({}) | {} |", name, count)?;
+                let trend = $filtered.trend(&format!("escapes.test.{}", name));
+                writeln!(
+                    $writer,
+                    "| Escapes test ({}) | {} |",
+                    name,
+                    marked(count, trend)
+                )?;
             }
         }
 
+        if let Some(agents) = $filtered.agents() {
+            let trend = $filtered.trend("agents.total_tokens");
+            writeln!(
+                $writer,
+                "| Agent tokens | {} |",
+                marked(agents.total_tokens, trend)
+            )?;
+        }
+
         if let Some(build) = $filtered.build_time() {
-            writeln!($writer, "| Build (cold) | {:.1}s |", build.cold)?;
-            writeln!($writer, "| Build (hot) | {:.1}s |", build.hot)?;
+            let cold = format!("{:.1}s", build.cold);
+            let hot = format!("{:.1}s", build.hot);
+            writeln!(
+                $writer,
+                "| Build (cold) | {} |",
+                marked(cold, $filtered.trend("build_time.cold"))
+            )?;
+            writeln!(
+                $writer,
+                "| Build (hot) | {} |",
+                marked(hot, $filtered.trend("build_time.hot"))
+            )?;
         }
 
         if let Some(tests) = $filtered.test_time() {
-            writeln!($writer, "| Test time | {:.1}s |", tests.total)?;
+            let value = format!("{:.1}s", tests.total);
+            writeln!(
+                $writer,
+                "| Test time | {} |",
+                marked(value, $filtered.trend("test_time.total"))
+            )?;
         }
 
         if let Some(items) = $filtered.sorted_binary_sizes() {
             for (name, size) in items {
-                writeln!($writer, "| Binary ({}) | {} |", name, human_bytes(size))?;
+                let trend = $filtered.trend(&format!("binary_size.{}", name));
+                writeln!(
+                    $writer,
+                    "| Binary ({}) | {} |",
+                    name,
+                    marked(human_bytes(size), trend)
+                )?;
+            }
+        }
+
+        let packages = $filtered.health_packages();
+        if !packages.is_empty() {
+            writeln!($writer, "\n## Package Health\n")?;
+            writeln!($writer, "| Package | Score | Grade |")?;
+            writeln!($writer, "|---------|------:|:-----:|")?;
+            for (name, package) in packages {
+                let trend = $filtered.trend(&format!("health.{}", name));
+                writeln!(
+                    $writer,
+                    "| {} | {} | {} |",
+                    name,
+                    marked(package.score, trend),
+                    package.grade
+                )?;
+            }
+        }
+
+        if let Some(files) = $filtered.sorted_agent_tokens().filter(|f| !f.is_empty()) {
+            writeln!($writer, "\n## Agent Context\n")?;
+            writeln!($writer, "| File | Tokens | Share |")?;
+            writeln!($writer, "|------|-------:|------:|")?;
+            for (name, tokens, share) in files {
+                let trend = $filtered.trend(&format!("agents.files.{}", name));
+                writeln!(
+                    $writer,
+                    "| `{}` | {} | {:.0}% |",
+                    name,
+                    marked(tokens, trend),
+                    share
+                )?;
+            }
+        }
+
+        let goals = $filtered.goals();
+        if !goals.is_empty() {
+            writeln!($writer, "\n## Goals\n")?;
+            writeln!($writer, "| Goal | Current | Target | Progress |")?;
+            writeln!($writer, "|------|--------:|-------:|----------|")?;
+            for goal in goals {
+                writeln!(
+                    $writer,
+                    "| {} | {} | {} | `{}` |",
+                    goal.metric,
+                    goal_value(goal, goal.current),
+                    goal_value(goal, goal.target),
+                    goal_progress(goal)
+                )?;
+            }
+        }
+
+        if let Some(suppressions) = $filtered.suppressions() {
+            writeln!($writer, "\n## Suppressions\n")?;
+            writeln!($writer, "{}\n", suppressions.summary())?;
+            writeln!($writer, "| Kind | Count |")?;
+            writeln!($writer, "|------|------:|")?;
+            for (kind, count) in &suppressions.by_kind {
+                writeln!($writer, "| {} | {} |", kind, count)?;
+            }
+        }
+
+        if let Some(suites) = $filtered.slow_tests() {
+            writeln!($writer, "\n## Slowest Tests\n")?;
+            writeln!($writer, "| Suite | Test | Time |")?;
+            writeln!($writer, "|-------|------|-----:|")?;
+            for (suite, tests) in suites {
+                for test in tests {
+                    writeln!(
+                        $writer,
+                        "| {} | `{}` | {} |",
+                        suite,
+                        test.name,
+                        slow_test_time(test)
+                    )?;
+                }
+            }
+        }
+
+        if let (Some(earlier), Some(changes)) = ($filtered.compared(), $filtered.changes()) {
+            writeln!(
+                $writer,
+                "\n## Changes Since {}\n",
+                earlier.commit.as_deref().unwrap_or("unknown")
+            )?;
+            if changes.is_empty() {
+                writeln!($writer, "No new violations.")?;
+            }
+            for (metric, count) in &changes.new_violations {
+                writeln!($writer, "- New violation: {} ({})", metric, count)?;
+            }
+            for (file, tokens) in &changes.over_limit {
+                writeln!($writer, "- Over limit: `{}` ({} tokens)", file, tokens)?;
             }
         }
     };
@@ -70,7 +247,7 @@ impl ReportFormatter for MarkdownFormatter {
     fn format(&self, baseline: &Baseline, filter: &dyn CheckFilter) -> anyhow::Result<String> {
         use std::fmt::Write;
 
-        let filtered = FilteredMetrics::new(baseline, filter);
+        let filtered = FilteredMetrics::new(baseline, filter).with_insights(&self.insights);
         let mut output = String::with_capacity(512);
         write_markdown_report!(&mut output, baseline, &filtered);
         Ok(output)
@@ -82,7 +259,7 @@ impl ReportFormatter for MarkdownFormatter {
         baseline: &Baseline,
         filter: &dyn CheckFilter,
     ) -> anyhow::Result<()> {
-        let filtered = FilteredMetrics::new(baseline, filter);
+        let filtered = FilteredMetrics::new(baseline, filter).with_insights(&self.insights);
         write_markdown_report!(writer, baseline, &filtered);
         Ok(())
     }
@@ -4,12 +4,13 @@
 use super::*;
 use crate::baseline::EscapesMetrics;
 use crate::report::test_support::{
-    AllChecks, assert_buffered_matches_streamed, create_test_baseline,
+    AllChecks, assert_buffered_matches_streamed, create_agents_baseline,
+    create_slow_tests_baseline, create_test_baseline, create_test_comparison, create_test_insights,
 };
 
 #[test]
 fn markdown_format_empty_baseline() {
-    let formatter = MarkdownFormatter;
+    let formatter = MarkdownFormatter::default();
     let empty = formatter.format_empty();
     assert!(empty.contains("# Quench Report"));
     assert!(empty.contains("No baseline found"));
@@ -18,7 +19,7 @@ fn markdown_format_empty_baseline() {
 #[test]
 fn markdown_format_includes_header() {
     let baseline = Baseline::default();
-    let formatter = MarkdownFormatter;
+    let formatter = MarkdownFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("# Quench Report"));
 }
@@ -26,7 +27,7 @@ fn markdown_format_includes_header() {
 #[test]
 fn markdown_format_produces_table() {
     let baseline = create_test_baseline();
-    let formatter = MarkdownFormatter;
+    let formatter = MarkdownFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("| Metric | Value |"));
     assert!(output.contains("|--------|------:|"));
@@ -35,7 +36,7 @@ fn markdown_format_produces_table() {
 #[test]
 fn markdown_format_includes_commit() {
     let baseline = create_test_baseline();
-    let formatter = MarkdownFormatter;
+    let formatter = MarkdownFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("**Baseline:** abc1234"));
 }
@@ -43,7 +44,7 @@ fn markdown_format_includes_commit() {
 #[test]
 fn markdown_format_includes_coverage() {
     let baseline = create_test_baseline();
-    let formatter = MarkdownFormatter;
+    let formatter = MarkdownFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("| Coverage | 85.5% |"));
 }
@@ -51,7 +52,7 @@ fn markdown_format_includes_coverage() {
 #[test]
 fn markdown_format_includes_escapes() {
     let baseline = create_test_baseline();
-    let formatter = MarkdownFormatter;
+    let formatter = MarkdownFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("| Escapes (unwrap) | 10 |"));
 }
@@ -59,7 +60,7 @@ fn markdown_format_includes_escapes() {
 #[test]
 fn markdown_format_includes_build_time() {
     let baseline = create_test_baseline();
-    let formatter = MarkdownFormatter;
+    let formatter = MarkdownFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("| Build (cold) | 45.0s |"));
     assert!(output.contains("| Build (hot) | 12.5s |"));
@@ -68,7 +69,7 @@ fn markdown_format_includes_build_time() {
 #[test]
 fn markdown_format_includes_binary_size() {
     let baseline = create_test_baseline();
-    let formatter = MarkdownFormatter;
+    let formatter = MarkdownFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("| Binary (quench) | 5.0 MB |"));
 }
@@ -76,7 +77,7 @@ fn markdown_format_includes_binary_size() {
 #[test]
 fn markdown_format_includes_test_time() {
     let baseline = create_test_baseline();
-    let formatter = MarkdownFormatter;
+    let formatter = MarkdownFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("| Test time | 30.5s |"));
 }
@@ -84,13 +85,13 @@ fn markdown_format_includes_test_time() {
 #[test]
 fn markdown_format_to_matches_format() {
     let baseline = create_test_baseline();
-    let formatter = MarkdownFormatter;
+    let formatter = MarkdownFormatter::default();
     assert_buffered_matches_streamed(&formatter, &baseline, &AllChecks);
 }
 
 #[test]
 fn markdown_format_empty_to_matches_format_empty() {
-    let formatter = MarkdownFormatter;
+    let formatter = MarkdownFormatter::default();
 
     let buffered = formatter.format_empty();
 
@@ -113,9 +114,11 @@ fn markdown_format_escapes_sorted_alphabetically() {
         .into_iter()
         .collect(),
         test: None,
+        source_lines: None,
+        by_package: None,
     });
 
-    let formatter = MarkdownFormatter;
+    let formatter = MarkdownFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
 
     let alpha_pos = output.find("alpha").unwrap();
@@ -125,3 +128,100 @@ fn markdown_format_escapes_sorted_alphabetically() {
     assert!(alpha_pos < middle_pos);
     assert!(middle_pos < zebra_pos);
 }
+
+#[test]
+fn markdown_format_includes_slow_tests() {
+    let baseline = create_slow_tests_baseline();
+    let output = MarkdownFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains("## Slowest Tests"));
+    assert!(output.contains("| unit | `tests::big` | 2100ms (+700ms) |"));
+}
+
+#[test]
+fn markdown_format_includes_package_health() {
+    let baseline = create_test_baseline();
+    let output = MarkdownFormatter::new(create_test_insights())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains("**Health:** "));
+    assert!(output.contains("## Package Health"));
+    assert!(output.contains("| core | 92 | A |"));
+}
+
+#[test]
+fn markdown_format_includes_goals_table() {
+    let baseline = create_test_baseline();
+    let output = MarkdownFormatter::new(create_test_insights())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains("## Goals"));
+    assert!(
+        output.contains("| coverage | 85.5% | 90.0% | `[##########----------] 50% (~9 days)` |")
+    );
+}
+
+#[test]
+fn markdown_format_omits_goals_without_targets() {
+    let baseline = create_test_baseline();
+    let output = MarkdownFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(!output.contains("## Goals"));
+}
+
+#[test]
+fn markdown_format_includes_suppressions_table() {
+    let baseline = create_test_baseline();
+    let output = MarkdownFormatter::new(create_test_insights())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains(
+        "## Suppressions\n\n3 (1 expired, 0 expiring, oldest 120 days)\n\n| Kind | Count |"
+    ));
+    assert!(output.contains("| inline | 2 |"));
+}
+
+#[test]
+fn markdown_format_includes_agent_context_table() {
+    let baseline = create_agents_baseline();
+    let output = MarkdownFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains("| Agent tokens | 4000 |"));
+    assert!(output.contains("## Agent Context"));
+    assert!(output.contains("| `CLAUDE.md` | 2500 | 62% |\n| `AGENTS.md` | 1000 | 25% |"));
+}
+
+#[test]
+fn markdown_format_marks_regressed_and_improved_cells() {
+    let baseline = create_test_baseline();
+    let output = MarkdownFormatter::new(create_test_comparison())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains("**Compared to:** 0ff1ce0"));
+    assert!(output.contains("| Coverage | 85.5% (regressed) |"));
+    assert!(output.contains("| Escapes (unwrap) | 10 (improved) |"));
+    assert!(output.contains("| Escapes (expect) | 5 |"));
+    assert!(output.contains("| Build (cold) | 45.0s |"));
+}
+
+#[test]
+fn markdown_format_lists_changes_since_comparison() {
+    let baseline = create_test_baseline();
+    let output = MarkdownFormatter::new(create_test_comparison())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains("## Changes Since 0ff1ce0\n\n- New violation: escapes.expect (5)\n"));
+}
+
+#[test]
+fn markdown_format_omits_changes_without_comparison() {
+    let baseline = create_test_baseline();
+    let output = MarkdownFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(!output.contains("## Changes Since"));
+    assert!(!output.contains("(regressed)"));
+}
@@ -3,25 +3,96 @@
 
 //! Report command implementation.
 //!
-//! Reads baseline files and outputs metrics in text, JSON, or HTML format.
+//! Reads baseline files and outputs metrics in text, JSON, HTML, or
+//! Markdown format, or as an SVG health badge. Reports also show the
+//! health score, progress toward configured goals, and the project's
+//! suppressions. With `--compare`, HTML and Markdown reports also mark
+//! metrics that regressed or improved since an earlier baseline.
+//! `--debt` and `--hotspots` swap the metrics for rankings of files.
 
+mod badge;
+pub mod debt;
+pub mod hotspots;
 mod html;
 mod json;
 mod markdown;
 mod text;
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use crate::baseline::{
-    Baseline, BuildTimeMetrics, CoverageMetrics, EscapesMetrics, TestTimeMetrics,
+    AgentsMetrics, Baseline, BuildTimeMetrics, CoverageMetrics, EscapesMetrics, SlowTest,
+    TestTimeMetrics,
 };
 use crate::cli::{CheckFilter, OutputFormat};
+use crate::goals::GoalProgress;
+use crate::health::HealthScore;
+use crate::suppressions::SuppressionSummary;
 
+use badge::BadgeFormatter;
 use html::HtmlFormatter;
 use json::JsonFormatter;
 use markdown::MarkdownFormatter;
 use text::TextFormatter;
 
+/// Scores derived from the baseline and config, shown alongside its metrics.
+#[derive(Debug, Clone, Default)]
+pub struct Insights {
+    /// Health score for the whole baseline.
+    pub health: Option<HealthScore>,
+    /// Progress toward configured goals.
+    pub goals: Vec<GoalProgress>,
+    /// Suppressions in the working tree, by kind and expiry.
+    pub suppressions: Option<SuppressionSummary>,
+    /// Earlier baseline to compare metrics against.
+    pub comparison: Option<Comparison>,
+}
+
+/// An earlier baseline the report compares against (`--compare`).
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    /// The earlier baseline.
+    pub baseline: Baseline,
+    /// Health score for the earlier baseline.
+    pub health: Option<HealthScore>,
+    /// Per-file token limit for agent files, if enabled.
+    pub max_tokens: Option<usize>,
+}
+
+/// Which way a metric moved since the compared baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Improved,
+    Regressed,
+}
+
+impl Trend {
+    /// Lowercase name, used as the HTML class and Markdown marker.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Trend::Improved => "improved",
+            Trend::Regressed => "regressed",
+        }
+    }
+}
+
+/// What appeared since the compared baseline.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Changes {
+    /// Violation counts that were zero before, by report metric
+    /// (e.g., "escapes.unwrap", "lint.clippy/todo").
+    pub new_violations: Vec<(String, usize)>,
+    /// Agent files now over the per-file token limit, with their tokens.
+    pub over_limit: Vec<(String, usize)>,
+}
+
+impl Changes {
+    /// Whether nothing new appeared.
+    pub fn is_empty(&self) -> bool {
+        self.new_violations.is_empty() && self.over_limit.is_empty()
+    }
+}
+
 /// Helper for accessing filtered metrics.
 ///
 /// Provides convenient access to baseline metrics while respecting
@@ -29,12 +100,215 @@ use text::TextFormatter;
 pub struct FilteredMetrics<'a> {
     baseline: &'a Baseline,
     filter: &'a dyn CheckFilter,
+    insights: Option<&'a Insights>,
+    /// Report metric values, filled in when comparing.
+    current: BTreeMap<String, f64>,
+    /// The compared baseline's report metric values.
+    previous: Option<BTreeMap<String, f64>>,
 }
 
 impl<'a> FilteredMetrics<'a> {
     /// Create a new filtered metrics accessor.
     pub fn new(baseline: &'a Baseline, filter: &'a dyn CheckFilter) -> Self {
-        Self { baseline, filter }
+        Self {
+            baseline,
+            filter,
+            insights: None,
+            current: BTreeMap::new(),
+            previous: None,
+        }
+    }
+
+    /// Attach the health score, goal progress, and comparison.
+    pub fn with_insights(mut self, insights: &'a Insights) -> Self {
+        self.insights = Some(insights);
+        if let Some(comparison) = &insights.comparison {
+            let earlier = Insights {
+                health: comparison.health.clone(),
+                ..Insights::default()
+            };
+            self.previous = Some(
+                FilteredMetrics::new(&comparison.baseline, self.filter)
+                    .with_insights(&earlier)
+                    .values(),
+            );
+            self.current = self.values();
+        }
+        self
+    }
+
+    /// Get the baseline being compared against, if any.
+    pub fn compared(&self) -> Option<&Baseline> {
+        self.comparison().map(|c| &c.baseline)
+    }
+
+    fn comparison(&self) -> Option<&Comparison> {
+        self.insights.and_then(|i| i.comparison.as_ref())
+    }
+
+    /// Which way a report metric (e.g., "coverage", "escapes.unwrap")
+    /// moved since the compared baseline. Coverage and health scores are
+    /// better higher; everything else is better lower.
+    pub fn trend(&self, metric: &str) -> Option<Trend> {
+        let previous = *self.previous.as_ref()?.get(metric)?;
+        let current = *self.current.get(metric)?;
+        if current == previous {
+            return None;
+        }
+        let higher_is_better = metric == "coverage"
+            || metric == "health"
+            || metric.starts_with("coverage.")
+            || metric.starts_with("health.");
+        if (current > previous) == higher_is_better {
+            Some(Trend::Improved)
+        } else {
+            Some(Trend::Regressed)
+        }
+    }
+
+    /// Violation kinds and over-limit agent files that appeared since the
+    /// compared baseline. Returns None without a comparison.
+    pub fn changes(&self) -> Option<Changes> {
+        let previous = self.previous.as_ref()?;
+        let before = |metric: &str| previous.get(metric).copied().unwrap_or(0.0);
+
+        let new_violations = self
+            .current
+            .iter()
+            .filter(|(metric, _)| {
+                (metric.starts_with("escapes.") && !metric.starts_with("escapes.test."))
+                    || metric.starts_with("lint.")
+                    || metric.starts_with("deadcode.")
+                    || metric.starts_with("deps.")
+            })
+            .filter(|(metric, count)| **count > 0.0 && before(metric) == 0.0)
+            .map(|(metric, count)| (metric.clone(), *count as usize))
+            .collect();
+
+        let over_limit = match self.comparison().and_then(|c| c.max_tokens) {
+            Some(limit) => self
+                .current
+                .iter()
+                .filter_map(|(metric, tokens)| {
+                    let file = metric.strip_prefix("agents.files.")?;
+                    (*tokens > limit as f64 && before(metric) <= limit as f64)
+                        .then(|| (file.to_string(), *tokens as usize))
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Some(Changes {
+            new_violations,
+            over_limit,
+        })
+    }
+
+    /// Included metric values by their report name (e.g., "coverage.core",
+    /// "escapes.unwrap", "agents.files.CLAUDE.md").
+    fn values(&self) -> BTreeMap<String, f64> {
+        let mut values = BTreeMap::new();
+        if let Some(health) = self.health() {
+            values.insert("health".to_string(), health.score as f64);
+            for (name, package) in self.health_packages() {
+                values.insert(format!("health.{}", name), package.score as f64);
+            }
+        }
+        if let Some(coverage) = self.coverage() {
+            values.insert("coverage".to_string(), coverage.total);
+        }
+        for (name, pct) in self.sorted_package_coverage().unwrap_or_default() {
+            values.insert(format!("coverage.{}", name), pct);
+        }
+        for (name, count) in self.sorted_escapes().unwrap_or_default() {
+            values.insert(format!("escapes.{}", name), count as f64);
+        }
+        for (name, count) in self.sorted_test_escapes().unwrap_or_default() {
+            values.insert(format!("escapes.test.{}", name), count as f64);
+        }
+        for (check, counts) in [
+            ("lint", &self.baseline.metrics.lint),
+            ("deadcode", &self.baseline.metrics.deadcode),
+            ("deps", &self.baseline.metrics.deps),
+        ] {
+            if !self.filter.should_include(check) {
+                continue;
+            }
+            for (name, count) in counts.iter().flatten() {
+                values.insert(format!("{}.{}", check, name), *count as f64);
+            }
+        }
+        if let Some(agents) = self.agents() {
+            values.insert(
+                "agents.total_tokens".to_string(),
+                agents.total_tokens as f64,
+            );
+            for (name, tokens) in &agents.files {
+                values.insert(format!("agents.files.{}", name), *tokens as f64);
+            }
+        }
+        if let Some(build) = self.build_time() {
+            values.insert("build_time.cold".to_string(), build.cold);
+            values.insert("build_time.hot".to_string(), build.hot);
+        }
+        for (name, size) in self.sorted_binary_sizes().unwrap_or_default() {
+            values.insert(format!("binary_size.{}", name), size as f64);
+        }
+        if let Some(tests) = self.test_time() {
+            values.insert("test_time.total".to_string(), tests.total);
+        }
+        values
+    }
+
+    /// Get the health score, if one was attached. It is scored from the
+    /// whole baseline, so it ignores the filter.
+    pub fn health(&self) -> Option<&HealthScore> {
+        self.insights.and_then(|i| i.health.as_ref())
+    }
+
+    /// Get the health scores of the packages the filter includes.
+    pub fn health_packages(&self) -> Vec<(&str, &HealthScore)> {
+        self.health()
+            .map(|health| {
+                health
+                    .packages
+                    .iter()
+                    .filter(|(name, _)| self.filter.should_include_package(name))
+                    .map(|(name, score)| (name.as_str(), score))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Get progress toward goals whose metric's check is included.
+    pub fn goals(&self) -> Vec<&GoalProgress> {
+        let goals = self
+            .insights
+            .map(|i| i.goals.as_slice())
+            .unwrap_or_default();
+        goals
+            .iter()
+            .filter(|goal| {
+                let check = if goal.metric.starts_with("escapes.") {
+                    "escapes"
+                } else {
+                    "tests"
+                };
+                self.filter.should_include(check)
+            })
+            .collect()
+    }
+
+    /// Get the suppression counts if there are any and the "escapes" check
+    /// is included.
+    pub fn suppressions(&self) -> Option<&SuppressionSummary> {
+        if self.filter.should_include("escapes") {
+            self.insights
+                .and_then(|i| i.suppressions.as_ref())
+                .filter(|s| s.total > 0)
+        } else {
+            None
+        }
     }
 
     /// Get coverage metrics if the "tests" check is included.
@@ -55,6 +329,15 @@ impl<'a> FilteredMetrics<'a> {
         }
     }
 
+    /// Get agent file token counts if the "agents" check is included.
+    pub fn agents(&self) -> Option<&AgentsMetrics> {
+        if self.filter.should_include("agents") {
+            self.baseline.metrics.agents.as_ref()
+        } else {
+            None
+        }
+    }
+
     /// Get build time metrics if the "build" check is included.
     pub fn build_time(&self) -> Option<&BuildTimeMetrics> {
         if self.filter.should_include("build") {
@@ -65,7 +348,7 @@ impl<'a> FilteredMetrics<'a> {
     }
 
     /// Get binary size metrics if the "build" check is included.
-    pub fn binary_size(&self) -> Option<&HashMap<String, u64>> {
+    pub fn binary_size(&self) -> Option<&BTreeMap<String, u64>> {
         if self.filter.should_include("build") {
             self.baseline.metrics.binary_size.as_ref()
         } else {
@@ -82,6 +365,15 @@ impl<'a> FilteredMetrics<'a> {
         }
     }
 
+    /// Get slowest tests by suite if the "tests" check is included.
+    pub fn slow_tests(&self) -> Option<&BTreeMap<String, Vec<SlowTest>>> {
+        if self.filter.should_include("tests") {
+            self.baseline.metrics.slow_tests.as_ref()
+        } else {
+            None
+        }
+    }
+
     /// Estimate number of metrics that will be included.
     pub fn count(&self) -> usize {
         let mut n = 0;
@@ -91,6 +383,9 @@ impl<'a> FilteredMetrics<'a> {
         if let Some(esc) = self.escapes() {
             n += esc.source.len();
         }
+        if let Some(agents) = self.agents() {
+            n += 1 + agents.files.len();
+        }
         if self.build_time().is_some() {
             n += 2; // cold + hot
         }
@@ -100,16 +395,35 @@ impl<'a> FilteredMetrics<'a> {
         if self.test_time().is_some() {
             n += 1;
         }
+        if let Some(slow) = self.slow_tests() {
+            n += slow.values().map(Vec::len).sum::<usize>();
+        }
         n
     }
 
     /// Iterate over escape source metrics in sorted order.
+    /// With packages selected, counts are summed over those packages when
+    /// the baseline records them per package.
     /// Returns None if escapes check is filtered out.
     pub fn sorted_escapes(&self) -> Option<Vec<(&str, usize)>> {
         self.escapes().map(|esc| {
-            let mut items: Vec<_> = esc.source.iter().map(|(k, v)| (k.as_str(), *v)).collect();
-            items.sort_by_key(|(k, _)| *k);
-            items
+            let packages = esc
+                .by_package
+                .as_ref()
+                .filter(|_| !self.filter.packages().is_empty());
+            let Some(packages) = packages else {
+                return esc.source.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+            };
+            let mut items: BTreeMap<&str, usize> = BTreeMap::new();
+            for (_, package) in packages
+                .iter()
+                .filter(|(name, _)| self.filter.should_include_package(name))
+            {
+                for (name, count) in &package.source {
+                    *items.entry(name.as_str()).or_default() += count;
+                }
+            }
+            items.into_iter().collect()
         })
     }
 
@@ -125,18 +439,43 @@ impl<'a> FilteredMetrics<'a> {
         })
     }
 
-    /// Iterate over coverage by package in sorted order.
+    /// Iterate over coverage by package in sorted order, for the packages
+    /// the filter includes.
     /// Returns None if tests check is filtered out or no package coverage.
     pub fn sorted_package_coverage(&self) -> Option<Vec<(&str, f64)>> {
         self.coverage().and_then(|cov| {
             cov.by_package.as_ref().map(|packages| {
-                let mut items: Vec<_> = packages.iter().map(|(k, v)| (k.as_str(), *v)).collect();
-                items.sort_by_key(|(k, _)| *k);
-                items
+                packages
+                    .iter()
+                    .filter(|(k, _)| self.filter.should_include_package(k))
+                    .map(|(k, v)| (k.as_str(), *v))
+                    .collect()
             })
         })
     }
 
+    /// Iterate over agent files with their tokens and share of the total
+    /// (percent), largest first.
+    /// Returns None if agents check is filtered out or no tokens recorded.
+    pub fn sorted_agent_tokens(&self) -> Option<Vec<(&str, usize, f64)>> {
+        self.agents().map(|agents| {
+            let mut items: Vec<_> = agents
+                .files
+                .iter()
+                .map(|(k, v)| {
+                    let share = if agents.total_tokens == 0 {
+                        0.0
+                    } else {
+                        *v as f64 * 100.0 / agents.total_tokens as f64
+                    };
+                    (k.as_str(), *v, share)
+                })
+                .collect();
+            items.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+            items
+        })
+    }
+
     /// Iterate over binary sizes in sorted order.
     /// Returns None if build check is filtered out or no binary sizes.
     pub fn sorted_binary_sizes(&self) -> Option<Vec<(&str, u64)>> {
@@ -173,12 +512,20 @@ pub trait ReportFormatter {
 }
 
 /// Create formatter based on output format.
-fn create_formatter(format: OutputFormat, compact: bool) -> Box<dyn ReportFormatter> {
+fn create_formatter(
+    format: OutputFormat,
+    compact: bool,
+    insights: &Insights,
+) -> Box<dyn ReportFormatter> {
+    let insights = insights.clone();
     match format {
-        OutputFormat::Text => Box::new(TextFormatter),
-        OutputFormat::Json => Box::new(JsonFormatter::new(compact)),
-        OutputFormat::Html => Box::new(HtmlFormatter),
-        OutputFormat::Markdown => Box::new(MarkdownFormatter),
+        OutputFormat::Text => Box::new(TextFormatter::new(insights)),
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            Box::new(JsonFormatter::new(compact).with_insights(insights))
+        }
+        OutputFormat::Html => Box::new(HtmlFormatter::new(insights)),
+        OutputFormat::Markdown => Box::new(MarkdownFormatter::new(insights)),
+        OutputFormat::Svg => Box::new(BadgeFormatter::new(insights.health)),
     }
 }
 
@@ -190,7 +537,7 @@ pub fn format_report<F: CheckFilter>(
     baseline: Option<&Baseline>,
     filter: &F,
 ) -> anyhow::Result<String> {
-    format_report_with_options(format, baseline, filter, false)
+    format_report_with_options(format, baseline, filter, false, &Insights::default())
 }
 
 /// Format a report with additional options.
@@ -201,8 +548,9 @@ pub fn format_report_with_options<F: CheckFilter>(
     baseline: Option<&Baseline>,
     filter: &F,
     compact: bool,
+    insights: &Insights,
 ) -> anyhow::Result<String> {
-    let formatter = create_formatter(format, compact);
+    let formatter = create_formatter(format, compact, insights);
 
     match baseline {
         Some(b) => formatter.format(b, filter),
@@ -219,8 +567,9 @@ pub fn format_report_to<F: CheckFilter>(
     baseline: Option<&Baseline>,
     filter: &F,
     compact: bool,
+    insights: &Insights,
 ) -> anyhow::Result<()> {
-    let formatter = create_formatter(format, compact);
+    let formatter = create_formatter(format, compact, insights);
 
     match baseline {
         Some(b) => formatter.format_to(writer, b, filter),
@@ -228,6 +577,38 @@ pub fn format_report_to<F: CheckFilter>(
     }
 }
 
+/// Format a slow test's time with its change since the previous run,
+/// e.g. `2100ms (+700ms)`.
+pub fn slow_test_time(test: &SlowTest) -> String {
+    match test.change_ms() {
+        Some(change) => format!("{}ms ({:+}ms)", test.ms, change),
+        None => format!("{}ms", test.ms),
+    }
+}
+
+/// Format a goal value: coverage as a percentage, escapes as a count.
+pub fn goal_value(goal: &GoalProgress, value: f64) -> String {
+    if goal.metric == "coverage" {
+        format!("{:.1}%", value)
+    } else {
+        format!("{}", value)
+    }
+}
+
+/// Format a goal's progress bar, percentage, and ETA,
+/// e.g. `[#####-----] 50% (~10 days)`.
+pub fn goal_progress(goal: &GoalProgress) -> String {
+    format!(
+        "{} {:.0}% ({})",
+        goal.bar(GOAL_BAR_WIDTH),
+        goal.progress,
+        goal.eta()
+    )
+}
+
+/// Cells in a goal progress bar.
+const GOAL_BAR_WIDTH: usize = 20;
+
 /// Helper to convert bytes to human-readable format (with space).
 pub fn human_bytes(bytes: u64) -> String {
     crate::file_size::human_size(bytes, true)
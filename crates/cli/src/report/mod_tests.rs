@@ -1,7 +1,10 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-use super::test_support::{AllChecks, ExcludeChecks, create_test_baseline};
+use super::test_support::{
+    AllChecks, ExcludeChecks, OnlyPackages, create_agents_baseline, create_slow_tests_baseline,
+    create_test_baseline, create_test_comparison, create_test_insights,
+};
 use super::*;
 
 /// Check if a metric is present in filtered metrics.
@@ -207,6 +210,8 @@ fn sorted_test_escapes_returns_sorted_when_present() {
                     .into_iter()
                     .collect(),
                 ),
+                source_lines: None,
+                by_package: None,
             }),
             ..Default::default()
         },
@@ -257,6 +262,94 @@ fn sorted_package_coverage_returns_sorted_when_present() {
     assert_eq!(packages[1], ("zebra", 70.0));
 }
 
+#[test]
+fn sorted_package_coverage_keeps_selected_packages() {
+    use crate::baseline::{BaselineMetrics, CoverageMetrics};
+
+    let baseline = Baseline {
+        metrics: BaselineMetrics {
+            coverage: Some(CoverageMetrics {
+                total: 80.0,
+                by_package: Some(
+                    [("cli".to_string(), 70.0), ("core".to_string(), 90.0)]
+                        .into_iter()
+                        .collect(),
+                ),
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let filter = OnlyPackages(vec!["cli"]);
+    let filtered = FilteredMetrics::new(&baseline, &filter);
+    assert_eq!(filtered.sorted_package_coverage().unwrap(), [("cli", 70.0)]);
+}
+
+#[test]
+fn sorted_escapes_sums_selected_packages() {
+    use crate::baseline::{BaselineMetrics, EscapesMetrics, PackageEscapesMetrics};
+
+    let package = |unwrap: usize, unsafe_count: usize| PackageEscapesMetrics {
+        source: [
+            ("unwrap".to_string(), unwrap),
+            ("unsafe".to_string(), unsafe_count),
+        ]
+        .into_iter()
+        .collect(),
+        source_lines: 100,
+    };
+    let baseline = Baseline {
+        metrics: BaselineMetrics {
+            escapes: Some(EscapesMetrics {
+                source: [("unwrap".to_string(), 9), ("unsafe".to_string(), 3)]
+                    .into_iter()
+                    .collect(),
+                test: None,
+                source_lines: None,
+                by_package: Some(
+                    [
+                        ("cli".to_string(), package(2, 0)),
+                        ("core".to_string(), package(4, 1)),
+                        ("web".to_string(), package(3, 2)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let all = FilteredMetrics::new(&baseline, &AllChecks);
+    assert_eq!(
+        all.sorted_escapes().unwrap(),
+        [("unsafe", 3), ("unwrap", 9)]
+    );
+
+    let filter = OnlyPackages(vec!["cli", "core"]);
+    let filtered = FilteredMetrics::new(&baseline, &filter);
+    assert_eq!(
+        filtered.sorted_escapes().unwrap(),
+        [("unsafe", 1), ("unwrap", 6)]
+    );
+}
+
+#[test]
+fn health_packages_keeps_selected_packages() {
+    let baseline = create_test_baseline();
+    let insights = create_test_insights();
+
+    let all = FilteredMetrics::new(&baseline, &AllChecks).with_insights(&insights);
+    assert_eq!(all.health_packages().len(), 1);
+
+    let filter = OnlyPackages(vec!["cli"]);
+    let filtered = FilteredMetrics::new(&baseline, &filter).with_insights(&insights);
+    assert!(filtered.health_packages().is_empty());
+    assert!(filtered.health().is_some());
+}
+
 #[test]
 fn sorted_binary_sizes_returns_alphabetical_order() {
     let baseline = create_test_baseline();
@@ -275,3 +368,127 @@ fn sorted_binary_sizes_returns_none_when_filtered() {
 
     assert!(filtered.sorted_binary_sizes().is_none());
 }
+
+#[test]
+fn sorted_agent_tokens_returns_largest_first_with_share() {
+    let baseline = create_agents_baseline();
+    let filtered = FilteredMetrics::new(&baseline, &AllChecks);
+
+    let files = filtered.sorted_agent_tokens().unwrap();
+    assert_eq!(
+        files,
+        vec![
+            ("CLAUDE.md", 2500, 62.5),
+            ("AGENTS.md", 1000, 25.0),
+            (".cursor/rules/api.mdc", 500, 12.5),
+        ]
+    );
+    assert_eq!(filtered.count(), 4);
+}
+
+#[test]
+fn sorted_agent_tokens_returns_none_when_filtered() {
+    let baseline = create_agents_baseline();
+    let filter = ExcludeChecks(vec!["agents"]);
+    let filtered = FilteredMetrics::new(&baseline, &filter);
+
+    assert!(filtered.sorted_agent_tokens().is_none());
+}
+
+#[test]
+fn slow_tests_returns_none_when_filtered() {
+    let baseline = create_slow_tests_baseline();
+    let filter = ExcludeChecks(vec!["tests"]);
+    let filtered = FilteredMetrics::new(&baseline, &filter);
+
+    assert!(filtered.slow_tests().is_none());
+}
+
+#[test]
+fn slow_test_time_shows_change_since_previous() {
+    let baseline = create_slow_tests_baseline();
+    let tests = &baseline.metrics.slow_tests.as_ref().unwrap()["unit"];
+
+    assert_eq!(slow_test_time(&tests[0]), "2100ms (+700ms)");
+    assert_eq!(slow_test_time(&tests[1]), "300ms");
+}
+
+#[test]
+fn filtered_goals_follow_their_checks() {
+    let baseline = create_test_baseline();
+    let insights = create_test_insights();
+
+    let all = FilteredMetrics::new(&baseline, &AllChecks).with_insights(&insights);
+    assert_eq!(all.goals().len(), 2);
+
+    let filter = ExcludeChecks(vec!["escapes"]);
+    let filtered = FilteredMetrics::new(&baseline, &filter).with_insights(&insights);
+    let goals: Vec<_> = filtered.goals().iter().map(|g| g.metric.as_str()).collect();
+    assert_eq!(goals, ["coverage"]);
+}
+
+#[test]
+fn trend_follows_metric_direction() {
+    let baseline = create_test_baseline();
+    let insights = create_test_comparison();
+    let filtered = FilteredMetrics::new(&baseline, &AllChecks).with_insights(&insights);
+    assert_eq!(filtered.trend("coverage"), Some(Trend::Regressed));
+    assert_eq!(filtered.trend("escapes.unwrap"), Some(Trend::Improved));
+    assert_eq!(filtered.trend("build_time.cold"), None);
+    assert_eq!(filtered.trend("escapes.expect"), None);
+}
+
+#[test]
+fn trend_is_none_without_comparison() {
+    let baseline = create_test_baseline();
+    let insights = create_test_insights();
+    let filtered = FilteredMetrics::new(&baseline, &AllChecks).with_insights(&insights);
+    assert_eq!(filtered.trend("coverage"), None);
+    assert_eq!(filtered.changes(), None);
+}
+
+#[test]
+fn changes_list_new_violation_kinds() {
+    let baseline = create_test_baseline();
+    let insights = create_test_comparison();
+    let filtered = FilteredMetrics::new(&baseline, &AllChecks).with_insights(&insights);
+    let changes = filtered.changes().unwrap();
+    assert_eq!(
+        changes.new_violations,
+        vec![("escapes.expect".to_string(), 5)]
+    );
+    assert!(changes.over_limit.is_empty());
+}
+
+#[test]
+fn changes_respect_the_check_filter() {
+    let baseline = create_test_baseline();
+    let insights = create_test_comparison();
+    let filter = ExcludeChecks(vec!["escapes"]);
+    let filtered = FilteredMetrics::new(&baseline, &filter).with_insights(&insights);
+    assert!(filtered.changes().unwrap().is_empty());
+}
+
+#[test]
+fn changes_list_agent_files_newly_over_the_token_limit() {
+    let baseline = create_agents_baseline();
+    let mut earlier = create_agents_baseline();
+    if let Some(agents) = earlier.metrics.agents.as_mut() {
+        agents.files.insert("CLAUDE.md".to_string(), 1500);
+    }
+    let insights = Insights {
+        comparison: Some(Comparison {
+            baseline: earlier,
+            health: None,
+            max_tokens: Some(2000),
+        }),
+        ..Default::default()
+    };
+    let filtered = FilteredMetrics::new(&baseline, &AllChecks).with_insights(&insights);
+    let changes = filtered.changes().unwrap();
+    assert_eq!(changes.over_limit, vec![("CLAUDE.md".to_string(), 2500)]);
+    assert_eq!(
+        filtered.trend("agents.files.CLAUDE.md"),
+        Some(Trend::Regressed)
+    );
+}
@@ -4,7 +4,8 @@
 //! Shared test utilities for report formatter tests.
 
 use crate::baseline::{
-    Baseline, BaselineMetrics, BuildTimeMetrics, CoverageMetrics, EscapesMetrics, TestTimeMetrics,
+    AgentsMetrics, Baseline, BaselineMetrics, BuildTimeMetrics, CoverageMetrics, EscapesMetrics,
+    SlowTest, TestTimeMetrics,
 };
 use crate::cli::CheckFilter;
 
@@ -36,6 +37,23 @@ impl CheckFilter for ExcludeChecks {
     }
 }
 
+/// Test filter that includes all checks but only specific packages.
+pub struct OnlyPackages(pub Vec<&'static str>);
+
+impl CheckFilter for OnlyPackages {
+    fn enabled_checks(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn disabled_checks(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn packages(&self) -> Vec<String> {
+        self.0.iter().map(|s| s.to_string()).collect()
+    }
+}
+
 /// Assert that buffered and streamed output match for a formatter.
 pub fn assert_buffered_matches_streamed<F: ReportFormatter>(
     formatter: &F,
@@ -60,6 +78,7 @@ pub fn create_test_baseline() -> Baseline {
         version: 1,
         updated: chrono::Utc::now(),
         commit: Some("abc1234".to_string()),
+        tags: Default::default(),
         metrics: BaselineMetrics {
             coverage: Some(CoverageMetrics {
                 total: 85.5,
@@ -70,7 +89,15 @@ pub fn create_test_baseline() -> Baseline {
                     .into_iter()
                     .collect(),
                 test: None,
+                source_lines: None,
+                by_package: None,
             }),
+            unsafe_code: None,
+            lint: None,
+            deadcode: None,
+            deps: None,
+            agents: None,
+            test_ratio: None,
             build_time: Some(BuildTimeMetrics {
                 cold: 45.0,
                 hot: 12.5,
@@ -81,6 +108,123 @@ pub fn create_test_baseline() -> Baseline {
                 avg: 0.5,
                 max: 2.0,
             }),
+            test_time_history: None,
+            slow_tests: None,
+            quench: None,
+            history: None,
+        },
+    }
+}
+
+/// Create a baseline with slowest tests recorded for one suite.
+pub fn create_slow_tests_baseline() -> Baseline {
+    let mut baseline = Baseline::default();
+    baseline.metrics.slow_tests = Some(
+        [(
+            "unit".to_string(),
+            vec![
+                SlowTest {
+                    name: "tests::big".to_string(),
+                    ms: 2100,
+                    previous: vec![1200, 1400],
+                },
+                SlowTest {
+                    name: "tests::new".to_string(),
+                    ms: 300,
+                    previous: Vec::new(),
+                },
+            ],
+        )]
+        .into_iter()
+        .collect(),
+    );
+    baseline
+}
+
+/// Create a baseline with token counts for three agent files.
+pub fn create_agents_baseline() -> Baseline {
+    let mut baseline = Baseline::default();
+    baseline.metrics.agents = Some(AgentsMetrics {
+        total_tokens: 4000,
+        files: [
+            ("AGENTS.md".to_string(), 1000),
+            ("CLAUDE.md".to_string(), 2500),
+            (".cursor/rules/api.mdc".to_string(), 500),
+        ]
+        .into_iter()
+        .collect(),
+    });
+    baseline
+}
+
+/// Create insights for the test baseline: a health score with one package,
+/// a coverage goal halfway there, an escapes goal with no history, and
+/// three suppressions (one expired).
+pub fn create_test_insights() -> super::Insights {
+    use chrono::TimeZone;
+
+    use crate::config::HealthConfig;
+    use crate::goals::GoalProgress;
+    use crate::health::HealthScore;
+    use crate::suppressions::SuppressionSummary;
+
+    let mut health =
+        HealthScore::from_metrics(&create_test_baseline().metrics, &HealthConfig::default())
+            .unwrap();
+    health.packages.insert(
+        "core".to_string(),
+        HealthScore {
+            score: 92,
+            grade: 'A',
+            metrics: Default::default(),
+            packages: Default::default(),
         },
+    );
+
+    let day = |d| chrono::Utc.with_ymd_and_hms(2026, 1, d, 0, 0, 0).unwrap();
+    let goals = vec![
+        GoalProgress::new(
+            "coverage",
+            85.5,
+            90.0,
+            true,
+            &[(day(1), 81.0), (day(10), 85.5)],
+        ),
+        GoalProgress::new("escapes.unwrap", 10.0, 0.0, false, &[]),
+    ];
+
+    super::Insights {
+        health: Some(health),
+        goals,
+        suppressions: Some(SuppressionSummary {
+            total: 3,
+            by_kind: [("allow_list", 1), ("inline", 2)].into_iter().collect(),
+            expired: 1,
+            expiring: 0,
+            oldest_days: Some(120),
+        }),
+        comparison: None,
+    }
+}
+
+/// Create insights comparing the test baseline against an earlier one
+/// with higher coverage, more `unwrap` escapes, and no `expect` escapes.
+pub fn create_test_comparison() -> super::Insights {
+    let mut earlier = create_test_baseline();
+    earlier.commit = Some("0ff1ce0".to_string());
+    if let Some(coverage) = earlier.metrics.coverage.as_mut() {
+        coverage.total = 90.0;
+    }
+    if let Some(escapes) = earlier.metrics.escapes.as_mut() {
+        escapes.source = [("unwrap".to_string(), 12)].into_iter().collect();
+    }
+
+    super::Insights {
+        comparison: Some(super::Comparison {
+            baseline: earlier,
+            health: None,
+            max_tokens: Some(2000),
+        }),
+        ..Default::default()
     }
 }
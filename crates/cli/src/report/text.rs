@@ -6,10 +6,23 @@
 use crate::baseline::Baseline;
 use crate::cli::CheckFilter;
 
-use super::{FilteredMetrics, ReportFormatter, human_bytes};
+use super::{
+    FilteredMetrics, Insights, ReportFormatter, goal_progress, goal_value, human_bytes,
+    slow_test_time,
+};
 
 /// Text format report formatter.
-pub struct TextFormatter;
+#[derive(Default)]
+pub struct TextFormatter {
+    insights: Insights,
+}
+
+impl TextFormatter {
+    /// Create a text formatter that shows the health score and goals.
+    pub fn new(insights: Insights) -> Self {
+        Self { insights }
+    }
+}
 
 /// Size estimation constants for pre-allocation.
 const TEXT_HEADER_SIZE: usize = 100;
@@ -29,6 +42,20 @@ macro_rules! write_text_report {
             let date = $baseline.updated.format("%Y-%m-%d");
             writeln!($writer, "Baseline: {}", date)?;
         }
+        if !$baseline.tags.is_empty() {
+            let tags: Vec<String> = $baseline
+                .tags
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect();
+            writeln!($writer, "Tags: {}", tags.join(", "))?;
+        }
+        if let Some(health) = $filtered.health() {
+            writeln!($writer, "Health: {}", health.summary())?;
+            for (name, package) in $filtered.health_packages() {
+                writeln!($writer, "  {}: {}", name, package.summary())?;
+            }
+        }
         writeln!($writer)?;
 
         // Coverage (mapped to "tests" check)
@@ -56,6 +83,16 @@ macro_rules! write_text_report {
             }
         }
 
+        // Agent context budget, largest files first
+        if let Some(agents) = $filtered.agents() {
+            writeln!($writer, "agents.total_tokens: {}", agents.total_tokens)?;
+        }
+        if let Some(files) = $filtered.sorted_agent_tokens() {
+            for (name, tokens, share) in files {
+                writeln!($writer, "  {}: {} ({:.0}%)", name, tokens, share)?;
+            }
+        }
+
         // Build time
         if let Some(build) = $filtered.build_time() {
             writeln!($writer, "build_time.cold: {:.1}s", build.cold)?;
@@ -67,12 +104,42 @@ macro_rules! write_text_report {
             writeln!($writer, "test_time.total: {:.1}s", tests.total)?;
         }
 
+        // Slowest tests per suite
+        if let Some(suites) = $filtered.slow_tests() {
+            for (suite, tests) in suites {
+                writeln!($writer, "slow_tests.{}:", suite)?;
+                for test in tests {
+                    writeln!($writer, "  {}: {}", test.name, slow_test_time(test))?;
+                }
+            }
+        }
+
         // Binary size
         if let Some(items) = $filtered.sorted_binary_sizes() {
             for (name, size) in items {
                 writeln!($writer, "binary_size.{}: {}", name, human_bytes(size))?;
             }
         }
+
+        // Goals
+        for goal in $filtered.goals() {
+            writeln!(
+                $writer,
+                "goal.{}: {} -> {} {}",
+                goal.metric,
+                goal_value(goal, goal.current),
+                goal_value(goal, goal.target),
+                goal_progress(goal)
+            )?;
+        }
+
+        // Suppressions in the working tree
+        if let Some(suppressions) = $filtered.suppressions() {
+            writeln!($writer, "suppressions: {}", suppressions.summary())?;
+            for (kind, count) in &suppressions.by_kind {
+                writeln!($writer, "  {}: {}", kind, count)?;
+            }
+        }
     };
 }
 
@@ -80,7 +147,7 @@ impl ReportFormatter for TextFormatter {
     fn format(&self, baseline: &Baseline, filter: &dyn CheckFilter) -> anyhow::Result<String> {
         use std::fmt::Write;
 
-        let filtered = FilteredMetrics::new(baseline, filter);
+        let filtered = FilteredMetrics::new(baseline, filter).with_insights(&self.insights);
         // Pre-allocate buffer based on estimated size
         let capacity = TEXT_HEADER_SIZE + filtered.count() * TEXT_METRIC_SIZE;
         let mut output = String::with_capacity(capacity);
@@ -94,7 +161,7 @@ impl ReportFormatter for TextFormatter {
         baseline: &Baseline,
         filter: &dyn CheckFilter,
     ) -> anyhow::Result<()> {
-        let filtered = FilteredMetrics::new(baseline, filter);
+        let filtered = FilteredMetrics::new(baseline, filter).with_insights(&self.insights);
         write_text_report!(writer, baseline, &filtered);
         Ok(())
     }
@@ -4,19 +4,20 @@
 use super::*;
 use crate::baseline::EscapesMetrics;
 use crate::report::test_support::{
-    AllChecks, assert_buffered_matches_streamed, create_test_baseline,
+    AllChecks, ExcludeChecks, assert_buffered_matches_streamed, create_agents_baseline,
+    create_slow_tests_baseline, create_test_baseline, create_test_insights,
 };
 
 #[test]
 fn text_format_empty_baseline() {
-    let formatter = TextFormatter;
+    let formatter = TextFormatter::default();
     assert_eq!(formatter.format_empty(), "No baseline found.\n");
 }
 
 #[test]
 fn text_format_includes_header() {
     let baseline = Baseline::default();
-    let formatter = TextFormatter;
+    let formatter = TextFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("Quench Report"));
     assert!(output.contains("============="));
@@ -25,7 +26,7 @@ fn text_format_includes_header() {
 #[test]
 fn text_format_includes_baseline_date() {
     let baseline = Baseline::default();
-    let formatter = TextFormatter;
+    let formatter = TextFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("Baseline:"));
 }
@@ -33,7 +34,7 @@ fn text_format_includes_baseline_date() {
 #[test]
 fn text_format_includes_commit_when_present() {
     let baseline = create_test_baseline();
-    let formatter = TextFormatter;
+    let formatter = TextFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("abc1234"));
 }
@@ -41,7 +42,7 @@ fn text_format_includes_commit_when_present() {
 #[test]
 fn text_format_includes_coverage() {
     let baseline = create_test_baseline();
-    let formatter = TextFormatter;
+    let formatter = TextFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("coverage: 85.5%"));
 }
@@ -49,7 +50,7 @@ fn text_format_includes_coverage() {
 #[test]
 fn text_format_includes_escapes() {
     let baseline = create_test_baseline();
-    let formatter = TextFormatter;
+    let formatter = TextFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("escapes.unwrap: 10"));
 }
@@ -57,7 +58,7 @@ fn text_format_includes_escapes() {
 #[test]
 fn text_format_includes_build_time() {
     let baseline = create_test_baseline();
-    let formatter = TextFormatter;
+    let formatter = TextFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("build_time.cold: 45.0s"));
     assert!(output.contains("build_time.hot: 12.5s"));
@@ -66,7 +67,7 @@ fn text_format_includes_build_time() {
 #[test]
 fn text_format_includes_binary_size() {
     let baseline = create_test_baseline();
-    let formatter = TextFormatter;
+    let formatter = TextFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("binary_size.quench: 5.0 MB"));
 }
@@ -74,7 +75,7 @@ fn text_format_includes_binary_size() {
 #[test]
 fn text_format_includes_test_time() {
     let baseline = create_test_baseline();
-    let formatter = TextFormatter;
+    let formatter = TextFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
     assert!(output.contains("test_time.total: 30.5s"));
 }
@@ -82,13 +83,13 @@ fn text_format_includes_test_time() {
 #[test]
 fn text_format_to_matches_format() {
     let baseline = create_test_baseline();
-    let formatter = TextFormatter;
+    let formatter = TextFormatter::default();
     assert_buffered_matches_streamed(&formatter, &baseline, &AllChecks);
 }
 
 #[test]
 fn text_format_empty_to_matches_format_empty() {
-    let formatter = TextFormatter;
+    let formatter = TextFormatter::default();
 
     let buffered = formatter.format_empty();
 
@@ -111,9 +112,11 @@ fn text_format_escapes_sorted_alphabetically() {
         .into_iter()
         .collect(),
         test: None,
+        source_lines: None,
+        by_package: None,
     });
 
-    let formatter = TextFormatter;
+    let formatter = TextFormatter::default();
     let output = formatter.format(&baseline, &AllChecks).unwrap();
 
     let alpha_pos = output.find("escapes.alpha").unwrap();
@@ -123,3 +126,87 @@ fn text_format_escapes_sorted_alphabetically() {
     assert!(alpha_pos < middle_pos);
     assert!(middle_pos < zebra_pos);
 }
+
+#[test]
+fn text_format_includes_slow_tests() {
+    let baseline = create_slow_tests_baseline();
+    let output = TextFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(
+        output.contains("slow_tests.unit:\n  tests::big: 2100ms (+700ms)\n  tests::new: 300ms\n")
+    );
+}
+
+#[test]
+fn text_format_includes_tags() {
+    let mut baseline = create_test_baseline();
+    baseline.tags.insert("env".to_string(), "ci".to_string());
+    baseline
+        .tags
+        .insert("branch".to_string(), "main".to_string());
+    let output = TextFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains("Tags: branch=main, env=ci\n"));
+}
+
+#[test]
+fn text_format_includes_health_and_packages() {
+    let baseline = create_test_baseline();
+    let output = TextFormatter::new(create_test_insights())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains("Health: "));
+    assert!(output.contains("  core: 92/100 (A)\n"));
+}
+
+#[test]
+fn text_format_omits_health_without_score() {
+    let baseline = create_test_baseline();
+    let output = TextFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(!output.contains("Health:"));
+}
+
+#[test]
+fn text_format_shows_goal_progress() {
+    let baseline = create_test_baseline();
+    let output = TextFormatter::new(create_test_insights())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(
+        output.contains("goal.coverage: 85.5% -> 90.0% [##########----------] 50% (~9 days)\n")
+    );
+    assert!(
+        output.contains("goal.escapes.unwrap: 10 -> 0 [--------------------] 0% (no progress)\n")
+    );
+}
+
+#[test]
+fn text_format_shows_suppressions() {
+    let baseline = create_test_baseline();
+    let output = TextFormatter::new(create_test_insights())
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains(
+        "suppressions: 3 (1 expired, 0 expiring, oldest 120 days)\n  allow_list: 1\n  inline: 2\n"
+    ));
+
+    let output = TextFormatter::new(create_test_insights())
+        .format(&baseline, &ExcludeChecks(vec!["escapes"]))
+        .unwrap();
+    assert!(!output.contains("suppressions:"));
+}
+
+#[test]
+fn text_format_includes_agent_tokens_largest_first() {
+    let baseline = create_agents_baseline();
+    let output = TextFormatter::default()
+        .format(&baseline, &AllChecks)
+        .unwrap();
+    assert!(output.contains(
+        "agents.total_tokens: 4000\n  CLAUDE.md: 2500 (62%)\n  AGENTS.md: 1000 (25%)\n  .cursor/rules/api.mdc: 500 (12%)\n"
+    ));
+}
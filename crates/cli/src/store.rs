@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! The configured result store, for commands that record or read history.
+//!
+//! `[store] backend = "sqlite"` needs quench built with the `sqlite`
+//! feature; without it, opening the store is a config error rather than a
+//! silent fallback to JSON files.
+
+use std::path::Path;
+
+use quench::baseline::{Baseline, MetricSample};
+use quench::check::CheckOutput;
+use quench::config::Config;
+#[cfg(not(feature = "sqlite"))]
+use quench::error::Error;
+
+/// An open sqlite store. Only exists when `[store] backend = "sqlite"`.
+pub struct ResultStore {
+    #[cfg(feature = "sqlite")]
+    store: quench::store::Store,
+    #[cfg(not(feature = "sqlite"))]
+    never: std::convert::Infallible,
+}
+
+impl ResultStore {
+    /// Open the configured store, or None with the default JSON backend.
+    pub fn open(root: &Path, config: &Config) -> anyhow::Result<Option<Self>> {
+        if !config.store.uses_sqlite() {
+            return Ok(None);
+        }
+        #[cfg(feature = "sqlite")]
+        {
+            let store = quench::store::Store::open(&root.join(&config.store.path))?;
+            Ok(Some(Self { store }))
+        }
+        #[cfg(not(feature = "sqlite"))]
+        {
+            let _ = root;
+            Err(Error::Config {
+                message: "store.backend = \"sqlite\" needs quench built with the `sqlite` feature"
+                    .to_string(),
+                path: None,
+            }
+            .into())
+        }
+    }
+
+    /// Record a `quench check` run.
+    pub fn record_run(
+        &mut self,
+        commit: Option<&str>,
+        ci: bool,
+        output: &CheckOutput,
+    ) -> anyhow::Result<()> {
+        #[cfg(feature = "sqlite")]
+        {
+            self.store.record_run(commit, ci, output)?;
+            Ok(())
+        }
+        #[cfg(not(feature = "sqlite"))]
+        {
+            let _ = (commit, ci, output);
+            match self.never {}
+        }
+    }
+
+    /// Keep a copy of a saved baseline.
+    pub fn record_baseline(&self, baseline: &Baseline) -> anyhow::Result<()> {
+        #[cfg(feature = "sqlite")]
+        {
+            self.store.record_baseline(baseline)?;
+            Ok(())
+        }
+        #[cfg(not(feature = "sqlite"))]
+        {
+            let _ = baseline;
+            match self.never {}
+        }
+    }
+
+    /// A commit's recorded metric value: `Some(None)` if a run was recorded
+    /// without the metric, None if the commit was never recorded.
+    pub fn metric_at(
+        &self,
+        commit: &str,
+        ci: bool,
+        metric: &str,
+    ) -> anyhow::Result<Option<Option<f64>>> {
+        #[cfg(feature = "sqlite")]
+        {
+            if !self.store.has_run(commit, ci)? {
+                return Ok(None);
+            }
+            Ok(Some(self.store.metric_at(commit, ci, metric)?))
+        }
+        #[cfg(not(feature = "sqlite"))]
+        {
+            let _ = (commit, ci, metric);
+            match self.never {}
+        }
+    }
+
+    /// Goal metric values from every recorded baseline, oldest first.
+    pub fn samples(&self) -> anyhow::Result<Vec<MetricSample>> {
+        #[cfg(feature = "sqlite")]
+        {
+            Ok(self.store.samples()?)
+        }
+        #[cfg(not(feature = "sqlite"))]
+        {
+            match self.never {}
+        }
+    }
+
+    /// The underlying database, for queries.
+    #[cfg(feature = "sqlite")]
+    pub fn inner(&self) -> &quench::store::Store {
+        &self.store
+    }
+
+    /// Without the `sqlite` feature a store can't be opened, so code
+    /// holding one is unreachable.
+    #[cfg(not(feature = "sqlite"))]
+    pub fn unreachable(&self) -> ! {
+        match self.never {}
+    }
+}
@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Filesystem watching for `quench watch`.
+//!
+//! Notifications are collected until the tree has been quiet for the
+//! debounce interval, then mapped to the checks the changed files can
+//! affect. Paths under `.git`, `.quench`, or ignored by the root
+//! `.gitignore` are dropped, so a run that writes its cache or build output
+//! doesn't trigger another.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher as _};
+
+use crate::adapter::glob::build_glob_set;
+use crate::checks::CHECK_NAMES;
+use crate::config::Config;
+use crate::interrupt;
+
+/// How often an idle watch checks for Ctrl-C.
+const INTERRUPT_POLL: Duration = Duration::from_millis(100);
+
+/// Directories quench never reacts to: git internals and its own state.
+const IGNORED_DIRS: &[&str] = &[".git", ".quench"];
+
+/// Decides which notified paths count as project changes.
+pub struct PathFilter {
+    root: PathBuf,
+    gitignore: Gitignore,
+}
+
+impl PathFilter {
+    /// Build a filter from the root `.gitignore`, if any.
+    pub fn new(root: &Path) -> Self {
+        let mut builder = GitignoreBuilder::new(root);
+        // A missing or malformed .gitignore just ignores less
+        let _ = builder.add(root.join(".gitignore"));
+        let gitignore = builder.build().unwrap_or_else(|_| Gitignore::empty());
+        Self {
+            root: root.to_path_buf(),
+            gitignore,
+        }
+    }
+
+    /// The path relative to the root, or None if changes to it are ignored.
+    pub fn relative(&self, path: &Path) -> Option<PathBuf> {
+        let relative = path.strip_prefix(&self.root).ok()?;
+        let first = relative.components().next()?;
+        if IGNORED_DIRS.iter().any(|dir| first.as_os_str() == *dir) {
+            return None;
+        }
+        if self
+            .gitignore
+            .matched_path_or_any_parents(relative, path.is_dir())
+            .is_ignore()
+        {
+            return None;
+        }
+        Some(relative.to_path_buf())
+    }
+}
+
+/// Files changed since the last run, relative to the project root.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Changes {
+    paths: BTreeSet<PathBuf>,
+}
+
+impl Changes {
+    pub fn insert(&mut self, relative: PathBuf) {
+        self.paths.insert(relative);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Path> {
+        self.paths.iter().map(PathBuf::as_path)
+    }
+
+    /// A short description for the watch log, e.g. "src/lib.rs and 2 more".
+    pub fn summary(&self) -> String {
+        let mut paths = self.iter();
+        match (paths.next(), self.len()) {
+            (None, _) => "nothing".to_string(),
+            (Some(first), 1) => first.display().to_string(),
+            (Some(first), n) => format!("{} and {} more", first.display(), n - 1),
+        }
+    }
+}
+
+/// Checks a batch of changes can affect, in canonical order.
+///
+/// Per-file checks rerun on any change, and the file cache keeps them to
+/// the changed files. The docs check only reads markdown (a removed file
+/// can also break links), the agents check only reads agent files, and
+/// the git check reads commits rather than the working tree.
+pub fn relevant_checks(changes: &Changes, root: &Path, config: &Config) -> Vec<&'static str> {
+    let agent_files = build_glob_set(&config.check.agents.files);
+    let is_agent_file = |path: &Path| {
+        agent_files.is_match(path) || path.file_name().is_some_and(|n| agent_files.is_match(n))
+    };
+    let docs = changes
+        .iter()
+        .any(|path| path.extension().is_some_and(|ext| ext == "md") || !root.join(path).exists());
+    let agents = changes.iter().any(is_agent_file);
+
+    CHECK_NAMES
+        .iter()
+        .copied()
+        .filter(|name| match *name {
+            "git" => false,
+            "docs" => docs,
+            "agents" => agents,
+            _ => !changes.is_empty(),
+        })
+        .collect()
+}
+
+/// Notifications for a project tree.
+pub struct Watcher {
+    // Dropping the watcher stops notifications
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+    filter: PathFilter,
+}
+
+impl Watcher {
+    /// Start watching `root` recursively.
+    pub fn new(root: &Path) -> notify::Result<Self> {
+        let (tx, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx)?;
+        watcher.watch(root, RecursiveMode::Recursive)?;
+        Ok(Self {
+            _watcher: watcher,
+            events,
+            filter: PathFilter::new(root),
+        })
+    }
+
+    /// Block until a file changes, then keep collecting changes until
+    /// `debounce` passes without one. None once notifications stop or
+    /// Ctrl-C is pressed.
+    pub fn next_batch(&self, debounce: Duration) -> notify::Result<Option<Changes>> {
+        let mut changes = Changes::default();
+        while changes.is_empty() {
+            match self.events.recv_timeout(INTERRUPT_POLL) {
+                Ok(event) => self.collect(event?, &mut changes),
+                Err(RecvTimeoutError::Timeout) if !interrupt::requested() => {}
+                Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => {
+                    return Ok(None);
+                }
+            }
+        }
+        loop {
+            match self.events.recv_timeout(debounce) {
+                Ok(event) => self.collect(event?, &mut changes),
+                Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => {
+                    return Ok(Some(changes));
+                }
+            }
+        }
+    }
+
+    fn collect(&self, event: Event, changes: &mut Changes) {
+        // Reads (including quench's own) aren't changes
+        if matches!(event.kind, EventKind::Access(_)) {
+            return;
+        }
+        for path in &event.paths {
+            if let Some(relative) = self.filter.relative(path) {
+                changes.insert(relative);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "watcher_tests.rs"]
+mod tests;
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::fs;
+
+use super::*;
+
+fn changes(paths: &[&str]) -> Changes {
+    let mut changes = Changes::default();
+    for path in paths {
+        changes.insert(PathBuf::from(path));
+    }
+    changes
+}
+
+// =============================================================================
+// PathFilter
+// =============================================================================
+
+#[test]
+fn filter_ignores_git_and_quench_state() {
+    let dir = tempfile::tempdir().unwrap();
+    let filter = PathFilter::new(dir.path());
+
+    assert_eq!(
+        filter.relative(&dir.path().join("src/lib.rs")),
+        Some(PathBuf::from("src/lib.rs"))
+    );
+    assert_eq!(filter.relative(&dir.path().join(".git/index")), None);
+    assert_eq!(filter.relative(&dir.path().join(".quench/cache.bin")), None);
+    assert_eq!(filter.relative(Path::new("/elsewhere/lib.rs")), None);
+}
+
+#[test]
+fn filter_honors_root_gitignore() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join(".gitignore"), "target/\n*.log\n").unwrap();
+    fs::create_dir_all(dir.path().join("target/debug")).unwrap();
+    let filter = PathFilter::new(dir.path());
+
+    assert_eq!(
+        filter.relative(&dir.path().join("target/debug/quench")),
+        None
+    );
+    assert_eq!(filter.relative(&dir.path().join("run.log")), None);
+    assert!(filter.relative(&dir.path().join("src/main.rs")).is_some());
+}
+
+// =============================================================================
+// Changes
+// =============================================================================
+
+#[test]
+fn summary_names_first_path_and_count() {
+    assert_eq!(changes(&["src/lib.rs"]).summary(), "src/lib.rs");
+    assert_eq!(
+        changes(&["src/b.rs", "src/a.rs", "src/c.rs"]).summary(),
+        "src/a.rs and 2 more"
+    );
+}
+
+// =============================================================================
+// Relevant checks
+// =============================================================================
+
+#[test]
+fn source_change_skips_docs_agents_and_git() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("lib.rs"), "").unwrap();
+
+    let checks = relevant_checks(&changes(&["lib.rs"]), dir.path(), &Config::default());
+    assert!(checks.contains(&"cloc"));
+    assert!(checks.contains(&"escapes"));
+    assert!(!checks.contains(&"docs"));
+    assert!(!checks.contains(&"agents"));
+    assert!(!checks.contains(&"git"));
+}
+
+#[test]
+fn agent_file_change_runs_docs_and_agents() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("crates/core")).unwrap();
+    fs::write(dir.path().join("crates/core/CLAUDE.md"), "").unwrap();
+
+    let checks = relevant_checks(
+        &changes(&["crates/core/CLAUDE.md"]),
+        dir.path(),
+        &Config::default(),
+    );
+    assert!(checks.contains(&"docs"));
+    assert!(checks.contains(&"agents"));
+}
+
+#[test]
+fn removed_file_runs_docs() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let checks = relevant_checks(&changes(&["src/gone.rs"]), dir.path(), &Config::default());
+    assert!(checks.contains(&"docs"));
+    assert!(!checks.contains(&"agents"));
+}
+
+// =============================================================================
+// Watcher
+// =============================================================================
+
+#[test]
+fn next_batch_collects_changed_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().canonicalize().unwrap();
+    let watcher = Watcher::new(&root).unwrap();
+
+    fs::create_dir_all(root.join(".quench")).unwrap();
+    fs::write(root.join(".quench/cache.bin"), "").unwrap();
+    fs::write(root.join("lib.rs"), "fn main() {}\n").unwrap();
+
+    let batch = watcher
+        .next_batch(Duration::from_millis(200))
+        .unwrap()
+        .unwrap();
+    assert_eq!(batch, changes(&["lib.rs"]));
+}
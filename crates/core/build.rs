@@ -27,12 +27,34 @@ pub const CODEX: &str = "CODEX";
 pub const CURSOR: &str = "CURSOR";
 /// Environment variable: indicates CI environment.
 pub const CI: &str = "CI";
+/// Environment variable: set by GitHub Actions.
+pub const GITHUB_ACTIONS: &str = "GITHUB_ACTIONS";
+/// Environment variable: set by GitLab CI.
+pub const GITLAB_CI: &str = "GITLAB_CI";
+/// Environment variable: set by CircleCI.
+pub const CIRCLECI: &str = "CIRCLECI";
+/// Environment variable: set by Buildkite.
+pub const BUILDKITE: &str = "BUILDKITE";
+/// Environment variable: set by Jenkins.
+pub const JENKINS_URL: &str = "JENKINS_URL";
+/// Environment variable: set by Azure Pipelines.
+pub const TF_BUILD: &str = "TF_BUILD";
 /// Environment variable: enables debug file listing.
 pub const QUENCH_DEBUG_FILES: &str = "QUENCH_DEBUG_FILES";
 /// Environment variable: enables debug/verbose output.
 pub const QUENCH_DEBUG: &str = "QUENCH_DEBUG";
+/// Environment variable: disables telemetry uploads (any value).
+pub const QUENCH_NO_TELEMETRY: &str = "QUENCH_NO_TELEMETRY";
 /// Environment variable: configures tracing log filter.
 pub const QUENCH_LOG: &str = "QUENCH_LOG";
+/// Environment variable: container engine for `container` suites and builds.
+pub const QUENCH_CONTAINER_ENGINE: &str = "QUENCH_CONTAINER_ENGINE";
+/// Environment variable: GitHub token for `quench bot` statuses and comments.
+pub const GITHUB_TOKEN: &str = "GITHUB_TOKEN";
+/// Environment variable: GitHub API base URL (GitHub Enterprise).
+pub const GITHUB_API_URL: &str = "GITHUB_API_URL";
+/// Environment variable: webhook secret that `quench bot` verifies deliveries with.
+pub const QUENCH_WEBHOOK_SECRET: &str = "QUENCH_WEBHOOK_SECRET";
 /// Environment variable: user home directory.
 pub const HOME: &str = "HOME";
 /// Environment variable: XDG data home directory.
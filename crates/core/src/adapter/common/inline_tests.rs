@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Inline tests: test code written inside a source file.
+//!
+//! Rust marks them with `#[cfg(test)]` ([`CfgTestInfo`]); Go, Python, and
+//! JavaScript have their own conventions, parsed by each adapter into the
+//! line ranges here. cloc counts these lines as test code, and escapes
+//! treats matches in them as test code.
+//!
+//! [`CfgTestInfo`]: crate::adapter::rust::CfgTestInfo
+
+use std::ops::Range;
+use std::path::Path;
+
+use crate::adapter::rust::CfgTestInfo;
+use crate::adapter::{go, javascript, python};
+
+/// Line ranges of a file's inline tests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InlineTestInfo {
+    /// Line ranges (0-indexed) holding test code, in file order.
+    pub test_ranges: Vec<Range<usize>>,
+}
+
+impl InlineTestInfo {
+    /// Parse inline tests with the convention for `path`'s language.
+    /// Returns None for languages without inline tests.
+    pub fn parse(path: &Path, content: &str) -> Option<Self> {
+        Self::parser(path).map(|parse| parse(content))
+    }
+
+    /// Check if `path`'s language has an inline test convention, without
+    /// reading the file.
+    pub fn supports(path: &Path) -> bool {
+        Self::parser(path).is_some()
+    }
+
+    fn parser(path: &Path) -> Option<fn(&str) -> Self> {
+        let ext = path.extension().and_then(|e| e.to_str())?;
+        match ext.to_ascii_lowercase().as_str() {
+            "rs" => Some(|content| Self::from(&CfgTestInfo::parse(content))),
+            "go" => Some(go::parse_inline_tests),
+            "py" => Some(python::parse_inline_tests),
+            "js" | "jsx" | "ts" | "tsx" | "mjs" | "mts" | "cjs" | "cts" => {
+                Some(javascript::parse_inline_tests)
+            }
+            _ => None,
+        }
+    }
+
+    /// Check if a line (0-indexed) is inline test code.
+    pub fn is_test_line(&self, line_idx: usize) -> bool {
+        self.test_ranges.iter().any(|r| r.contains(&line_idx))
+    }
+
+    /// Check if the file has any inline tests.
+    pub fn has_inline_tests(&self) -> bool {
+        !self.test_ranges.is_empty()
+    }
+}
+
+impl From<&CfgTestInfo> for InlineTestInfo {
+    fn from(info: &CfgTestInfo) -> Self {
+        Self {
+            test_ranges: info.test_ranges.clone(),
+        }
+    }
+}
+
+/// Lexer state for [`block_end`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LexerState {
+    Code,
+    BlockComment,
+    /// Inside a string or rune opened by this quote. Backtick strings (Go
+    /// raw strings, JS template literals) span lines.
+    Quoted(char),
+}
+
+/// Line (0-indexed) of the brace closing the first `{` at or after line
+/// `start`, for C-like syntax (Go, JavaScript). Braces in strings and
+/// comments don't count. With `raw_backticks` (Go), backslashes in
+/// backtick strings are literal. None if the block never opens or closes.
+pub(crate) fn block_end(lines: &[&str], start: usize, raw_backticks: bool) -> Option<usize> {
+    let mut state = LexerState::Code;
+    let mut depth: i32 = 0;
+    let mut opened = false;
+
+    for (offset, line) in lines.get(start..)?.iter().enumerate() {
+        let mut chars = line.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match state {
+                LexerState::Code => match ch {
+                    '/' if chars.peek() == Some(&'/') => break,
+                    '/' if chars.peek() == Some(&'*') => {
+                        chars.next();
+                        state = LexerState::BlockComment;
+                    }
+                    '"' | '\'' | '`' => state = LexerState::Quoted(ch),
+                    '{' => {
+                        depth += 1;
+                        opened = true;
+                    }
+                    '}' if opened => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(start + offset);
+                        }
+                    }
+                    _ => {}
+                },
+                LexerState::BlockComment => {
+                    if ch == '*' && chars.peek() == Some(&'/') {
+                        chars.next();
+                        state = LexerState::Code;
+                    }
+                }
+                LexerState::Quoted(quote) => {
+                    if ch == '\\' && !(quote == '`' && raw_backticks) {
+                        chars.next();
+                    } else if ch == quote {
+                        state = LexerState::Code;
+                    }
+                }
+            }
+        }
+        // Ordinary strings end with the line
+        if matches!(state, LexerState::Quoted(q) if q != '`') {
+            state = LexerState::Code;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+#[path = "inline_tests_tests.rs"]
+mod tests;
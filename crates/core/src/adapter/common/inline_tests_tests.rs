@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+/// Test ranges as (start, end) pairs.
+fn ranges(info: &InlineTestInfo) -> Vec<(usize, usize)> {
+    info.test_ranges.iter().map(|r| (r.start, r.end)).collect()
+}
+
+#[test]
+fn parse_dispatches_by_extension() {
+    let rust = "fn f() {}\n#[cfg(test)]\nmod tests {\n}\n";
+    let info = InlineTestInfo::parse(Path::new("src/lib.rs"), rust);
+    assert_eq!(info.as_ref().map(ranges), Some(vec![(1, 4)]));
+
+    let go = "package x\n\nfunc TestX(t *testing.T) {\n}\n";
+    let info = InlineTestInfo::parse(Path::new("x.go"), go);
+    assert_eq!(info.as_ref().map(ranges), Some(vec![(2, 4)]));
+
+    let ts = "if (import.meta.vitest) {\n}\n";
+    let info = InlineTestInfo::parse(Path::new("src/x.ts"), ts);
+    assert_eq!(info.as_ref().map(ranges), Some(vec![(0, 2)]));
+}
+
+#[test]
+fn parse_returns_none_for_other_languages() {
+    assert_eq!(InlineTestInfo::parse(Path::new("x.sh"), "echo hi\n"), None);
+    assert_eq!(InlineTestInfo::parse(Path::new("Makefile"), "all:\n"), None);
+    assert!(!InlineTestInfo::supports(Path::new("x.sh")));
+    assert!(InlineTestInfo::supports(Path::new("x.PY")));
+}
+
+#[test]
+fn is_test_line_checks_ranges() {
+    let info = InlineTestInfo {
+        test_ranges: vec![2..4, 7..8],
+    };
+    assert!(info.has_inline_tests());
+    assert!(!info.is_test_line(1));
+    assert!(info.is_test_line(2));
+    assert!(info.is_test_line(3));
+    assert!(!info.is_test_line(4));
+    assert!(info.is_test_line(7));
+    assert!(!InlineTestInfo::default().has_inline_tests());
+}
+
+#[test]
+fn block_end_matches_nested_braces() {
+    let lines = ["func f() {", "  if x {", "  }", "}", "func g() {}"];
+    assert_eq!(block_end(&lines, 0, false), Some(3));
+    assert_eq!(block_end(&lines, 4, false), Some(4));
+}
+
+#[test]
+fn block_end_skips_braces_in_strings_and_comments() {
+    let lines = [
+        "func f() {",
+        r#"  s := "}\"}""#,
+        "  r := '}'",
+        "  // }",
+        "  /* } */",
+        "}",
+    ];
+    assert_eq!(block_end(&lines, 0, false), Some(5));
+}
+
+#[test]
+fn block_end_handles_multiline_backticks() {
+    let lines = ["f() {", "  s := `", "  }", "  `", "}"];
+    assert_eq!(block_end(&lines, 0, false), Some(4));
+}
+
+#[test]
+fn block_end_raw_backticks_keep_backslashes() {
+    // In Go raw strings, `\` doesn't escape the closing backtick
+    let lines = ["func f() {", r"  s := `\`", "}"];
+    assert_eq!(block_end(&lines, 0, true), Some(2));
+    assert_eq!(block_end(&lines, 0, false), None);
+}
+
+#[test]
+fn block_end_none_when_unclosed() {
+    assert_eq!(block_end(&["func f() {", "  x"], 0, false), None);
+    assert_eq!(block_end(&["var x = 1"], 0, false), None);
+    assert_eq!(block_end(&["{}"], 5, false), None);
+}
@@ -30,7 +30,7 @@ pub trait PolicyConfig {
 /// Check lint policy against changed files.
 ///
 /// Takes a classifier closure to allow testing without a full adapter.
-pub fn check_lint_policy<P: PolicyConfig>(
+pub fn check_lint_policy<P: PolicyConfig + ?Sized>(
     changed_files: &[&Path],
     policy: &P,
     classify: impl Fn(&Path) -> FileKind,
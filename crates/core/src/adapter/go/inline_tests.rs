@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Inline test detection for Go.
+//!
+//! Go tests usually live in `_test.go` files. In other `.go` files, these
+//! count as inline tests:
+//! - `TestX(t *testing.T)`, `BenchmarkX(b *testing.B)`, `FuzzX(f *testing.F)`,
+//!   and `ExampleX()` functions
+//! - The whole file, when its `//go:build` constraint requires a test tag
+//!   (`test`, or one ending in `_test` such as `integration_test`)
+
+use crate::adapter::common::inline_tests::{InlineTestInfo, block_end};
+
+/// Test function name prefixes, with the parameter type each takes.
+const TEST_FUNCS: &[(&str, Option<&str>)] = &[
+    ("Test", Some("*testing.T")),
+    ("Benchmark", Some("*testing.B")),
+    ("Fuzz", Some("*testing.F")),
+    ("Example", None),
+];
+
+/// Parse a Go source file for inline tests.
+pub fn parse_inline_tests(content: &str) -> InlineTestInfo {
+    let lines: Vec<&str> = content.lines().collect();
+    if has_test_build_tag(&lines) {
+        return InlineTestInfo {
+            test_ranges: std::iter::once(0..lines.len()).collect(),
+        };
+    }
+
+    let mut info = InlineTestInfo::default();
+    let mut line_idx = 0;
+    while line_idx < lines.len() {
+        if is_test_func(lines[line_idx])
+            && let Some(end) = block_end(&lines, line_idx, true)
+        {
+            info.test_ranges.push(line_idx..end + 1);
+            line_idx = end + 1;
+        } else {
+            line_idx += 1;
+        }
+    }
+    info
+}
+
+/// Whether the file's build constraint requires a test tag.
+fn has_test_build_tag(lines: &[&str]) -> bool {
+    for line in lines {
+        let trimmed = line.trim();
+        // Constraints must come before the package clause
+        if trimmed.starts_with("package ") {
+            break;
+        }
+        if let Some(expr) = trimmed.strip_prefix("//go:build") {
+            return expr
+                .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '!'))
+                .any(|tag| tag == "test" || (tag.ends_with("_test") && !tag.starts_with('!')));
+        }
+    }
+    false
+}
+
+/// Whether a line declares a top-level test function. Methods never are.
+fn is_test_func(line: &str) -> bool {
+    let Some(rest) = line.strip_prefix("func ") else {
+        return false;
+    };
+    let Some(paren) = rest.find('(') else {
+        return false;
+    };
+    let name = rest[..paren].trim();
+    TEST_FUNCS.iter().any(|(prefix, param)| {
+        // `go test` only runs names whose next letter isn't lowercase
+        let Some(suffix) = name.strip_prefix(prefix) else {
+            return false;
+        };
+        let named = !suffix.starts_with(|c: char| c.is_lowercase());
+        named && param.is_none_or(|param| rest[paren..].contains(param))
+    })
+}
+
+#[cfg(test)]
+#[path = "inline_tests_tests.rs"]
+mod tests;
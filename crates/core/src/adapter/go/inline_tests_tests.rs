@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+/// Test ranges as (start, end) pairs.
+fn ranges(info: &InlineTestInfo) -> Vec<(usize, usize)> {
+    info.test_ranges.iter().map(|r| (r.start, r.end)).collect()
+}
+
+#[test]
+fn test_functions_are_inline_tests() {
+    let content = r#"package math
+
+func Add(a, b int) int {
+	return a + b
+}
+
+func TestAdd(t *testing.T) {
+	if Add(1, 2) != 3 {
+		t.Fatal("bad")
+	}
+}
+
+func BenchmarkAdd(b *testing.B) {
+	for i := 0; i < b.N; i++ {
+		Add(1, 2)
+	}
+}
+"#;
+    let info = parse_inline_tests(content);
+    assert_eq!(ranges(&info), vec![(6, 11), (12, 17)]);
+    assert!(!info.is_test_line(3)); // return a + b
+    assert!(info.is_test_line(8)); // t.Fatal
+}
+
+#[test]
+fn fuzz_and_example_functions_are_inline_tests() {
+    let content = "package x\n\nfunc FuzzParse(f *testing.F) {\n}\n\nfunc ExampleParse() {\n}\n";
+    let info = parse_inline_tests(content);
+    assert_eq!(ranges(&info), vec![(2, 4), (5, 7)]);
+}
+
+#[test]
+fn lookalike_functions_are_source() {
+    let content = r#"package x
+
+func Testify(t *testing.T) {
+}
+
+func TestHelper(name string) {
+}
+
+func (s *Suite) TestMethod(t *testing.T) {
+}
+"#;
+    assert!(!parse_inline_tests(content).has_inline_tests());
+}
+
+#[test]
+fn braces_in_raw_strings_dont_end_test() {
+    let content = "package x\n\nfunc TestJSON(t *testing.T) {\n\ts := `{\"a\": }\\`\n\t_ = s\n}\n";
+    let info = parse_inline_tests(content);
+    assert_eq!(ranges(&info), vec![(2, 6)]);
+}
+
+#[test]
+fn test_build_tag_marks_whole_file() {
+    let content = "//go:build integration_test && linux\n\npackage x\n\nfunc helper() {}\n";
+    let info = parse_inline_tests(content);
+    assert_eq!(ranges(&info), vec![(0, 5)]);
+
+    let content = "//go:build test\n\npackage x\n";
+    assert!(parse_inline_tests(content).is_test_line(2));
+}
+
+#[test]
+fn negated_or_unrelated_build_tags_are_source() {
+    for tag in ["!test", "!integration_test", "linux", "testing"] {
+        let content = format!("//go:build {tag}\n\npackage x\n\nfunc f() {{}}\n");
+        assert!(!parse_inline_tests(&content).has_inline_tests(), "{tag}");
+    }
+}
+
+#[test]
+fn build_tag_after_package_is_ignored() {
+    let content = "package x\n\n//go:build test\nfunc f() {}\n";
+    assert!(!parse_inline_tests(content).has_inline_tests());
+}
@@ -5,6 +5,7 @@
 //!
 //! Provides Go-specific behavior for checks:
 //! - File classification (source vs test)
+//! - Inline test detection (test functions in non-`_test.go` files)
 //! - Default patterns for Go projects
 //! - Go-specific escape patterns (unsafe.Pointer, go:linkname, go:noescape)
 //!
@@ -14,9 +15,11 @@ use std::path::Path;
 
 use globset::GlobSet;
 
+mod inline_tests;
 mod suppress;
 
 pub use crate::adapter::common::policy::PolicyCheckResult;
+pub use inline_tests::parse_inline_tests;
 pub use suppress::{NolintDirective, parse_nolint_directives};
 
 use super::common;
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Inline test detection for JavaScript and TypeScript.
+//!
+//! Vitest runs in-source tests written in an `if (import.meta.vitest) { ... }`
+//! block, which bundlers drop from production builds. Each such block
+//! counts as inline tests.
+
+use crate::adapter::common::inline_tests::{InlineTestInfo, block_end};
+
+/// Parse a JavaScript or TypeScript source file for inline tests.
+pub fn parse_inline_tests(content: &str) -> InlineTestInfo {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut info = InlineTestInfo::default();
+    let mut line_idx = 0;
+    while line_idx < lines.len() {
+        if is_vitest_guard(lines[line_idx])
+            && let Some(end) = block_end(&lines, line_idx, false)
+        {
+            info.test_ranges.push(line_idx..end + 1);
+            line_idx = end + 1;
+        } else {
+            line_idx += 1;
+        }
+    }
+    info
+}
+
+/// Whether a line opens an `if (import.meta.vitest)` block.
+fn is_vitest_guard(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    let Some(condition) = trimmed.strip_prefix("if") else {
+        return false;
+    };
+    let condition: String = condition.chars().filter(|c| !c.is_whitespace()).collect();
+    condition.starts_with("(import.meta.vitest)")
+}
+
+#[cfg(test)]
+#[path = "inline_tests_tests.rs"]
+mod tests;
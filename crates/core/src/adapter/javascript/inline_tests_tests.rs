@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+/// Test ranges as (start, end) pairs.
+fn ranges(info: &InlineTestInfo) -> Vec<(usize, usize)> {
+    info.test_ranges.iter().map(|r| (r.start, r.end)).collect()
+}
+
+#[test]
+fn vitest_block_is_inline_test() {
+    let content = r#"export function add(a, b) {
+  return a + b;
+}
+
+if (import.meta.vitest) {
+  const { it, expect } = import.meta.vitest;
+  it('adds', () => {
+    expect(add(1, 2)).toBe(3);
+  });
+}
+"#;
+    let info = parse_inline_tests(content);
+    assert_eq!(ranges(&info), vec![(4, 10)]);
+    assert!(!info.is_test_line(1)); // return a + b
+    assert!(info.is_test_line(7)); // expect
+}
+
+#[test]
+fn braces_in_template_literals_dont_end_block() {
+    let content =
+        "if (import.meta.vitest) {\n  const s = `\n}\n`;\n  test('x', () => {});\n}\nexport {};\n";
+    let info = parse_inline_tests(content);
+    assert_eq!(ranges(&info), vec![(0, 6)]);
+}
+
+#[test]
+fn guard_spacing_variants_are_recognized() {
+    let content = "if(import.meta.vitest){\n}\n";
+    assert_eq!(ranges(&parse_inline_tests(content)), vec![(0, 2)]);
+}
+
+#[test]
+fn other_conditions_are_source() {
+    let content = "if (import.meta.env.DEV) {\n  log();\n}\n// if (import.meta.vitest) {}\n";
+    assert!(!parse_inline_tests(content).has_inline_tests());
+}
@@ -7,6 +7,7 @@
 //! - File classification (source vs test)
 //! - Default patterns for JS/TS projects
 //! - JS/TS-specific escape patterns (Phase 495)
+//! - Inline test detection (Vitest `import.meta.vitest` blocks)
 //!
 //! See docs/specs/langs/javascript.md for specification.
 
@@ -15,12 +16,14 @@ use std::path::Path;
 use globset::GlobSet;
 
 mod bundler;
+mod inline_tests;
 mod package_manager;
 mod suppress;
 mod workspace;
 
 pub use crate::adapter::common::policy::PolicyCheckResult;
 pub use bundler::{Bundler, detect_bundler};
+pub use inline_tests::parse_inline_tests;
 pub use package_manager::PackageManager;
 pub use suppress::{JavaScriptSuppress, SuppressTool, parse_javascript_suppresses};
 pub use workspace::JsWorkspace;
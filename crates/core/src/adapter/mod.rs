@@ -81,6 +81,7 @@ pub mod ruby;
 pub mod rust;
 pub mod shell;
 
+pub use common::inline_tests::InlineTestInfo;
 pub use generic::GenericAdapter;
 pub use go::{enumerate_packages, parse_nolint_directives};
 pub use javascript::JsWorkspace;
@@ -93,7 +94,7 @@ pub(crate) use go::GoAdapter;
 pub(crate) use javascript::{Bundler, JavaScriptAdapter, detect_bundler};
 pub(crate) use python::PythonAdapter;
 pub(crate) use ruby::{RubyAdapter, parse_ruby_suppresses};
-pub use rust::{CfgTestInfo, RustAdapter};
+pub use rust::{CfgTestInfo, FeatureGates, RustAdapter, UnsafeCounts, UnsafeInfo};
 
 /// File classification result.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -111,7 +112,9 @@ pub enum FileKind {
 /// Adapters are responsible for:
 /// - Classifying files as source, test, or other
 /// - Providing default escape patterns
-/// - TODO(Future): Inline test detection, lint suppression patterns
+/// - TODO(Future): Lint suppression patterns
+///
+/// Inline tests are parsed per language by [`InlineTestInfo::parse`].
 pub trait Adapter: Send + Sync {
     /// Adapter identifier (e.g., "rust", "shell", "generic").
     fn name(&self) -> &'static str;
@@ -197,6 +200,12 @@ impl AdapterRegistry {
     pub fn classify(&self, path: &Path) -> FileKind {
         self.adapter_for(path).classify(path)
     }
+
+    /// Whether a language adapter (not the fallback) handles this file.
+    pub fn has_language_adapter(&self, path: &Path) -> bool {
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        self.by_extension.contains_key(ext)
+    }
 }
 
 impl Default for AdapterRegistry {
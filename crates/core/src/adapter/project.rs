@@ -213,5 +213,15 @@ pub fn apply_language_defaults(root: &Path, config: &mut Config) -> Vec<String>
         ProjectLanguage::Generic => {}
     }
 
+    // Named check instances and path scopes share the detected packages
+    for instance in &mut config.instances {
+        instance.config.project.packages = config.project.packages.clone();
+        instance.config.project.package_names = config.project.package_names.clone();
+    }
+    for scope in &mut config.scopes {
+        scope.config.project.packages = config.project.packages.clone();
+        scope.config.project.package_names = config.project.package_names.clone();
+    }
+
     exclude_patterns
 }
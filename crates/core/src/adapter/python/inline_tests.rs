@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Inline test detection for Python.
+//!
+//! In source files, these count as inline tests:
+//! - Doctest examples in docstrings: `>>>` lines, their `...` continuations,
+//!   and the expected output up to the next blank line
+//! - An `if __name__ == "__main__":` block that runs tests (`doctest`,
+//!   `unittest.main`, or `pytest.main`). Other main blocks are entry points.
+
+use crate::adapter::common::inline_tests::InlineTestInfo;
+
+/// Calls that make a main block a test runner.
+const TEST_RUNNERS: &[&str] = &["doctest.", "testmod(", "unittest.main", "pytest.main"];
+
+/// Parse a Python source file for inline tests.
+pub fn parse_inline_tests(content: &str) -> InlineTestInfo {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut is_test = doctest_lines(&lines);
+    for range in main_test_blocks(&lines) {
+        is_test[range].fill(true);
+    }
+
+    // Merge consecutive test lines into ranges
+    let mut info = InlineTestInfo::default();
+    let mut start = None;
+    for (line_idx, &test) in is_test.iter().chain([&false]).enumerate() {
+        match (test, start) {
+            (true, None) => start = Some(line_idx),
+            (false, Some(begin)) => {
+                info.test_ranges.push(begin..line_idx);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    info
+}
+
+/// Mark the doctest lines inside docstrings.
+fn doctest_lines(lines: &[&str]) -> Vec<bool> {
+    let mut is_test = vec![false; lines.len()];
+    // The delimiter of the open triple-quoted string, if any
+    let mut open: Option<&str> = None;
+    let mut in_example = false;
+
+    for (line_idx, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        match open {
+            Some(delim) => {
+                if trimmed.starts_with(">>>") {
+                    in_example = true;
+                } else if trimmed.is_empty() || trimmed == delim {
+                    in_example = false;
+                }
+                is_test[line_idx] = in_example;
+                if trimmed.matches(delim).count() % 2 == 1 {
+                    open = None;
+                    in_example = false;
+                }
+            }
+            None => {
+                open = ["\"\"\"", "'''"]
+                    .into_iter()
+                    .find(|delim| trimmed.matches(delim).count() % 2 == 1);
+            }
+        }
+    }
+    is_test
+}
+
+/// Line ranges of `if __name__ == "__main__":` blocks that run tests.
+fn main_test_blocks(lines: &[&str]) -> Vec<std::ops::Range<usize>> {
+    let mut blocks = Vec::new();
+    for (start, line) in lines.iter().enumerate() {
+        if !is_main_guard(line) {
+            continue;
+        }
+        // The block is every indented line after the guard
+        let body_end = lines[start + 1..]
+            .iter()
+            .position(|l| !l.trim().is_empty() && !l.starts_with([' ', '\t']))
+            .map_or(lines.len(), |offset| start + 1 + offset);
+        let end = (start + 1..body_end)
+            .rev()
+            .find(|&i| !lines[i].trim().is_empty())
+            .map_or(start + 1, |last| last + 1);
+        let runs_tests = lines[start..end]
+            .iter()
+            .any(|l| TEST_RUNNERS.iter().any(|runner| l.contains(runner)));
+        if runs_tests {
+            blocks.push(start..end);
+        }
+    }
+    blocks
+}
+
+/// Whether a top-level line is `if __name__ == "__main__":`, either way
+/// round and with either quote.
+fn is_main_guard(line: &str) -> bool {
+    let compact: String = line
+        .split('#')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| if c == '\'' { '"' } else { c })
+        .collect();
+    line.starts_with("if")
+        && matches!(
+            compact.as_str(),
+            "if__name__==\"__main__\":" | "if\"__main__\"==__name__:"
+        )
+}
+
+#[cfg(test)]
+#[path = "inline_tests_tests.rs"]
+mod tests;
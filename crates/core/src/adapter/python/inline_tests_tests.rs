@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+/// Test ranges as (start, end) pairs.
+fn ranges(info: &InlineTestInfo) -> Vec<(usize, usize)> {
+    info.test_ranges.iter().map(|r| (r.start, r.end)).collect()
+}
+
+#[test]
+fn doctest_examples_are_inline_tests() {
+    let content = r#"def add(a, b):
+    """Add two numbers.
+
+    >>> add(1, 2)
+    3
+    >>> add(
+    ...     2, 3)
+    5
+
+    More prose.
+    """
+    return a + b
+"#;
+    let info = parse_inline_tests(content);
+    assert_eq!(ranges(&info), vec![(3, 8)]);
+    assert!(!info.is_test_line(0)); // def
+    assert!(!info.is_test_line(9)); // More prose.
+    assert!(!info.is_test_line(11)); // return
+}
+
+#[test]
+fn doctest_output_ends_at_closing_quotes() {
+    let content = "def f():\n    '''\n    >>> f()\n    1\n    '''\n    return 1\n";
+    let info = parse_inline_tests(content);
+    assert_eq!(ranges(&info), vec![(2, 4)]);
+}
+
+#[test]
+fn prompts_outside_docstrings_are_source() {
+    let content = "x = 1\nprint('>>> not a doctest')\n";
+    assert!(!parse_inline_tests(content).has_inline_tests());
+}
+
+#[test]
+fn main_block_running_tests_is_inline_test() {
+    let content = r#"def f():
+    return 1
+
+
+if __name__ == '__main__':
+    import doctest
+
+    doctest.testmod()
+"#;
+    let info = parse_inline_tests(content);
+    assert_eq!(ranges(&info), vec![(4, 8)]);
+}
+
+#[test]
+fn main_block_variants_are_recognized() {
+    for guard in [
+        "if __name__ == \"__main__\":",
+        "if '__main__' == __name__:",
+        "if __name__=='__main__':  # run tests",
+    ] {
+        let content = format!("{guard}\n    unittest.main()\n");
+        let info = parse_inline_tests(&content);
+        assert_eq!(ranges(&info), vec![(0, 2)], "{guard}");
+    }
+}
+
+#[test]
+fn entry_point_main_block_is_source() {
+    let content = "def main():\n    pass\n\nif __name__ == \"__main__\":\n    main()\n";
+    assert!(!parse_inline_tests(content).has_inline_tests());
+}
+
+#[test]
+fn main_block_ends_at_dedent() {
+    let content = r#"if __name__ == "__main__":
+    pytest.main([__file__])
+
+x = 1
+"#;
+    let info = parse_inline_tests(content);
+    assert_eq!(ranges(&info), vec![(0, 2)]);
+}
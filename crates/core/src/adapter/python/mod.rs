@@ -12,6 +12,7 @@
 //! - Lint config policy checking
 //! - Suppress directive parsing (noqa, type: ignore, pylint)
 //! - Package manager detection (pip, poetry, uv, pipenv)
+//! - Inline test detection (doctests and test-running main blocks)
 //!
 //! See docs/specs/langs/python.md for specification.
 
@@ -23,9 +24,11 @@ pub use package_manager::{PackageManager, PythonTooling};
 
 use globset::GlobSet;
 
+mod inline_tests;
 mod suppress;
 
 pub use crate::adapter::common::policy::PolicyCheckResult;
+pub use inline_tests::parse_inline_tests;
 pub use suppress::{PythonSuppress, PythonSuppressKind, parse_python_suppresses};
 
 use super::common;
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Cargo feature gate detection.
+//!
+//! Parses Rust source files with `syn` to find items under
+//! `#[cfg(...)]` predicates that name a feature, including inside `all`,
+//! `any`, and `not`, and every feature named by `cfg`, `cfg_attr`, or
+//! `cfg!`. Code inside macro invocations is not parsed.
+
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{Attribute, Expr, Lit, Meta, Token};
+
+/// Feature gates in one Rust source file.
+#[derive(Debug, Default)]
+pub struct FeatureGates {
+    /// Lines (0-indexed) of each outermost item gated on a feature.
+    pub items: Vec<Range<usize>>,
+    /// Out-of-line modules (`mod name;`) gated on a feature, by name.
+    pub modules: Vec<String>,
+    /// Every feature named in a `cfg`, `cfg_attr`, or `cfg!`, with the
+    /// line (0-indexed) naming it.
+    pub uses: Vec<(String, usize)>,
+    /// Whether an inner `#![cfg(...)]` gates the whole file on a feature.
+    pub whole_file: bool,
+    /// Top-level items in the file.
+    pub top_level_items: usize,
+}
+
+impl FeatureGates {
+    /// Parse a Rust source file to find feature gates.
+    ///
+    /// Returns `None` when the file does not parse. Files that never
+    /// mention `feature` are not parsed at all.
+    pub fn parse(content: &str) -> Option<Self> {
+        if !content.contains("feature") {
+            return Some(Self::default());
+        }
+        let file = syn::parse_file(content.trim_start_matches('\u{feff}')).ok()?;
+        let mut finder = Finder::default();
+        finder.visit_file(&file);
+        let whole_file = file.attrs.iter().any(|attr| !cfg_features(attr).is_empty());
+        Some(Self {
+            items: finder.items,
+            modules: finder.modules,
+            uses: finder.uses,
+            whole_file,
+            top_level_items: file.items.len(),
+        })
+    }
+
+    /// Non-blank lines inside gated items (every non-blank line when the
+    /// whole file is gated).
+    pub fn lines(&self, content: &str) -> usize {
+        let gated: BTreeSet<usize> = self.items.iter().flat_map(Range::clone).collect();
+        content
+            .lines()
+            .enumerate()
+            .filter(|(idx, line)| {
+                (self.whole_file || gated.contains(idx)) && !line.trim().is_empty()
+            })
+            .count()
+    }
+
+    /// Gated items, counting every top-level item when the whole file is gated.
+    pub fn item_count(&self) -> usize {
+        if self.whole_file {
+            self.top_level_items
+        } else {
+            self.items.len()
+        }
+    }
+}
+
+/// Collects gated items and feature names while walking the syntax tree.
+#[derive(Default)]
+struct Finder {
+    items: Vec<Range<usize>>,
+    modules: Vec<String>,
+    uses: Vec<(String, usize)>,
+    /// Nesting depth inside gated items, so only the outermost count.
+    gated_depth: usize,
+}
+
+impl Finder {
+    /// Record `node` if `attrs` gate it on a feature, then walk it.
+    fn gated<N: Spanned>(&mut self, attrs: &[Attribute], node: &N, walk: impl FnOnce(&mut Self)) {
+        let gated = attrs.iter().any(|attr| !cfg_features(attr).is_empty());
+        if gated && self.gated_depth == 0 {
+            let span = node.span();
+            let first = span.start().line.saturating_sub(1);
+            self.items.push(first..span.end().line.max(first + 1));
+        }
+        self.gated_depth += usize::from(gated);
+        walk(self);
+        self.gated_depth -= usize::from(gated);
+    }
+}
+
+impl<'ast> Visit<'ast> for Finder {
+    fn visit_attribute(&mut self, attr: &'ast Attribute) {
+        let line = attr.span().start().line.saturating_sub(1);
+        let mut features = cfg_features(attr);
+        if attr.path().is_ident("cfg_attr")
+            && let Ok(args) = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            && let Some(predicate) = args.first()
+        {
+            collect_features(predicate, &mut features);
+        }
+        self.uses.extend(features.into_iter().map(|f| (f, line)));
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        if mac.path.is_ident("cfg")
+            && let Ok(args) = mac.parse_body_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        {
+            let line = mac.path.span().start().line.saturating_sub(1);
+            let mut features = Vec::new();
+            for meta in &args {
+                collect_features(meta, &mut features);
+            }
+            self.uses.extend(features.into_iter().map(|f| (f, line)));
+        }
+        visit::visit_macro(self, mac);
+    }
+
+    fn visit_item(&mut self, node: &'ast syn::Item) {
+        let attrs = item_attrs(node);
+        if let syn::Item::Mod(module) = node
+            && module.content.is_none()
+            && attrs.iter().any(|attr| !cfg_features(attr).is_empty())
+        {
+            self.modules.push(module.ident.to_string());
+        }
+        self.gated(attrs, node, |finder| visit::visit_item(finder, node));
+    }
+
+    fn visit_impl_item(&mut self, node: &'ast syn::ImplItem) {
+        let attrs = match node {
+            syn::ImplItem::Const(item) => &item.attrs[..],
+            syn::ImplItem::Fn(item) => &item.attrs,
+            syn::ImplItem::Type(item) => &item.attrs,
+            syn::ImplItem::Macro(item) => &item.attrs,
+            _ => &[],
+        };
+        self.gated(attrs, node, |finder| visit::visit_impl_item(finder, node));
+    }
+
+    fn visit_trait_item(&mut self, node: &'ast syn::TraitItem) {
+        let attrs = match node {
+            syn::TraitItem::Const(item) => &item.attrs[..],
+            syn::TraitItem::Fn(item) => &item.attrs,
+            syn::TraitItem::Type(item) => &item.attrs,
+            syn::TraitItem::Macro(item) => &item.attrs,
+            _ => &[],
+        };
+        self.gated(attrs, node, |finder| visit::visit_trait_item(finder, node));
+    }
+}
+
+fn item_attrs(item: &syn::Item) -> &[Attribute] {
+    match item {
+        syn::Item::Const(item) => &item.attrs,
+        syn::Item::Enum(item) => &item.attrs,
+        syn::Item::ExternCrate(item) => &item.attrs,
+        syn::Item::Fn(item) => &item.attrs,
+        syn::Item::ForeignMod(item) => &item.attrs,
+        syn::Item::Impl(item) => &item.attrs,
+        syn::Item::Macro(item) => &item.attrs,
+        syn::Item::Mod(item) => &item.attrs,
+        syn::Item::Static(item) => &item.attrs,
+        syn::Item::Struct(item) => &item.attrs,
+        syn::Item::Trait(item) => &item.attrs,
+        syn::Item::TraitAlias(item) => &item.attrs,
+        syn::Item::Type(item) => &item.attrs,
+        syn::Item::Union(item) => &item.attrs,
+        syn::Item::Use(item) => &item.attrs,
+        _ => &[],
+    }
+}
+
+/// Features a `#[cfg(...)]` attribute names (empty for other attributes).
+fn cfg_features(attr: &Attribute) -> Vec<String> {
+    let mut features = Vec::new();
+    if attr.path().is_ident("cfg") {
+        collect_features(&attr.meta, &mut features);
+    }
+    features
+}
+
+/// Features named in a cfg predicate, through `all`, `any`, and `not`.
+fn collect_features(meta: &Meta, out: &mut Vec<String>) {
+    match meta {
+        Meta::NameValue(pair) if pair.path.is_ident("feature") => {
+            if let Expr::Lit(expr) = &pair.value
+                && let Lit::Str(name) = &expr.lit
+            {
+                out.push(name.value());
+            }
+        }
+        Meta::List(list) => {
+            if let Ok(nested) =
+                list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            {
+                for meta in &nested {
+                    collect_features(meta, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+#[path = "feature_gates_tests.rs"]
+mod tests;
@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn used(gates: &FeatureGates) -> Vec<(&str, usize)> {
+    gates
+        .uses
+        .iter()
+        .map(|(name, line)| (name.as_str(), *line))
+        .collect()
+}
+
+#[test]
+fn gated_items_and_lines() {
+    let content = r#"pub fn always() {}
+
+#[cfg(feature = "serde")]
+pub fn to_json() {
+
+    todo!()
+}
+
+#[cfg(all(unix, not(feature = "std")))]
+mod bare {
+    #[cfg(feature = "std")]
+    fn nested() {}
+}
+"#;
+    let gates = FeatureGates::parse(content).unwrap();
+
+    assert_eq!(gates.items, vec![2..7, 8..13]);
+    assert_eq!(gates.item_count(), 2);
+    assert_eq!(gates.lines(content), 9);
+    assert_eq!(used(&gates), [("serde", 2), ("std", 8), ("std", 10)]);
+}
+
+#[test]
+fn impl_and_trait_items() {
+    let content = r#"
+impl S {
+    #[cfg(feature = "fast")]
+    fn fast(&self) {}
+}
+
+trait T {
+    #[cfg(feature = "fast")]
+    fn fast(&self);
+}
+"#;
+    let gates = FeatureGates::parse(content).unwrap();
+
+    assert_eq!(gates.items, vec![2..4, 7..9]);
+}
+
+#[test]
+fn other_cfgs_are_not_gates() {
+    let content = r#"
+#[cfg(test)]
+mod tests {}
+
+#[cfg(target_os = "linux")]
+fn linux() {}
+
+// #[cfg(feature = "commented")]
+fn name() -> &'static str {
+    "feature"
+}
+"#;
+    let gates = FeatureGates::parse(content).unwrap();
+
+    assert!(gates.items.is_empty());
+    assert!(gates.uses.is_empty());
+}
+
+#[test]
+fn cfg_attr_and_cfg_macro_are_uses_not_gates() {
+    let content = r#"
+#[cfg_attr(feature = "serde", derive(Serialize))]
+struct S;
+
+fn f() -> bool {
+    cfg!(feature = "fast")
+}
+"#;
+    let gates = FeatureGates::parse(content).unwrap();
+
+    assert!(gates.items.is_empty());
+    assert_eq!(used(&gates), [("serde", 1), ("fast", 5)]);
+}
+
+#[test]
+fn gated_out_of_line_modules() {
+    let content = "#[cfg(feature = \"cli\")]\nmod cli;\nmod core;\n";
+    let gates = FeatureGates::parse(content).unwrap();
+
+    assert_eq!(gates.modules, ["cli"]);
+    assert_eq!(gates.items, vec![0..2]);
+}
+
+#[test]
+fn inner_cfg_gates_whole_file() {
+    let content = "#![cfg(feature = \"cli\")]\n\nfn a() {}\nfn b() {}\n";
+    let gates = FeatureGates::parse(content).unwrap();
+
+    assert!(gates.whole_file);
+    assert_eq!(gates.item_count(), 2);
+    assert_eq!(gates.lines(content), 3);
+}
+
+#[test]
+fn unparseable_file_is_none() {
+    assert!(FeatureGates::parse("#[cfg(feature = \"x\")] fn f( {").is_none());
+}
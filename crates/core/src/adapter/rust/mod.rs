@@ -18,12 +18,16 @@ use super::common;
 use super::glob::build_glob_set;
 
 mod cfg_test;
+mod feature_gates;
 mod suppress;
+mod unsafe_code;
 mod workspace;
 
 pub use crate::adapter::common::policy::PolicyCheckResult;
 pub use cfg_test::{CfgTestBlock, CfgTestInfo, CfgTestItemKind};
+pub use feature_gates::FeatureGates;
 pub use suppress::{SuppressAttr, parse_suppress_attrs};
+pub use unsafe_code::{UnsafeCounts, UnsafeInfo, UnsafeKind, UnsafeSite};
 pub use workspace::CargoWorkspace;
 
 use super::{Adapter, EscapeAction, EscapePattern, FileKind};
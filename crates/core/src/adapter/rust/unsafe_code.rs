@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unsafe code detection.
+//!
+//! Parses Rust source files with `syn` to find unsafe blocks, functions,
+//! and impls. Unlike the `unsafe\s*\{` escape pattern, this never matches
+//! inside strings or comments, tells `unsafe fn` and `unsafe impl` apart
+//! from blocks, and measures the lines each block spans. Code inside
+//! macro invocations is not parsed and is not counted.
+
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+use proc_macro2::Span;
+use syn::visit::{self, Visit};
+
+/// The kind of unsafe code found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnsafeKind {
+    /// `unsafe { ... }`
+    Block,
+    /// `unsafe fn`, including methods and trait declarations
+    Fn,
+    /// `unsafe impl`
+    Impl,
+}
+
+/// A single piece of unsafe code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsafeSite {
+    pub kind: UnsafeKind,
+    /// Lines (0-indexed) from the `unsafe` keyword through the closing
+    /// brace for blocks; the signature line for functions and impls.
+    pub lines: Range<usize>,
+}
+
+/// Unsafe counts for one file or many.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UnsafeCounts {
+    pub blocks: usize,
+    pub fns: usize,
+    pub impls: usize,
+    /// Non-blank lines inside unsafe blocks (nested blocks count once).
+    pub lines: usize,
+}
+
+impl UnsafeCounts {
+    /// Add another file's counts to these.
+    pub fn add(&mut self, other: UnsafeCounts) {
+        self.blocks += other.blocks;
+        self.fns += other.fns;
+        self.impls += other.impls;
+        self.lines += other.lines;
+    }
+}
+
+/// Result of parsing a Rust file for unsafe code.
+#[derive(Debug, Default)]
+pub struct UnsafeInfo {
+    pub sites: Vec<UnsafeSite>,
+}
+
+impl UnsafeInfo {
+    /// Parse a Rust source file to find unsafe code.
+    ///
+    /// Returns `None` when the file does not parse. Files that never
+    /// mention `unsafe` are not parsed at all.
+    pub fn parse(content: &str) -> Option<Self> {
+        if !content.contains("unsafe") {
+            return Some(Self::default());
+        }
+        let file = syn::parse_file(content.trim_start_matches('\u{feff}')).ok()?;
+        let mut finder = Finder::default();
+        finder.visit_file(&file);
+        Some(Self {
+            sites: finder.sites,
+        })
+    }
+
+    /// Count sites, leaving out those starting on lines `skip` accepts
+    /// (0-indexed), such as `#[cfg(test)]` code.
+    pub fn count(&self, content: &str, skip: impl Fn(usize) -> bool) -> UnsafeCounts {
+        let mut counts = UnsafeCounts::default();
+        let mut block_lines = BTreeSet::new();
+        for site in self.sites.iter().filter(|s| !skip(s.lines.start)) {
+            match site.kind {
+                UnsafeKind::Block => {
+                    counts.blocks += 1;
+                    block_lines.extend(site.lines.clone());
+                }
+                UnsafeKind::Fn => counts.fns += 1,
+                UnsafeKind::Impl => counts.impls += 1,
+            }
+        }
+        counts.lines = content
+            .lines()
+            .enumerate()
+            .filter(|(idx, line)| block_lines.contains(idx) && !line.trim().is_empty())
+            .count();
+        counts
+    }
+}
+
+/// Collects unsafe sites while walking the syntax tree.
+#[derive(Default)]
+struct Finder {
+    sites: Vec<UnsafeSite>,
+}
+
+impl Finder {
+    fn push(&mut self, kind: UnsafeKind, start: Span, end: Span) {
+        // Span lines are 1-indexed
+        let first = start.start().line.saturating_sub(1);
+        let last = end.end().line.max(first + 1);
+        self.sites.push(UnsafeSite {
+            kind,
+            lines: first..last,
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for Finder {
+    fn visit_expr_unsafe(&mut self, node: &'ast syn::ExprUnsafe) {
+        self.push(
+            UnsafeKind::Block,
+            node.unsafe_token.span,
+            node.block.brace_token.span.close(),
+        );
+        visit::visit_expr_unsafe(self, node);
+    }
+
+    fn visit_item_fn(&mut self, node: &'ast syn::ItemFn) {
+        if let Some(token) = &node.sig.unsafety {
+            self.push(UnsafeKind::Fn, token.span, token.span);
+        }
+        visit::visit_item_fn(self, node);
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast syn::ImplItemFn) {
+        if let Some(token) = &node.sig.unsafety {
+            self.push(UnsafeKind::Fn, token.span, token.span);
+        }
+        visit::visit_impl_item_fn(self, node);
+    }
+
+    fn visit_trait_item_fn(&mut self, node: &'ast syn::TraitItemFn) {
+        if let Some(token) = &node.sig.unsafety {
+            self.push(UnsafeKind::Fn, token.span, token.span);
+        }
+        visit::visit_trait_item_fn(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast syn::ItemImpl) {
+        if let Some(token) = &node.unsafety {
+            self.push(UnsafeKind::Impl, token.span, token.span);
+        }
+        visit::visit_item_impl(self, node);
+    }
+}
+
+#[cfg(test)]
+#[path = "unsafe_code_tests.rs"]
+mod tests;
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn counts(content: &str) -> UnsafeCounts {
+    UnsafeInfo::parse(content)
+        .unwrap()
+        .count(content, |_| false)
+}
+
+#[test]
+fn counts_blocks_fns_and_impls() {
+    let content = r#"
+pub unsafe fn raw(p: *const u8) -> u8 {
+    unsafe { *p }
+}
+
+struct Handle;
+unsafe impl Send for Handle {}
+
+impl Handle {
+    pub unsafe fn get(&self) {}
+}
+
+trait Raw {
+    unsafe fn read(&self);
+}
+"#;
+    assert_eq!(
+        counts(content),
+        UnsafeCounts {
+            blocks: 1,
+            fns: 3,
+            impls: 1,
+            lines: 1,
+        }
+    );
+}
+
+#[test]
+fn block_lines_span_braces_and_skip_blanks() {
+    let content = r#"
+fn f(p: *const u8) -> u8 {
+    let x = unsafe {
+        let y = *p;
+
+        y
+    };
+    x
+}
+"#;
+    let info = UnsafeInfo::parse(content).unwrap();
+    assert_eq!(info.sites[0].lines, 2..7);
+    assert_eq!(info.count(content, |_| false).lines, 4);
+}
+
+#[test]
+fn nested_blocks_count_lines_once() {
+    let content = r#"
+fn f() {
+    unsafe {
+        g();
+        unsafe { h() }
+    }
+}
+"#;
+    let counts = counts(content);
+    assert_eq!(counts.blocks, 2);
+    assert_eq!(counts.lines, 4);
+}
+
+#[test]
+fn strings_comments_and_names_are_not_unsafe() {
+    let content = r#"
+// unsafe { not code }
+fn unsafe_name() -> &'static str {
+    /* unsafe { */
+    "unsafe { x }"
+}
+"#;
+    assert_eq!(counts(content), UnsafeCounts::default());
+}
+
+#[test]
+fn skipped_lines_leave_out_sites() {
+    let content = "fn f() { unsafe { g() } }\n\nfn t() { unsafe { g() } }\n";
+    let info = UnsafeInfo::parse(content).unwrap();
+    let counts = info.count(content, |line| line == 2);
+    assert_eq!(counts.blocks, 1);
+    assert_eq!(counts.lines, 1);
+}
+
+#[test]
+fn unparseable_file_is_none() {
+    assert!(UnsafeInfo::parse("fn f( { unsafe {} }").is_none());
+}
+
+#[test]
+fn file_without_unsafe_is_not_parsed() {
+    assert!(UnsafeInfo::parse("fn f( {").unwrap().sites.is_empty());
+}
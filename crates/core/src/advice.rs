@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Advice message catalog.
+//!
+//! Replaces built-in advice with project-specific messages keyed by rule ID
+//! (see [`crate::rules`]), e.g. to link an internal wiki or to translate.
+//! Messages may use placeholders filled from the violation: `{advice}` (the
+//! built-in text), `{file}`, `{line}`, `{pattern}`, and `{rule}`.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::check::{CheckResult, Field, Violation};
+use crate::config::AdviceConfig;
+use crate::rules::{RuleError, parse_rule_id, rule_id};
+
+/// Errors from loading the advice catalog.
+#[derive(Debug, thiserror::Error)]
+pub enum AdviceError {
+    #[error("advice: {0}")]
+    Rule(#[from] RuleError),
+
+    #[error("cannot read advice catalog {path}: {message}")]
+    Catalog { path: String, message: String },
+}
+
+/// Advice overrides by normalized rule ID.
+#[derive(Debug, Default)]
+pub struct AdviceCatalog {
+    messages: BTreeMap<String, String>,
+}
+
+impl AdviceCatalog {
+    /// Load `[advice]`: the catalog file first, then inline messages on top.
+    pub fn new(config: &AdviceConfig, root: &Path) -> Result<Self, AdviceError> {
+        let mut entries = BTreeMap::new();
+        if let Some(path) = &config.catalog {
+            let catalog_error = |message: String| AdviceError::Catalog {
+                path: path.clone(),
+                message,
+            };
+            let content = std::fs::read_to_string(root.join(path))
+                .map_err(|e| catalog_error(e.to_string()))?;
+            entries = parse_catalog(&content).map_err(catalog_error)?;
+        }
+        entries.extend(config.messages.clone());
+
+        let messages = entries
+            .into_iter()
+            .map(|(id, message)| Ok((parse_rule_id(&id)?, message)))
+            .collect::<Result<_, RuleError>>()?;
+        Ok(Self { messages })
+    }
+
+    /// Whether no advice is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Overridden advice for a check's violation, if the catalog has one.
+    pub fn resolve(&self, check: &str, violation: &Violation) -> Option<String> {
+        let rule = rule_id(check, &violation.violation_type);
+        let template = self.messages.get(&rule)?;
+        Some(render(template, violation, &rule))
+    }
+
+    /// Replace the advice of every violation the catalog covers.
+    pub fn apply(&self, results: &mut [CheckResult]) {
+        if self.is_empty() {
+            return;
+        }
+        for result in results {
+            for violation in &mut result.violations {
+                if let Some(advice) = self.resolve(&result.name, violation) {
+                    violation.advice = advice;
+                }
+            }
+        }
+    }
+}
+
+/// Parse a catalog file: a flat table of `"<rule-id>" = "<message>"`.
+pub fn parse_catalog(content: &str) -> Result<BTreeMap<String, String>, String> {
+    toml::from_str(content).map_err(|e| e.message().to_string())
+}
+
+/// Fill placeholders in one pass, so substituted text is never re-expanded.
+/// Unknown placeholders are kept verbatim.
+fn render(template: &str, violation: &Violation, rule: &str) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let value = after.find('}').and_then(|end| {
+            let value = match &after[..end] {
+                "advice" => violation.advice.clone(),
+                "file" => violation
+                    .file
+                    .as_ref()
+                    .map(|f| f.display().to_string())
+                    .unwrap_or_default(),
+                "line" => violation.line.map(|l| l.to_string()).unwrap_or_default(),
+                "pattern" => violation
+                    .text(Field::Pattern)
+                    .map(str::to_string)
+                    .unwrap_or_default(),
+                "rule" => rule.to_string(),
+                _ => return None,
+            };
+            Some((value, end))
+        });
+        match value {
+            Some((value, end)) => {
+                out.push_str(&value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+#[path = "advice_tests.rs"]
+mod tests;
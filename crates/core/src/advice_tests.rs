@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use tempfile::TempDir;
+
+fn catalog(messages: &[(&str, &str)]) -> AdviceCatalog {
+    let config = AdviceConfig {
+        catalog: None,
+        messages: messages
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+    };
+    AdviceCatalog::new(&config, Path::new(".")).unwrap()
+}
+
+#[test]
+fn resolve_replaces_advice_by_rule_id() {
+    let catalog = catalog(&[("cloc/file-too-large", "Split it up.")]);
+    let v = Violation::file_only("src/big.rs", "file_too_large", "Built-in.");
+    assert_eq!(catalog.resolve("cloc", &v).as_deref(), Some("Split it up."));
+    assert_eq!(catalog.resolve("escapes", &v), None);
+}
+
+#[test]
+fn resolve_fills_placeholders() {
+    let catalog = catalog(&[(
+        "escapes/forbidden",
+        "{advice} See https://wiki/{pattern} ({file}:{line}, {rule})",
+    )]);
+    let v = Violation::file("src/lib.rs", 7, "forbidden", "Avoid it.").with_pattern("unwrap");
+    assert_eq!(
+        catalog.resolve("escapes", &v).as_deref(),
+        Some("Avoid it. See https://wiki/unwrap (src/lib.rs:7, escapes/forbidden)")
+    );
+}
+
+#[test]
+fn render_does_not_expand_substituted_text() {
+    let catalog = catalog(&[("cloc/file-too-large", "> {advice} {unknown}")]);
+    let v = Violation::file_only("a.rs", "file_too_large", "Keep {file} literal");
+    assert_eq!(
+        catalog.resolve("cloc", &v).as_deref(),
+        Some("> Keep {file} literal {unknown}")
+    );
+}
+
+#[test]
+fn inline_messages_override_catalog_file() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("advice.toml"),
+        "\"cloc/file-too-large\" = \"From catalog.\"\n\"docs/broken-link\" = \"Fix the link.\"\n",
+    )
+    .unwrap();
+    let config = AdviceConfig {
+        catalog: Some("advice.toml".to_string()),
+        messages: [("cloc/file_too_large".to_string(), "Inline.".to_string())].into(),
+    };
+    let catalog = AdviceCatalog::new(&config, dir.path()).unwrap();
+
+    let big = Violation::file_only("a.rs", "file_too_large", "x");
+    let link = Violation::file("a.md", 1, "broken_link", "x");
+    assert_eq!(catalog.resolve("cloc", &big).as_deref(), Some("Inline."));
+    assert_eq!(
+        catalog.resolve("docs", &link).as_deref(),
+        Some("Fix the link.")
+    );
+}
+
+#[test]
+fn missing_catalog_file_is_an_error() {
+    let config = AdviceConfig {
+        catalog: Some("nope.toml".to_string()),
+        messages: Default::default(),
+    };
+    let err = AdviceCatalog::new(&config, Path::new("/nonexistent")).unwrap_err();
+    assert!(err.to_string().contains("nope.toml"));
+}
+
+#[test]
+fn unknown_check_in_key_is_an_error() {
+    let config = AdviceConfig {
+        catalog: None,
+        messages: [("bogus/rule".to_string(), "x".to_string())].into(),
+    };
+    assert!(matches!(
+        AdviceCatalog::new(&config, Path::new(".")),
+        Err(AdviceError::Rule(RuleError::UnknownCheck { .. }))
+    ));
+}
+
+#[test]
+fn apply_rewrites_matching_violations_only() {
+    let catalog = catalog(&[("cloc/file-too-large", "New.")]);
+    let mut results = vec![CheckResult::failed(
+        "cloc",
+        vec![
+            Violation::file_only("a.rs", "file_too_large", "Old."),
+            Violation::file_only("a_tests.rs", "missing_tests", "Keep."),
+        ],
+    )];
+    catalog.apply(&mut results);
+    assert_eq!(results[0].violations[0].advice, "New.");
+    assert_eq!(results[0].violations[1].advice, "Keep.");
+}
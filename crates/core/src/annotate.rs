@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Blame-based violation attribution (`quench annotate`).
+//!
+//! Runs `git blame` on each violation's line to find the commit that
+//! introduced it, then aggregates violations per author and per commit
+//! for targeted cleanup campaigns.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use git2::{Blame, Oid, Repository};
+use serde::Serialize;
+
+use crate::check::CheckOutput;
+
+/// Length of abbreviated commit hashes in reports.
+const SHORT_HASH: usize = 7;
+
+/// One violation attributed to the commit that last touched its line.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Attribution {
+    pub check: String,
+    #[serde(rename = "type")]
+    pub violation_type: String,
+    pub file: PathBuf,
+    pub line: u32,
+    pub commit: String,
+    pub author: String,
+}
+
+/// Violations introduced by one author.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuthorSummary {
+    pub author: String,
+    pub violations: usize,
+    pub commits: usize,
+}
+
+/// Violations introduced by one commit.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommitSummary {
+    pub commit: String,
+    pub author: String,
+    pub summary: String,
+    pub violations: usize,
+}
+
+/// Aggregated attribution report.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AnnotateReport {
+    /// Violations considered (all checks, all files).
+    pub total: usize,
+    /// Violations without a file and line, or on lines not in HEAD.
+    pub unattributed: usize,
+    /// Authors by violation count, most first.
+    pub by_author: Vec<AuthorSummary>,
+    /// Commits by violation count, most first.
+    pub by_commit: Vec<CommitSummary>,
+    pub violations: Vec<Attribution>,
+}
+
+/// Attribute each violation in `output` via `git blame` at HEAD.
+///
+/// Violation paths are relative to `root`.
+pub fn annotate(root: &Path, output: &CheckOutput) -> anyhow::Result<AnnotateReport> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+    let workdir = repo
+        .workdir()
+        .context("Bare repository has no working tree")?
+        .to_path_buf();
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+
+    let mut blames: HashMap<PathBuf, Option<Blame<'_>>> = HashMap::new();
+    let mut commits: HashMap<Oid, (String, String)> = HashMap::new();
+    let mut attributions = Vec::new();
+    let mut total = 0;
+
+    for result in &output.checks {
+        for violation in &result.violations {
+            total += 1;
+            let (Some(file), Some(line)) = (&violation.file, violation.line) else {
+                continue;
+            };
+            let Ok(repo_path) = root
+                .join(file)
+                .strip_prefix(&workdir)
+                .map(Path::to_path_buf)
+            else {
+                continue;
+            };
+            let blame = blames
+                .entry(repo_path)
+                .or_insert_with_key(|path| repo.blame_file(path, None).ok());
+            let Some(hunk) = blame.as_ref().and_then(|b| b.get_line(line as usize)) else {
+                continue;
+            };
+            let oid = hunk.final_commit_id();
+            let (author, summary) = commits.entry(oid).or_insert_with(|| {
+                let author = hunk
+                    .final_signature()
+                    .name()
+                    .unwrap_or("unknown")
+                    .to_string();
+                let summary = repo
+                    .find_commit(oid)
+                    .ok()
+                    .and_then(|c| c.summary().map(str::to_string))
+                    .unwrap_or_default();
+                (author, summary)
+            });
+            attributions.push((
+                Attribution {
+                    check: result.name.clone(),
+                    violation_type: violation.violation_type.clone(),
+                    file: file.clone(),
+                    line,
+                    commit: oid.to_string()[..SHORT_HASH].to_string(),
+                    author: author.clone(),
+                },
+                summary.clone(),
+            ));
+        }
+    }
+
+    Ok(AnnotateReport::new(total, attributions))
+}
+
+impl AnnotateReport {
+    /// Aggregate attributions, each paired with its commit's summary line.
+    pub fn new(total: usize, attributions: Vec<(Attribution, String)>) -> Self {
+        let mut authors: BTreeMap<&str, (usize, Vec<&str>)> = BTreeMap::new();
+        let mut commits: BTreeMap<&str, CommitSummary> = BTreeMap::new();
+        for (a, summary) in &attributions {
+            let author = authors.entry(&a.author).or_default();
+            author.0 += 1;
+            if !author.1.contains(&a.commit.as_str()) {
+                author.1.push(&a.commit);
+            }
+            commits
+                .entry(&a.commit)
+                .or_insert_with(|| CommitSummary {
+                    commit: a.commit.clone(),
+                    author: a.author.clone(),
+                    summary: summary.clone(),
+                    violations: 0,
+                })
+                .violations += 1;
+        }
+
+        let mut by_author: Vec<AuthorSummary> = authors
+            .into_iter()
+            .map(|(author, (violations, commits))| AuthorSummary {
+                author: author.to_string(),
+                violations,
+                commits: commits.len(),
+            })
+            .collect();
+        // Stable sort keeps ties in name order
+        by_author.sort_by_key(|a| std::cmp::Reverse(a.violations));
+        let mut by_commit: Vec<CommitSummary> = commits.into_values().collect();
+        by_commit.sort_by_key(|c| std::cmp::Reverse(c.violations));
+
+        let violations: Vec<Attribution> = attributions.into_iter().map(|(a, _)| a).collect();
+        Self {
+            total,
+            unattributed: total - violations.len(),
+            by_author,
+            by_commit,
+            violations,
+        }
+    }
+
+    /// Format as plain text.
+    pub fn format_text(&self) -> String {
+        let mut out = format!(
+            "Attributed {} of {} violations ({} unattributed)\n",
+            self.violations.len(),
+            self.total,
+            self.unattributed
+        );
+        if !self.by_author.is_empty() {
+            out.push_str("\nBy author:\n");
+            for a in &self.by_author {
+                out.push_str(&format!(
+                    "  {}: {} violations in {} commits\n",
+                    a.author, a.violations, a.commits
+                ));
+            }
+        }
+        if !self.by_commit.is_empty() {
+            out.push_str("\nBy commit:\n");
+            for c in &self.by_commit {
+                out.push_str(&format!(
+                    "  {} {}: {} violations ({})\n",
+                    c.commit, c.summary, c.violations, c.author
+                ));
+            }
+        }
+        out
+    }
+
+    /// Format as markdown tables.
+    pub fn format_markdown(&self) -> String {
+        let mut out = format!(
+            "## Violation Attribution\n\nAttributed {} of {} violations ({} unattributed).\n",
+            self.violations.len(),
+            self.total,
+            self.unattributed
+        );
+        if !self.by_author.is_empty() {
+            out.push_str(
+                "\n| Author | Violations | Commits |\n|--------|------------|---------|\n",
+            );
+            for a in &self.by_author {
+                out.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    a.author, a.violations, a.commits
+                ));
+            }
+        }
+        if !self.by_commit.is_empty() {
+            out.push_str(
+                "\n| Commit | Summary | Author | Violations |\n\
+                 |--------|---------|--------|------------|\n",
+            );
+            for c in &self.by_commit {
+                out.push_str(&format!(
+                    "| `{}` | {} | {} | {} |\n",
+                    c.commit,
+                    c.summary.replace('|', "\\|"),
+                    c.author,
+                    c.violations
+                ));
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+#[path = "annotate_tests.rs"]
+mod tests;
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::check::{CheckResult, Violation};
+use crate::test_utils::{git, temp_project};
+
+fn attribution(author: &str, commit: &str) -> (Attribution, String) {
+    (
+        Attribution {
+            check: "escapes".to_string(),
+            violation_type: "forbidden".to_string(),
+            file: PathBuf::from("src/lib.rs"),
+            line: 1,
+            commit: commit.to_string(),
+            author: author.to_string(),
+        },
+        format!("summary of {}", commit),
+    )
+}
+
+#[test]
+fn report_aggregates_by_author_and_commit() {
+    let report = AnnotateReport::new(
+        5,
+        vec![
+            attribution("Bea", "bbbbbbb"),
+            attribution("Ann", "aaaaaaa"),
+            attribution("Bea", "bbbbbbb"),
+            attribution("Bea", "ccccccc"),
+        ],
+    );
+
+    assert_eq!(report.unattributed, 1);
+    assert_eq!(
+        report.by_author,
+        [
+            AuthorSummary {
+                author: "Bea".to_string(),
+                violations: 3,
+                commits: 2,
+            },
+            AuthorSummary {
+                author: "Ann".to_string(),
+                violations: 1,
+                commits: 1,
+            },
+        ]
+    );
+    let commits: Vec<_> = report
+        .by_commit
+        .iter()
+        .map(|c| (c.commit.as_str(), c.violations))
+        .collect();
+    assert_eq!(commits, [("bbbbbbb", 2), ("aaaaaaa", 1), ("ccccccc", 1)]);
+    assert_eq!(report.by_commit[0].summary, "summary of bbbbbbb");
+}
+
+#[test]
+fn markdown_escapes_pipes_in_summaries() {
+    let (a, _) = attribution("Ann", "aaaaaaa");
+    let report = AnnotateReport::new(1, vec![(a, "fix: a | b".to_string())]);
+
+    let md = report.format_markdown();
+
+    assert!(md.contains("| Ann | 1 | 1 |"));
+    assert!(md.contains("| `aaaaaaa` | fix: a \\| b | Ann | 1 |"));
+}
+
+#[test]
+fn annotate_blames_violation_lines() {
+    let temp = temp_project();
+    git::init(temp.path());
+    std::fs::create_dir_all(temp.path().join("src")).unwrap();
+    std::fs::write(temp.path().join("src/lib.rs"), "fn a() {}\n").unwrap();
+    git::initial_commit(temp.path());
+    std::fs::write(temp.path().join("src/lib.rs"), "fn a() {}\nfn b() {}\n").unwrap();
+    git::commit_files(temp.path(), &["src/lib.rs"], "feat: add b");
+
+    let violations = vec![
+        Violation::file("src/lib.rs", 2, "forbidden", "Remove it."),
+        Violation::file("src/lib.rs", 1, "forbidden", "Remove it."),
+        Violation::file_only("src/lib.rs", "file_too_large", "Split it."),
+    ];
+    let output = CheckOutput::new(
+        "2026-01-01T00:00:00Z".to_string(),
+        vec![CheckResult::failed("escapes", violations)],
+    );
+
+    let report = annotate(temp.path(), &output).unwrap();
+
+    assert_eq!(report.total, 3);
+    assert_eq!(report.unattributed, 1);
+    assert_eq!(report.by_author[0].author, "Test User");
+    assert_eq!(report.by_author[0].commits, 2);
+    let summaries: Vec<_> = report
+        .by_commit
+        .iter()
+        .map(|c| c.summary.as_str())
+        .collect();
+    assert!(summaries.contains(&"feat: add b"));
+    assert!(summaries.contains(&"feat: initial commit"));
+}
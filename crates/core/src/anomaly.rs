@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Statistical anomaly detection for timing metrics.
+//!
+//! Flags a value that lies more than `sigma` standard deviations above the
+//! mean of its recent history, so slow creep is caught without hand-tuned
+//! thresholds.
+
+/// Samples kept per series; older samples are dropped.
+pub const HISTORY_LEN: usize = 20;
+
+/// Samples needed before a series is judged.
+pub const MIN_SAMPLES: usize = 5;
+
+/// Smallest spread used, as a fraction of the mean, so a flat history
+/// doesn't flag every small wobble.
+const MIN_SPREAD: f64 = 0.05;
+
+/// Mean and standard deviation of a history.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// Population statistics, or `None` with fewer than [`MIN_SAMPLES`] samples.
+    pub fn from_history(history: &[f64]) -> Option<Self> {
+        if history.len() < MIN_SAMPLES {
+            return None;
+        }
+        let n = history.len() as f64;
+        let mean = history.iter().sum::<f64>() / n;
+        let variance = history.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        Some(Self {
+            mean,
+            stddev: variance.sqrt(),
+        })
+    }
+
+    /// How far above the mean a value may go: `sigma` deviations, with a
+    /// floor of 5% of the mean.
+    pub fn allowance(&self, sigma: f64) -> f64 {
+        sigma * self.stddev.max(self.mean * MIN_SPREAD)
+    }
+
+    /// Highest value that is not an anomaly.
+    pub fn ceiling(&self, sigma: f64) -> f64 {
+        self.mean + self.allowance(sigma)
+    }
+}
+
+/// Append a sample to a history, keeping the newest [`HISTORY_LEN`] samples.
+pub fn push_sample<T>(history: &mut Vec<T>, value: T) {
+    history.push(value);
+    if history.len() > HISTORY_LEN {
+        history.drain(..history.len() - HISTORY_LEN);
+    }
+}
+
+#[cfg(test)]
+#[path = "anomaly_tests.rs"]
+mod tests;
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+
+#[test]
+fn too_few_samples_have_no_stats() {
+    assert_eq!(Stats::from_history(&[1.0, 2.0, 3.0, 4.0]), None);
+}
+
+#[test]
+fn stats_are_population_mean_and_stddev() {
+    let stats = Stats::from_history(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+    assert_eq!(stats.mean, 5.0);
+    assert_eq!(stats.stddev, 2.0);
+    assert_eq!(stats.ceiling(3.0), 11.0);
+}
+
+#[test]
+fn flat_history_uses_minimum_spread() {
+    let stats = Stats::from_history(&[10.0; 5]).unwrap();
+    assert_eq!(stats.stddev, 0.0);
+    assert_eq!(stats.allowance(2.0), 1.0);
+}
+
+#[test]
+fn push_sample_keeps_newest() {
+    let mut history: Vec<f64> = (0..HISTORY_LEN).map(|i| i as f64).collect();
+    push_sample(&mut history, 99.0);
+    assert_eq!(history.len(), HISTORY_LEN);
+    assert_eq!(history[0], 1.0);
+    assert_eq!(history.last(), Some(&99.0));
+}
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Crash-safe file writes for baselines and caches.
+//!
+//! Contents go to a temp file beside the target, are synced to disk, and
+//! then renamed over it, so a run interrupted mid-write (Ctrl-C, a kill, a
+//! power cut) leaves either the old file or the new one, never a truncated
+//! mix.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Suffix for the previous contents kept by [`write_with_backup`].
+pub const BACKUP_SUFFIX: &str = ".bak";
+
+/// Write `contents` to `path` atomically.
+pub fn write(path: &Path, contents: impl AsRef<[u8]>) -> std::io::Result<()> {
+    let temp_path = temp_path(path);
+    let result = write_synced(&temp_path, contents.as_ref())
+        .and_then(|()| std::fs::rename(&temp_path, path));
+    if result.is_err() {
+        std::fs::remove_file(&temp_path).ok();
+    }
+    result?;
+    sync_parent(path);
+    Ok(())
+}
+
+/// Write `contents` to `path` atomically, first keeping its previous
+/// contents in [`backup_path`].
+pub fn write_with_backup(path: &Path, contents: impl AsRef<[u8]>) -> std::io::Result<()> {
+    match std::fs::read(path) {
+        Ok(previous) => write(&backup_path(path), previous)?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    write(path, contents)
+}
+
+/// Where [`write_with_backup`] keeps the previous contents of `path`.
+pub fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(BACKUP_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// Temp file beside `path`, unique to this process so concurrent writers
+/// don't share one.
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}.tmp", std::process::id()));
+    PathBuf::from(name)
+}
+
+fn write_synced(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
+
+/// Sync the directory entry for a rename. Best-effort: some platforms and
+/// filesystems can't open or sync directories.
+fn sync_parent(path: &Path) {
+    #[cfg(unix)]
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty())
+        && let Ok(dir) = std::fs::File::open(parent)
+    {
+        dir.sync_all().ok();
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+#[cfg(test)]
+#[path = "atomic_tests.rs"]
+mod tests;
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use tempfile::TempDir;
+
+fn entries(dir: &Path) -> Vec<String> {
+    let mut names: Vec<_> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn write_replaces_contents_without_leaving_temp_files() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("baseline.json");
+    std::fs::write(&path, "old").unwrap();
+
+    write(&path, "new").unwrap();
+
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+    assert_eq!(entries(temp.path()), ["baseline.json"]);
+}
+
+#[test]
+fn write_fails_without_touching_target_when_dir_is_missing() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("missing").join("baseline.json");
+
+    assert!(write(&path, "new").is_err());
+    assert_eq!(entries(temp.path()), Vec::<String>::new());
+}
+
+#[test]
+fn write_with_backup_keeps_previous_contents() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("baseline.json");
+
+    write_with_backup(&path, "first").unwrap();
+    assert!(!backup_path(&path).exists(), "nothing to back up yet");
+
+    write_with_backup(&path, "second").unwrap();
+    write_with_backup(&path, "third").unwrap();
+
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "third");
+    // Only the most recent previous version is kept
+    assert_eq!(
+        std::fs::read_to_string(backup_path(&path)).unwrap(),
+        "second"
+    );
+    assert_eq!(entries(temp.path()), ["baseline.json", "baseline.json.bak"]);
+}
+
+#[test]
+fn backup_path_appends_suffix() {
+    assert_eq!(
+        backup_path(Path::new(".quench/baseline.json")),
+        PathBuf::from(".quench/baseline.json.bak")
+    );
+}
@@ -0,0 +1,431 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Baseline file I/O for ratcheting.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::atomic;
+use crate::git::{read_file_at_ref, read_git_note};
+use crate::metrics::UnsafeCode;
+
+/// Current baseline format version.
+pub const BASELINE_VERSION: u32 = 1;
+
+/// Baseline file containing stored metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Baseline {
+    /// Format version for forward compatibility.
+    pub version: u32,
+
+    /// Last update timestamp (ISO 8601).
+    pub updated: DateTime<Utc>,
+
+    /// Git commit hash when baseline was set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+
+    /// Tags of the run that set the baseline (from `--tag KEY=VALUE`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, String>,
+
+    /// Stored metrics.
+    pub metrics: BaselineMetrics,
+}
+
+/// All tracked metrics in the baseline.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BaselineMetrics {
+    /// Coverage percentage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<CoverageMetrics>,
+
+    /// Escape hatch counts by pattern.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub escapes: Option<EscapesMetrics>,
+
+    /// Unsafe Rust counts, overall and per package.
+    #[serde(rename = "unsafe", default, skip_serializing_if = "Option::is_none")]
+    pub unsafe_code: Option<UnsafeMetrics>,
+
+    /// Linter diagnostic counts by `<linter>/<rule>`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lint: Option<BTreeMap<String, usize>>,
+
+    /// Unused exports by language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadcode: Option<BTreeMap<String, usize>>,
+
+    /// Resolved dependencies by ecosystem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deps: Option<BTreeMap<String, usize>>,
+
+    /// Estimated tokens in root-scope agent files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agents: Option<AgentsMetrics>,
+
+    /// Test lines per source line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_ratio: Option<TestRatioMetrics>,
+
+    /// Binary sizes in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_size: Option<BTreeMap<String, u64>>,
+
+    /// Build times in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_time: Option<BuildTimeMetrics>,
+
+    /// Test execution times in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_time: Option<TestTimeMetrics>,
+
+    /// Recent per-suite test times in seconds, oldest first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_time_history: Option<BTreeMap<String, Vec<f64>>>,
+
+    /// Slowest individual tests by suite name, slowest first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slow_tests: Option<BTreeMap<String, Vec<SlowTest>>>,
+
+    /// Quench's own run time in seconds, by mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quench: Option<SelfTimeMetrics>,
+
+    /// Recent goal metric values, oldest first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history: Option<Vec<MetricSample>>,
+}
+
+/// Coverage metrics with optional per-package breakdown.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverageMetrics {
+    pub total: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_package: Option<BTreeMap<String, f64>>,
+}
+
+/// Escape hatch counts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscapesMetrics {
+    /// Source file escape counts by pattern name.
+    pub source: BTreeMap<String, usize>,
+    /// Test file escape counts (tracked but not ratcheted).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test: Option<BTreeMap<String, usize>>,
+    /// Non-blank source lines scanned (for density ratcheting).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_lines: Option<usize>,
+    /// Per-package source counts (workspaces only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by_package: Option<BTreeMap<String, PackageEscapesMetrics>>,
+}
+
+/// One package's source escape counts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageEscapesMetrics {
+    /// Source file escape counts by pattern name.
+    pub source: BTreeMap<String, usize>,
+    /// Non-blank source lines scanned in the package.
+    #[serde(default)]
+    pub source_lines: usize,
+}
+
+/// Unsafe Rust counts and the share of source lines inside unsafe blocks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnsafeMetrics {
+    pub total: UnsafeCode,
+    /// Per-package counts (workspaces only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by_package: Option<BTreeMap<String, UnsafeCode>>,
+}
+
+/// Estimated tokens in the agent files loaded for every task.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentsMetrics {
+    /// Combined tokens across all files.
+    pub total_tokens: usize,
+    /// Tokens per file, by path relative to the project root.
+    pub files: BTreeMap<String, usize>,
+}
+
+/// Test lines per source line, with optional per-package breakdown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestRatioMetrics {
+    pub total: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_package: Option<BTreeMap<String, f64>>,
+}
+
+/// Build time metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildTimeMetrics {
+    pub cold: f64,
+    pub hot: f64,
+}
+
+/// Test time metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestTimeMetrics {
+    pub total: f64,
+    pub avg: f64,
+    pub max: f64,
+}
+
+/// Quench's own run time in seconds, recorded per mode since CI runs
+/// do far more work than fast runs.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SelfTimeMetrics {
+    /// Fast mode (`quench check`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_time_fast: Option<f64>,
+    /// CI mode (`quench check --ci`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_time_ci: Option<f64>,
+}
+
+/// Goal metric values as of one baseline update.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricSample {
+    /// When the baseline was updated.
+    pub date: DateTime<Utc>,
+    /// Overall coverage percentage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<f64>,
+    /// Source escape counts by pattern name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub escapes: BTreeMap<String, usize>,
+    /// Tags of the run behind this update (from `--tag KEY=VALUE`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, String>,
+}
+
+/// A slow test and its recorded times in milliseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SlowTest {
+    pub name: String,
+    pub ms: u64,
+    /// Earlier recorded times, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub previous: Vec<u64>,
+}
+
+impl SlowTest {
+    /// Change in milliseconds since the previous recorded time.
+    pub fn change_ms(&self) -> Option<i64> {
+        self.previous
+            .last()
+            .map(|&prev| self.ms as i64 - prev as i64)
+    }
+}
+
+impl Default for Baseline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Baseline {
+    /// Create a new baseline with current timestamp.
+    pub fn new() -> Self {
+        Self {
+            version: BASELINE_VERSION,
+            updated: Utc::now(),
+            commit: None,
+            tags: BTreeMap::new(),
+            metrics: BaselineMetrics::default(),
+        }
+    }
+
+    /// Load baseline from file, returning None if not found.
+    pub fn load(path: &Path) -> Result<Option<Self>, BaselineError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content =
+            std::fs::read_to_string(path).map_err(|e| BaselineError::Read(e.to_string()))?;
+
+        Self::parse(&content).map(Some)
+    }
+
+    /// Load baseline from git notes for a specific commit.
+    ///
+    /// Returns None if no note exists for the commit.
+    pub fn load_from_notes(root: &Path, commit_ref: &str) -> Result<Option<Self>, BaselineError> {
+        let note_content =
+            read_git_note(root, commit_ref).map_err(|e| BaselineError::Read(e.to_string()))?;
+
+        note_content
+            .map(|content| Self::parse(&content))
+            .transpose()
+    }
+
+    /// Load the baseline file committed at `rev`, without checking it out.
+    ///
+    /// Returns None if the file doesn't exist in that commit.
+    pub fn load_from_ref(
+        root: &Path,
+        rev: &str,
+        path: &str,
+    ) -> Result<Option<Self>, BaselineError> {
+        let content =
+            read_file_at_ref(root, rev, path).map_err(|e| BaselineError::Read(e.to_string()))?;
+
+        content.map(|content| Self::parse(&content)).transpose()
+    }
+
+    /// Parse baseline JSON, rejecting versions newer than this build supports.
+    pub fn parse(content: &str) -> Result<Self, BaselineError> {
+        let baseline: Baseline =
+            serde_json::from_str(content).map_err(|e| BaselineError::Parse(e.to_string()))?;
+
+        // Version check for forward compatibility
+        if baseline.version > BASELINE_VERSION {
+            return Err(BaselineError::Version {
+                found: baseline.version,
+                supported: BASELINE_VERSION,
+            });
+        }
+
+        Ok(baseline)
+    }
+
+    /// Save baseline to file, creating parent directories if needed.
+    ///
+    /// The write is atomic, and the previous baseline is kept in
+    /// `<path>.bak` unless it was unreadable, so a good backup is never
+    /// replaced by a damaged file.
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| BaselineError::Write(e.to_string()))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| BaselineError::Serialize(e.to_string()))?;
+
+        let written = if matches!(Self::load(path), Ok(Some(_))) {
+            atomic::write_with_backup(path, content)
+        } else {
+            atomic::write(path, content)
+        };
+        written.map_err(|e| BaselineError::Write(e.to_string()))
+    }
+
+    /// Set git commit hash from current HEAD.
+    pub fn with_commit(mut self, root: &Path) -> Self {
+        if let Ok(output) = std::process::Command::new("git")
+            .args(["rev-parse", "--short", "HEAD"])
+            .current_dir(root)
+            .output()
+            && output.status.success()
+        {
+            self.commit = Some(String::from_utf8_lossy(&output.stdout).trim().to_string());
+        }
+        self
+    }
+
+    /// Update the timestamp to now.
+    pub fn touch(&mut self) {
+        self.updated = Utc::now();
+    }
+
+    /// Get the age of this baseline in days.
+    pub fn age_days(&self) -> i64 {
+        let now = Utc::now();
+        (now - self.updated).num_days()
+    }
+
+    /// Check if baseline is stale (older than threshold).
+    pub fn is_stale(&self, threshold_days: u32) -> bool {
+        threshold_days > 0 && self.age_days() > threshold_days as i64
+    }
+}
+
+impl BaselineMetrics {
+    /// Value of the metric at a dotted path into the stored metrics
+    /// (e.g., "escapes.source.unwrap", "coverage.total", "binary_size.quench").
+    ///
+    /// Keys may themselves contain dots (package names, targets); the
+    /// longest key matching the rest of the path wins.
+    pub fn value(&self, path: &str) -> Option<f64> {
+        let mut node = serde_json::to_value(self).ok()?;
+        let mut rest = path;
+        while !rest.is_empty() {
+            let object = node.as_object()?;
+            let key = object
+                .keys()
+                .filter(|k| {
+                    rest == k.as_str()
+                        || rest
+                            .strip_prefix(k.as_str())
+                            .is_some_and(|r| r.starts_with('.'))
+                })
+                .max_by_key(|k| k.len())?
+                .clone();
+            rest = rest[key.len()..].trim_start_matches('.');
+            node = object.get(&key)?.clone();
+        }
+        node.as_f64()
+    }
+
+    /// Every stored metric value by dotted path (e.g., "escapes.source.unwrap").
+    ///
+    /// Recorded histories (`history`, `test_time_history`, `slow_tests`) are
+    /// lists rather than metrics and are left out.
+    pub fn flatten(&self) -> BTreeMap<String, f64> {
+        fn walk(prefix: &str, node: &serde_json::Value, out: &mut BTreeMap<String, f64>) {
+            match node {
+                serde_json::Value::Object(object) => {
+                    for (key, value) in object {
+                        let path = if prefix.is_empty() {
+                            key.clone()
+                        } else {
+                            format!("{}.{}", prefix, key)
+                        };
+                        walk(&path, value, out);
+                    }
+                }
+                serde_json::Value::Number(n) => {
+                    if let Some(value) = n.as_f64() {
+                        out.insert(prefix.to_string(), value);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut out = BTreeMap::new();
+        if let Ok(node) = serde_json::to_value(self) {
+            walk("", &node, &mut out);
+        }
+        out
+    }
+}
+
+/// Errors that can occur during baseline operations.
+#[derive(Debug, thiserror::Error)]
+pub enum BaselineError {
+    #[error("failed to read baseline: {0}")]
+    Read(String),
+
+    #[error("failed to parse baseline: {0}")]
+    Parse(String),
+
+    #[error("baseline version {found} is newer than supported {supported}")]
+    Version { found: u32, supported: u32 },
+
+    #[error("failed to serialize baseline: {0}")]
+    Serialize(String),
+
+    #[error("failed to write baseline: {0}")]
+    Write(String),
+}
+
+#[cfg(test)]
+#[path = "baseline_tests.rs"]
+mod tests;
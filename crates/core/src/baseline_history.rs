@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Baseline history for auditing and rollback.
+//!
+//! Every baseline save also writes a snapshot to `.quench/history/`, named
+//! by when it was saved (e.g., `20260120T093000.123Z.json`). The history
+//! shows when a metric's ceiling moved, and `quench baseline update --from`
+//! restores a snapshot after an accidental `--fix`.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::Serialize;
+
+use crate::atomic;
+use crate::baseline::{Baseline, BaselineError};
+
+/// Directory holding baseline snapshots, relative to the project root.
+pub const HISTORY_DIR: &str = ".quench/history";
+
+/// `strftime` format of snapshot IDs.
+const ID_FORMAT: &str = "%Y%m%dT%H%M%S%.3fZ";
+
+/// A saved baseline snapshot.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    /// File stem, e.g. `20260120T093000.123Z`.
+    pub id: String,
+    /// When the baseline was saved.
+    pub saved: DateTime<Utc>,
+    pub baseline: Baseline,
+}
+
+/// A metric whose value differs between two snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricMove {
+    pub name: String,
+    /// Value before, or None if the metric was added.
+    pub from: Option<f64>,
+    /// Value after, or None if the metric was removed.
+    pub to: Option<f64>,
+}
+
+/// Record `baseline` in the history, returning the snapshot ID.
+pub fn record(root: &Path, baseline: &Baseline) -> Result<String, BaselineError> {
+    record_at(root, baseline, Utc::now())
+}
+
+fn record_at(
+    root: &Path,
+    baseline: &Baseline,
+    saved: DateTime<Utc>,
+) -> Result<String, BaselineError> {
+    let dir = root.join(HISTORY_DIR);
+    std::fs::create_dir_all(&dir).map_err(|e| BaselineError::Write(e.to_string()))?;
+
+    let content = serde_json::to_string_pretty(baseline)
+        .map_err(|e| BaselineError::Serialize(e.to_string()))?;
+
+    // Saves within the same millisecond get a counter
+    let stamp = saved.format(ID_FORMAT).to_string();
+    let mut id = stamp.clone();
+    let mut n = 1;
+    while snapshot_path(root, &id).exists() {
+        id = format!("{}-{}", stamp, n);
+        n += 1;
+    }
+
+    atomic::write(&snapshot_path(root, &id), content)
+        .map_err(|e| BaselineError::Write(e.to_string()))?;
+    Ok(id)
+}
+
+/// All snapshots, oldest first. Unreadable snapshots are skipped.
+pub fn list(root: &Path) -> Vec<Snapshot> {
+    let Ok(entries) = std::fs::read_dir(root.join(HISTORY_DIR)) else {
+        return Vec::new();
+    };
+
+    let mut snapshots: Vec<Snapshot> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| {
+            let id = path.file_stem()?.to_str()?.to_string();
+            let saved = parse_id(&id)?;
+            match Baseline::load(&path) {
+                Ok(Some(baseline)) => Some(Snapshot {
+                    id,
+                    saved,
+                    baseline,
+                }),
+                Ok(None) => None,
+                Err(e) => {
+                    tracing::warn!("skipping baseline snapshot {}: {}", path.display(), e);
+                    None
+                }
+            }
+        })
+        .collect();
+    snapshots.sort_by(|a, b| a.saved.cmp(&b.saved).then_with(|| a.id.cmp(&b.id)));
+    snapshots
+}
+
+/// Load the snapshot with `id`, returning None if there is none.
+pub fn load(root: &Path, id: &str) -> Result<Option<Baseline>, BaselineError> {
+    if parse_id(id).is_none() {
+        return Ok(None);
+    }
+    Baseline::load(&snapshot_path(root, id))
+}
+
+/// Delete all but the newest `keep` snapshots, and snapshots saved more
+/// than `older_than_days` ago. The newest snapshot is always kept.
+///
+/// Returns the IDs of the deleted snapshots, oldest first.
+pub fn prune(
+    root: &Path,
+    keep: Option<usize>,
+    older_than_days: Option<u32>,
+) -> Result<Vec<String>, BaselineError> {
+    let snapshots = list(root);
+    let now = Utc::now();
+    let keep = keep.unwrap_or(usize::MAX).max(1);
+    let excess = snapshots.len().saturating_sub(keep);
+
+    let mut removed = Vec::new();
+    for (i, snapshot) in snapshots.iter().enumerate() {
+        let newest = i + 1 == snapshots.len();
+        let too_old =
+            older_than_days.is_some_and(|days| (now - snapshot.saved).num_days() > i64::from(days));
+        if !newest && (i < excess || too_old) {
+            std::fs::remove_file(snapshot_path(root, &snapshot.id))
+                .map_err(|e| BaselineError::Write(e.to_string()))?;
+            removed.push(snapshot.id.clone());
+        }
+    }
+    Ok(removed)
+}
+
+/// Metrics whose values differ from `previous` to `current`, by name.
+pub fn moves(previous: &Baseline, current: &Baseline) -> Vec<MetricMove> {
+    let before = previous.metrics.flatten();
+    let after = current.metrics.flatten();
+
+    let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let from = before.get(name).copied();
+            let to = after.get(name).copied();
+            (from != to).then(|| MetricMove {
+                name: name.clone(),
+                from,
+                to,
+            })
+        })
+        .collect()
+}
+
+fn snapshot_path(root: &Path, id: &str) -> PathBuf {
+    root.join(HISTORY_DIR).join(format!("{}.json", id))
+}
+
+/// Save time of a snapshot ID, ignoring any same-millisecond counter.
+fn parse_id(id: &str) -> Option<DateTime<Utc>> {
+    let stamp = match id.split_once('-') {
+        Some((stamp, n)) if !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()) => stamp,
+        Some(_) => return None,
+        None => id,
+    };
+    NaiveDateTime::parse_from_str(stamp, ID_FORMAT)
+        .ok()
+        .map(|t| t.and_utc())
+}
+
+#[cfg(test)]
+#[path = "baseline_history_tests.rs"]
+mod tests;
@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::BTreeMap;
+
+use chrono::{Duration, TimeZone};
+
+use super::*;
+use crate::baseline::EscapesMetrics;
+
+fn with_unwraps(count: usize) -> Baseline {
+    let mut baseline = Baseline::new();
+    baseline.metrics.escapes = Some(EscapesMetrics {
+        source: BTreeMap::from([("unwrap".to_string(), count)]),
+        test: None,
+        source_lines: None,
+        by_package: None,
+    });
+    baseline
+}
+
+fn at(minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 20, 9, minute, 0).unwrap()
+}
+
+#[test]
+fn record_and_list_roundtrip() {
+    let dir = tempfile::tempdir().unwrap();
+    let id = record_at(dir.path(), &with_unwraps(3), at(30)).unwrap();
+
+    assert_eq!(id, "20260120T093000.000Z");
+    let snapshots = list(dir.path());
+    assert_eq!(snapshots.len(), 1);
+    assert_eq!(snapshots[0].id, id);
+    assert_eq!(snapshots[0].saved, at(30));
+    assert_eq!(
+        snapshots[0].baseline.metrics.value("escapes.source.unwrap"),
+        Some(3.0)
+    );
+}
+
+#[test]
+fn list_orders_oldest_first_and_keeps_same_time_saves() {
+    let dir = tempfile::tempdir().unwrap();
+    record_at(dir.path(), &with_unwraps(3), at(40)).unwrap();
+    record_at(dir.path(), &with_unwraps(5), at(10)).unwrap();
+    let second = record_at(dir.path(), &with_unwraps(4), at(40)).unwrap();
+
+    assert_eq!(second, "20260120T094000.000Z-1");
+    let ids: Vec<_> = list(dir.path()).into_iter().map(|s| s.id).collect();
+    assert_eq!(
+        ids,
+        [
+            "20260120T091000.000Z",
+            "20260120T094000.000Z",
+            "20260120T094000.000Z-1"
+        ]
+    );
+}
+
+#[test]
+fn list_skips_files_that_are_not_snapshots() {
+    let dir = tempfile::tempdir().unwrap();
+    record_at(dir.path(), &with_unwraps(3), at(30)).unwrap();
+    let history = dir.path().join(HISTORY_DIR);
+    std::fs::write(history.join("notes.json"), "{}").unwrap();
+    std::fs::write(history.join("20260120T093100.000Z.json"), "not json").unwrap();
+
+    assert_eq!(list(dir.path()).len(), 1);
+}
+
+#[test]
+fn load_rejects_ids_that_are_not_snapshot_names() {
+    let dir = tempfile::tempdir().unwrap();
+    let id = record_at(dir.path(), &with_unwraps(3), at(30)).unwrap();
+
+    assert!(load(dir.path(), &id).unwrap().is_some());
+    assert!(load(dir.path(), "20260120T093100.000Z").unwrap().is_none());
+    assert!(load(dir.path(), "../baseline").unwrap().is_none());
+    assert!(
+        load(dir.path(), "20260120T093000.000Z-../../baseline")
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn prune_keeps_newest_snapshots() {
+    let dir = tempfile::tempdir().unwrap();
+    for minute in [10, 20, 30] {
+        record_at(dir.path(), &with_unwraps(3), at(minute)).unwrap();
+    }
+
+    let removed = prune(dir.path(), Some(2), None).unwrap();
+
+    assert_eq!(removed, ["20260120T091000.000Z"]);
+    assert_eq!(list(dir.path()).len(), 2);
+}
+
+#[test]
+fn prune_by_age_never_removes_newest() {
+    let dir = tempfile::tempdir().unwrap();
+    let old = Utc::now() - Duration::days(40);
+    record_at(dir.path(), &with_unwraps(3), old).unwrap();
+    record_at(dir.path(), &with_unwraps(3), old + Duration::minutes(1)).unwrap();
+
+    let removed = prune(dir.path(), None, Some(30)).unwrap();
+
+    assert_eq!(removed.len(), 1);
+    assert_eq!(list(dir.path()).len(), 1);
+}
+
+#[test]
+fn moves_lists_changed_added_and_removed_metrics() {
+    let before = with_unwraps(3);
+    let mut after = Baseline::new();
+    after.metrics.escapes = Some(EscapesMetrics {
+        source: BTreeMap::from([("todo".to_string(), 1)]),
+        test: None,
+        source_lines: None,
+        by_package: None,
+    });
+
+    assert_eq!(
+        moves(&before, &after),
+        [
+            MetricMove {
+                name: "escapes.source.todo".to_string(),
+                from: None,
+                to: Some(1.0),
+            },
+            MetricMove {
+                name: "escapes.source.unwrap".to_string(),
+                from: Some(3.0),
+                to: None,
+            },
+        ]
+    );
+    assert!(moves(&before, &with_unwraps(3)).is_empty());
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use super::*;
 
@@ -33,8 +33,10 @@ fn save_and_load_roundtrip() {
 
     let mut baseline = Baseline::new();
     baseline.metrics.escapes = Some(EscapesMetrics {
-        source: HashMap::from([("unsafe".to_string(), 5)]),
-        test: Some(HashMap::from([("unsafe".to_string(), 10)])),
+        source: BTreeMap::from([("unsafe".to_string(), 5)]),
+        test: Some(BTreeMap::from([("unsafe".to_string(), 10)])),
+        source_lines: None,
+        by_package: None,
     });
 
     baseline.save(&path).unwrap();
@@ -92,11 +94,50 @@ fn creates_parent_directories() {
     assert!(path.exists());
 }
 
+#[test]
+fn save_keeps_previous_baseline_as_backup() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("baseline.json");
+    let backup = dir.path().join("baseline.json.bak");
+
+    let mut first = Baseline::new();
+    first.commit = Some("abc1234".to_string());
+    first.save(&path).unwrap();
+    assert!(!backup.exists());
+
+    Baseline::new().save(&path).unwrap();
+
+    let kept = Baseline::load(&backup).unwrap().unwrap();
+    assert_eq!(kept.commit.as_deref(), Some("abc1234"));
+}
+
+#[test]
+fn save_does_not_back_up_damaged_baseline() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("baseline.json");
+    let backup = dir.path().join("baseline.json.bak");
+
+    let mut good = Baseline::new();
+    good.commit = Some("abc1234".to_string());
+    good.save(&path).unwrap();
+    Baseline::new().save(&path).unwrap();
+    // A truncated write from an older quench
+    std::fs::write(&path, "{\"version\": 1, \"upd").unwrap();
+
+    Baseline::new().save(&path).unwrap();
+
+    let kept = Baseline::load(&backup).unwrap().unwrap();
+    assert_eq!(kept.commit.as_deref(), Some("abc1234"));
+    assert!(Baseline::load(&path).unwrap().is_some());
+}
+
 #[test]
 fn serializes_escapes_metrics() {
     let metrics = EscapesMetrics {
-        source: HashMap::from([("unsafe".to_string(), 3), ("unwrap".to_string(), 7)]),
-        test: Some(HashMap::from([("unsafe".to_string(), 15)])),
+        source: BTreeMap::from([("unsafe".to_string(), 3), ("unwrap".to_string(), 7)]),
+        test: Some(BTreeMap::from([("unsafe".to_string(), 15)])),
+        source_lines: None,
+        by_package: None,
     };
 
     let json = serde_json::to_string(&metrics).unwrap();
@@ -220,3 +261,110 @@ fn load_from_notes_returns_error_for_invalid_json() {
     let result = Baseline::load_from_notes(temp.path(), "HEAD");
     assert!(matches!(result, Err(BaselineError::Parse(_))));
 }
+
+// =============================================================================
+// LOAD_FROM_REF TESTS
+// =============================================================================
+
+fn commit_file(temp: &tempfile::TempDir, path: &str, content: &str) {
+    let full = temp.path().join(path);
+    std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+    std::fs::write(full, content).unwrap();
+    Command::new("git")
+        .args(["add", path])
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to git add");
+    Command::new("git")
+        .args(["commit", "-m", "update baseline"])
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to git commit");
+}
+
+#[test]
+fn load_from_ref_reads_committed_file_not_working_copy() {
+    let temp = tempfile::tempdir().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+    commit_file(
+        &temp,
+        ".quench/baseline.json",
+        r#"{"version":1,"updated":"2026-01-20T00:00:00Z","commit":"abc1234","metrics":{}}"#,
+    );
+    std::fs::write(temp.path().join(".quench/baseline.json"), "not valid json").unwrap();
+
+    let result = Baseline::load_from_ref(temp.path(), "HEAD", ".quench/baseline.json").unwrap();
+    assert_eq!(result.unwrap().commit.as_deref(), Some("abc1234"));
+}
+
+#[test]
+fn load_from_ref_returns_none_for_missing_file() {
+    let temp = tempfile::tempdir().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+
+    let result = Baseline::load_from_ref(temp.path(), "HEAD", ".quench/baseline.json").unwrap();
+    assert!(result.is_none());
+}
+
+#[test]
+fn load_from_ref_errors_for_unknown_ref() {
+    let temp = tempfile::tempdir().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+
+    let result = Baseline::load_from_ref(temp.path(), "no-such-branch", ".quench/baseline.json");
+    assert!(matches!(result, Err(BaselineError::Read(_))));
+}
+
+// =============================================================================
+// METRIC LOOKUP
+// =============================================================================
+
+fn metrics_json(json: &str) -> BaselineMetrics {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn metric_value_follows_dotted_path() {
+    let metrics =
+        metrics_json(r#"{"escapes": {"source": {"unwrap": 4}}, "coverage": {"total": 81.5}}"#);
+
+    assert_eq!(metrics.value("escapes.source.unwrap"), Some(4.0));
+    assert_eq!(metrics.value("coverage.total"), Some(81.5));
+}
+
+#[test]
+fn metric_value_matches_keys_containing_dots() {
+    let metrics =
+        metrics_json(r#"{"coverage": {"total": 80.0, "by_package": {"app.core": 72.5}}}"#);
+
+    assert_eq!(metrics.value("coverage.by_package.app.core"), Some(72.5));
+}
+
+#[test]
+fn metric_value_is_none_for_missing_or_non_numeric() {
+    let metrics = metrics_json(r#"{"escapes": {"source": {"unwrap": 4}}}"#);
+
+    assert_eq!(metrics.value("escapes.source.todo"), None);
+    assert_eq!(metrics.value("escapes.source"), None);
+    assert_eq!(metrics.value("coverage.total"), None);
+}
+
+#[test]
+fn flatten_lists_every_metric_by_path() {
+    let metrics = metrics_json(
+        r#"{"escapes": {"source": {"unwrap": 4}}, "coverage": {"total": 81.5},
+            "test_time_history": {"unit": [1.0, 2.0]}}"#,
+    );
+
+    let flat = metrics.flatten();
+    assert_eq!(
+        flat.into_iter().collect::<Vec<_>>(),
+        vec![
+            ("coverage.total".to_string(), 81.5),
+            ("escapes.source.unwrap".to_string(), 4.0),
+        ]
+    );
+}
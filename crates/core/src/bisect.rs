@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Searching history for where a metric crossed a threshold (`quench bisect`).
+//!
+//! Measuring a commit means checking it out and running the checks, so the
+//! search probes as few commits as it can: a binary search over first-parent
+//! history, like `git bisect`, assuming the metric stays crossed once it
+//! crosses.
+
+use std::fmt;
+
+/// The condition a metric crosses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Threshold {
+    Above(f64),
+    Below(f64),
+}
+
+impl Threshold {
+    /// Worse than `start`: below it for coverage (higher is better), above
+    /// it for everything else.
+    pub fn worse_than(metric: &str, start: f64) -> Self {
+        if metric.starts_with("coverage.") {
+            Threshold::Below(start)
+        } else {
+            Threshold::Above(start)
+        }
+    }
+
+    /// Whether `value` is past the threshold.
+    pub fn crossed(&self, value: f64) -> bool {
+        match *self {
+            Threshold::Above(limit) => value > limit,
+            Threshold::Below(limit) => value < limit,
+        }
+    }
+}
+
+impl fmt::Display for Threshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Threshold::Above(limit) => write!(f, "above {}", limit),
+            Threshold::Below(limit) => write!(f, "below {}", limit),
+        }
+    }
+}
+
+/// Result of a search over commits `0..len`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bisection {
+    /// Last commit measured as not crossed.
+    pub last_good: usize,
+    /// First commit measured as crossed.
+    pub first_bad: usize,
+}
+
+impl Bisection {
+    /// Whether commits between `last_good` and `first_bad` couldn't be
+    /// measured, so any of them (or `first_bad`) may be the culprit.
+    pub fn is_ambiguous(&self) -> bool {
+        self.first_bad - self.last_good > 1
+    }
+}
+
+/// Find the first crossed commit among `0..len`, given commit 0 isn't
+/// crossed and commit `len - 1` is.
+///
+/// `probe` says whether a commit is crossed, or None if it couldn't be
+/// measured; unmeasurable commits are skipped like `git bisect skip`.
+pub fn first_crossing<E>(
+    len: usize,
+    mut probe: impl FnMut(usize) -> Result<Option<bool>, E>,
+) -> Result<Bisection, E> {
+    let mut good = 0;
+    let mut bad = len.saturating_sub(1);
+    while bad - good > 1 {
+        let mid = good + (bad - good) / 2;
+        // Try the midpoint, then its neighbors toward each end
+        let candidates = (mid..bad).chain((good + 1..mid).rev());
+        let mut moved = false;
+        for candidate in candidates {
+            match probe(candidate)? {
+                Some(true) => bad = candidate,
+                Some(false) => good = candidate,
+                None => continue,
+            }
+            moved = true;
+            break;
+        }
+        if !moved {
+            break;
+        }
+    }
+    Ok(Bisection {
+        last_good: good,
+        first_bad: bad,
+    })
+}
+
+#[cfg(test)]
+#[path = "bisect_tests.rs"]
+mod tests;
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+use std::convert::Infallible;
+
+/// Search `values` for the first one above `limit`, recording probes.
+fn search(values: &[Option<f64>], limit: f64) -> (Bisection, Vec<usize>) {
+    let threshold = Threshold::Above(limit);
+    let mut probed = Vec::new();
+    let result = first_crossing::<Infallible>(values.len(), |i| {
+        probed.push(i);
+        Ok(values[i].map(|v| threshold.crossed(v)))
+    })
+    .unwrap();
+    (result, probed)
+}
+
+#[test]
+fn finds_first_crossing_commit() {
+    let values: Vec<Option<f64>> = [1.0, 1.0, 2.0, 2.0, 5.0, 6.0, 6.0, 7.0]
+        .into_iter()
+        .map(Some)
+        .collect();
+
+    let (result, _) = search(&values, 2.0);
+
+    assert_eq!(
+        result,
+        Bisection {
+            last_good: 3,
+            first_bad: 4
+        }
+    );
+    assert!(!result.is_ambiguous());
+}
+
+#[test]
+fn probes_logarithmically_many_commits() {
+    let values: Vec<Option<f64>> = (0..1024).map(|i| Some(i as f64)).collect();
+
+    let (result, probed) = search(&values, 700.0);
+
+    assert_eq!(result.first_bad, 701);
+    assert!(probed.len() <= 10, "probed {} commits", probed.len());
+}
+
+#[test]
+fn skips_unmeasurable_commits() {
+    let values = [Some(1.0), Some(1.0), None, Some(5.0), Some(5.0)];
+
+    let (result, _) = search(&values, 2.0);
+
+    assert_eq!(result.first_bad, 3);
+    assert_eq!(result.last_good, 1);
+    assert!(result.is_ambiguous());
+}
+
+#[test]
+fn adjacent_ends_need_no_probes() {
+    let (result, probed) = search(&[Some(1.0), Some(5.0)], 2.0);
+
+    assert_eq!(result.first_bad, 1);
+    assert!(probed.is_empty());
+}
+
+#[test]
+fn coverage_gets_worse_by_dropping() {
+    assert_eq!(
+        Threshold::worse_than("coverage.total", 80.0),
+        Threshold::Below(80.0)
+    );
+    assert_eq!(
+        Threshold::worse_than("escapes.source.unwrap", 4.0),
+        Threshold::Above(4.0)
+    );
+    assert!(Threshold::Below(80.0).crossed(79.9));
+    assert!(!Threshold::Below(80.0).crossed(80.0));
+    assert_eq!(Threshold::Above(4.0).to_string(), "above 4");
+}
@@ -0,0 +1,325 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Block-aware splitting of multi-language files.
+//!
+//! Single-file components (`.vue`, `.svelte`) mix markup with script and
+//! style sections, and notebooks (`.ipynb`) wrap code cells in JSON. Splitting
+//! them into single-language blocks lets escapes and cloc apply each block's
+//! own patterns and comment syntax instead of treating the file as opaque text.
+
+use serde::Deserialize;
+
+/// A single-language section of a multi-language file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    /// Extension naming the block's language (e.g., "ts", "css", "py", "md").
+    pub ext: &'static str,
+    /// 1-based file line of the block's first line.
+    pub line: u32,
+    /// Block source. Its lines map to consecutive file lines from `line`.
+    pub content: String,
+}
+
+/// Whether files with this extension are split into blocks.
+pub fn is_multi_language(ext: &str) -> bool {
+    matches!(ext, "vue" | "svelte" | "ipynb")
+}
+
+/// Split `content` into single-language blocks by file extension.
+///
+/// Returns `None` for single-language extensions and malformed notebooks.
+/// Component blocks cover every line of the file exactly once; notebook
+/// blocks cover cell sources only (outputs and metadata are dropped).
+pub fn split(ext: &str, content: &str) -> Option<Vec<Block>> {
+    match ext {
+        "vue" | "svelte" => Some(split_component(content)),
+        "ipynb" => split_notebook(content),
+        _ => None,
+    }
+}
+
+// =============================================================================
+// Single-file components
+// =============================================================================
+
+/// An open `<script>` or `<style>` section.
+struct Section {
+    ext: &'static str,
+    close: &'static str,
+    line: u32,
+    content: String,
+}
+
+/// Split a Vue/Svelte component into script, style, and markup blocks.
+///
+/// Markup (templates and the section tags themselves) becomes `html` blocks.
+/// Sections are recognized by tags at the start of a line, which is how
+/// components are conventionally laid out.
+fn split_component(content: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut markup: Option<Block> = None;
+    let mut section: Option<Section> = None;
+
+    for (idx, line) in content.lines().enumerate() {
+        let number = idx as u32 + 1;
+
+        if let Some(open) = section.as_mut() {
+            if line.trim_start().starts_with(open.close) {
+                if let Some(done) = section.take() {
+                    blocks.push(Block {
+                        ext: done.ext,
+                        line: done.line,
+                        content: done.content,
+                    });
+                }
+                push_line(&mut markup, &mut blocks, "html", number, line);
+            } else {
+                open.content.push_str(line);
+                open.content.push('\n');
+            }
+            continue;
+        }
+
+        let Some((ext, close, rest)) = opening_tag(line.trim_start()) else {
+            push_line(&mut markup, &mut blocks, "html", number, line);
+            continue;
+        };
+        if let Some(end) = rest.find(close) {
+            // Inline section: `<script>code</script>` on one line
+            flush(&mut markup, &mut blocks);
+            blocks.push(Block {
+                ext,
+                line: number,
+                content: format!("{}\n", &rest[..end]),
+            });
+        } else {
+            push_line(&mut markup, &mut blocks, "html", number, line);
+            flush(&mut markup, &mut blocks);
+            section = Some(Section {
+                ext,
+                close,
+                line: number + 1,
+                content: String::new(),
+            });
+        }
+    }
+
+    // Unclosed section: keep what was read
+    if let Some(open) = section {
+        blocks.push(Block {
+            ext: open.ext,
+            line: open.line,
+            content: open.content,
+        });
+    }
+    flush(&mut markup, &mut blocks);
+    blocks.retain(|b| !b.content.is_empty());
+    blocks.sort_by_key(|b| b.line);
+    blocks
+}
+
+/// Parse a `<script ...>` or `<style ...>` opening tag at the start of `line`.
+///
+/// Returns the section's language, its closing tag, and the text after the
+/// opening tag.
+fn opening_tag(line: &str) -> Option<(&'static str, &'static str, &str)> {
+    let (close, rest) = if let Some(rest) = line.strip_prefix("<script") {
+        ("</script>", rest)
+    } else if let Some(rest) = line.strip_prefix("<style") {
+        ("</style>", rest)
+    } else {
+        return None;
+    };
+    // `<scripts>` or `<style-guide>` are not section tags
+    if !rest.starts_with(['>', ' ', '\t']) && !rest.is_empty() {
+        return None;
+    }
+    let end = rest.find('>')?;
+    let lang = attribute(&rest[..end], "lang");
+    let ext = if close == "</script>" {
+        match lang {
+            Some("ts" | "typescript") => "ts",
+            Some("tsx") => "tsx",
+            Some("jsx") => "jsx",
+            _ => "js",
+        }
+    } else {
+        match lang {
+            Some("scss") => "scss",
+            Some("sass") => "sass",
+            Some("less") => "less",
+            _ => "css",
+        }
+    };
+    Some((ext, close, &rest[end + 1..]))
+}
+
+/// Value of a quoted attribute in a tag's attribute text.
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{}=", name))? + name.len() + 1;
+    let value = &attrs[start..];
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let value = &value[1..];
+    value.find(quote).map(|end| &value[..end])
+}
+
+/// Append a line to the open markup block, starting one if needed.
+fn push_line(
+    open: &mut Option<Block>,
+    blocks: &mut Vec<Block>,
+    ext: &'static str,
+    number: u32,
+    line: &str,
+) {
+    if open.as_ref().is_some_and(|b| b.ext != ext) {
+        flush(open, blocks);
+    }
+    let block = open.get_or_insert_with(|| Block {
+        ext,
+        line: number,
+        content: String::new(),
+    });
+    block.content.push_str(line);
+    block.content.push('\n');
+}
+
+fn flush(open: &mut Option<Block>, blocks: &mut Vec<Block>) {
+    if let Some(block) = open.take() {
+        blocks.push(block);
+    }
+}
+
+// =============================================================================
+// Notebooks
+// =============================================================================
+
+#[derive(Deserialize)]
+struct Notebook {
+    cells: Vec<Cell>,
+    #[serde(default)]
+    metadata: NotebookMetadata,
+}
+
+#[derive(Default, Deserialize)]
+struct NotebookMetadata {
+    kernelspec: Option<Kernelspec>,
+    language_info: Option<LanguageInfo>,
+}
+
+#[derive(Deserialize)]
+struct Kernelspec {
+    language: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LanguageInfo {
+    name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Cell {
+    cell_type: String,
+    #[serde(default)]
+    source: Source,
+}
+
+/// Cell source: nbformat allows a list of lines or one string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Source {
+    Lines(Vec<String>),
+    Text(String),
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Source::Text(String::new())
+    }
+}
+
+/// Split a notebook into code cells (in the kernel's language) and
+/// markdown cells (`md`).
+///
+/// Block lines point at the cell's source in the notebook JSON, which
+/// nbformat writes one source line per JSON line.
+fn split_notebook(content: &str) -> Option<Vec<Block>> {
+    let notebook: Notebook = serde_json::from_str(content).ok()?;
+    let language = notebook
+        .metadata
+        .kernelspec
+        .and_then(|k| k.language)
+        .or_else(|| notebook.metadata.language_info.and_then(|l| l.name));
+    let code_ext = language.as_deref().map_or("py", kernel_extension);
+    let mut source_lines = source_lines(content).into_iter();
+
+    let mut blocks = Vec::new();
+    for cell in notebook.cells {
+        let line = source_lines.next().unwrap_or(1);
+        let ext = match cell.cell_type.as_str() {
+            "code" => code_ext,
+            "markdown" => "md",
+            _ => continue,
+        };
+        let mut text = match cell.source {
+            Source::Lines(lines) => lines.concat(),
+            Source::Text(text) => text,
+        };
+        if text.trim().is_empty() {
+            continue;
+        }
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+        blocks.push(Block {
+            ext,
+            line,
+            content: text,
+        });
+    }
+    Some(blocks)
+}
+
+/// Extension for a notebook kernel language ("" if unknown).
+fn kernel_extension(language: &str) -> &'static str {
+    match language.to_lowercase().as_str() {
+        "python" | "python3" => "py",
+        "r" => "r",
+        "ruby" => "rb",
+        "javascript" => "js",
+        "typescript" => "ts",
+        "rust" => "rs",
+        "go" => "go",
+        "bash" | "sh" | "shell" => "sh",
+        "julia" => "jl",
+        "scala" => "scala",
+        _ => "",
+    }
+}
+
+/// 1-based line of the first source line of each `"source"` key, in order.
+fn source_lines(content: &str) -> Vec<u32> {
+    let line_at = |offset: usize| content[..offset].matches('\n').count() as u32 + 1;
+    let mut lines = Vec::new();
+    let mut from = 0;
+    while let Some(found) = content[from..].find("\"source\"") {
+        let key_end = from + found + "\"source\"".len();
+        from = key_end;
+        let value = content[key_end..].trim_start();
+        let Some(value) = value.strip_prefix(':') else {
+            continue;
+        };
+        let value_start = content.len() - value.len();
+        let first = match value.trim_start().strip_prefix('[') {
+            // First string of the array, usually on the next line
+            Some(items) => content.len() - items.trim_start().len(),
+            None => value_start + (value.len() - value.trim_start().len()),
+        };
+        lines.push(line_at(first));
+    }
+    lines
+}
+
+#[cfg(test)]
+#[path = "blocks_tests.rs"]
+mod tests;
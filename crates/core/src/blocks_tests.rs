@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn summary(blocks: &[Block]) -> Vec<(&str, u32)> {
+    blocks.iter().map(|b| (b.ext, b.line)).collect()
+}
+
+// =============================================================================
+// COMPONENT TESTS
+// =============================================================================
+
+const VUE: &str = r#"<template>
+  <div>{{ msg }}</div>
+</template>
+
+<script setup lang="ts">
+const msg: string = "hi" as any
+</script>
+
+<style scoped lang="scss">
+// nested
+.a { color: red; }
+</style>
+"#;
+
+#[test]
+fn vue_sections_become_language_blocks() {
+    let blocks = split("vue", VUE).unwrap();
+
+    assert_eq!(
+        summary(&blocks),
+        [
+            ("html", 1),
+            ("ts", 6),
+            ("html", 7),
+            ("scss", 10),
+            ("html", 12)
+        ]
+    );
+    assert_eq!(blocks[1].content, "const msg: string = \"hi\" as any\n");
+}
+
+#[test]
+fn component_blocks_cover_every_line_once() {
+    let blocks = split("vue", VUE).unwrap();
+
+    let lines: usize = blocks.iter().map(|b| b.content.lines().count()).sum();
+    assert_eq!(lines, VUE.lines().count());
+}
+
+#[test]
+fn script_without_lang_is_javascript_and_style_is_css() {
+    let content = "<script>\nlet x = 1;\n</script>\n<style>\np {}\n</style>\n";
+
+    let blocks = split("svelte", content).unwrap();
+
+    let langs: Vec<_> = blocks.iter().map(|b| b.ext).collect();
+    assert_eq!(langs, ["html", "js", "html", "css", "html"]);
+}
+
+#[test]
+fn single_quoted_lang_attribute_is_recognized() {
+    let content = "<script lang='typescript'>\nlet x = 1;\n</script>\n";
+
+    let blocks = split("svelte", content).unwrap();
+
+    assert_eq!(blocks[1].ext, "ts");
+}
+
+#[test]
+fn inline_section_is_one_block() {
+    let content = "<h1>Hi</h1>\n<script>let x = eval(y);</script>\n";
+
+    let blocks = split("svelte", content).unwrap();
+
+    assert_eq!(summary(&blocks), [("html", 1), ("js", 2)]);
+    assert_eq!(blocks[1].content, "let x = eval(y);\n");
+}
+
+#[test]
+fn script_lookalike_tags_are_markup() {
+    let content = "<scripts>\nx\n</scripts>\n";
+
+    let blocks = split("vue", content).unwrap();
+
+    assert_eq!(summary(&blocks), [("html", 1)]);
+}
+
+#[test]
+fn unclosed_section_keeps_its_content() {
+    let content = "<script>\nlet x = 1;\n";
+
+    let blocks = split("vue", content).unwrap();
+
+    assert_eq!(summary(&blocks), [("html", 1), ("js", 2)]);
+}
+
+// =============================================================================
+// NOTEBOOK TESTS
+// =============================================================================
+
+const NOTEBOOK: &str = r##"{
+ "cells": [
+  {
+   "cell_type": "markdown",
+   "metadata": {},
+   "source": [
+    "# Title\n",
+    "Intro"
+   ]
+  },
+  {
+   "cell_type": "code",
+   "execution_count": 1,
+   "metadata": {},
+   "outputs": [],
+   "source": [
+    "import os\n",
+    "x = eval(\"1\")"
+   ]
+  },
+  {
+   "cell_type": "raw",
+   "metadata": {},
+   "source": "raw text"
+  },
+  {
+   "cell_type": "code",
+   "metadata": {},
+   "outputs": [],
+   "source": "print(x)"
+  }
+ ],
+ "metadata": {
+  "kernelspec": {"display_name": "Python 3", "language": "python", "name": "python3"}
+ },
+ "nbformat": 4,
+ "nbformat_minor": 5
+}
+"##;
+
+#[test]
+fn notebook_cells_become_blocks_at_their_source_lines() {
+    let blocks = split("ipynb", NOTEBOOK).unwrap();
+
+    assert_eq!(summary(&blocks), [("md", 7), ("py", 17), ("py", 30)]);
+    assert_eq!(blocks[1].content, "import os\nx = eval(\"1\")\n");
+    assert_eq!(blocks[2].content, "print(x)\n");
+}
+
+#[test]
+fn notebook_language_comes_from_language_info() {
+    let content = r#"{"cells": [{"cell_type": "code", "source": ["1 + 1"]}],
+        "metadata": {"language_info": {"name": "R"}}}"#;
+
+    let blocks = split("ipynb", content).unwrap();
+
+    assert_eq!(blocks[0].ext, "r");
+}
+
+#[test]
+fn notebook_without_language_defaults_to_python() {
+    let content = r#"{"cells": [{"cell_type": "code", "source": ["1 + 1"]}]}"#;
+
+    let blocks = split("ipynb", content).unwrap();
+
+    assert_eq!(summary(&blocks), [("py", 1)]);
+}
+
+#[test]
+fn malformed_notebook_is_not_split() {
+    assert!(split("ipynb", "{not json").is_none());
+}
+
+#[test]
+fn single_language_files_are_not_split() {
+    assert!(split("rs", "fn main() {}\n").is_none());
+    assert!(!is_multi_language("rs"));
+    assert!(is_multi_language("svelte"));
+}
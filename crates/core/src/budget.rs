@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Time-boxed check selection for `--budget`.
+//!
+//! Checks are ordered by their last recorded duration (from the cache) and
+//! admitted cheapest first while the estimated total fits the budget. Checks
+//! without timing history are assumed cheap and always run, so their cost is
+//! recorded for next time.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::check::{Check, CheckResult};
+
+/// A check left out because it did not fit the budget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedCheck {
+    pub name: &'static str,
+    /// Last recorded duration in milliseconds.
+    pub estimate_ms: u64,
+}
+
+/// Checks to run and checks skipped under a budget.
+pub struct BudgetPlan {
+    pub run: Vec<Arc<dyn Check>>,
+    pub skipped: Vec<SkippedCheck>,
+}
+
+impl BudgetPlan {
+    /// Results reporting the skipped checks.
+    pub fn skipped_results(&self, budget: Duration) -> Vec<CheckResult> {
+        self.skipped
+            .iter()
+            .map(|s| {
+                CheckResult::skipped(
+                    s.name,
+                    format!(
+                        "over --budget {}ms (last run {}ms)",
+                        budget.as_millis(),
+                        s.estimate_ms
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Select checks that fit `budget`, cheapest first by historical `cost`.
+pub fn plan(
+    checks: Vec<Arc<dyn Check>>,
+    budget: Duration,
+    cost: impl Fn(&str) -> Option<u64>,
+) -> BudgetPlan {
+    let budget_ms = budget.as_millis() as u64;
+    let mut ordered: Vec<(u64, Arc<dyn Check>)> = checks
+        .into_iter()
+        .map(|c| (cost(c.name()).unwrap_or(0), c))
+        .collect();
+    // Stable sort keeps canonical order among equal costs
+    ordered.sort_by_key(|(ms, _)| *ms);
+
+    let mut spent = 0u64;
+    let mut run = Vec::new();
+    let mut skipped = Vec::new();
+    for (ms, check) in ordered {
+        if ms == 0 || spent + ms <= budget_ms {
+            spent += ms;
+            run.push(check);
+        } else {
+            skipped.push(SkippedCheck {
+                name: check.name(),
+                estimate_ms: ms,
+            });
+        }
+    }
+    BudgetPlan { run, skipped }
+}
+
+#[cfg(test)]
+#[path = "budget_tests.rs"]
+mod tests;
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::HashMap;
+
+use super::*;
+use crate::checks::all_checks;
+
+fn costs(entries: &[(&str, u64)]) -> HashMap<String, u64> {
+    entries.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+}
+
+fn names(plan: &BudgetPlan) -> Vec<&'static str> {
+    plan.run.iter().map(|c| c.name()).collect()
+}
+
+#[test]
+fn unknown_costs_always_run() {
+    let plan = plan(all_checks(), Duration::ZERO, |_| None);
+    assert_eq!(plan.run.len(), all_checks().len());
+    assert!(plan.skipped.is_empty());
+}
+
+#[test]
+fn cheapest_checks_fill_budget_first() {
+    let history = costs(&[("cloc", 100), ("escapes", 300), ("tests", 5000)]);
+    let plan = plan(all_checks(), Duration::from_millis(350), |n| {
+        history.get(n).copied()
+    });
+
+    let run = names(&plan);
+    assert!(run.contains(&"cloc"));
+    assert!(!run.contains(&"escapes"));
+    assert!(!run.contains(&"tests"));
+    assert_eq!(
+        plan.skipped,
+        vec![
+            SkippedCheck {
+                name: "escapes",
+                estimate_ms: 300
+            },
+            SkippedCheck {
+                name: "tests",
+                estimate_ms: 5000
+            },
+        ]
+    );
+}
+
+#[test]
+fn skipped_results_explain_budget() {
+    let history = costs(&[("tests", 1200)]);
+    let plan = plan(all_checks(), Duration::from_millis(500), |n| {
+        history.get(n).copied()
+    });
+    let results = plan.skipped_results(Duration::from_millis(500));
+
+    assert_eq!(results.len(), 1);
+    assert!(results[0].skipped);
+    assert_eq!(
+        results[0].error.as_deref(),
+        Some("over --budget 500ms (last run 1200ms)")
+    );
+}
@@ -0,0 +1,713 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! File-level caching for check results.
+//!
+//! Caches check violations by file content. A path index maps each path to
+//! its last-seen mtime+size and content hash, so unchanged files hit without
+//! being read; a changed mtime falls back to hashing the content, so branch
+//! switches and renames that restore identical content still hit.
+//! Provides 10x speedup on iterative runs where few files change.
+//!
+//! The cache is stored deflate-compressed, written from a background thread
+//! while results print, and memory-mapped on load when large.
+//!
+//! Config changes invalidate per check: changing one check's settings
+//! reruns only that check, while other checks keep their cached results.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::Metadata;
+use std::hash::{Hash, Hasher};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::JoinHandle;
+use std::time::SystemTime;
+
+use dashmap::{DashMap, DashSet};
+use flate2::Compression;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use globset::GlobSet;
+use serde::{Deserialize, Serialize};
+
+use crate::adapter::AdapterRegistry;
+use crate::adapter::glob::build_glob_set;
+use crate::check::{Details, Field, Violation};
+use crate::file_reader::FileBytes;
+
+/// Cache version for invalidation on format changes.
+/// Incremented when check logic changes (e.g., counting nonblank vs all lines).
+/// v7: Migrated from bincode to postcard serialization.
+/// v8: Fixed cfg(test) detection false positives on comments.
+/// v11: TOC validator skips box diagrams (blocks with top corner characters).
+/// v12: StripParentDirName resolves relative to parent dir, not root.
+/// v13: Added content validation for spec files (sections, tables, size limits).
+/// v14: Added source-based area detection for docs commit checking.
+/// v17: Removed WorkspaceConfig, consolidated packages into ProjectConfig.
+/// v18: Added target_path for docs cache.
+/// v19: Added per-language cloc check levels (warn vs error).
+/// v20: Added placeholders check for detecting placeholder tests.
+/// v21: Added change_type and lines_changed fields to missing_tests violations.
+/// v22: Added agent documentation check to git check.
+/// v23: Added skip_merge option to git check (merge commits now skipped by default).
+/// v24: Test pattern consolidation - hash language-specific patterns for file classification.
+/// v25: Removed standalone placeholders check, integrated metrics into tests check.
+/// v26: Added CI mode threshold checking (coverage and time violations).
+/// v27: Added license check --fix functionality.
+/// v28: Added Python coverage collection and parsing.
+/// v29: Renamed ignore → exclude terminology throughout config and adapters.
+/// v30: Changed default coverage check level from "off" to "warn".
+/// v31: Made test execution CI-only by default for all languages; added Rust/Go auto-detection.
+/// v32: Added cursor rule reconciliation (.mdc ↔ CLAUDE.md).
+/// v33: Standardized exclude patterns across all language adapters (Go, JS, Shell).
+/// v34: Removed reconcile_cursor/reconcile_direction fields; cursor reconciliation now uses sync config.
+/// v35: Added **/*_tests/** to default test patterns (generic, Rust, JavaScript adapters).
+/// v36: Python suppress comments now detected above @decorator lines.
+/// v37: JavaScript suppress config no longer inherits Rust-specific lint patterns.
+/// v38: Only #[cfg(test)] mod blocks count as test LOC; non-module items stay as source.
+/// v39: Language default escape patterns only apply to files of that language.
+/// v40: Per-check durations stored for `--budget` scheduling.
+/// v41: Config hash split into a shared hash and per-check hashes.
+/// v42: Content-addressed entries with a path index.
+/// v43: Deflate-compressed on disk.
+/// v44: Added whitespace check.
+/// v45: Added format check.
+/// v46: Added lint check.
+/// v47: Escapes metrics include source lines and density.
+/// v48: Added snapshots check.
+/// v49: Added deadcode check.
+/// v50: Added bare assertion counts to escapes.
+/// v51: Added deps check.
+/// v52: Added unsafe Rust counts to escapes.
+/// v53: Added feature gate metrics to deps.
+pub(crate) const CACHE_VERSION: u32 = 53;
+
+/// Cache file name within .quench directory.
+pub const CACHE_FILE_NAME: &str = "cache.bin";
+
+/// Error type for cache operations.
+#[derive(Debug, thiserror::Error)]
+pub enum CacheError {
+    /// I/O error.
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Serialization error.
+    #[error("serialization error: {0}")]
+    Postcard(#[from] postcard::Error),
+
+    /// Cache version mismatch.
+    #[error("cache version mismatch")]
+    VersionMismatch,
+
+    /// Quench version mismatch.
+    #[error("quench version changed")]
+    QuenchVersionMismatch,
+
+    /// Config hash changed.
+    #[error("config changed")]
+    ConfigChanged,
+}
+
+/// Hashes of the config that affects cached results.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigHashes {
+    /// Settings every check depends on (file classification, packages).
+    /// A change invalidates the whole cache.
+    pub shared: u64,
+    /// Settings of individual checks. A change reruns only that check.
+    pub checks: BTreeMap<String, u64>,
+}
+
+impl ConfigHashes {
+    /// Checks whose hash differs between `self` and `other`.
+    pub fn changed_checks(&self, other: &ConfigHashes) -> HashSet<String> {
+        self.checks
+            .keys()
+            .chain(other.checks.keys())
+            .filter(|name| self.checks.get(*name) != other.checks.get(*name))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Metadata used as cache key for a single file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FileCacheKey {
+    /// Modification time seconds since epoch.
+    pub mtime_secs: i64,
+    /// Modification time nanoseconds.
+    pub mtime_nanos: u32,
+    /// File size in bytes.
+    pub size: u64,
+}
+
+impl FileCacheKey {
+    /// Create cache key from file metadata.
+    pub fn from_metadata(meta: &Metadata) -> Self {
+        let mtime = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        let duration = mtime
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+        Self {
+            mtime_secs: duration.as_secs() as i64,
+            mtime_nanos: duration.subsec_nanos(),
+            size: meta.len(),
+        }
+    }
+
+    /// Create cache key from a WalkedFile.
+    pub fn from_walked_file(file: &crate::walker::WalkedFile) -> Self {
+        Self {
+            mtime_secs: file.mtime_secs,
+            mtime_nanos: file.mtime_nanos,
+            size: file.size,
+        }
+    }
+}
+
+/// Hash of a file's content together with its [`PathContext`].
+pub type ContentHash = u64;
+
+/// Path index entry: the content last seen at a path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct IndexEntry {
+    pub(crate) key: FileCacheKey,
+    pub(crate) hash: ContentHash,
+}
+
+/// Result of looking up a file.
+#[derive(Debug)]
+pub enum Lookup {
+    /// Cached violations for the file's content.
+    Hit(Arc<Vec<CachedViolation>>),
+    /// No entry; carries the content hash to insert under (None if unreadable).
+    Miss(Option<ContentHash>),
+}
+
+/// Path facts that cached violations depend on.
+///
+/// Mixed into content hashes so an entry is only reused at a path where the
+/// checks would compute the same violations: same language and source/test
+/// classification, same per-check excludes, and same package. Files without
+/// a language adapter (docs, agent files) resolve links relative to their
+/// location, so their full path is part of the context.
+pub struct PathContext {
+    registry: AdapterRegistry,
+    cloc_exclude: GlobSet,
+    escapes_exclude: GlobSet,
+    packages: Vec<String>,
+}
+
+impl PathContext {
+    /// Build from the project's config.
+    pub fn new(root: &Path, config: &crate::config::Config) -> Self {
+        Self {
+            registry: AdapterRegistry::for_project_with_config(root, config),
+            cloc_exclude: build_glob_set(&config.check.cloc.exclude),
+            escapes_exclude: build_glob_set(&config.check.escapes.exclude),
+            packages: config.project.packages.clone(),
+        }
+    }
+
+    /// Hash the context of a project-relative path.
+    fn hash_into(&self, relative: &Path, hasher: &mut impl Hasher) {
+        self.registry.classify(relative).hash(hasher);
+        relative.extension().hash(hasher);
+        if !self.registry.has_language_adapter(relative) {
+            relative.hash(hasher);
+        }
+        self.cloc_exclude.is_match(relative).hash(hasher);
+        self.escapes_exclude.is_match(relative).hash(hasher);
+        self.packages
+            .iter()
+            .filter(|pkg| relative.starts_with(pkg))
+            .max_by_key(|pkg| pkg.len())
+            .hash(hasher);
+    }
+
+    /// Content hash of a file at a project-relative path.
+    pub fn content_hash(&self, relative: &Path, content: &[u8]) -> ContentHash {
+        let mut hasher = DefaultHasher::new();
+        self.hash_into(relative, &mut hasher);
+        content.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Minimal violation data for cache storage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedViolation {
+    /// Check that produced this violation.
+    pub check: String,
+    /// Line number (if applicable).
+    pub line: Option<u32>,
+    /// Violation type/category.
+    pub violation_type: String,
+    /// Advice message.
+    pub advice: String,
+    /// Current value (for threshold violations).
+    pub value: Option<i64>,
+    /// Threshold that was exceeded.
+    pub threshold: Option<i64>,
+    /// Pattern name that matched (for escape violations).
+    pub pattern: Option<String>,
+    /// Total line count (for cloc violations).
+    pub lines: Option<i64>,
+    /// Non-blank line count (for cloc violations).
+    pub nonblank: Option<i64>,
+    /// Path in TOC/link that was broken (for docs violations).
+    pub target_path: Option<String>,
+}
+
+impl CachedViolation {
+    /// Create from a Violation and check name.
+    pub fn from_violation(v: &Violation, check: &str) -> Self {
+        Self {
+            check: check.to_string(),
+            line: v.line,
+            violation_type: v.violation_type.clone(),
+            advice: v.advice.clone(),
+            value: v.int(Field::Value),
+            threshold: v.int(Field::Threshold),
+            pattern: v.text(Field::Pattern).map(str::to_string),
+            lines: v.int(Field::Lines),
+            nonblank: v.int(Field::Nonblank),
+            target_path: v
+                .text(Field::Target)
+                .or_else(|| v.text(Field::Path))
+                .map(str::to_string),
+        }
+    }
+
+    /// Convert back to Violation with file path.
+    pub fn to_violation(&self, file: PathBuf) -> Violation {
+        let mut details = Details::default();
+        let ints = [
+            (Field::Value, self.value),
+            (Field::Threshold, self.threshold),
+            (Field::Lines, self.lines),
+            (Field::Nonblank, self.nonblank),
+        ];
+        for (field, n) in ints {
+            if let Some(n) = n {
+                details.insert(field, n);
+            }
+        }
+        if let Some(pattern) = &self.pattern {
+            details.insert(Field::Pattern, pattern.as_str());
+        }
+        if let Some(target) = &self.target_path {
+            details.insert(Field::Target, target.as_str());
+        }
+        Violation {
+            file: Some(file),
+            line: self.line,
+            violation_type: self.violation_type.clone(),
+            advice: self.advice.clone(),
+            details,
+        }
+    }
+}
+
+/// Persistent cache structure for serialization.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PersistentCache {
+    /// Cache format version.
+    pub version: u32,
+    /// Quench version that created this cache.
+    pub quench_version: String,
+    /// Hashes of config that affects check results.
+    pub config_hashes: ConfigHashes,
+    /// Content last seen at each path.
+    pub(crate) index: HashMap<PathBuf, IndexEntry>,
+    /// Violations by content hash.
+    pub(crate) entries: HashMap<ContentHash, Vec<CachedViolation>>,
+    /// Last run duration per check in milliseconds (for `--budget`).
+    pub(crate) check_durations: HashMap<String, u64>,
+}
+
+/// Runtime cache wrapper with thread-safe access.
+pub struct FileCache {
+    /// Content last seen at each path.
+    index: DashMap<PathBuf, IndexEntry>,
+    /// Violations by content hash (Arc for O(1) clone on hits).
+    entries: DashMap<ContentHash, Arc<Vec<CachedViolation>>>,
+    /// Config hashes for invalidation.
+    config_hashes: ConfigHashes,
+    /// Checks whose config changed since the cache was written; they rerun
+    /// on every file.
+    stale_checks: HashSet<String>,
+    /// Quench version.
+    quench_version: String,
+    /// Cache hit count.
+    hits: AtomicUsize,
+    /// Cache miss count.
+    misses: AtomicUsize,
+    /// Last run duration per check in milliseconds.
+    check_durations: DashMap<String, u64>,
+    /// Checks cut short this run; they're persisted as stale.
+    invalidated: DashSet<String>,
+}
+
+/// Cache statistics.
+#[derive(Debug, Clone, Default)]
+pub struct CacheStats {
+    /// Number of cache hits.
+    pub hits: usize,
+    /// Number of cache misses.
+    pub misses: usize,
+    /// Number of entries in cache.
+    pub entries: usize,
+}
+
+impl FileCache {
+    /// Create a new empty cache.
+    pub fn new(config_hashes: ConfigHashes) -> Self {
+        Self {
+            index: DashMap::new(),
+            entries: DashMap::new(),
+            config_hashes,
+            stale_checks: HashSet::new(),
+            quench_version: env!("CARGO_PKG_VERSION").to_string(),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+            check_durations: DashMap::new(),
+            invalidated: DashSet::new(),
+        }
+    }
+
+    /// Load cache from disk.
+    ///
+    /// Cached violations of checks whose config changed are dropped, and
+    /// those checks are marked stale so the runner reruns them on every file.
+    pub fn from_persistent(path: &Path, config_hashes: ConfigHashes) -> Result<Self, CacheError> {
+        let bytes = FileBytes::read(path)?;
+        let cache = decode(bytes.as_bytes())?;
+
+        // Validate version
+        if cache.version != CACHE_VERSION {
+            return Err(CacheError::VersionMismatch);
+        }
+        if cache.quench_version != env!("CARGO_PKG_VERSION") {
+            return Err(CacheError::QuenchVersionMismatch);
+        }
+        if cache.config_hashes.shared != config_hashes.shared {
+            return Err(CacheError::ConfigChanged);
+        }
+        let stale_checks = cache.config_hashes.changed_checks(&config_hashes);
+
+        // Convert serialized format to runtime format (wrap violations in Arc)
+        let entries: DashMap<ContentHash, Arc<Vec<CachedViolation>>> = cache
+            .entries
+            .into_iter()
+            .map(|(hash, mut violations)| {
+                if !stale_checks.is_empty() {
+                    violations.retain(|v| !stale_checks.contains(&v.check));
+                }
+                (hash, Arc::new(violations))
+            })
+            .collect();
+
+        Ok(Self {
+            index: cache.index.into_iter().collect(),
+            entries,
+            config_hashes,
+            stale_checks,
+            quench_version: cache.quench_version,
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+            check_durations: cache.check_durations.into_iter().collect(),
+            invalidated: DashSet::new(),
+        })
+    }
+
+    /// Look up cached violations for a file by its path index only.
+    ///
+    /// Returns Some if the path's last-seen mtime+size match.
+    /// The returned Arc allows O(1) clone instead of O(n) deep clone of violations.
+    pub fn lookup(&self, path: &Path, key: &FileCacheKey) -> Option<Arc<Vec<CachedViolation>>> {
+        let result = self.lookup_index(path, key);
+        self.count(result.is_some());
+        result
+    }
+
+    /// Look up cached violations for a file, falling back to its content.
+    ///
+    /// When the path index is stale (new mtime or unknown path), `hash` is
+    /// called to hash the file's content; an entry for that content is a hit
+    /// and re-points the path index at it.
+    pub fn lookup_file(
+        &self,
+        path: &Path,
+        key: &FileCacheKey,
+        hash: impl FnOnce() -> Option<ContentHash>,
+    ) -> Lookup {
+        if let Some(violations) = self.lookup_index(path, key) {
+            self.count(true);
+            return Lookup::Hit(violations);
+        }
+        let hash = hash();
+        let content_hit = hash.and_then(|h| Some((h, self.entries.get(&h)?.value().clone())));
+        self.count(content_hit.is_some());
+        match content_hit {
+            Some((hash, violations)) => {
+                self.index.insert(
+                    path.to_path_buf(),
+                    IndexEntry {
+                        key: key.clone(),
+                        hash,
+                    },
+                );
+                Lookup::Hit(violations)
+            }
+            None => Lookup::Miss(hash),
+        }
+    }
+
+    fn lookup_index(&self, path: &Path, key: &FileCacheKey) -> Option<Arc<Vec<CachedViolation>>> {
+        let entry = self.index.get(path)?;
+        if entry.key != *key {
+            return None;
+        }
+        self.entries.get(&entry.hash).map(|e| Arc::clone(e.value()))
+    }
+
+    fn count(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Insert or update a file's cached result.
+    pub fn insert(
+        &self,
+        path: PathBuf,
+        key: FileCacheKey,
+        hash: ContentHash,
+        violations: Vec<CachedViolation>,
+    ) {
+        self.entries.insert(hash, Arc::new(violations));
+        self.index.insert(path, IndexEntry { key, hash });
+    }
+
+    /// Whether a check's config changed, so its cached results were dropped.
+    pub fn is_stale(&self, check: &str) -> bool {
+        self.stale_checks.contains(check)
+    }
+
+    /// Add stale checks' fresh violations to cached files' entries.
+    ///
+    /// Paths sharing one content entry produce the same violations, so each
+    /// entry is extended once.
+    pub fn extend(&self, by_file: HashMap<PathBuf, Vec<CachedViolation>>) {
+        let mut by_hash: HashMap<ContentHash, Vec<CachedViolation>> = HashMap::new();
+        for (path, violations) in by_file {
+            if let Some(entry) = self.index.get(&path) {
+                by_hash.entry(entry.hash).or_insert(violations);
+            }
+        }
+        for (hash, violations) in by_hash {
+            if let Some(mut entry) = self.entries.get_mut(&hash) {
+                let mut all = (**entry).clone();
+                all.extend(violations);
+                *entry = Arc::new(all);
+            }
+        }
+    }
+
+    /// Record how long a check took on this run.
+    pub fn record_check_duration(&self, check: &str, duration_ms: u64) {
+        self.check_durations.insert(check.to_string(), duration_ms);
+    }
+
+    /// Mark a check's results from this run as incomplete.
+    ///
+    /// The persisted cache records a config hash the check can't match, so
+    /// the next run treats it as stale and reruns it on every file.
+    pub fn invalidate(&self, check: &str) {
+        self.invalidated.insert(check.to_string());
+    }
+
+    /// Duration of a check's last recorded run, in milliseconds.
+    pub fn check_duration(&self, check: &str) -> Option<u64> {
+        self.check_durations.get(check).map(|d| *d)
+    }
+
+    /// Snapshot the cache into its serialized form.
+    ///
+    /// Content entries no path points at (e.g., a file's previous
+    /// contents) are garbage-collected.
+    fn to_persistent(&self) -> PersistentCache {
+        let index: HashMap<PathBuf, IndexEntry> = self
+            .index
+            .iter()
+            .map(|e| (e.key().clone(), e.value().clone()))
+            .collect();
+        let reachable: HashSet<ContentHash> = index.values().map(|e| e.hash).collect();
+        PersistentCache {
+            version: CACHE_VERSION,
+            quench_version: self.quench_version.clone(),
+            config_hashes: self.persisted_hashes(),
+            // Convert runtime format to serialized format (extract from Arc)
+            entries: self
+                .entries
+                .iter()
+                .filter(|e| reachable.contains(e.key()))
+                .map(|e| (*e.key(), (**e.value()).clone()))
+                .collect(),
+            index,
+            check_durations: self
+                .check_durations
+                .iter()
+                .map(|e| (e.key().clone(), *e.value()))
+                .collect(),
+        }
+    }
+
+    /// Config hashes to persist, with invalidated checks' hashes flipped so
+    /// the next load sees their config as changed.
+    fn persisted_hashes(&self) -> ConfigHashes {
+        let mut hashes = self.config_hashes.clone();
+        for check in self.invalidated.iter() {
+            let hash = hashes.checks.entry(check.clone()).or_insert(u64::MAX);
+            *hash = !*hash;
+        }
+        hashes
+    }
+
+    /// Persist cache to disk.
+    pub fn persist(&self, path: &Path) -> Result<(), CacheError> {
+        write(path, &self.to_persistent())
+    }
+
+    /// Persist cache to disk asynchronously.
+    ///
+    /// Returns a join handle that can be waited on, or ignored if caller
+    /// doesn't care about completion. Snapshotting, serialization, and
+    /// compression all happen on the background thread, so results can
+    /// print meanwhile.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::path::PathBuf;
+    /// # use std::sync::Arc;
+    /// # use tempfile::tempdir;
+    /// # use quench_core::cache::FileCache;
+    /// # let dir = tempdir().unwrap();
+    /// # let cache_path = dir.path().join("cache.bin");
+    /// # let cache = Arc::new(FileCache::new(Default::default()));
+    /// // Fire and forget - cache write happens in background
+    /// let handle = cache.persist_async(cache_path);
+    /// // Wait for completion (optional - process can exit without waiting)
+    /// handle.join().unwrap().unwrap();
+    /// ```
+    pub fn persist_async(self: &Arc<Self>, path: PathBuf) -> JoinHandle<Result<(), CacheError>> {
+        let cache = Arc::clone(self);
+        std::thread::spawn(move || {
+            // Ensure parent directory exists
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            write(&path, &cache.to_persistent())
+        })
+    }
+
+    /// Get cache statistics.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.index.len(),
+        }
+    }
+}
+
+/// Serialize and compress a cache.
+pub(crate) fn encode(cache: &PersistentCache) -> Result<Vec<u8>, CacheError> {
+    let bytes = postcard::to_allocvec(cache)?;
+    // Fast level: persistence runs on every check, and the cache is mostly
+    // repeated paths and advice that compress well even at low effort
+    let mut encoder = DeflateEncoder::new(Vec::with_capacity(bytes.len() / 4), Compression::fast());
+    encoder.write_all(&bytes)?;
+    Ok(encoder.finish()?)
+}
+
+/// Decompress and deserialize a cache.
+fn decode(bytes: &[u8]) -> Result<PersistentCache, CacheError> {
+    let mut raw = Vec::with_capacity(bytes.len() * 4);
+    DeflateDecoder::new(bytes).read_to_end(&mut raw)?;
+    Ok(postcard::from_bytes(&raw)?)
+}
+
+/// Write a cache atomically via a temp file.
+fn write(path: &Path, cache: &PersistentCache) -> Result<(), CacheError> {
+    crate::atomic::write(path, encode(cache)?)?;
+    Ok(())
+}
+
+/// Compute the hashes of config fields that affect check results.
+pub fn hash_config(config: &crate::config::Config) -> ConfigHashes {
+    let mut hasher = DefaultHasher::new();
+    config.project.packages.hash(&mut hasher);
+
+    // Hash test/source patterns from resolution hierarchy:
+    // 1. Language-specific patterns (most specific)
+    // 2. Project-level patterns
+    // Changes to any of these affect file classification.
+    config.project.tests.hash(&mut hasher);
+    config.project.source.hash(&mut hasher);
+    config.rust.tests.hash(&mut hasher);
+    config.rust.source.hash(&mut hasher);
+    config.rust.exclude.hash(&mut hasher);
+    config.golang.tests.hash(&mut hasher);
+    config.golang.source.hash(&mut hasher);
+    config.javascript.tests.hash(&mut hasher);
+    config.javascript.source.hash(&mut hasher);
+    config.shell.tests.hash(&mut hasher);
+    config.shell.source.hash(&mut hasher);
+    // Scopes can change any check's findings in their files
+    for scope in &config.scopes {
+        scope.pattern.hash(&mut hasher);
+        scope.disable.hash(&mut hasher);
+        scope.section.to_string().hash(&mut hasher);
+    }
+    let shared = hasher.finish();
+
+    // Check sections hash their Debug form, which covers every field.
+    // (Sections holding a HashMap would hash nondeterministically.)
+    let mut cloc = DefaultHasher::new();
+    format!("{:?}", config.check.cloc).hash(&mut cloc);
+
+    // Escapes depends on its own section (level, excludes, patterns) and on
+    // the suppress levels of every language.
+    let mut escapes = DefaultHasher::new();
+    format!("{:?}", config.check.escapes).hash(&mut escapes);
+    config.rust.suppress.check.hash(&mut escapes);
+    config.javascript.suppress.check.hash(&mut escapes);
+    config.golang.suppress.check.hash(&mut escapes);
+    config.shell.suppress.check.hash(&mut escapes);
+    config.ruby.suppress.check.hash(&mut escapes);
+    config.python.suppress.check.hash(&mut escapes);
+
+    let mut checks = BTreeMap::from([
+        ("cloc".to_string(), cloc.finish()),
+        ("escapes".to_string(), escapes.finish()),
+    ]);
+
+    // Instances hash their paths and merged section
+    for instance in &config.instances {
+        let mut hasher = DefaultHasher::new();
+        instance.paths.hash(&mut hasher);
+        instance.section.to_string().hash(&mut hasher);
+        checks.insert(instance.name.clone(), hasher.finish());
+    }
+
+    ConfigHashes { shared, checks }
+}
+
+#[cfg(test)]
+#[path = "cache_tests.rs"]
+mod tests;
@@ -0,0 +1,685 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use tempfile::tempdir;
+
+#[test]
+fn file_cache_key_from_metadata() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.txt");
+    std::fs::write(&path, "hello").unwrap();
+
+    let meta = std::fs::metadata(&path).unwrap();
+    let key = FileCacheKey::from_metadata(&meta);
+
+    assert_eq!(key.size, 5);
+    assert!(key.mtime_secs > 0);
+}
+
+#[test]
+fn cache_lookup_miss_on_empty() {
+    let cache = FileCache::new(ConfigHashes::default());
+    let key = FileCacheKey {
+        mtime_secs: 100,
+        mtime_nanos: 0,
+        size: 50,
+    };
+
+    let result = cache.lookup(Path::new("nonexistent.rs"), &key);
+    assert!(result.is_none());
+    assert_eq!(cache.stats().misses, 1);
+}
+
+#[test]
+fn cache_insert_and_lookup_hit() {
+    let cache = FileCache::new(ConfigHashes::default());
+    let path = PathBuf::from("src/main.rs");
+    let key = FileCacheKey {
+        mtime_secs: 100,
+        mtime_nanos: 0,
+        size: 50,
+    };
+
+    let violations = vec![CachedViolation {
+        check: "cloc".to_string(),
+        line: Some(10),
+        violation_type: "file_too_large".to_string(),
+        advice: "Split the file".to_string(),
+        value: None,
+        threshold: None,
+        pattern: None,
+        lines: None,
+        nonblank: None,
+        target_path: None,
+    }];
+
+    cache.insert(path.clone(), key.clone(), 1, violations.clone());
+
+    let result = cache.lookup(&path, &key);
+    assert!(result.is_some());
+    let cached = result.unwrap();
+    assert_eq!(cached.len(), 1);
+    assert_eq!(cached[0].check, "cloc");
+    assert_eq!(cache.stats().hits, 1);
+}
+
+#[test]
+fn cache_miss_on_mtime_change() {
+    let cache = FileCache::new(ConfigHashes::default());
+    let path = PathBuf::from("src/main.rs");
+    let old_key = FileCacheKey {
+        mtime_secs: 100,
+        mtime_nanos: 0,
+        size: 50,
+    };
+    let new_key = FileCacheKey {
+        mtime_secs: 200, // Changed
+        mtime_nanos: 0,
+        size: 50,
+    };
+
+    cache.insert(path.clone(), old_key, 1, vec![]);
+
+    let result = cache.lookup(&path, &new_key);
+    assert!(result.is_none());
+    assert_eq!(cache.stats().misses, 1);
+}
+
+#[test]
+fn cache_miss_on_size_change() {
+    let cache = FileCache::new(ConfigHashes::default());
+    let path = PathBuf::from("src/main.rs");
+    let old_key = FileCacheKey {
+        mtime_secs: 100,
+        mtime_nanos: 0,
+        size: 50,
+    };
+    let new_key = FileCacheKey {
+        mtime_secs: 100,
+        mtime_nanos: 0,
+        size: 100, // Changed
+    };
+
+    cache.insert(path.clone(), old_key, 1, vec![]);
+
+    let result = cache.lookup(&path, &new_key);
+    assert!(result.is_none());
+}
+
+#[test]
+fn cache_persist_and_restore() {
+    let dir = tempdir().unwrap();
+    let cache_path = dir.path().join("cache.bin");
+    let config_hashes = hashes(12345);
+
+    // Create and populate cache
+    let cache = FileCache::new(config_hashes.clone());
+    let file_path = PathBuf::from("src/lib.rs");
+    let key = FileCacheKey {
+        mtime_secs: 100,
+        mtime_nanos: 500,
+        size: 1000,
+    };
+    cache.insert(
+        file_path.clone(),
+        key.clone(),
+        1,
+        vec![CachedViolation {
+            check: "cloc".to_string(),
+            line: Some(42),
+            violation_type: "file_too_large".to_string(),
+            advice: "Refactor".to_string(),
+            value: Some(100),
+            threshold: Some(50),
+            pattern: None,
+            lines: None,
+            nonblank: None,
+            target_path: None,
+        }],
+    );
+
+    // Persist
+    cache.persist(&cache_path).unwrap();
+
+    // Restore
+    let restored = FileCache::from_persistent(&cache_path, config_hashes).unwrap();
+
+    // Verify
+    let result = restored.lookup(&file_path, &key);
+    assert!(result.is_some());
+    let violations = result.unwrap();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].line, Some(42));
+}
+
+#[test]
+fn cache_rejects_version_mismatch() {
+    let dir = tempdir().unwrap();
+    let cache_path = dir.path().join("cache.bin");
+
+    // Create cache with wrong version
+    let bad_cache = PersistentCache {
+        version: CACHE_VERSION + 1, // Wrong version
+        quench_version: env!("CARGO_PKG_VERSION").to_string(),
+        config_hashes: ConfigHashes::default(),
+        index: HashMap::new(),
+        entries: HashMap::new(),
+        check_durations: HashMap::new(),
+    };
+
+    let bytes = encode(&bad_cache).unwrap();
+    std::fs::write(&cache_path, &bytes).unwrap();
+
+    let result = FileCache::from_persistent(&cache_path, ConfigHashes::default());
+    assert!(matches!(result, Err(CacheError::VersionMismatch)));
+}
+
+#[test]
+fn cache_rejects_config_change() {
+    let dir = tempdir().unwrap();
+    let cache_path = dir.path().join("cache.bin");
+
+    // Create cache with different shared config hash
+    let cache = FileCache::new(hashes(111));
+    cache.persist(&cache_path).unwrap();
+
+    let result = FileCache::from_persistent(&cache_path, hashes(222));
+    assert!(matches!(result, Err(CacheError::ConfigChanged)));
+}
+
+#[test]
+fn cached_violation_roundtrip() {
+    let violation = Violation::file("src/main.rs", 10, "test_type", "test advice");
+    let cached = CachedViolation::from_violation(&violation, "test_check");
+
+    assert_eq!(cached.check, "test_check");
+    assert_eq!(cached.line, Some(10));
+    assert_eq!(cached.violation_type, "test_type");
+    assert_eq!(cached.advice, "test advice");
+
+    let restored = cached.to_violation(PathBuf::from("src/main.rs"));
+    assert_eq!(restored.line, Some(10));
+    assert_eq!(restored.violation_type, "test_type");
+}
+
+#[test]
+fn hash_config_deterministic() {
+    let config = crate::config::Config::default();
+    let hash1 = hash_config(&config);
+    let hash2 = hash_config(&config);
+    assert_eq!(hash1, hash2);
+}
+
+#[test]
+fn cache_persist_async_completes() {
+    let dir = tempdir().unwrap();
+    let cache_path = dir.path().join("cache.bin");
+    let config_hashes = hashes(12345);
+
+    // Create and populate cache
+    let cache = Arc::new(FileCache::new(config_hashes.clone()));
+    let file_path = PathBuf::from("src/lib.rs");
+    let key = FileCacheKey {
+        mtime_secs: 100,
+        mtime_nanos: 500,
+        size: 1000,
+    };
+    cache.insert(file_path.clone(), key.clone(), 1, vec![]);
+
+    // Persist asynchronously and wait for completion
+    let handle = cache.persist_async(cache_path.clone());
+    handle
+        .join()
+        .expect("thread panicked")
+        .expect("persist failed");
+
+    // Verify file exists and can be restored
+    assert!(cache_path.exists());
+    let restored = FileCache::from_persistent(&cache_path, config_hashes).unwrap();
+    let result = restored.lookup(&file_path, &key);
+    assert!(result.is_some());
+}
+
+#[test]
+fn cache_lookup_returns_arc_for_efficient_cloning() {
+    use std::sync::Arc;
+
+    let cache = FileCache::new(ConfigHashes::default());
+    let path = PathBuf::from("src/main.rs");
+    let key = FileCacheKey {
+        mtime_secs: 100,
+        mtime_nanos: 0,
+        size: 50,
+    };
+
+    // Insert violations
+    let violations = vec![CachedViolation {
+        check: "test".to_string(),
+        line: Some(1),
+        violation_type: "test".to_string(),
+        advice: "test".to_string(),
+        value: None,
+        threshold: None,
+        pattern: None,
+        lines: None,
+        nonblank: None,
+        target_path: None,
+    }];
+    cache.insert(path.clone(), key.clone(), 1, violations);
+
+    // Get two references - should be the same Arc (same pointer)
+    let arc1 = cache.lookup(&path, &key).unwrap();
+    let arc2 = cache.lookup(&path, &key).unwrap();
+
+    // Verify both point to same underlying data (Arc::ptr_eq)
+    assert!(Arc::ptr_eq(&arc1, &arc2));
+}
+
+// =============================================================================
+// EDGE CASE TESTS
+// =============================================================================
+
+#[test]
+fn cache_handles_epoch_mtime() {
+    // Test that cache works correctly with epoch mtime (1970-01-01)
+    let cache = FileCache::new(ConfigHashes::default());
+    let path = PathBuf::from("ancient.rs");
+    let key = FileCacheKey {
+        mtime_secs: 0, // Epoch
+        mtime_nanos: 0,
+        size: 100,
+    };
+
+    // Should not panic or cause issues
+    cache.insert(path.clone(), key.clone(), 1, vec![]);
+    let result = cache.lookup(&path, &key);
+    assert!(result.is_some());
+    assert_eq!(cache.stats().hits, 1);
+}
+
+#[test]
+fn cache_handles_pre_epoch_mtime_gracefully() {
+    // Test that cache works with mtime that represents a time before epoch
+    // (would have negative duration, but from_metadata handles this)
+    let cache = FileCache::new(ConfigHashes::default());
+    let path = PathBuf::from("very_old.rs");
+
+    // Pre-epoch times get clamped to 0 by from_metadata (unwrap_or_default)
+    // This simulates what would happen
+    let key = FileCacheKey {
+        mtime_secs: 0,
+        mtime_nanos: 0,
+        size: 50,
+    };
+
+    cache.insert(path.clone(), key.clone(), 1, vec![]);
+    let result = cache.lookup(&path, &key);
+    assert!(result.is_some());
+}
+
+#[test]
+fn cache_concurrent_insert_lookup() {
+    use std::sync::Arc;
+    use std::thread;
+
+    let cache = Arc::new(FileCache::new(ConfigHashes::default()));
+    let num_threads = 10;
+    let num_ops = 100;
+
+    let handles: Vec<_> = (0..num_threads)
+        .map(|thread_id| {
+            let cache = Arc::clone(&cache);
+            thread::spawn(move || {
+                for i in 0..num_ops {
+                    let path = PathBuf::from(format!("file_{}.rs", i));
+                    let key = FileCacheKey {
+                        mtime_secs: i as i64,
+                        mtime_nanos: 0,
+                        size: (thread_id * num_ops + i) as u64,
+                    };
+
+                    // Insert
+                    cache.insert(path.clone(), key.clone(), i as u64, vec![]);
+
+                    // Lookup (may hit or miss depending on race with other threads)
+                    let _ = cache.lookup(&path, &key);
+                }
+            })
+        })
+        .collect();
+
+    // All threads should complete without panic
+    for handle in handles {
+        handle.join().expect("thread panicked");
+    }
+
+    // Cache should have entries (not necessarily all due to overwrites)
+    assert!(cache.stats().entries > 0);
+}
+
+#[test]
+fn file_cache_key_from_walked_file_epoch() {
+    use crate::file_size::FileSizeClass;
+    use crate::walker::WalkedFile;
+
+    // Simulate a file with epoch mtime
+    let walked = WalkedFile {
+        path: PathBuf::from("test.rs"),
+        mtime_secs: 0, // Epoch
+        mtime_nanos: 0,
+        size: 100,
+        depth: 0,
+        size_class: FileSizeClass::Small,
+    };
+
+    let key = FileCacheKey::from_walked_file(&walked);
+    assert_eq!(key.mtime_secs, 0);
+    assert_eq!(key.mtime_nanos, 0);
+    assert_eq!(key.size, 100);
+}
+
+#[test]
+fn hash_config_changes_when_escapes_check_off() {
+    use crate::config::CheckLevel;
+
+    let mut config = crate::config::Config::default();
+    let hash_default = hash_config(&config);
+
+    config.check.escapes.check = CheckLevel::Off;
+    let hash_off = hash_config(&config);
+
+    assert_ne!(
+        hash_default.checks["escapes"], hash_off.checks["escapes"],
+        "escapes hash must change when check.escapes.check changes"
+    );
+    assert_eq!(hash_default.checks["cloc"], hash_off.checks["cloc"]);
+    assert_eq!(hash_default.shared, hash_off.shared);
+}
+
+#[test]
+fn hash_config_changes_when_suppress_check_changes() {
+    use crate::config::SuppressLevel;
+
+    let mut config = crate::config::Config::default();
+    let hash_default = hash_config(&config);
+
+    config.javascript.suppress.check = SuppressLevel::Allow;
+    let hash_changed = hash_config(&config);
+
+    assert_ne!(
+        hash_default.checks["escapes"], hash_changed.checks["escapes"],
+        "escapes hash must change when javascript.suppress.check changes"
+    );
+}
+
+#[test]
+fn check_durations_survive_persist() {
+    let dir = tempdir().unwrap();
+    let cache_path = dir.path().join("cache.bin");
+
+    let cache = FileCache::new(ConfigHashes::default());
+    cache.record_check_duration("cloc", 120);
+    cache.persist(&cache_path).unwrap();
+
+    let loaded = FileCache::from_persistent(&cache_path, ConfigHashes::default()).unwrap();
+    assert_eq!(loaded.check_duration("cloc"), Some(120));
+    assert_eq!(loaded.check_duration("tests"), None);
+}
+
+fn hashes(shared: u64) -> ConfigHashes {
+    ConfigHashes {
+        shared,
+        checks: Default::default(),
+    }
+}
+
+fn violation(check: &str) -> CachedViolation {
+    CachedViolation {
+        check: check.to_string(),
+        line: Some(1),
+        violation_type: "forbidden".to_string(),
+        advice: "Fix it.".to_string(),
+        value: None,
+        threshold: None,
+        pattern: None,
+        lines: None,
+        nonblank: None,
+        target_path: None,
+    }
+}
+
+#[test]
+fn hash_config_changes_only_cloc_when_cloc_threshold_changes() {
+    let mut config = crate::config::Config::default();
+    let before = hash_config(&config);
+
+    config.check.cloc.max_lines += 100;
+    let after = hash_config(&config);
+
+    assert_ne!(before.checks["cloc"], after.checks["cloc"]);
+    assert_eq!(before.checks["escapes"], after.checks["escapes"]);
+    assert_eq!(before.shared, after.shared);
+}
+
+#[test]
+fn hash_config_changes_when_escape_patterns_change() {
+    let mut config = crate::config::Config::default();
+    let before = hash_config(&config);
+
+    config
+        .check
+        .escapes
+        .patterns
+        .push(toml::from_str("name = \"todo\"\npattern = \"TODO\"").unwrap());
+    let after = hash_config(&config);
+
+    assert_ne!(before.checks["escapes"], after.checks["escapes"]);
+}
+
+#[test]
+fn changed_check_hash_drops_only_that_checks_violations() {
+    let dir = tempdir().unwrap();
+    let cache_path = dir.path().join("cache.bin");
+    let mut old = hashes(1);
+    old.checks.insert("cloc".to_string(), 10);
+    old.checks.insert("escapes".to_string(), 20);
+
+    let cache = FileCache::new(old.clone());
+    let path = PathBuf::from("src/lib.rs");
+    let key = FileCacheKey {
+        mtime_secs: 1,
+        mtime_nanos: 0,
+        size: 10,
+    };
+    cache.insert(
+        path.clone(),
+        key.clone(),
+        1,
+        vec![violation("cloc"), violation("escapes")],
+    );
+    cache.persist(&cache_path).unwrap();
+
+    let mut new = old;
+    new.checks.insert("cloc".to_string(), 11);
+    let restored = FileCache::from_persistent(&cache_path, new).unwrap();
+
+    assert!(restored.is_stale("cloc"));
+    assert!(!restored.is_stale("escapes"));
+    let cached = restored.lookup(&path, &key).unwrap();
+    assert_eq!(cached.len(), 1);
+    assert_eq!(cached[0].check, "escapes");
+
+    restored.extend(HashMap::from([(path.clone(), vec![violation("cloc")])]));
+    assert_eq!(restored.lookup(&path, &key).unwrap().len(), 2);
+}
+
+#[test]
+fn invalidated_checks_are_stale_after_reload() {
+    let dir = tempdir().unwrap();
+    let cache_path = dir.path().join("cache.bin");
+    let mut config = hashes(1);
+    config.checks.insert("cloc".to_string(), 10);
+    config.checks.insert("escapes".to_string(), 20);
+
+    let cache = FileCache::new(config.clone());
+    cache.invalidate("cloc");
+    // Checks without a config hash of their own too
+    cache.invalidate("tests");
+    cache.persist(&cache_path).unwrap();
+
+    let restored = FileCache::from_persistent(&cache_path, config.clone()).unwrap();
+    assert!(restored.is_stale("cloc"));
+    assert!(restored.is_stale("tests"));
+    assert!(!restored.is_stale("escapes"));
+
+    // The next persist records the real hashes again
+    restored.persist(&cache_path).unwrap();
+    let again = FileCache::from_persistent(&cache_path, config).unwrap();
+    assert!(!again.is_stale("cloc"));
+    assert!(!again.is_stale("tests"));
+}
+
+// =============================================================================
+// CONTENT-ADDRESSED ENTRIES
+// =============================================================================
+
+fn key(mtime_secs: i64) -> FileCacheKey {
+    FileCacheKey {
+        mtime_secs,
+        mtime_nanos: 0,
+        size: 10,
+    }
+}
+
+#[test]
+fn renamed_file_hits_by_content() {
+    let cache = FileCache::new(ConfigHashes::default());
+    cache.insert(
+        PathBuf::from("src/old.rs"),
+        key(1),
+        7,
+        vec![violation("escapes")],
+    );
+
+    let lookup = cache.lookup_file(Path::new("src/new.rs"), &key(2), || Some(7));
+
+    assert!(matches!(lookup, Lookup::Hit(v) if v.len() == 1));
+    assert_eq!(cache.stats().hits, 1);
+    assert_eq!(cache.stats().misses, 0);
+    // The new path is now indexed without rehashing
+    assert!(cache.lookup(Path::new("src/new.rs"), &key(2)).is_some());
+}
+
+#[test]
+fn touched_file_with_same_content_hits() {
+    let cache = FileCache::new(ConfigHashes::default());
+    let path = PathBuf::from("src/lib.rs");
+    cache.insert(path.clone(), key(1), 7, vec![]);
+
+    let lookup = cache.lookup_file(&path, &key(2), || Some(7));
+
+    assert!(matches!(lookup, Lookup::Hit(_)));
+}
+
+#[test]
+fn index_hit_skips_hashing() {
+    let cache = FileCache::new(ConfigHashes::default());
+    let path = PathBuf::from("src/lib.rs");
+    cache.insert(path.clone(), key(1), 7, vec![]);
+
+    let lookup = cache.lookup_file(&path, &key(1), || panic!("hashed on index hit"));
+
+    assert!(matches!(lookup, Lookup::Hit(_)));
+}
+
+#[test]
+fn changed_content_misses_with_new_hash() {
+    let cache = FileCache::new(ConfigHashes::default());
+    let path = PathBuf::from("src/lib.rs");
+    cache.insert(path.clone(), key(1), 7, vec![]);
+
+    let lookup = cache.lookup_file(&path, &key(2), || Some(8));
+
+    assert!(matches!(lookup, Lookup::Miss(Some(8))));
+    assert_eq!(cache.stats().misses, 1);
+}
+
+#[test]
+fn persist_drops_unreferenced_content() {
+    let cache = FileCache::new(ConfigHashes::default());
+    let path = PathBuf::from("src/lib.rs");
+    cache.insert(path.clone(), key(1), 7, vec![]);
+    cache.insert(path.clone(), key(2), 8, vec![]);
+
+    let persistent = cache.to_persistent();
+
+    assert_eq!(persistent.entries.len(), 1);
+    assert!(persistent.entries.contains_key(&8));
+}
+
+#[test]
+fn extend_updates_shared_content_once() {
+    let cache = FileCache::new(ConfigHashes::default());
+    cache.insert(PathBuf::from("a.rs"), key(1), 7, vec![]);
+    cache.insert(PathBuf::from("b.rs"), key(1), 7, vec![]);
+
+    cache.extend(HashMap::from([
+        (PathBuf::from("a.rs"), vec![violation("cloc")]),
+        (PathBuf::from("b.rs"), vec![violation("cloc")]),
+    ]));
+
+    assert_eq!(cache.lookup(Path::new("a.rs"), &key(1)).unwrap().len(), 1);
+}
+
+#[test]
+fn content_hash_depends_on_path_context() {
+    let dir = tempdir().unwrap();
+    std::fs::write(dir.path().join("Cargo.toml"), "[package]\nname = \"x\"\n").unwrap();
+    let config = crate::config::Config::default();
+    let context = PathContext::new(dir.path(), &config);
+    let hash = |path: &str| context.content_hash(Path::new(path), b"fn main() {}");
+
+    assert_eq!(hash("src/a.rs"), hash("src/b.rs"));
+    // Test files are classified differently
+    assert_ne!(hash("src/a.rs"), hash("tests/a.rs"));
+    // Other languages have different rules
+    assert_ne!(hash("src/a.rs"), hash("src/a.go"));
+    // Non-code files are path-specific
+    assert_ne!(hash("docs/a.md"), hash("docs/b.md"));
+}
+
+// =============================================================================
+// ON-DISK FORMAT
+// =============================================================================
+
+#[test]
+fn persisted_cache_is_compressed() {
+    let dir = tempdir().unwrap();
+    let cache_path = dir.path().join("cache.bin");
+    let cache = FileCache::new(ConfigHashes::default());
+    for i in 0..100 {
+        let path = PathBuf::from(format!("src/module_{}.rs", i));
+        cache.insert(path, key(i), i as u64, vec![violation("escapes")]);
+    }
+
+    cache.persist(&cache_path).unwrap();
+
+    let raw = postcard::to_allocvec(&cache.to_persistent()).unwrap();
+    let on_disk = std::fs::read(&cache_path).unwrap();
+    assert!(on_disk.len() < raw.len() / 2);
+    let restored = FileCache::from_persistent(&cache_path, ConfigHashes::default()).unwrap();
+    assert_eq!(restored.stats().entries, 100);
+}
+
+#[test]
+fn uncompressed_cache_is_rejected() {
+    let dir = tempdir().unwrap();
+    let cache_path = dir.path().join("cache.bin");
+    let raw = postcard::to_allocvec(&FileCache::new(ConfigHashes::default()).to_persistent());
+    std::fs::write(&cache_path, raw.unwrap()).unwrap();
+
+    let result = FileCache::from_persistent(&cache_path, ConfigHashes::default());
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! The context checks run in.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicUsize;
+
+use crate::config::Config;
+use crate::diagnostics::Diagnostics;
+use crate::file_reader::{DecodeLog, FileContent, StagedContent};
+use crate::patch::FixPatch;
+use crate::walker::WalkedFile;
+
+/// Context passed to all checks during execution.
+pub struct CheckContext<'a> {
+    /// Project root directory.
+    pub root: &'a Path,
+    /// Discovered files from the walker.
+    pub files: &'a [WalkedFile],
+    /// Parsed configuration.
+    pub config: &'a Config,
+    /// Violation limit (None = unlimited).
+    pub limit: Option<usize>,
+    /// Running violation count across all checks.
+    pub violation_count: &'a AtomicUsize,
+    /// Files changed since base ref (for --base flag).
+    pub changed_files: Option<&'a [PathBuf]>,
+    /// Whether to automatically fix violations when possible.
+    pub fix: bool,
+    /// Whether check fixers run over the violations (`quench fix`). Plain
+    /// `--fix` leaves source edits to them alone.
+    pub run_fixers: bool,
+    /// Show what --fix would change without modifying files.
+    pub dry_run: bool,
+    /// Collects fixes instead of writing them (--emit-patch).
+    pub patch: Option<&'a FixPatch>,
+    /// Whether running in CI mode (enables slow checks like commit validation).
+    pub ci_mode: bool,
+    /// Base branch for commit comparison in CI mode.
+    pub base_branch: Option<&'a str>,
+    /// Whether checking only staged changes (--staged flag).
+    pub staged: bool,
+    /// Staged content read in place of the working tree (--staged flag).
+    pub staged_content: Option<&'a StagedContent>,
+    /// Whether verbose diagnostic output is enabled.
+    pub verbose: bool,
+    /// Files decoded lossily while checking.
+    pub decode_log: &'a DecodeLog,
+    /// Problems with the run itself, reported with a code and hint.
+    pub diagnostics: &'a Diagnostics,
+}
+
+impl CheckContext<'_> {
+    /// Read a file as text, recording lossy decoding for the run summary.
+    ///
+    /// With `--staged`, a staged file is read from the git index.
+    pub fn read_file(&self, path: &Path) -> std::io::Result<FileContent> {
+        match self.staged_content.and_then(|staged| staged.read(path)) {
+            Some(content) => Ok(self.decode_log.record(path, content)),
+            None => self.decode_log.read(path),
+        }
+    }
+
+    /// Write a fixed file, or record it in the patch with `--emit-patch` or
+    /// `--dry-run`. A dry run without a patch writes nothing.
+    ///
+    /// Fails for a file read from the git index, whose fix would drop its
+    /// unstaged changes.
+    pub fn write_fix(&self, path: &Path, content: &str) -> std::io::Result<()> {
+        if self
+            .staged_content
+            .is_some_and(|staged| staged.contains(path))
+        {
+            return Err(std::io::Error::other(format!(
+                "{} has unstaged changes",
+                path.display()
+            )));
+        }
+        match self.patch {
+            Some(patch) => {
+                patch.record(path, content);
+                Ok(())
+            }
+            None if self.dry_run => Ok(()),
+            None => std::fs::write(path, content),
+        }
+    }
+}
@@ -0,0 +1,725 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Check result types for output formatting.
+
+mod context;
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use crate::diagnostics::Diagnostic;
+use crate::fix::Fix;
+
+pub use context::CheckContext;
+
+/// The Check trait defines a single quality check.
+///
+/// Object-safe to allow dynamic dispatch via `Box<dyn Check>`.
+pub trait Check: Send + Sync {
+    /// Unique identifier for this check (e.g., "cloc", "escapes").
+    fn name(&self) -> &'static str;
+
+    /// Human-readable description for help output.
+    fn description(&self) -> &'static str;
+
+    /// Run the check and return results.
+    ///
+    /// Implementations should:
+    /// - Return `CheckResult::skipped()` if prerequisites are missing
+    /// - Respect `ctx.limit` for early termination
+    /// - Handle errors gracefully without panicking
+    fn run(&self, ctx: &CheckContext) -> CheckResult;
+
+    /// Whether this check is enabled by default in fast mode.
+    fn default_enabled(&self) -> bool {
+        true
+    }
+
+    /// Repairs this check's violations after it runs with `--fix`, if it can.
+    fn fixer(&self) -> Option<&dyn Fix> {
+        None
+    }
+
+    /// Whether cached results of unchanged files can stand in for a rerun.
+    ///
+    /// Checks that relate files to each other return false: a change in one
+    /// file can change the result for another, so they run on every file.
+    fn cacheable(&self) -> bool {
+        true
+    }
+}
+
+/// A single violation within a check.
+///
+/// Built with a constructor ([`Violation::file`], [`Violation::project`],
+/// ...) and `with_*` calls. Everything beyond location, type, and advice
+/// lives in [`Violation::details`], keyed by [`Field`], and serializes as
+/// top-level keys.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Violation {
+    /// File path (None for non-file violations like commit messages).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
+
+    /// Line number (None if not applicable).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+
+    /// Violation category (check-specific).
+    #[serde(rename = "type")]
+    pub violation_type: String,
+
+    /// Actionable guidance on how to fix.
+    pub advice: String,
+
+    /// Check-specific context, in [`Field`] order.
+    #[serde(flatten)]
+    pub details: Details,
+}
+
+/// Keys of [`Violation::details`], in output order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Field {
+    /// Current value (for threshold violations).
+    Value,
+    /// Threshold that was exceeded.
+    Threshold,
+    /// Pattern name that matched (for escape violations).
+    Pattern,
+    /// Total line count (for cloc violations).
+    Lines,
+    /// Non-blank line count (for cloc violations).
+    Nonblank,
+    /// Other file involved in sync comparison.
+    OtherFile,
+    /// Section name for section-level violations.
+    Section,
+    /// Commit hash for commit-level violations.
+    Commit,
+    /// Full commit message.
+    Message,
+    /// Expected docs pattern (for area-specific violations).
+    ExpectedDocs,
+    /// Area name that was matched (for area-specific violations).
+    Area,
+    /// How the area was matched ("scope" or "source").
+    AreaMatch,
+    /// Referenced path for broken_toc violations.
+    Path,
+    /// Link target for broken_link violations.
+    Target,
+    /// Type of change for missing_tests violations ("added" | "modified").
+    ChangeType,
+    /// Lines changed (added + deleted) for missing_tests violations.
+    LinesChanged,
+    /// Commit scope for git violations (e.g., "api" from "feat(api): ...").
+    Scope,
+    /// Expected value (e.g., expected license or year).
+    Expected,
+    /// Found value (e.g., actual license or year).
+    Found,
+    /// Violation on the same line that this one is grouped under.
+    Primary,
+}
+
+impl Field {
+    /// The kind of value this field holds.
+    pub fn kind(self) -> DetailKind {
+        match self {
+            Field::Value
+            | Field::Threshold
+            | Field::Lines
+            | Field::Nonblank
+            | Field::LinesChanged => DetailKind::Int,
+            Field::OtherFile => DetailKind::Path,
+            Field::Primary => DetailKind::Ref,
+            _ => DetailKind::Text,
+        }
+    }
+}
+
+/// The kinds of value a [`Detail`] can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetailKind {
+    Int,
+    Text,
+    Path,
+    Ref,
+}
+
+/// One value in [`Violation::details`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Detail {
+    Int(i64),
+    Text(String),
+    Path(PathBuf),
+    Ref(ViolationRef),
+}
+
+impl Detail {
+    pub fn kind(&self) -> DetailKind {
+        match self {
+            Detail::Int(_) => DetailKind::Int,
+            Detail::Text(_) => DetailKind::Text,
+            Detail::Path(_) => DetailKind::Path,
+            Detail::Ref(_) => DetailKind::Ref,
+        }
+    }
+}
+
+impl From<i64> for Detail {
+    fn from(n: i64) -> Self {
+        Detail::Int(n)
+    }
+}
+
+impl From<String> for Detail {
+    fn from(s: String) -> Self {
+        Detail::Text(s)
+    }
+}
+
+impl From<&str> for Detail {
+    fn from(s: &str) -> Self {
+        Detail::Text(s.to_string())
+    }
+}
+
+impl From<PathBuf> for Detail {
+    fn from(p: PathBuf) -> Self {
+        Detail::Path(p)
+    }
+}
+
+impl From<ViolationRef> for Detail {
+    fn from(r: ViolationRef) -> Self {
+        Detail::Ref(r)
+    }
+}
+
+/// Typed, ordered violation context.
+///
+/// Deserializing keeps known keys and ignores the rest, so output from
+/// newer versions still loads.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Details(BTreeMap<Field, Detail>);
+
+impl Details {
+    pub fn get(&self, field: Field) -> Option<&Detail> {
+        self.0.get(&field)
+    }
+
+    /// Set a field, replacing any previous value.
+    pub fn insert(&mut self, field: Field, detail: impl Into<Detail>) {
+        let detail = detail.into();
+        debug_assert_eq!(field.kind(), detail.kind(), "{field:?}");
+        self.0.insert(field, detail);
+    }
+
+    pub fn remove(&mut self, field: Field) -> Option<Detail> {
+        self.0.remove(&field)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Field, &Detail)> {
+        self.0.iter().map(|(field, detail)| (*field, detail))
+    }
+}
+
+impl<'de> Deserialize<'de> for Details {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let raw = BTreeMap::<String, JsonValue>::deserialize(deserializer)?;
+        let mut details = BTreeMap::new();
+        for (key, value) in raw {
+            let Ok(field) = serde_json::from_value::<Field>(JsonValue::String(key)) else {
+                continue;
+            };
+            let detail = match field.kind() {
+                DetailKind::Int => serde_json::from_value(value).map(Detail::Int),
+                DetailKind::Text => serde_json::from_value(value).map(Detail::Text),
+                DetailKind::Path => serde_json::from_value(value).map(Detail::Path),
+                DetailKind::Ref => serde_json::from_value(value).map(Detail::Ref),
+            }
+            .map_err(D::Error::custom)?;
+            details.insert(field, detail);
+        }
+        Ok(Details(details))
+    }
+}
+
+/// Reference to a violation by check name and violation type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViolationRef {
+    /// Check that reported the violation.
+    pub check: String,
+
+    /// Violation category.
+    #[serde(rename = "type")]
+    pub violation_type: String,
+}
+
+impl Violation {
+    fn new(
+        file: Option<PathBuf>,
+        line: Option<u32>,
+        violation_type: impl Into<String>,
+        advice: impl Into<String>,
+    ) -> Self {
+        Self {
+            file,
+            line,
+            violation_type: violation_type.into(),
+            advice: advice.into(),
+            details: Details::default(),
+        }
+    }
+
+    /// Create a file-based violation with line number.
+    pub fn file(
+        file: impl Into<PathBuf>,
+        line: u32,
+        violation_type: impl Into<String>,
+        advice: impl Into<String>,
+    ) -> Self {
+        Self::new(Some(file.into()), Some(line), violation_type, advice)
+    }
+
+    /// Create a file-based violation without line number.
+    pub fn file_only(
+        file: impl Into<PathBuf>,
+        violation_type: impl Into<String>,
+        advice: impl Into<String>,
+    ) -> Self {
+        Self::new(Some(file.into()), None, violation_type, advice)
+    }
+
+    /// Create a project-level violation (no file).
+    pub fn project(violation_type: impl Into<String>, advice: impl Into<String>) -> Self {
+        Self::new(None, None, violation_type, advice)
+    }
+
+    /// Create a commit-level violation (no file).
+    pub fn commit_violation(
+        hash: impl Into<String>,
+        message: impl Into<String>,
+        violation_type: impl Into<String>,
+        advice: impl Into<String>,
+    ) -> Self {
+        Self::project(violation_type, advice)
+            .with_detail(Field::Commit, hash.into())
+            .with_detail(Field::Message, message.into())
+    }
+
+    /// Add any detail field.
+    pub fn with_detail(mut self, field: Field, detail: impl Into<Detail>) -> Self {
+        self.details.insert(field, detail);
+        self
+    }
+
+    /// An integer detail, e.g. `Field::Value`.
+    pub fn int(&self, field: Field) -> Option<i64> {
+        match self.details.get(field)? {
+            Detail::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// A text detail, e.g. `Field::Pattern`.
+    pub fn text(&self, field: Field) -> Option<&str> {
+        match self.details.get(field)? {
+            Detail::Text(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// A path detail, e.g. `Field::OtherFile`.
+    pub fn path(&self, field: Field) -> Option<&Path> {
+        match self.details.get(field)? {
+            Detail::Path(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    /// The violation this one is grouped under, if any.
+    pub fn primary(&self) -> Option<&ViolationRef> {
+        match self.details.get(Field::Primary)? {
+            Detail::Ref(r) => Some(r),
+            _ => None,
+        }
+    }
+
+    /// Add expected docs pattern for area-specific violations.
+    pub fn with_expected_docs(self, docs: impl Into<String>) -> Self {
+        self.with_detail(Field::ExpectedDocs, docs.into())
+    }
+
+    /// Add area information to the violation.
+    pub fn with_area(self, name: impl Into<String>, match_type: impl Into<String>) -> Self {
+        self.with_detail(Field::Area, name.into())
+            .with_detail(Field::AreaMatch, match_type.into())
+    }
+
+    /// Add value/threshold context to the violation.
+    pub fn with_threshold(self, value: i64, threshold: i64) -> Self {
+        self.with_detail(Field::Value, value)
+            .with_detail(Field::Threshold, threshold)
+    }
+
+    /// Add line counts for cloc violations (both always included in JSON for convenience).
+    pub fn with_line_counts(self, lines: i64, nonblank: i64) -> Self {
+        self.with_detail(Field::Lines, lines)
+            .with_detail(Field::Nonblank, nonblank)
+    }
+
+    /// Add pattern context to the violation.
+    pub fn with_pattern(self, pattern: impl Into<String>) -> Self {
+        self.with_detail(Field::Pattern, pattern.into())
+    }
+
+    /// Add sync context to the violation.
+    pub fn with_sync(self, other_file: impl Into<PathBuf>, section: impl Into<String>) -> Self {
+        self.with_detail(Field::OtherFile, other_file.into())
+            .with_detail(Field::Section, section.into())
+    }
+
+    /// Add section context to the violation.
+    pub fn with_section(self, section: impl Into<String>) -> Self {
+        self.with_detail(Field::Section, section.into())
+    }
+
+    /// Add path context for broken_toc violations.
+    pub fn with_path(self, path: impl Into<String>) -> Self {
+        self.with_detail(Field::Path, path.into())
+    }
+
+    /// Add target context for broken_link violations.
+    pub fn with_target(self, target: impl Into<String>) -> Self {
+        self.with_detail(Field::Target, target.into())
+    }
+
+    /// Add change information for missing_tests violations.
+    pub fn with_change_info(self, change_type: impl Into<String>, lines_changed: i64) -> Self {
+        self.with_detail(Field::ChangeType, change_type.into())
+            .with_detail(Field::LinesChanged, lines_changed)
+    }
+
+    /// Add scope context for git commit violations.
+    pub fn with_scope(self, scope: impl Into<String>) -> Self {
+        self.with_detail(Field::Scope, scope.into())
+    }
+
+    /// Group this violation under another on the same line.
+    pub fn with_primary(self, primary: ViolationRef) -> Self {
+        self.with_detail(Field::Primary, primary)
+    }
+
+    /// Canonical ordering: file path, then line, then violation type.
+    ///
+    /// Violations without a file (e.g., commit messages) compare equal so a
+    /// stable sort keeps the order the check emitted them in.
+    pub fn cmp_canonical(&self, other: &Self) -> Ordering {
+        match (&self.file, &other.file) {
+            (None, None) => Ordering::Equal,
+            (a, b) => a
+                .cmp(b)
+                .then_with(|| self.line.cmp(&other.line))
+                .then_with(|| self.violation_type.cmp(&other.violation_type)),
+        }
+    }
+
+    /// Add expected/found values for license check violations.
+    pub fn with_expected_found(
+        self,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        self.with_detail(Field::Expected, expected.into())
+            .with_detail(Field::Found, found.into())
+    }
+}
+
+/// Result of running a single check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckResult {
+    /// Check identifier (e.g., "cloc", "escapes").
+    pub name: String,
+
+    /// Whether this check passed.
+    pub passed: bool,
+
+    /// True if check was skipped due to an error.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skipped: bool,
+
+    /// True if check is a stub (not yet implemented).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub stub: bool,
+
+    /// True if fixes were applied.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub fixed: bool,
+
+    /// Error message if check was skipped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// List of violations (always present for consistency, may be empty).
+    pub violations: Vec<Violation>,
+
+    /// Summary of fixes applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix_summary: Option<JsonValue>,
+
+    /// Aggregated metrics for this check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<JsonValue>,
+
+    /// Per-package breakdown of metrics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_package: Option<BTreeMap<String, JsonValue>>,
+
+    /// Execution duration (for --timing flag).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+}
+
+impl CheckResult {
+    /// Create a passing check result.
+    pub fn passed(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            passed: true,
+            skipped: false,
+            stub: false,
+            fixed: false,
+            error: None,
+            violations: Vec::new(),
+            fix_summary: None,
+            metrics: None,
+            by_package: None,
+            duration_ms: None,
+        }
+    }
+
+    /// Create a failing check result with violations.
+    pub fn failed(name: impl Into<String>, violations: Vec<Violation>) -> Self {
+        Self {
+            name: name.into(),
+            passed: false,
+            skipped: false,
+            stub: false,
+            fixed: false,
+            error: None,
+            violations,
+            fix_summary: None,
+            metrics: None,
+            by_package: None,
+            duration_ms: None,
+        }
+    }
+
+    /// Create a passing check result that includes warnings (violations that don't cause failure).
+    ///
+    /// Used when violations exist but all are at warn level, so the check passes
+    /// while still reporting issues.
+    pub fn passed_with_warnings(name: impl Into<String>, violations: Vec<Violation>) -> Self {
+        Self {
+            name: name.into(),
+            passed: true,
+            skipped: false,
+            stub: false,
+            fixed: false,
+            error: None,
+            violations,
+            fix_summary: None,
+            metrics: None,
+            by_package: None,
+            duration_ms: None,
+        }
+    }
+
+    /// Create a skipped check result with an error.
+    pub fn skipped(name: impl Into<String>, error: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            passed: false,
+            skipped: true,
+            stub: false,
+            fixed: false,
+            error: Some(error.into()),
+            violations: Vec::new(),
+            fix_summary: None,
+            metrics: None,
+            by_package: None,
+            duration_ms: None,
+        }
+    }
+
+    /// Create a stub check result (not yet implemented).
+    pub fn stub(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            passed: true,
+            skipped: false,
+            stub: true,
+            fixed: false,
+            error: None,
+            violations: Vec::new(),
+            fix_summary: None,
+            metrics: None,
+            by_package: None,
+            duration_ms: None,
+        }
+    }
+
+    /// Create a fixed check result.
+    pub fn fixed(name: impl Into<String>, summary: JsonValue) -> Self {
+        Self {
+            name: name.into(),
+            passed: true,
+            skipped: false,
+            stub: false,
+            fixed: true,
+            error: None,
+            violations: Vec::new(),
+            fix_summary: Some(summary),
+            metrics: None,
+            by_package: None,
+            duration_ms: None,
+        }
+    }
+
+    /// Create a result with metrics.
+    pub fn with_metrics(mut self, metrics: JsonValue) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Add per-package metrics breakdown.
+    pub fn with_by_package(mut self, by_package: BTreeMap<String, JsonValue>) -> Self {
+        self.by_package = Some(by_package);
+        self
+    }
+
+    /// Sort violations into canonical order (see [`Violation::cmp_canonical`]).
+    pub fn sort_violations(&mut self) {
+        self.violations.sort_by(Violation::cmp_canonical);
+    }
+
+    /// Mark this result as having fixes applied.
+    pub fn with_fix_summary(mut self, summary: JsonValue) -> Self {
+        self.fixed = true;
+        self.fix_summary = Some(summary);
+        self
+    }
+}
+
+/// Aggregated results from all checks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckOutput {
+    /// ISO 8601 timestamp.
+    pub timestamp: String,
+
+    /// Whether all checks passed.
+    pub passed: bool,
+
+    /// Run tags from `--tag KEY=VALUE`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, String>,
+
+    /// Files that weren't valid UTF-8 and were decoded lossily.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub undecodable_files: usize,
+
+    /// Results for each check.
+    pub checks: Vec<CheckResult>,
+
+    /// Problems with the run itself, in the order they were reported.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub diagnostics: Vec<Diagnostic>,
+
+    /// How CI mode was turned on; None outside CI mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ci: Option<CiMode>,
+
+    /// Whether Ctrl-C cut the run short, leaving results partial.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub interrupted: bool,
+}
+
+/// How a run entered CI mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CiMode {
+    /// `--ci` was passed.
+    Explicit,
+    /// A CI environment variable was set (see [`crate::env::detect_ci`]).
+    Inferred,
+}
+
+pub(crate) fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+impl CheckOutput {
+    /// Create output from check results.
+    pub fn new(timestamp: String, checks: Vec<CheckResult>) -> Self {
+        // Overall passed = all non-skipped checks passed
+        let passed = checks.iter().all(|c| c.passed || c.skipped);
+        Self {
+            timestamp,
+            passed,
+            tags: BTreeMap::new(),
+            undecodable_files: 0,
+            checks,
+            diagnostics: Vec::new(),
+            ci: None,
+            interrupted: false,
+        }
+    }
+
+    /// Attach run tags.
+    pub fn with_tags(mut self, tags: BTreeMap<String, String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Record how many files were decoded lossily.
+    pub fn with_undecodable_files(mut self, count: usize) -> Self {
+        self.undecodable_files = count;
+        self
+    }
+
+    /// Record how CI mode was turned on.
+    pub fn with_ci(mut self, ci: Option<CiMode>) -> Self {
+        self.ci = ci;
+        self
+    }
+
+    /// Record that Ctrl-C cut the run short.
+    pub fn with_interrupted(mut self, interrupted: bool) -> Self {
+        self.interrupted = interrupted;
+        self
+    }
+
+    /// Count total violations across all checks.
+    pub fn total_violations(&self) -> usize {
+        self.checks.iter().map(|c| c.violations.len()).sum()
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
@@ -16,8 +16,34 @@ fn violation_file_constructor() {
 fn violation_with_threshold() {
     let v = Violation::file("src/main.rs", 42, "file_too_large", "Split into modules.")
         .with_threshold(800, 750);
-    assert_eq!(v.value, Some(800));
-    assert_eq!(v.threshold, Some(750));
+    assert_eq!(v.int(Field::Value), Some(800));
+    assert_eq!(v.int(Field::Threshold), Some(750));
+}
+
+#[test]
+fn violation_details_serialize_as_top_level_keys_in_field_order() {
+    let v = Violation::file("src/lib.rs", 3, "forbidden", "Remove it.")
+        .with_pattern("unwrap")
+        .with_threshold(2, 1);
+    assert_eq!(
+        serde_json::to_string(&v).unwrap(),
+        r#"{"file":"src/lib.rs","line":3,"type":"forbidden","advice":"Remove it.","value":2,"threshold":1,"pattern":"unwrap"}"#
+    );
+}
+
+#[test]
+fn violation_deserializes_details_and_ignores_unknown_keys() {
+    let json = r#"{"type":"out_of_sync","advice":"Sync.","other_file":"AGENTS.md","section":"Setup","future":true}"#;
+    let v: Violation = serde_json::from_str(json).unwrap();
+    assert_eq!(v.path(Field::OtherFile), Some(Path::new("AGENTS.md")));
+    assert_eq!(v.text(Field::Section), Some("Setup"));
+    assert_eq!(v.details.iter().count(), 2);
+}
+
+#[test]
+fn violation_rejects_mistyped_detail() {
+    let json = r#"{"type":"file_too_large","advice":"Split.","value":"big"}"#;
+    assert!(serde_json::from_str::<Violation>(json).is_err());
 }
 
 #[test]
@@ -149,3 +175,87 @@ fn violation_without_scope_omits_field() {
 
     assert!(json.get("scope").is_none());
 }
+
+#[test]
+fn sort_violations_orders_by_file_line_type() {
+    let mut result = CheckResult::failed(
+        "escapes",
+        vec![
+            Violation::file("b.rs", 1, "forbidden", "x"),
+            Violation::file("a.rs", 9, "missing_comment", "x"),
+            Violation::file("a.rs", 9, "forbidden", "x"),
+            Violation::file("a.rs", 2, "forbidden", "x"),
+        ],
+    );
+    result.sort_violations();
+    let keys: Vec<_> = result
+        .violations
+        .iter()
+        .map(|v| {
+            (
+                v.file.clone().unwrap_or_default(),
+                v.line,
+                v.violation_type.clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            (PathBuf::from("a.rs"), Some(2), "forbidden".to_string()),
+            (PathBuf::from("a.rs"), Some(9), "forbidden".to_string()),
+            (
+                PathBuf::from("a.rs"),
+                Some(9),
+                "missing_comment".to_string()
+            ),
+            (PathBuf::from("b.rs"), Some(1), "forbidden".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn sort_violations_keeps_commit_order() {
+    let mut result = CheckResult::failed(
+        "git",
+        vec![
+            Violation::commit_violation("bbb", "second", "invalid_type", "x"),
+            Violation::commit_violation("aaa", "first", "invalid_format", "x"),
+        ],
+    );
+    result.sort_violations();
+    assert_eq!(result.violations[0].text(Field::Commit), Some("bbb"));
+    assert_eq!(result.violations[1].text(Field::Commit), Some("aaa"));
+}
+
+#[test]
+fn check_output_serializes_tags_only_when_present() {
+    let output = CheckOutput::new("2026-01-20T00:00:00Z".to_string(), vec![]);
+    let json = serde_json::to_value(&output).unwrap();
+    assert!(json.get("tags").is_none());
+
+    let tagged = output.with_tags(BTreeMap::from([("env".to_string(), "ci".to_string())]));
+    let json = serde_json::to_value(&tagged).unwrap();
+    assert_eq!(json["tags"]["env"], "ci");
+}
+
+#[test]
+fn check_output_roundtrips_through_json() {
+    let output = CheckOutput::new(
+        "2026-01-01T00:00:00Z".to_string(),
+        vec![
+            CheckResult::passed("cloc"),
+            CheckResult::failed(
+                "escapes",
+                vec![Violation::file("a.rs", 1, "forbidden", "x")],
+            ),
+        ],
+    );
+
+    let json = serde_json::to_string(&output).unwrap();
+    let restored: CheckOutput = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.checks.len(), 2);
+    assert!(!restored.checks[0].skipped);
+    assert_eq!(restored.checks[1].violations[0].line, Some(1));
+}
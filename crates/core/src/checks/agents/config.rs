@@ -3,8 +3,9 @@
 
 //! Configuration for the agents check.
 
-use serde::Deserialize;
+use schemars::JsonSchema;
 use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize};
 
 use crate::config::CheckLevel;
 
@@ -27,7 +28,8 @@ where
 }
 
 /// Content rule enforcement level.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
 pub enum ContentRule {
     /// Allow this content type.
     #[default]
@@ -41,6 +43,11 @@ impl ContentRule {
     pub fn allow() -> Self {
         ContentRule::Allow
     }
+
+    /// Returns ContentRule::Forbid (for serde defaults).
+    pub fn forbid() -> Self {
+        ContentRule::Forbid
+    }
 }
 
 impl<'de> Deserialize<'de> for ContentRule {
@@ -61,7 +68,7 @@ impl<'de> Deserialize<'de> for ContentRule {
 }
 
 /// Configuration for the agents check.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AgentsConfig {
     /// Check level: error, warn, or off.
@@ -108,6 +115,10 @@ pub struct AgentsConfig {
     #[serde(default = "ContentRule::allow")]
     pub mermaid: ContentRule,
 
+    /// Cursor rule (`.mdc`) frontmatter validation.
+    #[serde(default)]
+    pub frontmatter: FrontmatterConfig,
+
     /// Maximum lines per file (root scope, default: 500, None to disable).
     #[serde(
         default = "AgentsConfig::default_max_lines",
@@ -122,6 +133,11 @@ pub struct AgentsConfig {
     )]
     pub max_tokens: Option<usize>,
 
+    /// Maximum tokens across all root-scope agent files and cursor rules
+    /// combined (default: None).
+    #[serde(default, deserialize_with = "deserialize_optional_usize")]
+    pub max_total_tokens: Option<usize>,
+
     /// Root scope settings (overrides flat config).
     #[serde(default)]
     pub root: Option<AgentsScopeConfig>,
@@ -133,6 +149,10 @@ pub struct AgentsConfig {
     /// Module scope settings.
     #[serde(default)]
     pub module: Option<AgentsScopeConfig>,
+
+    /// Cursor rule settings (each file under `.cursor/rules/`).
+    #[serde(default)]
+    pub rules: Option<AgentsScopeConfig>,
 }
 
 impl Default for AgentsConfig {
@@ -149,11 +169,14 @@ impl Default for AgentsConfig {
             tables: ContentRule::default(),
             box_diagrams: ContentRule::allow(),
             mermaid: ContentRule::allow(),
+            frontmatter: FrontmatterConfig::default(),
             max_lines: Self::default_max_lines(),
             max_tokens: Self::default_max_tokens(),
+            max_total_tokens: None,
             root: None,
             package: None,
             module: None,
+            rules: None,
         }
     }
 }
@@ -164,7 +187,10 @@ impl AgentsConfig {
         vec![
             "CLAUDE.md".to_string(),
             "AGENTS.md".to_string(),
+            "GEMINI.md".to_string(),
             ".cursorrules".to_string(),
+            ".windsurfrules".to_string(),
+            ".github/copilot-instructions.md".to_string(),
             ".cursor/rules/*.md".to_string(),
             ".cursor/rules/*.mdc".to_string(),
         ]
@@ -192,7 +218,7 @@ impl AgentsConfig {
 }
 
 /// Per-scope configuration for agent files.
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AgentsScopeConfig {
     /// Files that must exist at this scope.
@@ -216,8 +242,36 @@ pub struct AgentsScopeConfig {
     pub max_tokens: Option<usize>,
 }
 
+/// Cursor rule frontmatter configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrontmatterConfig {
+    /// Keys every rule's frontmatter must set (e.g., `["description"]`).
+    #[serde(default)]
+    pub required: Vec<String>,
+
+    /// Globs that match no project files (default: forbid).
+    #[serde(default = "ContentRule::forbid")]
+    pub unmatched_globs: ContentRule,
+
+    /// Rules that apply to the same files but disagree on a section
+    /// (default: forbid).
+    #[serde(default = "ContentRule::forbid")]
+    pub conflicts: ContentRule,
+}
+
+impl Default for FrontmatterConfig {
+    fn default() -> Self {
+        Self {
+            required: Vec::new(),
+            unmatched_globs: ContentRule::forbid(),
+            conflicts: ContentRule::forbid(),
+        }
+    }
+}
+
 /// Section validation configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SectionsConfig {
     /// Required sections (simple form: names only, or extended form with advice).
@@ -255,11 +309,14 @@ impl SectionsConfig {
 }
 
 /// A required section with optional advice.
-#[derive(Debug, Clone)]
+///
+/// Accepts a bare name or `{ name = "...", advice = "..." }`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct RequiredSection {
     /// Section name (case-insensitive matching).
     pub name: String,
     /// Advice shown when section is missing.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub advice: Option<String>,
 }
 
@@ -243,11 +243,16 @@ pub fn check_line_count(content: &str, max_lines: usize) -> Option<SizeViolation
     }
 }
 
-/// Check if content exceeds the token limit.
+/// Estimate the token count of content.
 ///
 /// Uses `chars / 4` as a fast approximation.
+pub fn estimate_tokens(content: &str) -> usize {
+    content.chars().count() / 4
+}
+
+/// Check if content exceeds the token limit.
 pub fn check_token_count(content: &str, max_tokens: usize) -> Option<SizeViolation> {
-    let token_estimate = content.chars().count() / 4;
+    let token_estimate = estimate_tokens(content);
     if token_estimate > max_tokens {
         Some(SizeViolation {
             limit_type: SizeLimitType::Tokens,
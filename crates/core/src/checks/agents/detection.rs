@@ -25,8 +25,13 @@ pub enum Scope {
     Package(String),
     /// Nested deeper than root (non-package).
     Module,
+    /// Cursor rule under `.cursor/rules/`.
+    Rules,
 }
 
+/// Directory holding Cursor rule files, relative to the project root.
+pub const RULES_DIR: &str = ".cursor/rules";
+
 /// Detect agent files under a directory.
 ///
 /// Looks for files matching the given patterns at:
@@ -43,7 +48,11 @@ pub fn detect_agent_files(
     for pattern in patterns {
         let matches = match_pattern(pattern, root);
         for path in matches {
-            let scope = classify_scope(&path, root, packages);
+            let scope = match classify_scope(&path, root, packages) {
+                // Named paths like .github/copilot-instructions.md are project-wide
+                Scope::Module if !pattern.contains('*') => Scope::Root,
+                scope => scope,
+            };
             detected.push(DetectedFile { path, scope });
         }
     }
@@ -196,6 +205,11 @@ fn match_glob_pattern(pattern: &str, root: &Path) -> Vec<PathBuf> {
 pub fn classify_scope(file_path: &Path, root: &Path, packages: &[String]) -> Scope {
     let relative = file_path.strip_prefix(root).unwrap_or(file_path);
 
+    // Cursor rules form their own scope, wherever packages live
+    if relative.starts_with(RULES_DIR) {
+        return Scope::Rules;
+    }
+
     // Check if under a package directory
     for pkg in packages {
         if is_in_package(relative, pkg) {
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
 use std::path::Path;
 
 use super::*;
@@ -23,6 +25,15 @@ fn classify_scope_module_for_nested_file() {
     assert_eq!(classify_scope(file, root, &packages), Scope::Module);
 }
 
+#[test]
+fn classify_scope_rules_for_cursor_rule() {
+    let root = Path::new("/project");
+    let file = Path::new("/project/.cursor/rules/api.mdc");
+    let packages = vec![".cursor".to_string()];
+
+    assert_eq!(classify_scope(file, root, &packages), Scope::Rules);
+}
+
 #[test]
 fn classify_scope_package_with_exact_pattern() {
     let root = Path::new("/project");
@@ -79,3 +90,23 @@ fn extract_package_name_exact() {
         "packages/api"
     );
 }
+
+#[test]
+fn detect_agent_files_named_path_is_root_scope() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(temp.path().join(".github")).unwrap();
+    std::fs::write(
+        temp.path().join(".github/copilot-instructions.md"),
+        "# Project",
+    )
+    .unwrap();
+
+    let detected = detect_agent_files(
+        temp.path(),
+        &[],
+        &[".github/copilot-instructions.md".to_string()],
+    );
+
+    assert_eq!(detected.len(), 1);
+    assert_eq!(detected[0].scope, Scope::Root);
+}
@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Cursor rule frontmatter validation.
+//!
+//! Checks the metadata of `.cursor/rules/*.mdc` files: required keys,
+//! well-formed values, globs that compile and match project files, and
+//! rules that apply to the same files but disagree on a section.
+
+use std::path::Path;
+
+use globset::{GlobBuilder, GlobMatcher};
+
+use crate::check::Violation;
+
+use super::config::{ContentRule, FrontmatterConfig};
+use super::mdc::{MdcRule, discover_mdc_files, parse_mdc, strip_leading_header};
+use super::sync::{DiffType, compare_files};
+
+/// A top-level key in a rule's frontmatter.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FrontmatterKey {
+    /// Key name (e.g., "globs").
+    pub name: String,
+    /// Raw value text after the colon, trimmed.
+    pub value: String,
+    /// Line number in the file (1-indexed).
+    pub line: u32,
+}
+
+/// Collect the top-level keys of a file's frontmatter.
+///
+/// Returns no keys when the file has no frontmatter. Indented lines
+/// (nested values, list items) are skipped.
+pub fn frontmatter_keys(content: &str) -> Vec<FrontmatterKey> {
+    let mut lines = content.lines();
+    if lines.next().map(str::trim) != Some("---") {
+        return Vec::new();
+    }
+
+    let mut keys = Vec::new();
+    for (idx, line) in lines.enumerate() {
+        if line.trim() == "---" {
+            break;
+        }
+        if line.starts_with([' ', '\t', '-', '#']) {
+            continue;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            keys.push(FrontmatterKey {
+                name: name.trim().to_string(),
+                value: value.trim().to_string(),
+                line: idx as u32 + 2,
+            });
+        }
+    }
+    keys
+}
+
+/// A parsed rule with its compiled globs.
+struct RuleFile {
+    /// Path relative to the project root.
+    file: String,
+    rule: MdcRule,
+    matchers: Vec<GlobMatcher>,
+}
+
+impl RuleFile {
+    /// Whether the rule applies when editing `path`.
+    fn applies_to(&self, path: &str) -> bool {
+        self.rule.always_apply || self.matchers.iter().any(|m| m.is_match(path))
+    }
+}
+
+/// Validate the frontmatter of every `.mdc` rule under `root`.
+///
+/// `files` are the project's files relative to the root, in walk order.
+/// Malformed frontmatter is left to reconciliation (`cursor_parse_error`).
+pub fn check_frontmatter(
+    root: &Path,
+    files: &[String],
+    config: &FrontmatterConfig,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let mut rules = Vec::new();
+
+    for path in discover_mdc_files(root) {
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let Ok(rule) = parse_mdc(&content, path.clone()) else {
+            continue;
+        };
+        let file = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .to_string();
+        let keys = frontmatter_keys(&content);
+
+        check_metadata(&file, &keys, config, &mut violations);
+        let matchers = check_globs(&file, &rule, &keys, files, config, &mut violations);
+        rules.push(RuleFile {
+            file,
+            rule,
+            matchers,
+        });
+    }
+
+    if config.conflicts == ContentRule::Forbid {
+        check_conflicts(&rules, files, &mut violations);
+    }
+    violations
+}
+
+/// Report missing required keys and malformed values.
+fn check_metadata(
+    file: &str,
+    keys: &[FrontmatterKey],
+    config: &FrontmatterConfig,
+    violations: &mut Vec<Violation>,
+) {
+    for required in &config.required {
+        if !keys.iter().any(|k| &k.name == required) {
+            violations.push(Violation::file_only(
+                file,
+                "cursor_missing_metadata",
+                format!(
+                    "Frontmatter is missing required key `{}`. Add it between the --- delimiters.",
+                    required
+                ),
+            ));
+        }
+    }
+
+    for key in keys.iter().filter(|k| k.name == "alwaysApply") {
+        if key.value != "true" && key.value != "false" {
+            violations.push(Violation::file(
+                file,
+                key.line,
+                "cursor_invalid_metadata",
+                format!("alwaysApply must be true or false, not `{}`.", key.value),
+            ));
+        }
+    }
+}
+
+/// Compile the rule's globs, reporting invalid and unmatched ones.
+fn check_globs(
+    file: &str,
+    rule: &MdcRule,
+    keys: &[FrontmatterKey],
+    files: &[String],
+    config: &FrontmatterConfig,
+    violations: &mut Vec<Violation>,
+) -> Vec<GlobMatcher> {
+    let line = keys
+        .iter()
+        .find(|k| k.name == "globs")
+        .map_or(1, |k| k.line);
+
+    let mut matchers = Vec::new();
+    for pattern in rule.globs.iter().flatten() {
+        let glob = match GlobBuilder::new(pattern).literal_separator(true).build() {
+            Ok(glob) => glob,
+            Err(err) => {
+                violations.push(Violation::file(
+                    file,
+                    line,
+                    "cursor_invalid_glob",
+                    format!("Invalid glob `{}`: {}.", pattern, err.kind()),
+                ));
+                continue;
+            }
+        };
+        let matcher = glob.compile_matcher();
+        if config.unmatched_globs == ContentRule::Forbid
+            && !files.iter().any(|f| matcher.is_match(f))
+        {
+            violations.push(
+                Violation::file(
+                    file,
+                    line,
+                    "cursor_unmatched_glob",
+                    format!(
+                        "Glob `{}` matches no files in the project. Update or remove it.",
+                        pattern
+                    ),
+                )
+                .with_pattern(pattern),
+            );
+        }
+        matchers.push(matcher);
+    }
+    matchers
+}
+
+/// Report sections that two rules applying to the same files define
+/// differently. Each pair is reported on the later rule.
+fn check_conflicts(rules: &[RuleFile], files: &[String], violations: &mut Vec<Violation>) {
+    for (i, later) in rules.iter().enumerate() {
+        for earlier in &rules[..i] {
+            let Some(shared) = shared_target(earlier, later, files) else {
+                continue;
+            };
+            let comparison = compare_files(
+                strip_leading_header(&earlier.rule.body),
+                strip_leading_header(&later.rule.body),
+            );
+            for diff in comparison.differences {
+                if diff.diff_type != DiffType::ContentDiffers || diff.section.is_empty() {
+                    continue;
+                }
+                let heading = diff.target_heading.unwrap_or(diff.section);
+                violations.push(
+                    Violation::file_only(
+                        &later.file,
+                        "cursor_rule_conflict",
+                        format!(
+                            "Section differs from {}, and both rules apply to {}. Make the sections match or merge the rules.",
+                            earlier.file, shared
+                        ),
+                    )
+                    .with_sync(&earlier.file, heading),
+                );
+            }
+        }
+    }
+}
+
+/// Describe what two rules both apply to, if anything.
+fn shared_target(a: &RuleFile, b: &RuleFile, files: &[String]) -> Option<String> {
+    if a.rule.always_apply && b.rule.always_apply {
+        return Some("every file".to_string());
+    }
+    files
+        .iter()
+        .find(|f| a.applies_to(f) && b.applies_to(f))
+        .cloned()
+}
+
+#[cfg(test)]
+#[path = "frontmatter_tests.rs"]
+mod tests;
@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use crate::check::Field;
+
+// =============================================================================
+// HELPER
+// =============================================================================
+
+fn temp_dir() -> tempfile::TempDir {
+    tempfile::tempdir().unwrap()
+}
+
+fn write_file(root: &std::path::Path, rel: &str, content: &str) {
+    let path = root.join(rel);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).unwrap();
+    }
+    std::fs::write(path, content).unwrap();
+}
+
+fn files(paths: &[&str]) -> Vec<String> {
+    paths.iter().map(|p| p.to_string()).collect()
+}
+
+fn types(violations: &[Violation]) -> Vec<&str> {
+    violations
+        .iter()
+        .map(|v| v.violation_type.as_str())
+        .collect()
+}
+
+// =============================================================================
+// KEY PARSING
+// =============================================================================
+
+#[test]
+fn frontmatter_keys_records_names_values_and_lines() {
+    let content = "---\ndescription: \"API\"\nglobs: src/api/**\nalwaysApply: false\n---\n\nBody\n";
+    let keys = frontmatter_keys(content);
+
+    let names: Vec<_> = keys.iter().map(|k| (k.name.as_str(), k.line)).collect();
+    assert_eq!(
+        names,
+        vec![("description", 2), ("globs", 3), ("alwaysApply", 4)]
+    );
+    assert_eq!(keys[1].value, "src/api/**");
+}
+
+#[test]
+fn frontmatter_keys_skips_nested_lines_and_body() {
+    let content = "---\ntags:\n  - api\n# comment\n---\nnotes: in the body\n";
+    let keys = frontmatter_keys(content);
+
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].name, "tags");
+}
+
+#[test]
+fn frontmatter_keys_empty_without_frontmatter() {
+    assert!(frontmatter_keys("# Rule\n\nkey: value\n").is_empty());
+}
+
+// =============================================================================
+// METADATA
+// =============================================================================
+
+#[test]
+fn missing_required_key_generates_violation() {
+    let dir = temp_dir();
+    write_file(
+        dir.path(),
+        ".cursor/rules/api.mdc",
+        "---\nglobs: \"src/**\"\n---\n\nBody\n",
+    );
+    let config = FrontmatterConfig {
+        required: vec!["description".to_string(), "globs".to_string()],
+        ..FrontmatterConfig::default()
+    };
+
+    let violations = check_frontmatter(dir.path(), &files(&["src/lib.rs"]), &config);
+
+    assert_eq!(types(&violations), vec!["cursor_missing_metadata"]);
+    assert!(violations[0].advice.contains("`description`"));
+}
+
+#[test]
+fn non_boolean_always_apply_generates_violation() {
+    let dir = temp_dir();
+    write_file(
+        dir.path(),
+        ".cursor/rules/general.mdc",
+        "---\ndescription: General\nalwaysApply: yes\n---\n\nBody\n",
+    );
+
+    let violations = check_frontmatter(dir.path(), &[], &FrontmatterConfig::default());
+
+    assert_eq!(types(&violations), vec!["cursor_invalid_metadata"]);
+    assert_eq!(violations[0].line, Some(3));
+}
+
+#[test]
+fn malformed_frontmatter_left_to_reconciliation() {
+    let dir = temp_dir();
+    write_file(
+        dir.path(),
+        ".cursor/rules/bad.mdc",
+        "---\nalwaysApply: maybe\nno closing delimiter\n",
+    );
+
+    let violations = check_frontmatter(dir.path(), &[], &FrontmatterConfig::default());
+
+    assert!(violations.is_empty());
+}
+
+// =============================================================================
+// GLOBS
+// =============================================================================
+
+#[test]
+fn invalid_glob_generates_violation() {
+    let dir = temp_dir();
+    write_file(
+        dir.path(),
+        ".cursor/rules/api.mdc",
+        "---\nglobs: \"src/[api\"\n---\n\nBody\n",
+    );
+
+    let violations = check_frontmatter(
+        dir.path(),
+        &files(&["src/api/mod.rs"]),
+        &FrontmatterConfig::default(),
+    );
+
+    assert_eq!(types(&violations), vec!["cursor_invalid_glob"]);
+    assert_eq!(violations[0].line, Some(2));
+}
+
+#[test]
+fn unmatched_glob_generates_violation() {
+    let dir = temp_dir();
+    write_file(
+        dir.path(),
+        ".cursor/rules/web.mdc",
+        "---\nglobs: [\"src/**/*.rs\", \"web/**/*.tsx\"]\n---\n\nBody\n",
+    );
+
+    let violations = check_frontmatter(
+        dir.path(),
+        &files(&["src/api/mod.rs"]),
+        &FrontmatterConfig::default(),
+    );
+
+    assert_eq!(types(&violations), vec!["cursor_unmatched_glob"]);
+    assert_eq!(violations[0].text(Field::Pattern), Some("web/**/*.tsx"));
+}
+
+#[test]
+fn unmatched_glob_allowed_by_config() {
+    let dir = temp_dir();
+    write_file(
+        dir.path(),
+        ".cursor/rules/web.mdc",
+        "---\nglobs: \"web/**\"\n---\n\nBody\n",
+    );
+    let config = FrontmatterConfig {
+        unmatched_globs: ContentRule::Allow,
+        ..FrontmatterConfig::default()
+    };
+
+    assert!(check_frontmatter(dir.path(), &[], &config).is_empty());
+}
+
+#[test]
+fn single_star_does_not_cross_directories() {
+    let dir = temp_dir();
+    write_file(
+        dir.path(),
+        ".cursor/rules/top.mdc",
+        "---\nglobs: \"src/*.rs\"\n---\n\nBody\n",
+    );
+
+    let violations = check_frontmatter(
+        dir.path(),
+        &files(&["src/api/mod.rs"]),
+        &FrontmatterConfig::default(),
+    );
+
+    assert_eq!(types(&violations), vec!["cursor_unmatched_glob"]);
+}
+
+// =============================================================================
+// CONFLICTS
+// =============================================================================
+
+#[test]
+fn overlapping_rules_with_differing_section_conflict() {
+    let dir = temp_dir();
+    write_file(
+        dir.path(),
+        ".cursor/rules/api.mdc",
+        "---\nglobs: \"src/api/**\"\n---\n\n## Errors\n\nReturn Result.\n",
+    );
+    write_file(
+        dir.path(),
+        ".cursor/rules/rust.mdc",
+        "---\nglobs: \"**/*.rs\"\n---\n\n## Errors\n\nPanic on failure.\n",
+    );
+
+    let violations = check_frontmatter(
+        dir.path(),
+        &files(&["src/api/mod.rs"]),
+        &FrontmatterConfig::default(),
+    );
+
+    assert_eq!(types(&violations), vec!["cursor_rule_conflict"]);
+    let v = &violations[0];
+    assert_eq!(v.file.as_deref(), Some(Path::new(".cursor/rules/rust.mdc")));
+    assert_eq!(
+        v.path(Field::OtherFile),
+        Some(Path::new(".cursor/rules/api.mdc"))
+    );
+    assert_eq!(v.text(Field::Section), Some("Errors"));
+    assert!(v.advice.contains("src/api/mod.rs"));
+}
+
+#[test]
+fn disjoint_rules_do_not_conflict() {
+    let dir = temp_dir();
+    write_file(
+        dir.path(),
+        ".cursor/rules/api.mdc",
+        "---\nglobs: \"src/api/**\"\n---\n\n## Errors\n\nReturn Result.\n",
+    );
+    write_file(
+        dir.path(),
+        ".cursor/rules/cli.mdc",
+        "---\nglobs: \"src/cli/**\"\n---\n\n## Errors\n\nPrint and exit.\n",
+    );
+
+    let violations = check_frontmatter(
+        dir.path(),
+        &files(&["src/api/mod.rs", "src/cli/main.rs"]),
+        &FrontmatterConfig::default(),
+    );
+
+    assert!(violations.is_empty());
+}
+
+#[test]
+fn always_apply_rule_overlaps_scoped_rule() {
+    let dir = temp_dir();
+    write_file(
+        dir.path(),
+        ".cursor/rules/api.mdc",
+        "---\nglobs: \"src/api/**\"\n---\n\n## Style\n\nTabs.\n",
+    );
+    write_file(
+        dir.path(),
+        ".cursor/rules/general.mdc",
+        "---\nalwaysApply: true\n---\n\n## Style\n\nSpaces.\n",
+    );
+    let config = FrontmatterConfig {
+        conflicts: ContentRule::Forbid,
+        ..FrontmatterConfig::default()
+    };
+
+    let violations = check_frontmatter(dir.path(), &files(&["src/api/mod.rs"]), &config);
+    assert_eq!(types(&violations), vec!["cursor_rule_conflict"]);
+
+    let config = FrontmatterConfig {
+        conflicts: ContentRule::Allow,
+        ..config
+    };
+    assert!(check_frontmatter(dir.path(), &files(&["src/api/mod.rs"]), &config).is_empty());
+}
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Content rules and size limits, per agent file and across the files
+//! agents load for every task.
+
+use std::collections::BTreeMap;
+
+use crate::check::{CheckContext, Violation};
+
+use super::config::{AgentsConfig, ContentRule};
+use super::content::{
+    check_line_count, check_token_count, detect_box_diagrams, detect_mermaid_blocks, detect_tables,
+    estimate_tokens,
+};
+use super::detection::{DetectedFile, Scope};
+use super::{location_prefix, relative_path};
+
+/// Check content rules in agent files.
+pub(super) fn check_content(
+    ctx: &CheckContext,
+    config: &AgentsConfig,
+    detected: &[DetectedFile],
+    violations: &mut Vec<Violation>,
+) {
+    for file in detected {
+        let Ok(content) = std::fs::read_to_string(&file.path) else {
+            continue;
+        };
+
+        let rel_path = relative_path(ctx.root, file);
+        let location = location_prefix(file);
+
+        // Get effective limits for this scope
+        let (max_lines, max_tokens) = get_scope_limits(config, &file.scope);
+
+        // Determine which alternatives are allowed for advice messages
+        let mermaid_allowed = config.mermaid == ContentRule::Allow;
+        let box_allowed = config.box_diagrams == ContentRule::Allow;
+
+        // Check content rules
+        if config.tables == ContentRule::Forbid {
+            for issue in detect_tables(&content) {
+                let advice = issue
+                    .content_type
+                    .advice_with_alternatives(mermaid_allowed, box_allowed);
+                violations.push(Violation::file(
+                    &rel_path,
+                    issue.line,
+                    issue.content_type.violation_type(),
+                    format!("{}, {}", location, advice),
+                ));
+            }
+        }
+
+        if config.box_diagrams == ContentRule::Forbid {
+            for issue in detect_box_diagrams(&content) {
+                let advice = issue
+                    .content_type
+                    .advice_with_alternatives(mermaid_allowed, box_allowed);
+                violations.push(Violation::file(
+                    &rel_path,
+                    issue.line,
+                    issue.content_type.violation_type(),
+                    format!("{}, {}", location, advice),
+                ));
+            }
+        }
+
+        if config.mermaid == ContentRule::Forbid {
+            for issue in detect_mermaid_blocks(&content) {
+                let advice = issue
+                    .content_type
+                    .advice_with_alternatives(mermaid_allowed, box_allowed);
+                violations.push(Violation::file(
+                    &rel_path,
+                    issue.line,
+                    issue.content_type.violation_type(),
+                    format!("{}, {}", location, advice),
+                ));
+            }
+        }
+
+        // Check size limits
+        if let Some(limit) = max_lines
+            && let Some(violation) = check_line_count(&content, limit)
+        {
+            violations.push(
+                Violation::file_only(
+                    &rel_path,
+                    "file_too_large",
+                    format!(
+                        "{}, {}",
+                        location,
+                        violation
+                            .limit_type
+                            .advice_lowercase(violation.value, violation.threshold)
+                    ),
+                )
+                .with_threshold(violation.value as i64, violation.threshold as i64),
+            );
+        }
+
+        if let Some(limit) = max_tokens
+            && let Some(violation) = check_token_count(&content, limit)
+        {
+            violations.push(
+                Violation::file_only(
+                    &rel_path,
+                    "file_too_large",
+                    format!(
+                        "{}, {}",
+                        location,
+                        violation
+                            .limit_type
+                            .advice_lowercase(violation.value, violation.threshold)
+                    ),
+                )
+                .with_threshold(violation.value as i64, violation.threshold as i64),
+            );
+        }
+    }
+}
+
+/// Check the combined size of the files agents load for every task.
+///
+/// Returns the estimated tokens of each root-scope file and cursor rule,
+/// keyed by path relative to the root.
+pub(super) fn check_context_budget(
+    ctx: &CheckContext,
+    config: &AgentsConfig,
+    detected: &[DetectedFile],
+    violations: &mut Vec<Violation>,
+) -> BTreeMap<String, usize> {
+    let tokens: BTreeMap<String, usize> = detected
+        .iter()
+        .filter(|f| matches!(f.scope, Scope::Root | Scope::Rules))
+        .filter_map(|f| {
+            let content = std::fs::read_to_string(&f.path).ok()?;
+            Some((relative_path(ctx.root, f), estimate_tokens(&content)))
+        })
+        .collect();
+
+    let total: usize = tokens.values().sum();
+    if let Some(limit) = config.max_total_tokens
+        && total > limit
+    {
+        let mut largest: Vec<_> = tokens.iter().collect();
+        largest.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        let breakdown: Vec<String> = largest
+            .iter()
+            .take(3)
+            .map(|(file, n)| format!("{} (~{})", file, n))
+            .collect();
+        violations.push(
+            Violation::file_only(
+                "(project root)",
+                "context_too_large",
+                format!(
+                    "Agent files total ~{} tokens (max: {}). Largest: {}. Trim or deduplicate content.",
+                    total,
+                    limit,
+                    breakdown.join(", ")
+                ),
+            )
+            .with_threshold(total as i64, limit as i64),
+        );
+    }
+    tokens
+}
+
+/// Get effective size limits for a scope, with inheritance.
+fn get_scope_limits(config: &AgentsConfig, scope: &Scope) -> (Option<usize>, Option<usize>) {
+    let scope_config = match scope {
+        Scope::Root => config.root.as_ref(),
+        Scope::Package(_) => config.package.as_ref(),
+        Scope::Module => config.module.as_ref(),
+        Scope::Rules => config.rules.as_ref(),
+    };
+
+    // Scope config overrides top-level, top-level provides defaults
+    let max_lines = scope_config.and_then(|s| s.max_lines).or(config.max_lines);
+
+    let max_tokens = scope_config
+        .and_then(|s| s.max_tokens)
+        .or(config.max_tokens);
+
+    (max_lines, max_tokens)
+}
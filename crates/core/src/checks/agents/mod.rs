@@ -12,25 +12,36 @@
 pub mod config;
 pub mod content;
 mod detection;
+pub mod frontmatter;
+mod limits;
 pub mod mdc;
 mod reconcile;
 pub mod sections;
 mod sync;
 
+use std::path::{Path, PathBuf};
+
 use serde_json::json;
 
 use crate::check::{Check, CheckContext, CheckResult, Violation};
 use crate::config::CheckLevel;
+use crate::metrics::{self, CheckMetrics};
 
 pub use config::AgentsConfig;
-use config::ContentRule;
-use content::{
-    check_line_count, check_token_count, detect_box_diagrams, detect_mermaid_blocks, detect_tables,
-};
 use detection::{DetectedFile, Scope, detect_agent_files, file_exists_at_root};
+use limits::{check_content, check_context_budget};
 use sections::validate_sections;
 use sync::{DiffType, compare_files};
 
+/// Agent files at the project root: the ones synced with each other.
+pub fn root_agent_files(root: &Path, patterns: &[String]) -> Vec<PathBuf> {
+    detect_agent_files(root, &[], patterns)
+        .into_iter()
+        .filter(|f| f.scope == Scope::Root)
+        .map(|f| f.path)
+        .collect()
+}
+
 /// The agents check validates AI agent context files.
 pub struct AgentsCheck;
 
@@ -79,10 +90,17 @@ impl Check for AgentsCheck {
         // Check content rules (tables, diagrams, size limits)
         check_content(ctx, config, &detected, &mut violations);
 
+        // Check the combined token budget
+        let tokens = check_context_budget(ctx, config, &detected, &mut violations);
+        let total_tokens: usize = tokens.values().sum();
+
         // Check cursor rule reconciliation
         // Always call - it checks sync internally and returns early if disabled
         check_cursor_reconciliation(ctx, config, &mut violations, &mut fixes);
 
+        // Check cursor rule frontmatter
+        check_cursor_frontmatter(ctx, config, &mut violations);
+
         // Build metrics
         let files_found: Vec<String> = detected
             .iter()
@@ -98,11 +116,13 @@ impl Check for AgentsCheck {
         // Update in_sync metric based on whether we fixed things
         let final_in_sync = in_sync || !fixes.is_empty();
 
-        let metrics = json!({
-            "files_found": files_found,
-            "files_missing": files_missing,
-            "in_sync": final_in_sync,
-        });
+        let metrics = metrics::Agents {
+            files_found,
+            files_missing,
+            in_sync: final_in_sync,
+            total_tokens,
+            tokens,
+        };
 
         // Determine result based on violations and fixes
         let result = if violations.is_empty() {
@@ -116,7 +136,7 @@ impl Check for AgentsCheck {
             CheckResult::failed(self.name(), violations)
         };
 
-        result.with_metrics(metrics)
+        result.with_metrics(metrics.to_json())
     }
 
     fn default_enabled(&self) -> bool {
@@ -139,8 +159,11 @@ fn check_required_files(
         &config.required
     };
 
-    // Count root-scope detected files
-    let root_files: Vec<_> = detected.iter().filter(|f| f.scope == Scope::Root).collect();
+    // Count root-scope detected files; cursor rules count as project-wide
+    let root_files: Vec<_> = detected
+        .iter()
+        .filter(|f| matches!(f.scope, Scope::Root | Scope::Rules))
+        .collect();
 
     for filename in required {
         if filename == "*" {
@@ -316,12 +339,9 @@ fn check_sync(
     };
 
     // Find source file in detected
-    let source_file = root_files.iter().find(|f| {
-        f.path
-            .file_name()
-            .map(|n| n.to_string_lossy() == source_name)
-            .unwrap_or(false)
-    });
+    let source_file = root_files
+        .iter()
+        .find(|f| relative_path(ctx.root, f) == source_name);
 
     let Some(source_file) = source_file else {
         return true; // Source not present, nothing to sync
@@ -347,11 +367,7 @@ fn check_sync(
         let comparison = compare_files(&source_content, &target_content);
 
         if !comparison.in_sync {
-            let target_name = target_file
-                .path
-                .file_name()
-                .map(|n| n.to_string_lossy().to_string())
-                .unwrap_or_else(|| target_file.path.display().to_string());
+            let target_name = relative_path(ctx.root, target_file);
 
             // If fix mode is enabled, sync the target file from source
             if ctx.fix {
@@ -367,7 +383,7 @@ fn check_sync(
                         section_count,
                     );
                     continue;
-                } else if std::fs::write(&target_file.path, &source_content).is_ok() {
+                } else if ctx.write_fix(&target_file.path, &source_content).is_ok() {
                     // Actual fix: write and track
                     fixes.add_sync(target_name, source_name.to_string(), section_count);
                     continue;
@@ -431,7 +447,7 @@ fn check_sync(
 }
 
 /// Get the relative path of a detected file from the project root.
-fn relative_path(root: &std::path::Path, file: &DetectedFile) -> String {
+pub(super) fn relative_path(root: &std::path::Path, file: &DetectedFile) -> String {
     file.path
         .strip_prefix(root)
         .unwrap_or(&file.path)
@@ -445,7 +461,8 @@ fn relative_path(root: &std::path::Path, file: &DetectedFile) -> String {
 /// - "In the root CLAUDE.md" for files at project root
 /// - "In a package-level file (e.g. crates/**/CLAUDE.md)" for package files
 /// - "In a folder-level file (e.g. src/**/CLAUDE.md)" for nested module files
-fn location_prefix(file: &DetectedFile) -> String {
+/// - "In the cursor rule api.mdc" for files under `.cursor/rules/`
+pub(super) fn location_prefix(file: &DetectedFile) -> String {
     let filename = file
         .path
         .file_name()
@@ -462,6 +479,7 @@ fn location_prefix(file: &DetectedFile) -> String {
         Scope::Module => {
             format!("In a folder-level file (e.g. src/**/{})", filename)
         }
+        Scope::Rules => format!("In the cursor rule {}", filename),
     }
 }
 
@@ -477,10 +495,13 @@ fn check_sections(
         return;
     }
 
-    // Only check files at root scope for now
-    let root_files: Vec<_> = detected.iter().filter(|f| f.scope == Scope::Root).collect();
+    // Only check files at root scope and cursor rules for now
+    let files: Vec<_> = detected
+        .iter()
+        .filter(|f| matches!(f.scope, Scope::Root | Scope::Rules))
+        .collect();
 
-    for file in root_files {
+    for file in files {
         let Ok(content) = std::fs::read_to_string(&file.path) else {
             continue;
         };
@@ -489,8 +510,15 @@ fn check_sections(
         let rel_path = relative_path(ctx.root, file);
         let location = location_prefix(file);
 
+        // Each rule covers part of the project, so only forbidden sections apply
+        let missing = if file.scope == Scope::Rules {
+            Vec::new()
+        } else {
+            validation.missing
+        };
+
         // Generate violations for missing required sections
-        for missing in validation.missing {
+        for missing in missing {
             let advice = if let Some(ref section_advice) = missing.advice {
                 format!(
                     "{}, add a \"## {}\" section: {}",
@@ -520,112 +548,6 @@ fn check_sections(
     }
 }
 
-/// Check content rules in agent files.
-fn check_content(
-    ctx: &CheckContext,
-    config: &AgentsConfig,
-    detected: &[DetectedFile],
-    violations: &mut Vec<Violation>,
-) {
-    for file in detected {
-        let Ok(content) = std::fs::read_to_string(&file.path) else {
-            continue;
-        };
-
-        let rel_path = relative_path(ctx.root, file);
-        let location = location_prefix(file);
-
-        // Get effective limits for this scope
-        let (max_lines, max_tokens) = get_scope_limits(config, &file.scope);
-
-        // Determine which alternatives are allowed for advice messages
-        let mermaid_allowed = config.mermaid == ContentRule::Allow;
-        let box_allowed = config.box_diagrams == ContentRule::Allow;
-
-        // Check content rules
-        if config.tables == ContentRule::Forbid {
-            for issue in detect_tables(&content) {
-                let advice = issue
-                    .content_type
-                    .advice_with_alternatives(mermaid_allowed, box_allowed);
-                violations.push(Violation::file(
-                    &rel_path,
-                    issue.line,
-                    issue.content_type.violation_type(),
-                    format!("{}, {}", location, advice),
-                ));
-            }
-        }
-
-        if config.box_diagrams == ContentRule::Forbid {
-            for issue in detect_box_diagrams(&content) {
-                let advice = issue
-                    .content_type
-                    .advice_with_alternatives(mermaid_allowed, box_allowed);
-                violations.push(Violation::file(
-                    &rel_path,
-                    issue.line,
-                    issue.content_type.violation_type(),
-                    format!("{}, {}", location, advice),
-                ));
-            }
-        }
-
-        if config.mermaid == ContentRule::Forbid {
-            for issue in detect_mermaid_blocks(&content) {
-                let advice = issue
-                    .content_type
-                    .advice_with_alternatives(mermaid_allowed, box_allowed);
-                violations.push(Violation::file(
-                    &rel_path,
-                    issue.line,
-                    issue.content_type.violation_type(),
-                    format!("{}, {}", location, advice),
-                ));
-            }
-        }
-
-        // Check size limits
-        if let Some(limit) = max_lines
-            && let Some(violation) = check_line_count(&content, limit)
-        {
-            violations.push(
-                Violation::file_only(
-                    &rel_path,
-                    "file_too_large",
-                    format!(
-                        "{}, {}",
-                        location,
-                        violation
-                            .limit_type
-                            .advice_lowercase(violation.value, violation.threshold)
-                    ),
-                )
-                .with_threshold(violation.value as i64, violation.threshold as i64),
-            );
-        }
-
-        if let Some(limit) = max_tokens
-            && let Some(violation) = check_token_count(&content, limit)
-        {
-            violations.push(
-                Violation::file_only(
-                    &rel_path,
-                    "file_too_large",
-                    format!(
-                        "{}, {}",
-                        location,
-                        violation
-                            .limit_type
-                            .advice_lowercase(violation.value, violation.threshold)
-                    ),
-                )
-                .with_threshold(violation.value as i64, violation.threshold as i64),
-            );
-        }
-    }
-}
-
 /// Check cursor rule reconciliation.
 fn check_cursor_reconciliation(
     ctx: &CheckContext,
@@ -648,7 +570,8 @@ fn check_cursor_reconciliation(
         &config.files,
         &direction,
         ctx.fix,
-        ctx.dry_run,
+        // With --emit-patch, fixes come back unwritten for the patch
+        ctx.dry_run || ctx.patch.is_some(),
     );
 
     // Convert reconcile violations to check violations
@@ -674,27 +597,40 @@ fn check_cursor_reconciliation(
                 1,
             );
         } else {
+            if let Some(patch) = ctx.patch {
+                patch.record(&rf.target_path, &rf.content);
+            }
             fixes.add_sync(target, "cursor_reconcile".to_string(), 1);
         }
     }
 }
 
-/// Get effective size limits for a scope, with inheritance.
-fn get_scope_limits(config: &AgentsConfig, scope: &Scope) -> (Option<usize>, Option<usize>) {
-    let scope_config = match scope {
-        Scope::Root => config.root.as_ref(),
-        Scope::Package(_) => config.package.as_ref(),
-        Scope::Module => config.module.as_ref(),
-    };
-
-    // Scope config overrides top-level, top-level provides defaults
-    let max_lines = scope_config.and_then(|s| s.max_lines).or(config.max_lines);
-
-    let max_tokens = scope_config
-        .and_then(|s| s.max_tokens)
-        .or(config.max_tokens);
+/// Check cursor rule frontmatter, when `.mdc` rules are agent files.
+fn check_cursor_frontmatter(
+    ctx: &CheckContext,
+    config: &AgentsConfig,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.files.iter().any(|f| f.ends_with(".mdc")) {
+        return;
+    }
 
-    (max_lines, max_tokens)
+    let files: Vec<String> = ctx
+        .files
+        .iter()
+        .map(|f| {
+            f.path
+                .strip_prefix(ctx.root)
+                .unwrap_or(&f.path)
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect();
+    violations.extend(frontmatter::check_frontmatter(
+        ctx.root,
+        &files,
+        &config.frontmatter,
+    ));
 }
 
 #[cfg(test)]
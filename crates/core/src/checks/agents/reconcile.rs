@@ -279,15 +279,19 @@ fn check_cursor_to_agent(
     // Fix: append missing sections to agent file
     if ctx.fix && !missing_sections.is_empty() {
         let mut new_content = agent_content.to_string();
-        if !new_content.ends_with('\n') {
+        if !new_content.is_empty() && !new_content.ends_with('\n') {
             new_content.push('\n');
         }
 
         for cs in &missing_sections {
             if !cs.section.heading.is_empty() {
-                new_content.push_str(&format!("\n## {}\n\n", cs.section.heading));
+                // A new agent file starts with the first section's heading
+                if !new_content.is_empty() {
+                    new_content.push('\n');
+                }
+                new_content.push_str(&format!("## {}\n\n", cs.section.heading));
             }
-            new_content.push_str(&cs.section.content);
+            new_content.push_str(cs.section.content.trim_start_matches('\n'));
             if !cs.section.content.ends_with('\n') {
                 new_content.push('\n');
             }
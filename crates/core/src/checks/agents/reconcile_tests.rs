@@ -360,6 +360,38 @@ fn fix_creates_missing_agent_file() {
     );
 }
 
+#[test]
+fn fix_creates_root_agent_file_from_always_apply_rules() {
+    let dir = temp_dir();
+    let root = dir.path();
+
+    write_file(
+        root,
+        ".cursor/rules/general.mdc",
+        "---\nalwaysApply: true\n---\n\n## Code Style\n\nUse 4 spaces.\n",
+    );
+    write_file(
+        root,
+        ".cursor/rules/testing.mdc",
+        "---\nalwaysApply: true\n---\n\n## Testing\n\nRun cargo test.\n",
+    );
+
+    let (_violations, fixes) = check_cursor_reconciliation(
+        root,
+        &["CLAUDE.md".to_string()],
+        &ReconcileDirection::Bidirectional,
+        true,
+        false,
+    );
+
+    assert_eq!(fixes.len(), 1);
+    let content = std::fs::read_to_string(root.join("CLAUDE.md")).unwrap();
+    assert_eq!(
+        content,
+        "## Code Style\n\nUse 4 spaces.\n\n## Testing\n\nRun cargo test.\n"
+    );
+}
+
 #[test]
 fn fix_dry_run_does_not_write() {
     let dir = temp_dir();
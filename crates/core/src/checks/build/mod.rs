@@ -12,16 +12,19 @@
 
 mod javascript;
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
 use std::path::Path;
-use std::process::Command;
+use std::process::{Command, Output, Stdio};
 use std::time::{Duration, Instant};
 
-use serde_json::json;
-
 use crate::adapter::javascript::PackageManager;
 use crate::adapter::{ProjectLanguage, detect_bundler, detect_language};
 use crate::check::{Check, CheckContext, CheckResult, Violation};
+use crate::checks::testing::runners::run_with_timeout;
+use crate::container::{self, Container};
+use crate::interrupt;
+use crate::metrics::{self, CheckMetrics};
 use crate::tolerance::{parse_duration, parse_size};
 
 use javascript::{has_build_script, measure_bundle_size, resolve_js_targets};
@@ -60,6 +63,17 @@ impl Check for BuildCheck {
         let language = detect_language(ctx.root);
         let build_config = &ctx.config.check.build;
 
+        // Build in the configured container, if any
+        let container = match build_config
+            .container
+            .as_deref()
+            .map(|image| Container::detect(image, ctx.root))
+            .transpose()
+        {
+            Ok(container) => container,
+            Err(e) => return CheckResult::skipped(self.name(), e),
+        };
+
         // Parse time thresholds
         let time_cold_max = build_config
             .time_cold_max
@@ -102,31 +116,11 @@ impl Check for BuildCheck {
                     } else {
                         "Reduce binary size. Check for unnecessary dependencies."
                     };
-                    violations.push(Violation {
-                        file: None,
-                        line: None,
-                        violation_type: "size_exceeded".to_string(),
-                        advice: advice.to_string(),
-                        value: Some(size as i64),
-                        threshold: Some(threshold as i64),
-                        pattern: None,
-                        lines: None,
-                        nonblank: None,
-                        other_file: None,
-                        section: None,
-                        commit: None,
-                        message: None,
-                        expected_docs: None,
-                        area: None,
-                        area_match: None,
-                        path: None,
-                        target: Some(target.clone()),
-                        change_type: None,
-                        lines_changed: None,
-                        scope: None,
-                        expected: None,
-                        found: None,
-                    });
+                    violations.push(
+                        Violation::project("size_exceeded", advice)
+                            .with_threshold(size as i64, threshold as i64)
+                            .with_target(target.clone()),
+                    );
                 }
             }
         }
@@ -135,33 +129,13 @@ impl Check for BuildCheck {
         if explicit_targets {
             for target in &targets {
                 if !metrics.sizes.contains_key(target) {
-                    violations.push(Violation {
-                        file: None,
-                        line: None,
-                        violation_type: "missing_target".to_string(),
-                        advice:
-                            "Configured build target not found. Verify target exists and builds successfully."
-                                .to_string(),
-                        value: None,
-                        threshold: None,
-                        pattern: None,
-                        lines: None,
-                        nonblank: None,
-                        other_file: None,
-                        section: None,
-                        commit: None,
-                        message: None,
-                        expected_docs: None,
-                        area: None,
-                        area_match: None,
-                        path: None,
-                        target: Some(target.clone()),
-                        change_type: None,
-                        lines_changed: None,
-                        scope: None,
-                        expected: None,
-                        found: None,
-                    });
+                    violations.push(
+                        Violation::project(
+                            "missing_target",
+                            "Configured build target not found. Verify target exists and builds successfully.",
+                        )
+                        .with_target(target.clone()),
+                    );
                 }
             }
         }
@@ -171,7 +145,7 @@ impl Check for BuildCheck {
         let should_measure_hot = ctx.config.ratchet.build_time_hot || time_hot_max.is_some();
 
         if should_measure_cold {
-            metrics.time_cold = measure_cold_build(ctx.root, language);
+            metrics.time_cold = measure_cold_build(ctx.root, language, container.as_ref());
 
             // Check cold build time threshold
             if let (Some(duration), Some(max)) = (metrics.time_cold, time_cold_max)
@@ -185,7 +159,7 @@ impl Check for BuildCheck {
         }
 
         if should_measure_hot {
-            metrics.time_hot = measure_hot_build(ctx.root, language);
+            metrics.time_hot = measure_hot_build(ctx.root, language, container.as_ref());
 
             // Check hot build time threshold
             if let (Some(duration), Some(max)) = (metrics.time_hot, time_hot_max)
@@ -217,28 +191,23 @@ impl Check for BuildCheck {
 
 #[derive(Default)]
 struct BuildMetrics {
-    sizes: HashMap<String, u64>,
-    sizes_gzip: HashMap<String, u64>,
+    sizes: BTreeMap<String, u64>,
+    sizes_gzip: BTreeMap<String, u64>,
     time_cold: Option<Duration>,
     time_hot: Option<Duration>,
 }
 
 impl BuildMetrics {
     fn to_json(&self) -> serde_json::Value {
-        let mut result = json!({
-            "size": self.sizes,
-            "time": {
-                "cold": self.time_cold.map(|d| d.as_secs_f64()),
-                "hot": self.time_hot.map(|d| d.as_secs_f64()),
-            }
-        });
-
-        // Only include size_gzip if there are gzipped sizes
-        if !self.sizes_gzip.is_empty() {
-            result["size_gzip"] = json!(self.sizes_gzip);
+        metrics::Build {
+            size: self.sizes.clone(),
+            time: metrics::BuildTime {
+                cold: self.time_cold.map(|d| d.as_secs_f64()),
+                hot: self.time_hot.map(|d| d.as_secs_f64()),
+            },
+            size_gzip: self.sizes_gzip.clone(),
         }
-
-        result
+        .to_json()
     }
 
     fn has_metrics(&self) -> bool {
@@ -357,15 +326,15 @@ fn measure_binary_size(root: &Path, target: &str, language: ProjectLanguage) ->
 }
 
 /// Measure cold build time (clean build).
-fn measure_cold_build(root: &Path, language: ProjectLanguage) -> Option<Duration> {
+fn measure_cold_build(
+    root: &Path,
+    language: ProjectLanguage,
+    container: Option<&Container>,
+) -> Option<Duration> {
     match language {
         ProjectLanguage::Rust => {
             // Clean first
-            let output = Command::new("cargo")
-                .args(["clean"])
-                .current_dir(root)
-                .output()
-                .ok()?;
+            let output = run_build(root, container, "cargo", ["clean"])?;
 
             if !output.status.success() {
                 return None;
@@ -373,11 +342,7 @@ fn measure_cold_build(root: &Path, language: ProjectLanguage) -> Option<Duration
 
             // Time the build
             let start = Instant::now();
-            let output = Command::new("cargo")
-                .args(["build", "--release"])
-                .current_dir(root)
-                .output()
-                .ok()?;
+            let output = run_build(root, container, "cargo", ["build", "--release"])?;
 
             if output.status.success() {
                 Some(start.elapsed())
@@ -387,11 +352,7 @@ fn measure_cold_build(root: &Path, language: ProjectLanguage) -> Option<Duration
         }
         ProjectLanguage::Go => {
             // Clean first
-            let output = Command::new("go")
-                .args(["clean", "-cache"])
-                .current_dir(root)
-                .output()
-                .ok()?;
+            let output = run_build(root, container, "go", ["clean", "-cache"])?;
 
             if !output.status.success() {
                 return None;
@@ -399,11 +360,7 @@ fn measure_cold_build(root: &Path, language: ProjectLanguage) -> Option<Duration
 
             // Time the build
             let start = Instant::now();
-            let output = Command::new("go")
-                .args(["build", "./..."])
-                .current_dir(root)
-                .output()
-                .ok()?;
+            let output = run_build(root, container, "go", ["build", "./..."])?;
 
             if output.status.success() {
                 Some(start.elapsed())
@@ -429,11 +386,7 @@ fn measure_cold_build(root: &Path, language: ProjectLanguage) -> Option<Duration
             let run_cmd = pkg_mgr.run_command("build");
 
             let start = Instant::now();
-            let output = Command::new(&run_cmd[0])
-                .args(&run_cmd[1..])
-                .current_dir(root)
-                .output()
-                .ok()?;
+            let output = run_build(root, container, &run_cmd[0], &run_cmd[1..])?;
 
             if output.status.success() {
                 Some(start.elapsed())
@@ -446,7 +399,11 @@ fn measure_cold_build(root: &Path, language: ProjectLanguage) -> Option<Duration
 }
 
 /// Measure hot build time (incremental build).
-fn measure_hot_build(root: &Path, language: ProjectLanguage) -> Option<Duration> {
+fn measure_hot_build(
+    root: &Path,
+    language: ProjectLanguage,
+    container: Option<&Container>,
+) -> Option<Duration> {
     match language {
         ProjectLanguage::Rust => {
             let lib_rs = root.join("src/lib.rs");
@@ -463,11 +420,7 @@ fn measure_hot_build(root: &Path, language: ProjectLanguage) -> Option<Duration>
 
             // Time the build
             let start = Instant::now();
-            let output = Command::new("cargo")
-                .args(["build", "--release"])
-                .current_dir(root)
-                .output()
-                .ok()?;
+            let output = run_build(root, container, "cargo", ["build", "--release"])?;
 
             if output.status.success() {
                 Some(start.elapsed())
@@ -488,11 +441,7 @@ fn measure_hot_build(root: &Path, language: ProjectLanguage) -> Option<Duration>
 
             // Time the build
             let start = Instant::now();
-            let output = Command::new("go")
-                .args(["build", "./..."])
-                .current_dir(root)
-                .output()
-                .ok()?;
+            let output = run_build(root, container, "go", ["build", "./..."])?;
 
             if output.status.success() {
                 Some(start.elapsed())
@@ -527,11 +476,7 @@ fn measure_hot_build(root: &Path, language: ProjectLanguage) -> Option<Duration>
             let run_cmd = pkg_mgr.run_command("build");
 
             let start = Instant::now();
-            let output = Command::new(&run_cmd[0])
-                .args(&run_cmd[1..])
-                .current_dir(root)
-                .output()
-                .ok()?;
+            let output = run_build(root, container, &run_cmd[0], &run_cmd[1..])?;
 
             if output.status.success() {
                 Some(start.elapsed())
@@ -543,6 +488,26 @@ fn measure_hot_build(root: &Path, language: ProjectLanguage) -> Option<Duration>
     }
 }
 
+/// Run a build command in `root`, inside the container if one is configured.
+fn run_build<I, S>(
+    root: &Path,
+    container: Option<&Container>,
+    program: &str,
+    args: I,
+) -> Option<Output>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let mut cmd = Command::new(program);
+    cmd.args(args).current_dir(root);
+    let mut cmd = container::wrap(container, cmd);
+    cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+    // Killed on Ctrl-C rather than left building
+    let child = interrupt::spawn(&mut cmd).ok()?;
+    run_with_timeout(child, None).ok()
+}
+
 #[cfg(test)]
 #[path = "mod_tests.rs"]
 mod tests;
@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Per-file line and token counts, including the source/test split of
+//! files with inline tests.
+
+use std::path::Path;
+
+use crate::adapter::InlineTestInfo;
+use crate::check::CheckContext;
+use crate::file_reader::{FileBytes, FileContent};
+
+/// Non-blank (source, test) line counts for a file with inline tests
+/// (Go test functions, Python doctests, Vitest blocks), or None if it has
+/// none or can't be read.
+pub(super) fn inline_test_split(ctx: &CheckContext, path: &Path) -> Option<(usize, usize)> {
+    let file_content = ctx.read_file(path).ok()?;
+    let content = file_content.as_str()?;
+    let info = InlineTestInfo::parse(path, content)?;
+    if !info.has_inline_tests() {
+        return None;
+    }
+
+    let (mut source_lines, mut test_lines) = (0, 0);
+    for (idx, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if info.is_test_line(idx) {
+            test_lines += 1;
+        } else {
+            source_lines += 1;
+        }
+    }
+    Some((source_lines, test_lines))
+}
+
+/// Check if a file is a source code file (for LOC counting).
+/// Delegates to the shared `cloc` module's text extension check.
+pub(super) fn is_text_file(path: &Path) -> bool {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    crate::cloc::is_text_extension(&ext)
+}
+
+/// Metrics computed from a single file read.
+pub(super) struct FileMetrics {
+    /// Total lines (matches `wc -l`), used for size limit thresholds.
+    pub(super) lines: usize,
+    /// Non-blank lines (lines with at least one non-whitespace character).
+    pub(super) nonblank_lines: usize,
+    pub(super) tokens: usize,
+}
+
+/// Metrics of a file as checked: its staged content with `--staged`, else
+/// the file on disk.
+pub(super) fn file_metrics(ctx: &CheckContext, path: &Path) -> std::io::Result<FileMetrics> {
+    let Some(bytes) = ctx.staged_content.and_then(|staged| staged.bytes(path)) else {
+        return count_file_metrics(path);
+    };
+    if is_notebook(path)
+        && let Some(metrics) = std::str::from_utf8(bytes).ok().and_then(notebook_metrics)
+    {
+        return Ok(metrics);
+    }
+    Ok(byte_metrics(bytes))
+}
+
+/// Count lines and tokens from a single file read.
+/// - `lines`: total line count (matches `wc -l`)
+/// - `nonblank_lines`: lines with at least one non-whitespace character
+/// - `tokens`: chars/4 approximation (standard LLM heuristic)
+///
+/// Scans raw bytes (memory-mapped for large files) without UTF-8 validation.
+/// Notebooks count their code cells rather than the JSON around them.
+pub(super) fn count_file_metrics(path: &Path) -> std::io::Result<FileMetrics> {
+    if is_notebook(path) {
+        let content = FileContent::read(path)?;
+        if let Some(metrics) = content.as_str().and_then(notebook_metrics) {
+            return Ok(metrics);
+        }
+    }
+
+    let content = FileBytes::read(path)?;
+    Ok(byte_metrics(content.as_bytes()))
+}
+
+fn is_notebook(path: &Path) -> bool {
+    path.extension().and_then(|e| e.to_str()) == Some("ipynb")
+}
+
+/// Metrics of a notebook's code cells, or None if it doesn't parse.
+fn notebook_metrics(text: &str) -> Option<FileMetrics> {
+    let blocks = crate::blocks::split("ipynb", text)?;
+    let mut metrics = FileMetrics {
+        lines: 0,
+        nonblank_lines: 0,
+        tokens: 0,
+    };
+    for block in blocks.iter().filter(|b| b.ext != "md") {
+        let counts = crate::cloc::count_bytes(block.content.as_bytes());
+        metrics.lines += counts.lines;
+        metrics.nonblank_lines += counts.nonblank();
+        metrics.tokens += counts.tokens;
+    }
+    Some(metrics)
+}
+
+fn byte_metrics(bytes: &[u8]) -> FileMetrics {
+    let counts = crate::cloc::count_bytes(bytes);
+    FileMetrics {
+        lines: counts.lines,
+        nonblank_lines: counts.nonblank(),
+        tokens: counts.tokens,
+    }
+}
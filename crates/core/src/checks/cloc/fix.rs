@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Split-point suggestions for files over the size limit.
+
+use crate::check::{CheckContext, Field, Violation};
+use crate::fix::{Fix, Fixes};
+
+use super::ClocCheck;
+
+/// Suggests where to split files that are too large; splitting is left to
+/// a person.
+impl Fix for ClocCheck {
+    fn fix(&self, ctx: &CheckContext, violations: &[Violation]) -> Fixes {
+        let mut fixes = Fixes::default();
+        for (idx, violation) in violations.iter().enumerate() {
+            if !matches!(
+                violation.violation_type.as_str(),
+                "file_too_large" | "file_too_large_nonblank"
+            ) {
+                continue;
+            }
+            let (Some(file), Some(value), Some(threshold)) = (
+                violation.file.as_deref(),
+                violation.int(Field::Value),
+                violation.int(Field::Threshold),
+            ) else {
+                continue;
+            };
+            let Ok(file_content) = ctx.read_file(&ctx.root.join(file)) else {
+                continue;
+            };
+            let Some(content) = file_content.as_str() else {
+                continue;
+            };
+            let parts = (value.max(1) as usize).div_ceil(threshold.max(1) as usize);
+            let points = split_points(content, parts.max(2));
+            if points.is_empty() {
+                continue;
+            }
+            let listed: Vec<String> = points
+                .iter()
+                .map(|(line, text)| format!("line {} (`{}`)", line, text))
+                .collect();
+            fixes
+                .suggestions
+                .push((idx, format!("Split points: {}.", listed.join(", "))));
+        }
+        fixes
+    }
+}
+
+/// Characters of an item's first line shown with a split point.
+const SPLIT_TEXT_MAX: usize = 60;
+
+/// Where to cut `content` into `parts` pieces of similar size: the top-level
+/// item starting nearest each even cut, as (line, first line of the item).
+///
+/// Top-level items start unindented after a blank line, so doc comments
+/// and attributes stay with their item.
+pub(super) fn split_points(content: &str, parts: usize) -> Vec<(u32, String)> {
+    let lines: Vec<&str> = content.lines().collect();
+    let starts: Vec<usize> = (1..lines.len())
+        .filter(|&i| {
+            let line = lines[i];
+            lines[i - 1].trim().is_empty()
+                && !line.trim().is_empty()
+                && !line.starts_with(char::is_whitespace)
+                && !line.starts_with(['}', ')', ']'])
+        })
+        .collect();
+
+    let mut points: Vec<usize> = (1..parts)
+        .filter_map(|part| {
+            let cut = lines.len() * part / parts;
+            starts
+                .iter()
+                .copied()
+                .min_by_key(|start| start.abs_diff(cut))
+        })
+        .collect();
+    points.dedup();
+    points
+        .into_iter()
+        .map(|idx| {
+            let text = lines[idx].trim_end();
+            let text = match text.char_indices().nth(SPLIT_TEXT_MAX) {
+                Some((end, _)) => format!("{}...", &text[..end]),
+                None => text.to_string(),
+            };
+            (idx as u32 + 1, text)
+        })
+        .collect()
+}
@@ -0,0 +1,674 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Cloc (count lines of code) check.
+//!
+//! Validates file size limits per docs/specs/checks/cloc.md.
+
+mod counts;
+mod fix;
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::atomic::Ordering;
+
+use globset::GlobSet;
+
+use crate::adapter::glob::build_glob_set;
+use crate::adapter::rust::{CfgTestBlock, CfgTestInfo, CfgTestItemKind};
+use crate::adapter::{AdapterRegistry, FileKind, InlineTestInfo, RustAdapter};
+use crate::check::{Check, CheckContext, CheckResult, Violation};
+use crate::config::{CfgTestSplitMode, CheckLevel, ClocConfig, LineMetric};
+use crate::fix::Fix;
+use crate::metrics::{self, CheckMetrics};
+use counts::{file_metrics, inline_test_split, is_text_file};
+
+/// Parameters for creating a line-count violation.
+struct LineViolationInfo {
+    metric: LineMetric,
+    value: usize,
+    threshold: usize,
+    total_lines: usize,
+    nonblank_lines: usize,
+}
+
+/// The cloc check validates file size limits.
+pub struct ClocCheck;
+
+impl Check for ClocCheck {
+    fn name(&self) -> &'static str {
+        "cloc"
+    }
+
+    fn description(&self) -> &'static str {
+        "Lines of code and file size limits"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let cloc_config = &ctx.config.check.cloc;
+        let packages = &ctx.config.project.packages;
+
+        // NOTE: We don't early-return on global check.cloc.check = "off" because
+        // individual languages can override this setting. The per-file check level
+        // resolution handles "off" correctly by skipping files with that level.
+
+        // Build adapter registry for file classification
+        // Uses config-aware pattern resolution with hierarchy:
+        // 1. [<language>].tests - Language-specific override
+        // 2. [project].tests - Project-wide patterns
+        // 3. Adapter defaults - Built-in convention
+        let registry = AdapterRegistry::for_project_with_config(ctx.root, ctx.config);
+
+        // Get Rust config for cfg_test_split
+        let rust_config = &ctx.config.rust;
+        // Only create adapter for modes that need parsing
+        // Uses config-aware patterns for consistent test file classification
+        let rust_adapter = match rust_config.cfg_test_split {
+            CfgTestSplitMode::Count | CfgTestSplitMode::Require => {
+                use crate::config::RustConfig;
+                let fallback_test = if !ctx.config.project.tests.is_empty() {
+                    ctx.config.project.tests.clone()
+                } else {
+                    RustConfig::default_tests()
+                };
+                let patterns = crate::adapter::ResolvedPatterns {
+                    source: if !rust_config.source.is_empty() {
+                        rust_config.source.clone()
+                    } else {
+                        RustConfig::default_source()
+                    },
+                    test: if !rust_config.tests.is_empty() {
+                        rust_config.tests.clone()
+                    } else {
+                        fallback_test
+                    },
+                    exclude: if !rust_config.exclude.is_empty() {
+                        rust_config.exclude.clone()
+                    } else {
+                        RustConfig::default_exclude()
+                    },
+                };
+                Some(RustAdapter::with_patterns(patterns))
+            }
+            CfgTestSplitMode::Off => None,
+        };
+
+        // Compute effective test advice with actual threshold.
+        // The serde default bakes in MAX_LINES_TEST; recompute when the user
+        // hasn't customized advice_test so the target range matches the real limit.
+        let test_advice = if cloc_config.advice_test == ClocConfig::default_advice_test() {
+            crate::config::defaults::advice::cloc_test(cloc_config.max_lines_test)
+        } else {
+            cloc_config.advice_test.clone()
+        };
+
+        // Build pattern matcher for exclude patterns only
+        let exclude_matcher = ExcludeMatcher::new(&cloc_config.exclude);
+
+        // Churn per file for hotspot violations, when enabled
+        let hotspots = &ctx.config.hotspots;
+        let churn = if hotspots.check != CheckLevel::Off && crate::git::is_git_repo(ctx.root) {
+            crate::git::file_churn(ctx.root, crate::debt::churn_since(hotspots.window))
+                .unwrap_or_else(|e| {
+                    tracing::warn!("cannot read churn for hotspots: {}", e);
+                    BTreeMap::new()
+                })
+        } else {
+            BTreeMap::new()
+        };
+
+        // Files are scanned in parallel, then merged in file order so the
+        // violation limit cuts off the same violations a serial scan would
+        let tallies = ctx.par_scan_files(|ctx, file| {
+            // Skip non-text files
+            if !is_text_file(&file.path) {
+                return None;
+            }
+
+            let metrics = match file_metrics(ctx, &file.path) {
+                Ok(metrics) => metrics,
+                Err(e) => {
+                    tracing::warn!("failed to count lines in {}: {}", file.path.display(), e);
+                    return None;
+                }
+            };
+            let total_lines = metrics.lines;
+            let nonblank_lines = metrics.nonblank_lines;
+            let token_count = metrics.tokens;
+            let relative_path = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
+            let file_kind = registry.classify(relative_path);
+            let is_excluded = exclude_matcher.is_excluded(&file.path, ctx.root);
+            let mut found: Vec<FoundViolation> = Vec::new();
+
+            // Check if this is a Rust source file that might have inline tests
+            let is_rust_source = file.path.extension().and_then(|e| e.to_str()) == Some("rs")
+                && file_kind == FileKind::Source
+                && rust_adapter.is_some();
+
+            // Inline tests in other languages split like Rust's `count` mode
+            let inline_split = if file_kind == FileKind::Source
+                && file.path.extension().and_then(|e| e.to_str()) != Some("rs")
+                && InlineTestInfo::supports(&file.path)
+            {
+                inline_test_split(ctx, &file.path)
+            } else {
+                None
+            };
+
+            // Determine source/test line counts
+            let (file_source_lines, file_test_lines, is_test) =
+                if let (true, Some(adapter)) = (is_rust_source, rust_adapter.as_ref()) {
+                    // Use line-level classification for Rust source files
+                    // (uses mmap for large files per performance spec)
+                    let file_content = ctx.read_file(&file.path).ok()?;
+                    // Skip binary files
+                    let content = file_content.as_str()?;
+
+                    match rust_config.cfg_test_split {
+                        CfgTestSplitMode::Require => {
+                            // Check for inline tests and generate violation
+                            // Note: This respects rust.cloc.check level
+                            let rust_check_level = ctx.config.cloc_check_level_for_language("rust");
+                            if rust_check_level != CheckLevel::Off {
+                                let cfg_info = CfgTestInfo::parse(content);
+                                let is_error = rust_check_level == CheckLevel::Error;
+                                for block in &cfg_info.blocks {
+                                    if block.item_kind != CfgTestItemKind::Mod {
+                                        continue;
+                                    }
+                                    found.push(FoundViolation {
+                                        violation: create_inline_cfg_test_violation(
+                                            ctx, &file.path, block,
+                                        ),
+                                        is_error,
+                                        limited: false,
+                                    });
+                                }
+                            }
+                            // Still count as source (no splitting)
+                            (nonblank_lines, 0, false)
+                        }
+                        CfgTestSplitMode::Count => {
+                            // Existing behavior: split source/test
+                            let classification = adapter.classify_lines(relative_path, content);
+                            // File is considered "test" for size limits if it has more test than source
+                            let is_test = classification.test_lines > classification.source_lines;
+                            (
+                                classification.source_lines,
+                                classification.test_lines,
+                                is_test,
+                            )
+                        }
+                        CfgTestSplitMode::Off => unreachable!(), // Adapter is None
+                    }
+                } else if let Some((source_lines, test_lines)) = inline_split {
+                    (source_lines, test_lines, test_lines > source_lines)
+                } else {
+                    // Use whole-file classification
+                    let is_test = file_kind == FileKind::Test;
+                    if is_test {
+                        (0, nonblank_lines, true)
+                    } else {
+                        (nonblank_lines, 0, false)
+                    }
+                };
+
+            let tally = |violations| FileTally {
+                package: (!packages.is_empty())
+                    .then(|| file_package(&file.path, ctx.root, packages))
+                    .flatten(),
+                source_lines: file_source_lines,
+                test_lines: file_test_lines,
+                source_tokens: token_count * file_source_lines / nonblank_lines.max(1),
+                test_tokens: token_count * file_test_lines / nonblank_lines.max(1),
+                violations,
+            };
+
+            // Size limit check (skip excluded files)
+            // For files with both source and test lines, check source portion against source limit
+            if is_excluded {
+                return Some(tally(found));
+            }
+
+            // Get language-specific check level and advice
+            // Use file extension for language detection in mixed-language projects
+            // where only the primary language adapter is registered
+            let adapter_name = registry.adapter_for(relative_path).name();
+            let lang_key = if adapter_name == "generic" {
+                // Fall back to file extension for per-language config lookup
+                file.path
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .unwrap_or(adapter_name)
+            } else {
+                adapter_name
+            };
+            let lang_check_level = ctx.config.cloc_check_level_for_language(lang_key);
+
+            // Skip violation generation if this language is disabled
+            if lang_check_level == CheckLevel::Off {
+                return Some(tally(found));
+            }
+
+            let is_error = lang_check_level == CheckLevel::Error;
+
+            let max_lines = if is_test {
+                cloc_config.max_lines_test
+            } else {
+                cloc_config.max_lines
+            };
+
+            // Use configured line metric for size limit check
+            let line_count = match cloc_config.metric {
+                LineMetric::Lines => total_lines,
+                LineMetric::Nonblank => nonblank_lines,
+            };
+
+            let source_advice = ctx.config.cloc_advice_for_language(lang_key, max_lines);
+            let advice = if is_test {
+                &test_advice
+            } else {
+                &source_advice
+            };
+
+            if line_count > max_lines {
+                let info = LineViolationInfo {
+                    metric: cloc_config.metric,
+                    value: line_count,
+                    threshold: max_lines,
+                    total_lines,
+                    nonblank_lines,
+                };
+                found.push(FoundViolation {
+                    violation: create_line_violation(ctx, &file.path, advice, &info),
+                    is_error,
+                    limited: true,
+                });
+            }
+
+            // Hotspot check: large source files that keep changing
+            if !is_test
+                && let Some(&commits) = churn.get(&relative_path.display().to_string())
+                && crate::hotspots::is_hotspot(line_count, commits, hotspots)
+            {
+                found.push(FoundViolation {
+                    violation: create_hotspot_violation(
+                        ctx,
+                        &file.path,
+                        commits,
+                        total_lines,
+                        nonblank_lines,
+                    ),
+                    is_error: hotspots.check == CheckLevel::Error,
+                    limited: true,
+                });
+            }
+
+            // Token limit check
+            if let Some(max_tokens) = cloc_config.max_tokens
+                && token_count > max_tokens
+            {
+                found.push(FoundViolation {
+                    violation: create_token_violation(
+                        ctx,
+                        &file.path,
+                        advice,
+                        token_count,
+                        max_tokens,
+                    ),
+                    is_error,
+                    limited: true,
+                });
+            }
+
+            Some(tally(found))
+        });
+
+        // Track violations along with whether they are errors (true) or warnings (false)
+        let mut violation_infos: Vec<(Violation, bool)> = Vec::new();
+        let mut source_lines: usize = 0;
+        let mut source_files: usize = 0;
+        let mut source_tokens: usize = 0;
+        let mut test_lines: usize = 0;
+        let mut test_files: usize = 0;
+        let mut test_tokens: usize = 0;
+
+        // Per-package metrics (only tracked if packages are configured)
+        let mut package_metrics: HashMap<String, PackageMetrics> = HashMap::new();
+        let mut limit_reached = false;
+
+        for tally in tallies.into_iter().flatten() {
+            // Accumulate global metrics
+            source_lines += tally.source_lines;
+            test_lines += tally.test_lines;
+
+            // File counts: count file in source if any source lines, test if any test lines
+            if tally.source_lines > 0 {
+                source_files += 1;
+                source_tokens += tally.source_tokens;
+            }
+            if tally.test_lines > 0 {
+                test_files += 1;
+                test_tokens += tally.test_tokens;
+            }
+
+            // Accumulate per-package metrics
+            if let Some(pkg_name) = tally.package {
+                let pkg = package_metrics.entry(pkg_name).or_default();
+                pkg.source_lines += tally.source_lines;
+                pkg.test_lines += tally.test_lines;
+                if tally.source_lines > 0 {
+                    pkg.source_files += 1;
+                    pkg.source_tokens += tally.source_tokens;
+                }
+                if tally.test_lines > 0 {
+                    pkg.test_files += 1;
+                    pkg.test_tokens += tally.test_tokens;
+                }
+            }
+
+            for found in tally.violations {
+                if limit_reached || (found.limited && !ctx.admit_violation()) {
+                    limit_reached = true;
+                    break;
+                }
+                violation_infos.push((found.violation, found.is_error));
+            }
+        }
+        // Minimum test ratios: per package when packages are configured,
+        // otherwise for the whole project
+        if cloc_config.check != CheckLevel::Off {
+            let package_names = &ctx.config.project.package_names;
+            let mut ratios: Vec<(Option<String>, PackageMetrics)> = if packages.is_empty() {
+                vec![(
+                    None,
+                    PackageMetrics {
+                        source_lines,
+                        test_lines,
+                        ..Default::default()
+                    },
+                )]
+            } else {
+                package_metrics
+                    .iter()
+                    .map(|(path, package)| {
+                        let name = package_names.get(path).unwrap_or(path);
+                        (
+                            Some(name.clone()),
+                            PackageMetrics {
+                                source_lines: package.source_lines,
+                                test_lines: package.test_lines,
+                                ..Default::default()
+                            },
+                        )
+                    })
+                    .collect()
+            };
+            ratios.sort_by(|a, b| a.0.cmp(&b.0));
+
+            for (name, package) in ratios {
+                let min = name
+                    .as_ref()
+                    .and_then(|name| cloc_config.package.get(name))
+                    .and_then(|package| package.min_test_ratio)
+                    .or(cloc_config.min_test_ratio);
+                let ratio = (package.ratio() * 100.0).round() / 100.0;
+                let Some(min) = min.filter(|&min| package.source_lines > 0 && ratio < min) else {
+                    continue;
+                };
+                match try_create_ratio_violation(ctx, name, ratio, min) {
+                    Some(v) => violation_infos.push((v, cloc_config.check == CheckLevel::Error)),
+                    None => break,
+                }
+            }
+        }
+
+        // Separate errors and warnings, then build result
+        let violations: Vec<Violation> = violation_infos.iter().map(|(v, _)| v.clone()).collect();
+        let has_errors = violation_infos.iter().any(|(_, is_error)| *is_error);
+
+        let result = if violations.is_empty() {
+            CheckResult::passed(self.name())
+        } else if has_errors {
+            CheckResult::failed(self.name(), violations)
+        } else {
+            // All violations are warnings - pass but include them for reporting
+            CheckResult::passed_with_warnings(self.name(), violations)
+        };
+
+        // Calculate ratio
+        let ratio = if source_lines > 0 {
+            test_lines as f64 / source_lines as f64
+        } else {
+            0.0
+        };
+
+        let result = result.with_metrics(
+            metrics::Cloc {
+                source_lines,
+                source_files,
+                source_tokens,
+                test_lines,
+                test_files,
+                test_tokens,
+                ratio: (ratio * 100.0).round() / 100.0,
+            }
+            .to_json(),
+        );
+
+        // Add per-package metrics if packages are configured
+        if !package_metrics.is_empty() {
+            let package_names = &ctx.config.project.package_names;
+            let by_package: BTreeMap<String, serde_json::Value> = package_metrics
+                .into_iter()
+                .map(|(path, package)| {
+                    // Use package name from mapping if available, otherwise use path
+                    let name = package_names.get(&path).cloned().unwrap_or(path);
+                    let ratio = package.ratio();
+                    (
+                        name,
+                        metrics::Cloc {
+                            source_lines: package.source_lines,
+                            source_files: package.source_files,
+                            source_tokens: package.source_tokens,
+                            test_lines: package.test_lines,
+                            test_files: package.test_files,
+                            test_tokens: package.test_tokens,
+                            ratio: (ratio * 100.0).round() / 100.0,
+                        }
+                        .to_json(),
+                    )
+                })
+                .collect();
+            result.with_by_package(by_package)
+        } else {
+            result
+        }
+    }
+
+    fn default_enabled(&self) -> bool {
+        true
+    }
+
+    fn fixer(&self) -> Option<&dyn Fix> {
+        Some(self)
+    }
+}
+
+/// What one file adds to the cloc result.
+struct FileTally {
+    /// Package the file belongs to, if packages are configured.
+    package: Option<String>,
+    source_lines: usize,
+    test_lines: usize,
+    source_tokens: usize,
+    test_tokens: usize,
+    /// Violations in the order they were found.
+    violations: Vec<FoundViolation>,
+}
+
+/// A violation found while scanning a file, before the limit is applied.
+struct FoundViolation {
+    violation: Violation,
+    is_error: bool,
+    /// Whether it counts toward the violation limit.
+    limited: bool,
+}
+
+/// Per-package metrics.
+#[derive(Default)]
+struct PackageMetrics {
+    source_lines: usize,
+    source_files: usize,
+    source_tokens: usize,
+    test_lines: usize,
+    test_files: usize,
+    test_tokens: usize,
+}
+
+impl PackageMetrics {
+    fn ratio(&self) -> f64 {
+        if self.source_lines > 0 {
+            self.test_lines as f64 / self.source_lines as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Determine which package a file belongs to.
+///
+/// Uses `.ok()?` for strip_prefix because if the path isn't under root,
+/// it semantically cannot belong to any configured package.
+fn file_package(path: &Path, root: &Path, packages: &[String]) -> Option<String> {
+    let relative = path.strip_prefix(root).ok()?;
+
+    for pkg in packages {
+        // Special case: "." means root package (all files belong to it)
+        if pkg == "." {
+            return Some(pkg.clone());
+        }
+        // Check if file is under the package directory
+        if relative.starts_with(pkg) {
+            return Some(pkg.clone());
+        }
+    }
+    None
+}
+
+/// Pattern matcher for exclude patterns.
+struct ExcludeMatcher {
+    exclude_patterns: GlobSet,
+}
+
+impl ExcludeMatcher {
+    /// Create a new exclude matcher from config patterns.
+    fn new(exclude_patterns: &[String]) -> Self {
+        Self {
+            exclude_patterns: build_glob_set(exclude_patterns),
+        }
+    }
+
+    /// Check if a file should be excluded from violations.
+    fn is_excluded(&self, path: &Path, root: &Path) -> bool {
+        let relative = path.strip_prefix(root).unwrap_or(path);
+        self.exclude_patterns.is_match(relative)
+    }
+}
+
+/// Create a line count violation.
+fn create_line_violation(
+    ctx: &CheckContext,
+    file_path: &Path,
+    advice: &str,
+    info: &LineViolationInfo,
+) -> Violation {
+    let display_path = file_path.strip_prefix(ctx.root).unwrap_or(file_path);
+
+    // Use violation type that indicates which metric was checked
+    let violation_type = match info.metric {
+        LineMetric::Lines => "file_too_large",
+        LineMetric::Nonblank => "file_too_large_nonblank",
+    };
+
+    Violation::file_only(display_path, violation_type, advice)
+        .with_threshold(info.value as i64, info.threshold as i64)
+        .with_line_counts(info.total_lines as i64, info.nonblank_lines as i64)
+}
+
+/// Create a token count violation.
+fn create_token_violation(
+    ctx: &CheckContext,
+    file_path: &Path,
+    advice: &str,
+    value: usize,
+    threshold: usize,
+) -> Violation {
+    let display_path = file_path.strip_prefix(ctx.root).unwrap_or(file_path);
+
+    Violation::file_only(display_path, "file_too_large", advice)
+        .with_threshold(value as i64, threshold as i64)
+}
+
+/// Check violation limit and create a test ratio violation if under the limit.
+/// `package` is None when the ratio is for the whole project.
+fn try_create_ratio_violation(
+    ctx: &CheckContext,
+    package: Option<String>,
+    ratio: f64,
+    min: f64,
+) -> Option<Violation> {
+    let current = ctx.violation_count.fetch_add(1, Ordering::SeqCst);
+    if let Some(limit) = ctx.limit
+        && current >= limit
+    {
+        return None;
+    }
+
+    let advice = format!(
+        "{:.2} test lines per source line (min: {}). Add tests, or lower min_test_ratio.",
+        ratio, min
+    );
+    let violation = Violation::project("test_ratio_too_low", advice);
+    Some(match package {
+        Some(package) => violation.with_target(package),
+        None => violation,
+    })
+}
+
+/// Create a hotspot violation. The violation's value is the file's churn,
+/// against the churn threshold.
+fn create_hotspot_violation(
+    ctx: &CheckContext,
+    file_path: &Path,
+    churn: usize,
+    total_lines: usize,
+    nonblank_lines: usize,
+) -> Violation {
+    let display_path = file_path.strip_prefix(ctx.root).unwrap_or(file_path);
+    let advice = crate::config::defaults::advice::hotspot(ctx.config.hotspots.window);
+
+    Violation::file_only(display_path, "hotspot", advice)
+        .with_threshold(churn as i64, ctx.config.hotspots.churn as i64)
+        .with_line_counts(total_lines as i64, nonblank_lines as i64)
+}
+
+/// Create a violation for an inline `#[cfg(test)] mod` block.
+fn create_inline_cfg_test_violation(
+    ctx: &CheckContext,
+    file_path: &Path,
+    block: &CfgTestBlock,
+) -> Violation {
+    let display_path = file_path.strip_prefix(ctx.root).unwrap_or(file_path);
+    let line = block.attr_line as u32 + 1;
+    Violation::file(
+        display_path,
+        line,
+        "inline_cfg_test",
+        "Move tests to a sibling _tests.rs file.",
+    )
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
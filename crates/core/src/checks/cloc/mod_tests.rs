@@ -7,6 +7,8 @@
 
 use yare::parameterized;
 
+use super::counts::count_file_metrics;
+use super::fix::split_points;
 use super::*;
 use crate::test_utils::temp_file_with_content;
 
@@ -88,6 +90,25 @@ fn file_metrics_empty_file_tokens() {
     assert_eq!(metrics.tokens, 0);
 }
 
+#[test]
+fn file_metrics_notebook_counts_code_cells_only() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("nb.ipynb");
+    std::fs::write(
+        &path,
+        r#"{"cells": [
+            {"cell_type": "markdown", "source": ["Intro\n", "More"]},
+            {"cell_type": "code", "source": ["x = 1\n", "\n", "y = 2"]}
+        ]}"#,
+    )
+    .unwrap();
+
+    let metrics = count_file_metrics(&path).unwrap();
+
+    assert_eq!(metrics.lines, 3);
+    assert_eq!(metrics.nonblank_lines, 2);
+}
+
 // =============================================================================
 // ADAPTER/PATTERN TESTS
 // =============================================================================
@@ -212,3 +233,40 @@ fn bench_pattern_matching() {
         elapsed
     );
 }
+
+// =============================================================================
+// SPLIT POINTS
+// =============================================================================
+
+#[test]
+fn split_points_pick_top_level_items_near_even_cuts() {
+    let mut content = String::new();
+    for name in ["a", "b", "c", "d"] {
+        content.push_str(&format!(
+            "/// {}\nfn {}() {{\n    body();\n}}\n\n",
+            name, name
+        ));
+    }
+
+    assert_eq!(
+        split_points(&content, 2),
+        vec![(11, "/// c".to_string())],
+        "doc comments stay with their item"
+    );
+}
+
+#[test]
+fn split_points_skip_closing_braces_and_indented_lines() {
+    let content = "mod a {\n    fn x() {}\n\n    fn y() {}\n\n}\n";
+    assert!(split_points(content, 2).is_empty());
+}
+
+#[test]
+fn split_points_truncate_long_lines() {
+    let long = format!("fn {}() {{}}", "x".repeat(80));
+    let content = format!("fn a() {{}}\n\n{}\n", long);
+    let points = split_points(&content, 2);
+
+    assert_eq!(points.len(), 1);
+    assert_eq!(points[0].1, format!("{}...", &long[..60]));
+}
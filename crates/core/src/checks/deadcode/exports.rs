@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Exported item extraction and identifier scanning.
+//!
+//! Line-based on purpose: the check only wants items that are obviously
+//! unused, so a missed export is fine but a false positive is not.
+
+use std::path::Path;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// Languages the deadcode check understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    Rust,
+    JavaScript,
+    Go,
+}
+
+impl Lang {
+    /// Language for a file, by extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "rs" => Some(Lang::Rust),
+            "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "mts" | "cts" => Some(Lang::JavaScript),
+            "go" => Some(Lang::Go),
+            _ => None,
+        }
+    }
+
+    /// Metric key for this language.
+    pub fn name(self) -> &'static str {
+        match self {
+            Lang::Rust => "rust",
+            Lang::JavaScript => "javascript",
+            Lang::Go => "go",
+        }
+    }
+}
+
+/// An exported item found in a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Export {
+    /// 1-based line of the definition.
+    pub line: u32,
+    pub name: String,
+}
+
+/// `pub fn`, `pub struct`, ... but not `pub(crate)`, `pub use`, or `pub mod`.
+#[allow(clippy::expect_used)]
+static RUST_ITEM: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"^pub\s+(?:(?:const|async|unsafe|extern(?:\s+"[^"]*")?)\s+)*(?:fn|struct|enum|trait|type|const|static(?:\s+mut)?|union)\s+([A-Za-z_][A-Za-z0-9_]*)"#,
+    )
+    .expect("valid regex pattern")
+});
+
+/// `export function`, `export const`, ... but not `export default` or `export { .. }`.
+#[allow(clippy::expect_used)]
+static JS_EXPORT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^export\s+(?:declare\s+)?(?:async\s+)?(?:abstract\s+)?(?:function\*?|class|const|let|var|interface|type|enum)\s+([A-Za-z_$][A-Za-z0-9_$]*)",
+    )
+    .expect("valid regex pattern")
+});
+
+/// Top-level exported `func`, `type`, `var`, or `const` (methods are left
+/// out since they may satisfy an interface).
+#[allow(clippy::expect_used)]
+static GO_DECL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:func\s+|type\s+|var\s+|const\s+)([A-Z][A-Za-z0-9_]*)")
+        .expect("valid regex pattern")
+});
+
+/// Start of a grouped `const (`, `var (`, or `type (` declaration.
+#[allow(clippy::expect_used)]
+static GO_GROUP: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:const|var|type)\s*\($").expect("valid regex pattern"));
+
+/// Exported name on its own line inside a grouped declaration.
+#[allow(clippy::expect_used)]
+static GO_GROUP_ITEM: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s+([A-Z][A-Za-z0-9_]*)\b").expect("valid regex pattern"));
+
+/// Exported items defined in `content`.
+pub fn exports(lang: Lang, content: &str) -> Vec<Export> {
+    match lang {
+        Lang::Rust => line_exports(content, |line| {
+            let name = capture(&RUST_ITEM, line.trim_start())?;
+            (name != "main").then_some(name)
+        }),
+        Lang::JavaScript => line_exports(content, |line| capture(&JS_EXPORT, line.trim_start())),
+        Lang::Go => go_exports(content),
+    }
+}
+
+fn capture<'a>(pattern: &Regex, line: &'a str) -> Option<&'a str> {
+    Some(pattern.captures(line)?.get(1)?.as_str())
+}
+
+fn line_exports<'a>(content: &'a str, find: impl Fn(&'a str) -> Option<&'a str>) -> Vec<Export> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| {
+            Some(Export {
+                line: idx as u32 + 1,
+                name: find(line)?.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn go_exports(content: &str) -> Vec<Export> {
+    let mut exports = Vec::new();
+    let mut in_group = false;
+    for (idx, line) in content.lines().enumerate() {
+        let name = if in_group {
+            if line.starts_with(')') {
+                in_group = false;
+                continue;
+            }
+            capture(&GO_GROUP_ITEM, line)
+        } else if GO_GROUP.is_match(line.trim_end()) {
+            in_group = true;
+            continue;
+        } else {
+            capture(&GO_DECL, line)
+        };
+        if let Some(name) = name {
+            exports.push(Export {
+                line: idx as u32 + 1,
+                name: name.to_string(),
+            });
+        }
+    }
+    exports
+}
+
+/// Identifiers in `content`, in order, including those in comments and
+/// strings (a mention anywhere keeps an item alive).
+pub fn identifiers(content: &str) -> impl Iterator<Item = &str> {
+    content
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+        .filter(|word| word.starts_with(|c: char| !c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+#[path = "exports_tests.rs"]
+mod tests;
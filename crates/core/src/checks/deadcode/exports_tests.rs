@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn names(lang: Lang, content: &str) -> Vec<String> {
+    exports(lang, content).into_iter().map(|e| e.name).collect()
+}
+
+// =============================================================================
+// RUST
+// =============================================================================
+
+#[test]
+fn rust_pub_items_are_exports() {
+    let content = "\
+pub fn parse() {}
+pub struct Config;
+pub enum Mode {}
+pub trait Visit {}
+pub type Id = u32;
+pub const MAX: usize = 3;
+pub static mut COUNT: u32 = 0;
+    pub async fn load() {}
+pub const unsafe fn raw() {}
+pub extern \"C\" fn ffi() {}
+";
+
+    assert_eq!(
+        names(Lang::Rust, content),
+        [
+            "parse", "Config", "Mode", "Visit", "Id", "MAX", "COUNT", "load", "raw", "ffi"
+        ]
+    );
+}
+
+#[test]
+fn rust_restricted_and_reexported_items_are_not_exports() {
+    let content = "\
+pub(crate) fn internal() {}
+pub(super) struct Parent;
+fn private() {}
+pub use crate::other::Thing;
+pub mod nested;
+pub fn main() {}
+";
+
+    assert!(names(Lang::Rust, content).is_empty());
+}
+
+#[test]
+fn export_lines_are_one_based() {
+    let found = exports(Lang::Rust, "// header\n\npub fn parse() {}\n");
+
+    assert_eq!(
+        found,
+        [Export {
+            line: 3,
+            name: "parse".to_string()
+        }]
+    );
+}
+
+// =============================================================================
+// JAVASCRIPT
+// =============================================================================
+
+#[test]
+fn js_named_exports_are_exports() {
+    let content = "\
+export function render() {}
+export async function load() {}
+export const API_URL = '';
+export class Widget {}
+export interface Props {}
+export type Id = string;
+export abstract class Base {}
+export declare const VERSION: string;
+";
+
+    assert_eq!(
+        names(Lang::JavaScript, content),
+        [
+            "render", "load", "API_URL", "Widget", "Props", "Id", "Base", "VERSION"
+        ]
+    );
+}
+
+#[test]
+fn js_default_and_list_exports_are_not_exports() {
+    let content = "\
+export default function App() {}
+export { a, b };
+export * from './other';
+const local = 1;
+";
+
+    assert!(names(Lang::JavaScript, content).is_empty());
+}
+
+// =============================================================================
+// GO
+// =============================================================================
+
+#[test]
+fn go_top_level_exported_declarations_are_exports() {
+    let content = "\
+package parser
+
+func Parse(s string) error { return nil }
+func parse() {}
+func (p *Parser) Method() {}
+type Parser struct{}
+var Default = Parser{}
+const Version = \"1\"
+func Map[T any](xs []T) {}
+";
+
+    assert_eq!(
+        names(Lang::Go, content),
+        ["Parse", "Parser", "Default", "Version", "Map"]
+    );
+}
+
+#[test]
+fn go_grouped_declarations_are_exports() {
+    let content = "\
+const (
+\tModeFast = iota
+\tmodeSlow
+\tModeCI
+)
+
+func helper() {}
+";
+
+    assert_eq!(names(Lang::Go, content), ["ModeFast", "ModeCI"]);
+}
+
+// =============================================================================
+// IDENTIFIERS
+// =============================================================================
+
+#[test]
+fn identifiers_split_on_punctuation() {
+    let words: Vec<_> = identifiers("parser::parse(x.$el, 42, r#type)").collect();
+
+    assert_eq!(words, ["parser", "parse", "x", "$el", "r", "type"]);
+}
+
+#[test]
+fn lang_from_path_uses_extension() {
+    assert_eq!(Lang::from_path(Path::new("src/lib.rs")), Some(Lang::Rust));
+    assert_eq!(
+        Lang::from_path(Path::new("web/app.tsx")),
+        Some(Lang::JavaScript)
+    );
+    assert_eq!(Lang::from_path(Path::new("cmd/main.go")), Some(Lang::Go));
+    assert_eq!(Lang::from_path(Path::new("README.md")), None);
+}
@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unused public item check.
+//!
+//! Finds exported items nothing else mentions: Rust `pub` items referenced
+//! nowhere in the workspace, JS/TS exports no other file names, and Go
+//! exported identifiers unused outside their package, per
+//! docs/specs/checks/deadcode.md. Counts per language feed the `deadcode`
+//! ratchet.
+
+mod exports;
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::adapter::glob::build_glob_set;
+use crate::adapter::{AdapterRegistry, FileKind};
+use crate::check::{Check, CheckContext, CheckResult, Violation};
+use crate::config::CheckLevel;
+use crate::metrics::{self, CheckMetrics};
+
+use exports::{Export, Lang};
+
+/// The deadcode check reports exported items with no references.
+pub struct DeadcodeCheck;
+
+impl Check for DeadcodeCheck {
+    fn name(&self) -> &'static str {
+        "deadcode"
+    }
+
+    fn description(&self) -> &'static str {
+        "Unused public items"
+    }
+
+    fn default_enabled(&self) -> bool {
+        false
+    }
+
+    // An item's uses can be in any file, changed or not
+    fn cacheable(&self) -> bool {
+        false
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let config = &ctx.config.check.deadcode;
+        if config.check == CheckLevel::Off {
+            return CheckResult::passed(self.name());
+        }
+
+        let registry = AdapterRegistry::for_project_with_config(ctx.root, ctx.config);
+        let exclude = build_glob_set(&config.exclude);
+        let mut sources = Vec::new();
+        for file in ctx.files {
+            let relative = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
+            let Some(lang) = Lang::from_path(relative) else {
+                continue;
+            };
+            let Ok(content) = ctx.read_file(&file.path) else {
+                continue;
+            };
+            let Some(text) = content.as_str() else {
+                continue;
+            };
+            sources.push(Source {
+                path: relative.to_path_buf(),
+                lang,
+                // Test helpers are exported for their own suite, not for callers
+                defines: registry.classify(relative) != FileKind::Test
+                    && !exclude.is_match(relative),
+                content: text.to_string(),
+            });
+        }
+
+        let found = find_dead(&sources);
+        let mut dead: BTreeMap<String, usize> = found
+            .exports
+            .keys()
+            .map(|lang| (lang.to_string(), 0))
+            .collect();
+        let mut violations = Vec::new();
+        for item in &found.dead {
+            *dead.entry(item.lang.name().to_string()).or_default() += 1;
+            violations.push(
+                Violation::file(item.path, item.export.line, "dead_code", advice(item.lang))
+                    .with_target(item.export.name.clone()),
+            );
+        }
+
+        if let Some(limit) = ctx.limit {
+            violations.truncate(limit);
+        }
+        let metrics = metrics::Deadcode {
+            exports: found.exports.values().sum(),
+            dead,
+        }
+        .to_json();
+
+        if violations.is_empty() {
+            CheckResult::passed(self.name()).with_metrics(metrics)
+        } else if config.check == CheckLevel::Warn {
+            CheckResult::passed_with_warnings(self.name(), violations).with_metrics(metrics)
+        } else {
+            CheckResult::failed(self.name(), violations).with_metrics(metrics)
+        }
+    }
+}
+
+fn advice(lang: Lang) -> &'static str {
+    match lang {
+        Lang::Rust => {
+            "Remove the unused item, or narrow it to pub(crate) so the compiler tracks its use."
+        }
+        Lang::JavaScript => "Remove the unused export, or drop `export` if only this file uses it.",
+        Lang::Go => "Remove the unused identifier, or unexport it if only its package uses it.",
+    }
+}
+
+/// A scanned source file.
+struct Source {
+    /// Path relative to the project root.
+    path: PathBuf,
+    lang: Lang,
+    /// Whether exports defined here are reported (references always count).
+    defines: bool,
+    content: String,
+}
+
+/// An export with no references.
+struct DeadItem<'a> {
+    path: &'a Path,
+    lang: Lang,
+    export: Export,
+}
+
+/// Exports scanned per language, and the unused ones ordered by path and line.
+struct Found<'a> {
+    exports: BTreeMap<&'static str, usize>,
+    dead: Vec<DeadItem<'a>>,
+}
+
+/// Find exports nothing references.
+///
+/// - Rust: the name appears nowhere but its definition.
+/// - JS/TS: the name appears in no other file.
+/// - Go: the name appears in no file outside the defining directory.
+fn find_dead(sources: &[Source]) -> Found<'_> {
+    let mut exports = BTreeMap::new();
+    let mut candidates = Vec::new();
+    for (idx, source) in sources.iter().enumerate().filter(|(_, s)| s.defines) {
+        let found = exports::exports(source.lang, &source.content);
+        if !found.is_empty() {
+            *exports.entry(source.lang.name()).or_default() += found.len();
+        }
+        candidates.extend(found.into_iter().map(|export| (idx, export)));
+    }
+
+    let names: HashSet<String> = candidates.iter().map(|(_, e)| e.name.clone()).collect();
+    let mut references: HashMap<&str, Vec<(usize, usize)>> = HashMap::new();
+    for (idx, source) in sources.iter().enumerate() {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for word in exports::identifiers(&source.content) {
+            if let Some(name) = names.get(word) {
+                *counts.entry(name.as_str()).or_default() += 1;
+            }
+        }
+        for (name, count) in counts {
+            references.entry(name).or_default().push((idx, count));
+        }
+    }
+
+    let mut dead: Vec<DeadItem> = candidates
+        .into_iter()
+        .filter(|(idx, export)| {
+            let source = &sources[*idx];
+            let refs = references
+                .get(export.name.as_str())
+                .map_or(&[][..], Vec::as_slice);
+            let uses = refs.iter().filter(|(other, _)| match source.lang {
+                Lang::Rust => true,
+                Lang::JavaScript => other != idx,
+                Lang::Go => sources[*other].path.parent() != source.path.parent(),
+            });
+            let count: usize = uses.map(|(_, count)| count).sum();
+            match source.lang {
+                // The definition itself is one mention
+                Lang::Rust => count <= 1,
+                Lang::JavaScript | Lang::Go => count == 0,
+            }
+        })
+        .map(|(idx, export)| DeadItem {
+            path: &sources[idx].path,
+            lang: sources[idx].lang,
+            export,
+        })
+        .collect();
+    dead.sort_by(|a, b| (a.path, a.export.line).cmp(&(b.path, b.export.line)));
+
+    Found { exports, dead }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn source(path: &str, content: &str) -> Source {
+    Source {
+        path: PathBuf::from(path),
+        lang: Lang::from_path(Path::new(path)).unwrap(),
+        defines: true,
+        content: content.to_string(),
+    }
+}
+
+fn dead_names(sources: &[Source]) -> Vec<String> {
+    find_dead(sources)
+        .dead
+        .into_iter()
+        .map(|item| item.export.name)
+        .collect()
+}
+
+#[test]
+fn rust_item_used_in_its_own_file_is_alive() {
+    let sources = [source(
+        "src/lib.rs",
+        "pub fn helper() {}\nfn run() { helper(); }\n",
+    )];
+
+    assert!(dead_names(&sources).is_empty());
+}
+
+#[test]
+fn rust_item_used_in_another_file_is_alive() {
+    let sources = [
+        source("src/parse.rs", "pub fn parse() {}\n"),
+        source("src/main.rs", "fn main() { parse::parse(); }\n"),
+    ];
+
+    assert!(dead_names(&sources).is_empty());
+}
+
+#[test]
+fn rust_item_never_mentioned_is_dead() {
+    let sources = [
+        source("src/parse.rs", "pub fn parse() {}\npub fn unused() {}\n"),
+        source("src/main.rs", "fn main() { parse(); }\n"),
+    ];
+
+    assert_eq!(dead_names(&sources), ["unused"]);
+}
+
+#[test]
+fn js_export_used_only_in_its_own_file_is_dead() {
+    let sources = [
+        source(
+            "src/util.ts",
+            "export function fmt() {}\nconst x = fmt();\n",
+        ),
+        source("src/app.ts", "export const app = 1;\n"),
+        source("src/index.ts", "import { app } from './app';\n"),
+    ];
+
+    assert_eq!(dead_names(&sources), ["fmt"]);
+}
+
+#[test]
+fn go_identifier_used_only_in_its_package_is_dead() {
+    let sources = [
+        source(
+            "pkg/parse/parse.go",
+            "package parse\n\nfunc Parse() {}\nfunc Used() {}\n",
+        ),
+        source(
+            "pkg/parse/helper.go",
+            "package parse\n\nfunc h() { Parse() }\n",
+        ),
+        source(
+            "cmd/main.go",
+            "package main\n\nfunc main() { parse.Used() }\n",
+        ),
+    ];
+
+    assert_eq!(dead_names(&sources), ["Parse"]);
+}
+
+#[test]
+fn references_from_non_defining_files_count() {
+    let mut test = source("tests/parse_test.rs", "pub fn fixture() { parse(); }\n");
+    test.defines = false;
+    let sources = [source("src/parse.rs", "pub fn parse() {}\n"), test];
+
+    let found = find_dead(&sources);
+
+    assert!(found.dead.is_empty());
+    assert_eq!(found.exports.get("rust"), Some(&1));
+}
+
+#[test]
+fn dead_items_are_ordered_by_path_and_line() {
+    let sources = [
+        source("src/b.rs", "pub fn b2() {}\npub fn b1() {}\n"),
+        source("src/a.rs", "pub fn a() {}\n"),
+    ];
+
+    assert_eq!(dead_names(&sources), ["a", "b2", "b1"]);
+}
@@ -0,0 +1,301 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Cargo features and the Rust code they gate.
+//!
+//! Each Cargo package's `[features]` are matched against the `cfg`,
+//! `cfg_attr`, and `cfg!` predicates in its Rust sources (and the
+//! `CARGO_FEATURE_*` variables build scripts read) to measure how much
+//! code sits behind features and to find features that gate nothing or
+//! were never declared.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use toml::Value;
+
+use crate::adapter::FeatureGates;
+use crate::metrics;
+
+/// Features a Cargo package declares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CargoFeatures {
+    /// `[package] name`.
+    pub package: String,
+    /// Features in `[features]`, in declaration order.
+    pub declared: Vec<Feature>,
+    /// Optional dependencies never named as `dep:<name>`, which Cargo
+    /// turns into features of the same name.
+    pub implicit: BTreeSet<String>,
+}
+
+/// A feature declared in `[features]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Feature {
+    pub name: String,
+    /// 1-based line of the declaration.
+    pub line: u32,
+    /// Features and dependencies the feature enables.
+    pub enables: Vec<String>,
+}
+
+impl CargoFeatures {
+    /// Whether code may name `feature` in a `cfg` predicate.
+    fn declares(&self, feature: &str) -> bool {
+        self.implicit.contains(feature) || self.declared.iter().any(|f| f.name == feature)
+    }
+}
+
+/// Features of the package a `Cargo.toml` defines (`None` for a virtual
+/// workspace manifest).
+pub fn parse(content: &str) -> Result<Option<CargoFeatures>, String> {
+    let manifest: toml::Table = content
+        .parse()
+        .map_err(|e: toml::de::Error| e.message().to_string())?;
+    let Some(package) = manifest
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(Value::as_str)
+    else {
+        return Ok(None);
+    };
+
+    let lines = feature_lines(content);
+    let mut declared: Vec<Feature> = manifest
+        .get("features")
+        .and_then(Value::as_table)
+        .into_iter()
+        .flatten()
+        .map(|(name, enables)| Feature {
+            name: name.clone(),
+            line: lines.get(name.as_str()).copied().unwrap_or(1),
+            enables: enables
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(String::from)
+                .collect(),
+        })
+        .collect();
+    // `toml` tables iterate by key; report features in file order
+    declared.sort_by_key(|f| f.line);
+
+    let explicit: BTreeSet<&str> = declared
+        .iter()
+        .flat_map(|f| &f.enables)
+        .filter_map(|enable| enable.strip_prefix("dep:"))
+        .collect();
+    let targets = manifest.get("target").and_then(Value::as_table);
+    let implicit = std::iter::once(&manifest)
+        .chain(
+            targets
+                .into_iter()
+                .flat_map(|t| t.values().filter_map(Value::as_table)),
+        )
+        .flat_map(|table| {
+            ["dependencies", "build-dependencies"]
+                .into_iter()
+                .filter_map(|section| table.get(section).and_then(Value::as_table))
+        })
+        .flatten()
+        .filter(|(name, spec)| {
+            spec.get("optional").and_then(Value::as_bool) == Some(true)
+                && !explicit.contains(name.as_str())
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    Ok(Some(CargoFeatures {
+        package: package.to_string(),
+        declared,
+        implicit,
+    }))
+}
+
+/// 1-based line of each key in the `[features]` table.
+fn feature_lines(content: &str) -> BTreeMap<&str, u32> {
+    let mut lines = BTreeMap::new();
+    let mut in_features = false;
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_features = trimmed == "[features]";
+            continue;
+        }
+        if !in_features {
+            continue;
+        }
+        if let Some((key, _)) = trimmed.split_once('=') {
+            let key = key.trim().trim_matches('"');
+            if !key.is_empty() && !key.starts_with('#') {
+                lines.entry(key).or_insert(idx as u32 + 1);
+            }
+        }
+    }
+    lines
+}
+
+/// Feature gates in one Rust source file.
+#[derive(Debug)]
+pub struct RustFile {
+    /// Path relative to the project root.
+    pub path: PathBuf,
+    pub gates: FeatureGates,
+    /// Non-blank lines inside gated items.
+    pub gated_lines: usize,
+    /// Non-blank lines in the file.
+    pub source_lines: usize,
+    /// `CARGO_FEATURE_<NAME>` variables the file mentions, by `<NAME>`.
+    pub env: BTreeSet<String>,
+}
+
+impl RustFile {
+    /// Parse a source file, or `None` when it doesn't parse.
+    pub fn parse(path: PathBuf, content: &str) -> Option<Self> {
+        let gates = FeatureGates::parse(content)?;
+        Some(Self {
+            path,
+            gated_lines: gates.lines(content),
+            source_lines: content.lines().filter(|l| !l.trim().is_empty()).count(),
+            env: env_features(content),
+            gates,
+        })
+    }
+
+    /// Directory holding the files of the file's out-of-line modules.
+    fn module_dir(&self) -> PathBuf {
+        let parent = self.path.parent().unwrap_or(Path::new(""));
+        match self.path.file_name().and_then(|n| n.to_str()) {
+            Some("lib.rs" | "main.rs" | "mod.rs") => parent.to_path_buf(),
+            _ => self.path.with_extension(""),
+        }
+    }
+}
+
+/// Names following `CARGO_FEATURE_`, which Cargo sets for build scripts.
+fn env_features(content: &str) -> BTreeSet<String> {
+    content
+        .match_indices("CARGO_FEATURE_")
+        .map(|(idx, prefix)| {
+            content[idx + prefix.len()..]
+                .chars()
+                .take_while(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || *c == '_')
+                .collect::<String>()
+        })
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// A Cargo package: its directory, manifest, and features.
+#[derive(Debug)]
+pub struct Package {
+    /// Directory relative to the project root (empty for the root).
+    pub dir: PathBuf,
+    pub manifest: PathBuf,
+    pub features: CargoFeatures,
+}
+
+/// Feature gate totals and findings for one package.
+#[derive(Debug)]
+pub struct Audit<'a> {
+    pub package: &'a Package,
+    pub metrics: metrics::Features,
+    /// Declared features that enable nothing and no code names.
+    pub unused: Vec<&'a Feature>,
+    /// Features code names that the package never declares: file, 1-based
+    /// line, and feature.
+    pub undeclared: Vec<(&'a Path, u32, &'a str)>,
+}
+
+/// Match each package's features against the sources under its directory.
+///
+/// A source belongs to the innermost package containing it. Files of a
+/// gated `mod name;` are gated in full: their lines count, but only the
+/// declaration counts as an item.
+pub fn audit<'a>(packages: &'a [Package], sources: &'a [RustFile]) -> Vec<Audit<'a>> {
+    let gated_modules: Vec<PathBuf> = sources
+        .iter()
+        .flat_map(|source| {
+            let dir = source.module_dir();
+            source.gates.modules.iter().map(move |name| dir.join(name))
+        })
+        .collect();
+    let in_gated_module = |path: &Path| {
+        gated_modules
+            .iter()
+            .any(|module| path == module.with_extension("rs") || path.starts_with(module))
+    };
+
+    let mut by_package: Vec<Vec<&RustFile>> = vec![Vec::new(); packages.len()];
+    for source in sources {
+        let owner = packages
+            .iter()
+            .enumerate()
+            .filter(|(_, package)| source.path.starts_with(&package.dir))
+            .max_by_key(|(_, package)| package.dir.components().count());
+        if let Some((idx, _)) = owner {
+            by_package[idx].push(source);
+        }
+    }
+
+    packages
+        .iter()
+        .zip(by_package)
+        .map(|(package, sources)| {
+            let features = &package.features;
+            let mut metrics = metrics::Features {
+                declared: features.declared.len(),
+                ..Default::default()
+            };
+            let mut used = BTreeSet::new();
+            let mut undeclared = Vec::new();
+            for source in sources {
+                metrics.source_lines += source.source_lines;
+                if in_gated_module(&source.path) {
+                    metrics.gated_lines += source.source_lines;
+                } else {
+                    metrics.gated_lines += source.gated_lines;
+                    metrics.gated_items += source.gates.item_count();
+                }
+                for (name, line) in &source.gates.uses {
+                    used.insert(name.as_str());
+                    if !features.declares(name) {
+                        undeclared.push((source.path.as_path(), *line as u32 + 1, name.as_str()));
+                    }
+                }
+                used.extend(
+                    features
+                        .declared
+                        .iter()
+                        .map(|f| f.name.as_str())
+                        .filter(|name| source.env.contains(&name.to_uppercase().replace('-', "_"))),
+                );
+            }
+            undeclared.dedup();
+
+            let unused: Vec<&Feature> = features
+                .declared
+                .iter()
+                .filter(|f| f.name != "default" && f.enables.is_empty())
+                .filter(|f| !used.contains(f.name.as_str()))
+                .collect();
+            metrics.unused = unused.len();
+            metrics.undeclared = undeclared
+                .iter()
+                .map(|(_, _, name)| *name)
+                .collect::<BTreeSet<_>>()
+                .len();
+            Audit {
+                package,
+                metrics,
+                unused,
+                undeclared,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "features_tests.rs"]
+mod tests;
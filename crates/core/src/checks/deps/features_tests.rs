@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn package(dir: &str, manifest: &str) -> Package {
+    Package {
+        dir: PathBuf::from(dir),
+        manifest: Path::new(dir).join("Cargo.toml"),
+        features: parse(manifest).unwrap().unwrap(),
+    }
+}
+
+fn source(path: &str, content: &str) -> RustFile {
+    RustFile::parse(PathBuf::from(path), content).unwrap()
+}
+
+const MANIFEST: &str = r#"[package]
+name = "demo"
+version = "0.1.0"
+
+[features]
+default = ["std"]
+std = []
+serde = ["dep:serde"]
+# a comment
+"fast-math" = []
+cli = []
+
+[dependencies]
+serde = { version = "1", optional = true }
+rayon = { version = "1", optional = true }
+"#;
+
+#[test]
+fn parses_features_with_lines_and_implicit_optional_deps() {
+    let features = parse(MANIFEST).unwrap().unwrap();
+
+    assert_eq!(features.package, "demo");
+    let declared: Vec<(&str, u32)> = features
+        .declared
+        .iter()
+        .map(|f| (f.name.as_str(), f.line))
+        .collect();
+    assert_eq!(
+        declared,
+        [
+            ("default", 6),
+            ("std", 7),
+            ("serde", 8),
+            ("fast-math", 10),
+            ("cli", 11)
+        ]
+    );
+    assert_eq!(features.declared[0].enables, ["std"]);
+    // serde is named as dep:serde, so only rayon is an implicit feature
+    assert_eq!(features.implicit, BTreeSet::from(["rayon".to_string()]));
+}
+
+#[test]
+fn virtual_manifest_has_no_features() {
+    let manifest = "[workspace]\nmembers = [\"crates/*\"]\n";
+    assert_eq!(parse(manifest).unwrap(), None);
+}
+
+#[test]
+fn audit_counts_gated_code_and_finds_unused_and_undeclared() {
+    let packages = [package("", MANIFEST)];
+    let sources = [source(
+        "src/lib.rs",
+        r#"pub fn always() {}
+
+#[cfg(feature = "std")]
+pub fn with_std() {
+    todo!()
+}
+
+#[cfg(any(feature = "rayon", feature = "paralel"))]
+pub fn parallel() {}
+"#,
+    )];
+    let audits = audit(&packages, &sources);
+    let audit = &audits[0];
+
+    assert_eq!(
+        audit.metrics,
+        metrics::Features {
+            declared: 5,
+            unused: 2,
+            undeclared: 1,
+            gated_items: 2,
+            gated_lines: 6,
+            source_lines: 7,
+        }
+    );
+    let unused: Vec<&str> = audit.unused.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(unused, ["fast-math", "cli"]);
+    assert_eq!(audit.undeclared, [(Path::new("src/lib.rs"), 8, "paralel")]);
+}
+
+#[test]
+fn gated_module_files_count_in_full() {
+    let packages = [package("", MANIFEST)];
+    let sources = [
+        source("src/lib.rs", "#[cfg(feature = \"cli\")]\nmod cli;\n"),
+        source("src/cli.rs", "mod args;\n\npub fn run() {}\n"),
+        source("src/cli/args.rs", "pub struct Args;\n"),
+        source("src/other.rs", "pub fn other() {}\n"),
+    ];
+    let metrics = &audit(&packages, &sources)[0].metrics;
+
+    assert_eq!(metrics.gated_items, 1);
+    assert_eq!(metrics.gated_lines, 2 + 2 + 1);
+    assert_eq!(metrics.source_lines, 2 + 2 + 1 + 1);
+}
+
+#[test]
+fn build_script_env_vars_use_features() {
+    let packages = [package("", MANIFEST)];
+    let sources = [source(
+        "build.rs",
+        "fn main() {\n    if std::env::var(\"CARGO_FEATURE_FAST_MATH\").is_ok() {}\n}\n",
+    )];
+    let audits = audit(&packages, &sources);
+
+    let unused: Vec<&str> = audits[0].unused.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(unused, ["std", "cli"]);
+}
+
+#[test]
+fn sources_belong_to_the_innermost_package() {
+    let packages = [
+        package("", "[package]\nname = \"root\"\n"),
+        package(
+            "crates/inner",
+            "[package]\nname = \"inner\"\n[features]\nx = []\n",
+        ),
+    ];
+    let sources = [source(
+        "crates/inner/src/lib.rs",
+        "#[cfg(feature = \"x\")]\nfn x() {}\n",
+    )];
+    let audits = audit(&packages, &sources);
+
+    assert_eq!(audits[0].metrics.source_lines, 0);
+    assert!(audits[0].undeclared.is_empty());
+    assert_eq!(audits[1].metrics.gated_items, 1);
+    assert!(audits[1].unused.is_empty());
+}
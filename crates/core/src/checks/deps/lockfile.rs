@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Resolved packages in `Cargo.lock` and `package-lock.json`.
+
+use std::collections::HashMap;
+
+use toml::Value;
+
+use super::manifest::{Ecosystem, json_key_lines};
+
+/// A package resolved in a lockfile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Package {
+    pub name: String,
+    pub version: String,
+    /// 1-based line of the entry.
+    pub line: u32,
+}
+
+/// Ecosystem of a lockfile name.
+pub fn ecosystem(file_name: &str) -> Option<Ecosystem> {
+    match file_name {
+        "Cargo.lock" => Some(Ecosystem::Cargo),
+        "package-lock.json" => Some(Ecosystem::Npm),
+        _ => None,
+    }
+}
+
+/// Lockfile name of an ecosystem (Go has none).
+pub fn file_name(ecosystem: Ecosystem) -> Option<&'static str> {
+    match ecosystem {
+        Ecosystem::Cargo => Some("Cargo.lock"),
+        Ecosystem::Npm => Some("package-lock.json"),
+        Ecosystem::Go => None,
+    }
+}
+
+/// Packages a lockfile resolves from outside the repository.
+pub fn parse(ecosystem: Ecosystem, content: &str) -> Result<Vec<Package>, String> {
+    match ecosystem {
+        Ecosystem::Cargo => cargo(content),
+        Ecosystem::Npm => npm(content),
+        Ecosystem::Go => Ok(Vec::new()),
+    }
+}
+
+fn cargo(content: &str) -> Result<Vec<Package>, String> {
+    let lock: toml::Table = content
+        .parse()
+        .map_err(|e: toml::de::Error| e.message().to_string())?;
+    let lines = cargo_entry_lines(content);
+    let entries = lock.get("package").and_then(Value::as_array);
+    Ok(entries
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_table)
+        // Workspace members have no source
+        .filter(|entry| entry.contains_key("source"))
+        .filter_map(|entry| {
+            let name = entry.get("name")?.as_str()?;
+            let version = entry.get("version")?.as_str()?;
+            let line = lines.get(&(name, version)).copied().unwrap_or(1);
+            Some(Package {
+                name: name.to_string(),
+                version: version.to_string(),
+                line,
+            })
+        })
+        .collect())
+}
+
+/// Line of each `name = ".."` followed by its `version = ".."`.
+fn cargo_entry_lines(content: &str) -> HashMap<(&str, &str), u32> {
+    let mut lines = HashMap::new();
+    let mut name = None;
+    for (idx, line) in content.lines().enumerate() {
+        if let Some(value) = toml_string(line, "name") {
+            name = Some((value, idx as u32 + 1));
+        } else if let (Some(version), Some((name, line))) = (toml_string(line, "version"), name) {
+            lines.entry((name, version)).or_insert(line);
+        }
+    }
+    lines
+}
+
+/// The value of a `key = "value"` line.
+fn toml_string<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let value = line.strip_prefix(key)?.trim_start().strip_prefix('=')?;
+    value.trim().strip_prefix('"')?.strip_suffix('"')
+}
+
+fn npm(content: &str) -> Result<Vec<Package>, String> {
+    let lock: serde_json::Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+    let lines = json_key_lines(content);
+    let mut packages = Vec::new();
+    if let Some(entries) = lock.get("packages").and_then(|v| v.as_object()) {
+        // lockfileVersion 2 and 3: keyed by install path
+        for (path, entry) in entries {
+            // The root package and workspace members aren't under node_modules
+            let Some(idx) = path.rfind("node_modules/") else {
+                continue;
+            };
+            if entry.get("link").and_then(|v| v.as_bool()) == Some(true) {
+                continue;
+            }
+            let Some(version) = entry.get("version").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            packages.push(Package {
+                name: path[idx + "node_modules/".len()..].to_string(),
+                version: version.to_string(),
+                line: lines.get(path.as_str()).copied().unwrap_or(1),
+            });
+        }
+    } else if let Some(entries) = lock.get("dependencies").and_then(|v| v.as_object()) {
+        // lockfileVersion 1: nested by dependent
+        npm_v1(&lines, entries, &mut packages);
+    }
+    Ok(packages)
+}
+
+fn npm_v1(
+    lines: &HashMap<&str, u32>,
+    entries: &serde_json::Map<String, serde_json::Value>,
+    packages: &mut Vec<Package>,
+) {
+    for (name, entry) in entries {
+        if let Some(version) = entry.get("version").and_then(|v| v.as_str())
+            && !version.starts_with("file:")
+        {
+            packages.push(Package {
+                name: name.clone(),
+                version: version.to_string(),
+                line: lines.get(name.as_str()).copied().unwrap_or(1),
+            });
+        }
+        if let Some(nested) = entry.get("dependencies").and_then(|v| v.as_object()) {
+            npm_v1(lines, nested, packages);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "lockfile_tests.rs"]
+mod tests;
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn versions(packages: &[Package]) -> Vec<(&str, &str, u32)> {
+    packages
+        .iter()
+        .map(|p| (p.name.as_str(), p.version.as_str(), p.line))
+        .collect()
+}
+
+#[test]
+fn lockfile_names_map_to_ecosystems() {
+    assert_eq!(ecosystem("Cargo.lock"), Some(Ecosystem::Cargo));
+    assert_eq!(ecosystem("package-lock.json"), Some(Ecosystem::Npm));
+    assert_eq!(ecosystem("yarn.lock"), None);
+}
+
+#[test]
+fn cargo_lock_skips_workspace_members() {
+    let content = "\
+version = 3
+
+[[package]]
+name = \"app\"
+version = \"0.1.0\"
+dependencies = [\"syn\"]
+
+[[package]]
+name = \"syn\"
+version = \"1.0.109\"
+source = \"registry+https://github.com/rust-lang/crates.io-index\"
+
+[[package]]
+name = \"syn\"
+version = \"2.0.48\"
+source = \"registry+https://github.com/rust-lang/crates.io-index\"
+";
+    let packages = parse(Ecosystem::Cargo, content).unwrap();
+
+    assert_eq!(
+        versions(&packages),
+        [("syn", "1.0.109", 9), ("syn", "2.0.48", 14)]
+    );
+}
+
+#[test]
+fn package_lock_v3_reads_installed_packages() {
+    let content = r#"{
+  "name": "web",
+  "lockfileVersion": 3,
+  "packages": {
+    "": {
+      "name": "web"
+    },
+    "node_modules/react": {
+      "version": "18.2.0"
+    },
+    "node_modules/@acme/ui": {
+      "version": "1.0.0"
+    },
+    "node_modules/@acme/ui/node_modules/react": {
+      "version": "17.0.2"
+    },
+    "node_modules/member": {
+      "resolved": "packages/member",
+      "link": true
+    },
+    "packages/member": {
+      "version": "0.1.0"
+    }
+  }
+}"#;
+    let packages = parse(Ecosystem::Npm, content).unwrap();
+
+    assert_eq!(
+        versions(&packages),
+        [
+            ("@acme/ui", "1.0.0", 11),
+            ("react", "17.0.2", 14),
+            ("react", "18.2.0", 8),
+        ]
+    );
+}
+
+#[test]
+fn package_lock_v1_reads_nested_dependencies() {
+    let content = r#"{
+  "lockfileVersion": 1,
+  "dependencies": {
+    "lodash": {
+      "version": "4.17.21"
+    },
+    "old": {
+      "version": "1.0.0",
+      "dependencies": {
+        "lodash": {
+          "version": "3.10.1"
+        }
+      }
+    },
+    "local": {
+      "version": "file:../local"
+    }
+  }
+}"#;
+    let mut packages = parse(Ecosystem::Npm, content).unwrap();
+    packages.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+
+    let names: Vec<(&str, &str)> = packages
+        .iter()
+        .map(|p| (p.name.as_str(), p.version.as_str()))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            ("lodash", "3.10.1"),
+            ("lodash", "4.17.21"),
+            ("old", "1.0.0")
+        ]
+    );
+}
+
+#[test]
+fn invalid_lockfile_is_an_error() {
+    assert!(parse(Ecosystem::Cargo, "[[package]\n").is_err());
+    assert!(parse(Ecosystem::Npm, "{").is_err());
+}
@@ -0,0 +1,302 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Dependency declarations in `Cargo.toml`, `package.json`, and `go.mod`.
+
+use std::collections::HashMap;
+
+use toml::Value;
+
+/// Package ecosystem of a manifest or lockfile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Ecosystem {
+    Cargo,
+    Npm,
+    Go,
+}
+
+impl Ecosystem {
+    /// Name used in metrics and the baseline.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Cargo => "cargo",
+            Self::Npm => "npm",
+            Self::Go => "go",
+        }
+    }
+
+    /// Ecosystem of a manifest file name.
+    pub fn from_manifest(file_name: &str) -> Option<Self> {
+        match file_name {
+            "Cargo.toml" => Some(Self::Cargo),
+            "package.json" => Some(Self::Npm),
+            "go.mod" => Some(Self::Go),
+            _ => None,
+        }
+    }
+}
+
+/// Where a declared dependency comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A registry version requirement (or Go module version).
+    Registry(String),
+    /// A git repository, and whether it's pinned to a commit.
+    Git { url: String, pinned: bool },
+    /// A path or workspace package in the same repository.
+    Local,
+    /// Inherited from `[workspace.dependencies]` (`dep.workspace = true`).
+    Workspace,
+}
+
+/// How a dependency is declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// Used by the package: normal, dev, build, or optional.
+    Direct,
+    /// Shared declaration in `[workspace.dependencies]`, used by members.
+    Shared,
+    /// Go module required only by other dependencies (`// indirect`).
+    Indirect,
+}
+
+/// A dependency declared in a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dependency {
+    pub name: String,
+    /// 1-based line of the declaration.
+    pub line: u32,
+    pub source: Source,
+    pub kind: Kind,
+}
+
+/// Cargo tables holding dependencies, at the top level or under `[target.*]`.
+const CARGO_SECTIONS: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// package.json objects holding dependencies. Peer dependencies are left
+/// out: they state compatibility, so broad ranges are expected.
+const NPM_SECTIONS: &[&str] = &["dependencies", "devDependencies", "optionalDependencies"];
+
+/// npm specifier prefixes for packages in the same repository.
+const NPM_LOCAL_PREFIXES: &[&str] = &["file:", "link:", "workspace:", "portal:"];
+
+/// npm specifier prefixes for git repositories.
+const NPM_GIT_PREFIXES: &[&str] = &[
+    "git+",
+    "git://",
+    "github:",
+    "gitlab:",
+    "bitbucket:",
+    "gist:",
+];
+
+/// Dependencies declared in a manifest.
+pub fn parse(ecosystem: Ecosystem, content: &str) -> Result<Vec<Dependency>, String> {
+    match ecosystem {
+        Ecosystem::Cargo => cargo(content),
+        Ecosystem::Npm => npm(content),
+        Ecosystem::Go => Ok(go(content)),
+    }
+}
+
+fn cargo(content: &str) -> Result<Vec<Dependency>, String> {
+    let manifest: toml::Table = content
+        .parse()
+        .map_err(|e: toml::de::Error| e.message().to_string())?;
+
+    let targets = manifest.get("target").and_then(Value::as_table);
+    let mut tables: Vec<(&toml::Table, Kind)> = std::iter::once(&manifest)
+        .chain(
+            targets
+                .into_iter()
+                .flat_map(|t| t.values().filter_map(Value::as_table)),
+        )
+        .flat_map(|table| {
+            CARGO_SECTIONS
+                .iter()
+                .filter_map(|section| table.get(*section).and_then(Value::as_table))
+        })
+        .map(|deps| (deps, Kind::Direct))
+        .collect();
+    if let Some(shared) = manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("dependencies"))
+        .and_then(Value::as_table)
+    {
+        tables.push((shared, Kind::Shared));
+    }
+
+    let mut deps = Vec::new();
+    for (table, kind) in tables {
+        for (name, spec) in table {
+            deps.push(Dependency {
+                name: name.clone(),
+                line: toml_key_line(content, name),
+                source: cargo_source(spec),
+                kind,
+            });
+        }
+    }
+    Ok(deps)
+}
+
+fn cargo_source(spec: &Value) -> Source {
+    let Some(table) = spec.as_table() else {
+        return Source::Registry(spec.as_str().unwrap_or_default().to_string());
+    };
+    if table.get("workspace").and_then(Value::as_bool) == Some(true) {
+        Source::Workspace
+    } else if let Some(url) = table.get("git").and_then(Value::as_str) {
+        // A branch or tag can move; only a rev names a commit
+        Source::Git {
+            url: url.to_string(),
+            pinned: table.contains_key("rev"),
+        }
+    } else if table.contains_key("path") {
+        Source::Local
+    } else {
+        // Cargo reads a missing version as "*"
+        let version = table.get("version").and_then(Value::as_str).unwrap_or("*");
+        Source::Registry(version.to_string())
+    }
+}
+
+/// First line declaring `name` as a key (`name = ..`, `name.workspace = ..`)
+/// or a table (`[dependencies.name]`), or 1 if none does.
+fn toml_key_line(content: &str, name: &str) -> u32 {
+    let quoted = format!("\"{}\"", name);
+    let table_suffix = format!(".{}]", name);
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        let rest = trimmed
+            .strip_prefix(quoted.as_str())
+            .or_else(|| trimmed.strip_prefix(name));
+        let is_key = rest.is_some_and(|rest| rest.trim_start().starts_with(['=', '.']));
+        if is_key || (trimmed.starts_with('[') && trimmed.ends_with(table_suffix.as_str())) {
+            return idx as u32 + 1;
+        }
+    }
+    1
+}
+
+fn npm(content: &str) -> Result<Vec<Dependency>, String> {
+    let manifest: serde_json::Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+    let lines = json_key_lines(content);
+    let mut deps = Vec::new();
+    for section in NPM_SECTIONS {
+        let Some(entries) = manifest.get(*section).and_then(|v| v.as_object()) else {
+            continue;
+        };
+        for (name, spec) in entries {
+            deps.push(Dependency {
+                name: name.clone(),
+                line: lines.get(name.as_str()).copied().unwrap_or(1),
+                source: npm_source(spec.as_str().unwrap_or_default()),
+                kind: Kind::Direct,
+            });
+        }
+    }
+    Ok(deps)
+}
+
+fn npm_source(spec: &str) -> Source {
+    let spec = spec.trim();
+    if NPM_LOCAL_PREFIXES.iter().any(|p| spec.starts_with(p)) {
+        return Source::Local;
+    }
+    let (url, fragment) = match spec.split_once('#') {
+        Some((url, fragment)) => (url, Some(fragment)),
+        None => (spec, None),
+    };
+    let is_git = NPM_GIT_PREFIXES.iter().any(|p| spec.starts_with(p))
+        || (url.starts_with("https://") && url.ends_with(".git"))
+        || is_github_shorthand(url);
+    if is_git {
+        Source::Git {
+            url: url.to_string(),
+            pinned: fragment.is_some_and(is_commit),
+        }
+    } else {
+        Source::Registry(spec.to_string())
+    }
+}
+
+/// `owner/repo`, npm's shorthand for a GitHub repository.
+fn is_github_shorthand(spec: &str) -> bool {
+    let Some((owner, repo)) = spec.split_once('/') else {
+        return false;
+    };
+    let is_name = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+    is_name(owner) && is_name(repo)
+}
+
+/// A full or abbreviated commit hash.
+fn is_commit(rev: &str) -> bool {
+    (7..=40).contains(&rev.len()) && rev.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// First line of each `"key":` in JSON, for locating entries.
+pub(super) fn json_key_lines(content: &str) -> HashMap<&str, u32> {
+    let mut lines = HashMap::new();
+    for (idx, line) in content.lines().enumerate() {
+        let Some(rest) = line.trim_start().strip_prefix('"') else {
+            continue;
+        };
+        if let Some((key, after)) = rest.split_once('"')
+            && after.trim_start().starts_with(':')
+        {
+            lines.entry(key).or_insert(idx as u32 + 1);
+        }
+    }
+    lines
+}
+
+fn go(content: &str) -> Vec<Dependency> {
+    let mut deps = Vec::new();
+    let mut in_block = false;
+    for (idx, line) in content.lines().enumerate() {
+        let (code, comment) = line.split_once("//").unwrap_or((line, ""));
+        let code = code.trim();
+        let require = if in_block {
+            if code == ")" {
+                in_block = false;
+                continue;
+            }
+            code
+        } else if let Some(rest) = code.strip_prefix("require") {
+            let rest = rest.trim();
+            if rest == "(" {
+                in_block = true;
+                continue;
+            }
+            rest
+        } else {
+            continue;
+        };
+
+        let mut parts = require.split_whitespace();
+        let (Some(module), Some(version)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let indirect = comment.trim_start().starts_with("indirect");
+        deps.push(Dependency {
+            name: module.to_string(),
+            line: idx as u32 + 1,
+            source: Source::Registry(version.to_string()),
+            kind: if indirect {
+                Kind::Indirect
+            } else {
+                Kind::Direct
+            },
+        });
+    }
+    deps
+}
+
+#[cfg(test)]
+#[path = "manifest_tests.rs"]
+mod tests;
@@ -0,0 +1,211 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn find<'a>(deps: &'a [Dependency], name: &str) -> &'a Dependency {
+    deps.iter()
+        .find(|dep| dep.name == name)
+        .unwrap_or_else(|| panic!("no dependency {name} in {deps:?}"))
+}
+
+// =============================================================================
+// CARGO
+// =============================================================================
+
+#[test]
+fn cargo_reads_all_dependency_tables() {
+    let content = "\
+[package]
+name = \"app\"
+
+[dependencies]
+serde = \"1\"
+
+[dev-dependencies]
+tempfile = \"3\"
+
+[build-dependencies]
+cc = \"1\"
+
+[target.'cfg(unix)'.dependencies]
+libc = \"0.2\"
+";
+    let deps = parse(Ecosystem::Cargo, content).unwrap();
+
+    let mut names: Vec<&str> = deps.iter().map(|d| d.name.as_str()).collect();
+    names.sort_unstable();
+    assert_eq!(names, ["cc", "libc", "serde", "tempfile"]);
+    assert_eq!(find(&deps, "serde").line, 5);
+    assert_eq!(find(&deps, "libc").line, 14);
+    assert!(deps.iter().all(|d| d.kind == Kind::Direct));
+}
+
+#[test]
+fn cargo_sources() {
+    let content = "\
+[dependencies]
+plain = \"1.2\"
+table = { version = \"0.4\", features = [\"std\"] }
+bare = { features = [\"std\"] }
+local = { path = \"../local\" }
+inherited.workspace = true
+pinned = { git = \"https://example.com/pinned\", rev = \"0a1b2c3\" }
+branch = { git = \"https://example.com/branch\", branch = \"main\" }
+
+[dependencies.dotted]
+version = \"2\"
+";
+    let deps = parse(Ecosystem::Cargo, content).unwrap();
+
+    assert_eq!(find(&deps, "plain").source, Source::Registry("1.2".into()));
+    assert_eq!(find(&deps, "table").source, Source::Registry("0.4".into()));
+    assert_eq!(find(&deps, "bare").source, Source::Registry("*".into()));
+    assert_eq!(find(&deps, "local").source, Source::Local);
+    assert_eq!(find(&deps, "inherited").source, Source::Workspace);
+    assert_eq!(
+        find(&deps, "pinned").source,
+        Source::Git {
+            url: "https://example.com/pinned".into(),
+            pinned: true
+        }
+    );
+    assert!(matches!(
+        find(&deps, "branch").source,
+        Source::Git { pinned: false, .. }
+    ));
+    assert_eq!(find(&deps, "dotted").line, 10);
+}
+
+#[test]
+fn cargo_workspace_dependencies_are_shared() {
+    let content = "\
+[workspace]
+members = [\"crates/*\"]
+
+[workspace.dependencies]
+anyhow = \"1\"
+";
+    let deps = parse(Ecosystem::Cargo, content).unwrap();
+
+    assert_eq!(deps.len(), 1);
+    assert_eq!(deps[0].kind, Kind::Shared);
+    assert_eq!(deps[0].line, 5);
+}
+
+#[test]
+fn cargo_invalid_toml_is_an_error() {
+    assert!(parse(Ecosystem::Cargo, "[dependencies\n").is_err());
+}
+
+// =============================================================================
+// NPM
+// =============================================================================
+
+#[test]
+fn npm_reads_dependency_objects_but_not_peers() {
+    let content = r#"{
+  "name": "web",
+  "dependencies": {
+    "react": "^18.2.0"
+  },
+  "devDependencies": {
+    "vitest": "*"
+  },
+  "peerDependencies": {
+    "react-dom": "*"
+  }
+}"#;
+    let deps = parse(Ecosystem::Npm, content).unwrap();
+
+    assert_eq!(deps.len(), 2);
+    assert_eq!(find(&deps, "react").line, 4);
+    assert_eq!(find(&deps, "vitest").source, Source::Registry("*".into()));
+}
+
+#[test]
+fn npm_sources() {
+    let content = r#"{
+  "dependencies": {
+    "local": "file:../local",
+    "member": "workspace:*",
+    "short": "acme/short",
+    "short-pinned": "acme/short#0a1b2c3d",
+    "prefixed": "github:acme/prefixed#main",
+    "url": "git+https://example.com/url.git#v1.0.0",
+    "alias": "npm:@acme/real@^1.0.0"
+  }
+}"#;
+    let deps = parse(Ecosystem::Npm, content).unwrap();
+
+    assert_eq!(find(&deps, "local").source, Source::Local);
+    assert_eq!(find(&deps, "member").source, Source::Local);
+    assert_eq!(
+        find(&deps, "short").source,
+        Source::Git {
+            url: "acme/short".into(),
+            pinned: false
+        }
+    );
+    assert!(matches!(
+        find(&deps, "short-pinned").source,
+        Source::Git { pinned: true, .. }
+    ));
+    assert!(matches!(
+        find(&deps, "prefixed").source,
+        Source::Git { pinned: false, .. }
+    ));
+    // A tag isn't a commit
+    assert!(matches!(
+        find(&deps, "url").source,
+        Source::Git { pinned: false, .. }
+    ));
+    assert_eq!(
+        find(&deps, "alias").source,
+        Source::Registry("npm:@acme/real@^1.0.0".into())
+    );
+}
+
+#[test]
+fn npm_invalid_json_is_an_error() {
+    assert!(parse(Ecosystem::Npm, "{ \"dependencies\": ").is_err());
+}
+
+// =============================================================================
+// GO
+// =============================================================================
+
+#[test]
+fn go_reads_require_lines_and_blocks() {
+    let content = "\
+module example.com/app
+
+go 1.22
+
+require github.com/pkg/errors v0.9.1
+
+require (
+\tgolang.org/x/sync v0.6.0
+\tgolang.org/x/sys v0.17.0 // indirect
+)
+";
+    let deps = parse(Ecosystem::Go, content).unwrap();
+
+    assert_eq!(deps.len(), 3);
+    let errors = find(&deps, "github.com/pkg/errors");
+    assert_eq!(errors.line, 5);
+    assert_eq!(errors.source, Source::Registry("v0.9.1".into()));
+    assert_eq!(find(&deps, "golang.org/x/sync").kind, Kind::Direct);
+    assert_eq!(find(&deps, "golang.org/x/sys").kind, Kind::Indirect);
+    assert_eq!(find(&deps, "golang.org/x/sys").line, 9);
+}
+
+#[test]
+fn json_key_lines_keep_first_occurrence() {
+    let content = "{\n  \"a\": {\n    \"b\": 1\n  },\n  \"b\": 2\n}\n";
+    let lines = json_key_lines(content);
+
+    assert_eq!(lines.get("a"), Some(&2));
+    assert_eq!(lines.get("b"), Some(&3));
+}
@@ -0,0 +1,446 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Dependency hygiene check.
+//!
+//! Reads `Cargo.toml`, `package.json`, and `go.mod` manifests and the
+//! `Cargo.lock` and `package-lock.json` lockfiles, then flags wildcard
+//! versions, git dependencies not pinned to a commit, packages resolved at
+//! several versions, manifests over `max_dependencies`, Cargo features
+//! that gate no code, and `cfg` predicates naming undeclared features, per
+//! docs/specs/checks/deps.md. Dependency counts per ecosystem feed the
+//! `deps` ratchet.
+
+pub mod features;
+pub mod lockfile;
+pub mod manifest;
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+use globset::GlobSet;
+
+use crate::adapter::glob::build_glob_set;
+use crate::check::{Check, CheckContext, CheckResult, Field, Violation};
+use crate::config::{CheckLevel, DepsConfig};
+use crate::metrics::{self, CheckMetrics};
+
+use features::RustFile;
+use lockfile::Package;
+use manifest::{Dependency, Ecosystem, Kind, Source};
+
+/// The deps check audits declared and resolved dependencies.
+pub struct DepsCheck;
+
+impl Check for DepsCheck {
+    fn name(&self) -> &'static str {
+        "deps"
+    }
+
+    fn description(&self) -> &'static str {
+        "Dependency hygiene"
+    }
+
+    fn default_enabled(&self) -> bool {
+        false
+    }
+
+    // Counts and feature audits cover every manifest, lockfile, and source,
+    // changed or not
+    fn cacheable(&self) -> bool {
+        false
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let config = &ctx.config.check.deps;
+        if config.check == CheckLevel::Off {
+            return CheckResult::passed(self.name());
+        }
+
+        let scan = Scan::read(ctx, &build_glob_set(&config.exclude));
+        let duplicates = scan.duplicates(config);
+        let mut violations: Vec<Violation> = scan
+            .manifests
+            .iter()
+            .flat_map(|manifest| manifest_violations(manifest, config))
+            .collect();
+        if config.duplicates {
+            violations.extend(duplicates.iter().map(Duplicate::violation));
+        }
+        let audits = features::audit(&scan.packages, &scan.sources);
+        if config.features {
+            violations.extend(audits.iter().flat_map(feature_violations));
+        }
+        violations.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+
+        if let Some(limit) = ctx.limit {
+            violations.truncate(limit);
+        }
+        let metrics = metrics::Deps {
+            manifests: scan.manifests.len(),
+            direct: scan.direct_counts(),
+            total: scan.total_counts(),
+            duplicates: duplicates.len(),
+            features: audits
+                .into_iter()
+                .map(|audit| (audit.package.features.package.clone(), audit.metrics))
+                .collect(),
+        }
+        .to_json();
+
+        if violations.is_empty() {
+            CheckResult::passed(self.name()).with_metrics(metrics)
+        } else if config.check == CheckLevel::Warn {
+            CheckResult::passed_with_warnings(self.name(), violations).with_metrics(metrics)
+        } else {
+            CheckResult::failed(self.name(), violations).with_metrics(metrics)
+        }
+    }
+}
+
+/// A manifest and the dependencies it declares.
+struct Manifest {
+    /// Path relative to the project root.
+    path: PathBuf,
+    ecosystem: Ecosystem,
+    deps: Vec<Dependency>,
+}
+
+impl Manifest {
+    /// Names of the external dependencies the package itself uses.
+    fn declared(&self) -> BTreeSet<&str> {
+        self.deps
+            .iter()
+            .filter(|dep| dep.kind == Kind::Direct && dep.source != Source::Local)
+            .map(|dep| dep.name.as_str())
+            .collect()
+    }
+}
+
+/// A lockfile and the packages it resolves.
+struct Lockfile {
+    /// Path relative to the project root.
+    path: PathBuf,
+    ecosystem: Ecosystem,
+    packages: Vec<Package>,
+}
+
+impl Lockfile {
+    fn resolved(&self) -> impl Iterator<Item = Resolved<'_>> {
+        self.packages.iter().map(|package| Resolved {
+            path: &self.path,
+            line: package.line,
+            name: &package.name,
+            version: &package.version,
+        })
+    }
+}
+
+/// Manifests, lockfiles, Cargo packages, and Rust sources in the project,
+/// sorted by path.
+struct Scan {
+    manifests: Vec<Manifest>,
+    lockfiles: Vec<Lockfile>,
+    packages: Vec<features::Package>,
+    sources: Vec<RustFile>,
+}
+
+impl Scan {
+    fn read(ctx: &CheckContext, exclude: &GlobSet) -> Self {
+        let mut manifests = Vec::new();
+        let mut lockfiles = Vec::new();
+        let mut packages = Vec::new();
+        let mut sources = Vec::new();
+        for file in ctx.files {
+            let relative = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
+            let Some(name) = relative.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let manifest = Ecosystem::from_manifest(name);
+            let lock = lockfile::ecosystem(name);
+            let rust = relative.extension().is_some_and(|ext| ext == "rs");
+            if (manifest.is_none() && lock.is_none() && !rust) || exclude.is_match(relative) {
+                continue;
+            }
+            let Some(content) = read_text(ctx, &file.path) else {
+                continue;
+            };
+            let path = relative.to_path_buf();
+            if rust {
+                match RustFile::parse(path, &content) {
+                    Some(source) => sources.push(source),
+                    None => tracing::debug!("skipping {}: does not parse", relative.display()),
+                }
+            } else if let Some(ecosystem) = manifest {
+                if ecosystem == Ecosystem::Cargo {
+                    match features::parse(&content) {
+                        Ok(Some(features)) => packages.push(features::Package {
+                            dir: relative.parent().unwrap_or(Path::new("")).to_path_buf(),
+                            manifest: path.clone(),
+                            features,
+                        }),
+                        Ok(None) => {}
+                        Err(e) => tracing::debug!("skipping {}: {}", relative.display(), e),
+                    }
+                }
+                match manifest::parse(ecosystem, &content) {
+                    Ok(deps) => manifests.push(Manifest {
+                        path,
+                        ecosystem,
+                        deps,
+                    }),
+                    Err(e) => tracing::debug!("skipping {}: {}", relative.display(), e),
+                }
+            } else if let Some(ecosystem) = lock {
+                match lockfile::parse(ecosystem, &content) {
+                    Ok(packages) => lockfiles.push(Lockfile {
+                        path,
+                        ecosystem,
+                        packages,
+                    }),
+                    Err(e) => tracing::debug!("skipping {}: {}", relative.display(), e),
+                }
+            }
+        }
+        manifests.sort_by(|a, b| a.path.cmp(&b.path));
+        lockfiles.sort_by(|a, b| a.path.cmp(&b.path));
+        packages.sort_by(|a, b| a.manifest.cmp(&b.manifest));
+        sources.sort_by(|a, b| a.path.cmp(&b.path));
+        Self {
+            manifests,
+            lockfiles,
+            packages,
+            sources,
+        }
+    }
+
+    /// Ecosystems with a manifest, so a count that drops to zero is recorded.
+    fn ecosystems(&self) -> BTreeSet<Ecosystem> {
+        self.manifests.iter().map(|m| m.ecosystem).collect()
+    }
+
+    /// External dependencies declared across the workspace, per ecosystem.
+    ///
+    /// Packages of the workspace itself (path dependencies) don't count.
+    fn direct_counts(&self) -> BTreeMap<String, usize> {
+        self.ecosystems()
+            .into_iter()
+            .map(|ecosystem| (ecosystem.name().to_string(), self.direct(ecosystem).len()))
+            .collect()
+    }
+
+    fn direct(&self, ecosystem: Ecosystem) -> BTreeSet<&str> {
+        let deps = || {
+            self.manifests
+                .iter()
+                .filter(move |m| m.ecosystem == ecosystem)
+                .flat_map(|m| &m.deps)
+        };
+        let local: HashSet<&str> = deps()
+            .filter(|dep| dep.source == Source::Local)
+            .map(|dep| dep.name.as_str())
+            .collect();
+        deps()
+            .filter(|dep| dep.kind != Kind::Indirect && !local.contains(dep.name.as_str()))
+            .map(|dep| dep.name.as_str())
+            .collect()
+    }
+
+    /// Resolved packages per ecosystem: distinct name and version pairs in
+    /// lockfiles (`go.mod` lists them for Go), or the direct count without one.
+    fn total_counts(&self) -> BTreeMap<String, usize> {
+        self.ecosystems()
+            .into_iter()
+            .map(|ecosystem| {
+                let resolved: BTreeSet<(&str, &str)> = self
+                    .resolved(ecosystem)
+                    .iter()
+                    .map(|package| (package.name, package.version))
+                    .collect();
+                let count = if resolved.is_empty() {
+                    self.direct(ecosystem).len()
+                } else {
+                    resolved.len()
+                };
+                (ecosystem.name().to_string(), count)
+            })
+            .collect()
+    }
+
+    /// Every package version an ecosystem resolves, where it's resolved.
+    fn resolved(&self, ecosystem: Ecosystem) -> Vec<Resolved<'_>> {
+        if ecosystem == Ecosystem::Go {
+            // Go has no lockfile: each go.mod requires exact versions
+            return self
+                .manifests
+                .iter()
+                .filter(|m| m.ecosystem == Ecosystem::Go)
+                .flat_map(|m| {
+                    m.deps.iter().filter_map(move |dep| match &dep.source {
+                        Source::Registry(version) => Some(Resolved {
+                            path: &m.path,
+                            line: dep.line,
+                            name: &dep.name,
+                            version,
+                        }),
+                        _ => None,
+                    })
+                })
+                .collect();
+        }
+        self.lockfiles
+            .iter()
+            .filter(|lock| lock.ecosystem == ecosystem)
+            .flat_map(Lockfile::resolved)
+            .collect()
+    }
+
+    /// Packages resolved at more than one version, per lockfile (or across
+    /// all `go.mod` files), skipping `allow_duplicates`.
+    fn duplicates(&self, config: &DepsConfig) -> Vec<Duplicate> {
+        let mut groups: Vec<Vec<Resolved>> = self
+            .lockfiles
+            .iter()
+            .map(|lock| lock.resolved().collect())
+            .collect();
+        groups.push(self.resolved(Ecosystem::Go));
+
+        let mut duplicates = Vec::new();
+        for group in groups {
+            let mut by_name: BTreeMap<&str, (&Path, u32, BTreeSet<&str>)> = BTreeMap::new();
+            for package in group {
+                let (_, _, versions) = by_name.entry(package.name).or_insert((
+                    package.path,
+                    package.line,
+                    BTreeSet::new(),
+                ));
+                versions.insert(package.version);
+            }
+            duplicates.extend(
+                by_name
+                    .into_iter()
+                    .filter(|(name, (_, _, versions))| {
+                        versions.len() > 1 && !config.allow_duplicates.iter().any(|a| a == name)
+                    })
+                    .map(|(name, (path, line, versions))| Duplicate {
+                        path: path.to_path_buf(),
+                        line,
+                        name: name.to_string(),
+                        versions: versions.into_iter().map(String::from).collect(),
+                    }),
+            );
+        }
+        duplicates
+    }
+}
+
+/// A package version resolved at a lockfile or `go.mod` line.
+struct Resolved<'a> {
+    name: &'a str,
+    version: &'a str,
+    path: &'a Path,
+    line: u32,
+}
+
+/// A package resolved at several versions.
+struct Duplicate {
+    path: PathBuf,
+    line: u32,
+    name: String,
+    versions: Vec<String>,
+}
+
+impl Duplicate {
+    fn violation(&self) -> Violation {
+        Violation::file(
+            &self.path,
+            self.line,
+            "duplicate_dependency",
+            "Align dependents on one version, or add the package to allow_duplicates.",
+        )
+        .with_target(self.name.clone())
+        .with_detail(Field::Found, self.versions.join(", "))
+    }
+}
+
+/// Wildcard, unpinned git, and dependency count violations in a manifest.
+fn manifest_violations(manifest: &Manifest, config: &DepsConfig) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for dep in &manifest.deps {
+        let (violation_type, advice) = match &dep.source {
+            Source::Registry(req) if config.wildcards && is_wildcard(req) => (
+                "wildcard_version",
+                "Require a version range, e.g. \"1.4\", so a new major release can't break the build.",
+            ),
+            Source::Git { pinned: false, .. } if config.unpinned_git => {
+                ("unpinned_git", unpinned_git_advice(manifest.ecosystem))
+            }
+            _ => continue,
+        };
+        violations.push(
+            Violation::file(&manifest.path, dep.line, violation_type, advice)
+                .with_target(dep.name.clone()),
+        );
+    }
+
+    if let Some(max) = config.max_dependencies {
+        let count = manifest.declared().len();
+        if count > max {
+            violations.push(
+                Violation::file_only(
+                    &manifest.path,
+                    "too_many_dependencies",
+                    "Remove unused dependencies, or split the package.",
+                )
+                .with_threshold(count as i64, max as i64),
+            );
+        }
+    }
+    violations
+}
+
+/// Unused and undeclared feature violations in a package.
+fn feature_violations(audit: &features::Audit) -> Vec<Violation> {
+    let unused = audit.unused.iter().map(|feature| {
+        Violation::file(
+            &audit.package.manifest,
+            feature.line,
+            "unused_feature",
+            "Gate the code the feature is for on it, or remove the feature.",
+        )
+        .with_target(feature.name.clone())
+    });
+    let undeclared = audit.undeclared.iter().map(|(path, line, name)| {
+        Violation::file(
+            path,
+            *line,
+            "undeclared_feature",
+            "Declare the feature in the package's [features], or fix the name; this cfg can never be enabled.",
+        )
+        .with_target(name.to_string())
+    });
+    unused.chain(undeclared).collect()
+}
+
+/// Version requirements that accept any version.
+fn is_wildcard(req: &str) -> bool {
+    matches!(req.trim(), "" | "*" | "x" | "X" | "latest")
+}
+
+fn unpinned_git_advice(ecosystem: Ecosystem) -> &'static str {
+    match ecosystem {
+        Ecosystem::Npm => "Pin the git dependency to a commit with #<sha>.",
+        Ecosystem::Cargo | Ecosystem::Go => {
+            "Pin the git dependency to a commit with rev = \"<sha>\"."
+        }
+    }
+}
+
+fn read_text(ctx: &CheckContext, path: &Path) -> Option<String> {
+    let content = ctx.read_file(path).ok()?;
+    content.as_str().map(str::to_string)
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
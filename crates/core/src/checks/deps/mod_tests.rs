@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn manifest(path: &str, ecosystem: Ecosystem, content: &str) -> Manifest {
+    Manifest {
+        path: PathBuf::from(path),
+        ecosystem,
+        deps: manifest::parse(ecosystem, content).unwrap(),
+    }
+}
+
+fn lockfile(path: &str, ecosystem: Ecosystem, content: &str) -> Lockfile {
+    Lockfile {
+        path: PathBuf::from(path),
+        ecosystem,
+        packages: lockfile::parse(ecosystem, content).unwrap(),
+    }
+}
+
+fn types(violations: &[Violation]) -> Vec<(&str, Option<&str>)> {
+    violations
+        .iter()
+        .map(|v| (v.violation_type.as_str(), v.text(Field::Target)))
+        .collect()
+}
+
+const CARGO_LOCK: &str = "\
+[[package]]
+name = \"app\"
+version = \"0.1.0\"
+
+[[package]]
+name = \"serde\"
+version = \"1.0.197\"
+source = \"registry+https://github.com/rust-lang/crates.io-index\"
+
+[[package]]
+name = \"syn\"
+version = \"1.0.109\"
+source = \"registry+https://github.com/rust-lang/crates.io-index\"
+
+[[package]]
+name = \"syn\"
+version = \"2.0.48\"
+source = \"registry+https://github.com/rust-lang/crates.io-index\"
+";
+
+#[test]
+fn wildcard_and_unpinned_git_are_reported() {
+    let manifest = manifest(
+        "Cargo.toml",
+        Ecosystem::Cargo,
+        "[dependencies]\nok = \"1\"\nany = \"*\"\nfloating = { git = \"https://example.com/f\" }\nlocal = { path = \"../local\" }\n",
+    );
+
+    let violations = manifest_violations(&manifest, &DepsConfig::default());
+
+    assert_eq!(
+        types(&violations),
+        [
+            ("wildcard_version", Some("any")),
+            ("unpinned_git", Some("floating")),
+        ]
+    );
+}
+
+#[test]
+fn disabled_rules_are_not_reported() {
+    let manifest = manifest(
+        "package.json",
+        Ecosystem::Npm,
+        r#"{ "dependencies": { "any": "latest", "floating": "acme/floating" } }"#,
+    );
+    let config = DepsConfig {
+        wildcards: false,
+        unpinned_git: false,
+        ..DepsConfig::default()
+    };
+
+    assert!(manifest_violations(&manifest, &config).is_empty());
+}
+
+#[test]
+fn max_dependencies_counts_external_direct_dependencies() {
+    let manifest = manifest(
+        "Cargo.toml",
+        Ecosystem::Cargo,
+        "[dependencies]\na = \"1\"\nlocal = { path = \"../local\" }\n\n[dev-dependencies]\nb = \"1\"\n\n[workspace.dependencies]\nc = \"1\"\n",
+    );
+    let config = |max| DepsConfig {
+        max_dependencies: Some(max),
+        ..DepsConfig::default()
+    };
+
+    assert!(manifest_violations(&manifest, &config(2)).is_empty());
+    let violations = manifest_violations(&manifest, &config(1));
+    assert_eq!(types(&violations), [("too_many_dependencies", None)]);
+    assert_eq!(violations[0].int(Field::Value), Some(2));
+}
+
+#[test]
+fn duplicates_are_found_per_lockfile() {
+    let scan = Scan {
+        manifests: Vec::new(),
+        lockfiles: vec![lockfile("Cargo.lock", Ecosystem::Cargo, CARGO_LOCK)],
+        packages: Vec::new(),
+        sources: Vec::new(),
+    };
+
+    let duplicates = scan.duplicates(&DepsConfig::default());
+
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].name, "syn");
+    assert_eq!(duplicates[0].versions, ["1.0.109", "2.0.48"]);
+    assert_eq!(duplicates[0].line, 11);
+}
+
+#[test]
+fn allowed_duplicates_are_skipped() {
+    let scan = Scan {
+        manifests: Vec::new(),
+        lockfiles: vec![lockfile("Cargo.lock", Ecosystem::Cargo, CARGO_LOCK)],
+        packages: Vec::new(),
+        sources: Vec::new(),
+    };
+    let config = DepsConfig {
+        allow_duplicates: vec!["syn".to_string()],
+        ..DepsConfig::default()
+    };
+
+    assert!(scan.duplicates(&config).is_empty());
+}
+
+#[test]
+fn go_duplicates_span_modules() {
+    let scan = Scan {
+        manifests: vec![
+            manifest("go.mod", Ecosystem::Go, "require example.com/a v1.0.0\n"),
+            manifest(
+                "tools/go.mod",
+                Ecosystem::Go,
+                "require (\n\texample.com/a v1.2.0\n\texample.com/b v0.1.0\n)\n",
+            ),
+        ],
+        lockfiles: Vec::new(),
+        packages: Vec::new(),
+        sources: Vec::new(),
+    };
+
+    let duplicates = scan.duplicates(&DepsConfig::default());
+
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].name, "example.com/a");
+    assert_eq!(duplicates[0].path, PathBuf::from("go.mod"));
+}
+
+#[test]
+fn counts_use_lockfiles_and_skip_workspace_packages() {
+    let scan = Scan {
+        manifests: vec![
+            manifest(
+                "Cargo.toml",
+                Ecosystem::Cargo,
+                "[workspace.dependencies]\nserde = \"1\"\nsyn = \"2\"\ncore = { path = \"crates/core\" }\n",
+            ),
+            manifest(
+                "crates/core/Cargo.toml",
+                Ecosystem::Cargo,
+                "[dependencies]\nserde.workspace = true\n",
+            ),
+            manifest(
+                "web/package.json",
+                Ecosystem::Npm,
+                r#"{ "dependencies": { "react": "^18" } }"#,
+            ),
+            manifest(
+                "go.mod",
+                Ecosystem::Go,
+                "require (\n\texample.com/a v1.0.0\n\texample.com/b v1.0.0 // indirect\n)\n",
+            ),
+        ],
+        lockfiles: vec![lockfile("Cargo.lock", Ecosystem::Cargo, CARGO_LOCK)],
+        packages: Vec::new(),
+        sources: Vec::new(),
+    };
+
+    let direct = scan.direct_counts();
+    assert_eq!(direct.get("cargo"), Some(&2));
+    assert_eq!(direct.get("npm"), Some(&1));
+    assert_eq!(direct.get("go"), Some(&1));
+
+    let total = scan.total_counts();
+    assert_eq!(total.get("cargo"), Some(&3));
+    // No package-lock.json: falls back to declared dependencies
+    assert_eq!(total.get("npm"), Some(&1));
+    assert_eq!(total.get("go"), Some(&2));
+}
+
+#[test]
+fn wildcards_accept_any_version() {
+    for req in ["", "*", "x", "latest", " * "] {
+        assert!(is_wildcard(req), "{req:?} should be a wildcard");
+    }
+    for req in ["1", "^1.2", ">=0.1", "~2"] {
+        assert!(!is_wildcard(req), "{req:?} should not be a wildcard");
+    }
+}
+
+#[test]
+fn feature_violations_point_at_manifest_and_source() {
+    let packages = [features::Package {
+        dir: PathBuf::from("crates/app"),
+        manifest: PathBuf::from("crates/app/Cargo.toml"),
+        features: features::parse("[package]\nname = \"app\"\n\n[features]\nunused = []\n")
+            .unwrap()
+            .unwrap(),
+    }];
+    let sources = [RustFile::parse(
+        PathBuf::from("crates/app/src/lib.rs"),
+        "#[cfg(feature = \"typo\")]\nfn f() {}\n",
+    )
+    .unwrap()];
+    let audits = features::audit(&packages, &sources);
+
+    let violations = feature_violations(&audits[0]);
+
+    assert_eq!(
+        types(&violations),
+        [
+            ("unused_feature", Some("unused")),
+            ("undeclared_feature", Some("typo"))
+        ]
+    );
+    assert_eq!(violations[0].line, Some(5));
+    assert_eq!(violations[1].line, Some(1));
+}
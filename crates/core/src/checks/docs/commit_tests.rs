@@ -4,6 +4,7 @@
 //! Unit tests for commit checking.
 
 use super::*;
+use crate::check::Field;
 
 // =============================================================================
 // CONVENTIONAL COMMIT PARSING
@@ -196,10 +197,10 @@ fn creates_violation_with_expected_docs() {
     };
 
     let v = create_violation(&commit, Some("docs/api/**"));
-    assert_eq!(v.commit.as_deref(), Some("abc1234"));
-    assert_eq!(v.message.as_deref(), Some("feat(api): add endpoint"));
+    assert_eq!(v.text(Field::Commit), Some("abc1234"));
+    assert_eq!(v.text(Field::Message), Some("feat(api): add endpoint"));
     assert_eq!(v.violation_type, "missing_docs");
-    assert_eq!(v.expected_docs.as_deref(), Some("docs/api/**"));
+    assert_eq!(v.text(Field::ExpectedDocs), Some("docs/api/**"));
     assert!(v.advice.contains("docs/api/**"));
 }
 
@@ -213,8 +214,8 @@ fn creates_violation_without_expected_docs() {
     };
 
     let v = create_violation(&commit, None);
-    assert_eq!(v.commit.as_deref(), Some("abc1234"));
-    assert!(v.expected_docs.is_none());
+    assert_eq!(v.text(Field::Commit), Some("abc1234"));
+    assert!(v.text(Field::ExpectedDocs).is_none());
     assert!(v.advice.contains("docs/"));
 }
 
@@ -464,8 +465,8 @@ fn creates_area_violation_with_scope_match() {
     };
 
     let v = create_area_violation(&commit, &area);
-    assert_eq!(v.area.as_deref(), Some("api"));
-    assert_eq!(v.area_match.as_deref(), Some("scope"));
+    assert_eq!(v.text(Field::Area), Some("api"));
+    assert_eq!(v.text(Field::AreaMatch), Some("scope"));
     assert!(v.advice.contains("feat(api):"));
 }
 
@@ -486,8 +487,8 @@ fn creates_area_violation_with_source_match() {
     };
 
     let v = create_area_violation(&commit, &area);
-    assert_eq!(v.area.as_deref(), Some("api"));
-    assert_eq!(v.area_match.as_deref(), Some("source"));
+    assert_eq!(v.text(Field::Area), Some("api"));
+    assert_eq!(v.text(Field::AreaMatch), Some("source"));
     assert!(v.advice.contains("changes in api area"));
 }
 
@@ -551,5 +552,5 @@ fn creates_only_violations_for_missing_docs() {
 
     let violations = create_violations_for_commit(&commit, &result);
     assert_eq!(violations.len(), 1);
-    assert_eq!(violations[0].area.as_deref(), Some("cli"));
+    assert_eq!(violations[0].text(Field::Area), Some("cli"));
 }
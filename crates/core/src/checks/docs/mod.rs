@@ -15,14 +15,16 @@ mod links;
 mod specs;
 mod toc;
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 
 use dashmap::DashMap;
-use rayon::prelude::*;
 
 use crate::adapter::build_glob_set;
 use crate::check::{Check, CheckContext, CheckResult, Violation};
-use crate::file_reader::FileContent;
+use crate::fix::{Fix, Fixes};
+use crate::metrics::CheckMetrics;
+use crate::schedule::par_map_largest_first;
 
 /// Per-run cache for path existence checks.
 ///
@@ -91,24 +93,28 @@ where
         })
         .collect();
 
-    // Process in parallel
-    matching_files
-        .par_iter()
-        .flat_map(|walked| {
+    // Process in parallel, largest files first
+    par_map_largest_first(
+        &matching_files,
+        |walked| walked.size,
+        |walked| {
             let relative_path = walked.path.strip_prefix(ctx.root).unwrap_or(&walked.path);
 
             // Read file content (uses mmap for large files per performance spec)
-            let file_content = match FileContent::read(&walked.path) {
+            let file_content = match ctx.read_file(&walked.path) {
                 Ok(c) => c,
                 Err(_) => return Vec::new(),
             };
             let Some(content) = file_content.as_str() else {
-                return Vec::new(); // Skip non-UTF-8 files
+                return Vec::new(); // Skip binary files
             };
 
             validator(ctx, relative_path, content, path_cache)
-        })
-        .collect()
+        },
+    )
+    .into_iter()
+    .flatten()
+    .collect()
 }
 
 pub struct DocsCheck;
@@ -155,8 +161,7 @@ impl Check for DocsCheck {
         }
 
         // Collect metrics for JSON output
-        let metrics =
-            specs::collect_metrics(ctx).map(|m| serde_json::to_value(m).unwrap_or_default());
+        let metrics = specs::collect_metrics(ctx).map(|m| m.to_json());
 
         let result = if violations.is_empty() {
             CheckResult::passed("docs")
@@ -174,4 +179,48 @@ impl Check for DocsCheck {
     fn default_enabled(&self) -> bool {
         true
     }
+
+    fn fixer(&self) -> Option<&dyn Fix> {
+        Some(self)
+    }
+}
+
+/// Regenerates directory trees without the files they list that are gone.
+impl Fix for DocsCheck {
+    fn fix(&self, ctx: &CheckContext, violations: &[Violation]) -> Fixes {
+        let broken = |v: &Violation| v.violation_type == "broken_toc";
+        let mut lines: BTreeMap<&Path, BTreeSet<u32>> = BTreeMap::new();
+        for violation in violations.iter().filter(|v| broken(v)) {
+            if let (Some(file), Some(line)) = (violation.file.as_deref(), violation.line) {
+                lines.entry(file).or_default().insert(line);
+            }
+        }
+
+        let mut fixes = Fixes::default();
+        for (file, lines) in lines {
+            let path = ctx.root.join(file);
+            let Ok(file_content) = ctx.read_file(&path) else {
+                continue;
+            };
+            let Some(content) = file_content.as_str().filter(|_| file_content.round_trips()) else {
+                continue;
+            };
+            let Some((fixed, dropped)) = toc::regenerate(ctx.root, file, content, &lines) else {
+                continue;
+            };
+            fixes.edits.push((path, fixed));
+            fixes.fixed.extend(
+                violations
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, v)| {
+                        broken(v)
+                            && v.file.as_deref() == Some(file)
+                            && v.line.is_some_and(|line| dropped.contains(&line))
+                    })
+                    .map(|(idx, _)| idx),
+            );
+        }
+        fixes
+    }
 }
@@ -9,10 +9,9 @@ use std::collections::{HashSet, VecDeque};
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use serde::Serialize;
-
 use super::{content, links, toc};
 use crate::check::{CheckContext, Violation};
+use crate::metrics;
 
 /// Index file detection candidates in priority order.
 const INDEX_CANDIDATES: &[IndexCandidate] = &[
@@ -456,18 +455,8 @@ fn validate_specs_content(
     }
 }
 
-/// Specs metrics for reporting.
-#[derive(Debug, Serialize)]
-pub struct SpecsMetrics {
-    /// Detected or configured index file path.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_file: Option<String>,
-    /// Number of spec files found.
-    pub spec_files: usize,
-}
-
 /// Collect specs metrics for reporting.
-pub fn collect_metrics(ctx: &CheckContext) -> Option<SpecsMetrics> {
+pub fn collect_metrics(ctx: &CheckContext) -> Option<metrics::Docs> {
     let config = &ctx.config.check.docs.specs;
     let specs_dir = ctx.root.join(&config.path);
 
@@ -481,7 +470,7 @@ pub fn collect_metrics(ctx: &CheckContext) -> Option<SpecsMetrics> {
         .map(PathBuf::from)
         .or_else(|| detect_index_file(ctx.root, &config.path));
 
-    Some(SpecsMetrics {
+    Some(metrics::Docs {
         index_file: index_file.map(|p| p.to_string_lossy().to_string()),
         spec_files: count_spec_files(ctx.root, &config.path, &config.extension),
     })
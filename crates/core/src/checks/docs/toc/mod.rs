@@ -7,6 +7,7 @@
 
 mod detect;
 mod parse;
+mod regenerate;
 mod resolve;
 
 use std::path::Path;
@@ -16,12 +17,20 @@ use crate::check::{CheckContext, Violation};
 // Re-export functions used by specs.rs
 pub(super) use detect::{is_valid_tree_format, looks_like_tree};
 pub(super) use parse::{extract_fenced_blocks, parse_tree_block};
+pub(super) use regenerate::regenerate;
 
 use detect::TOC_LANGUAGE;
 
 use parse::TreeEntry;
 use resolve::{try_resolve, try_resolve_block};
 
+/// Ways an entry's path may be written, tried in order.
+const STRATEGIES: [ResolutionStrategy; 3] = [
+    ResolutionStrategy::RelativeToFile,
+    ResolutionStrategy::RelativeToRoot,
+    ResolutionStrategy::StripParentDirName,
+];
+
 // Re-exports for toc_tests.rs which uses `use super::*`
 // Required: tests use these types/functions directly
 #[allow(unused_imports)]
@@ -63,11 +72,7 @@ fn validate_file_toc_cached(
 ) -> Vec<Violation> {
     let mut violations = Vec::new();
     let blocks = extract_fenced_blocks(content);
-    let strategies = [
-        ResolutionStrategy::RelativeToFile,
-        ResolutionStrategy::RelativeToRoot,
-        ResolutionStrategy::StripParentDirName,
-    ];
+    let strategies = STRATEGIES;
 
     for block in blocks {
         // For explicit toc tag, validate format
@@ -143,7 +143,7 @@ fn parse_tree_line(
 /// Extract indent level and name from a tree line.
 ///
 /// Handles both box-drawing (├── name) and indentation (  name) formats.
-fn extract_indent_and_name(line: &str) -> Option<(usize, String)> {
+pub(super) fn extract_indent_and_name(line: &str) -> Option<(usize, String)> {
     // Check if this is a box-drawing tree line
     let has_box_drawing = line.contains('├') || line.contains('└') || line.contains('│');
 
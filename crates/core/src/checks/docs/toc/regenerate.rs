@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! TOC regeneration for `quench fix`.
+//!
+//! Rewrites a directory tree without the files that no longer exist,
+//! redrawing box-drawing connectors so the tree stays well formed.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use super::STRATEGIES;
+use super::detect::looks_like_tree;
+use super::parse::{FencedBlock, extract_fenced_blocks, extract_indent_and_name, parse_tree_block};
+use super::resolve::try_resolve;
+
+/// Rewrite the trees in `content`, the markdown file at `relative`, without
+/// the file entries on `lines` that no strategy resolves.
+///
+/// Returns the new content and the lines dropped, or None if none were.
+pub(crate) fn regenerate(
+    root: &Path,
+    relative: &Path,
+    content: &str,
+    lines: &BTreeSet<u32>,
+) -> Option<(String, Vec<u32>)> {
+    let abs_file = root.join(relative);
+    let mut source: Vec<String> = content.split_inclusive('\n').map(String::from).collect();
+    let mut dropped = Vec::new();
+
+    // Bottom-up, so earlier blocks keep their line numbers
+    for block in extract_fenced_blocks(content).iter().rev() {
+        if !looks_like_tree(block) {
+            continue;
+        }
+        let missing: BTreeSet<u32> = parse_tree_block(block)
+            .into_iter()
+            .filter(|entry| {
+                !entry.is_dir && lines.contains(&(block.start_line + entry.line_offset))
+            })
+            .filter(|entry| {
+                !STRATEGIES
+                    .iter()
+                    .any(|&s| try_resolve(root, &abs_file, &entry.path, s))
+            })
+            .map(|entry| entry.line_offset)
+            .collect();
+        if missing.is_empty() {
+            continue;
+        }
+
+        let start = block.start_line as usize - 1;
+        let end = start + block.lines.len();
+        let newline = if source[start].ends_with("\r\n") {
+            "\r\n"
+        } else {
+            "\n"
+        };
+        let redrawn: Vec<String> = redraw(block, &missing)
+            .into_iter()
+            .map(|line| format!("{}{}", line, newline))
+            .collect();
+        source.splice(start..end, redrawn);
+        dropped.extend(missing.iter().map(|offset| block.start_line + offset));
+    }
+
+    if dropped.is_empty() {
+        return None;
+    }
+    dropped.sort_unstable();
+    Some((source.concat(), dropped))
+}
+
+/// The block's lines without those at `missing` offsets.
+///
+/// Indentation trees only lose lines; box-drawing trees are redrawn, since
+/// the entry before a dropped last child becomes the last one.
+fn redraw(block: &FencedBlock, missing: &BTreeSet<u32>) -> Vec<String> {
+    let kept = block
+        .lines
+        .iter()
+        .enumerate()
+        .filter(|(offset, _)| !missing.contains(&(*offset as u32)))
+        .map(|(_, line)| line);
+    let box_drawing = block
+        .lines
+        .iter()
+        .any(|line| line.contains('├') || line.contains('└') || line.contains('│'));
+    if !box_drawing {
+        return kept.cloned().collect();
+    }
+
+    // Lines that aren't entries (e.g., blank) pass through unchanged
+    let items: Vec<(Option<usize>, &String)> = kept
+        .map(|line| (extract_indent_and_name(line).map(|(level, _)| level), line))
+        .collect();
+    // Whether another entry at `level` follows `idx` under the same parent
+    let continues = |idx: usize, level: usize| {
+        items[idx + 1..]
+            .iter()
+            .filter_map(|(l, _)| *l)
+            .take_while(|&l| l >= level)
+            .any(|l| l == level)
+    };
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(idx, (level, line))| {
+            let Some(level) = level.filter(|&l| l > 0) else {
+                return (*line).clone();
+            };
+            let Some((_, name)) = extract_indent_and_name(line) else {
+                return (*line).clone();
+            };
+            let mut out = String::new();
+            for ancestor in 1..level {
+                out.push_str(if continues(idx, ancestor) {
+                    "│   "
+                } else {
+                    "    "
+                });
+            }
+            out.push_str(if continues(idx, level) {
+                "├── "
+            } else {
+                "└── "
+            });
+            out.push_str(&name);
+            out
+        })
+        .collect()
+}
@@ -915,3 +915,57 @@ fn toc_handles_mixed_path_issues() {
         ResolutionStrategy::RelativeToRoot
     ));
 }
+
+// =============================================================================
+// REGENERATION
+// =============================================================================
+
+fn regenerate_project(content: &str, files: &[&str]) -> (tempfile::TempDir, Option<String>) {
+    let temp = tempfile::TempDir::new().unwrap();
+    for file in files {
+        let path = temp.path().join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "").unwrap();
+    }
+    let broken: std::collections::BTreeSet<u32> = (1..=content.lines().count() as u32).collect();
+    let result = regenerate(temp.path(), Path::new("README.md"), content, &broken);
+    (temp, result.map(|(fixed, _)| fixed))
+}
+
+#[test]
+fn regenerate_redraws_box_tree_without_missing_files() {
+    let content = "# Layout\n\n```\nsrc/\n├── lib.rs   # entry\n├── gone.rs\n└── old.rs\n```\n";
+    let (_temp, fixed) = regenerate_project(content, &["src/lib.rs"]);
+
+    assert_eq!(
+        fixed.unwrap(),
+        "# Layout\n\n```\nsrc/\n└── lib.rs   # entry\n```\n"
+    );
+}
+
+#[test]
+fn regenerate_keeps_nested_connectors() {
+    let content = "```\nsrc/\n├── cli/\n│   ├── main.rs\n│   └── gone.rs\n└── lib.rs\n```\n";
+    let (_temp, fixed) = regenerate_project(content, &["src/cli/main.rs", "src/lib.rs"]);
+
+    assert_eq!(
+        fixed.unwrap(),
+        "```\nsrc/\n├── cli/\n│   └── main.rs\n└── lib.rs\n```\n"
+    );
+}
+
+#[test]
+fn regenerate_drops_lines_from_indented_tree() {
+    let content = "```toc\nsrc/\n  lib.rs\n  gone.rs\n```\n";
+    let (_temp, fixed) = regenerate_project(content, &["src/lib.rs"]);
+
+    assert_eq!(fixed.unwrap(), "```toc\nsrc/\n  lib.rs\n```\n");
+}
+
+#[test]
+fn regenerate_leaves_resolvable_trees_alone() {
+    let content = "```\nsrc/\n└── lib.rs\n```\n";
+    let (_temp, fixed) = regenerate_project(content, &["src/lib.rs"]);
+
+    assert!(fixed.is_none());
+}
@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Bare assertion detection for test code.
+//!
+//! An assertion is bare when its failure says nothing beyond "false":
+//! Rust `assert!(x)` without a message, Node `assert(x)` / `assert.ok(x)`
+//! without a message, and Jest/Vitest `toBeTruthy()`, `toBeFalsy()`,
+//! `toBe(true)`, and `toBe(false)`.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::comment::is_match_in_comment;
+
+/// A bare assertion found in a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct BareAssertion {
+    /// 1-based line of the assertion.
+    pub line: u32,
+    /// The assertion form, e.g. `assert!` or `toBeTruthy`.
+    pub form: &'static str,
+}
+
+/// `assert!(` and `debug_assert!(`, not `foo_assert!(`.
+#[allow(clippy::expect_used)]
+static RUST_ASSERT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[^A-Za-z0-9_])((?:debug_)?assert)!\s*\(").expect("valid regex pattern")
+});
+
+/// `assert(` and `assert.ok(`, not `foo.assert(`.
+#[allow(clippy::expect_used)]
+static JS_ASSERT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[^A-Za-z0-9_$.])(assert(?:\.ok)?)\s*\(").expect("valid regex pattern")
+});
+
+/// Boolean matchers that report only "expected true, received false".
+#[allow(clippy::expect_used)]
+static JS_MATCHER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\.(toBeTruthy|toBeFalsy)\(\s*\)|\.toBe\(\s*(true|false)\s*\)")
+        .expect("valid regex pattern")
+});
+
+/// Bare assertions in a file with extension `ext`, ordered by line.
+pub(super) fn find_bare_assertions(ext: &str, content: &str) -> Vec<BareAssertion> {
+    let mut found = match ext {
+        "rs" => calls(content, &RUST_ASSERT, Quotes::Rust),
+        "js" | "jsx" | "ts" | "tsx" | "mjs" | "mts" => {
+            let mut found = calls(content, &JS_ASSERT, Quotes::JavaScript);
+            found.extend(matchers(content));
+            found
+        }
+        _ => Vec::new(),
+    };
+    found.sort_by_key(|a| a.line);
+    found
+}
+
+/// Assertion calls with a single argument.
+fn calls(content: &str, pattern: &Regex, quotes: Quotes) -> Vec<BareAssertion> {
+    pattern
+        .captures_iter(content)
+        .filter_map(|caps| {
+            let name = caps.get(1)?;
+            let args_start = caps.get(0)?.end();
+            if in_comment(content, name.start()) || has_message(&content[args_start..], quotes) {
+                return None;
+            }
+            Some(BareAssertion {
+                line: line_of(content, name.start()),
+                form: match name.as_str() {
+                    "assert" if quotes == Quotes::Rust => "assert!",
+                    "debug_assert" => "debug_assert!",
+                    "assert.ok" => "assert.ok",
+                    _ => "assert",
+                },
+            })
+        })
+        .collect()
+}
+
+fn matchers(content: &str) -> Vec<BareAssertion> {
+    JS_MATCHER
+        .captures_iter(content)
+        .filter_map(|caps| {
+            let whole = caps.get(0)?;
+            if in_comment(content, whole.start()) {
+                return None;
+            }
+            let form = match caps.get(1).map(|m| m.as_str()) {
+                Some("toBeTruthy") => "toBeTruthy",
+                Some(_) => "toBeFalsy",
+                None if caps.get(2)?.as_str() == "true" => "toBe(true)",
+                None => "toBe(false)",
+            };
+            Some(BareAssertion {
+                line: line_of(content, whole.start()),
+                form,
+            })
+        })
+        .collect()
+}
+
+/// String syntax to skip while scanning arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Quotes {
+    /// `"..."`, with `'` only as a char literal.
+    Rust,
+    /// `"..."`, `'...'`, and `` `...` ``.
+    JavaScript,
+}
+
+/// Whether the argument list starting just after `(` has a second argument.
+///
+/// Unbalanced input (the call runs past the end of the file) counts as
+/// having one, so half-parsed calls are never reported.
+fn has_message(args: &str, quotes: Quotes) -> bool {
+    let mut depth = 0usize;
+    let mut chars = args.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' if depth == 0 => return false,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                // A trailing comma before `)` is not a message
+                return !args[idx + 1..].trim_start().starts_with(')');
+            }
+            '"' => skip_string(&mut chars, '"'),
+            '\'' | '`' if quotes == Quotes::JavaScript => skip_string(&mut chars, c),
+            '\'' => skip_char_literal(&args[idx + 1..], &mut chars),
+            _ => {}
+        }
+    }
+    true
+}
+
+fn skip_string(chars: &mut impl Iterator<Item = (usize, char)>, quote: char) {
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            c if c == quote => return,
+            _ => {}
+        }
+    }
+}
+
+/// Skip `'x'` or `'\n'`, leaving lifetimes like `'a` alone.
+fn skip_char_literal(rest: &str, chars: &mut impl Iterator<Item = (usize, char)>) {
+    let len = if rest.starts_with('\\') {
+        rest.get(2..)
+            .and_then(|tail| tail.find('\''))
+            .map(|end| end + 3)
+    } else {
+        let mut it = rest.chars();
+        let first = it.next().map_or(0, char::len_utf8);
+        (it.next() == Some('\'')).then_some(first + 1)
+    };
+    for _ in 0..len.map_or(0, |bytes| rest[..bytes].chars().count()) {
+        chars.next();
+    }
+}
+
+fn in_comment(content: &str, offset: usize) -> bool {
+    let line_start = content[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = content[offset..]
+        .find('\n')
+        .map_or(content.len(), |i| offset + i);
+    is_match_in_comment(&content[line_start..line_end], offset - line_start)
+}
+
+fn line_of(content: &str, offset: usize) -> u32 {
+    content[..offset].matches('\n').count() as u32 + 1
+}
+
+#[cfg(test)]
+#[path = "assertions_tests.rs"]
+mod tests;
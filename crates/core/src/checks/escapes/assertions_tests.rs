@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+
+fn forms(ext: &str, content: &str) -> Vec<(u32, &'static str)> {
+    find_bare_assertions(ext, content)
+        .into_iter()
+        .map(|a| (a.line, a.form))
+        .collect()
+}
+
+#[test]
+fn rust_assert_without_message_is_bare() {
+    let content = "fn t() {\n    assert!(ok);\n    debug_assert!(v.is_empty());\n}\n";
+    assert_eq!(forms("rs", content), [(2, "assert!"), (3, "debug_assert!")]);
+}
+
+#[test]
+fn rust_assert_with_message_is_not_bare() {
+    let content =
+        "assert!(ok, \"parse failed for {input}\");\nassert!(\n    x > 1,\n    \"x was {x}\"\n);\n";
+    assert!(forms("rs", content).is_empty());
+}
+
+#[test]
+fn rust_commas_inside_nested_calls_are_not_messages() {
+    let content =
+        "assert!(matches!(x, Some(_)));\nassert!(f(a, b),);\nassert!(s.contains(\"a, b\"));\n";
+    assert_eq!(
+        forms("rs", content),
+        [(1, "assert!"), (2, "assert!"), (3, "assert!")]
+    );
+}
+
+#[test]
+fn rust_char_literals_and_lifetimes_are_skipped() {
+    let content = "assert!(c == ',');\nassert!(c == '\\'');\nassert!(f::<'a>(x), \"msg\");\n";
+    assert_eq!(forms("rs", content), [(1, "assert!"), (2, "assert!")]);
+}
+
+#[test]
+fn rust_assert_eq_and_custom_macros_are_ignored() {
+    let content = "assert_eq!(a, b);\nmy_assert!(x);\nprop_assert!(x);\n";
+    assert!(forms("rs", content).is_empty());
+}
+
+#[test]
+fn assertions_in_comments_are_ignored() {
+    assert!(forms("rs", "// assert!(x);\n").is_empty());
+    assert!(forms("ts", "// expect(x).toBeTruthy();\n").is_empty());
+}
+
+#[test]
+fn js_boolean_matchers_are_bare() {
+    let content = "expect(a).toBeTruthy();\nexpect(b).toBeFalsy();\nexpect(c).toBe(true);\nexpect(d).not.toBe( false );\n";
+    assert_eq!(
+        forms("ts", content),
+        [
+            (1, "toBeTruthy"),
+            (2, "toBeFalsy"),
+            (3, "toBe(true)"),
+            (4, "toBe(false)")
+        ]
+    );
+}
+
+#[test]
+fn js_value_matchers_are_not_bare() {
+    let content =
+        "expect(a).toBe(1);\nexpect(b).toEqual({ ok: true });\nexpect(c).toBeDefined();\n";
+    assert!(forms("js", content).is_empty());
+}
+
+#[test]
+fn js_assert_without_message_is_bare() {
+    let content = "assert(ok);\nassert.ok(list.length);\nassert(ok, 'load failed');\nassert.ok(x, `got ${x}`);\n";
+    assert_eq!(forms("js", content), [(1, "assert"), (2, "assert.ok")]);
+}
+
+#[test]
+fn js_strings_may_contain_commas() {
+    let content = "assert(s === 'a, b');\nassert.equal(a, b);\nthis.assert(x);\n";
+    assert_eq!(forms("mjs", content), [(1, "assert")]);
+}
+
+#[test]
+fn unterminated_call_is_not_reported() {
+    assert!(forms("rs", "assert!(x").is_empty());
+}
+
+#[test]
+fn other_languages_are_not_scanned() {
+    assert!(forms("py", "assert x\n").is_empty());
+}
@@ -52,11 +52,24 @@ pub(super) fn has_justification_comment(
 /// Check if comment content starts with the pattern (after comment marker).
 ///
 /// Normalizes both the comment and pattern by stripping comment markers,
-/// then checks if the comment content starts with the pattern content.
+/// then checks if the comment content starts with the pattern content and
+/// goes on to more than a placeholder.
 fn comment_starts_with_pattern(comment: &str, pattern: &str) -> bool {
     let comment_content = strip_comment_markers(comment);
     let pattern_content = strip_comment_markers(pattern);
-    comment_content.starts_with(&pattern_content)
+    comment_content
+        .strip_prefix(&pattern_content)
+        .is_some_and(|body| !is_placeholder(body))
+}
+
+/// Comment bodies that stand in for a justification, such as the stubs
+/// `quench fix` inserts.
+const PLACEHOLDERS: &[&str] = &["TODO", "FIXME", "XXX", "TBD"];
+
+/// Check if a justification body is only a placeholder like `TODO`.
+pub(super) fn is_placeholder(body: &str) -> bool {
+    let body = body.trim().trim_end_matches(['.', ':', '!']).trim_end();
+    PLACEHOLDERS.iter().any(|p| body.eq_ignore_ascii_case(p))
 }
 
 /// Strip comment markers and leading whitespace to get the content.
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Justification stubs for `missing_comment` violations (`quench fix`).
+//!
+//! Each escape that needs a comment gets its required comment above it,
+//! followed by `TODO`, for a person to fill in. A `TODO` doesn't justify
+//! anything, so the violations stay until the stubs are filled in; an escape
+//! that already has its stub doesn't get another.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use super::EscapesCheck;
+use super::comment::{is_comment_line, is_placeholder, strip_comment_markers};
+use super::patterns::{get_adapter_escape_patterns, merge_patterns};
+use crate::check::{CheckContext, Field, Violation};
+use crate::fix::{Fix, Fixes};
+
+impl Fix for EscapesCheck {
+    fn fix(&self, ctx: &CheckContext, violations: &[Violation]) -> Fixes {
+        let adapter_patterns = get_adapter_escape_patterns(ctx.root);
+        let patterns = merge_patterns(&ctx.config.check.escapes.patterns, &adapter_patterns);
+        let comment_for = |name: &str| {
+            patterns
+                .iter()
+                .find(|p| p.pattern.effective_name() == name)
+                .and_then(|p| p.pattern.comment.clone())
+                .unwrap_or_else(|| super::DEFAULT_COMMENT.to_string())
+        };
+
+        // Stubs by file, then line
+        let mut stubs: BTreeMap<&Path, BTreeMap<u32, BTreeSet<String>>> = BTreeMap::new();
+        let mut stubbed: BTreeMap<&Path, Vec<(usize, u32, String)>> = BTreeMap::new();
+        for (idx, violation) in violations.iter().enumerate() {
+            if violation.violation_type != "missing_comment" {
+                continue;
+            }
+            let (Some(file), Some(line), Some(pattern)) = (
+                violation.file.as_deref(),
+                violation.line,
+                violation.text(Field::Pattern),
+            ) else {
+                continue;
+            };
+            let comment = comment_for(pattern);
+            stubs
+                .entry(file)
+                .or_default()
+                .entry(line)
+                .or_default()
+                .insert(comment.clone());
+            stubbed.entry(file).or_default().push((idx, line, comment));
+        }
+
+        let mut fixes = Fixes::default();
+        for (file, mut lines) in stubs {
+            let path = ctx.root.join(file);
+            let Ok(file_content) = ctx.read_file(&path) else {
+                continue;
+            };
+            // Writing decoded text back would corrupt lossy or transcoded files
+            let Some(content) = file_content.as_str().filter(|_| file_content.round_trips()) else {
+                continue;
+            };
+            let text: Vec<&str> = content.lines().collect();
+            for (line, comments) in lines.iter_mut() {
+                comments.retain(|comment| !has_stub(&text, *line, comment));
+            }
+            lines.retain(|_, comments| !comments.is_empty());
+            if !lines.is_empty() {
+                fixes.edits.push((path, insert_stubs(content, &lines)));
+            }
+            for (idx, line, comment) in stubbed.remove(file).unwrap_or_default() {
+                let above: usize = lines.range(..=line).map(|(_, c)| c.len()).sum();
+                fixes.moved.push((idx, line + above as u32));
+                fixes.suggestions.push((
+                    idx,
+                    format!(
+                        "Replace the TODO in `{} TODO` with the justification.",
+                        comment
+                    ),
+                ));
+            }
+        }
+        fixes
+    }
+}
+
+/// Whether the comment lines directly above `line` (1-indexed) already hold
+/// a placeholder stub for `comment`, like one an earlier run inserted.
+fn has_stub(text: &[&str], line: u32, comment: &str) -> bool {
+    let comment = strip_comment_markers(comment);
+    text[..(line as usize).saturating_sub(1).min(text.len())]
+        .iter()
+        .rev()
+        .take_while(|above| is_comment_line(above))
+        .any(|above| {
+            strip_comment_markers(above)
+                .strip_prefix(&comment)
+                .is_some_and(is_placeholder)
+        })
+}
+
+/// Insert `<comment> TODO` above each line in `stubs` (1-indexed), indented
+/// like the line it justifies.
+fn insert_stubs(content: &str, stubs: &BTreeMap<u32, BTreeSet<String>>) -> String {
+    let mut out = String::with_capacity(content.len());
+    for (idx, line) in content.split_inclusive('\n').enumerate() {
+        if let Some(comments) = stubs.get(&(idx as u32 + 1)) {
+            let indent: String = line
+                .chars()
+                .take_while(|c| c == &' ' || c == &'\t')
+                .collect();
+            let newline = if line.ends_with("\r\n") { "\r\n" } else { "\n" };
+            for comment in comments {
+                out.push_str(&format!("{}{} TODO{}", indent, comment, newline));
+            }
+        }
+        out.push_str(line);
+    }
+    out
+}
+
+#[cfg(test)]
+#[path = "fix_tests.rs"]
+mod tests;
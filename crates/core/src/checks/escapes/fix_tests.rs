@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn stubs(entries: &[(u32, &str)]) -> BTreeMap<u32, BTreeSet<String>> {
+    let mut stubs: BTreeMap<u32, BTreeSet<String>> = BTreeMap::new();
+    for (line, comment) in entries {
+        stubs.entry(*line).or_default().insert(comment.to_string());
+    }
+    stubs
+}
+
+#[test]
+fn stub_goes_above_line_with_its_indent() {
+    let content = "fn f() {\n    unsafe { g() }\n}\n";
+    assert_eq!(
+        insert_stubs(content, &stubs(&[(2, "// SAFETY:")])),
+        "fn f() {\n    // SAFETY: TODO\n    unsafe { g() }\n}\n"
+    );
+}
+
+#[test]
+fn one_stub_per_comment_on_a_line() {
+    let content = "let x = a.unwrap() as u8;\n";
+    assert_eq!(
+        insert_stubs(
+            content,
+            &stubs(&[(1, "// JUSTIFIED:"), (1, "// JUSTIFIED:"), (1, "// CAST:")])
+        ),
+        "// CAST: TODO\n// JUSTIFIED: TODO\nlet x = a.unwrap() as u8;\n"
+    );
+}
+
+#[test]
+fn stub_keeps_crlf_line_endings() {
+    let content = "a\r\n\tunsafe {}\r\n";
+    assert_eq!(
+        insert_stubs(content, &stubs(&[(2, "// SAFETY:")])),
+        "a\r\n\t// SAFETY: TODO\r\n\tunsafe {}\r\n"
+    );
+}
+
+#[test]
+fn stub_on_last_line_without_newline() {
+    assert_eq!(
+        insert_stubs("unsafe {}", &stubs(&[(1, "// SAFETY:")])),
+        "// SAFETY: TODO\nunsafe {}"
+    );
+}
+
+#[test]
+fn existing_stub_above_line_is_found() {
+    let text = ["fn f() {", "    // SAFETY: TODO", "    unsafe { g() }", "}"];
+    assert!(has_stub(&text, 3, "// SAFETY:"));
+    assert!(!has_stub(&text, 3, "// CAST:"));
+}
+
+#[test]
+fn filled_in_or_distant_stub_is_not_found() {
+    let filled = ["// SAFETY: g has no preconditions", "unsafe { g() }"];
+    assert!(!has_stub(&filled, 2, "// SAFETY:"));
+    let distant = ["// SAFETY: TODO", "let x = 1;", "unsafe { g() }"];
+    assert!(!has_stub(&distant, 3, "// SAFETY:"));
+}
+
+#[test]
+fn stacked_stubs_are_found() {
+    let text = [
+        "// CAST: TODO",
+        "// JUSTIFIED: TODO",
+        "let x = a.unwrap() as u8;",
+    ];
+    assert!(has_stub(&text, 3, "// CAST:"));
+    assert!(has_stub(&text, 3, "// JUSTIFIED:"));
+}
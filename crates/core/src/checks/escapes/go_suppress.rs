@@ -22,11 +22,26 @@ pub fn check_go_suppress_violations(
     is_test_file: bool,
     limit_reached: &mut bool,
 ) -> Vec<Violation> {
-    // Parse nolint directives
-    let directives = parse_nolint_directives(content, config.comment.as_deref());
+    let unified = go_directives(content, config);
 
-    // Convert to unified format
-    let unified: Vec<UnifiedSuppressDirective> = directives
+    check_suppress_violations_generic(
+        ctx,
+        path,
+        unified,
+        config,
+        "go",
+        "suppress",
+        is_test_file,
+        limit_reached,
+    )
+}
+
+/// Parse Go nolint directives into the unified format.
+pub(super) fn go_directives(
+    content: &str,
+    config: &GoSuppressConfig,
+) -> Vec<UnifiedSuppressDirective> {
+    parse_nolint_directives(content, config.comment.as_deref())
         .into_iter()
         .map(|d| {
             let pattern = if d.codes.is_empty() {
@@ -42,16 +57,5 @@ pub fn check_go_suppress_violations(
                 pattern,
             }
         })
-        .collect();
-
-    check_suppress_violations_generic(
-        ctx,
-        path,
-        unified,
-        config,
-        "go",
-        "suppress",
-        is_test_file,
-        limit_reached,
-    )
+        .collect()
 }
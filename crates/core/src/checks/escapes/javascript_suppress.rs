@@ -23,11 +23,26 @@ pub fn check_javascript_suppress_violations(
     is_test_file: bool,
     limit_reached: &mut bool,
 ) -> Vec<Violation> {
-    // Parse JavaScript suppress directives
-    let directives = parse_javascript_suppresses(content, config.comment.as_deref());
+    let unified = javascript_directives(content, config);
 
-    // Convert to unified format
-    let unified: Vec<UnifiedSuppressDirective> = directives
+    check_suppress_violations_generic(
+        ctx,
+        path,
+        unified,
+        config,
+        "javascript",
+        "suppress",
+        is_test_file,
+        limit_reached,
+    )
+}
+
+/// Parse JavaScript suppress directives into the unified format.
+pub(super) fn javascript_directives(
+    content: &str,
+    config: &JavaScriptSuppressConfig,
+) -> Vec<UnifiedSuppressDirective> {
+    parse_javascript_suppresses(content, config.comment.as_deref())
         .into_iter()
         .map(|d| {
             let pattern = match d.tool {
@@ -54,16 +69,5 @@ pub fn check_javascript_suppress_violations(
                 pattern,
             }
         })
-        .collect();
-
-    check_suppress_violations_generic(
-        ctx,
-        path,
-        unified,
-        config,
-        "javascript",
-        "suppress",
-        is_test_file,
-        limit_reached,
-    )
+        .collect()
 }
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Path and line matchers for the escapes check.
+
+use std::path::Path;
+
+use globset::GlobSet;
+
+use crate::adapter::glob::build_glob_set;
+use crate::adapter::{FileKind, GenericAdapter};
+use crate::config::Config;
+use crate::pattern::PatternError;
+
+use super::patterns::{
+    CompiledEscapePattern, compile_merged_patterns, default_test_patterns,
+    get_adapter_escape_patterns, merge_patterns,
+};
+use super::{has_extension, is_source_file};
+
+/// Escape patterns as the check applies them, for attributing escape count
+/// changes to individual added lines (see [`crate::delta_owners`]).
+pub struct EscapeMatcher {
+    patterns: Vec<CompiledEscapePattern>,
+    adapter: GenericAdapter,
+    exclude: ExcludeMatcher,
+}
+
+impl EscapeMatcher {
+    /// Build from adapter defaults merged with the `[check.escapes]` config.
+    pub fn new(root: &Path, config: &Config) -> Result<Self, PatternError> {
+        let escapes = &config.check.escapes;
+        let merged = merge_patterns(&escapes.patterns, &get_adapter_escape_patterns(root));
+        let test_patterns = if config.project.tests.is_empty() {
+            default_test_patterns()
+        } else {
+            config.project.tests.clone()
+        };
+        Ok(Self {
+            patterns: compile_merged_patterns(&merged)?,
+            adapter: GenericAdapter::new(&[], &test_patterns),
+            exclude: ExcludeMatcher::new(&escapes.exclude),
+        })
+    }
+
+    /// Names of patterns matching `line` of `path` (relative to the project root).
+    ///
+    /// Empty for test, excluded, and non-source files, which don't count
+    /// toward the ratcheted source escape counts.
+    pub fn matches(&self, path: &Path, line: &str) -> Vec<&str> {
+        use crate::adapter::Adapter;
+        if !is_source_file(path)
+            || self.exclude.is_excluded(path, Path::new(""))
+            || self.adapter.classify(path) == FileKind::Test
+        {
+            return Vec::new();
+        }
+        self.patterns
+            .iter()
+            .filter(|p| p.extensions.is_empty() || has_extension(path, p.extensions))
+            .filter(|p| !p.matcher.find_all(line).is_empty())
+            .map(|p| p.name.as_str())
+            .collect()
+    }
+}
+
+/// Pattern matcher for exclude patterns.
+pub(super) struct ExcludeMatcher {
+    exclude_patterns: GlobSet,
+}
+
+impl ExcludeMatcher {
+    /// Create a new exclude matcher from config patterns.
+    pub(super) fn new(exclude_patterns: &[String]) -> Self {
+        Self {
+            exclude_patterns: build_glob_set(exclude_patterns),
+        }
+    }
+
+    /// Check if a file should be excluded from escape checks.
+    pub(super) fn is_excluded(&self, path: &Path, root: &Path) -> bool {
+        let relative = path.strip_prefix(root).unwrap_or(path);
+        self.exclude_patterns.is_match(relative)
+    }
+}
@@ -0,0 +1,241 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Metrics tracking for escape hatch detection.
+//!
+//! Tracks pattern match counts for source and test files,
+//! with optional per-package breakdown for workspaces. Source counts are
+//! also reported as densities (per 1000 source lines), and bare test
+//! assertions are counted for the whole project. Unsafe Rust is tallied
+//! from parsed source, overall and per package.
+
+use std::collections::BTreeMap;
+
+use serde_json::Value as JsonValue;
+
+use crate::adapter::UnsafeCounts;
+use crate::metrics::{CheckMetrics, Escapes, UnsafeCode};
+
+/// Metrics tracked during escapes check.
+#[derive(Default)]
+pub(super) struct EscapesMetrics {
+    /// Counts per pattern for source files.
+    source: BTreeMap<String, usize>,
+    /// Counts per pattern for test files.
+    test: BTreeMap<String, usize>,
+    /// Non-blank source lines scanned.
+    source_lines: usize,
+    /// Per-package breakdown (only if workspace configured).
+    packages: BTreeMap<String, PackageMetrics>,
+    /// Test assertions without a failure message.
+    bare_assertions: usize,
+    /// Unsafe Rust (only once a Rust source file is scanned).
+    unsafe_code: Option<UnsafeTally>,
+}
+
+#[derive(Default)]
+struct PackageMetrics {
+    source: BTreeMap<String, usize>,
+    test: BTreeMap<String, usize>,
+    source_lines: usize,
+    unsafe_code: Option<UnsafeTally>,
+}
+
+/// Unsafe counts and the Rust source lines they were found in.
+#[derive(Default, Clone, Copy)]
+struct UnsafeTally {
+    counts: UnsafeCounts,
+    source_lines: usize,
+}
+
+impl UnsafeTally {
+    fn add(&mut self, other: UnsafeTally) {
+        self.counts.add(other.counts);
+        self.source_lines += other.source_lines;
+    }
+
+    fn typed(&self) -> UnsafeCode {
+        let ratio = if self.source_lines == 0 {
+            0.0
+        } else {
+            (self.counts.lines as f64 / self.source_lines as f64 * 10000.0).round() / 10000.0
+        };
+        UnsafeCode {
+            blocks: self.counts.blocks,
+            fns: self.counts.fns,
+            impls: self.counts.impls,
+            lines: self.counts.lines,
+            source_lines: self.source_lines,
+            ratio,
+        }
+    }
+}
+
+fn add_unsafe(into: &mut Option<UnsafeTally>, other: Option<UnsafeTally>) {
+    if let Some(other) = other {
+        into.get_or_insert_default().add(other);
+    }
+}
+
+fn add_counts(into: &mut BTreeMap<String, usize>, counts: BTreeMap<String, usize>) {
+    for (name, count) in counts {
+        *into.entry(name).or_insert(0) += count;
+    }
+}
+
+/// Occurrences per 1000 source lines, rounded to two decimals.
+pub(super) fn density(count: usize, source_lines: usize) -> f64 {
+    if source_lines == 0 {
+        return 0.0;
+    }
+    (count as f64 * 1000.0 / source_lines as f64 * 100.0).round() / 100.0
+}
+
+impl EscapesMetrics {
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(super) fn increment(&mut self, pattern_name: &str, is_test: bool) {
+        let map = if is_test {
+            &mut self.test
+        } else {
+            &mut self.source
+        };
+        *map.entry(pattern_name.to_string()).or_insert(0) += 1;
+    }
+
+    pub(super) fn increment_package(&mut self, package: &str, pattern_name: &str, is_test: bool) {
+        let pkg = self.packages.entry(package.to_string()).or_default();
+        let map = if is_test {
+            &mut pkg.test
+        } else {
+            &mut pkg.source
+        };
+        *map.entry(pattern_name.to_string()).or_insert(0) += 1;
+    }
+
+    /// Record scanned source lines (for density).
+    pub(super) fn add_source_lines(&mut self, package: Option<&str>, lines: usize) {
+        self.source_lines += lines;
+        if let Some(package) = package {
+            self.packages
+                .entry(package.to_string())
+                .or_default()
+                .source_lines += lines;
+        }
+    }
+
+    /// Record a Rust source file's unsafe code and non-test source lines.
+    pub(super) fn add_unsafe(
+        &mut self,
+        package: Option<&str>,
+        counts: UnsafeCounts,
+        source_lines: usize,
+    ) {
+        let tally = Some(UnsafeTally {
+            counts,
+            source_lines,
+        });
+        add_unsafe(&mut self.unsafe_code, tally);
+        if let Some(package) = package {
+            let pkg = self.packages.entry(package.to_string()).or_default();
+            add_unsafe(&mut pkg.unsafe_code, tally);
+        }
+    }
+
+    pub(super) fn add_bare_assertion(&mut self) {
+        self.bare_assertions += 1;
+    }
+
+    /// Add another file's counts to these.
+    pub(super) fn merge(&mut self, other: EscapesMetrics) {
+        add_counts(&mut self.source, other.source);
+        add_counts(&mut self.test, other.test);
+        self.source_lines += other.source_lines;
+        self.bare_assertions += other.bare_assertions;
+        add_unsafe(&mut self.unsafe_code, other.unsafe_code);
+        for (name, package) in other.packages {
+            let pkg = self.packages.entry(name).or_default();
+            add_counts(&mut pkg.source, package.source);
+            add_counts(&mut pkg.test, package.test);
+            pkg.source_lines += package.source_lines;
+            add_unsafe(&mut pkg.unsafe_code, package.unsafe_code);
+        }
+    }
+
+    pub(super) fn source_count(&self, pattern_name: &str) -> usize {
+        self.source.get(pattern_name).copied().unwrap_or(0)
+    }
+
+    pub(super) fn source_lines(&self) -> usize {
+        self.source_lines
+    }
+
+    /// Convert to JSON metrics structure.
+    pub(super) fn to_json(&self, pattern_names: &[String]) -> JsonValue {
+        Escapes {
+            bare_assertions: Some(self.bare_assertions),
+            unsafe_code: self.unsafe_code.map(|tally| tally.typed()),
+            ..typed(&self.source, &self.test, self.source_lines, pattern_names)
+        }
+        .to_json()
+    }
+
+    /// Convert to by_package structure (only if packages exist).
+    pub(super) fn to_by_package(
+        &self,
+        pattern_names: &[String],
+    ) -> Option<BTreeMap<String, JsonValue>> {
+        if self.packages.is_empty() {
+            return None;
+        }
+
+        let result = self
+            .packages
+            .iter()
+            .map(|(pkg_name, pkg_metrics)| {
+                let metrics = Escapes {
+                    unsafe_code: pkg_metrics.unsafe_code.map(|tally| tally.typed()),
+                    ..typed(
+                        &pkg_metrics.source,
+                        &pkg_metrics.test,
+                        pkg_metrics.source_lines,
+                        pattern_names,
+                    )
+                };
+                (pkg_name.clone(), metrics.to_json())
+            })
+            .collect();
+
+        Some(result)
+    }
+}
+
+/// Counts and source density per pattern, including patterns with no matches.
+fn typed(
+    source: &BTreeMap<String, usize>,
+    test: &BTreeMap<String, usize>,
+    source_lines: usize,
+    pattern_names: &[String],
+) -> Escapes {
+    let count =
+        |counts: &BTreeMap<String, usize>, name: &String| counts.get(name).copied().unwrap_or(0);
+    Escapes {
+        source: pattern_names
+            .iter()
+            .map(|name| (name.clone(), count(source, name)))
+            .collect(),
+        test: pattern_names
+            .iter()
+            .map(|name| (name.clone(), count(test, name)))
+            .collect(),
+        source_lines,
+        density: pattern_names
+            .iter()
+            .map(|name| (name.clone(), density(count(source, name), source_lines)))
+            .collect(),
+        bare_assertions: None,
+        unsafe_code: None,
+    }
+}
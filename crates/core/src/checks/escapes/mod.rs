@@ -0,0 +1,657 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Escapes (escape hatches) check.
+//!
+//! Detects patterns that bypass type safety or error handling.
+//! See docs/specs/checks/escape-hatches.md.
+
+mod assertions;
+mod comment;
+mod fix;
+mod go_suppress;
+mod javascript_suppress;
+mod matcher;
+mod metrics;
+mod patterns;
+mod python_suppress;
+mod ruby_suppress;
+mod rust_suppress;
+mod shell_suppress;
+mod suppress_common;
+mod violations;
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::adapter::{CfgTestInfo, FileKind, GenericAdapter, InlineTestInfo, UnsafeInfo};
+use crate::check::{Check, CheckContext, CheckResult, Violation};
+use crate::config::{CheckLevel, Config, EscapeAction};
+use crate::fix::Fix;
+use go_suppress::{check_go_suppress_violations, go_directives};
+use javascript_suppress::{check_javascript_suppress_violations, javascript_directives};
+use python_suppress::{check_python_suppress_violations, python_directives};
+use ruby_suppress::{check_ruby_suppress_violations, ruby_directives};
+use rust_suppress::{check_rust_suppress_violations, rust_directives};
+use shell_suppress::{check_shell_suppress_violations, shell_directives};
+
+pub use matcher::EscapeMatcher;
+pub use suppress_common::UnifiedSuppressDirective;
+
+use comment::{has_justification_comment, is_match_in_comment};
+use matcher::ExcludeMatcher;
+use metrics::{EscapesMetrics, density};
+use patterns::{
+    PatternSetCache, compile_merged_patterns, default_test_patterns, get_adapter_escape_patterns,
+    merge_patterns,
+};
+use violations::{
+    create_density_violation, create_threshold_violation, format_comment_advice,
+    try_create_violation,
+};
+
+/// Comment required by `action = "comment"` patterns that don't set one.
+const DEFAULT_COMMENT: &str = "// JUSTIFIED:";
+
+/// The escapes check detects escape hatch patterns.
+pub struct EscapesCheck;
+
+impl Check for EscapesCheck {
+    fn name(&self) -> &'static str {
+        "escapes"
+    }
+
+    fn description(&self) -> &'static str {
+        "Escape hatch detection"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let config = &ctx.config.check.escapes;
+
+        if config.check == CheckLevel::Off {
+            return CheckResult::passed(self.name());
+        }
+
+        // Check lint policy for language-specific projects (only when --base is provided)
+        let policy_result = crate::checks::policy::check_lint_policy(ctx);
+
+        // Get adapter default patterns for the detected language
+        let adapter_patterns = get_adapter_escape_patterns(ctx.root);
+
+        // Merge patterns: config patterns override adapter defaults by name
+        let merged_patterns = merge_patterns(&config.patterns, &adapter_patterns);
+
+        // No patterns to check = pass
+        if merged_patterns.is_empty() {
+            return CheckResult::passed(self.name());
+        }
+
+        // Compile patterns once
+        let patterns = match compile_merged_patterns(&merged_patterns) {
+            Ok(p) => p,
+            Err(e) => return CheckResult::skipped(self.name(), e.to_string()),
+        };
+
+        // Collect pattern names for metrics output
+        let pattern_names: Vec<String> = patterns.iter().map(|p| p.name.clone()).collect();
+
+        // Get packages for by_package tracking
+        let packages = &ctx.config.project.packages;
+
+        // Use project test patterns or defaults
+        let test_patterns = if ctx.config.project.tests.is_empty() {
+            default_test_patterns()
+        } else {
+            ctx.config.project.tests.clone()
+        };
+
+        // Create adapter once for file classification (optimization: avoid per-file allocation)
+        let file_adapter = GenericAdapter::new(&[], &test_patterns);
+
+        // Build exclude matcher
+        let exclude_matcher = ExcludeMatcher::new(&config.exclude);
+
+        // Per-extension prefilters, compiled once and reused across files
+        let pattern_sets = PatternSetCache::new(&patterns);
+
+        // Bare assertions are only reported in test files changed on the branch
+        let changed: HashSet<&Path> = ctx
+            .changed_files
+            .unwrap_or_default()
+            .iter()
+            .map(|p| p.strip_prefix(ctx.root).unwrap_or(p))
+            .collect();
+        let assertion_advice = config
+            .assertions
+            .advice
+            .as_deref()
+            .unwrap_or(BARE_ASSERTION_ADVICE);
+
+        // Files are scanned in parallel, then merged in file order so the
+        // violation limit cuts off the same violations a serial scan would
+        let scans = ctx.par_scan_files(|ctx, file| {
+            // Skip non-source files (configs, docs, etc.)
+            if !is_source_file(&file.path) {
+                return None;
+            }
+
+            // Skip excluded files
+            if exclude_matcher.is_excluded(&file.path, ctx.root) {
+                return None;
+            }
+
+            // Skip files no pattern or suppress check applies to (before reading)
+            let ext = file_extension(&file.path);
+            if pattern_sets.for_extension(&ext).is_empty()
+                && !has_extension(&file.path, SUPPRESS_EXTENSIONS)
+                && !crate::blocks::is_multi_language(&ext)
+            {
+                return None;
+            }
+
+            // Read file content (uses mmap for large files per performance spec)
+            let file_content = ctx.read_file(&file.path).ok()?;
+            // Skip binary files
+            let content = file_content.as_str()?;
+
+            let relative = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
+            let mut scan = FileScan::default();
+            // The scan context has no limit; the merge below applies it
+            let mut limit_reached = false;
+
+            // Classify file as source or test
+            let is_test_file = classify_file(&file_adapter, &file.path, ctx.root) == FileKind::Test;
+            let package = find_package(&file.path, ctx.root, packages);
+
+            // Parse cfg(test) info for Rust files (reuse for suppress + escape checking)
+            let cfg_info = if has_extension(&file.path, &["rs"]) {
+                Some(CfgTestInfo::parse(content))
+            } else {
+                None
+            };
+            // Inline test lines in any language, for test classification
+            let inline = match cfg_info {
+                Some(ref info) => Some(InlineTestInfo::from(info)),
+                None => InlineTestInfo::parse(&file.path, content),
+            };
+
+            // Count non-blank source lines for density
+            if !is_test_file {
+                let source_lines = content
+                    .lines()
+                    .enumerate()
+                    .filter(|(i, line)| {
+                        !line.trim().is_empty()
+                            && !inline.as_ref().is_some_and(|info| info.is_test_line(*i))
+                    })
+                    .count();
+                scan.metrics
+                    .add_source_lines(package.as_deref(), source_lines);
+
+                // Unsafe Rust outside test code (files that don't parse are left out)
+                if cfg_info.is_some()
+                    && let Some(info) = UnsafeInfo::parse(content)
+                {
+                    let counts = info.count(content, |line| {
+                        inline.as_ref().is_some_and(|info| info.is_test_line(line))
+                    });
+                    scan.metrics
+                        .add_unsafe(package.as_deref(), counts, source_lines);
+                }
+            }
+
+            // Count bare assertions in test code whether or not the rule is on
+            for bare in assertions::find_bare_assertions(&ext, content) {
+                let in_test = is_test_file
+                    || inline
+                        .as_ref()
+                        .is_some_and(|info| info.is_test_line(bare.line as usize - 1));
+                if !in_test {
+                    continue;
+                }
+                scan.metrics.add_bare_assertion();
+
+                if config.assertions.check == CheckLevel::Off || !changed.contains(relative) {
+                    continue;
+                }
+                scan.assertion_violations.extend(try_create_violation(
+                    ctx,
+                    relative,
+                    bare.line,
+                    "bare_assertion",
+                    assertion_advice,
+                    bare.form,
+                ));
+            }
+
+            // Check for Rust suppress attribute violations
+            if let Some(ref info) = cfg_info {
+                scan.violations.extend(check_rust_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    &ctx.config.rust.suppress,
+                    is_test_file,
+                    info,
+                    &mut limit_reached,
+                ));
+            }
+
+            // Check for Shell shellcheck suppress directive violations
+            if has_extension(&file.path, &["sh", "bash", "bats"]) {
+                scan.violations.extend(check_shell_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    &ctx.config.shell.suppress,
+                    is_test_file,
+                    &mut limit_reached,
+                ));
+            }
+
+            // Check for Go nolint directive violations
+            if has_extension(&file.path, &["go"]) {
+                scan.violations.extend(check_go_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    &ctx.config.golang.suppress,
+                    is_test_file,
+                    &mut limit_reached,
+                ));
+            }
+
+            // Check for JavaScript/TypeScript suppress directive violations
+            if has_extension(&file.path, &["js", "jsx", "ts", "tsx", "mjs", "mts"]) {
+                scan.violations.extend(check_javascript_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    &ctx.config.javascript.suppress,
+                    is_test_file,
+                    &mut limit_reached,
+                ));
+            }
+
+            // Check for Ruby RuboCop/Standard suppress directive violations
+            if has_extension(&file.path, &["rb", "rake"]) {
+                scan.violations.extend(check_ruby_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    &ctx.config.ruby.suppress,
+                    is_test_file,
+                    &mut limit_reached,
+                ));
+            }
+
+            // Check for Python suppress directive violations (noqa, type: ignore, pylint)
+            if has_extension(&file.path, &["py"]) {
+                scan.violations.extend(check_python_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    &ctx.config.python.suppress,
+                    is_test_file,
+                    &mut limit_reached,
+                ));
+            }
+
+            // Multi-language files are matched block by block with each block's
+            // language patterns; `line_offset` maps block lines to file lines
+            let blocks = crate::blocks::split(&ext, content);
+            let segments: Vec<(&str, u32, &str)> = match &blocks {
+                Some(blocks) => blocks
+                    .iter()
+                    .map(|b| (b.ext, b.line - 1, b.content.as_str()))
+                    .collect(),
+                None => vec![(ext.as_str(), 0, content)],
+            };
+
+            for (block_ext, line_offset, content) in segments {
+                // Find matches for each pattern that can match (one RegexSet scan)
+                for pattern_idx in pattern_sets.for_extension(block_ext).candidates(content) {
+                    let pattern = &patterns[pattern_idx];
+                    let matches = pattern.matcher.find_all_with_lines(content);
+
+                    // Deduplicate matches by line - keep only first match per line
+                    // This prevents duplicate violations when pattern appears multiple
+                    // times on same line (e.g., in code AND in a comment)
+                    let mut seen_lines = HashSet::new();
+                    let unique_matches: Vec<_> = matches
+                        .into_iter()
+                        .filter(|m| seen_lines.insert(m.line))
+                        .collect();
+
+                    for m in unique_matches {
+                        // Calculate offset of match within the line
+                        let line_start =
+                            content[..m.offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+                        let offset_in_line = m.offset - line_start;
+
+                        // For comment and forbid actions, skip matches that appear only in comments.
+                        // This prevents false positives like "don't use eval" in explanatory comments.
+                        // Count action patterns (like TODO/FIXME) are often legitimately in comments.
+                        let skip_comment_matches =
+                            matches!(pattern.action, EscapeAction::Comment | EscapeAction::Forbid);
+                        if skip_comment_matches
+                            && is_match_in_comment(&m.line_content, offset_in_line)
+                        {
+                            continue;
+                        }
+
+                        // Check if line is in test code (file-level OR inline tests)
+                        // Note: m.line is 1-indexed, but is_test_line expects 0-indexed
+                        let is_test_code = is_test_file
+                            || inline.as_ref().is_some_and(|info| {
+                                info.is_test_line(m.line.saturating_sub(1) as usize)
+                            });
+
+                        // Always track metrics (both source and test)
+                        scan.metrics.increment(&pattern.name, is_test_code);
+                        if let Some(ref pkg) = package {
+                            scan.metrics
+                                .increment_package(pkg, &pattern.name, is_test_code);
+                        }
+
+                        // Handle test code based on pattern's in_tests setting
+                        if is_test_code {
+                            // Determine effective action for test code
+                            let test_action = match pattern.in_tests.as_deref() {
+                                Some("allow") => None, // Skip violations
+                                Some("forbid") => Some(EscapeAction::Forbid),
+                                Some("comment") => Some(EscapeAction::Comment),
+                                // Default: all patterns are allowed in tests (original behavior)
+                                // To make a pattern forbidden in tests, set in_tests = "forbid"
+                                None => None,
+                                _ => None, // Unknown value -> allow
+                            };
+
+                            // Skip if no violations needed for test code
+                            if test_action.is_none() {
+                                continue;
+                            }
+                        }
+
+                        // Source code: apply action logic
+                        match pattern.action {
+                            EscapeAction::Count => {
+                                // Just count - threshold check happens after all files
+                            }
+                            EscapeAction::Comment => {
+                                let comment_pattern =
+                                    pattern.comment.as_deref().unwrap_or(DEFAULT_COMMENT);
+
+                                if !has_justification_comment(content, m.line, comment_pattern) {
+                                    let advice =
+                                        format_comment_advice(&pattern.advice, comment_pattern);
+                                    scan.violations.extend(try_create_violation(
+                                        ctx,
+                                        relative,
+                                        m.line + line_offset,
+                                        "missing_comment",
+                                        &advice,
+                                        &pattern.name,
+                                    ));
+                                }
+                            }
+                            EscapeAction::Forbid => {
+                                scan.violations.extend(try_create_violation(
+                                    ctx,
+                                    relative,
+                                    m.line + line_offset,
+                                    "forbidden",
+                                    &pattern.advice,
+                                    &pattern.name,
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+
+            Some(scan)
+        });
+
+        let mut violations = Vec::new();
+        let mut assertion_violations = Vec::new();
+        let mut metrics = EscapesMetrics::new();
+        let mut limit_reached = false;
+
+        for scan in scans.into_iter().flatten() {
+            metrics.merge(scan.metrics);
+            if limit_reached {
+                continue;
+            }
+            // Bare assertions come first, as each file is scanned
+            for (found, into) in [
+                (scan.assertion_violations, &mut assertion_violations),
+                (scan.violations, &mut violations),
+            ] {
+                for violation in found {
+                    if !ctx.admit_violation() {
+                        limit_reached = true;
+                        break;
+                    }
+                    into.push(violation);
+                }
+                if limit_reached {
+                    break;
+                }
+            }
+        }
+        // Check count thresholds after scanning all files (uses metrics)
+        for pattern in &patterns {
+            if pattern.action == EscapeAction::Count {
+                let count = metrics.source_count(&pattern.name);
+                if count > pattern.threshold
+                    && let Some(v) = create_threshold_violation(
+                        ctx,
+                        &pattern.name,
+                        count,
+                        pattern.threshold,
+                        &pattern.advice,
+                    )
+                {
+                    violations.push(v);
+                }
+            }
+        }
+
+        // Check density thresholds (occurrences per 1000 source lines)
+        for pattern in &patterns {
+            let Some(max_density) = pattern.max_density else {
+                continue;
+            };
+            let count = metrics.source_count(&pattern.name);
+            let source_lines = metrics.source_lines();
+            if density(count, source_lines) > max_density
+                && let Some(v) = create_density_violation(
+                    ctx,
+                    &pattern.name,
+                    count,
+                    source_lines,
+                    max_density,
+                    &pattern.advice,
+                )
+            {
+                violations.push(v);
+            }
+        }
+
+        // Error-level bare assertions fail like escapes; warn-level ones are
+        // reported alongside policy warnings
+        let assertion_warnings = if config.assertions.check == CheckLevel::Error {
+            violations.append(&mut assertion_violations);
+            Vec::new()
+        } else {
+            assertion_violations
+        };
+
+        // Handle policy violations based on their check level
+        let has_escape_violations = !violations.is_empty();
+        let policy_fails = !policy_result.errors.is_empty();
+        let mut policy_violations = policy_result.errors;
+        policy_violations.extend(policy_result.warnings);
+        policy_violations.extend(assertion_warnings);
+
+        // Build result with metrics
+        let result = if has_escape_violations {
+            // Escape violations always cause failure, include policy violations too
+            violations.extend(policy_violations);
+            CheckResult::failed(self.name(), violations)
+        } else if policy_fails {
+            // Error level: fail, reporting warn-level languages alongside
+            CheckResult::failed(self.name(), policy_violations)
+        } else if !policy_violations.is_empty() {
+            // Warn level only: report but don't fail
+            CheckResult::passed_with_warnings(self.name(), policy_violations)
+        } else {
+            CheckResult::passed(self.name())
+        };
+
+        // Add metrics to result
+        let result = result.with_metrics(metrics.to_json(&pattern_names));
+
+        // Add by_package if workspace configured
+        if let Some(by_package) = metrics.to_by_package(&pattern_names) {
+            result.with_by_package(by_package)
+        } else {
+            result
+        }
+    }
+
+    fn default_enabled(&self) -> bool {
+        true
+    }
+
+    fn fixer(&self) -> Option<&dyn Fix> {
+        Some(self)
+    }
+}
+
+/// What scanning one file found.
+#[derive(Default)]
+struct FileScan {
+    metrics: EscapesMetrics,
+    /// Pattern and suppress violations, in scan order.
+    violations: Vec<Violation>,
+    /// Bare assertion violations, in line order.
+    assertion_violations: Vec<Violation>,
+}
+
+/// Default advice for `bare_assertion` violations.
+const BARE_ASSERTION_ADVICE: &str = "Add a message saying what was expected, or compare values (assert_eq!, toEqual) so the failure shows them.";
+
+/// Classify file as source or test using a pre-built adapter.
+fn classify_file(adapter: &GenericAdapter, path: &Path, root: &Path) -> FileKind {
+    use crate::adapter::Adapter;
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    adapter.classify(relative)
+}
+
+/// Find which package a file belongs to, if any.
+fn find_package(path: &Path, root: &Path, packages: &[String]) -> Option<String> {
+    let relative = path.strip_prefix(root).ok()?;
+    let relative_str = relative.to_string_lossy();
+
+    // Check if file is under any package directory
+    for pkg in packages {
+        // Handle wildcard patterns like "crates/*"
+        let prefix = pkg.trim_end_matches("/*");
+        if relative_str.starts_with(prefix) {
+            // Extract package name from path
+            let rest = relative_str.strip_prefix(prefix)?.trim_start_matches('/');
+            let parts: Vec<&str> = rest.split('/').collect();
+
+            if pkg.ends_with("/*") {
+                // Wildcard: first component after prefix is package name
+                if let Some(name) = parts.first()
+                    && !name.is_empty()
+                {
+                    return Some((*name).to_string());
+                }
+            } else {
+                // Exact path: use the last component of the pattern
+                return Some(pkg.split('/').next_back().unwrap_or(pkg).to_string());
+            }
+        }
+    }
+
+    None
+}
+
+/// Check if a file is a source code file (for escape pattern checking).
+/// Excludes configuration files, documentation, and data files.
+fn is_source_file(path: &Path) -> bool {
+    let ext = file_extension(path);
+
+    matches!(
+        ext.as_str(),
+        // Systems languages
+        "rs" | "c" | "cpp" | "h" | "hpp" | "go"
+        // JVM languages
+        | "java" | "kt" | "scala"
+        // Dynamic languages
+        | "py" | "rb" | "php" | "lua" | "pl" | "pm" | "r"
+        // JavaScript/TypeScript
+        | "js" | "ts" | "jsx" | "tsx"
+        // Apple platforms
+        | "swift" | "m" | "mm"
+        // .NET
+        | "cs"
+        // Shell scripts
+        | "sh" | "bash" | "zsh"
+        // Web
+        | "html" | "css" | "vue" | "svelte"
+        // Notebooks
+        | "ipynb"
+        // Other
+        | "sql" | "ex" | "exs" | "erl" | "clj" | "hs" | "ml"
+    )
+}
+
+/// Extensions with a language-specific suppress check.
+const SUPPRESS_EXTENSIONS: &[&str] = &[
+    "rs", "sh", "bash", "bats", "go", "js", "jsx", "ts", "tsx", "mjs", "mts", "rb", "rake", "py",
+];
+
+/// Lint suppression directives in a file, for the suppression inventory
+/// ([`crate::suppressions`]). Empty for files without a suppress check.
+pub fn suppress_directives(
+    path: &Path,
+    content: &str,
+    config: &Config,
+) -> Vec<UnifiedSuppressDirective> {
+    match file_extension(path).as_str() {
+        "rs" => rust_directives(content),
+        "sh" | "bash" | "bats" => shell_directives(content),
+        "go" => go_directives(content, &config.golang.suppress),
+        "js" | "jsx" | "ts" | "tsx" | "mjs" | "mts" => {
+            javascript_directives(content, &config.javascript.suppress)
+        }
+        "rb" | "rake" => ruby_directives(content),
+        "py" => python_directives(content, &config.python.suppress),
+        _ => Vec::new(),
+    }
+}
+
+/// Lowercased file extension ("" if none).
+fn file_extension(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
+
+/// Check if a file has one of the given extensions (case-insensitive).
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| extensions.iter().any(|ext| e.eq_ignore_ascii_case(ext)))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
@@ -99,6 +99,21 @@ fn is_match_in_comment_cases(line: &str, offset: usize, expected: bool) {
         1,
         false
     },
+    todo_placeholder_does_not_match = {
+        "// SAFETY: TODO\nunsafe { code }",
+        2,
+        false
+    },
+    fixme_placeholder_does_not_match = {
+        "unsafe { code } // SAFETY: fixme.",
+        1,
+        false
+    },
+    reason_mentioning_todo_matches = {
+        "// SAFETY: TODO list is never shared\nunsafe { code }",
+        2,
+        true
+    },
 )]
 fn comment_boundary_cases(content: &str, line: u32, expected: bool) {
     assert_eq!(
@@ -139,6 +154,67 @@ fn strip_comment_markers_cases(input: &str, expected: &str) {
     );
 }
 
+fn scoped_pattern(
+    name: &str,
+    regex: &str,
+    extensions: &'static [&'static str],
+) -> patterns::CompiledEscapePattern {
+    patterns::CompiledEscapePattern {
+        name: name.to_string(),
+        regex: regex.to_string(),
+        matcher: crate::pattern::CompiledPattern::compile(regex).unwrap(),
+        action: EscapeAction::Forbid,
+        advice: String::new(),
+        comment: None,
+        threshold: 0,
+        max_density: None,
+        in_tests: None,
+        extensions,
+    }
+}
+
+#[test]
+fn pattern_set_cache_scopes_patterns_by_extension() {
+    let patterns = vec![
+        scoped_pattern("unsafe", r"unsafe\s*\{", &["rs"]),
+        scoped_pattern("todo", "TODO", &[]),
+    ];
+    let cache = PatternSetCache::new(&patterns);
+
+    let content = "unsafe { x } // TODO";
+    assert_eq!(cache.for_extension("rs").candidates(content), vec![0, 1]);
+    assert_eq!(cache.for_extension("py").candidates(content), vec![1]);
+}
+
+#[test]
+fn pattern_set_cache_reports_empty_extensions() {
+    let patterns = vec![scoped_pattern("unsafe", r"unsafe\s*\{", &["rs"])];
+    let cache = PatternSetCache::new(&patterns);
+
+    assert!(!cache.for_extension("rs").is_empty());
+    assert!(cache.for_extension("py").is_empty());
+}
+
+#[test]
+fn pattern_set_cache_skips_patterns_absent_from_content() {
+    let patterns = vec![
+        scoped_pattern("unwrap", r"\.unwrap\(\)", &[]),
+        scoped_pattern("todo", "TODO", &[]),
+    ];
+    let cache = PatternSetCache::new(&patterns);
+
+    assert_eq!(
+        cache.for_extension("rs").candidates("// TODO: later"),
+        vec![1]
+    );
+    assert!(
+        cache
+            .for_extension("rs")
+            .candidates("fn main() {}")
+            .is_empty()
+    );
+}
+
 // Performance micro-benchmarks
 // Run with: cargo test --package quench -- bench_ --ignored --nocapture
 mod benchmarks {
@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Escape pattern compilation and merging utilities.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use crate::adapter::{
+    EscapePattern as AdapterEscapePattern, GoAdapter, JavaScriptAdapter, ProjectLanguage,
+    PythonAdapter, RubyAdapter, RustAdapter, ShellAdapter, detect_language,
+};
+use crate::config::{EscapeAction, EscapePattern as ConfigEscapePattern};
+use crate::pattern::{CompiledPattern, PatternError, PatternSet};
+
+use super::violations::default_advice;
+
+/// Compiled escape pattern ready for matching.
+pub(super) struct CompiledEscapePattern {
+    pub(super) name: String,
+    /// Original pattern string (for batching into a `RegexSet`).
+    pub(super) regex: String,
+    pub(super) matcher: CompiledPattern,
+    pub(super) action: EscapeAction,
+    pub(super) advice: String,
+    /// Required comment pattern for action = comment.
+    pub(super) comment: Option<String>,
+    /// Count threshold for action = count (default: 0).
+    pub(super) threshold: usize,
+    /// Maximum source occurrences per 1000 source lines.
+    pub(super) max_density: Option<f64>,
+    /// Override action for test code ("allow" | "comment" | "forbid").
+    pub(super) in_tests: Option<String>,
+    /// File extensions this pattern applies to (empty = all source files).
+    pub(super) extensions: &'static [&'static str],
+}
+
+/// Adapter default patterns and the file extensions they apply to.
+#[derive(Default)]
+pub(super) struct AdapterEscapes {
+    pub(super) patterns: Vec<ConfigEscapePattern>,
+    pub(super) extensions: &'static [&'static str],
+}
+
+/// An escape pattern with the file extensions it is scoped to.
+pub(super) struct ScopedPattern {
+    pub(super) pattern: ConfigEscapePattern,
+    /// Empty = all source files.
+    pub(super) extensions: &'static [&'static str],
+}
+
+/// Default test patterns for file classification.
+pub(super) fn default_test_patterns() -> Vec<String> {
+    vec![
+        "**/tests/**".to_string(),
+        "**/test/**".to_string(),
+        "**/benches/**".to_string(),
+        "benches/**".to_string(),
+        "**/test_utils.*".to_string(),
+        "test_utils.*".to_string(),
+        "**/*_test.*".to_string(),
+        "**/*_tests.*".to_string(),
+        "**/*.test.*".to_string(),
+        "**/*.spec.*".to_string(),
+        // Ruby RSpec patterns
+        "spec/**/*_spec.rb".to_string(),
+        "**/spec/**/*_spec.rb".to_string(),
+        // Ruby Cucumber/features patterns
+        "features/**/*.rb".to_string(),
+        "**/features/**/*.rb".to_string(),
+    ]
+}
+
+/// Get escape patterns from the adapter for the detected language.
+///
+/// Adapter defaults only apply to files of the adapter's language.
+pub(super) fn get_adapter_escape_patterns(root: &Path) -> AdapterEscapes {
+    use crate::adapter::Adapter;
+
+    fn from_adapter(adapter: &impl Adapter) -> AdapterEscapes {
+        AdapterEscapes {
+            patterns: convert_adapter_patterns(adapter.default_escapes()),
+            extensions: adapter.extensions(),
+        }
+    }
+
+    match detect_language(root) {
+        ProjectLanguage::Rust => from_adapter(&RustAdapter::new()),
+        ProjectLanguage::Go => from_adapter(&GoAdapter::new()),
+        ProjectLanguage::Shell => from_adapter(&ShellAdapter::new()),
+        ProjectLanguage::JavaScript => from_adapter(&JavaScriptAdapter::new()),
+        ProjectLanguage::Python => from_adapter(&PythonAdapter::new()),
+        ProjectLanguage::Ruby => from_adapter(&RubyAdapter::new()),
+        // No default patterns for generic projects
+        ProjectLanguage::Generic => AdapterEscapes::default(),
+    }
+}
+
+/// Convert adapter escape patterns to config format.
+fn convert_adapter_patterns(adapter_patterns: &[AdapterEscapePattern]) -> Vec<ConfigEscapePattern> {
+    adapter_patterns
+        .iter()
+        .map(|p| ConfigEscapePattern {
+            name: Some(p.name.to_string()),
+            pattern: p.pattern.to_string(),
+            action: adapter_action_to_config(p.action),
+            comment: p.comment.map(String::from),
+            advice: Some(p.advice.to_string()),
+            threshold: 0,
+            max_density: None,
+            source: Vec::new(),
+            tests: Vec::new(),
+            in_tests: p.in_tests.map(String::from),
+        })
+        .collect()
+}
+
+/// Convert adapter EscapeAction to config EscapeAction.
+fn adapter_action_to_config(action: crate::adapter::EscapeAction) -> EscapeAction {
+    match action {
+        crate::adapter::EscapeAction::Count => EscapeAction::Count,
+        crate::adapter::EscapeAction::Comment => EscapeAction::Comment,
+        crate::adapter::EscapeAction::Forbid => EscapeAction::Forbid,
+    }
+}
+
+/// Merge user config patterns with adapter defaults.
+/// User patterns override defaults by name and apply to all source files.
+pub(super) fn merge_patterns(
+    config_patterns: &[ConfigEscapePattern],
+    adapter: &AdapterEscapes,
+) -> Vec<ScopedPattern> {
+    let mut merged = Vec::new();
+    let config_names: HashSet<_> = config_patterns.iter().map(|p| p.effective_name()).collect();
+
+    // Add adapter defaults not overridden by config
+    for pattern in &adapter.patterns {
+        if !config_names.contains(pattern.effective_name()) {
+            merged.push(ScopedPattern {
+                pattern: pattern.clone(),
+                extensions: adapter.extensions,
+            });
+        }
+    }
+
+    // Add all config patterns (they take precedence)
+    merged.extend(config_patterns.iter().map(|p| ScopedPattern {
+        pattern: p.clone(),
+        extensions: &[],
+    }));
+
+    merged
+}
+
+/// Compile merged patterns into matchers.
+pub(super) fn compile_merged_patterns(
+    patterns: &[ScopedPattern],
+) -> Result<Vec<CompiledEscapePattern>, PatternError> {
+    patterns
+        .iter()
+        .map(|scoped| {
+            let p = &scoped.pattern;
+            let matcher = CompiledPattern::compile(&p.pattern)?;
+            let advice = p
+                .advice
+                .clone()
+                .unwrap_or_else(|| default_advice(&p.action));
+            Ok(CompiledEscapePattern {
+                name: p.effective_name().to_string(),
+                regex: p.pattern.clone(),
+                matcher,
+                action: p.action,
+                advice,
+                comment: p.comment.clone(),
+                threshold: p.threshold,
+                max_density: p.max_density,
+                in_tests: p.in_tests.clone(),
+                extensions: scoped.extensions,
+            })
+        })
+        .collect()
+}
+
+/// Patterns applicable to one file extension.
+pub(super) struct ExtensionPatterns {
+    /// Indices into the compiled pattern list.
+    ids: Vec<usize>,
+    /// Batched prefilter (None if the set failed to compile, e.g. size limits).
+    prefilter: Option<PatternSet>,
+}
+
+impl ExtensionPatterns {
+    /// Whether no pattern applies to this extension.
+    pub(super) fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Indices of patterns worth running on `content`, in pattern order.
+    pub(super) fn candidates(&self, content: &str) -> Vec<usize> {
+        match &self.prefilter {
+            Some(set) => set.matching(content),
+            None => self.ids.clone(),
+        }
+    }
+}
+
+/// Per-extension pattern prefilters, compiled on first use and reused
+/// for every later file with the same extension. Shared by the threads
+/// scanning files.
+pub(super) struct PatternSetCache<'a> {
+    patterns: &'a [CompiledEscapePattern],
+    sets: RwLock<HashMap<String, Arc<ExtensionPatterns>>>,
+}
+
+impl<'a> PatternSetCache<'a> {
+    pub(super) fn new(patterns: &'a [CompiledEscapePattern]) -> Self {
+        Self {
+            patterns,
+            sets: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Get (or build) the patterns applicable to a file extension.
+    pub(super) fn for_extension(&self, ext: &str) -> Arc<ExtensionPatterns> {
+        if let Some(set) = self.sets.read().unwrap_or_else(|e| e.into_inner()).get(ext) {
+            return Arc::clone(set);
+        }
+        let patterns = self.patterns;
+        let mut sets = self.sets.write().unwrap_or_else(|e| e.into_inner());
+        let set = sets.entry(ext.to_string()).or_insert_with(|| {
+            let ids: Vec<usize> = patterns
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| p.extensions.is_empty() || p.extensions.contains(&ext))
+                .map(|(i, _)| i)
+                .collect();
+            let prefilter =
+                PatternSet::new(ids.iter().map(|&i| (i, patterns[i].regex.as_str()))).ok();
+            Arc::new(ExtensionPatterns { ids, prefilter })
+        });
+        Arc::clone(set)
+    }
+}
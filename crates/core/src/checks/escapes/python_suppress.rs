@@ -22,11 +22,26 @@ pub(super) fn check_python_suppress_violations(
     is_test_file: bool,
     limit_reached: &mut bool,
 ) -> Vec<Violation> {
-    // Parse suppress directives
-    let suppresses = parse_python_suppresses(content, config.comment.as_deref());
+    let unified = python_directives(content, config);
 
-    // Convert to unified format
-    let unified: Vec<UnifiedSuppressDirective> = suppresses
+    check_suppress_violations_generic(
+        ctx,
+        path,
+        unified,
+        config,
+        "python",
+        "suppress",
+        is_test_file,
+        limit_reached,
+    )
+}
+
+/// Parse Python suppress directives into the unified format.
+pub(super) fn python_directives(
+    content: &str,
+    config: &PythonSuppressConfig,
+) -> Vec<UnifiedSuppressDirective> {
+    parse_python_suppresses(content, config.comment.as_deref())
         .into_iter()
         .map(|s| {
             let pattern = match s.kind {
@@ -57,16 +72,5 @@ pub(super) fn check_python_suppress_violations(
                 pattern,
             }
         })
-        .collect();
-
-    check_suppress_violations_generic(
-        ctx,
-        path,
-        unified,
-        config,
-        "python",
-        "suppress",
-        is_test_file,
-        limit_reached,
-    )
+        .collect()
 }
@@ -20,11 +20,23 @@ pub(super) fn check_ruby_suppress_violations(
     is_test_file: bool,
     limit_reached: &mut bool,
 ) -> Vec<Violation> {
-    // Parse RuboCop/Standard suppress directives
-    let suppresses = parse_ruby_suppresses(content, None);
+    let unified = ruby_directives(content);
 
-    // Convert to unified format
-    let unified: Vec<UnifiedSuppressDirective> = suppresses
+    check_suppress_violations_generic(
+        ctx,
+        path,
+        unified,
+        config,
+        "ruby",
+        "suppress",
+        is_test_file,
+        limit_reached,
+    )
+}
+
+/// Parse RuboCop/Standard suppress directives into the unified format.
+pub(super) fn ruby_directives(content: &str) -> Vec<UnifiedSuppressDirective> {
+    parse_ruby_suppresses(content, None)
         .into_iter()
         .map(|s| {
             let code = s.codes.first().map(|c| c.as_str()).unwrap_or("unknown");
@@ -38,16 +50,5 @@ pub(super) fn check_ruby_suppress_violations(
                 pattern,
             }
         })
-        .collect();
-
-    check_suppress_violations_generic(
-        ctx,
-        path,
-        unified,
-        config,
-        "ruby",
-        "suppress",
-        is_test_file,
-        limit_reached,
-    )
+        .collect()
 }
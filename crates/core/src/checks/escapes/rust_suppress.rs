@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust suppress attribute checking for the escapes check.
+//!
+//! Checks `#[allow(...)]` and `#[expect(...)]` attributes and enforces
+//! comment requirements.
+
+use std::path::Path;
+
+use crate::adapter::{CfgTestInfo, parse_suppress_attrs};
+use crate::check::{CheckContext, Violation};
+use crate::config::{SuppressConfig, SuppressLevel};
+
+use super::suppress_common::{
+    SuppressAttrInfo, SuppressCheckParams, SuppressViolationKind, UnifiedSuppressDirective,
+    build_suppress_missing_comment_advice, check_suppress_attr,
+};
+use super::violations::try_create_violation;
+
+/// Check Rust suppress attributes and return violations.
+pub fn check_rust_suppress_violations(
+    ctx: &CheckContext,
+    path: &Path,
+    content: &str,
+    config: &SuppressConfig,
+    is_test_file: bool,
+    cfg_info: &CfgTestInfo,
+    limit_reached: &mut bool,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    // Determine effective check level based on source vs test
+    let effective_check = if is_test_file {
+        config.test.check.unwrap_or(SuppressLevel::Allow)
+    } else {
+        config.source.check.unwrap_or(config.check)
+    };
+
+    // If allow, no checking needed
+    if effective_check == SuppressLevel::Allow {
+        return violations;
+    }
+
+    // Parse suppress attributes (don't filter by global pattern - let checker handle per-lint patterns)
+    let attrs = parse_suppress_attrs(content, None);
+
+    for attr in attrs {
+        if *limit_reached {
+            break;
+        }
+
+        // Check if this line is in test code (inline #[cfg(test)])
+        let is_test_line = cfg_info.is_test_line(attr.line);
+
+        if is_test_line {
+            // Use test policy
+            let test_check = config.test.check.unwrap_or(SuppressLevel::Allow);
+            if test_check == SuppressLevel::Allow {
+                continue;
+            }
+        }
+
+        // Get scope config and check level
+        let (scope_config, scope_check) = if is_test_file || is_test_line {
+            (
+                &config.test,
+                config.test.check.unwrap_or(SuppressLevel::Allow),
+            )
+        } else {
+            (&config.source, config.source.check.unwrap_or(config.check))
+        };
+
+        // Build params for shared checking logic
+        let params = SuppressCheckParams {
+            scope_config,
+            scope_check,
+            global_comment: config.comment.as_deref(),
+        };
+
+        let attr_info = SuppressAttrInfo {
+            codes: &attr.codes,
+            has_comment: attr.has_comment,
+            comment_text: attr.comment_text.as_deref(),
+        };
+
+        // Use shared checking logic
+        if let Some(violation_kind) = check_suppress_attr(&params, &attr_info) {
+            // Build pattern string for violation (use first code for display)
+            let code = attr.codes.first().map(|s| s.as_str()).unwrap_or("unknown");
+            let pattern = format!("#[{}({})]", attr.kind, code);
+
+            let (violation_type, advice) = match violation_kind {
+                SuppressViolationKind::Forbidden { ref code } => {
+                    let advice = format!(
+                        "Suppressing `{}` is forbidden. Remove the suppression or address the issue.",
+                        code
+                    );
+                    ("suppress_forbidden", advice)
+                }
+                SuppressViolationKind::MissingComment {
+                    ref lint_code,
+                    ref required_patterns,
+                } => {
+                    let advice = build_suppress_missing_comment_advice(
+                        "rust",
+                        lint_code.as_deref(),
+                        required_patterns,
+                    );
+                    ("suppress_missing_comment", advice)
+                }
+                SuppressViolationKind::AllForbidden => {
+                    let advice =
+                        "Lint suppressions are forbidden. Remove and fix the underlying issue.";
+                    ("suppress_forbidden", advice.to_string())
+                }
+            };
+
+            if let Some(v) = try_create_violation(
+                ctx,
+                path,
+                (attr.line + 1) as u32,
+                violation_type,
+                &advice,
+                &pattern,
+            ) {
+                violations.push(v);
+            } else {
+                *limit_reached = true;
+            }
+        }
+    }
+
+    violations
+}
+
+/// Parse Rust suppress attributes into the unified format.
+pub(super) fn rust_directives(content: &str) -> Vec<UnifiedSuppressDirective> {
+    parse_suppress_attrs(content, None)
+        .into_iter()
+        .map(|attr| UnifiedSuppressDirective {
+            line: attr.line,
+            pattern: format!("#[{}({})]", attr.kind, attr.codes.join(", ")),
+            codes: attr.codes,
+            has_comment: attr.has_comment,
+            comment_text: attr.comment_text,
+        })
+        .collect()
+}
@@ -20,11 +20,23 @@ pub(super) fn check_shell_suppress_violations(
     is_test_file: bool,
     limit_reached: &mut bool,
 ) -> Vec<Violation> {
-    // Parse shellcheck suppress directives
-    let suppresses = parse_shellcheck_suppresses(content, None);
+    let unified = shell_directives(content);
 
-    // Convert to unified format
-    let unified: Vec<UnifiedSuppressDirective> = suppresses
+    check_suppress_violations_generic(
+        ctx,
+        path,
+        unified,
+        config,
+        "shell",
+        "shellcheck",
+        is_test_file,
+        limit_reached,
+    )
+}
+
+/// Parse shellcheck suppress directives into the unified format.
+pub(super) fn shell_directives(content: &str) -> Vec<UnifiedSuppressDirective> {
+    parse_shellcheck_suppresses(content, None)
         .into_iter()
         .map(|s| {
             let code = s.codes.first().map(|c| c.as_str()).unwrap_or("unknown");
@@ -37,16 +49,5 @@ pub(super) fn check_shell_suppress_violations(
                 pattern,
             }
         })
-        .collect();
-
-    check_suppress_violations_generic(
-        ctx,
-        path,
-        unified,
-        config,
-        "shell",
-        "shellcheck",
-        is_test_file,
-        limit_reached,
-    )
+        .collect()
 }
@@ -14,6 +14,7 @@ use crate::config::{
     ShellSuppressConfig, SuppressConfig, SuppressLevel, SuppressScopeConfig,
 };
 
+use super::comment::is_placeholder;
 use super::violations::try_create_violation;
 
 // =============================================================================
@@ -569,25 +570,30 @@ fn find_required_patterns(
 /// Check if the attribute has a valid justification comment.
 /// If required_patterns is non-empty, comment must match one of them.
 /// If required_patterns is empty, any non-empty comment is valid.
+/// A placeholder such as `TODO` never justifies anything.
 fn has_valid_comment(attr: &SuppressAttrInfo, required_patterns: &[String]) -> bool {
     if !attr.has_comment {
         return false;
     }
+    let norm_text = attr
+        .comment_text
+        .map(normalize_comment_text)
+        .unwrap_or_default();
 
     // If no specific patterns required, any comment is valid
     if required_patterns.is_empty() {
-        return true;
+        return !is_placeholder(&norm_text);
     }
 
     // Need to match one of the patterns
-    let Some(text) = &attr.comment_text else {
+    if attr.comment_text.is_none() {
         return false;
-    };
-
-    let norm_text = normalize_comment_text(text);
+    }
     required_patterns.iter().any(|pattern| {
         let norm_pattern = normalize_comment_pattern(pattern);
-        norm_text.starts_with(&norm_pattern)
+        norm_text
+            .strip_prefix(&norm_pattern)
+            .is_some_and(|body| !is_placeholder(body))
     })
 }
 
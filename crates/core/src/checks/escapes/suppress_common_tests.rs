@@ -140,6 +140,32 @@ fn comment_level_passes_with_any_comment() {
     assert!(check_suppress_attr(&params, &attr).is_none());
 }
 
+#[test]
+fn comment_level_rejects_placeholder_comments() {
+    let scope_config = make_scope_config(vec![], vec![], HashMap::new());
+    let global = SuppressCheckParams {
+        scope_config: &scope_config,
+        scope_check: SuppressLevel::Comment,
+        global_comment: Some("// REASON:"),
+    };
+    let any = SuppressCheckParams {
+        global_comment: None,
+        ..global
+    };
+    let stub = SuppressAttrInfo {
+        codes: &["dead_code".to_string()],
+        has_comment: true,
+        comment_text: Some("// REASON: TODO"),
+    };
+    let todo = SuppressAttrInfo {
+        comment_text: Some("// TODO"),
+        ..stub
+    };
+
+    assert!(check_suppress_attr(&global, &stub).is_some());
+    assert!(check_suppress_attr(&any, &todo).is_some());
+}
+
 #[test]
 fn global_pattern_enforced() {
     let scope_config = make_scope_config(vec![], vec![], HashMap::new());
@@ -7,7 +7,7 @@
 
 use std::sync::atomic::Ordering;
 
-use crate::check::{CheckContext, Violation};
+use crate::check::{CheckContext, Field, Violation};
 use crate::config::EscapeAction;
 
 /// Default advice message for an escape action.
@@ -55,31 +55,35 @@ pub(super) fn create_threshold_violation(
         return None;
     }
 
-    Some(Violation {
-        file: None,
-        line: None,
-        violation_type: "threshold_exceeded".to_string(),
-        advice: advice.to_string(),
-        value: Some(count as i64),
-        threshold: Some(threshold as i64),
-        pattern: Some(pattern_name.to_string()),
-        lines: None,
-        nonblank: None,
-        other_file: None,
-        section: None,
-        commit: None,
-        message: None,
-        expected_docs: None,
-        area: None,
-        area_match: None,
-        path: None,
-        target: None,
-        change_type: None,
-        lines_changed: None,
-        scope: None,
-        expected: None,
-        found: None,
-    })
+    Some(
+        Violation::project("threshold_exceeded", advice)
+            .with_threshold(count as i64, threshold as i64)
+            .with_pattern(pattern_name),
+    )
+}
+
+/// Create a density exceeded violation (occurrences per 1000 source lines).
+///
+/// The threshold is the count `max_density` allows at the current size.
+pub(super) fn create_density_violation(
+    ctx: &CheckContext,
+    pattern_name: &str,
+    count: usize,
+    source_lines: usize,
+    max_density: f64,
+    advice: &str,
+) -> Option<Violation> {
+    let allowed = (max_density * source_lines as f64 / 1000.0).floor() as i64;
+    let mut violation = create_threshold_violation(ctx, pattern_name, count, 0, advice)?;
+    violation.violation_type = "density_exceeded".to_string();
+    violation.details.insert(Field::Threshold, allowed);
+    violation.advice = format!(
+        "{:.2} per 1000 source lines (max: {}). {}",
+        super::density(count, source_lines),
+        max_density,
+        advice
+    );
+    Some(violation)
 }
 
 /// Format comment advice with the required pattern.
@@ -0,0 +1,365 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Formatter status check.
+//!
+//! Runs the project's formatters in check mode and reports unformatted files
+//! per docs/specs/checks/format.md. `--fix` runs the formatters in write mode.
+
+use std::collections::BTreeMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::check::{Check, CheckContext, CheckResult, Violation};
+use crate::checks::testing::runners::run_with_timeout;
+use crate::config::CheckLevel;
+use crate::diagnostics::Code;
+use crate::interrupt;
+use crate::metrics::{self, CheckMetrics};
+
+/// The format check reports files the project's formatters would change.
+pub struct FormatCheck;
+
+impl Check for FormatCheck {
+    fn name(&self) -> &'static str {
+        "format"
+    }
+
+    fn description(&self) -> &'static str {
+        "Formatter status"
+    }
+
+    fn default_enabled(&self) -> bool {
+        false
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let config = &ctx.config.check.format;
+        if config.check == CheckLevel::Off {
+            return CheckResult::passed(self.name());
+        }
+
+        let formatters = match resolve_formatters(ctx.root, &config.formatters) {
+            Ok(formatters) => formatters,
+            Err(unknown) => {
+                return CheckResult::skipped(
+                    self.name(),
+                    format!(
+                        "unknown formatter '{}' (expected one of: {})",
+                        unknown,
+                        FORMATTERS
+                            .iter()
+                            .map(|f| f.name)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                );
+            }
+        };
+        if formatters.is_empty() {
+            return CheckResult::passed(self.name());
+        }
+
+        let mut violations = Vec::new();
+        let mut unformatted_by_formatter = BTreeMap::new();
+        let mut fixed = Vec::new();
+        let mut errors = Vec::new();
+
+        for formatter in formatters {
+            let output = match run(formatter.check, ctx.root, config.timeout) {
+                Ok(output) => output,
+                Err(e) => {
+                    errors.push(format!("{}: {}", formatter.name, e));
+                    continue;
+                }
+            };
+            let text = output.text();
+            let files = (formatter.parse)(&text, ctx.root);
+            if files.is_empty() {
+                if !output.success {
+                    let detail = text.lines().next().unwrap_or("no output").trim();
+                    violations.push(Violation::project(
+                        "format_error",
+                        format!("`{}` failed: {}", formatter.check.join(" "), detail),
+                    ));
+                }
+                unformatted_by_formatter.insert(formatter.name.to_string(), 0);
+                continue;
+            }
+            unformatted_by_formatter.insert(formatter.name.to_string(), files.len());
+
+            // Formatters write files themselves, so they stay out of patches
+            // and dry-run diffs
+            if ctx.fix && (ctx.dry_run || ctx.patch.is_none()) {
+                if !ctx.dry_run
+                    && let Err(e) = run(formatter.fix, ctx.root, config.timeout)
+                {
+                    errors.push(format!("{}: {}", formatter.name, e));
+                    continue;
+                }
+                fixed.push(json!({
+                    "formatter": formatter.name,
+                    "files": files.len(),
+                }));
+                continue;
+            }
+
+            let advice = format!(
+                "Run `{}` to format, or quench check --fix.",
+                formatter.fix.join(" ")
+            );
+            for file in files {
+                violations.push(match file.line {
+                    Some(line) => Violation::file(file.path, line, "unformatted", advice.clone()),
+                    None => Violation::file_only(file.path, "unformatted", advice.clone()),
+                });
+            }
+        }
+
+        // Nothing could run: report why instead of passing silently
+        if unformatted_by_formatter.is_empty() && !errors.is_empty() {
+            return CheckResult::skipped(self.name(), errors.join("; "));
+        }
+        for error in &errors {
+            ctx.diagnostics
+                .emit(Code::FormatterFailed, format!("format: {}", error));
+        }
+
+        let metrics = metrics::Format {
+            unformatted: unformatted_by_formatter,
+        }
+        .to_json();
+
+        if violations.is_empty() {
+            if fixed.is_empty() {
+                CheckResult::passed(self.name()).with_metrics(metrics)
+            } else {
+                CheckResult::fixed(self.name(), json!({ "formatted": fixed })).with_metrics(metrics)
+            }
+        } else if config.check == CheckLevel::Warn {
+            CheckResult::passed_with_warnings(self.name(), violations).with_metrics(metrics)
+        } else {
+            CheckResult::failed(self.name(), violations).with_metrics(metrics)
+        }
+    }
+}
+
+/// A supported formatter.
+struct Formatter {
+    name: &'static str,
+    /// Command that lists unformatted files without changing them.
+    check: &'static [&'static str],
+    /// Command that formats files in place.
+    fix: &'static [&'static str],
+    /// Extract unformatted files from the check command's output.
+    parse: fn(&str, &Path) -> Vec<Unformatted>,
+    /// Whether the project uses this formatter.
+    detect: fn(&Path) -> bool,
+}
+
+/// Formatters in the order they run.
+const FORMATTERS: &[Formatter] = &[
+    Formatter {
+        name: "rustfmt",
+        check: &["cargo", "fmt", "--all", "--check"],
+        fix: &["cargo", "fmt", "--all"],
+        parse: parse_rustfmt,
+        detect: |root| root.join("Cargo.toml").exists(),
+    },
+    Formatter {
+        name: "prettier",
+        check: &["npx", "--no-install", "prettier", "--check", "."],
+        fix: &["npx", "--no-install", "prettier", "--write", "."],
+        parse: parse_prettier,
+        detect: has_prettier,
+    },
+    Formatter {
+        name: "gofmt",
+        check: &["gofmt", "-l", "."],
+        fix: &["gofmt", "-w", "."],
+        parse: parse_file_list,
+        detect: |root| root.join("go.mod").exists(),
+    },
+    Formatter {
+        name: "ruff",
+        check: &["ruff", "format", "--check", "."],
+        fix: &["ruff", "format", "."],
+        parse: parse_ruff,
+        detect: has_ruff,
+    },
+];
+
+/// Configured formatters, or those detected from project files.
+///
+/// Returns the first unknown name as an error.
+fn resolve_formatters<'a>(
+    root: &Path,
+    names: &'a [String],
+) -> Result<Vec<&'static Formatter>, &'a str> {
+    if names.is_empty() {
+        return Ok(FORMATTERS.iter().filter(|f| (f.detect)(root)).collect());
+    }
+    names
+        .iter()
+        .map(|name| {
+            FORMATTERS
+                .iter()
+                .find(|f| f.name == name.as_str())
+                .ok_or(name.as_str())
+        })
+        .collect()
+}
+
+fn has_prettier(root: &Path) -> bool {
+    root.join("node_modules/.bin/prettier").exists()
+        || [
+            ".prettierrc",
+            ".prettierrc.json",
+            ".prettierrc.yaml",
+            ".prettierrc.yml",
+            ".prettierrc.js",
+            ".prettierrc.cjs",
+            ".prettierrc.mjs",
+            "prettier.config.js",
+            "prettier.config.cjs",
+            "prettier.config.mjs",
+        ]
+        .iter()
+        .any(|name| root.join(name).exists())
+}
+
+pub(super) fn has_ruff(root: &Path) -> bool {
+    root.join("ruff.toml").exists()
+        || root.join(".ruff.toml").exists()
+        || std::fs::read_to_string(root.join("pyproject.toml"))
+            .is_ok_and(|content| content.contains("[tool.ruff"))
+}
+
+/// Output of an external tool run.
+pub(super) struct RunOutput {
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl RunOutput {
+    /// Stdout followed by stderr.
+    pub fn text(&self) -> String {
+        format!("{}{}", self.stdout, self.stderr)
+    }
+}
+
+/// Run a tool in the project root, killing it if `timeout` is exceeded.
+pub(super) fn run(
+    command: &[&str],
+    root: &Path,
+    timeout: Option<Duration>,
+) -> Result<RunOutput, String> {
+    let mut cmd = Command::new(command[0]);
+    cmd.args(&command[1..])
+        .current_dir(root)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let child = interrupt::spawn(&mut cmd).map_err(|e| match e.kind() {
+        ErrorKind::NotFound => format!("{} not found", command[0]),
+        _ => format!("failed to run {}: {}", command[0], e),
+    })?;
+    let output = run_with_timeout(child, timeout).map_err(|e| match e.kind() {
+        ErrorKind::TimedOut => format!("`{}` timed out", command.join(" ")),
+        _ => e.to_string(),
+    })?;
+    Ok(RunOutput {
+        success: output.status.success(),
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+    })
+}
+
+/// A file a formatter would change.
+#[derive(Debug, PartialEq, Eq)]
+struct Unformatted {
+    /// Path relative to the project root.
+    path: PathBuf,
+    /// Start of the formatter's diff, when it reports one.
+    line: Option<u32>,
+}
+
+impl Unformatted {
+    fn new(path: &str, root: &Path, line: Option<u32>) -> Self {
+        let path = Path::new(path);
+        Self {
+            path: path.strip_prefix(root).unwrap_or(path).to_path_buf(),
+            line,
+        }
+    }
+}
+
+/// Keep the first entry per file.
+fn dedup(files: Vec<Unformatted>) -> Vec<Unformatted> {
+    let mut seen = std::collections::HashSet::new();
+    files
+        .into_iter()
+        .filter(|f| seen.insert(f.path.clone()))
+        .collect()
+}
+
+/// Parse `Diff in <path>:<line>:` (or `Diff in <path> at line <line>:`).
+fn parse_rustfmt(output: &str, root: &Path) -> Vec<Unformatted> {
+    let files = output
+        .lines()
+        .filter_map(|line| line.strip_prefix("Diff in "))
+        .map(|rest| {
+            let rest = rest.trim_end().trim_end_matches(':');
+            if let Some((path, line)) = rest.rsplit_once(" at line ") {
+                return Unformatted::new(path, root, line.parse().ok());
+            }
+            match rest.rsplit_once(':') {
+                Some((path, line)) if line.parse::<u32>().is_ok() => {
+                    Unformatted::new(path, root, line.parse().ok())
+                }
+                _ => Unformatted::new(rest, root, None),
+            }
+        })
+        .collect();
+    dedup(files)
+}
+
+/// Parse `[warn] <path>` lines, skipping the closing summary.
+fn parse_prettier(output: &str, root: &Path) -> Vec<Unformatted> {
+    let files = output
+        .lines()
+        .filter_map(|line| line.strip_prefix("[warn] "))
+        .filter(|path| !path.starts_with("Code style issues"))
+        .map(|path| Unformatted::new(path.trim(), root, None))
+        .collect();
+    dedup(files)
+}
+
+/// Parse one path per line (`gofmt -l`).
+fn parse_file_list(output: &str, root: &Path) -> Vec<Unformatted> {
+    let files = output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.contains(": "))
+        .map(|path| Unformatted::new(path, root, None))
+        .collect();
+    dedup(files)
+}
+
+/// Parse `Would reformat: <path>` lines.
+fn parse_ruff(output: &str, root: &Path) -> Vec<Unformatted> {
+    let files = output
+        .lines()
+        .filter_map(|line| line.strip_prefix("Would reformat: "))
+        .map(|path| Unformatted::new(path.trim(), root, None))
+        .collect();
+    dedup(files)
+}
+
+#[cfg(test)]
+#[path = "format_tests.rs"]
+mod tests;
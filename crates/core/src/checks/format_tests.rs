@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+use tempfile::TempDir;
+
+fn paths(files: &[Unformatted]) -> Vec<(&str, Option<u32>)> {
+    files
+        .iter()
+        .map(|f| (f.path.to_str().unwrap(), f.line))
+        .collect()
+}
+
+// =============================================================================
+// OUTPUT PARSING TESTS
+// =============================================================================
+
+#[test]
+fn rustfmt_diff_headers_give_file_and_first_line() {
+    let output = "\
+Diff in /work/src/lib.rs:3:
+ fn a() {
+-    x( );
++    x();
+ }
+Diff in /work/src/lib.rs:12:
+Diff in /work/src/main.rs:1:
+";
+
+    let files = parse_rustfmt(output, Path::new("/work"));
+
+    assert_eq!(
+        paths(&files),
+        [("src/lib.rs", Some(3)), ("src/main.rs", Some(1))]
+    );
+}
+
+#[test]
+fn rustfmt_legacy_diff_headers_are_parsed() {
+    let output = "Diff in /work/src/lib.rs at line 7:\n";
+
+    let files = parse_rustfmt(output, Path::new("/work"));
+
+    assert_eq!(paths(&files), [("src/lib.rs", Some(7))]);
+}
+
+#[test]
+fn prettier_warnings_skip_summary_line() {
+    let output = "\
+Checking formatting...
+[warn] src/app.ts
+[warn] styles/main.css
+[warn] Code style issues found in 2 files. Run Prettier with --write to fix.
+";
+
+    let files = parse_prettier(output, Path::new("/work"));
+
+    assert_eq!(
+        paths(&files),
+        [("src/app.ts", None), ("styles/main.css", None)]
+    );
+}
+
+#[test]
+fn gofmt_file_list_ignores_errors() {
+    let output = "main.go\ncmd/tool/run.go\nbad.go:3:1: expected declaration\n";
+
+    let files = parse_file_list(output, Path::new("/work"));
+
+    assert_eq!(
+        paths(&files),
+        [("main.go", None), ("cmd/tool/run.go", None)]
+    );
+}
+
+#[test]
+fn ruff_would_reformat_lines_are_parsed() {
+    let output = "Would reformat: app/main.py\nWould reformat: tests/test_x.py\n2 files would be reformatted, 5 files already formatted\n";
+
+    let files = parse_ruff(output, Path::new("/work"));
+
+    assert_eq!(
+        paths(&files),
+        [("app/main.py", None), ("tests/test_x.py", None)]
+    );
+}
+
+#[test]
+fn clean_output_has_no_files() {
+    let root = Path::new("/work");
+
+    assert!(parse_rustfmt("", root).is_empty());
+    assert!(
+        parse_prettier(
+            "Checking formatting...\nAll matched files use Prettier code style!\n",
+            root
+        )
+        .is_empty()
+    );
+    assert!(parse_file_list("", root).is_empty());
+    assert!(parse_ruff("3 files already formatted\n", root).is_empty());
+}
+
+// =============================================================================
+// FORMATTER RESOLUTION TESTS
+// =============================================================================
+
+fn names(formatters: &[&Formatter]) -> Vec<&'static str> {
+    formatters.iter().map(|f| f.name).collect()
+}
+
+#[test]
+fn formatters_are_detected_from_project_files() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("Cargo.toml"), "[package]\n").unwrap();
+    std::fs::write(temp.path().join("go.mod"), "module x\n").unwrap();
+    std::fs::write(
+        temp.path().join("pyproject.toml"),
+        "[tool.ruff]\nline-length = 100\n",
+    )
+    .unwrap();
+
+    let formatters = resolve_formatters(temp.path(), &[]).unwrap();
+
+    assert_eq!(names(&formatters), ["rustfmt", "gofmt", "ruff"]);
+}
+
+#[test]
+fn prettier_is_detected_from_its_config() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join(".prettierrc"), "{}\n").unwrap();
+
+    let formatters = resolve_formatters(temp.path(), &[]).unwrap();
+
+    assert_eq!(names(&formatters), ["prettier"]);
+}
+
+#[test]
+fn configured_formatters_override_detection() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("Cargo.toml"), "[package]\n").unwrap();
+
+    let formatters = resolve_formatters(temp.path(), &["gofmt".to_string()]).unwrap();
+
+    assert_eq!(names(&formatters), ["gofmt"]);
+}
+
+#[test]
+fn unknown_formatter_is_an_error() {
+    let configured = ["rustfmt".to_string(), "black".to_string()];
+
+    let result = resolve_formatters(Path::new("/work"), &configured);
+
+    assert_eq!(result.err(), Some("black"));
+}
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Dirty working tree gate (`[git] allow_dirty`).
+//!
+//! CI runs and baseline updates should measure committed code. When the
+//! gate is enabled, uncommitted changes are reported under the git check.
+
+use std::path::{Path, PathBuf};
+
+use crate::check::{CheckResult, Field, Violation};
+use crate::checks::order_key;
+use crate::config::AllowDirty;
+
+/// Dirty files listed in the advice before truncating.
+const MAX_LISTED: usize = 10;
+
+/// Quench's own state directory, written by every run.
+const STATE_DIR: &str = ".quench";
+
+/// Filter dirty files to those the gate reports, relative to `root`.
+///
+/// Files outside `root` and quench's own state files are ignored, except a
+/// file-based baseline.
+pub fn relevant_files(root: &Path, dirty: &[PathBuf], baseline: Option<&str>) -> Vec<PathBuf> {
+    dirty
+        .iter()
+        .filter_map(|path| path.strip_prefix(root).ok())
+        .map(Path::to_path_buf)
+        .filter(|path| {
+            !path.starts_with(STATE_DIR) || baseline.is_some_and(|b| path == Path::new(b))
+        })
+        .collect()
+}
+
+/// Build the violation listing dirty files.
+pub fn violation(files: &[PathBuf]) -> Violation {
+    let mut advice = String::from(
+        "Working tree has uncommitted changes; commit or stash them so results \
+         match a commit:",
+    );
+    for file in files.iter().take(MAX_LISTED) {
+        advice.push_str(&format!("\n  {}", file.display()));
+    }
+    if files.len() > MAX_LISTED {
+        advice.push_str(&format!("\n  ... and {} more", files.len() - MAX_LISTED));
+    }
+    Violation::project("dirty_workdir", advice).with_detail(Field::Value, files.len() as i64)
+}
+
+/// Add the violation to the git check's result.
+///
+/// A skipped or missing git result is replaced, since the gate applies
+/// whether or not commit validation is enabled. `Warn` reports without
+/// failing.
+pub fn apply(results: &mut Vec<CheckResult>, violation: Violation, level: AllowDirty) {
+    let fail = level == AllowDirty::Deny;
+    if let Some(result) = results.iter_mut().find(|r| r.name == "git" && !r.skipped) {
+        result.violations.push(violation);
+        result.passed &= !fail;
+        return;
+    }
+
+    results.retain(|r| r.name != "git");
+    let result = if fail {
+        CheckResult::failed("git", vec![violation])
+    } else {
+        CheckResult::passed_with_warnings("git", vec![violation])
+    };
+    let index = results
+        .iter()
+        .position(|r| order_key(&r.name) > order_key("git"))
+        .unwrap_or(results.len());
+    results.insert(index, result);
+}
+
+#[cfg(test)]
+#[path = "dirty_tests.rs"]
+mod tests;
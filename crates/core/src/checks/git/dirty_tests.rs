@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[test]
+fn relevant_files_skips_outside_root_and_state_dir() {
+    let root = Path::new("/repo");
+    let dirty = [
+        PathBuf::from("/repo/src/lib.rs"),
+        PathBuf::from("/elsewhere/main.rs"),
+        PathBuf::from("/repo/.quench/cache.bin"),
+        PathBuf::from("/repo/.quench/baseline.json"),
+    ];
+
+    let notes = relevant_files(root, &dirty, None);
+    assert_eq!(notes, [PathBuf::from("src/lib.rs")]);
+
+    let file = relevant_files(root, &dirty, Some(".quench/baseline.json"));
+    assert_eq!(
+        file,
+        [
+            PathBuf::from("src/lib.rs"),
+            PathBuf::from(".quench/baseline.json")
+        ]
+    );
+}
+
+#[test]
+fn violation_lists_files_and_truncates() {
+    let files: Vec<PathBuf> = (0..12)
+        .map(|i| PathBuf::from(format!("f{}.rs", i)))
+        .collect();
+
+    let v = violation(&files);
+
+    assert_eq!(v.violation_type, "dirty_workdir");
+    assert_eq!(v.int(Field::Value), Some(12));
+    assert!(v.file.is_none());
+    assert!(v.advice.contains("\n  f0.rs"));
+    assert!(v.advice.contains("\n  f9.rs"));
+    assert!(!v.advice.contains("f10.rs"));
+    assert!(v.advice.ends_with("... and 2 more"));
+}
+
+#[test]
+fn apply_deny_fails_existing_git_result() {
+    let mut results = vec![CheckResult::passed("git")];
+
+    apply(
+        &mut results,
+        violation(&[PathBuf::from("a.rs")]),
+        AllowDirty::Deny,
+    );
+
+    assert!(!results[0].passed);
+    assert_eq!(results[0].violations.len(), 1);
+}
+
+#[test]
+fn apply_warn_keeps_result_passing() {
+    let mut results = vec![CheckResult::passed("git")];
+
+    apply(
+        &mut results,
+        violation(&[PathBuf::from("a.rs")]),
+        AllowDirty::Warn,
+    );
+
+    assert!(results[0].passed);
+    assert_eq!(results[0].violations.len(), 1);
+}
+
+#[test]
+fn apply_replaces_skipped_git_result_in_check_order() {
+    let mut results = vec![
+        CheckResult::passed("cloc"),
+        CheckResult::skipped("git", "Check disabled"),
+        CheckResult::passed("license"),
+    ];
+
+    apply(
+        &mut results,
+        violation(&[PathBuf::from("a.rs")]),
+        AllowDirty::Deny,
+    );
+
+    let names: Vec<_> = results.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, ["cloc", "git", "license"]);
+    assert!(!results[1].passed);
+    assert!(!results[1].skipped);
+}
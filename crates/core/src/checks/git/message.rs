@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Commit message files, as passed to git's `commit-msg` hook.
+//!
+//! Git writes the message being committed to a file (`.git/COMMIT_EDITMSG`)
+//! including `#` comment lines and, with `git commit -v`, a diff below a
+//! scissors line. Only the subject line is validated.
+
+use std::collections::BTreeSet;
+
+use crate::check::{Field, Violation};
+use crate::config::GitCommitConfig;
+use crate::git::Commit;
+
+use super::scope::check_scope;
+use super::validate_commit;
+
+/// Line above which `git commit -v` places the diff.
+const SCISSORS: &str = "# ------------------------ >8 ------------------------";
+
+/// The subject line of a commit message file with its 1-based line number.
+///
+/// Comment lines and everything below the scissors line are ignored.
+pub fn subject_line(raw: &str) -> Option<(u32, &str)> {
+    raw.lines()
+        .take_while(|line| *line != SCISSORS)
+        .enumerate()
+        .filter(|(_, line)| !line.starts_with('#'))
+        .find(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| (i as u32 + 1, line.trim_end()))
+}
+
+/// Validate a commit message file's contents against `[git.commit]` rules.
+///
+/// Returns violations with the offending line set; merge messages are
+/// skipped when `skip_merge` is enabled.
+pub fn check_message(raw: &str, config: &GitCommitConfig) -> Vec<Violation> {
+    let (line, subject) = subject_line(raw).unwrap_or((1, ""));
+    let commit = Commit {
+        hash: String::new(),
+        message: subject.to_string(),
+    };
+    let mut violations = Vec::new();
+    validate_commit(&commit, config, &mut violations);
+    for violation in &mut violations {
+        violation.line = Some(line);
+        violation.details.remove(Field::Commit);
+    }
+    violations
+}
+
+/// Check a commit message file's scope against the scopes its staged
+/// changes touch (see `infer_scope`).
+pub fn check_message_scope(raw: &str, touched: &BTreeSet<String>) -> Option<Violation> {
+    let (line, subject) = subject_line(raw)?;
+    let commit = Commit {
+        hash: String::new(),
+        message: subject.to_string(),
+    };
+    let mut violation = check_scope(&commit, touched)?;
+    violation.line = Some(line);
+    violation.details.remove(Field::Commit);
+    Some(violation)
+}
+
+#[cfg(test)]
+#[path = "message_tests.rs"]
+mod tests;
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::field_reassign_with_default)]
+
+use super::*;
+
+#[test]
+fn subject_skips_comments_and_blank_lines() {
+    let raw = "# Please enter the commit message\n\nfeat: add parser\n\nBody text\n";
+    assert_eq!(subject_line(raw), Some((3, "feat: add parser")));
+}
+
+#[test]
+fn subject_ignores_verbose_diff() {
+    let raw = "\n# ------------------------ >8 ------------------------\nfix: not a subject\n";
+    assert_eq!(subject_line(raw), None);
+}
+
+#[test]
+fn valid_message_has_no_violations() {
+    let config = GitCommitConfig::default();
+    assert!(check_message("feat(api): add endpoint\n", &config).is_empty());
+}
+
+#[test]
+fn invalid_message_reports_line() {
+    let config = GitCommitConfig::default();
+    let violations = check_message("# comment\nupdate stuff\n", &config);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].violation_type, "invalid_format");
+    assert_eq!(violations[0].line, Some(2));
+    assert_eq!(violations[0].text(Field::Message), Some("update stuff"));
+}
+
+#[test]
+fn comment_only_message_is_empty() {
+    let config = GitCommitConfig::default();
+    let violations = check_message("# nothing here\n", &config);
+    assert_eq!(violations[0].violation_type, "empty_message");
+}
+
+#[test]
+fn scope_rules_apply() {
+    let mut config = GitCommitConfig::default();
+    config.scopes = Some(vec!["api".to_string()]);
+    let violations = check_message("feat(db): add index\n", &config);
+    assert_eq!(violations[0].violation_type, "invalid_scope");
+}
+
+#[test]
+fn merge_messages_are_skipped() {
+    let config = GitCommitConfig::default();
+    assert!(check_message("Merge branch 'main' into feature\n", &config).is_empty());
+}
+
+#[test]
+fn scope_check_reports_subject_line() {
+    let touched = ["cli".to_string()].into_iter().collect();
+    let v = check_message_scope("# comment\nfeat(core): add parser\n", &touched).unwrap();
+    assert_eq!(v.violation_type, "scope_mismatch");
+    assert_eq!(v.line, Some(2));
+    assert!(v.text(Field::Commit).is_none());
+}
@@ -6,18 +6,26 @@
 //! Validates commit message format and git-related conventions.
 //! Skips if not in a git repository.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use git2::Repository;
 
-use crate::check::{Check, CheckContext, CheckResult, Violation};
-use crate::config::GitCommitConfig;
-use crate::git::{Commit, get_all_branch_commits, get_commits_since, is_git_repo};
+use crate::check::{Check, CheckContext, CheckResult, Field, Violation};
+use crate::config::{GitCommitConfig, InferScope};
+use crate::diagnostics::Code;
+use crate::git::{
+    Commit, get_all_branch_commits, get_commit_paths, get_commits_since, is_git_repo,
+};
+use crate::metrics::{self, CheckMetrics};
 
+pub mod dirty;
 pub mod docs;
+pub mod message;
 pub mod parse;
+pub mod scope;
 mod template;
 
+use scope::{ScopeMap, check_scope, relative_to};
 use template::{TEMPLATE_PATH, generate_template};
 
 use docs::{DocsResult, check_commit_docs, primary_agent_file};
@@ -78,15 +86,24 @@ impl Check for GitCheck {
 
         // Validate each commit (if any)
         let mut validated_count = 0;
+        let mut warnings = Vec::new();
+        let scope_map = ScopeMap::new(&config.scope_paths, &ctx.config.project.packages);
         for commit in &commits {
             if validate_commit(commit, config, &mut violations) {
                 validated_count += 1;
+                if config.infer_scope != InferScope::Off && !scope_map.is_empty() {
+                    let scope_violations = match config.infer_scope {
+                        InferScope::Enforce => &mut violations,
+                        _ => &mut warnings,
+                    };
+                    check_commit_scope(ctx.root, commit, &scope_map, scope_violations);
+                }
             }
         }
 
         // Handle --fix for template creation
         let fix_summary = if ctx.fix && config.template {
-            fix_template(ctx.root, config, ctx.dry_run)
+            fix_template(ctx, config)
         } else {
             None
         };
@@ -96,31 +113,34 @@ impl Check for GitCheck {
             // Count commits with violations
             let commits_with_violations = violations
                 .iter()
-                .filter_map(|v| v.commit.as_ref())
+                .filter_map(|v| v.text(Field::Commit))
                 .collect::<std::collections::HashSet<_>>()
                 .len();
 
-            Some(serde_json::json!({
-                "commits_checked": validated_count,
-                "commits_valid": validated_count - commits_with_violations,
-                "commits_skipped": commits.len() - validated_count,
-            }))
+            Some(metrics::Git {
+                commits_checked: validated_count,
+                commits_valid: validated_count - commits_with_violations,
+                commits_skipped: commits.len() - validated_count,
+            })
         } else {
             None
         };
 
-        let mut result = if violations.is_empty() {
-            if let Some(summary) = fix_summary {
-                CheckResult::fixed(self.name(), summary)
-            } else {
-                CheckResult::passed(self.name())
-            }
-        } else {
+        let mut result = if !violations.is_empty() {
+            violations.append(&mut warnings);
             CheckResult::failed(self.name(), violations)
+        } else if let Some(summary) = fix_summary {
+            let mut result = CheckResult::fixed(self.name(), summary);
+            result.violations = warnings;
+            result
+        } else if !warnings.is_empty() {
+            CheckResult::passed_with_warnings(self.name(), warnings)
+        } else {
+            CheckResult::passed(self.name())
         };
 
         if let Some(m) = metrics {
-            result = result.with_metrics(m);
+            result = result.with_metrics(m.to_json());
         }
 
         result
@@ -246,6 +266,21 @@ pub fn validate_commit(
     true // Validated
 }
 
+/// Check a commit's scope against the packages its changes touch.
+fn check_commit_scope(
+    root: &Path,
+    commit: &Commit,
+    scope_map: &ScopeMap,
+    violations: &mut Vec<Violation>,
+) {
+    let Ok(paths) = get_commit_paths(root, &commit.hash) else {
+        return;
+    };
+    let paths = relative_to(root, &paths);
+    let touched = scope_map.infer(paths.iter().map(PathBuf::as_path));
+    violations.extend(check_scope(commit, &touched));
+}
+
 /// Format advice for invalid type violations.
 fn format_type_advice(allowed_types: Option<&[String]>) -> String {
     match allowed_types {
@@ -258,28 +293,31 @@ fn format_type_advice(allowed_types: Option<&[String]>) -> String {
 /// Fix template and git config if needed.
 ///
 /// Returns fix summary if anything was fixed, None otherwise.
-fn fix_template(root: &Path, config: &GitCommitConfig, dry_run: bool) -> Option<serde_json::Value> {
+fn fix_template(ctx: &CheckContext, config: &GitCommitConfig) -> Option<serde_json::Value> {
+    let (root, dry_run) = (ctx.root, ctx.dry_run);
     let template_path = root.join(TEMPLATE_PATH);
     let mut actions = Vec::new();
 
     // Create .gitmessage if missing
     if !template_path.exists() {
         let content = generate_template(config);
-        if !dry_run {
-            if let Err(e) = std::fs::write(&template_path, &content) {
-                // Log error but continue - this is a best-effort fix
-                eprintln!("Warning: Failed to create {}: {}", TEMPLATE_PATH, e);
-            } else {
-                actions.push(format!("Created {} (commit template)", TEMPLATE_PATH));
-            }
-        } else {
+        let written = ctx.write_fix(&template_path, &content);
+        if dry_run {
             actions.push(format!("Would create {} (commit template)", TEMPLATE_PATH));
+        } else if let Err(e) = written {
+            // Report but continue - this is a best-effort fix
+            ctx.diagnostics.emit(
+                Code::FixFailed,
+                format!("failed to create {}: {}", TEMPLATE_PATH, e),
+            );
+        } else {
+            actions.push(format!("Created {} (commit template)", TEMPLATE_PATH));
         }
     }
 
-    // Configure git commit.template if not set
+    // Configure git commit.template if not set (git config isn't part of a patch)
     if !is_template_configured(root) {
-        if !dry_run {
+        if !dry_run && ctx.patch.is_none() {
             if configure_git_template(root) {
                 actions.push("Configured git commit.template".to_string());
             }
@@ -80,8 +80,8 @@ fn rejects_non_conventional_format() {
 
     assert_eq!(violations.len(), 1);
     assert_eq!(violations[0].violation_type, "invalid_format");
-    assert_eq!(violations[0].commit, Some("abc1234".to_string()));
-    assert_eq!(violations[0].message, Some("update stuff".to_string()));
+    assert_eq!(violations[0].text(Field::Commit), Some("abc1234"));
+    assert_eq!(violations[0].text(Field::Message), Some("update stuff"));
 }
 
 #[test]
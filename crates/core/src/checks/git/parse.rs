@@ -103,7 +103,7 @@ pub fn is_merge_commit(message: &str) -> bool {
 /// # Examples
 ///
 /// ```
-/// use quench::checks::git::parse::{parse_conventional_commit, ParseResult};
+/// use quench_core::checks::git::parse::{parse_conventional_commit, ParseResult};
 ///
 /// let result = parse_conventional_commit("feat(api): add endpoint");
 /// assert!(matches!(result, ParseResult::Conventional(_)));
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Commit scope inference from changed paths.
+//!
+//! Maps path prefixes to conventional-commit scopes (by default, each
+//! workspace package's directory name) and checks that a commit's scope
+//! names a package it actually touches.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::check::Violation;
+use crate::git::Commit;
+
+use super::{ParseResult, parse_conventional_commit};
+
+/// Path prefix to scope mapping; the longest matching prefix wins.
+#[derive(Debug, Default)]
+pub struct ScopeMap {
+    /// `(prefix, scope)` pairs, longest prefix first.
+    entries: Vec<(PathBuf, String)>,
+}
+
+impl ScopeMap {
+    /// Build from `[git.commit.scope_paths]`, falling back to one scope per
+    /// workspace package (named after the package's directory).
+    pub fn new(scope_paths: &BTreeMap<String, String>, packages: &[String]) -> Self {
+        let mut entries: Vec<(PathBuf, String)> = if scope_paths.is_empty() {
+            packages
+                .iter()
+                .filter(|pkg| pkg.as_str() != ".")
+                .filter_map(|pkg| {
+                    let path = PathBuf::from(pkg);
+                    let name = path.file_name()?.to_string_lossy().into_owned();
+                    Some((path, name))
+                })
+                .collect()
+        } else {
+            scope_paths
+                .iter()
+                .map(|(prefix, scope)| (PathBuf::from(prefix.trim_end_matches('/')), scope.clone()))
+                .collect()
+        };
+        entries.sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.components().count()));
+        Self { entries }
+    }
+
+    /// Whether no scopes are mapped.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Scope for a project-relative path, if any prefix matches.
+    pub fn scope_for(&self, path: &Path) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(prefix, _)| path.starts_with(prefix))
+            .map(|(_, scope)| scope.as_str())
+    }
+
+    /// Scopes touched by a set of project-relative paths.
+    pub fn infer<'a>(&self, paths: impl IntoIterator<Item = &'a Path>) -> BTreeSet<String> {
+        paths
+            .into_iter()
+            .filter_map(|path| self.scope_for(path))
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// Make changed paths relative to the project root, dropping paths outside it.
+pub fn relative_to(root: &Path, paths: &[PathBuf]) -> Vec<PathBuf> {
+    let canonical = root.canonicalize().ok();
+    paths
+        .iter()
+        .filter_map(|path| {
+            path.strip_prefix(root)
+                .ok()
+                .or_else(|| path.strip_prefix(canonical.as_deref()?).ok())
+                .map(Path::to_path_buf)
+        })
+        .collect()
+}
+
+/// Check that a commit's scope is one of the scopes its changes touch.
+///
+/// Returns `None` for non-conventional messages (reported elsewhere) and for
+/// commits that touch no mapped path.
+pub fn check_scope(commit: &Commit, touched: &BTreeSet<String>) -> Option<Violation> {
+    let ParseResult::Conventional(parsed) = parse_conventional_commit(&commit.message) else {
+        return None;
+    };
+    if touched.is_empty() {
+        return None;
+    }
+    if let Some(scope) = &parsed.scope
+        && touched.contains(scope)
+    {
+        return None;
+    }
+
+    let suggested = touched.iter().next()?;
+    let touches = touched.iter().cloned().collect::<Vec<_>>().join(", ");
+    let fix = format!(
+        "{}({}): {}",
+        parsed.commit_type, suggested, parsed.description
+    );
+    let advice = if touched.len() == 1 {
+        format!("Commit touches {}; use: {}", touches, fix)
+    } else {
+        format!(
+            "Commit touches {}; use one of these scopes, e.g.: {}",
+            touches, fix
+        )
+    };
+
+    let mut violation =
+        Violation::commit_violation(&commit.hash, &commit.message, "scope_mismatch", advice);
+    if let Some(scope) = parsed.scope {
+        violation = violation.with_scope(scope);
+    }
+    Some(violation)
+}
+
+#[cfg(test)]
+#[path = "scope_tests.rs"]
+mod tests;
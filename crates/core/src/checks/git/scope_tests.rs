@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use crate::check::Field;
+
+fn commit(message: &str) -> Commit {
+    Commit {
+        hash: "abc1234".to_string(),
+        message: message.to_string(),
+    }
+}
+
+fn scopes(names: &[&str]) -> BTreeSet<String> {
+    names.iter().map(|s| s.to_string()).collect()
+}
+
+fn packages(paths: &[&str]) -> Vec<String> {
+    paths.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn package_directories_name_default_scopes() {
+    let map = ScopeMap::new(&BTreeMap::new(), &packages(&["crates/cli", "crates/core"]));
+    assert_eq!(
+        map.scope_for(Path::new("crates/cli/src/main.rs")),
+        Some("cli")
+    );
+    assert_eq!(map.scope_for(Path::new("crates/core/lib.rs")), Some("core"));
+    assert_eq!(map.scope_for(Path::new("README.md")), None);
+}
+
+#[test]
+fn root_package_is_not_a_scope() {
+    let map = ScopeMap::new(&BTreeMap::new(), &packages(&["."]));
+    assert!(map.is_empty());
+}
+
+#[test]
+fn scope_paths_override_packages() {
+    let mut paths = BTreeMap::new();
+    paths.insert("crates/cli/".to_string(), "cli".to_string());
+    paths.insert("crates/cli/src/checks".to_string(), "checks".to_string());
+    let map = ScopeMap::new(&paths, &packages(&["crates/core"]));
+
+    assert_eq!(
+        map.scope_for(Path::new("crates/cli/src/main.rs")),
+        Some("cli")
+    );
+    assert_eq!(
+        map.scope_for(Path::new("crates/cli/src/checks/git.rs")),
+        Some("checks")
+    );
+    assert_eq!(map.scope_for(Path::new("crates/core/lib.rs")), None);
+}
+
+#[test]
+fn infer_collects_touched_scopes() {
+    let map = ScopeMap::new(&BTreeMap::new(), &packages(&["crates/cli", "crates/core"]));
+    let touched = map.infer([
+        Path::new("crates/cli/a.rs"),
+        Path::new("crates/cli/b.rs"),
+        Path::new("docs/x.md"),
+    ]);
+    assert_eq!(touched, scopes(&["cli"]));
+}
+
+#[test]
+fn relative_to_strips_root_and_drops_outside_paths() {
+    let root = Path::new("/repo/project");
+    let paths = vec![
+        PathBuf::from("/repo/project/crates/cli/a.rs"),
+        PathBuf::from("/repo/other/b.rs"),
+    ];
+    assert_eq!(
+        relative_to(root, &paths),
+        vec![PathBuf::from("crates/cli/a.rs")]
+    );
+}
+
+#[test]
+fn matching_scope_passes() {
+    assert!(check_scope(&commit("feat(cli): add parser"), &scopes(&["cli"])).is_none());
+}
+
+#[test]
+fn any_touched_scope_passes() {
+    let touched = scopes(&["cli", "core"]);
+    assert!(check_scope(&commit("fix(core): handle empty"), &touched).is_none());
+}
+
+#[test]
+fn untouched_paths_skip_the_check() {
+    assert!(check_scope(&commit("docs: update readme"), &scopes(&[])).is_none());
+}
+
+#[test]
+fn non_conventional_messages_are_left_to_format_check() {
+    assert!(check_scope(&commit("update stuff"), &scopes(&["cli"])).is_none());
+}
+
+#[test]
+fn mismatched_scope_suggests_rewrite() {
+    let v = check_scope(&commit("feat(core): add parser"), &scopes(&["cli"])).unwrap();
+    assert_eq!(v.violation_type, "scope_mismatch");
+    assert_eq!(v.text(Field::Scope), Some("core"));
+    assert_eq!(v.advice, "Commit touches cli; use: feat(cli): add parser");
+}
+
+#[test]
+fn missing_scope_suggests_rewrite() {
+    let v = check_scope(&commit("feat: add parser"), &scopes(&["cli"])).unwrap();
+    assert_eq!(v.advice, "Commit touches cli; use: feat(cli): add parser");
+}
+
+#[test]
+fn multiple_scopes_suggest_one_of_them() {
+    let v = check_scope(&commit("fix: handle empty"), &scopes(&["cli", "core"])).unwrap();
+    assert_eq!(
+        v.advice,
+        "Commit touches cli, core; use one of these scopes, e.g.: fix(cli): handle empty"
+    );
+}
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Named check instances.
+//!
+//! Runs a built-in check with an instance's config and only the files its
+//! `paths` match, reporting under the instance name (e.g., "cloc:scripts").
+
+use std::sync::Arc;
+
+use crate::adapter::glob::build_glob_set;
+use crate::check::{Check, CheckContext, CheckResult};
+use crate::config::Config;
+use crate::walker::WalkedFile;
+
+use super::get_check;
+
+/// A built-in check running as a named instance.
+pub struct InstanceCheck {
+    inner: Arc<dyn Check>,
+    /// Leaked once per instance, since check names are `'static`.
+    name: &'static str,
+}
+
+impl InstanceCheck {
+    pub fn new(inner: Arc<dyn Check>, name: &str) -> Self {
+        Self {
+            inner,
+            name: Box::leak(name.to_string().into_boxed_str()),
+        }
+    }
+}
+
+impl Check for InstanceCheck {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn description(&self) -> &'static str {
+        self.inner.description()
+    }
+
+    fn default_enabled(&self) -> bool {
+        self.inner.default_enabled()
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let Some(instance) = ctx.config.instances.iter().find(|i| i.name == self.name) else {
+            return CheckResult::skipped(self.name, "instance is not configured".to_string());
+        };
+
+        let paths = build_glob_set(&instance.paths);
+        let files: Vec<WalkedFile> = ctx
+            .files
+            .iter()
+            .filter(|f| {
+                instance.paths.is_empty()
+                    || paths.is_match(f.path.strip_prefix(ctx.root).unwrap_or(&f.path))
+            })
+            .map(|f| WalkedFile {
+                path: f.path.clone(),
+                size: f.size,
+                mtime_secs: f.mtime_secs,
+                mtime_nanos: f.mtime_nanos,
+                depth: f.depth,
+                size_class: f.size_class,
+            })
+            .collect();
+        let scoped = CheckContext {
+            files: &files,
+            config: &instance.config,
+            ..*ctx
+        };
+
+        let result = self.inner.run(&scoped);
+        let mut result = crate::fix::apply(self.inner.as_ref(), result, &scoped);
+        result.name = self.name.to_string();
+        result
+    }
+}
+
+/// Append an instance check for each configured instance whose check is
+/// selected, so instances follow their check's `--<check>`/`--no-<check>` flags.
+pub fn with_instances(mut checks: Vec<Arc<dyn Check>>, config: &Config) -> Vec<Arc<dyn Check>> {
+    let instances: Vec<Arc<dyn Check>> = config
+        .instances
+        .iter()
+        .filter(|i| checks.iter().any(|c| c.name() == i.check))
+        .filter_map(|i| {
+            let inner = get_check(&i.check)?;
+            Some(Arc::new(InstanceCheck::new(inner, &i.name)) as Arc<dyn Check>)
+        })
+        .collect();
+    checks.extend(instances);
+    checks
+}
+
+#[cfg(test)]
+#[path = "instance_tests.rs"]
+mod tests;
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicUsize;
+
+use crate::check::{Field, Violation};
+use crate::checks::filter_checks;
+use crate::diagnostics::Diagnostics;
+use crate::file_reader::DecodeLog;
+use crate::file_size::FileSizeClass;
+
+const ROOT: &str = "/work";
+
+/// Reports each file it sees, with the cloc line limit it was configured with.
+struct Probe;
+
+impl Check for Probe {
+    fn name(&self) -> &'static str {
+        "cloc"
+    }
+
+    fn description(&self) -> &'static str {
+        "Probe"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let violations = ctx
+            .files
+            .iter()
+            .map(|f| {
+                Violation::file_only(f.path.strip_prefix(ctx.root).unwrap(), "seen", "probe")
+                    .with_detail(Field::Value, ctx.config.check.cloc.max_lines as i64)
+            })
+            .collect();
+        CheckResult::failed(self.name(), violations)
+    }
+}
+
+fn walked(path: &str) -> WalkedFile {
+    WalkedFile {
+        path: PathBuf::from(ROOT).join(path),
+        size: 1,
+        mtime_secs: 0,
+        mtime_nanos: 0,
+        depth: 1,
+        size_class: FileSizeClass::Small,
+    }
+}
+
+fn config(content: &str) -> Config {
+    crate::config::parse(content, Path::new("quench.toml")).unwrap()
+}
+
+fn run(check: &dyn Check, config: &Config, files: &[WalkedFile]) -> CheckResult {
+    let violation_count = AtomicUsize::new(0);
+    let decode_log = DecodeLog::default();
+    let diagnostics = Diagnostics::silent();
+    let ctx = CheckContext {
+        root: Path::new(ROOT),
+        files,
+        config,
+        limit: None,
+        violation_count: &violation_count,
+        changed_files: None,
+        fix: false,
+        run_fixers: false,
+        dry_run: false,
+        patch: None,
+        ci_mode: false,
+        base_branch: None,
+        staged: false,
+        staged_content: None,
+        verbose: false,
+        decode_log: &decode_log,
+        diagnostics: &diagnostics,
+    };
+    check.run(&ctx)
+}
+
+#[test]
+fn instance_runs_with_its_config_on_its_paths() {
+    let config = config(
+        r#"
+version = 1
+[check.cloc]
+max_lines = 500
+[check."cloc:scripts"]
+paths = ["scripts/**"]
+max_lines = 200
+"#,
+    );
+    let files = [walked("src/lib.rs"), walked("scripts/build.sh")];
+
+    let result = run(
+        &InstanceCheck::new(Arc::new(Probe), "cloc:scripts"),
+        &config,
+        &files,
+    );
+
+    assert_eq!(result.name, "cloc:scripts");
+    assert_eq!(result.violations.len(), 1);
+    let seen = &result.violations[0];
+    assert_eq!(seen.file.as_deref(), Some(Path::new("scripts/build.sh")));
+    assert_eq!(seen.int(Field::Value), Some(200));
+}
+
+#[test]
+fn instance_without_paths_sees_all_files() {
+    let config = config("version = 1\n[check.\"cloc:all\"]\nmax_lines = 10\n");
+    let files = [walked("src/lib.rs"), walked("scripts/build.sh")];
+
+    let result = run(
+        &InstanceCheck::new(Arc::new(Probe), "cloc:all"),
+        &config,
+        &files,
+    );
+
+    assert_eq!(result.violations.len(), 2);
+}
+
+#[test]
+fn instances_follow_their_check_selection() {
+    let config = config(
+        r#"
+version = 1
+[check."cloc:scripts"]
+paths = ["scripts/**"]
+[check."escapes:vendor"]
+paths = ["vendor/**"]
+"#,
+    );
+
+    let checks = with_instances(filter_checks(&[], &["escapes".to_string()]), &config);
+
+    let names: Vec<_> = checks.iter().map(|c| c.name()).collect();
+    assert!(names.contains(&"cloc:scripts"));
+    assert!(!names.contains(&"escapes:vendor"));
+}
+
+#[test]
+fn instances_sort_after_their_check() {
+    let mut names = vec!["escapes", "cloc:scripts", "git", "cloc"];
+    names.sort_by_key(|n| crate::checks::order_key(n));
+    assert_eq!(names, ["cloc", "cloc:scripts", "escapes", "git"]);
+}
@@ -14,7 +14,7 @@ use regex::Regex;
 use serde_json::json;
 
 use crate::check::{Check, CheckContext, CheckResult, Violation};
-use crate::file_reader::FileContent;
+use crate::metrics::{self, CheckMetrics};
 
 /// Regex pattern for matching SPDX-License-Identifier header lines.
 #[allow(clippy::expect_used)]
@@ -94,15 +94,17 @@ impl Check for LicenseCheck {
             }
 
             // Read file content
-            let file_content = match FileContent::read(&file.path) {
+            let file_content = match ctx.read_file(&file.path) {
                 Ok(c) => c,
                 Err(_) => continue,
             };
             let Some(content) = file_content.as_str() else {
-                continue; // Skip non-UTF-8 files
+                continue; // Skip binary files
             };
 
             files_checked += 1;
+            // Writing decoded text back would corrupt lossy or transcoded files
+            let fix = ctx.fix && file_content.round_trips();
 
             // Get file extension for comment syntax
             let ext = file.path.extension().and_then(|e| e.to_str()).unwrap_or("");
@@ -119,7 +121,7 @@ impl Check for LicenseCheck {
                     // Missing header
                     files_missing_headers += 1;
 
-                    if ctx.fix {
+                    if fix {
                         // Generate and insert header
                         let header = generate_header(
                             expected_license,
@@ -129,9 +131,7 @@ impl Check for LicenseCheck {
                         );
                         let new_content = insert_header_preserving_shebang(content, &header);
 
-                        if !ctx.dry_run {
-                            let _ = std::fs::write(&file.path, &new_content);
-                        }
+                        let _ = ctx.write_fix(&file.path, &new_content);
                         fixes
                             .headers_added
                             .push(relative_path.display().to_string());
@@ -170,13 +170,11 @@ impl Check for LicenseCheck {
                     if !year_includes_current(found_year, current_year) {
                         files_outdated_year += 1;
 
-                        if ctx.fix {
+                        if fix {
                             // Update year in content
                             let new_content = update_copyright_year(content, current_year);
 
-                            if !ctx.dry_run {
-                                let _ = std::fs::write(&file.path, &new_content);
-                            }
+                            let _ = ctx.write_fix(&file.path, &new_content);
                             fixes
                                 .years_updated
                                 .push(relative_path.display().to_string());
@@ -210,11 +208,9 @@ impl Check for LicenseCheck {
         if ctx.limit.is_none_or(|limit| violations.len() < limit) {
             check_root_file(
                 &ctx.root.join("LICENSE"),
-                ctx.root,
+                ctx,
                 expected_copyright,
                 current_year,
-                ctx.fix,
-                ctx.dry_run,
                 &mut violations,
                 &mut fixes,
                 &mut files_checked,
@@ -225,11 +221,9 @@ impl Check for LicenseCheck {
         if ctx.limit.is_none_or(|limit| violations.len() < limit) {
             check_root_file(
                 &ctx.root.join("README.md"),
-                ctx.root,
+                ctx,
                 expected_copyright,
                 current_year,
-                ctx.fix,
-                ctx.dry_run,
                 &mut violations,
                 &mut fixes,
                 &mut files_checked,
@@ -237,13 +231,14 @@ impl Check for LicenseCheck {
             );
         }
 
-        let metrics = json!({
-            "files_checked": files_checked,
-            "files_with_headers": files_with_headers,
-            "files_missing_headers": files_missing_headers,
-            "files_outdated_year": files_outdated_year,
-            "files_wrong_license": files_wrong_license,
-        });
+        let metrics = metrics::License {
+            files_checked,
+            files_with_headers,
+            files_missing_headers,
+            files_outdated_year,
+            files_wrong_license,
+        }
+        .to_json();
 
         // Determine result based on violations and fixes
         if violations.is_empty() {
@@ -457,11 +452,9 @@ fn update_copyright_year(content: &str, current_year: i32) -> String {
 #[allow(clippy::too_many_arguments)]
 fn check_root_file(
     file_path: &Path,
-    root: &Path,
+    ctx: &CheckContext,
     expected_copyright: &str,
     current_year: i32,
-    fix: bool,
-    dry_run: bool,
     violations: &mut Vec<Violation>,
     fixes: &mut LicenseFixes,
     files_checked: &mut usize,
@@ -479,7 +472,7 @@ fn check_root_file(
 
     *files_checked += 1;
 
-    let relative_path = file_path.strip_prefix(root).unwrap_or(file_path);
+    let relative_path = file_path.strip_prefix(ctx.root).unwrap_or(file_path);
 
     // Check if copyright line exists and includes current year
     if let Some(caps) = COPYRIGHT_PATTERN.captures(&content) {
@@ -490,13 +483,11 @@ fn check_root_file(
         if !year_includes_current(found_year, current_year) {
             *files_outdated_year += 1;
 
-            if fix {
+            if ctx.fix {
                 // Update year in content
                 let new_content = update_copyright_year(&content, current_year);
 
-                if !dry_run {
-                    let _ = std::fs::write(file_path, &new_content);
-                }
+                let _ = ctx.write_fix(file_path, &new_content);
                 fixes
                     .years_updated
                     .push(relative_path.display().to_string());
@@ -519,7 +510,7 @@ fn check_root_file(
         // Optionally check copyright holder matches expected
         if found_holder != expected_copyright {
             // Note: Not reporting this as a violation, just updating if in fix mode
-            if fix {
+            if ctx.fix {
                 // Could add logic to update copyright holder, but that's more invasive
                 // For now, we only update the year
             }
@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! External linter check.
+//!
+//! Runs clippy, eslint, golangci-lint, and ruff with JSON output and reports
+//! their diagnostics as quench violations per docs/specs/checks/lint.md.
+//! Counts per rule feed the `lint` ratchet.
+
+mod parse;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::adapter::glob::build_glob_set;
+use crate::check::{Check, CheckContext, CheckResult, Violation};
+use crate::checks::format::{has_ruff, run};
+use crate::config::CheckLevel;
+use crate::diagnostics::Code;
+use crate::metrics::{self, CheckMetrics};
+
+use parse::Diagnostic;
+
+/// The lint check reports diagnostics from the project's linters.
+pub struct LintCheck;
+
+impl Check for LintCheck {
+    fn name(&self) -> &'static str {
+        "lint"
+    }
+
+    fn description(&self) -> &'static str {
+        "External linters"
+    }
+
+    fn default_enabled(&self) -> bool {
+        false
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let config = &ctx.config.check.lint;
+        if config.check == CheckLevel::Off {
+            return CheckResult::passed(self.name());
+        }
+
+        let linters = match resolve_linters(ctx.root, &config.linters) {
+            Ok(linters) => linters,
+            Err(unknown) => {
+                return CheckResult::skipped(
+                    self.name(),
+                    format!(
+                        "unknown linter '{}' (expected one of: {})",
+                        unknown,
+                        LINTERS
+                            .iter()
+                            .map(|l| l.name)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                );
+            }
+        };
+        if linters.is_empty() {
+            return CheckResult::passed(self.name());
+        }
+
+        let exclude = build_glob_set(&config.exclude);
+        let mut violations = Vec::new();
+        let mut by_linter = BTreeMap::new();
+        let mut by_rule: BTreeMap<String, usize> = BTreeMap::new();
+        let mut errors = Vec::new();
+
+        for linter in linters {
+            let output = match run(linter.command, ctx.root, config.timeout) {
+                Ok(output) => output,
+                Err(e) => {
+                    errors.push(format!("{}: {}", linter.name, e));
+                    continue;
+                }
+            };
+            let Some(diagnostics) = (linter.parse)(&output.stdout, ctx.root) else {
+                let text = output.text();
+                let detail = text.lines().next().unwrap_or("no output").trim();
+                violations.push(Violation::project(
+                    "lint_error",
+                    format!("`{}` failed: {}", linter.command.join(" "), detail),
+                ));
+                continue;
+            };
+
+            let diagnostics: Vec<Diagnostic> = diagnostics
+                .into_iter()
+                .filter(|d| !exclude.is_match(&d.file))
+                .collect();
+            by_linter.insert(linter.name.to_string(), diagnostics.len());
+            for diagnostic in diagnostics {
+                let rule = format!("{}/{}", linter.name, diagnostic.rule);
+                *by_rule.entry(rule.clone()).or_default() += 1;
+                if ctx.limit.is_some_and(|limit| violations.len() >= limit) {
+                    continue;
+                }
+                violations.push(to_violation(diagnostic, rule));
+            }
+        }
+
+        // Nothing could run: report why instead of passing silently
+        if by_linter.is_empty() && violations.is_empty() && !errors.is_empty() {
+            return CheckResult::skipped(self.name(), errors.join("; "));
+        }
+        for error in &errors {
+            ctx.diagnostics
+                .emit(Code::LinterFailed, format!("lint: {}", error));
+        }
+
+        let metrics = metrics::Lint {
+            diagnostics: by_linter,
+            rules: by_rule,
+        }
+        .to_json();
+
+        if violations.is_empty() {
+            CheckResult::passed(self.name()).with_metrics(metrics)
+        } else if config.check == CheckLevel::Warn || config.grandfather {
+            CheckResult::passed_with_warnings(self.name(), violations).with_metrics(metrics)
+        } else {
+            CheckResult::failed(self.name(), violations).with_metrics(metrics)
+        }
+    }
+}
+
+fn to_violation(diagnostic: Diagnostic, rule: String) -> Violation {
+    let violation = match diagnostic.line {
+        Some(line) => Violation::file(diagnostic.file, line, "lint", diagnostic.message),
+        None => Violation::file_only(diagnostic.file, "lint", diagnostic.message),
+    };
+    violation.with_pattern(rule)
+}
+
+/// A supported linter.
+struct Linter {
+    name: &'static str,
+    /// Command that prints diagnostics as JSON on stdout.
+    command: &'static [&'static str],
+    parse: fn(&str, &Path) -> Option<Vec<Diagnostic>>,
+    /// Whether the project uses this linter.
+    detect: fn(&Path) -> bool,
+}
+
+/// Linters in the order they run.
+const LINTERS: &[Linter] = &[
+    Linter {
+        name: "clippy",
+        command: &["cargo", "clippy", "--all-targets", "--message-format=json"],
+        parse: parse::clippy,
+        detect: |root| root.join("Cargo.toml").exists(),
+    },
+    Linter {
+        name: "eslint",
+        command: &["npx", "--no-install", "eslint", "--format", "json", "."],
+        parse: parse::eslint,
+        detect: has_eslint,
+    },
+    Linter {
+        name: "golangci-lint",
+        command: &["golangci-lint", "run", "--out-format", "json"],
+        parse: parse::golangci,
+        detect: has_golangci,
+    },
+    Linter {
+        name: "ruff",
+        command: &["ruff", "check", "--output-format", "json", "."],
+        parse: parse::ruff,
+        detect: has_ruff,
+    },
+];
+
+/// Configured linters, or those detected from project files.
+///
+/// Returns the first unknown name as an error.
+fn resolve_linters<'a>(root: &Path, names: &'a [String]) -> Result<Vec<&'static Linter>, &'a str> {
+    if names.is_empty() {
+        return Ok(LINTERS.iter().filter(|l| (l.detect)(root)).collect());
+    }
+    names
+        .iter()
+        .map(|name| {
+            LINTERS
+                .iter()
+                .find(|l| l.name == name.as_str())
+                .ok_or(name.as_str())
+        })
+        .collect()
+}
+
+fn has_eslint(root: &Path) -> bool {
+    root.join("node_modules/.bin/eslint").exists()
+        || [
+            "eslint.config.js",
+            "eslint.config.mjs",
+            "eslint.config.cjs",
+            "eslint.config.ts",
+            ".eslintrc",
+            ".eslintrc.js",
+            ".eslintrc.cjs",
+            ".eslintrc.json",
+            ".eslintrc.yml",
+            ".eslintrc.yaml",
+        ]
+        .iter()
+        .any(|name| root.join(name).exists())
+}
+
+fn has_golangci(root: &Path) -> bool {
+    [
+        ".golangci.yml",
+        ".golangci.yaml",
+        ".golangci.toml",
+        ".golangci.json",
+    ]
+    .iter()
+    .any(|name| root.join(name).exists())
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
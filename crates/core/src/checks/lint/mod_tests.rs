@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use crate::check::Field;
+
+use tempfile::TempDir;
+
+fn names(linters: &[&Linter]) -> Vec<&'static str> {
+    linters.iter().map(|l| l.name).collect()
+}
+
+#[test]
+fn lint_check_is_opt_in() {
+    assert_eq!(LintCheck.name(), "lint");
+    assert!(!LintCheck.default_enabled());
+}
+
+#[test]
+fn linters_are_detected_from_project_files() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("Cargo.toml"), "[package]\n").unwrap();
+    std::fs::write(temp.path().join("eslint.config.js"), "export default [];\n").unwrap();
+    std::fs::write(temp.path().join(".golangci.yml"), "linters: {}\n").unwrap();
+    std::fs::write(temp.path().join("ruff.toml"), "line-length = 100\n").unwrap();
+
+    let linters = resolve_linters(temp.path(), &[]).unwrap();
+
+    assert_eq!(
+        names(&linters),
+        ["clippy", "eslint", "golangci-lint", "ruff"]
+    );
+}
+
+#[test]
+fn project_without_linter_config_detects_nothing() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("go.mod"), "module x\n").unwrap();
+
+    assert!(resolve_linters(temp.path(), &[]).unwrap().is_empty());
+}
+
+#[test]
+fn configured_linters_override_detection() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("Cargo.toml"), "[package]\n").unwrap();
+
+    let linters = resolve_linters(temp.path(), &["ruff".to_string()]).unwrap();
+
+    assert_eq!(names(&linters), ["ruff"]);
+}
+
+#[test]
+fn unknown_linter_is_an_error() {
+    let configured = ["pylint".to_string()];
+
+    let result = resolve_linters(Path::new("/work"), &configured);
+
+    assert_eq!(result.err(), Some("pylint"));
+}
+
+#[test]
+fn violation_carries_rule_and_message() {
+    let diagnostic = Diagnostic {
+        rule: "needless_return".to_string(),
+        file: "src/lib.rs".into(),
+        line: Some(3),
+        message: "unneeded `return` statement".to_string(),
+    };
+
+    let violation = to_violation(diagnostic, "clippy/needless_return".to_string());
+
+    assert_eq!(violation.violation_type, "lint");
+    assert_eq!(violation.line, Some(3));
+    assert_eq!(
+        violation.text(Field::Pattern),
+        Some("clippy/needless_return")
+    );
+    assert_eq!(violation.advice, "unneeded `return` statement");
+}
@@ -0,0 +1,255 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Parsers for linter JSON output.
+//!
+//! Each parser turns one linter's machine-readable report into
+//! [`Diagnostic`]s with paths relative to the project root. Returns `None`
+//! when the output isn't the expected JSON (e.g., the linter crashed).
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// A linter finding.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    /// Rule or lint name (e.g., "needless_return", "no-unused-vars", "F401").
+    pub rule: String,
+    /// Path relative to the project root.
+    pub file: PathBuf,
+    pub line: Option<u32>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn new(rule: String, file: &str, root: &Path, line: Option<u32>, message: String) -> Self {
+        let file = Path::new(file);
+        Self {
+            rule,
+            file: file.strip_prefix(root).unwrap_or(file).to_path_buf(),
+            line,
+            message,
+        }
+    }
+}
+
+/// Drop repeats (clippy reports a lint once per target that includes the file).
+fn dedup(diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    let mut seen = HashSet::new();
+    diagnostics
+        .into_iter()
+        .filter(|d| seen.insert(d.clone()))
+        .collect()
+}
+
+// =============================================================================
+// clippy (`cargo clippy --message-format=json`)
+// =============================================================================
+
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<RustcDiagnostic>,
+}
+
+#[derive(Deserialize)]
+struct RustcDiagnostic {
+    message: String,
+    level: String,
+    code: Option<RustcCode>,
+    #[serde(default)]
+    spans: Vec<RustcSpan>,
+}
+
+#[derive(Deserialize)]
+struct RustcCode {
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct RustcSpan {
+    file_name: String,
+    line_start: u32,
+    is_primary: bool,
+}
+
+/// Parse cargo's newline-delimited JSON messages.
+///
+/// Diagnostics without a primary span (summaries like "3 warnings emitted")
+/// are skipped. Compile errors without a lint code use the rule "error".
+pub fn clippy(output: &str, root: &Path) -> Option<Vec<Diagnostic>> {
+    let mut diagnostics = Vec::new();
+    let mut saw_message = false;
+    for line in output.lines().filter(|l| l.starts_with('{')) {
+        let Ok(message) = serde_json::from_str::<CargoMessage>(line) else {
+            continue;
+        };
+        saw_message = true;
+        if message.reason != "compiler-message" {
+            continue;
+        }
+        let Some(diagnostic) = message.message else {
+            continue;
+        };
+        if !matches!(diagnostic.level.as_str(), "warning" | "error") {
+            continue;
+        }
+        let Some(span) = diagnostic.spans.iter().find(|s| s.is_primary) else {
+            continue;
+        };
+        let rule = match diagnostic.code {
+            Some(code) => code.code.trim_start_matches("clippy::").to_string(),
+            None => "error".to_string(),
+        };
+        diagnostics.push(Diagnostic::new(
+            rule,
+            &span.file_name,
+            root,
+            Some(span.line_start),
+            diagnostic.message,
+        ));
+    }
+    saw_message.then(|| dedup(diagnostics))
+}
+
+// =============================================================================
+// eslint (`eslint -f json`)
+// =============================================================================
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EslintFile {
+    file_path: String,
+    #[serde(default)]
+    messages: Vec<EslintMessage>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EslintMessage {
+    rule_id: Option<String>,
+    message: String,
+    line: Option<u32>,
+}
+
+/// Parse eslint's JSON formatter output. Parse errors have no rule id and
+/// use the rule "parse-error".
+pub fn eslint(output: &str, root: &Path) -> Option<Vec<Diagnostic>> {
+    let files: Vec<EslintFile> = first_json(output, '[')?;
+    let diagnostics = files
+        .into_iter()
+        .flat_map(|file| {
+            file.messages.into_iter().map(move |m| {
+                Diagnostic::new(
+                    m.rule_id.unwrap_or_else(|| "parse-error".to_string()),
+                    &file.file_path,
+                    root,
+                    m.line,
+                    m.message,
+                )
+            })
+        })
+        .collect();
+    Some(dedup(diagnostics))
+}
+
+// =============================================================================
+// golangci-lint (`golangci-lint run --out-format json`)
+// =============================================================================
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GolangciReport {
+    issues: Option<Vec<GolangciIssue>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GolangciIssue {
+    from_linter: String,
+    text: String,
+    pos: GolangciPos,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GolangciPos {
+    filename: String,
+    line: u32,
+}
+
+/// Parse golangci-lint's JSON report. The rule is the reporting linter.
+pub fn golangci(output: &str, root: &Path) -> Option<Vec<Diagnostic>> {
+    let report: GolangciReport = first_json(output, '{')?;
+    let diagnostics = report
+        .issues
+        .unwrap_or_default()
+        .into_iter()
+        .map(|issue| {
+            Diagnostic::new(
+                issue.from_linter,
+                &issue.pos.filename,
+                root,
+                Some(issue.pos.line),
+                issue.text,
+            )
+        })
+        .collect();
+    Some(dedup(diagnostics))
+}
+
+// =============================================================================
+// ruff (`ruff check --output-format json`)
+// =============================================================================
+
+#[derive(Deserialize)]
+struct RuffDiagnostic {
+    code: Option<String>,
+    message: String,
+    filename: String,
+    location: Option<RuffLocation>,
+}
+
+#[derive(Deserialize)]
+struct RuffLocation {
+    row: u32,
+}
+
+/// Parse ruff's JSON output. Syntax errors have no code and use the rule
+/// "syntax-error".
+pub fn ruff(output: &str, root: &Path) -> Option<Vec<Diagnostic>> {
+    let found: Vec<RuffDiagnostic> = first_json(output, '[')?;
+    let diagnostics = found
+        .into_iter()
+        .map(|d| {
+            Diagnostic::new(
+                d.code.unwrap_or_else(|| "syntax-error".to_string()),
+                &d.filename,
+                root,
+                d.location.map(|l| l.row),
+                d.message,
+            )
+        })
+        .collect();
+    Some(dedup(diagnostics))
+}
+
+/// Parse the first JSON value on a line starting with `open`, ignoring any
+/// banner before it and text after it.
+fn first_json<T: serde::de::DeserializeOwned>(output: &str, open: char) -> Option<T> {
+    let mut offset = 0;
+    for line in output.split_inclusive('\n') {
+        if line.trim_start().starts_with(open) {
+            let mut values = serde_json::Deserializer::from_str(&output[offset..]).into_iter();
+            return values.next()?.ok();
+        }
+        offset += line.len();
+    }
+    None
+}
+
+#[cfg(test)]
+#[path = "parse_tests.rs"]
+mod tests;
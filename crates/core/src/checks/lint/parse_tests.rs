@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn summary(diagnostics: &[Diagnostic]) -> Vec<(&str, &str, Option<u32>)> {
+    diagnostics
+        .iter()
+        .map(|d| (d.rule.as_str(), d.file.to_str().unwrap(), d.line))
+        .collect()
+}
+
+const ROOT: &str = "/work";
+
+// =============================================================================
+// CLIPPY
+// =============================================================================
+
+const CLIPPY: &str = r#"{"reason":"compiler-artifact","package_id":"demo"}
+{"reason":"compiler-message","message":{"message":"unneeded `return` statement","level":"warning","code":{"code":"clippy::needless_return","explanation":null},"spans":[{"file_name":"src/lib.rs","line_start":3,"is_primary":true}]}}
+{"reason":"compiler-message","message":{"message":"unneeded `return` statement","level":"warning","code":{"code":"clippy::needless_return","explanation":null},"spans":[{"file_name":"src/lib.rs","line_start":3,"is_primary":true}]}}
+{"reason":"compiler-message","message":{"message":"unused variable: `x`","level":"warning","code":{"code":"unused_variables","explanation":null},"spans":[{"file_name":"src/main.rs","line_start":9,"is_primary":false},{"file_name":"src/main.rs","line_start":7,"is_primary":true}]}}
+{"reason":"compiler-message","message":{"message":"2 warnings emitted","level":"warning","code":null,"spans":[]}}
+{"reason":"build-finished","success":true}
+"#;
+
+#[test]
+fn clippy_messages_become_diagnostics_once() {
+    let diagnostics = clippy(CLIPPY, Path::new(ROOT)).unwrap();
+
+    assert_eq!(
+        summary(&diagnostics),
+        [
+            ("needless_return", "src/lib.rs", Some(3)),
+            ("unused_variables", "src/main.rs", Some(7)),
+        ]
+    );
+    assert_eq!(diagnostics[0].message, "unneeded `return` statement");
+}
+
+#[test]
+fn clippy_compile_error_without_code_is_error_rule() {
+    let output = r#"{"reason":"compiler-message","message":{"message":"expected `;`","level":"error","code":null,"spans":[{"file_name":"src/lib.rs","line_start":1,"is_primary":true}]}}"#;
+
+    let diagnostics = clippy(output, Path::new(ROOT)).unwrap();
+
+    assert_eq!(summary(&diagnostics), [("error", "src/lib.rs", Some(1))]);
+}
+
+#[test]
+fn clippy_without_json_is_unparsed() {
+    assert!(clippy("error: could not find `Cargo.toml`\n", Path::new(ROOT)).is_none());
+}
+
+// =============================================================================
+// ESLINT
+// =============================================================================
+
+#[test]
+fn eslint_messages_use_rule_ids() {
+    let output = r#"[{"filePath":"/work/src/app.js","messages":[
+        {"ruleId":"no-unused-vars","severity":2,"message":"'x' is assigned a value but never used.","line":1,"column":7},
+        {"ruleId":null,"fatal":true,"severity":2,"message":"Parsing error: Unexpected token","line":4}
+    ]},{"filePath":"/work/src/ok.js","messages":[]}]"#;
+
+    let diagnostics = eslint(output, Path::new(ROOT)).unwrap();
+
+    assert_eq!(
+        summary(&diagnostics),
+        [
+            ("no-unused-vars", "src/app.js", Some(1)),
+            ("parse-error", "src/app.js", Some(4)),
+        ]
+    );
+}
+
+#[test]
+fn eslint_banner_before_json_is_skipped() {
+    let output = "npm warn exec something\n[]\n";
+
+    assert_eq!(eslint(output, Path::new(ROOT)), Some(Vec::new()));
+}
+
+// =============================================================================
+// GOLANGCI-LINT
+// =============================================================================
+
+#[test]
+fn golangci_issues_use_linter_names() {
+    let output = r#"{"Issues":[{"FromLinter":"errcheck","Text":"Error return value is not checked","Pos":{"Filename":"main.go","Line":10,"Column":2}}],"Report":{}}
+level=info msg="done"
+"#;
+
+    let diagnostics = golangci(output, Path::new(ROOT)).unwrap();
+
+    assert_eq!(summary(&diagnostics), [("errcheck", "main.go", Some(10))]);
+}
+
+#[test]
+fn golangci_null_issues_is_clean() {
+    let output = r#"{"Issues":null,"Report":{}}"#;
+
+    assert_eq!(golangci(output, Path::new(ROOT)), Some(Vec::new()));
+}
+
+// =============================================================================
+// RUFF
+// =============================================================================
+
+#[test]
+fn ruff_codes_become_rules() {
+    let output = r#"[{"code":"F401","message":"`os` imported but unused","filename":"/work/app/main.py","location":{"row":1,"column":8}},
+        {"code":null,"message":"SyntaxError: Expected an expression","filename":"/work/app/bad.py","location":{"row":2,"column":1}}]"#;
+
+    let diagnostics = ruff(output, Path::new(ROOT)).unwrap();
+
+    assert_eq!(
+        summary(&diagnostics),
+        [
+            ("F401", "app/main.py", Some(1)),
+            ("syntax-error", "app/bad.py", Some(2)),
+        ]
+    );
+}
+
+#[test]
+fn non_json_output_is_unparsed() {
+    let root = Path::new(ROOT);
+
+    assert!(eslint("Oops! Something went wrong!\n", root).is_none());
+    assert!(golangci("", root).is_none());
+    assert!(ruff("error: unrecognized subcommand\n", root).is_none());
+}
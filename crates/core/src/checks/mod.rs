@@ -3,7 +3,7 @@
 
 //! Check registry and discovery.
 //!
-//! All 8 built-in checks are registered here:
+//! All 14 built-in checks are registered here:
 //! - cloc: Lines of code, file size limits (enabled by default)
 //! - escapes: Escape hatch detection (enabled by default)
 //! - agents: CLAUDE.md, .cursorrules validation (enabled by default)
@@ -12,17 +12,38 @@
 //! - git: Commit message format (disabled by default)
 //! - build: Binary/bundle size + build time (disabled by default)
 //! - license: License header validation (disabled by default)
+//! - whitespace: Line endings, trailing whitespace, indentation (disabled by default)
+//! - format: Formatter status via rustfmt, prettier, gofmt, ruff (disabled by default)
+//! - lint: External linters via clippy, eslint, golangci-lint, ruff (disabled by default)
+//! - snapshots: Orphaned, oversized, and churning test snapshots (disabled by default)
+//! - deadcode: Unused public items in Rust, JS/TS, and Go (disabled by default)
+//! - deps: Wildcard, unpinned, and duplicate dependencies (disabled by default)
+//!
+//! Mode checks run only when their flag asks for them:
+//! - release: Release readiness (`--ci --release-mode`)
+//!
+//! Named instances (`[check."cloc:scripts"]`) rerun a built-in check with
+//! their own settings and paths; see [`instance`].
 
 pub mod agents;
 pub mod build;
 pub mod cloc;
+pub mod deadcode;
+pub mod deps;
 pub mod docs;
 pub mod escapes;
+pub mod format;
 pub mod git;
+pub mod instance;
 pub mod license;
+pub mod lint;
 pub mod placeholders;
+pub mod policy;
+pub mod release;
+pub mod snapshots;
 pub mod stub;
 pub mod testing;
+pub mod whitespace;
 
 use std::sync::Arc;
 
@@ -30,9 +51,25 @@ use crate::check::Check;
 
 /// All registered check names in canonical order.
 pub const CHECK_NAMES: &[&str] = &[
-    "cloc", "escapes", "agents", "docs", "tests", "git", "build", "license",
+    "cloc",
+    "escapes",
+    "agents",
+    "docs",
+    "tests",
+    "git",
+    "build",
+    "license",
+    "whitespace",
+    "format",
+    "lint",
+    "snapshots",
+    "deadcode",
+    "deps",
 ];
 
+/// Checks that only run when a mode flag requests them, ordered after `CHECK_NAMES`.
+pub const MODE_CHECK_NAMES: &[&str] = &["release"];
+
 /// Checks enabled by default in fast mode.
 pub const DEFAULT_ENABLED: &[&str] = &["cloc", "escapes", "agents", "docs", "tests"];
 
@@ -47,9 +84,28 @@ pub fn all_checks() -> Vec<Arc<dyn Check>> {
         Arc::new(git::GitCheck),
         Arc::new(build::BuildCheck),
         Arc::new(license::LicenseCheck),
+        Arc::new(whitespace::WhitespaceCheck),
+        Arc::new(format::FormatCheck),
+        Arc::new(lint::LintCheck),
+        Arc::new(snapshots::SnapshotsCheck),
+        Arc::new(deadcode::DeadcodeCheck),
+        Arc::new(deps::DepsCheck),
     ]
 }
 
+/// Sort key placing each check in canonical order, with its named
+/// instances right after it.
+pub fn order_key(name: &str) -> (usize, bool) {
+    let base = name
+        .split_once(crate::config::INSTANCE_SEPARATOR)
+        .map_or(name, |(check, _)| check);
+    let position = CHECK_NAMES
+        .iter()
+        .position(|&n| n == base)
+        .unwrap_or(usize::MAX);
+    (position, base != name)
+}
+
 /// Get a check by name.
 pub fn get_check(name: &str) -> Option<Arc<dyn Check>> {
     all_checks().into_iter().find(|c| c.name() == name)
@@ -58,7 +114,7 @@ pub fn get_check(name: &str) -> Option<Arc<dyn Check>> {
 /// Filter checks based on enabled/disabled flags.
 ///
 /// Semantics:
-/// - No flags: run ALL 8 checks
+/// - No flags: run ALL 14 checks
 /// - `--<check>`: run ONLY specified checks
 /// - `--no-<check>`: run all EXCEPT specified checks
 pub fn filter_checks(enabled: &[String], disabled: &[String]) -> Vec<Arc<dyn Check>> {
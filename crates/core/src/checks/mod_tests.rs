@@ -8,9 +8,9 @@
 use super::*;
 
 #[test]
-fn all_checks_returns_8_checks() {
+fn all_checks_returns_14_checks() {
     let checks = all_checks();
-    assert_eq!(checks.len(), 8);
+    assert_eq!(checks.len(), 14);
 }
 
 #[test]
@@ -40,9 +40,15 @@ fn filter_with_disabled_excludes_those() {
 #[test]
 fn filter_default_runs_all_checks() {
     let checks = filter_checks(&[], &[]);
-    // All 8 checks run by default
-    assert_eq!(checks.len(), 8);
+    // All 14 checks run by default
+    assert_eq!(checks.len(), 14);
     assert!(checks.iter().any(|c| c.name() == "git"));
     assert!(checks.iter().any(|c| c.name() == "build"));
     assert!(checks.iter().any(|c| c.name() == "license"));
+    assert!(checks.iter().any(|c| c.name() == "whitespace"));
+    assert!(checks.iter().any(|c| c.name() == "format"));
+    assert!(checks.iter().any(|c| c.name() == "lint"));
+    assert!(checks.iter().any(|c| c.name() == "snapshots"));
+    assert!(checks.iter().any(|c| c.name() == "deadcode"));
+    assert!(checks.iter().any(|c| c.name() == "deps"));
 }
@@ -24,21 +24,21 @@ use serde_json::json;
 use crate::file_reader::FileContent;
 
 /// Placeholder metrics collected from test files.
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlaceholderMetrics {
     pub rust: RustMetrics,
     pub javascript: JsMetrics,
 }
 
 /// Rust placeholder metrics.
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RustMetrics {
     pub ignore: usize,
     pub todo: usize,
 }
 
 /// JavaScript placeholder metrics.
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JsMetrics {
     pub todo: usize,
     pub fixme: usize,
@@ -100,7 +100,7 @@ pub fn collect_placeholder_metrics<P: AsRef<Path>>(
             Err(_) => continue,
         };
         let Some(content) = file_content.as_str() else {
-            continue; // Skip non-UTF-8 files
+            continue; // Skip binary files
         };
 
         // Detect based on file extension
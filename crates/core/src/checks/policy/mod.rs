@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Cross-language lint config policy.
+//!
+//! Every language adapter declares which files configure its linters
+//! (`[<lang>.policy].lint_config`). With `lint_changes = "standalone"`, a
+//! branch that changes one of those files must not also change that
+//! language's source or test files. Lint config changes made in
+//! `chore(lint)` commits are exempt, so a linter bump can carry the fixes it
+//! requires.
+//!
+//! Reported by the escapes check as `lint_policy` violations.
+//! See docs/specs/10-language-adapters.md#lint-config-policy.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::adapter::common::policy::{self as matcher, PolicyConfig};
+use crate::adapter::{
+    Adapter, FileKind, GoAdapter, JavaScriptAdapter, PythonAdapter, RubyAdapter, RustAdapter,
+    ShellAdapter,
+};
+use crate::check::{CheckContext, Violation};
+use crate::checks::git::parse::{ParseResult, parse_conventional_commit};
+use crate::checks::testing::diff::{CommitChanges, get_commits_since};
+use crate::config::{CheckLevel, Config, LintChangesPolicy};
+
+/// Commit type whose lint config changes may be paired with source changes.
+const EXEMPT_TYPE: &str = "chore";
+
+/// Commit scope whose lint config changes may be paired with source changes.
+const EXEMPT_SCOPE: &str = "lint";
+
+/// Result of the lint policy check, split by check level.
+#[derive(Debug, Default)]
+pub struct PolicyCheckResult {
+    /// Violations from languages whose policy level is `error`.
+    pub errors: Vec<Violation>,
+    /// Violations from languages whose policy level is `warn`.
+    pub warnings: Vec<Violation>,
+}
+
+/// Constructs the adapter that classifies a language's files.
+type MakeAdapter = fn() -> Box<dyn Adapter>;
+
+/// One language's policy settings paired with its file classifier.
+struct LanguagePolicy<'a> {
+    language: &'static str,
+    policy: &'a dyn PolicyConfig,
+    classify: Box<dyn Fn(&Path) -> FileKind + 'a>,
+}
+
+/// Check every language's lint policy against the changed files.
+///
+/// Only runs when changed files are known (`--base` or `--staged`).
+pub fn check_lint_policy(ctx: &CheckContext) -> PolicyCheckResult {
+    let Some(changed_files) = ctx.changed_files else {
+        return PolicyCheckResult::default();
+    };
+
+    let languages = enabled_policies(ctx.config);
+    if languages.is_empty() {
+        return PolicyCheckResult::default();
+    }
+
+    let exempt = match ctx.base_branch {
+        Some(base) if !ctx.staged => exempt_lint_files(ctx.root, base),
+        _ => HashSet::new(),
+    };
+    let changed: Vec<&Path> = changed_files
+        .iter()
+        .map(|p| p.as_path())
+        .filter(|p| !exempt.contains(*p))
+        .map(|p| p.strip_prefix(ctx.root).unwrap_or(p))
+        .collect();
+
+    let mut result = PolicyCheckResult::default();
+    for language in &languages {
+        let Some(violation) = check_language(language, &changed) else {
+            continue;
+        };
+        match ctx
+            .config
+            .policy_check_level_for_language(language.language)
+        {
+            CheckLevel::Warn => result.warnings.push(violation),
+            _ => result.errors.push(violation),
+        }
+    }
+    result
+}
+
+/// Languages with `lint_changes = "standalone"` and a policy level other than `off`.
+fn enabled_policies(config: &Config) -> Vec<LanguagePolicy<'_>> {
+    let all: [(&'static str, &dyn PolicyConfig, MakeAdapter); 6] = [
+        ("rust", &config.rust.policy, || Box::new(RustAdapter::new())),
+        ("go", &config.golang.policy, || Box::new(GoAdapter::new())),
+        ("javascript", &config.javascript.policy, || {
+            Box::new(JavaScriptAdapter::new())
+        }),
+        ("python", &config.python.policy, || {
+            Box::new(PythonAdapter::new())
+        }),
+        ("ruby", &config.ruby.policy, || Box::new(RubyAdapter::new())),
+        ("shell", &config.shell.policy, || {
+            Box::new(ShellAdapter::new())
+        }),
+    ];
+
+    all.into_iter()
+        .filter(|(language, policy, _)| {
+            policy.lint_changes() == LintChangesPolicy::Standalone
+                && config.policy_check_level_for_language(language) != CheckLevel::Off
+        })
+        .map(|(language, policy, make_adapter)| {
+            let adapter = make_adapter();
+            LanguagePolicy {
+                language,
+                policy,
+                classify: Box::new(move |path| adapter.classify(path)),
+            }
+        })
+        .collect()
+}
+
+/// Check one language's policy, returning a violation if it was broken.
+fn check_language(language: &LanguagePolicy, changed: &[&Path]) -> Option<Violation> {
+    let result = matcher::check_lint_policy(changed, language.policy, &language.classify);
+    if !result.standalone_violated {
+        return None;
+    }
+    let advice = format!(
+        "Changed {} lint config: {}\nAlso changed source: {}\nSubmit lint config changes in a separate PR, or in a {}({}) commit with the fixes they require.",
+        language.language,
+        result.changed_lint_config.join(", "),
+        truncate_list(&result.changed_source, 3),
+        EXEMPT_TYPE,
+        EXEMPT_SCOPE,
+    );
+    Some(Violation::project("lint_policy", advice).with_pattern("lint_changes = standalone"))
+}
+
+/// Files changed only by `chore(lint)` commits on the branch.
+///
+/// A file also touched by any other commit stays subject to the policy.
+/// Errors reading history leave nothing exempt.
+fn exempt_lint_files(root: &Path, base: &str) -> HashSet<PathBuf> {
+    match get_commits_since(root, base) {
+        Ok(commits) => exempt_paths(&commits),
+        Err(_) => HashSet::new(),
+    }
+}
+
+/// Paths touched by exempt commits and by no other commit.
+fn exempt_paths(commits: &[CommitChanges]) -> HashSet<PathBuf> {
+    let mut exempt = HashSet::new();
+    let mut other = HashSet::new();
+    for commit in commits {
+        let target = if is_exempt_commit(&commit.message) {
+            &mut exempt
+        } else {
+            &mut other
+        };
+        target.extend(commit.changes.iter().map(|c| c.path.clone()));
+    }
+    exempt.retain(|path| !other.contains(path));
+    exempt
+}
+
+/// Whether a commit subject is a `chore(lint)` conventional commit.
+fn is_exempt_commit(message: &str) -> bool {
+    match parse_conventional_commit(message) {
+        ParseResult::Conventional(parsed) => {
+            parsed.commit_type == EXEMPT_TYPE && parsed.scope.as_deref() == Some(EXEMPT_SCOPE)
+        }
+        ParseResult::NonConventional => false,
+    }
+}
+
+/// Truncate a list for display, showing "and N more" if needed.
+fn truncate_list(items: &[String], max: usize) -> String {
+    if items.len() <= max {
+        items.join(", ")
+    } else {
+        let shown: Vec<_> = items.iter().take(max).cloned().collect();
+        format!("{} and {} more", shown.join(", "), items.len() - max)
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
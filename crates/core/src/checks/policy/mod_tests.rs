@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use crate::checks::testing::diff::{ChangeType, FileChange};
+
+use super::*;
+
+fn commit(message: &str, paths: &[&str]) -> CommitChanges {
+    CommitChanges {
+        hash: "abc1234".to_string(),
+        message: message.to_string(),
+        changes: paths
+            .iter()
+            .map(|p| FileChange {
+                path: PathBuf::from(p),
+                change_type: ChangeType::Modified,
+                lines_added: 1,
+                lines_deleted: 0,
+            })
+            .collect(),
+    }
+}
+
+fn standalone_config(content: &str) -> Config {
+    crate::config::parse(content, Path::new("quench.toml")).unwrap()
+}
+
+// =============================================================================
+// Enabled languages
+// =============================================================================
+
+#[test]
+fn no_languages_enabled_by_default() {
+    assert!(enabled_policies(&Config::default()).is_empty());
+}
+
+#[test]
+fn enables_every_standalone_language_not_turned_off() {
+    let config = standalone_config(
+        r#"
+version = 1
+[rust.policy]
+lint_changes = "standalone"
+[shell.policy]
+lint_changes = "standalone"
+[golang.policy]
+lint_changes = "standalone"
+check = "off"
+"#,
+    );
+    let languages: Vec<_> = enabled_policies(&config)
+        .iter()
+        .map(|l| l.language)
+        .collect();
+    assert_eq!(languages, ["rust", "shell"]);
+}
+
+// =============================================================================
+// Per-language matching
+// =============================================================================
+
+#[test]
+fn lint_config_is_only_paired_with_its_own_language() {
+    let config = standalone_config(
+        r#"
+version = 1
+[rust.policy]
+lint_changes = "standalone"
+[shell.policy]
+lint_changes = "standalone"
+"#,
+    );
+    let languages = enabled_policies(&config);
+    let changed = [Path::new(".shellcheckrc"), Path::new("src/lib.rs")];
+
+    let violations: Vec<_> = languages
+        .iter()
+        .filter_map(|l| check_language(l, &changed))
+        .collect();
+    assert!(violations.is_empty());
+
+    let changed = [Path::new(".shellcheckrc"), Path::new("scripts/build.sh")];
+    let violations: Vec<_> = languages
+        .iter()
+        .filter_map(|l| check_language(l, &changed))
+        .collect();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].violation_type, "lint_policy");
+    assert!(
+        violations[0]
+            .advice
+            .contains("Changed shell lint config: .shellcheckrc")
+    );
+}
+
+#[test]
+fn truncates_long_source_lists() {
+    let items: Vec<String> = ["a", "b", "c", "d", "e"].map(String::from).to_vec();
+    assert_eq!(truncate_list(&items, 3), "a, b, c and 2 more");
+    assert_eq!(truncate_list(&items[..2], 3), "a, b");
+}
+
+// =============================================================================
+// chore(lint) exemption
+// =============================================================================
+
+#[test]
+fn chore_lint_commits_are_exempt() {
+    assert!(is_exempt_commit("chore(lint): bump clippy"));
+    assert!(is_exempt_commit("chore(lint)!: enable pedantic"));
+    assert!(!is_exempt_commit("chore: bump clippy"));
+    assert!(!is_exempt_commit("fix(lint): bump clippy"));
+    assert!(!is_exempt_commit("bump clippy"));
+}
+
+#[test]
+fn exempts_paths_touched_only_by_chore_lint_commits() {
+    let commits = [
+        commit("chore(lint): bump clippy", &["clippy.toml", "src/lib.rs"]),
+        commit("feat: add parser", &["src/parser.rs"]),
+    ];
+    let exempt = exempt_paths(&commits);
+    assert!(exempt.contains(Path::new("clippy.toml")));
+    assert!(exempt.contains(Path::new("src/lib.rs")));
+    assert!(!exempt.contains(Path::new("src/parser.rs")));
+}
+
+#[test]
+fn paths_also_touched_by_other_commits_stay_subject_to_policy() {
+    let commits = [
+        commit("chore(lint): bump clippy", &["clippy.toml"]),
+        commit("feat: tweak lints", &["clippy.toml", "src/lib.rs"]),
+    ];
+    assert!(exempt_paths(&commits).is_empty());
+}
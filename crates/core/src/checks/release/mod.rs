@@ -0,0 +1,236 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Release readiness check.
+//!
+//! Verifies release prerequisites per docs/specs/checks/release.md:
+//! - Changelog has a section for the pending version
+//! - Version strings agree across manifests and docs
+//! - No placeholder tests remain
+//! - No `TODO(before-release)` markers remain
+//!
+//! Only runs with `--ci --release-mode`.
+
+pub mod version;
+
+use std::path::Path;
+
+use crate::adapter::glob::build_glob_set;
+use crate::check::{Check, CheckContext, CheckResult, Violation};
+use crate::checks::placeholders::{
+    default_js_patterns, default_rust_patterns, javascript::find_js_placeholders,
+    rust::find_rust_placeholders,
+};
+use crate::config::ReleaseConfig;
+use crate::metrics::{self, CheckMetrics};
+
+use version::{ManifestVersion, doc_mentions, has_changelog_section, manifest_versions};
+
+/// The release check verifies a project is ready to be released.
+pub struct ReleaseCheck;
+
+impl Check for ReleaseCheck {
+    fn name(&self) -> &'static str {
+        "release"
+    }
+
+    fn description(&self) -> &'static str {
+        "Release readiness"
+    }
+
+    fn default_enabled(&self) -> bool {
+        false
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let config = &ctx.config.check.release;
+        if config.check.as_deref() == Some("off") {
+            return CheckResult::passed(self.name());
+        }
+
+        let versions = manifest_versions(ctx.root, &ctx.config.project.packages);
+        let Some(pending) = versions.first().map(|v| v.version.clone()) else {
+            return CheckResult::skipped(self.name(), "No version in Cargo.toml or package.json");
+        };
+
+        let mut violations = Vec::new();
+        check_manifests(&versions, &pending, &mut violations);
+        check_changelog(ctx.root, config, &pending, &mut violations);
+        check_docs(ctx.root, config, &versions, &pending, &mut violations);
+        let (placeholders, markers) = scan_files(ctx, config, &mut violations);
+
+        let metrics = metrics::Release {
+            version: pending,
+            placeholders,
+            markers,
+        };
+
+        let result = if violations.is_empty() {
+            CheckResult::passed(self.name())
+        } else if config.check.as_deref() == Some("warn") {
+            CheckResult::passed_with_warnings(self.name(), violations)
+        } else {
+            CheckResult::failed(self.name(), violations)
+        };
+        result.with_metrics(metrics.to_json())
+    }
+}
+
+/// Every manifest must declare the pending version.
+fn check_manifests(versions: &[ManifestVersion], pending: &str, violations: &mut Vec<Violation>) {
+    let Some((first, rest)) = versions.split_first() else {
+        return;
+    };
+    for manifest in rest.iter().filter(|m| m.version != pending) {
+        violations.push(Violation::file_only(
+            &manifest.path,
+            "version_mismatch",
+            format!(
+                "Version {} does not match the pending release {} (from {}).",
+                manifest.version,
+                pending,
+                first.path.display()
+            ),
+        ));
+    }
+}
+
+/// The changelog must exist and have a section for the pending version.
+fn check_changelog(
+    root: &Path,
+    config: &ReleaseConfig,
+    pending: &str,
+    violations: &mut Vec<Violation>,
+) {
+    let advice = match std::fs::read_to_string(root.join(&config.changelog)) {
+        Err(_) => format!(
+            "Add {} with a section for {} describing the release.",
+            config.changelog, pending
+        ),
+        Ok(content) if !has_changelog_section(&content, pending) => format!(
+            "Add a section for {} (e.g., `## [{}]`) describing the release.",
+            pending, pending
+        ),
+        Ok(_) => return,
+    };
+    violations.push(Violation::file_only(
+        &config.changelog,
+        "missing_changelog",
+        advice,
+    ));
+}
+
+/// Versions pinned next to a package name in docs must match the pending version.
+fn check_docs(
+    root: &Path,
+    config: &ReleaseConfig,
+    versions: &[ManifestVersion],
+    pending: &str,
+    violations: &mut Vec<Violation>,
+) {
+    let mut names: Vec<&str> = versions.iter().filter_map(|v| v.name.as_deref()).collect();
+    names.sort_unstable();
+    names.dedup();
+
+    for doc in &config.docs {
+        let Ok(content) = std::fs::read_to_string(root.join(doc)) else {
+            continue;
+        };
+        for name in &names {
+            for (line, found) in doc_mentions(&content, name) {
+                if found != pending {
+                    violations.push(Violation::file(
+                        doc,
+                        line,
+                        "version_mismatch",
+                        format!(
+                            "{} {} does not match the pending release {}.",
+                            name, found, pending
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Scan project files for placeholder tests and release markers.
+///
+/// Returns `(placeholders, markers)` counts.
+fn scan_files(
+    ctx: &CheckContext,
+    config: &ReleaseConfig,
+    violations: &mut Vec<Violation>,
+) -> (usize, usize) {
+    let exclude = build_glob_set(&config.exclude);
+    let rust_patterns = default_rust_patterns();
+    let js_patterns = default_js_patterns();
+    let mut placeholders = 0;
+    let mut markers = 0;
+
+    for file in ctx.files {
+        let relative = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
+        if exclude.is_match(relative) || relative == Path::new(&config.changelog) {
+            continue;
+        }
+        // The config lists the markers themselves
+        if relative.file_name().is_some_and(|n| n == "quench.toml") {
+            continue;
+        }
+        let Ok(file_content) = ctx.read_file(&file.path) else {
+            continue;
+        };
+        let Some(content) = file_content.as_str() else {
+            continue;
+        };
+
+        let ext = relative.extension().and_then(|e| e.to_str()).unwrap_or("");
+        let found: Vec<(u32, String)> = match ext {
+            "rs" => find_rust_placeholders(content, &rust_patterns)
+                .into_iter()
+                .map(|p| (p.line, format!("{} ({})", p.test_name, p.kind.as_str())))
+                .collect(),
+            "js" | "jsx" | "ts" | "tsx" | "mjs" | "mts" => {
+                find_js_placeholders(content, &js_patterns)
+                    .into_iter()
+                    .map(|p| (p.line, format!("'{}' ({})", p.description, p.kind.as_str())))
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        for (line, test) in found {
+            placeholders += 1;
+            violations.push(Violation::file(
+                relative,
+                line,
+                "placeholder_test",
+                format!(
+                    "Implement or remove placeholder test {} before releasing.",
+                    test
+                ),
+            ));
+        }
+
+        for (idx, text) in content.lines().enumerate() {
+            if let Some(marker) = config.markers.iter().find(|m| text.contains(m.as_str())) {
+                markers += 1;
+                violations.push(Violation::file(
+                    relative,
+                    idx as u32 + 1,
+                    "release_marker",
+                    format!("Resolve {} before releasing.", marker),
+                ));
+            }
+        }
+
+        if ctx.limit.is_some_and(|l| violations.len() >= l) {
+            break;
+        }
+    }
+
+    (placeholders, markers)
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
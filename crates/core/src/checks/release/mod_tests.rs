@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use std::path::PathBuf;
+
+use super::*;
+use tempfile::TempDir;
+
+fn manifest(path: &str, name: &str, version: &str) -> ManifestVersion {
+    ManifestVersion {
+        path: PathBuf::from(path),
+        name: Some(name.to_string()),
+        version: version.to_string(),
+    }
+}
+
+#[test]
+fn release_check_name() {
+    assert_eq!(ReleaseCheck.name(), "release");
+    assert!(!ReleaseCheck.default_enabled());
+}
+
+#[test]
+fn mismatched_manifest_versions_are_reported() {
+    let versions = [
+        manifest("Cargo.toml", "app", "1.0.0"),
+        manifest("web/package.json", "web", "0.9.0"),
+    ];
+    let mut violations = Vec::new();
+    check_manifests(&versions, "1.0.0", &mut violations);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].violation_type, "version_mismatch");
+    assert_eq!(
+        violations[0].advice,
+        "Version 0.9.0 does not match the pending release 1.0.0 (from Cargo.toml)."
+    );
+}
+
+#[test]
+fn missing_changelog_is_reported() {
+    let dir = TempDir::new().unwrap();
+    let mut violations = Vec::new();
+    check_changelog(
+        dir.path(),
+        &ReleaseConfig::default(),
+        "1.0.0",
+        &mut violations,
+    );
+
+    assert_eq!(violations[0].violation_type, "missing_changelog");
+    assert!(violations[0].advice.starts_with("Add CHANGELOG.md"));
+}
+
+#[test]
+fn changelog_without_pending_section_is_reported() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("CHANGELOG.md"), "## [0.9.0]\n").unwrap();
+    let mut violations = Vec::new();
+    check_changelog(
+        dir.path(),
+        &ReleaseConfig::default(),
+        "1.0.0",
+        &mut violations,
+    );
+    assert_eq!(violations.len(), 1);
+
+    std::fs::write(dir.path().join("CHANGELOG.md"), "## [1.0.0]\n").unwrap();
+    violations.clear();
+    check_changelog(
+        dir.path(),
+        &ReleaseConfig::default(),
+        "1.0.0",
+        &mut violations,
+    );
+    assert!(violations.is_empty());
+}
+
+#[test]
+fn stale_doc_versions_are_reported_with_line() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("README.md"),
+        "# App\n\n```toml\napp = \"0.9.0\"\n```\n",
+    )
+    .unwrap();
+    let versions = [manifest("Cargo.toml", "app", "1.0.0")];
+    let mut violations = Vec::new();
+    check_docs(
+        dir.path(),
+        &ReleaseConfig::default(),
+        &versions,
+        "1.0.0",
+        &mut violations,
+    );
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].line, Some(4));
+    assert_eq!(
+        violations[0].advice,
+        "app 0.9.0 does not match the pending release 1.0.0."
+    );
+}
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Version strings in manifests, changelogs, and docs.
+
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+/// A version declared by a `Cargo.toml` or `package.json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestVersion {
+    /// Manifest path relative to the project root.
+    pub path: PathBuf,
+    /// Package name, if declared.
+    pub name: Option<String>,
+    /// Declared version.
+    pub version: String,
+}
+
+/// Name and version from a `Cargo.toml`.
+///
+/// Reads `[package]`, falling back to `[workspace.package]`. Inherited
+/// versions (`version.workspace = true`) are not literal and return `None`.
+pub fn cargo_version(content: &str) -> Option<(Option<String>, String)> {
+    let value: toml::Value = toml::from_str(content).ok()?;
+    let package = value.get("package");
+    let name = package
+        .and_then(|p| p.get("name"))
+        .and_then(|n| n.as_str())
+        .map(String::from);
+    let version = package
+        .and_then(|p| p.get("version"))
+        .and_then(|v| v.as_str())
+        .or_else(|| {
+            value
+                .get("workspace")?
+                .get("package")?
+                .get("version")?
+                .as_str()
+        })?;
+    Some((name, version.to_string()))
+}
+
+/// Name and version from a `package.json`.
+pub fn npm_version(content: &str) -> Option<(Option<String>, String)> {
+    let value: serde_json::Value = serde_json::from_str(content).ok()?;
+    let version = value.get("version")?.as_str()?;
+    let name = value.get("name").and_then(|n| n.as_str()).map(String::from);
+    Some((name, version.to_string()))
+}
+
+/// Versions declared by the root manifests, then by each package's manifests.
+///
+/// The first entry is the pending release version.
+pub fn manifest_versions(root: &Path, packages: &[String]) -> Vec<ManifestVersion> {
+    let dirs = std::iter::once(".").chain(packages.iter().map(String::as_str));
+    let mut versions = Vec::new();
+    for dir in dirs {
+        for (file, parse) in [
+            ("Cargo.toml", cargo_version as fn(&str) -> _),
+            ("package.json", npm_version),
+        ] {
+            let path = if dir == "." {
+                PathBuf::from(file)
+            } else {
+                Path::new(dir).join(file)
+            };
+            let Ok(content) = std::fs::read_to_string(root.join(&path)) else {
+                continue;
+            };
+            if let Some((name, version)) = parse(&content)
+                && !versions.iter().any(|v: &ManifestVersion| v.path == path)
+            {
+                versions.push(ManifestVersion {
+                    path,
+                    name,
+                    version,
+                });
+            }
+        }
+    }
+    versions
+}
+
+/// Whether a changelog has a heading for `version` (e.g., `## [1.2.0] - 2026-01-01`).
+pub fn has_changelog_section(content: &str, version: &str) -> bool {
+    let Ok(pattern) = Regex::new(&format!(
+        r"(^|[^0-9.])v?{}([^0-9.]|$)",
+        regex::escape(version)
+    )) else {
+        return false;
+    };
+    content
+        .lines()
+        .filter(|line| line.trim_start().starts_with('#'))
+        .any(|line| pattern.is_match(line))
+}
+
+/// Versions pinned next to a package name in docs, with 1-based line numbers.
+///
+/// Matches install snippets such as `quench = "1.2.0"`, `quench@1.2.0`,
+/// `"quench": "^1.2.0"`, and `quench v1.2.0`.
+pub fn doc_mentions(content: &str, name: &str) -> Vec<(u32, String)> {
+    let Ok(pattern) = Regex::new(&format!(
+        r#"\b{}(?:@|"?\s*[:=]\s*"[~^=]?|\s+v?)(\d+\.\d+\.\d+(?:-[0-9A-Za-z.]+)?)"#,
+        regex::escape(name)
+    )) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .enumerate()
+        .flat_map(|(i, line)| {
+            pattern
+                .captures_iter(line)
+                .filter_map(|cap| cap.get(1))
+                .map(move |m| (i as u32 + 1, m.as_str().to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "version_tests.rs"]
+mod tests;
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use tempfile::TempDir;
+
+#[test]
+fn cargo_version_reads_package() {
+    let toml = "[package]\nname = \"quench\"\nversion = \"0.4.1\"\n";
+    assert_eq!(
+        cargo_version(toml),
+        Some((Some("quench".to_string()), "0.4.1".to_string()))
+    );
+}
+
+#[test]
+fn cargo_version_falls_back_to_workspace_package() {
+    let toml =
+        "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion = \"1.0.0\"\n";
+    assert_eq!(cargo_version(toml), Some((None, "1.0.0".to_string())));
+}
+
+#[test]
+fn cargo_version_skips_inherited_version() {
+    let toml = "[package]\nname = \"cli\"\nversion.workspace = true\n";
+    assert_eq!(cargo_version(toml), None);
+}
+
+#[test]
+fn npm_version_reads_name_and_version() {
+    let json = r#"{"name": "web", "version": "2.1.0"}"#;
+    assert_eq!(
+        npm_version(json),
+        Some((Some("web".to_string()), "2.1.0".to_string()))
+    );
+}
+
+#[test]
+fn manifest_versions_lists_root_first() {
+    let dir = TempDir::new().unwrap();
+    std::fs::create_dir_all(dir.path().join("packages/web")).unwrap();
+    std::fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"app\"\nversion = \"1.0.0\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("packages/web/package.json"),
+        r#"{"name": "web", "version": "0.9.0"}"#,
+    )
+    .unwrap();
+
+    let versions = manifest_versions(dir.path(), &["packages/web".to_string()]);
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[0].path, PathBuf::from("Cargo.toml"));
+    assert_eq!(versions[0].version, "1.0.0");
+    assert_eq!(versions[1].path, PathBuf::from("packages/web/package.json"));
+}
+
+#[test]
+fn changelog_section_matches_heading_forms() {
+    assert!(has_changelog_section(
+        "# Changelog\n\n## [1.2.0] - 2026-01-01\n",
+        "1.2.0"
+    ));
+    assert!(has_changelog_section("## v1.2.0\n", "1.2.0"));
+    assert!(!has_changelog_section("## [1.2.01]\n", "1.2.0"));
+    assert!(!has_changelog_section("Released 1.2.0 soon\n", "1.2.0"));
+}
+
+#[test]
+fn doc_mentions_find_install_snippets() {
+    let doc = "quench = \"0.4.0\"\nnpm i quench@0.4.1\n\"quench\": \"^0.3.0\"\nquench v0.2.0 is out\nquenchy = \"9.9.9\"\n";
+    assert_eq!(
+        doc_mentions(doc, "quench"),
+        vec![
+            (1, "0.4.0".to_string()),
+            (2, "0.4.1".to_string()),
+            (3, "0.3.0".to_string()),
+            (4, "0.2.0".to_string()),
+        ]
+    );
+}
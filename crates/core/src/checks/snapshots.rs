@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Snapshot test management check.
+//!
+//! Watches snapshot files (insta `.snap`, jest/vitest `__snapshots__`) for
+//! orphans left behind by removed tests, giant snapshots nobody reviews,
+//! and branches that rewrite more snapshot lines than a reviewer can read,
+//! per docs/specs/checks/snapshots.md. Off by default.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use globset::GlobSet;
+
+use crate::adapter::glob::build_glob_set;
+use crate::check::{Check, CheckContext, CheckResult, Violation};
+use crate::checks::testing::diff::{get_base_changes, get_staged_changes};
+use crate::config::CheckLevel;
+use crate::metrics::{self, CheckMetrics};
+use crate::tolerance::parse_size;
+
+const ORPHAN_ADVICE: &str = "Delete the snapshot, or restore the test that writes it (e.g., `cargo insta test --unreferenced=delete`, `jest --ci -u`).";
+const TOO_LARGE_ADVICE: &str = "Snapshot only what the test asserts on: narrow the value, redact volatile fields, or split the snapshot.";
+const CHURN_ADVICE: &str = "Review snapshot updates on their own: split them into a separate change or narrow what the tests snapshot.";
+
+/// Lines read looking for an insta header before giving up.
+const HEADER_LINES: usize = 8;
+
+/// The snapshots check flags orphaned, oversized, and churning snapshots.
+pub struct SnapshotsCheck;
+
+impl Check for SnapshotsCheck {
+    fn name(&self) -> &'static str {
+        "snapshots"
+    }
+
+    fn description(&self) -> &'static str {
+        "Snapshot test management"
+    }
+
+    fn default_enabled(&self) -> bool {
+        false
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let config = &ctx.config.check.snapshots;
+        if config.check == CheckLevel::Off {
+            return CheckResult::passed(self.name());
+        }
+        let max_size = match parse_size(&config.max_size) {
+            Ok(size) => size,
+            Err(e) => {
+                return CheckResult::skipped(
+                    self.name(),
+                    format!("invalid max_size '{}': {}", config.max_size, e),
+                );
+            }
+        };
+
+        let patterns = build_glob_set(&config.patterns);
+        let exclude = build_glob_set(&config.exclude);
+        let mut violations = Vec::new();
+        let mut counts = metrics::Snapshots::default();
+
+        for file in ctx.files {
+            let relative = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
+            if !is_snapshot(relative, &patterns, &exclude) {
+                continue;
+            }
+            counts.files += 1;
+            counts.bytes += file.size;
+
+            if file.size > max_size {
+                counts.too_large += 1;
+                violations.push(
+                    Violation::file_only(relative, "snapshot_too_large", TOO_LARGE_ADVICE)
+                        .with_threshold(file.size as i64, max_size as i64),
+                );
+            }
+
+            if config.orphans
+                && let Some(test) = orphaned_by(ctx.root, relative)
+            {
+                counts.orphaned += 1;
+                violations.push(
+                    Violation::file_only(relative, "orphaned_snapshot", ORPHAN_ADVICE)
+                        .with_target(test.display().to_string()),
+                );
+            }
+        }
+
+        if let Some((files, lines)) = churn(ctx, &patterns, &exclude) {
+            counts.churn_files = Some(files);
+            counts.churn_lines = Some(lines);
+            if config.max_churn > 0 && lines > config.max_churn {
+                violations.push(
+                    Violation::project("snapshot_churn", CHURN_ADVICE)
+                        .with_threshold(lines as i64, config.max_churn as i64),
+                );
+            }
+        }
+
+        if let Some(limit) = ctx.limit {
+            violations.truncate(limit);
+        }
+        let metrics = counts.to_json();
+        if violations.is_empty() {
+            CheckResult::passed(self.name()).with_metrics(metrics)
+        } else if config.check == CheckLevel::Warn {
+            CheckResult::passed_with_warnings(self.name(), violations).with_metrics(metrics)
+        } else {
+            CheckResult::failed(self.name(), violations).with_metrics(metrics)
+        }
+    }
+}
+
+fn is_snapshot(relative: &Path, patterns: &GlobSet, exclude: &GlobSet) -> bool {
+    patterns.is_match(relative) && !exclude.is_match(relative)
+}
+
+/// The missing test behind an orphaned snapshot, or `None` if the snapshot
+/// is still referenced (or its owner can't be told).
+///
+/// - Jest/vitest: `dir/__snapshots__/x.test.ts.snap` belongs to `dir/x.test.ts`.
+/// - Insta: the header's `source:` file must exist and mention the snapshot
+///   name (`<module>__<name>.snap`, with insta's `-N` counter dropped).
+fn orphaned_by(root: &Path, snapshot: &Path) -> Option<PathBuf> {
+    let dir = snapshot.parent()?;
+    let file_name = snapshot.file_name()?.to_str()?;
+
+    if dir.file_name().is_some_and(|d| d == "__snapshots__") {
+        let test = dir.parent()?.join(file_name.strip_suffix(".snap")?);
+        return (!root.join(&test).exists()).then_some(test);
+    }
+
+    // Insta records the source relative to the workspace, which may sit
+    // anywhere above the snapshot
+    let source = insta_source(&root.join(snapshot))?;
+    let Some(found) = snapshot
+        .ancestors()
+        .skip(1)
+        .map(|dir| dir.join(&source))
+        .find(|candidate| root.join(candidate).is_file())
+    else {
+        return Some(source);
+    };
+    let name = insta_name(file_name)?;
+    let content = std::fs::read_to_string(root.join(&found)).ok()?;
+    (!content.contains(name)).then_some(found)
+}
+
+/// The `source:` path in an insta snapshot's header.
+fn insta_source(path: &Path) -> Option<PathBuf> {
+    let reader = BufReader::new(File::open(path).ok()?);
+    let mut lines = reader.lines().map_while(Result::ok).take(HEADER_LINES);
+    if lines.next()?.trim_end() != "---" {
+        return None;
+    }
+    lines
+        .take_while(|line| line.trim_end() != "---")
+        .find_map(|line| {
+            let value = line.strip_prefix("source:")?.trim();
+            let value = value.trim_matches(|c| c == '"' || c == '\'');
+            (!value.is_empty()).then(|| PathBuf::from(value))
+        })
+}
+
+/// Snapshot name from an insta file name, e.g. `parse` from
+/// `mycrate__parser__tests__parse-2.snap`.
+fn insta_name(file_name: &str) -> Option<&str> {
+    let stem = file_name.strip_suffix(".snap")?;
+    let name = stem.rsplit_once("__").map_or(stem, |(_, name)| name);
+    let name = match name.rsplit_once('-') {
+        Some((base, counter)) if counter.chars().all(|c| c.is_ascii_digit()) => base,
+        _ => name,
+    };
+    (!name.is_empty()).then_some(name)
+}
+
+/// Snapshot files and lines changed against `--base` or in `--staged`.
+fn churn(ctx: &CheckContext, patterns: &GlobSet, exclude: &GlobSet) -> Option<(usize, usize)> {
+    let changes = if ctx.staged {
+        get_staged_changes(ctx.root).ok()?
+    } else {
+        get_base_changes(ctx.root, ctx.base_branch?).ok()?
+    };
+    let snapshots: Vec<_> = changes
+        .iter()
+        .filter(|c| {
+            let relative = c.path.strip_prefix(ctx.root).unwrap_or(&c.path);
+            is_snapshot(relative, patterns, exclude)
+        })
+        .collect();
+    Some((
+        snapshots.len(),
+        snapshots.iter().map(|c| c.lines_changed()).sum(),
+    ))
+}
+
+#[cfg(test)]
+#[path = "snapshots_tests.rs"]
+mod tests;
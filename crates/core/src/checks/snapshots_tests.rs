@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use tempfile::TempDir;
+
+fn write(root: &Path, path: &str, content: &str) {
+    let path = root.join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+fn insta_snapshot(source: &str) -> String {
+    format!("---\nsource: {source}\nexpression: value\n---\n42\n")
+}
+
+// =============================================================================
+// INSTA NAME TESTS
+// =============================================================================
+
+#[test]
+fn insta_name_strips_module_path() {
+    assert_eq!(
+        insta_name("mycrate__parser__tests__parse_empty.snap"),
+        Some("parse_empty")
+    );
+}
+
+#[test]
+fn insta_name_drops_counter() {
+    assert_eq!(insta_name("mycrate__tests__parse-2.snap"), Some("parse"));
+}
+
+#[test]
+fn insta_name_keeps_non_numeric_suffix() {
+    assert_eq!(
+        insta_name("mycrate__tests__round-trip.snap"),
+        Some("round-trip")
+    );
+}
+
+#[test]
+fn insta_name_without_module_is_whole_stem() {
+    assert_eq!(insta_name("parse.snap"), Some("parse"));
+}
+
+// =============================================================================
+// INSTA HEADER TESTS
+// =============================================================================
+
+#[test]
+fn insta_source_reads_header() {
+    let temp = TempDir::new().unwrap();
+    write(temp.path(), "a.snap", &insta_snapshot("src/parser.rs"));
+
+    assert_eq!(
+        insta_source(&temp.path().join("a.snap")),
+        Some(PathBuf::from("src/parser.rs"))
+    );
+}
+
+#[test]
+fn insta_source_accepts_quoted_path() {
+    let temp = TempDir::new().unwrap();
+    write(
+        temp.path(),
+        "a.snap",
+        "---\nsource: \"src/lib.rs\"\n---\n1\n",
+    );
+
+    assert_eq!(
+        insta_source(&temp.path().join("a.snap")),
+        Some(PathBuf::from("src/lib.rs"))
+    );
+}
+
+#[test]
+fn insta_source_ignores_files_without_header() {
+    let temp = TempDir::new().unwrap();
+    write(temp.path(), "a.snap", "source: src/lib.rs\n");
+
+    assert_eq!(insta_source(&temp.path().join("a.snap")), None);
+}
+
+// =============================================================================
+// ORPHAN TESTS
+// =============================================================================
+
+#[test]
+fn jest_snapshot_with_test_is_not_orphaned() {
+    let temp = TempDir::new().unwrap();
+    write(
+        temp.path(),
+        "src/button.test.ts",
+        "test('renders', () => {});\n",
+    );
+    write(
+        temp.path(),
+        "src/__snapshots__/button.test.ts.snap",
+        "exports = {};\n",
+    );
+
+    let snapshot = Path::new("src/__snapshots__/button.test.ts.snap");
+    assert_eq!(orphaned_by(temp.path(), snapshot), None);
+}
+
+#[test]
+fn jest_snapshot_without_test_is_orphaned() {
+    let temp = TempDir::new().unwrap();
+    write(
+        temp.path(),
+        "src/__snapshots__/button.test.ts.snap",
+        "exports = {};\n",
+    );
+
+    let snapshot = Path::new("src/__snapshots__/button.test.ts.snap");
+    assert_eq!(
+        orphaned_by(temp.path(), snapshot),
+        Some(PathBuf::from("src/button.test.ts"))
+    );
+}
+
+#[test]
+fn insta_snapshot_named_in_source_is_not_orphaned() {
+    let temp = TempDir::new().unwrap();
+    write(
+        temp.path(),
+        "src/parser.rs",
+        "#[test]\nfn parse_empty() { insta::assert_snapshot!(parse(\"\")); }\n",
+    );
+    write(
+        temp.path(),
+        "src/snapshots/mycrate__parser__parse_empty.snap",
+        &insta_snapshot("src/parser.rs"),
+    );
+
+    let snapshot = Path::new("src/snapshots/mycrate__parser__parse_empty.snap");
+    assert_eq!(orphaned_by(temp.path(), snapshot), None);
+}
+
+#[test]
+fn insta_snapshot_resolves_source_from_workspace_member() {
+    let temp = TempDir::new().unwrap();
+    write(
+        temp.path(),
+        "crates/core/src/lib.rs",
+        "fn parse_empty() {}\n",
+    );
+    write(
+        temp.path(),
+        "crates/core/src/snapshots/core__parse_empty.snap",
+        &insta_snapshot("src/lib.rs"),
+    );
+
+    let snapshot = Path::new("crates/core/src/snapshots/core__parse_empty.snap");
+    assert_eq!(orphaned_by(temp.path(), snapshot), None);
+}
+
+#[test]
+fn insta_snapshot_missing_from_source_is_orphaned() {
+    let temp = TempDir::new().unwrap();
+    write(
+        temp.path(),
+        "src/parser.rs",
+        "#[test]\nfn parse_other() {}\n",
+    );
+    write(
+        temp.path(),
+        "src/snapshots/mycrate__parser__parse_empty.snap",
+        &insta_snapshot("src/parser.rs"),
+    );
+
+    let snapshot = Path::new("src/snapshots/mycrate__parser__parse_empty.snap");
+    assert_eq!(
+        orphaned_by(temp.path(), snapshot),
+        Some(PathBuf::from("src/parser.rs"))
+    );
+}
+
+#[test]
+fn insta_snapshot_with_deleted_source_is_orphaned() {
+    let temp = TempDir::new().unwrap();
+    write(
+        temp.path(),
+        "src/snapshots/mycrate__parser__parse_empty.snap",
+        &insta_snapshot("src/parser.rs"),
+    );
+
+    let snapshot = Path::new("src/snapshots/mycrate__parser__parse_empty.snap");
+    assert_eq!(
+        orphaned_by(temp.path(), snapshot),
+        Some(PathBuf::from("src/parser.rs"))
+    );
+}
+
+#[test]
+fn snapshot_of_unknown_format_is_not_orphaned() {
+    let temp = TempDir::new().unwrap();
+    write(temp.path(), "fixtures/output.snap", "plain text\n");
+
+    assert_eq!(
+        orphaned_by(temp.path(), Path::new("fixtures/output.snap")),
+        None
+    );
+}
@@ -36,6 +36,8 @@ fn stub_check_result_marked_as_stub() {
     let config = Config::default();
     let files = [];
     let violation_count = AtomicUsize::new(0);
+    let decode_log = crate::file_reader::DecodeLog::default();
+    let diagnostics = crate::diagnostics::Diagnostics::silent();
     let ctx = CheckContext {
         root: Path::new("."),
         files: &files,
@@ -44,11 +46,16 @@ fn stub_check_result_marked_as_stub() {
         violation_count: &violation_count,
         changed_files: None,
         fix: false,
+        run_fixers: false,
         dry_run: false,
+        patch: None,
         ci_mode: false,
         base_branch: None,
         staged: false,
+        staged_content: None,
         verbose: false,
+        decode_log: &decode_log,
+        diagnostics: &diagnostics,
     };
 
     let result = check.run(&ctx);
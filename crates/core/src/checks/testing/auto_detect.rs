@@ -5,15 +5,15 @@
 
 use std::path::Path;
 
-use serde_json::json;
-
 use crate::check::{CheckResult, Violation};
 use crate::config::TestSuiteConfig;
+use crate::metrics::{self, CheckMetrics};
 
 use super::runners::{
     RunnerContext, detect_go_runner, detect_js_runner, detect_py_runner, detect_rust_runner,
 };
 use super::suite::run_single_suite;
+use super::suite_metrics;
 
 /// Auto-detect JavaScript test runner.
 ///
@@ -38,6 +38,7 @@ pub fn auto_detect_js_suite(root: &Path) -> Option<(TestSuiteConfig, String)> {
         max_avg: None,
         max_test: None,
         timeout: None,
+        container: None,
     };
 
     Some((suite, detection.source.to_metric_string()))
@@ -61,6 +62,7 @@ pub fn auto_detect_py_suite(root: &Path) -> Option<(TestSuiteConfig, String)> {
         max_avg: None,
         max_test: None,
         timeout: None,
+        container: None,
     };
 
     Some((suite, detection.source.to_metric_string()))
@@ -84,6 +86,7 @@ pub fn auto_detect_rust_suite(root: &Path) -> Option<(TestSuiteConfig, String)>
         max_avg: None,
         max_test: None,
         timeout: None,
+        container: None,
     };
 
     Some((suite, detection.source.to_metric_string()))
@@ -107,6 +110,7 @@ pub fn auto_detect_go_suite(root: &Path) -> Option<(TestSuiteConfig, String)> {
         max_avg: None,
         max_test: None,
         timeout: None,
+        container: None,
     };
 
     Some((suite, detection.source.to_metric_string()))
@@ -122,39 +126,26 @@ pub fn run_auto_detected_suite(
     let result = run_single_suite(&suite, runner_ctx);
 
     // Build metrics with auto_detected flag
-    let mut metrics = json!({
-        "test_count": result.test_count,
-        "total_ms": result.total_ms,
-        "auto_detected": true,
-        "runner": suite.runner,
-        "suites": [{
-            "name": result.name,
-            "runner": result.runner,
-            "passed": result.passed,
-            "test_count": result.test_count,
-        }]
-    });
-
-    // Add optional timing metrics
-    if let Some(avg) = result.avg_ms {
-        metrics["avg_ms"] = json!(avg);
-    }
-    if let Some(max) = result.max_ms {
-        metrics["max_ms"] = json!(max);
-    }
-    if let Some(ref test) = result.max_test {
-        metrics["max_test"] = json!(test);
-    }
-
-    // Add detection source if provided
-    if let Some(source) = detection_source {
-        metrics["detection_source"] = json!(source);
-    }
-
-    // Add coverage if collected
-    if let Some(ref coverage) = result.coverage {
-        metrics["coverage"] = json!(coverage);
-    }
+    let metrics = metrics::Tests::Suites(metrics::TestSuites {
+        test_count: result.test_count,
+        doctest_count: result.doctest_count,
+        total_ms: result.total_ms,
+        auto_detected: true,
+        runner: Some(suite.runner.clone()),
+        detection_source,
+        suites: vec![suite_metrics(&result, None)],
+        avg_ms: result.avg_ms,
+        max_ms: result.max_ms,
+        max_test: result.max_test.clone(),
+        coverage: result
+            .coverage
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .collect(),
+        ..Default::default()
+    })
+    .to_json();
 
     if result.passed || result.skipped {
         CheckResult::passed(check_name).with_metrics(metrics)
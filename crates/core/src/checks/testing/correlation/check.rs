@@ -5,13 +5,12 @@
 
 use std::path::{Path, PathBuf};
 
-use serde_json::json;
-
 use crate::adapter::{Adapter, FileKind, GenericAdapter};
 use crate::check::{CheckContext, CheckResult, Violation};
 use crate::checks::placeholders::{
     PlaceholderMetrics, collect_placeholder_metrics, default_js_patterns, default_rust_patterns,
 };
+use crate::metrics::{self, CheckMetrics, CorrelationScope};
 
 use super::super::diff::{
     ChangeType, FileChange, get_base_changes, get_commits_since, get_staged_changes,
@@ -148,10 +147,14 @@ fn collect_test_file_placeholder_metrics(ctx: &CheckContext) -> PlaceholderMetri
 fn finalize_with_placeholders(
     violations: Vec<Violation>,
     ctx: &CheckContext,
-    mut metrics: serde_json::Value,
+    counts: metrics::TestCorrelation,
     check_name: &str,
 ) -> CheckResult {
-    metrics["placeholders"] = json!(collect_test_file_placeholder_metrics(ctx).to_json());
+    let metrics = metrics::Tests::Correlation(metrics::TestCorrelation {
+        placeholders: collect_test_file_placeholder_metrics(ctx),
+        ..counts
+    })
+    .to_json();
     let config_check = &ctx.config.check.tests.commit.check;
     if violations.is_empty() {
         CheckResult::passed(check_name).with_metrics(metrics)
@@ -179,7 +182,7 @@ pub fn check_branch_scope(
             Err(e) => return CheckResult::skipped(check_name, e),
         }
     } else {
-        return finalize_with_placeholders(vec![], ctx, json!({}), check_name);
+        return finalize_with_placeholders(vec![], ctx, Default::default(), check_name);
     };
 
     let mut result = analyze_correlation(&changes, correlation_config, ctx.root);
@@ -192,12 +195,15 @@ pub fn check_branch_scope(
         )
     });
     let violations = build_violations(&result.without_tests, &changes, ctx, None);
-    let metrics = json!({
-        "source_files_changed": result.with_tests.len() + result.without_tests.len(),
-        "with_test_changes": result.with_tests.len(),
-        "without_test_changes": result.without_tests.len(),
-        "scope": "branch",
-    });
+    let metrics = metrics::TestCorrelation {
+        source_files_changed: Some(result.with_tests.len() + result.without_tests.len()),
+        with_test_changes: Some(result.with_tests.len()),
+        without_test_changes: Some(result.without_tests.len()),
+        mechanical_files_changed: Some(result.mechanical.len()),
+        mechanical_lines_changed: Some(result.mechanical_lines),
+        scope: Some(CorrelationScope::Branch),
+        ..Default::default()
+    };
     finalize_with_placeholders(violations, ctx, metrics, check_name)
 }
 
@@ -214,9 +220,14 @@ pub fn check_commit_scope(
     };
     let mut violations = Vec::new();
     let mut failing_commits = Vec::new();
+    let mut mechanical_commits = 0;
 
     for commit in &commits {
         let analysis = analyze_commit(commit, correlation_config, ctx.root);
+        if analysis.is_mechanical {
+            mechanical_commits += 1;
+            continue;
+        }
         if analysis.is_test_only {
             continue;
         }
@@ -249,6 +260,12 @@ pub fn check_commit_scope(
 
     failing_commits.sort();
     failing_commits.dedup();
-    let metrics = json!({ "commits_checked": commits.len(), "commits_failing": failing_commits.len(), "scope": "commit" });
+    let metrics = metrics::TestCorrelation {
+        commits_checked: Some(commits.len()),
+        commits_failing: Some(failing_commits.len()),
+        commits_mechanical: Some(mechanical_commits),
+        scope: Some(CorrelationScope::Commit),
+        ..Default::default()
+    };
     finalize_with_placeholders(violations, ctx, metrics, check_name)
 }
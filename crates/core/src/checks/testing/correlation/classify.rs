@@ -4,7 +4,8 @@
 //! File change classification by glob patterns.
 //!
 //! Determines whether each changed file is a source file, test file,
-//! or excluded, using configurable glob patterns.
+//! mechanical (lockfiles, snapshots, generated artifacts), or excluded,
+//! using configurable glob patterns.
 
 use std::path::{Path, PathBuf};
 
@@ -28,6 +29,7 @@ pub(super) struct CompiledPatterns {
     test_patterns: GlobSet,
     source_patterns: GlobSet,
     exclude_patterns: GlobSet,
+    mechanical_patterns: GlobSet,
 }
 
 impl CompiledPatterns {
@@ -36,6 +38,7 @@ impl CompiledPatterns {
             test_patterns: build_glob_set(&config.test_patterns)?,
             source_patterns: build_glob_set(&config.source_patterns)?,
             exclude_patterns: build_glob_set(&config.exclude_patterns)?,
+            mechanical_patterns: build_glob_set(&config.mechanical_patterns)?,
         })
     }
 
@@ -44,10 +47,28 @@ impl CompiledPatterns {
             test_patterns: GlobSet::empty(),
             source_patterns: GlobSet::empty(),
             exclude_patterns: GlobSet::empty(),
+            mechanical_patterns: GlobSet::empty(),
         }
     }
 }
 
+/// Changes touching only mechanical files, deletions included.
+///
+/// These are neither source nor test changes: they never require tests.
+pub(super) fn mechanical_changes<'a>(
+    changes: &'a [FileChange],
+    patterns: &CompiledPatterns,
+    root: &Path,
+) -> Vec<&'a FileChange> {
+    changes
+        .iter()
+        .filter(|c| {
+            let rel_path = c.path.strip_prefix(root).unwrap_or(&c.path);
+            patterns.mechanical_patterns.is_match(rel_path)
+        })
+        .collect()
+}
+
 /// Classify changes into source and test files.
 ///
 /// Uses parallel processing for large change sets (>= PARALLEL_THRESHOLD files).
@@ -81,6 +102,10 @@ fn classify_changes_sequential<'a>(
         // Get relative path for pattern matching
         let rel_path = change.path.strip_prefix(root).unwrap_or(&change.path);
 
+        if patterns.mechanical_patterns.is_match(rel_path) {
+            continue;
+        }
+
         if patterns.test_patterns.is_match(rel_path) {
             test_changes.push(rel_path.to_path_buf());
         } else if patterns.source_patterns.is_match(rel_path)
@@ -106,7 +131,9 @@ fn classify_changes_parallel<'a>(
         .filter_map(|change| {
             let rel_path = change.path.strip_prefix(root).unwrap_or(&change.path);
 
-            if patterns.test_patterns.is_match(rel_path) {
+            if patterns.mechanical_patterns.is_match(rel_path) {
+                None
+            } else if patterns.test_patterns.is_match(rel_path) {
                 Some((None, Some(rel_path.to_path_buf())))
             } else if patterns.source_patterns.is_match(rel_path)
                 && !patterns.exclude_patterns.is_match(rel_path)
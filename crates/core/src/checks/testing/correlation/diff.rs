@@ -2,6 +2,9 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 //! Git diff analysis for inline test detection.
+//!
+//! Inline tests are `#[cfg(test)]` blocks and doctests (code blocks in
+//! `///` and `//!` doc comments).
 
 use std::path::Path;
 
@@ -20,16 +23,18 @@ pub enum DiffRange<'a> {
     Commit(&'a str),
 }
 
-/// Check if a Rust source file has inline test changes (#[cfg(test)] blocks).
+/// Check if a Rust source file has inline test changes (#[cfg(test)] blocks
+/// or doctests).
 ///
-/// Returns true if the file's diff contains changes within a #[cfg(test)] module.
+/// Returns true if the file's diff adds lines within a #[cfg(test)] module
+/// or a doctest.
 pub fn has_inline_test_changes(file_path: &Path, root: &Path, range: DiffRange<'_>) -> bool {
     let diff_content = match get_file_diff(file_path, root, range) {
         Ok(content) => content,
         Err(_) => return false,
     };
 
-    changes_in_cfg_test(&diff_content)
+    changes_in_cfg_test(&diff_content) || changes_in_doctest(&diff_content)
 }
 
 /// Get the diff for a specific file.
@@ -130,3 +135,86 @@ pub fn changes_in_cfg_test(diff_content: &str) -> bool {
 
     found_changes_in_test
 }
+
+/// Code block attributes rustdoc compiles and runs as a doctest.
+///
+/// `ignore` blocks are left out: they never run.
+const DOCTEST_ATTRS: &[&str] = &[
+    "rust",
+    "should_panic",
+    "no_run",
+    "compile_fail",
+    "test_harness",
+    "standalone_crate",
+];
+
+/// Parse diff content to detect if changes are within doctests.
+///
+/// A doctest is a code block in `///` or `//!` doc comments whose info
+/// string is empty or only Rust attributes (`rust`, `should_panic`,
+/// `no_run`, `compile_fail`, `editionNNNN`, ...). Blocks tagged `text`,
+/// another language, or `ignore` aren't doctests.
+pub fn changes_in_doctest(diff_content: &str) -> bool {
+    // Whether the current line is inside a doc comment code block, and
+    // whether that block is a doctest
+    let mut in_block = false;
+    let mut is_doctest = false;
+
+    for line in diff_content.lines() {
+        if line.starts_with("diff ")
+            || line.starts_with("index ")
+            || line.starts_with("--- ")
+            || line.starts_with("+++ ")
+            || line.starts_with("@@ ")
+        {
+            continue;
+        }
+
+        let content = line
+            .strip_prefix('+')
+            .or_else(|| line.strip_prefix('-'))
+            .or_else(|| line.strip_prefix(' '))
+            .unwrap_or(line);
+
+        let Some(doc) = doc_comment_text(content) else {
+            // Code blocks end with their doc comment
+            in_block = false;
+            continue;
+        };
+
+        if let Some(info) = doc.trim_start().strip_prefix("```") {
+            if in_block {
+                in_block = false;
+            } else {
+                in_block = true;
+                is_doctest = is_doctest_info(info);
+            }
+            continue;
+        }
+
+        if in_block && is_doctest && line.starts_with('+') {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// The text of a `///` or `//!` doc comment line.
+fn doc_comment_text(content: &str) -> Option<&str> {
+    let trimmed = content.trim_start();
+    // Four or more slashes make a plain comment
+    if trimmed.starts_with("////") {
+        return None;
+    }
+    trimmed
+        .strip_prefix("///")
+        .or_else(|| trimmed.strip_prefix("//!"))
+}
+
+/// Whether a code block info string marks a doctest.
+fn is_doctest_info(info: &str) -> bool {
+    info.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|attr| !attr.is_empty())
+        .all(|attr| DOCTEST_ATTRS.contains(&attr) || attr.starts_with("edition"))
+}
@@ -91,3 +91,68 @@ fn changes_in_cfg_test_context_only() {
 
     assert!(!changes_in_cfg_test(diff));
 }
+
+#[test]
+fn changes_in_doctest_detects_added_example() {
+    let diff = r#"diff --git a/src/math.rs b/src/math.rs
+--- a/src/math.rs
++++ b/src/math.rs
+@@ -1,3 +1,8 @@
+ /// Add two numbers.
++///
++/// ```
++/// assert_eq!(math::add(1, 2), 3);
++/// ```
+ pub fn add(a: i32, b: i32) -> i32 {
+     a + b
+ }
+"#;
+
+    assert!(changes_in_doctest(diff));
+    assert!(!changes_in_cfg_test(diff));
+}
+
+#[test]
+fn changes_in_doctest_accepts_rust_attributes() {
+    let diff = r#"@@ -1,4 +1,5 @@
+ //! ```should_panic,edition2021
+ //! use math::add;
++//! add(i32::MAX, 1);
+ //! ```
+"#;
+
+    assert!(changes_in_doctest(diff));
+}
+
+#[test]
+fn changes_in_doctest_ignores_non_doctest_blocks() {
+    let diff = r#"@@ -1,4 +1,12 @@
+ /// Output format:
++/// ```text
++/// add: 3
++/// ```
++/// ```ignore
++/// add(1, 2);
++/// ```
++// ```
++// add(1, 2);
++// ```
+ pub fn add(a: i32, b: i32) -> i32 {
+"#;
+
+    assert!(!changes_in_doctest(diff));
+}
+
+#[test]
+fn changes_in_doctest_ignores_prose_and_removals() {
+    let diff = r#"@@ -1,6 +1,6 @@
+-/// Add two numbers.
++/// Add two integers.
+ ///
+ /// ```
+-/// assert_eq!(math::add(1, 1), 2);
+ /// ```
+"#;
+
+    assert!(!changes_in_doctest(diff));
+}
@@ -15,7 +15,7 @@ use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
 use super::diff::{CommitChanges, FileChange};
-use classify::{CompiledPatterns, classify_changes};
+use classify::{CompiledPatterns, classify_changes, mechanical_changes};
 use matching::{correlation_base_name, extract_base_name, is_test_only};
 
 #[cfg(test)]
@@ -26,7 +26,7 @@ mod tests;
 pub use check::{check_branch_scope, check_commit_scope, missing_tests_advice};
 
 // Re-export diff analysis
-pub use diff::{DiffRange, changes_in_cfg_test, has_inline_test_changes};
+pub use diff::{DiffRange, changes_in_cfg_test, changes_in_doctest, has_inline_test_changes};
 
 // Re-export matching types and functions
 pub use matching::{
@@ -43,10 +43,12 @@ pub struct CorrelationConfig {
     pub source_patterns: Vec<String>,
     /// Files excluded from requiring tests.
     pub exclude_patterns: Vec<String>,
+    /// Lockfiles, snapshots, and generated artifacts: never source or test.
+    pub mechanical_patterns: Vec<String>,
 }
 
 /// Result of correlation analysis.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct CorrelationResult {
     /// Source files that have corresponding test changes.
     pub with_tests: Vec<PathBuf>,
@@ -54,6 +56,10 @@ pub struct CorrelationResult {
     pub without_tests: Vec<PathBuf>,
     /// Test-only changes (TDD workflow).
     pub test_only: Vec<PathBuf>,
+    /// Mechanical changes (lockfiles, snapshots, generated artifacts).
+    pub mechanical: Vec<PathBuf>,
+    /// Lines added and deleted across mechanical changes.
+    pub mechanical_lines: usize,
 }
 
 /// Result of analyzing a single commit for correlation.
@@ -67,6 +73,8 @@ pub struct CommitAnalysis {
     pub source_without_tests: Vec<PathBuf>,
     /// True if this commit contains only test changes (TDD workflow).
     pub is_test_only: bool,
+    /// True if this commit touches only mechanical files.
+    pub is_mechanical: bool,
 }
 
 /// Analyze a single commit for source/test correlation.
@@ -85,12 +93,15 @@ pub fn analyze_commit(
     let is_test_only = !result.test_only.is_empty()
         && result.with_tests.is_empty()
         && result.without_tests.is_empty();
+    let is_mechanical =
+        !result.mechanical.is_empty() && result.mechanical.len() == commit.changes.len();
 
     CommitAnalysis {
         hash: commit.hash.clone(),
         message: commit.message.clone(),
         source_without_tests: result.without_tests,
         is_test_only,
+        is_mechanical,
     }
 }
 
@@ -102,25 +113,36 @@ pub fn analyze_correlation(
 ) -> CorrelationResult {
     // Early termination: empty changes
     if changes.is_empty() {
-        return CorrelationResult {
-            with_tests: vec![],
-            without_tests: vec![],
-            test_only: vec![],
-        };
+        return CorrelationResult::default();
     }
 
     let patterns =
         CompiledPatterns::from_config(config).unwrap_or_else(|_| CompiledPatterns::empty());
 
+    let mechanical = mechanical_changes(changes, &patterns, root);
+    let mut result = correlate_sources(changes, &patterns, root);
+    result.mechanical_lines = mechanical.iter().map(|c| c.lines_changed()).sum();
+    result.mechanical = mechanical
+        .iter()
+        .map(|c| c.path.strip_prefix(root).unwrap_or(&c.path).to_path_buf())
+        .collect();
+    result
+}
+
+/// Match changed source files against changed test files.
+fn correlate_sources(
+    changes: &[FileChange],
+    patterns: &CompiledPatterns,
+    root: &Path,
+) -> CorrelationResult {
     // Classify changes (parallel for large sets)
-    let (source_changes, test_changes) = classify_changes(changes, &patterns, root);
+    let (source_changes, test_changes) = classify_changes(changes, patterns, root);
 
     // Early termination: no source changes
     if source_changes.is_empty() {
         return CorrelationResult {
-            with_tests: vec![],
-            without_tests: vec![],
             test_only: test_changes,
+            ..Default::default()
         };
     }
 
@@ -172,6 +194,7 @@ pub fn analyze_correlation(
         with_tests,
         without_tests,
         test_only,
+        ..Default::default()
     }
 }
 
@@ -224,5 +247,6 @@ fn analyze_single_source(
         with_tests,
         without_tests,
         test_only,
+        ..Default::default()
     }
 }
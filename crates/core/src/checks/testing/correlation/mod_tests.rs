@@ -39,6 +39,12 @@ fn rust_correlation_config() -> CorrelationConfig {
             "**/lib.rs".to_string(),
             "**/main.rs".to_string(),
         ],
+        mechanical_patterns: vec![
+            "**/Cargo.lock".to_string(),
+            "**/__snapshots__/**".to_string(),
+            "**/*.snap".to_string(),
+            "**/*.generated.*".to_string(),
+        ],
     }
 }
 
@@ -463,3 +469,86 @@ fn source_file_without_matching_test_detected() {
     assert_eq!(result.without_tests.len(), 1);
     assert_eq!(result.test_only.len(), 1);
 }
+
+// =============================================================================
+// MECHANICAL CHANGE TESTS
+// =============================================================================
+
+#[test]
+fn mechanical_changes_are_neither_source_nor_test() {
+    let root = Path::new("/project");
+    let changes = vec![
+        make_change("/project/Cargo.lock", ChangeType::Modified),
+        make_change("/project/src/schema.generated.rs", ChangeType::Modified),
+        make_change("/project/tests/snapshots/parse.snap", ChangeType::Modified),
+    ];
+
+    let config = rust_correlation_config();
+    let result = analyze_correlation(&changes, &config, root);
+
+    assert!(result.without_tests.is_empty());
+    assert!(result.test_only.is_empty());
+    assert_eq!(
+        result.mechanical,
+        [
+            PathBuf::from("Cargo.lock"),
+            PathBuf::from("src/schema.generated.rs"),
+            PathBuf::from("tests/snapshots/parse.snap"),
+        ]
+    );
+    assert_eq!(result.mechanical_lines, 45);
+}
+
+#[test]
+fn mechanical_changes_count_deletions() {
+    let root = Path::new("/project");
+    let changes = vec![
+        make_change("/project/src/parser.rs", ChangeType::Modified),
+        make_change("/project/crates/old/Cargo.lock", ChangeType::Deleted),
+    ];
+
+    let config = rust_correlation_config();
+    let result = analyze_correlation(&changes, &config, root);
+
+    assert_eq!(result.without_tests.len(), 1);
+    assert_eq!(result.mechanical, [PathBuf::from("crates/old/Cargo.lock")]);
+}
+
+#[test]
+fn analyze_commit_detects_mechanical_only() {
+    let root = Path::new("/project");
+    let commit = CommitChanges {
+        hash: "456def123abc".to_string(),
+        message: "chore: bump deps".to_string(),
+        changes: vec![
+            make_change("/project/Cargo.lock", ChangeType::Modified),
+            make_change("/project/src/__snapshots__/ui.snap", ChangeType::Modified),
+        ],
+    };
+
+    let config = rust_correlation_config();
+    let analysis = analyze_commit(&commit, &config, root);
+
+    assert!(analysis.is_mechanical);
+    assert!(!analysis.is_test_only);
+    assert!(analysis.source_without_tests.is_empty());
+}
+
+#[test]
+fn analyze_commit_with_source_is_not_mechanical() {
+    let root = Path::new("/project");
+    let commit = CommitChanges {
+        hash: "789abc456def".to_string(),
+        message: "feat: add dep".to_string(),
+        changes: vec![
+            make_change("/project/Cargo.lock", ChangeType::Modified),
+            make_change("/project/src/parser.rs", ChangeType::Modified),
+        ],
+    };
+
+    let config = rust_correlation_config();
+    let analysis = analyze_commit(&commit, &config, root);
+
+    assert!(!analysis.is_mechanical);
+    assert_eq!(analysis.source_without_tests.len(), 1);
+}
@@ -3,7 +3,7 @@
 
 //! Git diff parsing for change detection.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
@@ -48,6 +48,59 @@ pub fn get_base_changes(root: &Path, base: &str) -> Result<Vec<FileChange>, Stri
     merge_diff_outputs(&numstat, &name_status, root)
 }
 
+/// Get the lines added or modified since base ref, per root-relative file.
+///
+/// Compares against the working tree, which is what the test suites ran on.
+/// Deleted lines have no line in the new file and aren't included.
+pub fn get_changed_lines(
+    root: &Path,
+    base: &str,
+) -> Result<HashMap<PathBuf, BTreeSet<u32>>, String> {
+    let diff = run_git_diff(root, &["-U0", "--no-color", "--no-ext-diff", base])?;
+    Ok(parse_changed_lines(&diff))
+}
+
+/// Parse the new-side line numbers out of `git diff -U0` hunk headers.
+///
+/// Format: `@@ -<old>[,<count>] +<new>[,<count>] @@`, where a missing count
+/// means one line and a zero count means a pure deletion.
+fn parse_changed_lines(diff: &str) -> HashMap<PathBuf, BTreeSet<u32>> {
+    let mut changed: HashMap<PathBuf, BTreeSet<u32>> = HashMap::new();
+    let mut current: Option<PathBuf> = None;
+    let mut prev = "";
+
+    for line in diff.lines() {
+        // A file header is `--- a/<path>` then `+++ b/<path>`; checking both
+        // keeps an added `++ ...` line from looking like one
+        let header_path = line
+            .strip_prefix("+++ ")
+            .filter(|_| prev.starts_with("--- "));
+        prev = line;
+        if let Some(path) = header_path {
+            // Paths with spaces end in a tab; deleted files go to /dev/null
+            current = path
+                .trim_end_matches('\t')
+                .strip_prefix("b/")
+                .map(PathBuf::from);
+        } else if let Some(header) = line.strip_prefix("@@ ")
+            && let Some(path) = &current
+            && let Some(new_side) = header.split_whitespace().find(|s| s.starts_with('+'))
+        {
+            let (start, count) = match new_side[1..].split_once(',') {
+                Some((start, count)) => (start.parse::<u32>(), count.parse::<u32>()),
+                None => (new_side[1..].parse::<u32>(), Ok(1)),
+            };
+            if let (Ok(start), Ok(count)) = (start, count) {
+                changed
+                    .entry(path.clone())
+                    .or_default()
+                    .extend(start..start + count);
+            }
+        }
+    }
+    changed
+}
+
 /// Run a git diff command with the given arguments.
 pub fn run_git_diff(root: &Path, args: &[&str]) -> Result<String, String> {
     let mut cmd = Command::new("git");
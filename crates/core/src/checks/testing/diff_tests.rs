@@ -200,3 +200,53 @@ fn get_commit_changes_handles_initial_commit() {
     assert!(changes[0].path.to_string_lossy().contains("test.txt"));
     assert_eq!(changes[0].change_type, ChangeType::Added);
 }
+
+#[test]
+fn parse_changed_lines_reads_hunk_headers() {
+    let diff = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -3 +3 @@ fn a() {
+-    old
++    new
+@@ -10,0 +11,2 @@ fn b() {
++    one
++    two
+@@ -20,3 +22,0 @@ fn c() {
+-    gone
+diff --git a/src/new.rs b/src/new.rs
+new file mode 100644
+--- /dev/null
++++ b/src/new.rs
+@@ -0,0 +1,2 @@
++fn f() {}
++fn g() {}
+";
+    let changed = parse_changed_lines(diff);
+
+    let lib: Vec<u32> = changed[Path::new("src/lib.rs")].iter().copied().collect();
+    assert_eq!(lib, vec![3, 11, 12]);
+    let new: Vec<u32> = changed[Path::new("src/new.rs")].iter().copied().collect();
+    assert_eq!(new, vec![1, 2]);
+}
+
+#[test]
+fn parse_changed_lines_skips_deleted_files() {
+    let diff = "\
+--- a/src/old.rs
++++ /dev/null
+@@ -1,2 +0,0 @@
+-fn f() {}
+-fn g() {}
+";
+    assert!(parse_changed_lines(diff).is_empty());
+}
+
+#[test]
+fn parse_changed_lines_handles_paths_with_spaces() {
+    let diff = "--- a/src/my file.rs\t\n+++ b/src/my file.rs\t\n@@ -1 +1 @@\n-a\n+b\n";
+    let changed = parse_changed_lines(diff);
+    assert!(changed.contains_key(Path::new("src/my file.rs")));
+}
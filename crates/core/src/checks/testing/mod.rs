@@ -8,6 +8,7 @@
 pub mod auto_detect;
 pub mod correlation;
 pub mod diff;
+pub mod patch_coverage;
 pub mod patterns;
 pub mod placeholder;
 pub mod runners;
@@ -18,23 +19,26 @@ pub mod thresholds;
 #[path = "mod_tests.rs"]
 mod unit_tests;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
 use std::sync::Arc;
 
-use serde_json::json;
+use globset::Glob;
 
 use crate::adapter::{
     detect_language, patterns::correlation_exclude_defaults, resolve_project_patterns,
 };
 use crate::check::{Check, CheckContext, CheckResult, Violation};
+use crate::metrics::{self, CheckMetrics};
 
 use self::auto_detect::{
     auto_detect_go_suite, auto_detect_js_suite, auto_detect_py_suite, auto_detect_rust_suite,
 };
 use self::correlation::CorrelationConfig;
+use self::patch_coverage::PatchCoverage;
 use self::runners::{RunnerContext, filter_suites_for_mode};
 use self::suite::{SuiteResult, run_single_suite, run_suites};
-use self::thresholds::{check_coverage_thresholds, check_time_thresholds};
+use self::thresholds::{check_coverage_thresholds, check_patch_coverage, check_time_thresholds};
 
 pub struct TestsCheck;
 
@@ -113,6 +117,7 @@ impl Check for TestsCheck {
             } else {
                 config.exclude.clone()
             },
+            mechanical_patterns: config.mechanical.clone(),
         };
 
         // Commit scope: check each commit individually
@@ -148,76 +153,42 @@ impl TestsCheck {
         // Aggregate coverage from all suites
         let suite_refs: Vec<&SuiteResult> = suite_results.suites.iter().collect();
         let (aggregated_coverage, packages_coverage) = aggregate_suite_coverage(&suite_refs);
-
-        // Build metrics JSON with top-level aggregates
-        let mut metrics = json!({
-            "test_count": agg.test_count,
-            "total_ms": agg.total_ms,
-            "suites": suite_results.suites.iter().map(|s| {
-                let mut obj = json!({
-                    "name": s.name,
-                    "runner": s.runner,
-                    "passed": s.passed,
-                    "test_count": s.test_count,
-                });
-                if s.skipped_count > 0 {
-                    obj["skipped_count"] = json!(s.skipped_count);
-                }
-                if let Some(ref err) = s.error {
-                    obj["error"] = json!(err);
-                }
-                if s.total_ms > 0 {
-                    obj["total_ms"] = json!(s.total_ms);
-                }
-                if let Some(avg) = s.avg_ms {
-                    obj["avg_ms"] = json!(avg);
-                }
-                if let Some(max) = s.max_ms {
-                    obj["max_ms"] = json!(max);
-                }
-                if let Some(ref test) = s.max_test {
-                    obj["max_test"] = json!(test);
-                }
-                if let Some(p50) = s.p50_ms {
-                    obj["p50_ms"] = json!(p50);
-                }
-                if let Some(p90) = s.p90_ms {
-                    obj["p90_ms"] = json!(p90);
-                }
-                if let Some(p99) = s.p99_ms {
-                    obj["p99_ms"] = json!(p99);
-                }
-                obj
-            }).collect::<Vec<_>>(),
-        });
-
-        // Add optional aggregated timing metrics
-        if let Some(avg) = agg.avg_ms {
-            metrics["avg_ms"] = json!(avg);
-        }
-        if let Some(max) = agg.max_ms {
-            metrics["max_ms"] = json!(max);
-        }
-        if let Some(ref test) = agg.max_test {
-            metrics["max_test"] = json!(test);
-        }
-
-        // Add coverage to metrics if available
-        if !aggregated_coverage.is_empty() {
-            metrics["coverage"] = json!(aggregated_coverage);
-        }
-
-        // Add per-package coverage if available
-        if !packages_coverage.is_empty() {
-            metrics["coverage_by_package"] = json!(packages_coverage);
-        }
+        let paths_coverage =
+            aggregate_path_coverage(&ctx.config.check.tests.coverage.path, &suite_refs, ctx.root);
+        save_file_coverage(&suite_refs, ctx.root);
+        let patch = patch_coverage(ctx, &suite_refs);
+
+        // Build metrics with top-level aggregates
+        let metrics = metrics::Tests::Suites(metrics::TestSuites {
+            test_count: agg.test_count,
+            doctest_count: agg.doctest_count,
+            total_ms: agg.total_ms,
+            suites: suite_results
+                .suites
+                .iter()
+                .map(|s| suite_metrics(s, None))
+                .collect(),
+            avg_ms: agg.avg_ms,
+            max_ms: agg.max_ms,
+            max_test: agg.max_test.clone(),
+            coverage: aggregated_coverage.clone().into_iter().collect(),
+            coverage_by_package: packages_coverage.clone().into_iter().collect(),
+            coverage_by_path: paths_coverage.clone(),
+            patch_coverage: patch.as_ref().and_then(PatchCoverage::percent),
+            ..Default::default()
+        })
+        .to_json();
 
         // Collect coverage threshold violations
-        let coverage_violations = check_coverage_thresholds(
+        let mut coverage_violations = check_coverage_thresholds(
             &ctx.config.check.tests,
             &aggregated_coverage,
             &packages_coverage,
+            &paths_coverage,
         );
+        if let Some(ref patch) = patch {
+            coverage_violations.extend(check_patch_coverage(&ctx.config.check.tests, patch));
+        }
 
         // Collect time threshold violations from each suite
         let mut time_violations = Vec::new();
@@ -276,12 +247,15 @@ impl TestsCheck {
             }
         }
 
+        let impact = suite::impact_recorder(ctx, true);
         let runner_ctx = RunnerContext {
             root: ctx.root,
             ci_mode: ctx.ci_mode,
             collect_coverage: true,
             config: ctx.config,
             verbose: ctx.verbose,
+            impact: impact.as_ref(),
+            container: None,
         };
 
         // Run all auto-detected suites
@@ -292,10 +266,12 @@ impl TestsCheck {
                 (result, detection_source)
             })
             .collect();
+        suite::save_impact(ctx.root, impact);
 
         // Aggregate results
         let all_passed = suite_results.iter().all(|(r, _)| r.passed || r.skipped);
         let test_count: usize = suite_results.iter().map(|(r, _)| r.test_count).sum();
+        let doctest_count: usize = suite_results.iter().map(|(r, _)| r.doctest_count).sum();
         let total_ms: u64 = suite_results.iter().map(|(r, _)| r.total_ms).sum();
 
         // Weighted average across all suites
@@ -321,55 +297,24 @@ impl TestsCheck {
         let suites_only: Vec<&SuiteResult> = suite_results.iter().map(|(r, _)| r).collect();
         let (aggregated_coverage, packages_coverage) = aggregate_suite_coverage(&suites_only);
 
-        // Build metrics JSON
-        let mut metrics = json!({
-            "test_count": test_count,
-            "total_ms": total_ms,
-            "auto_detected": true,
-            "suites": suite_results.iter().map(|(s, source)| {
-                let mut obj = json!({
-                    "name": s.name,
-                    "runner": s.runner,
-                    "passed": s.passed,
-                    "test_count": s.test_count,
-                    "detection_source": source,
-                });
-                if s.total_ms > 0 {
-                    obj["total_ms"] = json!(s.total_ms);
-                }
-                if let Some(avg) = s.avg_ms {
-                    obj["avg_ms"] = json!(avg);
-                }
-                if let Some(max) = s.max_ms {
-                    obj["max_ms"] = json!(max);
-                }
-                if let Some(ref test) = s.max_test {
-                    obj["max_test"] = json!(test);
-                }
-                obj
-            }).collect::<Vec<_>>(),
-        });
-
-        // Add optional aggregated timing metrics
-        if let Some(avg) = avg_ms {
-            metrics["avg_ms"] = json!(avg);
-        }
-        if let Some(max) = max_ms {
-            metrics["max_ms"] = json!(max);
-        }
-        if let Some(ref test) = max_test {
-            metrics["max_test"] = json!(test);
-        }
-
-        // Add coverage if available
-        if !aggregated_coverage.is_empty() {
-            metrics["coverage"] = json!(aggregated_coverage);
-        }
-
-        // Add per-package coverage if available
-        if !packages_coverage.is_empty() {
-            metrics["coverage_by_package"] = json!(packages_coverage);
-        }
+        // Build metrics
+        let metrics = metrics::Tests::Suites(metrics::TestSuites {
+            test_count,
+            doctest_count,
+            total_ms,
+            auto_detected: true,
+            suites: suite_results
+                .iter()
+                .map(|(s, source)| suite_metrics(s, Some(source.clone())))
+                .collect(),
+            avg_ms,
+            max_ms,
+            max_test,
+            coverage: aggregated_coverage.into_iter().collect(),
+            coverage_by_package: packages_coverage.into_iter().collect(),
+            ..Default::default()
+        })
+        .to_json();
 
         // Build result
         if all_passed {
@@ -432,6 +377,116 @@ fn aggregate_suite_coverage(
     (by_language, by_package)
 }
 
+/// Coverage of the lines changed since `--base`, when suites report
+/// per-line coverage.
+fn patch_coverage(ctx: &CheckContext, suites: &[&SuiteResult]) -> Option<PatchCoverage> {
+    let base = ctx.base_branch?;
+    if suites.iter().all(|s| s.coverage_lines.is_none()) {
+        return None;
+    }
+    match diff::get_changed_lines(ctx.root, base) {
+        Ok(changed) => Some(PatchCoverage::compute(&changed, suites, ctx.root)),
+        Err(e) => {
+            tracing::warn!("cannot compute patch coverage: {}", e);
+            None
+        }
+    }
+}
+
+/// Average file coverage under each configured path glob.
+///
+/// Files covered by several suites take their best coverage. Globs match
+/// root-relative paths; globs that match no covered file are omitted.
+fn aggregate_path_coverage<T>(
+    globs: &BTreeMap<String, T>,
+    suites: &[&SuiteResult],
+    root: &Path,
+) -> BTreeMap<String, f64> {
+    if globs.is_empty() {
+        return BTreeMap::new();
+    }
+
+    let files = aggregate_file_coverage(suites, root);
+    globs
+        .keys()
+        .filter_map(|glob| {
+            let matcher = Glob::new(glob).ok()?.compile_matcher();
+            let matched: Vec<f64> = files
+                .iter()
+                .filter(|(path, _)| matcher.is_match(path))
+                .map(|(_, &pct)| pct)
+                .collect();
+            (!matched.is_empty()).then(|| {
+                (
+                    glob.clone(),
+                    matched.iter().sum::<f64>() / matched.len() as f64,
+                )
+            })
+        })
+        .collect()
+}
+
+/// Coverage per root-relative file, taking the best across suites.
+fn aggregate_file_coverage<'a>(suites: &[&'a SuiteResult], root: &Path) -> HashMap<&'a Path, f64> {
+    let mut files: HashMap<&Path, f64> = HashMap::new();
+    for &suite in suites {
+        for (path, &pct) in suite.coverage_by_file.iter().flatten() {
+            let path = Path::new(path);
+            let path = path.strip_prefix(root).unwrap_or(path);
+            files
+                .entry(path)
+                .and_modify(|existing| *existing = existing.max(pct))
+                .or_insert(pct);
+        }
+    }
+    files
+}
+
+/// Cache per-file coverage in `.quench/coverage.json` for `quench report --debt`.
+fn save_file_coverage(suites: &[&SuiteResult], root: &Path) {
+    let files: BTreeMap<String, f64> = aggregate_file_coverage(suites, root)
+        .into_iter()
+        .map(|(path, pct)| (path.display().to_string(), pct))
+        .collect();
+    if files.is_empty() {
+        return;
+    }
+    if let Err(e) = crate::debt::save_file_coverage(&crate::debt::file_coverage_path(root), &files)
+    {
+        tracing::warn!("failed to save file coverage: {}", e);
+    }
+}
+
+/// Slowest tests as a JSON array of `{name, ms}` objects.
+/// A suite's entry in the tests check metrics.
+fn suite_metrics(s: &SuiteResult, detection_source: Option<String>) -> metrics::TestSuite {
+    metrics::TestSuite {
+        name: s.name.clone(),
+        runner: s.runner.clone(),
+        passed: s.passed,
+        test_count: s.test_count,
+        doctest_count: s.doctest_count,
+        skipped_count: s.skipped_count,
+        error: s.error.clone(),
+        total_ms: s.total_ms,
+        avg_ms: s.avg_ms,
+        max_ms: s.max_ms,
+        max_test: s.max_test.clone(),
+        slowest: s
+            .slowest
+            .iter()
+            .map(|(name, ms)| metrics::SlowTest {
+                name: name.clone(),
+                ms: *ms,
+            })
+            .collect(),
+        p50_ms: s.p50_ms,
+        p90_ms: s.p90_ms,
+        p99_ms: s.p99_ms,
+        detection_source,
+    }
+}
+
 /// Build violations from failed suites.
 fn build_suite_violations(suites: &[&SuiteResult]) -> Vec<Violation> {
     suites
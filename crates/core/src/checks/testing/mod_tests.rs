@@ -5,7 +5,7 @@
 
 use std::path::Path;
 
-use crate::config::{TestsCommitConfig, TestsConfig};
+use crate::config::{TestsCommitConfig, TestsConfig, TestsPackageCoverageConfig};
 
 use super::correlation::missing_tests_advice;
 use super::patterns::{Language, detect_language};
@@ -293,3 +293,76 @@ fn aggregated_metrics_handles_missing_avg_ms() {
     // Only counts suites with avg_ms
     assert_eq!(agg.avg_ms, Some(50));
 }
+
+// =============================================================================
+// PATH COVERAGE TESTS
+// =============================================================================
+
+fn file_coverage(name: &str, files: &[(&str, f64)]) -> SuiteResult {
+    SuiteResult {
+        name: name.to_string(),
+        runner: name.to_string(),
+        passed: true,
+        coverage_by_file: Some(files.iter().map(|&(p, c)| (p.to_string(), c)).collect()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn path_coverage_averages_matching_files() {
+    let globs = BTreeMap::from([
+        ("src/core/**".to_string(), ()),
+        ("src/cli/**".to_string(), ()),
+        ("docs/**".to_string(), ()),
+    ]);
+    let cargo = file_coverage(
+        "cargo",
+        &[
+            ("src/core/parse.rs", 80.0),
+            ("/work/src/core/eval.rs", 90.0),
+            ("src/cli/main.rs", 40.0),
+        ],
+    );
+    let bats = file_coverage("bats", &[("src/cli/main.rs", 60.0)]);
+
+    let paths = aggregate_path_coverage(&globs, &[&cargo, &bats], Path::new("/work"));
+
+    // Files covered by several suites take their best coverage; globs that
+    // match nothing are omitted
+    assert_eq!(
+        paths,
+        BTreeMap::from([
+            ("src/cli/**".to_string(), 60.0),
+            ("src/core/**".to_string(), 85.0),
+        ])
+    );
+}
+
+#[test]
+fn path_coverage_below_min_is_violation() {
+    let mut config = TestsConfig::default();
+    config.coverage.check = "error".to_string();
+    config.coverage.path.insert(
+        "src/core/**".to_string(),
+        TestsPackageCoverageConfig { min: 90.0 },
+    );
+    config.coverage.path.insert(
+        "src/util/**".to_string(),
+        TestsPackageCoverageConfig { min: 50.0 },
+    );
+    let paths = BTreeMap::from([
+        ("src/core/**".to_string(), 85.0),
+        ("src/util/**".to_string(), 55.0),
+    ]);
+
+    let violations = check_coverage_thresholds(&config, &HashMap::new(), &HashMap::new(), &paths);
+
+    assert_eq!(violations.len(), 1);
+    let (violation, is_error) = &violations[0];
+    assert!(is_error);
+    assert_eq!(violation.violation_type, "coverage_below_min");
+    assert_eq!(
+        violation.advice,
+        "Path 'src/core/**' coverage 85.0% below minimum 90.0%"
+    );
+}
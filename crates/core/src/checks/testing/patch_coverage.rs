@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Patch coverage: coverage of only the lines changed since `--base`.
+//!
+//! Joins the per-line data from coverage reports with `git diff` hunks.
+//! Changed lines the report doesn't instrument (blank lines, comments,
+//! declarations) don't count either way.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use super::runners::LineHits;
+use super::suite::SuiteResult;
+
+/// Coverage of the changed lines.
+#[derive(Debug, Default, PartialEq)]
+pub struct PatchCoverage {
+    /// Changed lines the coverage reports instrument.
+    pub instrumented: usize,
+    /// Instrumented changed lines that ran.
+    pub covered: usize,
+    /// Changed lines that didn't run, per root-relative file.
+    pub uncovered: BTreeMap<PathBuf, Vec<u32>>,
+}
+
+impl PatchCoverage {
+    /// Compute patch coverage from changed lines and suite line coverage.
+    pub fn compute(
+        changed: &HashMap<PathBuf, BTreeSet<u32>>,
+        suites: &[&SuiteResult],
+        root: &Path,
+    ) -> Self {
+        let hits = merge_line_hits(suites, root);
+        let mut patch = Self::default();
+
+        for (path, lines) in changed {
+            let Some(file_hits) = find_hits(&hits, path) else {
+                continue;
+            };
+            for line in lines {
+                match file_hits.get(line) {
+                    Some(true) => {
+                        patch.instrumented += 1;
+                        patch.covered += 1;
+                    }
+                    Some(false) => {
+                        patch.instrumented += 1;
+                        patch.uncovered.entry(path.clone()).or_default().push(*line);
+                    }
+                    None => {}
+                }
+            }
+        }
+        patch
+    }
+
+    /// Percentage of instrumented changed lines that ran, or None if no
+    /// changed line is instrumented.
+    pub fn percent(&self) -> Option<f64> {
+        (self.instrumented > 0).then(|| self.covered as f64 / self.instrumented as f64 * 100.0)
+    }
+}
+
+/// Line hits across suites, keyed by root-relative path where the report
+/// gave an absolute one. A line ran if it ran in any suite.
+fn merge_line_hits(suites: &[&SuiteResult], root: &Path) -> HashMap<PathBuf, LineHits> {
+    let mut merged: HashMap<PathBuf, LineHits> = HashMap::new();
+    for suite in suites {
+        for (path, hits) in suite.coverage_lines.iter().flatten() {
+            let path = Path::new(path);
+            let path = path.strip_prefix(root).unwrap_or(path).to_path_buf();
+            let file = merged.entry(path).or_default();
+            for (&line, &ran) in hits {
+                *file.entry(line).or_default() |= ran;
+            }
+        }
+    }
+    merged
+}
+
+/// Line hits for a root-relative path. Reports that use module paths
+/// (Go's `example.com/m/pkg/a.go`) or trimmed ones (`src/lib.rs` in a
+/// workspace crate) match on whole trailing path components.
+fn find_hits<'a>(hits: &'a HashMap<PathBuf, LineHits>, path: &Path) -> Option<&'a LineHits> {
+    hits.get(path).or_else(|| {
+        hits.iter()
+            .find(|(key, _)| key.ends_with(path) || path.ends_with(key))
+            .map(|(_, file_hits)| file_hits)
+    })
+}
+
+/// Format line numbers as ranges: `3, 7-9, 12`.
+pub fn format_line_ranges(lines: &[u32]) -> String {
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for &line in lines {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == line => *end = line,
+            _ => ranges.push((line, line)),
+        }
+    }
+    ranges
+        .iter()
+        .map(|&(start, end)| {
+            if start == end {
+                start.to_string()
+            } else {
+                format!("{start}-{end}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+#[path = "patch_coverage_tests.rs"]
+mod tests;
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn suite(lines: &[(&str, &[(u32, bool)])]) -> SuiteResult {
+    SuiteResult {
+        coverage_lines: Some(
+            lines
+                .iter()
+                .map(|&(path, hits)| (path.to_string(), hits.iter().copied().collect()))
+                .collect(),
+        ),
+        ..Default::default()
+    }
+}
+
+fn changed(files: &[(&str, &[u32])]) -> HashMap<PathBuf, BTreeSet<u32>> {
+    files
+        .iter()
+        .map(|&(path, lines)| (PathBuf::from(path), lines.iter().copied().collect()))
+        .collect()
+}
+
+#[test]
+fn counts_only_instrumented_changed_lines() {
+    let s = suite(&[(
+        "src/lib.rs",
+        &[(1, true), (2, false), (3, true), (9, false)],
+    )]);
+    // Line 4 isn't instrumented; line 9 didn't change
+    let patch = PatchCoverage::compute(
+        &changed(&[("src/lib.rs", &[1, 2, 3, 4])]),
+        &[&s],
+        Path::new("/p"),
+    );
+
+    assert_eq!(patch.instrumented, 3);
+    assert_eq!(patch.covered, 2);
+    assert_eq!(patch.uncovered[Path::new("src/lib.rs")], vec![2]);
+    let pct = patch.percent().unwrap();
+    assert!((pct - 66.67).abs() < 0.01, "got {pct}");
+}
+
+#[test]
+fn no_instrumented_lines_has_no_percent() {
+    let s = suite(&[("src/lib.rs", &[(1, true)])]);
+    let patch = PatchCoverage::compute(&changed(&[("README.md", &[1, 2])]), &[&s], Path::new("/p"));
+
+    assert_eq!(patch, PatchCoverage::default());
+    assert_eq!(patch.percent(), None);
+}
+
+#[test]
+fn line_covered_by_any_suite_counts() {
+    let a = suite(&[("src/lib.rs", &[(1, false), (2, false)])]);
+    let b = suite(&[("src/lib.rs", &[(1, true)])]);
+    let patch = PatchCoverage::compute(
+        &changed(&[("src/lib.rs", &[1, 2])]),
+        &[&a, &b],
+        Path::new("/p"),
+    );
+
+    assert_eq!(patch.covered, 1);
+    assert_eq!(patch.uncovered[Path::new("src/lib.rs")], vec![2]);
+}
+
+#[test]
+fn matches_absolute_and_module_paths() {
+    let s = suite(&[
+        ("/p/crates/core/src/lib.rs", &[(1, true)]),
+        ("example.com/m/pkg/add.go", &[(5, false)]),
+    ]);
+    let patch = PatchCoverage::compute(
+        &changed(&[("crates/core/src/lib.rs", &[1]), ("pkg/add.go", &[5])]),
+        &[&s],
+        Path::new("/p"),
+    );
+
+    assert_eq!(patch.instrumented, 2);
+    assert_eq!(patch.covered, 1);
+    assert_eq!(patch.uncovered[Path::new("pkg/add.go")], vec![5]);
+}
+
+#[test]
+fn does_not_match_partial_file_names() {
+    let s = suite(&[("src/mylib.rs", &[(1, false)])]);
+    let patch = PatchCoverage::compute(&changed(&[("lib.rs", &[1])]), &[&s], Path::new("/p"));
+
+    assert_eq!(patch.instrumented, 0);
+}
+
+#[test]
+fn formats_line_ranges() {
+    assert_eq!(format_line_ranges(&[3]), "3");
+    assert_eq!(format_line_ranges(&[3, 7, 8, 9, 12]), "3, 7-9, 12");
+    assert_eq!(format_line_ranges(&[]), "");
+}
@@ -16,6 +16,7 @@ use super::{
     rust_binary_names, shell_script_files,
 };
 use crate::config::TestSuiteConfig;
+use crate::interrupt;
 
 /// Bats test runner for shell script testing.
 pub struct BatsRunner;
@@ -43,9 +44,14 @@ impl TestRunner for BatsRunner {
 
         let start = Instant::now();
 
+        // Target coverage instruments binaries and scripts on the host, so it
+        // isn't collected for suites run in a container
+        let target_coverage =
+            ctx.collect_coverage && ctx.container.is_none() && !config.targets.is_empty();
+
         // If collecting Rust binary coverage, build instrumented binaries FIRST
         // so they're available when bats runs
-        let rust_build = if ctx.collect_coverage && !config.targets.is_empty() {
+        let rust_build = if target_coverage {
             prepare_rust_binary_coverage(config, ctx)
         } else {
             None
@@ -60,15 +66,17 @@ impl TestRunner for BatsRunner {
         cmd.arg(test_path);
 
         cmd.current_dir(ctx.root);
-        cmd.stdout(Stdio::piped());
-        cmd.stderr(Stdio::piped());
 
         // Add coverage environment if we built instrumented binaries
         if let Some(ref build_result) = rust_build {
             cmd.envs(coverage_env(build_result));
         }
 
-        let child = match cmd.spawn() {
+        let mut cmd = ctx.wrap(cmd);
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let child = match interrupt::spawn(&mut cmd) {
             Ok(c) => c,
             Err(e) => {
                 return TestRunResult::failed(
@@ -94,7 +102,7 @@ impl TestRunner for BatsRunner {
         let mut result = parse_tap_output(&stdout, total_time);
 
         // Collect coverage if requested and targets specified
-        if ctx.collect_coverage && !config.targets.is_empty() {
+        if target_coverage {
             // Shell coverage via kcov
             if let Some(shell_coverage) = collect_bats_shell_coverage(config, ctx) {
                 result = result.with_collected_coverage(shell_coverage, "shell");
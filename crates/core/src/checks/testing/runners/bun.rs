@@ -16,6 +16,7 @@ use super::{
     run_with_timeout,
 };
 use crate::config::TestSuiteConfig;
+use crate::interrupt;
 
 /// Bun test runner for JavaScript/TypeScript test suites.
 pub struct BunRunner;
@@ -53,10 +54,11 @@ impl TestRunner for BunRunner {
         }
 
         cmd.current_dir(ctx.root);
+        let mut cmd = ctx.wrap(cmd);
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
 
-        let child = match cmd.spawn() {
+        let child = match interrupt::spawn(&mut cmd) {
             Ok(c) => c,
             Err(e) => {
                 return TestRunResult::failed(start.elapsed(), format!("failed to spawn bun: {e}"));
@@ -81,7 +83,7 @@ impl TestRunner for BunRunner {
 
         // Collect coverage if requested
         if ctx.collect_coverage {
-            let coverage = collect_bun_coverage(ctx.root, config.path.as_deref());
+            let coverage = collect_bun_coverage(ctx.root, config.path.as_deref(), ctx.container);
             result = result.with_collected_coverage(coverage, "javascript");
         }
 
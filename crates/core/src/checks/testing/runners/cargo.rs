@@ -13,6 +13,7 @@ use super::{
     run_with_timeout,
 };
 use crate::config::TestSuiteConfig;
+use crate::interrupt;
 
 /// Cargo test runner for Rust projects.
 pub struct CargoRunner;
@@ -44,12 +45,13 @@ impl TestRunner for CargoRunner {
             .unwrap_or_else(|| ctx.root.to_path_buf());
         cmd.current_dir(&work_dir);
 
+        let mut cmd = ctx.wrap(cmd);
         // Capture output
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
 
         // Spawn and run with optional timeout
-        let child = match cmd.spawn() {
+        let child = match interrupt::spawn(&mut cmd) {
             Ok(c) => c,
             Err(e) => {
                 return TestRunResult::failed(
@@ -86,7 +88,7 @@ impl TestRunner for CargoRunner {
 
         // Collect coverage if requested
         if ctx.collect_coverage {
-            let coverage = collect_rust_coverage(ctx.root, config.path.as_deref());
+            let coverage = collect_rust_coverage(ctx.root, config.path.as_deref(), ctx.container);
             result = result.with_collected_coverage(coverage, "rust");
         }
 
@@ -102,8 +104,12 @@ impl TestRunner for CargoRunner {
 /// test tests::test_fail ... FAILED
 /// test result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
 /// test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.01s
+/// test src/lib.rs - add (line 5) ... ok
 /// ```
 ///
+/// Doctests (named `<file> - <item> (line N)`) count as tests and are
+/// also counted in `doctest_count`.
+///
 /// Optimizations:
 /// - Pre-allocates test vector based on line count hint
 /// - Uses string slices to minimize allocations
@@ -148,10 +154,29 @@ pub fn parse_cargo_output(stdout: &str, total_time: Duration) -> TestRunResult {
     } else {
         TestRunResult::failed(total_time, "tests failed")
     };
+    result.doctest_count = tests.iter().filter(|t| is_doctest_name(&t.name)).count();
     result.tests = tests;
     result
 }
 
+/// Whether a test name is a rustdoc doctest name.
+///
+/// Formats: `src/lib.rs - add (line 5)`, with ` - compile` (no_run) or
+/// ` - compile fail` (compile_fail) appended.
+fn is_doctest_name(name: &str) -> bool {
+    let name = name
+        .strip_suffix(" - compile fail")
+        .or_else(|| name.strip_suffix(" - compile"))
+        .unwrap_or(name);
+    let Some((item, line)) = name
+        .strip_suffix(')')
+        .and_then(|rest| rest.rsplit_once(" (line "))
+    else {
+        return false;
+    };
+    item.contains(" - ") && !line.is_empty() && line.bytes().all(|b| b.is_ascii_digit())
+}
+
 /// Parse a test line after "test " prefix.
 /// Returns (name, result) where result is "ok", "FAILED", or "ignored".
 #[inline]
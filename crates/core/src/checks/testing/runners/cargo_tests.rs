@@ -169,6 +169,37 @@ test result: ok. 1 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out; fini
     assert_eq!(result.skipped_count(), 1);
 }
 
+#[test]
+fn counts_doctests() {
+    let output = r#"
+running 1 test
+test tests::add ... ok
+
+test result: ok. 1 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+
+running 4 tests
+test src/lib.rs - add (line 5) ... ok
+test src/lib.rs - Calc::sub (line 21) ... ignored
+test src/lib.rs - parse (line 40) - compile ... ok
+test src/lib.rs - parse (line 48) - compile fail ... ok
+
+test result: ok. 3 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.40s
+"#;
+    let result = parse_cargo_output(output, Duration::from_secs(1));
+
+    assert!(result.passed);
+    assert_eq!(result.tests.len(), 5);
+    assert_eq!(result.doctest_count, 4);
+}
+
+#[test]
+fn test_names_resembling_doctests_are_not_counted() {
+    assert!(is_doctest_name("src/lib.rs - add (line 5)"));
+    assert!(!is_doctest_name("tests::add"));
+    assert!(!is_doctest_name("tests::range (line 5)"));
+    assert!(!is_doctest_name("src/lib.rs - add (line five)"));
+}
+
 // =============================================================================
 // Error Categorization Tests
 // =============================================================================
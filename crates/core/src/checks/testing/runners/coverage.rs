@@ -3,7 +3,7 @@
 
 //! Coverage report parsing for cargo llvm-cov.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use std::process::{Command, Stdio};
 use std::sync::OnceLock;
@@ -11,6 +11,12 @@ use std::time::{Duration, Instant};
 
 use serde::Deserialize;
 
+use crate::container::{self, Container};
+
+/// Per-line execution data for one file: each instrumented line
+/// (1-indexed) and whether any test ran it.
+pub type LineHits = BTreeMap<u32, bool>;
+
 /// Result of collecting coverage.
 #[derive(Debug, Clone)]
 pub struct CoverageResult {
@@ -26,6 +32,9 @@ pub struct CoverageResult {
     pub files: HashMap<String, f64>,
     /// Per-package coverage data (package name -> line coverage %).
     pub packages: HashMap<String, f64>,
+    /// Per-line data (path as reported -> line hits), for reports that
+    /// include it. Used for patch coverage.
+    pub lines: HashMap<String, LineHits>,
 }
 
 impl CoverageResult {
@@ -37,6 +46,7 @@ impl CoverageResult {
             line_coverage: None,
             files: HashMap::new(),
             packages: HashMap::new(),
+            lines: HashMap::new(),
         }
     }
 
@@ -48,6 +58,7 @@ impl CoverageResult {
             line_coverage: None,
             files: HashMap::new(),
             packages: HashMap::new(),
+            lines: HashMap::new(),
         }
     }
 }
@@ -72,22 +83,35 @@ pub fn llvm_cov_available() -> bool {
 }
 
 /// Collect coverage for a Rust project.
-pub fn collect_rust_coverage(root: &Path, path: Option<&str>) -> CoverageResult {
-    if !llvm_cov_available() {
+///
+/// Covers the same tests as the cargo runner: unit and integration tests
+/// of every workspace member, plus doctests when the toolchain is nightly
+/// (cargo-llvm-cov only instruments doctests there). cargo-llvm-cov merges
+/// the profiles of all test binaries into one report.
+///
+/// With a container, cargo-llvm-cov runs in it instead of on the host.
+pub fn collect_rust_coverage(
+    root: &Path,
+    path: Option<&str>,
+    container: Option<&Container>,
+) -> CoverageResult {
+    if container.is_none() && !llvm_cov_available() {
         return CoverageResult::skipped();
     }
 
     let start = Instant::now();
 
-    let mut cmd = Command::new("cargo");
-    cmd.args(["llvm-cov", "--json", "--release"]);
-
     // Set working directory
     let work_dir = path
         .map(|p| root.join(p))
         .unwrap_or_else(|| root.to_path_buf());
+
+    let doctests = nightly_toolchain(&work_dir, container);
+    let mut cmd = Command::new("cargo");
+    cmd.args(llvm_cov_args(doctests));
     cmd.current_dir(&work_dir);
 
+    let mut cmd = container::wrap(container, cmd);
     cmd.stdout(Stdio::piped());
     cmd.stderr(Stdio::piped());
 
@@ -113,6 +137,33 @@ pub fn collect_rust_coverage(root: &Path, path: Option<&str>) -> CoverageResult
     parse_llvm_cov_json(&stdout, duration)
 }
 
+/// Arguments for `cargo llvm-cov`, matching the runner's `cargo test --all`.
+fn llvm_cov_args(doctests: bool) -> Vec<&'static str> {
+    let mut args = vec!["llvm-cov", "--json", "--release", "--workspace"];
+    if doctests {
+        args.push("--doctests");
+    }
+    args
+}
+
+/// Whether `rustc` in `work_dir` (honoring rust-toolchain files) is nightly.
+fn nightly_toolchain(work_dir: &Path, container: Option<&Container>) -> bool {
+    let mut cmd = Command::new("rustc");
+    cmd.arg("--version").current_dir(work_dir);
+    let mut cmd = container::wrap(container, cmd);
+    cmd.stdout(Stdio::piped()).stderr(Stdio::null());
+    cmd.output()
+        .is_ok_and(|out| out.status.success() && is_nightly(&String::from_utf8_lossy(&out.stdout)))
+}
+
+/// Whether `rustc --version` output names a nightly toolchain.
+fn is_nightly(version: &str) -> bool {
+    version
+        .split_whitespace()
+        .nth(1)
+        .is_some_and(|v| v.contains("-nightly"))
+}
+
 // =============================================================================
 // JSON Parsing
 // =============================================================================
@@ -142,6 +193,67 @@ struct LlvmCovLines {
 struct LlvmCovFile {
     filename: String,
     summary: LlvmCovSummary,
+    /// `[line, col, count, has_count, is_region_entry, is_gap_region]`,
+    /// in file order. Absent with `--summary-only`.
+    #[serde(default)]
+    segments: Vec<Vec<serde_json::Value>>,
+}
+
+/// One llvm-cov segment: where a region's count starts or resumes.
+struct Segment {
+    line: u32,
+    count: u64,
+    has_count: bool,
+    /// Starts a counted region (not a gap, not a resumed region).
+    region_start: bool,
+}
+
+impl Segment {
+    fn parse(raw: &[serde_json::Value]) -> Option<Self> {
+        let flag = |i: usize| raw.get(i).and_then(|v| v.as_bool()).unwrap_or(false);
+        Some(Self {
+            line: u32::try_from(raw.first()?.as_u64()?).ok()?,
+            count: raw.get(2)?.as_u64()?,
+            has_count: flag(3),
+            region_start: flag(3) && flag(4) && !flag(5),
+        })
+    }
+}
+
+/// Line hits from llvm-cov segments, following llvm-cov's own line
+/// coverage: a line counts if a region starts on it or a region from an
+/// earlier line covers it, and ran if any of those regions ran.
+fn segment_line_hits(segments: &[Vec<serde_json::Value>]) -> LineHits {
+    let segments: Vec<Segment> = segments.iter().filter_map(|s| Segment::parse(s)).collect();
+    let mut hits = LineHits::new();
+    // The segment in effect at the start of the current line
+    let mut wrapped: Option<&Segment> = None;
+    let mut idx = 0;
+    let Some(last_line) = segments.last().map(|s| s.line) else {
+        return hits;
+    };
+
+    for line in segments[0].line..=last_line {
+        let start = idx;
+        while idx < segments.len() && segments[idx].line == line {
+            idx += 1;
+        }
+        let on_line = &segments[start..idx];
+
+        let mut mapped = wrapped.is_some_and(|w| w.has_count);
+        let mut ran = wrapped.is_some_and(|w| w.has_count && w.count > 0);
+        for segment in on_line.iter().filter(|s| s.region_start) {
+            mapped = true;
+            ran |= segment.count > 0;
+        }
+        if mapped {
+            hits.insert(line, ran);
+        }
+        if let Some(last) = on_line.last() {
+            wrapped = Some(last);
+        }
+    }
+    hits
 }
 
 fn parse_llvm_cov_json(json: &str, duration: Duration) -> CoverageResult {
@@ -163,12 +275,16 @@ fn parse_llvm_cov_json(json: &str, duration: Duration) -> CoverageResult {
     // Extract per-file coverage and group by package
     let mut files = HashMap::new();
     let mut package_files: HashMap<String, Vec<f64>> = HashMap::new();
+    let mut lines = HashMap::new();
 
     for file in &data.files {
         // Normalize path: remove workspace prefix, keep relative
         let path = normalize_coverage_path(&file.filename);
         let coverage = file.summary.lines.percent;
         files.insert(path, coverage);
+        if !file.segments.is_empty() {
+            lines.insert(file.filename.clone(), segment_line_hits(&file.segments));
+        }
 
         // Group by package
         let package = extract_package_name(&file.filename);
@@ -191,6 +307,7 @@ fn parse_llvm_cov_json(json: &str, duration: Duration) -> CoverageResult {
         line_coverage: Some(line_coverage),
         files,
         packages,
+        lines,
     }
 }
 
@@ -103,6 +103,31 @@ fn coverage_result_skipped_returns_success_without_data() {
     assert!(result.packages.is_empty());
 }
 
+// =============================================================================
+// INVOCATION TESTS
+// =============================================================================
+
+#[test]
+fn llvm_cov_covers_whole_workspace() {
+    assert_eq!(
+        llvm_cov_args(false),
+        ["llvm-cov", "--json", "--release", "--workspace"]
+    );
+}
+
+#[test]
+fn llvm_cov_includes_doctests_on_nightly() {
+    assert_eq!(llvm_cov_args(true).last(), Some(&"--doctests"));
+}
+
+#[test]
+fn detects_nightly_toolchain() {
+    assert!(is_nightly("rustc 1.86.0-nightly (a580b5c37 2025-01-08)\n"));
+    assert!(!is_nightly("rustc 1.85.0 (4d91de4e4 2025-02-17)\n"));
+    assert!(!is_nightly("rustc 1.85.0-beta.3 (0d9d1cc19 2025-01-28)\n"));
+    assert!(!is_nightly(""));
+}
+
 // =============================================================================
 // PACKAGE EXTRACTION TESTS
 // =============================================================================
@@ -200,3 +225,66 @@ fn parses_per_package_coverage_with_root_fallback() {
     assert_eq!(result.packages.len(), 1);
     assert_eq!(result.packages.get("root"), Some(&75.0));
 }
+
+#[test]
+fn parses_line_hits_from_llvm_cov_segments() {
+    // fn f() {        line 1: region entered 3 times
+    //     if x {      line 2: ran, then opens a branch that never did
+    //         g();    line 3: inside the branch
+    //     }           line 4: the branch's closing brace
+    // }               line 5: back in the outer region
+    let json = r#"{
+        "data": [{
+            "totals": { "lines": { "percent": 80.0 } },
+            "files": [{
+                "filename": "/home/user/project/src/lib.rs",
+                "summary": { "lines": { "percent": 80.0 } },
+                "segments": [
+                    [1, 10, 3, true, true, false],
+                    [2, 10, 0, true, true, false],
+                    [4, 6, 3, true, false, false],
+                    [5, 2, 0, false, false, false]
+                ]
+            }]
+        }]
+    }"#;
+
+    let result = parse_llvm_cov_json(json, Duration::from_secs(1));
+    let hits = &result.lines["/home/user/project/src/lib.rs"];
+
+    assert_eq!(hits.get(&1), Some(&true));
+    assert_eq!(hits.get(&2), Some(&true));
+    assert_eq!(hits.get(&3), Some(&false));
+    assert_eq!(hits.get(&4), Some(&false));
+    assert_eq!(hits.get(&5), Some(&true));
+    assert_eq!(hits.get(&6), None);
+}
+
+#[test]
+fn gap_regions_do_not_map_lines() {
+    let segments: Vec<Vec<serde_json::Value>> = serde_json::from_str(
+        "[[1, 1, 1, true, true, false], [2, 1, 0, true, true, true], [3, 1, 0, false, false, false]]",
+    )
+    .unwrap();
+    let hits = segment_line_hits(&segments);
+
+    assert_eq!(hits.get(&1), Some(&true));
+    // The gap starts on line 2, but line 2 is still inside line 1's region
+    assert_eq!(hits.get(&2), Some(&true));
+    assert_eq!(hits.get(&3), Some(&false));
+}
+
+#[test]
+fn summary_only_report_has_no_line_hits() {
+    let json = r#"{
+        "data": [{
+            "totals": { "lines": { "percent": 75.0 } },
+            "files": [{
+                "filename": "/p/src/lib.rs",
+                "summary": { "lines": { "percent": 75.0 } }
+            }]
+        }]
+    }"#;
+
+    assert!(parse_llvm_cov_json(json, Duration::ZERO).lines.is_empty());
+}
@@ -17,6 +17,7 @@ use super::{
     run_with_timeout,
 };
 use crate::config::TestSuiteConfig;
+use crate::interrupt;
 
 /// Cucumber runner for Ruby BDD test suites.
 pub struct CucumberRunner;
@@ -55,10 +56,11 @@ impl TestRunner for CucumberRunner {
         }
 
         cmd.current_dir(ctx.root);
+        let mut cmd = ctx.wrap(cmd);
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
 
-        let child = match cmd.spawn() {
+        let child = match interrupt::spawn(&mut cmd) {
             Ok(c) => c,
             Err(e) => {
                 return TestRunResult::failed(
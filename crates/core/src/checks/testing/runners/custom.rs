@@ -15,6 +15,7 @@ use super::{
     run_with_timeout,
 };
 use crate::config::TestSuiteConfig;
+use crate::interrupt;
 
 /// Custom command runner for arbitrary test commands.
 pub struct CustomRunner;
@@ -45,8 +46,8 @@ impl TestRunner for CustomRunner {
 
         let start = Instant::now();
 
-        // Execute command via shell
-        let mut cmd = if cfg!(target_os = "windows") {
+        // Execute command via shell (containers are always Unix)
+        let mut cmd = if cfg!(target_os = "windows") && ctx.container.is_none() {
             let mut c = Command::new("cmd");
             c.args(["/C", command]);
             c
@@ -57,10 +58,11 @@ impl TestRunner for CustomRunner {
         };
 
         cmd.current_dir(ctx.root);
+        let mut cmd = ctx.wrap(cmd);
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
 
-        let child = match cmd.spawn() {
+        let child = match interrupt::spawn(&mut cmd) {
             Ok(c) => c,
             Err(e) => {
                 return TestRunResult::failed(
@@ -17,6 +17,7 @@ fn make_config(command: Option<&str>) -> TestSuiteConfig {
         max_avg: None,
         max_test: None,
         timeout: None,
+        container: None,
     }
 }
 
@@ -27,6 +28,8 @@ fn make_ctx<'a>(root: &'a std::path::Path, config: &'a crate::config::Config) ->
         collect_coverage: false,
         config,
         verbose: false,
+        impact: None,
+        container: None,
     }
 }
 
@@ -17,6 +17,7 @@ use super::{
     run_with_timeout,
 };
 use crate::config::TestSuiteConfig;
+use crate::interrupt;
 
 /// Go test runner for Go projects.
 pub struct GoRunner;
@@ -53,10 +54,11 @@ impl TestRunner for GoRunner {
         cmd.arg(test_path);
 
         cmd.current_dir(ctx.root);
+        let mut cmd = ctx.wrap(cmd);
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
 
-        let child = match cmd.spawn() {
+        let child = match interrupt::spawn(&mut cmd) {
             Ok(c) => c,
             Err(e) => {
                 return TestRunResult::failed(
@@ -86,7 +88,7 @@ impl TestRunner for GoRunner {
 
         // Collect coverage if requested
         if ctx.collect_coverage {
-            let coverage = collect_go_coverage(ctx.root, config.path.as_deref());
+            let coverage = collect_go_coverage(ctx.root, config.path.as_deref(), ctx.container);
             result = result.with_collected_coverage(coverage, "go");
         }
 
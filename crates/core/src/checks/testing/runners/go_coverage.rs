@@ -11,7 +11,8 @@ use std::process::{Command, Stdio};
 use std::sync::OnceLock;
 use std::time::{Duration, Instant};
 
-use super::CoverageResult;
+use super::{CoverageResult, LineHits};
+use crate::container::{self, Container};
 
 // Cache Go availability to avoid repeated checks
 static GO_AVAILABLE: OnceLock<bool> = OnceLock::new();
@@ -34,9 +35,14 @@ pub fn go_available() -> bool {
 /// Collect Go coverage for a project.
 ///
 /// Executes `go test -coverprofile` and parses the resulting coverage profile.
-/// Returns a skipped result if Go is not available.
-pub fn collect_go_coverage(root: &Path, test_path: Option<&str>) -> CoverageResult {
-    if !go_available() {
+/// Returns a skipped result if Go is not available on the host (with a
+/// container, `go test` runs in it instead).
+pub fn collect_go_coverage(
+    root: &Path,
+    test_path: Option<&str>,
+    container: Option<&Container>,
+) -> CoverageResult {
+    if container.is_none() && !go_available() {
         return CoverageResult::skipped();
     }
 
@@ -50,6 +56,7 @@ pub fn collect_go_coverage(root: &Path, test_path: Option<&str>) -> CoverageResu
     cmd.arg(&cover_file);
     cmd.arg(test_path.unwrap_or("./..."));
     cmd.current_dir(root);
+    let mut cmd = container::wrap(container, cmd);
     cmd.stdout(Stdio::piped());
     cmd.stderr(Stdio::piped());
 
@@ -100,6 +107,7 @@ pub fn collect_go_coverage(root: &Path, test_path: Option<&str>) -> CoverageResu
 /// Returns per-file coverage as (covered_statements / total_statements) * 100.
 pub fn parse_cover_profile(content: &str, duration: Duration) -> CoverageResult {
     let mut file_stats: HashMap<String, (u64, u64)> = HashMap::new(); // (covered, total)
+    let mut lines: HashMap<String, LineHits> = HashMap::new();
 
     for line in content.lines().skip(1) {
         // Skip mode line
@@ -109,11 +117,17 @@ pub fn parse_cover_profile(content: &str, duration: Duration) -> CoverageResult
         }
 
         if let Some((file, statements, count)) = parse_profile_line(line) {
-            let entry = file_stats.entry(file).or_default();
+            let entry = file_stats.entry(file.clone()).or_default();
             entry.1 += statements; // total
             if count > 0 {
                 entry.0 += statements; // covered
             }
+            if let Some((start, end)) = profile_block_lines(line) {
+                let hits = lines.entry(file).or_default();
+                for line_no in start..=end {
+                    *hits.entry(line_no).or_default() |= count > 0;
+                }
+            }
         }
     }
 
@@ -125,6 +139,7 @@ pub fn parse_cover_profile(content: &str, duration: Duration) -> CoverageResult
             line_coverage: None,
             files: HashMap::new(),
             packages: HashMap::new(),
+            lines: HashMap::new(),
         };
     }
 
@@ -176,9 +191,19 @@ pub fn parse_cover_profile(content: &str, duration: Duration) -> CoverageResult
         line_coverage,
         files,
         packages,
+        lines,
     }
 }
 
+/// Start and end lines of a coverage profile block, from its
+/// `<startLine>.<startCol>,<endLine>.<endCol>` position.
+fn profile_block_lines(line: &str) -> Option<(u32, u32)> {
+    let rest = &line[line.rfind(':')? + 1..];
+    let (start, end) = rest.split_whitespace().next()?.split_once(',')?;
+    let line_of = |pos: &str| pos.split('.').next()?.parse().ok();
+    Some((line_of(start)?, line_of(end)?))
+}
+
 /// Parse a single line from Go's coverage profile.
 ///
 /// Format: `<file>:<startLine>.<startCol>,<endLine>.<endCol> <numStatements> <count>`
@@ -38,6 +38,24 @@ github.com/example/pkg/math/math.go:9.14,11.2 1 0
     );
 }
 
+#[test]
+fn parses_line_hits_from_profile_blocks() {
+    let content = r#"mode: set
+github.com/example/pkg/math/math.go:5.14,7.2 1 1
+github.com/example/pkg/math/math.go:7.2,9.3 1 0
+github.com/example/pkg/math/math.go:11.14,12.2 1 0
+"#;
+    let result = parse_cover_profile(content, Duration::from_secs(1));
+    let hits = &result.lines["github.com/example/pkg/math/math.go"];
+
+    assert_eq!(hits.get(&5), Some(&true));
+    // Shared by a covered and an uncovered block
+    assert_eq!(hits.get(&7), Some(&true));
+    assert_eq!(hits.get(&8), Some(&false));
+    assert_eq!(hits.get(&10), None);
+    assert_eq!(hits.get(&12), Some(&false));
+}
+
 #[test]
 fn parses_multi_file_profile() {
     let content = r#"mode: set
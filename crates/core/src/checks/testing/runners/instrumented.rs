@@ -32,6 +32,9 @@ pub fn build_instrumented(targets: &[String], root: &Path) -> Result<Instrumente
     let profile_dir = root.join("target").join("quench-coverage");
     std::fs::create_dir_all(&profile_dir)
         .map_err(|e| format!("failed to create profile dir: {e}"))?;
+    // Profiles from earlier runs describe binaries this build replaces;
+    // merging them would mix counts from different code
+    clear_profiles(&profile_dir);
 
     // Build with instrumentation
     let mut cmd = Command::new("cargo");
@@ -76,6 +79,21 @@ pub fn build_instrumented(targets: &[String], root: &Path) -> Result<Instrumente
     })
 }
 
+/// Remove `.profraw` and `.profdata` files left in `profile_dir`.
+fn clear_profiles(profile_dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(profile_dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|e| e.path()) {
+        if path
+            .extension()
+            .is_some_and(|ext| ext == "profraw" || ext == "profdata")
+        {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
 /// Get environment variables needed to run an instrumented binary.
 ///
 /// These should be set when executing tests that use the instrumented binary
@@ -146,6 +164,7 @@ pub fn collect_instrumented_coverage(build: &InstrumentedBuild, root: &Path) ->
             line_coverage: Some(line_coverage),
             files,
             packages: std::collections::HashMap::new(),
+            lines: std::collections::HashMap::new(),
         },
         Err(e) => CoverageResult::failed(duration, e),
     }
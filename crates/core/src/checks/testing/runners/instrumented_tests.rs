@@ -59,6 +59,20 @@ fn build_instrumented_fails_with_no_targets() {
     assert!(result.unwrap_err().contains("no targets"));
 }
 
+#[test]
+fn clear_profiles_removes_stale_profiles_only() {
+    let temp = tempfile::TempDir::new().unwrap();
+    for name in ["1-abc.profraw", "merged.profdata", "notes.txt"] {
+        std::fs::write(temp.path().join(name), "").unwrap();
+    }
+
+    clear_profiles(temp.path());
+
+    assert!(!temp.path().join("1-abc.profraw").exists());
+    assert!(!temp.path().join("merged.profdata").exists());
+    assert!(temp.path().join("notes.txt").exists());
+}
+
 #[test]
 fn parse_llvm_cov_export_extracts_coverage() {
     let json = r#"{
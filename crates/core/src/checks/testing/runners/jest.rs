@@ -20,6 +20,7 @@ use super::{
 };
 use crate::adapter::javascript::PackageManager;
 use crate::config::TestSuiteConfig;
+use crate::interrupt;
 
 /// Jest runner for JavaScript/TypeScript test suites.
 pub struct JestRunner;
@@ -65,10 +66,11 @@ impl TestRunner for JestRunner {
         }
 
         cmd.current_dir(ctx.root);
+        let mut cmd = ctx.wrap(cmd);
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
 
-        let child = match cmd.spawn() {
+        let child = match interrupt::spawn(&mut cmd) {
             Ok(c) => c,
             Err(e) => {
                 return TestRunResult::failed(
@@ -95,7 +97,7 @@ impl TestRunner for JestRunner {
 
         // Collect coverage if requested
         if ctx.collect_coverage {
-            let coverage = collect_jest_coverage(ctx.root, config.path.as_deref());
+            let coverage = collect_jest_coverage(ctx.root, config.path.as_deref(), ctx.container);
             result = result.with_collected_coverage(coverage, "javascript");
         }
 
@@ -11,8 +11,9 @@ use std::path::Path;
 use std::process::{Command, Stdio};
 use std::time::{Duration, Instant};
 
-use super::CoverageResult;
+use super::{CoverageResult, LineHits};
 use crate::adapter::javascript::PackageManager;
+use crate::container::{self, Container};
 
 // =============================================================================
 // Coverage Collection Functions
@@ -40,7 +41,11 @@ fn coverage_dir_for(test_path: Option<&str>) -> String {
 ///
 /// Runs jest with coverage using the detected package manager's exec command.
 /// Returns a skipped result if jest is not available.
-pub fn collect_jest_coverage(root: &Path, test_path: Option<&str>) -> CoverageResult {
+pub fn collect_jest_coverage(
+    root: &Path,
+    test_path: Option<&str>,
+    container: Option<&Container>,
+) -> CoverageResult {
     let start = Instant::now();
 
     let coverage_dir = coverage_dir_for(test_path);
@@ -67,6 +72,7 @@ pub fn collect_jest_coverage(root: &Path, test_path: Option<&str>) -> CoverageRe
         cmd.arg(path);
     }
     cmd.current_dir(root);
+    let mut cmd = container::wrap(container, cmd);
     cmd.stdout(Stdio::piped());
     cmd.stderr(Stdio::piped());
 
@@ -103,7 +109,11 @@ pub fn collect_jest_coverage(root: &Path, test_path: Option<&str>) -> CoverageRe
 /// Collect Vitest coverage.
 ///
 /// Runs vitest with coverage using the detected package manager's exec command.
-pub fn collect_vitest_coverage(root: &Path, test_path: Option<&str>) -> CoverageResult {
+pub fn collect_vitest_coverage(
+    root: &Path,
+    test_path: Option<&str>,
+    container: Option<&Container>,
+) -> CoverageResult {
     let start = Instant::now();
 
     let coverage_dir = coverage_dir_for(test_path);
@@ -122,6 +132,7 @@ pub fn collect_vitest_coverage(root: &Path, test_path: Option<&str>) -> Coverage
         cmd.arg(path);
     }
     cmd.current_dir(root);
+    let mut cmd = container::wrap(container, cmd);
     cmd.stdout(Stdio::piped());
     cmd.stderr(Stdio::piped());
 
@@ -158,7 +169,11 @@ pub fn collect_vitest_coverage(root: &Path, test_path: Option<&str>) -> Coverage
 /// Collect Bun coverage.
 ///
 /// Runs `bun test --coverage` and parses LCOV output.
-pub fn collect_bun_coverage(root: &Path, test_path: Option<&str>) -> CoverageResult {
+pub fn collect_bun_coverage(
+    root: &Path,
+    test_path: Option<&str>,
+    container: Option<&Container>,
+) -> CoverageResult {
     let start = Instant::now();
 
     let coverage_dir = coverage_dir_for(test_path);
@@ -173,6 +188,7 @@ pub fn collect_bun_coverage(root: &Path, test_path: Option<&str>) -> CoverageRes
         cmd.arg(path);
     }
     cmd.current_dir(root);
+    let mut cmd = container::wrap(container, cmd);
     cmd.stdout(Stdio::piped());
     cmd.stderr(Stdio::piped());
 
@@ -239,9 +255,11 @@ pub fn collect_bun_coverage(root: &Path, test_path: Option<&str>) -> CoverageRes
 /// - `end_of_record` - Marks end of file record
 pub fn parse_lcov_report(content: &str, duration: Duration) -> CoverageResult {
     let mut file_stats: HashMap<String, (u64, u64)> = HashMap::new(); // (hit, found)
+    let mut lines: HashMap<String, LineHits> = HashMap::new();
     let mut current_file: Option<String> = None;
     let mut current_hit: u64 = 0;
     let mut current_found: u64 = 0;
+    let mut current_lines = LineHits::new();
 
     for line in content.lines() {
         let line = line.trim();
@@ -249,6 +267,15 @@ pub fn parse_lcov_report(content: &str, duration: Duration) -> CoverageResult {
             current_file = Some(path.to_string());
             current_hit = 0;
             current_found = 0;
+            current_lines = LineHits::new();
+        } else if let Some(data) = line.strip_prefix("DA:") {
+            let mut fields = data.split(',');
+            if let (Some(Ok(line_no)), Some(Ok(count))) = (
+                fields.next().map(str::parse::<u32>),
+                fields.next().map(str::parse::<u64>),
+            ) {
+                *current_lines.entry(line_no).or_default() |= count > 0;
+            }
         } else if let Some(value) = line.strip_prefix("LH:") {
             current_hit = value.parse().unwrap_or(0);
         } else if let Some(value) = line.strip_prefix("LF:") {
@@ -259,13 +286,16 @@ pub fn parse_lcov_report(content: &str, duration: Duration) -> CoverageResult {
                 // Skip node_modules and empty paths
                 if !normalized.is_empty() && should_include_file(file) {
                     file_stats.insert(normalized, (current_hit, current_found));
+                    lines.insert(file.clone(), std::mem::take(&mut current_lines));
                 }
             }
             current_file = None;
         }
     }
 
-    calculate_coverage_result(file_stats, duration)
+    let mut result = calculate_coverage_result(file_stats, duration);
+    result.lines = lines;
+    result
 }
 
 /// Calculate CoverageResult from file statistics.
@@ -281,6 +311,7 @@ fn calculate_coverage_result(
             line_coverage: None,
             files: HashMap::new(),
             packages: HashMap::new(),
+            lines: HashMap::new(),
         };
     }
 
@@ -331,6 +362,7 @@ fn calculate_coverage_result(
         line_coverage,
         files,
         packages,
+        lines: HashMap::new(),
     }
 }
 
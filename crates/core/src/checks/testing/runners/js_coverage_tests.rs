@@ -50,6 +50,18 @@ end_of_record
     );
 }
 
+#[test]
+fn parses_line_hits_from_lcov() {
+    let content = "SF:/project/src/lib.js\nDA:1,1\nDA:2,0\nDA:4,3\nLF:3\nLH:2\nend_of_record\n";
+    let result = parse_lcov_report(content, Duration::ZERO);
+    let hits = &result.lines["/project/src/lib.js"];
+
+    assert_eq!(hits.get(&1), Some(&true));
+    assert_eq!(hits.get(&2), Some(&false));
+    assert_eq!(hits.get(&3), None);
+    assert_eq!(hits.get(&4), Some(&true));
+}
+
 #[test]
 fn parses_multi_file_lcov() {
     let content = r#"TN:
@@ -182,6 +182,7 @@ fn parse_cobertura_xml(xml: &str, duration: Duration, root: &Path) -> CoverageRe
         line_coverage: overall_coverage,
         files,
         packages: std::collections::HashMap::new(),
+        lines: std::collections::HashMap::new(),
     }
 }
 
@@ -35,7 +35,7 @@ mod vitest;
 pub use bats::BatsRunner;
 pub use bun::BunRunner;
 pub use cargo::{CargoRunner, categorize_cargo_error, parse_cargo_output};
-pub use coverage::CoverageResult;
+pub use coverage::{CoverageResult, LineHits};
 pub use cucumber::CucumberRunner;
 pub use custom::CustomRunner;
 pub use go::GoRunner;
@@ -69,10 +69,14 @@ use std::collections::HashMap;
 use std::io::{self, Read};
 use std::path::Path;
 use std::process::{Child, Command, Output, Stdio};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
 use std::time::{Duration, Instant};
 
 use crate::config::TestSuiteConfig;
+use crate::container::{self, Container};
+use crate::impact::ImpactMap;
+use crate::interrupt;
 
 // =============================================================================
 // Runner Helper Macros
@@ -88,7 +92,11 @@ use crate::config::TestSuiteConfig;
 macro_rules! run_setup_or_fail {
     ($config:expr, $ctx:expr) => {
         if let Some(setup) = &$config.setup {
-            if let Err(e) = $crate::checks::testing::runners::run_setup_command(setup, $ctx.root) {
+            if let Err(e) = $crate::checks::testing::runners::run_setup_command(
+                setup,
+                $ctx.root,
+                $ctx.container,
+            ) {
                 return $crate::checks::testing::runners::TestRunResult::failed(
                     std::time::Duration::ZERO,
                     e,
@@ -107,6 +115,7 @@ pub const RUNNER_NAMES: &[&str] = &[
 ];
 
 /// Context passed to test runners during execution.
+#[derive(Clone, Copy)]
 pub struct RunnerContext<'a> {
     /// Project root directory.
     pub root: &'a Path,
@@ -118,6 +127,17 @@ pub struct RunnerContext<'a> {
     pub config: &'a crate::config::Config,
     /// Whether verbose diagnostic output is enabled.
     pub verbose: bool,
+    /// Per-test coverage sink, when test impact recording is enabled.
+    pub impact: Option<&'a Mutex<ImpactMap>>,
+    /// Container the suite runs in, if configured.
+    pub container: Option<&'a Container>,
+}
+
+impl RunnerContext<'_> {
+    /// `cmd` run in the suite's container, or unchanged without one.
+    pub fn wrap(&self, cmd: Command) -> Command {
+        container::wrap(self.container, cmd)
+    }
 }
 
 /// Trait for pluggable test runners.
@@ -170,23 +190,26 @@ pub fn filter_suites_for_mode(suites: &[TestSuiteConfig], ci_mode: bool) -> Vec<
 /// Execute a setup command before running tests.
 ///
 /// Returns Ok(()) on success, Err(message) on failure.
-pub fn run_setup_command(setup: &str, root: &Path) -> Result<(), String> {
-    // Use shell to handle complex commands
-    let output = if cfg!(target_os = "windows") {
-        Command::new("cmd")
-            .args(["/C", setup])
-            .current_dir(root)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .output()
+pub fn run_setup_command(
+    setup: &str,
+    root: &Path,
+    container: Option<&Container>,
+) -> Result<(), String> {
+    // Use shell to handle complex commands (containers are always Unix)
+    let mut cmd = if cfg!(target_os = "windows") && container.is_none() {
+        let mut c = Command::new("cmd");
+        c.args(["/C", setup]);
+        c
     } else {
-        Command::new("sh")
-            .args(["-c", setup])
-            .current_dir(root)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .output()
+        let mut c = Command::new("sh");
+        c.args(["-c", setup]);
+        c
     };
+    cmd.current_dir(root);
+    let output = container::wrap(container, cmd)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output();
 
     match output {
         Ok(out) if out.status.success() => Ok(()),
@@ -369,6 +392,15 @@ pub fn merge_coverage_results(a: CoverageResult, b: CoverageResult) -> CoverageR
         Some(files.values().sum::<f64>() / files.len() as f64)
     };
 
+    // Merge lines: a line ran if it ran in either
+    let mut lines = a.lines;
+    for (path, hits) in b.lines {
+        let merged = lines.entry(path).or_default();
+        for (line, ran) in hits {
+            *merged.entry(line).or_default() |= ran;
+        }
+    }
+
     CoverageResult {
         success: a.success && b.success,
         error: a.error.or(b.error),
@@ -376,6 +408,7 @@ pub fn merge_coverage_results(a: CoverageResult, b: CoverageResult) -> CoverageR
         line_coverage: total_coverage,
         files,
         packages,
+        lines,
     }
 }
 
@@ -417,59 +450,52 @@ pub fn handle_timeout_error(
 ///
 /// If timeout is None, waits indefinitely.
 /// If timeout expires, kills the process and returns a TimedOut error.
+/// If the run is interrupted (Ctrl-C), kills the process and returns an
+/// Interrupted error.
 pub fn run_with_timeout(mut child: Child, timeout: Option<Duration>) -> io::Result<Output> {
-    match timeout {
-        Some(t) => {
-            let start = Instant::now();
-            let poll_interval = Duration::from_millis(50);
-
-            loop {
-                match child.try_wait() {
-                    Ok(Some(status)) => {
-                        // Process finished - collect output
-                        let stdout = child
-                            .stdout
-                            .take()
-                            .map(|mut s| {
-                                let mut buf = Vec::new();
-                                s.read_to_end(&mut buf).ok();
-                                buf
-                            })
-                            .unwrap_or_default();
-                        let stderr = child
-                            .stderr
-                            .take()
-                            .map(|mut s| {
-                                let mut buf = Vec::new();
-                                s.read_to_end(&mut buf).ok();
-                                buf
-                            })
-                            .unwrap_or_default();
-                        return Ok(Output {
-                            status,
-                            stdout,
-                            stderr,
-                        });
-                    }
-                    Ok(None) => {
-                        // Process still running
-                        if start.elapsed() > t {
-                            // Timeout - kill the process
-                            child.kill().ok();
-                            child.wait().ok();
-                            return Err(io::Error::new(
-                                io::ErrorKind::TimedOut,
-                                format!("command timed out after {:?}", t),
-                            ));
-                        }
-                        std::thread::sleep(poll_interval);
-                    }
-                    Err(e) => return Err(e),
-                }
-            }
+    // Drain pipes on threads so a chatty child can't fill them and stall
+    let stdout = child.stdout.take().map(drain);
+    let stderr = child.stderr.take().map(drain);
+
+    let start = Instant::now();
+    let poll_interval = Duration::from_millis(50);
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            interrupt::reaped(&child);
+            break status;
         }
-        None => child.wait_with_output(),
-    }
+        if interrupt::requested() {
+            interrupt::kill(&mut child);
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "interrupted"));
+        }
+        if let Some(t) = timeout
+            && start.elapsed() > t
+        {
+            interrupt::kill(&mut child);
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!("command timed out after {:?}", t),
+            ));
+        }
+        std::thread::sleep(poll_interval);
+    };
+
+    let collect =
+        |pipe: Option<JoinHandle<Vec<u8>>>| pipe.and_then(|p| p.join().ok()).unwrap_or_default();
+    Ok(Output {
+        status,
+        stdout: collect(stdout),
+        stderr: collect(stderr),
+    })
+}
+
+/// Read a pipe to the end on a background thread.
+fn drain(mut pipe: impl Read + Send + 'static) -> JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        pipe.read_to_end(&mut buf).ok();
+        buf
+    })
 }
 
 #[cfg(test)]
@@ -52,6 +52,7 @@ fn filter_suites_ci_mode_includes_all() {
             max_avg: None,
             max_test: None,
             timeout: None,
+            container: None,
         },
         TestSuiteConfig {
             runner: "pytest".to_string(),
@@ -65,6 +66,7 @@ fn filter_suites_ci_mode_includes_all() {
             max_avg: None,
             max_test: None,
             timeout: None,
+            container: None,
         },
     ];
 
@@ -88,6 +90,7 @@ fn filter_suites_fast_mode_excludes_ci_only() {
             max_avg: None,
             max_test: None,
             timeout: None,
+            container: None,
         },
         TestSuiteConfig {
             runner: "pytest".to_string(),
@@ -101,6 +104,7 @@ fn filter_suites_fast_mode_excludes_ci_only() {
             max_avg: None,
             max_test: None,
             timeout: None,
+            container: None,
         },
     ];
 
@@ -113,14 +117,14 @@ fn filter_suites_fast_mode_excludes_ci_only() {
 #[test]
 fn run_setup_command_succeeds() {
     let temp = tempdir().unwrap();
-    let result = run_setup_command("echo hello", temp.path());
+    let result = run_setup_command("echo hello", temp.path(), None);
     assert!(result.is_ok());
 }
 
 #[test]
 fn run_setup_command_fails_on_bad_command() {
     let temp = tempdir().unwrap();
-    let result = run_setup_command("exit 1", temp.path());
+    let result = run_setup_command("exit 1", temp.path(), None);
     assert!(result.is_err());
     assert!(result.unwrap_err().contains("setup command failed"));
 }
@@ -128,7 +132,7 @@ fn run_setup_command_fails_on_bad_command() {
 #[test]
 fn run_setup_command_reports_stderr() {
     let temp = tempdir().unwrap();
-    let result = run_setup_command("echo 'error message' >&2 && exit 1", temp.path());
+    let result = run_setup_command("echo 'error message' >&2 && exit 1", temp.path(), None);
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert!(err.contains("error message"));
@@ -158,6 +162,7 @@ fn merge_coverage_results_combines_files() {
         line_coverage: Some(70.0),
         files: [("src/a.rs".to_string(), 70.0)].into_iter().collect(),
         packages: HashMap::new(),
+        lines: HashMap::new(),
     };
 
     let b = CoverageResult {
@@ -167,6 +172,7 @@ fn merge_coverage_results_combines_files() {
         line_coverage: Some(80.0),
         files: [("src/b.rs".to_string(), 80.0)].into_iter().collect(),
         packages: HashMap::new(),
+        lines: HashMap::new(),
     };
 
     let merged = merge_coverage_results(a, b);
@@ -186,6 +192,7 @@ fn merge_coverage_results_takes_max_for_same_file() {
         line_coverage: Some(60.0),
         files: [("src/lib.rs".to_string(), 60.0)].into_iter().collect(),
         packages: HashMap::new(),
+        lines: HashMap::new(),
     };
 
     let b = CoverageResult {
@@ -195,6 +202,7 @@ fn merge_coverage_results_takes_max_for_same_file() {
         line_coverage: Some(80.0),
         files: [("src/lib.rs".to_string(), 80.0)].into_iter().collect(),
         packages: HashMap::new(),
+        lines: HashMap::new(),
     };
 
     let merged = merge_coverage_results(a, b);
@@ -212,6 +220,7 @@ fn merge_coverage_results_recalculates_overall() {
         line_coverage: Some(50.0),
         files: [("src/a.rs".to_string(), 50.0)].into_iter().collect(),
         packages: HashMap::new(),
+        lines: HashMap::new(),
     };
 
     let b = CoverageResult {
@@ -221,6 +230,7 @@ fn merge_coverage_results_recalculates_overall() {
         line_coverage: Some(90.0),
         files: [("src/b.rs".to_string(), 90.0)].into_iter().collect(),
         packages: HashMap::new(),
+        lines: HashMap::new(),
     };
 
     let merged = merge_coverage_results(a, b);
@@ -237,6 +247,7 @@ fn merge_coverage_results_handles_empty_files() {
         line_coverage: Some(60.0),
         files: HashMap::new(),
         packages: HashMap::new(),
+        lines: HashMap::new(),
     };
 
     let b = CoverageResult {
@@ -246,6 +257,7 @@ fn merge_coverage_results_handles_empty_files() {
         line_coverage: Some(80.0),
         files: HashMap::new(),
         packages: HashMap::new(),
+        lines: HashMap::new(),
     };
 
     let merged = merge_coverage_results(a, b);
@@ -253,6 +265,28 @@ fn merge_coverage_results_handles_empty_files() {
     assert_eq!(merged.line_coverage, Some(80.0));
 }
 
+#[test]
+fn merge_coverage_results_ors_line_hits() {
+    let hits = |pairs: &[(u32, bool)]| -> HashMap<String, LineHits> {
+        [("src/a.rs".to_string(), pairs.iter().copied().collect())].into()
+    };
+    let a = CoverageResult {
+        lines: hits(&[(1, true), (2, false), (3, false)]),
+        ..CoverageResult::skipped()
+    };
+    let b = CoverageResult {
+        lines: hits(&[(2, true), (3, false), (4, true)]),
+        ..CoverageResult::skipped()
+    };
+
+    let merged = merge_coverage_results(a, b);
+    let lines = &merged.lines["src/a.rs"];
+    assert_eq!(lines.get(&1), Some(&true));
+    assert_eq!(lines.get(&2), Some(&true));
+    assert_eq!(lines.get(&3), Some(&false));
+    assert_eq!(lines.get(&4), Some(&true));
+}
+
 #[test]
 fn aggregated_coverage_merge_rust() {
     let mut agg = AggregatedCoverage::default();
@@ -264,6 +298,7 @@ fn aggregated_coverage_merge_rust() {
         line_coverage: Some(70.0),
         files: [("src/a.rs".to_string(), 70.0)].into_iter().collect(),
         packages: HashMap::new(),
+        lines: HashMap::new(),
     };
 
     let result2 = CoverageResult {
@@ -273,6 +308,7 @@ fn aggregated_coverage_merge_rust() {
         line_coverage: Some(90.0),
         files: [("src/b.rs".to_string(), 90.0)].into_iter().collect(),
         packages: HashMap::new(),
+        lines: HashMap::new(),
     };
 
     agg.merge_rust(result1);
@@ -295,6 +331,7 @@ fn aggregated_coverage_to_coverage_map() {
             line_coverage: Some(75.0),
             files: HashMap::new(),
             packages: HashMap::new(),
+            lines: HashMap::new(),
         }),
         shell: Some(CoverageResult {
             success: true,
@@ -303,6 +340,7 @@ fn aggregated_coverage_to_coverage_map() {
             line_coverage: Some(60.0),
             files: HashMap::new(),
             packages: HashMap::new(),
+            lines: HashMap::new(),
         }),
         go: None,
         javascript: None,
@@ -334,6 +372,7 @@ fn aggregated_coverage_has_data() {
             line_coverage: Some(50.0),
             files: HashMap::new(),
             packages: HashMap::new(),
+            lines: HashMap::new(),
         }),
         ..Default::default()
     };
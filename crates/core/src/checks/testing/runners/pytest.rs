@@ -14,6 +14,7 @@ use super::{
     handle_timeout_error, run_setup_or_fail, run_with_timeout,
 };
 use crate::config::TestSuiteConfig;
+use crate::interrupt;
 
 /// Pytest runner for Python test suites.
 pub struct PytestRunner;
@@ -48,10 +49,11 @@ impl TestRunner for PytestRunner {
         }
 
         cmd.current_dir(ctx.root);
+        let mut cmd = ctx.wrap(cmd);
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
 
-        let child = match cmd.spawn() {
+        let child = match interrupt::spawn(&mut cmd) {
             Ok(c) => c,
             Err(e) => {
                 return TestRunResult::failed(
@@ -81,7 +83,12 @@ impl TestRunner for PytestRunner {
 
         // Collect coverage if requested
         if ctx.collect_coverage {
-            let coverage = collect_python_coverage(ctx.root, config.path.as_deref());
+            let coverage = collect_python_coverage(
+                ctx.root,
+                config.path.as_deref(),
+                ctx.impact,
+                ctx.container,
+            );
             result = result.with_collected_coverage(coverage, "python");
         }
 
@@ -5,15 +5,17 @@
 //!
 //! Supports coverage.py and pytest-cov, parsing both JSON and Cobertura XML formats.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::Path;
 use std::process::{Command, Stdio};
-use std::sync::OnceLock;
+use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
 
 use serde::Deserialize;
 
-use super::CoverageResult;
+use super::{CoverageResult, LineHits};
+use crate::container::{self, Container};
+use crate::impact::ImpactMap;
 
 // Cache coverage.py availability to avoid repeated checks
 static COVERAGE_AVAILABLE: OnceLock<bool> = OnceLock::new();
@@ -60,9 +62,15 @@ pub fn pytest_cov_available() -> bool {
 /// 2. Or run `coverage run -m pytest && coverage json` if coverage.py is available
 /// 3. Parse coverage.json (preferred) or coverage.xml (fallback)
 ///
-/// Returns a skipped result if no coverage tools are available.
-pub fn collect_python_coverage(root: &Path, test_path: Option<&str>) -> CoverageResult {
-    if !pytest_cov_available() && !coverage_available() {
+/// Returns a skipped result if no coverage tools are available. With a
+/// container, `pytest --cov` runs in it and pytest-cov is assumed installed.
+pub fn collect_python_coverage(
+    root: &Path,
+    test_path: Option<&str>,
+    impact: Option<&Mutex<ImpactMap>>,
+    container: Option<&Container>,
+) -> CoverageResult {
+    if container.is_none() && !pytest_cov_available() && !coverage_available() {
         return CoverageResult::skipped();
     }
 
@@ -76,9 +84,13 @@ pub fn collect_python_coverage(root: &Path, test_path: Option<&str>) -> Coverage
         "."
     };
 
+    if container.is_some() {
+        return run_pytest_cov(root, test_path, source_dir, impact, container);
+    }
+
     // Try pytest-cov first (preferred)
     if pytest_cov_available() {
-        let result = run_pytest_cov(root, test_path, source_dir);
+        let result = run_pytest_cov(root, test_path, source_dir, impact, None);
         if result.success {
             return result;
         }
@@ -94,7 +106,15 @@ pub fn collect_python_coverage(root: &Path, test_path: Option<&str>) -> Coverage
 }
 
 /// Run pytest with --cov flag and parse results.
-fn run_pytest_cov(root: &Path, test_path: Option<&str>, source_dir: &str) -> CoverageResult {
+///
+/// With `impact`, records per-test coverage contexts into it.
+fn run_pytest_cov(
+    root: &Path,
+    test_path: Option<&str>,
+    source_dir: &str,
+    impact: Option<&Mutex<ImpactMap>>,
+    container: Option<&Container>,
+) -> CoverageResult {
     let start = Instant::now();
 
     // Clean up any existing coverage files first
@@ -104,6 +124,9 @@ fn run_pytest_cov(root: &Path, test_path: Option<&str>, source_dir: &str) -> Cov
     let mut cmd = Command::new("pytest");
     cmd.arg(format!("--cov={source_dir}"));
     cmd.arg("--cov-report=json");
+    if impact.is_some() {
+        cmd.arg("--cov-context=test");
+    }
     cmd.arg("-q"); // Quiet mode for speed
 
     if let Some(path) = test_path {
@@ -111,6 +134,7 @@ fn run_pytest_cov(root: &Path, test_path: Option<&str>, source_dir: &str) -> Cov
     }
 
     cmd.current_dir(root);
+    let mut cmd = container::wrap(container, cmd);
     cmd.stdout(Stdio::piped());
     cmd.stderr(Stdio::piped());
 
@@ -124,6 +148,18 @@ fn run_pytest_cov(root: &Path, test_path: Option<&str>, source_dir: &str) -> Cov
         }
     };
 
+    // pytest-cov's JSON report omits contexts; regenerate it with them
+    if impact.is_some() {
+        let mut json_cmd = Command::new("coverage");
+        json_cmd
+            .args(["json", "--show-contexts", "-o", "coverage.json"])
+            .current_dir(root);
+        let _ = container::wrap(container, json_cmd)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+    }
+
     let duration = start.elapsed();
 
     // Even if tests fail, coverage may have been collected
@@ -144,6 +180,13 @@ fn run_pytest_cov(root: &Path, test_path: Option<&str>, source_dir: &str) -> Cov
         let _ = std::fs::remove_file(&json_path);
         let _ = std::fs::remove_file(root.join(".coverage"));
 
+        if let Some(impact) = impact {
+            let recorded = parse_test_contexts(&content);
+            impact
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .merge(recorded);
+        }
         return parse_coverage_json(&content, duration);
     }
 
@@ -270,6 +313,18 @@ struct CoverageJson {
 #[derive(Debug, Deserialize)]
 struct FileData {
     summary: FileSummary,
+    #[serde(default)]
+    executed_lines: Vec<u32>,
+    #[serde(default)]
+    missing_lines: Vec<u32>,
+}
+
+impl FileData {
+    fn line_hits(&self) -> LineHits {
+        let executed = self.executed_lines.iter().map(|&line| (line, true));
+        let missing = self.missing_lines.iter().map(|&line| (line, false));
+        executed.chain(missing).collect()
+    }
 }
 
 /// File-level summary from coverage.json.
@@ -301,16 +356,19 @@ pub(crate) fn parse_coverage_json(json: &str, duration: Duration) -> CoverageRes
             line_coverage: Some(report.totals.percent_covered),
             files: HashMap::new(),
             packages: HashMap::new(),
+            lines: HashMap::new(),
         };
     }
 
     let mut files: HashMap<String, f64> = HashMap::new();
     let mut package_files: HashMap<String, Vec<f64>> = HashMap::new();
+    let mut lines: HashMap<String, LineHits> = HashMap::new();
 
     for (file_path, file_data) in &report.files {
         let coverage = file_data.summary.percent_covered;
         let normalized_path = normalize_python_path(file_path);
         files.insert(normalized_path.clone(), coverage);
+        lines.insert(file_path.clone(), file_data.line_hits());
 
         let package = extract_python_package(&normalized_path);
         package_files.entry(package).or_default().push(coverage);
@@ -332,7 +390,48 @@ pub(crate) fn parse_coverage_json(json: &str, duration: Duration) -> CoverageRes
         line_coverage: Some(report.totals.percent_covered),
         files,
         packages,
+        lines,
+    }
+}
+
+/// coverage.json written with `--show-contexts`.
+#[derive(Debug, Deserialize)]
+struct ContextsJson {
+    files: HashMap<String, ContextsFileData>,
+}
+
+/// Per-file contexts: line number to the contexts that executed it.
+#[derive(Debug, Deserialize)]
+struct ContextsFileData {
+    #[serde(default)]
+    contexts: HashMap<String, Vec<String>>,
+}
+
+/// Parse pytest-cov test contexts into the files each test executed.
+///
+/// Contexts look like `tests/test_a.py::test_x|run`; the phase suffix is
+/// dropped and the empty (no test) context is skipped.
+pub(crate) fn parse_test_contexts(json: &str) -> ImpactMap {
+    let mut tests: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    if let Ok(report) = serde_json::from_str::<ContextsJson>(json) {
+        for (file_path, data) in report.files {
+            let file = normalize_python_path(&file_path);
+            for context in data.contexts.into_values().flatten() {
+                let test = context.split('|').next().unwrap_or_default();
+                if !test.is_empty() {
+                    tests
+                        .entry(test.to_string())
+                        .or_default()
+                        .insert(file.clone());
+                }
+            }
+        }
+    }
+    let mut map = ImpactMap::new();
+    for (test, files) in tests {
+        map.record(test, files);
     }
+    map
 }
 
 // =============================================================================
@@ -408,6 +507,7 @@ pub(crate) fn parse_cobertura_xml(xml: &str, duration: Duration) -> CoverageResu
         line_coverage,
         files,
         packages,
+        lines: HashMap::new(),
     }
 }
 
@@ -106,6 +106,27 @@ fn parses_coverage_json_empty_files() {
     assert!(result.files.is_empty());
 }
 
+#[test]
+fn parses_coverage_json_line_hits() {
+    let json = r#"{
+        "files": {
+            "src/calc/math.py": {
+                "executed_lines": [1, 2, 4],
+                "missing_lines": [5],
+                "summary": {"percent_covered": 75.0}
+            }
+        },
+        "totals": {"percent_covered": 75.0}
+    }"#;
+
+    let result = parse_coverage_json(json, Duration::ZERO);
+    let hits = &result.lines["src/calc/math.py"];
+
+    assert_eq!(hits.get(&1), Some(&true));
+    assert_eq!(hits.get(&3), None);
+    assert_eq!(hits.get(&5), Some(&false));
+}
+
 #[test]
 fn parses_coverage_json_invalid() {
     let json = "not valid json";
@@ -117,6 +138,46 @@ fn parses_coverage_json_invalid() {
     assert!(result.error.unwrap().contains("failed to parse"));
 }
 
+#[test]
+fn parses_test_contexts_into_impact_map() {
+    let json = r#"{
+        "files": {
+            "src/myproject/api.py": {
+                "summary": {"percent_covered": 100.0},
+                "contexts": {
+                    "1": [""],
+                    "3": ["tests/test_api.py::test_get|run"],
+                    "4": ["tests/test_api.py::test_get|run", "tests/test_db.py::test_save|setup"]
+                }
+            },
+            "src/myproject/db.py": {
+                "summary": {"percent_covered": 50.0},
+                "contexts": {"2": ["tests/test_db.py::test_save|run"]}
+            }
+        },
+        "totals": {"percent_covered": 75.0}
+    }"#;
+
+    let map = parse_test_contexts(json);
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(
+        map.suggest(&["src/myproject/db.py".to_string()]).tests,
+        ["tests/test_db.py::test_save"]
+    );
+    assert_eq!(
+        map.suggest(&["src/myproject/api.py".to_string()]).tests,
+        ["tests/test_api.py::test_get", "tests/test_db.py::test_save"]
+    );
+}
+
+#[test]
+fn parses_test_contexts_without_contexts() {
+    let json = r#"{"files": {"src/a.py": {"summary": {"percent_covered": 1.0}}}, "totals": {"percent_covered": 1.0}}"#;
+
+    assert!(parse_test_contexts(json).is_empty());
+}
+
 // =============================================================================
 // XML Parser Tests
 // =============================================================================
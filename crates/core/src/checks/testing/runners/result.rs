@@ -8,6 +8,8 @@ use std::time::Duration;
 
 use serde::Serialize;
 
+use super::LineHits;
+
 /// Result of running a single test.
 #[derive(Debug, Clone, Serialize)]
 pub struct TestResult {
@@ -66,10 +68,16 @@ pub struct TestRunResult {
     pub total_time: Duration,
     /// Individual test results (if available).
     pub tests: Vec<TestResult>,
+    /// How many of `tests` are Rust doctests.
+    pub doctest_count: usize,
     /// Coverage percentage (0-100) by language.
     pub coverage: Option<HashMap<String, f64>>,
     /// Per-package coverage percentage (0-100).
     pub coverage_by_package: Option<HashMap<String, f64>>,
+    /// Per-file coverage percentage (0-100).
+    pub coverage_by_file: Option<HashMap<String, f64>>,
+    /// Per-line coverage (path as reported -> line hits).
+    pub coverage_lines: Option<HashMap<String, LineHits>>,
 }
 
 impl TestRunResult {
@@ -81,8 +89,11 @@ impl TestRunResult {
             error: None,
             total_time,
             tests: Vec::new(),
+            doctest_count: 0,
             coverage: None,
             coverage_by_package: None,
+            coverage_by_file: None,
+            coverage_lines: None,
         }
     }
 
@@ -94,8 +105,11 @@ impl TestRunResult {
             error: Some(error.into()),
             total_time,
             tests: Vec::new(),
+            doctest_count: 0,
             coverage: None,
             coverage_by_package: None,
+            coverage_by_file: None,
+            coverage_lines: None,
         }
     }
 
@@ -107,8 +121,11 @@ impl TestRunResult {
             error: Some(error.into()),
             total_time: Duration::ZERO,
             tests: Vec::new(),
+            doctest_count: 0,
             coverage: None,
             coverage_by_package: None,
+            coverage_by_file: None,
+            coverage_lines: None,
         }
     }
 
@@ -134,10 +151,16 @@ impl TestRunResult {
         self
     }
 
+    /// Add per-file coverage data.
+    pub fn with_file_coverage(mut self, files: HashMap<String, f64>) -> Self {
+        self.coverage_by_file = Some(files);
+        self
+    }
+
     /// Add coverage data from a `CoverageResult`.
     ///
     /// This is a convenience method that handles the common pattern of
-    /// extracting line coverage and package coverage from a `CoverageResult`.
+    /// extracting line, package, and file coverage from a `CoverageResult`.
     pub fn with_collected_coverage(
         mut self,
         coverage: super::CoverageResult,
@@ -149,6 +172,12 @@ impl TestRunResult {
         if !coverage.packages.is_empty() {
             self = self.with_package_coverage(coverage.packages);
         }
+        if !coverage.files.is_empty() {
+            self = self.with_file_coverage(coverage.files);
+        }
+        if !coverage.lines.is_empty() {
+            self.coverage_lines = Some(coverage.lines);
+        }
         self
     }
 
@@ -171,6 +200,18 @@ impl TestRunResult {
         self.tests.iter().max_by_key(|t| t.duration)
     }
 
+    /// Get the `n` slowest non-skipped tests, slowest first.
+    pub fn slowest_tests(&self, n: usize) -> Vec<&TestResult> {
+        let mut tests: Vec<&TestResult> = self.tests.iter().filter(|t| !t.skipped).collect();
+        tests.sort_by(|a, b| {
+            b.duration
+                .cmp(&a.duration)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        tests.truncate(n);
+        tests
+    }
+
     /// Get count of passed tests.
     pub fn passed_count(&self) -> usize {
         self.tests.iter().filter(|t| t.passed && !t.skipped).count()
@@ -232,3 +232,20 @@ fn percentile_duration_all_skipped() {
     let result = TestRunResult::passed(Duration::ZERO).with_tests(tests);
     assert!(result.percentile_duration(50.0).is_none());
 }
+
+#[test]
+fn slowest_tests_sorted_and_truncated() {
+    let result = TestRunResult::passed(Duration::from_secs(1)).with_tests(vec![
+        TestResult::passed("fast", Duration::from_millis(10)),
+        TestResult::passed("slow", Duration::from_millis(300)),
+        TestResult::skipped("ignored"),
+        TestResult::passed("medium", Duration::from_millis(100)),
+    ]);
+
+    let names: Vec<&str> = result
+        .slowest_tests(2)
+        .iter()
+        .map(|t| t.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["slow", "medium"]);
+}
@@ -11,7 +11,7 @@ use std::time::{Duration, Instant};
 
 use serde::Deserialize;
 
-use super::CoverageResult;
+use super::{CoverageResult, LineHits};
 
 /// Collect Ruby coverage from SimpleCov's .resultset.json.
 ///
@@ -100,10 +100,18 @@ pub(crate) fn parse_simplecov_json(json: &str, duration: Duration) -> CoverageRe
 
     let mut files: HashMap<String, f64> = HashMap::new();
     let mut package_files: HashMap<String, Vec<f64>> = HashMap::new();
+    let mut lines: HashMap<String, LineHits> = HashMap::new();
 
     // Merge coverage from all suites (RSpec, Minitest, etc.)
     for suite in resultset.suites.values() {
         for (file_path, file_data) in &suite.coverage {
+            let hits = lines.entry(file_path.clone()).or_default();
+            for (idx, count) in file_data.lines().iter().enumerate() {
+                if let (Some(count), Ok(line_no)) = (count, u32::try_from(idx + 1)) {
+                    *hits.entry(line_no).or_default() |= *count > 0;
+                }
+            }
+
             let coverage = calculate_line_coverage(file_data.lines());
             if let Some(pct) = coverage {
                 let normalized_path = normalize_ruby_path(file_path);
@@ -123,6 +131,7 @@ pub(crate) fn parse_simplecov_json(json: &str, duration: Duration) -> CoverageRe
             line_coverage: None,
             files: HashMap::new(),
             packages: HashMap::new(),
+            lines: HashMap::new(),
         };
     }
 
@@ -145,6 +154,7 @@ pub(crate) fn parse_simplecov_json(json: &str, duration: Duration) -> CoverageRe
         line_coverage: Some(total_coverage),
         files,
         packages,
+        lines,
     }
 }
 
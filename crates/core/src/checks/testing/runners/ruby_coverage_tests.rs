@@ -42,6 +42,25 @@ fn parses_modern_simplecov_format() {
     assert!((calc_coverage - 100.0).abs() < 0.01);
 }
 
+#[test]
+fn parses_line_hits_from_simplecov() {
+    let json = r#"{
+        "RSpec": {
+            "coverage": {"/project/lib/math.rb": {"lines": [1, null, 0]}}
+        },
+        "Minitest": {
+            "coverage": {"/project/lib/math.rb": {"lines": [0, null, 2]}}
+        }
+    }"#;
+    let result = parse_simplecov_json(json, Duration::ZERO);
+    let hits = &result.lines["/project/lib/math.rb"];
+
+    assert_eq!(hits.get(&1), Some(&true));
+    assert_eq!(hits.get(&2), None);
+    // Covered by either suite
+    assert_eq!(hits.get(&3), Some(&true));
+}
+
 #[test]
 fn parses_legacy_simplecov_format() {
     let json = r#"{
@@ -4,13 +4,19 @@
 //! Test suite execution and result types.
 
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
 
 use rayon::prelude::*;
 
 use crate::check::CheckContext;
 use crate::config::TestSuiteConfig;
+use crate::container::Container;
+use crate::impact::ImpactMap;
 
-use super::runners::{RunnerContext, filter_suites_for_mode, get_runner, run_setup_command};
+use super::runners::{
+    LineHits, RunnerContext, filter_suites_for_mode, get_runner, run_setup_command,
+};
 
 /// Format milliseconds as a human-friendly duration string.
 ///
@@ -37,6 +43,8 @@ pub struct SuiteResults {
 pub struct AggregatedMetrics {
     /// Total tests across all suites.
     pub test_count: usize,
+    /// Rust doctests across all suites (included in `test_count`).
+    pub doctest_count: usize,
     /// Total execution time in milliseconds.
     pub total_ms: u64,
     /// Weighted average time per test in milliseconds.
@@ -51,6 +59,7 @@ impl SuiteResults {
     /// Calculate aggregated timing metrics across all suites.
     pub fn aggregated_metrics(&self) -> AggregatedMetrics {
         let test_count: usize = self.suites.iter().map(|s| s.test_count).sum();
+        let doctest_count: usize = self.suites.iter().map(|s| s.doctest_count).sum();
 
         let total_ms: u64 = self.suites.iter().map(|s| s.total_ms).sum();
 
@@ -77,6 +86,7 @@ impl SuiteResults {
 
         AggregatedMetrics {
             test_count,
+            doctest_count,
             total_ms,
             avg_ms,
             max_ms,
@@ -100,6 +110,8 @@ pub struct SuiteResult {
     pub error: Option<String>,
     /// Number of tests run.
     pub test_count: usize,
+    /// Number of Rust doctests among the tests run.
+    pub doctest_count: usize,
     /// Number of skipped/ignored tests.
     pub skipped_count: usize,
     /// Total time in milliseconds.
@@ -110,6 +122,8 @@ pub struct SuiteResult {
     pub max_ms: Option<u64>,
     /// Name of the slowest test.
     pub max_test: Option<String>,
+    /// Slowest tests as `(name, milliseconds)`, slowest first.
+    pub slowest: Vec<(String, u64)>,
     /// 50th percentile duration in milliseconds.
     pub p50_ms: Option<u64>,
     /// 90th percentile duration in milliseconds.
@@ -120,6 +134,10 @@ pub struct SuiteResult {
     pub coverage: Option<HashMap<String, f64>>,
     /// Per-package coverage data (package name -> percentage).
     pub coverage_by_package: Option<HashMap<String, f64>>,
+    /// Per-file coverage data (path -> percentage).
+    pub coverage_by_file: Option<HashMap<String, f64>>,
+    /// Per-line coverage data (path -> line hits).
+    pub coverage_lines: Option<HashMap<String, LineHits>>,
 }
 
 /// Run configured test suites.
@@ -135,12 +153,15 @@ pub fn run_suites(ctx: &CheckContext) -> Option<SuiteResults> {
         return None;
     }
 
+    let impact = impact_recorder(ctx, ctx.ci_mode);
     let runner_ctx = RunnerContext {
         root: ctx.root,
         ci_mode: ctx.ci_mode,
         collect_coverage: ctx.ci_mode, // Coverage only in CI
         config: ctx.config,
         verbose: ctx.verbose,
+        impact: impact.as_ref(),
+        container: None,
     };
 
     // Filter suites for current mode
@@ -177,12 +198,46 @@ pub fn run_suites(ctx: &CheckContext) -> Option<SuiteResults> {
         }
     }
 
+    save_impact(ctx.root, impact);
+
     Some(SuiteResults {
         passed: all_passed,
         suites: results,
     })
 }
 
+/// Start recording test impact if `[check.tests.coverage] impact` is on
+/// and this run collects coverage.
+pub(super) fn impact_recorder(
+    ctx: &CheckContext,
+    collect_coverage: bool,
+) -> Option<Mutex<ImpactMap>> {
+    (collect_coverage && ctx.config.check.tests.coverage.impact)
+        .then(|| Mutex::new(ImpactMap::new()))
+}
+
+/// Merge recorded test impact into `.quench/impact.bin`.
+pub(super) fn save_impact(root: &Path, recorder: Option<Mutex<ImpactMap>>) {
+    let Some(recorded) = recorder.map(|m| m.into_inner().unwrap_or_else(|e| e.into_inner())) else {
+        return;
+    };
+    if recorded.is_empty() {
+        return;
+    }
+    let path = ImpactMap::path(root);
+    let mut map = match ImpactMap::load(&path) {
+        Ok(existing) => existing.unwrap_or_default(),
+        Err(e) => {
+            tracing::warn!("discarding unreadable test impact map: {}", e);
+            ImpactMap::new()
+        }
+    };
+    map.merge(recorded);
+    if let Err(e) = map.save(&path) {
+        tracing::warn!("failed to save test impact map: {}", e);
+    }
+}
+
 /// Execute a single test suite and return its result.
 pub fn run_single_suite(suite: &TestSuiteConfig, runner_ctx: &RunnerContext) -> SuiteResult {
     let suite_name = suite.name.clone().unwrap_or_else(|| suite.runner.clone());
@@ -195,11 +250,40 @@ pub fn run_single_suite(suite: &TestSuiteConfig, runner_ctx: &RunnerContext) ->
         } else {
             eprintln!("    runner: {}", suite.runner);
         }
+        if let Some(ref image) = suite.container {
+            eprintln!("    container: {}", image);
+        }
     }
 
+    // Run in the suite's container, if any
+    let container = match suite
+        .container
+        .as_deref()
+        .map(|image| Container::detect(image, runner_ctx.root))
+        .transpose()
+    {
+        Ok(container) => container,
+        Err(e) => {
+            if runner_ctx.verbose {
+                eprintln!("  Suite {:?} skipped: {}", suite_name, e);
+            }
+            return SuiteResult {
+                name: suite_name,
+                runner: suite.runner.clone(),
+                skipped: true,
+                error: Some(e),
+                ..Default::default()
+            };
+        }
+    };
+    let runner_ctx = &RunnerContext {
+        container: container.as_ref(),
+        ..*runner_ctx
+    };
+
     // Run setup command if configured
     if let Some(ref setup) = suite.setup
-        && let Err(e) = run_setup_command(setup, runner_ctx.root)
+        && let Err(e) = run_setup_command(setup, runner_ctx.root, runner_ctx.container)
     {
         // Setup failure skips the suite
         if runner_ctx.verbose {
@@ -231,8 +315,8 @@ pub fn run_single_suite(suite: &TestSuiteConfig, runner_ctx: &RunnerContext) ->
         }
     };
 
-    // Check runner availability
-    if !runner.available(runner_ctx) {
+    // Check runner availability (a container brings its own toolchain)
+    if runner_ctx.container.is_none() && !runner.available(runner_ctx) {
         if runner_ctx.verbose {
             eprintln!("  Suite {:?} skipped: runner not available", suite_name);
         }
@@ -250,6 +334,7 @@ pub fn run_single_suite(suite: &TestSuiteConfig, runner_ctx: &RunnerContext) ->
 
     // Collect metrics before moving error
     let test_count = run_result.test_count();
+    let doctest_count = run_result.doctest_count;
     let skipped_count = run_result.skipped_count();
     let total_ms = run_result.total_time.as_millis() as u64;
     let avg_ms = run_result.avg_duration().map(|d| d.as_millis() as u64);
@@ -257,6 +342,11 @@ pub fn run_single_suite(suite: &TestSuiteConfig, runner_ctx: &RunnerContext) ->
         .slowest_test()
         .map(|t| t.duration.as_millis() as u64);
     let max_test = run_result.slowest_test().map(|t| t.name.clone());
+    let slowest = run_result
+        .slowest_tests(runner_ctx.config.check.tests.time.slowest)
+        .into_iter()
+        .map(|t| (t.name.clone(), t.duration.as_millis() as u64))
+        .collect();
     let p50_ms = run_result
         .percentile_duration(50.0)
         .map(|d| d.as_millis() as u64);
@@ -268,6 +358,8 @@ pub fn run_single_suite(suite: &TestSuiteConfig, runner_ctx: &RunnerContext) ->
         .map(|d| d.as_millis() as u64);
     let coverage = run_result.coverage.clone();
     let coverage_by_package = run_result.coverage_by_package.clone();
+    let coverage_by_file = run_result.coverage_by_file.clone();
+    let coverage_lines = run_result.coverage_lines.clone();
 
     // Verbose: show suite completion
     if runner_ctx.verbose {
@@ -305,16 +397,20 @@ pub fn run_single_suite(suite: &TestSuiteConfig, runner_ctx: &RunnerContext) ->
         skipped: run_result.skipped,
         error: run_result.error,
         test_count,
+        doctest_count,
         skipped_count,
         total_ms,
         avg_ms,
         max_ms,
         max_test,
+        slowest,
         p50_ms,
         p90_ms,
         p99_ms,
         coverage,
         coverage_by_package,
+        coverage_by_file,
+        coverage_lines,
     }
 }
 
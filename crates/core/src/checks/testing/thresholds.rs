@@ -3,11 +3,12 @@
 
 //! Threshold checking for test coverage and timing.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::check::Violation;
 use crate::config::{TestSuiteConfig, TestsConfig};
 
+use super::patch_coverage::{PatchCoverage, format_line_ranges};
 use super::suite::SuiteResult;
 
 /// Check coverage against configured thresholds.
@@ -15,6 +16,7 @@ pub fn check_coverage_thresholds(
     config: &TestsConfig,
     coverage: &HashMap<String, f64>,
     packages: &HashMap<String, f64>,
+    paths: &BTreeMap<String, f64>,
 ) -> Vec<(Violation, bool)> {
     let cov_config = &config.coverage;
     if cov_config.check == "off" {
@@ -56,6 +58,57 @@ pub fn check_coverage_thresholds(
         }
     }
 
+    // Check per-path thresholds
+    for (glob, path_config) in &cov_config.path {
+        if let Some(&actual) = paths.get(glob)
+            && actual < path_config.min
+        {
+            let advice = format!(
+                "Path '{}' coverage {:.1}% below minimum {:.1}%",
+                glob, actual, path_config.min
+            );
+            let v =
+                Violation::file_only(format!("<coverage:{}>", glob), "coverage_below_min", advice)
+                    .with_threshold(actual as i64, path_config.min as i64);
+            violations.push((v, is_error));
+        }
+    }
+
+    violations
+}
+
+/// Check patch coverage against `patch_min`.
+///
+/// Below the minimum, reports the overall shortfall and each file's
+/// uncovered changed lines.
+pub fn check_patch_coverage(config: &TestsConfig, patch: &PatchCoverage) -> Vec<(Violation, bool)> {
+    let cov_config = &config.coverage;
+    let (Some(min), Some(actual)) = (cov_config.patch_min, patch.percent()) else {
+        return Vec::new();
+    };
+    if cov_config.check == "off" || actual >= min {
+        return Vec::new();
+    }
+
+    let is_error = cov_config.check == "error";
+    let advice = format!(
+        "Patch coverage {:.1}% below minimum {:.1}% ({} of {} changed lines covered)",
+        actual, min, patch.covered, patch.instrumented
+    );
+    let summary = Violation::file_only("<coverage:patch>", "patch_coverage_below_min", advice)
+        .with_threshold(actual as i64, min as i64);
+
+    let mut violations = vec![(summary, is_error)];
+    for (path, lines) in &patch.uncovered {
+        let advice = format!(
+            "Changed lines not covered by tests: {}",
+            format_line_ranges(lines)
+        );
+        violations.push((
+            Violation::file(path, lines[0], "patch_uncovered", advice),
+            is_error,
+        ));
+    }
     violations
 }
 
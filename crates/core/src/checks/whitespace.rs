@@ -0,0 +1,383 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Whitespace hygiene check.
+//!
+//! Detects wrong line endings, trailing whitespace, missing final newlines,
+//! and tab/space indentation mixups per docs/specs/checks/whitespace.md.
+//! Off by default, since formatters usually cover this.
+
+use std::path::Path;
+
+use globset::GlobSet;
+use serde_json::json;
+
+use crate::adapter::glob::build_glob_set;
+use crate::check::{Check, CheckContext, CheckResult, Field, Violation};
+use crate::config::{CheckLevel, IndentStyle, LineEndings, WhitespaceConfig};
+use crate::metrics::{self, CheckMetrics};
+
+/// The whitespace check validates line endings, trailing whitespace,
+/// final newlines, and indentation.
+pub struct WhitespaceCheck;
+
+impl Check for WhitespaceCheck {
+    fn name(&self) -> &'static str {
+        "whitespace"
+    }
+
+    fn description(&self) -> &'static str {
+        "Whitespace hygiene"
+    }
+
+    fn default_enabled(&self) -> bool {
+        false
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let config = &ctx.config.check.whitespace;
+        if config.check == CheckLevel::Off {
+            return CheckResult::passed(self.name());
+        }
+
+        let exclude = build_glob_set(&config.exclude);
+        let mut violations = Vec::new();
+        let mut fixed_files = Vec::new();
+        let mut files_checked = 0;
+        let mut counts = IssueCounts::default();
+
+        for file in ctx.files {
+            let relative = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
+            if !is_checked_file(relative, &exclude) {
+                continue;
+            }
+            let Ok(file_content) = ctx.read_file(&file.path) else {
+                continue;
+            };
+            let Some(content) = file_content.as_str() else {
+                continue; // Skip binary files
+            };
+            files_checked += 1;
+
+            let indent = config.indent_for(crate::cloc::language_name(&extension(relative)));
+            let issues = scan(content, config, indent);
+            if issues.is_empty() {
+                continue;
+            }
+            counts.add(&issues);
+
+            // Writing decoded text back would corrupt lossy or transcoded files
+            if ctx.fix && file_content.round_trips() {
+                let normalized = normalize(content, config, indent);
+                // A file that can't be rewritten (e.g., partially staged) stays a violation
+                if ctx.write_fix(&file.path, &normalized).is_ok() {
+                    fixed_files.push(relative.display().to_string());
+                    continue;
+                }
+            }
+
+            violations.extend(issues.violations(relative, config, indent));
+            if let Some(limit) = ctx.limit
+                && violations.len() >= limit
+            {
+                break;
+            }
+        }
+
+        let metrics = metrics::Whitespace {
+            files_checked,
+            line_ending: counts.line_ending,
+            trailing_whitespace: counts.trailing,
+            missing_final_newline: counts.final_newline,
+            wrong_indent: counts.indent,
+        }
+        .to_json();
+
+        if violations.is_empty() {
+            if fixed_files.is_empty() {
+                CheckResult::passed(self.name()).with_metrics(metrics)
+            } else {
+                let summary = json!({
+                    "files_normalized": fixed_files.len(),
+                    "files": fixed_files,
+                });
+                CheckResult::fixed(self.name(), summary).with_metrics(metrics)
+            }
+        } else if config.check == CheckLevel::Warn {
+            CheckResult::passed_with_warnings(self.name(), violations).with_metrics(metrics)
+        } else {
+            CheckResult::failed(self.name(), violations).with_metrics(metrics)
+        }
+    }
+}
+
+/// Whether a file is in scope: known source extensions, minus excludes.
+///
+/// Notebooks are skipped; they're JSON written by Jupyter.
+fn is_checked_file(relative: &Path, exclude: &GlobSet) -> bool {
+    let ext = extension(relative);
+    ext != "ipynb" && crate::cloc::is_text_extension(&ext) && !exclude.is_match(relative)
+}
+
+fn extension(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
+
+/// Files with each kind of issue, for metrics.
+#[derive(Default)]
+struct IssueCounts {
+    line_ending: usize,
+    trailing: usize,
+    final_newline: usize,
+    indent: usize,
+}
+
+impl IssueCounts {
+    fn add(&mut self, issues: &Issues) {
+        self.line_ending += usize::from(issues.line_ending.count > 0);
+        self.trailing += usize::from(issues.trailing.count > 0);
+        self.final_newline += usize::from(issues.missing_final_newline.is_some());
+        self.indent += usize::from(issues.indent.count > 0);
+    }
+}
+
+/// Lines with one kind of issue: how many, and the first (1-based).
+#[derive(Debug, Default, PartialEq, Eq)]
+struct LineIssue {
+    count: usize,
+    first: u32,
+}
+
+impl LineIssue {
+    fn record(&mut self, line: u32) {
+        if self.count == 0 {
+            self.first = line;
+        }
+        self.count += 1;
+    }
+}
+
+/// Whitespace issues found in one file.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Issues {
+    line_ending: LineIssue,
+    trailing: LineIssue,
+    indent: LineIssue,
+    /// Last line, if it lacks a newline.
+    missing_final_newline: Option<u32>,
+}
+
+impl Issues {
+    fn is_empty(&self) -> bool {
+        self.line_ending.count == 0
+            && self.trailing.count == 0
+            && self.indent.count == 0
+            && self.missing_final_newline.is_none()
+    }
+
+    /// One violation per kind of issue, at its first line.
+    fn violations(
+        &self,
+        path: &Path,
+        config: &WhitespaceConfig,
+        indent: IndentStyle,
+    ) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        if self.line_ending.count > 0 {
+            let (expected, found) = match config.line_endings {
+                LineEndings::Crlf => ("crlf", "lf"),
+                _ => ("lf", "crlf"),
+            };
+            violations.push(with_count(
+                Violation::file(
+                    path,
+                    self.line_ending.first,
+                    "line_ending",
+                    format!(
+                        "Convert line endings to {}, or run --fix.",
+                        expected.to_uppercase()
+                    ),
+                )
+                .with_expected_found(expected, found),
+                self.line_ending.count,
+            ));
+        }
+        if self.trailing.count > 0 {
+            violations.push(with_count(
+                Violation::file(
+                    path,
+                    self.trailing.first,
+                    "trailing_whitespace",
+                    "Remove trailing spaces and tabs, or run --fix.",
+                ),
+                self.trailing.count,
+            ));
+        }
+        if let Some(line) = self.missing_final_newline {
+            violations.push(Violation::file(
+                path,
+                line,
+                "missing_final_newline",
+                "End the file with a newline, or run --fix.",
+            ));
+        }
+        if self.indent.count > 0 {
+            let (expected, found) = match indent {
+                IndentStyle::Tabs => ("tabs", "spaces"),
+                _ => ("spaces", "tabs"),
+            };
+            violations.push(with_count(
+                Violation::file(
+                    path,
+                    self.indent.first,
+                    "wrong_indent",
+                    format!("Indent with {}, or run --fix.", expected),
+                )
+                .with_expected_found(expected, found),
+                self.indent.count,
+            ));
+        }
+        violations
+    }
+}
+
+/// Attach the number of offending lines.
+fn with_count(violation: Violation, count: usize) -> Violation {
+    violation.with_detail(Field::Value, count as i64)
+}
+
+/// A line split into indentation, body, trailing whitespace, and ending.
+struct Line<'a> {
+    indent: &'a str,
+    body: &'a str,
+    trailing: &'a str,
+    ending: &'a str,
+}
+
+impl<'a> Line<'a> {
+    fn parse(raw: &'a str) -> Self {
+        let (text, ending) = if let Some(text) = raw.strip_suffix("\r\n") {
+            (text, "\r\n")
+        } else if let Some(text) = raw.strip_suffix('\n') {
+            (text, "\n")
+        } else {
+            (raw, "")
+        };
+        let content = text.trim_end_matches([' ', '\t']);
+        let trailing = &text[content.len()..];
+        let body = content.trim_start_matches([' ', '\t']);
+        let indent = &content[..content.len() - body.len()];
+        Self {
+            indent,
+            body,
+            trailing,
+            ending,
+        }
+    }
+}
+
+/// Find whitespace issues in file content.
+fn scan(content: &str, config: &WhitespaceConfig, indent: IndentStyle) -> Issues {
+    let mut issues = Issues::default();
+    let mut number = 0;
+    for raw in content.split_inclusive('\n') {
+        number += 1;
+        let line = Line::parse(raw);
+
+        let wrong_ending = match config.line_endings {
+            LineEndings::Lf => line.ending == "\r\n",
+            LineEndings::Crlf => line.ending == "\n",
+            LineEndings::Any => false,
+        };
+        if wrong_ending {
+            issues.line_ending.record(number);
+        }
+        if config.trailing && !line.trailing.is_empty() {
+            issues.trailing.record(number);
+        }
+        if is_wrong_indent(line.indent, indent, config.tab_width) {
+            issues.indent.record(number);
+        }
+        if config.final_newline && line.ending.is_empty() {
+            issues.missing_final_newline = Some(number);
+        }
+    }
+    issues
+}
+
+/// Whether leading whitespace violates the indentation policy.
+///
+/// Tab-indented code may align with spaces after its tabs, so `tabs` only
+/// flags lines that start with a full tab width of spaces.
+fn is_wrong_indent(indent: &str, style: IndentStyle, tab_width: usize) -> bool {
+    match style {
+        IndentStyle::Spaces => indent.contains('\t'),
+        IndentStyle::Tabs => {
+            let spaces = indent.len() - indent.trim_start_matches(' ').len();
+            spaces >= tab_width.max(1)
+        }
+        IndentStyle::Any => false,
+    }
+}
+
+/// Rewrite content to satisfy the policy (what `--fix` writes).
+fn normalize(content: &str, config: &WhitespaceConfig, indent: IndentStyle) -> String {
+    let mut out = String::with_capacity(content.len());
+    let default_ending = match config.line_endings {
+        LineEndings::Crlf => "\r\n",
+        _ => "\n",
+    };
+    for raw in content.split_inclusive('\n') {
+        let line = Line::parse(raw);
+        let tab_width = config.tab_width.max(1);
+        match indent {
+            IndentStyle::Spaces => out.push_str(&expand_tabs(line.indent, tab_width)),
+            IndentStyle::Tabs => out.push_str(&collapse_spaces(line.indent, tab_width)),
+            IndentStyle::Any => out.push_str(line.indent),
+        }
+        out.push_str(line.body);
+        if !config.trailing {
+            out.push_str(line.trailing);
+        }
+        let ending = match (config.line_endings, line.ending) {
+            (_, "") if !config.final_newline => "",
+            (LineEndings::Any, "") => default_ending,
+            (LineEndings::Any, ending) => ending,
+            _ => default_ending,
+        };
+        out.push_str(ending);
+    }
+    out
+}
+
+/// Expand tabs in leading whitespace to the next tab stop.
+fn expand_tabs(indent: &str, tab_width: usize) -> String {
+    let mut out = String::new();
+    for c in indent.chars() {
+        if c == '\t' {
+            let pad = tab_width - out.len() % tab_width;
+            out.push_str(&" ".repeat(pad));
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Replace each leading run of `tab_width` spaces with a tab.
+fn collapse_spaces(indent: &str, tab_width: usize) -> String {
+    let spaces = indent.len() - indent.trim_start_matches(' ').len();
+    format!(
+        "{}{}{}",
+        "\t".repeat(spaces / tab_width),
+        " ".repeat(spaces % tab_width),
+        &indent[spaces..]
+    )
+}
+
+#[cfg(test)]
+#[path = "whitespace_tests.rs"]
+mod tests;
@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn config() -> WhitespaceConfig {
+    WhitespaceConfig::default()
+}
+
+// =============================================================================
+// SCAN TESTS
+// =============================================================================
+
+#[test]
+fn clean_content_has_no_issues() {
+    let issues = scan("fn main() {\n    x();\n}\n", &config(), IndentStyle::Spaces);
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn crlf_is_flagged_when_lf_required() {
+    let issues = scan("a\nb\r\nc\r\n", &config(), IndentStyle::Any);
+
+    assert_eq!(issues.line_ending, LineIssue { count: 2, first: 2 });
+}
+
+#[test]
+fn lf_is_flagged_when_crlf_required() {
+    let config = WhitespaceConfig {
+        line_endings: LineEndings::Crlf,
+        ..config()
+    };
+
+    let issues = scan("a\r\nb\n", &config, IndentStyle::Any);
+
+    assert_eq!(issues.line_ending, LineIssue { count: 1, first: 2 });
+}
+
+#[test]
+fn any_line_endings_are_not_checked() {
+    let config = WhitespaceConfig {
+        line_endings: LineEndings::Any,
+        ..config()
+    };
+
+    assert!(scan("a\r\nb\n", &config, IndentStyle::Any).is_empty());
+}
+
+#[test]
+fn trailing_whitespace_before_crlf_is_still_trailing() {
+    let config = WhitespaceConfig {
+        line_endings: LineEndings::Any,
+        ..config()
+    };
+
+    let issues = scan("ok\nbad \t\r\n", &config, IndentStyle::Any);
+
+    assert_eq!(issues.trailing, LineIssue { count: 1, first: 2 });
+}
+
+#[test]
+fn whitespace_only_line_is_trailing() {
+    let issues = scan("a\n   \nb\n", &config(), IndentStyle::Any);
+
+    assert_eq!(issues.trailing, LineIssue { count: 1, first: 2 });
+}
+
+#[test]
+fn trailing_can_be_disabled() {
+    let config = WhitespaceConfig {
+        trailing: false,
+        ..config()
+    };
+
+    assert!(scan("a  \n", &config, IndentStyle::Any).is_empty());
+}
+
+#[test]
+fn missing_final_newline_reports_last_line() {
+    let issues = scan("a\nb", &config(), IndentStyle::Any);
+
+    assert_eq!(issues.missing_final_newline, Some(2));
+}
+
+#[test]
+fn empty_file_needs_no_final_newline() {
+    assert!(scan("", &config(), IndentStyle::Any).is_empty());
+}
+
+#[test]
+fn spaces_policy_flags_tabs_in_indentation() {
+    let issues = scan("a\n\tb\n  \tc\nd\te\n", &config(), IndentStyle::Spaces);
+
+    assert_eq!(issues.indent, LineIssue { count: 2, first: 2 });
+}
+
+#[test]
+fn tabs_policy_flags_full_width_space_indentation() {
+    let content = "\tok\n\t  aligned\n  short\n    bad\n";
+
+    let issues = scan(content, &config(), IndentStyle::Tabs);
+
+    assert_eq!(issues.indent, LineIssue { count: 1, first: 4 });
+}
+
+// =============================================================================
+// NORMALIZE TESTS
+// =============================================================================
+
+#[test]
+fn normalize_fixes_endings_trailing_and_final_newline() {
+    let fixed = normalize("a  \r\nb\r\nc", &config(), IndentStyle::Any);
+
+    assert_eq!(fixed, "a\nb\nc\n");
+}
+
+#[test]
+fn normalize_keeps_mixed_endings_when_any() {
+    let config = WhitespaceConfig {
+        line_endings: LineEndings::Any,
+        ..config()
+    };
+
+    assert_eq!(normalize("a\r\nb\n", &config, IndentStyle::Any), "a\r\nb\n");
+}
+
+#[test]
+fn normalize_expands_tabs_to_tab_stops() {
+    let config = WhitespaceConfig {
+        tab_width: 2,
+        ..config()
+    };
+
+    let fixed = normalize("\tx\n \ty\n", &config, IndentStyle::Spaces);
+
+    assert_eq!(fixed, "  x\n  y\n");
+}
+
+#[test]
+fn normalize_collapses_spaces_to_tabs() {
+    let fixed = normalize("        x\n      y\n", &config(), IndentStyle::Tabs);
+
+    assert_eq!(fixed, "\t\tx\n\t  y\n");
+}
+
+#[test]
+fn normalized_content_scans_clean() {
+    let content = "  \tmixed  \r\n\tline\r\nend";
+
+    for style in [IndentStyle::Spaces, IndentStyle::Tabs] {
+        let fixed = normalize(content, &config(), style);
+        assert!(scan(&fixed, &config(), style).is_empty(), "{:?}", style);
+    }
+}
+
+// =============================================================================
+// CONFIG TESTS
+// =============================================================================
+
+#[test]
+fn language_override_is_case_insensitive() {
+    let mut config = WhitespaceConfig {
+        indent: IndentStyle::Spaces,
+        ..config()
+    };
+    config.languages.insert("go".to_string(), IndentStyle::Tabs);
+
+    assert_eq!(config.indent_for("Go"), IndentStyle::Tabs);
+    assert_eq!(config.indent_for("Rust"), IndentStyle::Spaces);
+}
+
+#[test]
+fn notebooks_and_unknown_extensions_are_not_checked() {
+    let exclude = build_glob_set(&["vendor/**".to_string()]);
+
+    assert!(is_checked_file(Path::new("src/main.rs"), &exclude));
+    assert!(!is_checked_file(Path::new("notes.ipynb"), &exclude));
+    assert!(!is_checked_file(Path::new("logo.png"), &exclude));
+    assert!(!is_checked_file(Path::new("vendor/lib.rs"), &exclude));
+}
@@ -0,0 +1,536 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! CI workflow scaffolding for `quench init --ci`.
+//!
+//! The generated workflow checks pull requests against their base branch,
+//! updates the git notes baseline on the default branch, and uploads the
+//! HTML report from both. Toolchain and dependency setup comes from the
+//! project's languages and their package managers and test runners.
+
+use std::path::Path;
+
+use crate::adapter::javascript::PackageManager as JsPackageManager;
+use crate::adapter::python::PackageManager as PyPackageManager;
+use crate::init::DetectedLanguage;
+
+/// Installer used by generated workflows.
+pub const INSTALL_SCRIPT_URL: &str =
+    "https://github.com/alfredjeanlab/quench/releases/latest/download/install.sh";
+
+/// Report file the workflows build and upload.
+const REPORT_FILE: &str = "quench-report.html";
+
+/// CI provider to generate a workflow for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CiProvider {
+    GitHub,
+    GitLab,
+    Circle,
+}
+
+impl CiProvider {
+    /// Name used in `--ci` and the generated file's header.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CiProvider::GitHub => "github",
+            CiProvider::GitLab => "gitlab",
+            CiProvider::Circle => "circle",
+        }
+    }
+
+    /// Where the provider reads its workflow from.
+    pub fn workflow_path(&self) -> &'static str {
+        match self {
+            CiProvider::GitHub => ".github/workflows/quench.yml",
+            CiProvider::GitLab => ".gitlab-ci.yml",
+            CiProvider::Circle => ".circleci/config.yml",
+        }
+    }
+
+    /// Where to write the workflow when `workflow_path` is taken, for
+    /// providers that can include another file; None if they can't.
+    pub fn include_path(&self) -> Option<&'static str> {
+        match self {
+            CiProvider::GitLab => Some(".gitlab/ci/quench.yml"),
+            CiProvider::GitHub | CiProvider::Circle => None,
+        }
+    }
+}
+
+/// A shell command for Docker-image providers, which may need root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ImageCommand {
+    run: String,
+    root: bool,
+}
+
+impl ImageCommand {
+    fn user(run: impl Into<String>) -> Self {
+        Self {
+            run: run.into(),
+            root: false,
+        }
+    }
+
+    fn root(run: impl Into<String>) -> Self {
+        Self {
+            run: run.into(),
+            root: true,
+        }
+    }
+}
+
+/// How one language's toolchain gets set up on each provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Toolchain {
+    /// GitHub Actions steps, unindented.
+    github_steps: Vec<&'static str>,
+    /// GitLab image with the toolchain.
+    gitlab_image: Option<&'static str>,
+    /// CircleCI image with the toolchain.
+    circle_image: Option<&'static str>,
+    /// Commands completing the toolchain inside those images.
+    image_setup: Vec<ImageCommand>,
+    /// Commands installing the project's dependencies, on every provider.
+    install: Vec<String>,
+}
+
+/// What a generated workflow sets up and which branch it ratchets on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CiPlan {
+    /// Branch whose pushes update the baseline.
+    pub branch: String,
+    toolchains: Vec<Toolchain>,
+}
+
+impl CiPlan {
+    /// Plan setup for `languages`, detecting package managers and test
+    /// runners under `root`.
+    pub fn detect(root: &Path, languages: &[DetectedLanguage], branch: &str) -> Self {
+        let toolchains = languages
+            .iter()
+            .map(|lang| toolchain(root, *lang))
+            .collect();
+        Self {
+            branch: branch.to_string(),
+            toolchains,
+        }
+    }
+
+    /// Render the workflow file for `provider`.
+    pub fn render(&self, provider: CiProvider) -> String {
+        match provider {
+            CiProvider::GitHub => self.render_github(),
+            CiProvider::GitLab => self.render_gitlab(),
+            CiProvider::Circle => self.render_circle(),
+        }
+    }
+
+    fn install_commands(&self) -> Vec<&str> {
+        self.toolchains
+            .iter()
+            .flat_map(|t| t.install.iter().map(String::as_str))
+            .collect()
+    }
+
+    fn image_commands(&self) -> impl Iterator<Item = &ImageCommand> {
+        self.toolchains.iter().flat_map(|t| &t.image_setup)
+    }
+
+    fn render_github(&self) -> String {
+        let mut setup = String::new();
+        for step in self.toolchains.iter().flat_map(|t| &t.github_steps) {
+            setup.push_str(&indent(step, 6));
+        }
+        let install = self.install_commands();
+        if !install.is_empty() {
+            setup.push_str("      - name: Install dependencies\n        run: |\n");
+            for command in install {
+                setup.push_str(&format!("          {}\n", command));
+            }
+        }
+        GITHUB_TEMPLATE
+            .replace("{setup}", &setup)
+            .replace("{branch}", &self.branch)
+            .replace("{install_url}", INSTALL_SCRIPT_URL)
+            .replace("{report}", REPORT_FILE)
+    }
+
+    fn render_gitlab(&self) -> String {
+        let image = self
+            .toolchains
+            .iter()
+            .find_map(|t| t.gitlab_image)
+            .unwrap_or("buildpack-deps:stable");
+        let mut setup = String::new();
+        for command in self.image_commands() {
+            setup.push_str(&format!("    - {}\n", command.run));
+        }
+        for command in self.install_commands() {
+            setup.push_str(&format!("    - {}\n", command));
+        }
+        GITLAB_TEMPLATE
+            .replace("{image}", image)
+            .replace("{setup}", &setup)
+            .replace("{branch}", &self.branch)
+            .replace("{install_url}", INSTALL_SCRIPT_URL)
+            .replace("{report}", REPORT_FILE)
+    }
+
+    fn render_circle(&self) -> String {
+        let image = self
+            .toolchains
+            .iter()
+            .find_map(|t| t.circle_image)
+            .unwrap_or("cimg/base:current");
+        // cimg images run as an unprivileged user with passwordless sudo
+        let mut commands: Vec<String> = self
+            .image_commands()
+            .map(|c| {
+                if c.root {
+                    format!("sudo {}", c.run)
+                } else {
+                    c.run.clone()
+                }
+            })
+            .collect();
+        commands.extend(self.install_commands().into_iter().map(String::from));
+        let setup = if commands.is_empty() {
+            String::new()
+        } else {
+            let mut step = String::from(
+                "      - run:\n          name: Install toolchain and dependencies\n          command: |\n",
+            );
+            for command in commands {
+                step.push_str(&format!("            {}\n", command));
+            }
+            step
+        };
+        CIRCLE_TEMPLATE
+            .replace("{image}", image)
+            .replace("{setup}", &setup)
+            .replace("{branch}", &self.branch)
+            .replace("{install_url}", INSTALL_SCRIPT_URL)
+            .replace("{report}", REPORT_FILE)
+    }
+}
+
+/// Setup for one language, shaped by the package manager and test runner
+/// found under `root`.
+fn toolchain(root: &Path, lang: DetectedLanguage) -> Toolchain {
+    match lang {
+        DetectedLanguage::Rust => Toolchain {
+            github_steps: vec![
+                "- uses: dtolnay/rust-toolchain@stable\n  with:\n    components: clippy, rustfmt",
+                "- uses: Swatinem/rust-cache@v2",
+            ],
+            gitlab_image: Some("rust:latest"),
+            circle_image: Some("cimg/rust:1.87"),
+            image_setup: vec![ImageCommand::user("rustup component add clippy rustfmt")],
+            install: Vec::new(),
+        },
+        DetectedLanguage::Golang => Toolchain {
+            github_steps: vec!["- uses: actions/setup-go@v5\n  with:\n    go-version-file: go.mod"],
+            gitlab_image: Some("golang:latest"),
+            circle_image: Some("cimg/go:1.24"),
+            image_setup: Vec::new(),
+            install: vec!["go mod download".to_string()],
+        },
+        DetectedLanguage::JavaScript => {
+            let manager = JsPackageManager::detect(root);
+            let (github_steps, image_setup, install) = match manager {
+                JsPackageManager::Npm => (vec![], vec![], "npm ci"),
+                JsPackageManager::Pnpm => (
+                    vec!["- uses: pnpm/action-setup@v4"],
+                    vec![ImageCommand::root("corepack enable")],
+                    "pnpm install --frozen-lockfile",
+                ),
+                JsPackageManager::Yarn => (
+                    vec![],
+                    vec![ImageCommand::root("corepack enable")],
+                    "yarn install --frozen-lockfile",
+                ),
+                JsPackageManager::Bun => (
+                    vec!["- uses: oven-sh/setup-bun@v2"],
+                    vec![ImageCommand::root("npm install -g bun")],
+                    "bun install --frozen-lockfile",
+                ),
+            };
+            let mut steps = github_steps;
+            steps.push("- uses: actions/setup-node@v4\n  with:\n    node-version: lts/*");
+            Toolchain {
+                github_steps: steps,
+                gitlab_image: Some("node:lts"),
+                circle_image: Some("cimg/node:lts"),
+                image_setup,
+                install: vec![install.to_string()],
+            }
+        }
+        DetectedLanguage::Shell => {
+            // Lint needs shellcheck (preinstalled on GitHub runners); bats
+            // only when there are bats suites to run
+            let bats = has_bats_tests(root);
+            let packages = if bats {
+                "shellcheck bats"
+            } else {
+                "shellcheck"
+            };
+            Toolchain {
+                github_steps: if bats {
+                    vec![
+                        "- name: Install bats\n  run: sudo apt-get update && sudo apt-get install -y bats",
+                    ]
+                } else {
+                    Vec::new()
+                },
+                gitlab_image: None,
+                circle_image: None,
+                image_setup: vec![
+                    ImageCommand::root("apt-get update"),
+                    ImageCommand::root(format!("apt-get install -y {}", packages)),
+                ],
+                install: Vec::new(),
+            }
+        }
+        DetectedLanguage::Ruby => Toolchain {
+            github_steps: vec![
+                "- uses: ruby/setup-ruby@v1\n  with:\n    ruby-version: ruby\n    bundler-cache: true",
+            ],
+            gitlab_image: Some("ruby:latest"),
+            circle_image: Some("cimg/ruby:3.4"),
+            // setup-ruby's bundler-cache already installs the bundle on GitHub
+            image_setup: vec![ImageCommand::user("bundle install")],
+            install: Vec::new(),
+        },
+        DetectedLanguage::Python => {
+            let manager = PyPackageManager::detect(root);
+            let (github_tool, image_tool, install) = match manager {
+                PyPackageManager::Uv => (
+                    Some("- uses: astral-sh/setup-uv@v6"),
+                    Some("pip install uv"),
+                    "uv sync".to_string(),
+                ),
+                PyPackageManager::Poetry => (
+                    Some("- name: Install Poetry\n  run: pipx install poetry"),
+                    Some("pip install poetry"),
+                    "poetry install".to_string(),
+                ),
+                PyPackageManager::Pipenv => (
+                    Some("- name: Install Pipenv\n  run: pipx install pipenv"),
+                    Some("pip install pipenv"),
+                    "pipenv install --dev".to_string(),
+                ),
+                PyPackageManager::Pip if root.join("requirements.txt").exists() => {
+                    (None, None, "pip install -r requirements.txt".to_string())
+                }
+                PyPackageManager::Pip => (None, None, "pip install -e .".to_string()),
+            };
+            let mut github_steps =
+                vec!["- uses: actions/setup-python@v5\n  with:\n    python-version: \"3.x\""];
+            github_steps.extend(github_tool);
+            Toolchain {
+                github_steps,
+                gitlab_image: Some("python:3"),
+                circle_image: Some("cimg/python:3.13"),
+                image_setup: image_tool.into_iter().map(ImageCommand::user).collect(),
+                install: vec![install],
+            }
+        }
+    }
+}
+
+/// Whether the project has bats suites in the usual test directories.
+fn has_bats_tests(root: &Path) -> bool {
+    ["tests", "test"].iter().any(|dir| {
+        root.join(dir)
+            .read_dir()
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .any(|entry| entry.path().extension().and_then(|e| e.to_str()) == Some("bats"))
+            })
+            .unwrap_or(false)
+    })
+}
+
+/// Indent each line of a YAML fragment by `width` spaces.
+fn indent(fragment: &str, width: usize) -> String {
+    let pad = " ".repeat(width);
+    fragment
+        .lines()
+        .map(|line| format!("{}{}\n", pad, line))
+        .collect()
+}
+
+const GITHUB_TEMPLATE: &str = r#"# Generated by `quench init --ci github`.
+name: quench
+
+on:
+  pull_request:
+  push:
+    branches: [{branch}]
+
+jobs:
+  check:
+    name: Check
+    if: github.event_name == 'pull_request'
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v6
+        with:
+          fetch-depth: 0
+{setup}      - name: Install quench
+        run: |
+          curl -fsSL {install_url} | bash
+          echo "$HOME/.local/bin" >> "$GITHUB_PATH"
+      - name: Fetch baseline
+        run: git fetch origin refs/notes/quench:refs/notes/quench || true
+      - name: Check quality
+        run: quench check --ci --base "origin/${{ github.base_ref }}"
+      - name: Build report
+        if: always()
+        run: quench report -o {report}
+      - uses: actions/upload-artifact@v4
+        if: always()
+        with:
+          name: quench-report
+          path: {report}
+
+  ratchet:
+    name: Update baseline
+    if: github.event_name == 'push'
+    runs-on: ubuntu-latest
+    permissions:
+      contents: write
+    steps:
+      - uses: actions/checkout@v6
+        with:
+          fetch-depth: 0
+{setup}      - name: Install quench
+        run: |
+          curl -fsSL {install_url} | bash
+          echo "$HOME/.local/bin" >> "$GITHUB_PATH"
+      - name: Fetch baseline
+        run: git fetch origin refs/notes/quench:refs/notes/quench || true
+      - name: Update baseline
+        run: |
+          quench check --ci --fix
+          git push origin refs/notes/quench
+      - name: Build report
+        if: always()
+        run: quench report -o {report}
+      - uses: actions/upload-artifact@v4
+        if: always()
+        with:
+          name: quench-report
+          path: {report}
+"#;
+
+const GITLAB_TEMPLATE: &str = r#"# Generated by `quench init --ci gitlab`.
+#
+# Updating the baseline pushes git notes, which needs a project access token
+# with write_repository scope in the QUENCH_PUSH_TOKEN CI/CD variable.
+
+.quench:
+  image: {image}
+  variables:
+    GIT_DEPTH: "0"
+  before_script:
+{setup}    - curl -fsSL {install_url} | bash
+    - export PATH="$HOME/.local/bin:$PATH"
+    - git fetch origin "refs/notes/quench:refs/notes/quench" || true
+  artifacts:
+    when: always
+    paths:
+      - {report}
+
+quench:check:
+  extends: .quench
+  stage: test
+  rules:
+    - if: $CI_PIPELINE_SOURCE == "merge_request_event"
+  script:
+    - git fetch origin "$CI_MERGE_REQUEST_TARGET_BRANCH_NAME"
+    - quench check --ci --base "origin/$CI_MERGE_REQUEST_TARGET_BRANCH_NAME" || status=$?
+    - quench report -o {report}
+    - exit ${status:-0}
+
+quench:ratchet:
+  extends: .quench
+  stage: test
+  rules:
+    - if: $CI_COMMIT_BRANCH == "{branch}"
+  script:
+    - quench check --ci --fix || status=$?
+    - quench report -o {report}
+    - git push "https://oauth2:${QUENCH_PUSH_TOKEN}@${CI_SERVER_HOST}/${CI_PROJECT_PATH}.git" refs/notes/quench
+    - exit ${status:-0}
+"#;
+
+const CIRCLE_TEMPLATE: &str = r#"# Generated by `quench init --ci circle`.
+#
+# Updating the baseline pushes git notes, which needs a deploy key with write
+# access (Project Settings > SSH Keys).
+
+version: 2.1
+
+commands:
+  setup-quench:
+    steps:
+      - checkout
+{setup}      - run:
+          name: Install quench
+          command: |
+            curl -fsSL {install_url} | bash
+            echo 'export PATH="$HOME/.local/bin:$PATH"' >> "$BASH_ENV"
+      - run:
+          name: Fetch baseline
+          command: git fetch origin refs/notes/quench:refs/notes/quench || true
+  report:
+    steps:
+      - run:
+          name: Build report
+          when: always
+          command: quench report -o {report}
+      - store_artifacts:
+          path: {report}
+
+jobs:
+  check:
+    docker:
+      - image: {image}
+    steps:
+      - setup-quench
+      - run:
+          name: Check quality
+          command: quench check --ci --base origin/{branch}
+      - report
+  ratchet:
+    docker:
+      - image: {image}
+    steps:
+      - setup-quench
+      - run:
+          name: Update baseline
+          command: |
+            quench check --ci --fix
+            git push origin refs/notes/quench
+      - report
+
+workflows:
+  quench:
+    jobs:
+      - check:
+          filters:
+            branches:
+              ignore: {branch}
+      - ratchet:
+          filters:
+            branches:
+              only: {branch}
+"#;
+
+#[cfg(test)]
+#[path = "ci_workflow_tests.rs"]
+mod tests;
@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn plan(temp: &TempDir, languages: &[DetectedLanguage]) -> CiPlan {
+    CiPlan::detect(temp.path(), languages, "main")
+}
+
+// =============================================================================
+// GITHUB TESTS
+// =============================================================================
+
+#[test]
+fn github_checks_pull_requests_against_base() {
+    let temp = TempDir::new().unwrap();
+    let workflow = plan(&temp, &[]).render(CiProvider::GitHub);
+
+    assert!(workflow.contains("quench check --ci --base \"origin/${{ github.base_ref }}\""));
+    assert!(workflow.contains("fetch-depth: 0"));
+}
+
+#[test]
+fn github_ratchets_on_branch_pushes() {
+    let temp = TempDir::new().unwrap();
+    let workflow = CiPlan::detect(temp.path(), &[], "trunk").render(CiProvider::GitHub);
+
+    assert!(workflow.contains("branches: [trunk]"));
+    assert!(
+        workflow.contains("quench check --ci --fix\n          git push origin refs/notes/quench")
+    );
+    assert!(workflow.contains("contents: write"));
+}
+
+#[test]
+fn github_uploads_html_report_even_on_failure() {
+    let temp = TempDir::new().unwrap();
+    let workflow = plan(&temp, &[]).render(CiProvider::GitHub);
+
+    assert_eq!(
+        workflow.matches("uses: actions/upload-artifact@v4").count(),
+        2
+    );
+    assert!(workflow.contains("if: always()\n        run: quench report -o quench-report.html"));
+}
+
+#[test]
+fn github_sets_up_each_language() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("pnpm-lock.yaml"), "").unwrap();
+    let workflow = plan(
+        &temp,
+        &[DetectedLanguage::Rust, DetectedLanguage::JavaScript],
+    )
+    .render(CiProvider::GitHub);
+
+    assert!(workflow.contains("      - uses: dtolnay/rust-toolchain@stable\n        with:\n"));
+    assert!(workflow.contains("      - uses: pnpm/action-setup@v4\n"));
+    assert!(workflow.contains("      - uses: actions/setup-node@v4\n"));
+    assert!(workflow.contains("          pnpm install --frozen-lockfile\n"));
+}
+
+#[test]
+fn github_omits_install_step_without_dependencies() {
+    let temp = TempDir::new().unwrap();
+    let workflow = plan(&temp, &[DetectedLanguage::Rust]).render(CiProvider::GitHub);
+
+    assert!(!workflow.contains("Install dependencies"));
+}
+
+// =============================================================================
+// TOOLCHAIN DETECTION TESTS
+// =============================================================================
+
+#[test]
+fn python_install_follows_package_manager() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("uv.lock"), "").unwrap();
+    let workflow = plan(&temp, &[DetectedLanguage::Python]).render(CiProvider::GitHub);
+
+    assert!(workflow.contains("astral-sh/setup-uv"));
+    assert!(workflow.contains("          uv sync\n"));
+}
+
+#[test]
+fn python_pip_installs_requirements_when_present() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("requirements.txt"), "pytest\n").unwrap();
+    let workflow = plan(&temp, &[DetectedLanguage::Python]).render(CiProvider::GitLab);
+
+    assert!(workflow.contains("    - pip install -r requirements.txt\n"));
+}
+
+#[test]
+fn shell_installs_bats_only_with_bats_suites() {
+    let temp = TempDir::new().unwrap();
+    let without = plan(&temp, &[DetectedLanguage::Shell]).render(CiProvider::GitLab);
+    fs::create_dir(temp.path().join("tests")).unwrap();
+    fs::write(temp.path().join("tests/cli.bats"), "").unwrap();
+    let with = plan(&temp, &[DetectedLanguage::Shell]).render(CiProvider::GitLab);
+
+    assert!(without.contains("apt-get install -y shellcheck\n"));
+    assert!(with.contains("apt-get install -y shellcheck bats\n"));
+}
+
+// =============================================================================
+// GITLAB TESTS
+// =============================================================================
+
+#[test]
+fn gitlab_uses_first_language_image() {
+    let temp = TempDir::new().unwrap();
+    let workflow = plan(&temp, &[DetectedLanguage::Shell, DetectedLanguage::Golang])
+        .render(CiProvider::GitLab);
+
+    assert!(workflow.contains("  image: golang:latest\n"));
+    assert!(workflow.contains("    - go mod download\n"));
+}
+
+#[test]
+fn gitlab_defaults_to_buildpack_image() {
+    let temp = TempDir::new().unwrap();
+    let workflow = plan(&temp, &[]).render(CiProvider::GitLab);
+
+    assert!(workflow.contains("  image: buildpack-deps:stable\n"));
+}
+
+#[test]
+fn gitlab_checks_merge_requests_and_ratchets_on_branch() {
+    let temp = TempDir::new().unwrap();
+    let workflow = plan(&temp, &[]).render(CiProvider::GitLab);
+
+    assert!(
+        workflow
+            .contains("quench check --ci --base \"origin/$CI_MERGE_REQUEST_TARGET_BRANCH_NAME\"")
+    );
+    assert!(workflow.contains("$CI_COMMIT_BRANCH == \"main\""));
+    assert!(workflow.contains("when: always"));
+}
+
+// =============================================================================
+// CIRCLECI TESTS
+// =============================================================================
+
+#[test]
+fn circle_runs_root_commands_with_sudo() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("yarn.lock"), "").unwrap();
+    let workflow = plan(&temp, &[DetectedLanguage::JavaScript]).render(CiProvider::Circle);
+
+    assert!(workflow.contains("      - image: cimg/node:lts\n"));
+    assert!(workflow.contains("            sudo corepack enable\n"));
+    assert!(workflow.contains("            yarn install --frozen-lockfile\n"));
+}
+
+#[test]
+fn circle_filters_jobs_by_branch() {
+    let temp = TempDir::new().unwrap();
+    let workflow = plan(&temp, &[]).render(CiProvider::Circle);
+
+    assert!(workflow.contains("ignore: main"));
+    assert!(workflow.contains("only: main"));
+    assert!(workflow.contains("quench check --ci --base origin/main"));
+    assert!(!workflow.contains("Install toolchain and dependencies"));
+}
+
+// =============================================================================
+// PROVIDER TESTS
+// =============================================================================
+
+#[test]
+fn only_gitlab_has_include_path() {
+    assert_eq!(
+        CiProvider::GitLab.include_path(),
+        Some(".gitlab/ci/quench.yml")
+    );
+    assert_eq!(CiProvider::GitHub.include_path(), None);
+    assert_eq!(CiProvider::Circle.include_path(), None);
+}
+
+#[test]
+fn rendered_workflows_have_no_placeholders() {
+    let temp = TempDir::new().unwrap();
+    let plan = plan(&temp, &[DetectedLanguage::Rust, DetectedLanguage::Ruby]);
+    for provider in [CiProvider::GitHub, CiProvider::GitLab, CiProvider::Circle] {
+        let workflow = plan.render(provider);
+        for placeholder in [
+            "{setup}",
+            "{branch}",
+            "{image}",
+            "{install_url}",
+            "{report}",
+        ] {
+            assert!(
+                !workflow.contains(placeholder),
+                "{} left in {} workflow",
+                placeholder,
+                provider.name()
+            );
+        }
+        assert!(workflow.starts_with(&format!(
+            "# Generated by `quench init --ci {}`.",
+            provider.name()
+        )));
+    }
+}
@@ -54,6 +54,18 @@ pub fn comment_style(ext: &str) -> Option<CommentStyle> {
             line: &["//", "#"],
             block: &[("/*", "*/")],
         }),
+        "css" => Some(CommentStyle {
+            line: &[],
+            block: &[("/*", "*/")],
+        }),
+        "scss" | "sass" | "less" => Some(CommentStyle {
+            line: &["//"],
+            block: &[("/*", "*/")],
+        }),
+        "html" => Some(CommentStyle {
+            line: &[],
+            block: &[("<!--", "-->")],
+        }),
         "vue" | "svelte" => Some(CommentStyle {
             line: &["//"],
             block: &[("/*", "*/"), ("<!--", "-->")],
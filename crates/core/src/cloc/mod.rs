@@ -8,7 +8,10 @@
 
 pub mod comment;
 
+use memchr::memchr_iter;
+
 /// Metrics for a single file.
+#[derive(Default)]
 pub struct FileMetrics {
     /// Total line count (matches `wc -l`).
     pub lines: usize,
@@ -24,25 +27,108 @@ pub struct FileMetrics {
     pub tokens: usize,
 }
 
+/// Line and token counts computed directly from bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ByteMetrics {
+    /// Total line count (matches `str::lines().count()`).
+    pub lines: usize,
+    /// Blank lines (whitespace only).
+    pub blank: usize,
+    /// Token estimate (chars / 4).
+    pub tokens: usize,
+}
+
+impl ByteMetrics {
+    /// Non-blank line count.
+    pub fn nonblank(&self) -> usize {
+        self.lines - self.blank
+    }
+}
+
+/// Count lines, blank lines, and tokens without UTF-8 validation or allocation.
+///
+/// Newlines are located with `memchr`; chars are counted as non-continuation
+/// bytes. Lines containing non-ASCII bytes fall back to Unicode whitespace
+/// rules, so results match the `&str` equivalents for valid UTF-8.
+pub fn count_bytes(content: &[u8]) -> ByteMetrics {
+    let mut lines = 0;
+    let mut blank = 0;
+    let mut start = 0;
+
+    for end in memchr_iter(b'\n', content) {
+        lines += 1;
+        if is_blank_line(&content[start..end]) {
+            blank += 1;
+        }
+        start = end + 1;
+    }
+    if start < content.len() {
+        // Final line without trailing newline
+        lines += 1;
+        if is_blank_line(&content[start..]) {
+            blank += 1;
+        }
+    }
+
+    // UTF-8 continuation bytes are 0b10xxxxxx; every other byte starts a char
+    let chars = content.iter().filter(|&&b| (b as i8) >= -0x40).count();
+
+    ByteMetrics {
+        lines,
+        blank,
+        tokens: chars / 4,
+    }
+}
+
+/// Whether a line (without its `\n`) is whitespace only.
+fn is_blank_line(line: &[u8]) -> bool {
+    if line.is_ascii() {
+        // char::is_whitespace for ASCII (includes vertical tab, unlike u8::is_ascii_whitespace)
+        line.iter()
+            .all(|b| matches!(b, b' ' | b'\t' | b'\r' | b'\x0B' | b'\x0C'))
+    } else {
+        String::from_utf8_lossy(line).trim().is_empty()
+    }
+}
+
 /// Count metrics from file content using the file extension for comment detection.
 ///
 /// If no comment style is known for the extension, all non-blank lines are
 /// counted as code (matching `cloc` behavior for unknown languages).
+/// Multi-language files (components, notebooks) are counted block by block,
+/// each with its own language's comment style.
 pub fn count_file_metrics(content: &str, ext: &str) -> FileMetrics {
-    let lines = content.lines().count();
-    let tokens = content.chars().count() / 4;
+    let Some(blocks) = crate::blocks::split(ext, content) else {
+        return count_language_metrics(content, ext);
+    };
+    let mut total = FileMetrics::default();
+    for block in &blocks {
+        let metrics = count_language_metrics(&block.content, block.ext);
+        total.lines += metrics.lines;
+        total.blank += metrics.blank;
+        total.comment += metrics.comment;
+        total.code += metrics.code;
+        total.nonblank += metrics.nonblank;
+        total.tokens += metrics.tokens;
+    }
+    total
+}
+
+/// Count metrics for single-language content.
+fn count_language_metrics(content: &str, ext: &str) -> FileMetrics {
+    let bytes = count_bytes(content.as_bytes());
+    let lines = bytes.lines;
+    let tokens = bytes.tokens;
 
     let (blank, comment_count, code) = match comment::comment_style(ext) {
         Some(style) => {
             let lc = comment::count_lines(content, &style);
             (lc.blank, lc.comment, lc.code)
         }
-        None => {
-            // Unknown language: blank vs code only
-            let blank = content.lines().filter(|l| l.trim().is_empty()).count();
-            let code = lines - blank;
-            (blank, 0, code)
-        }
+        // Notebook markdown cells are prose, counted like doc comments
+        None if ext == "md" => (bytes.blank, bytes.nonblank(), 0),
+        // Unknown language: blank vs code only
+        None => (bytes.blank, 0, bytes.nonblank()),
     };
 
     FileMetrics {
@@ -84,6 +170,7 @@ pub fn language_name(ext: &str) -> &str {
         "pl" | "pm" => "Perl",
         "vue" => "Vue",
         "svelte" => "Svelte",
+        "ipynb" => "Jupyter Notebook",
         other => other,
     }
 }
@@ -110,6 +197,8 @@ pub fn is_text_extension(ext: &str) -> bool {
         | "sh" | "bash" | "zsh" | "fish" | "bats" | "ps1" | "bat" | "cmd"
         // Web (code only)
         | "vue" | "svelte"
+        // Notebooks (code cells)
+        | "ipynb"
         // SQL
         | "sql"
     )
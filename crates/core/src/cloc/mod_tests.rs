@@ -4,6 +4,7 @@
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 
 use super::*;
+use yare::parameterized;
 
 #[test]
 fn count_file_metrics_rust() {
@@ -44,6 +45,27 @@ fn count_file_metrics_tokens() {
     assert_eq!(m.tokens, 3);
 }
 
+#[test]
+fn count_file_metrics_vue_uses_each_block_style() {
+    let content = "<template>\n  <!-- note -->\n</template>\n<script>\n// setup\nlet x = 1;\n</script>\n<style>\n/* theme */\np {}\n</style>\n";
+    let m = count_file_metrics(content, "vue");
+    assert_eq!(m.lines, 11);
+    assert_eq!(m.comment, 3);
+    assert_eq!(m.code, 8);
+}
+
+#[test]
+fn count_file_metrics_notebook_counts_cells() {
+    let content = r##"{"cells": [
+        {"cell_type": "markdown", "source": ["# Title\n", "Intro"]},
+        {"cell_type": "code", "source": ["# setup\n", "x = 1"]}
+    ]}"##;
+    let m = count_file_metrics(content, "ipynb");
+    assert_eq!(m.lines, 4);
+    assert_eq!(m.comment, 3);
+    assert_eq!(m.code, 1);
+}
+
 #[test]
 fn language_name_known() {
     assert_eq!(language_name("rs"), "Rust");
@@ -81,3 +103,32 @@ fn is_text_extension_unknown() {
     assert!(!is_text_extension("json"));
     assert!(!is_text_extension("yaml"));
 }
+
+#[parameterized(
+    empty = { "" },
+    trailing_newline = { "a\nb\n" },
+    no_trailing_newline = { "a\nb" },
+    blank_lines = { "a\n\n   \n\tb\n" },
+    crlf = { "a\r\n\r\nb\r\n" },
+    lone_cr = { "\r" },
+    vertical_tab = { "\x0B\n" },
+    unicode_whitespace = { "a\n\u{3000}\u{a0}\nb" },
+    multibyte_chars = { "héllo wörld 日本語\n" },
+)]
+fn count_bytes_matches_str_counting(content: &str) {
+    let m = count_bytes(content.as_bytes());
+    assert_eq!(m.lines, content.lines().count());
+    assert_eq!(
+        m.blank,
+        content.lines().filter(|l| l.trim().is_empty()).count()
+    );
+    assert_eq!(m.tokens, content.chars().count() / 4);
+}
+
+#[test]
+fn count_bytes_tolerates_invalid_utf8() {
+    let m = count_bytes(b"ok\xFF\n\n");
+    assert_eq!(m.lines, 2);
+    assert_eq!(m.blank, 1);
+    assert_eq!(m.nonblank(), 1);
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! CODEOWNERS parsing for ownership-scoped runs.
+//!
+//! Follows GitHub's rules: patterns use gitignore syntax and the last
+//! matching line wins. A pattern with no owners leaves its files unowned.
+
+use std::path::{Path, PathBuf};
+
+use globset::{GlobBuilder, GlobMatcher};
+
+/// Locations searched for a CODEOWNERS file, in GitHub's order.
+pub const CODEOWNERS_PATHS: &[&str] = &[".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// A parsed CODEOWNERS file.
+#[derive(Debug, Default)]
+pub struct CodeOwners {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug)]
+struct Rule {
+    matchers: Vec<GlobMatcher>,
+    owners: Vec<String>,
+}
+
+impl CodeOwners {
+    /// Find and parse the project's CODEOWNERS file.
+    ///
+    /// Returns the file's path alongside the rules, or `None` if there is none.
+    pub fn find(root: &Path) -> Option<(PathBuf, Self)> {
+        CODEOWNERS_PATHS.iter().find_map(|rel| {
+            let path = root.join(rel);
+            let content = std::fs::read_to_string(&path).ok()?;
+            Some((path, Self::parse(&content)))
+        })
+    }
+
+    /// Parse CODEOWNERS content. Invalid patterns are skipped.
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    return None;
+                }
+                let mut parts = line.split_whitespace();
+                let pattern = parts.next()?;
+                let owners = parts
+                    .take_while(|p| !p.starts_with('#'))
+                    .map(String::from)
+                    .collect();
+                Some(Rule {
+                    matchers: matchers(pattern)?,
+                    owners,
+                })
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Owners of a project-relative path (empty if unowned).
+    pub fn owners_of(&self, path: &Path) -> &[String] {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matchers.iter().any(|m| m.is_match(path)))
+            .map(|rule| rule.owners.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Whether `owner` owns a project-relative path.
+    pub fn is_owned_by(&self, path: &Path, owner: &str) -> bool {
+        let owner = normalize_owner(owner);
+        self.owners_of(path)
+            .iter()
+            .any(|o| o.eq_ignore_ascii_case(&owner))
+    }
+}
+
+/// Add the `@` GitHub handles carry, so `--owner org/team` matches `@org/team`.
+pub fn normalize_owner(owner: &str) -> String {
+    if owner.starts_with('@') || owner.contains('@') {
+        owner.to_string()
+    } else {
+        format!("@{}", owner)
+    }
+}
+
+/// Translate a gitignore-style pattern into globs over project-relative paths.
+fn matchers(pattern: &str) -> Option<Vec<GlobMatcher>> {
+    // A slash anywhere but the end anchors the pattern to the root
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = trimmed.contains('/');
+    let base = trimmed.trim_start_matches('/');
+    if base.is_empty() {
+        return None;
+    }
+    let base = if anchored || base.starts_with("**") {
+        base.to_string()
+    } else {
+        format!("**/{}", base)
+    };
+
+    // Matching a directory matches everything beneath it
+    let mut globs = vec![format!("{}/**", base)];
+    if !pattern.ends_with('/') {
+        globs.push(base);
+    }
+    globs
+        .iter()
+        .map(|g| {
+            GlobBuilder::new(g)
+                .literal_separator(true)
+                .build()
+                .ok()
+                .map(|glob| glob.compile_matcher())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "codeowners_tests.rs"]
+mod tests;
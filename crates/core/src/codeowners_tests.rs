@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use yare::parameterized;
+
+const CODEOWNERS: &str = "\
+# Default owners
+*                   @org/platform
+*.md                @org/docs
+/crates/cli/        @org/cli @alice
+docs/api/           @org/api
+vendor/             # unowned
+crates/**/tests     @org/qa
+";
+
+#[parameterized(
+    fallback = { "Cargo.toml", "@org/platform" },
+    extension_any_depth = { "crates/core/README.md", "@org/docs" },
+    anchored_dir = { "crates/cli/src/main.rs", "@org/cli" },
+    nested_dir = { "docs/api/index.html", "@org/api" },
+    double_star = { "crates/core/tests/a.rs", "@org/qa" },
+)]
+fn last_matching_rule_wins(path: &str, owner: &str) {
+    let owners = CodeOwners::parse(CODEOWNERS);
+    assert_eq!(owners.owners_of(Path::new(path))[0], owner);
+}
+
+#[test]
+fn rule_without_owners_leaves_files_unowned() {
+    let owners = CodeOwners::parse(CODEOWNERS);
+    assert!(owners.owners_of(Path::new("vendor/lib.rs")).is_empty());
+}
+
+#[test]
+fn anchored_pattern_does_not_match_nested_dirs() {
+    let owners = CodeOwners::parse("/cli/ @org/cli\n");
+    assert!(owners.is_owned_by(Path::new("cli/main.rs"), "@org/cli"));
+    assert!(!owners.is_owned_by(Path::new("tools/cli/main.rs"), "@org/cli"));
+}
+
+#[test]
+fn owner_matching_is_case_insensitive_and_accepts_bare_names() {
+    let owners = CodeOwners::parse(CODEOWNERS);
+    let path = Path::new("crates/cli/src/lib.rs");
+    assert!(owners.is_owned_by(path, "@Org/CLI"));
+    assert!(owners.is_owned_by(path, "alice"));
+    assert!(!owners.is_owned_by(path, "@org/platform"));
+}
+
+#[test]
+fn find_checks_github_dir_first() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join(".github")).unwrap();
+    std::fs::write(dir.path().join(".github/CODEOWNERS"), "* @a\n").unwrap();
+    std::fs::write(dir.path().join("CODEOWNERS"), "* @b\n").unwrap();
+
+    let (path, owners) = CodeOwners::find(dir.path()).unwrap();
+    assert!(path.ends_with(".github/CODEOWNERS"));
+    assert!(owners.is_owned_by(Path::new("x.rs"), "@a"));
+}
@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Cross-check config coherence.
+//!
+//! Each setting can be valid on its own while the combination does nothing:
+//! coverage thresholds with no suite to measure coverage, a ratchet baseline
+//! file git never commits, or a sync source with nothing to sync to. These
+//! are reported as diagnostics before checks run.
+
+use std::path::Path;
+
+use git2::Repository;
+
+use crate::checks::agents::root_agent_files;
+use crate::config::{CheckLevel, Config};
+use crate::diagnostics::{Code, Diagnostic};
+
+/// Diagnostics for settings that contradict each other.
+pub fn validate(config: &Config, root: &Path) -> Vec<Diagnostic> {
+    [
+        coverage_without_suites(config),
+        baseline_ignored(config, root),
+        agents_sync_single_file(config, root),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Coverage thresholds with no suite to collect coverage.
+fn coverage_without_suites(config: &Config) -> Option<Diagnostic> {
+    let tests = &config.check.tests;
+    let coverage = &tests.coverage;
+    let thresholds =
+        coverage.min.is_some() || !coverage.package.is_empty() || !coverage.path.is_empty();
+    let off = tests.check.as_deref() == Some("off") || coverage.check == "off";
+    if !thresholds || off || !tests.suite.is_empty() || tests.auto {
+        return None;
+    }
+    Some(Diagnostic::new(
+        Code::CoverageWithoutSuites,
+        "[check.tests.coverage] sets thresholds, but no test suites are configured to measure coverage",
+    ))
+}
+
+/// A ratchet baseline file that `.gitignore` keeps out of commits.
+fn baseline_ignored(config: &Config, root: &Path) -> Option<Diagnostic> {
+    if config.ratchet.check == CheckLevel::Off {
+        return None;
+    }
+    let path = config.git.baseline_path()?;
+    let repo = Repository::discover(root).ok()?;
+    let workdir = repo.workdir()?.canonicalize().ok()?;
+    let root = root.canonicalize().ok()?;
+    let relative = root.join(path).strip_prefix(&workdir).ok()?.to_path_buf();
+    if !repo.is_path_ignored(&relative).ok()? {
+        return None;
+    }
+    Some(Diagnostic::new(
+        Code::BaselineIgnored,
+        format!(
+            "ratchet baseline {} is ignored by git, so other checkouts and CI never see it",
+            path
+        ),
+    ))
+}
+
+/// A sync source with no other agent file to keep in sync.
+fn agents_sync_single_file(config: &Config, root: &Path) -> Option<Diagnostic> {
+    let agents = &config.check.agents;
+    if agents.check == CheckLevel::Off || !agents.sync {
+        return None;
+    }
+    // Syncing is on by default; only an explicit source shows intent
+    let source = agents.sync_from.as_deref()?;
+    if root_agent_files(root, &agents.files).len() >= 2 {
+        return None;
+    }
+    Some(Diagnostic::new(
+        Code::AgentsSyncSingleFile,
+        format!(
+            "[check.agents] syncs from {}, but the project has no other agent file to sync",
+            source
+        ),
+    ))
+}
+
+#[cfg(test)]
+#[path = "coherence_tests.rs"]
+mod tests;
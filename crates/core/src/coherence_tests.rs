@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use crate::config::parse;
+use crate::test_utils::{git, temp_project};
+
+fn codes(config: &str, root: &Path) -> Vec<Code> {
+    let config = parse(config, Path::new("quench.toml")).unwrap();
+    validate(&config, root)
+        .into_iter()
+        .map(|d| d.code)
+        .collect()
+}
+
+// =============================================================================
+// Coverage
+// =============================================================================
+
+#[test]
+fn coverage_thresholds_without_suites_warn() {
+    let temp = temp_project();
+    let config = "version = 1\n[check.tests.coverage]\nmin = 80\n";
+
+    assert_eq!(codes(config, temp.path()), [Code::CoverageWithoutSuites]);
+}
+
+#[test]
+fn coverage_thresholds_with_suites_or_auto_pass() {
+    let temp = temp_project();
+    let suite = "version = 1\n[[check.tests.suite]]\nrunner = \"cargo\"\n[check.tests.coverage]\nmin = 80\n";
+    let auto = "version = 1\n[check.tests]\nauto = true\n[check.tests.coverage]\nmin = 80\n";
+    let off = "version = 1\n[check.tests.coverage]\ncheck = \"off\"\nmin = 80\n";
+
+    assert!(codes(suite, temp.path()).is_empty());
+    assert!(codes(auto, temp.path()).is_empty());
+    assert!(codes(off, temp.path()).is_empty());
+}
+
+// =============================================================================
+// Baseline
+// =============================================================================
+
+#[test]
+fn ignored_baseline_file_warns() {
+    let temp = temp_project();
+    git::init(temp.path());
+    std::fs::write(temp.path().join(".gitignore"), ".quench/\n").unwrap();
+    let config = "version = 1\n[git]\nbaseline = \".quench/baseline.json\"\n";
+
+    assert_eq!(codes(config, temp.path()), [Code::BaselineIgnored]);
+}
+
+#[test]
+fn committed_or_unratcheted_baseline_passes() {
+    let temp = temp_project();
+    git::init(temp.path());
+    std::fs::write(temp.path().join(".gitignore"), ".quench/\n").unwrap();
+    let tracked = "version = 1\n[git]\nbaseline = \"baseline.json\"\n";
+    let off =
+        "version = 1\n[git]\nbaseline = \".quench/baseline.json\"\n[ratchet]\ncheck = \"off\"\n";
+
+    assert!(codes(tracked, temp.path()).is_empty());
+    assert!(codes(off, temp.path()).is_empty());
+    assert!(codes("version = 1\n", temp.path()).is_empty());
+}
+
+// =============================================================================
+// Agents
+// =============================================================================
+
+#[test]
+fn sync_from_with_one_agent_file_warns() {
+    let temp = temp_project();
+    std::fs::write(temp.path().join("CLAUDE.md"), "# Project\n").unwrap();
+    let config = "version = 1\n[check.agents]\nsync_from = \"CLAUDE.md\"\n";
+
+    assert_eq!(codes(config, temp.path()), [Code::AgentsSyncSingleFile]);
+}
+
+#[test]
+fn sync_with_two_agent_files_or_default_source_passes() {
+    let temp = temp_project();
+    std::fs::write(temp.path().join("CLAUDE.md"), "# Project\n").unwrap();
+    let config = "version = 1\n[check.agents]\nsync_from = \"CLAUDE.md\"\n";
+
+    // Sync is on by default, so a lone file without sync_from is fine
+    assert!(codes("version = 1\n", temp.path()).is_empty());
+
+    std::fs::write(temp.path().join("AGENTS.md"), "# Project\n").unwrap();
+    assert!(codes(config, temp.path()).is_empty());
+}
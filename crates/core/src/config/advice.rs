@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Advice message catalog configuration.
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Overrides for violation advice, keyed by rule ID.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct AdviceConfig {
+    /// TOML file of `"<rule-id>" = "<message>"` entries, relative to the
+    /// project root (e.g., ".quench/advice.toml").
+    pub catalog: Option<String>,
+
+    /// Inline messages by rule ID; these win over catalog entries.
+    pub messages: BTreeMap<String, String>,
+}
@@ -3,15 +3,16 @@
 
 //! Check-specific configuration structures.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use serde::Deserialize;
+use schemars::JsonSchema;
 use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize};
 
-use crate::config::{ContentRule, RequiredSection, deserialize_optional_usize};
+use crate::config::{ContentRule, RequiredSection, Sample, deserialize_optional_usize};
 
 /// Documentation check configuration.
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct DocsConfig {
     /// Check level: "error" | "warn" | "off"
@@ -39,7 +40,7 @@ pub struct DocsConfig {
 }
 
 /// Configuration for commit checking in CI mode.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct DocsCommitConfig {
     /// Check level: "error" | "warn" | "off" (default: "off")
@@ -77,7 +78,7 @@ impl DocsCommitConfig {
 }
 
 /// Area mapping for scoped commits.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct DocsAreaConfig {
     /// Required docs pattern (glob).
@@ -89,7 +90,7 @@ pub struct DocsAreaConfig {
 }
 
 /// Configuration for TOC validation.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct TocConfig {
     /// Check level: "error" | "warn" | "off"
@@ -132,7 +133,7 @@ impl TocConfig {
 }
 
 /// Configuration for link validation.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct LinksConfig {
     /// Check level: "error" | "warn" | "off"
@@ -175,7 +176,7 @@ impl LinksConfig {
 }
 
 /// Configuration for specs directory validation.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct SpecsConfig {
     /// Check level: "error" | "warn" | "off"
@@ -270,7 +271,7 @@ impl SpecsConfig {
 }
 
 /// Section validation for specs (separate from agents to allow different defaults).
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SpecsSectionsConfig {
     /// Required sections (simple form: names only, or extended form with advice).
@@ -283,7 +284,7 @@ pub struct SpecsSectionsConfig {
 }
 
 /// Escapes check configuration.
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct EscapesConfig {
     /// Check level: error, warn, or off.
@@ -297,10 +298,40 @@ pub struct EscapesConfig {
     /// Patterns to detect (overrides defaults).
     #[serde(default)]
     pub patterns: Vec<EscapePattern>,
+    /// Share of files to check per fast-mode run (e.g., "10%"); CI mode
+    /// checks every file.
+    #[serde(default)]
+    pub sample: Option<Sample>,
+
+    /// Assertions without a failure message in changed test code.
+    #[serde(default)]
+    pub assertions: EscapeAssertionsConfig,
+}
+
+/// Bare assertion rule (`[check.escapes.assertions]`). Counts are always
+/// reported; violations need `check` set and changed files (`--base` or
+/// `--staged`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct EscapeAssertionsConfig {
+    /// Check level (default: off).
+    pub check: CheckLevel,
+
+    /// Custom advice message for violations.
+    pub advice: Option<String>,
+}
+
+impl Default for EscapeAssertionsConfig {
+    fn default() -> Self {
+        Self {
+            check: CheckLevel::Off,
+            advice: None,
+        }
+    }
 }
 
 /// A single escape hatch pattern definition.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct EscapePattern {
     /// Unique name for this pattern (e.g., "unwrap", "unsafe").
@@ -323,6 +354,10 @@ pub struct EscapePattern {
     #[serde(default)]
     pub threshold: usize,
 
+    /// Maximum source occurrences per 1000 source lines (default: none).
+    #[serde(default)]
+    pub max_density: Option<f64>,
+
     /// Custom advice message for violations.
     #[serde(default)]
     pub advice: Option<String>,
@@ -348,7 +383,7 @@ impl EscapePattern {
 }
 
 /// Action to take when pattern is matched.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum EscapeAction {
     #[default]
@@ -358,7 +393,7 @@ pub enum EscapeAction {
 }
 
 /// Which line metric to use for size thresholds.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum LineMetric {
     /// Total lines (matches `wc -l`).
@@ -369,7 +404,7 @@ pub enum LineMetric {
 }
 
 /// Cloc check configuration.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ClocConfig {
     /// Maximum lines per file (default: 750).
@@ -413,6 +448,27 @@ pub struct ClocConfig {
     /// Advice message for test file violations.
     #[serde(default = "ClocConfig::default_advice_test")]
     pub advice_test: String,
+    /// Share of files to check per fast-mode run (e.g., "10%"); CI mode
+    /// checks every file.
+    #[serde(default)]
+    pub sample: Option<Sample>,
+
+    /// Minimum test lines per source line for each package, or for the
+    /// whole project when no packages are configured (default: none).
+    #[serde(default)]
+    pub min_test_ratio: Option<f64>,
+
+    /// Per-package overrides, by package name.
+    #[serde(default)]
+    pub package: BTreeMap<String, ClocPackageConfig>,
+}
+
+/// Per-package cloc settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct ClocPackageConfig {
+    /// Minimum test lines per source line for this package.
+    pub min_test_ratio: Option<f64>,
 }
 
 impl Default for ClocConfig {
@@ -427,6 +483,9 @@ impl Default for ClocConfig {
             max_tokens: Self::default_max_tokens(),
             advice: Self::default_advice(),
             advice_test: Self::default_advice_test(),
+            sample: None,
+            min_test_ratio: None,
+            package: BTreeMap::new(),
         }
     }
 }
@@ -458,7 +517,7 @@ impl ClocConfig {
 }
 
 /// Check level: error, warn, or off.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum CheckLevel {
     #[default]
@@ -492,7 +551,7 @@ where
 ///
 /// Allows overriding the global cloc.check level and advice per language.
 /// Unset fields inherit from [check.cloc].
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct LangClocConfig {
     /// Check level: error, warn, or off.
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unused public item check configuration.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::CheckLevel;
+
+/// Deadcode check configuration. Off unless `check` is set, since a
+/// library's public API is unused inside its own repository by design.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct DeadcodeConfig {
+    /// Check level (default: off).
+    pub check: CheckLevel,
+
+    /// Exclude patterns (items defined in matching files aren't reported,
+    /// but references from them still count).
+    pub exclude: Vec<String>,
+}
+
+impl Default for DeadcodeConfig {
+    fn default() -> Self {
+        Self {
+            check: CheckLevel::Off,
+            exclude: Vec::new(),
+        }
+    }
+}
@@ -71,6 +71,15 @@ Group tests by the concern they exercise and place each group in a \
 subfolder or its own sibling test file."
         )
     }
+
+    /// Default advice for churn hotspots.
+    pub fn hotspot(window: u32) -> String {
+        format!(
+            "This file is large and changed often in the last {window} days, so it \
+costs the most to keep working in. Refactor it before its next change: \
+split it by concern and put the parts that keep changing behind tests."
+        )
+    }
 }
 
 /// Default glob patterns for test file detection.
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Dependency hygiene check configuration.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::CheckLevel;
+
+/// Deps check configuration. Off unless `check` is set.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct DepsConfig {
+    /// Check level (default: off).
+    pub check: CheckLevel,
+
+    /// Flag `*` and other any-version requirements (default: true).
+    pub wildcards: bool,
+
+    /// Flag git dependencies not pinned to a commit (default: true).
+    pub unpinned_git: bool,
+
+    /// Flag packages resolved at more than one version (default: true).
+    pub duplicates: bool,
+
+    /// Flag Cargo features that enable nothing and gate no code, and `cfg`
+    /// predicates naming features the package never declares (default: true).
+    pub features: bool,
+
+    /// Packages allowed at more than one version.
+    pub allow_duplicates: Vec<String>,
+
+    /// Most dependencies a single manifest may declare, dev dependencies
+    /// included (default: no limit).
+    pub max_dependencies: Option<usize>,
+
+    /// Exclude patterns (manifests, lockfiles, and Rust sources matching
+    /// these aren't read).
+    pub exclude: Vec<String>,
+}
+
+impl Default for DepsConfig {
+    fn default() -> Self {
+        Self {
+            check: CheckLevel::Off,
+            wildcards: true,
+            unpinned_git: true,
+            duplicates: true,
+            features: true,
+            allow_duplicates: Vec::new(),
+            max_dependencies: None,
+            exclude: Vec::new(),
+        }
+    }
+}
@@ -11,7 +11,7 @@
 
 use std::time::Duration;
 
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serializer};
 
 /// Parse a duration string into a Duration.
 ///
@@ -76,6 +76,38 @@ where
     }
 }
 
+/// Format a duration in the largest unit that represents it exactly.
+pub fn format_duration(duration: Duration) -> String {
+    let ms = duration.as_millis();
+    if !ms.is_multiple_of(1000) {
+        format!("{ms}ms")
+    } else if ms >= 60_000 && ms.is_multiple_of(60_000) {
+        format!("{}m", ms / 60_000)
+    } else {
+        format!("{}s", ms / 1000)
+    }
+}
+
+/// Serialize an optional duration as a string (e.g., `"30s"`).
+pub fn serialize_option<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match duration {
+        Some(duration) => serializer.serialize_str(&format_duration(*duration)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// JSON Schema for an optional duration string.
+pub fn option_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+        "type": ["string", "null"],
+        "format": "duration",
+        "examples": ["30s"]
+    })
+}
+
 #[cfg(test)]
 #[path = "duration_tests.rs"]
 mod tests;
@@ -90,3 +90,26 @@ fn rejects_negative_duration() {
     let err = parse_duration("-5s").unwrap_err();
     assert!(err.contains("negative"));
 }
+
+#[test]
+fn formats_in_largest_exact_unit() {
+    assert_eq!(format_duration(Duration::from_millis(500)), "500ms");
+    assert_eq!(format_duration(Duration::from_millis(1500)), "1500ms");
+    assert_eq!(format_duration(Duration::from_secs(30)), "30s");
+    assert_eq!(format_duration(Duration::from_secs(120)), "2m");
+    assert_eq!(format_duration(Duration::from_secs(90)), "90s");
+}
+
+#[test]
+fn formatted_durations_parse_back() {
+    for duration in [
+        Duration::from_millis(250),
+        Duration::from_secs(5),
+        Duration::from_secs(300),
+    ] {
+        assert_eq!(
+            parse_duration(&format_duration(duration)).unwrap(),
+            duration
+        );
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Formatter status check configuration.
+
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{CheckLevel, duration};
+
+/// Format check configuration. Off unless `check` is set, since it runs
+/// external tools.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct FormatConfig {
+    /// Check level (default: off).
+    pub check: CheckLevel,
+
+    /// Formatters to run: "rustfmt", "prettier", "gofmt", "ruff".
+    /// Empty (default) detects them from project files.
+    pub formatters: Vec<String>,
+
+    /// Timeout per formatter run (kills the process if exceeded).
+    #[serde(
+        deserialize_with = "duration::deserialize_option",
+        serialize_with = "duration::serialize_option"
+    )]
+    #[schemars(schema_with = "duration::option_schema")]
+    pub timeout: Option<Duration>,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            check: CheckLevel::Off,
+            formatters: Vec::new(),
+            timeout: None,
+        }
+    }
+}
@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Git configuration: baseline storage, base commits, and commit messages.
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Git configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct GitConfig {
+    /// Baseline file path for ratcheting.
+    #[serde(default = "GitConfig::default_baseline")]
+    pub baseline: String,
+
+    /// Commit message validation settings.
+    #[serde(default)]
+    pub commit: GitCommitConfig,
+
+    /// Whether CI runs and baseline updates tolerate uncommitted changes.
+    #[serde(default)]
+    pub allow_dirty: AllowDirty,
+
+    /// How the base commit is found from the base ref (default: merge-base).
+    #[serde(default)]
+    pub base_strategy: BaseStrategy,
+}
+
+impl Default for GitConfig {
+    fn default() -> Self {
+        Self {
+            baseline: Self::default_baseline(),
+            commit: GitCommitConfig::default(),
+            allow_dirty: AllowDirty::default(),
+            base_strategy: BaseStrategy::default(),
+        }
+    }
+}
+
+/// How the base commit is computed from a base ref (`--base`, or the
+/// detected main branch in CI).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum BaseStrategy {
+    /// Where the branch and the ref's current tip diverged (default).
+    #[default]
+    MergeBase,
+    /// Where the branch forked from the ref, per the ref's reflog, so
+    /// commits the ref dropped when it was rebased don't count as changes.
+    ForkPoint,
+    /// The ref itself, so commits it gained since the branch forked show up
+    /// as changes.
+    Exact,
+}
+
+impl BaseStrategy {
+    /// Name as written in `quench.toml`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::MergeBase => "merge-base",
+            Self::ForkPoint => "fork-point",
+            Self::Exact => "exact",
+        }
+    }
+}
+
+/// Working tree policy for CI runs and baseline updates.
+///
+/// Accepts `true` (default), `false`, or `"warn"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AllowDirty {
+    /// Uncommitted changes are allowed.
+    #[default]
+    Allow,
+    /// Report uncommitted changes without failing.
+    Warn,
+    /// Fail on uncommitted changes and skip baseline updates.
+    Deny,
+}
+
+impl<'de> serde::Deserialize<'de> for AllowDirty {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Helper {
+            Bool(bool),
+            Level(String),
+        }
+
+        match Helper::deserialize(deserializer)? {
+            Helper::Bool(true) => Ok(Self::Allow),
+            Helper::Bool(false) => Ok(Self::Deny),
+            Helper::Level(level) if level == "warn" => Ok(Self::Warn),
+            Helper::Level(level) => Err(serde::de::Error::custom(format!(
+                "allow_dirty must be true, false, or \"warn\", not \"{}\"",
+                level
+            ))),
+        }
+    }
+}
+
+impl Serialize for AllowDirty {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Allow => serializer.serialize_bool(true),
+            Self::Warn => serializer.serialize_str("warn"),
+            Self::Deny => serializer.serialize_bool(false),
+        }
+    }
+}
+
+impl JsonSchema for AllowDirty {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "AllowDirty".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "`true` allows uncommitted changes, `false` fails on them, `\"warn\"` reports them.",
+            "anyOf": [{ "type": "boolean" }, { "const": "warn" }]
+        })
+    }
+}
+
+impl GitConfig {
+    fn default_baseline() -> String {
+        "notes".to_string()
+    }
+
+    /// Check if baseline is configured to use git notes.
+    pub fn uses_notes(&self) -> bool {
+        self.baseline == "notes"
+    }
+
+    /// Get baseline file path (returns None if using notes mode).
+    pub fn baseline_path(&self) -> Option<&str> {
+        if self.uses_notes() {
+            None
+        } else {
+            Some(&self.baseline)
+        }
+    }
+}
+
+/// Git commit message configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct GitCommitConfig {
+    /// Check level: "error" | "warn" | "off"
+    pub check: Option<String>,
+
+    /// Commit format: "conventional" | "none" (default: "conventional")
+    pub format: Option<String>,
+
+    /// Allowed commit types (None = use defaults, Some([]) = any type)
+    pub types: Option<Vec<String>>,
+
+    /// Allowed scopes (None = any scope allowed)
+    pub scopes: Option<Vec<String>>,
+
+    /// Check that commit format is documented in agent files (default: true)
+    pub agents: bool,
+
+    /// Create .gitmessage template with --fix (default: true)
+    pub template: bool,
+
+    /// Skip merge commits (e.g., "Merge branch 'x'") (default: true)
+    pub skip_merge: bool,
+
+    /// Check commit scopes against the packages they touch (default: off)
+    pub infer_scope: InferScope,
+
+    /// Path prefix -> scope overrides (default: one scope per package directory)
+    pub scope_paths: BTreeMap<String, String>,
+}
+
+/// How commit scopes are checked against the paths a commit changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum InferScope {
+    /// No scope inference (default).
+    #[default]
+    Off,
+    /// Report mismatched scopes as warnings.
+    Suggest,
+    /// Fail on mismatched scopes.
+    Enforce,
+}
+
+impl Default for GitCommitConfig {
+    fn default() -> Self {
+        Self {
+            check: None,
+            format: None,
+            types: None,
+            scopes: None,
+            agents: true,
+            template: true,
+            skip_merge: true,
+            infer_scope: InferScope::Off,
+            scope_paths: BTreeMap::new(),
+        }
+    }
+}
+
+impl GitCommitConfig {
+    /// Get effective format (default: "conventional").
+    pub fn effective_format(&self) -> &str {
+        self.format.as_deref().unwrap_or("conventional")
+    }
+}
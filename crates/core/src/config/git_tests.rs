@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use std::path::PathBuf;
+
+// Git skip_merge config tests
+
+#[test]
+fn git_skip_merge_defaults_to_true() {
+    let config = GitCommitConfig::default();
+    assert!(config.skip_merge);
+}
+
+#[test]
+fn git_skip_merge_can_be_disabled() {
+    let toml = r#"
+version = 1
+[git.commit]
+skip_merge = false
+"#;
+    let config: Config = parse(toml, Path::new("test.toml")).unwrap();
+    assert!(!config.git.commit.skip_merge);
+}
+
+// Git baseline config tests
+
+#[test]
+fn git_baseline_defaults_to_notes() {
+    let path = PathBuf::from("quench.toml");
+    let content = "version = 1\n";
+    let config = parse(content, &path).unwrap();
+    assert_eq!(config.git.baseline, "notes");
+    assert!(config.git.uses_notes());
+    assert!(config.git.baseline_path().is_none());
+}
+
+#[test]
+fn git_baseline_uses_notes_returns_true_for_notes() {
+    let config = GitConfig {
+        baseline: "notes".to_string(),
+        commit: GitCommitConfig::default(),
+        allow_dirty: AllowDirty::default(),
+        base_strategy: BaseStrategy::default(),
+    };
+    assert!(config.uses_notes());
+    assert!(config.baseline_path().is_none());
+}
+
+#[test]
+fn git_baseline_uses_notes_returns_false_for_file_path() {
+    let config = GitConfig {
+        baseline: ".quench/baseline.json".to_string(),
+        commit: GitCommitConfig::default(),
+        allow_dirty: AllowDirty::default(),
+        base_strategy: BaseStrategy::default(),
+    };
+    assert!(!config.uses_notes());
+    assert_eq!(config.baseline_path(), Some(".quench/baseline.json"));
+}
+
+#[test]
+fn git_baseline_can_be_set_to_file_path() {
+    let path = PathBuf::from("quench.toml");
+    let content = r#"
+version = 1
+
+[git]
+baseline = ".quench/baseline.json"
+"#;
+    let config = parse(content, &path).unwrap();
+    assert_eq!(config.git.baseline, ".quench/baseline.json");
+    assert!(!config.git.uses_notes());
+    assert_eq!(config.git.baseline_path(), Some(".quench/baseline.json"));
+}
+
+#[test]
+fn git_allow_dirty_accepts_bool_or_warn() {
+    let path = PathBuf::from("quench.toml");
+    let parse_dirty = |value: &str| {
+        let content = format!("version = 1\n[git]\nallow_dirty = {}\n", value);
+        parse(&content, &path).map(|c| c.git.allow_dirty)
+    };
+
+    assert_eq!(
+        parse("version = 1\n", &path).unwrap().git.allow_dirty,
+        AllowDirty::Allow
+    );
+    assert_eq!(parse_dirty("true").unwrap(), AllowDirty::Allow);
+    assert_eq!(parse_dirty("false").unwrap(), AllowDirty::Deny);
+    assert_eq!(parse_dirty("\"warn\"").unwrap(), AllowDirty::Warn);
+    assert!(parse_dirty("\"error\"").is_err());
+}
+
+#[test]
+fn git_base_strategy_defaults_to_merge_base() {
+    let path = PathBuf::from("quench.toml");
+    let parse_strategy = |value: &str| {
+        let content = format!("version = 1\n[git]\nbase_strategy = \"{}\"\n", value);
+        parse(&content, &path).map(|c| c.git.base_strategy)
+    };
+
+    assert_eq!(
+        parse("version = 1\n", &path).unwrap().git.base_strategy,
+        BaseStrategy::MergeBase
+    );
+    assert_eq!(
+        parse_strategy("fork-point").unwrap(),
+        BaseStrategy::ForkPoint
+    );
+    assert_eq!(parse_strategy("exact").unwrap(), BaseStrategy::Exact);
+    assert!(parse_strategy("merge_base").is_err());
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Goal target configuration.
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Target values the ratchet is working toward (`[goals]`).
+///
+/// ```toml
+/// [goals]
+/// coverage.target = 85
+/// escapes.unwrap.target = 0
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct GoalsConfig {
+    /// Overall coverage percentage goal.
+    pub coverage: Option<GoalConfig>,
+
+    /// Source escape count goals by pattern name.
+    pub escapes: BTreeMap<String, GoalConfig>,
+}
+
+/// A single goal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GoalConfig {
+    /// Value to reach.
+    pub target: f64,
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Health score configuration.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Health score model (`[health]`). Unset values use the defaults in
+/// [`crate::health`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct HealthConfig {
+    /// Overall coverage percentage.
+    pub coverage: HealthMetricConfig,
+
+    /// Source escapes per 1000 source lines.
+    pub escapes: HealthMetricConfig,
+
+    /// Linter diagnostics per 1000 source lines.
+    pub lint: HealthMetricConfig,
+
+    /// Cold build time in seconds.
+    pub build_time: HealthMetricConfig,
+
+    /// Total test time in seconds.
+    pub test_time: HealthMetricConfig,
+}
+
+/// How one metric contributes to the health score.
+///
+/// Accepts either a bare weight or the full form:
+/// - `coverage = 3`
+/// - `coverage = { weight = 3, good = 90, bad = 50 }`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, JsonSchema)]
+pub struct HealthMetricConfig {
+    /// Relative weight (0 leaves the metric out).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+
+    /// Value that scores 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub good: Option<f64>,
+
+    /// Value that scores 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bad: Option<f64>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+enum HealthMetricHelper {
+    Weight(f64),
+    Full {
+        weight: Option<f64>,
+        good: Option<f64>,
+        bad: Option<f64>,
+    },
+}
+
+impl<'de> Deserialize<'de> for HealthMetricConfig {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(match HealthMetricHelper::deserialize(deserializer)? {
+            HealthMetricHelper::Weight(weight) => Self {
+                weight: Some(weight),
+                good: None,
+                bad: None,
+            },
+            HealthMetricHelper::Full { weight, good, bad } => Self { weight, good, bad },
+        })
+    }
+}
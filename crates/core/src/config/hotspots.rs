@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Churn hotspot configuration.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::CheckLevel;
+
+/// Files that change often and are large (`[hotspots]`).
+///
+/// ```toml
+/// [hotspots]
+/// window = 90     # Days of git history
+/// churn = 10      # Commits within the window
+/// lines = 400     # File size
+/// check = "warn"  # Flag hotspots in the cloc check
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct HotspotsConfig {
+    /// Days of git history counted as churn (default: 90).
+    pub window: u32,
+
+    /// Commits within the window that make a file high-churn (default: 10).
+    pub churn: usize,
+
+    /// Lines, per `[check.cloc] metric`, that make a file complex (default: 400).
+    pub lines: usize,
+
+    /// Level for hotspot violations in the cloc check (default: off).
+    pub check: CheckLevel,
+}
+
+impl Default for HotspotsConfig {
+    fn default() -> Self {
+        Self {
+            window: 90,
+            churn: 10,
+            lines: 400,
+            check: CheckLevel::Off,
+        }
+    }
+}
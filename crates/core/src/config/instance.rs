@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Named check instances (`[check."<check>:<name>"]`).
+//!
+//! An instance runs a built-in check again with its own settings and file
+//! scope. Its section inherits `[check.<check>]` and overrides individual
+//! keys; `paths` limits the files the instance sees.
+
+use schemars::JsonSchema;
+use toml::{Table, Value};
+
+use super::Config;
+use crate::checks::CHECK_NAMES;
+
+/// Separator between the check name and the instance name.
+pub const INSTANCE_SEPARATOR: char = ':';
+
+/// A named instance of a built-in check.
+#[derive(Debug)]
+pub struct CheckInstance {
+    /// Name reported in output (e.g., "cloc:scripts").
+    pub name: String,
+    /// Built-in check the instance runs (e.g., "cloc").
+    pub check: String,
+    /// Globs limiting the files the instance sees (empty = all files).
+    pub paths: Vec<String>,
+    /// The check's section merged with the instance's keys.
+    pub section: Table,
+    /// The project config with the check's section replaced by the
+    /// instance's merged section.
+    pub config: Box<Config>,
+}
+
+/// Keys an instance section adds to its check's keys. Instances are read
+/// from the raw table, so this type only describes them for the config
+/// reference.
+#[derive(JsonSchema)]
+pub struct InstanceSection {
+    /// Globs limiting the files the instance sees (default: all files).
+    /// Other keys override the check's `[check.<check>]` settings.
+    pub paths: Vec<String>,
+}
+
+/// Remove instance sections from `[check]`, in key order.
+pub(super) fn extract(root: &mut Table) -> Result<Vec<(String, Table)>, String> {
+    let Some(Value::Table(check)) = root.get_mut("check") else {
+        return Ok(Vec::new());
+    };
+    let keys: Vec<String> = check
+        .keys()
+        .filter(|key| key.contains(INSTANCE_SEPARATOR))
+        .cloned()
+        .collect();
+    keys.into_iter()
+        .map(|key| match check.remove(&key) {
+            Some(Value::Table(section)) => Ok((key, section)),
+            _ => Err(format!("[check.\"{}\"] must be a table", key)),
+        })
+        .collect()
+}
+
+/// Build each instance's config from the project table (without instance
+/// sections) and the instance's own section.
+pub(super) fn build(
+    root: &Table,
+    sections: Vec<(String, Table)>,
+) -> Result<Vec<CheckInstance>, String> {
+    sections
+        .into_iter()
+        .map(|(name, section)| build_one(root, name, section))
+        .collect()
+}
+
+fn build_one(root: &Table, name: String, mut section: Table) -> Result<CheckInstance, String> {
+    let Some((check, instance)) = name.split_once(INSTANCE_SEPARATOR) else {
+        return Err(format!("invalid check instance '{}'", name));
+    };
+    if !CHECK_NAMES.contains(&check) {
+        return Err(format!(
+            "unknown check '{}' in instance '{}' (expected one of: {})",
+            check,
+            name,
+            CHECK_NAMES.join(", ")
+        ));
+    }
+    if instance.is_empty() || instance.contains(INSTANCE_SEPARATOR) {
+        return Err(format!(
+            "invalid check instance '{}': expected <check>:<name>",
+            name
+        ));
+    }
+
+    let paths = match section.remove("paths") {
+        Some(value) => value
+            .try_into::<Vec<String>>()
+            .map_err(|e| format!("check instance '{}': paths: {}", name, e))?,
+        None => Vec::new(),
+    };
+
+    // Instance keys replace the base section's keys
+    let mut merged = root.clone();
+    let checks = merged
+        .entry("check")
+        .or_insert_with(|| Value::Table(Table::new()));
+    let mut merged_section = Table::new();
+    if let Value::Table(checks) = checks {
+        let base = checks
+            .entry(check)
+            .or_insert_with(|| Value::Table(Table::new()));
+        if let Value::Table(base) = base {
+            base.extend(section);
+            merged_section = base.clone();
+        }
+    }
+    let config: Config = Value::Table(merged)
+        .try_into()
+        .map_err(|e| format!("check instance '{}': {}", name, e))?;
+
+    Ok(CheckInstance {
+        check: check.to_string(),
+        name,
+        paths,
+        section: merged_section,
+        config: Box::new(config),
+    })
+}
@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use std::path::PathBuf;
+
+fn parse_config(content: &str) -> Result<Config> {
+    parse(content, &PathBuf::from("quench.toml"))
+}
+
+#[test]
+fn config_without_instances_has_none() {
+    let config = parse_config("version = 1\n[check.cloc]\nmax_lines = 500\n").unwrap();
+    assert!(config.instances.is_empty());
+    assert_eq!(config.check.cloc.max_lines, 500);
+}
+
+#[test]
+fn instance_inherits_base_section_and_overrides_keys() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[check.cloc]
+max_lines = 500
+exclude = ["scripts/**"]
+max_lines_test = 900
+
+[check."cloc:scripts"]
+paths = ["scripts/**"]
+max_lines = 200
+exclude = []
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.check.cloc.max_lines, 500);
+    assert_eq!(config.instances.len(), 1);
+    let instance = &config.instances[0];
+    assert_eq!(instance.name, "cloc:scripts");
+    assert_eq!(instance.check, "cloc");
+    assert_eq!(instance.paths, ["scripts/**"]);
+    assert_eq!(instance.config.check.cloc.max_lines, 200);
+    assert_eq!(instance.config.check.cloc.max_lines_test, 900);
+    assert!(instance.config.check.cloc.exclude.is_empty());
+    assert!(instance.config.instances.is_empty());
+}
+
+#[test]
+fn instance_without_base_section_uses_defaults() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[check."escapes:vendor"]
+check = "warn"
+"#,
+    )
+    .unwrap();
+
+    let instance = &config.instances[0];
+    assert_eq!(instance.config.check.escapes.check, CheckLevel::Warn);
+    assert_eq!(config.check.escapes.check, CheckLevel::default());
+    assert!(instance.paths.is_empty());
+}
+
+#[test]
+fn instances_are_ordered_by_name() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[check."cloc:tools"]
+max_lines = 300
+
+[check."cloc:scripts"]
+max_lines = 200
+"#,
+    )
+    .unwrap();
+
+    let names: Vec<_> = config.instances.iter().map(|i| i.name.as_str()).collect();
+    assert_eq!(names, ["cloc:scripts", "cloc:tools"]);
+}
+
+#[test]
+fn instance_of_unknown_check_is_rejected() {
+    let err = parse_config("version = 1\n[check.\"style:scripts\"]\n").unwrap_err();
+    assert!(err.to_string().contains("unknown check 'style'"));
+}
+
+#[test]
+fn instance_without_name_is_rejected() {
+    let err = parse_config("version = 1\n[check.\"cloc:\"]\n").unwrap_err();
+    assert!(err.to_string().contains("expected <check>:<name>"));
+}
+
+#[test]
+fn instance_unknown_field_is_rejected() {
+    let err = parse_config("version = 1\n[check.\"cloc:scripts\"]\nmax_line = 5\n").unwrap_err();
+    let message = err.to_string();
+    assert!(
+        message.contains("check instance 'cloc:scripts'"),
+        "{message}"
+    );
+    assert!(message.contains("max_line"), "{message}");
+}
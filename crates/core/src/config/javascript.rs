@@ -3,13 +3,14 @@
 
 //! JavaScript/TypeScript language-specific configuration.
 
-use serde::Deserialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 use super::lang_common::{LanguageDefaults, define_policy_config};
 use super::{CheckLevel, LangClocConfig, LintChangesPolicy, SuppressLevel, SuppressScopeConfig};
 
 /// JavaScript/TypeScript language-specific configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct JavaScriptConfig {
     /// Source file patterns.
@@ -57,7 +58,7 @@ impl Default for JavaScriptConfig {
 }
 
 /// JavaScript/TypeScript lint suppression configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct JavaScriptSuppressConfig {
     /// Check level: forbid, comment, or allow (default: "comment").
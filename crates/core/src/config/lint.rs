@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! External linter check configuration.
+
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{CheckLevel, duration};
+
+/// Lint check configuration. Off unless `check` is set, since it runs
+/// external tools.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct LintConfig {
+    /// Check level (default: off).
+    pub check: CheckLevel,
+
+    /// Linters to run: "clippy", "eslint", "golangci-lint", "ruff".
+    /// Empty (default) detects them from project files.
+    pub linters: Vec<String>,
+
+    /// Timeout per linter run (kills the process if exceeded).
+    #[serde(
+        deserialize_with = "duration::deserialize_option",
+        serialize_with = "duration::serialize_option"
+    )]
+    #[schemars(schema_with = "duration::option_schema")]
+    pub timeout: Option<Duration>,
+
+    /// Report diagnostics as warnings and leave enforcement to the ratchet,
+    /// so existing findings don't fail the check but new ones do.
+    pub grandfather: bool,
+
+    /// Exclude patterns (diagnostics in matching files are dropped).
+    pub exclude: Vec<String>,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            check: CheckLevel::Off,
+            linters: Vec::new(),
+            timeout: None,
+            grandfather: false,
+            exclude: Vec::new(),
+        }
+    }
+}
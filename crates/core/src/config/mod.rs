@@ -5,23 +5,48 @@
 //!
 //! Handles quench.toml parsing with version validation and unknown key warnings.
 
+mod advice;
 mod checks;
+mod deadcode;
 pub mod defaults;
+mod deps;
 pub mod duration;
+mod format;
+mod git;
 mod go;
+mod goals;
+mod health;
+mod hotspots;
+mod instance;
 mod javascript;
 mod lang_common;
+mod lint;
+mod nested;
+mod new_code;
 mod python;
+mod quarantine;
 mod ratchet;
+pub mod reference;
+mod release;
 mod ruby;
+mod rust;
+mod sample;
+mod scope;
 mod shell;
+mod snapshots;
+mod store;
 mod suppress;
+mod telemetry;
 mod test_config;
+mod tools;
+mod whitespace;
 
 use std::path::Path;
 
-use serde::Deserialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
+pub use advice::AdviceConfig;
 pub use checks::CheckLevel;
 
 use crate::error::{Error, Result};
@@ -30,18 +55,38 @@ pub(crate) use checks::{
     ClocConfig, DocsAreaConfig, DocsCommitConfig, DocsConfig, EscapeAction, EscapePattern,
     EscapesConfig, LangClocConfig, LineMetric, SpecsConfig, SpecsSectionsConfig,
 };
+pub use deadcode::DeadcodeConfig;
+pub use deps::DepsConfig;
+pub use format::FormatConfig;
+pub use git::{AllowDirty, BaseStrategy, GitCommitConfig, GitConfig, InferScope};
 pub(crate) use go::{GoConfig, GoPolicyConfig, GoSuppressConfig};
+pub use goals::{GoalConfig, GoalsConfig};
+pub use health::{HealthConfig, HealthMetricConfig};
+pub use hotspots::HotspotsConfig;
+pub use instance::{CheckInstance, INSTANCE_SEPARATOR, InstanceSection};
 pub(crate) use javascript::{JavaScriptConfig, JavaScriptPolicyConfig, JavaScriptSuppressConfig};
+pub use lint::LintConfig;
+pub use new_code::{NewCodeConfig, NewCodeSince};
 pub(crate) use python::{PythonConfig, PythonPolicyConfig, PythonSuppressConfig};
-pub(crate) use ratchet::RatchetConfig;
+pub use quarantine::QuarantineConfig;
+pub use ratchet::RatchetConfig;
 #[cfg(test)]
 pub(crate) use ratchet::RatchetPackageConfig;
+pub use release::ReleaseConfig;
 pub(crate) use ruby::{RubyConfig, RubyPolicyConfig, RubySuppressConfig};
+pub use rust::{CfgTestSplitMode, RustConfig, RustDefaults, RustPolicyConfig};
+pub use sample::Sample;
+pub use scope::{PathScope, SCOPED_CHECKS, ScopeSection};
 pub(crate) use shell::{ShellConfig, ShellPolicyConfig, ShellSuppressConfig};
+pub use snapshots::SnapshotsConfig;
+pub use store::{StoreBackend, StoreConfig};
 pub(crate) use suppress::{SuppressConfig, SuppressLevel, SuppressScopeConfig};
-#[cfg(test)]
-pub(crate) use test_config::TestsCommitConfig;
+pub use telemetry::TelemetryConfig;
 pub(crate) use test_config::{TestSuiteConfig, TestsConfig};
+#[cfg(test)]
+pub(crate) use test_config::{TestsCommitConfig, TestsPackageCoverageConfig};
+pub use tools::{ToolMismatch, ToolsConfig};
+pub use whitespace::{IndentStyle, LineEndings, WhitespaceConfig};
 
 pub(crate) use crate::checks::agents::config::{
     AgentsConfig, ContentRule, RequiredSection, SectionsConfig, deserialize_optional_usize,
@@ -54,7 +99,7 @@ struct VersionOnly {
 }
 
 /// Full configuration.
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
     /// Config file version (must be 1).
@@ -99,95 +144,55 @@ pub struct Config {
     /// Shell-specific configuration.
     #[serde(default)]
     pub shell: ShellConfig,
-}
-
-/// Git configuration.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(default, deny_unknown_fields)]
-pub struct GitConfig {
-    /// Baseline file path for ratcheting.
-    #[serde(default = "GitConfig::default_baseline")]
-    pub baseline: String,
 
-    /// Commit message validation settings.
+    /// Per-rule enablement by rule ID (e.g., `"cloc/file-too-large" = false`).
     #[serde(default)]
-    pub commit: GitCommitConfig,
-}
-
-impl Default for GitConfig {
-    fn default() -> Self {
-        Self {
-            baseline: Self::default_baseline(),
-            commit: GitCommitConfig::default(),
-        }
-    }
-}
+    #[schemars(extend("additionalProperties" = { "title": "<rule>", "type": "boolean" }))]
+    pub rules: std::collections::BTreeMap<String, bool>,
 
-impl GitConfig {
-    fn default_baseline() -> String {
-        "notes".to_string()
-    }
+    /// Experimental directories whose violations only warn until they expire.
+    #[serde(default)]
+    pub quarantine: Vec<QuarantineConfig>,
 
-    /// Check if baseline is configured to use git notes.
-    pub fn uses_notes(&self) -> bool {
-        self.baseline == "notes"
-    }
+    /// Strict rules for new files only (`[new_code]`).
+    #[serde(default)]
+    pub new_code: NewCodeConfig,
 
-    /// Get baseline file path (returns None if using notes mode).
-    pub fn baseline_path(&self) -> Option<&str> {
-        if self.uses_notes() {
-            None
-        } else {
-            Some(&self.baseline)
-        }
-    }
-}
+    /// Pinned tool versions (`[tools]`).
+    #[serde(default)]
+    pub tools: ToolsConfig,
 
-/// Git commit message configuration.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(default, deny_unknown_fields)]
-pub struct GitCommitConfig {
-    /// Check level: "error" | "warn" | "off"
-    pub check: Option<String>,
+    /// Advice overrides by rule ID (`[advice]`).
+    #[serde(default)]
+    pub advice: AdviceConfig,
 
-    /// Commit format: "conventional" | "none" (default: "conventional")
-    pub format: Option<String>,
+    /// Opt-in anonymized run summaries (`[telemetry]`).
+    #[serde(default)]
+    pub telemetry: TelemetryConfig,
 
-    /// Allowed commit types (None = use defaults, Some([]) = any type)
-    pub types: Option<Vec<String>>,
+    /// Health score weights and scales (`[health]`).
+    #[serde(default)]
+    pub health: HealthConfig,
 
-    /// Allowed scopes (None = any scope allowed)
-    pub scopes: Option<Vec<String>>,
+    /// Target values tracked in reports (`[goals]`).
+    #[serde(default)]
+    pub goals: GoalsConfig,
 
-    /// Check that commit format is documented in agent files (default: true)
-    pub agents: bool,
+    /// Churn and size thresholds for hotspots (`[hotspots]`).
+    #[serde(default)]
+    pub hotspots: HotspotsConfig,
 
-    /// Create .gitmessage template with --fix (default: true)
-    pub template: bool,
+    /// Backend for run history, per-file results, and baselines (`[store]`).
+    #[serde(default)]
+    pub store: StoreConfig,
 
-    /// Skip merge commits (e.g., "Merge branch 'x'") (default: true)
-    pub skip_merge: bool,
-}
+    /// Named check instances (`[check."<check>:<name>"]`), in name order.
+    #[serde(skip)]
+    pub instances: Vec<CheckInstance>,
 
-impl Default for GitCommitConfig {
-    fn default() -> Self {
-        Self {
-            check: None,
-            format: None,
-            types: None,
-            scopes: None,
-            agents: true,
-            template: true,
-            skip_merge: true,
-        }
-    }
-}
-
-impl GitCommitConfig {
-    /// Get effective format (default: "conventional").
-    pub fn effective_format(&self) -> &str {
-        self.format.as_deref().unwrap_or("conventional")
-    }
+    /// Path-scoped check policies (`[scope."<glob>"]`), in glob order.
+    #[serde(skip)]
+    pub scopes: Vec<PathScope>,
 }
 
 /// Identify a language from an adapter name or file extension.
@@ -314,142 +319,8 @@ impl Config {
     }
 }
 
-/// Mode for handling #[cfg(test)] blocks in Rust files.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum CfgTestSplitMode {
-    /// Split #[cfg(test)] blocks into test LOC (default).
-    #[default]
-    Count,
-    /// Fail if source files contain inline #[cfg(test)] blocks.
-    Require,
-    /// Count all lines as source LOC, don't parse for #[cfg(test)].
-    Off,
-}
-
-use lang_common::{LanguageDefaults, define_policy_config};
-
-/// Rust language-specific configuration.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct RustConfig {
-    /// Source file patterns.
-    #[serde(default = "RustDefaults::default_source")]
-    pub source: Vec<String>,
-
-    /// Test file patterns.
-    #[serde(default = "RustDefaults::default_tests")]
-    pub tests: Vec<String>,
-
-    /// Exclude patterns (walker-level: prevents I/O on subtrees).
-    #[serde(default = "RustDefaults::default_exclude", alias = "ignore")]
-    pub exclude: Vec<String>,
-
-    /// How to handle #[cfg(test)] blocks (default: "count").
-    #[serde(default)]
-    pub cfg_test_split: CfgTestSplitMode,
-
-    /// Lint suppression settings.
-    #[serde(default)]
-    pub suppress: SuppressConfig,
-
-    /// Lint configuration policy.
-    #[serde(default)]
-    pub policy: RustPolicyConfig,
-
-    /// Per-language cloc settings.
-    #[serde(default)]
-    pub cloc: Option<LangClocConfig>,
-
-    /// Custom cloc advice for source files (None = use generic default).
-    /// Note: Deprecated in favor of cloc.advice.
-    #[serde(default)]
-    pub cloc_advice: Option<String>,
-}
-
-impl Default for RustConfig {
-    fn default() -> Self {
-        Self {
-            source: RustDefaults::default_source(),
-            tests: RustDefaults::default_tests(),
-            exclude: RustDefaults::default_exclude(),
-            cfg_test_split: CfgTestSplitMode::default(),
-            suppress: SuppressConfig::default(),
-            policy: RustPolicyConfig::default(),
-            cloc: None,
-            cloc_advice: None,
-        }
-    }
-}
-
-/// Rust language defaults.
-pub struct RustDefaults;
-
-impl LanguageDefaults for RustDefaults {
-    fn default_source() -> Vec<String> {
-        vec!["**/*.rs".to_string()]
-    }
-
-    fn default_tests() -> Vec<String> {
-        vec![
-            "**/tests/**".to_string(),
-            "**/test/**/*.rs".to_string(),
-            "**/benches/**".to_string(),
-            "**/*_test.rs".to_string(),
-            "**/*_tests.rs".to_string(),
-            "**/*_tests/**".to_string(),
-        ]
-    }
-
-    fn default_exclude() -> Vec<String> {
-        vec!["target/**".to_string()]
-    }
-
-    fn default_cloc_advice(threshold: usize) -> String {
-        let range = defaults::advice::target_range(threshold);
-        format!(
-            "First, look for repetitive patterns that could be extracted into helper \
-functions, or refactor to be more unit testable and concise.\n\
-\n\
-Then split into sibling modules or submodules in a folder by semantic concern \
-(target {range} each).\n\
-\n\
-Avoid removing individual lines to satisfy the linter; \
-prefer extracting testable code blocks."
-        )
-    }
-}
-
-impl RustConfig {
-    pub(crate) fn default_source() -> Vec<String> {
-        RustDefaults::default_source()
-    }
-
-    pub(crate) fn default_tests() -> Vec<String> {
-        RustDefaults::default_tests()
-    }
-
-    pub(crate) fn default_exclude() -> Vec<String> {
-        RustDefaults::default_exclude()
-    }
-
-    pub(crate) fn default_cloc_advice(threshold: usize) -> String {
-        RustDefaults::default_cloc_advice(threshold)
-    }
-}
-
-define_policy_config!(
-    RustPolicyConfig,
-    [
-        "rustfmt.toml",
-        ".rustfmt.toml",
-        "clippy.toml",
-        ".clippy.toml",
-    ]
-);
-
 /// Lint changes policy.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum LintChangesPolicy {
     /// No policy - mixed changes allowed.
@@ -460,7 +331,7 @@ pub enum LintChangesPolicy {
 }
 
 /// Check-specific configurations.
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct CheckConfig {
     /// Cloc (count lines of code) check configuration.
@@ -490,10 +361,51 @@ pub struct CheckConfig {
     /// Build check configuration.
     #[serde(default)]
     pub build: BuildConfig,
+
+    /// Whitespace hygiene check configuration.
+    #[serde(default)]
+    pub whitespace: WhitespaceConfig,
+
+    /// Formatter status check configuration.
+    #[serde(default)]
+    pub format: FormatConfig,
+
+    /// External linter check configuration.
+    #[serde(default)]
+    pub lint: LintConfig,
+
+    /// Snapshot test management check configuration.
+    #[serde(default)]
+    pub snapshots: SnapshotsConfig,
+
+    /// Unused public item check configuration.
+    #[serde(default)]
+    pub deadcode: DeadcodeConfig,
+
+    /// Dependency hygiene check configuration.
+    #[serde(default)]
+    pub deps: DepsConfig,
+
+    /// Release readiness check configuration (`--release-mode`).
+    #[serde(default)]
+    pub release: ReleaseConfig,
+}
+
+impl CheckConfig {
+    /// Sample rate of a check that supports `sample`, when set.
+    pub fn sample(&self, check: &str) -> Option<Sample> {
+        match check {
+            "cloc" => self.cloc.sample,
+            "escapes" => self.escapes.sample,
+            "license" => self.license.sample,
+            "whitespace" => self.whitespace.sample,
+            _ => None,
+        }
+    }
 }
 
 /// License check configuration.
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct LicenseConfig {
     /// Check level: "error" | "warn" | "off"
@@ -513,10 +425,15 @@ pub struct LicenseConfig {
     /// Exclude patterns (files matching these won't be checked).
     #[serde(default)]
     pub exclude: Vec<String>,
+
+    /// Share of files to check per fast-mode run (e.g., "10%"); CI mode
+    /// checks every file.
+    #[serde(default)]
+    pub sample: Option<Sample>,
 }
 
 /// Build check configuration.
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct BuildConfig {
     /// Check level: "error" | "warn" | "off"
@@ -535,13 +452,16 @@ pub struct BuildConfig {
     /// Maximum hot build time (e.g., "5s").
     pub time_hot_max: Option<String>,
 
+    /// Container image to build in (e.g., "rust:1.85").
+    pub container: Option<String>,
+
     /// Per-target configuration.
     #[serde(default)]
     pub target: std::collections::HashMap<String, BuildTargetConfig>,
 }
 
 /// Per-target build configuration.
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct BuildTargetConfig {
     /// Maximum binary size for this target.
@@ -549,7 +469,7 @@ pub struct BuildTargetConfig {
 }
 
 /// Project-level configuration.
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ProjectConfig {
     /// Project name.
@@ -571,13 +491,49 @@ pub struct ProjectConfig {
     #[serde(default, alias = "ignore")]
     pub exclude: ExcludeConfig,
 
+    /// Skip files ignored by `.gitignore` (default: true).
+    #[serde(default = "ProjectConfig::default_gitignore")]
+    pub gitignore: bool,
+
+    /// Ignore files in gitignore syntax, read in every directory
+    /// (default: [".ignore", ".quenchignore"]).
+    #[serde(default = "ProjectConfig::default_ignore_files")]
+    pub ignore_files: Vec<String>,
+
     /// Package name lookup (path -> name).
     /// Auto-populated when detecting workspaces; not user-configurable.
     #[serde(default, skip_serializing)]
     pub package_names: std::collections::HashMap<String, String>,
 }
 
+impl Default for ProjectConfig {
+    fn default() -> Self {
+        Self {
+            name: None,
+            source: Vec::new(),
+            // Empty: checks fall back to their language's test patterns
+            tests: Vec::new(),
+            packages: Vec::new(),
+            exclude: ExcludeConfig::default(),
+            gitignore: Self::default_gitignore(),
+            ignore_files: Self::default_ignore_files(),
+            package_names: std::collections::HashMap::new(),
+        }
+    }
+}
+
 impl ProjectConfig {
+    fn default_gitignore() -> bool {
+        true
+    }
+
+    fn default_ignore_files() -> Vec<String> {
+        crate::walker::DEFAULT_IGNORE_FILES
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
     /// Default test patterns matching common conventions.
     fn default_test_patterns() -> Vec<String> {
         vec![
@@ -598,7 +554,8 @@ impl ProjectConfig {
 /// Accepts either shorthand or full form:
 /// - `exclude = ["pattern1", "pattern2"]`
 /// - `exclude = { patterns = ["pattern1", "pattern2"] }`
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, JsonSchema)]
+#[serde(transparent)]
 pub struct ExcludeConfig {
     pub patterns: Vec<String>,
 }
@@ -627,13 +584,20 @@ impl<'de> serde::Deserialize<'de> for ExcludeConfig {
 pub const SUPPORTED_VERSION: i64 = 1;
 
 /// Load and validate config from a file path.
+///
+/// Nested override files under the config's directory become scopes for
+/// their directories (see [`nested`]).
 pub fn load(path: &Path) -> Result<Config> {
     let content = std::fs::read_to_string(path).map_err(|e| Error::Io {
         path: path.to_path_buf(),
         source: e,
     })?;
 
-    parse(&content, path)
+    let root = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    parse_in(&content, path, Some(root))
 }
 
 /// Load config with warnings for unknown keys.
@@ -646,6 +610,11 @@ pub fn load_with_warnings(path: &Path) -> Result<Config> {
 
 /// Parse config from string content (strict mode).
 pub fn parse(content: &str, path: &Path) -> Result<Config> {
+    parse_in(content, path, None)
+}
+
+/// Parse config, merging nested override files under `root` when given.
+fn parse_in(content: &str, path: &Path, root: Option<&Path>) -> Result<Config> {
     // First check version
     let version_check: VersionOnly = toml::from_str(content).map_err(|e| Error::Config {
         message: e.to_string(),
@@ -667,11 +636,30 @@ pub fn parse(content: &str, path: &Path) -> Result<Config> {
         });
     }
 
-    // Parse full config
-    toml::from_str(content).map_err(|e| Error::Config {
-        message: e.to_string(),
+    let config_error = |message: String| Error::Config {
+        message,
         path: Some(path.to_path_buf()),
-    })
+    };
+    let mut table: toml::Table =
+        toml::from_str(content).map_err(|e| config_error(e.to_string()))?;
+    let sections = instance::extract(&mut table).map_err(config_error)?;
+    let scopes = scope::extract(&mut table).map_err(config_error)?;
+
+    // Parse full config (from the source when possible, for error positions)
+    let mut config: Config = if sections.is_empty() && scopes.is_empty() {
+        toml::from_str(content).map_err(|e| config_error(e.to_string()))?
+    } else {
+        toml::Value::Table(table.clone())
+            .try_into()
+            .map_err(|e: toml::de::Error| config_error(e.to_string()))?
+    };
+    config.instances = instance::build(&table, sections).map_err(config_error)?;
+    config.scopes = scope::build(&table, scopes).map_err(config_error)?;
+    if let Some(root) = root {
+        let nested = nested::load(root, &table, &config.project)?;
+        config.scopes.extend(nested);
+    }
+    Ok(config)
 }
 
 /// Parse config with warnings for unknown keys.
@@ -706,10 +694,18 @@ mod defaults_tests;
 #[path = "ratchet_tests.rs"]
 mod ratchet_tests;
 
+#[cfg(test)]
+#[path = "git_tests.rs"]
+mod git_tests;
+
 #[cfg(test)]
 #[path = "go_tests.rs"]
 mod go_tests;
 
+#[cfg(test)]
+#[path = "instance_tests.rs"]
+mod instance_tests;
+
 #[cfg(test)]
 #[path = "javascript_tests.rs"]
 mod javascript_tests;
@@ -722,6 +718,14 @@ mod python_tests;
 #[path = "ruby_tests.rs"]
 mod ruby_tests;
 
+#[cfg(test)]
+#[path = "scope_tests.rs"]
+mod scope_tests;
+
+#[cfg(test)]
+#[path = "sections_tests.rs"]
+mod sections_tests;
+
 #[cfg(test)]
 #[path = "shell_tests.rs"]
 mod shell_tests;
@@ -27,6 +27,23 @@ name = "test-project"
     assert_eq!(config.project.name, Some("test-project".to_string()));
 }
 
+#[test]
+fn project_ignore_files_default_and_override() {
+    let path = PathBuf::from("quench.toml");
+    let config = parse("version = 1\n[project]\nname = \"p\"\n", &path).unwrap();
+    assert!(config.project.gitignore);
+    assert_eq!(config.project.ignore_files, [".ignore", ".quenchignore"]);
+    assert_eq!(
+        Config::default().project.ignore_files,
+        config.project.ignore_files
+    );
+
+    let content = "version = 1\n[project]\ngitignore = false\nignore_files = [\".quenchignore\"]\n";
+    let config = parse(content, &path).unwrap();
+    assert!(!config.project.gitignore);
+    assert_eq!(config.project.ignore_files, [".quenchignore"]);
+}
+
 #[test]
 fn rejects_missing_version() {
     let path = PathBuf::from("quench.toml");
@@ -803,25 +820,6 @@ check = "error"
     );
 }
 
-// Git skip_merge config tests
-
-#[test]
-fn git_skip_merge_defaults_to_true() {
-    let config = GitCommitConfig::default();
-    assert!(config.skip_merge);
-}
-
-#[test]
-fn git_skip_merge_can_be_disabled() {
-    let toml = r#"
-version = 1
-[git.commit]
-skip_merge = false
-"#;
-    let config: Config = parse(toml, Path::new("test.toml")).unwrap();
-    assert!(!config.git.commit.skip_merge);
-}
-
 // Test suite configuration tests
 
 #[test]
@@ -983,50 +981,3 @@ check = "off"
     let config = parse(content, &path).unwrap();
     assert_eq!(config.check.tests.time.check, "off");
 }
-
-// Git baseline config tests
-
-#[test]
-fn git_baseline_defaults_to_notes() {
-    let path = PathBuf::from("quench.toml");
-    let content = "version = 1\n";
-    let config = parse(content, &path).unwrap();
-    assert_eq!(config.git.baseline, "notes");
-    assert!(config.git.uses_notes());
-    assert!(config.git.baseline_path().is_none());
-}
-
-#[test]
-fn git_baseline_uses_notes_returns_true_for_notes() {
-    let config = GitConfig {
-        baseline: "notes".to_string(),
-        commit: GitCommitConfig::default(),
-    };
-    assert!(config.uses_notes());
-    assert!(config.baseline_path().is_none());
-}
-
-#[test]
-fn git_baseline_uses_notes_returns_false_for_file_path() {
-    let config = GitConfig {
-        baseline: ".quench/baseline.json".to_string(),
-        commit: GitCommitConfig::default(),
-    };
-    assert!(!config.uses_notes());
-    assert_eq!(config.baseline_path(), Some(".quench/baseline.json"));
-}
-
-#[test]
-fn git_baseline_can_be_set_to_file_path() {
-    let path = PathBuf::from("quench.toml");
-    let content = r#"
-version = 1
-
-[git]
-baseline = ".quench/baseline.json"
-"#;
-    let config = parse(content, &path).unwrap();
-    assert_eq!(config.git.baseline, ".quench/baseline.json");
-    assert!(!config.git.uses_notes());
-    assert_eq!(config.git.baseline_path(), Some(".quench/baseline.json"));
-}
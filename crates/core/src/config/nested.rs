@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Nested config files that override the project config for a directory.
+//!
+//! A `quench.override.toml`, or a `quench.toml` without `version`, below the
+//! project root holds the keys of a `[scope."<glob>"]` section for the files
+//! under its directory. Nested files cascade: each inherits the overrides of
+//! the nested files above it. A `quench.toml` with `version` is a project of
+//! its own, so its subtree is left alone.
+
+use std::path::{Path, PathBuf};
+
+use ignore::WalkBuilder;
+use toml::{Table, Value};
+
+use super::scope::{self, PathScope};
+use super::{ProjectConfig, SUPPORTED_VERSION};
+use crate::adapter::glob::build_glob_set;
+use crate::discovery::is_project_config;
+use crate::error::{Error, Result};
+
+/// Name of a nested file that only holds overrides.
+const OVERRIDE_FILE: &str = "quench.override.toml";
+
+/// Name of a nested file that overrides when it has no `version`.
+const CONFIG_FILE: &str = "quench.toml";
+
+/// Build a scope for each nested config file under `root`, parents first.
+pub(super) fn load(
+    root: &Path,
+    project: &Table,
+    settings: &ProjectConfig,
+) -> Result<Vec<PathScope>> {
+    let mut nested: Vec<(PathBuf, PathBuf, Table)> = Vec::new();
+    for (dir, file) in discover(root, settings)? {
+        let own = read(&file)?;
+        // Sorted parents first, so the last ancestor found is the nearest
+        let section = match nested.iter().rev().find(|(d, _, _)| dir.starts_with(d)) {
+            Some((_, _, parent)) => cascade(parent, own),
+            None => own,
+        };
+        nested.push((dir, file, section));
+    }
+
+    nested
+        .into_iter()
+        .map(|(dir, file, section)| {
+            scope::build_one(project, pattern(&dir), section).map_err(|message| Error::Config {
+                message,
+                path: Some(file),
+            })
+        })
+        .collect()
+}
+
+/// Directories below `root` with a nested config file, and the file, sorted.
+fn discover(root: &Path, settings: &ProjectConfig) -> Result<Vec<(PathBuf, PathBuf)>> {
+    let excluded = build_glob_set(&settings.exclude.patterns);
+    let filter_root = root.to_path_buf();
+    let mut builder = WalkBuilder::new(root);
+    builder.git_ignore(settings.gitignore).ignore(false);
+    for name in &settings.ignore_files {
+        builder.add_custom_ignore_filename(name);
+    }
+    let walker = builder
+        .filter_entry(move |entry| {
+            let relative = entry
+                .path()
+                .strip_prefix(&filter_root)
+                .unwrap_or(entry.path());
+            if excluded.is_match(relative) {
+                return false;
+            }
+            // Another project's subtree belongs to its own config
+            let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+            let config = entry.path().join(CONFIG_FILE);
+            entry.depth() == 0 || !is_dir || !config.is_file() || !is_project_config(&config)
+        })
+        .build();
+
+    let mut found: Vec<(PathBuf, PathBuf)> = Vec::new();
+    for entry in walker.flatten() {
+        let name = entry.file_name();
+        if entry.depth() < 2 || (name != CONFIG_FILE && name != OVERRIDE_FILE) {
+            continue;
+        }
+        let file = entry.path().to_path_buf();
+        let Some(dir) = file
+            .parent()
+            .and_then(|dir| dir.strip_prefix(root).ok())
+            .map(Path::to_path_buf)
+        else {
+            continue;
+        };
+        if found.iter().any(|(d, _)| *d == dir) {
+            return Err(Error::Config {
+                message: format!(
+                    "{} has both {} and {}; keep one",
+                    dir.display(),
+                    CONFIG_FILE,
+                    OVERRIDE_FILE
+                ),
+                path: Some(file),
+            });
+        }
+        found.push((dir, file));
+    }
+    found.sort();
+    Ok(found)
+}
+
+/// Read a nested file as a scope section.
+///
+/// `check = "off"` in a check table is shorthand for disabling the check.
+fn read(path: &Path) -> Result<Table> {
+    let config_error = |message: String| Error::Config {
+        message,
+        path: Some(path.to_path_buf()),
+    };
+    let content = std::fs::read_to_string(path).map_err(|e| Error::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    let mut section: Table = toml::from_str(&content).map_err(|e| config_error(e.to_string()))?;
+
+    if let Some(version) = section.remove("version")
+        && version.as_integer() != Some(SUPPORTED_VERSION)
+    {
+        return Err(config_error(format!(
+            "unsupported config version {} (supported: {})",
+            version, SUPPORTED_VERSION
+        )));
+    }
+
+    let enable = names(&mut section, "enable").map_err(config_error)?;
+    let mut disable = names(&mut section, "disable").map_err(config_error)?;
+    if let Some(Value::Table(checks)) = section.get_mut("check") {
+        checks.retain(|check, keys| {
+            let is_off = keys.get("check").and_then(Value::as_str) == Some("off");
+            if is_off && !disable.iter().any(|c| c == check) {
+                disable.push(check.to_string());
+            }
+            !is_off
+        });
+    }
+    for (key, list) in [("enable", enable), ("disable", disable)] {
+        if !list.is_empty() {
+            section.insert(key.to_string(), list.into());
+        }
+    }
+    Ok(section)
+}
+
+/// Merge a nested section over its parent's.
+///
+/// Check keys replace the parent's key by key; `enable` and `disable` add to
+/// the parent's lists, with the child winning where they conflict.
+fn cascade(parent: &Table, mut own: Table) -> Table {
+    let mut merged = parent.clone();
+
+    let mut own_checks = own.remove("check");
+    if let (Some(Value::Table(base)), Some(Value::Table(checks))) =
+        (merged.get_mut("check"), own_checks.as_mut())
+    {
+        for (check, keys) in std::mem::take(checks) {
+            match (base.get_mut(&check), keys) {
+                (Some(Value::Table(base)), Value::Table(keys)) => base.extend(keys),
+                (_, keys) => {
+                    base.insert(check, keys);
+                }
+            }
+        }
+    } else if let Some(checks) = own_checks {
+        merged.insert("check".to_string(), checks);
+    }
+
+    // Lists were validated when the parent and child were read
+    let mut enable = names(&mut merged, "enable").unwrap_or_default();
+    let mut disable = names(&mut merged, "disable").unwrap_or_default();
+    let own_enable = names(&mut own, "enable").unwrap_or_default();
+    let own_disable = names(&mut own, "disable").unwrap_or_default();
+    enable.retain(|c| !own_disable.contains(c) && !own_enable.contains(c));
+    disable.retain(|c| !own_enable.contains(c) && !own_disable.contains(c));
+    enable.extend(own_enable);
+    disable.extend(own_disable);
+    for (key, list) in [("enable", enable), ("disable", disable)] {
+        if !list.is_empty() {
+            merged.insert(key.to_string(), list.into());
+        }
+    }
+
+    // Unknown keys pass through for the scope to report
+    merged.extend(own);
+    merged
+}
+
+/// Remove a list of check names from a section.
+fn names(section: &mut Table, key: &str) -> std::result::Result<Vec<String>, String> {
+    match section.remove(key) {
+        Some(value) => value.try_into().map_err(|e| format!("{}: {}", key, e)),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Glob for the files under a directory relative to the project root.
+fn pattern(dir: &Path) -> String {
+    let parts: Vec<String> = dir
+        .components()
+        .map(|c| globset::escape(&c.as_os_str().to_string_lossy()))
+        .collect();
+    format!("{}/**", parts.join("/"))
+}
+
+#[cfg(test)]
+#[path = "nested_tests.rs"]
+mod tests;
@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use crate::config::{CheckLevel, Config, load};
+use crate::test_utils::{create_tree, temp_project_with_config};
+
+fn load_in(root: &Path) -> Result<Config> {
+    load(&root.join("quench.toml"))
+}
+
+// =============================================================================
+// Discovery
+// =============================================================================
+
+#[test]
+fn nested_files_become_directory_scopes() {
+    let temp = temp_project_with_config("version = 1\n[check.cloc]\nmax_lines = 500\n");
+    create_tree(
+        temp.path(),
+        &[
+            (
+                "packages/api/quench.toml",
+                "[check.cloc]\nmax_lines = 1000\n",
+            ),
+            (
+                "packages/web/quench.override.toml",
+                "version = 1\ndisable = [\"escapes\"]\n",
+            ),
+        ],
+    );
+
+    let config = load_in(temp.path()).unwrap();
+
+    let patterns: Vec<_> = config.scopes.iter().map(|s| s.pattern.as_str()).collect();
+    assert_eq!(patterns, ["packages/api/**", "packages/web/**"]);
+    assert_eq!(config.check.cloc.max_lines, 500);
+    assert_eq!(config.scopes[0].config.check.cloc.max_lines, 1000);
+    assert!(config.scopes[1].disables("escapes"));
+}
+
+#[test]
+fn versioned_nested_config_is_its_own_project() {
+    let temp = temp_project_with_config("version = 1\n");
+    create_tree(
+        temp.path(),
+        &[
+            (
+                "vendor/lib/quench.toml",
+                "version = 1\n[check.cloc]\nmax_lines = 9\n",
+            ),
+            (
+                "vendor/lib/sub/quench.override.toml",
+                "disable = [\"cloc\"]\n",
+            ),
+        ],
+    );
+
+    assert!(load_in(temp.path()).unwrap().scopes.is_empty());
+}
+
+#[test]
+fn excluded_and_ignored_directories_are_skipped() {
+    let temp = temp_project_with_config("version = 1\n[project]\nexclude = [\"tests/fixtures\"]\n");
+    create_tree(
+        temp.path(),
+        &[
+            (
+                "tests/fixtures/a/quench.override.toml",
+                "disable = [\"cloc\"]\n",
+            ),
+            (".hidden/quench.override.toml", "disable = [\"cloc\"]\n"),
+        ],
+    );
+
+    assert!(load_in(temp.path()).unwrap().scopes.is_empty());
+}
+
+#[test]
+fn parse_ignores_nested_files() {
+    let temp = temp_project_with_config("version = 1\n");
+    create_tree(
+        temp.path(),
+        &[("pkg/quench.override.toml", "disable = [\"cloc\"]\n")],
+    );
+
+    let config = crate::config::parse("version = 1\n", &temp.path().join("quench.toml")).unwrap();
+
+    assert!(config.scopes.is_empty());
+}
+
+// =============================================================================
+// Cascading
+// =============================================================================
+
+#[test]
+fn nested_files_inherit_their_parents() {
+    let temp = temp_project_with_config("version = 1\n");
+    create_tree(
+        temp.path(),
+        &[
+            (
+                "packages/quench.override.toml",
+                "disable = [\"docs\", \"license\"]\n[check.cloc]\nmax_lines = 800\nmax_lines_test = 1200\n",
+            ),
+            (
+                "packages/api/quench.override.toml",
+                "enable = [\"license\"]\n[check.cloc]\nmax_lines = 1000\n",
+            ),
+        ],
+    );
+
+    let config = load_in(temp.path()).unwrap();
+
+    let api = &config.scopes[1];
+    assert_eq!(api.pattern, "packages/api/**");
+    assert_eq!(api.config.check.cloc.max_lines, 1000);
+    assert_eq!(api.config.check.cloc.max_lines_test, 1200);
+    assert_eq!(api.disable, ["docs"]);
+    assert_eq!(api.enable, ["license"]);
+}
+
+#[test]
+fn check_off_disables_the_check() {
+    let temp = temp_project_with_config("version = 1\n[check.escapes]\ncheck = \"error\"\n");
+    create_tree(
+        temp.path(),
+        &[("scripts/quench.toml", "[check.escapes]\ncheck = \"off\"\n")],
+    );
+
+    let config = load_in(temp.path()).unwrap();
+
+    let scope = &config.scopes[0];
+    assert!(scope.disables("escapes"));
+    assert!(!scope.overrides("escapes"));
+    assert_eq!(scope.config.check.escapes.check, CheckLevel::Error);
+}
+
+// =============================================================================
+// Errors
+// =============================================================================
+
+#[test]
+fn both_nested_files_in_one_directory_is_an_error() {
+    let temp = temp_project_with_config("version = 1\n");
+    create_tree(
+        temp.path(),
+        &[
+            ("pkg/quench.toml", "disable = [\"cloc\"]\n"),
+            ("pkg/quench.override.toml", "disable = [\"docs\"]\n"),
+        ],
+    );
+
+    let err = load_in(temp.path()).unwrap_err().to_string();
+    assert!(
+        err.contains("pkg has both quench.toml and quench.override.toml"),
+        "{err}"
+    );
+}
+
+#[test]
+fn invalid_nested_file_names_the_file() {
+    let temp = temp_project_with_config("version = 1\n");
+    create_tree(
+        temp.path(),
+        &[(
+            "pkg/quench.override.toml",
+            "[check.build]\ntargets = [\"x\"]\n",
+        )],
+    );
+
+    let err = load_in(temp.path()).unwrap_err();
+    let Error::Config { message, path } = err else {
+        panic!("expected config error");
+    };
+    assert!(
+        message.contains("check 'build' can't be scoped"),
+        "{message}"
+    );
+    assert!(path.unwrap().ends_with("pkg/quench.override.toml"));
+}
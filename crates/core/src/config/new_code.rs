@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! New-code enforcement window configuration.
+
+use chrono::NaiveDate;
+use schemars::JsonSchema;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Strict rules for new files only; older (legacy) files only warn.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct NewCodeConfig {
+    /// Files first committed after this point are new. None = off.
+    #[serde(deserialize_with = "deserialize_since")]
+    pub since: Option<NewCodeSince>,
+}
+
+/// Start of the new-code window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewCodeSince {
+    /// Files first committed after this day are new.
+    Date(NaiveDate),
+    /// Files first committed after the baseline was created are new.
+    Baseline,
+}
+
+impl Serialize for NewCodeSince {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Date(date) => serializer.collect_str(&date.format("%Y-%m-%d")),
+            Self::Baseline => serializer.serialize_str("baseline"),
+        }
+    }
+}
+
+impl JsonSchema for NewCodeSince {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "NewCodeSince".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "`\"baseline\"` or a date (YYYY-MM-DD).",
+            "type": "string"
+        })
+    }
+}
+
+/// Accept `"baseline"`, a TOML date (`2026-03-01`), or a date string.
+fn deserialize_since<'de, D>(deserializer: D) -> Result<Option<NewCodeSince>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let text = match toml::Value::deserialize(deserializer)? {
+        toml::Value::String(s) if s == "baseline" => return Ok(Some(NewCodeSince::Baseline)),
+        toml::Value::String(s) => s,
+        toml::Value::Datetime(dt) => dt.to_string(),
+        other => {
+            return Err(D::Error::custom(format!(
+                "expected \"baseline\" or a date (YYYY-MM-DD), found {}",
+                other.type_str()
+            )));
+        }
+    };
+    NaiveDate::parse_from_str(&text, "%Y-%m-%d")
+        .map(|date| Some(NewCodeSince::Date(date)))
+        .map_err(|_| {
+            D::Error::custom(format!(
+                "invalid since {:?}, expected \"baseline\" or YYYY-MM-DD",
+                text
+            ))
+        })
+}
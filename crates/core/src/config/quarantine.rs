@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Quarantined directory configuration.
+
+use chrono::NaiveDate;
+use schemars::JsonSchema;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A directory whose violations only warn until an expiry date.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct QuarantineConfig {
+    /// Directory relative to the project root (e.g., "spikes/parser").
+    pub path: String,
+
+    /// Last day the quarantine applies (e.g., 2026-06-30). None = no expiry.
+    #[serde(default, deserialize_with = "deserialize_date")]
+    pub until: Option<NaiveDate>,
+
+    /// Why the directory is quarantined (shown when it expires).
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Accept a TOML date (`2026-06-30`) or a date string (`"2026-06-30"`).
+fn deserialize_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let text = match toml::Value::deserialize(deserializer)? {
+        toml::Value::String(s) => s,
+        toml::Value::Datetime(dt) => dt.to_string(),
+        other => {
+            return Err(D::Error::custom(format!(
+                "expected a date (YYYY-MM-DD), found {}",
+                other.type_str()
+            )));
+        }
+    };
+    NaiveDate::parse_from_str(&text, "%Y-%m-%d")
+        .map(Some)
+        .map_err(|_| D::Error::custom(format!("invalid date {:?}, expected YYYY-MM-DD", text)))
+}
@@ -6,13 +6,14 @@
 use std::collections::HashMap;
 use std::time::Duration;
 
-use serde::Deserialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 use super::CheckLevel;
 use crate::tolerance::{parse_duration, parse_size};
 
 /// Ratcheting configuration.
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct RatchetConfig {
     /// Check level: "error" | "warn" | "off"
@@ -26,6 +27,28 @@ pub struct RatchetConfig {
     #[serde(default = "default_true")]
     pub escapes: bool,
 
+    /// Ratchet escapes per 1000 source lines instead of raw counts
+    /// (default: false).
+    #[serde(default)]
+    pub escapes_density: bool,
+
+    /// Ratchet the share of Rust source lines inside unsafe blocks,
+    /// overall and per package (default: false).
+    #[serde(default)]
+    pub unsafe_ratio: bool,
+
+    /// Ratchet linter diagnostic counts per rule (default: true).
+    #[serde(default = "default_true")]
+    pub lint: bool,
+
+    /// Ratchet unused export counts per language (default: true).
+    #[serde(default = "default_true")]
+    pub deadcode: bool,
+
+    /// Ratchet resolved dependency counts per ecosystem (default: true).
+    #[serde(default = "default_true")]
+    pub deps: bool,
+
     /// Ratchet binary size (default: false).
     #[serde(default)]
     pub binary_size: bool,
@@ -50,6 +73,25 @@ pub struct RatchetConfig {
     #[serde(default)]
     pub test_time_max: bool,
 
+    /// Flag suites whose test time deviates from their recorded history
+    /// (default: false).
+    #[serde(default)]
+    pub test_time_anomaly: bool,
+
+    /// Standard deviations above the rolling mean before a suite is
+    /// flagged (default: 3.0).
+    #[serde(default = "default_anomaly_sigma")]
+    pub test_time_anomaly_sigma: f64,
+
+    /// Warn when quench's own run time regresses (default: true).
+    #[serde(default = "default_true")]
+    pub self_time: bool,
+
+    /// Percent slowdown of quench's own run time allowed before warning
+    /// (default: 50).
+    #[serde(default = "default_self_time_tolerance")]
+    pub self_time_tolerance: f64,
+
     /// Coverage tolerance (percentage points allowed to drop).
     #[serde(default)]
     pub coverage_tolerance: Option<f64>,
@@ -73,10 +115,15 @@ pub struct RatchetConfig {
     /// Per-package ratchet settings.
     #[serde(default)]
     pub package: HashMap<String, RatchetPackageConfig>,
+
+    /// URL that receives a JSON POST with the responsible commits when
+    /// a ratchet regression is found in CI.
+    #[serde(default)]
+    pub webhook: Option<String>,
 }
 
 /// Per-package ratcheting configuration.
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct RatchetPackageConfig {
     /// Override coverage ratcheting for this package (None = inherit global).
@@ -90,7 +137,18 @@ fn default_stale_days() -> u32 {
     30
 }
 
+fn default_anomaly_sigma() -> f64 {
+    3.0
+}
+
+fn default_self_time_tolerance() -> f64 {
+    50.0
+}
+
 impl RatchetConfig {
+    /// Webhook delivery time limit.
+    pub const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
     /// Get coverage tolerance in percentage points.
     pub fn coverage_tolerance_pct(&self) -> Option<f64> {
         self.coverage_tolerance
@@ -34,6 +34,8 @@ fn ratchet_config_defaults_with_section() {
     assert!(!config.ratchet.test_time_total);
     assert!(!config.ratchet.test_time_avg);
     assert!(!config.ratchet.test_time_max);
+    assert!(!config.ratchet.test_time_anomaly);
+    assert_eq!(config.ratchet.test_time_anomaly_sigma, 3.0);
     assert_eq!(config.ratchet.stale_days, 30);
     assert!(config.ratchet.package.is_empty());
 }
@@ -56,6 +58,22 @@ stale_days = 7
     assert_eq!(config.ratchet.stale_days, 7);
 }
 
+#[test]
+fn ratchet_webhook_parsing() {
+    let config = parse_config(
+        r#"
+version = 1
+[ratchet]
+webhook = "https://hooks.example.com/quench"
+"#,
+    );
+    assert_eq!(
+        config.ratchet.webhook.as_deref(),
+        Some("https://hooks.example.com/quench")
+    );
+    assert_eq!(parse_config("version = 1\n").ratchet.webhook, None);
+}
+
 #[test]
 fn ratchet_tolerance_parsing() {
     let config = parse_config(
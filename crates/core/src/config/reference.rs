@@ -0,0 +1,439 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Configuration reference, generated from the config structs.
+//!
+//! Every config struct derives `JsonSchema`, so its doc comments, serde
+//! names, and defaults describe each key. This module walks that schema
+//! into one [`Entry`] per key, which `quench config docs` renders. Because
+//! the reference comes from the structs, it can't drift from the parser.
+
+use std::fmt::Write;
+
+use schemars::SchemaGenerator;
+use serde_json::{Map, Value, json};
+
+use super::{Config, InstanceSection, ScopeSection};
+
+/// Defaults longer than this are elided in the rendered reference.
+const MAX_DEFAULT_WIDTH: usize = 60;
+
+/// One config key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// TOML table the key lives in (e.g., `check.cloc`, or
+    /// `check.escapes.patterns[]` for an array of tables).
+    pub table: String,
+    /// Key within the table (e.g., `<tool>` for user-chosen keys).
+    pub name: String,
+    /// Value type, e.g. `integer` or `"error" | "warn" | "off"`.
+    pub kind: String,
+    /// Default value as TOML, if the key has one.
+    pub default: Option<String>,
+    /// Description from the field's doc comment.
+    pub description: String,
+    /// A TOML line setting the key.
+    pub example: String,
+}
+
+impl Entry {
+    /// Dotted key path, e.g. `check.cloc.max_lines`.
+    pub fn key(&self) -> String {
+        if self.table.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}.{}", self.table, self.name)
+        }
+    }
+}
+
+/// JSON Schema for `quench.toml`.
+pub fn schema() -> Value {
+    let mut generator = SchemaGenerator::default();
+    let root = generator.root_schema_for::<Config>();
+    let scope = generator.subschema_for::<ScopeSection>();
+    let instance = generator.subschema_for::<InstanceSection>();
+    let mut schema = serde_json::to_value(root).unwrap_or_default();
+
+    // Scopes and check instances are read from the raw table, so `Config`
+    // skips them. Describe their sections here.
+    schema["properties"]["scope"] = json!({
+        "description": "Check policies for files matching a glob.",
+        "type": "object",
+        "additionalProperties": with_title(scope.to_value(), "<glob>"),
+    });
+    schema["$defs"]["CheckConfig"]["patternProperties"] = json!({
+        "^[a-z]+:.+$": with_title(instance.to_value(), "<check>:<name>"),
+    });
+    if let Some(defs) = schema["$defs"].as_object_mut() {
+        for (name, def) in generator.definitions() {
+            defs.entry(name.clone()).or_insert_with(|| def.clone());
+        }
+    }
+    schema
+}
+
+/// Name a map's values, for the placeholder key in the reference.
+fn with_title(mut schema: Value, title: &str) -> Value {
+    schema["title"] = json!(title);
+    schema
+}
+
+/// Every config key, in table order.
+pub fn entries() -> Vec<Entry> {
+    let schema = schema();
+    let walker = Walker {
+        defs: schema.get("$defs").and_then(Value::as_object),
+    };
+    let mut entries = Vec::new();
+    walker.table(&schema, "", None, &mut entries);
+    entries
+}
+
+/// The reference as Markdown, one section per table.
+pub fn markdown() -> String {
+    let mut out = String::from(
+        "# Configuration Reference\n\n\
+         Every key quench reads from `quench.toml`. Generated from the config \
+         structs by `quench config docs --output md`; do not edit by hand.\n",
+    );
+    let mut current = None;
+    for entry in entries() {
+        if current.as_deref() != Some(entry.table.as_str()) {
+            if entry.table.is_empty() {
+                out.push_str("\n## Top level\n");
+            } else {
+                let _ = write!(out, "\n## `{}`\n", header(&entry.table));
+            }
+            current = Some(entry.table.clone());
+        }
+        let _ = write!(out, "\n### `{}`\n\n", entry.key());
+        if !entry.description.is_empty() {
+            let _ = write!(out, "{}\n\n", entry.description);
+        }
+        let _ = writeln!(out, "- Type: {}", entry.kind);
+        match &entry.default {
+            Some(default)
+                if default.chars().count() > MAX_DEFAULT_WIDTH || default.contains('\n') =>
+            {
+                let _ = writeln!(out, "- Default: built in (long text)");
+            }
+            Some(default) => {
+                let _ = writeln!(out, "- Default: `{}`", default);
+            }
+            None => {
+                let _ = writeln!(out, "- Default: none");
+            }
+        }
+        out.push_str("\n```toml\n");
+        if !entry.table.is_empty() {
+            let _ = writeln!(out, "{}", header(&entry.table));
+        }
+        let _ = write!(out, "{}\n```\n", entry.example);
+    }
+    out
+}
+
+/// TOML header for a table path: `[a.b]`, or `[[a.b]]` for arrays of tables.
+fn header(table: &str) -> String {
+    let table = table
+        .split('.')
+        .map(|segment| match segment.strip_suffix("[]") {
+            Some(name) => format!("{}[]", key(name)),
+            None => key(segment),
+        })
+        .collect::<Vec<_>>()
+        .join(".");
+    if let Some(array) = table.strip_suffix("[]") {
+        format!("[[{}]]", array)
+    } else {
+        format!("[{}]", table)
+    }
+}
+
+struct Walker<'a> {
+    defs: Option<&'a Map<String, Value>>,
+}
+
+impl Walker<'_> {
+    /// Follow `$ref` and drop `null` from optional values.
+    fn resolve<'s>(&'s self, schema: &'s Value) -> &'s Value {
+        if let Some(name) = schema
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|r| r.strip_prefix("#/$defs/"))
+            && let Some(def) = self.defs.and_then(|defs| defs.get(name))
+        {
+            return self.resolve(def);
+        }
+        if let Some(variants) = schema.get("anyOf").and_then(Value::as_array) {
+            let non_null: Vec<&Value> = variants.iter().filter(|v| !is_null(v)).collect();
+            if let [only] = non_null.as_slice() {
+                return self.resolve(only);
+            }
+        }
+        schema
+    }
+
+    /// A schema's description, preferring the field's over its type's.
+    fn description(&self, schema: &Value) -> String {
+        let text = schema
+            .get("description")
+            .or_else(|| self.resolve(schema).get("description"))
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        text.split("\n\n")
+            .map(|paragraph| paragraph.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Add entries for each key of a table schema.
+    fn table(&self, schema: &Value, path: &str, default: Option<&Value>, out: &mut Vec<Entry>) {
+        let resolved = self.resolve(schema);
+        let mut keys: Vec<(&str, &Value, Option<&Value>)> = Vec::new();
+        if let Some(properties) = resolved.get("properties").and_then(Value::as_object) {
+            for (name, field) in properties {
+                let field_default = field
+                    .get("default")
+                    .or_else(|| default.and_then(|d| d.get(name)));
+                keys.push((name, field, field_default));
+            }
+        }
+        let patterns = resolved.get("patternProperties").and_then(Value::as_object);
+        let values = resolved
+            .get("additionalProperties")
+            .filter(|v| v.is_object());
+        for values in patterns.into_iter().flat_map(|p| p.values()).chain(values) {
+            let name = values
+                .get("title")
+                .and_then(Value::as_str)
+                .unwrap_or("<name>");
+            keys.push((name, values, None));
+        }
+        // A table's own keys come before its subtables, as in a TOML file.
+        keys.sort_by_key(|(_, field, _)| self.has_subtable(self.resolve(field)));
+        for (name, field, field_default) in keys {
+            self.key(field, path, name, field_default, out);
+        }
+    }
+
+    /// Add entries for one key: a leaf, or the keys of a nested table.
+    fn key(
+        &self,
+        schema: &Value,
+        table: &str,
+        name: &str,
+        default: Option<&Value>,
+        out: &mut Vec<Entry>,
+    ) {
+        let resolved = self.resolve(schema);
+        let path = join(table, name);
+        if self.is_table(resolved) {
+            self.table(resolved, &path, default, out);
+            return;
+        }
+        if self.has_subtable(resolved)
+            && let Some(items) = resolved.get("items")
+        {
+            self.table(items, &format!("{}[]", path), None, out);
+            return;
+        }
+        let default = default.filter(|d| !d.is_null());
+        let example = default
+            .filter(|d| !is_empty(d))
+            .map(toml)
+            .filter(|d| d.chars().count() <= MAX_DEFAULT_WIDTH && !d.contains('\n'))
+            .unwrap_or_else(|| self.placeholder(resolved));
+        out.push(Entry {
+            table: table.to_string(),
+            name: name.to_string(),
+            kind: self.kind(resolved),
+            default: default.map(toml),
+            description: self.description(schema),
+            example: format!("{} = {}", key(name), example),
+        });
+    }
+
+    /// Whether a schema is a TOML table with named keys.
+    fn is_table(&self, schema: &Value) -> bool {
+        schema.get("properties").is_some()
+            || schema
+                .get("additionalProperties")
+                .map(|v| self.is_table(self.resolve(v)))
+                .unwrap_or(false)
+    }
+
+    /// Whether a key's value is written as its own TOML table.
+    fn has_subtable(&self, schema: &Value) -> bool {
+        self.is_table(schema)
+            || schema
+                .get("items")
+                .map(|items| self.is_table(self.resolve(items)))
+                .unwrap_or(false)
+    }
+
+    /// Human-readable value type.
+    fn kind(&self, schema: &Value) -> String {
+        let schema = self.resolve(schema);
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            return values.iter().map(toml).collect::<Vec<_>>().join(" | ");
+        }
+        if let Some(value) = schema.get("const") {
+            return toml(value);
+        }
+        if let Some(variants) = schema
+            .get("anyOf")
+            .or_else(|| schema.get("oneOf"))
+            .and_then(Value::as_array)
+        {
+            return variants
+                .iter()
+                .filter(|v| !is_null(v))
+                .map(|v| self.kind(v))
+                .collect::<Vec<_>>()
+                .join(" | ");
+        }
+        match primary_type(schema) {
+            Some("array") => match schema.get("items") {
+                Some(items) => format!("array of {}", plural(&self.kind(items))),
+                None => "array".to_string(),
+            },
+            Some("object") => match schema.get("additionalProperties").filter(|v| v.is_object()) {
+                Some(values) => format!("table of {}", plural(&self.kind(values))),
+                None => "table".to_string(),
+            },
+            Some("string") => match schema.get("format").and_then(Value::as_str) {
+                Some(format @ ("date" | "duration")) => format.to_string(),
+                _ => "string".to_string(),
+            },
+            Some(other) => other.to_string(),
+            None => "any".to_string(),
+        }
+    }
+
+    /// An illustrative value for a key without a useful default.
+    fn placeholder(&self, schema: &Value) -> String {
+        let schema = self.resolve(schema);
+        if let Some(value) = ["examples", "enum"]
+            .iter()
+            .find_map(|k| schema.get(*k).and_then(Value::as_array)?.first())
+            .or_else(|| schema.get("const"))
+        {
+            return toml(value);
+        }
+        if let Some(first) = schema
+            .get("anyOf")
+            .or_else(|| schema.get("oneOf"))
+            .and_then(Value::as_array)
+            .and_then(|variants| variants.iter().find(|v| !is_null(v)))
+        {
+            return self.placeholder(first);
+        }
+        match primary_type(schema) {
+            Some("array") => match schema.get("items") {
+                Some(items) => format!("[{}]", self.placeholder(items)),
+                None => "[]".to_string(),
+            },
+            Some("object") => match schema.get("additionalProperties").filter(|v| v.is_object()) {
+                Some(values) => format!("{{ \"<name>\" = {} }}", self.placeholder(values)),
+                None => "{}".to_string(),
+            },
+            Some("boolean") => "true".to_string(),
+            Some("integer") => "1".to_string(),
+            Some("number") => "1.0".to_string(),
+            Some("string") if schema.get("format").and_then(Value::as_str) == Some("date") => {
+                "2026-01-01".to_string()
+            }
+            _ => "\"...\"".to_string(),
+        }
+    }
+}
+
+fn join(table: &str, name: &str) -> String {
+    if table.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", table, name)
+    }
+}
+
+fn is_null(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("null")
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Array(items) => items.is_empty(),
+        Value::Object(map) => map.is_empty(),
+        Value::String(s) => s.is_empty(),
+        _ => false,
+    }
+}
+
+/// The schema's type, ignoring `null` for optional values.
+fn primary_type(schema: &Value) -> Option<&str> {
+    match schema.get("type")? {
+        Value::String(t) => Some(t),
+        Value::Array(types) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|t| *t != "null"),
+        _ => None,
+    }
+}
+
+fn plural(kind: &str) -> String {
+    match kind {
+        "string" | "integer" | "number" | "boolean" | "date" | "duration" | "table" => {
+            format!("{}s", kind)
+        }
+        other => match other.strip_prefix("array of ") {
+            Some(items) => format!("arrays of {}", items),
+            None => other.to_string(),
+        },
+    }
+}
+
+/// Render a JSON value as an inline TOML value.
+fn toml(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => serde_json::to_string(s).unwrap_or_default(),
+        Value::Array(items) => format!(
+            "[{}]",
+            items.iter().map(toml).collect::<Vec<_>>().join(", ")
+        ),
+        Value::Object(map) => {
+            let fields: Vec<String> = map
+                .iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| format!("{} = {}", key(k), toml(v)))
+                .collect();
+            if fields.is_empty() {
+                "{}".to_string()
+            } else {
+                format!("{{ {} }}", fields.join(", "))
+            }
+        }
+    }
+}
+
+/// A TOML key, quoted unless bare.
+fn key(name: &str) -> String {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        name.to_string()
+    } else {
+        serde_json::to_string(name).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+#[path = "reference_tests.rs"]
+mod tests;
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+
+fn entry(key: &str) -> Entry {
+    entries()
+        .into_iter()
+        .find(|e| e.key() == key)
+        .unwrap_or_else(|| panic!("no entry for {key}"))
+}
+
+// =============================================================================
+// ENTRY TESTS
+// =============================================================================
+
+#[test]
+fn integer_key_has_type_default_and_description() {
+    let e = entry("check.cloc.max_lines");
+    assert_eq!(e.table, "check.cloc");
+    assert_eq!(e.kind, "integer");
+    assert_eq!(e.default.as_deref(), Some("750"));
+    assert!(!e.description.is_empty());
+    assert_eq!(e.example, "max_lines = 750");
+}
+
+#[test]
+fn enum_key_lists_its_values() {
+    let e = entry("check.cloc.check");
+    assert_eq!(e.kind, r#""error" | "warn" | "off""#);
+    assert_eq!(e.default.as_deref(), Some(r#""error""#));
+}
+
+#[test]
+fn duration_key_uses_example_value() {
+    let e = entry("check.format.timeout");
+    assert_eq!(e.kind, "duration");
+    assert_eq!(e.default, None);
+    assert_eq!(e.example, r#"timeout = "30s""#);
+}
+
+#[test]
+fn array_of_tables_keys_are_listed_per_item() {
+    let e = entry("check.escapes.patterns[].pattern");
+    assert_eq!(e.table, "check.escapes.patterns[]");
+    assert_eq!(e.kind, "string");
+}
+
+#[test]
+fn user_named_keys_use_a_placeholder() {
+    let e = entry("tools.<tool>");
+    assert_eq!(e.kind, "string");
+    assert_eq!(e.example, r#""<tool>" = "...""#);
+}
+
+#[test]
+fn scopes_and_instances_are_documented() {
+    let e = entry("scope.<glob>.enable");
+    assert_eq!(e.kind, "array of strings");
+    assert_eq!(header(&e.table), r#"[scope."<glob>"]"#);
+
+    let e = entry("check.<check>:<name>.paths");
+    assert_eq!(header(&e.table), r#"[check."<check>:<name>"]"#);
+}
+
+#[test]
+fn every_key_is_documented() {
+    let undocumented: Vec<String> = entries()
+        .iter()
+        .filter(|e| e.description.is_empty())
+        .map(Entry::key)
+        .collect();
+    assert!(
+        undocumented.is_empty(),
+        "undocumented keys: {undocumented:?}"
+    );
+}
+
+#[test]
+fn table_keys_precede_subtables() {
+    let tables: Vec<String> = entries().into_iter().map(|e| e.table).collect();
+    let mut seen = Vec::new();
+    for table in tables {
+        if seen.last() != Some(&table) {
+            assert!(!seen.contains(&table), "table {table} split into sections");
+            seen.push(table);
+        }
+    }
+}
+
+#[test]
+fn examples_are_valid_toml() {
+    for e in entries() {
+        let snippet = if e.table.is_empty() {
+            e.example.clone()
+        } else {
+            format!("{}\n{}", header(&e.table), e.example)
+        };
+        if let Err(err) = toml::from_str::<toml::Value>(&snippet) {
+            panic!("invalid example for {}: {err}\n{snippet}", e.key());
+        }
+    }
+}
+
+// =============================================================================
+// MARKDOWN TESTS
+// =============================================================================
+
+#[test]
+fn markdown_has_a_section_per_table_and_key() {
+    let md = markdown();
+    assert!(md.starts_with("# Configuration Reference\n"));
+    assert!(md.contains("\n## `[check.cloc]`\n"));
+    assert!(md.contains("\n## `[[check.escapes.patterns]]`\n"));
+    assert!(md.contains(
+        "\n### `check.cloc.max_lines`\n\n\
+         Maximum lines per file (default: 750).\n\n\
+         - Type: integer\n\
+         - Default: `750`\n\n\
+         ```toml\n[check.cloc]\nmax_lines = 750\n```\n"
+    ));
+}
+
+#[test]
+fn markdown_elides_long_defaults() {
+    let md = markdown();
+    let section = md.split("### `check.cloc.advice`").nth(1).unwrap();
+    let section = section.split("###").next().unwrap();
+    assert!(section.contains("- Default: built in (long text)"));
+}
+
+#[test]
+fn markdown_is_deterministic() {
+    assert_eq!(markdown(), markdown());
+}
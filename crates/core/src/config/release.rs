@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Release readiness check configuration.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Release readiness check configuration.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReleaseConfig {
+    /// Check level: "error" | "warn" | "off"
+    pub check: Option<String>,
+
+    /// Changelog file that must have a section for the pending version.
+    pub changelog: String,
+
+    /// Markers that must be resolved before release.
+    pub markers: Vec<String>,
+
+    /// Docs whose `<package> ... x.y.z` mentions must match the pending version.
+    pub docs: Vec<String>,
+
+    /// Exclude patterns (files matching these aren't scanned for markers or placeholders).
+    pub exclude: Vec<String>,
+}
+
+impl Default for ReleaseConfig {
+    fn default() -> Self {
+        Self {
+            check: None,
+            changelog: "CHANGELOG.md".to_string(),
+            markers: vec!["TODO(before-release)".to_string()],
+            docs: vec!["README.md".to_string()],
+            exclude: Vec::new(),
+        }
+    }
+}
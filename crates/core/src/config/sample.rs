@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Per-run file sampling for expensive checks (`sample = "10%"`).
+
+use std::path::Path;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Share of files a check scans per fast-mode run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sample {
+    percent: u8,
+}
+
+impl Sample {
+    /// Parse a percentage from 1% to 100% (e.g., "10%").
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let invalid = || {
+            format!(
+                "invalid sample {:?}, expected a percentage from 1% to 100% (e.g., \"10%\")",
+                text
+            )
+        };
+        let percent: u8 = text
+            .trim()
+            .strip_suffix('%')
+            .and_then(|n| n.trim().parse().ok())
+            .ok_or_else(invalid)?;
+        if !(1..=100).contains(&percent) {
+            return Err(invalid());
+        }
+        Ok(Self { percent })
+    }
+
+    pub fn percent(self) -> u8 {
+        self.percent
+    }
+
+    /// Whether a file (relative to the project root) is in the run's sample.
+    ///
+    /// The same seed always picks the same files, so reruns at one commit
+    /// agree and each commit covers a different share of the project. The
+    /// hash is fixed, so every quench build and platform picks alike.
+    pub fn includes(self, seed: &str, relative: &Path) -> bool {
+        let path = relative.to_string_lossy().replace('\\', "/");
+        let key = [seed.as_bytes(), &[0], path.as_bytes()];
+        fnv1a(key.into_iter().flatten()) % 100 < u64::from(self.percent)
+    }
+}
+
+/// 64-bit FNV-1a.
+fn fnv1a<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u64 {
+    bytes
+        .into_iter()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+impl<'de> Deserialize<'de> for Sample {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        Self::parse(&text).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for Sample {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(&format_args!("{}%", self.percent))
+    }
+}
+
+impl JsonSchema for Sample {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Sample".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Percentage of files to check per run, from `\"1%\"` to `\"100%\"`.",
+            "type": "string"
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "sample_tests.rs"]
+mod tests;
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+
+#[test]
+fn parses_percentages() {
+    assert_eq!(Sample::parse("10%").unwrap().percent(), 10);
+    assert_eq!(Sample::parse(" 100 % ").unwrap().percent(), 100);
+    assert!(Sample::parse("0%").is_err());
+    assert!(Sample::parse("101%").is_err());
+    assert!(Sample::parse("10").is_err());
+    assert!(Sample::parse("half").is_err());
+}
+
+#[test]
+fn sample_is_deterministic_per_seed() {
+    let sample = Sample::parse("30%").unwrap();
+    let paths: Vec<String> = (0..1000).map(|i| format!("src/file_{i}.rs")).collect();
+    let picked = |seed: &str| -> Vec<bool> {
+        paths
+            .iter()
+            .map(|p| sample.includes(seed, Path::new(p)))
+            .collect()
+    };
+
+    let first = picked("abc123");
+    assert_eq!(first, picked("abc123"));
+    assert_ne!(first, picked("def456"));
+
+    // Roughly the configured share
+    let count = first.iter().filter(|&&b| b).count();
+    assert!((200..400).contains(&count), "{count}");
+}
+
+#[test]
+fn full_sample_includes_every_file() {
+    let sample = Sample::parse("100%").unwrap();
+    assert!((0..100).all(|i| sample.includes("seed", Path::new(&format!("f{i}")))));
+}
+
+#[test]
+fn fnv1a_matches_reference_values() {
+    assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+}
+
+#[test]
+fn sample_is_stable_across_builds() {
+    let sample = Sample::parse("50%").unwrap();
+    let picked: Vec<bool> = ["src/a.rs", "src/b.rs", "src/c.rs", "src/d.rs"]
+        .iter()
+        .map(|p| sample.includes("abc123", Path::new(p)))
+        .collect();
+    assert_eq!(picked, [true, false, true, false]);
+}
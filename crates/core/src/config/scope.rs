@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Path-scoped check policies (`[scope."<glob>"]`).
+//!
+//! A scope turns checks on or off and overrides their settings for the files
+//! its glob matches. Its `check` tables merge over the project's `[check]`
+//! sections the same way check instances do.
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use toml::{Table, Value};
+
+use super::Config;
+use crate::checks::CHECK_NAMES;
+
+/// Checks a scope can rerun with its own settings. The rest don't scan the
+/// walked files, so scopes can only disable their findings.
+pub const SCOPED_CHECKS: &[&str] = &["cloc", "escapes", "agents", "docs", "license", "whitespace"];
+
+/// Policy for files matching a glob.
+#[derive(Debug)]
+pub struct PathScope {
+    /// Glob relative to the project root (e.g., "services/*").
+    pub pattern: String,
+    /// Checks turned on for matching files.
+    pub enable: Vec<String>,
+    /// Checks whose findings in matching files are dropped.
+    pub disable: Vec<String>,
+    /// The scope's `check` overrides, with `enable` applied.
+    pub section: Table,
+    /// The project config with the scope's overrides merged in.
+    pub config: Box<Config>,
+}
+
+/// Keys of a `[scope."<glob>"]` section. Scopes are read from the raw
+/// table, so this type only describes them for the config reference.
+#[derive(JsonSchema)]
+pub struct ScopeSection {
+    /// Checks turned on for matching files (e.g., `["cloc"]`).
+    pub enable: Vec<String>,
+    /// Checks whose findings in matching files are dropped.
+    pub disable: Vec<String>,
+    /// Check settings for matching files, merged over the project's
+    /// (e.g., `cloc = { max_lines = 1000 }`).
+    #[schemars(extend("additionalProperties" = { "title": "<check>", "type": "object" }))]
+    pub check: BTreeMap<String, serde_json::Map<String, serde_json::Value>>,
+}
+
+impl PathScope {
+    /// Whether matching files rerun `check` with the scope's config.
+    pub fn overrides(&self, check: &str) -> bool {
+        self.section.contains_key(check)
+    }
+
+    /// Whether findings of `check` in matching files are dropped.
+    pub fn disables(&self, check: &str) -> bool {
+        self.disable.iter().any(|c| c == check)
+    }
+}
+
+/// Remove the `[scope]` table, returning its sections in key order.
+pub(super) fn extract(root: &mut Table) -> Result<Vec<(String, Table)>, String> {
+    let Some(scope) = root.remove("scope") else {
+        return Ok(Vec::new());
+    };
+    let Value::Table(scope) = scope else {
+        return Err("[scope] must be a table of globs".to_string());
+    };
+    scope
+        .into_iter()
+        .map(|(pattern, section)| match section {
+            Value::Table(section) => Ok((pattern, section)),
+            _ => Err(format!("[scope.\"{}\"] must be a table", pattern)),
+        })
+        .collect()
+}
+
+/// Build each scope's config from the project table (without scopes or
+/// instances) and the scope's own section.
+pub(super) fn build(
+    root: &Table,
+    sections: Vec<(String, Table)>,
+) -> Result<Vec<PathScope>, String> {
+    sections
+        .into_iter()
+        .map(|(pattern, section)| build_one(root, pattern, section))
+        .collect()
+}
+
+pub(super) fn build_one(
+    root: &Table,
+    pattern: String,
+    mut section: Table,
+) -> Result<PathScope, String> {
+    let enable = check_list(&pattern, "enable", section.remove("enable"))?;
+    let disable = check_list(&pattern, "disable", section.remove("disable"))?;
+    let mut overrides = match section.remove("check") {
+        Some(Value::Table(overrides)) => overrides,
+        Some(_) => return Err(format!("scope '{}': check must be a table", pattern)),
+        None => Table::new(),
+    };
+    if let Some(key) = section.keys().next() {
+        return Err(format!(
+            "scope '{}': unknown field '{}' (expected enable, disable, check)",
+            pattern, key
+        ));
+    }
+
+    if let Some(check) = enable.iter().find(|c| disable.contains(c)) {
+        return Err(format!(
+            "scope '{}': check '{}' is both enabled and disabled",
+            pattern, check
+        ));
+    }
+    for check in overrides.keys().chain(&enable) {
+        if !CHECK_NAMES.contains(&check.as_str()) {
+            return Err(unknown_check(&pattern, check));
+        }
+        if !SCOPED_CHECKS.contains(&check.as_str()) {
+            return Err(format!(
+                "scope '{}': check '{}' can't be scoped (scoped checks: {})",
+                pattern,
+                check,
+                SCOPED_CHECKS.join(", ")
+            ));
+        }
+    }
+
+    // Enabled checks report errors unless the scope sets their level
+    for check in &enable {
+        if let Value::Table(overrides) = overrides
+            .entry(check.as_str())
+            .or_insert_with(|| Value::Table(Table::new()))
+        {
+            overrides
+                .entry("check")
+                .or_insert_with(|| Value::String("error".to_string()));
+        }
+    }
+
+    // Scope keys replace the project's keys, check by check
+    let mut merged = root.clone();
+    let checks = merged
+        .entry("check")
+        .or_insert_with(|| Value::Table(Table::new()));
+    if let Value::Table(checks) = checks {
+        for (check, keys) in &overrides {
+            let Value::Table(keys) = keys else {
+                return Err(format!(
+                    "scope '{}': check.{} must be a table",
+                    pattern, check
+                ));
+            };
+            let base = checks
+                .entry(check.as_str())
+                .or_insert_with(|| Value::Table(Table::new()));
+            if let Value::Table(base) = base {
+                base.extend(keys.clone());
+            }
+        }
+    }
+    let config: Config = Value::Table(merged)
+        .try_into()
+        .map_err(|e| format!("scope '{}': {}", pattern, e))?;
+
+    Ok(PathScope {
+        pattern,
+        enable,
+        disable,
+        section: overrides,
+        config: Box::new(config),
+    })
+}
+
+/// Parse a list of check names.
+fn check_list(pattern: &str, field: &str, value: Option<Value>) -> Result<Vec<String>, String> {
+    let Some(value) = value else {
+        return Ok(Vec::new());
+    };
+    let checks: Vec<String> = value
+        .try_into()
+        .map_err(|e| format!("scope '{}': {}: {}", pattern, field, e))?;
+    match checks.iter().find(|c| !CHECK_NAMES.contains(&c.as_str())) {
+        Some(check) => Err(unknown_check(pattern, check)),
+        None => Ok(checks),
+    }
+}
+
+fn unknown_check(pattern: &str, check: &str) -> String {
+    format!(
+        "scope '{}': unknown check '{}' (expected one of: {})",
+        pattern,
+        check,
+        CHECK_NAMES.join(", ")
+    )
+}
@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use std::path::PathBuf;
+
+fn parse_config(content: &str) -> Result<Config> {
+    parse(content, &PathBuf::from("quench.toml"))
+}
+
+#[test]
+fn scope_merges_check_overrides_over_project_sections() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[check.cloc]
+max_lines = 500
+max_lines_test = 900
+
+[scope."services/*"]
+disable = ["docs"]
+
+[scope."services/*".check.cloc]
+max_lines = 300
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.check.cloc.max_lines, 500);
+    assert_eq!(config.scopes.len(), 1);
+    let scope = &config.scopes[0];
+    assert_eq!(scope.pattern, "services/*");
+    assert_eq!(scope.disable, ["docs"]);
+    assert!(scope.disables("docs"));
+    assert!(scope.overrides("cloc"));
+    assert!(!scope.overrides("escapes"));
+    assert_eq!(scope.config.check.cloc.max_lines, 300);
+    assert_eq!(scope.config.check.cloc.max_lines_test, 900);
+}
+
+#[test]
+fn enabled_check_reports_errors_in_scope() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[scope."infra/**"]
+enable = ["whitespace"]
+"#,
+    )
+    .unwrap();
+
+    let scope = &config.scopes[0];
+    assert!(scope.overrides("whitespace"));
+    assert_eq!(scope.config.check.whitespace.check, CheckLevel::Error);
+    assert_eq!(config.check.whitespace.check, CheckLevel::Off);
+}
+
+#[test]
+fn enabled_check_keeps_scope_level() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[scope."infra/**"]
+enable = ["whitespace"]
+
+[scope."infra/**".check.whitespace]
+check = "warn"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.scopes[0].config.check.whitespace.check,
+        CheckLevel::Warn
+    );
+}
+
+#[test]
+fn scopes_are_ordered_by_glob() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[scope."services/*"]
+disable = ["docs"]
+
+[scope."libs/*"]
+disable = ["docs"]
+"#,
+    )
+    .unwrap();
+
+    let patterns: Vec<_> = config.scopes.iter().map(|s| s.pattern.as_str()).collect();
+    assert_eq!(patterns, ["libs/*", "services/*"]);
+}
+
+#[test]
+fn scope_of_unknown_check_is_rejected() {
+    let err = parse_config("version = 1\n[scope.\"libs/*\"]\ndisable = [\"style\"]\n").unwrap_err();
+    assert!(err.to_string().contains("unknown check 'style'"));
+}
+
+#[test]
+fn scope_override_of_unscoped_check_is_rejected() {
+    let err =
+        parse_config("version = 1\n[scope.\"libs/*\".check.build]\ncheck = \"off\"\n").unwrap_err();
+    assert!(err.to_string().contains("check 'build' can't be scoped"));
+}
+
+#[test]
+fn scope_enabling_and_disabling_same_check_is_rejected() {
+    let err = parse_config(
+        "version = 1\n[scope.\"libs/*\"]\nenable = [\"cloc\"]\ndisable = [\"cloc\"]\n",
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("both enabled and disabled"));
+}
+
+#[test]
+fn scope_unknown_field_is_rejected() {
+    let err = parse_config("version = 1\n[scope.\"libs/*\"]\nmax_lines = 5\n").unwrap_err();
+    assert!(err.to_string().contains("unknown field 'max_lines'"));
+}
+
+#[test]
+fn scope_invalid_override_is_rejected() {
+    let err =
+        parse_config("version = 1\n[scope.\"libs/*\".check.cloc]\nmax_line = 5\n").unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("scope 'libs/*'"), "{message}");
+    assert!(message.contains("max_line"), "{message}");
+}
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use std::path::PathBuf;
+
+// Quarantine, new code, tools, health, goals, and hotspots config tests
+
+#[test]
+fn quarantine_accepts_toml_and_string_dates() {
+    let path = PathBuf::from("quench.toml");
+    let content = r#"
+version = 1
+
+[[quarantine]]
+path = "spikes/"
+until = 2026-06-30
+reason = "parser prototype"
+
+[[quarantine]]
+path = "vendor/proto"
+until = "2026-07-01"
+
+[[quarantine]]
+path = "experimental"
+"#;
+    let config = parse(content, &path).unwrap();
+    let until: Vec<_> = config
+        .quarantine
+        .iter()
+        .map(|q| q.until.map(|d| d.to_string()))
+        .collect();
+    assert_eq!(
+        until,
+        vec![
+            Some("2026-06-30".to_string()),
+            Some("2026-07-01".to_string()),
+            None
+        ]
+    );
+    assert_eq!(
+        config.quarantine[0].reason.as_deref(),
+        Some("parser prototype")
+    );
+}
+
+#[test]
+fn quarantine_rejects_invalid_date() {
+    let path = PathBuf::from("quench.toml");
+    let content = "version = 1\n[[quarantine]]\npath = \"spikes\"\nuntil = \"June\"\n";
+    let err = parse(content, &path).unwrap_err();
+    assert!(err.to_string().contains("expected YYYY-MM-DD"));
+}
+
+#[test]
+fn new_code_since_accepts_dates_and_baseline() {
+    let path = PathBuf::from("quench.toml");
+    let since = |value: &str| {
+        let content = format!("version = 1\n[new_code]\nsince = {}\n", value);
+        parse(&content, &path).unwrap().new_code.since
+    };
+    let march = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+
+    assert_eq!(since("2026-03-01"), Some(NewCodeSince::Date(march)));
+    assert_eq!(since("\"2026-03-01\""), Some(NewCodeSince::Date(march)));
+    assert_eq!(since("\"baseline\""), Some(NewCodeSince::Baseline));
+    assert_eq!(parse("version = 1\n", &path).unwrap().new_code.since, None);
+}
+
+#[test]
+fn new_code_rejects_invalid_since() {
+    let path = PathBuf::from("quench.toml");
+    let content = "version = 1\n[new_code]\nsince = \"adoption\"\n";
+    let err = parse(content, &path).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("expected \"baseline\" or YYYY-MM-DD")
+    );
+}
+
+#[test]
+fn tools_parses_versions_and_on_mismatch() {
+    let path = PathBuf::from("quench.toml");
+    let content = "version = 1\n[tools]\ncargo = \"1.85\"\nnode = 20\non_mismatch = \"skip\"\n";
+
+    let tools = parse(content, &path).unwrap().tools;
+
+    assert_eq!(tools.on_mismatch, ToolMismatch::Skip);
+    assert_eq!(
+        tools.versions.get("cargo").map(String::as_str),
+        Some("1.85")
+    );
+    assert_eq!(tools.versions.get("node").map(String::as_str), Some("20"));
+    let default = parse("version = 1\n", &path).unwrap().tools;
+    assert_eq!(default.on_mismatch, ToolMismatch::Error);
+    assert!(default.versions.is_empty());
+}
+
+#[test]
+fn tools_rejects_unknown_tools_and_unquoted_decimals() {
+    let path = PathBuf::from("quench.toml");
+
+    let err = parse("version = 1\n[tools]\nmake = \"4\"\n", &path).unwrap_err();
+    assert!(err.to_string().contains("unknown tool 'make'"));
+
+    let err = parse("version = 1\n[tools]\ngo = 1.22\n", &path).unwrap_err();
+    assert!(err.to_string().contains("quote it"));
+}
+
+#[test]
+fn health_accepts_weight_or_full_form() {
+    let path = PathBuf::from("quench.toml");
+    let content =
+        "version = 1\n[health]\ncoverage = 5\nescapes = { weight = 1, good = 2, bad = 8 }\n";
+
+    let health = parse(content, &path).unwrap().health;
+
+    assert_eq!(health.coverage.weight, Some(5.0));
+    assert_eq!(health.coverage.good, None);
+    assert_eq!(
+        health.escapes,
+        HealthMetricConfig {
+            weight: Some(1.0),
+            good: Some(2.0),
+            bad: Some(8.0),
+        }
+    );
+    assert_eq!(health.lint, HealthMetricConfig::default());
+}
+
+#[test]
+fn health_rejects_unknown_metrics() {
+    let path = PathBuf::from("quench.toml");
+    assert!(parse("version = 1\n[health]\ncomplexity = 2\n", &path).is_err());
+    assert!(parse("version = 1\n[health]\ncoverage = { wieght = 2 }\n", &path).is_err());
+}
+
+#[test]
+fn goals_parse_dotted_targets() {
+    let path = PathBuf::from("quench.toml");
+    let content = "version = 1\n[goals]\ncoverage.target = 85\nescapes.unwrap.target = 0\n";
+
+    let goals = parse(content, &path).unwrap().goals;
+
+    assert_eq!(goals.coverage, Some(GoalConfig { target: 85.0 }));
+    assert_eq!(goals.escapes["unwrap"], GoalConfig { target: 0.0 });
+}
+
+#[test]
+fn goals_reject_unknown_keys() {
+    let path = PathBuf::from("quench.toml");
+    assert!(parse("version = 1\n[goals]\ncoverage.goal = 85\n", &path).is_err());
+    assert!(parse("version = 1\n[goals]\nlatency.target = 5\n", &path).is_err());
+}
+
+#[test]
+fn hotspots_defaults_and_overrides() {
+    let path = PathBuf::from("quench.toml");
+
+    let defaults = parse("version = 1\n", &path).unwrap().hotspots;
+    assert_eq!(defaults.window, 90);
+    assert_eq!(defaults.check, CheckLevel::Off);
+
+    let content = "version = 1\n[hotspots]\nwindow = 30\nchurn = 5\ncheck = \"warn\"\n";
+    let hotspots = parse(content, &path).unwrap().hotspots;
+    assert_eq!(hotspots.window, 30);
+    assert_eq!(hotspots.churn, 5);
+    assert_eq!(hotspots.lines, 400);
+    assert_eq!(hotspots.check, CheckLevel::Warn);
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Snapshot test management check configuration.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::CheckLevel;
+
+/// Snapshots check configuration. Off unless `check` is set.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct SnapshotsConfig {
+    /// Check level (default: off).
+    pub check: CheckLevel,
+
+    /// Snapshot file patterns (default: `**/*.snap`, covering insta and
+    /// jest/vitest `__snapshots__`).
+    pub patterns: Vec<String>,
+
+    /// Flag snapshots whose test is gone (default: true).
+    pub orphans: bool,
+
+    /// Largest allowed snapshot file, e.g. "64KB" (default: "64KB").
+    pub max_size: String,
+
+    /// Most snapshot lines a branch may change with `--base` or `--staged`
+    /// (default: 1000; 0 disables).
+    pub max_churn: usize,
+
+    /// Exclude patterns (snapshots matching these aren't checked).
+    pub exclude: Vec<String>,
+}
+
+impl Default for SnapshotsConfig {
+    fn default() -> Self {
+        Self {
+            check: CheckLevel::Off,
+            patterns: vec!["**/*.snap".to_string()],
+            orphans: true,
+            max_size: "64KB".to_string(),
+            max_churn: 1000,
+            exclude: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Result storage configuration.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Where run history, per-file results, and baselines are kept (`[store]`).
+///
+/// ```toml
+/// [store]
+/// backend = "sqlite"            # Needs quench built with the `sqlite` feature
+/// path = ".quench/quench.db"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct StoreConfig {
+    /// Storage backend: "json" | "sqlite" (default: "json").
+    pub backend: StoreBackend,
+
+    /// Database file for the sqlite backend (default: ".quench/quench.db").
+    pub path: String,
+}
+
+impl Default for StoreConfig {
+    fn default() -> Self {
+        Self {
+            backend: StoreBackend::default(),
+            path: ".quench/quench.db".to_string(),
+        }
+    }
+}
+
+impl StoreConfig {
+    /// Whether runs are recorded in the sqlite database.
+    pub fn uses_sqlite(&self) -> bool {
+        self.backend == StoreBackend::Sqlite
+    }
+}
+
+/// Storage backend for results and history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum StoreBackend {
+    /// Baselines in git notes or `[git] baseline`, bisect results in `.quench/bisect/` (default).
+    #[default]
+    Json,
+    /// Every run, per-file result, and baseline update in one SQLite database.
+    Sqlite,
+}
@@ -5,11 +5,12 @@
 //!
 //! Used by Rust, Go, and Shell language adapters.
 
+use schemars::JsonSchema;
 use serde::de::{self, MapAccess, Visitor};
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Lint suppression configuration for #[allow(...)] and #[expect(...)].
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SuppressConfig {
     /// Check level: forbid, comment, or allow (default: "comment").
@@ -104,9 +105,16 @@ fn merge_with_defaults(
 ///
 /// NOTE: Uses custom deserializer to accept arbitrary lint codes as fields
 /// (e.g., `dead_code = "// REASON:"`), which are parsed into the `patterns` map.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[schemars(extend("additionalProperties" = {
+    "title": "<lint>",
+    "type": "array",
+    "items": { "type": "string" },
+    "description": "Comment prefixes that justify suppressing the named lint code."
+}))]
 pub struct SuppressScopeConfig {
     /// Override check level for this scope.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub check: Option<SuppressLevel>,
 
     /// Lint codes that don't require comments (per-code allow list).
@@ -118,6 +126,7 @@ pub struct SuppressScopeConfig {
     /// Per-lint-code comment patterns. Maps lint code to list of valid comment prefixes.
     /// Any of the patterns is accepted.
     /// Example: {"dead_code" => ["// KEEP UNTIL:", "// NOTE(compat):"]}
+    #[serde(flatten)]
     pub patterns: std::collections::HashMap<String, Vec<String>>,
 }
 
@@ -275,7 +284,7 @@ impl SuppressScopeConfig {
 }
 
 /// Suppress check level.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum SuppressLevel {
     /// Never allowed - any suppression fails.
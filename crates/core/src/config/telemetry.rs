@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Opt-in telemetry configuration.
+
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::duration;
+
+/// Where to send anonymized run summaries. Disabled unless `endpoint` is set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct TelemetryConfig {
+    /// `http://` or `https://` URL that receives each run summary as a JSON POST.
+    pub endpoint: Option<String>,
+
+    /// Upload time limit (default: 5s).
+    #[serde(
+        deserialize_with = "duration::deserialize_option",
+        serialize_with = "duration::serialize_option"
+    )]
+    #[schemars(schema_with = "duration::option_schema")]
+    pub timeout: Option<Duration>,
+}
+
+impl TelemetryConfig {
+    /// Upload time limit when none is configured.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Effective upload time limit.
+    pub fn timeout(&self) -> Duration {
+        self.timeout.unwrap_or(Self::DEFAULT_TIMEOUT)
+    }
+}
@@ -3,14 +3,15 @@
 
 //! Test suite configuration.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use serde::Deserialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 use super::duration;
 
 /// Tests check configuration.
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct TestsConfig {
     /// Check level: "error" | "warn" | "off"
@@ -38,7 +39,7 @@ pub struct TestsConfig {
 }
 
 /// Configuration for a single test suite.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TestSuiteConfig {
     /// Runner name: "cargo", "bats", "pytest", etc.
@@ -69,35 +70,64 @@ pub struct TestSuiteConfig {
     pub ci: bool,
 
     /// Maximum total time for this suite.
-    #[serde(default, deserialize_with = "duration::deserialize_option")]
+    #[serde(
+        default,
+        deserialize_with = "duration::deserialize_option",
+        serialize_with = "duration::serialize_option"
+    )]
+    #[schemars(schema_with = "duration::option_schema")]
     pub max_total: Option<std::time::Duration>,
 
     /// Maximum average time per test.
-    #[serde(default, deserialize_with = "duration::deserialize_option")]
+    #[serde(
+        default,
+        deserialize_with = "duration::deserialize_option",
+        serialize_with = "duration::serialize_option"
+    )]
+    #[schemars(schema_with = "duration::option_schema")]
     pub max_avg: Option<std::time::Duration>,
 
     /// Maximum time for slowest individual test.
-    #[serde(default, deserialize_with = "duration::deserialize_option")]
+    #[serde(
+        default,
+        deserialize_with = "duration::deserialize_option",
+        serialize_with = "duration::serialize_option"
+    )]
+    #[schemars(schema_with = "duration::option_schema")]
     pub max_test: Option<std::time::Duration>,
 
     /// Timeout for suite execution (kills process if exceeded).
-    #[serde(default, deserialize_with = "duration::deserialize_option")]
+    #[serde(
+        default,
+        deserialize_with = "duration::deserialize_option",
+        serialize_with = "duration::serialize_option"
+    )]
+    #[schemars(schema_with = "duration::option_schema")]
     pub timeout: Option<std::time::Duration>,
+
+    /// Container image to run the suite in (e.g., "node:20").
+    #[serde(default)]
+    pub container: Option<String>,
 }
 
 /// Time limit configuration for test suites.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct TestsTimeConfig {
     /// Check level: "error" | "warn" | "off"
     #[serde(default = "TestsTimeConfig::default_check")]
     pub check: String,
+
+    /// Slowest tests recorded per suite (0 to disable).
+    #[serde(default = "TestsTimeConfig::default_slowest")]
+    pub slowest: usize,
 }
 
 impl Default for TestsTimeConfig {
     fn default() -> Self {
         Self {
             check: Self::default_check(),
+            slowest: Self::default_slowest(),
         }
     }
 }
@@ -106,10 +136,14 @@ impl TestsTimeConfig {
     fn default_check() -> String {
         "warn".to_string()
     }
+
+    fn default_slowest() -> usize {
+        5
+    }
 }
 
 /// Coverage threshold configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct TestsCoverageConfig {
     /// Check level: "error" | "warn" | "off"
@@ -120,16 +154,30 @@ pub struct TestsCoverageConfig {
     #[serde(default)]
     pub min: Option<f64>,
 
+    /// Minimum coverage percentage (0-100) of the lines changed since
+    /// `--base` (patch coverage).
+    #[serde(default)]
+    pub patch_min: Option<f64>,
+
     /// Per-package coverage thresholds.
     #[serde(default)]
     pub package: HashMap<String, TestsPackageCoverageConfig>,
+
+    /// Per-path coverage thresholds, keyed by glob (e.g., "src/core/**").
+    #[serde(default)]
+    pub path: BTreeMap<String, TestsPackageCoverageConfig>,
+
+    /// Record which files each test executes into `.quench/impact.bin`
+    /// (default: false). Only runners with per-test coverage contribute.
+    #[serde(default)]
+    pub impact: bool,
 }
 
-/// Per-package coverage threshold.
-#[derive(Debug, Clone, Deserialize)]
+/// Per-package or per-path coverage threshold.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TestsPackageCoverageConfig {
-    /// Minimum coverage percentage for this package.
+    /// Minimum coverage percentage for this package or path.
     pub min: f64,
 }
 
@@ -138,7 +186,10 @@ impl Default for TestsCoverageConfig {
         Self {
             check: Self::default_check(),
             min: None,
+            patch_min: None,
             package: HashMap::new(),
+            path: BTreeMap::new(),
+            impact: false,
         }
     }
 }
@@ -150,7 +201,7 @@ impl TestsCoverageConfig {
 }
 
 /// Tests commit check configuration.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct TestsCommitConfig {
     /// Check level: "error" | "warn" | "off"
@@ -168,6 +219,11 @@ pub struct TestsCommitConfig {
     /// Excluded patterns (never require tests).
     #[serde(default = "TestsCommitConfig::default_exclude")]
     pub exclude: Vec<String>,
+
+    /// Mechanical changes (lockfiles, snapshots, generated artifacts):
+    /// never require tests and are reported separately.
+    #[serde(default = "TestsCommitConfig::default_mechanical")]
+    pub mechanical: Vec<String>,
 }
 
 impl Default for TestsCommitConfig {
@@ -177,6 +233,7 @@ impl Default for TestsCommitConfig {
             scope: Self::default_scope(),
             placeholders: Self::default_placeholders(),
             exclude: Self::default_exclude(),
+            mechanical: Self::default_mechanical(),
         }
     }
 }
@@ -197,4 +254,30 @@ impl TestsCommitConfig {
     fn default_exclude() -> Vec<String> {
         vec![] // Empty = inherit language-aware defaults
     }
+
+    fn default_mechanical() -> Vec<String> {
+        [
+            // Lockfiles
+            "**/Cargo.lock",
+            "**/package-lock.json",
+            "**/npm-shrinkwrap.json",
+            "**/yarn.lock",
+            "**/pnpm-lock.yaml",
+            "**/bun.lock",
+            "**/bun.lockb",
+            "**/go.sum",
+            "**/Gemfile.lock",
+            "**/poetry.lock",
+            "**/uv.lock",
+            "**/Pipfile.lock",
+            // Snapshots
+            "**/__snapshots__/**",
+            "**/*.snap",
+            // Generated artifacts
+            "**/*.generated.*",
+            "**/*.pb.go",
+        ]
+        .map(String::from)
+        .to_vec()
+    }
 }
@@ -31,6 +31,7 @@ fn tests_commit_config_defaults() {
 fn tests_time_config_defaults() {
     let config = parse_config("version = 1\n");
     assert_eq!(config.check.tests.time.check, "warn");
+    assert_eq!(config.check.tests.time.slowest, 5);
 }
 
 #[test]
@@ -39,6 +40,13 @@ fn tests_coverage_config_defaults() {
     assert_eq!(config.check.tests.coverage.check, "warn");
     assert!(config.check.tests.coverage.min.is_none());
     assert!(config.check.tests.coverage.package.is_empty());
+    assert!(!config.check.tests.coverage.impact);
+}
+
+#[test]
+fn tests_coverage_impact_enabled() {
+    let config = parse_config("version = 1\n[check.tests.coverage]\nimpact = true\n");
+    assert!(config.check.tests.coverage.impact);
 }
 
 #[test]
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Pinned tool version configuration.
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Expected toolchain versions, verified when `quench check` starts.
+///
+/// ```toml
+/// [tools]
+/// cargo = "1.85"
+/// node = "20"
+/// on_mismatch = "skip"
+/// ```
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
+#[schemars(extend("additionalProperties" = {
+    "title": "<tool>",
+    "type": "string",
+    "description": "Expected version prefix for the named tool (e.g., `cargo = \"1.85\"`)."
+}))]
+pub struct ToolsConfig {
+    /// What a mismatched or missing tool does to the checks that use it.
+    pub on_mismatch: ToolMismatch,
+    /// Tool name -> expected version prefix (e.g., "cargo" -> "1.85").
+    #[serde(flatten)]
+    pub versions: BTreeMap<String, String>,
+}
+
+/// Outcome for checks that use a mismatched tool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ToolMismatch {
+    /// Run the checks, failing them with a `tool_version` violation.
+    #[default]
+    Error,
+    /// Skip the checks, with the mismatch as the reason.
+    Skip,
+}
+
+impl<'de> Deserialize<'de> for ToolsConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let table = BTreeMap::<String, toml::Value>::deserialize(deserializer)?;
+        let mut config = ToolsConfig::default();
+        for (key, value) in table {
+            if key == "on_mismatch" {
+                config.on_mismatch = match value.as_str() {
+                    Some("error") => ToolMismatch::Error,
+                    Some("skip") => ToolMismatch::Skip,
+                    _ => {
+                        return Err(D::Error::custom(format!(
+                            "invalid on_mismatch {}, expected \"error\" or \"skip\"",
+                            value
+                        )));
+                    }
+                };
+                continue;
+            }
+            if crate::tools::find(&key).is_none() {
+                return Err(D::Error::custom(format!(
+                    "unknown tool '{}' in [tools] (known: {})",
+                    key,
+                    crate::tools::names().join(", ")
+                )));
+            }
+            let version = match value {
+                toml::Value::String(s) => s,
+                toml::Value::Integer(n) => n.to_string(),
+                other => {
+                    return Err(D::Error::custom(format!(
+                        "invalid version for tool '{}': {} (quote it, e.g. \"1.22\")",
+                        key, other
+                    )));
+                }
+            };
+            config.versions.insert(key, version);
+        }
+        Ok(config)
+    }
+}
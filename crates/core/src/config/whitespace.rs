@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Whitespace hygiene check configuration.
+
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{CheckLevel, Sample};
+
+/// Whitespace check configuration. Off unless `check` is set, since
+/// formatters usually cover this.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct WhitespaceConfig {
+    /// Check level (default: off).
+    pub check: CheckLevel,
+
+    /// Required line endings (default: lf).
+    pub line_endings: LineEndings,
+
+    /// Flag trailing spaces and tabs (default: true).
+    pub trailing: bool,
+
+    /// Require a newline at the end of non-empty files (default: true).
+    pub final_newline: bool,
+
+    /// Indentation policy for languages without an override (default: any).
+    pub indent: IndentStyle,
+
+    /// Indentation policy per language, keyed by lowercase language name
+    /// as shown by `quench cloc` (e.g., `go = "tabs"`).
+    pub languages: BTreeMap<String, IndentStyle>,
+
+    /// Columns per tab when converting indentation (default: 4).
+    pub tab_width: usize,
+
+    /// Exclude patterns (files matching these won't be checked).
+    pub exclude: Vec<String>,
+
+    /// Share of files to check per fast-mode run (e.g., "10%"); CI mode
+    /// checks every file.
+    pub sample: Option<Sample>,
+}
+
+impl Default for WhitespaceConfig {
+    fn default() -> Self {
+        Self {
+            check: CheckLevel::Off,
+            line_endings: LineEndings::Lf,
+            trailing: true,
+            final_newline: true,
+            indent: IndentStyle::Any,
+            languages: BTreeMap::new(),
+            tab_width: 4,
+            exclude: Vec::new(),
+            sample: None,
+        }
+    }
+}
+
+impl WhitespaceConfig {
+    /// Indentation policy for a language name.
+    pub fn indent_for(&self, language: &str) -> IndentStyle {
+        self.languages
+            .get(&language.to_lowercase())
+            .copied()
+            .unwrap_or(self.indent)
+    }
+}
+
+/// Line ending policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEndings {
+    Lf,
+    Crlf,
+    /// Don't check line endings.
+    Any,
+}
+
+/// Indentation policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum IndentStyle {
+    Spaces,
+    Tabs,
+    /// Don't check indentation.
+    Any,
+}
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Running commands inside a container image.
+//!
+//! A test suite or build with `container = "<image>"` runs through
+//! `docker run` (or `podman run`) with the project root mounted at the same
+//! path inside the container. Output files and coverage reports are written
+//! to the mount, so they land exactly where quench reads them on the host.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+
+use crate::env;
+
+/// Container engines, in detection order.
+pub const ENGINES: &[&str] = &["docker", "podman"];
+
+// Cache engine detection to avoid repeated checks
+static DETECTED_ENGINE: OnceLock<Option<String>> = OnceLock::new();
+
+/// A container image that commands run in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Container {
+    engine: String,
+    image: String,
+    root: PathBuf,
+}
+
+impl Container {
+    /// Run commands in `image` via `engine`, with `root` mounted.
+    pub fn new(engine: impl Into<String>, image: impl Into<String>, root: &Path) -> Self {
+        Self {
+            engine: engine.into(),
+            image: image.into(),
+            root: root.to_path_buf(),
+        }
+    }
+
+    /// Run commands in `image` via the engine named by
+    /// `QUENCH_CONTAINER_ENGINE`, else the first of [`ENGINES`] installed.
+    pub fn detect(image: &str, root: &Path) -> Result<Self, String> {
+        let engine = env::quench_container_engine()
+            .or_else(|| DETECTED_ENGINE.get_or_init(detect_engine).clone())
+            .ok_or_else(|| {
+                format!(
+                    "container {} needs a container engine ({})",
+                    image,
+                    ENGINES.join(" or ")
+                )
+            })?;
+        Ok(Self::new(engine, image, root))
+    }
+
+    /// Rewrite `cmd` to run inside the container.
+    ///
+    /// The working directory and explicitly set environment variables carry
+    /// over; stdio is left for the caller to configure.
+    pub fn wrap(&self, cmd: &Command) -> Command {
+        let mut wrapped = Command::new(&self.engine);
+        wrapped.args(["run", "--rm"]);
+
+        let mount = self.root.display().to_string();
+        wrapped.arg("-v").arg(format!("{}:{}", mount, mount));
+        let work_dir = cmd.get_current_dir().unwrap_or(&self.root);
+        wrapped.arg("-w").arg(work_dir);
+        wrapped.args(self.user_args());
+
+        for (key, value) in cmd.get_envs() {
+            if let Some(value) = value {
+                let mut pair = key.to_os_string();
+                pair.push("=");
+                pair.push(value);
+                wrapped.arg("-e").arg(pair);
+            }
+        }
+
+        wrapped.arg(&self.image);
+        wrapped.arg(cmd.get_program());
+        wrapped.args(cmd.get_args());
+        wrapped.current_dir(&self.root);
+        wrapped
+    }
+
+    /// Arguments that keep files written to the mount owned by the project's
+    /// owner rather than the container's root user.
+    fn user_args(&self) -> Vec<String> {
+        if self.engine.ends_with("podman") {
+            // Rootless podman maps the invoking user into the container
+            return vec!["--userns=keep-id".to_string()];
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            if let Ok(meta) = self.root.metadata() {
+                return vec![
+                    "--user".to_string(),
+                    format!("{}:{}", meta.uid(), meta.gid()),
+                ];
+            }
+        }
+        Vec::new()
+    }
+}
+
+/// `cmd` run inside `container`, or unchanged without one.
+pub fn wrap(container: Option<&Container>, cmd: Command) -> Command {
+    match container {
+        Some(container) => container.wrap(&cmd),
+        None => cmd,
+    }
+}
+
+/// The first installed engine of [`ENGINES`].
+fn detect_engine() -> Option<String> {
+    ENGINES
+        .iter()
+        .find(|engine| {
+            Command::new(engine)
+                .arg("--version")
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .is_ok_and(|s| s.success())
+        })
+        .map(|engine| engine.to_string())
+}
+
+#[cfg(test)]
+#[path = "container_tests.rs"]
+mod tests;
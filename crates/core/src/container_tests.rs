@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+fn args(cmd: &Command) -> Vec<String> {
+    cmd.get_args()
+        .map(|a| a.to_string_lossy().into_owned())
+        .collect()
+}
+
+#[test]
+fn wrap_runs_command_in_image_with_root_mounted_at_same_path() {
+    let container = Container::new("podman", "node:20", Path::new("/work"));
+    let mut cmd = Command::new("npx");
+    cmd.args(["jest", "--coverage"]);
+    cmd.current_dir("/work/packages/ui");
+
+    let wrapped = container.wrap(&cmd);
+
+    assert_eq!(wrapped.get_program(), "podman");
+    assert_eq!(
+        args(&wrapped),
+        [
+            "run",
+            "--rm",
+            "-v",
+            "/work:/work",
+            "-w",
+            "/work/packages/ui",
+            "--userns=keep-id",
+            "node:20",
+            "npx",
+            "jest",
+            "--coverage",
+        ]
+    );
+    assert_eq!(wrapped.get_current_dir(), Some(Path::new("/work")));
+}
+
+#[test]
+fn wrap_defaults_working_directory_to_root() {
+    let container = Container::new("podman", "golang:1.22", Path::new("/work"));
+
+    let wrapped = container.wrap(&Command::new("go"));
+
+    let args = args(&wrapped);
+    let w = args.iter().position(|a| a == "-w").unwrap();
+    assert_eq!(args[w + 1], "/work");
+}
+
+#[test]
+fn wrap_passes_explicit_environment() {
+    let container = Container::new("podman", "rust:1.85", Path::new("/work"));
+    let mut cmd = Command::new("cargo");
+    cmd.env("RUSTFLAGS", "-C instrument-coverage");
+    cmd.env_remove("CARGO_TARGET_DIR");
+
+    let wrapped = container.wrap(&cmd);
+
+    let args = args(&wrapped);
+    let e = args.iter().position(|a| a == "-e").unwrap();
+    assert_eq!(args[e + 1], "RUSTFLAGS=-C instrument-coverage");
+    assert_eq!(args.iter().filter(|a| *a == "-e").count(), 1);
+}
+
+#[cfg(unix)]
+#[test]
+fn docker_runs_as_owner_of_project_root() {
+    use std::os::unix::fs::MetadataExt;
+
+    let root = tempfile::tempdir().unwrap();
+    let meta = root.path().metadata().unwrap();
+    let container = Container::new("docker", "node:20", root.path());
+
+    let args = args(&container.wrap(&Command::new("node")));
+
+    let user = args.iter().position(|a| a == "--user").unwrap();
+    assert_eq!(args[user + 1], format!("{}:{}", meta.uid(), meta.gid()));
+}
+
+#[test]
+fn wrap_without_container_keeps_command() {
+    let mut cmd = Command::new("cargo");
+    cmd.arg("test");
+
+    let cmd = wrap(None, cmd);
+
+    assert_eq!(cmd.get_program(), "cargo");
+    assert_eq!(args(&cmd), ["test"]);
+}
@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Technical debt ranking for `quench report --debt`.
+//!
+//! Scores each source file from five factors, each 0-1: lines over the
+//! cloc limit, escape density, missing tests, low coverage, and recent git
+//! churn. The debt score is their mean scaled to 0-100, so a file only
+//! tops the list when several factors stack up.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::adapter::project::apply_language_defaults;
+use crate::adapter::{AdapterRegistry, FileKind};
+use crate::checks::escapes::EscapeMatcher;
+use crate::checks::testing::correlation::TestIndex;
+use crate::cloc;
+use crate::config::{Config, LineMetric};
+use crate::file_reader::FileContent;
+use crate::git;
+use crate::walker::{FileWalker, WalkerConfig};
+
+/// Per-file coverage cache name within `.quench/`.
+pub const FILE_COVERAGE_NAME: &str = "coverage.json";
+
+/// Escapes per 1000 lines at which the escape factor saturates.
+const ESCAPE_DENSITY_MAX: f64 = 10.0;
+
+/// Coverage at or above this adds no debt.
+const COVERAGE_GOOD: f64 = 80.0;
+
+/// Coverage at or below this is full debt.
+const COVERAGE_BAD: f64 = 40.0;
+
+/// What debt scoring needs to know about one source file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileFacts {
+    /// Path relative to the project root.
+    pub path: String,
+    /// Line count, per `[check.cloc] metric`.
+    pub lines: usize,
+    /// Escape hatch matches.
+    pub escapes: usize,
+    /// Whether a correlated test file or inline tests exist.
+    pub has_test: bool,
+    /// Line coverage percent from the last `quench check --ci` run.
+    pub coverage: Option<f64>,
+    /// Commits touching the file in the last `[hotspots] window` days.
+    pub churn: usize,
+}
+
+/// A ranked file with its debt score.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileDebt {
+    pub path: String,
+    /// Debt score, 0-100.
+    pub score: f64,
+    pub lines: usize,
+    pub escapes: usize,
+    pub has_test: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<f64>,
+    pub churn: usize,
+    /// Factor scores, 0-1, by name (`size`, `escapes`, `tests`, `coverage`, `churn`).
+    pub factors: BTreeMap<&'static str, f64>,
+}
+
+/// Score `files` and keep the `top` with the most debt, worst first.
+///
+/// Files without any debt are dropped; ties sort by path.
+pub fn rank(files: &[FileFacts], max_lines: usize, top: usize) -> Vec<FileDebt> {
+    let max_churn = files.iter().map(|f| f.churn).max().unwrap_or(0);
+    let mut ranked: Vec<FileDebt> = files
+        .iter()
+        .map(|file| score(file, max_lines, max_churn))
+        .filter(|debt| debt.score > 0.0)
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    ranked.truncate(top);
+    ranked
+}
+
+fn score(file: &FileFacts, max_lines: usize, max_churn: usize) -> FileDebt {
+    let size = if max_lines > 0 && file.lines > max_lines {
+        ((file.lines - max_lines) as f64 / max_lines as f64).min(1.0)
+    } else {
+        0.0
+    };
+    let escapes = if file.lines > 0 {
+        let density = file.escapes as f64 * 1000.0 / file.lines as f64;
+        (density / ESCAPE_DENSITY_MAX).min(1.0)
+    } else {
+        0.0
+    };
+    let tests = if file.has_test { 0.0 } else { 1.0 };
+    let coverage = file.coverage.map_or(0.0, |pct| {
+        ((COVERAGE_GOOD - pct) / (COVERAGE_GOOD - COVERAGE_BAD)).clamp(0.0, 1.0)
+    });
+    let churn = if max_churn > 0 {
+        file.churn as f64 / max_churn as f64
+    } else {
+        0.0
+    };
+
+    let factors = BTreeMap::from([
+        ("size", size),
+        ("escapes", escapes),
+        ("tests", tests),
+        ("coverage", coverage),
+        ("churn", churn),
+    ]);
+    let mean = factors.values().sum::<f64>() / factors.len() as f64;
+    FileDebt {
+        path: file.path.clone(),
+        score: (mean * 1000.0).round() / 10.0,
+        lines: file.lines,
+        escapes: file.escapes,
+        has_test: file.has_test,
+        coverage: file.coverage,
+        churn: file.churn,
+        factors: factors
+            .into_iter()
+            .map(|(name, value)| (name, (value * 100.0).round() / 100.0))
+            .collect(),
+    }
+}
+
+/// Gather [`FileFacts`] for every source file under `root`.
+///
+/// Churn is read from git when `root` is in a repository, and coverage
+/// from the per-file cache the tests check leaves behind.
+pub fn collect(root: &Path, config: &mut Config) -> anyhow::Result<Vec<FileFacts>> {
+    let mut exclude_patterns = apply_language_defaults(root, config);
+    for pattern in &config.check.cloc.exclude {
+        if !exclude_patterns.contains(pattern) {
+            exclude_patterns.push(pattern.clone());
+        }
+    }
+    let walker = FileWalker::new(WalkerConfig {
+        exclude_patterns,
+        ..WalkerConfig::for_project(&config.project)
+    });
+    let (walked, _) = walker.walk_collect(root);
+
+    let registry = AdapterRegistry::for_project_with_config(root, config);
+    let escapes = EscapeMatcher::new(root, config)?;
+    let coverage = load_file_coverage(&file_coverage_path(root))?;
+    let churn = if git::is_git_repo(root) {
+        git::file_churn(root, churn_since(config.hotspots.window))?
+    } else {
+        BTreeMap::new()
+    };
+
+    let mut sources = Vec::new();
+    let mut tests = Vec::new();
+    for file in walked {
+        let ext = match file.path.extension().and_then(|e| e.to_str()) {
+            Some(e) => e.to_lowercase(),
+            None => continue,
+        };
+        if !cloc::is_text_extension(&ext) {
+            continue;
+        }
+        let relative = file.path.strip_prefix(root).unwrap_or(&file.path);
+        match registry.classify(relative) {
+            FileKind::Source => sources.push((relative.to_path_buf(), file.path, ext)),
+            FileKind::Test => tests.push(relative.to_path_buf()),
+            FileKind::Other => {}
+        }
+    }
+    let index = TestIndex::new(&tests);
+
+    let mut facts = Vec::new();
+    for (relative, path, ext) in sources {
+        let Ok(content) = FileContent::read(&path) else {
+            continue;
+        };
+        let Some(text) = content.as_str() else {
+            continue;
+        };
+        let metrics = cloc::count_file_metrics(text, &ext);
+        let key = relative.display().to_string();
+        facts.push(FileFacts {
+            lines: match config.check.cloc.metric {
+                LineMetric::Lines => metrics.lines,
+                LineMetric::Nonblank => metrics.nonblank,
+            },
+            escapes: text
+                .lines()
+                .map(|line| escapes.matches(&relative, line).len())
+                .sum(),
+            has_test: index.has_test_for(&relative)
+                || index.has_test_at_location(&relative)
+                || (ext == "rs" && text.contains("#[cfg(test)]")),
+            coverage: coverage.get(&key).copied(),
+            churn: churn.get(&key).copied().unwrap_or(0),
+            path: key,
+        });
+    }
+    Ok(facts)
+}
+
+/// Unix timestamp `window` days ago, where churn counting starts.
+pub fn churn_since(window: u32) -> i64 {
+    chrono::Utc::now().timestamp() - i64::from(window) * 86_400
+}
+
+/// Path of the per-file coverage cache under a project root.
+pub fn file_coverage_path(root: &Path) -> PathBuf {
+    root.join(".quench").join(FILE_COVERAGE_NAME)
+}
+
+/// Load per-file coverage by root-relative path; empty when none is cached.
+pub fn load_file_coverage(path: &Path) -> anyhow::Result<BTreeMap<String, f64>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(serde_json::from_str(&content)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Write per-file coverage atomically via a temp file.
+pub fn save_file_coverage(path: &Path, coverage: &BTreeMap<String, f64>) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    crate::atomic::write(path, serde_json::to_string_pretty(coverage)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "debt_tests.rs"]
+mod tests;
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use tempfile::TempDir;
+
+use super::*;
+
+fn facts(path: &str) -> FileFacts {
+    FileFacts {
+        path: path.to_string(),
+        lines: 100,
+        has_test: true,
+        ..Default::default()
+    }
+}
+
+// =============================================================================
+// SCORING TESTS
+// =============================================================================
+
+#[test]
+fn clean_file_has_no_debt() {
+    assert!(rank(&[facts("src/lib.rs")], 750, 20).is_empty());
+}
+
+#[test]
+fn each_factor_contributes_a_fifth() {
+    let untested = FileFacts {
+        has_test: false,
+        ..facts("src/lib.rs")
+    };
+
+    let ranked = rank(&[untested], 750, 20);
+    assert_eq!(ranked[0].score, 20.0);
+    assert_eq!(ranked[0].factors["tests"], 1.0);
+    assert_eq!(ranked[0].factors["size"], 0.0);
+}
+
+#[test]
+fn size_factor_scales_over_limit() {
+    let big = FileFacts {
+        lines: 150,
+        ..facts("src/big.rs")
+    };
+    let huge = FileFacts {
+        lines: 400,
+        ..facts("src/huge.rs")
+    };
+
+    let ranked = rank(&[big, huge], 100, 20);
+    assert_eq!(ranked[0].path, "src/huge.rs");
+    assert_eq!(ranked[0].factors["size"], 1.0);
+    assert_eq!(ranked[1].factors["size"], 0.5);
+}
+
+#[test]
+fn escape_factor_uses_density() {
+    // 1 escape in 100 lines is 10 per 1000, the saturation point
+    let file = FileFacts {
+        escapes: 1,
+        ..facts("src/lib.rs")
+    };
+
+    let ranked = rank(&[file], 750, 20);
+    assert_eq!(ranked[0].factors["escapes"], 1.0);
+}
+
+#[test]
+fn coverage_factor_between_good_and_bad() {
+    let file = FileFacts {
+        coverage: Some(60.0),
+        ..facts("src/lib.rs")
+    };
+
+    let ranked = rank(&[file], 750, 20);
+    assert_eq!(ranked[0].factors["coverage"], 0.5);
+    assert_eq!(ranked[0].score, 10.0);
+}
+
+#[test]
+fn churn_factor_relative_to_busiest_file() {
+    let busy = FileFacts {
+        churn: 10,
+        ..facts("src/busy.rs")
+    };
+    let quiet = FileFacts {
+        churn: 5,
+        ..facts("src/quiet.rs")
+    };
+
+    let ranked = rank(&[quiet, busy], 750, 20);
+    assert_eq!(ranked[0].path, "src/busy.rs");
+    assert_eq!(ranked[0].factors["churn"], 1.0);
+    assert_eq!(ranked[1].factors["churn"], 0.5);
+}
+
+#[test]
+fn rank_keeps_top_n_with_ties_by_path() {
+    let files: Vec<FileFacts> = ["src/c.rs", "src/a.rs", "src/b.rs"]
+        .iter()
+        .map(|path| FileFacts {
+            has_test: false,
+            ..facts(path)
+        })
+        .collect();
+
+    let ranked = rank(&files, 750, 2);
+    let paths: Vec<&str> = ranked.iter().map(|d| d.path.as_str()).collect();
+    assert_eq!(paths, ["src/a.rs", "src/b.rs"]);
+}
+
+// =============================================================================
+// COLLECTION TESTS
+// =============================================================================
+
+#[test]
+fn collect_gathers_source_file_facts() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        "[package]\nname = \"t\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all(temp.path().join("src")).unwrap();
+    std::fs::create_dir_all(temp.path().join("tests")).unwrap();
+    std::fs::write(
+        temp.path().join("src/parser.rs"),
+        "pub fn parse() {\n    let x = 1;\n    unsafe { read(x) }\n}\n",
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("src/lexer.rs"), "pub fn lex() {}\n").unwrap();
+    std::fs::write(
+        temp.path().join("tests/parser_tests.rs"),
+        "#[test]\nfn t() {}\n",
+    )
+    .unwrap();
+    save_file_coverage(
+        &file_coverage_path(temp.path()),
+        &BTreeMap::from([("src/parser.rs".to_string(), 55.0)]),
+    )
+    .unwrap();
+
+    let mut facts = collect(temp.path(), &mut Config::default()).unwrap();
+    facts.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let paths: Vec<&str> = facts.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(paths, ["src/lexer.rs", "src/parser.rs"]);
+    assert!(!facts[0].has_test);
+    assert_eq!(facts[1].lines, 4);
+    assert_eq!(facts[1].escapes, 1);
+    assert!(facts[1].has_test);
+    assert_eq!(facts[1].coverage, Some(55.0));
+    assert_eq!(facts[1].churn, 0);
+}
+
+#[test]
+fn file_coverage_missing_is_empty() {
+    let temp = TempDir::new().unwrap();
+    let loaded = load_file_coverage(&file_coverage_path(temp.path())).unwrap();
+    assert!(loaded.is_empty());
+}
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Attribution of ratchet regressions to the branch commits behind them.
+//!
+//! Walks the commits between the baseline commit and HEAD. Escape count
+//! regressions are attributed to the commits whose diffs add matching
+//! source lines; metrics that can't be traced to lines (coverage, sizes,
+//! timings) list every commit on the branch.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use git2::{Commit, Repository, Sort};
+use serde::Serialize;
+use serde_json::{Value as JsonValue, json};
+
+use crate::checks::escapes::EscapeMatcher;
+use crate::ratchet::RatchetResult;
+
+/// Length of abbreviated commit hashes in reports.
+const SHORT_HASH: usize = 7;
+
+/// A branch commit that contributed to a metric regression.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeltaOwner {
+    pub commit: String,
+    pub author: String,
+    pub email: String,
+    pub summary: String,
+    /// Added lines matching the escape pattern (escape metrics only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added: Option<usize>,
+    /// Files the commit added matching lines to, or changed for other metrics.
+    pub files: Vec<String>,
+}
+
+/// Attribute each failing comparison in `result` to commits in `base..HEAD`.
+///
+/// Returns owners keyed by metric name, most responsible first. Metrics
+/// with no contributing commit are omitted; merge commits are skipped.
+pub fn attribute(
+    root: &Path,
+    base: &str,
+    result: &RatchetResult,
+    escapes: &EscapeMatcher,
+) -> anyhow::Result<BTreeMap<String, Vec<DeltaOwner>>> {
+    let regressed: Vec<&str> = result
+        .comparisons
+        .iter()
+        .filter(|c| !c.passed)
+        .map(|c| c.name.as_str())
+        .collect();
+    if regressed.is_empty() {
+        return Ok(BTreeMap::new());
+    }
+
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+    let workdir = repo
+        .workdir()
+        .context("Bare repository has no working tree")?
+        .to_path_buf();
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    let prefix = root.strip_prefix(&workdir).unwrap_or(Path::new(""));
+    let base_oid = repo
+        .revparse_single(base)
+        .with_context(|| format!("Failed to resolve base ref: {}", base))?
+        .peel_to_commit()?
+        .id();
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push_head()?;
+    revwalk.hide(base_oid)?;
+    revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)?;
+
+    let mut owners: BTreeMap<String, Vec<DeltaOwner>> = BTreeMap::new();
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        if commit.parent_count() > 1 {
+            continue;
+        }
+        let delta = CommitDelta::new(&repo, &commit, prefix, escapes)?;
+        for &metric in &regressed {
+            let pattern = metric
+                .strip_prefix("escapes.")
+                .map(|p| p.strip_suffix(".density").unwrap_or(p));
+            let owner = match pattern {
+                Some(pattern) => match delta.added.get(pattern) {
+                    Some((count, files)) => {
+                        delta.owner(&commit, Some(*count), files.iter().cloned().collect())
+                    }
+                    None => continue,
+                },
+                None if delta.changed.is_empty() => continue,
+                None => delta.owner(&commit, None, delta.changed.clone()),
+            };
+            owners.entry(metric.to_string()).or_default().push(owner);
+        }
+    }
+
+    // Stable sort keeps ties in commit order
+    for list in owners.values_mut() {
+        list.sort_by_key(|o| std::cmp::Reverse(o.added.unwrap_or(0)));
+    }
+    Ok(owners)
+}
+
+/// Webhook body announcing the regressed metrics and their owners.
+pub fn webhook_payload(result: &RatchetResult) -> JsonValue {
+    let regressions: Vec<JsonValue> = result
+        .comparisons
+        .iter()
+        .filter(|c| !c.passed)
+        .map(|c| {
+            json!({
+                "name": c.name,
+                "current": c.current,
+                "baseline": c.baseline,
+                "threshold": c.threshold,
+                "owners": result.owners.get(&c.name).map(Vec::as_slice).unwrap_or_default(),
+            })
+        })
+        .collect();
+    json!({
+        "event": "ratchet_regression",
+        "quench_version": env!("CARGO_PKG_VERSION"),
+        "regressions": regressions,
+    })
+}
+
+/// What one commit changed under the project root.
+struct CommitDelta {
+    /// Added escape lines per pattern name, with the files they were added to.
+    added: BTreeMap<String, (usize, BTreeSet<String>)>,
+    /// Changed files, relative to the project root.
+    changed: Vec<String>,
+}
+
+impl CommitDelta {
+    fn new(
+        repo: &Repository,
+        commit: &Commit<'_>,
+        prefix: &Path,
+        escapes: &EscapeMatcher,
+    ) -> anyhow::Result<Self> {
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
+        let diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), None)?;
+
+        let relative = |path: Option<&Path>| -> Option<PathBuf> {
+            path.and_then(|p| p.strip_prefix(prefix).ok())
+                .map(Path::to_path_buf)
+        };
+        let changed = diff
+            .deltas()
+            .filter_map(|d| relative(d.new_file().path().or(d.old_file().path())))
+            .map(|p| p.display().to_string())
+            .collect();
+
+        let mut added: BTreeMap<String, (usize, BTreeSet<String>)> = BTreeMap::new();
+        diff.foreach(
+            &mut |_, _| true,
+            None,
+            None,
+            Some(&mut |delta, _, line| {
+                if line.origin() != '+' {
+                    return true;
+                }
+                let Some(path) = relative(delta.new_file().path()) else {
+                    return true;
+                };
+                let content = String::from_utf8_lossy(line.content());
+                for name in escapes.matches(&path, &content) {
+                    let entry = added.entry(name.to_string()).or_default();
+                    entry.0 += 1;
+                    entry.1.insert(path.display().to_string());
+                }
+                true
+            }),
+        )?;
+
+        Ok(Self { added, changed })
+    }
+
+    fn owner(&self, commit: &Commit<'_>, added: Option<usize>, files: Vec<String>) -> DeltaOwner {
+        let author = commit.author();
+        DeltaOwner {
+            commit: commit.id().to_string()[..SHORT_HASH].to_string(),
+            author: author.name().unwrap_or("unknown").to_string(),
+            email: author.email().unwrap_or_default().to_string(),
+            summary: commit.summary().unwrap_or_default().to_string(),
+            added,
+            files,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "delta_owners_tests.rs"]
+mod tests;
@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use crate::config::{Config, parse};
+use crate::ratchet::MetricComparison;
+use crate::test_utils::{git, temp_project};
+
+const CONFIG: &str = r#"
+version = 1
+[[check.escapes.patterns]]
+name = "panic"
+pattern = "panic!"
+action = "count"
+"#;
+
+fn comparison(name: &str, passed: bool) -> MetricComparison {
+    MetricComparison {
+        name: name.to_string(),
+        current: 2.0,
+        baseline: 1.0,
+        tolerance: 0.0,
+        threshold: 1.0,
+        passed,
+        improved: false,
+    }
+}
+
+fn regressed(names: &[&str]) -> RatchetResult {
+    RatchetResult {
+        passed: false,
+        comparisons: names.iter().map(|n| comparison(n, false)).collect(),
+        improvements: Vec::new(),
+        owners: BTreeMap::new(),
+        packages: BTreeMap::new(),
+    }
+}
+
+fn commit_as(root: &Path, author: &str, file: &str, content: &str, message: &str) {
+    let path = root.join(file);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+    git::add_all(root);
+    std::process::Command::new("git")
+        .args(["-c", &format!("user.name={}", author)])
+        .args(["-c", &format!("user.email={}@example.com", author)])
+        .args(["commit", "-m", message])
+        .current_dir(root)
+        .output()
+        .unwrap();
+}
+
+/// Repo with a baseline commit on `main` and two branch commits.
+fn branch_project() -> tempfile::TempDir {
+    let temp = temp_project();
+    git::init(temp.path());
+    std::fs::write(temp.path().join("src.rs"), "fn a() {}\n").unwrap();
+    git::initial_commit(temp.path());
+    git::create_branch(temp.path(), "feature");
+    commit_as(
+        temp.path(),
+        "ann",
+        "lib.rs",
+        "fn b() { panic!() }\nfn c() { panic!() }\n",
+        "feat: add b and c",
+    );
+    commit_as(temp.path(), "bea", "docs.rs", "fn d() {}\n", "docs: add d");
+    temp
+}
+
+fn matcher(root: &Path) -> EscapeMatcher {
+    let config: Config = parse(CONFIG, Path::new("quench.toml")).unwrap();
+    EscapeMatcher::new(root, &config).unwrap()
+}
+
+#[test]
+fn escape_regression_is_attributed_to_commit_adding_matches() {
+    let temp = branch_project();
+
+    let owners = attribute(
+        temp.path(),
+        "main",
+        &regressed(&["escapes.panic"]),
+        &matcher(temp.path()),
+    )
+    .unwrap();
+
+    let panic = &owners["escapes.panic"];
+    assert_eq!(panic.len(), 1);
+    assert_eq!(panic[0].author, "ann");
+    assert_eq!(panic[0].email, "ann@example.com");
+    assert_eq!(panic[0].summary, "feat: add b and c");
+    assert_eq!(panic[0].added, Some(2));
+    assert_eq!(panic[0].files, ["lib.rs"]);
+}
+
+#[test]
+fn other_regressions_list_every_branch_commit() {
+    let temp = branch_project();
+
+    let owners = attribute(
+        temp.path(),
+        "main",
+        &regressed(&["binary_size.app"]),
+        &matcher(temp.path()),
+    )
+    .unwrap();
+
+    let authors: Vec<_> = owners["binary_size.app"]
+        .iter()
+        .map(|o| (o.author.as_str(), o.added))
+        .collect();
+    assert_eq!(authors, [("ann", None), ("bea", None)]);
+}
+
+#[test]
+fn passing_metrics_are_not_attributed() {
+    let temp = branch_project();
+    let mut result = regressed(&[]);
+    result.comparisons.push(comparison("escapes.panic", true));
+
+    let owners = attribute(temp.path(), "main", &result, &matcher(temp.path())).unwrap();
+
+    assert!(owners.is_empty());
+}
+
+#[test]
+fn escapes_added_in_test_files_are_not_attributed() {
+    let temp = temp_project();
+    git::init(temp.path());
+    std::fs::write(temp.path().join("src.rs"), "fn a() {}\n").unwrap();
+    git::initial_commit(temp.path());
+    commit_as(
+        temp.path(),
+        "ann",
+        "tests/it.rs",
+        "fn t() { panic!() }\n",
+        "test: add t",
+    );
+
+    let owners = attribute(
+        temp.path(),
+        "main~1",
+        &regressed(&["escapes.panic"]),
+        &matcher(temp.path()),
+    )
+    .unwrap();
+
+    assert!(owners.is_empty());
+}
+
+#[test]
+fn webhook_payload_lists_regressions_with_owners() {
+    let mut result = regressed(&["escapes.panic"]);
+    result.comparisons.push(comparison("escapes.todo", true));
+    result.owners.insert(
+        "escapes.panic".to_string(),
+        vec![DeltaOwner {
+            commit: "abc1234".to_string(),
+            author: "ann".to_string(),
+            email: "ann@example.com".to_string(),
+            summary: "feat: add b".to_string(),
+            added: Some(1),
+            files: vec!["lib.rs".to_string()],
+        }],
+    );
+
+    let payload = webhook_payload(&result);
+
+    assert_eq!(payload["event"], "ratchet_regression");
+    let regressions = payload["regressions"].as_array().unwrap();
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0]["name"], "escapes.panic");
+    assert_eq!(regressions[0]["owners"][0]["author"], "ann");
+    assert_eq!(regressions[0]["owners"][0]["added"], 1);
+}
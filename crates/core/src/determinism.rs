@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Determinism verification for `--check-determinism`.
+//!
+//! Runs are compared on their JSON form with run-specific fields
+//! (timestamps, durations) removed, so any remaining difference is
+//! nondeterminism in check logic or output ordering.
+
+use serde_json::Value as JsonValue;
+
+use crate::check::CheckResult;
+
+/// Fields that legitimately differ between runs.
+const VOLATILE_FIELDS: &[&str] = &["duration_ms", "timestamp"];
+
+/// Render check results as pretty JSON with volatile fields removed.
+pub fn canonical_json(results: &[CheckResult]) -> String {
+    let mut value = serde_json::to_value(results).unwrap_or(JsonValue::Null);
+    strip_volatile(&mut value);
+    serde_json::to_string_pretty(&value).unwrap_or_default()
+}
+
+/// Remove run-specific fields (timestamps, durations) at any depth.
+pub fn strip_volatile(value: &mut JsonValue) {
+    match value {
+        JsonValue::Object(map) => {
+            for field in VOLATILE_FIELDS {
+                map.remove(*field);
+            }
+            map.values_mut().for_each(strip_volatile);
+        }
+        JsonValue::Array(items) => items.iter_mut().for_each(strip_volatile),
+        _ => {}
+    }
+}
+
+/// Compare two runs, returning a line diff if they differ.
+///
+/// Only differing lines are reported, prefixed with `-` (first run)
+/// and `+` (second run), each with its 1-based line number.
+pub fn diff_runs(first: &[CheckResult], second: &[CheckResult]) -> Option<String> {
+    diff_text(&canonical_json(first), &canonical_json(second))
+}
+
+/// Line diff of two texts, or None if they are equal.
+///
+/// Same format as [`diff_runs`].
+pub fn diff_text(a: &str, b: &str) -> Option<String> {
+    if a == b {
+        return None;
+    }
+
+    let a_lines: Vec<&str> = a.lines().collect();
+    let b_lines: Vec<&str> = b.lines().collect();
+    let mut diff = String::new();
+    for i in 0..a_lines.len().max(b_lines.len()) {
+        let left = a_lines.get(i).copied();
+        let right = b_lines.get(i).copied();
+        if left == right {
+            continue;
+        }
+        if let Some(line) = left {
+            diff.push_str(&format!("-{}: {}\n", i + 1, line));
+        }
+        if let Some(line) = right {
+            diff.push_str(&format!("+{}: {}\n", i + 1, line));
+        }
+    }
+    Some(diff)
+}
+
+#[cfg(test)]
+#[path = "determinism_tests.rs"]
+mod tests;
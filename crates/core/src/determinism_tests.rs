@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::check::Violation;
+
+fn result_with(violations: Vec<Violation>) -> CheckResult {
+    CheckResult::failed("cloc", violations)
+}
+
+#[test]
+fn identical_runs_have_no_diff() {
+    let a = vec![result_with(vec![Violation::file("a.rs", 1, "x", "fix")])];
+    let b = a.clone();
+    assert!(diff_runs(&a, &b).is_none());
+}
+
+#[test]
+fn durations_are_ignored() {
+    let mut a = result_with(vec![]);
+    let mut b = result_with(vec![]);
+    a.duration_ms = Some(3);
+    b.duration_ms = Some(97);
+    assert!(diff_runs(&[a], &[b]).is_none());
+}
+
+#[test]
+fn differing_runs_report_changed_lines() {
+    let a = vec![result_with(vec![Violation::file("a.rs", 1, "x", "fix")])];
+    let b = vec![result_with(vec![Violation::file("b.rs", 1, "x", "fix")])];
+    let diff = diff_runs(&a, &b).unwrap();
+    assert!(diff.contains("-"));
+    assert!(diff.contains("\"a.rs\""));
+    assert!(diff.contains("\"b.rs\""));
+}
+
+#[test]
+fn canonical_json_strips_nested_volatile_fields() {
+    let mut r = result_with(vec![]);
+    r.duration_ms = Some(10);
+    let json = canonical_json(&[r]);
+    assert!(!json.contains("duration_ms"));
+}
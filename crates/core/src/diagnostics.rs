@@ -0,0 +1,241 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Diagnostics about the run itself.
+//!
+//! Problems quench hits while running (staged files it can't list, a
+//! baseline it can't load) are reported here instead of as ad-hoc stderr
+//! lines. Each has a stable code, a severity, and a hint. They print to
+//! stderr as they happen and are included in JSON output.
+//!
+//! See docs/specs/03-output.md#diagnostics.
+
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use termcolor::{StandardStream, WriteColor};
+
+use crate::color::{resolve_color, scheme};
+
+/// How serious a diagnostic is. Neither severity changes the exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Something was skipped or degraded; the run still completed.
+    Warning,
+    /// Part of a check could not run, so its results may be incomplete.
+    Error,
+}
+
+/// Stable diagnostic codes, each with one remediation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Code {
+    /// `--staged` could not list staged files.
+    StagedFilesUnavailable,
+    /// `--base` could not list changed files.
+    ChangedFilesUnavailable,
+    /// A file was not valid UTF-8 and was decoded lossily.
+    UndecodableFile,
+    /// A `[[quarantine]]` entry is past its `until` date.
+    QuarantineExpired,
+    /// `[new_code]` is configured outside a git repository.
+    NewCodeNeedsGit,
+    /// Git history for `[new_code]` could not be read or cached.
+    FileHistoryUnavailable,
+    /// The ratchet baseline could not be loaded.
+    BaselineLoadFailed,
+    /// The ratchet baseline is older than `[ratchet] stale_days`.
+    BaselineStale,
+    /// `--fix` left the baseline alone because the tree is dirty.
+    BaselineNotUpdated,
+    /// The ratchet baseline could not be saved.
+    BaselineSaveFailed,
+    /// Quench itself ran slower than its baseline allows.
+    SelfTimeRegressed,
+    /// `--save` could not write the metrics file.
+    MetricsSaveFailed,
+    /// A configured linter could not run.
+    LinterFailed,
+    /// A configured formatter could not run.
+    FormatterFailed,
+    /// `--fix` could not apply a fix.
+    FixFailed,
+    /// A check reported metrics that don't match their schema.
+    InvalidMetrics,
+    /// The `[store]` database could not record the run or baseline.
+    StoreWriteFailed,
+    /// Coverage thresholds are set, but no test suite collects coverage.
+    CoverageWithoutSuites,
+    /// The ratchet baseline file is ignored by git.
+    BaselineIgnored,
+    /// `[check.agents] sync_from` is set with no other agent file to sync.
+    AgentsSyncSingleFile,
+    /// `quench init --import` could not carry over a tool config.
+    ImportFailed,
+    /// `quench sbom` left out a manifest, lockfile, or whole ecosystem.
+    SbomIncomplete,
+}
+
+impl Code {
+    /// The code as it appears in output, e.g. `staged_files_unavailable`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Code::StagedFilesUnavailable => "staged_files_unavailable",
+            Code::ChangedFilesUnavailable => "changed_files_unavailable",
+            Code::UndecodableFile => "undecodable_file",
+            Code::QuarantineExpired => "quarantine_expired",
+            Code::NewCodeNeedsGit => "new_code_needs_git",
+            Code::FileHistoryUnavailable => "file_history_unavailable",
+            Code::BaselineLoadFailed => "baseline_load_failed",
+            Code::BaselineStale => "baseline_stale",
+            Code::BaselineNotUpdated => "baseline_not_updated",
+            Code::BaselineSaveFailed => "baseline_save_failed",
+            Code::SelfTimeRegressed => "self_time_regressed",
+            Code::MetricsSaveFailed => "metrics_save_failed",
+            Code::LinterFailed => "linter_failed",
+            Code::FormatterFailed => "formatter_failed",
+            Code::FixFailed => "fix_failed",
+            Code::InvalidMetrics => "invalid_metrics",
+            Code::StoreWriteFailed => "store_write_failed",
+            Code::CoverageWithoutSuites => "coverage_without_suites",
+            Code::BaselineIgnored => "baseline_ignored",
+            Code::AgentsSyncSingleFile => "agents_sync_single_file",
+            Code::ImportFailed => "import_failed",
+            Code::SbomIncomplete => "sbom_incomplete",
+        }
+    }
+
+    /// The severity diagnostics with this code are reported at.
+    pub fn severity(self) -> Severity {
+        match self {
+            Code::LinterFailed | Code::FormatterFailed => Severity::Error,
+            _ => Severity::Warning,
+        }
+    }
+
+    /// How to resolve a diagnostic with this code.
+    pub fn hint(self) -> &'static str {
+        match self {
+            Code::StagedFilesUnavailable => "Run inside a git repository, or drop --staged.",
+            Code::ChangedFilesUnavailable => "Check that the base ref exists locally.",
+            Code::UndecodableFile => "Re-encode the file as UTF-8, or exclude it.",
+            Code::QuarantineExpired => "Fix the violations, or extend the quarantine's `until`.",
+            Code::NewCodeNeedsGit => "Run inside a git repository, or remove [new_code].",
+            Code::FileHistoryUnavailable => "Fetch full history; shallow clones lack it.",
+            Code::BaselineLoadFailed => {
+                "Fix or delete the baseline, then run `quench check --fix`."
+            }
+            Code::BaselineStale => "Refresh it with `quench check --fix`.",
+            Code::BaselineNotUpdated => "Commit or stash your changes, then rerun with --fix.",
+            Code::BaselineSaveFailed => "Check that the baseline location is writable.",
+            Code::SelfTimeRegressed => "Find the slow check with --timing.",
+            Code::MetricsSaveFailed => "Check that the --save path is writable.",
+            Code::LinterFailed => "Install the linter, or remove it from [check.lint].",
+            Code::FormatterFailed => "Install the formatter, or remove it from [check.format].",
+            Code::FixFailed => "Apply the fix by hand.",
+            Code::InvalidMetrics => "This is a quench bug; please report it.",
+            Code::StoreWriteFailed => "Check that the [store] path is writable.",
+            Code::CoverageWithoutSuites => {
+                "Add a [[check.tests.suite]] or set `auto = true`, or remove the thresholds."
+            }
+            Code::BaselineIgnored => {
+                "Remove it from .gitignore, or set [git] baseline = \"notes\"."
+            }
+            Code::AgentsSyncSingleFile => "Add the agent files to keep in sync, or drop sync_from.",
+            Code::ImportFailed => {
+                "Carry the settings over by hand, or drop the file from --import."
+            }
+            Code::SbomIncomplete => "Fix the file, or commit a lockfile for the ecosystem.",
+        }
+    }
+}
+
+/// One problem with the run itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub code: Code,
+    pub severity: Severity,
+    pub message: String,
+    pub hint: String,
+}
+
+impl Diagnostic {
+    /// A diagnostic with the code's severity and hint.
+    pub fn new(code: Code, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            severity: code.severity(),
+            message: message.into(),
+            hint: code.hint().to_string(),
+        }
+    }
+
+    /// Write as `warning[code]: message`, with the hint on the next line.
+    pub fn write_to(&self, out: &mut impl WriteColor) -> std::io::Result<()> {
+        let (spec, label) = match self.severity {
+            Severity::Warning => (scheme::warn(), "warning"),
+            Severity::Error => (scheme::fail(), "error"),
+        };
+        out.set_color(&spec)?;
+        write!(out, "{}[{}]", label, self.code.as_str())?;
+        out.reset()?;
+        writeln!(out, ": {}", self.message)?;
+        writeln!(out, "  hint: {}", self.hint)
+    }
+}
+
+/// Diagnostics reported during one run, from any thread.
+#[derive(Debug)]
+pub struct Diagnostics {
+    reported: Mutex<Vec<Diagnostic>>,
+    /// Print each diagnostic to stderr as it is reported.
+    echo: bool,
+}
+
+impl Default for Diagnostics {
+    fn default() -> Self {
+        Self {
+            reported: Mutex::new(Vec::new()),
+            echo: true,
+        }
+    }
+}
+
+impl Diagnostics {
+    /// Collect diagnostics without printing them.
+    pub fn silent() -> Self {
+        Self {
+            echo: false,
+            ..Self::default()
+        }
+    }
+
+    /// Record a diagnostic, printing it to stderr unless silent.
+    pub fn report(&self, diagnostic: Diagnostic) {
+        if self.echo {
+            let mut stderr = StandardStream::stderr(resolve_color());
+            let _ = diagnostic.write_to(&mut stderr);
+        }
+        if let Ok(mut reported) = self.reported.lock() {
+            reported.push(diagnostic);
+        }
+    }
+
+    /// Record a diagnostic with the code's severity and hint.
+    pub fn emit(&self, code: Code, message: impl Into<String>) {
+        self.report(Diagnostic::new(code, message));
+    }
+
+    /// Everything reported so far, in report order.
+    pub fn all(&self) -> Vec<Diagnostic> {
+        self.reported
+            .lock()
+            .map(|reported| reported.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+#[path = "diagnostics_tests.rs"]
+mod tests;
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use termcolor::NoColor;
+
+use super::*;
+
+#[test]
+fn diagnostic_takes_severity_and_hint_from_code() {
+    let diagnostic = Diagnostic::new(Code::LinterFailed, "lint: eslint not found");
+    assert_eq!(diagnostic.severity, Severity::Error);
+    assert_eq!(diagnostic.hint, Code::LinterFailed.hint());
+}
+
+#[test]
+fn code_serializes_as_its_name() {
+    for code in [
+        Code::StagedFilesUnavailable,
+        Code::BaselineStale,
+        Code::FixFailed,
+    ] {
+        let json = serde_json::to_value(code).unwrap();
+        assert_eq!(json, code.as_str());
+    }
+}
+
+#[test]
+fn diagnostic_json_has_code_severity_message_hint() {
+    let diagnostic = Diagnostic::new(Code::StagedFilesUnavailable, "could not get staged files");
+    let json = serde_json::to_value(&diagnostic).unwrap();
+    assert_eq!(json["code"], "staged_files_unavailable");
+    assert_eq!(json["severity"], "warning");
+    assert_eq!(json["message"], "could not get staged files");
+    assert_eq!(json["hint"], Code::StagedFilesUnavailable.hint());
+}
+
+#[test]
+fn text_names_code_with_hint_below() {
+    let diagnostic = Diagnostic::new(Code::BaselineStale, "baseline is 45 days old");
+    let mut out = NoColor::new(Vec::new());
+    diagnostic.write_to(&mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out.into_inner()).unwrap(),
+        "warning[baseline_stale]: baseline is 45 days old\n  hint: Refresh it with `quench check --fix`.\n"
+    );
+}
+
+#[test]
+fn diagnostics_collect_in_report_order() {
+    let diagnostics = Diagnostics::silent();
+    diagnostics.emit(Code::BaselineStale, "first");
+    diagnostics.emit(Code::MetricsSaveFailed, "second");
+
+    let messages: Vec<String> = diagnostics.all().into_iter().map(|d| d.message).collect();
+    assert_eq!(messages, ["first", "second"]);
+}
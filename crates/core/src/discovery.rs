@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Config file discovery.
+//!
+//! Walks from the current directory up to the git root looking for quench.toml.
+//! A `quench.toml` without `version` is a nested override file, so the walk
+//! continues past it to the project config.
+
+use std::path::{Path, PathBuf};
+
+/// Find quench.toml starting from `start_dir` and walking up to git root.
+///
+/// Without a project config above it, the nearest nested file is returned so
+/// loading reports the missing version.
+pub fn find_config(start_dir: &Path) -> Option<PathBuf> {
+    let mut current = start_dir.to_path_buf();
+    let mut nested = None;
+
+    loop {
+        let config_path = current.join("quench.toml");
+        if config_path.exists() {
+            if is_project_config(&config_path) {
+                return Some(config_path);
+            }
+            nested.get_or_insert(config_path);
+        }
+
+        // Stop at git root
+        if current.join(".git").exists() {
+            return nested;
+        }
+
+        // Move up one directory
+        match current.parent() {
+            Some(parent) => current = parent.to_path_buf(),
+            None => return nested,
+        }
+    }
+}
+
+/// Whether a `quench.toml` is a project config rather than a nested one.
+///
+/// Only a file that parses without `version` is nested; anything else is
+/// left for loading to report.
+pub fn is_project_config(path: &Path) -> bool {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| content.parse::<toml::Table>().ok())
+        .is_none_or(|table| table.contains_key("version"))
+}
+
+#[cfg(test)]
+#[path = "discovery_tests.rs"]
+mod tests;
@@ -76,3 +76,43 @@ fn returns_none_when_no_config() {
     let found = find_config(dir.path());
     assert_eq!(found, None);
 }
+
+#[test]
+fn skips_nested_override_config_for_project_config() {
+    let dir = tempdir().unwrap();
+    let config_path = dir.path().join("quench.toml");
+    fs::write(&config_path, "version = 1\n").unwrap();
+
+    let subdir = dir.path().join("packages/api");
+    fs::create_dir_all(&subdir).unwrap();
+    fs::write(
+        subdir.join("quench.toml"),
+        "[check.cloc]\nmax_lines = 900\n",
+    )
+    .unwrap();
+
+    assert_eq!(find_config(&subdir), Some(config_path));
+}
+
+#[test]
+fn returns_nested_config_without_project_config() {
+    let dir = tempdir().unwrap();
+    fs::create_dir(dir.path().join(".git")).unwrap();
+    let config_path = dir.path().join("quench.toml");
+    fs::write(&config_path, "[check.cloc]\nmax_lines = 900\n").unwrap();
+
+    assert_eq!(find_config(dir.path()), Some(config_path));
+}
+
+#[test]
+fn malformed_config_is_not_skipped() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("quench.toml"), "version = 1\n").unwrap();
+
+    let subdir = dir.path().join("broken");
+    fs::create_dir(&subdir).unwrap();
+    let config_path = subdir.join("quench.toml");
+    fs::write(&config_path, "[check.cloc\n").unwrap();
+
+    assert_eq!(find_config(&subdir), Some(config_path));
+}
@@ -52,6 +52,70 @@ pub fn is_non_interactive() -> bool {
         || std::env::var_os(names::CI).is_some()
 }
 
+/// Variables CI providers set, checked in order by [`detect_ci`].
+pub const CI_VARS: &[&str] = &[
+    names::CI,
+    names::GITHUB_ACTIONS,
+    names::GITLAB_CI,
+    names::CIRCLECI,
+    names::BUILDKITE,
+    names::JENKINS_URL,
+    names::TF_BUILD,
+];
+
+/// Returns the first CI variable that is set, if any.
+///
+/// Empty values and `"0"` or `"false"` (case-insensitive) don't count, so
+/// `CI=false` opts out.
+pub fn detect_ci() -> Option<&'static str> {
+    detect_ci_with(|name| std::env::var(name).ok())
+}
+
+fn detect_ci_with(lookup: impl Fn(&str) -> Option<String>) -> Option<&'static str> {
+    CI_VARS.iter().copied().find(|name| {
+        lookup(name).is_some_and(|v| !v.is_empty() && v != "0" && !v.eq_ignore_ascii_case("false"))
+    })
+}
+
+/// Returns `true` if `QUENCH_NO_TELEMETRY` is set to any value.
+///
+/// Lets a user or CI job opt out of a project's `[telemetry]` uploads.
+pub fn no_telemetry() -> bool {
+    std::env::var_os(names::QUENCH_NO_TELEMETRY).is_some()
+}
+
+/// Returns the container engine from `QUENCH_CONTAINER_ENGINE`, if set.
+///
+/// Overrides detection of `docker` or `podman` for `container` images.
+pub fn quench_container_engine() -> Option<String> {
+    std::env::var(names::QUENCH_CONTAINER_ENGINE)
+        .ok()
+        .filter(|v| !v.is_empty())
+}
+
+/// Returns the GitHub token from `GITHUB_TOKEN`, if set.
+///
+/// `quench bot` posts commit statuses and comments with it.
+pub fn github_token() -> Option<String> {
+    std::env::var(names::GITHUB_TOKEN)
+        .ok()
+        .filter(|v| !v.is_empty())
+}
+
+/// Returns the GitHub API base URL from `GITHUB_API_URL`, if set.
+pub fn github_api_url() -> Option<String> {
+    std::env::var(names::GITHUB_API_URL)
+        .ok()
+        .filter(|v| !v.is_empty())
+}
+
+/// Returns the webhook secret from `QUENCH_WEBHOOK_SECRET`, if set.
+pub fn quench_webhook_secret() -> Option<String> {
+    std::env::var(names::QUENCH_WEBHOOK_SECRET)
+        .ok()
+        .filter(|v| !v.is_empty())
+}
+
 /// Returns `true` if `QUENCH_DEBUG` is `"1"` or `"true"` (case-insensitive).
 pub fn quench_debug() -> bool {
     std::env::var(names::QUENCH_DEBUG).is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true"))
@@ -69,3 +69,32 @@ fn names_xdg_config_home_is_correct() {
 fn quench_log_var_returns_correct_name() {
     assert_eq!(quench_log_var(), "QUENCH_LOG");
 }
+
+#[test]
+fn detect_ci_finds_first_set_variable() {
+    let env = |vars: &'static [(&'static str, &'static str)]| {
+        move |name: &str| {
+            vars.iter()
+                .find(|(k, _)| *k == name)
+                .map(|(_, v)| v.to_string())
+        }
+    };
+
+    assert_eq!(detect_ci_with(env(&[])), None);
+    assert_eq!(detect_ci_with(env(&[("CI", "true")])), Some("CI"));
+    assert_eq!(
+        detect_ci_with(env(&[("GITLAB_CI", "true"), ("JENKINS_URL", "http://ci")])),
+        Some("GITLAB_CI")
+    );
+}
+
+#[test]
+fn detect_ci_ignores_false_values() {
+    let env = |value: &'static str| move |name: &str| (name == "CI").then(|| value.to_string());
+
+    assert_eq!(detect_ci_with(env("false")), None);
+    assert_eq!(detect_ci_with(env("FALSE")), None);
+    assert_eq!(detect_ci_with(env("0")), None);
+    assert_eq!(detect_ci_with(env("")), None);
+    assert_eq!(detect_ci_with(env("1")), Some("CI"));
+}
@@ -57,6 +57,8 @@ pub enum ExitCode {
     ConfigError = 2,
     /// Internal error
     InternalError = 3,
+    /// Interrupted by Ctrl-C (128 + SIGINT)
+    Interrupted = 130,
 }
 
 impl From<&Error> for ExitCode {
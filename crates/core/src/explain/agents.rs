@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `agents` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "missing_file",
+        checks: &["agents"],
+        description: "A required agent file (e.g., CLAUDE.md) doesn't exist, or no agent file exists at all.",
+        rationale: "Agents work better with a project's conventions written down where they look for them.",
+        config: &["check.agents.required", "check.agents.files"],
+        fixes: &["Create the file at the project root, e.g. with `quench init`."],
+    },
+    Explanation {
+        violation_type: "forbidden_file",
+        checks: &["agents"],
+        description: "An agent file the project forbids exists at the project root.",
+        rationale: "Projects standardize on one set of agent files so instructions don't diverge.",
+        config: &["check.agents.forbid"],
+        fixes: &["Move its content into an allowed agent file and delete it."],
+    },
+    Explanation {
+        violation_type: "out_of_sync",
+        checks: &["agents"],
+        description: "A section of an agent file differs from the same section of the sync source.",
+        rationale: "Different agents reading different instructions behave differently.",
+        config: &["check.agents.sync", "check.agents.sync_from"],
+        fixes: &[
+            "Run `quench check --fix` to copy the section from the sync source, or reconcile by hand.",
+        ],
+    },
+    Explanation {
+        violation_type: "missing_section",
+        checks: &["agents"],
+        description: "An agent file lacks a required section.",
+        rationale: "Required sections (e.g., a landing checklist) make sure agents see the essentials.",
+        config: &[
+            "check.agents.sections.required[].name",
+            "check.agents.sections.required[].advice",
+        ],
+        fixes: &["Add a heading with the section's name and the content its advice describes."],
+    },
+    Explanation {
+        violation_type: "forbidden_section",
+        checks: &["agents"],
+        description: "An agent file has a section whose heading matches a forbidden pattern.",
+        rationale: "Some content (e.g., secrets or stale notes) doesn't belong in agent context.",
+        config: &["check.agents.sections.forbid"],
+        fixes: &["Remove or rename the section."],
+    },
+    Explanation {
+        violation_type: "file_too_large",
+        checks: &["agents"],
+        description: "An agent file has more lines or tokens than its scope's limit.",
+        rationale: "Agent files are loaded into every session; their size costs context on every task.",
+        config: &[
+            "check.agents.max_lines",
+            "check.agents.max_tokens",
+            "check.agents.root.max_lines",
+            "check.agents.package.max_lines",
+            "check.agents.module.max_lines",
+        ],
+        fixes: &[
+            "Trim it to what agents need, and move package-specific notes to that package's agent file.",
+        ],
+    },
+    Explanation {
+        violation_type: "context_too_large",
+        checks: &["agents"],
+        description: "All agent files together exceed `max_total_tokens`.",
+        rationale: "Agents load several agent files at once; their total is what fills the context window.",
+        config: &["check.agents.max_total_tokens"],
+        fixes: &["Deduplicate content shared between agent files, starting with the largest."],
+    },
+    Explanation {
+        violation_type: "forbidden_table",
+        checks: &["agents", "docs"],
+        description: "An agent file or spec has a markdown table where tables are forbidden.",
+        rationale: "Tables spend many tokens on alignment and pipes for little information.",
+        config: &["check.agents.tables", "check.docs.specs.tables"],
+        fixes: &["Convert the table to a list or prose."],
+    },
+    Explanation {
+        violation_type: "forbidden_diagram",
+        checks: &["agents", "docs"],
+        description: "An agent file or spec has a box-drawing diagram where they are forbidden.",
+        rationale: "Box diagrams are token-heavy and hard for models to read.",
+        config: &["check.agents.box_diagrams", "check.docs.specs.box_diagrams"],
+        fixes: &["Describe the structure with a list instead."],
+    },
+    Explanation {
+        violation_type: "forbidden_mermaid",
+        checks: &["agents", "docs"],
+        description: "An agent file or spec has a mermaid block where they are forbidden.",
+        rationale: "Mermaid source is token-heavy and meant for rendering, not reading.",
+        config: &["check.agents.mermaid", "check.docs.specs.mermaid"],
+        fixes: &["Describe the flow with a list instead."],
+    },
+    Explanation {
+        violation_type: "cursor_missing_metadata",
+        checks: &["agents"],
+        description: "A Cursor `.mdc` rule's frontmatter lacks a required key.",
+        rationale: "Cursor uses frontmatter to decide when a rule applies.",
+        config: &["check.agents.frontmatter.required"],
+        fixes: &["Add the key between the `---` delimiters, e.g. `description: ...`."],
+    },
+    Explanation {
+        violation_type: "cursor_invalid_metadata",
+        checks: &["agents"],
+        description: "A Cursor rule's `alwaysApply` is neither `true` nor `false`.",
+        rationale: "An invalid value makes it unclear whether the rule applies.",
+        config: &[],
+        fixes: &["Set `alwaysApply: true` or `alwaysApply: false`."],
+    },
+    Explanation {
+        violation_type: "cursor_invalid_glob",
+        checks: &["agents"],
+        description: "A Cursor rule's `globs` contains a pattern that doesn't parse.",
+        rationale: "A broken glob silently keeps the rule from applying.",
+        config: &[],
+        fixes: &["Fix the pattern, e.g. close an unclosed `[` or `{`."],
+    },
+    Explanation {
+        violation_type: "cursor_unmatched_glob",
+        checks: &["agents"],
+        description: "A Cursor rule's glob matches no file in the project.",
+        rationale: "Globs go stale when files move, and the rule stops applying.",
+        config: &["check.agents.frontmatter.unmatched_globs"],
+        fixes: &["Update the glob to the files' new location, or remove it."],
+    },
+    Explanation {
+        violation_type: "cursor_rule_conflict",
+        checks: &["agents"],
+        description: "Two Cursor rules that apply to the same files define a section differently.",
+        rationale: "Conflicting instructions for the same file leave the agent to guess.",
+        config: &["check.agents.frontmatter.conflicts"],
+        fixes: &["Make the sections match, or merge the rules."],
+    },
+    Explanation {
+        violation_type: "cursor_parse_error",
+        checks: &["agents"],
+        description: "A Cursor `.mdc` rule's frontmatter is malformed.",
+        rationale: "Cursor can't tell when a rule with broken frontmatter applies.",
+        config: &["check.agents.sync"],
+        fixes: &["Fix the frontmatter between the `---` delimiters."],
+    },
+    Explanation {
+        violation_type: "cursor_missing_in_claude",
+        checks: &["agents"],
+        description: "A section of an `alwaysApply` Cursor rule is missing from, or differs in, the root \
+            agent file.",
+        rationale: "Rules every agent should follow belong in both Cursor rules and the agent file.",
+        config: &["check.agents.sync", "check.agents.sync_from"],
+        fixes: &["Run `quench check --fix` to add the section, or reconcile the two by hand."],
+    },
+    Explanation {
+        violation_type: "claude_missing_in_cursor",
+        checks: &["agents"],
+        description: "A section of the root agent file is in no `alwaysApply` Cursor rule.",
+        rationale: "Cursor users would miss instructions other agents see.",
+        config: &["check.agents.sync", "check.agents.sync_from"],
+        fixes: &[
+            "Add the section to an `alwaysApply` rule, or set `sync_from` to make one side the source.",
+        ],
+    },
+    Explanation {
+        violation_type: "cursor_no_agent_file",
+        checks: &["agents"],
+        description: "A Cursor rule is scoped to a directory that has no agent file.",
+        rationale: "Directory-scoped instructions should reach every agent working there.",
+        config: &["check.agents.sync", "check.agents.sync_from"],
+        fixes: &["Run `quench check --fix` to create the agent file from the rule."],
+    },
+    Explanation {
+        violation_type: "cursor_dir_missing_in_agent",
+        checks: &["agents"],
+        description: "A section of a directory-scoped Cursor rule is missing from that directory's agent file.",
+        rationale: "Cursor and other agents would get different instructions for the same directory.",
+        config: &["check.agents.sync", "check.agents.sync_from"],
+        fixes: &["Copy the section into the directory's agent file."],
+    },
+    Explanation {
+        violation_type: "agent_dir_missing_in_cursor",
+        checks: &["agents"],
+        description: "A section of a directory's agent file is missing from the Cursor rule scoped to it.",
+        rationale: "Cursor and other agents would get different instructions for the same directory.",
+        config: &["check.agents.sync", "check.agents.sync_from"],
+        fixes: &["Copy the section into the Cursor rule."],
+    },
+];
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `build` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "size_exceeded",
+        checks: &["build"],
+        description: "A release binary or bundle is larger than its size limit.",
+        rationale: "Binary size creeps up one dependency at a time.",
+        config: &["check.build.size_max", "check.build.target.<name>.size_max"],
+        fixes: &["Drop or feature-gate heavy dependencies, or enable LTO and symbol stripping."],
+    },
+    Explanation {
+        violation_type: "time_cold_exceeded",
+        checks: &["build"],
+        description: "A clean build took longer than `time_cold_max`.",
+        rationale: "Cold build time is what CI and new contributors pay.",
+        config: &["check.build.time_cold_max"],
+        fixes: &["Trim dependencies and heavy build scripts, or cache build artifacts in CI."],
+    },
+    Explanation {
+        violation_type: "time_hot_exceeded",
+        checks: &["build"],
+        description: "An incremental build took longer than `time_hot_max`.",
+        rationale: "Incremental build time is what every edit-compile cycle pays.",
+        config: &["check.build.time_hot_max"],
+        fixes: &["Split large crates or modules so an edit rebuilds less."],
+    },
+    Explanation {
+        violation_type: "missing_target",
+        checks: &["build"],
+        description: "A configured build target wasn't produced by the build.",
+        rationale: "A target that can't be measured can't be held to its limits.",
+        config: &["check.build.targets"],
+        fixes: &["Fix the target's name in `targets`, or fix the build so it produces it."],
+    },
+];
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `cloc` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "file_too_large",
+        checks: &["cloc"],
+        description: "A source or test file has more lines (or tokens) than the limit for its kind.",
+        rationale: "Large files are hard to review and expensive to load into an agent's context; \
+            they usually hold several concerns that would be easier to test apart.",
+        config: &[
+            "check.cloc.max_lines",
+            "check.cloc.max_lines_test",
+            "check.cloc.max_tokens",
+            "check.cloc.exclude",
+            "check.cloc.advice",
+            "check.cloc.advice_test",
+        ],
+        fixes: &[
+            "Extract repeated code into helpers, then split the file by concern into a module folder.",
+            "For test files, parameterize similar tests and move each group into its own file.",
+        ],
+    },
+    Explanation {
+        violation_type: "file_too_large_nonblank",
+        checks: &["cloc"],
+        description: "A file has more non-blank lines than `max_lines` allows, with `metric = \"nonblank\"`.",
+        rationale: "Counting only non-blank lines keeps spacing from deciding whether a file is too large.",
+        config: &[
+            "check.cloc.metric",
+            "check.cloc.max_lines",
+            "check.cloc.max_lines_test",
+            "check.cloc.exclude",
+        ],
+        fixes: &["Split the file by concern, as for `file_too_large`."],
+    },
+    Explanation {
+        violation_type: "test_ratio_too_low",
+        checks: &["cloc"],
+        description: "The project, or a package, has fewer test lines per source line than `min_test_ratio`.",
+        rationale: "A falling test-to-source ratio means code is being added faster than it is tested.",
+        config: &[
+            "check.cloc.min_test_ratio",
+            "check.cloc.package.<name>.min_test_ratio",
+        ],
+        fixes: &[
+            "Add tests for the least tested code in the package.",
+            "Lower `min_test_ratio` if the minimum doesn't fit the package.",
+        ],
+    },
+    Explanation {
+        violation_type: "inline_cfg_test",
+        checks: &["cloc"],
+        description: "A Rust source file contains an inline `#[cfg(test)] mod` block while \
+            `cfg_test_split = \"require\"`.",
+        rationale: "Keeping tests in sibling `_tests.rs` files keeps source files short and their \
+            line counts honest.",
+        config: &["rust.cfg_test_split"],
+        fixes: &[
+            "Move the module body to `<file>_tests.rs` and declare it with \
+             `#[cfg(test)] #[path = \"<file>_tests.rs\"] mod tests;`.",
+        ],
+    },
+    Explanation {
+        violation_type: "hotspot",
+        checks: &["cloc"],
+        description: "A file is both large and changed often within the hotspot window.",
+        rationale: "Big files that keep changing cost the most to work in and attract the most bugs.",
+        config: &[
+            "hotspots.check",
+            "hotspots.churn",
+            "hotspots.lines",
+            "hotspots.window",
+        ],
+        fixes: &[
+            "Split the file by concern before its next change, and cover the parts that keep changing with tests.",
+        ],
+    },
+];
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `deadcode` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[Explanation {
+    violation_type: "dead_code",
+    checks: &["deadcode"],
+    description: "An exported item (Rust `pub`, a JS/TS export, a Go exported identifier) is used nowhere else.",
+    rationale: "Unused public API still has to be maintained and read.",
+    config: &["check.deadcode.check", "check.deadcode.exclude"],
+    fixes: &["Delete the item, or make it private if only its own module uses it."],
+}];
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `deps` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "wildcard_version",
+        checks: &["deps"],
+        description: "A dependency accepts any version (`*`, `latest`, or no version).",
+        rationale: "A new major release of the dependency can break the build without any change here.",
+        config: &["check.deps.wildcards", "check.deps.exclude"],
+        fixes: &["Require a version range, e.g. `\"1.4\"` or `^1.4.0`."],
+    },
+    Explanation {
+        violation_type: "unpinned_git",
+        checks: &["deps"],
+        description: "A git dependency follows a branch or tag instead of a commit.",
+        rationale: "A branch can move, so two builds of the same commit may use different code.",
+        config: &["check.deps.unpinned_git", "check.deps.exclude"],
+        fixes: &["Pin the commit: `rev = \"<sha>\"` in Cargo.toml, `#<sha>` in package.json."],
+    },
+    Explanation {
+        violation_type: "duplicate_dependency",
+        checks: &["deps"],
+        description: "A lockfile (or the workspace's go.mod files) resolves a package at more than one version.",
+        rationale: "Each copy adds build time and binary size, and types from different versions don't mix.",
+        config: &["check.deps.duplicates", "check.deps.allow_duplicates"],
+        fixes: &["Align the dependents on one version, or list the package in `allow_duplicates`."],
+    },
+    Explanation {
+        violation_type: "too_many_dependencies",
+        checks: &["deps"],
+        description: "A manifest declares more dependencies than `max_dependencies`.",
+        rationale: "Every dependency is code to audit, update, and build.",
+        config: &["check.deps.max_dependencies"],
+        fixes: &["Remove unused dependencies, or split the package."],
+    },
+    Explanation {
+        violation_type: "unused_feature",
+        checks: &["deps"],
+        description: "A Cargo feature enables nothing, and no `cfg`, `cfg_attr`, or `cfg!` in the package names it.",
+        rationale: "Turning the feature on or off changes nothing, so the feature matrix tests builds that are all the same.",
+        config: &["check.deps.features", "check.deps.exclude"],
+        fixes: &["Gate the code the feature is for on it, or remove the feature."],
+    },
+    Explanation {
+        violation_type: "undeclared_feature",
+        checks: &["deps"],
+        description: "A `cfg`, `cfg_attr`, or `cfg!` names a feature the package's Cargo.toml doesn't declare.",
+        rationale: "Cargo can never enable the feature, so the code it gates never compiles (or, under `not`, always does).",
+        config: &["check.deps.features", "check.deps.exclude"],
+        fixes: &["Declare the feature in `[features]`, or fix the misspelled name."],
+    },
+];
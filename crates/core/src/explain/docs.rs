@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `docs` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "broken_link",
+        checks: &["docs"],
+        description: "A markdown link points to a local file that doesn't exist.",
+        rationale: "Broken links usually mean the docs weren't updated when files moved.",
+        config: &[
+            "check.docs.links.check",
+            "check.docs.links.include",
+            "check.docs.links.exclude",
+        ],
+        fixes: &["Point the link at the file's new path, or remove it."],
+    },
+    Explanation {
+        violation_type: "broken_toc",
+        checks: &["docs"],
+        description: "A directory tree in a markdown code block lists a path that doesn't exist.",
+        rationale: "Directory trees in docs are maps; a wrong one sends readers to files that aren't there.",
+        config: &[
+            "check.docs.toc.check",
+            "check.docs.toc.include",
+            "check.docs.toc.exclude",
+        ],
+        fixes: &[
+            "Update the tree to the current layout.",
+            "If the tree is illustrative, tag the block with a language like `text` or `ignore`.",
+        ],
+    },
+    Explanation {
+        violation_type: "invalid_toc_format",
+        checks: &["docs"],
+        description: "A code block tagged `toc` isn't a box-drawing or indented directory tree.",
+        rationale: "Quench can only verify trees it can parse.",
+        config: &["check.docs.toc.check"],
+        fixes: &["Use box-drawing (`├──`, `└──`, `│`) or consistent indentation."],
+    },
+    Explanation {
+        violation_type: "missing_index",
+        checks: &["docs"],
+        description: "The specs directory has no index file.",
+        rationale: "An index is how readers and agents find the right spec.",
+        config: &["check.docs.specs.path", "check.docs.specs.index_file"],
+        fixes: &["Create CLAUDE.md, overview.md, or index.md in the specs directory."],
+    },
+    Explanation {
+        violation_type: "unreachable_spec",
+        checks: &["docs"],
+        description: "A spec file isn't reachable from the specs index.",
+        rationale: "A spec nothing links to is effectively lost.",
+        config: &["check.docs.specs.index"],
+        fixes: &["Link the spec from the index, or list it in the index's directory tree."],
+    },
+    Explanation {
+        violation_type: "missing_section",
+        checks: &["docs"],
+        description: "A spec file lacks a required section.",
+        rationale: "Required sections (e.g., Purpose) keep specs consistent and complete.",
+        config: &[
+            "check.docs.specs.sections.required[].name",
+            "check.docs.specs.sections.required[].advice",
+        ],
+        fixes: &["Add a heading with the section's name."],
+    },
+    Explanation {
+        violation_type: "forbidden_section",
+        checks: &["docs"],
+        description: "A spec file has a section whose heading matches a forbidden pattern.",
+        rationale: "Some sections (e.g., TODO) mark a spec as unfinished.",
+        config: &["check.docs.specs.sections.forbid"],
+        fixes: &["Resolve and remove the section, or rename it."],
+    },
+    Explanation {
+        violation_type: "spec_too_large",
+        checks: &["docs"],
+        description: "A spec file has more lines or tokens than the specs limit.",
+        rationale: "Agents load specs into context; oversized ones crowd out the code.",
+        config: &["check.docs.specs.max_lines", "check.docs.specs.max_tokens"],
+        fixes: &["Split the spec by topic and link the parts from the index."],
+    },
+    Explanation {
+        violation_type: "missing_docs",
+        checks: &["docs"],
+        description: "A feature commit on the branch doesn't update the docs its area requires.",
+        rationale: "Docs written with the change are accurate; docs written later often aren't written.",
+        config: &[
+            "check.docs.commit.check",
+            "check.docs.commit.types",
+            "check.docs.area.<name>.docs",
+            "check.docs.area.<name>.source",
+        ],
+        fixes: &["Update the docs the area names (e.g., docs/api/**) on the same branch."],
+    },
+];
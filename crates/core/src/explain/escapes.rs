@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `escapes` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "missing_comment",
+        checks: &["escapes"],
+        description: "An escape hatch with `action = \"comment\"` (e.g., `unsafe`) has no justification \
+            comment on or above its line.",
+        rationale: "Escape hatches are sometimes needed; a comment saying why lets reviewers check the reason.",
+        config: &[
+            "check.escapes.patterns[].action",
+            "check.escapes.patterns[].comment",
+            "check.escapes.patterns[].in_tests",
+            "check.escapes.exclude",
+        ],
+        fixes: &["Add the required comment, e.g. `// SAFETY: the pointer is valid for len bytes`."],
+    },
+    Explanation {
+        violation_type: "forbidden",
+        checks: &["escapes"],
+        description: "An escape hatch with `action = \"forbid\"` appears in source code.",
+        rationale: "Some shortcuts (e.g., `.unwrap()` in library code) should never ship.",
+        config: &[
+            "check.escapes.patterns[].action",
+            "check.escapes.patterns[].in_tests",
+            "check.escapes.patterns[].advice",
+            "check.escapes.exclude",
+        ],
+        fixes: &[
+            "Handle the case explicitly, e.g. return an error with `?` instead of unwrapping.",
+        ],
+    },
+    Explanation {
+        violation_type: "threshold_exceeded",
+        checks: &["escapes"],
+        description: "An escape hatch with `action = \"count\"` occurs more often than its `threshold`.",
+        rationale: "Counting lets a project tolerate a few escape hatches while stopping their spread.",
+        config: &[
+            "check.escapes.patterns[].threshold",
+            "check.escapes.patterns[].action",
+            "check.escapes.exclude",
+        ],
+        fixes: &["Remove occurrences until the count is within the threshold."],
+    },
+    Explanation {
+        violation_type: "density_exceeded",
+        checks: &["escapes"],
+        description: "An escape hatch occurs more often per 1000 source lines than its `max_density`.",
+        rationale: "A density limit scales with the codebase, unlike a fixed count.",
+        config: &[
+            "check.escapes.patterns[].max_density",
+            "check.escapes.exclude",
+        ],
+        fixes: &["Remove occurrences, starting with the files that have the most."],
+    },
+    Explanation {
+        violation_type: "bare_assertion",
+        checks: &["escapes"],
+        description: "A changed test file has an assertion that fails without saying what was expected \
+            (e.g., `assert!(x)`, `expect(x).toBeTruthy()`).",
+        rationale: "A bare failure sends the reader to the test source to find out what broke.",
+        config: &[
+            "check.escapes.assertions.check",
+            "check.escapes.assertions.advice",
+        ],
+        fixes: &[
+            "Add a message (`assert!(ok, \"parse should accept empty input\")`).",
+            "Compare values so the failure shows them (`assert_eq!`, `toEqual`).",
+        ],
+    },
+    Explanation {
+        violation_type: "suppress_forbidden",
+        checks: &["escapes"],
+        description: "A lint suppression (`#[allow(...)]`, `//nolint`, `# noqa`, `eslint-disable`, ...) \
+            silences a lint the project forbids suppressing.",
+        rationale: "Some lints catch real bugs; suppressing them hides the bug instead of fixing it.",
+        config: &[
+            "rust.suppress.check",
+            "rust.suppress.source.forbid",
+            "rust.suppress.source.allow",
+            "rust.suppress.test.forbid",
+        ],
+        fixes: &["Remove the suppression and fix the code the lint flagged."],
+    },
+    Explanation {
+        violation_type: "suppress_missing_comment",
+        checks: &["escapes"],
+        description: "A lint suppression has no justification comment.",
+        rationale: "A suppression without a reason can't be reviewed or revisited.",
+        config: &[
+            "rust.suppress.check",
+            "rust.suppress.comment",
+            "rust.suppress.source.<lint>",
+            "rust.suppress.source.allow",
+        ],
+        fixes: &["Add a comment above the suppression saying why, e.g. \
+             `// KEEP: FFI callback signature` before `#[allow(clippy::too_many_arguments)]`."],
+    },
+    Explanation {
+        violation_type: "lint_policy",
+        checks: &["escapes"],
+        description: "A change mixes lint configuration edits with source changes while \
+            `lint_changes = \"standalone\"`.",
+        rationale: "Lint config changes alter what every file is held to; reviewing them apart from code \
+            keeps them from slipping in.",
+        config: &[
+            "rust.policy.check",
+            "rust.policy.lint_changes",
+            "rust.policy.lint_config",
+        ],
+        fixes: &["Commit the lint config change on its own, e.g. as `chore(lint): ...`."],
+    },
+];
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `format` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "unformatted",
+        checks: &["format"],
+        description: "A file differs from its formatter's output (rustfmt, prettier, gofmt, ruff, ...).",
+        rationale: "Formatting differences hide real changes in diffs.",
+        config: &["check.format.formatters"],
+        fixes: &["Run the formatter, or `quench check --fix`."],
+    },
+    Explanation {
+        violation_type: "format_error",
+        checks: &["format"],
+        description: "A formatter failed to run or to parse a file.",
+        rationale: "A file the formatter can't read usually has a syntax error.",
+        config: &["check.format.formatters", "check.format.timeout"],
+        fixes: &["Run the formatter command from the advice and fix the error it prints."],
+    },
+];
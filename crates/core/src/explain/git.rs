@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `git` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "empty_message",
+        checks: &["git"],
+        description: "A commit on the branch has an empty message.",
+        rationale: "History is only useful if each commit says what it does.",
+        config: &["git.commit.check"],
+        fixes: &["Reword the commit: `<type>(<scope>): <description>`."],
+    },
+    Explanation {
+        violation_type: "invalid_format",
+        checks: &["git"],
+        description: "A commit message doesn't follow the conventional commit format.",
+        rationale: "A consistent format makes history searchable and feeds changelogs.",
+        config: &["git.commit.check", "git.commit.format"],
+        fixes: &[
+            "Reword the commit as `<type>(<scope>): <description>`, e.g. `fix(parser): handle empty input`.",
+        ],
+    },
+    Explanation {
+        violation_type: "invalid_type",
+        checks: &["git"],
+        description: "A commit's type isn't one of the allowed types.",
+        rationale: "A fixed set of types keeps history and changelogs consistent.",
+        config: &["git.commit.types"],
+        fixes: &["Reword the commit with an allowed type, e.g. `feat`, `fix`, or `chore`."],
+    },
+    Explanation {
+        violation_type: "invalid_scope",
+        checks: &["git"],
+        description: "A commit's scope isn't one of the allowed scopes.",
+        rationale: "Known scopes make it possible to filter history by area.",
+        config: &["git.commit.scopes"],
+        fixes: &["Reword the commit with an allowed scope, or add the scope to `scopes`."],
+    },
+    Explanation {
+        violation_type: "scope_mismatch",
+        checks: &["git"],
+        description: "A commit's scope names a package the commit doesn't touch.",
+        rationale: "A scope that doesn't match the change misleads anyone filtering history by area.",
+        config: &["git.commit.infer_scope", "git.commit.scope_paths"],
+        fixes: &[
+            "Reword the commit with the scope of a package it changes, as the advice suggests.",
+        ],
+    },
+    Explanation {
+        violation_type: "missing_docs",
+        checks: &["git"],
+        description: "The agent files don't document the commit message format.",
+        rationale: "Agents write commits too; they follow the format only if it is written down.",
+        config: &["git.commit.agents"],
+        fixes: &["Add a Commits section to CLAUDE.md describing the format and allowed types."],
+    },
+    Explanation {
+        violation_type: "dirty_workdir",
+        checks: &["git"],
+        description: "A `--ci` run or baseline update found uncommitted changes with `allow_dirty` set.",
+        rationale: "Baselines and CI results should describe a commit, not local edits.",
+        config: &["git.allow_dirty", "git.baseline"],
+        fixes: &["Commit or stash the listed files, then re-run."],
+    },
+];
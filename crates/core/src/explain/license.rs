@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `license` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "missing_header",
+        checks: &["license"],
+        description: "A source file has no license header.",
+        rationale: "License headers make each file's terms clear when it is copied out of the project.",
+        config: &[
+            "check.license.license",
+            "check.license.copyright",
+            "check.license.patterns",
+            "check.license.exclude",
+        ],
+        fixes: &["Run `quench check --fix` to add the header."],
+    },
+    Explanation {
+        violation_type: "outdated_year",
+        checks: &["license"],
+        description: "A file's copyright year isn't the current year.",
+        rationale: "The year records when the file was last changed.",
+        config: &["check.license.copyright"],
+        fixes: &["Run `quench check --fix` to update the year."],
+    },
+    Explanation {
+        violation_type: "wrong_license",
+        checks: &["license"],
+        description: "A file's SPDX license identifier doesn't match the project's license.",
+        rationale: "Mixed license headers make the project's terms unclear.",
+        config: &["check.license.license"],
+        fixes: &["Run `quench check --fix` to correct the identifier."],
+    },
+];
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `lint` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "lint",
+        checks: &["lint"],
+        description: "An external linter (clippy, eslint, golangci-lint, ruff, ...) reported a diagnostic.",
+        rationale: "Running linters through quench puts their findings next to the other checks.",
+        config: &[
+            "check.lint.linters",
+            "check.lint.grandfather",
+            "check.lint.exclude",
+        ],
+        fixes: &["Fix the diagnostic as the linter's message describes."],
+    },
+    Explanation {
+        violation_type: "lint_error",
+        checks: &["lint"],
+        description: "A linter failed to run (missing, crashed, or timed out).",
+        rationale: "A linter that doesn't run reports nothing, which looks like a pass.",
+        config: &["check.lint.linters", "check.lint.timeout"],
+        fixes: &["Install the linter (see `quench doctor`), or run its command to see the error."],
+    },
+];
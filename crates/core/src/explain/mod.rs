@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Violation type explanations (`quench explain`).
+//!
+//! A violation's advice is short and specific to one finding. The
+//! explanation is the long form, one per violation type and check: what the
+//! violation means, why quench reports it, the config keys that tune it
+//! (named as in `quench config docs`), and example fixes.
+
+mod agents;
+mod build;
+mod cloc;
+mod deadcode;
+mod deps;
+mod docs;
+mod escapes;
+mod format;
+mod git;
+mod license;
+mod lint;
+mod release;
+mod snapshots;
+mod tests_check;
+mod tools;
+mod whitespace;
+
+use crate::config::INSTANCE_SEPARATOR;
+
+/// The long-form description of a violation type.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Explanation {
+    /// Violation type as it appears in output, e.g. `file_too_large`.
+    pub violation_type: &'static str,
+    /// Checks that report it with this meaning.
+    pub checks: &'static [&'static str],
+    /// What the violation means.
+    pub description: &'static str,
+    /// Why quench reports it.
+    pub rationale: &'static str,
+    /// Config keys that tune or disable it.
+    pub config: &'static [&'static str],
+    /// Example fixes.
+    pub fixes: &'static [&'static str],
+}
+
+impl Explanation {
+    /// Rule IDs (`<check>/<type>`) naming this violation type.
+    pub fn rule_ids(&self) -> Vec<String> {
+        self.checks
+            .iter()
+            .map(|check| crate::rules::rule_id(check, self.violation_type))
+            .collect()
+    }
+}
+
+/// Find the explanations for a violation type or rule ID.
+///
+/// Accepts `file_too_large`, `file-too-large`, or a rule ID such as
+/// `cloc/file-too-large` (or `cloc:scripts/file-too-large`), which limits
+/// the result to that check. A type several checks report with different
+/// meanings has one explanation per meaning.
+pub fn explain(query: &str) -> Vec<&'static Explanation> {
+    let query = query.trim().replace('-', "_");
+    let (check, violation_type) = match query.split_once('/') {
+        Some((check, violation_type)) => {
+            let base = check
+                .split_once(INSTANCE_SEPARATOR)
+                .map_or(check, |(base, _)| base);
+            (Some(base), violation_type)
+        }
+        None => (None, query.as_str()),
+    };
+    explanations()
+        .filter(|e| e.violation_type == violation_type)
+        .filter(|e| check.is_none_or(|check| e.checks.contains(&check)))
+        .collect()
+}
+
+/// Every violation type quench reports, grouped by check in check order.
+const BY_CHECK: &[&[Explanation]] = &[
+    cloc::EXPLANATIONS,
+    escapes::EXPLANATIONS,
+    agents::EXPLANATIONS,
+    docs::EXPLANATIONS,
+    tests_check::EXPLANATIONS,
+    git::EXPLANATIONS,
+    build::EXPLANATIONS,
+    license::EXPLANATIONS,
+    whitespace::EXPLANATIONS,
+    format::EXPLANATIONS,
+    lint::EXPLANATIONS,
+    snapshots::EXPLANATIONS,
+    deadcode::EXPLANATIONS,
+    deps::EXPLANATIONS,
+    release::EXPLANATIONS,
+    tools::EXPLANATIONS,
+];
+
+/// Every violation type quench reports, in check order.
+pub fn explanations() -> impl Iterator<Item = &'static Explanation> {
+    BY_CHECK.iter().flat_map(|check| check.iter())
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
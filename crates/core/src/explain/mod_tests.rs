@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+use std::collections::HashSet;
+
+use crate::checks::{CHECK_NAMES, MODE_CHECK_NAMES};
+
+#[test]
+fn every_explanation_names_known_checks() {
+    for explanation in explanations() {
+        assert!(
+            !explanation.checks.is_empty(),
+            "{}",
+            explanation.violation_type
+        );
+        for check in explanation.checks {
+            assert!(
+                CHECK_NAMES.contains(check) || MODE_CHECK_NAMES.contains(check),
+                "{}: unknown check {check}",
+                explanation.violation_type
+            );
+        }
+    }
+}
+
+#[test]
+fn each_check_explains_a_type_once() {
+    let mut seen = HashSet::new();
+    for explanation in explanations() {
+        for check in explanation.checks {
+            assert!(
+                seen.insert((*check, explanation.violation_type)),
+                "{check}/{} explained twice",
+                explanation.violation_type
+            );
+        }
+    }
+}
+
+#[test]
+fn config_keys_exist_in_reference() {
+    let keys: HashSet<String> = crate::config::reference::entries()
+        .iter()
+        .map(|entry| entry.key())
+        .collect();
+    for explanation in explanations() {
+        for key in explanation.config {
+            assert!(
+                keys.contains(*key),
+                "{}: `{key}` is not a config key",
+                explanation.violation_type
+            );
+        }
+    }
+}
+
+#[test]
+fn explain_accepts_type_with_hyphens_or_underscores() {
+    let found = explain("missing_comment");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found, explain("missing-comment"));
+}
+
+#[test]
+fn explain_returns_each_meaning_of_a_shared_type() {
+    let found = explain("file_too_large");
+    let checks: Vec<_> = found.iter().flat_map(|e| e.checks.iter()).collect();
+    assert_eq!(checks, [&"cloc", &"agents"]);
+}
+
+#[test]
+fn explain_rule_id_limits_to_check() {
+    let found = explain("agents/file-too-large");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].checks, ["agents"]);
+
+    let found = explain("cloc:scripts/file-too-large");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].checks, ["cloc"]);
+
+    assert!(explain("git/file-too-large").is_empty());
+}
+
+#[test]
+fn explain_unknown_type_is_empty() {
+    assert!(explain("no_such_violation").is_empty());
+}
+
+#[test]
+fn rule_ids_cover_every_check() {
+    let explanation = explain("forbidden_table")[0];
+    assert_eq!(
+        explanation.rule_ids(),
+        ["agents/forbidden-table", "docs/forbidden-table"]
+    );
+}
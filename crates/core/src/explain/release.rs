@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `release` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "missing_changelog",
+        checks: &["release"],
+        description: "The changelog has no section for the pending version.",
+        rationale: "Users learn what a release changes from its changelog.",
+        config: &["check.release.changelog"],
+        fixes: &["Add a section such as `## [1.4.0]` describing the release."],
+    },
+    Explanation {
+        violation_type: "version_mismatch",
+        checks: &["release"],
+        description: "A manifest or doc pins a version other than the pending release.",
+        rationale: "Releases with mismatched versions publish the wrong numbers.",
+        config: &["check.release.docs"],
+        fixes: &["Update the version to the pending release in every manifest and doc."],
+    },
+    Explanation {
+        violation_type: "placeholder_test",
+        checks: &["release"],
+        description: "A placeholder test (e.g., `#[ignore = \"TODO\"]`, `test.todo`) remains at release time.",
+        rationale: "A placeholder marks behavior that was planned but never tested.",
+        config: &["check.release.exclude"],
+        fixes: &["Implement the test, or remove it."],
+    },
+    Explanation {
+        violation_type: "release_marker",
+        checks: &["release"],
+        description: "A `TODO(before-release)` marker remains at release time.",
+        rationale: "The marker was left to block the release until it was resolved.",
+        config: &["check.release.markers", "check.release.exclude"],
+        fixes: &["Do what the marker asks, then remove it."],
+    },
+];
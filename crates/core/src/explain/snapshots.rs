@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `snapshots` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "orphaned_snapshot",
+        checks: &["snapshots"],
+        description: "A snapshot file belongs to no test.",
+        rationale: "Orphaned snapshots are dead weight that reviewers still read.",
+        config: &["check.snapshots.orphans", "check.snapshots.patterns"],
+        fixes: &["Delete it, e.g. `cargo insta test --unreferenced=delete` or `jest --ci -u`."],
+    },
+    Explanation {
+        violation_type: "snapshot_too_large",
+        checks: &["snapshots"],
+        description: "A snapshot file is larger than `max_size`.",
+        rationale: "Large snapshots get approved without being read.",
+        config: &["check.snapshots.max_size", "check.snapshots.exclude"],
+        fixes: &[
+            "Snapshot only what the test asserts on: narrow the value or redact volatile fields.",
+        ],
+    },
+    Explanation {
+        violation_type: "snapshot_churn",
+        checks: &["snapshots"],
+        description: "A change rewrites more snapshot lines than `max_churn`.",
+        rationale: "Mass snapshot updates hide the few lines that matter.",
+        config: &["check.snapshots.max_churn"],
+        fixes: &["Put snapshot updates in their own change, or narrow what the tests snapshot."],
+    },
+];
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `tests` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "missing_tests",
+        checks: &["tests"],
+        description: "A changed source file has no matching test change (test file, inline tests, or doctest).",
+        rationale: "Changes land with tests when tests are required with the change.",
+        config: &[
+            "check.tests.commit.check",
+            "check.tests.commit.scope",
+            "check.tests.commit.exclude",
+            "check.tests.commit.placeholders",
+            "check.tests.commit.mechanical",
+        ],
+        fixes: &[
+            "Add or update the file's tests (e.g., tests/parser.rs or a sibling parser_tests.rs).",
+            "Add a doctest or `#[cfg(test)]` test in the file itself.",
+        ],
+    },
+    Explanation {
+        violation_type: "test_suite_failed",
+        checks: &["tests"],
+        description: "A configured test suite failed, or couldn't run (e.g., a compile error or timeout).",
+        rationale: "Timing and coverage from a failing suite can't be trusted.",
+        config: &[
+            "check.tests.suite[].runner",
+            "check.tests.suite[].command",
+            "check.tests.suite[].setup",
+            "check.tests.suite[].timeout",
+        ],
+        fixes: &["Run the suite's command locally and fix the failing tests."],
+    },
+    Explanation {
+        violation_type: "coverage_below_min",
+        checks: &["tests"],
+        description: "Line coverage for the project, a package, or a path glob is below its minimum.",
+        rationale: "Untested code is where regressions go unnoticed.",
+        config: &[
+            "check.tests.coverage.check",
+            "check.tests.coverage.min",
+            "check.tests.coverage.package.<name>.min",
+            "check.tests.coverage.path.<name>.min",
+        ],
+        fixes: &["Add tests for the least covered files in the package or path."],
+    },
+    Explanation {
+        violation_type: "patch_coverage_below_min",
+        checks: &["tests"],
+        description: "Coverage of the lines changed since `--base` is below `patch_min`.",
+        rationale: "Holding new code to a coverage bar improves coverage without failing on older code.",
+        config: &[
+            "check.tests.coverage.check",
+            "check.tests.coverage.patch_min",
+        ],
+        fixes: &[
+            "Add tests that run the changed lines listed by the `patch_uncovered` violations.",
+        ],
+    },
+    Explanation {
+        violation_type: "patch_uncovered",
+        checks: &["tests"],
+        description: "Lines a file changed since `--base` that no test ran, reported with \
+            `patch_coverage_below_min`.",
+        rationale: "Listing the lines shows exactly what the new tests need to reach.",
+        config: &["check.tests.coverage.patch_min"],
+        fixes: &["Add a test that exercises the listed lines."],
+    },
+    Explanation {
+        violation_type: "time_total_exceeded",
+        checks: &["tests"],
+        description: "A suite's total run time is over its `max_total`.",
+        rationale: "Slow suites get run less often.",
+        config: &["check.tests.suite[].max_total", "check.tests.time.check"],
+        fixes: &["Speed up or split the slowest tests (see the suite's `slowest` metrics)."],
+    },
+    Explanation {
+        violation_type: "time_avg_exceeded",
+        checks: &["tests"],
+        description: "A suite's average test time is over its `max_avg`.",
+        rationale: "A rising average means tests are doing more setup or I/O than they need.",
+        config: &["check.tests.suite[].max_avg", "check.tests.time.check"],
+        fixes: &["Share expensive fixtures, or replace real I/O with in-memory fakes."],
+    },
+    Explanation {
+        violation_type: "time_test_exceeded",
+        checks: &["tests"],
+        description: "A single test ran longer than its suite's `max_test`.",
+        rationale: "One slow test holds up every run of the suite.",
+        config: &["check.tests.suite[].max_test", "check.tests.time.check"],
+        fixes: &["Shrink the test's input, or move it to a slower suite that runs less often."],
+    },
+];
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations any check reports when a pinned tool drifts.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[Explanation {
+    violation_type: "tool_version",
+    checks: &["tests", "build"],
+    description: "A tool pinned in `[tools]` is missing or at another version.",
+    rationale: "Toolchain drift shifts timing, coverage, and size metrics for reasons unrelated to the code.",
+    config: &["tools.<tool>", "tools.on_mismatch"],
+    fixes: &["Install the pinned version (`quench doctor` prints the command), or update the pin."],
+}];
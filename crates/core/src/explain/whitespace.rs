@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Explanations for violations of the `whitespace` check.
+
+use super::Explanation;
+
+pub(super) const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        violation_type: "line_ending",
+        checks: &["whitespace"],
+        description: "A file's line endings don't match the configured style.",
+        rationale: "Mixed line endings produce noisy diffs.",
+        config: &["check.whitespace.line_endings", "check.whitespace.exclude"],
+        fixes: &["Run `quench check --fix` to convert the line endings."],
+    },
+    Explanation {
+        violation_type: "trailing_whitespace",
+        checks: &["whitespace"],
+        description: "Lines end with spaces or tabs.",
+        rationale: "Trailing whitespace produces noisy diffs.",
+        config: &["check.whitespace.trailing", "check.whitespace.exclude"],
+        fixes: &["Run `quench check --fix` to strip it."],
+    },
+    Explanation {
+        violation_type: "missing_final_newline",
+        checks: &["whitespace"],
+        description: "A file doesn't end with a newline.",
+        rationale: "Tools that append to or concatenate files expect a final newline.",
+        config: &["check.whitespace.final_newline", "check.whitespace.exclude"],
+        fixes: &["Run `quench check --fix` to add it."],
+    },
+    Explanation {
+        violation_type: "wrong_indent",
+        checks: &["whitespace"],
+        description: "A line is indented with tabs where spaces are expected, or the reverse.",
+        rationale: "Mixed indentation renders differently in every editor.",
+        config: &[
+            "check.whitespace.indent",
+            "check.whitespace.tab_width",
+            "check.whitespace.languages",
+        ],
+        fixes: &["Run `quench check --fix` to re-indent."],
+    },
+];
@@ -0,0 +1,263 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Centralized file reading with size-based strategy.
+//!
+// Allow unsafe_code for memory-mapped I/O (required by memmap2).
+// Safety justification:
+// 1. File handle is valid (just opened)
+// 2. We don't mutate the mapped memory
+// 3. Stale data on concurrent modification is acceptable for linting
+#![allow(unsafe_code)]
+//!
+//! Per docs/specs/20-performance.md:
+//! - < 64KB: Direct read into buffer
+//! - >= 64KB: Memory-mapped I/O
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use memmap2::Mmap;
+
+use crate::file_size::MMAP_THRESHOLD;
+
+/// UTF-8 byte order mark.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Bytes scanned for NUL when sniffing binary content (same window as git).
+const BINARY_SNIFF_LEN: usize = 8000;
+
+/// Content of a file, decoded to text.
+///
+/// A leading byte order mark is stripped, UTF-16 files with a BOM are
+/// transcoded, and invalid UTF-8 is decoded lossily rather than skipped.
+pub enum FileContent {
+    /// Small UTF-8 file read into memory.
+    Owned(String),
+    /// Large UTF-8 file memory-mapped.
+    Mapped(MappedContent),
+    /// UTF-16 file transcoded to UTF-8.
+    Transcoded(String),
+    /// Invalid UTF-8 (or UTF-16) decoded with U+FFFD replacement characters.
+    Lossy(String),
+    /// Binary content (NUL bytes), which has no text.
+    Binary,
+}
+
+/// Memory-mapped file content with UTF-8 validation.
+pub struct MappedContent {
+    mmap: Mmap,
+    /// Offset past a leading BOM.
+    start: usize,
+}
+
+impl MappedContent {
+    /// Get content as string slice.
+    /// Returns None if content is not valid UTF-8.
+    pub fn as_str(&self) -> Option<&str> {
+        std::str::from_utf8(&self.mmap[self.start..]).ok()
+    }
+}
+
+impl FileContent {
+    /// Read file using appropriate strategy based on size.
+    pub fn read(path: &Path) -> io::Result<Self> {
+        let meta = fs::metadata(path)?;
+        let size = meta.len();
+
+        if size < MMAP_THRESHOLD {
+            // Small file: direct read
+            Ok(Self::decode(fs::read(path)?))
+        } else {
+            // Large file: memory-map
+            let file = File::open(path)?;
+            // SAFETY: File handle is valid (just opened), we don't mutate the mapped memory,
+            // and stale data on concurrent modification is acceptable for linting.
+            let mmap = unsafe { Mmap::map(&file)? };
+            let start = if mmap.starts_with(UTF8_BOM) {
+                UTF8_BOM.len()
+            } else {
+                0
+            };
+            if std::str::from_utf8(&mmap[start..]).is_ok() {
+                Ok(FileContent::Mapped(MappedContent { mmap, start }))
+            } else {
+                Ok(Self::decode(mmap.to_vec()))
+            }
+        }
+    }
+
+    /// Decode file bytes to text.
+    fn decode(bytes: Vec<u8>) -> Self {
+        if let Some(rest) = bytes.strip_prefix(UTF8_BOM) {
+            return Self::decode_utf8(rest.to_vec());
+        }
+        if let Some(rest) = bytes.strip_prefix(b"\xFF\xFE") {
+            return Self::decode_utf16(rest, u16::from_le_bytes);
+        }
+        if let Some(rest) = bytes.strip_prefix(b"\xFE\xFF") {
+            return Self::decode_utf16(rest, u16::from_be_bytes);
+        }
+        let sniff = &bytes[..bytes.len().min(BINARY_SNIFF_LEN)];
+        if memchr::memchr(0, sniff).is_some() {
+            return FileContent::Binary;
+        }
+        Self::decode_utf8(bytes)
+    }
+
+    fn decode_utf8(bytes: Vec<u8>) -> Self {
+        match String::from_utf8(bytes) {
+            Ok(text) => FileContent::Owned(text),
+            Err(e) => FileContent::Lossy(String::from_utf8_lossy(e.as_bytes()).into_owned()),
+        }
+    }
+
+    fn decode_utf16(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> Self {
+        let units = bytes.chunks(2).map(|pair| match *pair {
+            [a, b] => unit([a, b]),
+            // Odd trailing byte: not a code unit
+            _ => 0xD800,
+        });
+        let mut lossy = false;
+        let text = char::decode_utf16(units)
+            .map(|c| {
+                c.unwrap_or_else(|_| {
+                    lossy = true;
+                    char::REPLACEMENT_CHARACTER
+                })
+            })
+            .collect();
+        if lossy {
+            FileContent::Lossy(text)
+        } else {
+            FileContent::Transcoded(text)
+        }
+    }
+
+    /// Get content as string slice, or `None` for binary content.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            FileContent::Owned(s) | FileContent::Transcoded(s) | FileContent::Lossy(s) => Some(s),
+            FileContent::Mapped(m) => m.as_str(),
+            FileContent::Binary => None,
+        }
+    }
+
+    /// Whether decoding replaced invalid bytes.
+    pub fn is_lossy(&self) -> bool {
+        matches!(self, FileContent::Lossy(_))
+    }
+
+    /// Whether writing `as_str()` back keeps the file's bytes (apart from a
+    /// UTF-8 BOM), so fixes can safely rewrite it.
+    pub fn round_trips(&self) -> bool {
+        matches!(self, FileContent::Owned(_) | FileContent::Mapped(_))
+    }
+}
+
+/// Files decoded lossily, collected across the checks of one run.
+///
+/// Several checks may read the same file; each is recorded once.
+#[derive(Debug, Default)]
+pub struct DecodeLog {
+    lossy: Mutex<BTreeSet<PathBuf>>,
+}
+
+impl DecodeLog {
+    /// Read a file, recording it if decoding was lossy.
+    pub fn read(&self, path: &Path) -> io::Result<FileContent> {
+        Ok(self.record(path, FileContent::read(path)?))
+    }
+
+    /// Record content decoded from elsewhere (e.g., the git index) if it
+    /// was lossy.
+    pub fn record(&self, path: &Path, content: FileContent) -> FileContent {
+        if content.is_lossy()
+            && let Ok(mut lossy) = self.lossy.lock()
+        {
+            lossy.insert(path.to_path_buf());
+        }
+        content
+    }
+
+    /// Files decoded lossily so far, sorted.
+    pub fn lossy(&self) -> Vec<PathBuf> {
+        self.lossy
+            .lock()
+            .map(|lossy| lossy.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Staged (git index) content of partially staged files, which `--staged`
+/// runs check in place of the working tree.
+///
+/// Such a file is checked as it will be committed, not as it sits on disk.
+#[derive(Debug, Default)]
+pub struct StagedContent {
+    blobs: HashMap<PathBuf, Vec<u8>>,
+}
+
+impl StagedContent {
+    /// Staged content keyed by absolute path.
+    pub fn new(blobs: HashMap<PathBuf, Vec<u8>>) -> Self {
+        Self { blobs }
+    }
+
+    /// Whether `path` has staged content.
+    pub fn contains(&self, path: &Path) -> bool {
+        self.blobs.contains_key(path)
+    }
+
+    /// Staged content of `path` decoded to text, or None if it isn't staged.
+    pub fn read(&self, path: &Path) -> Option<FileContent> {
+        Some(FileContent::decode(self.blobs.get(path)?.clone()))
+    }
+
+    /// Staged bytes of `path`, or None if it isn't staged.
+    pub fn bytes(&self, path: &Path) -> Option<&[u8]> {
+        self.blobs.get(path).map(Vec::as_slice)
+    }
+}
+
+/// Raw file bytes, either owned or memory-mapped, without UTF-8 validation.
+///
+/// For byte-level scans (line counting) that don't need `&str`.
+pub enum FileBytes {
+    /// Small file read into memory.
+    Owned(Vec<u8>),
+    /// Large file memory-mapped.
+    Mapped(Mmap),
+}
+
+impl FileBytes {
+    /// Read file bytes using appropriate strategy based on size.
+    pub fn read(path: &Path) -> io::Result<Self> {
+        let meta = fs::metadata(path)?;
+
+        if meta.len() < MMAP_THRESHOLD {
+            Ok(FileBytes::Owned(fs::read(path)?))
+        } else {
+            let file = File::open(path)?;
+            // SAFETY: File handle is valid (just opened), we don't mutate the mapped memory,
+            // and stale data on concurrent modification is acceptable for linting.
+            let mmap = unsafe { Mmap::map(&file)? };
+            Ok(FileBytes::Mapped(mmap))
+        }
+    }
+
+    /// Get content as a byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            FileBytes::Owned(v) => v,
+            FileBytes::Mapped(m) => m,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "file_reader_tests.rs"]
+mod tests;
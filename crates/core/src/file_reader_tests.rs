@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+#[test]
+fn reads_small_file_directly() {
+    let mut file = NamedTempFile::new().unwrap();
+    writeln!(file, "hello world").unwrap();
+
+    let content = FileContent::read(file.path()).unwrap();
+    assert!(matches!(content, FileContent::Owned(_)));
+    assert_eq!(content.as_str(), Some("hello world\n"));
+}
+
+#[test]
+fn reads_large_file_with_mmap() {
+    let mut file = NamedTempFile::new().unwrap();
+    // Write more than MMAP_THRESHOLD bytes
+    let data = "x".repeat(65 * 1024);
+    write!(file, "{}", data).unwrap();
+
+    let content = FileContent::read(file.path()).unwrap();
+    assert!(matches!(content, FileContent::Mapped(_)));
+    assert_eq!(content.as_str().map(|s| s.len()), Some(65 * 1024));
+}
+
+#[test]
+fn decodes_invalid_utf8_lossily() {
+    let mut file = NamedTempFile::new().unwrap();
+    // Write more than MMAP_THRESHOLD bytes with invalid UTF-8
+    let mut data = vec![b'x'; 65 * 1024];
+    data[1000] = 0xFF; // Invalid UTF-8 byte
+    file.write_all(&data).unwrap();
+
+    let content = FileContent::read(file.path()).unwrap();
+    assert!(content.is_lossy());
+    assert!(!content.round_trips());
+    let text = content.as_str().unwrap();
+    assert_eq!(text.chars().nth(1000), Some(char::REPLACEMENT_CHARACTER));
+    assert_eq!(text.chars().count(), 65 * 1024);
+}
+
+#[test]
+fn strips_utf8_bom() {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(b"\xEF\xBB\xBF// SPDX\n").unwrap();
+
+    let content = FileContent::read(file.path()).unwrap();
+    assert!(!content.is_lossy());
+    assert_eq!(content.as_str(), Some("// SPDX\n"));
+}
+
+#[test]
+fn strips_utf8_bom_from_mapped_file() {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(b"\xEF\xBB\xBF").unwrap();
+    file.write_all(&vec![b'x'; 65 * 1024]).unwrap();
+
+    let content = FileContent::read(file.path()).unwrap();
+    assert!(matches!(content, FileContent::Mapped(_)));
+    assert!(content.as_str().unwrap().starts_with('x'));
+}
+
+#[test]
+fn transcodes_utf16_with_bom() {
+    let mut file = NamedTempFile::new().unwrap();
+    let mut data = vec![0xFF, 0xFE];
+    for unit in "héllo\n".encode_utf16() {
+        data.extend(unit.to_le_bytes());
+    }
+    file.write_all(&data).unwrap();
+
+    let content = FileContent::read(file.path()).unwrap();
+    assert!(!content.is_lossy());
+    assert!(!content.round_trips());
+    assert_eq!(content.as_str(), Some("héllo\n"));
+}
+
+#[test]
+fn binary_content_has_no_text() {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(b"\x89PNG\r\n\x1a\n\x00\x00").unwrap();
+
+    let content = FileContent::read(file.path()).unwrap();
+    assert!(matches!(content, FileContent::Binary));
+    assert!(content.as_str().is_none());
+}
+
+#[test]
+fn decode_log_records_lossy_files_once() {
+    let mut lossy = NamedTempFile::new().unwrap();
+    lossy.write_all(b"caf\xE9\n").unwrap();
+    let mut valid = NamedTempFile::new().unwrap();
+    valid.write_all(b"ok\n").unwrap();
+    let log = DecodeLog::default();
+
+    log.read(lossy.path()).unwrap();
+    log.read(lossy.path()).unwrap();
+    log.read(valid.path()).unwrap();
+
+    assert_eq!(log.lossy(), [lossy.path().to_path_buf()]);
+}
+
+#[test]
+fn staged_content_decodes_staged_blobs_only() {
+    let path = PathBuf::from("/project/src/lib.rs");
+    let staged = StagedContent::new(HashMap::from([(
+        path.clone(),
+        b"\xEF\xBB\xBFfn f() {}\n".to_vec(),
+    )]));
+
+    assert_eq!(staged.read(&path).unwrap().as_str(), Some("fn f() {}\n"));
+    assert!(staged.read(Path::new("/project/src/main.rs")).is_none());
+    assert!(staged.contains(&path));
+}
+
+#[test]
+fn reads_small_file_bytes_directly() {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(b"ok\xFF\n").unwrap();
+
+    let bytes = FileBytes::read(file.path()).unwrap();
+    assert!(matches!(bytes, FileBytes::Owned(_)));
+    assert_eq!(bytes.as_bytes(), b"ok\xFF\n");
+}
+
+#[test]
+fn reads_large_file_bytes_with_mmap() {
+    let mut file = NamedTempFile::new().unwrap();
+    let mut data = vec![b'x'; 65 * 1024];
+    data[1000] = 0xFF; // Invalid UTF-8 is fine for raw bytes
+    file.write_all(&data).unwrap();
+
+    let bytes = FileBytes::read(file.path()).unwrap();
+    assert!(matches!(bytes, FileBytes::Mapped(_)));
+    assert_eq!(bytes.as_bytes().len(), 65 * 1024);
+}
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Fixers for check violations (`quench fix`).
+//!
+//! A check that can repair its violations after the fact implements
+//! [`Fix`] and returns itself from [`Check::fixer`]. Under `quench fix`, the
+//! runner hands each fixer its check's violations and applies what comes
+//! back: edits go through [`CheckContext::write_fix`], so `--dry-run` and
+//! `--emit-patch` collect them as a diff, and suggestions are appended to
+//! the advice of violations that can't be fixed automatically.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+
+use crate::check::{Check, CheckContext, CheckResult, Violation};
+
+/// What a fixer would change.
+#[derive(Debug, Default)]
+pub struct Fixes {
+    /// Fixed file contents, by absolute path.
+    pub edits: Vec<(PathBuf, String)>,
+    /// Violations the edits fix, as indices into the violations passed in.
+    pub fixed: Vec<usize>,
+    /// Guidance for violations that need a person, by index.
+    pub suggestions: Vec<(usize, String)>,
+    /// Violations the edits leave in place on another line, by index, with
+    /// their new line.
+    pub moved: Vec<(usize, u32)>,
+}
+
+/// Repairs some of a check's violations.
+pub trait Fix: Send + Sync {
+    /// Compute fixes for `violations`, without writing anything.
+    ///
+    /// Violation paths are relative to `ctx.root`.
+    fn fix(&self, ctx: &CheckContext, violations: &[Violation]) -> Fixes;
+}
+
+/// Run `check`'s fixer over `result` under `quench fix`.
+///
+/// Violations whose file was written are dropped; a result left with none
+/// passes and is reported as fixed. A file that can't be written (e.g.,
+/// partially staged) keeps its violations.
+pub fn apply(check: &dyn Check, mut result: CheckResult, ctx: &CheckContext) -> CheckResult {
+    let Some(fixer) = check.fixer() else {
+        return result;
+    };
+    if !ctx.run_fixers || result.skipped || result.violations.is_empty() {
+        return result;
+    }
+
+    let fixes = fixer.fix(ctx, &result.violations);
+    for (idx, suggestion) in fixes.suggestions {
+        if let Some(violation) = result.violations.get_mut(idx) {
+            violation.advice = format!("{}\n{}", violation.advice, suggestion);
+        }
+    }
+
+    let written: BTreeSet<PathBuf> = fixes
+        .edits
+        .iter()
+        .filter(|(path, content)| ctx.write_fix(path, content).is_ok())
+        .map(|(path, _)| relative(ctx.root, path).to_path_buf())
+        .collect();
+    let in_written = |v: &Violation| {
+        v.file
+            .as_deref()
+            .is_some_and(|file| written.contains(relative(ctx.root, file)))
+    };
+    // Previews leave the files, and so the lines, as they were
+    if ctx.patch.is_none() && !ctx.dry_run {
+        for (idx, line) in fixes.moved {
+            if let Some(violation) = result.violations.get_mut(idx).filter(|v| in_written(v)) {
+                violation.line = Some(line);
+            }
+        }
+    }
+    let fixed: BTreeSet<usize> = fixes
+        .fixed
+        .into_iter()
+        .filter(|&idx| result.violations.get(idx).is_some_and(in_written))
+        .collect();
+    if fixed.is_empty() {
+        return result;
+    }
+
+    let mut idx = 0;
+    result.violations.retain(|_| {
+        idx += 1;
+        !fixed.contains(&(idx - 1))
+    });
+    let files: Vec<String> = written.iter().map(|p| p.display().to_string()).collect();
+    let summary = json!({
+        "violations_fixed": fixed.len(),
+        "files_fixed": files,
+    });
+    if result.violations.is_empty() {
+        result.passed = true;
+        result.with_fix_summary(summary)
+    } else {
+        // Still failing: report the remaining violations, not FIXED
+        result.fix_summary = Some(summary);
+        result
+    }
+}
+
+fn relative<'a>(root: &Path, path: &'a Path) -> &'a Path {
+    path.strip_prefix(root).unwrap_or(path)
+}
+
+#[cfg(test)]
+#[path = "fix_tests.rs"]
+mod tests;
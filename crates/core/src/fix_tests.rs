@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+use std::sync::atomic::AtomicUsize;
+
+use tempfile::TempDir;
+
+use crate::config::Config;
+use crate::diagnostics::Diagnostics;
+use crate::file_reader::DecodeLog;
+use crate::patch::FixPatch;
+
+/// Flags `bad.txt` (fixable, by rewriting it) and `big.txt` (not fixable).
+struct Probe;
+
+impl Check for Probe {
+    fn name(&self) -> &'static str {
+        "probe"
+    }
+
+    fn description(&self) -> &'static str {
+        "Probe"
+    }
+
+    fn run(&self, _ctx: &CheckContext) -> CheckResult {
+        CheckResult::failed(
+            self.name(),
+            vec![
+                Violation::file("bad.txt", 1, "bad", "Fix it."),
+                Violation::file_only("big.txt", "big", "Split it."),
+            ],
+        )
+    }
+
+    fn fixer(&self) -> Option<&dyn Fix> {
+        Some(self)
+    }
+}
+
+impl Fix for Probe {
+    fn fix(&self, ctx: &CheckContext, violations: &[Violation]) -> Fixes {
+        let mut fixes = Fixes::default();
+        for (idx, violation) in violations.iter().enumerate() {
+            match violation.violation_type.as_str() {
+                "bad" => {
+                    fixes
+                        .edits
+                        .push((ctx.root.join("bad.txt"), "good\n".to_string()));
+                    fixes.fixed.push(idx);
+                }
+                _ => fixes
+                    .suggestions
+                    .push((idx, "Split at line 2.".to_string())),
+            }
+        }
+        fixes
+    }
+}
+
+/// Run the probe and its fixer, as the runner does.
+fn run(
+    root: &Path,
+    fix: bool,
+    run_fixers: bool,
+    dry_run: bool,
+    patch: Option<&FixPatch>,
+    only_bad: bool,
+) -> CheckResult {
+    let config = Config::default();
+    let violation_count = AtomicUsize::new(0);
+    let decode_log = DecodeLog::default();
+    let diagnostics = Diagnostics::silent();
+    let ctx = CheckContext {
+        root,
+        files: &[],
+        config: &config,
+        limit: None,
+        violation_count: &violation_count,
+        changed_files: None,
+        fix,
+        run_fixers,
+        dry_run,
+        patch,
+        ci_mode: false,
+        base_branch: None,
+        staged: false,
+        staged_content: None,
+        verbose: false,
+        decode_log: &decode_log,
+        diagnostics: &diagnostics,
+    };
+    let mut result = Probe.run(&ctx);
+    if only_bad {
+        result.violations.truncate(1);
+    }
+    apply(&Probe, result, &ctx)
+}
+
+fn project() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("bad.txt"), "bad\n").unwrap();
+    temp
+}
+
+#[test]
+fn fixed_violations_are_dropped_and_files_written() {
+    let temp = project();
+    let result = run(temp.path(), true, true, false, None, true);
+
+    assert!(result.passed);
+    assert!(result.fixed);
+    assert!(result.violations.is_empty());
+    assert_eq!(
+        result.fix_summary.unwrap(),
+        json!({ "violations_fixed": 1, "files_fixed": ["bad.txt"] })
+    );
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("bad.txt")).unwrap(),
+        "good\n"
+    );
+}
+
+#[test]
+fn unfixed_violations_keep_the_check_failing_with_suggestions() {
+    let temp = project();
+    let result = run(temp.path(), true, true, false, None, false);
+
+    assert!(!result.passed);
+    assert!(!result.fixed, "FIXED would hide the remaining violations");
+    assert_eq!(result.violations.len(), 1);
+    assert_eq!(result.violations[0].advice, "Split it.\nSplit at line 2.");
+    assert!(result.fix_summary.is_some());
+}
+
+#[test]
+fn without_fix_result_is_unchanged() {
+    let temp = project();
+    let result = run(temp.path(), false, false, false, None, false);
+
+    assert!(!result.passed);
+    assert_eq!(result.violations.len(), 2);
+    assert_eq!(result.violations[1].advice, "Split it.");
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("bad.txt")).unwrap(),
+        "bad\n"
+    );
+}
+
+#[test]
+fn check_fix_without_fixers_leaves_files_alone() {
+    let temp = project();
+    let result = run(temp.path(), true, false, false, None, false);
+
+    assert!(!result.passed);
+    assert_eq!(result.violations.len(), 2);
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("bad.txt")).unwrap(),
+        "bad\n"
+    );
+}
+
+#[test]
+fn dry_run_records_fixes_in_patch_without_writing() {
+    let temp = project();
+    let patch = FixPatch::new();
+    let result = run(temp.path(), true, true, true, Some(&patch), true);
+
+    assert!(result.fixed);
+    assert_eq!(patch.len(), 1);
+    assert!(patch.render(temp.path()).unwrap().contains("+good"));
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("bad.txt")).unwrap(),
+        "bad\n"
+    );
+}
+
+#[test]
+fn dry_run_without_patch_writes_nothing() {
+    let temp = project();
+    let result = run(temp.path(), true, true, true, None, true);
+
+    assert!(result.fixed);
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("bad.txt")).unwrap(),
+        "bad\n"
+    );
+}
@@ -0,0 +1,624 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Git utilities for change detection.
+//!
+//! Uses git2 (libgit2) for all git operations to avoid subprocess overhead.
+//!
+//! ## File Detection
+//!
+//! When detecting changed files:
+//! - Added files: path from `new_file()`
+//! - Modified files: path from `new_file()` (same as old)
+//! - Renamed files: path from `new_file()` (the new location)
+//! - Deleted files: path from `old_file()` (since `new_file()` is empty)
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use git2::Repository;
+
+use crate::config::BaseStrategy;
+
+/// Extract file path from a diff delta.
+///
+/// For deleted files, `new_file().path()` is `None`, so fall back to `old_file()`.
+/// Order matters: try `new_file` first (works for add, modify, rename, copy),
+/// then fall back to `old_file` (needed for delete).
+fn extract_path<'a>(delta: &'a git2::DiffDelta<'a>) -> Option<&'a Path> {
+    delta.new_file().path().or_else(|| delta.old_file().path())
+}
+
+/// A commit with its hash and message.
+#[derive(Debug, Clone)]
+pub struct Commit {
+    /// Short commit hash (7 characters).
+    pub hash: String,
+    /// Full commit message (subject line only).
+    pub message: String,
+}
+
+/// Collect commits from a revwalk iterator into a Vec.
+fn collect_commits(repo: &Repository, revwalk: git2::Revwalk) -> anyhow::Result<Vec<Commit>> {
+    let mut commits = Vec::new();
+    for oid in revwalk {
+        let oid = oid?;
+        let commit = repo.find_commit(oid)?;
+        commits.push(Commit {
+            hash: oid.to_string()[..7].to_string(),
+            message: commit.summary().unwrap_or("").to_string(),
+        });
+    }
+    Ok(commits)
+}
+
+/// Check if a path is in a git repository.
+pub fn is_git_repo(root: &Path) -> bool {
+    Repository::discover(root).is_ok()
+}
+
+/// Detect base branch for CI mode (main or master).
+pub fn detect_base_branch(root: &Path) -> Option<String> {
+    let repo = Repository::discover(root).ok()?;
+
+    // Check if main branch exists locally
+    if repo.find_branch("main", git2::BranchType::Local).is_ok() {
+        return Some("main".to_string());
+    }
+
+    // Fall back to master locally
+    if repo.find_branch("master", git2::BranchType::Local).is_ok() {
+        return Some("master".to_string());
+    }
+
+    // Check for remote branches if local don't exist
+    for name in ["origin/main", "origin/master"] {
+        if repo.revparse_single(name).is_ok() {
+            return Some(name.to_string());
+        }
+    }
+
+    None
+}
+
+/// Get commits since a base ref.
+///
+/// Returns commits from newest to oldest.
+pub fn get_commits_since(root: &Path, base: &str) -> anyhow::Result<Vec<Commit>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+
+    // Resolve base and HEAD
+    let base_oid = repo
+        .revparse_single(base)
+        .with_context(|| format!("Failed to resolve base ref: {}", base))?
+        .id();
+    let head_oid = repo
+        .head()
+        .context("Failed to get HEAD")?
+        .target()
+        .ok_or_else(|| anyhow::anyhow!("HEAD has no target"))?;
+
+    // Walk commits from HEAD, stopping at base
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push(head_oid)?;
+    revwalk.hide(base_oid)?;
+
+    collect_commits(&repo, revwalk)
+}
+
+/// Get first-parent commits from `from` to `to`, oldest first, including both.
+///
+/// Following first parents keeps merged branches' intermediate commits out,
+/// so every commit listed is one the mainline actually had.
+pub fn get_first_parent_commits(root: &Path, from: &str, to: &str) -> anyhow::Result<Vec<Commit>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+    let resolve = |rev: &str| -> anyhow::Result<git2::Oid> {
+        Ok(repo
+            .revparse_single(rev)
+            .with_context(|| format!("Failed to resolve ref: {}", rev))?
+            .peel_to_commit()?
+            .id())
+    };
+    let from_oid = resolve(from)?;
+    let to_oid = resolve(to)?;
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push(to_oid)?;
+    revwalk.hide(from_oid)?;
+    revwalk.simplify_first_parent()?;
+
+    let mut commits = collect_commits(&repo, revwalk)?;
+    let from_commit = repo.find_commit(from_oid)?;
+    commits.push(Commit {
+        hash: from_oid.to_string()[..7].to_string(),
+        message: from_commit.summary().unwrap_or("").to_string(),
+    });
+    commits.reverse();
+    Ok(commits)
+}
+
+/// Get all commits on current branch (for CI mode).
+pub fn get_all_branch_commits(root: &Path) -> anyhow::Result<Vec<Commit>> {
+    if let Some(base) = detect_base_branch(root) {
+        get_commits_since(root, &base)
+    } else {
+        // No base branch found, get all commits
+        let repo = Repository::discover(root).context("Failed to open repository")?;
+        let head_oid = repo
+            .head()
+            .context("Failed to get HEAD")?
+            .target()
+            .ok_or_else(|| anyhow::anyhow!("HEAD has no target"))?;
+
+        let mut revwalk = repo.revwalk()?;
+        revwalk.push(head_oid)?;
+
+        collect_commits(&repo, revwalk)
+    }
+}
+
+/// Resolve the commit to compare changes against for base ref `base`.
+///
+/// With `merge-base`, commits the ref gained after the branch forked don't
+/// count as changes. With `fork-point`, neither do commits the ref dropped
+/// when it was rebased: the newest reflog entry of the ref that HEAD
+/// contains is used, falling back to the merge base without a reflog (as
+/// in fresh CI clones).
+pub fn resolve_base(root: &Path, base: &str, strategy: BaseStrategy) -> anyhow::Result<String> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+    let base_commit = repo
+        .revparse_single(base)
+        .with_context(|| format!("Failed to resolve base ref: {}", base))?
+        .peel_to_commit()
+        .context("Base ref is not a commit")?
+        .id();
+    if strategy == BaseStrategy::Exact {
+        return Ok(base_commit.to_string());
+    }
+
+    let head = repo.head()?.peel_to_commit()?.id();
+    if strategy == BaseStrategy::ForkPoint
+        && let Some(fork_point) = fork_point(&repo, base, head)
+    {
+        return Ok(fork_point.to_string());
+    }
+
+    let merge_base = repo
+        .merge_base(head, base_commit)
+        .with_context(|| format!("No common ancestor with {}", base))?;
+    Ok(merge_base.to_string())
+}
+
+/// Newest commit in `base`'s reflog that `head` contains.
+fn fork_point(repo: &Repository, base: &str, head: git2::Oid) -> Option<git2::Oid> {
+    let (_, reference) = repo.revparse_ext(base).ok()?;
+    let reflog = repo.reflog(reference?.name()?).ok()?;
+    reflog
+        .iter()
+        .map(|entry| entry.id_new())
+        .find(|&id| id == head || repo.graph_descendant_of(head, id).unwrap_or(false))
+}
+
+/// Get list of changed files compared to a git base ref.
+///
+/// Combines committed, staged, and unstaged changes using git2 diff operations.
+pub fn get_changed_files(root: &Path, base: &str) -> anyhow::Result<Vec<PathBuf>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+
+    // Resolve base to a tree
+    let base_tree = repo
+        .revparse_single(base)
+        .with_context(|| format!("Failed to resolve base ref: {}", base))?
+        .peel_to_tree()
+        .context("Failed to get tree for base ref")?;
+
+    // Get HEAD tree
+    let head_tree = repo.head()?.peel_to_tree()?;
+
+    // Get index for staged changes
+    let index = repo.index()?;
+
+    let mut files = std::collections::HashSet::new();
+
+    // Compare HEAD to base (committed changes on branch)
+    let head_diff = repo.diff_tree_to_tree(Some(&base_tree), Some(&head_tree), None)?;
+    for delta in head_diff.deltas() {
+        if let Some(path) = extract_path(&delta) {
+            files.insert(root.join(path));
+        }
+    }
+
+    // Compare index to base (staged changes)
+    let index_diff = repo.diff_tree_to_index(Some(&base_tree), Some(&index), None)?;
+    for delta in index_diff.deltas() {
+        if let Some(path) = extract_path(&delta) {
+            files.insert(root.join(path));
+        }
+    }
+
+    // Compare workdir to index (unstaged changes)
+    let workdir_diff = repo.diff_index_to_workdir(Some(&index), None)?;
+    for delta in workdir_diff.deltas() {
+        if let Some(path) = extract_path(&delta) {
+            files.insert(root.join(path));
+        }
+    }
+
+    Ok(files.into_iter().collect())
+}
+
+/// Get paths changed by a single commit.
+///
+/// Compares the commit's tree to its first parent (or the empty tree for a
+/// root commit). Only trees are read, so this works in partial clones.
+/// Paths are joined to the repository's working directory.
+pub fn get_commit_paths(root: &Path, hash: &str) -> anyhow::Result<Vec<PathBuf>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+    let commit = repo
+        .revparse_single(hash)
+        .with_context(|| format!("Failed to resolve commit: {}", hash))?
+        .peel_to_commit()?;
+    let tree = commit.tree()?;
+    let parent_tree = match commit.parent(0) {
+        Ok(parent) => Some(parent.tree()?),
+        Err(_) => None,
+    };
+
+    let workdir = repo.workdir().unwrap_or(root);
+
+    let diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)?;
+    Ok(diff
+        .deltas()
+        .filter_map(|delta| extract_path(&delta).map(|path| workdir.join(path)))
+        .collect())
+}
+
+/// Record when each file was first committed, walking commits after `since`.
+///
+/// Walks history oldest first, so a path keeps the commit time (unix seconds)
+/// of the commit that added it; a renamed file keeps the time of the path it
+/// was renamed from. Paths are relative to `root`, and files outside it are
+/// skipped. Returns the HEAD commit walked to (None for an unborn branch).
+pub fn record_file_additions(
+    root: &Path,
+    since: Option<&str>,
+    added: &mut BTreeMap<String, i64>,
+) -> anyhow::Result<Option<String>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+    let head_oid = match repo.head() {
+        Ok(head) => head
+            .target()
+            .ok_or_else(|| anyhow::anyhow!("HEAD has no target"))?,
+        Err(e) if e.code() == git2::ErrorCode::UnbornBranch => return Ok(None),
+        Err(e) => return Err(e).context("Failed to get HEAD"),
+    };
+
+    let prefix = root_prefix(&repo, root);
+    let relative = |path: &Path| -> Option<String> {
+        let path = path.strip_prefix(&prefix).ok()?;
+        Some(path.to_string_lossy().replace('\\', "/"))
+    };
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE)?;
+    revwalk.push(head_oid)?;
+    if let Some(since) = since {
+        let since_oid = git2::Oid::from_str(since)
+            .with_context(|| format!("Invalid commit hash: {}", since))?;
+        revwalk
+            .hide(since_oid)
+            .with_context(|| format!("Failed to find commit: {}", since))?;
+    }
+
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        let time = commit.time().seconds();
+        let tree = commit.tree()?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
+        let mut diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)?;
+        diff.find_similar(None)?;
+
+        for delta in diff.deltas() {
+            let Some(path) = delta.new_file().path().and_then(&relative) else {
+                continue;
+            };
+            match delta.status() {
+                git2::Delta::Added | git2::Delta::Copied => {
+                    added.entry(path).or_insert(time);
+                }
+                git2::Delta::Renamed => {
+                    let since = delta
+                        .old_file()
+                        .path()
+                        .and_then(&relative)
+                        .and_then(|old| added.get(&old).copied())
+                        .unwrap_or(time);
+                    added.entry(path).or_insert(since);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(Some(head_oid.to_string()))
+}
+
+/// Count the commits touching each file since `since` (unix seconds).
+///
+/// Walks back from HEAD, newest first, diffing each commit against its
+/// first parent. Paths are relative to `root`, and files outside it are
+/// skipped. Empty for an unborn branch.
+pub fn file_churn(root: &Path, since: i64) -> anyhow::Result<BTreeMap<String, usize>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+    let mut churn = BTreeMap::new();
+    let head_oid = match repo.head() {
+        Ok(head) => head
+            .target()
+            .ok_or_else(|| anyhow::anyhow!("HEAD has no target"))?,
+        Err(e) if e.code() == git2::ErrorCode::UnbornBranch => return Ok(churn),
+        Err(e) => return Err(e).context("Failed to get HEAD"),
+    };
+    let prefix = root_prefix(&repo, root);
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.set_sorting(git2::Sort::TIME)?;
+    revwalk.push(head_oid)?;
+
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        if commit.time().seconds() < since {
+            break;
+        }
+        let tree = commit.tree()?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
+        let diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)?;
+        for delta in diff.deltas() {
+            let Some(path) = extract_path(&delta).and_then(|p| p.strip_prefix(&prefix).ok()) else {
+                continue;
+            };
+            *churn
+                .entry(path.to_string_lossy().replace('\\', "/"))
+                .or_insert(0) += 1;
+        }
+    }
+
+    Ok(churn)
+}
+
+/// Root's location within the repository's working directory.
+fn root_prefix(repo: &Repository, root: &Path) -> PathBuf {
+    let workdir = repo.workdir().unwrap_or(root);
+    root.canonicalize()
+        .ok()
+        .zip(workdir.canonicalize().ok())
+        .and_then(|(root, workdir)| root.strip_prefix(workdir).ok().map(Path::to_path_buf))
+        .unwrap_or_default()
+}
+
+/// Get list of staged files (for --staged flag).
+///
+/// Uses git2 to compare the index against HEAD to find staged changes.
+pub fn get_staged_files(root: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+
+    // Get HEAD tree (handle case of empty repo with no commits)
+    let head_tree = match repo.head() {
+        Ok(head) => Some(head.peel_to_tree().context("Failed to get HEAD tree")?),
+        Err(e) if e.code() == git2::ErrorCode::UnbornBranch => None,
+        Err(e) => return Err(e).context("Failed to get HEAD"),
+    };
+
+    let index = repo.index().context("Failed to get repository index")?;
+
+    // Compare HEAD tree to index to find staged changes
+    let diff = repo
+        .diff_tree_to_index(head_tree.as_ref(), Some(&index), None)
+        .context("Failed to compute diff")?;
+
+    let mut files = Vec::new();
+    for delta in diff.deltas() {
+        if let Some(path) = extract_path(&delta) {
+            files.push(root.join(path));
+        }
+    }
+
+    Ok(files)
+}
+
+/// Read the staged (index) content of `files` that differs from the
+/// working tree, keyed by path.
+///
+/// Files whose working tree matches the index read the same from disk and
+/// are left out, as are files with no index entry (staged deletions).
+pub fn get_staged_blobs(
+    root: &Path,
+    files: &[PathBuf],
+) -> anyhow::Result<HashMap<PathBuf, Vec<u8>>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+    let index = repo.index().context("Failed to get repository index")?;
+    let prefix = root_prefix(&repo, root);
+
+    let mut blobs = HashMap::new();
+    for file in files {
+        let relative = file.strip_prefix(root).unwrap_or(file);
+        let Some(entry) = index.get_path(&prefix.join(relative), 0) else {
+            continue;
+        };
+        let blob = repo
+            .find_blob(entry.id)
+            .with_context(|| format!("Failed to read staged {}", relative.display()))?;
+        if std::fs::read(file).is_ok_and(|content| content == blob.content()) {
+            continue;
+        }
+        blobs.insert(file.clone(), blob.content().to_vec());
+    }
+
+    Ok(blobs)
+}
+
+/// Get files with uncommitted changes in the working tree.
+///
+/// Includes staged and unstaged modifications, deletions, and untracked
+/// files; ignored files are excluded.
+pub fn get_dirty_files(root: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+    let workdir = repo
+        .workdir()
+        .context("Bare repository has no working tree")?;
+
+    let mut options = git2::StatusOptions::new();
+    options.include_untracked(true).recurse_untracked_dirs(true);
+    let statuses = repo
+        .statuses(Some(&mut options))
+        .context("Failed to get working tree status")?;
+
+    let mut files: Vec<PathBuf> = statuses
+        .iter()
+        .filter(|entry| !entry.status().is_ignored())
+        .filter_map(|entry| entry.path().map(|path| workdir.join(path)))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Save content to git notes for HEAD commit.
+///
+/// Uses `refs/notes/quench` namespace to avoid conflicts with other tools.
+pub fn save_to_git_notes(root: &Path, content: &str) -> anyhow::Result<()> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+
+    let head_commit = repo
+        .head()
+        .context("Failed to get HEAD")?
+        .peel_to_commit()
+        .context("HEAD is not a commit")?;
+
+    let sig = repo
+        .signature()
+        .or_else(|_| git2::Signature::now("quench", "quench@local"))?;
+
+    // Get or create notes ref
+    let notes_ref = "refs/notes/quench";
+
+    // Add note (this handles creating the ref if needed)
+    repo.note(
+        &sig,            // author
+        &sig,            // committer
+        Some(notes_ref), // ref name
+        head_commit.id(),
+        content,
+        true, // overwrite existing notes
+    )?;
+
+    Ok(())
+}
+
+/// Read git note for a specific commit.
+pub fn read_git_note(root: &Path, commit_ref: &str) -> anyhow::Result<Option<String>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+
+    let commit = repo
+        .revparse_single(commit_ref)
+        .context("Failed to resolve commit ref")?
+        .peel_to_commit()
+        .context("Ref is not a commit")?;
+
+    let notes_ref = "refs/notes/quench";
+
+    match repo.find_note(Some(notes_ref), commit.id()) {
+        Ok(note) => Ok(note.message().map(|s| s.to_string())),
+        Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+        Err(e) => Err(e).context("Failed to read git note"),
+    }
+}
+
+/// Read a file's contents as committed at `rev`, without checking it out.
+///
+/// Returns None if the file doesn't exist in that commit.
+pub fn read_file_at_ref(root: &Path, rev: &str, path: &str) -> anyhow::Result<Option<String>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+
+    let tree = repo
+        .revparse_single(rev)
+        .with_context(|| format!("Failed to resolve ref: {}", rev))?
+        .peel_to_commit()
+        .context("Ref is not a commit")?
+        .tree()?;
+
+    let entry = match tree.get_path(Path::new(path)) {
+        Ok(entry) => entry,
+        Err(e) if e.code() == git2::ErrorCode::NotFound => return Ok(None),
+        Err(e) => return Err(e).context("Failed to read tree"),
+    };
+    let blob = entry
+        .to_object(&repo)?
+        .peel_to_blob()
+        .with_context(|| format!("{} is not a file at {}", path, rev))?;
+    Ok(Some(String::from_utf8_lossy(blob.content()).into_owned()))
+}
+
+/// Commit time (unix seconds) of the oldest commit with a quench note.
+///
+/// Approximates when the git notes baseline was first recorded.
+pub fn earliest_note_time(root: &Path) -> anyhow::Result<Option<i64>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+
+    let notes = match repo.notes(Some("refs/notes/quench")) {
+        Ok(notes) => notes,
+        Err(e) if e.code() == git2::ErrorCode::NotFound => return Ok(None),
+        Err(e) => return Err(e).context("Failed to read git notes"),
+    };
+    let mut earliest = None;
+    for note in notes {
+        let (_, commit_oid) = note?;
+        let time = repo.find_commit(commit_oid)?.time().seconds();
+        earliest = Some(earliest.map_or(time, |t: i64| t.min(time)));
+    }
+    Ok(earliest)
+}
+
+/// Find the merge-base commit for ratchet comparison.
+///
+/// If base_ref is provided, uses that ref.
+/// Otherwise, finds merge-base with main/master.
+/// Falls back to HEAD~1 if no merge-base found.
+pub fn find_ratchet_base(root: &Path, base_ref: Option<&str>) -> anyhow::Result<String> {
+    let repo = Repository::discover(root)?;
+
+    if let Some(base_ref) = base_ref {
+        // Explicit --base REF provided
+        let commit = repo.revparse_single(base_ref)?.peel_to_commit()?;
+        return Ok(commit.id().to_string());
+    }
+
+    // Try to find merge-base with main branch
+    let head = repo.head()?.peel_to_commit()?;
+
+    for main_branch in &["origin/main", "origin/master", "main", "master"] {
+        if let Ok(main_ref) = repo.revparse_single(main_branch)
+            && let Ok(main_commit) = main_ref.peel_to_commit()
+            && let Ok(base) = repo.merge_base(head.id(), main_commit.id())
+        {
+            return Ok(base.to_string());
+        }
+    }
+
+    // Fallback: use HEAD~1 (parent commit)
+    if let Ok(parent) = head.parent(0) {
+        return Ok(parent.id().to_string());
+    }
+
+    // Last resort: HEAD itself (initial commit)
+    Ok(head.id().to_string())
+}
+
+#[cfg(test)]
+#[path = "git_tests.rs"]
+mod tests;
@@ -78,12 +78,68 @@ fn git_mv(temp: &TempDir, old: &str, new: &str) {
         .expect("Failed to rename file");
 }
 
+/// Run a git command, returning its trimmed stdout.
+fn git(temp: &TempDir, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to run git");
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
 /// Create a file and stage it.
 fn create_and_stage(temp: &TempDir, filename: &str, content: &str) {
     std::fs::write(temp.path().join(filename), content).unwrap();
     git_add(temp, filename);
 }
 
+// =============================================================================
+// GET_DIRTY_FILES TESTS
+// =============================================================================
+
+#[test]
+fn get_dirty_files_clean_tree() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+
+    let files = get_dirty_files(temp.path()).unwrap();
+    assert!(files.is_empty(), "Expected clean working tree");
+}
+
+#[test]
+fn get_dirty_files_includes_staged_unstaged_and_untracked() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+
+    create_and_stage(&temp, "staged.txt", "content");
+    std::fs::write(temp.path().join("README.md"), "# Changed\n").unwrap();
+    std::fs::write(temp.path().join("untracked.txt"), "content").unwrap();
+
+    let files = get_dirty_files(temp.path()).unwrap();
+    let names: Vec<_> = files
+        .iter()
+        .map(|f| f.file_name().unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(names, ["README.md", "staged.txt", "untracked.txt"]);
+}
+
+#[test]
+fn get_dirty_files_excludes_ignored() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    std::fs::write(temp.path().join(".gitignore"), "*.log\n").unwrap();
+    git_add(&temp, ".gitignore");
+    git_commit(&temp, "chore: ignore logs");
+
+    std::fs::write(temp.path().join("debug.log"), "noise").unwrap();
+
+    let files = get_dirty_files(temp.path()).unwrap();
+    assert!(files.is_empty(), "Ignored files are not dirty");
+}
+
 // =============================================================================
 // GET_STAGED_FILES TESTS
 // =============================================================================
@@ -167,6 +223,50 @@ fn get_staged_files_new_repo_no_commits() {
     assert!(files[0].ends_with("first.txt"));
 }
 
+#[test]
+fn get_staged_blobs_reads_partially_staged_files_from_index() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+
+    create_and_stage(&temp, "partial.txt", "staged\n");
+    std::fs::write(temp.path().join("partial.txt"), "staged\nunstaged\n").unwrap();
+    create_and_stage(&temp, "full.txt", "staged\n");
+
+    let files = get_staged_files(temp.path()).unwrap();
+    let blobs = get_staged_blobs(temp.path(), &files).unwrap();
+    assert_eq!(blobs[&temp.path().join("partial.txt")], b"staged\n");
+    assert!(!blobs.contains_key(&temp.path().join("full.txt")));
+}
+
+// =============================================================================
+// GET_COMMIT_PATHS TESTS
+// =============================================================================
+
+#[test]
+fn get_commit_paths_lists_files_changed_by_commit() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+    create_and_stage(&temp, "new.txt", "content");
+    git_commit(&temp, "feat: add new file");
+
+    let paths = get_commit_paths(temp.path(), "HEAD").unwrap();
+    assert_eq!(paths.len(), 1);
+    assert!(paths[0].ends_with("new.txt"));
+}
+
+#[test]
+fn get_commit_paths_handles_root_commit() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+
+    let paths = get_commit_paths(temp.path(), "HEAD").unwrap();
+    assert_eq!(paths.len(), 1);
+    assert!(paths[0].ends_with("README.md"));
+}
+
 // =============================================================================
 // GET_CHANGED_FILES TESTS
 // =============================================================================
@@ -265,6 +365,59 @@ fn get_changed_files_invalid_base_ref() {
     assert!(result.is_err());
 }
 
+// =============================================================================
+// GET_FIRST_PARENT_COMMITS TESTS
+// =============================================================================
+
+#[test]
+fn get_first_parent_commits_lists_oldest_first_including_ends() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+    Command::new("git")
+        .args(["tag", "v1"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    create_and_stage(&temp, "a.txt", "a");
+    git_commit(&temp, "feat: add a");
+    create_and_stage(&temp, "b.txt", "b");
+    git_commit(&temp, "feat: add b");
+
+    let commits = get_first_parent_commits(temp.path(), "v1", "HEAD").unwrap();
+
+    let messages: Vec<&str> = commits.iter().map(|c| c.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        ["chore: initial commit", "feat: add a", "feat: add b"]
+    );
+}
+
+#[test]
+fn get_first_parent_commits_skips_merged_branch_commits() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+    git_checkout_b(&temp, "feature");
+    create_and_stage(&temp, "f.txt", "f");
+    git_commit(&temp, "feat: on branch");
+    Command::new("git")
+        .args(["checkout", "main"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    Command::new("git")
+        .args(["merge", "--no-ff", "-m", "merge feature", "feature"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+
+    let commits = get_first_parent_commits(temp.path(), "HEAD~1", "HEAD").unwrap();
+
+    let messages: Vec<&str> = commits.iter().map(|c| c.message.as_str()).collect();
+    assert_eq!(messages, ["chore: initial commit", "merge feature"]);
+}
+
 // =============================================================================
 // IS_GIT_REPO TESTS
 // =============================================================================
@@ -425,6 +578,104 @@ fn get_changed_files_empty_repo() {
     assert!(result.is_err(), "should error when base ref doesn't exist");
 }
 
+// =============================================================================
+// RESOLVE_BASE TESTS
+// =============================================================================
+
+/// A `feature` branch with one commit, and a `main` that gained a commit
+/// after it forked. Leaves `feature` checked out.
+fn branch_behind_main(temp: &TempDir) {
+    init_git_repo(temp);
+    create_initial_commit(temp);
+    git_checkout_b(temp, "feature");
+    create_and_stage(temp, "feature.txt", "content");
+    git_commit(temp, "feat: feature work");
+    git(temp, &["checkout", "main"]);
+    create_and_stage(temp, "main.txt", "content");
+    git_commit(temp, "feat: main work");
+    git(temp, &["checkout", "feature"]);
+}
+
+fn changed_names(temp: &TempDir, base: &str) -> Vec<String> {
+    let mut names: Vec<String> = get_changed_files(temp.path(), base)
+        .unwrap()
+        .iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn resolve_base_merge_base_ignores_commits_base_gained() {
+    let temp = TempDir::new().unwrap();
+    branch_behind_main(&temp);
+
+    let base = resolve_base(temp.path(), "main", BaseStrategy::MergeBase).unwrap();
+
+    assert_eq!(base, git(&temp, &["merge-base", "main", "HEAD"]));
+    assert_eq!(changed_names(&temp, &base), ["feature.txt"]);
+}
+
+#[test]
+fn resolve_base_exact_uses_ref_tip() {
+    let temp = TempDir::new().unwrap();
+    branch_behind_main(&temp);
+
+    let base = resolve_base(temp.path(), "main", BaseStrategy::Exact).unwrap();
+
+    assert_eq!(base, git(&temp, &["rev-parse", "main"]));
+    assert_eq!(changed_names(&temp, &base), ["feature.txt", "main.txt"]);
+}
+
+#[test]
+fn resolve_base_fork_point_ignores_rebased_upstream() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+    create_and_stage(&temp, "upstream.txt", "v1");
+    git_commit(&temp, "feat: upstream work");
+    let forked_from = git(&temp, &["rev-parse", "HEAD"]);
+    git_checkout_b(&temp, "feature");
+    create_and_stage(&temp, "feature.txt", "content");
+    git_commit(&temp, "feat: feature work");
+    // Rewrite main's last commit, as a rebase or amend would
+    git(&temp, &["checkout", "main"]);
+    create_and_stage(&temp, "upstream.txt", "v2");
+    git(&temp, &["commit", "--amend", "-m", "feat: upstream work"]);
+    git(&temp, &["checkout", "feature"]);
+
+    let fork_point = resolve_base(temp.path(), "main", BaseStrategy::ForkPoint).unwrap();
+    let merge_base = resolve_base(temp.path(), "main", BaseStrategy::MergeBase).unwrap();
+
+    assert_eq!(fork_point, forked_from);
+    assert_eq!(changed_names(&temp, &fork_point), ["feature.txt"]);
+    assert_eq!(
+        changed_names(&temp, &merge_base),
+        ["feature.txt", "upstream.txt"]
+    );
+}
+
+#[test]
+fn resolve_base_fork_point_without_reflog_uses_merge_base() {
+    let temp = TempDir::new().unwrap();
+    branch_behind_main(&temp);
+    git(&temp, &["reflog", "expire", "--expire=all", "--all"]);
+
+    let fork_point = resolve_base(temp.path(), "main", BaseStrategy::ForkPoint).unwrap();
+
+    assert_eq!(fork_point, git(&temp, &["merge-base", "main", "HEAD"]));
+}
+
+#[test]
+fn resolve_base_fails_for_unknown_ref() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+
+    assert!(resolve_base(temp.path(), "nonexistent", BaseStrategy::MergeBase).is_err());
+}
+
 // =============================================================================
 // FIND_RATCHET_BASE TESTS
 // =============================================================================
@@ -496,3 +747,149 @@ fn find_ratchet_base_errors_for_unborn_branch() {
     let result = find_ratchet_base(temp.path(), None);
     assert!(result.is_err());
 }
+
+// =============================================================================
+// RECORD_FILE_ADDITIONS TESTS
+// =============================================================================
+
+/// Commit times for file addition tests (unix seconds).
+const T1: i64 = 1_700_000_000;
+const T2: i64 = 1_700_086_400;
+
+/// Commit with a fixed committer time (unix seconds).
+fn git_commit_at(temp: &TempDir, message: &str, time: i64) {
+    Command::new("git")
+        .args(["commit", "-m", message])
+        .env("GIT_COMMITTER_DATE", format!("{} +0000", time))
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to git commit");
+}
+
+#[test]
+fn record_file_additions_keeps_first_commit_time() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_and_stage(&temp, "old.rs", "fn a() {}");
+    git_commit_at(&temp, "feat: old", T1);
+    create_and_stage(&temp, "old.rs", "fn a() {}\nfn b() {}");
+    create_and_stage(&temp, "new.rs", "fn c() {}");
+    git_commit_at(&temp, "feat: new", T2);
+
+    let mut added = BTreeMap::new();
+    let head = record_file_additions(temp.path(), None, &mut added).unwrap();
+
+    assert_eq!(head.map(|h| h.len()), Some(40));
+    assert_eq!(
+        added,
+        BTreeMap::from([("new.rs".to_string(), T2), ("old.rs".to_string(), T1)])
+    );
+}
+
+#[test]
+fn record_file_additions_renamed_file_keeps_original_time() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_and_stage(&temp, "old.rs", "fn a() {}\nfn b() {}\nfn c() {}\n");
+    git_commit_at(&temp, "feat: old", T1);
+    git_mv(&temp, "old.rs", "moved.rs");
+    git_commit_at(&temp, "refactor: move", T2);
+
+    let mut added = BTreeMap::new();
+    record_file_additions(temp.path(), None, &mut added).unwrap();
+
+    assert_eq!(added.get("moved.rs"), Some(&T1));
+}
+
+#[test]
+fn record_file_additions_walks_only_commits_since() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_and_stage(&temp, "a.rs", "fn a() {}");
+    git_commit_at(&temp, "feat: a", T1);
+    let mut added = BTreeMap::new();
+    let first = record_file_additions(temp.path(), None, &mut added).unwrap();
+    create_and_stage(&temp, "b.rs", "fn b() {}");
+    git_commit_at(&temp, "feat: b", T2);
+
+    let mut later = BTreeMap::new();
+    record_file_additions(temp.path(), first.as_deref(), &mut later).unwrap();
+
+    assert_eq!(later, BTreeMap::from([("b.rs".to_string(), T2)]));
+}
+
+#[test]
+fn record_file_additions_paths_are_relative_to_root() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    std::fs::create_dir_all(temp.path().join("app/src")).unwrap();
+    create_and_stage(&temp, "app/src/lib.rs", "fn a() {}");
+    create_and_stage(&temp, "other.rs", "fn b() {}");
+    git_commit_at(&temp, "feat: app", T1);
+
+    let mut added = BTreeMap::new();
+    record_file_additions(&temp.path().join("app"), None, &mut added).unwrap();
+
+    assert_eq!(added, BTreeMap::from([("src/lib.rs".to_string(), T1)]));
+}
+
+#[test]
+fn record_file_additions_unborn_branch_has_no_head() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+
+    let mut added = BTreeMap::new();
+    let head = record_file_additions(temp.path(), None, &mut added).unwrap();
+
+    assert_eq!(head, None);
+    assert!(added.is_empty());
+}
+
+// =============================================================================
+// FILE CHURN TESTS
+// =============================================================================
+
+#[test]
+fn file_churn_counts_commits_per_file_since() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_and_stage(&temp, "a.rs", "fn a() {}");
+    create_and_stage(&temp, "b.rs", "fn b() {}");
+    git_commit_at(&temp, "feat: a and b", T1);
+    create_and_stage(&temp, "a.rs", "fn a() {}\nfn a2() {}");
+    git_commit_at(&temp, "feat: a2", T2);
+    create_and_stage(&temp, "a.rs", "fn a() {}\nfn a3() {}");
+    git_commit_at(&temp, "feat: a3", T2 + 60);
+
+    assert_eq!(
+        file_churn(temp.path(), T1).unwrap(),
+        BTreeMap::from([("a.rs".to_string(), 3), ("b.rs".to_string(), 1)])
+    );
+    assert_eq!(
+        file_churn(temp.path(), T2).unwrap(),
+        BTreeMap::from([("a.rs".to_string(), 2)])
+    );
+}
+
+#[test]
+fn file_churn_paths_are_relative_to_root() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    std::fs::create_dir_all(temp.path().join("app/src")).unwrap();
+    create_and_stage(&temp, "app/src/lib.rs", "fn a() {}");
+    create_and_stage(&temp, "other.rs", "fn b() {}");
+    git_commit_at(&temp, "feat: app", T1);
+
+    assert_eq!(
+        file_churn(&temp.path().join("app"), T1).unwrap(),
+        BTreeMap::from([("src/lib.rs".to_string(), 1)])
+    );
+}
+
+#[test]
+fn file_churn_unborn_branch_is_empty() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+
+    assert!(file_churn(temp.path(), 0).unwrap().is_empty());
+}
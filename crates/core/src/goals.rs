@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Goal tracking toward target values.
+//!
+//! Where the ratchet only stops metrics from getting worse, goals say where
+//! they should end up. Progress is measured from the oldest value in the
+//! baseline's history, and the ETA extrapolates the average change per day
+//! across that history.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::baseline::{BaselineMetrics, MetricSample};
+use crate::config::GoalsConfig;
+
+/// Progress toward one goal.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GoalProgress {
+    /// Metric name (`coverage`, `escapes.<pattern>`).
+    pub metric: String,
+    /// Current value.
+    pub current: f64,
+    /// Target value.
+    pub target: f64,
+    /// Percent of the way from the oldest recorded value to the target, 0-100.
+    pub progress: f64,
+    /// Average change per day across the history.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub velocity: Option<f64>,
+    /// Days until the target at the current velocity. None when reached
+    /// or not moving toward the target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eta_days: Option<u64>,
+    /// Whether the target is met.
+    pub reached: bool,
+}
+
+impl GoalProgress {
+    /// Build progress from the current value and the metric's history.
+    ///
+    /// `higher_is_better` decides which side of the target counts as reached.
+    pub fn new(
+        metric: impl Into<String>,
+        current: f64,
+        target: f64,
+        higher_is_better: bool,
+        history: &[(DateTime<Utc>, f64)],
+    ) -> Self {
+        let reached = if higher_is_better {
+            current >= target
+        } else {
+            current <= target
+        };
+        let start = history.first().map_or(current, |&(_, value)| value);
+        let progress = if reached {
+            100.0
+        } else if current == start || start == target {
+            0.0
+        } else {
+            ((current - start) / (target - start) * 100.0).clamp(0.0, 100.0)
+        };
+        let velocity = velocity(history);
+        let eta_days = velocity.filter(|_| !reached).and_then(|v| {
+            let days = (target - current) / v;
+            (days.is_finite() && days > 0.0).then(|| days.ceil() as u64)
+        });
+        Self {
+            metric: metric.into(),
+            current,
+            target,
+            progress: (progress * 10.0).round() / 10.0,
+            velocity,
+            eta_days,
+            reached,
+        }
+    }
+
+    /// Progress bar `width` cells wide, e.g. `[######----]`.
+    pub fn bar(&self, width: usize) -> String {
+        let filled = ((self.progress / 100.0) * width as f64).round() as usize;
+        let filled = filled.min(width);
+        format!("[{}{}]", "#".repeat(filled), "-".repeat(width - filled))
+    }
+
+    /// `reached`, `~12 days`, or `no progress`.
+    pub fn eta(&self) -> String {
+        match (self.reached, self.eta_days) {
+            (true, _) => "reached".to_string(),
+            (false, Some(1)) => "~1 day".to_string(),
+            (false, Some(days)) => format!("~{days} days"),
+            (false, None) => "no progress".to_string(),
+        }
+    }
+}
+
+/// Progress toward every configured goal the baseline has a value for,
+/// coverage first, then escapes by pattern.
+pub fn track(metrics: &BaselineMetrics, config: &GoalsConfig) -> Vec<GoalProgress> {
+    let history = metrics.history.as_deref().unwrap_or_default();
+    let mut goals = Vec::new();
+
+    if let (Some(goal), Some(coverage)) = (&config.coverage, &metrics.coverage) {
+        let series = series(history, |s| s.coverage);
+        goals.push(GoalProgress::new(
+            "coverage",
+            coverage.total,
+            goal.target,
+            true,
+            &series,
+        ));
+    }
+
+    if let Some(escapes) = &metrics.escapes {
+        for (pattern, goal) in &config.escapes {
+            let Some(&count) = escapes.source.get(pattern) else {
+                continue;
+            };
+            let series = series(history, |s| s.escapes.get(pattern).map(|&n| n as f64));
+            goals.push(GoalProgress::new(
+                format!("escapes.{pattern}"),
+                count as f64,
+                goal.target,
+                false,
+                &series,
+            ));
+        }
+    }
+
+    goals
+}
+
+/// Dated values of one metric from the history.
+fn series(
+    history: &[MetricSample],
+    value: impl Fn(&MetricSample) -> Option<f64>,
+) -> Vec<(DateTime<Utc>, f64)> {
+    history
+        .iter()
+        .filter_map(|s| value(s).map(|v| (s.date, v)))
+        .collect()
+}
+
+/// Average change per day from the first to the last sample. None with
+/// fewer than two samples or no time between them.
+fn velocity(history: &[(DateTime<Utc>, f64)]) -> Option<f64> {
+    let (&(first_date, first), &(last_date, last)) = (history.first()?, history.last()?);
+    let days = (last_date - first_date).num_seconds() as f64 / 86_400.0;
+    (days > 0.0).then(|| (last - first) / days)
+}
+
+#[cfg(test)]
+#[path = "goals_tests.rs"]
+mod tests;
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::collections::BTreeMap;
+
+use chrono::TimeZone;
+
+use super::*;
+use crate::baseline::{CoverageMetrics, EscapesMetrics};
+use crate::config::GoalConfig;
+
+fn day(n: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, n, 0, 0, 0).unwrap()
+}
+
+fn sample(n: u32, coverage: f64, unwraps: usize) -> MetricSample {
+    MetricSample {
+        date: day(n),
+        coverage: Some(coverage),
+        escapes: BTreeMap::from([("unwrap".to_string(), unwraps)]),
+        tags: BTreeMap::new(),
+    }
+}
+
+fn metrics(coverage: f64, unwraps: usize, history: Vec<MetricSample>) -> BaselineMetrics {
+    BaselineMetrics {
+        coverage: Some(CoverageMetrics {
+            total: coverage,
+            by_package: None,
+        }),
+        escapes: Some(EscapesMetrics {
+            source: BTreeMap::from([("unwrap".to_string(), unwraps)]),
+            test: None,
+            source_lines: None,
+            by_package: None,
+        }),
+        history: Some(history),
+        ..Default::default()
+    }
+}
+
+fn goals(coverage: f64, unwraps: f64) -> GoalsConfig {
+    GoalsConfig {
+        coverage: Some(GoalConfig { target: coverage }),
+        escapes: BTreeMap::from([("unwrap".to_string(), GoalConfig { target: unwraps })]),
+    }
+}
+
+// =============================================================================
+// PROGRESS TESTS
+// =============================================================================
+
+#[test]
+fn no_goals_tracks_nothing() {
+    let metrics = metrics(70.0, 10, vec![]);
+    assert!(track(&metrics, &GoalsConfig::default()).is_empty());
+}
+
+#[test]
+fn progress_measured_from_oldest_sample() {
+    // Coverage 60 -> 70 of a 60 -> 80 climb is halfway
+    let metrics = metrics(70.0, 10, vec![sample(1, 60.0, 20), sample(11, 70.0, 10)]);
+
+    let tracked = track(&metrics, &goals(80.0, 0.0));
+    assert_eq!(tracked[0].metric, "coverage");
+    assert_eq!(tracked[0].progress, 50.0);
+    assert_eq!(tracked[1].metric, "escapes.unwrap");
+    assert_eq!(tracked[1].progress, 50.0);
+}
+
+#[test]
+fn goal_without_history_has_no_progress() {
+    let metrics = metrics(70.0, 10, vec![]);
+
+    let tracked = track(&metrics, &goals(80.0, 0.0));
+    assert_eq!(tracked[0].progress, 0.0);
+    assert_eq!(tracked[0].velocity, None);
+    assert_eq!(tracked[0].eta(), "no progress");
+}
+
+#[test]
+fn reached_goal_is_complete() {
+    let metrics = metrics(86.0, 0, vec![sample(1, 70.0, 5), sample(5, 86.0, 0)]);
+
+    let tracked = track(&metrics, &goals(85.0, 0.0));
+    assert!(tracked.iter().all(|g| g.reached));
+    assert!(tracked.iter().all(|g| g.progress == 100.0));
+    assert_eq!(tracked[0].eta_days, None);
+    assert_eq!(tracked[0].eta(), "reached");
+}
+
+#[test]
+fn goal_skipped_without_current_value() {
+    let mut metrics = metrics(70.0, 10, vec![]);
+    metrics.coverage = None;
+
+    let tracked = track(&metrics, &goals(80.0, 0.0));
+    assert_eq!(tracked.len(), 1);
+    assert_eq!(tracked[0].metric, "escapes.unwrap");
+}
+
+// =============================================================================
+// ETA TESTS
+// =============================================================================
+
+#[test]
+fn eta_extrapolates_velocity() {
+    // +1 point per day, 10 points to go
+    let metrics = metrics(70.0, 10, vec![sample(1, 60.0, 20), sample(11, 70.0, 10)]);
+
+    let tracked = track(&metrics, &goals(80.0, 0.0));
+    assert_eq!(tracked[0].velocity, Some(1.0));
+    assert_eq!(tracked[0].eta_days, Some(10));
+    assert_eq!(tracked[0].eta(), "~10 days");
+    // -1 unwrap per day, 10 to go
+    assert_eq!(tracked[1].velocity, Some(-1.0));
+    assert_eq!(tracked[1].eta_days, Some(10));
+}
+
+#[test]
+fn moving_away_from_target_has_no_eta() {
+    let metrics = metrics(60.0, 20, vec![sample(1, 70.0, 10), sample(11, 60.0, 20)]);
+
+    let tracked = track(&metrics, &goals(80.0, 0.0));
+    assert_eq!(tracked[0].progress, 0.0);
+    assert_eq!(tracked[0].eta_days, None);
+    assert_eq!(tracked[1].eta_days, None);
+}
+
+#[test]
+fn samples_on_same_instant_have_no_velocity() {
+    let metrics = metrics(70.0, 10, vec![sample(1, 60.0, 20), sample(1, 70.0, 10)]);
+
+    let tracked = track(&metrics, &goals(80.0, 0.0));
+    assert_eq!(tracked[0].velocity, None);
+}
+
+// =============================================================================
+// DISPLAY TESTS
+// =============================================================================
+
+#[test]
+fn bar_fills_with_progress() {
+    let goal = GoalProgress::new("coverage", 70.0, 80.0, true, &[(day(1), 60.0)]);
+    assert_eq!(goal.bar(10), "[#####-----]");
+
+    let done = GoalProgress::new("coverage", 90.0, 80.0, true, &[]);
+    assert_eq!(done.bar(4), "[####]");
+}
+
+#[test]
+fn eta_singular_day() {
+    let goal = GoalProgress::new(
+        "coverage",
+        79.5,
+        80.0,
+        true,
+        &[(day(1), 78.5), (day(2), 79.5)],
+    );
+    assert_eq!(goal.eta(), "~1 day");
+}
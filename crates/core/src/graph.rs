@@ -0,0 +1,282 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Workspace dependency graph with metric overlays (`quench graph`).
+//!
+//! Nodes are the project's packages; edges are dependencies between them,
+//! read from each package's `Cargo.toml` or `package.json`. Nodes carry the
+//! LOC, coverage, and escape counts of the last `quench check` run.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::check::{CheckOutput, CheckResult};
+
+/// Dependency tables in `Cargo.toml`.
+const CARGO_DEPENDENCY_TABLES: &[&str] =
+    &["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Dependency fields in `package.json`.
+const NPM_DEPENDENCY_FIELDS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+/// A workspace package.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// Display name, as used in per-package metrics.
+    pub name: String,
+    /// Directory relative to the project root.
+    pub path: String,
+    pub metrics: NodeMetrics,
+}
+
+/// Metrics shown on a node (None = not measured).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeMetrics {
+    /// Source lines of code.
+    pub loc: Option<u64>,
+    /// Line coverage percentage.
+    pub coverage: Option<f64>,
+    /// Escape hatches in source files.
+    pub escapes: Option<u64>,
+}
+
+/// Packages and the dependencies between them.
+#[derive(Debug, Default)]
+pub struct Graph {
+    pub nodes: Vec<Node>,
+    /// (dependent, dependency) node indices.
+    pub edges: BTreeSet<(usize, usize)>,
+}
+
+impl Graph {
+    /// Build the graph of `packages` (directories relative to `root`).
+    ///
+    /// `names` maps package directories to display names; directories
+    /// without one use their manifest name, or the directory name.
+    pub fn from_packages(
+        root: &Path,
+        packages: &[String],
+        names: &HashMap<String, String>,
+    ) -> Self {
+        let manifests: Vec<Manifest> = packages
+            .iter()
+            .map(|path| Manifest::read(&root.join(path)).unwrap_or_default())
+            .collect();
+
+        let nodes: Vec<Node> = packages
+            .iter()
+            .zip(&manifests)
+            .map(|(path, manifest)| Node {
+                name: names
+                    .get(path)
+                    .or(manifest.name.as_ref())
+                    .cloned()
+                    .unwrap_or_else(|| dir_name(path).to_string()),
+                path: path.clone(),
+                metrics: NodeMetrics::default(),
+            })
+            .collect();
+
+        // Dependencies refer to packages by manifest name
+        let by_manifest_name: HashMap<&str, usize> = manifests
+            .iter()
+            .enumerate()
+            .filter_map(|(i, m)| Some((m.name.as_deref()?, i)))
+            .collect();
+        let edges = manifests
+            .iter()
+            .enumerate()
+            .flat_map(|(i, manifest)| {
+                manifest
+                    .dependencies
+                    .iter()
+                    .filter_map(|dep| by_manifest_name.get(dep.as_str()))
+                    .filter(move |&&j| j != i)
+                    .map(move |&j| (i, j))
+            })
+            .collect();
+
+        Self { nodes, edges }
+    }
+
+    /// Annotate nodes with the per-package metrics of a check run.
+    ///
+    /// A project without packages has a single root node (`.`), which takes
+    /// the project-wide metrics.
+    pub fn overlay(&mut self, output: &CheckOutput) {
+        let check = |name: &str| output.checks.iter().find(|c| c.name == name);
+
+        for node in &mut self.nodes {
+            node.metrics.loc = check("cloc")
+                .and_then(|c| package_metrics(c, node))
+                .and_then(|m| m.get("source_lines"))
+                .and_then(|v| v.as_u64());
+            node.metrics.escapes = check("escapes")
+                .and_then(|c| package_metrics(c, node))
+                .and_then(|m| m.get("source"))
+                .and_then(|v| v.as_object())
+                .map(|counts| counts.values().filter_map(|v| v.as_u64()).sum());
+            node.metrics.coverage = check("tests")
+                .and_then(|c| c.metrics.as_ref())
+                .and_then(|m| {
+                    if node.path == "." {
+                        // Project-wide coverage is per language
+                        let languages = m.get("coverage")?.as_object()?;
+                        let values: Vec<f64> =
+                            languages.values().filter_map(|v| v.as_f64()).collect();
+                        (!values.is_empty())
+                            .then(|| values.iter().sum::<f64>() / values.len() as f64)
+                    } else {
+                        let packages = m.get("coverage_by_package")?.as_object()?;
+                        keys(node).find_map(|key| packages.get(key))?.as_f64()
+                    }
+                });
+        }
+    }
+
+    /// Render as a Graphviz DOT digraph.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph workspace {\n");
+        out.push_str("  rankdir=LR;\n");
+        out.push_str("  node [shape=box];\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "  n{} [label=\"{}\"];",
+                i,
+                label(node).join("\\n").replace('"', "\\\"")
+            );
+        }
+        for (from, to) in &self.edges {
+            let _ = writeln!(out, "  n{} -> n{};", from, to);
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Render as a Mermaid flowchart.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("graph LR\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "  n{}[\"{}\"]",
+                i,
+                label(node).join("<br/>").replace('"', "#quot;")
+            );
+        }
+        for (from, to) in &self.edges {
+            let _ = writeln!(out, "  n{} --> n{}", from, to);
+        }
+        out
+    }
+}
+
+/// Node label lines: name, then each measured metric.
+fn label(node: &Node) -> Vec<String> {
+    let mut lines = vec![node.name.clone()];
+    if let Some(loc) = node.metrics.loc {
+        lines.push(format!("loc: {}", loc));
+    }
+    if let Some(coverage) = node.metrics.coverage {
+        lines.push(format!("coverage: {:.1}%", coverage));
+    }
+    if let Some(escapes) = node.metrics.escapes {
+        lines.push(format!("escapes: {}", escapes));
+    }
+    lines
+}
+
+/// A check's metrics for a node: its `by_package` entry, or the
+/// project-wide metrics for the root node.
+fn package_metrics<'a>(check: &'a CheckResult, node: &Node) -> Option<&'a serde_json::Value> {
+    if node.path == "." {
+        return check.metrics.as_ref();
+    }
+    let packages = check.by_package.as_ref()?;
+    keys(node).find_map(|key| packages.get(key))
+}
+
+/// Keys a node's per-package metrics may be recorded under: checks use the
+/// package name when known, else its path (or directory name).
+fn keys(node: &Node) -> impl Iterator<Item = &str> {
+    [node.name.as_str(), node.path.as_str(), dir_name(&node.path)].into_iter()
+}
+
+fn dir_name(path: &str) -> &str {
+    path.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or(path)
+}
+
+/// Package name and dependency names from a package manifest.
+#[derive(Debug, Default)]
+struct Manifest {
+    name: Option<String>,
+    dependencies: Vec<String>,
+}
+
+impl Manifest {
+    fn read(dir: &Path) -> Option<Self> {
+        if let Ok(content) = std::fs::read_to_string(dir.join("Cargo.toml")) {
+            return Self::from_cargo(&content.parse().ok()?);
+        }
+        let content = std::fs::read_to_string(dir.join("package.json")).ok()?;
+        Self::from_package_json(&serde_json::from_str(&content).ok()?)
+    }
+
+    fn from_cargo(value: &toml::Value) -> Option<Self> {
+        let name = value
+            .get("package")
+            .and_then(|p| p.get("name"))
+            .and_then(|n| n.as_str())
+            .map(String::from);
+
+        // Platform-specific tables ([target.'cfg(..)'.dependencies]) count too
+        let targets = value
+            .get("target")
+            .and_then(|t| t.as_table())
+            .into_iter()
+            .flat_map(|targets| targets.values());
+        let dependencies = std::iter::once(value)
+            .chain(targets)
+            .flat_map(|section| {
+                CARGO_DEPENDENCY_TABLES
+                    .iter()
+                    .filter_map(|table| section.get(*table)?.as_table())
+            })
+            .flat_map(|table| {
+                // A renamed dependency names its package with `package = "..."`
+                table.iter().map(|(key, spec)| {
+                    spec.get("package")
+                        .and_then(|p| p.as_str())
+                        .unwrap_or(key)
+                        .to_string()
+                })
+            })
+            .collect();
+
+        Some(Self { name, dependencies })
+    }
+
+    fn from_package_json(value: &serde_json::Value) -> Option<Self> {
+        let name = value.get("name").and_then(|n| n.as_str()).map(String::from);
+        let dependencies = NPM_DEPENDENCY_FIELDS
+            .iter()
+            .filter_map(|field| value.get(*field)?.as_object())
+            .flat_map(|deps| deps.keys().cloned())
+            .collect();
+        Some(Self { name, dependencies })
+    }
+}
+
+#[cfg(test)]
+#[path = "graph_tests.rs"]
+mod tests;
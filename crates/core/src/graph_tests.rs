@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+use std::collections::BTreeMap;
+
+use serde_json::json;
+use tempfile::TempDir;
+
+fn write(dir: &TempDir, path: &str, content: &str) {
+    let path = dir.path().join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+fn packages(paths: &[&str]) -> Vec<String> {
+    paths.iter().map(|p| p.to_string()).collect()
+}
+
+/// (dependent, dependency) names for each edge.
+fn edges(graph: &Graph) -> Vec<(&str, &str)> {
+    graph
+        .edges
+        .iter()
+        .map(|&(from, to)| {
+            (
+                graph.nodes[from].name.as_str(),
+                graph.nodes[to].name.as_str(),
+            )
+        })
+        .collect()
+}
+
+fn cargo_workspace() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    write(
+        &dir,
+        "crates/core/Cargo.toml",
+        "[package]\nname = \"core\"\n\n[dependencies]\nserde = \"1\"\n",
+    );
+    write(
+        &dir,
+        "crates/cli/Cargo.toml",
+        r#"[package]
+name = "cli"
+
+[dependencies]
+core = { path = "../core" }
+
+[dev-dependencies]
+helpers = { package = "test-helpers", path = "../helpers" }
+"#,
+    );
+    write(
+        &dir,
+        "crates/helpers/Cargo.toml",
+        "[package]\nname = \"test-helpers\"\n\n[target.'cfg(unix)'.dependencies]\ncore = { path = \"../core\" }\n",
+    );
+    dir
+}
+
+#[test]
+fn cargo_edges_follow_workspace_dependencies() {
+    let dir = cargo_workspace();
+
+    let graph = Graph::from_packages(
+        dir.path(),
+        &packages(&["crates/core", "crates/cli", "crates/helpers"]),
+        &HashMap::new(),
+    );
+
+    assert_eq!(
+        graph
+            .nodes
+            .iter()
+            .map(|n| n.name.as_str())
+            .collect::<Vec<_>>(),
+        ["core", "cli", "test-helpers"]
+    );
+    // Renamed and platform-specific dependencies resolve; external ones don't
+    assert_eq!(
+        edges(&graph),
+        [
+            ("cli", "core"),
+            ("cli", "test-helpers"),
+            ("test-helpers", "core")
+        ]
+    );
+}
+
+#[test]
+fn package_json_edges_use_manifest_names_and_display_names() {
+    let dir = TempDir::new().unwrap();
+    write(&dir, "packages/ui/package.json", r#"{"name": "@acme/ui"}"#);
+    write(
+        &dir,
+        "packages/app/package.json",
+        r#"{"name": "@acme/app", "dependencies": {"@acme/ui": "*", "react": "^18"}}"#,
+    );
+    let names = HashMap::from([
+        ("packages/ui".to_string(), "ui".to_string()),
+        ("packages/app".to_string(), "app".to_string()),
+    ]);
+
+    let graph = Graph::from_packages(
+        dir.path(),
+        &packages(&["packages/ui", "packages/app"]),
+        &names,
+    );
+
+    assert_eq!(edges(&graph), [("app", "ui")]);
+}
+
+#[test]
+fn package_without_manifest_is_named_by_directory() {
+    let dir = TempDir::new().unwrap();
+
+    let graph = Graph::from_packages(dir.path(), &packages(&["tools/gen"]), &HashMap::new());
+
+    assert_eq!(graph.nodes[0].name, "gen");
+    assert!(graph.edges.is_empty());
+}
+
+fn check(name: &str) -> CheckResult {
+    CheckResult::passed(name)
+}
+
+#[test]
+fn overlay_reads_per_package_metrics() {
+    let dir = cargo_workspace();
+    let mut graph = Graph::from_packages(
+        dir.path(),
+        &packages(&["crates/core", "crates/cli"]),
+        &HashMap::new(),
+    );
+    let output = CheckOutput::new(
+        "2026-01-01T00:00:00Z".to_string(),
+        vec![
+            check("cloc").with_by_package(BTreeMap::from([(
+                "core".to_string(),
+                json!({"source_lines": 1200, "test_lines": 300}),
+            )])),
+            check("escapes").with_by_package(BTreeMap::from([(
+                "core".to_string(),
+                json!({"source": {"unsafe": 2, "unwrap": 3}, "test": {"unwrap": 9}}),
+            )])),
+            check("tests").with_metrics(json!({"coverage_by_package": {"core": 81.25}})),
+        ],
+    );
+
+    graph.overlay(&output);
+
+    assert_eq!(
+        graph.nodes[0].metrics,
+        NodeMetrics {
+            loc: Some(1200),
+            coverage: Some(81.25),
+            escapes: Some(5),
+        }
+    );
+    assert_eq!(graph.nodes[1].metrics, NodeMetrics::default());
+}
+
+#[test]
+fn overlay_uses_project_metrics_for_root_node() {
+    let dir = TempDir::new().unwrap();
+    let mut graph = Graph::from_packages(dir.path(), &packages(&["."]), &HashMap::new());
+    let output = CheckOutput::new(
+        "2026-01-01T00:00:00Z".to_string(),
+        vec![
+            check("cloc").with_metrics(json!({"source_lines": 40})),
+            check("tests").with_metrics(json!({"coverage": {"rust": 70.0, "shell": 50.0}})),
+        ],
+    );
+
+    graph.overlay(&output);
+
+    assert_eq!(
+        graph.nodes[0].metrics,
+        NodeMetrics {
+            loc: Some(40),
+            coverage: Some(60.0),
+            escapes: None,
+        }
+    );
+}
+
+fn annotated_graph() -> Graph {
+    let node = |name: &str, metrics| Node {
+        name: name.to_string(),
+        path: format!("crates/{}", name),
+        metrics,
+    };
+    Graph {
+        nodes: vec![
+            node(
+                "cli",
+                NodeMetrics {
+                    loc: Some(900),
+                    coverage: Some(72.46),
+                    escapes: Some(4),
+                },
+            ),
+            node("core", NodeMetrics::default()),
+        ],
+        edges: BTreeSet::from([(0, 1)]),
+    }
+}
+
+#[test]
+fn dot_labels_nodes_with_metrics() {
+    assert_eq!(
+        annotated_graph().to_dot(),
+        r#"digraph workspace {
+  rankdir=LR;
+  node [shape=box];
+  n0 [label="cli\nloc: 900\ncoverage: 72.5%\nescapes: 4"];
+  n1 [label="core"];
+  n0 -> n1;
+}
+"#
+    );
+}
+
+#[test]
+fn mermaid_labels_nodes_with_metrics() {
+    assert_eq!(
+        annotated_graph().to_mermaid(),
+        r#"graph LR
+  n0["cli<br/>loc: 900<br/>coverage: 72.5%<br/>escapes: 4"]
+  n1["core"]
+  n0 --> n1
+"#
+    );
+}
@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Project health score.
+//!
+//! Condenses baseline metrics into one 0-100 score. Each metric scores
+//! 0-100 on a straight line from its `bad` value to its `good` value, and
+//! the health score is their weighted average. Metrics the baseline
+//! doesn't have are left out rather than counted as zero.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::baseline::BaselineMetrics;
+use crate::config::{HealthConfig, HealthMetricConfig};
+
+/// Default `(weight, good, bad)` per metric.
+const COVERAGE: (f64, f64, f64) = (3.0, 80.0, 40.0);
+const ESCAPES: (f64, f64, f64) = (2.0, 0.0, 10.0);
+const LINT: (f64, f64, f64) = (2.0, 0.0, 20.0);
+const BUILD_TIME: (f64, f64, f64) = (1.0, 60.0, 600.0);
+const TEST_TIME: (f64, f64, f64) = (1.0, 30.0, 300.0);
+
+/// A health score with the per-metric scores behind it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthScore {
+    /// Weighted score, 0-100.
+    pub score: u32,
+    /// Letter grade for `score`.
+    pub grade: char,
+    /// Score per contributing metric, 0-100.
+    pub metrics: BTreeMap<&'static str, f64>,
+    /// Scores for workspace packages, from the metrics tracked per package.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub packages: BTreeMap<String, HealthScore>,
+}
+
+impl HealthScore {
+    /// Score `metrics` with the configured model. None when no weighted
+    /// metric has a value.
+    pub fn from_metrics(metrics: &BaselineMetrics, config: &HealthConfig) -> Option<Self> {
+        let model = Model::new(config);
+        let mut score = model.score(&[
+            ("coverage", coverage(metrics)),
+            ("escapes", escape_density(metrics)),
+            ("lint", lint_density(metrics)),
+            ("build_time", build_time(metrics)),
+            ("test_time", test_time(metrics)),
+        ])?;
+        score.packages = package_values(metrics)
+            .into_iter()
+            .filter_map(|(name, [cov, esc])| {
+                let package = model.score(&[("coverage", cov), ("escapes", esc)])?;
+                Some((name, package))
+            })
+            .collect();
+        Some(score)
+    }
+
+    /// Lower the score to at most `max`, regrading it.
+    pub fn capped(mut self, max: u32) -> Self {
+        if self.score > max {
+            self.score = max;
+            self.grade = grade(max);
+        }
+        self
+    }
+
+    /// `87/100 (B)`.
+    pub fn summary(&self) -> String {
+        format!("{}/100 ({})", self.score, self.grade)
+    }
+}
+
+/// Letter grade: A from 90, B from 80, C from 70, D from 60, else F.
+pub fn grade(score: u32) -> char {
+    match score {
+        90.. => 'A',
+        80..=89 => 'B',
+        70..=79 => 'C',
+        60..=69 => 'D',
+        _ => 'F',
+    }
+}
+
+/// Resolved `(weight, good, bad)` per metric.
+struct Model(BTreeMap<&'static str, (f64, f64, f64)>);
+
+impl Model {
+    fn new(config: &HealthConfig) -> Self {
+        let resolve = |metric: &HealthMetricConfig, (weight, good, bad): (f64, f64, f64)| {
+            (
+                metric.weight.unwrap_or(weight).max(0.0),
+                metric.good.unwrap_or(good),
+                metric.bad.unwrap_or(bad),
+            )
+        };
+        Self(BTreeMap::from([
+            ("coverage", resolve(&config.coverage, COVERAGE)),
+            ("escapes", resolve(&config.escapes, ESCAPES)),
+            ("lint", resolve(&config.lint, LINT)),
+            ("build_time", resolve(&config.build_time, BUILD_TIME)),
+            ("test_time", resolve(&config.test_time, TEST_TIME)),
+        ]))
+    }
+
+    fn score(&self, values: &[(&'static str, Option<f64>)]) -> Option<HealthScore> {
+        let mut metrics = BTreeMap::new();
+        let mut weighted = 0.0;
+        let mut total_weight = 0.0;
+        for &(name, value) in values {
+            let (Some(value), Some(&(weight, good, bad))) = (value, self.0.get(name)) else {
+                continue;
+            };
+            if weight == 0.0 {
+                continue;
+            }
+            let score = scale(value, good, bad);
+            metrics.insert(name, (score * 10.0).round() / 10.0);
+            weighted += score * weight;
+            total_weight += weight;
+        }
+        if total_weight == 0.0 {
+            return None;
+        }
+        let score = (weighted / total_weight).round() as u32;
+        Some(HealthScore {
+            score,
+            grade: grade(score),
+            metrics,
+            packages: BTreeMap::new(),
+        })
+    }
+}
+
+/// 100 at `good`, 0 at `bad`, linear between and clamped outside.
+fn scale(value: f64, good: f64, bad: f64) -> f64 {
+    if good == bad {
+        return if value == good { 100.0 } else { 0.0 };
+    }
+    ((value - bad) / (good - bad) * 100.0).clamp(0.0, 100.0)
+}
+
+/// Per 1000 lines, or None without a line count.
+fn per_kloc(count: usize, lines: Option<usize>) -> Option<f64> {
+    lines
+        .filter(|&lines| lines > 0)
+        .map(|lines| count as f64 * 1000.0 / lines as f64)
+}
+
+fn coverage(metrics: &BaselineMetrics) -> Option<f64> {
+    metrics.coverage.as_ref().map(|c| c.total)
+}
+
+fn escape_density(metrics: &BaselineMetrics) -> Option<f64> {
+    let escapes = metrics.escapes.as_ref()?;
+    per_kloc(escapes.source.values().sum(), escapes.source_lines)
+}
+
+/// Lint density shares the source line count recorded by the escapes check.
+fn lint_density(metrics: &BaselineMetrics) -> Option<f64> {
+    let lint = metrics.lint.as_ref()?;
+    let lines = metrics.escapes.as_ref()?.source_lines;
+    per_kloc(lint.values().sum(), lines)
+}
+
+fn build_time(metrics: &BaselineMetrics) -> Option<f64> {
+    // Zero means only the hot build was measured
+    metrics
+        .build_time
+        .as_ref()
+        .map(|b| b.cold)
+        .filter(|&cold| cold > 0.0)
+}
+
+fn test_time(metrics: &BaselineMetrics) -> Option<f64> {
+    metrics.test_time.as_ref().map(|t| t.total)
+}
+
+/// `[coverage, escape density]` per package.
+fn package_values(metrics: &BaselineMetrics) -> BTreeMap<String, [Option<f64>; 2]> {
+    let mut packages: BTreeMap<String, [Option<f64>; 2]> = BTreeMap::new();
+    let coverage = metrics
+        .coverage
+        .as_ref()
+        .and_then(|c| c.by_package.as_ref());
+    for (name, &pct) in coverage.into_iter().flatten() {
+        packages.entry(name.clone()).or_default()[0] = Some(pct);
+    }
+    let escapes = metrics.escapes.as_ref().and_then(|e| e.by_package.as_ref());
+    for (name, package) in escapes.into_iter().flatten() {
+        packages.entry(name.clone()).or_default()[1] =
+            per_kloc(package.source.values().sum(), Some(package.source_lines));
+    }
+    packages
+}
+
+#[cfg(test)]
+#[path = "health_tests.rs"]
+mod tests;
@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use crate::baseline::{
+    BuildTimeMetrics, CoverageMetrics, EscapesMetrics, PackageEscapesMetrics, TestTimeMetrics,
+};
+
+fn escapes(count: usize, source_lines: usize) -> EscapesMetrics {
+    EscapesMetrics {
+        source: BTreeMap::from([("unwrap".to_string(), count)]),
+        test: None,
+        source_lines: Some(source_lines),
+        by_package: None,
+    }
+}
+
+fn coverage(total: f64) -> CoverageMetrics {
+    CoverageMetrics {
+        total,
+        by_package: None,
+    }
+}
+
+// =============================================================================
+// SCORE TESTS
+// =============================================================================
+
+#[test]
+fn no_metrics_has_no_score() {
+    let score = HealthScore::from_metrics(&BaselineMetrics::default(), &HealthConfig::default());
+    assert_eq!(score, None);
+}
+
+#[test]
+fn metric_at_good_value_scores_100() {
+    let metrics = BaselineMetrics {
+        coverage: Some(coverage(80.0)),
+        escapes: Some(escapes(0, 1000)),
+        ..Default::default()
+    };
+
+    let score = HealthScore::from_metrics(&metrics, &HealthConfig::default()).unwrap();
+    assert_eq!(score.score, 100);
+    assert_eq!(score.grade, 'A');
+    assert_eq!(score.metrics["coverage"], 100.0);
+    assert_eq!(score.metrics["escapes"], 100.0);
+}
+
+#[test]
+fn metric_scales_linearly_between_bad_and_good() {
+    // Coverage 60% is halfway between bad (40) and good (80)
+    let metrics = BaselineMetrics {
+        coverage: Some(coverage(60.0)),
+        ..Default::default()
+    };
+
+    let score = HealthScore::from_metrics(&metrics, &HealthConfig::default()).unwrap();
+    assert_eq!(score.score, 50);
+    assert_eq!(score.grade, 'F');
+}
+
+#[test]
+fn lower_is_better_metrics_clamp_at_bad() {
+    // 30 escapes per 1000 lines is past bad (10)
+    let metrics = BaselineMetrics {
+        escapes: Some(escapes(30, 1000)),
+        ..Default::default()
+    };
+
+    let score = HealthScore::from_metrics(&metrics, &HealthConfig::default()).unwrap();
+    assert_eq!(score.score, 0);
+}
+
+#[test]
+fn score_is_weighted_average() {
+    // coverage 100 (weight 3), escapes 0 (weight 2) -> 60
+    let metrics = BaselineMetrics {
+        coverage: Some(coverage(90.0)),
+        escapes: Some(escapes(50, 1000)),
+        ..Default::default()
+    };
+
+    let score = HealthScore::from_metrics(&metrics, &HealthConfig::default()).unwrap();
+    assert_eq!(score.score, 60);
+    assert_eq!(score.grade, 'D');
+}
+
+#[test]
+fn lint_and_escapes_need_source_lines() {
+    let metrics = BaselineMetrics {
+        escapes: Some(EscapesMetrics {
+            source_lines: None,
+            ..escapes(3, 0)
+        }),
+        lint: Some(BTreeMap::from([("clippy/unwrap_used".to_string(), 4)])),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        HealthScore::from_metrics(&metrics, &HealthConfig::default()),
+        None
+    );
+}
+
+#[test]
+fn times_score_against_their_scales() {
+    let metrics = BaselineMetrics {
+        build_time: Some(BuildTimeMetrics {
+            cold: 60.0,
+            hot: 5.0,
+        }),
+        test_time: Some(TestTimeMetrics {
+            total: 300.0,
+            avg: 1.0,
+            max: 10.0,
+        }),
+        ..Default::default()
+    };
+
+    let score = HealthScore::from_metrics(&metrics, &HealthConfig::default()).unwrap();
+    assert_eq!(score.metrics["build_time"], 100.0);
+    assert_eq!(score.metrics["test_time"], 0.0);
+    assert_eq!(score.score, 50);
+}
+
+// =============================================================================
+// CONFIG TESTS
+// =============================================================================
+
+#[test]
+fn zero_weight_leaves_metric_out() {
+    let metrics = BaselineMetrics {
+        coverage: Some(coverage(90.0)),
+        escapes: Some(escapes(50, 1000)),
+        ..Default::default()
+    };
+    let config = HealthConfig {
+        escapes: HealthMetricConfig {
+            weight: Some(0.0),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let score = HealthScore::from_metrics(&metrics, &config).unwrap();
+    assert_eq!(score.score, 100);
+    assert!(!score.metrics.contains_key("escapes"));
+}
+
+#[test]
+fn configured_scale_overrides_default() {
+    let metrics = BaselineMetrics {
+        coverage: Some(coverage(80.0)),
+        ..Default::default()
+    };
+    let config = HealthConfig {
+        coverage: HealthMetricConfig {
+            weight: None,
+            good: Some(100.0),
+            bad: Some(60.0),
+        },
+        ..Default::default()
+    };
+
+    let score = HealthScore::from_metrics(&metrics, &config).unwrap();
+    assert_eq!(score.score, 50);
+}
+
+// =============================================================================
+// PACKAGE TESTS
+// =============================================================================
+
+#[test]
+fn packages_score_from_their_own_metrics() {
+    let metrics = BaselineMetrics {
+        coverage: Some(CoverageMetrics {
+            total: 70.0,
+            by_package: Some(BTreeMap::from([
+                ("core".to_string(), 80.0),
+                ("cli".to_string(), 40.0),
+            ])),
+        }),
+        escapes: Some(EscapesMetrics {
+            by_package: Some(BTreeMap::from([(
+                "core".to_string(),
+                PackageEscapesMetrics {
+                    source: BTreeMap::from([("unwrap".to_string(), 0)]),
+                    source_lines: 500,
+                },
+            )])),
+            ..escapes(0, 1000)
+        }),
+        ..Default::default()
+    };
+
+    let score = HealthScore::from_metrics(&metrics, &HealthConfig::default()).unwrap();
+    assert_eq!(score.packages["core"].score, 100);
+    assert_eq!(score.packages["cli"].score, 0);
+    assert!(!score.packages["cli"].metrics.contains_key("escapes"));
+}
+
+// =============================================================================
+// GRADE TESTS
+// =============================================================================
+
+#[test]
+fn grade_boundaries() {
+    assert_eq!(grade(100), 'A');
+    assert_eq!(grade(90), 'A');
+    assert_eq!(grade(89), 'B');
+    assert_eq!(grade(80), 'B');
+    assert_eq!(grade(70), 'C');
+    assert_eq!(grade(60), 'D');
+    assert_eq!(grade(59), 'F');
+    assert_eq!(grade(0), 'F');
+}
+
+#[test]
+fn summary_shows_score_and_grade() {
+    let metrics = BaselineMetrics {
+        coverage: Some(coverage(76.0)),
+        ..Default::default()
+    };
+
+    let score = HealthScore::from_metrics(&metrics, &HealthConfig::default()).unwrap();
+    assert_eq!(score.summary(), "90/100 (A)");
+}
+
+#[test]
+fn capped_lowers_score_and_grade() {
+    let metrics = BaselineMetrics {
+        coverage: Some(coverage(76.0)),
+        ..Default::default()
+    };
+    let score = HealthScore::from_metrics(&metrics, &HealthConfig::default()).unwrap();
+
+    assert_eq!(score.clone().capped(100).summary(), "90/100 (A)");
+    assert_eq!(score.capped(50).summary(), "50/100 (F)");
+}
@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Git hook installation for `quench init --hooks`.
+//!
+//! Installs a `pre-commit` hook running `quench check --staged` and a
+//! `commit-msg` hook running `quench check-commit-msg`. A hook that quench
+//! didn't write is kept: it moves aside to `<hook>.pre-quench`, and the
+//! installed hook runs it first. Uninstalling moves it back.
+
+use std::path::{Path, PathBuf};
+
+use git2::Repository;
+
+/// Marker line identifying hooks that quench installed.
+const MARKER: &str = "# Installed by `quench init --hooks`";
+
+/// Suffix for a hook that was in place before quench's.
+const BACKUP_SUFFIX: &str = ".pre-quench";
+
+/// A git hook quench installs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hook {
+    PreCommit,
+    CommitMsg,
+}
+
+impl Hook {
+    /// Every hook quench installs.
+    pub const ALL: [Hook; 2] = [Hook::PreCommit, Hook::CommitMsg];
+
+    /// The hook's file name in the hooks directory.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Hook::PreCommit => "pre-commit",
+            Hook::CommitMsg => "commit-msg",
+        }
+    }
+
+    /// The quench command the hook runs.
+    fn command(&self) -> &'static str {
+        match self {
+            Hook::PreCommit => "quench check --staged",
+            Hook::CommitMsg => "quench check-commit-msg \"$1\"",
+        }
+    }
+
+    /// The hook script.
+    pub fn script(&self) -> String {
+        let backup = format!("\"$0{}\"", BACKUP_SUFFIX);
+        format!(
+            "#!/bin/sh\n{MARKER}; remove with `quench init --remove-hooks`.\n\n\
+             # Run the hook this one replaced, if any\n\
+             if [ -x {backup} ]; then\n    {backup} \"$@\" || exit $?\nfi\n\n\
+             exec {}\n",
+            self.command()
+        )
+    }
+}
+
+/// What happened to one hook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookAction {
+    /// Written where there was no hook.
+    Installed,
+    /// An earlier quench hook was rewritten.
+    Updated,
+    /// Written after moving an existing hook to `<hook>.pre-quench`.
+    Chained,
+    /// Removed; the replaced hook was restored if there was one.
+    Removed { restored: bool },
+    /// Left alone because quench didn't write it.
+    NotOurs,
+    /// Nothing to remove.
+    Missing,
+}
+
+/// The hooks directory for the repository containing `root`, honoring
+/// `core.hooksPath`.
+pub fn hooks_dir(root: &Path) -> anyhow::Result<PathBuf> {
+    let repo = Repository::discover(root)?;
+    let configured = repo
+        .config()
+        .and_then(|config| config.get_path("core.hooksPath"))
+        .ok();
+    Ok(match configured {
+        Some(path) if path.is_absolute() => path,
+        // Relative hooks paths are relative to the working tree
+        Some(path) => repo.workdir().unwrap_or_else(|| repo.path()).join(path),
+        None => common_dir(&repo).join("hooks"),
+    })
+}
+
+/// The git directory shared by all worktrees, which holds the hooks.
+fn common_dir(repo: &Repository) -> PathBuf {
+    let git_dir = repo.path();
+    match std::fs::read_to_string(git_dir.join("commondir")) {
+        // Linked worktrees point at it, usually by a relative path
+        Ok(common) => git_dir.join(common.trim()),
+        Err(_) => git_dir.to_path_buf(),
+    }
+}
+
+/// Whether a hook file was written by quench.
+fn is_ours(path: &Path) -> bool {
+    std::fs::read_to_string(path).is_ok_and(|content| content.contains(MARKER))
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(BACKUP_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// Install `hook` into `dir`, keeping any hook quench didn't write.
+pub fn install(dir: &Path, hook: Hook) -> anyhow::Result<HookAction> {
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(hook.name());
+    let action = if !path.exists() {
+        HookAction::Installed
+    } else if is_ours(&path) {
+        HookAction::Updated
+    } else {
+        let backup = backup_path(&path);
+        if backup.exists() {
+            anyhow::bail!(
+                "{} exists and so does {}; move one of them first",
+                path.display(),
+                backup.display()
+            );
+        }
+        std::fs::rename(&path, &backup)?;
+        HookAction::Chained
+    };
+    std::fs::write(&path, hook.script())?;
+    make_executable(&path)?;
+    Ok(action)
+}
+
+/// Remove quench's `hook` from `dir`, restoring the hook it replaced.
+pub fn uninstall(dir: &Path, hook: Hook) -> anyhow::Result<HookAction> {
+    let path = dir.join(hook.name());
+    if !path.exists() {
+        return Ok(HookAction::Missing);
+    }
+    if !is_ours(&path) {
+        return Ok(HookAction::NotOurs);
+    }
+    std::fs::remove_file(&path)?;
+    let backup = backup_path(&path);
+    let restored = backup.exists();
+    if restored {
+        std::fs::rename(&backup, &path)?;
+    }
+    Ok(HookAction::Removed { restored })
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = std::fs::metadata(path)?.permissions();
+    permissions.set_mode(permissions.mode() | 0o755);
+    std::fs::set_permissions(path, permissions)
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "hooks_tests.rs"]
+mod tests;
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use tempfile::TempDir;
+
+#[test]
+fn hook_scripts_run_quench() {
+    assert!(
+        Hook::PreCommit
+            .script()
+            .contains("exec quench check --staged\n")
+    );
+    assert!(
+        Hook::CommitMsg
+            .script()
+            .contains("exec quench check-commit-msg \"$1\"\n")
+    );
+    assert!(Hook::PreCommit.script().starts_with("#!/bin/sh\n"));
+}
+
+#[test]
+fn install_writes_executable_hook() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path().join("hooks");
+    assert_eq!(
+        install(&dir, Hook::PreCommit).unwrap(),
+        HookAction::Installed
+    );
+    let path = dir.join("pre-commit");
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        Hook::PreCommit.script()
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111, "hook should be executable");
+    }
+}
+
+#[test]
+fn install_again_updates_own_hook() {
+    let temp = TempDir::new().unwrap();
+    install(temp.path(), Hook::CommitMsg).unwrap();
+    assert_eq!(
+        install(temp.path(), Hook::CommitMsg).unwrap(),
+        HookAction::Updated
+    );
+    assert!(!temp.path().join("commit-msg.pre-quench").exists());
+}
+
+#[test]
+fn install_chains_existing_hook() {
+    let temp = TempDir::new().unwrap();
+    let existing = "#!/bin/sh\nmake lint\n";
+    std::fs::write(temp.path().join("pre-commit"), existing).unwrap();
+
+    assert_eq!(
+        install(temp.path(), Hook::PreCommit).unwrap(),
+        HookAction::Chained
+    );
+    let backup = std::fs::read_to_string(temp.path().join("pre-commit.pre-quench")).unwrap();
+    assert_eq!(backup, existing);
+    let hook = std::fs::read_to_string(temp.path().join("pre-commit")).unwrap();
+    assert!(hook.contains("\"$0.pre-quench\" \"$@\" || exit $?"));
+}
+
+#[test]
+fn install_refuses_to_overwrite_backup() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("pre-commit"), "#!/bin/sh\n").unwrap();
+    std::fs::write(temp.path().join("pre-commit.pre-quench"), "#!/bin/sh\n").unwrap();
+    assert!(install(temp.path(), Hook::PreCommit).is_err());
+}
+
+#[test]
+fn uninstall_restores_chained_hook() {
+    let temp = TempDir::new().unwrap();
+    let existing = "#!/bin/sh\nmake lint\n";
+    std::fs::write(temp.path().join("pre-commit"), existing).unwrap();
+    install(temp.path(), Hook::PreCommit).unwrap();
+
+    assert_eq!(
+        uninstall(temp.path(), Hook::PreCommit).unwrap(),
+        HookAction::Removed { restored: true }
+    );
+    let hook = std::fs::read_to_string(temp.path().join("pre-commit")).unwrap();
+    assert_eq!(hook, existing);
+    assert!(!temp.path().join("pre-commit.pre-quench").exists());
+}
+
+#[test]
+fn uninstall_leaves_other_hooks_alone() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("commit-msg"), "#!/bin/sh\n").unwrap();
+    assert_eq!(
+        uninstall(temp.path(), Hook::CommitMsg).unwrap(),
+        HookAction::NotOurs
+    );
+    assert!(temp.path().join("commit-msg").exists());
+    assert_eq!(
+        uninstall(temp.path(), Hook::PreCommit).unwrap(),
+        HookAction::Missing
+    );
+}
+
+#[test]
+fn hooks_dir_defaults_to_git_dir() {
+    let temp = TempDir::new().unwrap();
+    git2::Repository::init(temp.path()).unwrap();
+    let dir = hooks_dir(temp.path()).unwrap();
+    assert!(dir.ends_with(".git/hooks"), "got {}", dir.display());
+}
+
+#[test]
+fn hooks_dir_honors_core_hooks_path() {
+    let temp = TempDir::new().unwrap();
+    let repo = git2::Repository::init(temp.path()).unwrap();
+    repo.config()
+        .unwrap()
+        .set_str("core.hooksPath", ".githooks")
+        .unwrap();
+    let dir = hooks_dir(temp.path()).unwrap();
+    assert!(dir.ends_with(".githooks"), "got {}", dir.display());
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Churn hotspot analysis.
+//!
+//! A hotspot is a file that is both large and changed often: the place
+//! where refactoring pays off soonest. Churn is the number of commits
+//! touching a file within `[hotspots] window` days. A file is a hotspot
+//! when it meets both the churn and the line thresholds.
+
+use serde::Serialize;
+
+use crate::config::HotspotsConfig;
+use crate::debt::FileFacts;
+
+/// A file over both hotspot thresholds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Hotspot {
+    /// Path relative to the project root.
+    pub path: String,
+    /// Commits touching the file within the window.
+    pub churn: usize,
+    /// Line count, per `[check.cloc] metric`.
+    pub lines: usize,
+    /// Churn ratio times size ratio, each relative to its threshold.
+    pub score: f64,
+}
+
+/// Whether a file with `lines` and `churn` is a hotspot.
+pub fn is_hotspot(lines: usize, churn: usize, config: &HotspotsConfig) -> bool {
+    churn > 0 && churn >= config.churn && lines >= config.lines
+}
+
+/// Hotspots among `files`, hottest first; ties sort by path.
+pub fn find(files: &[FileFacts], config: &HotspotsConfig) -> Vec<Hotspot> {
+    let mut hotspots: Vec<Hotspot> = files
+        .iter()
+        .filter(|file| is_hotspot(file.lines, file.churn, config))
+        .map(|file| {
+            let churn = file.churn as f64 / config.churn.max(1) as f64;
+            let size = file.lines as f64 / config.lines.max(1) as f64;
+            Hotspot {
+                path: file.path.clone(),
+                churn: file.churn,
+                lines: file.lines,
+                score: (churn * size * 10.0).round() / 10.0,
+            }
+        })
+        .collect();
+    hotspots.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    hotspots
+}
+
+#[cfg(test)]
+#[path = "hotspots_tests.rs"]
+mod tests;
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+
+fn file(path: &str, lines: usize, churn: usize) -> FileFacts {
+    FileFacts {
+        path: path.to_string(),
+        lines,
+        churn,
+        ..Default::default()
+    }
+}
+
+fn config() -> HotspotsConfig {
+    HotspotsConfig {
+        churn: 10,
+        lines: 400,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn hotspot_needs_both_churn_and_size() {
+    let files = [
+        file("src/big_quiet.rs", 2000, 2),
+        file("src/small_busy.rs", 50, 40),
+        file("src/hot.rs", 400, 10),
+    ];
+
+    let hotspots = find(&files, &config());
+    assert_eq!(hotspots.len(), 1);
+    assert_eq!(hotspots[0].path, "src/hot.rs");
+    assert_eq!(hotspots[0].score, 1.0);
+}
+
+#[test]
+fn hotspots_rank_by_score_then_path() {
+    let files = [
+        file("src/b.rs", 800, 10),
+        file("src/a.rs", 400, 20),
+        file("src/c.rs", 1200, 30),
+    ];
+
+    let hotspots = find(&files, &config());
+    let paths: Vec<&str> = hotspots.iter().map(|h| h.path.as_str()).collect();
+    assert_eq!(paths, ["src/c.rs", "src/a.rs", "src/b.rs"]);
+    assert_eq!(hotspots[0].score, 9.0);
+}
+
+#[test]
+fn zero_thresholds_still_need_churn() {
+    let config = HotspotsConfig {
+        churn: 0,
+        lines: 0,
+        ..Default::default()
+    };
+    assert!(!is_hotspot(100, 0, &config));
+    assert!(is_hotspot(100, 1, &config));
+}
@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Test impact analysis storage.
+//!
+//! Records which source files each test executed, using per-test coverage
+//! contexts from runners that support them, in `.quench/impact.bin`.
+//! `quench suggest-tests` reads it back to pick the tests affected by a diff.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use flate2::Compression;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use serde::{Deserialize, Serialize};
+
+/// Impact map file name within `.quench/`.
+pub const IMPACT_FILE_NAME: &str = "impact.bin";
+
+/// Impact format version, bumped on incompatible changes.
+pub const IMPACT_VERSION: u32 = 1;
+
+/// Errors reading or writing the impact map.
+#[derive(Debug, thiserror::Error)]
+pub enum ImpactError {
+    /// I/O error.
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Serialization error.
+    #[error("serialization error: {0}")]
+    Postcard(#[from] postcard::Error),
+
+    /// Written by an incompatible quench version.
+    #[error("unsupported impact map version {0} (expected {IMPACT_VERSION})")]
+    Version(u32),
+}
+
+/// Source files executed by each test.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImpactMap {
+    version: u32,
+    /// Test id (e.g., `tests/test_api.py::test_get`) to project-relative source paths.
+    tests: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// Tests selected for a set of changed files.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Suggestion {
+    /// Tests that executed a changed file or live in one, sorted.
+    pub tests: Vec<String>,
+    /// Changed files no recorded test touches.
+    pub unmapped: Vec<String>,
+}
+
+impl Default for ImpactMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ImpactMap {
+    pub fn new() -> Self {
+        Self {
+            version: IMPACT_VERSION,
+            tests: BTreeMap::new(),
+        }
+    }
+
+    /// Path of the impact map under a project root.
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(".quench").join(IMPACT_FILE_NAME)
+    }
+
+    /// Load an impact map, or `None` if none has been recorded.
+    pub fn load(path: &Path) -> Result<Option<Self>, ImpactError> {
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let mut raw = Vec::with_capacity(bytes.len() * 4);
+        DeflateDecoder::new(bytes.as_slice()).read_to_end(&mut raw)?;
+        let map: Self = postcard::from_bytes(&raw)?;
+        if map.version != IMPACT_VERSION {
+            return Err(ImpactError::Version(map.version));
+        }
+        Ok(Some(map))
+    }
+
+    /// Write the impact map atomically via a temp file.
+    pub fn save(&self, path: &Path) -> Result<(), ImpactError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let bytes = postcard::to_allocvec(self)?;
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::fast());
+        encoder.write_all(&bytes)?;
+        crate::atomic::write(path, encoder.finish()?)?;
+        Ok(())
+    }
+
+    /// Record the files one test executed, replacing any earlier record.
+    pub fn record(&mut self, test: impl Into<String>, files: BTreeSet<String>) {
+        self.tests.insert(test.into(), files);
+    }
+
+    /// Merge tests recorded in `newer`, replacing their earlier records.
+    pub fn merge(&mut self, newer: ImpactMap) {
+        self.tests.extend(newer.tests);
+    }
+
+    /// Number of recorded tests.
+    pub fn len(&self) -> usize {
+        self.tests.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tests.is_empty()
+    }
+
+    /// Select the tests affected by `changed` project-relative paths.
+    ///
+    /// A test is affected when it executed a changed file or when its id
+    /// names a changed test file (`<file>::<name>`).
+    pub fn suggest(&self, changed: &[String]) -> Suggestion {
+        let mut tests = BTreeSet::new();
+        let mut unmapped = Vec::new();
+        for file in changed {
+            let mut mapped = false;
+            for (test, files) in &self.tests {
+                let test_file = test.split("::").next().unwrap_or(test);
+                if files.contains(file) || test_file == file {
+                    tests.insert(test.clone());
+                    mapped = true;
+                }
+            }
+            if !mapped {
+                unmapped.push(file.clone());
+            }
+        }
+        Suggestion {
+            tests: tests.into_iter().collect(),
+            unmapped,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "impact_tests.rs"]
+mod tests;
@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use tempfile::TempDir;
+
+fn files(paths: &[&str]) -> BTreeSet<String> {
+    paths.iter().map(|p| p.to_string()).collect()
+}
+
+fn sample() -> ImpactMap {
+    let mut map = ImpactMap::new();
+    map.record(
+        "tests/test_api.py::test_get",
+        files(&["src/api.py", "src/db.py"]),
+    );
+    map.record("tests/test_db.py::test_save", files(&["src/db.py"]));
+    map
+}
+
+#[test]
+fn save_and_load_roundtrip() {
+    let temp = TempDir::new().unwrap();
+    let path = ImpactMap::path(temp.path());
+
+    sample().save(&path).unwrap();
+
+    assert_eq!(ImpactMap::load(&path).unwrap(), Some(sample()));
+    // Only the map itself is left behind
+    assert_eq!(
+        std::fs::read_dir(path.parent().unwrap()).unwrap().count(),
+        1
+    );
+}
+
+#[test]
+fn load_missing_file_returns_none() {
+    let temp = TempDir::new().unwrap();
+    assert_eq!(
+        ImpactMap::load(&ImpactMap::path(temp.path())).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn load_rejects_other_versions() {
+    let temp = TempDir::new().unwrap();
+    let path = ImpactMap::path(temp.path());
+    let mut map = sample();
+    map.version = IMPACT_VERSION + 1;
+    map.save(&path).unwrap();
+
+    assert!(matches!(
+        ImpactMap::load(&path),
+        Err(ImpactError::Version(_))
+    ));
+}
+
+#[test]
+fn merge_replaces_rerecorded_tests() {
+    let mut map = sample();
+    let mut newer = ImpactMap::new();
+    newer.record("tests/test_db.py::test_save", files(&["src/cache.py"]));
+
+    map.merge(newer);
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(
+        map.suggest(&["src/cache.py".to_string()]).tests,
+        ["tests/test_db.py::test_save"]
+    );
+}
+
+#[test]
+fn suggest_selects_tests_executing_changed_files() {
+    let suggestion = sample().suggest(&["src/db.py".to_string()]);
+
+    assert_eq!(
+        suggestion.tests,
+        ["tests/test_api.py::test_get", "tests/test_db.py::test_save"]
+    );
+    assert!(suggestion.unmapped.is_empty());
+}
+
+#[test]
+fn suggest_selects_tests_in_changed_test_files() {
+    let suggestion = sample().suggest(&["tests/test_db.py".to_string()]);
+
+    assert_eq!(suggestion.tests, ["tests/test_db.py::test_save"]);
+}
+
+#[test]
+fn suggest_reports_unmapped_files() {
+    let suggestion = sample().suggest(&["src/api.py".to_string(), "README.md".to_string()]);
+
+    assert_eq!(suggestion.tests, ["tests/test_api.py::test_get"]);
+    assert_eq!(suggestion.unmapped, ["README.md"]);
+}
@@ -22,6 +22,41 @@ pub enum DetectedAgent {
     Claude,
     /// Cursor agent with the actual required file/pattern found.
     Cursor(CursorMarker),
+    /// OpenAI Codex and other tools reading AGENTS.md.
+    Codex,
+    /// Gemini CLI (GEMINI.md).
+    Gemini,
+    /// Windsurf (.windsurfrules).
+    Windsurf,
+    /// GitHub Copilot (.github/copilot-instructions.md).
+    Copilot,
+}
+
+impl DetectedAgent {
+    /// Profile name for the agent (e.g., "claude").
+    pub fn name(&self) -> &'static str {
+        match self {
+            DetectedAgent::Claude => "claude",
+            DetectedAgent::Cursor(_) => "cursor",
+            DetectedAgent::Codex => "codex",
+            DetectedAgent::Gemini => "gemini",
+            DetectedAgent::Windsurf => "windsurf",
+            DetectedAgent::Copilot => "copilot",
+        }
+    }
+
+    /// File (or pattern) the agent was detected by, relative to the root.
+    pub fn file(&self) -> &'static str {
+        match self {
+            DetectedAgent::Claude => "CLAUDE.md",
+            DetectedAgent::Cursor(CursorMarker::Cursorrules) => ".cursorrules",
+            DetectedAgent::Cursor(CursorMarker::CursorRulesDir) => ".cursor/rules/*.mdc",
+            DetectedAgent::Codex => "AGENTS.md",
+            DetectedAgent::Gemini => "GEMINI.md",
+            DetectedAgent::Windsurf => ".windsurfrules",
+            DetectedAgent::Copilot => ".github/copilot-instructions.md",
+        }
+    }
 }
 
 /// Cursor marker type detected in the project.
@@ -121,6 +156,8 @@ fn has_gemspec(root: &Path) -> bool {
 ///
 /// Returns a list of detected agents. Detection is additive:
 /// a project with CLAUDE.md and .cursorrules returns both Claude and Cursor.
+/// AGENTS.md, GEMINI.md, .windsurfrules, and .github/copilot-instructions.md
+/// mark Codex, Gemini, Windsurf, and Copilot.
 pub fn detect_agents(root: &Path) -> Vec<DetectedAgent> {
     let mut agents = Vec::new();
 
@@ -136,6 +173,18 @@ pub fn detect_agents(root: &Path) -> Vec<DetectedAgent> {
         agents.push(DetectedAgent::Cursor(CursorMarker::CursorRulesDir));
     }
 
+    // Single-file agents: the marker file exists
+    for agent in [
+        DetectedAgent::Codex,
+        DetectedAgent::Gemini,
+        DetectedAgent::Windsurf,
+        DetectedAgent::Copilot,
+    ] {
+        if root.join(agent.file()).exists() {
+            agents.push(agent);
+        }
+    }
+
     agents
 }
 
@@ -217,6 +217,47 @@ fn detect_cursor_from_md_rules() {
     assert!(detected.contains(&DetectedAgent::Cursor(CursorMarker::CursorRulesDir)));
 }
 
+#[test]
+fn detect_codex_from_agents_md() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("AGENTS.md"), "# Project").unwrap();
+
+    let detected = detect_agents(temp.path());
+    assert_eq!(detected, vec![DetectedAgent::Codex]);
+}
+
+#[test]
+fn detect_gemini_from_gemini_md() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("GEMINI.md"), "# Project").unwrap();
+
+    let detected = detect_agents(temp.path());
+    assert_eq!(detected, vec![DetectedAgent::Gemini]);
+}
+
+#[test]
+fn detect_windsurf_from_windsurfrules() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join(".windsurfrules"), "# Rules").unwrap();
+
+    let detected = detect_agents(temp.path());
+    assert_eq!(detected, vec![DetectedAgent::Windsurf]);
+}
+
+#[test]
+fn detect_copilot_from_copilot_instructions() {
+    let temp = TempDir::new().unwrap();
+    fs::create_dir_all(temp.path().join(".github")).unwrap();
+    fs::write(
+        temp.path().join(".github/copilot-instructions.md"),
+        "# Project",
+    )
+    .unwrap();
+
+    let detected = detect_agents(temp.path());
+    assert_eq!(detected, vec![DetectedAgent::Copilot]);
+}
+
 #[test]
 fn agent_detection_is_additive() {
     let temp = TempDir::new().unwrap();
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Ctrl-C handling for commands that run checks.
+//!
+//! The first interrupt only sets a flag: checks that haven't started are
+//! skipped, running child processes (test suites, builds) are killed, and
+//! the run finishes with a partial summary. A second interrupt kills the
+//! children's process groups and exits at once.
+
+use std::io;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Process groups of live children from [`spawn`], 0 for a free slot. A
+/// fixed table of atomics, since the signal handler can't take a lock.
+static GROUPS: [AtomicI32; 64] = [const { AtomicI32::new(0) }; 64];
+
+/// Catch Ctrl-C instead of exiting, for the rest of the process.
+#[allow(unsafe_code)]
+pub fn install() {
+    #[cfg(unix)]
+    // SAFETY: the handler only stores to an atomic and calls `_exit`, both
+    // async-signal-safe.
+    unsafe {
+        libc::signal(
+            libc::SIGINT,
+            on_interrupt as extern "C" fn(libc::c_int) as libc::sighandler_t,
+        );
+    }
+}
+
+#[cfg(unix)]
+// Signal handlers must be `extern "C"` and may only make async-signal-safe
+// calls: this one swaps an atomic, and on the second interrupt kills the
+// children's groups (outside the terminal's group, Ctrl-C doesn't reach them)
+// and calls `_exit`, which skips destructors and atexit handlers.
+#[allow(unsafe_code)]
+extern "C" fn on_interrupt(_: libc::c_int) {
+    if INTERRUPTED.swap(true, Ordering::SeqCst) {
+        for group in &GROUPS {
+            let pgid = group.load(Ordering::SeqCst);
+            if pgid > 0 {
+                // SAFETY: `kill` is async-signal-safe.
+                unsafe { libc::kill(-pgid, libc::SIGKILL) };
+            }
+        }
+        // SAFETY: `_exit` is async-signal-safe.
+        unsafe { libc::_exit(crate::error::ExitCode::Interrupted as i32) };
+    }
+}
+
+/// Whether the run was interrupted.
+pub fn requested() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Spawn a child in its own process group, so [`kill`] reaches everything
+/// it starts (e.g., cargo's test binaries, jest's workers).
+///
+/// The group is tracked until [`kill`] or [`reaped`], so a second interrupt
+/// can kill it before exiting.
+pub fn spawn(cmd: &mut Command) -> io::Result<Child> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // Outside the terminal's foreground group the child can't read it
+        cmd.process_group(0).stdin(Stdio::null());
+    }
+    #[cfg(not(unix))]
+    cmd.stdin(Stdio::null());
+    let child = cmd.spawn()?;
+    track(&child);
+    Ok(child)
+}
+
+/// Kill a child started with [`spawn`] and its process group, and reap it.
+#[allow(unsafe_code)]
+pub fn kill(child: &mut Child) {
+    #[cfg(unix)]
+    if let Ok(pid) = libc::pid_t::try_from(child.id()) {
+        // SAFETY: `kill` has no memory-safety preconditions; the group is
+        // the child's own, created by `spawn`.
+        unsafe { libc::kill(-pid, libc::SIGKILL) };
+    }
+    child.kill().ok();
+    child.wait().ok();
+    reaped(child);
+}
+
+/// Stop tracking a child started with [`spawn`] once it has been waited on.
+pub fn reaped(child: &Child) {
+    let Ok(pid) = i32::try_from(child.id()) else {
+        return;
+    };
+    if let Some(group) = GROUPS.iter().find(|g| g.load(Ordering::SeqCst) == pid) {
+        group.store(0, Ordering::SeqCst);
+    }
+}
+
+/// Record a child's process group in a free slot. With every slot taken the
+/// child goes untracked; it is still killed by the first interrupt.
+fn track(child: &Child) {
+    let Ok(pid) = i32::try_from(child.id()) else {
+        return;
+    };
+    GROUPS.iter().any(|g| {
+        g.compare_exchange(0, pid, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+    });
+}
+
+#[cfg(test)]
+#[path = "interrupt_tests.rs"]
+mod tests;
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::io::{BufRead, BufReader, Read};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use super::*;
+
+#[cfg(unix)]
+#[test]
+fn kill_reaches_grandchildren() {
+    let mut child = spawn(
+        Command::new("sh")
+            .args(["-c", "sleep 30 & echo started; wait"])
+            .stdout(Stdio::piped()),
+    )
+    .unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut line = String::new();
+    stdout.read_line(&mut line).unwrap();
+    assert_eq!(line, "started\n");
+
+    kill(&mut child);
+
+    // The pipe closes only once the backgrounded sleep is gone too
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut rest = Vec::new();
+        stdout.read_to_end(&mut rest).ok();
+        tx.send(()).ok();
+    });
+    assert!(rx.recv_timeout(Duration::from_secs(10)).is_ok());
+}
+
+#[cfg(unix)]
+#[test]
+fn spawned_children_get_no_stdin() {
+    let output = spawn(Command::new("cat").stdout(Stdio::piped()))
+        .unwrap()
+        .wait_with_output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
+
+fn tracked(child: &Child) -> bool {
+    let pid = i32::try_from(child.id()).unwrap();
+    GROUPS.iter().any(|g| g.load(Ordering::SeqCst) == pid)
+}
+
+#[cfg(unix)]
+#[test]
+fn spawned_children_are_tracked_until_killed() {
+    let mut child = spawn(Command::new("sleep").arg("30")).unwrap();
+    assert!(tracked(&child));
+
+    kill(&mut child);
+    assert!(!tracked(&child));
+}
+
+#[cfg(unix)]
+#[test]
+fn reaped_children_are_no_longer_tracked() {
+    let mut child = spawn(&mut Command::new("true")).unwrap();
+    child.wait().unwrap();
+    assert!(tracked(&child));
+
+    reaped(&child);
+    assert!(!tracked(&child));
+}
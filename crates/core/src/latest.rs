@@ -36,7 +36,7 @@ impl LatestMetrics {
             std::fs::create_dir_all(parent)?;
         }
         let content = serde_json::to_string_pretty(self)?;
-        std::fs::write(path, content)?;
+        crate::atomic::write(path, content)?;
         Ok(())
     }
 
@@ -12,7 +12,12 @@ fn save_and_load_latest_metrics() {
     let output = CheckOutput {
         timestamp: "2026-01-27T00:00:00Z".to_string(),
         passed: true,
+        tags: Default::default(),
+        undecodable_files: 0,
         checks: vec![],
+        diagnostics: vec![],
+        ci: None,
+        interrupted: false,
     };
 
     let latest = LatestMetrics {
@@ -45,7 +50,12 @@ fn save_creates_parent_directories() {
     let output = CheckOutput {
         timestamp: "2026-01-27T00:00:00Z".to_string(),
         passed: true,
+        tags: Default::default(),
+        undecodable_files: 0,
         checks: vec![],
+        diagnostics: vec![],
+        ci: None,
+        interrupted: false,
     };
 
     let latest = LatestMetrics {
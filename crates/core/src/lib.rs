@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! The quench check engine as a library.
+//!
+//! `quench-core` holds everything `quench check` needs except the command
+//! line: configuration loading, file discovery and walking, the checks and
+//! the runner that executes them, baselines and ratcheting, and the output
+//! formatters. It has no clap dependency, so bots, editor plugins, and
+//! server-side analyzers can run checks in-process and consume the same
+//! [`CheckResult`]s the CLI prints.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::path::Path;
+//!
+//! use quench_core::checks;
+//! use quench_core::config::{self, Config};
+//! use quench_core::discovery;
+//! use quench_core::runner::{CheckRunner, RunnerConfig};
+//! use quench_core::walker::FileWalker;
+//!
+//! let root = Path::new(".");
+//! let config = match discovery::find_config(root) {
+//!     Some(path) => config::load(&path)?,
+//!     None => Config::default(),
+//! };
+//!
+//! let walker = FileWalker::from_exclude_config(&config.project.exclude);
+//! let (files, handle) = walker.walk(root);
+//! let files: Vec<_> = files.iter().collect();
+//! handle.join();
+//!
+//! let runner = CheckRunner::new(RunnerConfig::default());
+//! for result in runner.run(checks::all_checks(), &files, &config, root) {
+//!     println!("{}: {} violations", result.name, result.violations.len());
+//! }
+//! # Ok::<(), quench_core::Error>(())
+//! ```
+//!
+//! # Stability
+//!
+//! The API tracks the `quench` binary and follows its version. Baseline
+//! files and JSON output are versioned formats; the Rust API is not yet.
+
+pub mod adapter;
+pub mod advice;
+pub mod annotate;
+pub mod anomaly;
+pub mod atomic;
+pub mod baseline;
+pub mod baseline_history;
+pub mod bisect;
+pub mod blocks;
+pub mod budget;
+pub mod cache;
+pub mod check;
+pub mod checks;
+pub mod ci_workflow;
+pub mod cloc;
+pub mod codeowners;
+pub mod coherence;
+pub mod color;
+pub mod config;
+pub mod container;
+pub mod debt;
+pub mod delta_owners;
+pub mod determinism;
+pub mod diagnostics;
+pub mod discovery;
+pub mod env;
+pub mod error;
+pub mod explain;
+pub mod file_reader;
+pub mod file_size;
+pub mod fix;
+pub mod git;
+pub mod goals;
+pub mod graph;
+pub mod health;
+pub mod hooks;
+pub mod hotspots;
+pub mod impact;
+pub mod init;
+pub mod interrupt;
+pub mod latest;
+pub mod lock;
+pub mod metrics;
+pub mod migrate;
+pub mod new_code;
+pub mod output;
+pub mod patch;
+pub mod pattern;
+pub mod profiles;
+pub mod quarantine;
+pub mod ratchet;
+pub mod refresh;
+pub mod rules;
+pub mod runner;
+pub mod sbom;
+pub mod schedule;
+pub mod scope;
+pub mod sparse;
+#[cfg(feature = "sqlite")]
+pub mod store;
+pub mod suppressions;
+pub mod telemetry;
+pub mod testkit;
+pub mod timing;
+pub mod tolerance;
+pub mod tools;
+pub mod verbose;
+pub mod walker;
+
+pub use baseline::Baseline;
+pub use check::{Check, CheckContext, CheckResult, Violation};
+pub use config::Config;
+pub use error::{Error, ExitCode};
+
+#[cfg(test)]
+pub mod test_utils;
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Advisory lock against concurrent `quench check` runs.
+//!
+//! A run holds `.quench/lock` while it reads and writes the cache and
+//! baseline, so an editor's `quench watch` and a manual run don't corrupt
+//! each other's writes. The lock is an OS file lock, which the OS releases
+//! when its process exits (e.g., after a second Ctrl-C), so a crashed run
+//! never leaves a stale lock behind. The file holds the owner's PID while
+//! it runs, to name it to whoever waits.
+
+use std::fs::{File, OpenOptions, TryLockError};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Lock file name within `.quench/`.
+pub const LOCK_FILE_NAME: &str = "lock";
+
+/// How often [`RunLock::wait`] retries a held lock.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Why the lock couldn't be taken.
+#[derive(Debug, thiserror::Error)]
+pub enum LockError {
+    /// Another process holds it. `pid` is `None` when the holder hasn't
+    /// recorded its PID yet.
+    #[error("{} holds {}", describe_holder(*.pid), .path.display())]
+    Held { pid: Option<u32>, path: PathBuf },
+
+    /// The lock file couldn't be opened or locked.
+    #[error("failed to lock {}: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// A held lock, released on drop.
+#[derive(Debug)]
+pub struct RunLock {
+    file: File,
+}
+
+impl RunLock {
+    /// The lock file for the project at `root`.
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(".quench").join(LOCK_FILE_NAME)
+    }
+
+    /// Take the lock, or fail with [`LockError::Held`] if another process
+    /// has it.
+    pub fn acquire(root: &Path) -> Result<Self, LockError> {
+        let path = Self::path(root);
+        let io_error = |source| LockError::Io {
+            path: path.clone(),
+            source,
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(io_error)?;
+        }
+
+        // Holding the lock, not the file's contents, decides ownership: an
+        // empty or unreadable file may belong to a holder still writing it
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .map_err(io_error)?;
+        match file.try_lock() {
+            Ok(()) => {}
+            Err(TryLockError::WouldBlock) => {
+                return Err(LockError::Held {
+                    pid: holder(&path),
+                    path,
+                });
+            }
+            Err(TryLockError::Error(source)) => return Err(io_error(source)),
+        }
+
+        // Replace whatever PID a run that exited left behind
+        file.set_len(0)
+            .and_then(|()| writeln!(file, "{}", std::process::id()))
+            .map_err(io_error)?;
+        Ok(RunLock { file })
+    }
+
+    /// Take the lock, waiting for the process holding it to finish.
+    /// `on_wait` is called once with the holder's PID, if it has recorded
+    /// one. Gives up with [`LockError::Held`] when interrupted.
+    pub fn wait(root: &Path, on_wait: impl FnOnce(Option<u32>)) -> Result<Self, LockError> {
+        let mut on_wait = Some(on_wait);
+        loop {
+            match Self::acquire(root) {
+                Err(LockError::Held { pid, .. }) if !crate::interrupt::requested() => {
+                    if let Some(on_wait) = on_wait.take() {
+                        on_wait(pid);
+                    }
+                    std::thread::sleep(POLL_INTERVAL);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl Drop for RunLock {
+    fn drop(&mut self) {
+        // The file stays, since removing it would let a run that opened it
+        // before the removal lock an orphan; closing it releases the lock
+        self.file.set_len(0).ok();
+    }
+}
+
+/// PID recorded in a lock file.
+fn holder(path: &Path) -> Option<u32> {
+    std::fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// "another quench run (pid 123)", or without the PID when it's unknown.
+pub fn describe_holder(pid: Option<u32>) -> String {
+    match pid {
+        Some(pid) => format!("another quench run (pid {})", pid),
+        None => "another quench run".to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "lock_tests.rs"]
+mod tests;
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::panic)]
+
+use super::*;
+use tempfile::TempDir;
+
+/// Lock `.quench/lock` the way another process would, without recording a PID.
+fn lock_without_pid(root: &Path) -> File {
+    let path = RunLock::path(root);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let file = File::create(&path).unwrap();
+    file.lock().unwrap();
+    file
+}
+
+#[test]
+fn acquire_writes_pid_and_drop_releases() {
+    let temp = TempDir::new().unwrap();
+    let path = RunLock::path(temp.path());
+
+    let lock = RunLock::acquire(temp.path()).unwrap();
+    assert_eq!(holder(&path), Some(std::process::id()));
+
+    drop(lock);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "");
+    // Nothing but the lock file is left behind
+    assert_eq!(
+        std::fs::read_dir(temp.path().join(".quench"))
+            .unwrap()
+            .count(),
+        1
+    );
+    RunLock::acquire(temp.path()).unwrap();
+}
+
+#[test]
+fn acquire_fails_while_lock_held() {
+    let temp = TempDir::new().unwrap();
+    let _lock = RunLock::acquire(temp.path()).unwrap();
+
+    match RunLock::acquire(temp.path()).unwrap_err() {
+        LockError::Held { pid, .. } => assert_eq!(pid, Some(std::process::id())),
+        other => panic!("expected Held, got {other:?}"),
+    }
+    // The holder's PID is untouched
+    assert_eq!(
+        holder(&RunLock::path(temp.path())),
+        Some(std::process::id())
+    );
+}
+
+#[test]
+fn held_lock_without_pid_is_held() {
+    let temp = TempDir::new().unwrap();
+    let _file = lock_without_pid(temp.path());
+
+    match RunLock::acquire(temp.path()).unwrap_err() {
+        LockError::Held { pid, .. } => assert_eq!(pid, None),
+        other => panic!("expected Held, got {other:?}"),
+    }
+}
+
+#[test]
+fn acquire_takes_over_pid_left_by_exited_run() {
+    let temp = TempDir::new().unwrap();
+    let path = RunLock::path(temp.path());
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "4194304\n").unwrap();
+
+    let _lock = RunLock::acquire(temp.path()).unwrap();
+    assert_eq!(holder(&path), Some(std::process::id()));
+}
+
+#[test]
+fn wait_returns_once_holder_releases() {
+    let temp = TempDir::new().unwrap();
+    let file = lock_without_pid(temp.path());
+    let releaser = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(300));
+        drop(file);
+    });
+
+    let mut waited = false;
+    let _lock = RunLock::wait(temp.path(), |_| waited = true).unwrap();
+    releaser.join().unwrap();
+
+    assert!(waited);
+    assert_eq!(
+        holder(&RunLock::path(temp.path())),
+        Some(std::process::id())
+    );
+}
+
+#[test]
+fn held_error_names_holder_when_known() {
+    let path = PathBuf::from(".quench/lock");
+    let known = LockError::Held {
+        pid: Some(42),
+        path: path.clone(),
+    };
+    assert_eq!(
+        known.to_string(),
+        "another quench run (pid 42) holds .quench/lock"
+    );
+    let unknown = LockError::Held { pid: None, path };
+    assert_eq!(unknown.to_string(), "another quench run holds .quench/lock");
+}
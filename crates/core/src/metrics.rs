@@ -0,0 +1,552 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Typed per-check metrics and the registry that validates them.
+//!
+//! Each check that reports metrics builds one of the structs below and
+//! hands [`CheckMetrics::to_json`] to [`CheckResult::with_metrics`]. Before
+//! results reach the output formatters or the baseline, the runner passes
+//! them through [`validate`], which round-trips each check's metrics (and
+//! per-package metrics) through its registered type. Unknown keys and
+//! mistyped values are dropped with an `invalid_metrics` diagnostic rather
+//! than drifting silently, and consumers like the ratchet read them back
+//! with [`parse`] instead of probing JSON paths.
+//!
+//! Checks without a registered type pass through unchanged.
+
+use std::collections::BTreeMap;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use crate::check::CheckResult;
+use crate::checks::placeholders::PlaceholderMetrics;
+use crate::diagnostics::{Code, Diagnostics};
+
+/// Metrics reported by one check.
+pub trait CheckMetrics: Serialize + DeserializeOwned {
+    /// The check reporting these metrics.
+    const CHECK: &'static str;
+
+    /// Serialize for [`CheckResult::with_metrics`].
+    fn to_json(&self) -> JsonValue {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+/// Metrics that failed to match their check's registered type.
+#[derive(Debug, thiserror::Error)]
+#[error("{check} metrics don't match their schema: {source}")]
+pub struct MetricsError {
+    pub check: String,
+    pub source: serde_json::Error,
+}
+
+// =============================================================================
+// Check Metrics
+// =============================================================================
+
+/// `agents`: detected agent files and their token counts.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Agents {
+    pub files_found: Vec<String>,
+    pub files_missing: Vec<String>,
+    pub in_sync: bool,
+    pub total_tokens: usize,
+    /// Estimated tokens per agent file.
+    pub tokens: BTreeMap<String, usize>,
+}
+
+impl CheckMetrics for Agents {
+    const CHECK: &'static str = "agents";
+}
+
+/// `cloc`: line, file, and token counts. Also each `by_package` entry.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Cloc {
+    pub source_lines: usize,
+    pub source_files: usize,
+    pub source_tokens: usize,
+    pub test_lines: usize,
+    pub test_files: usize,
+    pub test_tokens: usize,
+    /// Test lines per source line, rounded to two places.
+    pub ratio: f64,
+}
+
+impl CheckMetrics for Cloc {
+    const CHECK: &'static str = "cloc";
+}
+
+/// `escapes`: pattern counts by configured pattern. Also each `by_package`
+/// entry.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Escapes {
+    pub source: BTreeMap<String, usize>,
+    pub test: BTreeMap<String, usize>,
+    pub source_lines: usize,
+    /// Source matches per 1,000 source lines.
+    pub density: BTreeMap<String, f64>,
+    /// Test assertions without a failure message (omitted per package).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bare_assertions: Option<usize>,
+    /// Unsafe Rust in source files (only when Rust files were scanned).
+    #[serde(rename = "unsafe", default, skip_serializing_if = "Option::is_none")]
+    pub unsafe_code: Option<UnsafeCode>,
+}
+
+impl CheckMetrics for Escapes {
+    const CHECK: &'static str = "escapes";
+}
+
+/// `escapes.unsafe`: unsafe Rust found by parsing rather than by pattern.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UnsafeCode {
+    pub blocks: usize,
+    pub fns: usize,
+    pub impls: usize,
+    /// Non-blank lines inside unsafe blocks.
+    pub lines: usize,
+    /// Non-blank Rust source lines scanned.
+    pub source_lines: usize,
+    /// Unsafe lines per Rust source line, rounded to four places.
+    pub ratio: f64,
+}
+
+/// `lint`: diagnostic counts by linter and by rule.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Lint {
+    pub diagnostics: BTreeMap<String, usize>,
+    pub rules: BTreeMap<String, usize>,
+}
+
+impl CheckMetrics for Lint {
+    const CHECK: &'static str = "lint";
+}
+
+/// `build`: binary sizes in bytes and build times in seconds.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Build {
+    pub size: BTreeMap<String, u64>,
+    pub time: BuildTime,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub size_gzip: BTreeMap<String, u64>,
+}
+
+impl CheckMetrics for Build {
+    const CHECK: &'static str = "build";
+}
+
+/// Cold and hot build times in seconds; `null` when not measured.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BuildTime {
+    pub cold: Option<f64>,
+    pub hot: Option<f64>,
+}
+
+/// `tests`: suite results when suites run, commit correlation otherwise.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Tests {
+    Suites(TestSuites),
+    Correlation(TestCorrelation),
+}
+
+impl CheckMetrics for Tests {
+    const CHECK: &'static str = "tests";
+}
+
+/// Aggregated results of the configured or auto-detected test suites.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestSuites {
+    pub test_count: usize,
+    /// Rust doctests, included in `test_count`.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub doctest_count: usize,
+    pub total_ms: u64,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub auto_detected: bool,
+    /// Runner of a single auto-detected suite.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detection_source: Option<String>,
+    pub suites: Vec<TestSuite>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avg_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_test: Option<String>,
+    /// Coverage fraction by language.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub coverage: BTreeMap<String, f64>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub coverage_by_package: BTreeMap<String, f64>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub coverage_by_path: BTreeMap<String, f64>,
+    /// Coverage of the lines changed since `--base`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub patch_coverage: Option<f64>,
+}
+
+/// One test suite's result.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestSuite {
+    pub name: String,
+    pub runner: String,
+    pub passed: bool,
+    pub test_count: usize,
+    /// Rust doctests, included in `test_count`.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub doctest_count: usize,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub skipped_count: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Zero (and omitted) when the runner reported no timing.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub total_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avg_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_test: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub slowest: Vec<SlowTest>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p50_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p90_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p99_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detection_source: Option<String>,
+}
+
+/// One of a suite's slowest tests.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SlowTest {
+    pub name: String,
+    pub ms: u64,
+}
+
+/// Source/test change correlation, by branch or by commit.
+///
+/// Counts are absent when there was nothing to compare against.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestCorrelation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_files_changed: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub with_test_changes: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub without_test_changes: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commits_checked: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commits_failing: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<CorrelationScope>,
+    /// Lockfiles, snapshots, and generated artifacts changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mechanical_files_changed: Option<usize>,
+    /// Lines added and deleted in mechanical files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mechanical_lines_changed: Option<usize>,
+    /// Commits touching only mechanical files (not checked for tests).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commits_mechanical: Option<usize>,
+    /// Placeholder tests found in test files.
+    pub placeholders: PlaceholderMetrics,
+}
+
+/// Whether correlation looked at the branch as a whole or each commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CorrelationScope {
+    Branch,
+    Commit,
+}
+
+/// `git`: commit message validation counts.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Git {
+    pub commits_checked: usize,
+    pub commits_valid: usize,
+    pub commits_skipped: usize,
+}
+
+impl CheckMetrics for Git {
+    const CHECK: &'static str = "git";
+}
+
+/// `docs`: the specs directory.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Docs {
+    /// Detected or configured index file path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_file: Option<String>,
+    /// Number of spec files found.
+    pub spec_files: usize,
+}
+
+impl CheckMetrics for Docs {
+    const CHECK: &'static str = "docs";
+}
+
+/// `license`: header counts.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct License {
+    pub files_checked: usize,
+    pub files_with_headers: usize,
+    pub files_missing_headers: usize,
+    pub files_outdated_year: usize,
+    pub files_wrong_license: usize,
+}
+
+impl CheckMetrics for License {
+    const CHECK: &'static str = "license";
+}
+
+/// `whitespace`: files checked and issues by kind.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Whitespace {
+    pub files_checked: usize,
+    pub line_ending: usize,
+    pub trailing_whitespace: usize,
+    pub missing_final_newline: usize,
+    pub wrong_indent: usize,
+}
+
+impl CheckMetrics for Whitespace {
+    const CHECK: &'static str = "whitespace";
+}
+
+/// `format`: unformatted file counts by formatter.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Format {
+    pub unformatted: BTreeMap<String, usize>,
+}
+
+impl CheckMetrics for Format {
+    const CHECK: &'static str = "format";
+}
+
+/// `snapshots`: snapshot files, their size, and issues by kind.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Snapshots {
+    pub files: usize,
+    pub bytes: u64,
+    pub orphaned: usize,
+    pub too_large: usize,
+    /// Snapshot files changed against `--base` or in `--staged`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub churn_files: Option<usize>,
+    /// Snapshot lines added and deleted against `--base` or in `--staged`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub churn_lines: Option<usize>,
+}
+
+impl CheckMetrics for Snapshots {
+    const CHECK: &'static str = "snapshots";
+}
+
+/// `deadcode`: exports scanned and unused exports by language.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Deadcode {
+    pub exports: usize,
+    pub dead: BTreeMap<String, usize>,
+}
+
+impl CheckMetrics for Deadcode {
+    const CHECK: &'static str = "deadcode";
+}
+
+/// `deps`: manifests read, dependencies per ecosystem, and duplicates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Deps {
+    pub manifests: usize,
+    /// External dependencies declared across manifests.
+    pub direct: BTreeMap<String, usize>,
+    /// Distinct package versions resolved, direct and transitive.
+    pub total: BTreeMap<String, usize>,
+    pub duplicates: usize,
+    /// Feature-gated code per Cargo package.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub features: BTreeMap<String, Features>,
+}
+
+/// `deps.features.<package>`: Cargo features and the code behind them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Features {
+    /// Features in `[features]`.
+    pub declared: usize,
+    /// Declared features that enable nothing and gate no code.
+    pub unused: usize,
+    /// Features named in `cfg` predicates but never declared.
+    pub undeclared: usize,
+    /// Outermost items gated on a feature.
+    pub gated_items: usize,
+    /// Non-blank lines of gated code.
+    pub gated_lines: usize,
+    /// Non-blank lines of Rust source in the package.
+    pub source_lines: usize,
+}
+
+impl CheckMetrics for Deps {
+    const CHECK: &'static str = "deps";
+}
+
+/// `release`: the pending version and leftover markers.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Release {
+    pub version: String,
+    pub placeholders: usize,
+    pub markers: usize,
+}
+
+impl CheckMetrics for Release {
+    const CHECK: &'static str = "release";
+}
+
+fn is_zero<T: Default + PartialEq>(n: &T) -> bool {
+    *n == T::default()
+}
+
+// =============================================================================
+// Registry
+// =============================================================================
+
+type Normalizer = fn(JsonValue) -> Result<JsonValue, serde_json::Error>;
+
+/// Metrics types by check.
+const REGISTRY: &[(&str, Normalizer)] = &[
+    (Agents::CHECK, round_trip::<Agents>),
+    (Build::CHECK, round_trip::<Build>),
+    (Cloc::CHECK, round_trip::<Cloc>),
+    (Deadcode::CHECK, round_trip::<Deadcode>),
+    (Deps::CHECK, round_trip::<Deps>),
+    (Docs::CHECK, round_trip::<Docs>),
+    (Escapes::CHECK, round_trip::<Escapes>),
+    (Format::CHECK, round_trip::<Format>),
+    (Git::CHECK, round_trip::<Git>),
+    (License::CHECK, round_trip::<License>),
+    (Lint::CHECK, round_trip::<Lint>),
+    (Release::CHECK, round_trip::<Release>),
+    (Snapshots::CHECK, round_trip::<Snapshots>),
+    (Tests::CHECK, round_trip::<Tests>),
+    (Whitespace::CHECK, round_trip::<Whitespace>),
+];
+
+/// Per-package metrics types by check.
+const PACKAGE_REGISTRY: &[(&str, Normalizer)] = &[
+    (Cloc::CHECK, round_trip::<Cloc>),
+    (Escapes::CHECK, round_trip::<Escapes>),
+];
+
+fn round_trip<M: CheckMetrics>(value: JsonValue) -> Result<JsonValue, serde_json::Error> {
+    serde_json::from_value::<M>(value).and_then(|m| serde_json::to_value(m))
+}
+
+/// The built-in check a (possibly instance) check name runs, e.g. `cloc`
+/// for `cloc:scripts`.
+fn base_check(check: &str) -> &str {
+    check.split_once(':').map_or(check, |(base, _)| base)
+}
+
+fn lookup(registry: &[(&str, Normalizer)], check: &str) -> Option<Normalizer> {
+    let base = base_check(check);
+    registry
+        .iter()
+        .find(|(name, _)| *name == base)
+        .map(|(_, normalize)| *normalize)
+}
+
+/// Whether `check` has a registered metrics type.
+pub fn is_registered(check: &str) -> bool {
+    lookup(REGISTRY, check).is_some()
+}
+
+/// Validate `check`'s metrics against its registered type, returning them
+/// in canonical form.
+pub fn normalize(check: &str, metrics: JsonValue) -> Result<JsonValue, MetricsError> {
+    normalize_with(REGISTRY, check, metrics)
+}
+
+/// Validate one of `check`'s `by_package` entries.
+pub fn normalize_package(check: &str, metrics: JsonValue) -> Result<JsonValue, MetricsError> {
+    normalize_with(PACKAGE_REGISTRY, check, metrics)
+}
+
+fn normalize_with(
+    registry: &[(&str, Normalizer)],
+    check: &str,
+    metrics: JsonValue,
+) -> Result<JsonValue, MetricsError> {
+    match lookup(registry, check) {
+        Some(normalize) => normalize(metrics).map_err(|source| MetricsError {
+            check: check.to_string(),
+            source,
+        }),
+        None => Ok(metrics),
+    }
+}
+
+/// Normalize a result's metrics in place, dropping any that don't match
+/// their schema with an `invalid_metrics` diagnostic.
+pub fn validate(result: &mut CheckResult, diagnostics: &Diagnostics) {
+    if let Some(metrics) = result.metrics.take() {
+        match normalize(&result.name, metrics) {
+            Ok(metrics) => result.metrics = Some(metrics),
+            Err(e) => diagnostics.emit(Code::InvalidMetrics, e.to_string()),
+        }
+    }
+    if let Some(packages) = result.by_package.take() {
+        let normalized: Result<BTreeMap<_, _>, MetricsError> = packages
+            .into_iter()
+            .map(|(name, metrics)| Ok((name, normalize_package(&result.name, metrics)?)))
+            .collect();
+        match normalized {
+            Ok(packages) => result.by_package = Some(packages),
+            Err(e) => diagnostics.emit(Code::InvalidMetrics, format!("by_package: {e}")),
+        }
+    }
+}
+
+/// Read a result's metrics as `M`, if it reported metrics of that shape.
+pub fn parse<M: CheckMetrics>(result: &CheckResult) -> Option<M> {
+    parse_json(result.metrics.as_ref()?)
+}
+
+/// Read raw metrics JSON as `M`, e.g. a `by_package` entry.
+pub fn parse_json<M: CheckMetrics>(metrics: &JsonValue) -> Option<M> {
+    M::deserialize(metrics).ok()
+}
+
+#[cfg(test)]
+#[path = "metrics_tests.rs"]
+mod tests;
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use serde_json::json;
+
+use super::*;
+
+// =============================================================================
+// Registry
+// =============================================================================
+
+#[test]
+fn normalize_accepts_metrics_matching_schema() {
+    let metrics = json!({ "diagnostics": { "clippy": 1 }, "rules": { "clippy/x": 1 } });
+    assert_eq!(normalize("lint", metrics.clone()).unwrap(), metrics);
+}
+
+#[test]
+fn normalize_rejects_unknown_keys() {
+    let metrics = json!({ "diagnostics": {}, "rules": {}, "rule": {} });
+    let err = normalize("lint", metrics).unwrap_err();
+    assert_eq!(err.check, "lint");
+    assert!(err.to_string().contains("unknown field `rule`"), "{err}");
+}
+
+#[test]
+fn normalize_rejects_mistyped_values() {
+    let metrics = json!({ "version": "1.2.0", "placeholders": "none", "markers": 0 });
+    assert!(normalize("release", metrics).is_err());
+}
+
+#[test]
+fn normalize_uses_base_check_for_instances() {
+    let metrics = json!({ "unformatted": { "rustfmt": "2" } });
+    assert!(normalize("format:docs", metrics).is_err());
+}
+
+#[test]
+fn normalize_passes_unregistered_checks_through() {
+    let metrics = json!({ "anything": [1, 2, 3] });
+    assert!(!is_registered("custom"));
+    assert_eq!(normalize("custom", metrics.clone()).unwrap(), metrics);
+}
+
+#[test]
+fn normalize_package_checks_per_package_schema() {
+    let cloc = Cloc {
+        source_lines: 10,
+        ..Default::default()
+    };
+    assert!(normalize_package("cloc", cloc.to_json()).is_ok());
+    assert!(normalize_package("cloc", json!({ "source_lines": 10 })).is_err());
+}
+
+#[test]
+fn validate_drops_invalid_metrics_with_diagnostic() {
+    let diagnostics = Diagnostics::silent();
+    let mut result = CheckResult::passed("git").with_metrics(json!({ "commits": 3 }));
+
+    validate(&mut result, &diagnostics);
+
+    assert!(result.metrics.is_none());
+    let all = diagnostics.all();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].code, Code::InvalidMetrics);
+}
+
+#[test]
+fn validate_keeps_valid_metrics_without_diagnostic() {
+    let diagnostics = Diagnostics::silent();
+    let git = Git {
+        commits_checked: 3,
+        commits_valid: 2,
+        commits_skipped: 1,
+    };
+    let mut result = CheckResult::passed("git").with_metrics(git.to_json());
+
+    validate(&mut result, &diagnostics);
+
+    assert_eq!(parse::<Git>(&result), Some(git));
+    assert!(diagnostics.all().is_empty());
+}
+
+// =============================================================================
+// Shapes
+// =============================================================================
+
+#[test]
+fn build_time_serializes_unmeasured_as_null() {
+    let build = Build::default();
+    assert_eq!(
+        build.to_json(),
+        json!({ "size": {}, "time": { "cold": null, "hot": null } })
+    );
+}
+
+#[test]
+fn test_suite_omits_unset_fields() {
+    let suite = TestSuite {
+        name: "unit".to_string(),
+        runner: "cargo".to_string(),
+        passed: true,
+        test_count: 4,
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&suite).unwrap(),
+        json!({ "name": "unit", "runner": "cargo", "passed": true, "test_count": 4 })
+    );
+}
+
+#[test]
+fn tests_metrics_distinguish_suites_from_correlation() {
+    let suites = json!({ "test_count": 0, "total_ms": 0, "suites": [] });
+    assert!(matches!(
+        parse_json::<Tests>(&suites),
+        Some(Tests::Suites(_))
+    ));
+
+    let correlation = Tests::Correlation(TestCorrelation {
+        commits_checked: Some(2),
+        commits_failing: Some(0),
+        scope: Some(CorrelationScope::Commit),
+        ..Default::default()
+    });
+    let json = correlation.to_json();
+    assert_eq!(json["scope"], "commit");
+    assert_eq!(parse_json::<Tests>(&json), Some(correlation));
+}
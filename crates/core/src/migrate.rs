@@ -0,0 +1,301 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Translating existing tool configs for `quench init --import`.
+//!
+//! Reads the settings teams have already tuned (lint ignores, coverage
+//! targets, warning budgets) so the generated quench.toml starts from them
+//! instead of from defaults. Settings without a quench equivalent are
+//! listed in a comment rather than dropped silently.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// Config files `--import` understands, for error messages.
+pub const SUPPORTED: &[&str] = &[
+    ".eslintrc[.json|.yml|.yaml]",
+    ".eslintignore",
+    "eslint.config.[js|mjs|cjs]",
+    "clippy.toml",
+    "codecov.yml",
+];
+
+static MAX_WARNINGS: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"--max-warnings[= ](\d+)").ok());
+
+/// Settings collected from imported configs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Imported {
+    /// Files that were read, in order.
+    pub sources: Vec<String>,
+    /// Walker-level exclude patterns (gitignore syntax).
+    pub exclude: Vec<String>,
+    /// Linters for the lint check.
+    pub linters: Vec<String>,
+    /// Warning budget from `eslint --max-warnings`.
+    pub max_warnings: Option<u64>,
+    /// Overall coverage minimum (percent).
+    pub coverage_min: Option<f64>,
+    /// Per-path coverage minimums, keyed by glob.
+    pub coverage_paths: BTreeMap<String, f64>,
+    /// Coverage drop allowed by the ratchet (percentage points).
+    pub coverage_tolerance: Option<f64>,
+    /// Settings read but not translated, as `file: setting`.
+    pub skipped: Vec<String>,
+}
+
+impl Imported {
+    /// Read one config file (relative to `root`) into these settings.
+    pub fn import(&mut self, root: &Path, file: &str) -> Result<(), String> {
+        let name = Path::new(file)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(file);
+        let read =
+            || std::fs::read_to_string(root.join(file)).map_err(|e| format!("{}: {}", file, e));
+        match name {
+            ".eslintrc" | ".eslintrc.json" | ".eslintrc.yml" | ".eslintrc.yaml" => {
+                self.import_eslintrc(root, file, &read()?)?
+            }
+            ".eslintignore" => self.import_ignore_file(&read()?),
+            "eslint.config.js" | "eslint.config.mjs" | "eslint.config.cjs" => {
+                // Flat configs are code; only the linter and budget carry over
+                read()?;
+                self.add_eslint(root);
+                self.skipped.push(format!("{}: ignores", file));
+            }
+            "clippy.toml" | ".clippy.toml" => self.import_clippy(file, &read()?)?,
+            "codecov.yml" | ".codecov.yml" | "codecov.yaml" | ".codecov.yaml" => {
+                self.import_codecov(file, &read()?)?
+            }
+            _ => {
+                return Err(format!(
+                    "{}: unsupported config (supported: {})",
+                    file,
+                    SUPPORTED.join(", ")
+                ));
+            }
+        }
+        self.sources.push(file.to_string());
+        Ok(())
+    }
+
+    fn import_eslintrc(&mut self, root: &Path, file: &str, content: &str) -> Result<(), String> {
+        // YAML parses the JSON form too
+        let config: serde_yaml::Value =
+            serde_yaml::from_str(content).map_err(|e| format!("{}: {}", file, e))?;
+        match config.get("ignorePatterns") {
+            Some(serde_yaml::Value::String(pattern)) => self.add_exclude(pattern),
+            Some(serde_yaml::Value::Sequence(patterns)) => {
+                for pattern in patterns.iter().filter_map(|p| p.as_str()) {
+                    self.add_exclude(pattern);
+                }
+            }
+            _ => {}
+        }
+        self.add_eslint(root);
+        Ok(())
+    }
+
+    fn import_ignore_file(&mut self, content: &str) {
+        for line in content.lines().map(str::trim) {
+            if !line.is_empty() && !line.starts_with('#') {
+                self.add_exclude(line);
+            }
+        }
+    }
+
+    /// Enable eslint, with the `--max-warnings` budget from package.json
+    /// scripts when there is one.
+    fn add_eslint(&mut self, root: &Path) {
+        self.add_linter("eslint");
+        let scripts = std::fs::read_to_string(root.join("package.json")).unwrap_or_default();
+        let budget = MAX_WARNINGS
+            .iter()
+            .flat_map(|re| re.captures_iter(&scripts))
+            .filter_map(|c| c[1].parse::<u64>().ok())
+            .min();
+        if budget.is_some() {
+            self.max_warnings = budget;
+        }
+    }
+
+    fn import_clippy(&mut self, file: &str, content: &str) -> Result<(), String> {
+        let config: toml::Table =
+            toml::from_str(content).map_err(|e| format!("{}: {}", file, e))?;
+        self.add_linter("clippy");
+        // Lint thresholds stay in clippy.toml, which clippy keeps reading
+        for key in config.keys() {
+            self.skipped.push(format!("{}: {}", file, key));
+        }
+        Ok(())
+    }
+
+    fn import_codecov(&mut self, file: &str, content: &str) -> Result<(), String> {
+        let config: serde_yaml::Value =
+            serde_yaml::from_str(content).map_err(|e| format!("{}: {}", file, e))?;
+
+        let project = config
+            .get("coverage")
+            .and_then(|c| c.get("status"))
+            .and_then(|s| s.get("project"))
+            .and_then(|p| p.as_mapping());
+        for (name, status) in project.into_iter().flatten() {
+            let name = name.as_str().unwrap_or_default();
+            let target = status.get("target").and_then(percent);
+            let paths: Vec<&str> = status
+                .get("paths")
+                .and_then(|p| p.as_sequence())
+                .map(|seq| seq.iter().filter_map(|p| p.as_str()).collect())
+                .unwrap_or_default();
+            if let Some(threshold) = status.get("threshold").and_then(percent) {
+                self.coverage_tolerance = Some(threshold);
+            }
+            match target {
+                // "auto" compares against the base commit, which the ratchet does
+                None => {}
+                Some(min) if paths.is_empty() => self.coverage_min = Some(min),
+                Some(min) => {
+                    for path in paths {
+                        self.coverage_paths.insert(path_glob(path), min);
+                    }
+                }
+            }
+            if status
+                .get("target")
+                .is_some_and(|t| t.as_str() == Some("auto"))
+            {
+                self.skipped.push(format!(
+                    "{}: coverage.status.project.{}.target auto",
+                    file, name
+                ));
+            }
+        }
+
+        for key in ["ignore", "flags", "component_management"] {
+            if config.get(key).is_some() {
+                self.skipped.push(format!("{}: {}", file, key));
+            }
+        }
+        if config
+            .get("coverage")
+            .and_then(|c| c.get("status"))
+            .and_then(|s| s.get("patch"))
+            .is_some()
+        {
+            self.skipped
+                .push(format!("{}: coverage.status.patch", file));
+        }
+        Ok(())
+    }
+
+    fn add_exclude(&mut self, pattern: &str) {
+        if let Some(negated) = pattern.strip_prefix('!') {
+            self.skipped.push(format!("un-ignore {}", negated));
+        } else if !self.exclude.iter().any(|p| p == pattern) {
+            self.exclude.push(pattern.to_string());
+        }
+    }
+
+    fn add_linter(&mut self, linter: &str) {
+        if !self.linters.iter().any(|l| l == linter) {
+            self.linters.push(linter.to_string());
+        }
+    }
+
+    /// Merge the imported settings into a generated quench.toml.
+    pub fn apply(&self, config: &str) -> String {
+        if self.sources.is_empty() {
+            return config.to_string();
+        }
+        let mut config = config.to_string();
+
+        // [ratchet] is already in the template; add the key inside it
+        if let Some(tolerance) = self.coverage_tolerance
+            && let Some(start) = config.find("\n[ratchet]\n")
+        {
+            let end = config[start + 1..]
+                .find("\n\n")
+                .map_or(config.len(), |i| start + 1 + i + 1);
+            config.insert_str(
+                end,
+                &format!(
+                    "coverage_tolerance = {:?}   # Allowed coverage drop (points)\n",
+                    tolerance
+                ),
+            );
+        }
+
+        config.push_str(&format!("\n# Imported from {}\n", self.sources.join(", ")));
+        if !self.exclude.is_empty() {
+            config.push_str(&format!(
+                "[project]\nexclude = {}\n\n",
+                toml_list(&self.exclude)
+            ));
+        }
+        if !self.linters.is_empty() {
+            config.push_str(&format!(
+                "[check.lint]\ncheck = \"error\"\nlinters = {}\n",
+                toml_list(&self.linters)
+            ));
+            if let Some(budget) = self.max_warnings.filter(|n| *n > 0) {
+                config.push_str(&format!(
+                    "grandfather = true   # was --max-warnings {}; the ratchet keeps it from growing\n",
+                    budget
+                ));
+            }
+            config.push('\n');
+        }
+        if self.coverage_min.is_some() || !self.coverage_paths.is_empty() {
+            config.push_str("# Coverage comes from test suites; see `quench config tests`\n");
+            config.push_str("[check.tests.coverage]\ncheck = \"error\"\n");
+            if let Some(min) = self.coverage_min {
+                config.push_str(&format!("min = {:?}\n", min));
+            }
+            for (glob, min) in &self.coverage_paths {
+                config.push_str(&format!(
+                    "\n[check.tests.coverage.path.{:?}]\nmin = {:?}\n",
+                    glob, min
+                ));
+            }
+            config.push('\n');
+        }
+        if !self.skipped.is_empty() {
+            config.push_str("# Not translated (no quench equivalent):\n");
+            for setting in &self.skipped {
+                config.push_str(&format!("#   {}\n", setting));
+            }
+        }
+        config
+    }
+}
+
+/// Parse a codecov percentage: `80%`, `"80"`, or `80`.
+fn percent(value: &serde_yaml::Value) -> Option<f64> {
+    match value {
+        serde_yaml::Value::Number(n) => n.as_f64(),
+        serde_yaml::Value::String(s) => s.trim().trim_end_matches('%').parse().ok(),
+        _ => None,
+    }
+}
+
+/// Turn a codecov path (a directory or glob) into a quench glob.
+fn path_glob(path: &str) -> String {
+    if path.contains('*') {
+        path.to_string()
+    } else {
+        format!("{}/**", path.trim_end_matches('/'))
+    }
+}
+
+fn toml_list(items: &[String]) -> String {
+    let quoted: Vec<String> = items.iter().map(|item| format!("{:?}", item)).collect();
+    format!("[{}]", quoted.join(", "))
+}
+
+#[cfg(test)]
+#[path = "migrate_tests.rs"]
+mod tests;
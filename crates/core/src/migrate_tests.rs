@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn import(temp: &TempDir, files: &[(&str, &str)]) -> Imported {
+    let mut imported = Imported::default();
+    for (name, content) in files {
+        fs::write(temp.path().join(name), content).unwrap();
+    }
+    for (name, _) in files {
+        if *name != "package.json" {
+            imported.import(temp.path(), name).unwrap();
+        }
+    }
+    imported
+}
+
+// =============================================================================
+// ESLINT TESTS
+// =============================================================================
+
+#[test]
+fn eslintrc_ignore_patterns_become_excludes() {
+    let temp = TempDir::new().unwrap();
+    let imported = import(
+        &temp,
+        &[(
+            ".eslintrc.json",
+            r#"{ "ignorePatterns": ["dist/", "*.min.js", "!keep.min.js"] }"#,
+        )],
+    );
+
+    assert_eq!(imported.exclude, vec!["dist/", "*.min.js"]);
+    assert_eq!(imported.linters, vec!["eslint"]);
+    assert_eq!(imported.skipped, vec!["un-ignore keep.min.js"]);
+}
+
+#[test]
+fn eslintrc_yaml_with_single_ignore_pattern() {
+    let temp = TempDir::new().unwrap();
+    let imported = import(&temp, &[(".eslintrc.yml", "ignorePatterns: build/\n")]);
+
+    assert_eq!(imported.exclude, vec!["build/"]);
+}
+
+#[test]
+fn eslintignore_skips_comments_and_blank_lines() {
+    let temp = TempDir::new().unwrap();
+    let imported = import(
+        &temp,
+        &[(".eslintignore", "# generated\nvendor/\n\ncoverage/\n")],
+    );
+
+    assert_eq!(imported.exclude, vec!["vendor/", "coverage/"]);
+    assert!(imported.linters.is_empty());
+}
+
+#[test]
+fn max_warnings_read_from_package_scripts() {
+    let temp = TempDir::new().unwrap();
+    let imported = import(
+        &temp,
+        &[
+            (
+                "package.json",
+                r#"{ "scripts": { "lint": "eslint . --max-warnings 12" } }"#,
+            ),
+            (".eslintrc", "{}"),
+        ],
+    );
+
+    assert_eq!(imported.max_warnings, Some(12));
+}
+
+#[test]
+fn flat_config_enables_eslint_without_ignores() {
+    let temp = TempDir::new().unwrap();
+    let imported = import(&temp, &[("eslint.config.js", "export default [];\n")]);
+
+    assert_eq!(imported.linters, vec!["eslint"]);
+    assert_eq!(imported.skipped, vec!["eslint.config.js: ignores"]);
+}
+
+// =============================================================================
+// CLIPPY TESTS
+// =============================================================================
+
+#[test]
+fn clippy_toml_enables_clippy_and_lists_thresholds() {
+    let temp = TempDir::new().unwrap();
+    let imported = import(&temp, &[("clippy.toml", "too-many-lines-threshold = 80\n")]);
+
+    assert_eq!(imported.linters, vec!["clippy"]);
+    assert_eq!(
+        imported.skipped,
+        vec!["clippy.toml: too-many-lines-threshold"]
+    );
+}
+
+// =============================================================================
+// CODECOV TESTS
+// =============================================================================
+
+#[test]
+fn codecov_project_target_becomes_coverage_min() {
+    let temp = TempDir::new().unwrap();
+    let imported = import(
+        &temp,
+        &[(
+            "codecov.yml",
+            "coverage:\n  status:\n    project:\n      default:\n        target: 80%\n        threshold: 1%\n",
+        )],
+    );
+
+    assert_eq!(imported.coverage_min, Some(80.0));
+    assert_eq!(imported.coverage_tolerance, Some(1.0));
+}
+
+#[test]
+fn codecov_paths_become_path_minimums() {
+    let temp = TempDir::new().unwrap();
+    let imported = import(
+        &temp,
+        &[(
+            "codecov.yml",
+            "coverage:\n  status:\n    project:\n      core:\n        target: 90\n        paths: [src/core/]\n",
+        )],
+    );
+
+    assert_eq!(imported.coverage_min, None);
+    assert_eq!(imported.coverage_paths.get("src/core/**"), Some(&90.0));
+}
+
+#[test]
+fn codecov_auto_target_and_ignore_are_skipped() {
+    let temp = TempDir::new().unwrap();
+    let imported = import(
+        &temp,
+        &[(
+            "codecov.yml",
+            "coverage:\n  status:\n    project:\n      default:\n        target: auto\nignore:\n  - tests/\n",
+        )],
+    );
+
+    assert_eq!(imported.coverage_min, None);
+    assert_eq!(
+        imported.skipped,
+        vec![
+            "codecov.yml: coverage.status.project.default.target auto",
+            "codecov.yml: ignore",
+        ]
+    );
+}
+
+// =============================================================================
+// ERROR TESTS
+// =============================================================================
+
+#[test]
+fn unsupported_file_is_an_error() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("tslint.json"), "{}").unwrap();
+
+    let err = Imported::default()
+        .import(temp.path(), "tslint.json")
+        .unwrap_err();
+    assert!(err.contains("unsupported config"));
+}
+
+#[test]
+fn missing_file_is_an_error() {
+    let temp = TempDir::new().unwrap();
+    let err = Imported::default()
+        .import(temp.path(), "codecov.yml")
+        .unwrap_err();
+    assert!(err.starts_with("codecov.yml: "));
+}
+
+// =============================================================================
+// APPLY TESTS
+// =============================================================================
+
+#[test]
+fn apply_without_imports_leaves_config_unchanged() {
+    let config = crate::profiles::default_template();
+    assert_eq!(Imported::default().apply(&config), config);
+}
+
+#[test]
+fn applied_config_parses() {
+    let temp = TempDir::new().unwrap();
+    let imported = import(
+        &temp,
+        &[
+            (
+                "package.json",
+                r#"{ "scripts": { "lint": "eslint --max-warnings=3 ." } }"#,
+            ),
+            (".eslintrc.json", r#"{ "ignorePatterns": ["dist/"] }"#),
+            ("clippy.toml", "msrv = \"1.80\"\n"),
+            (
+                "codecov.yml",
+                "coverage:\n  status:\n    project:\n      default:\n        target: 75%\n        threshold: 0.5\n      core:\n        target: 90%\n        paths: [\"src/core\"]\n",
+            ),
+        ],
+    );
+
+    let content = imported.apply(&crate::profiles::default_template());
+    assert!(content.contains("# Imported from .eslintrc.json, clippy.toml, codecov.yml"));
+    assert!(content.contains("#   clippy.toml: msrv"));
+
+    let config = crate::config::parse(&content, Path::new("quench.toml")).unwrap();
+    assert_eq!(config.project.exclude.patterns, vec!["dist/"]);
+    assert_eq!(config.check.lint.linters, vec!["eslint", "clippy"]);
+    assert!(config.check.lint.grandfather);
+    assert_eq!(config.check.tests.coverage.min, Some(75.0));
+    assert_eq!(config.check.tests.coverage.path["src/core/**"].min, 90.0);
+    assert_eq!(config.ratchet.coverage_tolerance_pct(), Some(0.5));
+}
+
+#[test]
+fn zero_max_warnings_keeps_lint_strict() {
+    let temp = TempDir::new().unwrap();
+    let imported = import(
+        &temp,
+        &[
+            (
+                "package.json",
+                r#"{ "scripts": { "lint": "eslint . --max-warnings 0" } }"#,
+            ),
+            (".eslintrc", "{}"),
+        ],
+    );
+
+    let content = imported.apply(&crate::profiles::default_template());
+    let config = crate::config::parse(&content, Path::new("quench.toml")).unwrap();
+    assert!(!config.check.lint.grandfather);
+}
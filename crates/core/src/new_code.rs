@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! New-code enforcement window.
+//!
+//! With `[new_code] since` set, only files first committed after that point
+//! get strict treatment. Violations in older (legacy) files are reported as
+//! warnings, the way quarantined directories are.
+//!
+//! When each file was first committed comes from git history, cached in
+//! `.quench/file-dates.bin` and extended with new commits on later runs.
+
+use std::collections::{BTreeMap, HashSet};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{Local, NaiveDate, TimeZone};
+use flate2::Compression;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use serde::{Deserialize, Serialize};
+
+use crate::check::{CheckResult, Violation};
+use crate::config::{GitConfig, NewCodeSince};
+use crate::diagnostics::{Code, Diagnostics};
+use crate::git;
+use crate::quarantine::warn_only;
+
+/// File dates cache name within `.quench/`.
+pub const FILE_DATES_NAME: &str = "file-dates.bin";
+
+/// File dates format version, bumped on incompatible changes.
+pub const FILE_DATES_VERSION: u32 = 1;
+
+/// When each file was first committed, as of a HEAD commit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileDates {
+    version: u32,
+    /// Last commit walked (None = no history walked yet).
+    head: Option<String>,
+    /// Root-relative path to first commit time (unix seconds).
+    added: BTreeMap<String, i64>,
+}
+
+impl Default for FileDates {
+    fn default() -> Self {
+        Self {
+            version: FILE_DATES_VERSION,
+            head: None,
+            added: BTreeMap::new(),
+        }
+    }
+}
+
+impl FileDates {
+    /// Path of the cache under a project root.
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(".quench").join(FILE_DATES_NAME)
+    }
+
+    /// Load the cache, or `None` if it's missing, unreadable, or outdated.
+    pub fn load(path: &Path) -> Option<Self> {
+        let bytes = std::fs::read(path).ok()?;
+        let mut raw = Vec::with_capacity(bytes.len() * 4);
+        DeflateDecoder::new(bytes.as_slice())
+            .read_to_end(&mut raw)
+            .ok()?;
+        let dates: Self = postcard::from_bytes(&raw).ok()?;
+        (dates.version == FILE_DATES_VERSION).then_some(dates)
+    }
+
+    /// Write the cache atomically via a temp file.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let bytes = postcard::to_allocvec(self)?;
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::fast());
+        encoder.write_all(&bytes)?;
+        crate::atomic::write(path, encoder.finish()?)?;
+        Ok(())
+    }
+
+    /// Load the cache and bring it up to date with HEAD.
+    ///
+    /// Only commits since the cached HEAD are walked. If that commit is gone
+    /// (e.g., after a force push), history is walked again from the start.
+    pub fn refresh(root: &Path, diagnostics: &Diagnostics) -> anyhow::Result<Self> {
+        let path = Self::path(root);
+        let mut dates = Self::load(&path).unwrap_or_default();
+        let head = match git::record_file_additions(root, dates.head.as_deref(), &mut dates.added) {
+            Ok(head) => head,
+            Err(_) if dates.head.is_some() => {
+                dates = Self::default();
+                git::record_file_additions(root, None, &mut dates.added)?
+            }
+            Err(e) => return Err(e),
+        };
+        if head != dates.head {
+            dates.head = head;
+            if let Err(e) = dates.save(&path) {
+                diagnostics.emit(
+                    Code::FileHistoryUnavailable,
+                    format!("failed to save file dates: {}", e),
+                );
+            }
+        }
+        Ok(dates)
+    }
+
+    /// First commit time of a root-relative path, if committed.
+    pub fn added(&self, path: &str) -> Option<i64> {
+        self.added.get(path).copied()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn insert(&mut self, path: &str, time: i64) {
+        self.added.insert(path.to_string(), time);
+    }
+}
+
+/// End of the new-code window's legacy period, in unix seconds.
+///
+/// Files first committed after the cutoff are new. `Baseline` uses when the
+/// baseline was first committed (file) or first recorded (git notes); it is
+/// `None` until a baseline exists.
+pub fn cutoff(
+    since: NewCodeSince,
+    root: &Path,
+    git_config: &GitConfig,
+    dates: &FileDates,
+) -> anyhow::Result<Option<i64>> {
+    match since {
+        NewCodeSince::Date(date) => Ok(Some(end_of_day(date))),
+        NewCodeSince::Baseline if git_config.uses_notes() => git::earliest_note_time(root),
+        NewCodeSince::Baseline => Ok(dates.added(git_config.baseline.trim_start_matches("./"))),
+    }
+}
+
+/// Last second of a local calendar day.
+fn end_of_day(date: NaiveDate) -> i64 {
+    let next = date.succ_opt().unwrap_or(date).and_hms_opt(0, 0, 0);
+    next.and_then(|midnight| Local.from_local_datetime(&midnight).earliest())
+        .map_or(i64::MAX, |midnight| midnight.timestamp() - 1)
+}
+
+/// Files first committed at or before the cutoff.
+#[derive(Debug, Default)]
+pub struct LegacyFiles {
+    files: HashSet<PathBuf>,
+}
+
+impl LegacyFiles {
+    /// Committed files no newer than `cutoff`. Uncommitted files are new.
+    pub fn new(dates: &FileDates, cutoff: i64) -> Self {
+        let files = dates
+            .added
+            .iter()
+            .filter(|&(_, &time)| time <= cutoff)
+            .map(|(path, _)| PathBuf::from(path))
+            .collect();
+        Self { files }
+    }
+
+    /// Whether a violation is in a legacy file.
+    pub fn covers(&self, root: &Path, violation: &Violation) -> bool {
+        let Some(file) = &violation.file else {
+            return false;
+        };
+        let relative = file.strip_prefix(root).unwrap_or(file);
+        self.files.contains(relative)
+    }
+
+    /// Downgrade failed checks to warnings when all their violations are in legacy files.
+    pub fn apply(&self, root: &Path, results: &mut [CheckResult]) {
+        if self.files.is_empty() {
+            return;
+        }
+        warn_only(results, |v| self.covers(root, v));
+    }
+}
+
+#[cfg(test)]
+#[path = "new_code_tests.rs"]
+mod tests;
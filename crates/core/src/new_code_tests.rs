@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+use tempfile::TempDir;
+
+fn dates(entries: &[(&str, i64)]) -> FileDates {
+    let mut dates = FileDates::default();
+    for (path, time) in entries {
+        dates.insert(path, *time);
+    }
+    dates
+}
+
+fn day(date: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap()
+}
+
+fn git_config(baseline: &str) -> GitConfig {
+    GitConfig {
+        baseline: baseline.to_string(),
+        ..GitConfig::default()
+    }
+}
+
+#[test]
+fn legacy_files_are_committed_by_cutoff() {
+    let legacy = LegacyFiles::new(&dates(&[("src/old.rs", 100), ("src/new.rs", 200)]), 100);
+    let root = Path::new("/repo");
+
+    assert!(legacy.covers(root, &Violation::file("src/old.rs", 1, "forbidden", "x")));
+    assert!(legacy.covers(
+        root,
+        &Violation::file("/repo/src/old.rs", 1, "forbidden", "x")
+    ));
+    assert!(!legacy.covers(root, &Violation::file("src/new.rs", 1, "forbidden", "x")));
+    // Uncommitted files and project-level violations are new
+    assert!(!legacy.covers(root, &Violation::file("src/draft.rs", 1, "forbidden", "x")));
+    assert!(!legacy.covers(root, &Violation::project("missing", "x")));
+}
+
+#[test]
+fn apply_downgrades_checks_with_only_legacy_violations() {
+    let legacy = LegacyFiles::new(&dates(&[("src/old.rs", 100), ("src/new.rs", 200)]), 100);
+    let mut results = vec![
+        CheckResult::failed(
+            "cloc",
+            vec![Violation::file("src/old.rs", 1, "file_too_large", "split")],
+        ),
+        CheckResult::failed(
+            "escapes",
+            vec![
+                Violation::file("src/old.rs", 1, "forbidden", "remove"),
+                Violation::file("src/new.rs", 2, "forbidden", "remove"),
+            ],
+        ),
+    ];
+
+    legacy.apply(Path::new("/repo"), &mut results);
+
+    assert!(results[0].passed);
+    assert_eq!(results[0].violations.len(), 1);
+    assert!(!results[1].passed);
+}
+
+#[test]
+fn date_cutoff_includes_the_whole_day() {
+    let cutoff = cutoff(
+        NewCodeSince::Date(day("2026-03-01")),
+        Path::new("/repo"),
+        &git_config(".quench/baseline.json"),
+        &FileDates::default(),
+    )
+    .unwrap()
+    .unwrap();
+
+    let local = |date: &str, time: &str| {
+        let naive = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .unwrap()
+            .and_time(chrono::NaiveTime::parse_from_str(time, "%H:%M:%S").unwrap());
+        Local.from_local_datetime(&naive).unwrap().timestamp()
+    };
+    assert!(local("2026-03-01", "23:59:59") <= cutoff);
+    assert!(local("2026-03-02", "00:00:00") > cutoff);
+}
+
+#[test]
+fn baseline_cutoff_is_when_baseline_file_was_committed() {
+    let dates = dates(&[(".quench/baseline.json", 500)]);
+    let root = Path::new("/repo");
+    let since = NewCodeSince::Baseline;
+
+    assert_eq!(
+        cutoff(since, root, &git_config(".quench/baseline.json"), &dates).unwrap(),
+        Some(500)
+    );
+    assert_eq!(
+        cutoff(since, root, &git_config("./.quench/baseline.json"), &dates).unwrap(),
+        Some(500)
+    );
+    // No baseline committed yet
+    assert_eq!(
+        cutoff(since, root, &git_config("quench-baseline.json"), &dates).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn file_dates_round_trip() {
+    let temp = TempDir::new().unwrap();
+    let path = FileDates::path(temp.path());
+    let dates = dates(&[("src/lib.rs", 100)]);
+
+    dates.save(&path).unwrap();
+
+    assert_eq!(FileDates::load(&path), Some(dates));
+}
+
+#[test]
+fn file_dates_load_ignores_missing_or_corrupt_cache() {
+    let temp = TempDir::new().unwrap();
+    let path = FileDates::path(temp.path());
+    assert_eq!(FileDates::load(&path), None);
+
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, b"not a cache").unwrap();
+    assert_eq!(FileDates::load(&path), None);
+}
@@ -4,14 +4,17 @@
 //! JSON output formatter.
 //!
 //! Produces output conforming to docs/specs/output.schema.json.
-//! JSON is buffered and written at the end (not streamed).
+//! JSON is buffered and written at the end; see `ndjson` for streaming.
 
+use std::collections::BTreeMap;
 use std::io::Write;
 
 use chrono::Utc;
 use serde::Serialize;
 
-use crate::check::{CheckOutput, CheckResult};
+use crate::check::{CheckOutput, CheckResult, CiMode};
+use crate::delta_owners::DeltaOwner;
+use crate::diagnostics::Diagnostic;
 use crate::ratchet::{MetricComparison, MetricImprovement, RatchetResult};
 use crate::timing::TimingInfo;
 
@@ -22,6 +25,9 @@ pub struct RatchetOutput {
     pub comparisons: Vec<MetricComparisonOutput>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub improvements: Vec<MetricImprovementOutput>,
+    /// Whether each package's own metrics held (workspaces only).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub packages: BTreeMap<String, bool>,
 }
 
 /// Individual metric comparison for JSON output.
@@ -40,6 +46,9 @@ pub struct MetricComparisonOutput {
     pub max_allowed: Option<f64>,
     pub passed: bool,
     pub improved: bool,
+    /// Branch commits responsible for a regression (CI only).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<DeltaOwner>,
 }
 
 /// Metric improvement for JSON output.
@@ -54,8 +63,16 @@ impl From<&RatchetResult> for RatchetOutput {
     fn from(result: &RatchetResult) -> Self {
         Self {
             passed: result.passed,
-            comparisons: result.comparisons.iter().map(Into::into).collect(),
+            comparisons: result
+                .comparisons
+                .iter()
+                .map(|comp| MetricComparisonOutput {
+                    owners: result.owners.get(&comp.name).cloned().unwrap_or_default(),
+                    ..comp.into()
+                })
+                .collect(),
             improvements: result.improvements.iter().map(Into::into).collect(),
+            packages: result.packages.clone(),
         }
     }
 }
@@ -87,6 +104,7 @@ impl From<&MetricComparison> for MetricComparisonOutput {
             },
             passed: comp.passed,
             improved: comp.improved,
+            owners: Vec::new(),
         }
     }
 }
@@ -111,7 +129,17 @@ pub struct JsonFormatter<W: Write> {
 struct CombinedOutput<'a> {
     timestamp: &'a str,
     passed: bool,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    tags: &'a BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "crate::check::is_zero")]
+    undecodable_files: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ci: Option<CiMode>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    interrupted: bool,
     checks: &'a [CheckResult],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    diagnostics: &'a [Diagnostic],
     #[serde(skip_serializing_if = "Option::is_none")]
     ratchet: Option<RatchetOutput>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -149,7 +177,12 @@ impl<W: Write> JsonFormatter<W> {
         let combined = CombinedOutput {
             timestamp: &output.timestamp,
             passed: output.passed && ratchet.as_ref().is_none_or(|r| r.passed),
+            tags: &output.tags,
+            undecodable_files: output.undecodable_files,
+            ci: output.ci,
+            interrupted: output.interrupted,
             checks: &output.checks,
+            diagnostics: &output.diagnostics,
             ratchet: ratchet.map(Into::into),
             timing,
         };
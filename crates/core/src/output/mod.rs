@@ -4,6 +4,7 @@
 //! Output formatting for check results.
 
 pub mod json;
+pub mod ndjson;
 pub mod text;
 
 /// Output formatting options.
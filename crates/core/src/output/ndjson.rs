@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Newline-delimited JSON event stream.
+//!
+//! Writes one JSON event per line, tagged by `event`: `run_started`, then
+//! `check_started`, `violation`, and `check_finished` for each check, and a
+//! final `summary` once the run (and ratchet) is done. Each line is flushed
+//! as it is written so consumers see events as they happen.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use super::json::RatchetOutput;
+use crate::check::{CheckOutput, CheckResult, CiMode, Violation};
+use crate::diagnostics::Diagnostic;
+use crate::ratchet::RatchetResult;
+use crate::timing::TimingInfo;
+
+/// NDJSON event stream formatter.
+pub struct NdjsonFormatter<W: Write> {
+    writer: W,
+}
+
+/// One line of output. Tagged `event` rather than `type`, which
+/// violations already use.
+#[derive(Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+enum Event<'a> {
+    RunStarted {
+        timestamp: &'a str,
+        checks: &'a [String],
+    },
+    CheckStarted {
+        check: &'a str,
+    },
+    Violation {
+        check: &'a str,
+        #[serde(flatten)]
+        violation: &'a Violation,
+    },
+    CheckFinished(CheckFinished<'a>),
+    Summary(Summary<'a>),
+}
+
+/// A check's result with its violations counted rather than listed.
+#[derive(Serialize)]
+struct CheckFinished<'a> {
+    check: &'a str,
+    passed: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    skipped: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stub: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    fixed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    violations: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fix_summary: Option<&'a JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metrics: Option<&'a JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    by_package: Option<&'a BTreeMap<String, JsonValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_ms: Option<u64>,
+}
+
+/// The final event: the run's outcome without the per-check results.
+#[derive(Serialize)]
+struct Summary<'a> {
+    timestamp: &'a str,
+    passed: bool,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    tags: &'a BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "crate::check::is_zero")]
+    undecodable_files: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ci: Option<CiMode>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    interrupted: bool,
+    checks: usize,
+    violations: usize,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    diagnostics: &'a [Diagnostic],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ratchet: Option<RatchetOutput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timing: Option<&'a TimingInfo>,
+}
+
+impl<W: Write> NdjsonFormatter<W> {
+    /// Create a new NDJSON formatter.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Announce the run and the checks it expects to report.
+    pub fn write_run_started(&mut self, timestamp: &str, checks: &[String]) -> std::io::Result<()> {
+        self.write_event(&Event::RunStarted { timestamp, checks })
+    }
+
+    /// Announce that a check began running.
+    pub fn write_check_started(&mut self, check: &str) -> std::io::Result<()> {
+        self.write_event(&Event::CheckStarted { check })
+    }
+
+    /// Write a check's violations, then its `check_finished` event.
+    pub fn write_check(&mut self, result: &CheckResult) -> std::io::Result<()> {
+        for violation in &result.violations {
+            self.write_event(&Event::Violation {
+                check: &result.name,
+                violation,
+            })?;
+        }
+        self.write_event(&Event::CheckFinished(CheckFinished {
+            check: &result.name,
+            passed: result.passed,
+            skipped: result.skipped,
+            stub: result.stub,
+            fixed: result.fixed,
+            error: result.error.as_deref(),
+            violations: result.violations.len(),
+            fix_summary: result.fix_summary.as_ref(),
+            metrics: result.metrics.as_ref(),
+            by_package: result.by_package.as_ref(),
+            duration_ms: result.duration_ms,
+        }))
+    }
+
+    /// Write the summary event with optional ratchet and timing.
+    pub fn write_summary(
+        &mut self,
+        output: &CheckOutput,
+        ratchet: Option<&RatchetResult>,
+        timing: Option<&TimingInfo>,
+    ) -> std::io::Result<()> {
+        self.write_event(&Event::Summary(Summary {
+            timestamp: &output.timestamp,
+            passed: output.passed && ratchet.as_ref().is_none_or(|r| r.passed),
+            tags: &output.tags,
+            undecodable_files: output.undecodable_files,
+            ci: output.ci,
+            interrupted: output.interrupted,
+            checks: output.checks.len(),
+            violations: output.total_violations(),
+            diagnostics: &output.diagnostics,
+            ratchet: ratchet.map(Into::into),
+            timing,
+        }))
+    }
+
+    fn write_event(&mut self, event: &Event) -> std::io::Result<()> {
+        serde_json::to_writer(&mut self.writer, event).map_err(std::io::Error::other)?;
+        writeln!(self.writer)?;
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+#[path = "ndjson_tests.rs"]
+mod tests;
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::NdjsonFormatter;
+use crate::check::{CheckResult, Violation};
+use crate::output::json::create_output;
+
+fn events(buffer: &[u8]) -> Vec<serde_json::Value> {
+    std::str::from_utf8(buffer)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn run_started_lists_checks() {
+    let mut buffer = Vec::new();
+    let mut formatter = NdjsonFormatter::new(&mut buffer);
+
+    let checks = ["cloc".to_string(), "escapes".to_string()];
+    formatter
+        .write_run_started("2026-01-21T10:30:00Z", &checks)
+        .unwrap();
+    formatter.write_check_started("cloc").unwrap();
+
+    let events = events(&buffer);
+    assert_eq!(events[0]["event"], "run_started");
+    assert_eq!(events[0]["checks"], serde_json::json!(["cloc", "escapes"]));
+    assert_eq!(events[1]["event"], "check_started");
+    assert_eq!(events[1]["check"], "cloc");
+}
+
+#[test]
+fn check_writes_violations_then_finished() {
+    let mut buffer = Vec::new();
+    let mut formatter = NdjsonFormatter::new(&mut buffer);
+
+    let violations = vec![
+        Violation::file_only("src/a.rs", "file_too_large", "Split it."),
+        Violation::file_only("src/b.rs", "file_too_large", "Split it."),
+    ];
+    formatter
+        .write_check(&CheckResult::failed("cloc", violations))
+        .unwrap();
+
+    let events = events(&buffer);
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[0]["event"], "violation");
+    assert_eq!(events[0]["check"], "cloc");
+    assert_eq!(events[0]["type"], "file_too_large");
+    assert_eq!(events[1]["file"], "src/b.rs");
+    assert_eq!(events[2]["event"], "check_finished");
+    assert_eq!(events[2]["passed"], false);
+    assert_eq!(events[2]["violations"], 2);
+}
+
+#[test]
+fn summary_counts_without_results() {
+    let mut buffer = Vec::new();
+    let mut formatter = NdjsonFormatter::new(&mut buffer);
+
+    let violation = Violation::file_only("src/big.rs", "file_too_large", "Split it.");
+    let output = create_output(vec![
+        CheckResult::failed("cloc", vec![violation]),
+        CheckResult::passed("escapes"),
+    ]);
+    formatter.write_summary(&output, None, None).unwrap();
+
+    let events = events(&buffer);
+    assert_eq!(events[0]["event"], "summary");
+    assert_eq!(events[0]["passed"], false);
+    assert_eq!(events[0]["checks"], 2);
+    assert_eq!(events[0]["violations"], 1);
+    assert!(events[0].get("ratchet").is_none());
+}
@@ -14,11 +14,16 @@ use std::io::Write;
 use termcolor::{ColorChoice, StandardStream, WriteColor};
 
 use super::FormatOptions;
-use crate::check::{CheckOutput, CheckResult, Violation};
+use crate::check::{CheckOutput, CheckResult, Field, Violation};
 use crate::color::scheme;
 use crate::config::CheckLevel;
+use crate::delta_owners::DeltaOwner;
+use crate::health::HealthScore;
 use crate::ratchet::RatchetResult;
 
+/// Responsible commits listed per regressed metric.
+const MAX_OWNERS: usize = 5;
+
 /// Text output formatter with color support.
 pub struct TextFormatter {
     stdout: StandardStream,
@@ -136,6 +141,40 @@ impl TextFormatter {
             }
         }
 
+        // Show whitespace normalization counts
+        if let Some(count) = summary.get("files_normalized").and_then(|n| n.as_u64()) {
+            writeln!(self.stdout, "  Normalized whitespace: {} files", count)?;
+        }
+
+        // Show fixer edits
+        if let (Some(violations), Some(files)) = (
+            summary.get("violations_fixed").and_then(|n| n.as_u64()),
+            summary.get("files_fixed").and_then(|f| f.as_array()),
+        ) {
+            writeln!(
+                self.stdout,
+                "  Fixed {} violations in {} files",
+                violations,
+                files.len()
+            )?;
+        }
+
+        // Show formatter runs
+        if let Some(formatted) = summary.get("formatted").and_then(|f| f.as_array()) {
+            for entry in formatted {
+                let formatter = entry
+                    .get("formatter")
+                    .and_then(|f| f.as_str())
+                    .unwrap_or("?");
+                let files = entry.get("files").and_then(|n| n.as_u64()).unwrap_or(0);
+                writeln!(
+                    self.stdout,
+                    "  Formatted {} files with {}",
+                    files, formatter
+                )?;
+            }
+        }
+
         // Show previews for dry-run
         if let Some(previews) = summary.get("previews").and_then(|p| p.as_array()) {
             for entry in previews {
@@ -233,6 +272,18 @@ impl TextFormatter {
             write!(self.stdout, ": ")?;
         }
 
+        // Grouped violations point at their primary instead of repeating advice
+        if let Some(primary) = v.primary() {
+            writeln!(
+                self.stdout,
+                "{} (see {}: {})",
+                self.format_violation_desc(v),
+                primary.check,
+                primary.violation_type
+            )?;
+            return Ok(());
+        }
+
         // Violation description (includes type-specific info)
         writeln!(self.stdout, "{}", self.format_violation_desc(v))?;
 
@@ -267,7 +318,7 @@ impl TextFormatter {
             "missing_file" => "missing required file".to_string(),
             "forbidden_file" => "forbidden file exists".to_string(),
             "out_of_sync" => {
-                if let Some(ref other) = v.other_file {
+                if let Some(other) = v.path(Field::OtherFile) {
                     format!("out of sync with {}", other.display())
                 } else {
                     "out of sync".to_string()
@@ -281,7 +332,11 @@ impl TextFormatter {
             "file_too_large" => {
                 // Agents check sets value/threshold but not lines - use "tokens:" prefix
                 // Cloc check sets lines/nonblank - use default format with "lines:" prefix
-                match (v.value, v.threshold, v.lines) {
+                match (
+                    v.int(Field::Value),
+                    v.int(Field::Threshold),
+                    v.int(Field::Lines),
+                ) {
                     (Some(val), Some(thresh), None) => {
                         format!("file too large (tokens: {} vs {})", val, thresh)
                     }
@@ -289,7 +344,7 @@ impl TextFormatter {
                 }
             }
             // Docs commit check - show commit hash and message
-            "missing_docs" => match (&v.commit, &v.message) {
+            "missing_docs" => match (&v.text(Field::Commit), &v.text(Field::Message)) {
                 (Some(hash), Some(msg)) => {
                     format!("feature commits without documentation: {}: {}", hash, msg)
                 }
@@ -297,8 +352,8 @@ impl TextFormatter {
             },
             // Build check - size violations with human-readable formatting
             "size_exceeded" => {
-                let target = v.target.as_deref().unwrap_or("binary");
-                match (v.value, v.threshold) {
+                let target = v.text(Field::Target).unwrap_or("binary");
+                match (v.int(Field::Value), v.int(Field::Threshold)) {
                     (Some(val), Some(thresh)) => {
                         format!(
                             "{}: {} (max: {})",
@@ -317,7 +372,7 @@ impl TextFormatter {
                 } else {
                     "hot build"
                 };
-                match (v.value, v.threshold) {
+                match (v.int(Field::Value), v.int(Field::Threshold)) {
                     (Some(val), Some(thresh)) => {
                         format!(
                             "{}: {:.1}s (max: {:.1}s)",
@@ -331,12 +386,12 @@ impl TextFormatter {
             }
             // Build check - missing target
             "missing_target" => {
-                let target = v.target.as_deref().unwrap_or("unknown");
+                let target = v.text(Field::Target).unwrap_or("unknown");
                 format!("target not found: {}", target)
             }
             // License check - human-readable descriptions
             "missing_header" => "missing license header".to_string(),
-            "wrong_license" => match (&v.expected, &v.found) {
+            "wrong_license" => match (&v.text(Field::Expected), &v.text(Field::Found)) {
                 (Some(expected), Some(found)) => {
                     format!(
                         "wrong license identifier (expected: {}, found: {})",
@@ -345,7 +400,7 @@ impl TextFormatter {
                 }
                 _ => "wrong license identifier".to_string(),
             },
-            "outdated_year" => match (&v.expected, &v.found) {
+            "outdated_year" => match (&v.text(Field::Expected), &v.text(Field::Found)) {
                 (Some(expected), Some(found)) => {
                     format!(
                         "outdated copyright year (expected: {}, found: {})",
@@ -354,13 +409,96 @@ impl TextFormatter {
                 }
                 _ => "outdated copyright year".to_string(),
             },
+            // Whitespace check - lines affected
+            "line_ending" | "trailing_whitespace" | "wrong_indent" => {
+                let kind = match (
+                    v.violation_type.as_str(),
+                    &v.text(Field::Expected),
+                    &v.text(Field::Found),
+                ) {
+                    ("trailing_whitespace", _, _) => "trailing whitespace".to_string(),
+                    ("line_ending", Some(expected), Some(found)) => {
+                        format!("{} line endings (expected: {})", found, expected)
+                    }
+                    (_, Some(expected), Some(found)) => {
+                        format!("indented with {} (expected: {})", found, expected)
+                    }
+                    _ => v.violation_type.replace('_', " "),
+                };
+                match v.int(Field::Value) {
+                    Some(lines) if lines > 1 => format!("{} on {} lines", kind, lines),
+                    _ => kind,
+                }
+            }
+            "missing_final_newline" => "no newline at end of file".to_string(),
+            // Snapshots check - sizes and churn with units
+            "snapshot_too_large" => match (v.int(Field::Value), v.int(Field::Threshold)) {
+                (Some(val), Some(thresh)) => format!(
+                    "snapshot too large: {} (max: {})",
+                    crate::file_size::human_size(val as u64, true),
+                    crate::file_size::human_size(thresh as u64, true)
+                ),
+                _ => "snapshot too large".to_string(),
+            },
+            "orphaned_snapshot" => match v.text(Field::Target) {
+                Some(test) => format!("orphaned snapshot (test not found: {})", test),
+                None => "orphaned snapshot".to_string(),
+            },
+            "snapshot_churn" => match (v.int(Field::Value), v.int(Field::Threshold)) {
+                (Some(val), Some(thresh)) => {
+                    format!("snapshot churn: {} lines changed (max: {})", val, thresh)
+                }
+                _ => "snapshot churn".to_string(),
+            },
+            "dead_code" => match v.text(Field::Target) {
+                Some(name) => format!("unused export: {}", name),
+                None => "unused export".to_string(),
+            },
+            "wildcard_version" | "unpinned_git" => {
+                let kind = match v.violation_type.as_str() {
+                    "wildcard_version" => "wildcard version",
+                    _ => "unpinned git dependency",
+                };
+                match v.text(Field::Target) {
+                    Some(name) => format!("{}: {}", kind, name),
+                    None => kind.to_string(),
+                }
+            }
+            "unused_feature" | "undeclared_feature" => {
+                let kind = match v.violation_type.as_str() {
+                    "unused_feature" => "unused feature",
+                    _ => "undeclared feature",
+                };
+                match v.text(Field::Target) {
+                    Some(name) => format!("{}: {}", kind, name),
+                    None => kind.to_string(),
+                }
+            }
+            "duplicate_dependency" => match (v.text(Field::Target), v.text(Field::Found)) {
+                (Some(name), Some(versions)) => {
+                    format!("duplicate dependency: {} ({})", name, versions)
+                }
+                _ => "duplicate dependency".to_string(),
+            },
+            "too_many_dependencies" => match (v.int(Field::Value), v.int(Field::Threshold)) {
+                (Some(val), Some(thresh)) => {
+                    format!("too many dependencies: {} (max: {})", val, thresh)
+                }
+                _ => "too many dependencies".to_string(),
+            },
+            "hotspot" => match (v.int(Field::Value), v.int(Field::Lines)) {
+                (Some(churn), Some(lines)) => {
+                    format!("hotspot ({} commits, {} lines)", churn, lines)
+                }
+                _ => self.format_default_desc(v),
+            },
             // Other checks - existing behavior
             _ => self.format_default_desc(v),
         }
     }
 
     fn format_default_desc(&self, v: &Violation) -> String {
-        let base = match (v.value, v.threshold) {
+        let base = match (v.int(Field::Value), v.int(Field::Threshold)) {
             (Some(val), Some(thresh)) => {
                 // Use labeled format for cloc line violations
                 let label = match v.violation_type.as_str() {
@@ -375,17 +513,43 @@ impl TextFormatter {
 
         // Append context field if present
         // Priority: pattern (for escapes), path (for broken_toc), target (for broken_link)
-        if let Some(ref pattern) = v.pattern {
+        if let Some(pattern) = v.text(Field::Pattern) {
             format!("{}: {}", base, pattern)
-        } else if let Some(ref path) = v.path {
+        } else if let Some(path) = v.text(Field::Path) {
             format!("{}: {}", base, path)
-        } else if let Some(ref target) = v.target {
+        } else if let Some(target) = v.text(Field::Target) {
             format!("{}: {}", base, target)
         } else {
             base
         }
     }
 
+    /// Write the branch commits behind a regressed metric.
+    fn write_owners(&mut self, owners: Option<&Vec<DeltaOwner>>) -> std::io::Result<()> {
+        let Some(owners) = owners else {
+            return Ok(());
+        };
+        for owner in owners.iter().take(MAX_OWNERS) {
+            let detail = match owner.added {
+                Some(added) => format!("+{} in {}", added, owner.files.join(", ")),
+                None => owner.summary.clone(),
+            };
+            writeln!(
+                self.stdout,
+                "    introduced by {} {} <{}>: {}",
+                owner.commit, owner.author, owner.email, detail
+            )?;
+        }
+        if owners.len() > MAX_OWNERS {
+            writeln!(
+                self.stdout,
+                "    ... and {} more",
+                owners.len() - MAX_OWNERS
+            )?;
+        }
+        Ok(())
+    }
+
     /// Write ratchet comparison results.
     ///
     /// The `check_level` parameter controls whether regressions are shown as WARN or FAIL.
@@ -432,6 +596,7 @@ impl TextFormatter {
                         comp.format_value(comp.baseline)
                     )?;
                     writeln!(self.stdout, "    {}", comp.advice())?;
+                    self.write_owners(result.owners.get(&comp.name))?;
                 }
             }
         } else {
@@ -486,9 +651,17 @@ impl TextFormatter {
         if !skipped.is_empty() {
             writeln!(self.stdout, "SKIP: {}", skipped.join(", "))?;
         }
+        if output.interrupted {
+            writeln!(self.stdout, "INTERRUPTED: partial results")?;
+        }
         Ok(())
     }
 
+    /// Write the health score line after the summary.
+    pub fn write_health(&mut self, health: &HealthScore) -> std::io::Result<()> {
+        writeln!(self.stdout, "Health: {}", health.summary())
+    }
+
     /// Write truncation message if applicable.
     pub fn write_truncation_message(&mut self, _total: usize) -> std::io::Result<()> {
         if let Some(limit) = self.options.limit
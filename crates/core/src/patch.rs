@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Fix patches for `quench check --fix --emit-patch`.
+//!
+//! Checks hand fixed file contents to [`CheckContext::write_fix`], which
+//! records them here instead of writing when a patch is being emitted.
+//! The patch renders as a unified diff that `git apply` accepts.
+//!
+//! [`CheckContext::write_fix`]: crate::check::CheckContext::write_fix
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use git2::{DiffOptions, Patch};
+
+/// A file's contents before and after its fixes.
+#[derive(Debug, Clone)]
+struct Edit {
+    /// Contents on disk, or None for a file the fix creates.
+    old: Option<String>,
+    new: String,
+}
+
+/// Fixed file contents collected from checks, by path.
+#[derive(Debug, Default)]
+pub struct FixPatch {
+    edits: Mutex<BTreeMap<PathBuf, Edit>>,
+}
+
+impl FixPatch {
+    /// Create an empty patch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `content` as the fixed contents of `path`. A later fix to the
+    /// same file replaces the earlier one, as a second write would.
+    pub fn record(&self, path: &Path, content: &str) {
+        let mut edits = self.edits.lock().unwrap_or_else(|e| e.into_inner());
+        edits
+            .entry(path.to_path_buf())
+            .or_insert_with(|| Edit {
+                old: std::fs::read_to_string(path).ok(),
+                new: String::new(),
+            })
+            .new = content.to_string();
+    }
+
+    /// Number of files with changes.
+    pub fn len(&self) -> usize {
+        let edits = self.edits.lock().unwrap_or_else(|e| e.into_inner());
+        edits
+            .values()
+            .filter(|edit| edit.old.as_deref() != Some(edit.new.as_str()))
+            .count()
+    }
+
+    /// Whether no file changes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Render the changes as a unified diff with paths relative to `root`.
+    pub fn render(&self, root: &Path) -> anyhow::Result<String> {
+        let edits = self.edits.lock().unwrap_or_else(|e| e.into_inner());
+        let mut out = String::new();
+        for (path, edit) in edits.iter() {
+            if edit.old.as_deref() == Some(edit.new.as_str()) {
+                continue;
+            }
+            let relative = path.strip_prefix(root).unwrap_or(path);
+            out.push_str(&diff_file(relative, edit)?);
+        }
+        Ok(out)
+    }
+}
+
+/// One file's unified diff, with a git header so `git apply` can create
+/// new files.
+fn diff_file(path: &Path, edit: &Edit) -> anyhow::Result<String> {
+    let name = path.to_string_lossy().replace('\\', "/");
+    let old = edit.old.as_deref().unwrap_or("");
+    let mut options = DiffOptions::new();
+    options.context_lines(3);
+    let mut patch = Patch::from_buffers(
+        old.as_bytes(),
+        Some(path),
+        edit.new.as_bytes(),
+        Some(path),
+        Some(&mut options),
+    )?;
+    let text = String::from_utf8_lossy(&patch.to_buf()?).into_owned();
+
+    // Hunks start at the first `@@`; the header is rewritten for new files
+    let hunks = text.find("\n@@").map_or("", |i| &text[i + 1..]);
+    let header = match edit.old {
+        Some(_) => format!("diff --git a/{name} b/{name}\n--- a/{name}\n+++ b/{name}\n"),
+        None => format!(
+            "diff --git a/{name} b/{name}\nnew file mode 100644\n--- /dev/null\n+++ b/{name}\n"
+        ),
+    };
+    Ok(format!("{}{}", header, hunks))
+}
+
+#[cfg(test)]
+#[path = "patch_tests.rs"]
+mod tests;
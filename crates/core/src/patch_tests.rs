@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used)]
+
+use super::*;
+use tempfile::TempDir;
+
+#[test]
+fn render_shows_changed_lines() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("src/lib.rs");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "fn a() {}   \nfn b() {}\n").unwrap();
+
+    let patch = FixPatch::new();
+    patch.record(&path, "fn a() {}\nfn b() {}\n");
+
+    assert_eq!(
+        patch.render(temp.path()).unwrap(),
+        "diff --git a/src/lib.rs b/src/lib.rs\n\
+         --- a/src/lib.rs\n\
+         +++ b/src/lib.rs\n\
+         @@ -1,2 +1,2 @@\n\
+         -fn a() {}   \n\
+         +fn a() {}\n \
+         fn b() {}\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "fn a() {}   \nfn b() {}\n",
+        "recording must not write the file"
+    );
+}
+
+#[test]
+fn render_marks_new_files() {
+    let temp = TempDir::new().unwrap();
+    let patch = FixPatch::new();
+    patch.record(&temp.path().join("CLAUDE.md"), "# Rules\n");
+
+    assert_eq!(
+        patch.render(temp.path()).unwrap(),
+        "diff --git a/CLAUDE.md b/CLAUDE.md\n\
+         new file mode 100644\n\
+         --- /dev/null\n\
+         +++ b/CLAUDE.md\n\
+         @@ -0,0 +1 @@\n\
+         +# Rules\n"
+    );
+}
+
+#[test]
+fn later_fix_replaces_earlier_one() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("a.txt");
+    std::fs::write(&path, "one\n").unwrap();
+
+    let patch = FixPatch::new();
+    patch.record(&path, "two\n");
+    patch.record(&path, "three\n");
+
+    let rendered = patch.render(temp.path()).unwrap();
+    assert!(rendered.contains("-one\n+three\n"), "{}", rendered);
+    assert_eq!(patch.len(), 1);
+}
+
+#[test]
+fn unchanged_files_are_left_out() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("a.txt");
+    std::fs::write(&path, "same\n").unwrap();
+
+    let patch = FixPatch::new();
+    patch.record(&path, "same\n");
+
+    assert!(patch.is_empty());
+    assert_eq!(patch.render(temp.path()).unwrap(), "");
+}
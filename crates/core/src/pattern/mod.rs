@@ -7,12 +7,16 @@
 //! - Single literal: memchr::memmem
 //! - Multiple literals: aho-corasick
 //! - Complex regex: regex crate
+//!
+//! Groups of patterns are prefiltered with a `RegexSet` (see [`set`]).
 
 pub mod matcher;
+pub mod set;
 
 pub(crate) use matcher::{CompiledPattern, PatternError};
 #[cfg(test)]
 pub(crate) use matcher::{LineMatch, byte_offset_to_line};
+pub(crate) use set::PatternSet;
 
 #[cfg(test)]
 #[path = "mod_tests.rs"]
@@ -66,3 +66,28 @@ fn find_with_lines_returns_correct_data() {
     assert_eq!(matches[0].line, 2);
     assert_eq!(matches[0].text, "unwrap");
 }
+
+#[test]
+fn pattern_set_reports_matching_ids_in_order() {
+    let set = PatternSet::new([(7, r"unsafe\s*\{"), (3, "unwrap"), (9, "TODO")]).unwrap();
+    let ids = set.matching("x.unwrap();\nunsafe { y }\n");
+    assert_eq!(ids, vec![7, 3]);
+}
+
+#[test]
+fn pattern_set_no_match_is_empty() {
+    let set = PatternSet::new([(0, "FIXME")]).unwrap();
+    assert!(set.matching("clean code").is_empty());
+}
+
+#[test]
+fn empty_pattern_set_matches_nothing() {
+    let set = PatternSet::new(std::iter::empty()).unwrap();
+    assert!(set.is_empty());
+    assert!(set.matching("anything").is_empty());
+}
+
+#[test]
+fn pattern_set_rejects_invalid_regex() {
+    assert!(PatternSet::new([(0, "(unclosed")]).is_err());
+}
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Batched pattern prefilter using a single `RegexSet` scan.
+//!
+//! Answers "which patterns match anywhere in this file?" in one pass, so
+//! per-pattern matchers only run for patterns that can produce results.
+
+use regex::RegexSet;
+
+use super::PatternError;
+
+/// Prefilter over a group of patterns.
+pub struct PatternSet {
+    set: RegexSet,
+    /// Caller-supplied ids, parallel to the patterns in `set`.
+    ids: Vec<usize>,
+}
+
+impl PatternSet {
+    /// Compile `(id, pattern)` pairs into a single set.
+    pub fn new<'a>(
+        patterns: impl IntoIterator<Item = (usize, &'a str)>,
+    ) -> Result<Self, PatternError> {
+        let (ids, sources): (Vec<usize>, Vec<&str>) = patterns.into_iter().unzip();
+        let set = RegexSet::new(sources)?;
+        Ok(Self { set, ids })
+    }
+
+    /// Number of patterns in the set.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether the set contains no patterns.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Ids of the patterns that match somewhere in `content`, in insertion order.
+    pub fn matching(&self, content: &str) -> Vec<usize> {
+        if self.ids.is_empty() {
+            return Vec::new();
+        }
+        self.set
+            .matches(content)
+            .into_iter()
+            .map(|i| self.ids[i])
+            .collect()
+    }
+}
@@ -9,7 +9,7 @@ use std::path::Path;
 
 use crate::adapter::javascript::PackageManager;
 use crate::adapter::python::PythonTooling;
-use crate::init::{CursorMarker, DetectedAgent};
+use crate::init::DetectedAgent;
 
 // =============================================================================
 // PROFILE DEFAULTS
@@ -35,6 +35,46 @@ required = [".cursorrules"]
 "#
 }
 
+/// Codex agent profile configuration for quench init.
+///
+/// Sets up [check.agents] with AGENTS.md as required.
+pub fn codex_profile_defaults() -> &'static str {
+    r#"[check.agents]
+check = "error"
+required = ["AGENTS.md"]
+"#
+}
+
+/// Gemini agent profile configuration for quench init.
+///
+/// Sets up [check.agents] with GEMINI.md as required.
+pub fn gemini_profile_defaults() -> &'static str {
+    r#"[check.agents]
+check = "error"
+required = ["GEMINI.md"]
+"#
+}
+
+/// Windsurf agent profile configuration for quench init.
+///
+/// Sets up [check.agents] with .windsurfrules as required.
+pub fn windsurf_profile_defaults() -> &'static str {
+    r#"[check.agents]
+check = "error"
+required = [".windsurfrules"]
+"#
+}
+
+/// Copilot agent profile configuration for quench init.
+///
+/// Sets up [check.agents] with .github/copilot-instructions.md as required.
+pub fn copilot_profile_defaults() -> &'static str {
+    r#"[check.agents]
+check = "error"
+required = [".github/copilot-instructions.md"]
+"#
+}
+
 /// JavaScript profile configuration for quench init.
 pub fn javascript_profile_defaults() -> String {
     r#"# See: `quench config javascript`
@@ -293,6 +333,10 @@ impl ProfileRegistry {
             "shell",
             "claude",
             "cursor",
+            "codex",
+            "gemini",
+            "windsurf",
+            "copilot",
         ]
     }
 
@@ -310,6 +354,10 @@ impl ProfileRegistry {
             "python" | "py" => Some(python_profile_defaults()),
             "claude" => Some(claude_profile_defaults().to_string()),
             "cursor" => Some(cursor_profile_defaults().to_string()),
+            "codex" => Some(codex_profile_defaults().to_string()),
+            "gemini" => Some(gemini_profile_defaults().to_string()),
+            "windsurf" => Some(windsurf_profile_defaults().to_string()),
+            "copilot" => Some(copilot_profile_defaults().to_string()),
             _ => None,
         }
     }
@@ -321,7 +369,20 @@ impl ProfileRegistry {
 
     /// Check if a profile is an agent profile (vs language profile).
     pub fn is_agent_profile(name: &str) -> bool {
-        matches!(name.to_lowercase().as_str(), "claude" | "cursor")
+        Self::agent_file(name).is_some()
+    }
+
+    /// File an agent profile requires, or None for other profiles.
+    pub fn agent_file(name: &str) -> Option<&'static str> {
+        match name.to_lowercase().as_str() {
+            "claude" => Some("CLAUDE.md"),
+            "cursor" => Some(".cursorrules"),
+            "codex" => Some("AGENTS.md"),
+            "gemini" => Some("GEMINI.md"),
+            "windsurf" => Some(".windsurfrules"),
+            "copilot" => Some(".github/copilot-instructions.md"),
+            _ => None,
+        }
     }
 
     /// Suggest similar profile names for typos.
@@ -361,14 +422,7 @@ pub fn agents_detected_section(agents: &[DetectedAgent]) -> String {
         return String::new();
     }
 
-    let required: Vec<&str> = agents
-        .iter()
-        .map(|a| match a {
-            DetectedAgent::Claude => "CLAUDE.md",
-            DetectedAgent::Cursor(CursorMarker::Cursorrules) => ".cursorrules",
-            DetectedAgent::Cursor(CursorMarker::CursorRulesDir) => ".cursor/rules/*.mdc",
-        })
-        .collect();
+    let required: Vec<&str> = agents.iter().map(DetectedAgent::file).collect();
 
     format!(
         r#"[check.agents]
@@ -507,14 +561,7 @@ pub fn agents_section(agents: &[DetectedAgent]) -> String {
         return "# See: `quench config agents`\n[check.agents]\ncheck = \"error\"\n".to_string();
     }
 
-    let required: Vec<&str> = agents
-        .iter()
-        .map(|a| match a {
-            DetectedAgent::Claude => "CLAUDE.md",
-            DetectedAgent::Cursor(CursorMarker::Cursorrules) => ".cursorrules",
-            DetectedAgent::Cursor(CursorMarker::CursorRulesDir) => ".cursor/rules/*.mdc",
-        })
-        .collect();
+    let required: Vec<&str> = agents.iter().map(DetectedAgent::file).collect();
 
     format!(
         "# See: `quench config agents`\n[check.agents]\ncheck = \"error\"\nrequired = {:?}\n",
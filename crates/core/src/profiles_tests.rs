@@ -369,3 +369,21 @@ fn ruby_detected_section_is_valid_toml() {
 fn python_detected_section_is_valid_toml() {
     assert_detected_section_valid(python_detected_section(), "python");
 }
+
+#[test]
+fn agent_profiles_require_their_files() {
+    for (profile, file) in [
+        ("claude", "CLAUDE.md"),
+        ("cursor", ".cursorrules"),
+        ("codex", "AGENTS.md"),
+        ("gemini", "GEMINI.md"),
+        ("windsurf", ".windsurfrules"),
+        ("copilot", ".github/copilot-instructions.md"),
+    ] {
+        assert!(ProfileRegistry::is_agent_profile(profile));
+        assert_eq!(ProfileRegistry::agent_file(profile), Some(file));
+        let content = ProfileRegistry::get(profile).unwrap();
+        assert!(content.contains(&format!("required = [\"{}\"]", file)));
+    }
+    assert_eq!(ProfileRegistry::agent_file("rust"), None);
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Quarantined directories.
+//!
+//! Violations in a quarantined directory are reported as warnings until the
+//! quarantine's `until` date, then count as errors again.
+
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+
+use crate::check::{CheckResult, Violation};
+use crate::config::QuarantineConfig;
+
+/// Active and expired quarantines as of a given day.
+#[derive(Debug, Default)]
+pub struct Quarantine<'a> {
+    active: Vec<PathBuf>,
+    expired: Vec<&'a QuarantineConfig>,
+}
+
+impl<'a> Quarantine<'a> {
+    /// Split configured quarantines into active and expired as of `today`.
+    pub fn new(entries: &'a [QuarantineConfig], today: NaiveDate) -> Self {
+        let mut quarantine = Self::default();
+        for entry in entries {
+            if entry.until.is_some_and(|until| today > until) {
+                quarantine.expired.push(entry);
+            } else {
+                quarantine
+                    .active
+                    .push(PathBuf::from(entry.path.trim_end_matches('/')));
+            }
+        }
+        quarantine
+    }
+
+    /// Quarantines past their `until` date.
+    pub fn expired(&self) -> &[&'a QuarantineConfig] {
+        &self.expired
+    }
+
+    /// Whether a violation is in an active quarantine.
+    pub fn covers(&self, root: &Path, violation: &Violation) -> bool {
+        let Some(file) = &violation.file else {
+            return false;
+        };
+        let relative = file.strip_prefix(root).unwrap_or(file);
+        self.active.iter().any(|dir| relative.starts_with(dir))
+    }
+
+    /// Downgrade failed checks to warnings when all their violations are quarantined.
+    pub fn apply(&self, root: &Path, results: &mut [CheckResult]) {
+        if self.active.is_empty() {
+            return;
+        }
+        warn_only(results, |v| self.covers(root, v));
+    }
+}
+
+/// Downgrade failed checks to warnings when `covers` holds for all their violations.
+pub fn warn_only(results: &mut [CheckResult], covers: impl Fn(&Violation) -> bool) {
+    for result in results {
+        if result.passed || result.skipped || result.error.is_some() {
+            continue;
+        }
+        if !result.violations.is_empty() && result.violations.iter().all(&covers) {
+            result.passed = true;
+        }
+    }
+}
+
+/// Warning for an expired quarantine.
+pub fn expiry_warning(entry: &QuarantineConfig) -> String {
+    let mut message = format!("quarantine for {} expired", entry.path);
+    if let Some(until) = entry.until {
+        message.push_str(&format!(" on {}", until));
+    }
+    message.push_str("; its violations are errors again");
+    if let Some(reason) = &entry.reason {
+        message.push_str(&format!(" ({})", reason));
+    }
+    message
+}
+
+#[cfg(test)]
+#[path = "quarantine_tests.rs"]
+mod tests;
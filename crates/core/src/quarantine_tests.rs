@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn entry(path: &str, until: Option<&str>) -> QuarantineConfig {
+    QuarantineConfig {
+        path: path.to_string(),
+        until: until.map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").unwrap()),
+        reason: None,
+    }
+}
+
+fn day(date: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap()
+}
+
+#[test]
+fn entries_expire_after_until_date() {
+    let entries = vec![
+        entry("spikes/", Some("2026-03-01")),
+        entry("vendor/proto", None),
+    ];
+
+    assert!(
+        Quarantine::new(&entries, day("2026-03-01"))
+            .expired()
+            .is_empty()
+    );
+    let later = Quarantine::new(&entries, day("2026-03-02"));
+    assert_eq!(later.expired().len(), 1);
+    assert_eq!(later.expired()[0].path, "spikes/");
+}
+
+#[test]
+fn apply_downgrades_fully_quarantined_checks() {
+    let entries = vec![entry("spikes", None)];
+    let quarantine = Quarantine::new(&entries, day("2026-01-01"));
+    let root = Path::new("/repo");
+    let mut results = vec![
+        CheckResult::failed(
+            "cloc",
+            vec![Violation::file(
+                "spikes/big.rs",
+                1,
+                "file_too_large",
+                "split",
+            )],
+        ),
+        CheckResult::failed(
+            "escapes",
+            vec![
+                Violation::file("/repo/spikes/a.rs", 1, "forbidden", "remove"),
+                Violation::file("src/b.rs", 2, "forbidden", "remove"),
+            ],
+        ),
+    ];
+
+    quarantine.apply(root, &mut results);
+
+    assert!(results[0].passed);
+    assert_eq!(results[0].violations.len(), 1);
+    assert!(!results[1].passed);
+}
+
+#[test]
+fn expired_quarantine_keeps_failures() {
+    let entries = vec![entry("spikes", Some("2026-01-01"))];
+    let quarantine = Quarantine::new(&entries, day("2026-02-01"));
+    let mut results = vec![CheckResult::failed(
+        "cloc",
+        vec![Violation::file(
+            "spikes/big.rs",
+            1,
+            "file_too_large",
+            "split",
+        )],
+    )];
+
+    quarantine.apply(Path::new("/repo"), &mut results);
+
+    assert!(!results[0].passed);
+}
+
+#[test]
+fn commit_violations_are_never_quarantined() {
+    let entries = vec![entry("spikes", None)];
+    let quarantine = Quarantine::new(&entries, day("2026-01-01"));
+    let violation = Violation::commit_violation("abc", "bad", "invalid_format", "fix");
+
+    assert!(!quarantine.covers(Path::new("/repo"), &violation));
+}
+
+#[test]
+fn expiry_warning_includes_date_and_reason() {
+    let mut config = entry("spikes", Some("2026-01-01"));
+    config.reason = Some("parser prototype".to_string());
+
+    assert_eq!(
+        expiry_warning(&config),
+        "quarantine for spikes expired on 2026-01-01; its violations are errors again (parser prototype)"
+    );
+}
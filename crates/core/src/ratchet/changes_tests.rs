@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn metrics(json: &str) -> BaselineMetrics {
+    serde_json::from_str(json).unwrap()
+}
+
+fn change(name: &str, old_value: Option<f64>, new_value: Option<f64>) -> BaselineChange {
+    BaselineChange {
+        name: name.to_string(),
+        old_value,
+        new_value,
+    }
+}
+
+#[test]
+fn unchanged_metrics_are_not_listed() {
+    let old = metrics(r#"{"escapes": {"source": {"unwrap": 4}}}"#);
+    assert!(baseline_changes(&old, &old.clone()).is_empty());
+}
+
+#[test]
+fn lists_moved_added_and_dropped_metrics_by_name() {
+    let old = metrics(r#"{"escapes": {"source": {"unwrap": 4, "todo": 2}}}"#);
+    let new = metrics(
+        r#"{"escapes": {"source": {"unwrap": 3}}, "build_time": {"cold": 12.5, "hot": 2.0}}"#,
+    );
+
+    assert_eq!(
+        baseline_changes(&old, &new),
+        vec![
+            change("build_time.cold", None, Some(12.5)),
+            change("build_time.hot", None, Some(2.0)),
+            change("escapes.source.todo", Some(2.0), None),
+            change("escapes.source.unwrap", Some(4.0), Some(3.0)),
+        ]
+    );
+}
+
+#[test]
+fn delta_is_signed_and_formatted_by_metric() {
+    assert_eq!(
+        change("escapes.source.unwrap", Some(4.0), Some(3.0)).format_delta(),
+        Some("-1".to_string())
+    );
+    assert_eq!(
+        change("coverage.total", Some(0.75), Some(0.8)).format_delta(),
+        Some("+5.0%".to_string())
+    );
+    assert_eq!(
+        change("build_time.cold", Some(10.0), Some(12.5)).format_delta(),
+        Some("+2.5s".to_string())
+    );
+    assert_eq!(
+        change("build_time.cold", None, Some(12.5)).format_delta(),
+        None
+    );
+}
+
+#[test]
+fn diff_shows_old_and_new_values_with_delta() {
+    let changes = vec![
+        change("escapes.source.todo", Some(2.0), None),
+        change("escapes.source.unwrap", Some(4.0), Some(3.0)),
+        change("test_time.total", None, Some(1.5)),
+    ];
+
+    assert_eq!(
+        format_baseline_diff(".quench/baseline.json", &changes),
+        "  --- .quench/baseline.json (current)\n\
+         \x20 +++ .quench/baseline.json (after --fix)\n\
+         \x20 -escapes.source.todo: 2\n\
+         \x20 -escapes.source.unwrap: 4\n\
+         \x20 +escapes.source.unwrap: 3 (-1)\n\
+         \x20 +test_time.total: 1.5s\n"
+    );
+}
@@ -0,0 +1,538 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Per-metric comparison against the baseline.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::anomaly::Stats;
+use crate::baseline::BaselineMetrics;
+use crate::config::RatchetConfig;
+
+use super::timing::compare_timing;
+use super::{CurrentMetrics, MetricComparison, MetricImprovement, RatchetResult};
+
+/// Compare current metrics against baseline using ratchet config.
+pub fn compare(
+    current: &CurrentMetrics,
+    baseline: &BaselineMetrics,
+    config: &RatchetConfig,
+) -> RatchetResult {
+    let mut comparisons = Vec::new();
+    let mut improvements = Vec::new();
+    let mut passed = true;
+
+    // Compare escapes if enabled
+    if config.escapes
+        && let (Some(curr), Some(base)) = (&current.escapes, &baseline.escapes)
+    {
+        // Density needs line counts on both sides (older baselines lack them)
+        let lines = match (curr.source_lines, base.source_lines) {
+            (Some(curr_lines), Some(base_lines)) if config.escapes_density => {
+                Some((curr_lines, base_lines))
+            }
+            _ => None,
+        };
+        for (pattern, &curr_count) in &curr.source {
+            let base_count = base.source.get(pattern).copied().unwrap_or(0);
+
+            // Density ratchets down like counts, compared at two decimals
+            if let Some((curr_lines, base_lines)) = lines {
+                let name = format!("escapes.{}.density", pattern);
+                let curr_density = escape_density(curr_count, curr_lines);
+                let base_density = escape_density(base_count, base_lines);
+                let comparison = MetricComparison {
+                    name: name.clone(),
+                    current: curr_density,
+                    baseline: base_density,
+                    tolerance: 0.0,
+                    threshold: base_density,
+                    passed: curr_density <= base_density,
+                    improved: curr_density < base_density,
+                };
+                if !comparison.passed {
+                    passed = false;
+                }
+                if comparison.improved {
+                    improvements.push(MetricImprovement {
+                        name,
+                        old_value: base_density,
+                        new_value: curr_density,
+                    });
+                }
+                comparisons.push(comparison);
+                continue;
+            }
+
+            // Escapes ratchet down (lower is better)
+            let comparison = MetricComparison {
+                name: format!("escapes.{}", pattern),
+                current: curr_count as f64,
+                baseline: base_count as f64,
+                tolerance: 0.0,               // No tolerance for counts
+                threshold: base_count as f64, // Can't exceed baseline
+                passed: curr_count <= base_count,
+                improved: curr_count < base_count,
+            };
+
+            if !comparison.passed {
+                passed = false;
+            }
+
+            if comparison.improved {
+                improvements.push(MetricImprovement {
+                    name: format!("escapes.{}", pattern),
+                    old_value: base_count as f64,
+                    new_value: curr_count as f64,
+                });
+            }
+
+            comparisons.push(comparison);
+        }
+    }
+
+    // Unsafe ratio: share of Rust source lines in unsafe blocks ratchets down
+    if config.unsafe_ratio
+        && let (Some(curr), Some(base)) = (&current.unsafe_code, &baseline.unsafe_code)
+    {
+        let comparison = MetricComparison {
+            name: "unsafe.ratio".to_string(),
+            current: curr.total.ratio,
+            baseline: base.total.ratio,
+            tolerance: 0.0,
+            threshold: base.total.ratio,
+            passed: curr.total.ratio <= base.total.ratio,
+            improved: curr.total.ratio < base.total.ratio,
+        };
+        if !comparison.passed {
+            passed = false;
+        }
+        if comparison.improved {
+            improvements.push(MetricImprovement {
+                name: comparison.name.clone(),
+                old_value: base.total.ratio,
+                new_value: curr.total.ratio,
+            });
+        }
+        comparisons.push(comparison);
+    }
+
+    // Lint: per-rule counts ratchet down. Rules missing on either side
+    // count as zero, so a new rule regresses and a cleared one improves.
+    if config.lint
+        && let (Some(curr), Some(base)) = (&current.lint, &baseline.lint)
+    {
+        let rules: BTreeSet<&String> = curr.keys().chain(base.keys()).collect();
+        for rule in rules {
+            let curr_count = curr.get(rule).copied().unwrap_or(0);
+            let base_count = base.get(rule).copied().unwrap_or(0);
+            let comparison = MetricComparison {
+                name: format!("lint.{}", rule),
+                current: curr_count as f64,
+                baseline: base_count as f64,
+                tolerance: 0.0,
+                threshold: base_count as f64,
+                passed: curr_count <= base_count,
+                improved: curr_count < base_count,
+            };
+
+            if !comparison.passed {
+                passed = false;
+            }
+
+            if comparison.improved {
+                improvements.push(MetricImprovement {
+                    name: comparison.name.clone(),
+                    old_value: base_count as f64,
+                    new_value: curr_count as f64,
+                });
+            }
+
+            comparisons.push(comparison);
+        }
+    }
+
+    // Deadcode: per-language counts ratchet down, like lint rules
+    if config.deadcode
+        && let (Some(curr), Some(base)) = (&current.deadcode, &baseline.deadcode)
+    {
+        let langs: BTreeSet<&String> = curr.keys().chain(base.keys()).collect();
+        for lang in langs {
+            let curr_count = curr.get(lang).copied().unwrap_or(0);
+            let base_count = base.get(lang).copied().unwrap_or(0);
+            let comparison = MetricComparison {
+                name: format!("deadcode.{}", lang),
+                current: curr_count as f64,
+                baseline: base_count as f64,
+                tolerance: 0.0,
+                threshold: base_count as f64,
+                passed: curr_count <= base_count,
+                improved: curr_count < base_count,
+            };
+
+            if !comparison.passed {
+                passed = false;
+            }
+
+            if comparison.improved {
+                improvements.push(MetricImprovement {
+                    name: comparison.name.clone(),
+                    old_value: base_count as f64,
+                    new_value: curr_count as f64,
+                });
+            }
+
+            comparisons.push(comparison);
+        }
+    }
+
+    // Deps: per-ecosystem resolved counts ratchet down
+    if config.deps
+        && let (Some(curr), Some(base)) = (&current.deps, &baseline.deps)
+    {
+        let ecosystems: BTreeSet<&String> = curr.keys().chain(base.keys()).collect();
+        for ecosystem in ecosystems {
+            let curr_count = curr.get(ecosystem).copied().unwrap_or(0);
+            let base_count = base.get(ecosystem).copied().unwrap_or(0);
+            let comparison = MetricComparison {
+                name: format!("deps.{}", ecosystem),
+                current: curr_count as f64,
+                baseline: base_count as f64,
+                tolerance: 0.0,
+                threshold: base_count as f64,
+                passed: curr_count <= base_count,
+                improved: curr_count < base_count,
+            };
+
+            if !comparison.passed {
+                passed = false;
+            }
+
+            if comparison.improved {
+                improvements.push(MetricImprovement {
+                    name: comparison.name.clone(),
+                    old_value: base_count as f64,
+                    new_value: curr_count as f64,
+                });
+            }
+
+            comparisons.push(comparison);
+        }
+    }
+
+    // Coverage: ratchets UP (higher is better)
+    if config.coverage
+        && let (Some(curr), Some(base)) = (&current.coverage, &baseline.coverage)
+    {
+        let tolerance = config.coverage_tolerance_pct().unwrap_or(0.0);
+        let min_allowed = base.total - tolerance;
+
+        let comparison = MetricComparison {
+            name: "coverage.total".to_string(),
+            current: curr.total,
+            baseline: base.total,
+            tolerance,
+            threshold: min_allowed, // min allowed (floor)
+            passed: curr.total >= min_allowed,
+            improved: curr.total > base.total,
+        };
+
+        if !comparison.passed {
+            passed = false;
+        }
+        if comparison.improved {
+            improvements.push(MetricImprovement {
+                name: "coverage.total".to_string(),
+                old_value: base.total,
+                new_value: curr.total,
+            });
+        }
+        comparisons.push(comparison);
+    }
+
+    // Binary size: ratchets down (smaller is better)
+    if config.binary_size
+        && let (Some(curr), Some(base)) = (&current.binary_size, &baseline.binary_size)
+    {
+        let tolerance = config.binary_size_tolerance_bytes().unwrap_or(0);
+        for (target, &curr_size) in curr {
+            let base_size = base.get(target).copied().unwrap_or(0);
+            let max_allowed = base_size.saturating_add(tolerance);
+
+            let comparison = MetricComparison {
+                name: format!("binary_size.{}", target),
+                current: curr_size as f64,
+                baseline: base_size as f64,
+                tolerance: tolerance as f64,
+                threshold: max_allowed as f64,
+                passed: curr_size <= max_allowed,
+                improved: curr_size < base_size,
+            };
+
+            if !comparison.passed {
+                passed = false;
+            }
+            if comparison.improved {
+                improvements.push(MetricImprovement {
+                    name: comparison.name.clone(),
+                    old_value: base_size as f64,
+                    new_value: curr_size as f64,
+                });
+            }
+            comparisons.push(comparison);
+        }
+    }
+
+    // Build time cold: ratchets down (faster is better)
+    if config.build_time_cold {
+        compare_timing(
+            "build_time.cold",
+            current.build_time.as_ref().and_then(|t| t.cold),
+            baseline.build_time.as_ref().map(|t| t.cold),
+            config.build_time_tolerance_duration(),
+            &mut comparisons,
+            &mut improvements,
+            &mut passed,
+        );
+    }
+
+    // Build time hot: ratchets down
+    if config.build_time_hot {
+        compare_timing(
+            "build_time.hot",
+            current.build_time.as_ref().and_then(|t| t.hot),
+            baseline.build_time.as_ref().map(|t| t.hot),
+            config.build_time_tolerance_duration(),
+            &mut comparisons,
+            &mut improvements,
+            &mut passed,
+        );
+    }
+
+    // Test time total: ratchets down
+    if config.test_time_total {
+        compare_timing(
+            "test_time.total",
+            current.test_time.as_ref().map(|t| t.total),
+            baseline.test_time.as_ref().map(|t| t.total),
+            config.test_time_tolerance_duration(),
+            &mut comparisons,
+            &mut improvements,
+            &mut passed,
+        );
+    }
+
+    // Test time avg: ratchets down
+    if config.test_time_avg {
+        compare_timing(
+            "test_time.avg",
+            current.test_time.as_ref().map(|t| t.avg),
+            baseline.test_time.as_ref().map(|t| t.avg),
+            config.test_time_tolerance_duration(),
+            &mut comparisons,
+            &mut improvements,
+            &mut passed,
+        );
+    }
+
+    // Test time max: ratchets down
+    if config.test_time_max {
+        compare_timing(
+            "test_time.max",
+            current.test_time.as_ref().map(|t| t.max),
+            baseline.test_time.as_ref().map(|t| t.max),
+            config.test_time_tolerance_duration(),
+            &mut comparisons,
+            &mut improvements,
+            &mut passed,
+        );
+    }
+
+    // Per-suite test time: flag outliers against recorded history
+    if config.test_time_anomaly
+        && let Some(history) = &baseline.test_time_history
+    {
+        for (suite, curr) in &current.suite_times {
+            let Some(stats) = history.get(suite).and_then(|h| Stats::from_history(h)) else {
+                continue;
+            };
+            let curr_secs = curr.as_secs_f64();
+            let tolerance = stats.allowance(config.test_time_anomaly_sigma);
+            let comparison = MetricComparison {
+                name: format!("test_time.suite.{}", suite),
+                current: curr_secs,
+                baseline: stats.mean,
+                tolerance,
+                threshold: stats.mean + tolerance,
+                passed: curr_secs <= stats.mean + tolerance,
+                // History is appended on every update; nothing to ratchet
+                improved: false,
+            };
+            if !comparison.passed {
+                passed = false;
+            }
+            comparisons.push(comparison);
+        }
+    }
+
+    // Per-package escapes, unsafe ratio, and coverage, so a regression confined to one
+    // package fails even when other packages make up for it in the totals
+    let mut packages = BTreeMap::new();
+    for (package, comparison) in compare_packages(current, baseline, config) {
+        let held = packages.entry(package).or_insert(true);
+        *held &= comparison.passed;
+        if !comparison.passed {
+            passed = false;
+        }
+        if comparison.improved {
+            improvements.push(MetricImprovement {
+                name: comparison.name.clone(),
+                old_value: comparison.baseline,
+                new_value: comparison.current,
+            });
+        }
+        comparisons.push(comparison);
+    }
+
+    RatchetResult {
+        passed,
+        comparisons,
+        improvements,
+        owners: BTreeMap::new(),
+        packages,
+    }
+}
+
+/// Compare each package's escapes, unsafe ratio, and coverage against its
+/// baseline.
+///
+/// Package settings in `[ratchet.package.<name>]` override the global
+/// `escapes` and `coverage` switches. Packages without a baseline entry
+/// are skipped; the totals still cover them.
+fn compare_packages(
+    current: &CurrentMetrics,
+    baseline: &BaselineMetrics,
+    config: &RatchetConfig,
+) -> Vec<(String, MetricComparison)> {
+    let mut out = Vec::new();
+
+    // Escapes: ratchet down per pattern, as density when both sides have lines
+    if let (Some(curr), Some(base_by_pkg)) = (
+        &current.escapes,
+        baseline
+            .escapes
+            .as_ref()
+            .and_then(|e| e.by_package.as_ref()),
+    ) {
+        for (pkg, curr_pkg) in &curr.by_package {
+            if !config.is_escapes_ratcheted(pkg) {
+                continue;
+            }
+            let Some(base_pkg) = base_by_pkg.get(pkg) else {
+                continue;
+            };
+            let density =
+                config.escapes_density && curr_pkg.source_lines > 0 && base_pkg.source_lines > 0;
+            for (pattern, &curr_count) in &curr_pkg.source {
+                let base_count = base_pkg.source.get(pattern).copied().unwrap_or(0);
+                let (name, current, baseline) = if density {
+                    (
+                        format!("escapes.{}/{}.density", pkg, pattern),
+                        escape_density(curr_count, curr_pkg.source_lines),
+                        escape_density(base_count, base_pkg.source_lines),
+                    )
+                } else {
+                    (
+                        format!("escapes.{}/{}", pkg, pattern),
+                        curr_count as f64,
+                        base_count as f64,
+                    )
+                };
+                out.push((
+                    pkg.clone(),
+                    MetricComparison {
+                        name,
+                        current,
+                        baseline,
+                        tolerance: 0.0,
+                        threshold: baseline,
+                        passed: current <= baseline,
+                        improved: current < baseline,
+                    },
+                ));
+            }
+        }
+    }
+
+    // Unsafe ratio: ratchets down per package
+    if config.unsafe_ratio
+        && let (Some(curr_by_pkg), Some(base_by_pkg)) = (
+            current
+                .unsafe_code
+                .as_ref()
+                .and_then(|u| u.by_package.as_ref()),
+            baseline
+                .unsafe_code
+                .as_ref()
+                .and_then(|u| u.by_package.as_ref()),
+        )
+    {
+        for (pkg, curr_pkg) in curr_by_pkg {
+            let Some(base_pkg) = base_by_pkg.get(pkg) else {
+                continue;
+            };
+            out.push((
+                pkg.clone(),
+                MetricComparison {
+                    name: format!("unsafe.{}.ratio", pkg),
+                    current: curr_pkg.ratio,
+                    baseline: base_pkg.ratio,
+                    tolerance: 0.0,
+                    threshold: base_pkg.ratio,
+                    passed: curr_pkg.ratio <= base_pkg.ratio,
+                    improved: curr_pkg.ratio < base_pkg.ratio,
+                },
+            ));
+        }
+    }
+
+    // Coverage: ratchets up; a package missing from the run counts as 0%
+    if let (Some(curr), Some(base_by_pkg)) = (
+        &current.coverage,
+        baseline
+            .coverage
+            .as_ref()
+            .and_then(|c| c.by_package.as_ref()),
+    ) {
+        let tolerance = config.coverage_tolerance_pct().unwrap_or(0.0);
+        for (pkg, &base_pct) in base_by_pkg {
+            if !config.is_coverage_ratcheted(pkg) {
+                continue;
+            }
+            let curr_pct = curr.by_package.get(pkg).copied().unwrap_or(0.0);
+            let min_allowed = base_pct - tolerance;
+            out.push((
+                pkg.clone(),
+                MetricComparison {
+                    name: format!("coverage.{}", pkg),
+                    current: curr_pct,
+                    baseline: base_pct,
+                    tolerance,
+                    threshold: min_allowed,
+                    passed: curr_pct >= min_allowed,
+                    improved: curr_pct > base_pct,
+                },
+            ));
+        }
+    }
+
+    out
+}
+
+/// Escapes per 1000 source lines, rounded to two decimals.
+fn escape_density(count: usize, source_lines: usize) -> f64 {
+    if source_lines == 0 {
+        return 0.0;
+    }
+    (count as f64 * 1000.0 / source_lines as f64 * 100.0).round() / 100.0
+}
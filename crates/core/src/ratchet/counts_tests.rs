@@ -0,0 +1,390 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::BTreeMap;
+
+use serde_json::json;
+
+use super::*;
+use crate::baseline::{AgentsMetrics as BaselineAgents, Baseline, BaselineMetrics};
+use crate::check::{CheckOutput, CheckResult};
+use crate::config::{CheckLevel, RatchetConfig};
+
+// =============================================================================
+// LINT RATCHET TESTS
+// =============================================================================
+
+fn make_lint_config() -> RatchetConfig {
+    RatchetConfig {
+        check: CheckLevel::Error,
+        lint: true,
+        ..Default::default()
+    }
+}
+
+fn lint_counts(counts: &[(&str, usize)]) -> Option<BTreeMap<String, usize>> {
+    Some(counts.iter().map(|(k, v)| (k.to_string(), *v)).collect())
+}
+
+#[test]
+fn extract_lint_rules_from_lint_check() {
+    let metrics_json = json!({
+        "diagnostics": { "clippy": 3 },
+        "rules": { "clippy/needless_return": 2, "clippy/let_and_return": 1 }
+    });
+    let check_result = CheckResult::passed("lint").with_metrics(metrics_json);
+    let output = CheckOutput::new("2026-01-20T00:00:00Z".to_string(), vec![check_result]);
+
+    let current = CurrentMetrics::from_output(&output);
+
+    assert_eq!(
+        current.lint,
+        lint_counts(&[("clippy/let_and_return", 1), ("clippy/needless_return", 2)])
+    );
+}
+
+#[test]
+fn lint_new_diagnostic_for_existing_rule_fails() {
+    let baseline = BaselineMetrics {
+        lint: lint_counts(&[("clippy/needless_return", 3)]),
+        ..Default::default()
+    };
+    let current = CurrentMetrics {
+        lint: lint_counts(&[("clippy/needless_return", 4)]),
+        ..Default::default()
+    };
+
+    let result = compare(&current, &baseline, &make_lint_config());
+
+    assert!(!result.passed);
+    assert_eq!(result.comparisons[0].name, "lint.clippy/needless_return");
+    assert_eq!(
+        result.comparisons[0].advice(),
+        "Fix new linter diagnostics or update baseline with --fix."
+    );
+}
+
+#[test]
+fn lint_new_rule_fails_and_cleared_rule_improves() {
+    let baseline = BaselineMetrics {
+        lint: lint_counts(&[("ruff/F401", 2)]),
+        ..Default::default()
+    };
+    let current = CurrentMetrics {
+        lint: lint_counts(&[("ruff/E711", 1)]),
+        ..Default::default()
+    };
+
+    let result = compare(&current, &baseline, &make_lint_config());
+
+    assert!(!result.passed);
+    let names: Vec<_> = result.comparisons.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["lint.ruff/E711", "lint.ruff/F401"]);
+    assert_eq!(result.improvements.len(), 1);
+    assert_eq!(result.improvements[0].name, "lint.ruff/F401");
+}
+
+#[test]
+fn lint_ratchet_disabled_skips_comparison() {
+    let baseline = BaselineMetrics {
+        lint: lint_counts(&[("ruff/F401", 0)]),
+        ..Default::default()
+    };
+    let current = CurrentMetrics {
+        lint: lint_counts(&[("ruff/F401", 5)]),
+        ..Default::default()
+    };
+    let config = RatchetConfig {
+        check: CheckLevel::Error,
+        ..Default::default()
+    };
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(result.passed);
+    assert!(result.comparisons.is_empty());
+}
+
+#[test]
+fn update_baseline_replaces_lint_counts() {
+    let mut baseline = Baseline::new();
+    baseline.metrics.lint = lint_counts(&[("ruff/F401", 2), ("ruff/E711", 1)]);
+    let current = CurrentMetrics {
+        lint: lint_counts(&[("ruff/F401", 1)]),
+        ..Default::default()
+    };
+
+    update_baseline(&mut baseline, &current);
+
+    assert_eq!(baseline.metrics.lint, lint_counts(&[("ruff/F401", 1)]));
+}
+
+// =============================================================================
+// DEADCODE RATCHET TESTS
+// =============================================================================
+
+fn make_deadcode_config() -> RatchetConfig {
+    RatchetConfig {
+        check: CheckLevel::Error,
+        deadcode: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn extract_deadcode_counts_from_deadcode_check() {
+    let metrics_json = json!({
+        "exports": 12,
+        "dead": { "rust": 2, "go": 0 }
+    });
+    let check_result = CheckResult::passed("deadcode").with_metrics(metrics_json);
+    let output = CheckOutput::new("2026-01-20T00:00:00Z".to_string(), vec![check_result]);
+
+    let current = CurrentMetrics::from_output(&output);
+
+    assert_eq!(current.deadcode, lint_counts(&[("go", 0), ("rust", 2)]));
+}
+
+#[test]
+fn deadcode_new_unused_export_fails() {
+    let baseline = BaselineMetrics {
+        deadcode: lint_counts(&[("rust", 1)]),
+        ..Default::default()
+    };
+    let current = CurrentMetrics {
+        deadcode: lint_counts(&[("rust", 2)]),
+        ..Default::default()
+    };
+
+    let result = compare(&current, &baseline, &make_deadcode_config());
+
+    assert!(!result.passed);
+    assert_eq!(result.comparisons[0].name, "deadcode.rust");
+    assert_eq!(
+        result.comparisons[0].advice(),
+        "Remove the newly unused exports or update baseline with --fix."
+    );
+}
+
+#[test]
+fn deadcode_removed_export_improves() {
+    let baseline = BaselineMetrics {
+        deadcode: lint_counts(&[("javascript", 3)]),
+        ..Default::default()
+    };
+    let current = CurrentMetrics {
+        deadcode: lint_counts(&[("javascript", 1)]),
+        ..Default::default()
+    };
+
+    let result = compare(&current, &baseline, &make_deadcode_config());
+
+    assert!(result.passed);
+    assert_eq!(result.improvements.len(), 1);
+    assert_eq!(result.improvements[0].name, "deadcode.javascript");
+}
+
+#[test]
+fn update_baseline_replaces_deadcode_counts() {
+    let mut baseline = Baseline::new();
+    baseline.metrics.deadcode = lint_counts(&[("go", 2), ("rust", 1)]);
+    let current = CurrentMetrics {
+        deadcode: lint_counts(&[("rust", 0)]),
+        ..Default::default()
+    };
+
+    update_baseline(&mut baseline, &current);
+
+    assert_eq!(baseline.metrics.deadcode, lint_counts(&[("rust", 0)]));
+}
+
+// =============================================================================
+// DEPS RATCHET TESTS
+// =============================================================================
+
+fn make_deps_config() -> RatchetConfig {
+    RatchetConfig {
+        check: CheckLevel::Error,
+        deps: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn extract_deps_counts_from_deps_check() {
+    let metrics_json = json!({
+        "manifests": 3,
+        "direct": { "cargo": 12, "npm": 4 },
+        "total": { "cargo": 140, "npm": 310 },
+        "duplicates": 2
+    });
+    let check_result = CheckResult::passed("deps").with_metrics(metrics_json);
+    let output = CheckOutput::new("2026-01-20T00:00:00Z".to_string(), vec![check_result]);
+
+    let current = CurrentMetrics::from_output(&output);
+
+    assert_eq!(current.deps, lint_counts(&[("cargo", 140), ("npm", 310)]));
+}
+
+#[test]
+fn deps_new_dependency_fails() {
+    let baseline = BaselineMetrics {
+        deps: lint_counts(&[("cargo", 140)]),
+        ..Default::default()
+    };
+    let current = CurrentMetrics {
+        deps: lint_counts(&[("cargo", 143)]),
+        ..Default::default()
+    };
+
+    let result = compare(&current, &baseline, &make_deps_config());
+
+    assert!(!result.passed);
+    assert_eq!(result.comparisons[0].name, "deps.cargo");
+    assert_eq!(
+        result.comparisons[0].advice(),
+        "Remove the new dependencies or update baseline with --fix."
+    );
+}
+
+#[test]
+fn deps_removed_dependency_improves() {
+    let baseline = BaselineMetrics {
+        deps: lint_counts(&[("npm", 310)]),
+        ..Default::default()
+    };
+    let current = CurrentMetrics {
+        deps: lint_counts(&[("npm", 300)]),
+        ..Default::default()
+    };
+
+    let result = compare(&current, &baseline, &make_deps_config());
+
+    assert!(result.passed);
+    assert_eq!(result.improvements.len(), 1);
+    assert_eq!(result.improvements[0].name, "deps.npm");
+}
+
+#[test]
+fn deps_ratchet_disabled_skips_comparison() {
+    let baseline = BaselineMetrics {
+        deps: lint_counts(&[("cargo", 1)]),
+        ..Default::default()
+    };
+    let current = CurrentMetrics {
+        deps: lint_counts(&[("cargo", 5)]),
+        ..Default::default()
+    };
+    let config = RatchetConfig {
+        deps: false,
+        ..make_deps_config()
+    };
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(result.passed);
+    assert!(result.comparisons.is_empty());
+}
+
+#[test]
+fn update_baseline_replaces_deps_counts() {
+    let mut baseline = Baseline::new();
+    baseline.metrics.deps = lint_counts(&[("cargo", 140), ("go", 8)]);
+    let current = CurrentMetrics {
+        deps: lint_counts(&[("cargo", 138)]),
+        ..Default::default()
+    };
+
+    update_baseline(&mut baseline, &current);
+
+    assert_eq!(baseline.metrics.deps, lint_counts(&[("cargo", 138)]));
+}
+
+// =============================================================================
+// TEST RATIO TESTS
+// =============================================================================
+
+#[test]
+fn extract_test_ratio_from_cloc_check() {
+    let cloc = |ratio: f64| {
+        json!({
+            "source_lines": 100, "source_files": 2, "source_tokens": 900,
+            "test_lines": 50, "test_files": 1, "test_tokens": 400,
+            "ratio": ratio
+        })
+    };
+    let check_result = CheckResult::passed("cloc")
+        .with_metrics(cloc(0.5))
+        .with_by_package(BTreeMap::from([
+            ("cli".to_string(), cloc(0.2)),
+            ("core".to_string(), cloc(0.8)),
+        ]));
+    let output = CheckOutput::new("2026-01-20T00:00:00Z".to_string(), vec![check_result]);
+
+    let current = CurrentMetrics::from_output(&output);
+
+    let ratio = current.test_ratio.unwrap();
+    assert_eq!(ratio.total, 0.5);
+    assert_eq!(
+        ratio.by_package,
+        Some(BTreeMap::from([
+            ("cli".to_string(), 0.2),
+            ("core".to_string(), 0.8)
+        ]))
+    );
+}
+
+#[test]
+fn update_baseline_replaces_test_ratio() {
+    let mut baseline = Baseline::new();
+    baseline.metrics.test_ratio = Some(TestRatioMetrics {
+        total: 0.4,
+        by_package: Some(BTreeMap::from([("old".to_string(), 0.1)])),
+    });
+    let current = CurrentMetrics {
+        test_ratio: Some(TestRatioMetrics {
+            total: 0.6,
+            by_package: None,
+        }),
+        ..Default::default()
+    };
+
+    update_baseline(&mut baseline, &current);
+
+    assert_eq!(baseline.metrics.test_ratio, current.test_ratio);
+}
+
+// =============================================================================
+// AGENT TOKEN TESTS
+// =============================================================================
+
+#[test]
+fn update_baseline_records_agent_tokens() {
+    let metrics_json = json!({
+        "files_found": ["CLAUDE.md", "AGENTS.md"],
+        "files_missing": [],
+        "in_sync": true,
+        "total_tokens": 1500,
+        "tokens": { "AGENTS.md": 500, "CLAUDE.md": 1000 }
+    });
+    let check_result = CheckResult::passed("agents").with_metrics(metrics_json);
+    let output = CheckOutput::new("2026-01-20T00:00:00Z".to_string(), vec![check_result]);
+    let current = CurrentMetrics::from_output(&output);
+
+    let mut baseline = Baseline::new();
+    baseline.metrics.agents = Some(BaselineAgents {
+        total_tokens: 900,
+        files: BTreeMap::from([("GEMINI.md".to_string(), 900)]),
+    });
+    update_baseline(&mut baseline, &current);
+
+    assert_eq!(baseline.metrics.value("agents.total_tokens"), Some(1500.0));
+    let agents = baseline.metrics.agents.unwrap();
+    assert_eq!(
+        agents.files,
+        BTreeMap::from([
+            ("AGENTS.md".to_string(), 500),
+            ("CLAUDE.md".to_string(), 1000)
+        ])
+    );
+}
@@ -0,0 +1,500 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Ratchet enforcement and metrics comparison.
+
+mod compare;
+mod timing;
+mod update;
+
+pub use compare::compare;
+pub use timing::compare_self_time;
+pub use update::update_baseline;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+
+use crate::baseline::{BaselineMetrics, PackageEscapesMetrics, TestRatioMetrics, UnsafeMetrics};
+use crate::check::CheckOutput;
+use crate::delta_owners::DeltaOwner;
+use crate::metrics::{
+    self, Agents, Build, BuildTime, CheckMetrics, Cloc, Deadcode, Deps, Escapes, Lint, TestSuites,
+    Tests,
+};
+
+/// Current metrics extracted from check results.
+#[derive(Debug, Clone, Default)]
+pub struct CurrentMetrics {
+    pub escapes: Option<EscapesCurrent>,
+    /// Unsafe Rust counts, overall and per package.
+    pub unsafe_code: Option<UnsafeMetrics>,
+    /// Linter diagnostic counts by `<linter>/<rule>`.
+    pub lint: Option<BTreeMap<String, usize>>,
+    /// Unused exports by language.
+    pub deadcode: Option<BTreeMap<String, usize>>,
+    /// Resolved dependencies by ecosystem.
+    pub deps: Option<BTreeMap<String, usize>>,
+    /// Estimated tokens per root-scope agent file.
+    pub agent_tokens: Option<BTreeMap<String, usize>>,
+    /// Test lines per source line, overall and per package.
+    pub test_ratio: Option<TestRatioMetrics>,
+    pub coverage: Option<CoverageCurrent>,
+    pub binary_size: Option<BTreeMap<String, u64>>,
+    pub build_time: Option<BuildTimeCurrent>,
+    pub test_time: Option<TestTimeCurrent>,
+    /// Total time per test suite, for anomaly detection.
+    pub suite_times: BTreeMap<String, Duration>,
+    /// Slowest tests per suite as `(name, milliseconds)`, slowest first.
+    pub slow_tests: BTreeMap<String, Vec<(String, u64)>>,
+    /// Quench's own run time. Measured by the caller, not part of check output.
+    pub self_time: Option<SelfTimeCurrent>,
+}
+
+/// Current coverage metrics extracted from tests output.
+#[derive(Debug, Clone)]
+pub struct CoverageCurrent {
+    pub total: f64,
+    pub by_package: BTreeMap<String, f64>,
+}
+
+/// Current escape metrics extracted from check output.
+#[derive(Debug, Clone)]
+pub struct EscapesCurrent {
+    pub source: BTreeMap<String, usize>,
+    pub test: BTreeMap<String, usize>,
+    /// Non-blank source lines scanned.
+    pub source_lines: Option<usize>,
+    /// Per-package source counts (workspaces only).
+    pub by_package: BTreeMap<String, PackageEscapesMetrics>,
+}
+
+/// Current build time metrics.
+#[derive(Debug, Clone)]
+pub struct BuildTimeCurrent {
+    pub cold: Option<Duration>,
+    pub hot: Option<Duration>,
+}
+
+/// Current test time metrics.
+#[derive(Debug, Clone)]
+pub struct TestTimeCurrent {
+    pub total: Duration,
+    pub avg: Duration,
+    pub max: Duration,
+}
+
+/// Quench's own run time for one mode.
+#[derive(Debug, Clone, Copy)]
+pub struct SelfTimeCurrent {
+    /// Whether this was a CI run (`--ci`).
+    pub ci: bool,
+    /// Wall time from startup through the ratchet phase.
+    pub elapsed: Duration,
+}
+
+impl SelfTimeCurrent {
+    /// Runs faster than this never warn; their timing is mostly noise.
+    pub const MIN_WARN: Duration = Duration::from_secs(1);
+
+    /// `quench.self_time_ci` or `quench.self_time_fast`.
+    pub fn metric_name(&self) -> &'static str {
+        if self.ci {
+            "quench.self_time_ci"
+        } else {
+            "quench.self_time_fast"
+        }
+    }
+
+    /// The baseline value for this run's mode.
+    fn baseline(&self, metrics: &BaselineMetrics) -> Option<f64> {
+        let recorded = metrics.quench.as_ref()?;
+        if self.ci {
+            recorded.self_time_ci
+        } else {
+            recorded.self_time_fast
+        }
+    }
+}
+
+impl CurrentMetrics {
+    /// Extract metrics from check output.
+    pub fn from_output(output: &CheckOutput) -> Self {
+        let mut metrics = Self::default();
+        let check = |name: &str| output.checks.iter().find(|c| c.name == name);
+
+        // Find escapes check result and extract metrics
+        if let Some(escapes_result) = check(Escapes::CHECK)
+            && let Some(escapes) = metrics::parse::<Escapes>(escapes_result)
+        {
+            let mut current = EscapesCurrent {
+                source: escapes.source,
+                test: escapes.test,
+                source_lines: Some(escapes.source_lines),
+                by_package: BTreeMap::new(),
+            };
+            if let Some(packages) = &escapes_result.by_package {
+                current.by_package = extract_package_escapes(packages);
+            }
+            metrics.escapes = Some(current);
+
+            // Unsafe Rust, reported only when Rust files were scanned
+            if let Some(total) = escapes.unsafe_code {
+                let by_package = escapes_result.by_package.as_ref().map(|packages| {
+                    packages
+                        .iter()
+                        .filter_map(|(name, json)| {
+                            let package = metrics::parse_json::<Escapes>(json)?;
+                            Some((name.clone(), package.unsafe_code?))
+                        })
+                        .collect()
+                });
+                metrics.unsafe_code = Some(UnsafeMetrics { total, by_package });
+            }
+        }
+
+        // Extract linter diagnostic counts
+        if let Some(lint) = check(Lint::CHECK).and_then(metrics::parse::<Lint>) {
+            metrics.lint = Some(lint.rules);
+        }
+
+        // Extract unused export counts
+        if let Some(deadcode) = check(Deadcode::CHECK).and_then(metrics::parse::<Deadcode>) {
+            metrics.deadcode = Some(deadcode.dead);
+        }
+
+        // Extract resolved dependency counts
+        if let Some(deps) = check(Deps::CHECK).and_then(metrics::parse::<Deps>) {
+            metrics.deps = Some(deps.total);
+        }
+
+        // Extract test-to-source ratios
+        if let Some(cloc_result) = check(Cloc::CHECK)
+            && let Some(cloc) = metrics::parse::<Cloc>(cloc_result)
+        {
+            let by_package = cloc_result.by_package.as_ref().map(|packages| {
+                packages
+                    .iter()
+                    .filter_map(|(name, json)| {
+                        Some((name.clone(), metrics::parse_json::<Cloc>(json)?.ratio))
+                    })
+                    .collect()
+            });
+            metrics.test_ratio = Some(TestRatioMetrics {
+                total: cloc.ratio,
+                by_package,
+            });
+        }
+
+        // Extract agent file token counts
+        if let Some(agents) = check(Agents::CHECK).and_then(metrics::parse::<Agents>) {
+            metrics.agent_tokens = Some(agents.tokens);
+        }
+
+        // Extract build metrics (binary size and build time)
+        if let Some(build) = check(Build::CHECK).and_then(metrics::parse::<Build>) {
+            metrics.binary_size = (!build.size.is_empty()).then_some(build.size);
+            metrics.build_time = extract_build_time(&build.time);
+        }
+
+        // Extract test time and coverage metrics
+        if let Some(Tests::Suites(tests)) = check(Tests::CHECK).and_then(metrics::parse::<Tests>) {
+            metrics.test_time = Some(extract_test_time(&tests));
+            metrics.suite_times = extract_suite_times(&tests);
+            metrics.slow_tests = extract_slow_tests(&tests);
+            metrics.coverage = extract_coverage_metrics(tests);
+        }
+
+        metrics
+    }
+}
+
+/// Extract per-package source counts from the escapes check's `by_package`.
+fn extract_package_escapes(
+    packages: &BTreeMap<String, serde_json::Value>,
+) -> BTreeMap<String, PackageEscapesMetrics> {
+    packages
+        .iter()
+        .filter_map(|(name, json)| {
+            let package = metrics::parse_json::<Escapes>(json)?;
+            Some((
+                name.clone(),
+                PackageEscapesMetrics {
+                    source: package.source,
+                    source_lines: package.source_lines,
+                },
+            ))
+        })
+        .collect()
+}
+
+fn extract_build_time(time: &BuildTime) -> Option<BuildTimeCurrent> {
+    let cold = time.cold.map(Duration::from_secs_f64);
+    let hot = time.hot.map(Duration::from_secs_f64);
+
+    if cold.is_none() && hot.is_none() {
+        None
+    } else {
+        Some(BuildTimeCurrent { cold, hot })
+    }
+}
+
+/// Extract test time metrics from the suites' aggregates.
+///
+/// Note: `avg` and `max` default to zero when no test reported timing.
+/// This allows ratcheting on just `total` without requiring all fields.
+/// However, be aware that missing fields will appear as "improved to 0".
+fn extract_test_time(tests: &TestSuites) -> TestTimeCurrent {
+    TestTimeCurrent {
+        total: Duration::from_millis(tests.total_ms),
+        avg: Duration::from_millis(tests.avg_ms.unwrap_or(0)),
+        max: Duration::from_millis(tests.max_ms.unwrap_or(0)),
+    }
+}
+
+/// Extract per-suite total times.
+///
+/// Skipped, failed, and untimed suites are left out so they don't skew
+/// the history.
+fn extract_suite_times(tests: &TestSuites) -> BTreeMap<String, Duration> {
+    tests
+        .suites
+        .iter()
+        .filter(|s| s.passed && s.total_ms > 0)
+        .map(|s| (s.name.clone(), Duration::from_millis(s.total_ms)))
+        .collect()
+}
+
+/// Extract each suite's slowest tests.
+fn extract_slow_tests(tests: &TestSuites) -> BTreeMap<String, Vec<(String, u64)>> {
+    tests
+        .suites
+        .iter()
+        .filter(|s| !s.slowest.is_empty())
+        .map(|s| {
+            let slowest = s.slowest.iter().map(|t| (t.name.clone(), t.ms)).collect();
+            (s.name.clone(), slowest)
+        })
+        .collect()
+}
+
+/// Extract coverage metrics from the suites' aggregates.
+///
+/// Coverage is stored as a fraction (0.0 to 1.0) in the baseline.
+/// The metrics have coverage keyed by language (e.g., "rust": 0.82).
+fn extract_coverage_metrics(tests: TestSuites) -> Option<CoverageCurrent> {
+    // Extract total from first language (typically "rust" or "typescript")
+    let total = tests.coverage.values().next().copied()?;
+
+    Some(CoverageCurrent {
+        total,
+        by_package: tests.coverage_by_package,
+    })
+}
+
+/// Result of ratchet comparison.
+#[derive(Debug, Clone)]
+pub struct RatchetResult {
+    /// Whether all ratcheted metrics pass.
+    pub passed: bool,
+
+    /// Individual metric comparison results.
+    pub comparisons: Vec<MetricComparison>,
+
+    /// Metrics that improved (for baseline update).
+    pub improvements: Vec<MetricImprovement>,
+
+    /// Branch commits behind each regressed metric, by metric name.
+    ///
+    /// Empty unless filled in by [`crate::delta_owners::attribute`].
+    pub owners: BTreeMap<String, Vec<DeltaOwner>>,
+
+    /// Whether each package's own metrics held, by package name.
+    ///
+    /// Only packages with a per-package comparison appear (workspaces).
+    pub packages: BTreeMap<String, bool>,
+}
+
+/// Comparison of a single metric.
+#[derive(Debug, Clone)]
+pub struct MetricComparison {
+    pub name: String,
+    pub current: f64,
+    pub baseline: f64,
+    pub tolerance: f64,
+    /// The allowed threshold (baseline ± tolerance).
+    /// For "lower is better" metrics: max allowed = baseline + tolerance.
+    /// For "higher is better" metrics: min allowed = baseline - tolerance.
+    pub threshold: f64,
+    pub passed: bool,
+    pub improved: bool,
+}
+
+impl MetricComparison {
+    /// Format the value based on metric type.
+    pub fn format_value(&self, value: f64) -> String {
+        format_metric_value(&self.name, value)
+    }
+
+    /// Get contextual advice for this metric failure.
+    pub fn advice(&self) -> &'static str {
+        if self.name.starts_with("escapes.") {
+            match self.name.as_str() {
+                n if n.contains("unsafe") => "Reduce unsafe blocks or add // SAFETY: comments.",
+                n if n.contains("unwrap") => "Replace .unwrap() with proper error handling.",
+                n if n.contains("todo") || n.contains("fixme") => {
+                    "Resolve TODO/FIXME comments before merging."
+                }
+                _ => "Reduce escape hatch usage or update baseline with --fix.",
+            }
+        } else if self.name.starts_with("unsafe.") {
+            "Move code out of unsafe blocks or update baseline with --fix."
+        } else if self.name.starts_with("lint.") {
+            "Fix new linter diagnostics or update baseline with --fix."
+        } else if self.name.starts_with("deadcode.") {
+            "Remove the newly unused exports or update baseline with --fix."
+        } else if self.name.starts_with("deps.") {
+            "Remove the new dependencies or update baseline with --fix."
+        } else if self.name.starts_with("binary_size.") {
+            "Reduce binary size: strip symbols, remove unused deps, enable LTO."
+        } else if self.name.starts_with("build_time.") {
+            "Reduce build time: check for new heavy deps or complex generics."
+        } else if self.name.starts_with("test_time.suite.") {
+            "Suite is slower than its recent history: look for new slow tests or setup."
+        } else if self.name.starts_with("test_time.") {
+            "Reduce test time: parallelize tests or optimize slow tests."
+        } else if self.name.starts_with("quench.") {
+            "Quench itself got slower: find the slow check with --timing."
+        } else if self.name.starts_with("coverage.") {
+            "Increase test coverage for changed code."
+        } else {
+            "Metric regressed. Clean up or update baseline with --fix."
+        }
+    }
+}
+
+/// A metric that improved from baseline.
+#[derive(Debug, Clone)]
+pub struct MetricImprovement {
+    pub name: String,
+    pub old_value: f64,
+    pub new_value: f64,
+}
+
+/// Format a metric value based on its type (determined by name prefix).
+fn format_metric_value(name: &str, value: f64) -> String {
+    if name.starts_with("build_time.")
+        || name.starts_with("test_time.")
+        || name.starts_with("quench.")
+    {
+        format!("{:.1}s", value)
+    } else if name.starts_with("coverage.") {
+        format!("{:.1}%", value * 100.0)
+    } else if name.starts_with("unsafe.") && name.ends_with(".ratio") {
+        format!("{:.2}%", value * 100.0)
+    } else if name.ends_with(".density") {
+        format!("{:.2}/kloc", value)
+    } else {
+        format!("{}", value as i64)
+    }
+}
+
+impl MetricImprovement {
+    /// Format the value based on metric type.
+    pub fn format_value(&self, value: f64) -> String {
+        format_metric_value(&self.name, value)
+    }
+}
+
+/// A stored metric that a baseline update changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaselineChange {
+    pub name: String,
+    /// Stored value before the update (`None` for a new metric).
+    pub old_value: Option<f64>,
+    /// Stored value after the update (`None` for a dropped metric).
+    pub new_value: Option<f64>,
+}
+
+impl BaselineChange {
+    /// Format the value based on metric type.
+    pub fn format_value(&self, value: f64) -> String {
+        format_metric_value(&self.name, value)
+    }
+
+    /// Signed change in the metric's units (e.g., "-2", "+1.5%"), when
+    /// the metric exists on both sides.
+    pub fn format_delta(&self) -> Option<String> {
+        let delta = self.new_value? - self.old_value?;
+        let sign = if delta < 0.0 { "-" } else { "+" };
+        Some(format!("{}{}", sign, self.format_value(delta.abs())))
+    }
+}
+
+/// Render baseline changes as a unified diff of `name: value` lines, with
+/// the signed change on each moved metric.
+pub fn format_baseline_diff(target: &str, changes: &[BaselineChange]) -> String {
+    let mut out = format!(
+        "  --- {} (current)\n  +++ {} (after --fix)\n",
+        target, target
+    );
+    for change in changes {
+        if let Some(old) = change.old_value {
+            out.push_str(&format!(
+                "  -{}: {}\n",
+                change.name,
+                change.format_value(old)
+            ));
+        }
+        if let Some(new) = change.new_value {
+            let delta = change
+                .format_delta()
+                .map(|d| format!(" ({})", d))
+                .unwrap_or_default();
+            out.push_str(&format!(
+                "  +{}: {}{}\n",
+                change.name,
+                change.format_value(new),
+                delta
+            ));
+        }
+    }
+    out
+}
+
+/// Metrics whose stored values differ between two baselines, by name.
+pub fn baseline_changes(old: &BaselineMetrics, new: &BaselineMetrics) -> Vec<BaselineChange> {
+    let old = old.flatten();
+    let new = new.flatten();
+    let names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let (old_value, new_value) = (old.get(name).copied(), new.get(name).copied());
+            (old_value != new_value).then(|| BaselineChange {
+                name: name.clone(),
+                old_value,
+                new_value,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
+
+#[cfg(test)]
+#[path = "packages_tests.rs"]
+mod packages_tests;
+
+#[cfg(test)]
+#[path = "timing_tests.rs"]
+mod timing_tests;
+
+#[cfg(test)]
+#[path = "counts_tests.rs"]
+mod counts_tests;
+
+#[cfg(test)]
+#[path = "changes_tests.rs"]
+mod changes_tests;
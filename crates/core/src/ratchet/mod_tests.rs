@@ -1,16 +1,17 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use serde_json::json;
 
+use super::timing_tests::make_suite_times;
 use super::*;
-use crate::baseline::{BaselineMetrics, EscapesMetrics as BaselineEscapes};
+use crate::baseline::{Baseline, BaselineMetrics, EscapesMetrics as BaselineEscapes};
 use crate::check::{CheckOutput, CheckResult};
 use crate::config::{CheckLevel, RatchetConfig};
 
-fn make_config(escapes: bool) -> RatchetConfig {
+pub(super) fn make_config(escapes: bool) -> RatchetConfig {
     RatchetConfig {
         check: CheckLevel::Error,
         escapes,
@@ -18,21 +19,25 @@ fn make_config(escapes: bool) -> RatchetConfig {
     }
 }
 
-fn make_baseline_metrics(escapes: HashMap<String, usize>) -> BaselineMetrics {
+pub(super) fn make_baseline_metrics(escapes: BTreeMap<String, usize>) -> BaselineMetrics {
     BaselineMetrics {
         escapes: Some(BaselineEscapes {
             source: escapes,
             test: None,
+            source_lines: None,
+            by_package: None,
         }),
         ..Default::default()
     }
 }
 
-fn make_current_metrics(escapes: HashMap<String, usize>) -> CurrentMetrics {
+pub(super) fn make_current_metrics(escapes: BTreeMap<String, usize>) -> CurrentMetrics {
     CurrentMetrics {
         escapes: Some(EscapesCurrent {
             source: escapes,
-            test: HashMap::new(),
+            test: BTreeMap::new(),
+            source_lines: None,
+            by_package: BTreeMap::new(),
         }),
         ..Default::default()
     }
@@ -41,8 +46,8 @@ fn make_current_metrics(escapes: HashMap<String, usize>) -> CurrentMetrics {
 #[test]
 fn compare_same_values_passes() {
     let config = make_config(true);
-    let baseline = make_baseline_metrics(HashMap::from([("unsafe".to_string(), 5)]));
-    let current = make_current_metrics(HashMap::from([("unsafe".to_string(), 5)]));
+    let baseline = make_baseline_metrics(BTreeMap::from([("unsafe".to_string(), 5)]));
+    let current = make_current_metrics(BTreeMap::from([("unsafe".to_string(), 5)]));
 
     let result = compare(&current, &baseline, &config);
 
@@ -56,8 +61,8 @@ fn compare_same_values_passes() {
 #[test]
 fn compare_regression_fails() {
     let config = make_config(true);
-    let baseline = make_baseline_metrics(HashMap::from([("unsafe".to_string(), 5)]));
-    let current = make_current_metrics(HashMap::from([("unsafe".to_string(), 7)]));
+    let baseline = make_baseline_metrics(BTreeMap::from([("unsafe".to_string(), 5)]));
+    let current = make_current_metrics(BTreeMap::from([("unsafe".to_string(), 7)]));
 
     let result = compare(&current, &baseline, &config);
 
@@ -70,8 +75,8 @@ fn compare_regression_fails() {
 #[test]
 fn compare_improvement_passes_and_tracks() {
     let config = make_config(true);
-    let baseline = make_baseline_metrics(HashMap::from([("unsafe".to_string(), 10)]));
-    let current = make_current_metrics(HashMap::from([("unsafe".to_string(), 3)]));
+    let baseline = make_baseline_metrics(BTreeMap::from([("unsafe".to_string(), 10)]));
+    let current = make_current_metrics(BTreeMap::from([("unsafe".to_string(), 3)]));
 
     let result = compare(&current, &baseline, &config);
 
@@ -87,8 +92,8 @@ fn compare_improvement_passes_and_tracks() {
 #[test]
 fn compare_escapes_disabled_skips() {
     let config = make_config(false); // escapes = false
-    let baseline = make_baseline_metrics(HashMap::from([("unsafe".to_string(), 5)]));
-    let current = make_current_metrics(HashMap::from([("unsafe".to_string(), 100)])); // regression
+    let baseline = make_baseline_metrics(BTreeMap::from([("unsafe".to_string(), 5)]));
+    let current = make_current_metrics(BTreeMap::from([("unsafe".to_string(), 100)])); // regression
 
     let result = compare(&current, &baseline, &config);
 
@@ -100,8 +105,8 @@ fn compare_escapes_disabled_skips() {
 #[test]
 fn compare_new_pattern_against_empty_baseline() {
     let config = make_config(true);
-    let baseline = make_baseline_metrics(HashMap::new()); // no patterns
-    let current = make_current_metrics(HashMap::from([("unwrap".to_string(), 3)]));
+    let baseline = make_baseline_metrics(BTreeMap::new()); // no patterns
+    let current = make_current_metrics(BTreeMap::from([("unwrap".to_string(), 3)]));
 
     let result = compare(&current, &baseline, &config);
 
@@ -116,7 +121,9 @@ fn compare_new_pattern_against_empty_baseline() {
 fn extract_metrics_from_check_output() {
     let metrics_json = json!({
         "source": { "unsafe": 5, "unwrap": 3 },
-        "test": { "unsafe": 10 }
+        "test": { "unsafe": 10, "unwrap": 0 },
+        "source_lines": 1000,
+        "density": { "unsafe": 5.0, "unwrap": 3.0 }
     });
 
     let check_result = CheckResult::passed("escapes").with_metrics(metrics_json);
@@ -129,6 +136,16 @@ fn extract_metrics_from_check_output() {
     assert_eq!(escapes.source.get("unsafe"), Some(&5));
     assert_eq!(escapes.source.get("unwrap"), Some(&3));
     assert_eq!(escapes.test.get("unsafe"), Some(&10));
+    assert_eq!(escapes.source_lines, Some(1000));
+}
+
+#[test]
+fn extract_metrics_ignores_off_schema_escapes() {
+    let metrics_json = json!({ "source": { "unsafe": 5 }, "test": {} });
+    let check_result = CheckResult::passed("escapes").with_metrics(metrics_json);
+    let output = CheckOutput::new("2026-01-20T00:00:00Z".to_string(), vec![check_result]);
+
+    assert!(CurrentMetrics::from_output(&output).escapes.is_none());
 }
 
 #[test]
@@ -144,7 +161,7 @@ fn extract_metrics_no_escapes_check() {
 #[test]
 fn update_baseline_with_current() {
     let mut baseline = Baseline::new();
-    let current = make_current_metrics(HashMap::from([
+    let current = make_current_metrics(BTreeMap::from([
         ("unsafe".to_string(), 5),
         ("unwrap".to_string(), 3),
     ]));
@@ -161,11 +178,13 @@ fn update_baseline_with_current() {
 fn update_baseline_replaces_values() {
     let mut baseline = Baseline::new();
     baseline.metrics.escapes = Some(BaselineEscapes {
-        source: HashMap::from([("unsafe".to_string(), 10)]),
+        source: BTreeMap::from([("unsafe".to_string(), 10)]),
         test: None,
+        source_lines: None,
+        by_package: None,
     });
 
-    let current = make_current_metrics(HashMap::from([("unsafe".to_string(), 3)]));
+    let current = make_current_metrics(BTreeMap::from([("unsafe".to_string(), 3)]));
 
     update_baseline(&mut baseline, &current);
 
@@ -176,11 +195,11 @@ fn update_baseline_replaces_values() {
 #[test]
 fn multiple_patterns_mixed_results() {
     let config = make_config(true);
-    let baseline = make_baseline_metrics(HashMap::from([
+    let baseline = make_baseline_metrics(BTreeMap::from([
         ("unsafe".to_string(), 5),
         ("unwrap".to_string(), 10),
     ]));
-    let current = make_current_metrics(HashMap::from([
+    let current = make_current_metrics(BTreeMap::from([
         ("unsafe".to_string(), 7), // regression
         ("unwrap".to_string(), 5), // improvement
     ]));
@@ -195,6 +214,201 @@ fn multiple_patterns_mixed_results() {
     assert_eq!(result.improvements[0].name, "escapes.unwrap");
 }
 
+/// Baseline and current `unwrap` escapes as (count, source lines).
+fn density_metrics(
+    base: (usize, usize),
+    curr: (usize, usize),
+) -> (BaselineMetrics, CurrentMetrics) {
+    let mut baseline = make_baseline_metrics(BTreeMap::from([("unwrap".to_string(), base.0)]));
+    let mut current = make_current_metrics(BTreeMap::from([("unwrap".to_string(), curr.0)]));
+    baseline.escapes.as_mut().unwrap().source_lines = Some(base.1);
+    current.escapes.as_mut().unwrap().source_lines = Some(curr.1);
+    (baseline, current)
+}
+
+#[test]
+fn escapes_density_allows_counts_growing_with_code() {
+    let config = RatchetConfig {
+        escapes_density: true,
+        ..make_config(true)
+    };
+    // 10 per 1000 lines before and after
+    let (baseline, current) = density_metrics((10, 1000), (20, 2000));
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(result.passed);
+    assert_eq!(result.comparisons.len(), 1);
+    assert_eq!(result.comparisons[0].name, "escapes.unwrap.density");
+    assert_eq!(result.comparisons[0].current, 10.0);
+}
+
+#[test]
+fn escapes_density_fails_when_density_rises() {
+    let config = RatchetConfig {
+        escapes_density: true,
+        ..make_config(true)
+    };
+    let (baseline, current) = density_metrics((10, 1000), (12, 1000));
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(!result.passed);
+    assert_eq!(result.comparisons[0].format_value(12.0), "12.00/kloc");
+}
+
+#[test]
+fn escapes_density_falls_back_to_counts_without_baseline_lines() {
+    let config = RatchetConfig {
+        escapes_density: true,
+        ..make_config(true)
+    };
+    let (mut baseline, current) = density_metrics((10, 1000), (20, 2000));
+    baseline.escapes.as_mut().unwrap().source_lines = None;
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(!result.passed);
+    assert_eq!(result.comparisons[0].name, "escapes.unwrap");
+}
+
+#[test]
+fn escapes_compare_counts_unless_density_enabled() {
+    let (baseline, current) = density_metrics((10, 1000), (20, 2000));
+
+    let result = compare(&current, &baseline, &make_config(true));
+
+    assert!(!result.passed);
+    assert_eq!(result.comparisons[0].name, "escapes.unwrap");
+}
+
+#[test]
+fn update_baseline_records_source_lines() {
+    let mut baseline = Baseline::new();
+    let (_, current) = density_metrics((0, 0), (3, 1500));
+
+    update_baseline(&mut baseline, &current);
+
+    assert_eq!(baseline.metrics.escapes.unwrap().source_lines, Some(1500));
+}
+
+// =============================================================================
+// Unsafe Ratio Tests
+// =============================================================================
+
+use crate::metrics::UnsafeCode;
+
+/// Unsafe lines out of `source_lines`, with the ratio the escapes check reports.
+fn unsafe_code(lines: usize, source_lines: usize) -> UnsafeCode {
+    UnsafeCode {
+        blocks: lines,
+        lines,
+        source_lines,
+        ratio: lines as f64 / source_lines as f64,
+        ..Default::default()
+    }
+}
+
+/// Baseline and current unsafe code as (lines, source lines), in the
+/// `core` package and in total.
+fn unsafe_metrics(base: (usize, usize), curr: (usize, usize)) -> (BaselineMetrics, CurrentMetrics) {
+    let metrics = |(lines, source_lines)| UnsafeMetrics {
+        total: unsafe_code(lines, source_lines),
+        by_package: Some(BTreeMap::from([(
+            "core".to_string(),
+            unsafe_code(lines, source_lines),
+        )])),
+    };
+    let baseline = BaselineMetrics {
+        unsafe_code: Some(metrics(base)),
+        ..Default::default()
+    };
+    let current = CurrentMetrics {
+        unsafe_code: Some(metrics(curr)),
+        ..Default::default()
+    };
+    (baseline, current)
+}
+
+fn unsafe_config() -> RatchetConfig {
+    RatchetConfig {
+        unsafe_ratio: true,
+        ..make_config(true)
+    }
+}
+
+#[test]
+fn unsafe_ratio_allows_unsafe_growing_with_code() {
+    let (baseline, current) = unsafe_metrics((10, 1000), (20, 2000));
+
+    let result = compare(&current, &baseline, &unsafe_config());
+
+    assert!(result.passed);
+    let names: Vec<&str> = result.comparisons.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["unsafe.ratio", "unsafe.core.ratio"]);
+}
+
+#[test]
+fn unsafe_ratio_fails_when_share_rises() {
+    let (baseline, current) = unsafe_metrics((10, 1000), (15, 1000));
+
+    let result = compare(&current, &baseline, &unsafe_config());
+
+    assert!(!result.passed);
+    assert_eq!(
+        result.packages,
+        BTreeMap::from([("core".to_string(), false)])
+    );
+    assert_eq!(result.comparisons[0].format_value(0.015), "1.50%");
+    assert!(result.comparisons[0].advice().contains("unsafe blocks"));
+}
+
+#[test]
+fn unsafe_ratio_is_off_by_default() {
+    let (baseline, current) = unsafe_metrics((10, 1000), (15, 1000));
+
+    let result = compare(&current, &baseline, &make_config(true));
+
+    assert!(result.passed);
+    assert!(result.comparisons.is_empty());
+}
+
+#[test]
+fn extract_unsafe_metrics_from_check_output() {
+    let escapes = |lines: usize| {
+        json!({
+            "source": {}, "test": {}, "source_lines": 1000, "density": {},
+            "unsafe": {
+                "blocks": 2, "fns": 1, "impls": 0, "lines": lines,
+                "source_lines": 1000, "ratio": lines as f64 / 1000.0
+            }
+        })
+    };
+    let check_result = CheckResult::passed("escapes")
+        .with_metrics(escapes(30))
+        .with_by_package(BTreeMap::from([("core".to_string(), escapes(30))]));
+    let output = CheckOutput::new("2026-01-20T00:00:00Z".to_string(), vec![check_result]);
+
+    let current = CurrentMetrics::from_output(&output);
+
+    let unsafe_code = current.unsafe_code.unwrap();
+    assert_eq!(unsafe_code.total.blocks, 2);
+    assert_eq!(unsafe_code.total.ratio, 0.03);
+    assert_eq!(unsafe_code.by_package.unwrap()["core"].lines, 30);
+}
+
+#[test]
+fn update_baseline_records_unsafe_code() {
+    let mut baseline = Baseline::new();
+    let (_, current) = unsafe_metrics((0, 0), (5, 500));
+
+    update_baseline(&mut baseline, &current);
+
+    let recorded = baseline.metrics.unsafe_code.unwrap();
+    assert_eq!(recorded.total.lines, 5);
+    assert!(recorded.by_package.unwrap().contains_key("core"));
+}
+
 // =============================================================================
 // Performance Metrics Tests
 // =============================================================================
@@ -215,11 +429,11 @@ fn make_binary_size_config(tolerance: Option<&str>) -> RatchetConfig {
 fn binary_size_regression_fails() {
     let config = make_binary_size_config(None);
     let baseline = BaselineMetrics {
-        binary_size: Some(HashMap::from([("myapp".to_string(), 1_000_000)])),
+        binary_size: Some(BTreeMap::from([("myapp".to_string(), 1_000_000)])),
         ..Default::default()
     };
     let current = CurrentMetrics {
-        binary_size: Some(HashMap::from([("myapp".to_string(), 1_500_000)])),
+        binary_size: Some(BTreeMap::from([("myapp".to_string(), 1_500_000)])),
         ..Default::default()
     };
 
@@ -236,11 +450,11 @@ fn binary_size_regression_fails() {
 fn binary_size_within_tolerance_passes() {
     let config = make_binary_size_config(Some("100KB"));
     let baseline = BaselineMetrics {
-        binary_size: Some(HashMap::from([("myapp".to_string(), 1_000_000)])),
+        binary_size: Some(BTreeMap::from([("myapp".to_string(), 1_000_000)])),
         ..Default::default()
     };
     let current = CurrentMetrics {
-        binary_size: Some(HashMap::from([("myapp".to_string(), 1_050_000)])),
+        binary_size: Some(BTreeMap::from([("myapp".to_string(), 1_050_000)])),
         ..Default::default()
     };
 
@@ -255,11 +469,11 @@ fn binary_size_within_tolerance_passes() {
 fn binary_size_exceeds_tolerance_fails() {
     let config = make_binary_size_config(Some("100KB")); // 102,400 bytes tolerance
     let baseline = BaselineMetrics {
-        binary_size: Some(HashMap::from([("myapp".to_string(), 1_000_000)])),
+        binary_size: Some(BTreeMap::from([("myapp".to_string(), 1_000_000)])),
         ..Default::default()
     };
     let current = CurrentMetrics {
-        binary_size: Some(HashMap::from([("myapp".to_string(), 1_200_000)])), // +200KB
+        binary_size: Some(BTreeMap::from([("myapp".to_string(), 1_200_000)])), // +200KB
         ..Default::default()
     };
 
@@ -272,11 +486,11 @@ fn binary_size_exceeds_tolerance_fails() {
 fn binary_size_improvement_tracked() {
     let config = make_binary_size_config(None);
     let baseline = BaselineMetrics {
-        binary_size: Some(HashMap::from([("myapp".to_string(), 1_000_000)])),
+        binary_size: Some(BTreeMap::from([("myapp".to_string(), 1_000_000)])),
         ..Default::default()
     };
     let current = CurrentMetrics {
-        binary_size: Some(HashMap::from([("myapp".to_string(), 800_000)])),
+        binary_size: Some(BTreeMap::from([("myapp".to_string(), 800_000)])),
         ..Default::default()
     };
 
@@ -370,9 +584,11 @@ fn extract_build_metrics() {
 #[test]
 fn extract_test_time_metrics() {
     let metrics_json = json!({
-        "total": 30.5,
-        "avg": 0.5,
-        "max": 2.0
+        "test_count": 61,
+        "total_ms": 30500,
+        "avg_ms": 500,
+        "max_ms": 2000,
+        "suites": [{ "name": "unit", "runner": "cargo", "passed": true, "test_count": 61 }]
     });
 
     let check_result = CheckResult::passed("tests").with_metrics(metrics_json);
@@ -391,7 +607,7 @@ fn extract_test_time_metrics() {
 fn update_baseline_with_perf_metrics() {
     let mut baseline = Baseline::new();
     let current = CurrentMetrics {
-        binary_size: Some(HashMap::from([("myapp".to_string(), 800_000)])),
+        binary_size: Some(BTreeMap::from([("myapp".to_string(), 800_000)])),
         build_time: Some(BuildTimeCurrent {
             cold: Some(Duration::from_secs(10)),
             hot: Some(Duration::from_secs(2)),
@@ -451,7 +667,7 @@ fn make_coverage_current(total: f64) -> CurrentMetrics {
     CurrentMetrics {
         coverage: Some(CoverageCurrent {
             total,
-            by_package: HashMap::new(),
+            by_package: BTreeMap::new(),
         }),
         ..Default::default()
     }
@@ -550,7 +766,9 @@ fn coverage_disabled_skips_comparison() {
 #[test]
 fn extract_coverage_from_tests_output() {
     let metrics_json = json!({
-        "total": 30.5,
+        "test_count": 10,
+        "total_ms": 30500,
+        "suites": [],
         "coverage": { "rust": 0.82 },
         "coverage_by_package": {
             "core": 0.90,
@@ -573,7 +791,9 @@ fn extract_coverage_from_tests_output() {
 #[test]
 fn extract_coverage_no_by_package() {
     let metrics_json = json!({
-        "total": 30.5,
+        "test_count": 10,
+        "total_ms": 30500,
+        "suites": [],
         "coverage": { "rust": 0.78 }
     });
 
@@ -594,7 +814,7 @@ fn update_baseline_with_coverage() {
     let current = CurrentMetrics {
         coverage: Some(CoverageCurrent {
             total: 0.85,
-            by_package: HashMap::from([("core".to_string(), 0.92), ("cli".to_string(), 0.71)]),
+            by_package: BTreeMap::from([("core".to_string(), 0.92), ("cli".to_string(), 0.71)]),
         }),
         ..Default::default()
     };
@@ -611,192 +831,69 @@ fn update_baseline_with_coverage() {
 }
 
 #[test]
-fn update_baseline_coverage_empty_by_package() {
+fn update_baseline_records_goal_history() {
     let mut baseline = Baseline::new();
-    let current = CurrentMetrics {
-        coverage: Some(CoverageCurrent {
-            total: 0.80,
-            by_package: HashMap::new(),
-        }),
-        ..Default::default()
-    };
-
-    update_baseline(&mut baseline, &current);
-
-    assert!(baseline.metrics.coverage.is_some());
-    let coverage = baseline.metrics.coverage.unwrap();
-    assert_eq!(coverage.total, 0.80);
-    assert!(coverage.by_package.is_none()); // Empty map should not be serialized
-}
-
-// =============================================================================
-// Per-Package Ratcheting Tests
-// =============================================================================
-
-use crate::config::RatchetPackageConfig;
-
-fn make_per_package_baseline(by_package: HashMap<String, f64>) -> BaselineMetrics {
-    BaselineMetrics {
-        coverage: Some(BaselineCoverage {
-            total: 0.80,
-            by_package: Some(by_package),
-        }),
-        ..Default::default()
-    }
-}
-
-fn make_per_package_current(total: f64, by_package: HashMap<String, f64>) -> CurrentMetrics {
-    CurrentMetrics {
-        coverage: Some(CoverageCurrent { total, by_package }),
-        ..Default::default()
-    }
-}
-
-#[test]
-fn per_package_coverage_regression_fails() {
-    let config = RatchetConfig {
-        check: CheckLevel::Error,
-        coverage: true,
-        ..Default::default()
-    };
-    let baseline = make_per_package_baseline(HashMap::from([
-        ("core".to_string(), 0.90),
-        ("cli".to_string(), 0.70),
-    ]));
-    let current = make_per_package_current(
-        0.80,
-        HashMap::from([
-            ("core".to_string(), 0.85), // Regression from 90% to 85%
-            ("cli".to_string(), 0.70),  // Same
-        ]),
+    update_baseline(
+        &mut baseline,
+        &make_current_metrics(BTreeMap::from([("unwrap".to_string(), 4)])),
     );
-
-    let result = compare(&current, &baseline, &config);
-
-    assert!(!result.passed);
-    // Should have total + 2 per-package comparisons
-    assert_eq!(result.comparisons.len(), 3);
-
-    // Find the core comparison
-    let core_comp = result
-        .comparisons
-        .iter()
-        .find(|c| c.name == "coverage.core")
-        .unwrap();
-    assert!(!core_comp.passed);
-    assert_eq!(core_comp.current, 0.85);
-    assert_eq!(core_comp.baseline, 0.90);
-}
-
-#[test]
-fn per_package_coverage_disabled_skips() {
-    let config = RatchetConfig {
-        check: CheckLevel::Error,
-        coverage: true,
-        package: HashMap::from([(
-            "cli".to_string(),
-            RatchetPackageConfig {
-                coverage: Some(false), // Disable coverage ratcheting for cli
-                escapes: None,
-            },
-        )]),
-        ..Default::default()
-    };
-    let baseline = make_per_package_baseline(HashMap::from([
-        ("core".to_string(), 0.90),
-        ("cli".to_string(), 0.70),
-    ]));
-    let current = make_per_package_current(
-        0.80,
-        HashMap::from([
-            ("core".to_string(), 0.90), // Same
-            ("cli".to_string(), 0.50),  // Regression, but disabled
-        ]),
+    update_baseline(
+        &mut baseline,
+        &CurrentMetrics {
+            coverage: Some(CoverageCurrent {
+                total: 72.5,
+                by_package: BTreeMap::new(),
+            }),
+            ..Default::default()
+        },
     );
 
-    let result = compare(&current, &baseline, &config);
-
-    assert!(result.passed);
-    // Should have total + 1 per-package (core only, cli disabled)
-    assert_eq!(result.comparisons.len(), 2);
-
-    // cli comparison should not exist
-    assert!(result.comparisons.iter().all(|c| c.name != "coverage.cli"));
+    let history = baseline.metrics.history.unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].coverage, None);
+    assert_eq!(history[1].coverage, Some(72.5));
+    // Escape counts carry over from the stored baseline
+    assert_eq!(history[1].escapes.get("unwrap"), Some(&4));
+    assert_eq!(history[1].date, baseline.updated);
 }
 
 #[test]
-fn per_package_coverage_improvement_tracked() {
-    let config = RatchetConfig {
-        check: CheckLevel::Error,
-        coverage: true,
-        ..Default::default()
-    };
-    let baseline = make_per_package_baseline(HashMap::from([("core".to_string(), 0.80)]));
-    let current = make_per_package_current(0.85, HashMap::from([("core".to_string(), 0.90)]));
-
-    let result = compare(&current, &baseline, &config);
+fn update_baseline_history_keeps_each_runs_tags() {
+    let mut baseline = Baseline::new();
+    let current = make_current_metrics(BTreeMap::from([("unwrap".to_string(), 4)]));
+    for env in ["ci", "nightly"] {
+        baseline.tags = BTreeMap::from([("env".to_string(), env.to_string())]);
+        update_baseline(&mut baseline, &current);
+    }
 
-    assert!(result.passed);
-    // Both total and core should be improvements
-    assert_eq!(result.improvements.len(), 2);
-    assert!(
-        result
-            .improvements
-            .iter()
-            .any(|i| i.name == "coverage.total")
-    );
-    assert!(
-        result
-            .improvements
-            .iter()
-            .any(|i| i.name == "coverage.core")
-    );
+    let history = baseline.metrics.history.unwrap();
+    assert_eq!(history[0].tags["env"], "ci");
+    assert_eq!(history[1].tags["env"], "nightly");
 }
 
 #[test]
-fn is_coverage_ratcheted_default() {
-    // Note: RatchetConfig::default() uses bool::default() (false) for coverage,
-    // but serde deserialization uses default_true(). This tests the method logic.
-    let config = RatchetConfig {
-        coverage: true,
-        ..Default::default()
-    };
+fn update_baseline_without_goal_metrics_records_no_history() {
+    let mut baseline = Baseline::new();
+    update_baseline(&mut baseline, &make_suite_times("unit", 1.0));
 
-    assert!(config.is_coverage_ratcheted("any_package"));
+    assert!(baseline.metrics.history.is_none());
 }
 
 #[test]
-fn is_coverage_ratcheted_package_override() {
-    let config = RatchetConfig {
-        coverage: true,
-        package: HashMap::from([(
-            "cli".to_string(),
-            RatchetPackageConfig {
-                coverage: Some(false),
-                escapes: None,
-            },
-        )]),
+fn update_baseline_coverage_empty_by_package() {
+    let mut baseline = Baseline::new();
+    let current = CurrentMetrics {
+        coverage: Some(CoverageCurrent {
+            total: 0.80,
+            by_package: BTreeMap::new(),
+        }),
         ..Default::default()
     };
 
-    assert!(config.is_coverage_ratcheted("core")); // Not configured, uses global
-    assert!(!config.is_coverage_ratcheted("cli")); // Explicitly disabled
-}
-
-#[test]
-fn is_escapes_ratcheted_package_override() {
-    let config = RatchetConfig {
-        escapes: true,
-        package: HashMap::from([(
-            "tests".to_string(),
-            RatchetPackageConfig {
-                coverage: None,
-                escapes: Some(false), // Don't ratchet escapes in tests package
-            },
-        )]),
-        ..Default::default()
-    };
+    update_baseline(&mut baseline, &current);
 
-    assert!(config.is_escapes_ratcheted("core")); // Uses global
-    assert!(!config.is_escapes_ratcheted("tests")); // Explicitly disabled
+    assert!(baseline.metrics.coverage.is_some());
+    let coverage = baseline.metrics.coverage.unwrap();
+    assert_eq!(coverage.total, 0.80);
+    assert!(coverage.by_package.is_none()); // Empty map should not be serialized
 }
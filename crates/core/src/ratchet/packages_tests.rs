@@ -0,0 +1,371 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::tests::{make_baseline_metrics, make_config, make_current_metrics};
+use super::*;
+use crate::baseline::{BaselineMetrics, CoverageMetrics as BaselineCoverage};
+use crate::config::{CheckLevel, RatchetConfig};
+
+// =============================================================================
+// Per-Package Ratcheting Tests
+// =============================================================================
+
+use crate::config::RatchetPackageConfig;
+
+fn make_per_package_baseline(by_package: BTreeMap<String, f64>) -> BaselineMetrics {
+    BaselineMetrics {
+        coverage: Some(BaselineCoverage {
+            total: 0.80,
+            by_package: Some(by_package),
+        }),
+        ..Default::default()
+    }
+}
+
+fn make_per_package_current(total: f64, by_package: BTreeMap<String, f64>) -> CurrentMetrics {
+    CurrentMetrics {
+        coverage: Some(CoverageCurrent { total, by_package }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn per_package_coverage_regression_fails() {
+    let config = RatchetConfig {
+        check: CheckLevel::Error,
+        coverage: true,
+        ..Default::default()
+    };
+    let baseline = make_per_package_baseline(BTreeMap::from([
+        ("core".to_string(), 0.90),
+        ("cli".to_string(), 0.70),
+    ]));
+    let current = make_per_package_current(
+        0.80,
+        BTreeMap::from([
+            ("core".to_string(), 0.85), // Regression from 90% to 85%
+            ("cli".to_string(), 0.70),  // Same
+        ]),
+    );
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(!result.passed);
+    // Should have total + 2 per-package comparisons
+    assert_eq!(result.comparisons.len(), 3);
+
+    // Find the core comparison
+    let core_comp = result
+        .comparisons
+        .iter()
+        .find(|c| c.name == "coverage.core")
+        .unwrap();
+    assert!(!core_comp.passed);
+    assert_eq!(core_comp.current, 0.85);
+    assert_eq!(core_comp.baseline, 0.90);
+}
+
+#[test]
+fn per_package_coverage_disabled_skips() {
+    let config = RatchetConfig {
+        check: CheckLevel::Error,
+        coverage: true,
+        package: HashMap::from([(
+            "cli".to_string(),
+            RatchetPackageConfig {
+                coverage: Some(false), // Disable coverage ratcheting for cli
+                escapes: None,
+            },
+        )]),
+        ..Default::default()
+    };
+    let baseline = make_per_package_baseline(BTreeMap::from([
+        ("core".to_string(), 0.90),
+        ("cli".to_string(), 0.70),
+    ]));
+    let current = make_per_package_current(
+        0.80,
+        BTreeMap::from([
+            ("core".to_string(), 0.90), // Same
+            ("cli".to_string(), 0.50),  // Regression, but disabled
+        ]),
+    );
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(result.passed);
+    // Should have total + 1 per-package (core only, cli disabled)
+    assert_eq!(result.comparisons.len(), 2);
+
+    // cli comparison should not exist
+    assert!(result.comparisons.iter().all(|c| c.name != "coverage.cli"));
+}
+
+#[test]
+fn per_package_coverage_improvement_tracked() {
+    let config = RatchetConfig {
+        check: CheckLevel::Error,
+        coverage: true,
+        ..Default::default()
+    };
+    let baseline = make_per_package_baseline(BTreeMap::from([("core".to_string(), 0.80)]));
+    let current = make_per_package_current(0.85, BTreeMap::from([("core".to_string(), 0.90)]));
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(result.passed);
+    // Both total and core should be improvements
+    assert_eq!(result.improvements.len(), 2);
+    assert!(
+        result
+            .improvements
+            .iter()
+            .any(|i| i.name == "coverage.total")
+    );
+    assert!(
+        result
+            .improvements
+            .iter()
+            .any(|i| i.name == "coverage.core")
+    );
+}
+
+#[test]
+fn is_coverage_ratcheted_default() {
+    // Note: RatchetConfig::default() uses bool::default() (false) for coverage,
+    // but serde deserialization uses default_true(). This tests the method logic.
+    let config = RatchetConfig {
+        coverage: true,
+        ..Default::default()
+    };
+
+    assert!(config.is_coverage_ratcheted("any_package"));
+}
+
+#[test]
+fn is_coverage_ratcheted_package_override() {
+    let config = RatchetConfig {
+        coverage: true,
+        package: HashMap::from([(
+            "cli".to_string(),
+            RatchetPackageConfig {
+                coverage: Some(false),
+                escapes: None,
+            },
+        )]),
+        ..Default::default()
+    };
+
+    assert!(config.is_coverage_ratcheted("core")); // Not configured, uses global
+    assert!(!config.is_coverage_ratcheted("cli")); // Explicitly disabled
+}
+
+#[test]
+fn is_escapes_ratcheted_package_override() {
+    let config = RatchetConfig {
+        escapes: true,
+        package: HashMap::from([(
+            "tests".to_string(),
+            RatchetPackageConfig {
+                coverage: None,
+                escapes: Some(false), // Don't ratchet escapes in tests package
+            },
+        )]),
+        ..Default::default()
+    };
+
+    assert!(config.is_escapes_ratcheted("core")); // Uses global
+    assert!(!config.is_escapes_ratcheted("tests")); // Explicitly disabled
+}
+
+fn package_escapes(packages: &[(&str, usize, usize)]) -> BTreeMap<String, PackageEscapesMetrics> {
+    packages
+        .iter()
+        .map(|&(name, unsafe_count, source_lines)| {
+            (
+                name.to_string(),
+                PackageEscapesMetrics {
+                    source: BTreeMap::from([("unsafe".to_string(), unsafe_count)]),
+                    source_lines,
+                },
+            )
+        })
+        .collect()
+}
+
+fn make_package_escapes(
+    base: &[(&str, usize, usize)],
+    curr: &[(&str, usize, usize)],
+) -> (BaselineMetrics, CurrentMetrics) {
+    let total = |packages: &[(&str, usize, usize)]| packages.iter().map(|p| p.1).sum::<usize>();
+    let mut baseline = make_baseline_metrics(BTreeMap::from([("unsafe".to_string(), total(base))]));
+    if let Some(escapes) = &mut baseline.escapes {
+        escapes.by_package = Some(package_escapes(base));
+    }
+    let mut current = make_current_metrics(BTreeMap::from([("unsafe".to_string(), total(curr))]));
+    if let Some(escapes) = &mut current.escapes {
+        escapes.by_package = package_escapes(curr);
+    }
+    (baseline, current)
+}
+
+#[test]
+fn per_package_escapes_regression_fails_despite_total() {
+    // core gains one unsafe while cli drops one: the total holds
+    let (baseline, current) = make_package_escapes(
+        &[("core", 2, 1000), ("cli", 3, 1000)],
+        &[("core", 3, 1000), ("cli", 2, 1000)],
+    );
+
+    let result = compare(&current, &baseline, &make_config(true));
+
+    assert!(!result.passed);
+    let total = result
+        .comparisons
+        .iter()
+        .find(|c| c.name == "escapes.unsafe")
+        .unwrap();
+    assert!(total.passed);
+    let core = result
+        .comparisons
+        .iter()
+        .find(|c| c.name == "escapes.core/unsafe")
+        .unwrap();
+    assert!(!core.passed);
+    assert_eq!(core.threshold, 2.0);
+    assert!(
+        result
+            .improvements
+            .iter()
+            .any(|i| i.name == "escapes.cli/unsafe")
+    );
+    assert_eq!(
+        result.packages,
+        BTreeMap::from([("cli".to_string(), true), ("core".to_string(), false)])
+    );
+}
+
+#[test]
+fn per_package_escapes_respect_package_override() {
+    let config = RatchetConfig {
+        package: HashMap::from([(
+            "core".to_string(),
+            RatchetPackageConfig {
+                coverage: None,
+                escapes: Some(false),
+            },
+        )]),
+        ..make_config(true)
+    };
+    let (baseline, current) = make_package_escapes(
+        &[("core", 2, 1000), ("cli", 3, 1000)],
+        &[("core", 3, 1000), ("cli", 2, 1000)],
+    );
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(result.passed);
+    assert!(
+        result
+            .comparisons
+            .iter()
+            .all(|c| c.name != "escapes.core/unsafe")
+    );
+}
+
+#[test]
+fn per_package_escapes_enabled_by_package_override() {
+    let config = RatchetConfig {
+        package: HashMap::from([(
+            "core".to_string(),
+            RatchetPackageConfig {
+                coverage: None,
+                escapes: Some(true),
+            },
+        )]),
+        ..make_config(false)
+    };
+    let (baseline, current) = make_package_escapes(&[("core", 2, 1000)], &[("core", 3, 1000)]);
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(!result.passed);
+    let names: Vec<_> = result.comparisons.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["escapes.core/unsafe"]);
+}
+
+#[test]
+fn per_package_escapes_skip_packages_without_baseline() {
+    let (baseline, current) =
+        make_package_escapes(&[("core", 2, 1000)], &[("core", 2, 1000), ("new", 4, 1000)]);
+
+    let result = compare(&current, &baseline, &make_config(true));
+
+    assert!(
+        result
+            .comparisons
+            .iter()
+            .all(|c| !c.name.starts_with("escapes.new/"))
+    );
+    assert_eq!(
+        result.packages,
+        BTreeMap::from([("core".to_string(), true)])
+    );
+}
+
+#[test]
+fn per_package_escapes_use_density_when_enabled() {
+    let config = RatchetConfig {
+        escapes_density: true,
+        ..make_config(true)
+    };
+    // core doubles in size with one more unsafe: density drops
+    let (baseline, current) = make_package_escapes(&[("core", 2, 1000)], &[("core", 3, 2000)]);
+
+    let result = compare(&current, &baseline, &config);
+
+    let core = result
+        .comparisons
+        .iter()
+        .find(|c| c.name == "escapes.core/unsafe.density")
+        .unwrap();
+    assert!(core.passed);
+    assert_eq!(core.current, 1.5);
+    assert_eq!(core.baseline, 2.0);
+}
+
+#[test]
+fn per_package_coverage_enabled_by_package_override() {
+    let config = RatchetConfig {
+        check: CheckLevel::Error,
+        coverage: false,
+        package: HashMap::from([(
+            "core".to_string(),
+            RatchetPackageConfig {
+                coverage: Some(true),
+                escapes: None,
+            },
+        )]),
+        ..Default::default()
+    };
+    let baseline = make_per_package_baseline(BTreeMap::from([
+        ("core".to_string(), 0.90),
+        ("cli".to_string(), 0.70),
+    ]));
+    let current = make_per_package_current(
+        0.70,
+        BTreeMap::from([("core".to_string(), 0.85), ("cli".to_string(), 0.50)]),
+    );
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(!result.passed);
+    let names: Vec<_> = result.comparisons.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["coverage.core"]);
+    assert_eq!(
+        result.packages,
+        BTreeMap::from([("core".to_string(), false)])
+    );
+}
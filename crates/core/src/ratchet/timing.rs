@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Timing comparisons, which allow a tolerance above the baseline.
+
+use std::time::Duration;
+
+use crate::baseline::BaselineMetrics;
+use crate::config::RatchetConfig;
+
+use super::{MetricComparison, MetricImprovement, SelfTimeCurrent};
+
+/// Compare quench's own run time against the baseline for its mode.
+///
+/// Kept out of [`compare`] because a slower quench warns rather than
+/// failing the ratchet. Returns `None` when disabled or without a
+/// recorded time. The comparison fails when the run is more than
+/// `self_time_tolerance` percent slower and over [`SelfTimeCurrent::MIN_WARN`].
+pub fn compare_self_time(
+    current: &SelfTimeCurrent,
+    baseline: &BaselineMetrics,
+    config: &RatchetConfig,
+) -> Option<MetricComparison> {
+    if !config.self_time {
+        return None;
+    }
+    let base = current.baseline(baseline)?;
+    let curr_secs = current.elapsed.as_secs_f64();
+    let tolerance = base * config.self_time_tolerance.max(0.0) / 100.0;
+    let threshold = base + tolerance;
+    Some(MetricComparison {
+        name: current.metric_name().to_string(),
+        current: curr_secs,
+        baseline: base,
+        tolerance,
+        threshold,
+        passed: curr_secs <= threshold || current.elapsed < SelfTimeCurrent::MIN_WARN,
+        improved: curr_secs < base,
+    })
+}
+
+/// Compare a timing metric against baseline with tolerance.
+pub(super) fn compare_timing(
+    name: &str,
+    current: Option<Duration>,
+    baseline: Option<f64>,
+    tolerance: Option<Duration>,
+    comparisons: &mut Vec<MetricComparison>,
+    improvements: &mut Vec<MetricImprovement>,
+    passed: &mut bool,
+) {
+    if let (Some(curr), Some(base)) = (current, baseline) {
+        let curr_secs = curr.as_secs_f64();
+        let tolerance_secs = tolerance.map(|d| d.as_secs_f64()).unwrap_or(0.0);
+        let max_allowed = base + tolerance_secs;
+
+        let comparison = MetricComparison {
+            name: name.to_string(),
+            current: curr_secs,
+            baseline: base,
+            tolerance: tolerance_secs,
+            threshold: max_allowed,
+            passed: curr_secs <= max_allowed,
+            improved: curr_secs < base,
+        };
+
+        if !comparison.passed {
+            *passed = false;
+        }
+        if comparison.improved {
+            improvements.push(MetricImprovement {
+                name: name.to_string(),
+                old_value: base,
+                new_value: curr_secs,
+            });
+        }
+        comparisons.push(comparison);
+    }
+}
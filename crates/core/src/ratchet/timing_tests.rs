@@ -0,0 +1,273 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::BTreeMap;
+
+use serde_json::json;
+
+use super::tests::make_config;
+use super::*;
+use crate::baseline::{Baseline, BaselineMetrics, SelfTimeMetrics};
+use crate::check::{CheckOutput, CheckResult};
+use crate::config::{CheckLevel, RatchetConfig};
+
+// =============================================================================
+// Test Time Anomaly Tests
+// =============================================================================
+
+fn make_anomaly_config() -> RatchetConfig {
+    RatchetConfig {
+        check: CheckLevel::Error,
+        escapes: false,
+        test_time_anomaly: true,
+        test_time_anomaly_sigma: 3.0,
+        ..Default::default()
+    }
+}
+
+fn make_history(suite: &str, samples: &[f64]) -> BaselineMetrics {
+    BaselineMetrics {
+        test_time_history: Some(BTreeMap::from([(suite.to_string(), samples.to_vec())])),
+        ..Default::default()
+    }
+}
+
+pub(super) fn make_suite_times(suite: &str, secs: f64) -> CurrentMetrics {
+    CurrentMetrics {
+        suite_times: BTreeMap::from([(suite.to_string(), Duration::from_secs_f64(secs))]),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn anomaly_flags_suite_far_above_history() {
+    let baseline = make_history("unit", &[10.0, 11.0, 9.0, 10.0, 10.0]);
+    let result = compare(
+        &make_suite_times("unit", 20.0),
+        &baseline,
+        &make_anomaly_config(),
+    );
+
+    assert!(!result.passed);
+    assert_eq!(result.comparisons[0].name, "test_time.suite.unit");
+    assert_eq!(result.comparisons[0].baseline, 10.0);
+}
+
+#[test]
+fn anomaly_allows_normal_variation() {
+    let baseline = make_history("unit", &[10.0, 11.0, 9.0, 10.0, 10.0]);
+    let result = compare(
+        &make_suite_times("unit", 11.5),
+        &baseline,
+        &make_anomaly_config(),
+    );
+
+    assert!(result.passed);
+    assert!(result.improvements.is_empty());
+}
+
+#[test]
+fn anomaly_needs_enough_history() {
+    let baseline = make_history("unit", &[10.0, 10.0]);
+    let result = compare(
+        &make_suite_times("unit", 100.0),
+        &baseline,
+        &make_anomaly_config(),
+    );
+
+    assert!(result.passed);
+    assert!(result.comparisons.is_empty());
+}
+
+#[test]
+fn anomaly_disabled_by_default() {
+    let baseline = make_history("unit", &[10.0; 5]);
+    let result = compare(
+        &make_suite_times("unit", 100.0),
+        &baseline,
+        &make_config(false),
+    );
+
+    assert!(result.comparisons.is_empty());
+}
+
+#[test]
+fn extract_suite_times_skips_failed_suites() {
+    let metrics_json = json!({
+        "test_count": 0,
+        "total_ms": 3000,
+        "suites": [
+            { "name": "unit", "runner": "cargo", "passed": true, "test_count": 0, "total_ms": 1000 },
+            { "name": "slow", "runner": "cargo", "passed": false, "test_count": 0, "total_ms": 2000 },
+            { "name": "empty", "runner": "cargo", "passed": true, "test_count": 0 }
+        ]
+    });
+    let check_result = CheckResult::passed("tests").with_metrics(metrics_json);
+    let output = CheckOutput::new("2026-01-20T00:00:00Z".to_string(), vec![check_result]);
+
+    let current = CurrentMetrics::from_output(&output);
+
+    assert_eq!(
+        current.suite_times,
+        BTreeMap::from([("unit".to_string(), Duration::from_secs(1))])
+    );
+}
+
+#[test]
+fn update_baseline_appends_suite_history() {
+    let mut baseline = Baseline::new();
+    update_baseline(&mut baseline, &make_suite_times("unit", 1.0));
+    update_baseline(&mut baseline, &make_suite_times("unit", 2.0));
+
+    let history = baseline.metrics.test_time_history.unwrap();
+    assert_eq!(history.get("unit"), Some(&vec![1.0, 2.0]));
+}
+
+// =============================================================================
+// Slow Test Tracking Tests
+// =============================================================================
+
+fn make_slow_tests(tests: &[(&str, u64)]) -> CurrentMetrics {
+    CurrentMetrics {
+        slow_tests: BTreeMap::from([(
+            "unit".to_string(),
+            tests.iter().map(|(n, ms)| (n.to_string(), *ms)).collect(),
+        )]),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn extract_slow_tests_from_suites() {
+    let metrics_json = json!({
+        "test_count": 0,
+        "total_ms": 1000,
+        "suites": [
+            {
+                "name": "unit", "runner": "cargo", "passed": true, "test_count": 0,
+                "slowest": [{ "name": "a", "ms": 500 }]
+            },
+            { "name": "empty", "runner": "cargo", "passed": true, "test_count": 0 }
+        ]
+    });
+    let check_result = CheckResult::passed("tests").with_metrics(metrics_json);
+    let output = CheckOutput::new("2026-01-20T00:00:00Z".to_string(), vec![check_result]);
+
+    let current = CurrentMetrics::from_output(&output);
+
+    assert_eq!(
+        current.slow_tests,
+        BTreeMap::from([("unit".to_string(), vec![("a".to_string(), 500)])])
+    );
+}
+
+#[test]
+fn update_baseline_carries_slow_test_history() {
+    let mut baseline = Baseline::new();
+    update_baseline(&mut baseline, &make_slow_tests(&[("a", 100), ("b", 50)]));
+    update_baseline(&mut baseline, &make_slow_tests(&[("a", 150), ("c", 80)]));
+
+    let unit = &baseline.metrics.slow_tests.unwrap()["unit"];
+    assert_eq!(unit.len(), 2);
+    assert_eq!((unit[0].name.as_str(), unit[0].ms), ("a", 150));
+    assert_eq!(unit[0].previous, vec![100]);
+    assert_eq!((unit[1].name.as_str(), unit[1].ms), ("c", 80));
+    assert!(unit[1].previous.is_empty());
+}
+
+// =============================================================================
+// SELF-TIME TESTS
+// =============================================================================
+
+fn make_self_time_config() -> RatchetConfig {
+    RatchetConfig {
+        self_time: true,
+        self_time_tolerance: 50.0,
+        ..Default::default()
+    }
+}
+
+fn self_time(ci: bool, secs: f64) -> SelfTimeCurrent {
+    SelfTimeCurrent {
+        ci,
+        elapsed: Duration::from_secs_f64(secs),
+    }
+}
+
+fn self_time_baseline(fast: f64, ci: f64) -> BaselineMetrics {
+    BaselineMetrics {
+        quench: Some(SelfTimeMetrics {
+            self_time_fast: Some(fast),
+            self_time_ci: Some(ci),
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn self_time_within_tolerance_passes() {
+    let baseline = self_time_baseline(2.0, 10.0);
+
+    let comparison =
+        compare_self_time(&self_time(false, 2.9), &baseline, &make_self_time_config()).unwrap();
+    assert_eq!(comparison.name, "quench.self_time_fast");
+    assert_eq!(comparison.threshold, 3.0);
+    assert!(comparison.passed);
+}
+
+#[test]
+fn self_time_regression_compares_same_mode() {
+    let baseline = self_time_baseline(2.0, 10.0);
+
+    // 8s is a regression for fast mode but not for CI mode
+    let config = make_self_time_config();
+    let ci = compare_self_time(&self_time(true, 8.0), &baseline, &config).unwrap();
+    assert_eq!(ci.name, "quench.self_time_ci");
+    assert!(ci.passed);
+    let fast = compare_self_time(&self_time(false, 8.0), &baseline, &config).unwrap();
+    assert!(!fast.passed);
+    assert_eq!(fast.format_value(fast.current), "8.0s");
+}
+
+#[test]
+fn self_time_short_runs_never_warn() {
+    let baseline = self_time_baseline(0.1, 0.1);
+
+    let comparison =
+        compare_self_time(&self_time(false, 0.5), &baseline, &make_self_time_config()).unwrap();
+    assert!(comparison.passed);
+}
+
+#[test]
+fn self_time_skipped_when_disabled_or_unrecorded() {
+    let disabled = RatchetConfig {
+        self_time: false,
+        ..make_self_time_config()
+    };
+    let current = self_time(false, 30.0);
+    assert!(compare_self_time(&current, &self_time_baseline(2.0, 10.0), &disabled).is_none());
+    assert!(
+        compare_self_time(
+            &current,
+            &BaselineMetrics::default(),
+            &make_self_time_config()
+        )
+        .is_none()
+    );
+}
+
+#[test]
+fn update_baseline_records_self_time_per_mode() {
+    let mut baseline = Baseline::new();
+    baseline.metrics = self_time_baseline(2.0, 10.0);
+    let current = CurrentMetrics {
+        self_time: Some(self_time(true, 12.5)),
+        ..Default::default()
+    };
+
+    update_baseline(&mut baseline, &current);
+
+    let recorded = baseline.metrics.quench.unwrap();
+    assert_eq!(recorded.self_time_fast, Some(2.0));
+    assert_eq!(recorded.self_time_ci, Some(12.5));
+}
@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Baseline updates from the current metrics.
+
+use std::collections::BTreeMap;
+
+use crate::anomaly::push_sample;
+use crate::baseline::{
+    AgentsMetrics as BaselineAgents, Baseline, BuildTimeMetrics as BaselineBuildTime,
+    CoverageMetrics as BaselineCoverage, EscapesMetrics as BaselineEscapes, MetricSample,
+    SelfTimeMetrics, SlowTest, TestTimeMetrics as BaselineTestTime,
+};
+
+use super::CurrentMetrics;
+
+/// Update baseline with current metrics.
+pub fn update_baseline(baseline: &mut Baseline, current: &CurrentMetrics) {
+    // Update escapes metrics
+    if let Some(curr_escapes) = &current.escapes {
+        let base_escapes = baseline
+            .metrics
+            .escapes
+            .get_or_insert_with(|| BaselineEscapes {
+                source: BTreeMap::new(),
+                test: None,
+                source_lines: None,
+                by_package: None,
+            });
+
+        // Update all source counts (baseline is always current snapshot)
+        for (pattern, &count) in &curr_escapes.source {
+            base_escapes.source.insert(pattern.clone(), count);
+        }
+
+        // Optionally track test counts
+        if !curr_escapes.test.is_empty() {
+            base_escapes.test = Some(curr_escapes.test.clone());
+        }
+
+        if curr_escapes.source_lines.is_some() {
+            base_escapes.source_lines = curr_escapes.source_lines;
+        }
+
+        if !curr_escapes.by_package.is_empty() {
+            base_escapes.by_package = Some(curr_escapes.by_package.clone());
+        }
+    }
+
+    // Replace unsafe counts (removed packages drop out)
+    if let Some(curr_unsafe) = &current.unsafe_code {
+        baseline.metrics.unsafe_code = Some(curr_unsafe.clone());
+    }
+
+    // Replace lint counts (rules cleared since the baseline drop out)
+    if let Some(curr_lint) = &current.lint {
+        baseline.metrics.lint = Some(curr_lint.clone());
+    }
+
+    // Replace unused export counts (languages no longer scanned drop out)
+    if let Some(curr_deadcode) = &current.deadcode {
+        baseline.metrics.deadcode = Some(curr_deadcode.clone());
+    }
+
+    // Replace dependency counts (ecosystems no longer present drop out)
+    if let Some(curr_deps) = &current.deps {
+        baseline.metrics.deps = Some(curr_deps.clone());
+    }
+
+    // Replace agent token counts (removed files drop out)
+    if let Some(curr_tokens) = &current.agent_tokens {
+        baseline.metrics.agents = Some(BaselineAgents {
+            total_tokens: curr_tokens.values().sum(),
+            files: curr_tokens.clone(),
+        });
+    }
+
+    // Replace test ratios (removed packages drop out)
+    if let Some(curr_ratio) = &current.test_ratio {
+        baseline.metrics.test_ratio = Some(curr_ratio.clone());
+    }
+
+    // Update coverage metrics
+    if let Some(curr_cov) = &current.coverage {
+        baseline.metrics.coverage = Some(BaselineCoverage {
+            total: curr_cov.total,
+            by_package: if curr_cov.by_package.is_empty() {
+                None
+            } else {
+                Some(curr_cov.by_package.clone())
+            },
+        });
+    }
+
+    // Update binary size metrics
+    if let Some(curr_sizes) = &current.binary_size {
+        let base_sizes = baseline
+            .metrics
+            .binary_size
+            .get_or_insert_with(BTreeMap::new);
+        for (target, &size) in curr_sizes {
+            base_sizes.insert(target.clone(), size);
+        }
+    }
+
+    // Update build time metrics
+    if let Some(curr_time) = &current.build_time {
+        let base_time = baseline
+            .metrics
+            .build_time
+            .get_or_insert(BaselineBuildTime {
+                cold: 0.0,
+                hot: 0.0,
+            });
+        if let Some(cold) = curr_time.cold {
+            base_time.cold = cold.as_secs_f64();
+        }
+        if let Some(hot) = curr_time.hot {
+            base_time.hot = hot.as_secs_f64();
+        }
+    }
+
+    // Update test time metrics
+    if let Some(curr_time) = &current.test_time {
+        baseline.metrics.test_time = Some(BaselineTestTime {
+            total: curr_time.total.as_secs_f64(),
+            avg: curr_time.avg.as_secs_f64(),
+            max: curr_time.max.as_secs_f64(),
+        });
+    }
+
+    // Append per-suite test times to their history
+    if !current.suite_times.is_empty() {
+        let history = baseline
+            .metrics
+            .test_time_history
+            .get_or_insert_with(BTreeMap::new);
+        for (suite, time) in &current.suite_times {
+            push_sample(
+                history.entry(suite.clone()).or_default(),
+                time.as_secs_f64(),
+            );
+        }
+    }
+
+    // Record quench's own run time for this mode
+    if let Some(self_time) = &current.self_time {
+        let recorded = baseline
+            .metrics
+            .quench
+            .get_or_insert_with(SelfTimeMetrics::default);
+        let secs = Some(self_time.elapsed.as_secs_f64());
+        if self_time.ci {
+            recorded.self_time_ci = secs;
+        } else {
+            recorded.self_time_fast = secs;
+        }
+    }
+
+    // Replace slowest tests, carrying over the times recorded for each test
+    if !current.slow_tests.is_empty() {
+        let base_slow = baseline
+            .metrics
+            .slow_tests
+            .get_or_insert_with(BTreeMap::new);
+        for (suite, tests) in &current.slow_tests {
+            let old = base_slow.remove(suite).unwrap_or_default();
+            let updated = tests
+                .iter()
+                .map(|(name, ms)| {
+                    let mut previous = Vec::new();
+                    if let Some(prior) = old.iter().find(|t| &t.name == name) {
+                        previous = prior.previous.clone();
+                        push_sample(&mut previous, prior.ms);
+                    }
+                    SlowTest {
+                        name: name.clone(),
+                        ms: *ms,
+                        previous,
+                    }
+                })
+                .collect();
+            base_slow.insert(suite.clone(), updated);
+        }
+    }
+
+    // Update timestamp
+    baseline.touch();
+
+    // Record goal metrics so reports can estimate velocity
+    if current.coverage.is_some() || current.escapes.is_some() {
+        let sample = MetricSample {
+            date: baseline.updated,
+            coverage: baseline.metrics.coverage.as_ref().map(|c| c.total),
+            escapes: baseline
+                .metrics
+                .escapes
+                .as_ref()
+                .map(|e| e.source.clone())
+                .unwrap_or_default(),
+            tags: baseline.tags.clone(),
+        };
+        push_sample(
+            baseline.metrics.history.get_or_insert_with(Vec::new),
+            sample,
+        );
+    }
+}
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Scheduled baseline refresh (`quench ratchet refresh`).
+//!
+//! Decides whether a fresh CI run should replace the stored baseline and
+//! summarizes the outcome for automation (e.g., opening a pull request).
+
+use serde::Serialize;
+
+use crate::baseline::Baseline;
+use crate::ratchet::RatchetResult;
+
+/// Why the baseline was (or would be) refreshed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefreshReason {
+    /// No baseline existed yet.
+    Missing,
+    /// At least one ratcheted metric improved.
+    Improved,
+    /// The baseline is older than `ratchet.stale_days`.
+    Stale,
+    /// Refresh was requested without `--if-stale`.
+    Forced,
+}
+
+/// Decide whether to refresh the baseline.
+///
+/// With `if_stale`, the baseline is only refreshed when it is missing,
+/// metrics improved, or it is older than `stale_days`, and never while a
+/// ratcheted metric regressed (that would lock in the regression).
+pub fn decide(
+    baseline: Option<&Baseline>,
+    ratchet: Option<&RatchetResult>,
+    stale_days: u32,
+    if_stale: bool,
+) -> Option<RefreshReason> {
+    if !if_stale {
+        return Some(RefreshReason::Forced);
+    }
+    let Some(baseline) = baseline else {
+        return Some(RefreshReason::Missing);
+    };
+    if ratchet.is_some_and(|r| !r.passed) {
+        return None;
+    }
+    if ratchet.is_some_and(|r| !r.improvements.is_empty()) {
+        Some(RefreshReason::Improved)
+    } else if baseline.is_stale(stale_days) {
+        Some(RefreshReason::Stale)
+    } else {
+        None
+    }
+}
+
+/// A metric that changed between the baseline and the current run.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricChange {
+    pub name: String,
+    pub baseline: f64,
+    pub current: f64,
+}
+
+/// Machine-readable result of a refresh run.
+#[derive(Debug, Clone, Serialize)]
+pub struct RefreshSummary {
+    /// Whether the baseline was written.
+    pub updated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<RefreshReason>,
+    /// Where the baseline is stored (`git notes` or a file path).
+    pub target: String,
+    /// Age of the previous baseline in days.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline_age_days: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    pub improvements: Vec<MetricChange>,
+    pub regressions: Vec<MetricChange>,
+    /// Suggested pull request title (when updated).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Suggested pull request body in Markdown (when updated).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+impl RefreshSummary {
+    /// Summarize a refresh decision.
+    pub fn new(
+        reason: Option<RefreshReason>,
+        target: impl Into<String>,
+        baseline: Option<&Baseline>,
+        ratchet: Option<&RatchetResult>,
+        commit: Option<String>,
+    ) -> Self {
+        let improvements: Vec<MetricChange> = ratchet
+            .map(|r| {
+                r.improvements
+                    .iter()
+                    .map(|i| MetricChange {
+                        name: i.name.clone(),
+                        baseline: i.old_value,
+                        current: i.new_value,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let regressions: Vec<MetricChange> = ratchet
+            .map(|r| {
+                r.comparisons
+                    .iter()
+                    .filter(|c| !c.passed)
+                    .map(|c| MetricChange {
+                        name: c.name.clone(),
+                        baseline: c.baseline,
+                        current: c.current,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut summary = Self {
+            updated: reason.is_some(),
+            reason,
+            target: target.into(),
+            baseline_age_days: baseline.map(Baseline::age_days),
+            commit,
+            improvements,
+            regressions,
+            title: None,
+            body: None,
+        };
+        if let Some(reason) = reason {
+            summary.title = Some(pr_title(reason).to_string());
+            summary.body = Some(summary.pr_body());
+        }
+        summary
+    }
+
+    /// One-line human-readable summary.
+    pub fn format_text(&self) -> String {
+        match self.reason {
+            Some(reason) => format!(
+                "ratchet refresh: updated baseline ({}, {})",
+                self.target,
+                reason_label(reason)
+            ),
+            None if !self.regressions.is_empty() => format!(
+                "ratchet refresh: skipped ({} metric(s) regressed)",
+                self.regressions.len()
+            ),
+            None => "ratchet refresh: baseline is current, nothing to do".to_string(),
+        }
+    }
+
+    fn pr_body(&self) -> String {
+        let mut body = String::from("Automated baseline refresh by `quench ratchet refresh`.\n\n");
+        if let Some(reason) = self.reason {
+            body.push_str(&format!("Reason: {}\n", reason_label(reason)));
+        }
+        if let Some(commit) = &self.commit {
+            body.push_str(&format!("Commit: {}\n", commit));
+        }
+        if !self.improvements.is_empty() {
+            body.push_str("\n| Metric | Baseline | Current |\n|--------|----------|---------|\n");
+            for change in &self.improvements {
+                body.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    change.name, change.baseline, change.current
+                ));
+            }
+        }
+        body
+    }
+}
+
+fn pr_title(reason: RefreshReason) -> &'static str {
+    match reason {
+        RefreshReason::Improved => "chore(quench): ratchet baseline to improved metrics",
+        _ => "chore(quench): refresh baseline",
+    }
+}
+
+fn reason_label(reason: RefreshReason) -> &'static str {
+    match reason {
+        RefreshReason::Missing => "no baseline",
+        RefreshReason::Improved => "metrics improved",
+        RefreshReason::Stale => "baseline stale",
+        RefreshReason::Forced => "requested",
+    }
+}
+
+#[cfg(test)]
+#[path = "refresh_tests.rs"]
+mod tests;
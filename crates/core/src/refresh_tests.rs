@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::ratchet::{MetricComparison, MetricImprovement};
+
+fn aged(days: i64) -> Baseline {
+    let mut baseline = Baseline::new();
+    baseline.updated = chrono::Utc::now() - chrono::Duration::days(days);
+    baseline
+}
+
+fn result(passed: bool, improved: bool) -> RatchetResult {
+    let comparison = MetricComparison {
+        name: "escapes.unsafe".to_string(),
+        current: if improved { 2.0 } else { 5.0 },
+        baseline: 3.0,
+        tolerance: 0.0,
+        threshold: 3.0,
+        passed,
+        improved,
+    };
+    RatchetResult {
+        passed,
+        improvements: if improved {
+            vec![MetricImprovement {
+                name: comparison.name.clone(),
+                old_value: comparison.baseline,
+                new_value: comparison.current,
+            }]
+        } else {
+            Vec::new()
+        },
+        comparisons: vec![comparison],
+        owners: Default::default(),
+        packages: Default::default(),
+    }
+}
+
+#[test]
+fn without_if_stale_always_refreshes() {
+    let baseline = aged(0);
+    let ratchet = result(false, false);
+    assert_eq!(
+        decide(Some(&baseline), Some(&ratchet), 30, false),
+        Some(RefreshReason::Forced)
+    );
+}
+
+#[test]
+fn missing_baseline_refreshes() {
+    assert_eq!(decide(None, None, 30, true), Some(RefreshReason::Missing));
+}
+
+#[test]
+fn improvement_refreshes_fresh_baseline() {
+    let baseline = aged(1);
+    let ratchet = result(true, true);
+    assert_eq!(
+        decide(Some(&baseline), Some(&ratchet), 30, true),
+        Some(RefreshReason::Improved)
+    );
+}
+
+#[test]
+fn stale_baseline_refreshes() {
+    let baseline = aged(45);
+    let ratchet = result(true, false);
+    assert_eq!(
+        decide(Some(&baseline), Some(&ratchet), 30, true),
+        Some(RefreshReason::Stale)
+    );
+}
+
+#[test]
+fn current_baseline_is_left_alone() {
+    let baseline = aged(1);
+    let ratchet = result(true, false);
+    assert_eq!(decide(Some(&baseline), Some(&ratchet), 30, true), None);
+    // stale_days = 0 disables staleness
+    assert_eq!(decide(Some(&aged(400)), Some(&ratchet), 0, true), None);
+}
+
+#[test]
+fn regression_blocks_refresh() {
+    let baseline = aged(45);
+    let ratchet = result(false, false);
+    assert_eq!(decide(Some(&baseline), Some(&ratchet), 30, true), None);
+
+    let summary = RefreshSummary::new(None, "git notes", Some(&baseline), Some(&ratchet), None);
+    assert!(!summary.updated);
+    assert_eq!(summary.regressions.len(), 1);
+    assert!(summary.title.is_none());
+    assert!(summary.format_text().contains("1 metric(s) regressed"));
+}
+
+#[test]
+fn updated_summary_includes_pr_fields() {
+    let baseline = aged(1);
+    let ratchet = result(true, true);
+    let summary = RefreshSummary::new(
+        Some(RefreshReason::Improved),
+        ".quench/baseline.json",
+        Some(&baseline),
+        Some(&ratchet),
+        Some("abc1234".to_string()),
+    );
+
+    assert!(summary.updated);
+    assert_eq!(summary.baseline_age_days, Some(1));
+    assert_eq!(
+        summary.title.as_deref(),
+        Some("chore(quench): ratchet baseline to improved metrics")
+    );
+    let body = summary.body.as_deref().unwrap();
+    assert!(body.contains("Commit: abc1234"));
+    assert!(body.contains("| escapes.unsafe | 3 | 2 |"));
+
+    let json = serde_json::to_value(&summary).unwrap();
+    assert_eq!(json["reason"], "improved");
+    assert_eq!(json["improvements"][0]["current"], 2.0);
+}
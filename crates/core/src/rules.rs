@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Stable rule IDs and per-rule enablement.
+//!
+//! A rule ID names one violation type of one check: `<check>/<type>`, with
+//! the violation type's underscores written as hyphens
+//! (e.g., `cloc/file-too-large`, `escapes/suppress-missing-comment`).
+//!
+//! Rules are filtered after checks run, so metrics are unaffected. With a
+//! filter, checks run without the violation limit, so dropped violations
+//! can't crowd out kept ones. A rule of a check also covers its named
+//! instances (`cloc/file-too-large` applies to `cloc:scripts`); an instance
+//! rule (`cloc:scripts/file-too-large`) covers only that instance.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::check::CheckResult;
+use crate::checks::{CHECK_NAMES, MODE_CHECK_NAMES};
+use crate::config::INSTANCE_SEPARATOR;
+
+/// Build the rule ID for a check's violation type.
+pub fn rule_id(check: &str, violation_type: &str) -> String {
+    format!("{}/{}", check, violation_type.replace('_', "-"))
+}
+
+/// Errors from invalid rule IDs.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum RuleError {
+    #[error("invalid rule ID '{0}': expected <check>/<rule> (e.g., cloc/file-too-large)")]
+    Malformed(String),
+
+    #[error("unknown check '{check}' in rule ID '{id}'")]
+    UnknownCheck { id: String, check: String },
+}
+
+/// Normalize and validate a rule ID (underscores are accepted for hyphens).
+pub fn parse_rule_id(id: &str) -> Result<String, RuleError> {
+    let normalized = id.trim().replace('_', "-");
+    let Some((check, rule)) = normalized.split_once('/') else {
+        return Err(RuleError::Malformed(id.to_string()));
+    };
+    if rule.is_empty() || rule.contains('/') {
+        return Err(RuleError::Malformed(id.to_string()));
+    }
+    let base = check
+        .split_once(INSTANCE_SEPARATOR)
+        .map_or(check, |(base, _)| base);
+    if !CHECK_NAMES.contains(&base) && !MODE_CHECK_NAMES.contains(&base) {
+        return Err(RuleError::UnknownCheck {
+            id: id.to_string(),
+            check: check.to_string(),
+        });
+    }
+    Ok(normalized)
+}
+
+/// Which rules may report violations.
+#[derive(Debug, Default)]
+pub struct RuleFilter {
+    /// If non-empty, only these rules report.
+    only: BTreeSet<String>,
+    /// Rules that never report.
+    skip: BTreeSet<String>,
+}
+
+impl RuleFilter {
+    /// Build a filter from `--only-rule`, `--skip-rule`, and the `[rules]` config map.
+    ///
+    /// Config entries set to `false` are skipped; `--only-rule` takes
+    /// precedence over config so a disabled rule can still be inspected.
+    pub fn new(
+        only: &[String],
+        skip: &[String],
+        config: &BTreeMap<String, bool>,
+    ) -> Result<Self, RuleError> {
+        let only: BTreeSet<String> = only
+            .iter()
+            .map(|id| parse_rule_id(id))
+            .collect::<Result<_, _>>()?;
+        let mut skip: BTreeSet<String> = skip
+            .iter()
+            .map(|id| parse_rule_id(id))
+            .collect::<Result<_, _>>()?;
+        for (id, enabled) in config {
+            let id = parse_rule_id(id)?;
+            if !enabled && !only.contains(&id) {
+                skip.insert(id);
+            }
+        }
+        Ok(Self { only, skip })
+    }
+
+    /// Whether the filter allows every rule.
+    pub fn is_empty(&self) -> bool {
+        self.only.is_empty() && self.skip.is_empty()
+    }
+
+    /// Whether a violation type of a check may report.
+    pub fn allows(&self, check: &str, violation_type: &str) -> bool {
+        let mut ids = vec![rule_id(check, violation_type)];
+        if let Some((base, _)) = check.split_once(INSTANCE_SEPARATOR) {
+            ids.push(rule_id(base, violation_type));
+        }
+        (self.only.is_empty() || ids.iter().any(|id| self.only.contains(id)))
+            && !ids.iter().any(|id| self.skip.contains(id))
+    }
+
+    /// Drop filtered violations. A failed check whose violations were all
+    /// filtered out passes.
+    pub fn apply(&self, results: &mut [CheckResult]) {
+        if self.is_empty() {
+            return;
+        }
+        for result in results {
+            let before = result.violations.len();
+            result
+                .violations
+                .retain(|v| self.allows(&result.name, &v.violation_type));
+            let removed = before != result.violations.len();
+            if removed && result.violations.is_empty() && !result.skipped && result.error.is_none()
+            {
+                result.passed = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "rules_tests.rs"]
+mod tests;
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::check::Violation;
+use yare::parameterized;
+
+fn filter(only: &[&str], skip: &[&str], config: &[(&str, bool)]) -> RuleFilter {
+    let only: Vec<String> = only.iter().map(|s| s.to_string()).collect();
+    let skip: Vec<String> = skip.iter().map(|s| s.to_string()).collect();
+    let config = config.iter().map(|(k, v)| (k.to_string(), *v)).collect();
+    RuleFilter::new(&only, &skip, &config).unwrap()
+}
+
+#[test]
+fn rule_id_hyphenates_violation_type() {
+    assert_eq!(rule_id("cloc", "file_too_large"), "cloc/file-too-large");
+}
+
+#[parameterized(
+    hyphenated = { "cloc/file-too-large", "cloc/file-too-large" },
+    underscored = { "escapes/suppress_missing_comment", "escapes/suppress-missing-comment" },
+    trimmed = { " git/invalid-type ", "git/invalid-type" },
+)]
+fn parse_rule_id_normalizes(input: &str, expected: &str) {
+    assert_eq!(parse_rule_id(input).unwrap(), expected);
+}
+
+#[parameterized(
+    no_slash = { "cloc" },
+    empty_rule = { "cloc/" },
+    nested = { "cloc/a/b" },
+)]
+fn parse_rule_id_rejects_malformed(input: &str) {
+    assert!(matches!(parse_rule_id(input), Err(RuleError::Malformed(_))));
+}
+
+#[test]
+fn parse_rule_id_rejects_unknown_check() {
+    assert!(matches!(
+        parse_rule_id("style/thing"),
+        Err(RuleError::UnknownCheck { .. })
+    ));
+}
+
+#[test]
+fn empty_filter_allows_everything() {
+    let f = filter(&[], &[], &[]);
+    assert!(f.is_empty());
+    assert!(f.allows("cloc", "file_too_large"));
+}
+
+#[test]
+fn only_restricts_to_listed_rules() {
+    let f = filter(&["cloc/file-too-large"], &[], &[]);
+    assert!(f.allows("cloc", "file_too_large"));
+    assert!(!f.allows("cloc", "inline_cfg_test"));
+    assert!(!f.allows("escapes", "forbidden"));
+}
+
+#[test]
+fn skip_and_config_disable_rules() {
+    let f = filter(
+        &[],
+        &["cloc/file-too-large"],
+        &[("escapes/forbidden", false)],
+    );
+    assert!(!f.allows("cloc", "file_too_large"));
+    assert!(!f.allows("escapes", "forbidden"));
+    assert!(f.allows("escapes", "missing_comment"));
+}
+
+#[test]
+fn only_overrides_config_disable() {
+    let f = filter(&["escapes/forbidden"], &[], &[("escapes/forbidden", false)]);
+    assert!(f.allows("escapes", "forbidden"));
+}
+
+#[test]
+fn apply_passes_check_when_all_violations_filtered() {
+    let mut results = vec![
+        CheckResult::failed(
+            "cloc",
+            vec![Violation::file("a.rs", 1, "file_too_large", "split")],
+        ),
+        CheckResult::failed(
+            "escapes",
+            vec![
+                Violation::file("a.rs", 1, "forbidden", "remove"),
+                Violation::file("b.rs", 2, "missing_comment", "justify"),
+            ],
+        ),
+    ];
+    filter(&[], &["cloc/file-too-large", "escapes/forbidden"], &[]).apply(&mut results);
+
+    assert!(results[0].passed);
+    assert!(results[0].violations.is_empty());
+    assert!(!results[1].passed);
+    assert_eq!(results[1].violations.len(), 1);
+}
+
+#[test]
+fn parse_rule_id_accepts_check_instance() {
+    assert_eq!(
+        parse_rule_id("cloc:scripts/file_too_large"),
+        Ok("cloc:scripts/file-too-large".to_string())
+    );
+    assert!(matches!(
+        parse_rule_id("style:scripts/thing"),
+        Err(RuleError::UnknownCheck { .. })
+    ));
+}
+
+#[test]
+fn check_rule_covers_its_instances() {
+    let f = filter(&[], &["cloc/file-too-large"], &[]);
+    assert!(!f.allows("cloc:scripts", "file_too_large"));
+}
+
+#[test]
+fn instance_rule_covers_only_that_instance() {
+    let f = filter(&[], &["cloc:scripts/file-too-large"], &[]);
+    assert!(!f.allows("cloc:scripts", "file_too_large"));
+    assert!(f.allows("cloc:tools", "file_too_large"));
+    assert!(f.allows("cloc", "file_too_large"));
+}
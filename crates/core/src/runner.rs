@@ -0,0 +1,651 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Parallel check runner with error recovery and caching.
+//!
+//! Runs checks in parallel using rayon, isolating errors so one
+//! check failure doesn't prevent other checks from running.
+//! Supports file-level caching for faster iterative runs. Path scopes
+//! (`[scope."<glob>"]`) are applied to each check's result per file.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::time::Instant;
+
+use rayon::prelude::*;
+
+use crate::cache::{CachedViolation, ContentHash, FileCache, FileCacheKey, Lookup, PathContext};
+use crate::check::{Check, CheckContext, CheckResult, Field, Violation, ViolationRef};
+use crate::config::{Config, Sample};
+use crate::diagnostics::Diagnostics;
+use crate::file_reader::{DecodeLog, StagedContent};
+use crate::fix;
+use crate::interrupt;
+use crate::patch::FixPatch;
+use crate::schedule::{longest_first, par_map_largest_first};
+use crate::scope::Scopes;
+use crate::walker::WalkedFile;
+
+/// Cached violations for a file (Arc for O(1) clone).
+type CachedViolationsArc = Arc<Vec<CachedViolation>>;
+
+/// Configuration for the check runner.
+#[derive(Default)]
+pub struct RunnerConfig {
+    /// Maximum violations before early termination (None = unlimited).
+    pub limit: Option<usize>,
+    /// Files changed since base ref (for --base flag).
+    pub changed_files: Option<Vec<PathBuf>>,
+    /// Whether to automatically fix violations when possible.
+    pub fix: bool,
+    /// Whether check fixers run over the violations (`quench fix`).
+    pub run_fixers: bool,
+    /// Show what --fix would change without modifying files.
+    pub dry_run: bool,
+    /// Collects fixes instead of writing them (--emit-patch).
+    pub patch: Option<Arc<FixPatch>>,
+    /// Whether running in CI mode (enables slow checks like commit validation).
+    pub ci_mode: bool,
+    /// Base branch for commit comparison in CI mode.
+    pub base_branch: Option<String>,
+    /// Whether checking only staged changes (--staged flag).
+    pub staged: bool,
+    /// Staged content read in place of the working tree (--staged flag).
+    pub staged_content: Option<Arc<StagedContent>>,
+    /// Whether verbose diagnostic output is enabled.
+    pub verbose: bool,
+    /// Seed that picks the files of checks with `sample` (e.g., the HEAD
+    /// commit). None checks every file.
+    pub sample_seed: Option<String>,
+    /// Worker threads for checks and their file scans (None = one per CPU).
+    pub jobs: Option<usize>,
+}
+
+impl RunnerConfig {
+    /// Build a CheckContext from this configuration.
+    fn build_context<'a>(
+        &'a self,
+        root: &'a Path,
+        files: &'a [WalkedFile],
+        config: &'a Config,
+        violation_count: &'a AtomicUsize,
+        decode_log: &'a DecodeLog,
+        diagnostics: &'a Diagnostics,
+    ) -> CheckContext<'a> {
+        CheckContext {
+            root,
+            files,
+            config,
+            limit: self.limit,
+            violation_count,
+            changed_files: self.changed_files.as_deref(),
+            fix: self.fix,
+            run_fixers: self.run_fixers,
+            dry_run: self.dry_run,
+            patch: self.patch.as_deref(),
+            ci_mode: self.ci_mode,
+            base_branch: self.base_branch.as_deref(),
+            staged: self.staged,
+            staged_content: self.staged_content.as_deref(),
+            verbose: self.verbose,
+            decode_log,
+            diagnostics,
+        }
+    }
+
+    /// Seed and rate for a check that samples its files this run.
+    fn sample(&self, check: &str, config: &Config) -> Option<(&str, Sample)> {
+        Some((self.sample_seed.as_deref()?, config.check.sample(check)?))
+    }
+
+    /// The files a check scans: a sample when it has one, else all of them.
+    fn sampled(
+        &self,
+        check: &str,
+        files: &[WalkedFile],
+        config: &Config,
+        root: &Path,
+    ) -> Option<Vec<WalkedFile>> {
+        let (seed, sample) = self.sample(check, config)?;
+        let files = files
+            .iter()
+            .filter(|f| sample.includes(seed, f.path.strip_prefix(root).unwrap_or(&f.path)))
+            .map(|f| WalkedFile {
+                path: f.path.clone(),
+                size: f.size,
+                mtime_secs: f.mtime_secs,
+                mtime_nanos: f.mtime_nanos,
+                depth: f.depth,
+                size_class: f.size_class,
+            })
+            .collect();
+        Some(files)
+    }
+}
+
+/// Drop a sampled result's metrics, which only cover part of the project.
+fn partial(mut result: CheckResult) -> CheckResult {
+    result.metrics = None;
+    result.by_package = None;
+    result
+}
+
+/// Stand in for a check that Ctrl-C stopped or kept from starting; its
+/// findings would be incomplete.
+fn interrupted(check: &str) -> CheckResult {
+    CheckResult::skipped(check, "interrupted")
+}
+
+/// Progress of one check, reported while checks run.
+pub enum CheckEvent<'a> {
+    /// The check began running.
+    Started(&'a str),
+    /// The check completed with this result.
+    Finished(&'a CheckResult),
+}
+
+/// The check runner executes multiple checks in parallel.
+pub struct CheckRunner {
+    config: RunnerConfig,
+    cache: Option<Arc<FileCache>>,
+    decode_log: DecodeLog,
+    diagnostics: Arc<Diagnostics>,
+}
+
+impl CheckRunner {
+    pub fn new(config: RunnerConfig) -> Self {
+        Self {
+            config,
+            cache: None,
+            decode_log: DecodeLog::default(),
+            diagnostics: Arc::new(Diagnostics::default()),
+        }
+    }
+
+    /// Enable caching for this runner.
+    pub fn with_cache(mut self, cache: Arc<FileCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Report diagnostics from checks to this collector.
+    pub fn with_diagnostics(mut self, diagnostics: Arc<Diagnostics>) -> Self {
+        self.diagnostics = diagnostics;
+        self
+    }
+
+    /// Files the checks decoded lossily (invalid UTF-8), sorted.
+    pub fn lossy_files(&self) -> Vec<PathBuf> {
+        self.decode_log.lossy()
+    }
+
+    /// Run all provided checks and return results.
+    ///
+    /// Checks run in parallel. Errors are isolated - one check failing
+    /// doesn't prevent other checks from running.
+    ///
+    /// If a cache is configured, files with valid cache entries will
+    /// use cached violations instead of re-running checks.
+    pub fn run(
+        &self,
+        checks: Vec<Arc<dyn Check>>,
+        files: &[WalkedFile],
+        config: &Config,
+        root: &Path,
+    ) -> Vec<CheckResult> {
+        self.run_with(checks, files, config, root, &|_| {})
+    }
+
+    /// Run all provided checks, calling `on_event` as each one starts and
+    /// completes.
+    ///
+    /// `on_event` is called from worker threads as checks progress. Finished
+    /// events carry the same result that is later returned, so callers can
+    /// stream output.
+    pub fn run_with(
+        &self,
+        checks: Vec<Arc<dyn Check>>,
+        files: &[WalkedFile],
+        config: &Config,
+        root: &Path,
+        on_event: &(dyn Fn(CheckEvent) + Sync),
+    ) -> Vec<CheckResult> {
+        if let Some(jobs) = self.config.jobs {
+            match rayon::ThreadPoolBuilder::new().num_threads(jobs).build() {
+                Ok(pool) => {
+                    return pool
+                        .install(|| self.run_in_pool(checks, files, config, root, on_event));
+                }
+                Err(e) => tracing::warn!("cannot start {} worker threads: {}", jobs, e),
+            }
+        }
+        self.run_in_pool(checks, files, config, root, on_event)
+    }
+
+    /// Run checks on the current rayon pool.
+    fn run_in_pool(
+        &self,
+        checks: Vec<Arc<dyn Check>>,
+        files: &[WalkedFile],
+        config: &Config,
+        root: &Path,
+        on_event: &(dyn Fn(CheckEvent) + Sync),
+    ) -> Vec<CheckResult> {
+        // If no cache, run checks normally. Fixers need every violation,
+        // not just those in changed files, so `quench fix` runs uncached too.
+        let Some(cache) = self.cache.as_ref().filter(|_| !self.config.run_fixers) else {
+            return self.run_uncached(checks, files, config, root, on_event);
+        };
+
+        // Separate files into cached and uncached
+        // Pre-size for expected distribution (optimized for warm cache case).
+        // Cold runs will reallocate, but that's acceptable as they're infrequent
+        // (~4 reallocations worst case, negligible vs check work).
+        let file_count = files.len();
+        let mut cached_violations: HashMap<PathBuf, CachedViolationsArc> =
+            HashMap::with_capacity(file_count);
+        // Expect ~10% cache miss on warm runs. Cold runs will reallocate.
+        let mut uncached_files: Vec<&WalkedFile> = Vec::with_capacity(file_count / 10 + 1);
+        // Content hashes of uncached files, to insert their results under
+        let mut miss_hashes: HashMap<PathBuf, ContentHash> = HashMap::new();
+
+        // Files whose path index is stale are read and hashed, so look up in parallel
+        let context = PathContext::new(root, config);
+        let staged = self.config.staged_content.as_deref();
+        let lookups: Vec<Lookup> = par_map_largest_first(
+            files,
+            |file| file.size,
+            |file| {
+                // The cache describes working tree content, not the index
+                if staged.is_some_and(|staged| staged.contains(&file.path)) {
+                    return Lookup::Miss(None);
+                }
+                let key = FileCacheKey::from_walked_file(file);
+                cache.lookup_file(&file.path, &key, || {
+                    let content = std::fs::read(&file.path).ok()?;
+                    let relative = file.path.strip_prefix(root).unwrap_or(&file.path);
+                    Some(context.content_hash(relative, &content))
+                })
+            },
+        );
+
+        for (file, lookup) in files.iter().zip(lookups) {
+            match lookup {
+                // Arc clone is O(1) - just increments refcount
+                Lookup::Hit(violations) => {
+                    cached_violations.insert(file.path.clone(), violations);
+                }
+                Lookup::Miss(hash) => {
+                    if let Some(hash) = hash {
+                        miss_hashes.insert(file.path.clone(), hash);
+                    }
+                    uncached_files.push(file);
+                }
+            }
+        }
+
+        // Build owned files for uncached (needed for CheckContext)
+        // Note: We need owned WalkedFiles for the context, so we clone
+        let uncached_owned: Vec<WalkedFile> = uncached_files
+            .iter()
+            .map(|f| WalkedFile {
+                path: f.path.clone(),
+                size: f.size,
+                mtime_secs: f.mtime_secs,
+                mtime_nanos: f.mtime_nanos,
+                depth: f.depth,
+                size_class: f.size_class,
+            })
+            .collect();
+
+        // Checks that relate files to each other never use or fill the cache
+        let uncacheable: HashSet<&str> = checks
+            .iter()
+            .filter(|check| !check.cacheable())
+            .map(|check| check.name())
+            .collect();
+        // Checks that rerun on every file: stale ones and uncacheable ones
+        let rerun_all: HashSet<&str> = checks
+            .iter()
+            .map(|check| check.name())
+            .filter(|name| cache.is_stale(name) || uncacheable.contains(name))
+            .collect();
+
+        // Files a sampled check skipped stay uncached, so later runs check them
+        let unsampled: HashSet<PathBuf> = checks
+            .iter()
+            .filter(|check| !rerun_all.contains(check.name()))
+            .filter_map(|check| self.config.sample(check.name(), config))
+            .flat_map(|(seed, sample)| {
+                uncached_files.iter().filter(move |f| {
+                    !sample.includes(seed, f.path.strip_prefix(root).unwrap_or(&f.path))
+                })
+            })
+            .map(|f| f.path.clone())
+            .collect();
+
+        let violation_count = AtomicUsize::new(0);
+        let scopes = Scopes::new(&config.scopes);
+
+        // Start the slowest checks first so they don't finish the run alone
+        let mut checks = checks;
+        longest_first(&mut checks, |name| cache.check_duration(name));
+
+        // Run checks on uncached files
+        let results: Vec<CheckResult> = checks
+            .into_par_iter()
+            .map(|check| {
+                let check_name = check.name();
+                // A check whose config changed, or that relates files to each
+                // other, reruns on every file
+                let stale = rerun_all.contains(check_name);
+
+                // Get cached violations for this check
+                let cached_for_check: Vec<Violation> = cached_violations
+                    .iter()
+                    .filter(|_| !stale)
+                    .flat_map(|(path, violations)| {
+                        violations
+                            .iter()
+                            .filter(|v| v.check == check_name)
+                            .filter(|v| {
+                                // For docs violations with target paths (broken_link, broken_toc),
+                                // validate that the target still doesn't exist. If the target now
+                                // exists (e.g., created by --fix), the cached violation is stale.
+                                if check_name == "docs"
+                                    && let Some(target) = &v.target_path
+                                    && let Some(parent) = path.parent()
+                                {
+                                    // Normalize ./prefix and resolve relative to source directory
+                                    let normalized = target.strip_prefix("./").unwrap_or(target);
+                                    let resolved = parent.join(normalized);
+                                    // Skip this cached violation if target now exists
+                                    if resolved.exists() {
+                                        return false;
+                                    }
+                                }
+                                true
+                            })
+                            .map(|v| {
+                                // Convert to relative path for display
+                                let display_path = path.strip_prefix(root).unwrap_or(path);
+                                v.to_violation(display_path.to_path_buf())
+                            })
+                    })
+                    .collect();
+
+                let check_files = if stale { files } else { &uncached_owned[..] };
+                // A stale check rescans everything to refresh the cache
+                let sampled = if stale {
+                    None
+                } else {
+                    self.config.sampled(check_name, check_files, config, root)
+                };
+                let check_files = sampled.as_deref().unwrap_or(check_files);
+                let ctx = self.config.build_context(
+                    root,
+                    check_files,
+                    config,
+                    &violation_count,
+                    &self.decode_log,
+                    &self.diagnostics,
+                );
+
+                // Run check on uncached files (all files if stale) with timing
+                on_event(CheckEvent::Started(check_name));
+                let check_start = Instant::now();
+                let mut result = if interrupt::requested() {
+                    interrupted(check_name)
+                } else {
+                    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                        let result = scopes.apply(check.as_ref(), check.run(&ctx), &ctx);
+                        fix::apply(check.as_ref(), result, &ctx)
+                    })) {
+                        Ok(result) => result,
+                        Err(_) => CheckResult::skipped(
+                            check_name,
+                            "Internal error: check panicked".to_string(),
+                        ),
+                    }
+                };
+                result.duration_ms = Some(check_start.elapsed().as_millis() as u64);
+                if sampled.is_some() {
+                    result = partial(result);
+                }
+                crate::metrics::validate(&mut result, &self.diagnostics);
+
+                // Merge cached violations into result
+                let result = if cached_for_check.is_empty() {
+                    result.sort_violations();
+                    result
+                } else {
+                    let mut all_violations = cached_for_check;
+                    all_violations.extend(result.violations);
+
+                    let passed = all_violations.is_empty() && !result.skipped;
+                    let mut merged = CheckResult {
+                        name: result.name,
+                        passed,
+                        skipped: result.skipped,
+                        stub: result.stub,
+                        fixed: result.fixed,
+                        error: result.error,
+                        violations: all_violations,
+                        fix_summary: result.fix_summary,
+                        metrics: result.metrics,
+                        by_package: result.by_package,
+                        duration_ms: result.duration_ms,
+                    };
+                    merged.sort_violations();
+                    merged
+                };
+                // Still running at Ctrl-C, so possibly cut short: report it
+                // as interrupted and have the next run redo it on every file
+                let result = if interrupt::requested() {
+                    cache.invalidate(check_name);
+                    interrupted(check_name)
+                } else {
+                    result
+                };
+                on_event(CheckEvent::Finished(&result));
+                result
+            })
+            .collect();
+
+        // Update cache with violations from newly processed files
+        // Group violations by file path
+        // Pre-size for uncached file count (Phase 3 optimization)
+        let mut violations_by_file: HashMap<PathBuf, Vec<CachedViolation>> =
+            HashMap::with_capacity(uncached_files.len());
+        // Fresh violations of stale checks in files that were cache hits
+        let mut stale_by_file: HashMap<PathBuf, Vec<CachedViolation>> = HashMap::new();
+
+        // Build a set for O(1) lookup instead of O(n) linear search
+        let processed_paths: std::collections::HashSet<&Path> =
+            uncached_files.iter().map(|f| f.path.as_path()).collect();
+
+        for result in &results {
+            if uncacheable.contains(result.name.as_str()) {
+                continue;
+            }
+            for violation in &result.violations {
+                if let Some(file_path) = &violation.file {
+                    // Only cache violations from files we just processed
+                    let abs_path = if file_path.is_absolute() {
+                        file_path.clone()
+                    } else {
+                        root.join(file_path)
+                    };
+
+                    // Check if this file was in uncached_files (O(1) lookup)
+                    if processed_paths.contains(abs_path.as_path()) {
+                        violations_by_file
+                            .entry(abs_path)
+                            .or_default()
+                            .push(CachedViolation::from_violation(violation, &result.name));
+                    } else if cache.is_stale(&result.name)
+                        && cached_violations.contains_key(&abs_path)
+                    {
+                        stale_by_file
+                            .entry(abs_path)
+                            .or_default()
+                            .push(CachedViolation::from_violation(violation, &result.name));
+                    }
+                }
+            }
+        }
+
+        // Insert all processed files into cache (including those with no violations)
+        for file in &uncached_files {
+            // Unreadable files have no content hash and stay uncached
+            let Some(hash) = miss_hashes.remove(&file.path) else {
+                continue;
+            };
+            if unsampled.contains(&file.path) {
+                continue;
+            }
+            let key = FileCacheKey::from_walked_file(file);
+            let violations = violations_by_file.remove(&file.path).unwrap_or_default();
+            cache.insert(file.path.clone(), key, hash, violations);
+        }
+        cache.extend(stale_by_file);
+
+        // Remember check durations for --budget scheduling
+        for result in &results {
+            if let (false, Some(ms)) = (result.skipped, result.duration_ms) {
+                cache.record_check_duration(&result.name, ms);
+            }
+        }
+
+        // Sort results by canonical check order for consistent output
+        let mut sorted = results;
+        sorted.sort_by_key(|r| crate::checks::order_key(&r.name));
+
+        sorted
+    }
+
+    /// Run checks without caching.
+    fn run_uncached(
+        &self,
+        checks: Vec<Arc<dyn Check>>,
+        files: &[WalkedFile],
+        config: &Config,
+        root: &Path,
+        on_event: &(dyn Fn(CheckEvent) + Sync),
+    ) -> Vec<CheckResult> {
+        let violation_count = AtomicUsize::new(0);
+        let scopes = Scopes::new(&config.scopes);
+
+        // Run checks in parallel
+        let results: Vec<CheckResult> = checks
+            .into_par_iter()
+            .map(|check| {
+                let sampled = self.config.sampled(check.name(), files, config, root);
+                let ctx = self.config.build_context(
+                    root,
+                    sampled.as_deref().unwrap_or(files),
+                    config,
+                    &violation_count,
+                    &self.decode_log,
+                    &self.diagnostics,
+                );
+
+                // Catch panics to ensure error isolation, with timing
+                on_event(CheckEvent::Started(check.name()));
+                let check_start = Instant::now();
+                let mut result = if interrupt::requested() {
+                    interrupted(check.name())
+                } else {
+                    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                        let result = scopes.apply(check.as_ref(), check.run(&ctx), &ctx);
+                        fix::apply(check.as_ref(), result, &ctx)
+                    })) {
+                        Ok(result) => result,
+                        Err(_) => {
+                            // Check panicked - return skipped result
+                            CheckResult::skipped(
+                                check.name(),
+                                "Internal error: check panicked".to_string(),
+                            )
+                        }
+                    }
+                };
+                result.duration_ms = Some(check_start.elapsed().as_millis() as u64);
+                if sampled.is_some() {
+                    result = partial(result);
+                }
+                crate::metrics::validate(&mut result, &self.diagnostics);
+                result.sort_violations();
+                // Still running at Ctrl-C, so possibly cut short
+                if interrupt::requested() {
+                    result = interrupted(check.name());
+                }
+                on_event(CheckEvent::Finished(&result));
+                result
+            })
+            .collect();
+
+        // Sort results by canonical check order for consistent output
+        let mut sorted = results;
+        sorted.sort_by_key(|r| crate::checks::order_key(&r.name));
+
+        sorted
+    }
+
+    /// Check if early termination is needed based on violation count.
+    pub fn should_terminate(&self, violation_count: usize) -> bool {
+        if let Some(limit) = self.config.limit {
+            violation_count >= limit
+        } else {
+            false
+        }
+    }
+}
+
+/// Group violations that different rules report on the same line.
+///
+/// Within each `file:line`, the first violation from a failing check (in
+/// canonical check order) is the primary; the rest are marked as grouped
+/// under it, so output can list one finding instead of near-duplicates.
+/// Violations are kept, so counts and exit codes are unchanged.
+pub fn correlate_violations(results: &mut [CheckResult]) {
+    let mut by_line: BTreeMap<(&Path, u32), Vec<(usize, usize)>> = BTreeMap::new();
+    for (ri, result) in results.iter().enumerate() {
+        for (vi, violation) in result.violations.iter().enumerate() {
+            if let (Some(file), Some(line)) = (&violation.file, violation.line) {
+                by_line.entry((file, line)).or_default().push((ri, vi));
+            }
+        }
+    }
+
+    let mut grouped = Vec::new();
+    for members in by_line.into_values().filter(|m| m.len() > 1) {
+        let Some(&(pr, pv)) = members
+            .iter()
+            .min_by_key(|&&(ri, vi)| (results[ri].passed, ri, vi))
+        else {
+            continue;
+        };
+        let primary = ViolationRef {
+            check: results[pr].name.clone(),
+            violation_type: results[pr].violations[pv].violation_type.clone(),
+        };
+        grouped.extend(
+            members
+                .into_iter()
+                .filter(|&m| m != (pr, pv))
+                .map(|m| (m, primary.clone())),
+        );
+    }
+    for ((ri, vi), primary) in grouped {
+        results[ri].violations[vi]
+            .details
+            .insert(Field::Primary, primary);
+    }
+}
+
+#[cfg(test)]
+#[path = "runner_tests.rs"]
+mod tests;
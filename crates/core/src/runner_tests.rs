@@ -0,0 +1,448 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for the check runner.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use super::*;
+use crate::check::{Check, CheckContext, CheckResult, Violation, ViolationRef};
+
+/// Mock check that can be configured to pass, fail, or panic.
+struct MockCheck {
+    name: &'static str,
+    behavior: MockBehavior,
+    ran: AtomicBool,
+}
+
+enum MockBehavior {
+    Pass,
+    Fail(usize), // Number of violations
+    Panic,
+}
+
+impl MockCheck {
+    fn new(name: &'static str, behavior: MockBehavior) -> Self {
+        Self {
+            name,
+            behavior,
+            ran: AtomicBool::new(false),
+        }
+    }
+
+    fn did_run(&self) -> bool {
+        self.ran.load(Ordering::SeqCst)
+    }
+}
+
+impl Check for MockCheck {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn description(&self) -> &'static str {
+        "Mock check"
+    }
+
+    fn run(&self, _ctx: &CheckContext) -> CheckResult {
+        self.ran.store(true, Ordering::SeqCst);
+
+        match &self.behavior {
+            MockBehavior::Pass => CheckResult::passed(self.name),
+            MockBehavior::Fail(count) => {
+                let violations: Vec<_> = (0..*count)
+                    .map(|i| {
+                        Violation::file_only(format!("file{}.rs", i), "test_violation", "Fix this")
+                    })
+                    .collect();
+                CheckResult::failed(self.name, violations)
+            }
+            MockBehavior::Panic => panic!("Mock check panicked"),
+        }
+    }
+
+    fn default_enabled(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn runner_executes_all_checks() {
+    let runner = CheckRunner::new(RunnerConfig {
+        limit: None,
+        changed_files: None,
+        fix: false,
+        run_fixers: false,
+        dry_run: false,
+        patch: None,
+        ci_mode: false,
+        base_branch: None,
+        staged: false,
+        staged_content: None,
+        verbose: false,
+        sample_seed: None,
+        jobs: None,
+    });
+    let config = Config::default();
+    let files = vec![];
+    let root = std::path::Path::new(".");
+
+    let checks: Vec<Arc<dyn Check>> = vec![
+        Arc::new(MockCheck::new("check1", MockBehavior::Pass)),
+        Arc::new(MockCheck::new("check2", MockBehavior::Fail(1))),
+        Arc::new(MockCheck::new("check3", MockBehavior::Pass)),
+    ];
+
+    let results = runner.run(checks, &files, &config, root);
+
+    assert_eq!(results.len(), 3, "all checks should have results");
+}
+
+#[test]
+fn runner_isolates_panicking_check() {
+    let runner = CheckRunner::new(RunnerConfig {
+        limit: None,
+        changed_files: None,
+        fix: false,
+        run_fixers: false,
+        dry_run: false,
+        patch: None,
+        ci_mode: false,
+        base_branch: None,
+        staged: false,
+        staged_content: None,
+        verbose: false,
+        sample_seed: None,
+        jobs: None,
+    });
+    let config = Config::default();
+    let files = vec![];
+    let root = std::path::Path::new(".");
+
+    let passing = Arc::new(MockCheck::new("passing", MockBehavior::Pass));
+    let panicking = Arc::new(MockCheck::new("panicking", MockBehavior::Panic));
+
+    let checks: Vec<Arc<dyn Check>> = vec![passing.clone(), panicking.clone()];
+
+    let results = runner.run(checks, &files, &config, root);
+
+    // Both checks should have results
+    assert_eq!(results.len(), 2);
+
+    // Passing check should have run and passed
+    let pass_result = results.iter().find(|r| r.name == "passing").unwrap();
+    assert!(pass_result.passed);
+
+    // Panicking check should be skipped with error
+    let panic_result = results.iter().find(|r| r.name == "panicking").unwrap();
+    assert!(panic_result.skipped);
+    assert!(panic_result.error.is_some());
+}
+
+#[test]
+fn runner_continues_after_check_failure() {
+    let runner = CheckRunner::new(RunnerConfig {
+        limit: None,
+        changed_files: None,
+        fix: false,
+        run_fixers: false,
+        dry_run: false,
+        patch: None,
+        ci_mode: false,
+        base_branch: None,
+        staged: false,
+        staged_content: None,
+        verbose: false,
+        sample_seed: None,
+        jobs: None,
+    });
+    let config = Config::default();
+    let files = vec![];
+    let root = std::path::Path::new(".");
+
+    let check1 = Arc::new(MockCheck::new("check1", MockBehavior::Fail(5)));
+    let check2 = Arc::new(MockCheck::new("check2", MockBehavior::Pass));
+
+    let checks: Vec<Arc<dyn Check>> = vec![check1.clone(), check2.clone()];
+
+    let results = runner.run(checks, &files, &config, root);
+
+    // Both checks should run
+    assert!(check1.did_run());
+    assert!(check2.did_run());
+
+    // First failed, second passed
+    let result1 = results.iter().find(|r| r.name == "check1").unwrap();
+    let result2 = results.iter().find(|r| r.name == "check2").unwrap();
+    assert!(!result1.passed);
+    assert!(result2.passed);
+}
+
+#[test]
+fn runner_reports_each_check_as_it_starts_and_completes() {
+    let runner = CheckRunner::new(RunnerConfig::default());
+    let config = Config::default();
+    let files = vec![];
+    let root = std::path::Path::new(".");
+
+    let checks: Vec<Arc<dyn Check>> = vec![
+        Arc::new(MockCheck::new("check1", MockBehavior::Fail(2))),
+        Arc::new(MockCheck::new("check2", MockBehavior::Panic)),
+    ];
+
+    let seen = std::sync::Mutex::new(Vec::new());
+    let results = runner.run_with(checks, &files, &config, root, &|event| {
+        let entry = match event {
+            CheckEvent::Started(name) => (name.to_string(), None),
+            CheckEvent::Finished(result) => (result.name.clone(), Some(result.violations.len())),
+        };
+        seen.lock().unwrap().push(entry);
+    });
+
+    let seen = seen.into_inner().unwrap();
+    for name in ["check1", "check2"] {
+        let events: Vec<_> = seen.iter().filter(|(n, _)| n == name).collect();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].1, None, "{} should start before finishing", name);
+    }
+    assert!(seen.contains(&("check1".to_string(), Some(2))));
+    assert!(seen.contains(&("check2".to_string(), Some(0))));
+    assert_eq!(results.len(), 2);
+}
+
+#[test]
+fn should_terminate_with_limit() {
+    let runner = CheckRunner::new(RunnerConfig {
+        limit: Some(10),
+        changed_files: None,
+        fix: false,
+        run_fixers: false,
+        dry_run: false,
+        patch: None,
+        ci_mode: false,
+        base_branch: None,
+        staged: false,
+        staged_content: None,
+        verbose: false,
+        sample_seed: None,
+        jobs: None,
+    });
+    assert!(!runner.should_terminate(5));
+    assert!(runner.should_terminate(10));
+    assert!(runner.should_terminate(15));
+}
+
+#[test]
+fn should_terminate_without_limit() {
+    let runner = CheckRunner::new(RunnerConfig {
+        limit: None,
+        changed_files: None,
+        fix: false,
+        run_fixers: false,
+        dry_run: false,
+        patch: None,
+        ci_mode: false,
+        base_branch: None,
+        staged: false,
+        staged_content: None,
+        verbose: false,
+        sample_seed: None,
+        jobs: None,
+    });
+    assert!(!runner.should_terminate(1000));
+}
+
+// =============================================================================
+// VIOLATION CORRELATION
+// =============================================================================
+
+#[test]
+fn correlate_groups_same_line_violations_under_first_check() {
+    let mut results = vec![
+        CheckResult::failed(
+            "escapes",
+            vec![Violation::file("src/lib.rs", 3, "suppress_forbidden", "a")],
+        ),
+        CheckResult::failed(
+            "docs",
+            vec![Violation::file("src/lib.rs", 3, "broken_link", "b")],
+        ),
+    ];
+    correlate_violations(&mut results);
+
+    assert!(results[0].violations[0].primary().is_none());
+    assert_eq!(
+        results[1].violations[0].primary(),
+        Some(&ViolationRef {
+            check: "escapes".to_string(),
+            violation_type: "suppress_forbidden".to_string(),
+        })
+    );
+}
+
+#[test]
+fn correlate_prefers_failing_check_as_primary() {
+    let mut results = vec![
+        CheckResult::passed_with_warnings(
+            "escapes",
+            vec![Violation::file("src/lib.rs", 3, "missing_comment", "a")],
+        ),
+        CheckResult::failed(
+            "docs",
+            vec![Violation::file("src/lib.rs", 3, "broken_link", "b")],
+        ),
+    ];
+    correlate_violations(&mut results);
+
+    assert_eq!(
+        results[0].violations[0]
+            .primary()
+            .as_ref()
+            .map(|p| p.check.as_str()),
+        Some("docs")
+    );
+    assert!(results[1].violations[0].primary().is_none());
+}
+
+#[test]
+fn correlate_groups_within_one_check() {
+    let mut results = vec![CheckResult::failed(
+        "escapes",
+        vec![
+            Violation::file("src/lib.rs", 3, "forbidden", "a"),
+            Violation::file("src/lib.rs", 3, "suppress_forbidden", "b"),
+        ],
+    )];
+    correlate_violations(&mut results);
+
+    assert!(results[0].violations[0].primary().is_none());
+    assert_eq!(
+        results[0].violations[1]
+            .primary()
+            .as_ref()
+            .map(|p| p.violation_type.as_str()),
+        Some("forbidden")
+    );
+}
+
+#[test]
+fn correlate_ignores_different_lines_and_lineless_violations() {
+    let mut results = vec![CheckResult::failed(
+        "cloc",
+        vec![
+            Violation::file("src/lib.rs", 3, "forbidden", "a"),
+            Violation::file("src/lib.rs", 4, "forbidden", "b"),
+            Violation::file_only("src/lib.rs", "file_too_large", "c"),
+            Violation::file_only("src/lib.rs", "file_too_large", "d"),
+        ],
+    )];
+    correlate_violations(&mut results);
+
+    assert!(results[0].violations.iter().all(|v| v.primary().is_none()));
+}
+
+// =============================================================================
+// Sampling
+// =============================================================================
+
+/// Escapes stand-in that fails once per file it scans, with metrics.
+struct PerFileCheck;
+
+impl Check for PerFileCheck {
+    fn name(&self) -> &'static str {
+        "escapes"
+    }
+
+    fn description(&self) -> &'static str {
+        "Per-file check"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let violations = ctx
+            .files
+            .iter()
+            .map(|f| Violation::file_only(f.path.clone(), "forbidden", "Remove it"))
+            .collect();
+        let metrics = serde_json::json!({
+            "source": {}, "test": {}, "source_lines": 0, "density": {}
+        });
+        CheckResult::failed(self.name(), violations).with_metrics(metrics)
+    }
+}
+
+fn sampled_run(config: &Config, files: &[WalkedFile], seed: Option<&str>) -> CheckResult {
+    let runner = CheckRunner::new(RunnerConfig {
+        sample_seed: seed.map(str::to_string),
+        ..Default::default()
+    });
+    let checks: Vec<Arc<dyn Check>> = vec![Arc::new(PerFileCheck)];
+    let mut results = runner.run(checks, files, config, Path::new("/project"));
+    results.remove(0)
+}
+
+#[test]
+fn sampled_check_scans_a_share_of_files_without_metrics() {
+    let mut config = Config::default();
+    config.check.escapes.sample = Sample::parse("30%").ok();
+    let files: Vec<WalkedFile> = (0..200)
+        .map(|i| WalkedFile {
+            path: PathBuf::from(format!("/project/src/file_{i}.rs")),
+            size: 1,
+            mtime_secs: 0,
+            mtime_nanos: 0,
+            depth: 2,
+            size_class: crate::file_size::FileSizeClass::Small,
+        })
+        .collect();
+    let scanned = |result: &CheckResult| -> Vec<PathBuf> {
+        result
+            .violations
+            .iter()
+            .filter_map(|v| v.file.clone())
+            .collect()
+    };
+
+    let sampled = sampled_run(&config, &files, Some("abc123"));
+    assert!((30..90).contains(&sampled.violations.len()));
+    assert!(sampled.metrics.is_none());
+    assert_eq!(
+        scanned(&sampled),
+        scanned(&sampled_run(&config, &files, Some("abc123")))
+    );
+
+    // No seed (CI mode): every file, with metrics
+    let full = sampled_run(&config, &files, None);
+    assert_eq!(full.violations.len(), 200);
+    assert!(full.metrics.is_some());
+}
+
+/// Check that reports the size of the thread pool it runs on.
+struct PoolSizeCheck;
+
+impl Check for PoolSizeCheck {
+    fn name(&self) -> &'static str {
+        "pool"
+    }
+
+    fn description(&self) -> &'static str {
+        "Pool size check"
+    }
+
+    fn run(&self, _ctx: &CheckContext) -> CheckResult {
+        CheckResult::passed(self.name())
+            .with_metrics(serde_json::json!({ "threads": rayon::current_num_threads() }))
+    }
+}
+
+#[test]
+fn runner_uses_configured_jobs() {
+    let runner = CheckRunner::new(RunnerConfig {
+        jobs: Some(1),
+        ..Default::default()
+    });
+    let checks: Vec<Arc<dyn Check>> = vec![Arc::new(PoolSizeCheck)];
+    let results = runner.run(checks, &[], &Config::default(), Path::new("."));
+
+    assert_eq!(results[0].metrics.as_ref().unwrap()["threads"], 1);
+}
@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Software bill of materials from lockfiles (`quench sbom`).
+//!
+//! Components are the packages resolved in `Cargo.lock` and
+//! `package-lock.json`, plus the modules `go.mod` requires, read with the
+//! deps check's parsers. Rendered as CycloneDX 1.5 or SPDX 2.3 JSON.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use globset::GlobSet;
+use serde_json::{Value as JsonValue, json};
+
+use crate::checks::deps::lockfile;
+use crate::checks::deps::manifest::{self, Ecosystem, Source};
+
+/// A package in the bill of materials.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Component {
+    pub ecosystem: Ecosystem,
+    pub name: String,
+    pub version: String,
+}
+
+impl Component {
+    /// Package URL, e.g. `pkg:cargo/serde@1.0.197`.
+    pub fn purl(&self) -> String {
+        let kind = match self.ecosystem {
+            Ecosystem::Cargo => "cargo",
+            Ecosystem::Npm => "npm",
+            Ecosystem::Go => "golang",
+        };
+        // The npm scope is a namespace, with its `@` encoded
+        let name = match self.name.strip_prefix('@') {
+            Some(scoped) => format!("%40{}", scoped),
+            None => self.name.clone(),
+        };
+        format!("pkg:{}/{}@{}", kind, name, self.version)
+    }
+}
+
+/// Components resolved across the project's lockfiles.
+#[derive(Debug, Default)]
+pub struct Sbom {
+    /// Name of the described project.
+    pub name: String,
+    /// Sorted, without duplicates.
+    pub components: Vec<Component>,
+    /// Ecosystems with a manifest but no lockfile; their packages are missing.
+    pub unlocked: Vec<Ecosystem>,
+    /// Lockfiles that couldn't be read or parsed, with the reason.
+    pub skipped: Vec<(PathBuf, String)>,
+}
+
+impl Sbom {
+    /// Collect components from the lockfiles and `go.mod` files among
+    /// `files`, skipping those `exclude` matches (relative to `root`).
+    ///
+    /// A lockfile next to a manifest is read even when it isn't among
+    /// `files`, since libraries often keep it out of git.
+    pub fn read(name: &str, root: &Path, files: &[PathBuf], exclude: &GlobSet) -> Self {
+        let mut sbom = Sbom {
+            name: name.to_string(),
+            ..Sbom::default()
+        };
+        let mut components = BTreeSet::new();
+        let mut manifests = BTreeSet::new();
+        let mut locked = BTreeSet::new();
+
+        let mut paths: BTreeSet<PathBuf> = files.iter().cloned().collect();
+        for path in files {
+            let sibling = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .and_then(Ecosystem::from_manifest)
+                .and_then(lockfile::file_name)
+                .and_then(|lock| Some(path.parent()?.join(lock)));
+            if let Some(lock) = sibling.filter(|lock| lock.is_file()) {
+                paths.insert(lock);
+            }
+        }
+
+        for path in &paths {
+            let relative = path.strip_prefix(root).unwrap_or(path);
+            let Some(file_name) = relative.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let is_manifest = Ecosystem::from_manifest(file_name);
+            let Some(ecosystem) = is_manifest.or_else(|| lockfile::ecosystem(file_name)) else {
+                continue;
+            };
+            if exclude.is_match(relative) {
+                continue;
+            }
+            if is_manifest.is_some() {
+                manifests.insert(ecosystem);
+                if ecosystem != Ecosystem::Go {
+                    continue;
+                }
+            }
+            let content = match std::fs::read_to_string(path) {
+                Ok(content) => content,
+                Err(e) => {
+                    sbom.skipped.push((relative.to_path_buf(), e.to_string()));
+                    continue;
+                }
+            };
+
+            let parsed: Result<Vec<(String, String)>, String> = if is_manifest.is_some() {
+                // Go has no lockfile: go.mod requires exact versions
+                manifest::parse(ecosystem, &content).map(|deps| {
+                    deps.into_iter()
+                        .filter_map(|dep| match dep.source {
+                            Source::Registry(version) => Some((dep.name, version)),
+                            _ => None,
+                        })
+                        .collect()
+                })
+            } else {
+                lockfile::parse(ecosystem, &content).map(|packages| {
+                    packages
+                        .into_iter()
+                        .map(|package| (package.name, package.version))
+                        .collect()
+                })
+            };
+            match parsed {
+                Ok(packages) => {
+                    locked.insert(ecosystem);
+                    components.extend(packages.into_iter().map(|(name, version)| Component {
+                        ecosystem,
+                        name,
+                        version,
+                    }));
+                }
+                Err(e) => sbom.skipped.push((relative.to_path_buf(), e)),
+            }
+        }
+
+        sbom.components = components.into_iter().collect();
+        sbom.unlocked = manifests.difference(&locked).copied().collect();
+        sbom
+    }
+
+    /// CycloneDX 1.5 JSON.
+    pub fn to_cyclonedx(&self, timestamp: DateTime<Utc>) -> JsonValue {
+        let components: Vec<JsonValue> = self
+            .components
+            .iter()
+            .map(|component| {
+                let purl = component.purl();
+                json!({
+                    "type": "library",
+                    "bom-ref": purl,
+                    "name": component.name,
+                    "version": component.version,
+                    "purl": purl,
+                })
+            })
+            .collect();
+        json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "version": 1,
+            "metadata": {
+                "timestamp": format_timestamp(timestamp),
+                "tools": {
+                    "components": [{
+                        "type": "application",
+                        "name": "quench",
+                        "version": env!("CARGO_PKG_VERSION"),
+                    }],
+                },
+                "component": {
+                    "type": "application",
+                    "bom-ref": "root",
+                    "name": self.name,
+                },
+            },
+            "components": components,
+        })
+    }
+
+    /// SPDX 2.3 JSON. The document describes the project, which depends on
+    /// every component.
+    pub fn to_spdx(&self, timestamp: DateTime<Utc>) -> JsonValue {
+        let root_id = "SPDXRef-Root";
+        let mut packages = vec![json!({
+            "name": self.name,
+            "SPDXID": root_id,
+            "downloadLocation": "NOASSERTION",
+            "filesAnalyzed": false,
+        })];
+        let mut relationships = vec![json!({
+            "spdxElementId": "SPDXRef-DOCUMENT",
+            "relationshipType": "DESCRIBES",
+            "relatedSpdxElement": root_id,
+        })];
+        for (idx, component) in self.components.iter().enumerate() {
+            // SPDX IDs allow only letters, digits, `.`, and `-`
+            let id = format!("SPDXRef-Package-{}", idx + 1);
+            packages.push(json!({
+                "name": component.name,
+                "SPDXID": id,
+                "versionInfo": component.version,
+                "downloadLocation": "NOASSERTION",
+                "filesAnalyzed": false,
+                "externalRefs": [{
+                    "referenceCategory": "PACKAGE-MANAGER",
+                    "referenceType": "purl",
+                    "referenceLocator": component.purl(),
+                }],
+            }));
+            relationships.push(json!({
+                "spdxElementId": root_id,
+                "relationshipType": "DEPENDS_ON",
+                "relatedSpdxElement": id,
+            }));
+        }
+        json!({
+            "spdxVersion": "SPDX-2.3",
+            "dataLicense": "CC0-1.0",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "name": self.name,
+            "documentNamespace": format!(
+                "urn:quench:sbom:{}:{}",
+                self.name.replace(char::is_whitespace, "-"),
+                timestamp.timestamp()
+            ),
+            "creationInfo": {
+                "created": format_timestamp(timestamp),
+                "creators": [format!("Tool: quench-{}", env!("CARGO_PKG_VERSION"))],
+            },
+            "packages": packages,
+            "relationships": relationships,
+        })
+    }
+}
+
+/// Both formats want whole seconds in UTC, e.g. `2026-01-20T09:30:00Z`.
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+#[path = "sbom_tests.rs"]
+mod tests;
@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+use chrono::TimeZone;
+use tempfile::TempDir;
+
+use crate::adapter::glob::build_glob_set;
+
+const CARGO_LOCK: &str = "\
+version = 3
+
+[[package]]
+name = \"app\"
+version = \"0.1.0\"
+
+[[package]]
+name = \"serde\"
+version = \"1.0.197\"
+source = \"registry+https://github.com/rust-lang/crates.io-index\"
+";
+
+const PACKAGE_LOCK: &str = r#"{
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "web" },
+    "node_modules/@acme/ui": { "version": "1.0.0" }
+  }
+}"#;
+
+fn write(dir: &TempDir, path: &str, content: &str) -> PathBuf {
+    let path = dir.path().join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn timestamp() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 20, 9, 30, 0).unwrap()
+}
+
+fn sample() -> Sbom {
+    Sbom {
+        name: "app".to_string(),
+        components: vec![
+            Component {
+                ecosystem: Ecosystem::Cargo,
+                name: "serde".to_string(),
+                version: "1.0.197".to_string(),
+            },
+            Component {
+                ecosystem: Ecosystem::Npm,
+                name: "@acme/ui".to_string(),
+                version: "1.0.0".to_string(),
+            },
+        ],
+        ..Sbom::default()
+    }
+}
+
+#[test]
+fn purls_follow_ecosystem_conventions() {
+    let component = |ecosystem, name: &str| Component {
+        ecosystem,
+        name: name.to_string(),
+        version: "1.0.0".to_string(),
+    };
+
+    assert_eq!(
+        component(Ecosystem::Cargo, "serde").purl(),
+        "pkg:cargo/serde@1.0.0"
+    );
+    assert_eq!(
+        component(Ecosystem::Npm, "@acme/ui").purl(),
+        "pkg:npm/%40acme/ui@1.0.0"
+    );
+    assert_eq!(
+        component(Ecosystem::Go, "github.com/pkg/errors").purl(),
+        "pkg:golang/github.com/pkg/errors@1.0.0"
+    );
+}
+
+#[test]
+fn read_collects_lockfiles_and_go_modules() {
+    let dir = TempDir::new().unwrap();
+    let files = vec![
+        write(&dir, "Cargo.toml", "[dependencies]\nserde = \"1\"\n"),
+        write(&dir, "Cargo.lock", CARGO_LOCK),
+        write(&dir, "web/package-lock.json", PACKAGE_LOCK),
+        write(
+            &dir,
+            "tools/go.mod",
+            "module example.com/tools\n\nrequire github.com/pkg/errors v0.9.1 // indirect\n",
+        ),
+    ];
+
+    let sbom = Sbom::read("app", dir.path(), &files, &build_glob_set(&[]));
+
+    let purls: Vec<String> = sbom.components.iter().map(Component::purl).collect();
+    assert_eq!(
+        purls,
+        [
+            "pkg:cargo/serde@1.0.197",
+            "pkg:npm/%40acme/ui@1.0.0",
+            "pkg:golang/github.com/pkg/errors@v0.9.1",
+        ]
+    );
+    assert!(sbom.unlocked.is_empty());
+    assert!(sbom.skipped.is_empty());
+}
+
+#[test]
+fn read_reports_manifests_without_lockfiles_and_bad_lockfiles() {
+    let dir = TempDir::new().unwrap();
+    let files = vec![
+        write(&dir, "package.json", "{ \"dependencies\": {} }"),
+        write(&dir, "Cargo.lock", "[[package]\n"),
+    ];
+
+    let sbom = Sbom::read("app", dir.path(), &files, &build_glob_set(&[]));
+
+    assert!(sbom.components.is_empty());
+    assert_eq!(sbom.unlocked, [Ecosystem::Npm]);
+    assert_eq!(sbom.skipped.len(), 1);
+    assert_eq!(sbom.skipped[0].0, PathBuf::from("Cargo.lock"));
+}
+
+#[test]
+fn read_finds_lockfile_next_to_manifest() {
+    let dir = TempDir::new().unwrap();
+    // Cargo.lock exists but isn't walked, e.g. it's gitignored
+    write(&dir, "Cargo.lock", CARGO_LOCK);
+    let files = vec![write(&dir, "Cargo.toml", "[dependencies]\nserde = \"1\"\n")];
+
+    let sbom = Sbom::read("app", dir.path(), &files, &build_glob_set(&[]));
+
+    assert_eq!(sbom.components.len(), 1);
+    assert!(sbom.unlocked.is_empty());
+}
+
+#[test]
+fn read_skips_excluded_lockfiles() {
+    let dir = TempDir::new().unwrap();
+    let files = vec![write(&dir, "examples/Cargo.lock", CARGO_LOCK)];
+
+    let sbom = Sbom::read(
+        "app",
+        dir.path(),
+        &files,
+        &build_glob_set(&["examples/**".to_string()]),
+    );
+
+    assert!(sbom.components.is_empty());
+}
+
+#[test]
+fn cyclonedx_lists_components() {
+    let bom = sample().to_cyclonedx(timestamp());
+
+    assert_eq!(bom["bomFormat"], "CycloneDX");
+    assert_eq!(bom["specVersion"], "1.5");
+    assert_eq!(bom["metadata"]["timestamp"], "2026-01-20T09:30:00Z");
+    assert_eq!(bom["metadata"]["component"]["name"], "app");
+    let components = bom["components"].as_array().unwrap();
+    assert_eq!(components.len(), 2);
+    assert_eq!(components[0]["name"], "serde");
+    assert_eq!(components[0]["version"], "1.0.197");
+    assert_eq!(components[0]["purl"], "pkg:cargo/serde@1.0.197");
+    assert_eq!(components[0]["bom-ref"], components[0]["purl"]);
+}
+
+#[test]
+fn spdx_describes_project_depending_on_components() {
+    let doc = sample().to_spdx(timestamp());
+
+    assert_eq!(doc["spdxVersion"], "SPDX-2.3");
+    assert_eq!(doc["creationInfo"]["created"], "2026-01-20T09:30:00Z");
+    let packages = doc["packages"].as_array().unwrap();
+    assert_eq!(packages.len(), 3);
+    assert_eq!(packages[0]["SPDXID"], "SPDXRef-Root");
+    assert_eq!(packages[2]["name"], "@acme/ui");
+    assert_eq!(
+        packages[2]["externalRefs"][0]["referenceLocator"],
+        "pkg:npm/%40acme/ui@1.0.0"
+    );
+    let relationships = doc["relationships"].as_array().unwrap();
+    assert_eq!(relationships[0]["relationshipType"], "DESCRIBES");
+    assert_eq!(relationships[1]["spdxElementId"], "SPDXRef-Root");
+    assert_eq!(relationships[1]["relationshipType"], "DEPENDS_ON");
+    assert_eq!(
+        relationships[1]["relatedSpdxElement"],
+        packages[1]["SPDXID"]
+    );
+}
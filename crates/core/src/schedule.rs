@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Largest-first scheduling of parallel work.
+//!
+//! Rayon hands out work in the order it's given, so a few large files (or a
+//! slow check) at the end of walker order can leave one thread busy after
+//! the rest of the pool goes idle. Starting the biggest items first lets the
+//! small ones fill in around them. Results keep their input order, so output
+//! and `--limit` truncation don't depend on scheduling.
+
+use std::cmp::Reverse;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+
+use crate::check::{Check, CheckContext};
+use crate::walker::WalkedFile;
+
+/// Map `items` in parallel, starting the largest by `size` first.
+///
+/// Results are returned in the order of `items`.
+pub fn par_map_largest_first<T, R, S, F>(items: &[T], size: S, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    S: Fn(&T) -> u64,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    // Stable sort keeps input order among equal sizes
+    order.sort_by_key(|&i| Reverse(size(&items[i])));
+
+    let mut results: Vec<(usize, R)> = order.into_par_iter().map(|i| (i, f(&items[i]))).collect();
+    results.sort_unstable_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+impl CheckContext<'_> {
+    /// Count one violation toward the limit. False once the limit is reached.
+    pub fn admit_violation(&self) -> bool {
+        let current = self.violation_count.fetch_add(1, Ordering::SeqCst);
+        self.limit.is_none_or(|limit| current < limit)
+    }
+
+    /// Scan the files in parallel, largest first, returning results in file
+    /// order.
+    ///
+    /// `scan` gets a context without a violation limit, so each file keeps
+    /// every violation it finds. Callers merge the results in order and
+    /// pass each violation through [`Self::admit_violation`], which keeps
+    /// output and `--limit` truncation the same as a serial scan.
+    pub fn par_scan_files<R, F>(&self, scan: F) -> Vec<R>
+    where
+        R: Send,
+        F: Fn(&CheckContext, &WalkedFile) -> R + Sync + Send,
+    {
+        par_map_largest_first(
+            self.files,
+            |file| file.size,
+            |file| {
+                let count = AtomicUsize::new(0);
+                let ctx = CheckContext {
+                    limit: None,
+                    violation_count: &count,
+                    ..*self
+                };
+                scan(&ctx, file)
+            },
+        )
+    }
+}
+
+/// Order checks slowest first by historical `cost` in milliseconds.
+///
+/// Checks without timing history keep their relative order after the rest.
+pub fn longest_first(checks: &mut [Arc<dyn Check>], cost: impl Fn(&str) -> Option<u64>) {
+    checks.sort_by_key(|c| Reverse(cost(c.name()).unwrap_or(0)));
+}
+
+/// Size distribution of the files a run schedules, for `--verbose`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleStats {
+    pub files: usize,
+    pub total_bytes: u64,
+    /// Largest file and its size in bytes.
+    pub largest: Option<(PathBuf, u64)>,
+    /// Share of all bytes held by the largest 1% of files (at least one).
+    pub top_share: f64,
+}
+
+impl ScheduleStats {
+    pub fn new(files: &[WalkedFile]) -> Self {
+        let mut sizes: Vec<u64> = files.iter().map(|f| f.size).collect();
+        sizes.sort_unstable_by_key(|&s| Reverse(s));
+        let total_bytes: u64 = sizes.iter().sum();
+        let top: u64 = sizes.iter().take(files.len().div_ceil(100)).sum();
+        let top_share = if total_bytes == 0 {
+            0.0
+        } else {
+            top as f64 / total_bytes as f64
+        };
+        let largest = files
+            .iter()
+            .max_by_key(|f| (f.size, Reverse(&f.path)))
+            .map(|f| (f.path.clone(), f.size));
+        Self {
+            files: files.len(),
+            total_bytes,
+            largest,
+            top_share,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "schedule_tests.rs"]
+mod tests;
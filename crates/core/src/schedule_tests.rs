@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+use super::*;
+use crate::checks::all_checks;
+use crate::config::Config;
+use crate::diagnostics::Diagnostics;
+use crate::file_reader::DecodeLog;
+use crate::file_size::FileSizeClass;
+
+fn walked(path: &str, size: u64) -> WalkedFile {
+    WalkedFile {
+        path: PathBuf::from(path),
+        size,
+        mtime_secs: 0,
+        mtime_nanos: 0,
+        depth: 1,
+        size_class: FileSizeClass::from_size(size),
+    }
+}
+
+// =============================================================================
+// Files
+// =============================================================================
+
+#[test]
+fn largest_items_start_first() {
+    let sizes = [10u64, 5000, 30, 5000, 700];
+    let started = Mutex::new(Vec::new());
+
+    // A single thread starts items in scheduled order
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+    pool.install(|| {
+        par_map_largest_first(&sizes, |&s| s, |&s| started.lock().unwrap().push(s));
+    });
+
+    assert_eq!(started.into_inner().unwrap(), [5000, 5000, 700, 30, 10]);
+}
+
+#[test]
+fn results_keep_input_order() {
+    let items: Vec<u64> = (0..200).map(|i| (i * 37) % 101).collect();
+
+    let results = par_map_largest_first(&items, |&s| s, |&s| s * 2);
+
+    let expected: Vec<u64> = items.iter().map(|s| s * 2).collect();
+    assert_eq!(results, expected);
+}
+
+// =============================================================================
+// Check context
+// =============================================================================
+
+#[test]
+fn par_scan_files_keeps_file_order_without_a_limit() {
+    let config = Config::default();
+    let files = [walked("a.rs", 1), walked("b.rs", 300), walked("c.rs", 20)];
+    let violation_count = AtomicUsize::new(0);
+    let decode_log = DecodeLog::default();
+    let diagnostics = Diagnostics::silent();
+    let ctx = CheckContext {
+        root: Path::new("."),
+        files: &files,
+        config: &config,
+        limit: Some(1),
+        violation_count: &violation_count,
+        changed_files: None,
+        fix: false,
+        run_fixers: false,
+        dry_run: false,
+        patch: None,
+        ci_mode: false,
+        base_branch: None,
+        staged: false,
+        staged_content: None,
+        verbose: false,
+        decode_log: &decode_log,
+        diagnostics: &diagnostics,
+    };
+
+    let scanned = ctx.par_scan_files(|ctx, file| {
+        assert!(ctx.admit_violation() && ctx.admit_violation());
+        file.path.display().to_string()
+    });
+    assert_eq!(scanned, ["a.rs", "b.rs", "c.rs"]);
+
+    assert!(ctx.admit_violation());
+    assert!(!ctx.admit_violation());
+}
+
+// =============================================================================
+// Checks
+// =============================================================================
+
+#[test]
+fn slowest_checks_start_first() {
+    let history: HashMap<&str, u64> = [("cloc", 40), ("tests", 900), ("escapes", 300)].into();
+    let mut checks = all_checks();
+
+    longest_first(&mut checks, |name| history.get(name).copied());
+
+    let names: Vec<_> = checks.iter().map(|c| c.name()).collect();
+    assert_eq!(names[..3], ["tests", "escapes", "cloc"]);
+    // Checks without history keep canonical order
+    let rest: Vec<_> = all_checks()
+        .iter()
+        .map(|c| c.name())
+        .filter(|n| !history.contains_key(n))
+        .collect();
+    assert_eq!(names[3..], rest[..]);
+}
+
+// =============================================================================
+// Stats
+// =============================================================================
+
+#[test]
+fn stats_summarize_skew() {
+    let mut files: Vec<WalkedFile> = (0..199).map(|i| walked(&format!("f{i}.rs"), 10)).collect();
+    files.push(walked("big.rs", 2010));
+
+    let stats = ScheduleStats::new(&files);
+
+    assert_eq!(stats.files, 200);
+    assert_eq!(stats.total_bytes, 4000);
+    assert_eq!(stats.largest, Some((PathBuf::from("big.rs"), 2010)));
+    // Top 1% of 200 files is the two largest
+    assert!(
+        (stats.top_share - 0.505).abs() < 1e-9,
+        "{}",
+        stats.top_share
+    );
+}
+
+#[test]
+fn stats_of_no_files() {
+    let stats = ScheduleStats::new(&[]);
+    assert_eq!(stats.files, 0);
+    assert_eq!(stats.largest, None);
+    assert_eq!(stats.top_share, 0.0);
+}
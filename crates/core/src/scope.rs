@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Path-scoped check policies.
+//!
+//! Each file follows the most specific scope whose glob matches it (the
+//! longest pattern wins). A scope that overrides a check reruns it on its
+//! files with the scope's config and replaces the project-wide findings
+//! there; a scope that disables a check drops them.
+
+use std::path::Path;
+
+use globset::GlobSet;
+
+use crate::adapter::glob::build_glob_set;
+use crate::check::{Check, CheckContext, CheckResult};
+use crate::config::{INSTANCE_SEPARATOR, PathScope};
+use crate::walker::WalkedFile;
+
+/// Configured scopes, most specific first.
+pub struct Scopes<'a> {
+    entries: Vec<(GlobSet, &'a PathScope)>,
+}
+
+impl<'a> Scopes<'a> {
+    pub fn new(scopes: &'a [PathScope]) -> Self {
+        let mut entries: Vec<_> = scopes
+            .iter()
+            .map(|scope| (build_glob_set(std::slice::from_ref(&scope.pattern)), scope))
+            .collect();
+        // Stable, so equal lengths keep glob order
+        entries.sort_by_key(|(_, scope)| std::cmp::Reverse(scope.pattern.len()));
+        Self { entries }
+    }
+
+    /// Index of the scope governing a path relative to the project root.
+    fn find(&self, relative: &Path) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|(globs, _)| globs.is_match(relative))
+    }
+
+    /// Apply the scopes to a check's project-wide result.
+    ///
+    /// Named instances keep their own result, since their `paths` already
+    /// scope them.
+    pub fn apply(
+        &self,
+        check: &dyn Check,
+        mut result: CheckResult,
+        ctx: &CheckContext,
+    ) -> CheckResult {
+        let name = check.name();
+        if result.skipped || name.contains(INSTANCE_SEPARATOR) {
+            return result;
+        }
+        let replaced = |scope: &PathScope| scope.disables(name) || scope.overrides(name);
+        if !self.entries.iter().any(|(_, scope)| replaced(scope)) {
+            return result;
+        }
+
+        // Drop findings the scopes replace
+        let before = result.violations.len();
+        result.violations.retain(|v| {
+            let Some(file) = &v.file else {
+                return true;
+            };
+            let relative = file.strip_prefix(ctx.root).unwrap_or(file);
+            self.find(relative)
+                .is_none_or(|i| !replaced(self.entries[i].1))
+        });
+        if before != result.violations.len()
+            && result.violations.is_empty()
+            && result.error.is_none()
+        {
+            result.passed = true;
+        }
+
+        // Rerun overriding scopes on their files
+        let mut files: Vec<Vec<WalkedFile>> = self.entries.iter().map(|_| Vec::new()).collect();
+        for file in ctx.files {
+            let relative = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
+            if let Some(i) = self.find(relative) {
+                files[i].push(WalkedFile {
+                    path: file.path.clone(),
+                    size: file.size,
+                    mtime_secs: file.mtime_secs,
+                    mtime_nanos: file.mtime_nanos,
+                    depth: file.depth,
+                    size_class: file.size_class,
+                });
+            }
+        }
+        for (i, (_, scope)) in self.entries.iter().enumerate() {
+            if files[i].is_empty() || scope.disables(name) || !scope.overrides(name) {
+                continue;
+            }
+            let scoped = CheckContext {
+                files: &files[i],
+                config: &scope.config,
+                ..*ctx
+            };
+            let scope_result = check.run(&scoped);
+
+            // Project-level findings come from the project-wide run
+            let violations: Vec<_> = scope_result
+                .violations
+                .into_iter()
+                .filter(|v| {
+                    v.file.as_ref().is_some_and(|file| {
+                        self.find(file.strip_prefix(ctx.root).unwrap_or(file)) == Some(i)
+                    })
+                })
+                .collect();
+            if !scope_result.passed && !violations.is_empty() {
+                result.passed = false;
+            }
+            result.violations.extend(violations);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+#[path = "scope_tests.rs"]
+mod tests;
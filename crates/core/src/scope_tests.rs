@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+
+use crate::check::{Field, Violation};
+use crate::config::Config;
+use crate::diagnostics::Diagnostics;
+use crate::file_reader::DecodeLog;
+use crate::file_size::FileSizeClass;
+
+const ROOT: &str = "/work";
+
+/// Flags each file it sees with the cloc line limit it was configured with,
+/// plus one project-level violation.
+struct Probe;
+
+impl Check for Probe {
+    fn name(&self) -> &'static str {
+        "cloc"
+    }
+
+    fn description(&self) -> &'static str {
+        "Probe"
+    }
+
+    fn run(&self, ctx: &CheckContext) -> CheckResult {
+        let mut violations: Vec<_> = ctx
+            .files
+            .iter()
+            .map(|f| {
+                Violation::file_only(f.path.strip_prefix(ctx.root).unwrap(), "seen", "probe")
+                    .with_detail(Field::Value, ctx.config.check.cloc.max_lines as i64)
+            })
+            .collect();
+        violations.push(Violation::project("project", "probe"));
+        CheckResult::failed(self.name(), violations)
+    }
+}
+
+fn walked(path: &str) -> WalkedFile {
+    WalkedFile {
+        path: PathBuf::from(ROOT).join(path),
+        size: 1,
+        mtime_secs: 0,
+        mtime_nanos: 0,
+        depth: 1,
+        size_class: FileSizeClass::Small,
+    }
+}
+
+fn config(content: &str) -> Config {
+    crate::config::parse(content, Path::new("quench.toml")).unwrap()
+}
+
+/// Apply the scopes to `base`'s result, as the runner does.
+fn apply_to(
+    config: &Config,
+    files: &[WalkedFile],
+    base: impl FnOnce(&CheckContext) -> CheckResult,
+) -> CheckResult {
+    let violation_count = AtomicUsize::new(0);
+    let decode_log = DecodeLog::default();
+    let diagnostics = Diagnostics::silent();
+    let ctx = CheckContext {
+        root: Path::new(ROOT),
+        files,
+        config,
+        limit: None,
+        violation_count: &violation_count,
+        changed_files: None,
+        fix: false,
+        run_fixers: false,
+        dry_run: false,
+        patch: None,
+        ci_mode: false,
+        base_branch: None,
+        staged: false,
+        staged_content: None,
+        verbose: false,
+        decode_log: &decode_log,
+        diagnostics: &diagnostics,
+    };
+    Scopes::new(&config.scopes).apply(&Probe, base(&ctx), &ctx)
+}
+
+fn run(config: &Config, files: &[WalkedFile]) -> CheckResult {
+    apply_to(config, files, |ctx| Probe.run(ctx))
+}
+
+/// (file, configured max_lines) for each file violation, sorted.
+fn seen(result: &CheckResult) -> Vec<(String, Option<i64>)> {
+    let mut seen: Vec<_> = result
+        .violations
+        .iter()
+        .filter_map(|v| Some((v.file.as_ref()?.display().to_string(), v.int(Field::Value))))
+        .collect();
+    seen.sort();
+    seen
+}
+
+#[test]
+fn overriding_scope_reruns_its_files_with_its_config() {
+    let config = config(
+        r#"
+version = 1
+[check.cloc]
+max_lines = 500
+[scope."services/*".check.cloc]
+max_lines = 200
+"#,
+    );
+    let files = [walked("src/lib.rs"), walked("services/api.rs")];
+
+    let result = run(&config, &files);
+
+    assert_eq!(
+        seen(&result),
+        [
+            ("services/api.rs".to_string(), Some(200)),
+            ("src/lib.rs".to_string(), Some(500)),
+        ]
+    );
+    // The project-level finding is reported once, from the project-wide run
+    let project = result
+        .violations
+        .iter()
+        .filter(|v| v.violation_type == "project");
+    assert_eq!(project.count(), 1);
+}
+
+#[test]
+fn disabling_scope_drops_findings_in_its_files() {
+    let config = config("version = 1\n[scope.\"services/*\"]\ndisable = [\"cloc\"]\n");
+    let files = [walked("src/lib.rs"), walked("services/api.rs")];
+
+    let result = run(&config, &files);
+
+    assert_eq!(seen(&result), [("src/lib.rs".to_string(), Some(750))]);
+}
+
+#[test]
+fn most_specific_scope_wins() {
+    let config = config(
+        r#"
+version = 1
+[scope."services/**".check.cloc]
+max_lines = 300
+[scope."services/legacy/**".check.cloc]
+max_lines = 2000
+"#,
+    );
+    let files = [walked("services/api.rs"), walked("services/legacy/old.rs")];
+
+    let result = run(&config, &files);
+
+    assert_eq!(
+        seen(&result),
+        [
+            ("services/api.rs".to_string(), Some(300)),
+            ("services/legacy/old.rs".to_string(), Some(2000)),
+        ]
+    );
+}
+
+#[test]
+fn scopes_without_this_check_keep_the_result() {
+    let config = config("version = 1\n[scope.\"services/*\"]\ndisable = [\"docs\"]\n");
+    let files = [walked("services/api.rs")];
+
+    let result = run(&config, &files);
+
+    assert_eq!(seen(&result), [("services/api.rs".to_string(), Some(750))]);
+    assert!(!result.passed);
+}
+
+#[test]
+fn check_passes_when_scopes_drop_all_its_findings() {
+    let config = config("version = 1\n[scope.\"**\"]\ndisable = [\"cloc\"]\n");
+    let files = [walked("src/lib.rs")];
+
+    let result = apply_to(&config, &files, |_| {
+        CheckResult::failed(
+            "cloc",
+            vec![Violation::file_only("src/lib.rs", "seen", "probe")],
+        )
+    });
+
+    assert!(result.passed);
+    assert!(result.violations.is_empty());
+}
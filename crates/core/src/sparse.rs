@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Sparse-checkout and partial-clone detection.
+//!
+//! In a sparse checkout, files outside the sparse cone exist in git but not
+//! on disk; in a partial clone, blobs may be missing from the object store.
+//! Checks that compare git history against the working tree (or read diff
+//! contents) would report bogus violations, so they are skipped instead.
+
+use std::path::Path;
+
+use git2::Repository;
+
+/// Checks that need files outside the sparse cone.
+const SPARSE_CHECKOUT_CHECKS: &[&str] = &["docs", "tests"];
+
+/// Checks that read blob contents from history.
+const PARTIAL_CLONE_CHECKS: &[&str] = &["tests"];
+
+/// How much of the repository is locally available.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SparseState {
+    /// `core.sparseCheckout` is enabled.
+    pub sparse_checkout: bool,
+    /// The repository has a promisor remote (objects fetched on demand).
+    pub partial_clone: bool,
+}
+
+impl SparseState {
+    /// Detect sparse checkout and partial clone for the repository at `root`.
+    ///
+    /// Returns the default (full) state outside a git repository.
+    pub fn detect(root: &Path) -> Self {
+        let Ok(repo) = Repository::discover(root) else {
+            return Self::default();
+        };
+        let Ok(config) = repo.config() else {
+            return Self::default();
+        };
+
+        let sparse_checkout = config.get_bool("core.sparseCheckout").unwrap_or(false);
+        let partial_clone =
+            config.get_string("extensions.partialClone").is_ok() || has_promisor_remote(&config);
+
+        Self {
+            sparse_checkout,
+            partial_clone,
+        }
+    }
+
+    /// Whether any part of the repository may be absent locally.
+    pub fn is_active(&self) -> bool {
+        self.sparse_checkout || self.partial_clone
+    }
+
+    /// Human-readable description (e.g., "sparse checkout").
+    pub fn label(&self) -> &'static str {
+        match (self.sparse_checkout, self.partial_clone) {
+            (true, true) => "sparse checkout, partial clone",
+            (true, false) => "sparse checkout",
+            (false, true) => "partial clone",
+            (false, false) => "full checkout",
+        }
+    }
+
+    /// Whether a check must be skipped because it needs absent files or blobs.
+    pub fn skips(&self, check: &str) -> bool {
+        (self.sparse_checkout && SPARSE_CHECKOUT_CHECKS.contains(&check))
+            || (self.partial_clone && PARTIAL_CLONE_CHECKS.contains(&check))
+    }
+
+    /// Skip reason with a hint for getting a full result.
+    pub fn skip_reason(&self) -> String {
+        let hint = if self.sparse_checkout {
+            "run in a full checkout or `git sparse-checkout disable`"
+        } else {
+            "fetch missing objects (e.g., `git fetch --refetch`) or use a full clone"
+        };
+        format!(
+            "skipped (sparse): files or blobs may be absent in a {}; {}",
+            self.label(),
+            hint
+        )
+    }
+}
+
+/// Whether any remote is marked `remote.<name>.promisor = true`.
+fn has_promisor_remote(config: &git2::Config) -> bool {
+    let Ok(mut entries) = config.entries(Some(r"remote\..*\.promisor")) else {
+        return false;
+    };
+    while let Some(Ok(entry)) = entries.next() {
+        if entry
+            .value()
+            .is_some_and(|v| matches!(v.to_ascii_lowercase().as_str(), "true" | "yes" | "on" | "1"))
+        {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+#[path = "sparse_tests.rs"]
+mod tests;
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::process::Command;
+
+use tempfile::TempDir;
+
+use super::*;
+
+fn git(temp: &TempDir, args: &[&str]) {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(temp.path())
+        .status()
+        .expect("git should run");
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+#[test]
+fn non_repo_is_full() {
+    let temp = TempDir::new().unwrap();
+    assert_eq!(SparseState::detect(temp.path()), SparseState::default());
+}
+
+#[test]
+fn plain_repo_is_full() {
+    let temp = TempDir::new().unwrap();
+    git(&temp, &["init", "-q"]);
+    let state = SparseState::detect(temp.path());
+    assert!(!state.is_active());
+    assert!(!state.skips("tests"));
+}
+
+#[test]
+fn detects_sparse_checkout() {
+    let temp = TempDir::new().unwrap();
+    git(&temp, &["init", "-q"]);
+    git(&temp, &["config", "core.sparseCheckout", "true"]);
+
+    let state = SparseState::detect(temp.path());
+    assert!(state.sparse_checkout);
+    assert!(!state.partial_clone);
+    assert!(state.skips("docs"));
+    assert!(state.skips("tests"));
+    assert!(!state.skips("cloc"));
+    assert!(state.skip_reason().contains("git sparse-checkout disable"));
+}
+
+#[test]
+fn detects_partial_clone_from_promisor_remote() {
+    let temp = TempDir::new().unwrap();
+    git(&temp, &["init", "-q"]);
+    git(&temp, &["config", "remote.origin.promisor", "true"]);
+
+    let state = SparseState::detect(temp.path());
+    assert!(state.partial_clone);
+    assert_eq!(state.label(), "partial clone");
+    assert!(state.skips("tests"));
+    assert!(!state.skips("docs"));
+}
@@ -0,0 +1,316 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! SQLite storage for run history, per-file results, and baselines.
+//!
+//! With `[store] backend = "sqlite"`, every `quench check` run is recorded
+//! with its flattened metrics and per-file violation counts, and every
+//! baseline update is kept rather than overwritten. This backs
+//! `quench query`, the goal trends in `quench report`, and `quench bisect`
+//! measurements, replacing `.quench/bisect/*.json`.
+//!
+//! Only built with the `sqlite` feature.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use rusqlite::{Connection, OptionalExtension, params};
+
+use crate::baseline::{Baseline, MetricSample};
+use crate::check::CheckOutput;
+use crate::ratchet::{self, CurrentMetrics};
+
+/// Schema version, stored as the database's `user_version`.
+pub const SCHEMA_VERSION: i64 = 1;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY,
+    recorded TEXT NOT NULL,
+    commit_hash TEXT,
+    ci INTEGER NOT NULL,
+    passed INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS runs_by_commit ON runs (commit_hash, ci);
+
+CREATE TABLE IF NOT EXISTS metrics (
+    run_id INTEGER NOT NULL REFERENCES runs (id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    value REAL NOT NULL,
+    PRIMARY KEY (run_id, name)
+);
+CREATE INDEX IF NOT EXISTS metrics_by_name ON metrics (name);
+
+CREATE TABLE IF NOT EXISTS file_results (
+    run_id INTEGER NOT NULL REFERENCES runs (id) ON DELETE CASCADE,
+    file TEXT NOT NULL,
+    check_name TEXT NOT NULL,
+    violations INTEGER NOT NULL,
+    PRIMARY KEY (run_id, file, check_name)
+);
+CREATE INDEX IF NOT EXISTS file_results_by_file ON file_results (file);
+
+CREATE TABLE IF NOT EXISTS baselines (
+    id INTEGER PRIMARY KEY,
+    recorded TEXT NOT NULL,
+    commit_hash TEXT,
+    body TEXT NOT NULL
+);
+";
+
+/// One recorded value of a metric.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricPoint {
+    /// When the run was recorded.
+    pub recorded: DateTime<Utc>,
+    /// Commit the run checked, if known.
+    pub commit: Option<String>,
+    /// Whether the run was in CI mode.
+    pub ci: bool,
+    /// The metric's value.
+    pub value: f64,
+}
+
+/// One check's violation count for a file in a recorded run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileResult {
+    /// When the run was recorded.
+    pub recorded: DateTime<Utc>,
+    /// Commit the run checked, if known.
+    pub commit: Option<String>,
+    /// Check that reported the violations.
+    pub check: String,
+    /// Violations reported for the file.
+    pub violations: usize,
+}
+
+/// Errors from the result store.
+#[derive(Debug, thiserror::Error)]
+pub enum StoreError {
+    #[error("failed to create {}: {source}", path.display())]
+    Create {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("store database error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+
+    #[error("failed to encode stored baseline: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("store schema version {found} is newer than supported {supported}")]
+    Version { found: i64, supported: i64 },
+}
+
+/// A connection to the result database.
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    /// Open the database at `path`, creating it and its directory if needed.
+    pub fn open(path: &Path) -> Result<Self, StoreError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|source| StoreError::Create {
+                path: parent.to_path_buf(),
+                source,
+            })?;
+        }
+        Self::init(Connection::open(path)?)
+    }
+
+    /// Open a private in-memory database.
+    pub fn open_in_memory() -> Result<Self, StoreError> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self, StoreError> {
+        let version: i64 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+        if version > SCHEMA_VERSION {
+            return Err(StoreError::Version {
+                found: version,
+                supported: SCHEMA_VERSION,
+            });
+        }
+        conn.pragma_update(None, "foreign_keys", true)?;
+        conn.execute_batch(SCHEMA)?;
+        conn.pragma_update(None, "user_version", SCHEMA_VERSION)?;
+        Ok(Self { conn })
+    }
+
+    /// Record a run's metrics and per-file violation counts, returning its id.
+    pub fn record_run(
+        &mut self,
+        commit: Option<&str>,
+        ci: bool,
+        output: &CheckOutput,
+    ) -> Result<i64, StoreError> {
+        let mut baseline = Baseline::new();
+        ratchet::update_baseline(&mut baseline, &CurrentMetrics::from_output(output));
+
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "INSERT INTO runs (recorded, commit_hash, ci, passed) VALUES (?1, ?2, ?3, ?4)",
+            params![Utc::now(), commit, ci, output.passed],
+        )?;
+        let run_id = tx.last_insert_rowid();
+        {
+            let mut insert =
+                tx.prepare("INSERT INTO metrics (run_id, name, value) VALUES (?1, ?2, ?3)")?;
+            for (name, value) in baseline.metrics.flatten() {
+                insert.execute(params![run_id, name, value])?;
+            }
+
+            let mut insert = tx.prepare(
+                "INSERT INTO file_results (run_id, file, check_name, violations) VALUES (?1, ?2, ?3, ?4)",
+            )?;
+            for ((file, check), count) in file_counts(output) {
+                insert.execute(params![run_id, file, check, count])?;
+            }
+        }
+        tx.commit()?;
+        Ok(run_id)
+    }
+
+    /// Value of `metric` in the latest run recorded for `commit`.
+    pub fn metric_at(
+        &self,
+        commit: &str,
+        ci: bool,
+        metric: &str,
+    ) -> Result<Option<f64>, StoreError> {
+        let value = self
+            .conn
+            .query_row(
+                "SELECT m.value FROM runs r JOIN metrics m ON m.run_id = r.id
+                 WHERE r.commit_hash = ?1 AND r.ci = ?2 AND m.name = ?3
+                 ORDER BY r.id DESC LIMIT 1",
+                params![commit, ci, metric],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(value)
+    }
+
+    /// Whether any run was recorded for `commit`, so a missing metric was
+    /// measured as absent rather than never measured.
+    pub fn has_run(&self, commit: &str, ci: bool) -> Result<bool, StoreError> {
+        let found = self
+            .conn
+            .query_row(
+                "SELECT 1 FROM runs WHERE commit_hash = ?1 AND ci = ?2 LIMIT 1",
+                params![commit, ci],
+                |_| Ok(()),
+            )
+            .optional()?;
+        Ok(found.is_some())
+    }
+
+    /// The newest `limit` recorded values of `metric`, oldest first.
+    pub fn metric_history(
+        &self,
+        metric: &str,
+        limit: usize,
+    ) -> Result<Vec<MetricPoint>, StoreError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT r.recorded, r.commit_hash, r.ci, m.value FROM runs r
+             JOIN metrics m ON m.run_id = r.id
+             WHERE m.name = ?1 ORDER BY r.id DESC LIMIT ?2",
+        )?;
+        let mut points = stmt
+            .query_map(params![metric, limit as i64], |row| {
+                Ok(MetricPoint {
+                    recorded: row.get(0)?,
+                    commit: row.get(1)?,
+                    ci: row.get(2)?,
+                    value: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        points.reverse();
+        Ok(points)
+    }
+
+    /// The newest `limit` recorded results for `file`, oldest first.
+    pub fn file_history(&self, file: &str, limit: usize) -> Result<Vec<FileResult>, StoreError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT r.recorded, r.commit_hash, f.check_name, f.violations FROM runs r
+             JOIN file_results f ON f.run_id = r.id
+             WHERE f.file = ?1 ORDER BY r.id DESC, f.check_name DESC LIMIT ?2",
+        )?;
+        let mut results = stmt
+            .query_map(params![file, limit as i64], |row| {
+                Ok(FileResult {
+                    recorded: row.get(0)?,
+                    commit: row.get(1)?,
+                    check: row.get(2)?,
+                    violations: row.get::<_, i64>(3)? as usize,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        results.reverse();
+        Ok(results)
+    }
+
+    /// Keep a copy of a saved baseline.
+    pub fn record_baseline(&self, baseline: &Baseline) -> Result<(), StoreError> {
+        self.conn.execute(
+            "INSERT INTO baselines (recorded, commit_hash, body) VALUES (?1, ?2, ?3)",
+            params![
+                baseline.updated,
+                baseline.commit,
+                serde_json::to_string(baseline)?
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Goal metric values from every recorded baseline, oldest first.
+    ///
+    /// Unlike the samples kept in the baseline itself, these aren't capped.
+    pub fn samples(&self) -> Result<Vec<MetricSample>, StoreError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT body FROM baselines ORDER BY id")?;
+        let bodies = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut samples = Vec::new();
+        for body in bodies {
+            let baseline: Baseline = serde_json::from_str(&body)?;
+            let metrics = baseline.metrics;
+            if metrics.coverage.is_none() && metrics.escapes.is_none() {
+                continue;
+            }
+            samples.push(MetricSample {
+                date: baseline.updated,
+                coverage: metrics.coverage.map(|c| c.total),
+                escapes: metrics.escapes.map(|e| e.source).unwrap_or_default(),
+                tags: baseline.tags,
+            });
+        }
+        Ok(samples)
+    }
+}
+
+/// Violation counts by file and check. Project-level violations are left out.
+fn file_counts(output: &CheckOutput) -> BTreeMap<(String, String), usize> {
+    let mut counts = BTreeMap::new();
+    for result in &output.checks {
+        for violation in &result.violations {
+            let Some(file) = &violation.file else {
+                continue;
+            };
+            let key = (file.display().to_string(), result.name.clone());
+            *counts.entry(key).or_default() += 1;
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+#[path = "store_tests.rs"]
+mod tests;
@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use crate::baseline::{BaselineMetrics, EscapesMetrics};
+use crate::check::{CheckResult, Violation};
+use crate::metrics::{CheckMetrics, Escapes};
+
+use super::*;
+
+fn escapes_output(unwraps: usize, violations: Vec<Violation>) -> CheckOutput {
+    let escapes = Escapes {
+        source: BTreeMap::from([("unwrap".to_string(), unwraps)]),
+        source_lines: 100,
+        ..Default::default()
+    };
+    let result = if violations.is_empty() {
+        CheckResult::passed("escapes")
+    } else {
+        CheckResult::failed("escapes", violations)
+    };
+    CheckOutput::new(
+        "2026-01-01T00:00:00Z".to_string(),
+        vec![result.with_metrics(escapes.to_json())],
+    )
+}
+
+fn baseline_with_unwraps(count: usize) -> Baseline {
+    let mut baseline = Baseline::new();
+    baseline.metrics = BaselineMetrics {
+        escapes: Some(EscapesMetrics {
+            source: BTreeMap::from([("unwrap".to_string(), count)]),
+            test: None,
+            source_lines: None,
+            by_package: None,
+        }),
+        ..Default::default()
+    };
+    baseline
+}
+
+// =============================================================================
+// Schema
+// =============================================================================
+
+#[test]
+fn open_creates_database_and_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(".quench/quench.db");
+
+    Store::open(&path).unwrap();
+    assert!(path.exists());
+    // Reopening an existing database keeps its schema
+    Store::open(&path).unwrap();
+}
+
+#[test]
+fn open_rejects_newer_schema() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("quench.db");
+    Connection::open(&path)
+        .unwrap()
+        .pragma_update(None, "user_version", SCHEMA_VERSION + 1)
+        .unwrap();
+
+    let err = Store::open(&path).err().unwrap();
+    assert!(matches!(err, StoreError::Version { .. }), "{err}");
+}
+
+// =============================================================================
+// Runs
+// =============================================================================
+
+#[test]
+fn metric_at_returns_latest_run_for_commit() {
+    let mut store = Store::open_in_memory().unwrap();
+    store
+        .record_run(Some("abc1234"), false, &escapes_output(3, vec![]))
+        .unwrap();
+    store
+        .record_run(Some("abc1234"), false, &escapes_output(5, vec![]))
+        .unwrap();
+
+    let value = store
+        .metric_at("abc1234", false, "escapes.source.unwrap")
+        .unwrap();
+    assert_eq!(value, Some(5.0));
+    assert_eq!(
+        store
+            .metric_at("abc1234", true, "escapes.source.unwrap")
+            .unwrap(),
+        None
+    );
+    assert!(store.has_run("abc1234", false).unwrap());
+    assert!(!store.has_run("def5678", false).unwrap());
+}
+
+#[test]
+fn metric_history_returns_newest_values_oldest_first() {
+    let mut store = Store::open_in_memory().unwrap();
+    for (commit, count) in [("a", 1), ("b", 2), ("c", 3)] {
+        store
+            .record_run(Some(commit), false, &escapes_output(count, vec![]))
+            .unwrap();
+    }
+
+    let history = store.metric_history("escapes.source.unwrap", 2).unwrap();
+    let values: Vec<_> = history
+        .iter()
+        .map(|p| (p.commit.as_deref(), p.value))
+        .collect();
+    assert_eq!(values, [(Some("b"), 2.0), (Some("c"), 3.0)]);
+}
+
+#[test]
+fn file_history_counts_violations_per_check() {
+    let mut store = Store::open_in_memory().unwrap();
+    let violations = vec![
+        Violation::file("src/lib.rs", 1, "forbidden", "no"),
+        Violation::file("src/lib.rs", 9, "forbidden", "no"),
+        Violation::project("lint_policy", "no"),
+    ];
+    store
+        .record_run(Some("a"), false, &escapes_output(2, violations))
+        .unwrap();
+    store
+        .record_run(Some("b"), false, &escapes_output(0, vec![]))
+        .unwrap();
+
+    let history = store.file_history("src/lib.rs", 10).unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].commit.as_deref(), Some("a"));
+    assert_eq!(history[0].check, "escapes");
+    assert_eq!(history[0].violations, 2);
+}
+
+// =============================================================================
+// Baselines
+// =============================================================================
+
+#[test]
+fn samples_come_from_every_recorded_baseline() {
+    let store = Store::open_in_memory().unwrap();
+    for count in [9, 7, 4] {
+        store
+            .record_baseline(&baseline_with_unwraps(count))
+            .unwrap();
+    }
+    store.record_baseline(&Baseline::new()).unwrap();
+
+    let counts: Vec<_> = store
+        .samples()
+        .unwrap()
+        .iter()
+        .map(|s| s.escapes["unwrap"])
+        .collect();
+    assert_eq!(counts, [9, 7, 4]);
+}
@@ -0,0 +1,389 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Suppression inventory (`quench suppressions list`, `quench report`).
+//!
+//! Collects every way a finding is silenced: inline lint suppressions in
+//! source files, per-language `allow` lists, `[[quarantine]]` entries, and
+//! grandfathered lint findings. Each gets an age from `git blame` and an
+//! optional expiry: quarantines use `until`, everything else an
+//! `expires YYYY-MM-DD` note in its justification or TOML comment.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, NaiveDate};
+use git2::{Blame, Repository};
+use serde::Serialize;
+
+use crate::adapter::project::apply_language_defaults;
+use crate::checks::escapes::suppress_directives;
+use crate::config::{Config, SuppressScopeConfig};
+use crate::walker::{FileWalker, WalkerConfig};
+
+/// Days ahead `--expiring` and the report look by default.
+pub const DEFAULT_EXPIRING_DAYS: u32 = 30;
+
+/// How a suppression silences findings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuppressionKind {
+    /// A lint suppression in source (e.g., `#[allow(...)]`, `# noqa`).
+    Inline,
+    /// A lint code in a `[<lang>.suppress] allow` list.
+    AllowList,
+    /// A `[[quarantine]]` directory.
+    Quarantine,
+    /// Lint findings left to the ratchet (`[check.lint] grandfather`).
+    Grandfather,
+}
+
+impl SuppressionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Inline => "inline",
+            Self::AllowList => "allow_list",
+            Self::Quarantine => "quarantine",
+            Self::Grandfather => "grandfather",
+        }
+    }
+}
+
+/// One suppression and where it's written.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Suppression {
+    pub kind: SuppressionKind,
+    /// Source file, or the config file for config entries.
+    pub file: PathBuf,
+    pub line: u32,
+    /// What's suppressed, e.g. `#[allow(dead_code)]`.
+    pub pattern: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Day its line was committed; None if uncommitted.
+    pub added: Option<NaiveDate>,
+    /// Last day it applies.
+    pub expires: Option<NaiveDate>,
+}
+
+impl Suppression {
+    /// Days since it was committed.
+    pub fn age_days(&self, today: NaiveDate) -> Option<i64> {
+        self.added.map(|added| (today - added).num_days())
+    }
+
+    /// Whether `today` is past its expiry.
+    pub fn is_expired(&self, today: NaiveDate) -> bool {
+        self.expires.is_some_and(|expires| today > expires)
+    }
+
+    /// Whether it has expired or expires within `days`.
+    pub fn expires_within(&self, today: NaiveDate, days: u32) -> bool {
+        self.expires
+            .is_some_and(|expires| (expires - today).num_days() <= i64::from(days))
+    }
+}
+
+/// Counts for the report.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SuppressionSummary {
+    pub total: usize,
+    pub by_kind: BTreeMap<&'static str, usize>,
+    pub expired: usize,
+    /// Not yet expired, but expiring within [`DEFAULT_EXPIRING_DAYS`].
+    pub expiring: usize,
+    /// Age of the oldest committed suppression.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oldest_days: Option<i64>,
+}
+
+impl SuppressionSummary {
+    pub fn new(suppressions: &[Suppression], today: NaiveDate) -> Self {
+        let mut summary = Self {
+            total: suppressions.len(),
+            ..Default::default()
+        };
+        for s in suppressions {
+            *summary.by_kind.entry(s.kind.as_str()).or_default() += 1;
+            if s.is_expired(today) {
+                summary.expired += 1;
+            } else if s.expires_within(today, DEFAULT_EXPIRING_DAYS) {
+                summary.expiring += 1;
+            }
+        }
+        summary.oldest_days = suppressions.iter().filter_map(|s| s.age_days(today)).max();
+        summary
+    }
+
+    /// One-line summary, e.g. "12 (1 expired, 2 expiring, oldest 412 days)".
+    pub fn summary(&self) -> String {
+        let mut parts = vec![
+            format!("{} expired", self.expired),
+            format!("{} expiring", self.expiring),
+        ];
+        if let Some(days) = self.oldest_days {
+            parts.push(format!("oldest {} days", days));
+        }
+        format!("{} ({})", self.total, parts.join(", "))
+    }
+}
+
+/// Every suppression in the project, by file and line.
+///
+/// `config` gets language defaults applied, as for a check run.
+pub fn collect(root: &Path, config: &mut Config, config_path: Option<&Path>) -> Vec<Suppression> {
+    let mut suppressions = inline(root, config);
+    if let Some(path) = config_path
+        && let Ok(text) = std::fs::read_to_string(path)
+    {
+        let file = path.strip_prefix(root).unwrap_or(path);
+        suppressions.extend(configured(config, file, &text));
+    }
+    suppressions.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+    add_dates(root, &mut suppressions);
+    suppressions
+}
+
+/// Inline suppressions in source files.
+fn inline(root: &Path, config: &mut Config) -> Vec<Suppression> {
+    let exclude_patterns = apply_language_defaults(root, config);
+    let walker = FileWalker::new(WalkerConfig {
+        exclude_patterns,
+        ..WalkerConfig::for_project(&config.project)
+    });
+    let (files, _) = walker.walk_collect(root);
+
+    let mut suppressions = Vec::new();
+    for file in files {
+        let Ok(content) = std::fs::read_to_string(&file.path) else {
+            continue;
+        };
+        let directives = suppress_directives(&file.path, &content, config);
+        if directives.is_empty() {
+            continue;
+        }
+        let lines: Vec<&str> = content.lines().collect();
+        let relative = file.path.strip_prefix(root).unwrap_or(&file.path);
+        for directive in directives {
+            let line_text = lines.get(directive.line).copied().unwrap_or_default();
+            let expires = directive
+                .comment_text
+                .as_deref()
+                .and_then(parse_expiry)
+                .or_else(|| parse_expiry(line_text));
+            suppressions.push(Suppression {
+                kind: SuppressionKind::Inline,
+                file: relative.to_path_buf(),
+                line: directive.line as u32 + 1,
+                pattern: directive.pattern,
+                reason: directive.comment_text,
+                added: None,
+                expires,
+            });
+        }
+    }
+    suppressions
+}
+
+/// Suppressions written in the config file.
+///
+/// Entries that only come from defaults aren't in the file, so they're
+/// left out.
+fn configured(config: &Config, file: &Path, text: &str) -> Vec<Suppression> {
+    let toml = TomlLines::new(text);
+    let mut suppressions = Vec::new();
+    let mut push = |kind, line: usize, pattern: String, reason: Option<String>, expires| {
+        suppressions.push(Suppression {
+            kind,
+            file: file.to_path_buf(),
+            line: line as u32 + 1,
+            pattern,
+            reason,
+            added: None,
+            expires,
+        });
+    };
+
+    let scopes: [(&str, &SuppressScopeConfig, &SuppressScopeConfig); 6] = [
+        (
+            "rust",
+            &config.rust.suppress.source,
+            &config.rust.suppress.test,
+        ),
+        (
+            "golang",
+            &config.golang.suppress.source,
+            &config.golang.suppress.test,
+        ),
+        (
+            "javascript",
+            &config.javascript.suppress.source,
+            &config.javascript.suppress.test,
+        ),
+        (
+            "python",
+            &config.python.suppress.source,
+            &config.python.suppress.test,
+        ),
+        (
+            "ruby",
+            &config.ruby.suppress.source,
+            &config.ruby.suppress.test,
+        ),
+        (
+            "shell",
+            &config.shell.suppress.source,
+            &config.shell.suppress.test,
+        ),
+    ];
+    for (lang, source, test) in scopes {
+        for (scope, entries) in [("source", source), ("test", test)] {
+            let table = format!("{}.suppress.{}", lang, scope);
+            for code in &entries.allow {
+                let quoted = format!("\"{}\"", code);
+                let Some(line) = toml
+                    .find(&table, &quoted)
+                    .or_else(|| toml.find(lang, &quoted))
+                else {
+                    continue;
+                };
+                let comment = toml.comment(line);
+                push(
+                    SuppressionKind::AllowList,
+                    line,
+                    format!("{}.allow: {}", table, code),
+                    comment.map(str::to_string),
+                    comment.and_then(parse_expiry),
+                );
+            }
+        }
+    }
+
+    for entry in &config.quarantine {
+        let Some(line) = toml.find("quarantine", &format!("\"{}\"", entry.path)) else {
+            continue;
+        };
+        push(
+            SuppressionKind::Quarantine,
+            line,
+            format!("quarantine: {}", entry.path),
+            entry.reason.clone(),
+            entry.until,
+        );
+    }
+
+    if config.check.lint.grandfather
+        && let Some(line) = toml.find("check.lint", "grandfather")
+    {
+        let comment = toml.comment(line);
+        push(
+            SuppressionKind::Grandfather,
+            line,
+            "check.lint.grandfather".to_string(),
+            comment.map(str::to_string),
+            comment.and_then(parse_expiry),
+        );
+    }
+    suppressions
+}
+
+/// Config file lines with the table each is in.
+struct TomlLines<'a> {
+    lines: Vec<(String, &'a str)>,
+}
+
+impl<'a> TomlLines<'a> {
+    fn new(text: &'a str) -> Self {
+        let mut table = String::new();
+        let lines = text
+            .lines()
+            .map(|line| {
+                let trimmed = line.trim();
+                if trimmed.starts_with('[') {
+                    table = trimmed
+                        .trim_matches(|c| c == '[' || c == ']')
+                        .trim()
+                        .to_string();
+                }
+                (table.clone(), line)
+            })
+            .collect();
+        Self { lines }
+    }
+
+    /// First line in `table` (or a table under it, or a dotted key in its
+    /// parent) containing `needle` before any comment.
+    fn find(&self, table: &str, needle: &str) -> Option<usize> {
+        self.lines.iter().position(|(current, line)| {
+            let in_table = current == table
+                || current.starts_with(&format!("{}.", table))
+                || table.starts_with(&format!("{}.", current));
+            in_table && strip_comment(line).contains(needle)
+        })
+    }
+
+    /// A line's trailing comment.
+    fn comment(&self, line: usize) -> Option<&'a str> {
+        let text = self.lines.get(line)?.1;
+        let code = strip_comment(text);
+        let comment = text[code.len()..].trim_start_matches('#').trim();
+        (!comment.is_empty()).then_some(comment)
+    }
+}
+
+/// A TOML line without its trailing comment, ignoring `#` inside strings.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Expiry from an `expires YYYY-MM-DD` (or `expires: YYYY-MM-DD`) note.
+pub fn parse_expiry(text: &str) -> Option<NaiveDate> {
+    let lower = text.to_ascii_lowercase();
+    let start = lower.find("expires")? + "expires".len();
+    let rest = text[start..].trim_start_matches([':', '=', ' ']);
+    NaiveDate::parse_from_str(rest.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// Fill in `added` from `git blame` at HEAD.
+fn add_dates(root: &Path, suppressions: &mut [Suppression]) {
+    let Ok(repo) = Repository::discover(root) else {
+        return;
+    };
+    let Some(workdir) = repo.workdir().map(Path::to_path_buf) else {
+        return;
+    };
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    let mut blames: HashMap<PathBuf, Option<Blame<'_>>> = HashMap::new();
+    for suppression in suppressions {
+        let Ok(repo_path) = root
+            .join(&suppression.file)
+            .strip_prefix(&workdir)
+            .map(Path::to_path_buf)
+        else {
+            continue;
+        };
+        let blame = blames
+            .entry(repo_path)
+            .or_insert_with_key(|path| repo.blame_file(path, None).ok());
+        let Some(hunk) = blame
+            .as_ref()
+            .and_then(|b| b.get_line(suppression.line as usize))
+        else {
+            continue;
+        };
+        let seconds = hunk.final_signature().when().seconds();
+        suppression.added = DateTime::from_timestamp(seconds, 0).map(|t| t.date_naive());
+    }
+}
+
+#[cfg(test)]
+#[path = "suppressions_tests.rs"]
+mod tests;
@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use crate::test_utils::{git, temp_project_with_config};
+
+fn date(text: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+}
+
+fn suppression(added: Option<&str>, expires: Option<&str>) -> Suppression {
+    Suppression {
+        kind: SuppressionKind::Inline,
+        file: PathBuf::from("src/lib.rs"),
+        line: 1,
+        pattern: "#[allow(dead_code)]".to_string(),
+        reason: None,
+        added: added.map(date),
+        expires: expires.map(date),
+    }
+}
+
+fn collect_in(root: &Path) -> Vec<Suppression> {
+    let path = root.join("quench.toml");
+    let mut config = crate::config::load(&path).unwrap();
+    collect(root, &mut config, Some(&path))
+}
+
+// =============================================================================
+// Expiry
+// =============================================================================
+
+#[test]
+fn parse_expiry_finds_note_in_comment() {
+    assert_eq!(
+        parse_expiry("KEEP: legacy API, expires 2026-12-31"),
+        Some(date("2026-12-31"))
+    );
+    assert_eq!(
+        parse_expiry("Expires: 2026-01-02 after the migration"),
+        Some(date("2026-01-02"))
+    );
+    assert_eq!(parse_expiry("expires soon"), None);
+    assert_eq!(parse_expiry("no note"), None);
+}
+
+#[test]
+fn expiry_counts_the_last_day_as_active() {
+    let s = suppression(None, Some("2026-03-10"));
+
+    assert!(!s.is_expired(date("2026-03-10")));
+    assert!(s.is_expired(date("2026-03-11")));
+    assert!(s.expires_within(date("2026-03-01"), 9));
+    assert!(!s.expires_within(date("2026-03-01"), 8));
+    assert!(!suppression(None, None).expires_within(date("2026-03-01"), 365));
+}
+
+#[test]
+fn summary_counts_kinds_expiry_and_oldest() {
+    let today = date("2026-06-01");
+    let mut allow = suppression(Some("2026-05-01"), None);
+    allow.kind = SuppressionKind::AllowList;
+    let suppressions = vec![
+        suppression(Some("2025-06-01"), Some("2026-05-01")),
+        suppression(None, Some("2026-06-20")),
+        suppression(None, Some("2027-01-01")),
+        allow,
+    ];
+
+    let summary = SuppressionSummary::new(&suppressions, today);
+
+    assert_eq!(summary.total, 4);
+    assert_eq!(summary.by_kind["inline"], 3);
+    assert_eq!(summary.by_kind["allow_list"], 1);
+    assert_eq!(summary.expired, 1);
+    assert_eq!(summary.expiring, 1);
+    assert_eq!(summary.oldest_days, Some(365));
+    assert_eq!(
+        summary.summary(),
+        "4 (1 expired, 1 expiring, oldest 365 days)"
+    );
+}
+
+// =============================================================================
+// Collection
+// =============================================================================
+
+#[test]
+fn collects_inline_suppressions_with_expiry() {
+    let temp = temp_project_with_config("version = 1\n");
+    std::fs::create_dir_all(temp.path().join("src")).unwrap();
+    std::fs::write(
+        temp.path().join("src/lib.rs"),
+        "// KEEP: FFI callback, expires 2026-09-01\n#[allow(dead_code)]\nfn a() {}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        temp.path().join("check.py"),
+        "import os  # noqa: F401  expires 2026-07-01\n",
+    )
+    .unwrap();
+
+    let found = collect_in(temp.path());
+
+    let summary: Vec<_> = found
+        .iter()
+        .map(|s| (s.file.to_str().unwrap(), s.line, s.expires))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("check.py", 1, Some(date("2026-07-01"))),
+            ("src/lib.rs", 2, Some(date("2026-09-01"))),
+        ]
+    );
+    assert_eq!(found[1].pattern, "#[allow(dead_code)]");
+    assert_eq!(found[1].added, None);
+}
+
+#[test]
+fn collects_config_entries_from_the_file() {
+    let temp = temp_project_with_config(
+        r#"version = 1
+
+[rust.suppress.source]
+allow = [
+  "dead_code",  # expires 2026-10-01
+]
+
+[[quarantine]]
+path = "spikes/parser"
+until = 2026-08-31
+reason = "prototype"
+
+[check.lint]
+check = "error"
+grandfather = true  # until the clippy backlog is gone
+"#,
+    );
+
+    let found = collect_in(temp.path());
+
+    let summary: Vec<_> = found.iter().map(|s| (s.kind, s.line, s.expires)).collect();
+    assert_eq!(
+        summary,
+        [
+            (SuppressionKind::AllowList, 5, Some(date("2026-10-01"))),
+            (SuppressionKind::Quarantine, 9, Some(date("2026-08-31"))),
+            (SuppressionKind::Grandfather, 15, None),
+        ]
+    );
+    assert_eq!(found[0].pattern, "rust.suppress.source.allow: dead_code");
+    assert_eq!(found[1].reason.as_deref(), Some("prototype"));
+    assert_eq!(
+        found[2].reason.as_deref(),
+        Some("until the clippy backlog is gone")
+    );
+}
+
+#[test]
+fn dates_suppressions_from_blame() {
+    let temp = temp_project_with_config("version = 1\n");
+    git::init(temp.path());
+    std::fs::write(temp.path().join("lib.rs"), "#[allow(unused)]\nfn a() {}\n").unwrap();
+    git::initial_commit(temp.path());
+
+    let found = collect_in(temp.path());
+
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].added, Some(chrono::Local::now().date_naive()));
+}
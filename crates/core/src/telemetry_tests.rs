@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use crate::check::{CheckResult, Violation};
+use yare::parameterized;
+
+#[parameterized(
+    tiny = { 0, "<100" },
+    small = { 999, "100-1k" },
+    medium = { 1_000, "1k-10k" },
+    large = { 99_999, "10k-100k" },
+    huge = { 100_000, "100k+" },
+)]
+fn size_bucket_groups_file_counts(files: usize, expected: &str) {
+    assert_eq!(size_bucket(files), expected);
+}
+
+fn run() -> RunInfo {
+    RunInfo {
+        files: 42,
+        total_ms: 120,
+        ci: true,
+    }
+}
+
+#[test]
+fn summary_counts_check_outcomes() {
+    let output = CheckOutput::new(
+        "2026-01-01T00:00:00Z".to_string(),
+        vec![
+            CheckResult::passed("cloc"),
+            CheckResult::failed(
+                "escapes",
+                vec![Violation::file("src/secret.rs", 1, "forbidden", "x")],
+            ),
+            CheckResult::skipped("tests", "no runner".to_string()),
+        ],
+    );
+    let summary = summary(&output, run());
+
+    assert_eq!(summary["repo_size"], "<100");
+    assert_eq!(summary["passed"], false);
+    assert_eq!(summary["ci"], true);
+    assert_eq!(
+        summary["counts"],
+        json!({"passed": 1, "failed": 1, "skipped": 1})
+    );
+    assert_eq!(summary["checks"][1]["violations"], 1);
+}
+
+#[test]
+fn summary_omits_paths_and_advice() {
+    let output = CheckOutput::new(
+        "2026-01-01T00:00:00Z".to_string(),
+        vec![CheckResult::failed(
+            "escapes",
+            vec![Violation::file(
+                "src/secret.rs",
+                1,
+                "forbidden",
+                "private advice",
+            )],
+        )],
+    );
+    let text = summary(&output, run()).to_string();
+    assert!(!text.contains("secret"));
+    assert!(!text.contains("private advice"));
+    assert!(!text.contains("2026-01-01"));
+}
+
+#[test]
+fn summary_names_instances_by_their_check() {
+    let output = CheckOutput::new(
+        "2026-01-01T00:00:00Z".to_string(),
+        vec![CheckResult::passed("cloc:internal-scripts")],
+    );
+    let summary = summary(&output, run());
+    assert_eq!(summary["checks"][0]["name"], "cloc");
+    assert!(!summary.to_string().contains("internal-scripts"));
+}
+
+#[parameterized(
+    file = { "file:///etc/passwd" },
+    option = { "--config=/tmp/x" },
+    bare = { "metrics.example.com" },
+)]
+fn send_rejects_non_http_endpoints(endpoint: &str) {
+    let err = send(endpoint, Duration::from_secs(1), &json!({})).unwrap_err();
+    assert!(err.contains("not an http(s) URL"), "{}", err);
+}
@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Golden-file test harness for fixture projects.
+//!
+//! Runs a quench binary against `<fixtures>/<case>` with `-o json` and
+//! compares the output, with run-specific fields removed, to
+//! `<goldens>/<case>.json`. Set `QUENCH_BLESS=1` to write the goldens
+//! instead of comparing.
+//!
+//! ```ignore
+//! let harness = GoldenHarness::new(env!("CARGO_BIN_EXE_quench"), "tests/fixtures", "tests/golden")
+//!     .args(["--no-git"]);
+//! harness.check("minimal").unwrap();
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde_json::Value as JsonValue;
+
+use crate::determinism::{diff_text, strip_volatile};
+
+/// Environment variable that switches the harness to writing goldens.
+pub const BLESS_ENV: &str = "QUENCH_BLESS";
+
+/// Runs fixture cases and compares their JSON output to golden files.
+#[derive(Debug, Clone)]
+pub struct GoldenHarness {
+    bin: PathBuf,
+    fixtures: PathBuf,
+    goldens: PathBuf,
+    args: Vec<String>,
+}
+
+/// Errors from running or comparing a golden case.
+#[derive(Debug, thiserror::Error)]
+pub enum GoldenError {
+    #[error("failed to run {bin}: {source}")]
+    Spawn {
+        bin: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("{case}: output is not JSON ({source}); stderr:\n{stderr}")]
+    InvalidOutput {
+        case: String,
+        stderr: String,
+        source: serde_json::Error,
+    },
+
+    #[error("{case}: golden file {} not found (run with {BLESS_ENV}=1 to create it)", path.display())]
+    MissingGolden { case: String, path: PathBuf },
+
+    #[error("{case}: output differs from {}:\n{diff}", path.display())]
+    Mismatch {
+        case: String,
+        path: PathBuf,
+        diff: String,
+    },
+
+    #[error("failed to access {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl GoldenHarness {
+    /// Create a harness for a quench binary, fixture root, and golden directory.
+    pub fn new(
+        bin: impl Into<PathBuf>,
+        fixtures: impl Into<PathBuf>,
+        goldens: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            bin: bin.into(),
+            fixtures: fixtures.into(),
+            goldens: goldens.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Extra arguments passed to `quench check` for every case.
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Path to the golden file for a case.
+    pub fn golden_path(&self, case: &str) -> PathBuf {
+        self.goldens.join(format!("{}.json", case))
+    }
+
+    /// Run a case and return its normalized JSON output.
+    ///
+    /// A failing check run is not an error; failures are part of the output.
+    pub fn run(&self, case: &str) -> Result<JsonValue, GoldenError> {
+        let output = Command::new(&self.bin)
+            .args(["check", "--no-cache", "-o", "json"])
+            .args(&self.args)
+            .current_dir(self.fixtures.join(case))
+            .output()
+            .map_err(|source| GoldenError::Spawn {
+                bin: self.bin.clone(),
+                source,
+            })?;
+
+        let mut value: JsonValue = serde_json::from_slice(&output.stdout).map_err(|source| {
+            GoldenError::InvalidOutput {
+                case: case.to_string(),
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+                source,
+            }
+        })?;
+        strip_volatile(&mut value);
+        Ok(value)
+    }
+
+    /// Run a case and compare it to its golden file (or write it when blessing).
+    pub fn check(&self, case: &str) -> Result<(), GoldenError> {
+        let actual = self.run(case)?;
+        if std::env::var_os(BLESS_ENV).is_some_and(|v| v != "0") {
+            self.bless(case, &actual)
+        } else {
+            self.compare(case, &actual)
+        }
+    }
+
+    /// Compare normalized output to the case's golden file.
+    pub fn compare(&self, case: &str, actual: &JsonValue) -> Result<(), GoldenError> {
+        let path = self.golden_path(case);
+        let expected = match std::fs::read_to_string(&path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(GoldenError::MissingGolden {
+                    case: case.to_string(),
+                    path,
+                });
+            }
+            Err(source) => return Err(GoldenError::Io { path, source }),
+        };
+
+        match diff_text(expected.trim_end(), &render(actual)) {
+            None => Ok(()),
+            Some(diff) => Err(GoldenError::Mismatch {
+                case: case.to_string(),
+                path,
+                diff,
+            }),
+        }
+    }
+
+    /// Write normalized output as the case's golden file.
+    pub fn bless(&self, case: &str, actual: &JsonValue) -> Result<(), GoldenError> {
+        let path = self.golden_path(case);
+        write_golden(&path, actual).map_err(|source| GoldenError::Io { path, source })
+    }
+}
+
+fn render(value: &JsonValue) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_default()
+}
+
+fn write_golden(path: &Path, value: &JsonValue) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, format!("{}\n", render(value)))
+}
+
+#[cfg(test)]
+#[path = "testkit_tests.rs"]
+mod tests;
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use serde_json::json;
+use tempfile::TempDir;
+
+fn harness(goldens: &Path) -> GoldenHarness {
+    GoldenHarness::new("quench", "fixtures", goldens)
+}
+
+#[test]
+fn golden_path_uses_case_name() {
+    let h = GoldenHarness::new("quench", "fixtures", "golden");
+    assert_eq!(
+        h.golden_path("rust/simple"),
+        Path::new("golden/rust/simple.json")
+    );
+}
+
+#[test]
+fn compare_reports_missing_golden() {
+    let dir = TempDir::new().unwrap();
+    let err = harness(dir.path()).compare("case", &json!({})).unwrap_err();
+    assert!(matches!(err, GoldenError::MissingGolden { .. }));
+}
+
+#[test]
+fn blessed_output_compares_equal() {
+    let dir = TempDir::new().unwrap();
+    let h = harness(dir.path());
+    let value = json!({"passed": true, "checks": [{"name": "cloc"}]});
+
+    h.bless("nested/case", &value).unwrap();
+    h.compare("nested/case", &value).unwrap();
+}
+
+#[test]
+fn compare_reports_diff_on_mismatch() {
+    let dir = TempDir::new().unwrap();
+    let h = harness(dir.path());
+    h.bless("case", &json!({"passed": true})).unwrap();
+
+    let err = h.compare("case", &json!({"passed": false})).unwrap_err();
+    let GoldenError::Mismatch { diff, .. } = err else {
+        panic!("expected mismatch, got {err:?}");
+    };
+    assert!(diff.contains("-2:   \"passed\": true"));
+    assert!(diff.contains("+2:   \"passed\": false"));
+}
+
+#[test]
+fn run_reports_spawn_failure() {
+    let dir = TempDir::new().unwrap();
+    let h = GoldenHarness::new(dir.path().join("no-such-bin"), dir.path(), dir.path());
+    assert!(matches!(h.run("."), Err(GoldenError::Spawn { .. })));
+}
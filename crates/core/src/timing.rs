@@ -3,7 +3,7 @@
 
 //! Timing data structures for --timing flag.
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use serde::Serialize;
 
@@ -31,8 +31,8 @@ pub struct TimingInfo {
     /// Cache hits.
     pub cache_hits: usize,
     /// Per-check timing (check name -> milliseconds).
-    #[serde(skip_serializing_if = "HashMap::is_empty")]
-    pub checks: HashMap<String, u64>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub checks: BTreeMap<String, u64>,
 }
 
 impl PhaseTiming {
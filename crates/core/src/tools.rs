@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Tool version pinning (`[tools]`).
+//!
+//! Toolchain drift shifts metrics (test timing, coverage, build sizes) for
+//! reasons unrelated to the code. Pinned versions are verified when
+//! `quench check` starts; a mismatch either fails or skips the checks that
+//! use the tool.
+
+use std::process::Command;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::check::{CheckResult, Field, Violation};
+use crate::config::{ToolMismatch, ToolsConfig};
+
+/// A tool whose version can be pinned.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Tool {
+    /// Name used as the `[tools]` key.
+    pub name: &'static str,
+    /// Command that prints the version.
+    pub command: &'static [&'static str],
+    /// Checks whose results depend on this tool.
+    pub checks: &'static [&'static str],
+    /// Install command; `{version}` is replaced by the pinned version.
+    pub install: &'static str,
+}
+
+/// Tools that can be pinned, by name.
+pub const TOOLS: &[Tool] = &[
+    Tool {
+        name: "bun",
+        command: &["bun", "--version"],
+        checks: &["tests", "build"],
+        install: "curl -fsSL https://bun.sh/install | bash -s bun-v{version}",
+    },
+    Tool {
+        name: "cargo",
+        command: &["cargo", "--version"],
+        checks: &["tests", "build"],
+        install: "rustup toolchain install {version} && rustup override set {version}",
+    },
+    Tool {
+        name: "go",
+        command: &["go", "version"],
+        checks: &["tests", "build"],
+        install: "go install golang.org/dl/go{version}@latest && go{version} download",
+    },
+    Tool {
+        name: "node",
+        command: &["node", "--version"],
+        checks: &["tests", "build"],
+        install: "nvm install {version} && nvm use {version}",
+    },
+    Tool {
+        name: "pytest",
+        command: &["pytest", "--version"],
+        checks: &["tests"],
+        install: "pip install \"pytest=={version}.*\"",
+    },
+    Tool {
+        name: "python",
+        command: &["python3", "--version"],
+        checks: &["tests"],
+        install: "pyenv install {version} && pyenv local {version}",
+    },
+    Tool {
+        name: "ruby",
+        command: &["ruby", "--version"],
+        checks: &["tests"],
+        install: "rbenv install {version} && rbenv local {version}",
+    },
+    Tool {
+        name: "rustc",
+        command: &["rustc", "--version"],
+        checks: &["tests", "build"],
+        install: "rustup toolchain install {version} && rustup override set {version}",
+    },
+];
+
+/// First dotted version number in command output.
+#[allow(clippy::expect_used)]
+static VERSION_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\d+(?:\.\d+)+").expect("valid regex"));
+
+/// Look up a tool by name.
+pub fn find(name: &str) -> Option<&'static Tool> {
+    TOOLS.iter().find(|t| t.name == name)
+}
+
+/// Names of all tools that can be pinned.
+pub fn names() -> Vec<&'static str> {
+    TOOLS.iter().map(|t| t.name).collect()
+}
+
+impl Tool {
+    /// The installed version, or None if the tool isn't installed.
+    pub fn detect(&self) -> Option<String> {
+        let output = Command::new(self.command[0])
+            .args(&self.command[1..])
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        // Some tools (older pytest) print their version to stderr
+        parse_version(&String::from_utf8_lossy(&output.stdout))
+            .or_else(|| parse_version(&String::from_utf8_lossy(&output.stderr)))
+    }
+
+    /// Command that installs `version`.
+    pub fn install_command(&self, version: &str) -> String {
+        self.install.replace("{version}", version)
+    }
+}
+
+/// Extract the version number from a tool's version output
+/// (e.g., "cargo 1.85.0 (d73d2caf9 2024-12-31)" -> "1.85.0").
+pub fn parse_version(output: &str) -> Option<String> {
+    VERSION_PATTERN.find(output).map(|m| m.as_str().to_string())
+}
+
+/// Whether `found` is the pinned version: `expected`'s components are a
+/// prefix of `found`'s ("1.85" matches "1.85.0" but not "1.8.5").
+pub fn version_matches(expected: &str, found: &str) -> bool {
+    let expected: Vec<&str> = expected.trim_start_matches('v').split('.').collect();
+    let found: Vec<&str> = found.split('.').collect();
+    expected.len() <= found.len() && expected.iter().zip(&found).all(|(e, f)| e == f)
+}
+
+/// A pinned tool and the version installed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolStatus {
+    pub tool: &'static Tool,
+    /// Pinned version.
+    pub expected: String,
+    /// Installed version (None = not installed).
+    pub found: Option<String>,
+}
+
+impl ToolStatus {
+    /// Whether the installed version is the pinned one.
+    pub fn ok(&self) -> bool {
+        self.found
+            .as_deref()
+            .is_some_and(|found| version_matches(&self.expected, found))
+    }
+
+    /// Why the tool doesn't match its pin.
+    pub fn reason(&self) -> String {
+        match &self.found {
+            Some(found) => format!(
+                "{} {} doesn't match pinned version {}",
+                self.tool.name, found, self.expected
+            ),
+            None => format!(
+                "{} not found (pinned version {})",
+                self.tool.name, self.expected
+            ),
+        }
+    }
+}
+
+/// Check pinned versions using `detect` to find installed versions.
+pub fn verify_with(
+    config: &ToolsConfig,
+    detect: impl Fn(&Tool) -> Option<String>,
+) -> Vec<ToolStatus> {
+    config
+        .versions
+        .iter()
+        .filter_map(|(name, expected)| {
+            let tool = find(name)?;
+            Some(ToolStatus {
+                tool,
+                expected: expected.clone(),
+                found: detect(tool),
+            })
+        })
+        .collect()
+}
+
+/// Check pinned versions against the installed tools.
+pub fn verify(config: &ToolsConfig) -> Vec<ToolStatus> {
+    verify_with(config, Tool::detect)
+}
+
+/// Pinned tools that don't match, and what that does to dependent checks.
+#[derive(Debug)]
+pub struct ToolMismatches {
+    mismatches: Vec<ToolStatus>,
+    on_mismatch: ToolMismatch,
+}
+
+impl ToolMismatches {
+    /// The mismatches among `statuses`.
+    pub fn new(statuses: Vec<ToolStatus>, on_mismatch: ToolMismatch) -> Self {
+        Self {
+            mismatches: statuses.into_iter().filter(|s| !s.ok()).collect(),
+            on_mismatch,
+        }
+    }
+
+    /// Whether every pinned tool matches.
+    pub fn is_empty(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Mismatched tools.
+    pub fn mismatches(&self) -> &[ToolStatus] {
+        &self.mismatches
+    }
+
+    /// Why `check` is skipped (`on_mismatch = "skip"` only).
+    pub fn skip_reason(&self, check: &str) -> Option<String> {
+        if self.on_mismatch != ToolMismatch::Skip {
+            return None;
+        }
+        let reasons: Vec<String> = self.affecting(check).map(ToolStatus::reason).collect();
+        (!reasons.is_empty()).then(|| reasons.join("; "))
+    }
+
+    /// Fail checks that used a mismatched tool (`on_mismatch = "error"` only).
+    ///
+    /// Skipped and stub results didn't run the tool, so they're left alone.
+    pub fn apply(&self, results: &mut [CheckResult]) {
+        if self.on_mismatch != ToolMismatch::Error {
+            return;
+        }
+        for result in results.iter_mut().filter(|r| !r.skipped && !r.stub) {
+            let violations: Vec<Violation> = self
+                .affecting(&result.name)
+                .map(|status| {
+                    let mut v = Violation::project(
+                        "tool_version",
+                        format!(
+                            "{}; metrics may shift with the toolchain. Run `quench doctor`.",
+                            status.reason()
+                        ),
+                    );
+                    v.details.insert(
+                        Field::Expected,
+                        format!("{} {}", status.tool.name, status.expected),
+                    );
+                    if let Some(found) = &status.found {
+                        v.details
+                            .insert(Field::Found, format!("{} {}", status.tool.name, found));
+                    }
+                    v
+                })
+                .collect();
+            if !violations.is_empty() {
+                result.violations.extend(violations);
+                result.passed = false;
+            }
+        }
+    }
+
+    fn affecting<'a>(&'a self, check: &'a str) -> impl Iterator<Item = &'a ToolStatus> {
+        self.mismatches
+            .iter()
+            .filter(move |s| s.tool.checks.contains(&check))
+    }
+}
+
+#[cfg(test)]
+#[path = "tools_tests.rs"]
+mod tests;
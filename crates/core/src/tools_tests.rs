@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+
+use std::collections::BTreeMap;
+
+fn tools(versions: &[(&str, &str)], on_mismatch: ToolMismatch) -> ToolsConfig {
+    ToolsConfig {
+        on_mismatch,
+        versions: versions
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<BTreeMap<_, _>>(),
+    }
+}
+
+/// Installed versions: cargo 1.84.0, node 20.11.0, no go.
+fn installed(tool: &Tool) -> Option<String> {
+    match tool.name {
+        "cargo" => Some("1.84.0".to_string()),
+        "node" => Some("20.11.0".to_string()),
+        _ => None,
+    }
+}
+
+fn mismatches(versions: &[(&str, &str)], on_mismatch: ToolMismatch) -> ToolMismatches {
+    let config = tools(versions, on_mismatch);
+    ToolMismatches::new(verify_with(&config, installed), on_mismatch)
+}
+
+#[test]
+fn parse_version_finds_dotted_number() {
+    assert_eq!(
+        parse_version("cargo 1.85.0 (d73d2caf9 2024-12-31)").as_deref(),
+        Some("1.85.0")
+    );
+    assert_eq!(parse_version("v20.11.0\n").as_deref(), Some("20.11.0"));
+    assert_eq!(
+        parse_version("go version go1.22.1 linux/amd64").as_deref(),
+        Some("1.22.1")
+    );
+    assert_eq!(parse_version("Python 3.12.1").as_deref(), Some("3.12.1"));
+    assert_eq!(parse_version("no version here"), None);
+}
+
+#[test]
+fn version_matches_by_component_prefix() {
+    assert!(version_matches("1.85", "1.85.0"));
+    assert!(version_matches("1.85.0", "1.85.0"));
+    assert!(version_matches("20", "20.11.0"));
+    assert!(version_matches("v20", "20.11.0"));
+    assert!(!version_matches("1.8", "1.85.0"));
+    assert!(!version_matches("1.85.1", "1.85"));
+    assert!(!version_matches("21", "20.11.0"));
+}
+
+#[test]
+fn verify_reports_mismatched_and_missing_tools() {
+    let result = mismatches(
+        &[("cargo", "1.85"), ("node", "20"), ("go", "1.22")],
+        ToolMismatch::Error,
+    );
+
+    let reasons: Vec<String> = result.mismatches().iter().map(|s| s.reason()).collect();
+    assert_eq!(
+        reasons,
+        [
+            "cargo 1.84.0 doesn't match pinned version 1.85",
+            "go not found (pinned version 1.22)",
+        ]
+    );
+}
+
+#[test]
+fn error_mode_fails_checks_that_use_the_tool() {
+    let result = mismatches(&[("cargo", "1.85")], ToolMismatch::Error);
+    let mut results = vec![
+        CheckResult::passed("cloc"),
+        CheckResult::passed("tests"),
+        CheckResult::stub("build"),
+    ];
+
+    result.apply(&mut results);
+
+    assert!(results[0].passed);
+    assert!(!results[1].passed);
+    let v = &results[1].violations[0];
+    assert_eq!(v.violation_type, "tool_version");
+    assert_eq!(v.text(Field::Expected), Some("cargo 1.85"));
+    assert_eq!(v.text(Field::Found), Some("cargo 1.84.0"));
+    // Stub results never ran the tool
+    assert!(results[2].violations.is_empty());
+    assert_eq!(result.skip_reason("tests"), None);
+}
+
+#[test]
+fn skip_mode_names_the_mismatch_as_reason() {
+    let result = mismatches(&[("cargo", "1.85"), ("node", "20")], ToolMismatch::Skip);
+    let mut results = vec![CheckResult::passed("tests")];
+
+    result.apply(&mut results);
+
+    assert!(results[0].passed);
+    assert_eq!(
+        result.skip_reason("build").as_deref(),
+        Some("cargo 1.84.0 doesn't match pinned version 1.85")
+    );
+    assert_eq!(result.skip_reason("cloc"), None);
+}
+
+#[test]
+fn pytest_only_affects_tests() {
+    let result = mismatches(&[("pytest", "8")], ToolMismatch::Skip);
+
+    assert!(result.skip_reason("tests").is_some());
+    assert_eq!(result.skip_reason("build"), None);
+}
+
+#[test]
+fn install_command_substitutes_version() {
+    assert_eq!(
+        find("node").unwrap().install_command("20"),
+        "nvm install 20 && nvm use 20"
+    );
+}
@@ -4,7 +4,8 @@
 //! Parallel file walking with gitignore support.
 //!
 //! Uses the `ignore` crate for efficient, parallel file discovery
-//! that respects `.gitignore`, custom ignore patterns, and depth limits.
+//! that respects `.gitignore`, `.ignore`, `.quenchignore`, custom ignore
+//! patterns, and depth limits.
 
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -15,7 +16,7 @@ use crossbeam_channel::{Receiver, bounded};
 use ignore::overrides::OverrideBuilder;
 use ignore::{WalkBuilder, WalkState};
 
-use crate::config::ExcludeConfig;
+use crate::config::{ExcludeConfig, ProjectConfig};
 use crate::file_size::{self, FileSizeClass};
 
 /// Helper to check if an ignore::Error is a symlink loop error.
@@ -63,6 +64,12 @@ pub const DEFAULT_MAX_DEPTH: usize = 100;
 /// Skipping at the walker level prevents any I/O on these subtrees.
 pub(crate) const SKIP_DIRECTORIES: &[&str] = &["node_modules", ".git"];
 
+/// Quench's own ignore file, in gitignore syntax.
+pub const QUENCH_IGNORE_FILE: &str = ".quenchignore";
+
+/// Ignore files read in every directory by default, besides `.gitignore`.
+pub const DEFAULT_IGNORE_FILES: &[&str] = &[".ignore", QUENCH_IGNORE_FILE];
+
 /// Walker configuration.
 #[derive(Debug, Clone)]
 pub struct WalkerConfig {
@@ -75,6 +82,10 @@ pub struct WalkerConfig {
     /// Whether to respect gitignore files.
     pub git_ignore: bool,
 
+    /// Other ignore file names (gitignore syntax) read in every directory.
+    /// Unlike `.gitignore`, these apply outside git repositories too.
+    pub ignore_files: Vec<String>,
+
     /// Whether to skip hidden files.
     pub hidden: bool,
 
@@ -102,6 +113,7 @@ impl Default for WalkerConfig {
             max_depth: Some(DEFAULT_MAX_DEPTH),
             exclude_patterns: Vec::new(),
             git_ignore: true,
+            ignore_files: DEFAULT_IGNORE_FILES.iter().map(|s| s.to_string()).collect(),
             hidden: true, // Skip hidden files by default
             threads: 0,   // Auto-detect
             parallel_threshold: DEFAULT_PARALLEL_THRESHOLD,
@@ -111,6 +123,18 @@ impl Default for WalkerConfig {
     }
 }
 
+impl WalkerConfig {
+    /// Defaults with the project's ignore file settings and exclude patterns.
+    pub fn for_project(project: &ProjectConfig) -> Self {
+        Self {
+            exclude_patterns: project.exclude.patterns.clone(),
+            git_ignore: project.gitignore,
+            ignore_files: project.ignore_files.clone(),
+            ..Default::default()
+        }
+    }
+}
+
 /// File discovered by the walker.
 #[derive(Debug)]
 pub struct WalkedFile {
@@ -230,8 +254,13 @@ impl FileWalker {
             .git_ignore(self.config.git_ignore)
             .git_exclude(true)
             .git_global(true)
+            .ignore(false) // `.ignore` is read only when listed in ignore_files
             .follow_links(true); // Follow symlinks (ignore crate detects loops)
 
+        for name in &self.config.ignore_files {
+            builder.add_custom_ignore_filename(name);
+        }
+
         if let Some(depth) = self.config.max_depth {
             builder.max_depth(Some(depth));
         }
@@ -174,6 +174,96 @@ fn from_exclude_config() {
     assert_eq!(walker.config.exclude_patterns, exclude.patterns);
 }
 
+/// Relative paths of walked files, sorted.
+fn walked_names(root: &Path, config: WalkerConfig) -> Vec<String> {
+    let (files, _) = FileWalker::new(config).walk_collect(root);
+    let mut names: Vec<String> = files
+        .iter()
+        .map(|f| f.path.strip_prefix(root).unwrap().display().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn respects_quenchignore_without_git() {
+    let tmp = TempDir::new().unwrap();
+    create_tree(
+        tmp.path(),
+        &[
+            ("src/lib.rs", "fn main() {}"),
+            ("gen/out.rs", "fn generated() {}"),
+            ("src/api.pb.rs", "fn generated() {}"),
+            (".quenchignore", "gen/\n"),
+            ("src/.quenchignore", "*.pb.rs\n"),
+        ],
+    );
+
+    assert_eq!(
+        walked_names(tmp.path(), WalkerConfig::default()),
+        ["src/lib.rs"]
+    );
+}
+
+#[test]
+fn respects_dot_ignore() {
+    let tmp = TempDir::new().unwrap();
+    create_tree(
+        tmp.path(),
+        &[
+            ("src/lib.rs", "fn main() {}"),
+            ("dist/bundle.js", "bundle()"),
+            (".ignore", "dist/\n"),
+        ],
+    );
+
+    assert_eq!(
+        walked_names(tmp.path(), WalkerConfig::default()),
+        ["src/lib.rs"]
+    );
+}
+
+#[test]
+fn ignore_files_are_configurable() {
+    let tmp = TempDir::new().unwrap();
+    create_tree(
+        tmp.path(),
+        &[
+            ("src/lib.rs", "fn main() {}"),
+            ("dist/bundle.js", "bundle()"),
+            ("gen/out.rs", "fn generated() {}"),
+            (".ignore", "dist/\n"),
+            (".quenchignore", "gen/\n"),
+        ],
+    );
+
+    let config = WalkerConfig {
+        ignore_files: vec![QUENCH_IGNORE_FILE.to_string()],
+        ..WalkerConfig::default()
+    };
+    assert_eq!(
+        walked_names(tmp.path(), config),
+        ["dist/bundle.js", "src/lib.rs"]
+    );
+}
+
+#[test]
+fn for_project_uses_ignore_settings() {
+    let project = ProjectConfig {
+        exclude: ExcludeConfig {
+            patterns: vec!["*.log".to_string()],
+        },
+        gitignore: false,
+        ignore_files: vec![".customignore".to_string()],
+        ..ProjectConfig::default()
+    };
+
+    let config = WalkerConfig::for_project(&project);
+    assert_eq!(config.exclude_patterns, ["*.log"]);
+    assert!(!config.git_ignore);
+    assert_eq!(config.ignore_files, [".customignore"]);
+}
+
 // Adaptive parallel/sequential tests
 
 #[test]
@@ -0,0 +1,2 @@
+//! File at maximum depth.
+pub fn at_depth() -> &'static str { "deep" }
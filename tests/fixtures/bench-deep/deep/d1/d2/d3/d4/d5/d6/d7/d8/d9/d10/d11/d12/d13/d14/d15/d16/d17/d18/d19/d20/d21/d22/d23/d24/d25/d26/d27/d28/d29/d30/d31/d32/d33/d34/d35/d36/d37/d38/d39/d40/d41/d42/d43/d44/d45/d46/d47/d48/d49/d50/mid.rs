@@ -0,0 +1,2 @@
+//! File at mid depth.
+pub fn at_mid() -> i32 { 50 }
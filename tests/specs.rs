@@ -15,6 +15,15 @@ mod prelude;
 #[path = "specs/cli/dry_run.rs"]
 mod cli_dry_run;
 
+#[path = "specs/cli/emit_patch.rs"]
+mod cli_emit_patch;
+
+#[path = "specs/cli/fix.rs"]
+mod cli_fix;
+
+#[path = "specs/cli/lock.rs"]
+mod cli_lock;
+
 #[path = "specs/cli/init_tests/mod.rs"]
 mod cli_init;
 
@@ -42,6 +51,60 @@ mod cli_ci_mode;
 #[path = "specs/cli/help.rs"]
 mod cli_help;
 
+#[path = "specs/cli/rules.rs"]
+mod cli_rules;
+
+#[path = "specs/cli/budget.rs"]
+mod cli_budget;
+
+#[path = "specs/cli/commit_msg.rs"]
+mod cli_commit_msg;
+
+#[path = "specs/cli/owner.rs"]
+mod cli_owner;
+
+#[path = "specs/cli/annotate.rs"]
+mod cli_annotate;
+
+#[path = "specs/cli/suggest_tests.rs"]
+mod cli_suggest_tests;
+
+#[path = "specs/cli/graph.rs"]
+mod cli_graph;
+
+#[path = "specs/cli/sbom.rs"]
+mod cli_sbom;
+
+#[path = "specs/cli/doctor.rs"]
+mod cli_doctor;
+
+#[path = "specs/cli/explain.rs"]
+mod cli_explain;
+
+#[path = "specs/cli/bot.rs"]
+mod cli_bot;
+
+#[path = "specs/cli/bisect.rs"]
+mod cli_bisect;
+
+#[path = "specs/cli/query.rs"]
+mod cli_query;
+
+#[path = "specs/cli/watch.rs"]
+mod cli_watch;
+
+#[path = "specs/cli/suppressions.rs"]
+mod cli_suppressions;
+
+#[path = "specs/cli/baseline.rs"]
+mod cli_baseline;
+
+#[path = "specs/cli/loc.rs"]
+mod cli_loc;
+
+#[path = "specs/cli/interrupt.rs"]
+mod cli_interrupt;
+
 // config/
 #[path = "specs/config/mod.rs"]
 mod config;
@@ -84,10 +147,37 @@ mod checks_build;
 #[path = "specs/checks/license.rs"]
 mod checks_license;
 
+#[path = "specs/checks/release.rs"]
+mod checks_release;
+
+#[path = "specs/checks/whitespace.rs"]
+mod checks_whitespace;
+
+#[path = "specs/checks/format.rs"]
+mod checks_format;
+
+#[path = "specs/checks/lint.rs"]
+mod checks_lint;
+
+#[path = "specs/checks/snapshots.rs"]
+mod checks_snapshots;
+
+#[path = "specs/checks/deadcode.rs"]
+mod checks_deadcode;
+
+#[path = "specs/checks/deps.rs"]
+mod checks_deps;
+
 // output/
 #[path = "specs/output/format.rs"]
 mod output_format;
 
+#[path = "specs/output/determinism.rs"]
+mod output_determinism;
+
+#[path = "specs/output/golden.rs"]
+mod output_golden;
+
 // modes/
 #[path = "specs/modes/cache.rs"]
 mod modes_cache;
@@ -98,9 +188,21 @@ mod modes_file_walking;
 #[path = "specs/modes/ratchet.rs"]
 mod modes_ratchet;
 
+#[path = "specs/modes/ratchet_metrics.rs"]
+mod modes_ratchet_metrics;
+
+#[path = "specs/modes/ratchet_refresh.rs"]
+mod modes_ratchet_refresh;
+
 #[path = "specs/modes/large_files.rs"]
 mod modes_large_files;
 
+#[path = "specs/modes/sparse.rs"]
+mod modes_sparse;
+
+#[path = "specs/modes/staged.rs"]
+mod modes_staged;
+
 // adapters/
 #[path = "specs/adapters/mod.rs"]
 mod adapters;
@@ -110,3 +110,52 @@ fn agents_size_violation_includes_threshold() {
     assert!(v.get("value").is_some(), "should have value field");
     assert!(v.get("threshold").is_some(), "should have threshold field");
 }
+
+/// Spec: docs/specs/checks/agents.md#context-budget
+///
+/// > Exceeding it generates a `context_too_large` violation naming the largest
+/// > files.
+#[test]
+fn agents_combined_tokens_over_budget_generates_violation() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.agents]
+sync = false
+sections.required = []
+max_total_tokens = 150
+"#,
+    );
+    temp.file("CLAUDE.md", &format!("# Project\n\n{}\n", "a".repeat(400)));
+    temp.file("AGENTS.md", &format!("# Project\n\n{}\n", "b".repeat(200)));
+
+    let agents = check("agents").pwd(temp.path()).json().fails();
+    let v = agents.require_violation("context_too_large");
+
+    assert_eq!(v.get("threshold").and_then(|t| t.as_i64()), Some(150));
+    let advice = v.get("advice").and_then(|a| a.as_str()).unwrap();
+    assert!(advice.contains("Largest: CLAUDE.md (~103), AGENTS.md (~53)"));
+}
+
+/// Spec: docs/specs/checks/agents.md#context-budget
+///
+/// > The total is reported as the `total_tokens` metric with a per-file
+/// > `tokens` breakdown
+#[test]
+fn agents_reports_total_tokens_with_breakdown() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.agents]
+sync = false
+sections.required = []
+"#,
+    );
+    temp.file("CLAUDE.md", &format!("# Project\n\n{}\n", "a".repeat(400)));
+    temp.file("AGENTS.md", &format!("# Project\n\n{}\n", "b".repeat(200)));
+
+    let agents = check("agents").pwd(temp.path()).json().passes();
+    let metrics = agents.require("metrics");
+
+    assert_eq!(metrics["total_tokens"], 156);
+    assert_eq!(metrics["tokens"]["CLAUDE.md"], 103);
+    assert_eq!(metrics["tokens"]["AGENTS.md"], 53);
+}
@@ -424,3 +424,138 @@ fn cursor_parse_error_json_format() {
             .contains("unterminated frontmatter")
     );
 }
+
+// =============================================================================
+// FRONTMATTER VALIDATION
+// =============================================================================
+
+const FRONTMATTER_CONFIG: &str = r#"[check.agents]
+required = []
+sync = false
+sections.required = []
+max_lines = false
+max_tokens = false
+"#;
+
+/// Spec: docs/specs/checks/agents.cursor.md#frontmatter-validation
+///
+/// > Each key in `frontmatter.required` must appear in the frontmatter
+#[test]
+fn frontmatter_missing_required_key_fails() {
+    let temp = Project::empty();
+    temp.config(&format!(
+        "{}frontmatter.required = [\"description\"]\n",
+        FRONTMATTER_CONFIG
+    ));
+    temp.file("src/api/users.ts", "export {};\n");
+    temp.file(
+        ".cursor/rules/api.mdc",
+        "---\nglobs: \"src/api/**\"\n---\n\n## API\n\nUse REST.\n",
+    );
+
+    check("agents").pwd(temp.path()).exits(1).stdout_eq(
+        "agents: FAIL
+  .cursor/rules/api.mdc: cursor_missing_metadata
+    Frontmatter is missing required key `description`. Add it between the --- delimiters.
+FAIL: agents
+",
+    );
+}
+
+/// Spec: docs/specs/checks/agents.cursor.md#frontmatter-validation
+///
+/// > `alwaysApply` must be `true` or `false`
+#[test]
+fn frontmatter_non_boolean_always_apply_fails() {
+    let temp = Project::empty();
+    temp.config(FRONTMATTER_CONFIG);
+    temp.file(
+        ".cursor/rules/general.mdc",
+        "---\nalwaysApply: yes\n---\n\n## Style\n\nSpaces.\n",
+    );
+
+    let result = check("agents").pwd(temp.path()).json().fails();
+    let violation = result.require_violation("cursor_invalid_metadata");
+    assert_eq!(violation.get("line").and_then(|l| l.as_u64()), Some(2));
+}
+
+/// Spec: docs/specs/checks/agents.cursor.md#frontmatter-validation
+///
+/// > each pattern must compile, and must match at least one project file
+#[test]
+fn frontmatter_invalid_and_unmatched_globs_fail() {
+    let temp = Project::empty();
+    temp.config(FRONTMATTER_CONFIG);
+    temp.file("src/api/users.ts", "export {};\n");
+    temp.file(
+        ".cursor/rules/api.mdc",
+        "---\nglobs: [\"src/[api\", \"web/**\"]\n---\n\n## API\n\nUse REST.\n",
+    );
+
+    let result = check("agents").pwd(temp.path()).json().fails();
+    assert!(result.has_violation("cursor_invalid_glob"));
+    let violation = result.require_violation("cursor_unmatched_glob");
+    assert_eq!(
+        violation.get("pattern").and_then(|p| p.as_str()),
+        Some("web/**")
+    );
+}
+
+/// Spec: docs/specs/checks/agents.cursor.md#frontmatter-validation
+///
+/// > unmatched_globs = "allow" accepts globs that match nothing
+#[test]
+fn frontmatter_unmatched_globs_allowed_by_config() {
+    let temp = Project::empty();
+    temp.config(&format!(
+        "{}frontmatter.unmatched_globs = \"allow\"\n",
+        FRONTMATTER_CONFIG
+    ));
+    temp.file(
+        ".cursor/rules/web.mdc",
+        "---\nglobs: \"web/**\"\n---\n\n## Web\n\nUse React.\n",
+    );
+
+    check("agents").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/agents.cursor.md#frontmatter-validation
+///
+/// > two rules that apply to the same file must not define the same
+/// > section with different content
+#[test]
+fn frontmatter_overlapping_rules_conflict() {
+    let temp = Project::empty();
+    temp.config(FRONTMATTER_CONFIG);
+    temp.file("src/api/Form.tsx", "export {};\n");
+    temp.file(
+        ".cursor/rules/api.mdc",
+        "---\nglobs: \"src/api/**\"\n---\n\n## Components\n\nUse classes.\n",
+    );
+    temp.file(
+        ".cursor/rules/tsx.mdc",
+        "---\nglobs: \"**/*.tsx\"\n---\n\n## Components\n\nUse functions.\n",
+    );
+
+    let result = check("agents").pwd(temp.path()).json().fails();
+    let violation = result.require_violation("cursor_rule_conflict");
+    assert_eq!(
+        violation.get("file").and_then(|f| f.as_str()),
+        Some(".cursor/rules/tsx.mdc")
+    );
+    assert_eq!(
+        violation.get("other_file").and_then(|f| f.as_str()),
+        Some(".cursor/rules/api.mdc")
+    );
+    assert_eq!(
+        violation.get("section").and_then(|s| s.as_str()),
+        Some("Components")
+    );
+    assert!(
+        violation
+            .get("advice")
+            .and_then(|a| a.as_str())
+            .unwrap()
+            .contains("src/api/Form.tsx")
+    );
+}
@@ -181,3 +181,67 @@ fn agents_forbidden_section_glob_matches() {
 
     assert!(matches_test, "should match Test* glob pattern");
 }
+
+// =============================================================================
+// OTHER AGENT ECOSYSTEMS
+// =============================================================================
+
+/// Spec: docs/specs/checks/agents.md#agent-files
+///
+/// > GEMINI.md, .windsurfrules, and .github/copilot-instructions.md are
+/// > recognized at the project root.
+#[test]
+fn agents_detects_other_agent_files_at_project_root() {
+    let content =
+        "# Project\n\n## Directory Structure\n\nLayout.\n\n## Landing the Plane\n\n- Done\n";
+    let temp = Project::empty();
+    temp.config("[check.agents]\nmax_tokens = false\n");
+    temp.file("GEMINI.md", content);
+    temp.file(".windsurfrules", content);
+    temp.file(".github/copilot-instructions.md", content);
+
+    let agents = check("agents").pwd(temp.path()).json().passes();
+    let metrics = agents.require("metrics");
+    let files_found = metrics.get("files_found").unwrap().as_array().unwrap();
+    for file in [
+        "GEMINI.md",
+        ".windsurfrules",
+        ".github/copilot-instructions.md",
+    ] {
+        assert!(
+            files_found.iter().any(|f| f.as_str() == Some(file)),
+            "should detect {}",
+            file
+        );
+    }
+}
+
+/// Spec: docs/specs/checks/agents.md#sync-behavior
+///
+/// > .github/copilot-instructions.md is synced with the root agent files.
+#[test]
+fn agents_syncs_copilot_instructions_with_root_files() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.agents]
+files = ["AGENTS.md", ".github/copilot-instructions.md"]
+sections.required = []
+"#,
+    );
+    temp.file("AGENTS.md", "# Project\n\n## Testing\n\nRun the tests.\n");
+    temp.file(
+        ".github/copilot-instructions.md",
+        "# Project\n\n## Testing\n\nSkip the tests.\n",
+    );
+
+    let agents = check("agents").pwd(temp.path()).json().fails();
+    let violation = agents.require_violation("out_of_sync");
+    assert_eq!(
+        violation.get("file").and_then(|f| f.as_str()),
+        Some(".github/copilot-instructions.md")
+    );
+    assert_eq!(
+        violation.get("other_file").and_then(|f| f.as_str()),
+        Some("AGENTS.md")
+    );
+}
@@ -19,3 +19,4 @@ mod defaults;
 mod detection;
 mod edge_cases;
 mod output;
+mod rules;
@@ -260,16 +260,16 @@ FAIL: agents
 fn exact_out_of_sync_text() {
     check("agents").on("agents/out-of-sync").fails().stdout_eq(
         r###"agents: FAIL
+  .cursorrules: missing required section
+    In the root .cursorrules, add a "## Directory Structure" section: Overview of project layout and key directories
+  .cursorrules: missing required section
+    In the root .cursorrules, add a "## Landing the Plane" section: Checklist for AI agents before completing work
   .cursorrules: out of sync with CLAUDE.md
     Code Style differs. Use --fix to sync from CLAUDE.md, or reconcile manually.
   CLAUDE.md: missing required section
     In the root CLAUDE.md, add a "## Directory Structure" section: Overview of project layout and key directories
   CLAUDE.md: missing required section
     In the root CLAUDE.md, add a "## Landing the Plane" section: Checklist for AI agents before completing work
-  .cursorrules: missing required section
-    In the root .cursorrules, add a "## Directory Structure" section: Overview of project layout and key directories
-  .cursorrules: missing required section
-    In the root .cursorrules, add a "## Landing the Plane" section: Checklist for AI agents before completing work
 FAIL: agents
 "###,
     );
@@ -320,12 +320,12 @@ fn exact_oversized_lines_text() {
         .fails()
         .stdout_eq(
             r###"agents: FAIL
+  CLAUDE.md: file too large (tokens: 59 vs 50)
+    In the root CLAUDE.md, file has 59 lines (max: 50). Split into smaller files or reduce content.
   CLAUDE.md: missing required section
     In the root CLAUDE.md, add a "## Directory Structure" section: Overview of project layout and key directories
   CLAUDE.md: missing required section
     In the root CLAUDE.md, add a "## Landing the Plane" section: Checklist for AI agents before completing work
-  CLAUDE.md: file too large (tokens: 59 vs 50)
-    In the root CLAUDE.md, file has 59 lines (max: 50). Split into smaller files or reduce content.
 FAIL: agents
 "###,
         );
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the cursor rules scope.
+//!
+//! Tests that files under `.cursor/rules/` are checked as rules in
+//! their own right rather than as folder-level agent files.
+//!
+//! Reference: docs/specs/checks/agents.md#rules-cursor-rules-directory
+
+use crate::prelude::*;
+
+/// Spec: docs/specs/checks/agents.md#rules-cursor-rules-directory
+///
+/// > A rule satisfies the `required = ["*"]` wildcard, like a root agent file
+#[test]
+fn cursor_rules_satisfy_required_wildcard() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.agents]
+sections.required = []
+"#,
+    );
+    temp.file(
+        ".cursor/rules/review.mdc",
+        "---\ndescription: Code review checklist\n---\n\n## Review\n\nCheck the tests.\n",
+    );
+
+    check("agents").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/agents.md#rules-cursor-rules-directory
+///
+/// > Forbidden sections and content rules apply to each rule; required
+/// > sections don't
+#[test]
+fn cursor_rules_checked_for_forbidden_sections_only() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.agents]
+required = []
+sync = false
+sections.required = ["Directory Structure"]
+sections.forbid = ["Secrets"]
+"#,
+    );
+    temp.file(
+        ".cursor/rules/api.mdc",
+        "---\ndescription: API\n---\n\n## Secrets\n\nAsk the team.\n",
+    );
+
+    check("agents")
+        .pwd(temp.path())
+        .exits(1)
+        .stdout_eq(
+            r#"agents: FAIL
+  .cursor/rules/api.mdc:5: forbidden section found
+    In the cursor rule api.mdc, remove or rename the "Secrets" section (matches forbidden pattern "Secrets")
+FAIL: agents
+"#,
+        );
+}
+
+/// Spec: docs/specs/checks/agents.md#size-limits
+///
+/// > Size limits come from `[check.agents.rules]`, falling back to the
+/// > flat limits
+#[test]
+fn cursor_rules_use_rules_size_limits() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.agents]
+required = []
+sync = false
+sections.required = []
+
+[check.agents.rules]
+max_lines = 3
+"#,
+    );
+    temp.file("CLAUDE.md", "# Project\n\nOne.\nTwo.\nThree.\nFour.\n");
+    temp.file(
+        ".cursor/rules/api.mdc",
+        "---\ndescription: API\n---\n\nOne.\nTwo.\n",
+    );
+
+    let result = check("agents").pwd(temp.path()).json().fails();
+    let violation = result.require_violation("file_too_large");
+    assert_eq!(
+        violation.get("file").and_then(|f| f.as_str()),
+        Some(".cursor/rules/api.mdc")
+    );
+    assert_eq!(result.violations().len(), 1);
+}
@@ -94,6 +94,112 @@ fn cloc_calculates_source_to_test_ratio() {
     );
 }
 
+/// Spec: docs/specs/checks/cloc.md#language-specific-go-python-javascript
+///
+/// > Inline tests in other languages are split the same way
+#[test]
+fn cloc_splits_inline_go_test_functions() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("go.mod", "module example.com/m\n\ngo 1.22\n");
+    temp.file(
+        "math.go",
+        r#"package math
+
+func Add(a, b int) int {
+	return a + b
+}
+
+func TestAdd(t *testing.T) {
+	if Add(1, 2) != 3 {
+		t.Fatal("bad")
+	}
+}
+"#,
+    );
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    let metrics = cloc.require("metrics");
+    assert_eq!(
+        metrics.get("source_lines").and_then(|v| v.as_u64()),
+        Some(4)
+    );
+    assert_eq!(metrics.get("test_lines").and_then(|v| v.as_u64()), Some(5));
+}
+
+/// Spec: docs/specs/checks/cloc.md#language-specific-go-python-javascript
+///
+/// > Python: Doctest examples; `if __name__ == "__main__":` blocks that run tests
+#[test]
+fn cloc_splits_python_doctests_and_test_main_block() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("pyproject.toml", "[project]\nname = \"calc\"\n");
+    temp.file(
+        "calc.py",
+        r#"def add(a, b):
+    """Add two numbers.
+
+    >>> add(1, 2)
+    3
+    """
+    return a + b
+
+
+if __name__ == "__main__":
+    import doctest
+    doctest.testmod()
+"#,
+    );
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    let metrics = cloc.require("metrics");
+    assert_eq!(
+        metrics.get("source_lines").and_then(|v| v.as_u64()),
+        Some(4)
+    );
+    assert_eq!(metrics.get("test_lines").and_then(|v| v.as_u64()), Some(5));
+}
+
+/// Spec: docs/specs/checks/cloc.md#language-specific-go-python-javascript
+///
+/// > A source file with more test lines than source lines is held to
+/// > `max_lines_test`.
+#[test]
+fn cloc_holds_mostly_vitest_file_to_test_limit() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.cloc]
+max_lines = 5
+max_lines_test = 20
+"#,
+    );
+    temp.file("package.json", r#"{"name": "app"}"#);
+    temp.file(
+        "src/math.ts",
+        r#"export const add = (a: number, b: number) => a + b;
+
+if (import.meta.vitest) {
+  const { it, expect } = import.meta.vitest;
+  it('adds', () => {
+    expect(add(1, 2)).toBe(3);
+  });
+  it('adds negatives', () => {
+    expect(add(-1, -2)).toBe(-3);
+  });
+}
+"#,
+    );
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    let metrics = cloc.require("metrics");
+    assert_eq!(
+        metrics.get("source_lines").and_then(|v| v.as_u64()),
+        Some(1)
+    );
+    assert_eq!(metrics.get("test_lines").and_then(|v| v.as_u64()), Some(9));
+}
+
 // =============================================================================
 // JSON OUTPUT SPECS
 // =============================================================================
@@ -506,3 +612,189 @@ metric = "nonblank"
         "metric=nonblank should use nonblank for value"
     );
 }
+
+// =============================================================================
+// HOTSPOTS
+// =============================================================================
+
+/// Git project with `src/lib.rs` committed three times at 8 lines.
+fn hotspot_project(check: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!(
+        "[hotspots]\nchurn = 3\nlines = 5\ncheck = \"{check}\"\n"
+    ));
+    git_init(&temp);
+    for n in 1..=3 {
+        let body: String = (0..5 + n).map(|i| format!("fn f{i}() {{}}\n")).collect();
+        temp.file("src/lib.rs", &body);
+        git_add_all(&temp);
+        git_commit(&temp, &format!("feat: lib {n}"));
+    }
+    temp
+}
+
+/// Spec: docs/specs/checks/cloc.md#hotspots
+///
+/// > With `[hotspots] check = "warn"` (off by default), the cloc check flags source files
+#[test]
+fn cloc_warns_on_hotspot() {
+    let temp = hotspot_project("warn");
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    let v = cloc.require_violation("hotspot");
+    assert_eq!(v.get("file").and_then(|f| f.as_str()), Some("src/lib.rs"));
+    assert_eq!(v.get("value").and_then(|v| v.as_i64()), Some(3));
+
+    check("cloc")
+        .pwd(temp.path())
+        .passes()
+        .stdout_has("src/lib.rs: hotspot (3 commits, 8 lines)");
+}
+
+/// Spec: docs/specs/checks/cloc.md#hotspots
+///
+/// > (off by default)
+#[test]
+fn cloc_hotspots_off_by_default() {
+    let temp = hotspot_project("off");
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    assert!(cloc.violations().is_empty());
+}
+
+/// Spec: docs/specs/checks/cloc.md#hotspots
+///
+/// > `check = "error"` fails the check instead
+#[test]
+fn cloc_hotspot_error_fails() {
+    let temp = hotspot_project("error");
+
+    check("cloc")
+        .pwd(temp.path())
+        .json()
+        .fails()
+        .require_violation("hotspot");
+}
+
+// =============================================================================
+// SAMPLING
+// =============================================================================
+
+/// A project with twenty files over a one-line limit, sampled at half.
+fn sampled_project() -> Project {
+    let temp = Project::empty();
+    temp.config("[check.cloc]\nmax_lines = 1\nsample = \"50%\"\n");
+    for i in 0..20 {
+        temp.file(format!("src/file_{i}.rs"), "fn a() {}\nfn b() {}\n");
+    }
+    temp
+}
+
+/// Spec: docs/specs/21-sampling.md
+///
+/// > The sample is seeded by the HEAD commit: reruns at one commit check the
+/// > same files
+#[test]
+fn cloc_sample_checks_a_share_of_files() {
+    let temp = sampled_project();
+
+    let first = check("cloc").pwd(temp.path()).json().fails();
+    let count = first.violations().len();
+    assert!((1..20).contains(&count), "{count}");
+    assert!(first.get("metrics").is_none());
+
+    let second = check("cloc").pwd(temp.path()).json().fails();
+    assert_eq!(second.violations().len(), count);
+}
+
+/// Spec: docs/specs/21-sampling.md
+///
+/// > `--ci`, `--base`, and `--staged` check every file
+#[test]
+fn cloc_sample_ci_checks_every_file() {
+    let temp = sampled_project();
+
+    let cloc = check("cloc")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .json()
+        .fails();
+    assert_eq!(cloc.violations().len(), 20);
+    assert!(cloc.get("metrics").is_some());
+}
+
+// =============================================================================
+// TEST RATIO SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/cloc.md#test-ratio
+///
+/// > `min_test_ratio` fails when test LOC / source LOC is below the minimum
+#[test]
+fn cloc_fails_when_test_ratio_below_minimum() {
+    let temp = Project::empty();
+    temp.config("[check.cloc]\nmin_test_ratio = 0.5\n");
+    temp.file("src/lib.rs", "fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\n");
+    temp.file("tests/lib_test.rs", "fn t() {}\n");
+
+    let cloc = check("cloc").pwd(temp.path()).json().fails();
+    let v = cloc.require_violation("test_ratio_too_low");
+    assert!(v.get("file").is_none());
+    assert!(v.get("target").is_none());
+    assert!(
+        v["advice"].as_str().unwrap().starts_with("0.25 test lines"),
+        "{v}"
+    );
+}
+
+/// Spec: docs/specs/checks/cloc.md#test-ratio
+///
+/// > A project that meets the minimum passes
+#[test]
+fn cloc_passes_when_test_ratio_meets_minimum() {
+    let temp = Project::empty();
+    temp.config("[check.cloc]\nmin_test_ratio = 0.5\n");
+    temp.file("src/lib.rs", "fn a() {}\nfn b() {}\n");
+    temp.file("tests/lib_test.rs", "fn t() {}\n");
+
+    check("cloc").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/cloc.md#test-ratio
+///
+/// > With packages configured, each package is held to its own minimum
+#[test]
+fn cloc_test_ratio_minimum_per_package() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[project]
+packages = ["crates/cli", "crates/core"]
+
+[check.cloc]
+min_test_ratio = 0.5
+
+[check.cloc.package."crates/cli"]
+min_test_ratio = 0.0
+"#,
+    );
+    temp.file("crates/cli/src/main.rs", "fn main() {}\n");
+    temp.file("crates/core/src/lib.rs", "fn a() {}\n");
+
+    let cloc = check("cloc").pwd(temp.path()).json().fails();
+    let violations = cloc.violations_of_type("test_ratio_too_low");
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0]["target"], "crates/core");
+}
+
+/// Spec: docs/specs/checks/cloc.md#test-ratio
+///
+/// > `check = "warn"` reports a low ratio without failing
+#[test]
+fn cloc_test_ratio_warn_level_passes() {
+    let temp = Project::empty();
+    temp.config("[check.cloc]\ncheck = \"warn\"\nmin_test_ratio = 0.5\n");
+    temp.file("src/lib.rs", "fn a() {}\n");
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    assert!(cloc.has_violation("test_ratio_too_low"));
+}
@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the deadcode check.
+//!
+//! Tests that quench correctly:
+//! - Stays off unless configured
+//! - Reports unused Rust, JS/TS, and Go exports
+//! - Treats test files as references, not definitions
+//! - Ratchets unused export counts per language
+//!
+//! Reference: docs/specs/checks/deadcode.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::fs;
+
+use crate::prelude::*;
+
+fn deadcode_project(config: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!("[check.deadcode]\ncheck = \"error\"\n{}", config));
+    temp
+}
+
+// =============================================================================
+// DEFAULT BEHAVIOR SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/deadcode.md#purpose
+///
+/// > **Disabled by default.**
+#[test]
+fn deadcode_disabled_by_default() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("src/lib.rs", "pub fn unused() {}\n");
+
+    let deadcode = check("deadcode").pwd(temp.path()).json().passes();
+
+    assert!(deadcode.violations().is_empty());
+}
+
+// =============================================================================
+// SCOPE SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/deadcode.md#scope
+///
+/// > The name appears nowhere but its definition
+#[test]
+fn unused_rust_pub_item_generates_violation() {
+    let temp = deadcode_project("");
+    temp.file(
+        "src/lib.rs",
+        "pub fn used() {}\npub fn unused() {}\n\nfn call() {\n    used();\n}\n",
+    );
+
+    check("deadcode")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("src/lib.rs:2: unused export: unused");
+}
+
+/// Spec: docs/specs/checks/deadcode.md#scope
+///
+/// > No other file mentions the name
+#[test]
+fn js_export_used_only_in_its_own_file_generates_violation() {
+    let temp = deadcode_project("");
+    temp.file(
+        "src/format.ts",
+        "export function formatBytes(n: number) { return `${n}`; }\nexport const label = formatBytes(1);\n",
+    );
+    temp.file(
+        "src/index.ts",
+        "import { label } from './format';\nconsole.log(label);\n",
+    );
+
+    let deadcode = check("deadcode").pwd(temp.path()).json().fails();
+
+    let violation = deadcode.require_violation("dead_code");
+    assert_eq!(
+        violation.get("target").and_then(|v| v.as_str()),
+        Some("formatBytes")
+    );
+    assert_eq!(deadcode.violations().len(), 1);
+}
+
+/// Spec: docs/specs/checks/deadcode.md#scope
+///
+/// > No file outside the package directory mentions the name
+#[test]
+fn go_identifier_unused_outside_package_generates_violation() {
+    let temp = deadcode_project("");
+    temp.file(
+        "pkg/store/cache.go",
+        "package store\n\nfunc Get() {}\n\nfunc Evict() {}\n\nfunc init() { Evict() }\n",
+    );
+    temp.file(
+        "cmd/main.go",
+        "package main\n\nimport \"example.com/pkg/store\"\n\nfunc main() { store.Get() }\n",
+    );
+
+    let deadcode = check("deadcode").pwd(temp.path()).json().fails();
+
+    let violation = deadcode.require_violation("dead_code");
+    assert_eq!(
+        violation.get("file").and_then(|v| v.as_str()),
+        Some("pkg/store/cache.go")
+    );
+    assert_eq!(
+        violation.get("target").and_then(|v| v.as_str()),
+        Some("Evict")
+    );
+    assert_eq!(deadcode.violations().len(), 1);
+}
+
+/// Spec: docs/specs/checks/deadcode.md#scope
+///
+/// > Test files don't define exports but do count as references.
+#[test]
+fn test_files_count_as_references_only() {
+    let temp = deadcode_project("");
+    temp.file("src/lib.rs", "pub fn helper() {}\n");
+    temp.file(
+        "tests/basic.rs",
+        "pub fn fixture() {}\n\n#[test]\nfn works() { app::helper(); }\n",
+    );
+
+    check("deadcode").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/deadcode.md#configuration
+///
+/// > exclude = ["src/bin/**"]     # Files whose exports are never reported
+#[test]
+fn excluded_files_are_not_reported() {
+    let temp = deadcode_project("exclude = [\"src/bin/**\"]\n");
+    temp.file("src/bin/tool.rs", "pub fn entry() {}\n");
+
+    check("deadcode").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/deadcode.md#configuration
+///
+/// > check = "error"              # error | warn | off (default: off)
+#[test]
+fn warn_level_reports_without_failing() {
+    let temp = Project::empty();
+    temp.config("[check.deadcode]\ncheck = \"warn\"\n");
+    temp.file("src/lib.rs", "pub fn unused() {}\n");
+
+    let deadcode = check("deadcode").pwd(temp.path()).json().passes();
+
+    assert!(deadcode.has_violation("dead_code"));
+}
+
+/// Spec: docs/specs/checks/deadcode.md#scope
+///
+/// > References can live in any file, so the check skips the per-file cache
+/// > and scans every file on each run.
+#[test]
+fn cached_run_counts_references_in_unchanged_files() {
+    let temp = deadcode_project("");
+    temp.file("src/lib.rs", "pub fn helper() {}\n");
+    temp.file("src/main.rs", "fn main() {\n    helper();\n}\n");
+
+    quench_cmd()
+        .args(["check", "--deadcode"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    // Only lib.rs changes; main.rs, which uses helper, stays cached
+    temp.file("src/lib.rs", "pub fn helper() {}\n\nfn local() {}\n");
+
+    quench_cmd()
+        .args(["check", "--deadcode"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+}
+
+// =============================================================================
+// RATCHET SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/deadcode.md#ratchet
+///
+/// > Counts per language are recorded in the baseline and compared by the ratchet
+#[test]
+fn new_unused_export_fails_ratchet() {
+    let temp = Project::empty();
+    temp.config(
+        "[git]\nbaseline = \".quench/baseline.json\"\n\n[ratchet]\ncheck = \"error\"\n\n[check.deadcode]\ncheck = \"warn\"\n",
+    );
+    fs::create_dir_all(temp.path().join(".quench")).unwrap();
+    fs::write(
+        temp.path().join(".quench/baseline.json"),
+        r#"{
+  "version": 1,
+  "updated": "2026-01-20T00:00:00Z",
+  "metrics": {
+    "deadcode": { "rust": 1 }
+  }
+}"#,
+    )
+    .unwrap();
+    temp.file("src/lib.rs", "pub fn old() {}\npub fn new() {}\n");
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--deadcode"])
+        .fails()
+        .stdout_has("deadcode.rust: 2 (max: 1 from baseline)");
+}
@@ -0,0 +1,458 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the deps check.
+//!
+//! Tests that quench correctly:
+//! - Stays off unless configured
+//! - Reports wildcard versions and unpinned git dependencies
+//! - Reports packages resolved at several versions
+//! - Enforces max_dependencies per manifest
+//! - Measures feature-gated Rust code and reports unused and undeclared features
+//! - Ratchets resolved dependency counts per ecosystem
+//!
+//! Reference: docs/specs/checks/deps.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::fs;
+
+use crate::prelude::*;
+
+fn deps_project(config: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!("[check.deps]\ncheck = \"error\"\n{}", config));
+    temp
+}
+
+const CARGO_LOCK_DUPLICATE: &str = r#"version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+
+[[package]]
+name = "syn"
+version = "1.0.109"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "syn"
+version = "2.0.48"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#;
+
+// =============================================================================
+// DEFAULT BEHAVIOR SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/deps.md#purpose
+///
+/// > **Disabled by default.**
+#[test]
+fn deps_disabled_by_default() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\n\n[dependencies]\nserde = \"*\"\n",
+    );
+
+    let deps = check("deps").pwd(temp.path()).json().passes();
+
+    assert!(deps.violations().is_empty());
+}
+
+// =============================================================================
+// RULE SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/deps.md#rules
+///
+/// > A registry dependency accepts any version
+#[test]
+fn cargo_wildcard_version_generates_violation() {
+    let temp = deps_project("");
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\n\n[dependencies]\nanyhow = \"1\"\nserde = \"*\"\n",
+    );
+
+    check("deps")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("Cargo.toml:6: wildcard version: serde");
+}
+
+/// Spec: docs/specs/checks/deps.md#rules
+///
+/// > A git dependency has no commit: Cargo without `rev`, npm without `#<sha>`
+#[test]
+fn unpinned_git_dependencies_generate_violations() {
+    let temp = deps_project("");
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\n\n[dependencies]\npinned = { git = \"https://example.com/pinned\", rev = \"0a1b2c3\" }\nfloating = { git = \"https://example.com/floating\", branch = \"main\" }\n",
+    );
+    temp.file(
+        "web/package.json",
+        r#"{
+  "name": "web",
+  "dependencies": {
+    "widgets": "github:acme/widgets#main",
+    "gadgets": "github:acme/gadgets#0a1b2c3d"
+  }
+}
+"#,
+    );
+
+    let deps = check("deps").pwd(temp.path()).json().fails();
+
+    let targets: Vec<&str> = deps
+        .violations()
+        .iter()
+        .filter(|v| v.get("type").and_then(|t| t.as_str()) == Some("unpinned_git"))
+        .filter_map(|v| v.get("target").and_then(|t| t.as_str()))
+        .collect();
+    assert_eq!(targets, vec!["floating", "widgets"]);
+}
+
+/// Spec: docs/specs/checks/deps.md#rules
+///
+/// > A lockfile resolves a package at more than one version
+#[test]
+fn package_resolved_at_two_versions_generates_violation() {
+    let temp = deps_project("");
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\n\n[dependencies]\nsyn = \"2\"\n",
+    );
+    temp.file("Cargo.lock", CARGO_LOCK_DUPLICATE);
+
+    check("deps")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("Cargo.lock:8: duplicate dependency: syn (1.0.109, 2.0.48)");
+}
+
+/// Spec: docs/specs/checks/deps.md#configuration
+///
+/// > allow_duplicates = ["syn"]      # Packages allowed at several versions
+#[test]
+fn allowed_duplicates_are_not_reported() {
+    let temp = deps_project("allow_duplicates = [\"syn\"]\n");
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\n\n[dependencies]\nsyn = \"2\"\n",
+    );
+    temp.file("Cargo.lock", CARGO_LOCK_DUPLICATE);
+
+    check("deps").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/deps.md#rules
+///
+/// > for Go, the `go.mod` files of the workspace require different versions
+#[test]
+fn go_modules_requiring_different_versions_generate_violation() {
+    let temp = deps_project("");
+    temp.file(
+        "go.mod",
+        "module example.com/app\n\ngo 1.22\n\nrequire github.com/pkg/errors v0.9.1\n",
+    );
+    temp.file(
+        "tools/go.mod",
+        "module example.com/tools\n\ngo 1.22\n\nrequire (\n\tgithub.com/pkg/errors v0.8.0\n)\n",
+    );
+
+    let deps = check("deps").pwd(temp.path()).json().fails();
+
+    let violation = deps.require_violation("duplicate_dependency");
+    assert_eq!(
+        violation.get("target").and_then(|v| v.as_str()),
+        Some("github.com/pkg/errors")
+    );
+    assert_eq!(
+        violation.get("found").and_then(|v| v.as_str()),
+        Some("v0.8.0, v0.9.1")
+    );
+}
+
+/// Spec: docs/specs/checks/deps.md#rules
+///
+/// > A manifest declares more external dependencies than `max_dependencies`
+#[test]
+fn manifest_over_max_dependencies_generates_violation() {
+    let temp = deps_project("max_dependencies = 1\n");
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\n\n[dependencies]\nanyhow = \"1\"\ncore = { path = \"crates/core\" }\n\n[dev-dependencies]\ntempfile = \"3\"\n",
+    );
+
+    check("deps")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("Cargo.toml: too many dependencies: 2 (max: 1)");
+}
+
+/// Spec: docs/specs/checks/deps.md#configuration
+///
+/// > wildcards = true                # Flag any-version requirements (default: true)
+#[test]
+fn disabled_rule_is_not_reported() {
+    let temp = deps_project("wildcards = false\n");
+    temp.file(
+        "package.json",
+        r#"{ "name": "web", "dependencies": { "left-pad": "latest" } }"#,
+    );
+
+    check("deps").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/deps.md#configuration
+///
+/// > check = "error"                 # error | warn | off (default: off)
+#[test]
+fn warn_level_reports_without_failing() {
+    let temp = Project::empty();
+    temp.config("[check.deps]\ncheck = \"warn\"\n");
+    temp.file(
+        "package.json",
+        r#"{ "name": "web", "dependencies": { "left-pad": "*" } }"#,
+    );
+
+    let deps = check("deps").pwd(temp.path()).json().passes();
+
+    assert!(deps.has_violation("wildcard_version"));
+}
+
+// =============================================================================
+// FEATURE GATE SPECS
+// =============================================================================
+
+const FEATURES_MANIFEST: &str = r#"[package]
+name = "app"
+version = "0.1.0"
+
+[features]
+default = ["std"]
+std = []
+simd = []
+
+[dependencies]
+rayon = { version = "1", optional = true }
+"#;
+
+const FEATURES_LIB: &str = r#"pub fn always() {}
+
+#[cfg(feature = "std")]
+pub fn with_std() {
+    todo!()
+}
+
+#[cfg(feature = "rayon")]
+mod parallel;
+
+pub fn name() -> &'static str {
+    "feature = \"simd\""
+}
+"#;
+
+/// Spec: docs/specs/checks/deps.md#feature-gates
+///
+/// > Each Cargo package's `[features]` are matched against the Rust sources
+/// > under its directory
+#[test]
+fn deps_json_reports_feature_gated_code_per_package() {
+    let temp = deps_project("");
+    temp.file("Cargo.toml", FEATURES_MANIFEST);
+    temp.file("src/lib.rs", FEATURES_LIB);
+    temp.file(
+        "src/parallel.rs",
+        "pub fn par() {}
+
+pub fn map() {}
+",
+    );
+
+    let deps = check("deps").pwd(temp.path()).json().fails();
+    let features = &deps.require("metrics")["features"]["app"];
+
+    assert_eq!(features["declared"], 3);
+    assert_eq!(features["unused"], 1);
+    assert_eq!(features["undeclared"], 0);
+    assert_eq!(features["gated_items"], 2);
+    assert_eq!(features["gated_lines"], 8);
+    assert_eq!(features["source_lines"], 12);
+}
+
+/// Spec: docs/specs/checks/deps.md#rules
+///
+/// > A Cargo feature other than `default` enables nothing (`name = []`), and no
+/// > source in the package names it
+#[test]
+fn feature_gating_no_code_generates_violation() {
+    let temp = deps_project("");
+    temp.file("Cargo.toml", FEATURES_MANIFEST);
+    temp.file("src/lib.rs", FEATURES_LIB);
+    temp.file(
+        "src/parallel.rs",
+        "pub fn par() {}
+",
+    );
+
+    check("deps")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("Cargo.toml:8: unused feature: simd");
+}
+
+/// Spec: docs/specs/checks/deps.md#rules
+///
+/// > A `cfg`, `cfg_attr`, or `cfg!` names a feature the package neither
+/// > declares nor gets from an optional dependency
+#[test]
+fn cfg_naming_undeclared_feature_generates_violation() {
+    let temp = deps_project("");
+    temp.file(
+        "crates/core/Cargo.toml",
+        "[package]\nname = \"core\"\n\n[features]\nparallel = []\n",
+    );
+    temp.file(
+        "crates/core/src/lib.rs",
+        "#[cfg(feature = \"parallel\")]\npub fn par() {}\n\npub fn fast() -> bool {\n    cfg!(feature = \"paralel\")\n}\n",
+    );
+
+    let deps = check("deps").pwd(temp.path()).json().fails();
+
+    let violation = deps.require_violation("undeclared_feature");
+    assert_eq!(
+        violation.get("file").and_then(|v| v.as_str()),
+        Some("crates/core/src/lib.rs")
+    );
+    assert_eq!(violation.get("line").and_then(|v| v.as_u64()), Some(5));
+    assert_eq!(
+        violation.get("target").and_then(|v| v.as_str()),
+        Some("paralel")
+    );
+    assert_eq!(deps.require("metrics")["features"]["core"]["undeclared"], 1);
+}
+
+/// Spec: docs/specs/checks/deps.md#feature-gates
+///
+/// > Every Rust source is read on each run, changed or not, so a feature named
+/// > only in an unchanged file is never reported as unused.
+#[test]
+fn cached_run_keeps_features_named_in_unchanged_files() {
+    let temp = deps_project("");
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\n\n[features]\nsimd = []\n",
+    );
+    temp.file("src/lib.rs", "pub fn f() {}\n");
+    temp.file(
+        "src/fast.rs",
+        "#[cfg(feature = \"simd\")]\npub fn fast() {}\n",
+    );
+
+    quench_cmd()
+        .args(["check", "--deps"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    // Only the manifest changes; fast.rs, which names simd, stays cached
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\nversion = \"0.2.0\"\n\n[features]\nsimd = []\n",
+    );
+
+    quench_cmd()
+        .args(["check", "--deps"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+}
+
+/// Spec: docs/specs/checks/deps.md#configuration
+///
+/// > features = true                 # Flag unused and undeclared Cargo features (default: true)
+#[test]
+fn features_disabled_keeps_metrics_without_violations() {
+    let temp = deps_project("features = false\n");
+    temp.file("Cargo.toml", FEATURES_MANIFEST);
+    temp.file("src/lib.rs", "#[cfg(feature = \"missing\")]\nfn f() {}\n");
+
+    let deps = check("deps").pwd(temp.path()).json().passes();
+
+    assert_eq!(deps.require("metrics")["features"]["app"]["unused"], 2);
+    assert_eq!(deps.require("metrics")["features"]["app"]["undeclared"], 1);
+}
+
+// =============================================================================
+// RATCHET SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/deps.md#scope
+///
+/// > The check skips the per-file cache and reads them all on every run, so
+/// > counts never shrink because files were unchanged.
+#[test]
+fn cached_run_counts_unchanged_manifests() {
+    let temp = deps_project("");
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\n\n[dependencies]\nanyhow = \"1\"\nserde = \"1\"\n",
+    );
+    temp.file("src/lib.rs", "pub fn f() {}\n");
+
+    let direct = || {
+        let output = quench_cmd()
+            .args(["check", "--deps", "-o", "json"])
+            .current_dir(temp.path())
+            .output()
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        json["checks"][0]["metrics"]["direct"]["cargo"].clone()
+    };
+    assert_eq!(direct(), 2);
+
+    // Only lib.rs changes; Cargo.toml stays cached
+    temp.file("src/lib.rs", "pub fn f() {}\npub fn g() {}\n");
+
+    assert_eq!(direct(), 2);
+}
+
+/// Spec: docs/specs/checks/deps.md#ratchet
+///
+/// > Resolved package counts per ecosystem are recorded in the baseline and
+/// > compared by the ratchet
+#[test]
+fn new_dependency_fails_ratchet() {
+    let temp = Project::empty();
+    temp.config(
+        "[git]\nbaseline = \".quench/baseline.json\"\n\n[ratchet]\ncheck = \"error\"\n\n[check.deps]\ncheck = \"warn\"\n",
+    );
+    fs::create_dir_all(temp.path().join(".quench")).unwrap();
+    fs::write(
+        temp.path().join(".quench/baseline.json"),
+        r#"{
+  "version": 1,
+  "updated": "2026-01-20T00:00:00Z",
+  "metrics": {
+    "deps": { "cargo": 1 }
+  }
+}"#,
+    )
+    .unwrap();
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\n\n[dependencies]\nanyhow = \"1\"\nserde = \"1\"\n",
+    );
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--deps"])
+        .fails()
+        .stdout_has("deps.cargo: 2 (max: 1 from baseline)");
+}
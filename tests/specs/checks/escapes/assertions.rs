@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Bare assertion specs: test assertions without a failure message.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+const CARGO_TOML: &str = "[package]\nname = \"app\"\nversion = \"0.1.0\"\nedition = \"2024\"\n";
+
+/// Rust project with one committed test file, and `config` appended.
+fn assertions_project(config: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(config);
+    temp.file("Cargo.toml", CARGO_TOML);
+    temp.file("src/lib.rs", "pub fn ok() -> bool { true }\n");
+    temp.file(
+        "tests/old.rs",
+        "#[test]\nfn old() { assert!(app::ok()); }\n",
+    );
+    git_init(&temp);
+    git_initial_commit(&temp);
+    temp
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#bare-assertions
+///
+/// > Bare assertions in test code are always counted in `bare_assertions`
+#[test]
+fn bare_assertions_counted_when_rule_is_off() {
+    let temp = Project::empty();
+    temp.file("Cargo.toml", CARGO_TOML);
+    temp.file(
+        "src/lib.rs",
+        "pub fn ok() -> bool { true }\n\n#[cfg(test)]\nmod tests {\n    #[test]\n    fn t() { assert!(super::ok()); }\n}\n",
+    );
+    temp.file(
+        "tests/it.rs",
+        "#[test]\nfn t() {\n    assert!(app::ok());\n    assert!(app::ok(), \"ok should hold\");\n}\n",
+    );
+
+    let escapes = check("escapes").pwd(temp.path()).json().passes();
+
+    assert_eq!(escapes.require("metrics")["bare_assertions"], 2);
+    assert!(!escapes.has_violation("bare_assertion"));
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#bare-assertions
+///
+/// > Only test files changed on the branch are reported
+#[test]
+fn bare_assertion_in_changed_test_file_fails() {
+    let temp = assertions_project("[check.escapes.assertions]\ncheck = \"error\"\n");
+    temp.file(
+        "tests/new.rs",
+        "#[test]\nfn new() {\n    assert!(app::ok());\n}\n",
+    );
+    git_add_all(&temp);
+
+    let escapes = check("escapes")
+        .pwd(temp.path())
+        .args(&["--staged"])
+        .json()
+        .fails();
+
+    let violation = escapes.require_violation("bare_assertion");
+    assert_eq!(
+        violation.get("file").and_then(|v| v.as_str()),
+        Some("tests/new.rs")
+    );
+    assert_eq!(violation.get("line").and_then(|v| v.as_u64()), Some(3));
+    assert_eq!(
+        violation.get("pattern").and_then(|v| v.as_str()),
+        Some("assert!")
+    );
+    assert_eq!(escapes.violations().len(), 1, "tests/old.rs is unchanged");
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#bare-assertions
+///
+/// > Without `--base` or `--staged` nothing is reported
+#[test]
+fn bare_assertions_not_reported_without_changed_files() {
+    let temp = assertions_project("[check.escapes.assertions]\ncheck = \"error\"\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#bare-assertions
+///
+/// > `check = "warn"` reports them without failing
+#[test]
+fn warn_level_bare_assertions_do_not_fail() {
+    let temp = assertions_project("[check.escapes.assertions]\ncheck = \"warn\"\n");
+    temp.file(
+        "tests/old.rs",
+        "#[test]\nfn old() {\n    assert!(app::ok());\n    assert!(!app::ok() == false);\n}\n",
+    );
+    git_add_all(&temp);
+
+    check("escapes")
+        .pwd(temp.path())
+        .args(&["--staged"])
+        .passes()
+        .stdout_has("tests/old.rs:3: bare_assertion: assert!");
+}
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Density specs: escapes per 1000 source lines.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+const TODO_CONFIG: &str = r#"
+[[check.escapes.patterns]]
+name = "todo"
+pattern = "TODO"
+action = "count"
+threshold = 100
+"#;
+
+/// Ten source lines with `todos` TODO comments.
+fn source(todos: usize) -> String {
+    (0..10)
+        .map(|i| {
+            if i < todos {
+                format!("fn f{}() {{}} // TODO\n", i)
+            } else {
+                format!("fn f{}() {{}}\n", i)
+            }
+        })
+        .collect()
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#density
+///
+/// > Each source count is also reported as a **density**: occurrences per
+/// > 1000 non-blank source lines (test files and `#[cfg(test)]` blocks excluded)
+#[test]
+fn escapes_json_reports_density_per_thousand_source_lines() {
+    let temp = Project::empty();
+    temp.config(TODO_CONFIG);
+    temp.file("src/lib.rs", &source(2));
+    temp.file("tests/it.rs", "// TODO\nfn t() {}\n");
+
+    let escapes = check("escapes").pwd(temp.path()).json().passes();
+    let metrics = escapes.require("metrics");
+
+    assert_eq!(metrics["source_lines"], 10);
+    assert_eq!(metrics["density"]["todo"], 200.0);
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#density
+///
+/// > Each source count is also reported as a **density** [...] overall and
+/// > per package
+#[test]
+fn escapes_by_package_reports_density() {
+    let temp = Project::empty();
+    temp.config(&format!(
+        "[project]\npackages = [\"crates/a\", \"crates/b\"]\n{}",
+        TODO_CONFIG
+    ));
+    temp.file("crates/a/src/lib.rs", &source(1));
+    temp.file("crates/b/src/lib.rs", &source(0));
+
+    let escapes = check("escapes").pwd(temp.path()).json().passes();
+    let by_package = escapes.require("by_package");
+
+    assert_eq!(by_package["a"]["density"]["todo"], 100.0);
+    assert_eq!(by_package["b"]["density"]["todo"], 0.0);
+    assert_eq!(by_package["b"]["source_lines"], 10);
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#density
+///
+/// > A `density_exceeded` violation reports the count and the count
+/// > `max_density` allows at the current size
+#[test]
+fn escapes_max_density_exceeded_fails() {
+    let temp = Project::empty();
+    temp.config(&format!("{}max_density = 150.0\n", TODO_CONFIG));
+    temp.file("src/lib.rs", &source(2));
+
+    let escapes = check("escapes").pwd(temp.path()).json().fails();
+    let violation = escapes.require_violation("density_exceeded");
+
+    assert_eq!(violation["pattern"], "todo");
+    assert_eq!(violation["value"], 2);
+    assert_eq!(violation["threshold"], 1);
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#density
+///
+/// > Any pattern can set `max_density` to fail on density instead of (or as
+/// > well as) raw counts
+#[test]
+fn escapes_max_density_allows_counts_growing_with_code() {
+    let temp = Project::empty();
+    temp.config(&format!("{}max_density = 200.0\n", TODO_CONFIG));
+    temp.file("src/a.rs", &source(2));
+    temp.file("src/b.rs", &source(2));
+
+    check("escapes").pwd(temp.path()).json().passes();
+}
@@ -177,3 +177,58 @@ eval "$CMD"
         .fails()
         .stdout_has("missing_comment");
 }
+
+/// Spec: docs/specs/20-performance.md#3-size-gated-file-reading
+///
+/// > Any other invalid UTF-8 is decoded lossily (U+FFFD replacement
+/// > characters) rather than skipped, so the file is still checked; each such
+/// > file gets a warning on stderr and is counted in `undecodable_files`.
+#[test]
+fn escapes_checks_invalid_utf8_files_lossily() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[[check.escapes.patterns]]
+name = "unsafe"
+pattern = "unsafe\\s*\\{"
+action = "forbid"
+"#,
+    );
+    // Latin-1 "café" is not valid UTF-8
+    std::fs::create_dir_all(temp.path().join("src")).unwrap();
+    std::fs::write(
+        temp.path().join("src/lib.rs"),
+        b"// caf\xE9\nfn f() { unsafe { } }\n",
+    )
+    .unwrap();
+
+    let escapes = check("escapes").pwd(temp.path()).json().fails();
+    let violation = escapes.require_violation("forbidden");
+    assert_eq!(violation.get("line").and_then(|l| l.as_u64()), Some(2));
+    assert_eq!(escapes.value()["undecodable_files"], 1);
+
+    check("escapes")
+        .pwd(temp.path())
+        .fails()
+        .stderr_has("src/lib.rs: not valid UTF-8, decoded lossily");
+}
+
+/// Spec: docs/specs/20-performance.md#3-size-gated-file-reading
+///
+/// > A leading UTF-8 byte order mark is stripped.
+#[test]
+fn escapes_strips_utf8_bom() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[[check.escapes.patterns]]
+name = "unsafe"
+pattern = "^unsafe"
+action = "forbid"
+"#,
+    );
+    temp.file("src/lib.rs", "\u{FEFF}unsafe fn f() {}\n");
+
+    let escapes = check("escapes").pwd(temp.path()).json().fails();
+    let violation = escapes.require_violation("forbidden");
+    assert_eq!(violation.get("line").and_then(|l| l.as_u64()), Some(1));
+    assert!(escapes.value().get("undecodable_files").is_none());
+}
@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Inline test specs for Go, Python, and JavaScript.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Spec: docs/specs/langs/golang.md#escapes-in-test-code
+///
+/// > Inline test code: Test functions and test-tagged files
+#[test]
+fn go_test_function_in_source_file_is_test_code() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("go.mod", "module example.com/m\n\ngo 1.22\n");
+    temp.file(
+        "util.go",
+        r#"package util
+
+func Size() int {
+	return 8
+}
+
+func TestPointer(t *testing.T) {
+	_ = unsafe.Pointer(nil)
+}
+"#,
+    );
+
+    let escapes = check("escapes").pwd(temp.path()).json().passes();
+    let test = escapes.require("metrics").get("test").unwrap();
+    assert_eq!(test.get("unsafe_pointer").and_then(|v| v.as_u64()), Some(1));
+}
+
+/// Spec: docs/specs/langs/golang.md#escapes-in-test-code
+///
+/// > Test files: Any `*_test.go` file
+#[test]
+fn go_escape_outside_test_function_still_needs_comment() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("go.mod", "module example.com/m\n\ngo 1.22\n");
+    temp.file(
+        "util.go",
+        r#"package util
+
+func Ptr() {
+	_ = unsafe.Pointer(nil)
+}
+
+func TestSize(t *testing.T) {
+}
+"#,
+    );
+
+    check("escapes")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("util.go:4");
+}
+
+/// Spec: docs/specs/langs/javascript.md#escapes-in-test-code
+///
+/// > Inline test code: `import.meta.vitest` blocks
+#[test]
+fn vitest_block_is_test_code() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("package.json", r#"{"name": "app"}"#);
+    temp.file(
+        "src/math.ts",
+        r#"export const add = (a: number, b: number) => a + b;
+
+if (import.meta.vitest) {
+  const { it } = import.meta.vitest;
+  // @ts-ignore
+  it('adds', () => add('1', 2));
+}
+"#,
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/langs/python.md#test-code-detection
+///
+/// > cloc counts inline test lines as test LOC, and escape patterns in them
+/// > count as test code.
+#[test]
+fn python_test_main_block_is_test_code() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("pyproject.toml", "[project]\nname = \"calc\"\n");
+    temp.file(
+        "calc.py",
+        r#"def add(a, b):
+    return a + b
+
+
+if __name__ == "__main__":
+    import unittest
+    print(eval("add(1, 2)"))
+    unittest.main()
+"#,
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
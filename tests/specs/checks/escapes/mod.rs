@@ -13,10 +13,15 @@
 //! Reference: docs/specs/checks/escape-hatches.md
 
 mod actions;
+mod assertions;
+mod density;
 mod edge_cases;
+mod inline_tests;
+mod multi_language;
 mod output;
 mod suppress_other;
 mod suppress_rust;
+mod unsafe_code;
 
 /// Helper: project with exclude pattern for generated files.
 fn exclude_project() -> crate::prelude::Project {
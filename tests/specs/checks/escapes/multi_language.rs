@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Multi-language file specs: component sections and notebook cells.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Find the violation for a pattern.
+fn violation<'a>(escapes: &'a CheckJson, pattern: &str) -> &'a serde_json::Value {
+    escapes
+        .require("violations")
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|v| v.get("pattern").and_then(|p| p.as_str()) == Some(pattern))
+        .unwrap_or_else(|| panic!("no {} violation", pattern))
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#multi-language-files
+///
+/// > Vue and Svelte components are split into `<script>`, `<style>`, and
+/// > markup blocks. Each block gets its own language's patterns: a
+/// > `<script lang="ts">` section is checked with the TypeScript patterns.
+#[test]
+fn component_script_block_uses_typescript_patterns() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("package.json", r#"{"name": "app"}"#);
+    temp.file(
+        "src/App.vue",
+        r#"<template>
+  <p>{{ msg }}</p>
+</template>
+
+<script setup lang="ts">
+// @ts-ignore
+const msg = load()
+</script>
+"#,
+    );
+
+    let escapes = check("escapes").pwd(temp.path()).json().fails();
+    let v = violation(&escapes, "ts_ignore");
+
+    assert_eq!(v.get("file").and_then(|f| f.as_str()), Some("src/App.vue"));
+    assert_eq!(v.get("line").and_then(|l| l.as_u64()), Some(6));
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#multi-language-files
+///
+/// > Notebook code cells are checked with the kernel language's patterns;
+/// > markdown cells, outputs, and metadata are not.
+#[test]
+fn notebook_code_cells_use_kernel_language_patterns() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("pyproject.toml", "[project]\nname = \"nb\"\n");
+    temp.file(
+        "analysis.ipynb",
+        r##"{
+ "cells": [
+  {
+   "cell_type": "markdown",
+   "metadata": {},
+   "source": ["Never call breakpoint() in CI"]
+  },
+  {
+   "cell_type": "code",
+   "metadata": {},
+   "outputs": [],
+   "source": [
+    "x = 1\n",
+    "breakpoint()"
+   ]
+  }
+ ],
+ "metadata": {"kernelspec": {"language": "python", "name": "python3"}},
+ "nbformat": 4,
+ "nbformat_minor": 5
+}
+"##,
+    );
+
+    let escapes = check("escapes").pwd(temp.path()).json().fails();
+    let violations = escapes.require("violations").as_array().unwrap();
+    let v = violation(&escapes, "breakpoint");
+
+    assert_eq!(violations.len(), 1, "markdown cell must not match");
+    assert_eq!(
+        v.get("file").and_then(|f| f.as_str()),
+        Some("analysis.ipynb")
+    );
+    assert_eq!(v.get("line").and_then(|l| l.as_u64()), Some(14));
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unsafe code specs: parsed unsafe Rust counts and the unsafe ratio.
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+const LIB: &str = r#"// SAFETY: the caller passes a valid pointer
+pub unsafe fn read(p: *const u8) -> u8 {
+    // SAFETY: forwarded from the caller
+    unsafe {
+        *p
+    }
+}
+
+pub struct Handle;
+// SAFETY: Handle holds no data
+unsafe impl Send for Handle {}
+
+pub fn name() -> &'static str {
+    "unsafe in name only"
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn t() {
+        // SAFETY: test
+        unsafe { super::read(&1) };
+    }
+}
+"#;
+
+fn rust_project() -> Project {
+    let temp = Project::empty();
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    );
+    temp
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#unsafe-code
+///
+/// > Rust source files are also parsed for unsafe code: `blocks`, `fns`,
+/// > `impls`, and the non-blank `lines` inside unsafe blocks
+#[test]
+fn escapes_json_reports_parsed_unsafe_code() {
+    let temp = rust_project();
+    temp.file("src/lib.rs", LIB);
+
+    let escapes = check("escapes").pwd(temp.path()).json().passes();
+    let unsafe_code = &escapes.require("metrics")["unsafe"];
+
+    assert_eq!(unsafe_code["blocks"], 1);
+    assert_eq!(unsafe_code["fns"], 1);
+    assert_eq!(unsafe_code["impls"], 1);
+    assert_eq!(unsafe_code["lines"], 3);
+    assert_eq!(unsafe_code["source_lines"], 13);
+    assert_eq!(unsafe_code["ratio"], 0.2308);
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#unsafe-code
+///
+/// > overall and per package
+#[test]
+fn escapes_by_package_reports_unsafe_code() {
+    let temp = rust_project();
+    temp.config("[project]\npackages = [\"crates/a\", \"crates/b\"]\n");
+    temp.file("crates/a/src/lib.rs", LIB);
+    temp.file("crates/b/src/lib.rs", "pub fn safe() {}\n");
+
+    let escapes = check("escapes").pwd(temp.path()).json().passes();
+    let by_package = escapes.require("by_package");
+
+    assert_eq!(by_package["a"]["unsafe"]["blocks"], 1);
+    assert_eq!(by_package["b"]["unsafe"]["lines"], 0);
+    assert_eq!(by_package["b"]["unsafe"]["ratio"], 0.0);
+}
+
+/// Spec: docs/specs/checks/escape-hatches.md#unsafe-code
+///
+/// > Projects without Rust source omit `unsafe`
+#[test]
+fn escapes_json_omits_unsafe_without_rust_files() {
+    let temp = Project::empty();
+    temp.config(
+        "[[check.escapes.patterns]]\nname = \"todo\"\npattern = \"TODO\"\naction = \"count\"\n",
+    );
+    temp.file("src/main.py", "x = 1\n");
+
+    let escapes = check("escapes").pwd(temp.path()).json().passes();
+
+    assert!(escapes.require("metrics").get("unsafe").is_none());
+}
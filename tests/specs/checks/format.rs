@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the format check.
+//!
+//! Tests that quench correctly:
+//! - Stays off unless configured
+//! - Reports files rustfmt would change
+//! - Runs the formatter with --fix
+//! - Skips formatters that aren't installed
+//!
+//! Reference: docs/specs/checks/format.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+const CARGO_TOML: &str = r#"[package]
+name = "demo"
+version = "0.1.0"
+edition = "2021"
+"#;
+
+fn rust_project(config: &str, lib: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(config);
+    temp.file("Cargo.toml", CARGO_TOML);
+    temp.file("src/lib.rs", lib);
+    temp
+}
+
+/// Spec: docs/specs/checks/format.md#purpose
+///
+/// > **Disabled by default.**
+#[test]
+fn format_disabled_by_default() {
+    let temp = rust_project("", "pub fn f( ) {}\n");
+
+    let format = check("format").pwd(temp.path()).json().passes();
+
+    assert!(format.violations().is_empty());
+}
+
+/// Spec: docs/specs/checks/format.md#unformatted-file
+///
+/// > rustfmt reports the line where its diff starts
+#[test]
+fn rustfmt_unformatted_file_generates_violation() {
+    let temp = rust_project(
+        "[check.format]\ncheck = \"error\"\n",
+        "pub fn a() {}\n\npub fn f( ) {}\n",
+    );
+
+    let format = check("format").pwd(temp.path()).json().fails();
+
+    let violation = format.require_violation("unformatted");
+    assert_eq!(
+        violation.get("file").and_then(|v| v.as_str()),
+        Some("src/lib.rs")
+    );
+    assert!(violation.get("line").and_then(|v| v.as_u64()).is_some());
+}
+
+/// Spec: docs/specs/checks/format.md#unformatted-file
+///
+/// > A formatter would change the file.
+#[test]
+fn formatted_project_passes() {
+    let temp = rust_project("[check.format]\ncheck = \"error\"\n", "pub fn f() {}\n");
+
+    let format = check("format").pwd(temp.path()).json().passes();
+
+    assert_eq!(format.require("metrics")["unformatted"]["rustfmt"], 0);
+}
+
+/// Spec: docs/specs/checks/format.md#auto-fix---fix
+///
+/// > Runs the fix command of each formatter that reported files.
+#[test]
+fn fix_runs_the_formatter() {
+    let temp = rust_project("[check.format]\ncheck = \"error\"\n", "pub fn f( ) {}\n");
+
+    check("format")
+        .pwd(temp.path())
+        .args(&["--fix"])
+        .passes()
+        .stdout_has("Formatted 1 files with rustfmt");
+
+    let content = std::fs::read_to_string(temp.path().join("src/lib.rs")).unwrap();
+    assert_eq!(content, "pub fn f() {}\n");
+}
+
+/// Spec: docs/specs/checks/format.md#missing-formatter
+///
+/// > If none could run, the check is skipped
+#[test]
+fn missing_formatter_skips_check() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.format]
+check = "error"
+formatters = ["gofmt"]
+"#,
+    );
+    temp.file("main.go", "package main\n");
+    // Hide any installed gofmt
+    let format = check("format")
+        .pwd(temp.path())
+        .env("PATH", "/nonexistent")
+        .json()
+        .passes();
+
+    assert_eq!(format.require("skipped"), true);
+    assert!(
+        format
+            .require("error")
+            .as_str()
+            .unwrap()
+            .contains("gofmt not found")
+    );
+}
@@ -181,6 +181,86 @@ agents = false
     check("git").pwd(temp.path()).args(&["--ci"]).passes();
 }
 
+// =============================================================================
+// SCOPE INFERENCE SPECS
+// =============================================================================
+
+/// Project with two packages and `infer_scope` set to `mode`.
+fn scope_inference_project(mode: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!(
+        r#"[project]
+packages = ["crates/cli", "crates/core"]
+
+[git.commit]
+check = "error"
+agents = false
+infer_scope = "{mode}"
+"#
+    ));
+    temp.file("crates/cli/src/lib.rs", "");
+    temp.file("crates/core/src/lib.rs", "");
+    git_init(&temp);
+    git_initial_commit(&temp);
+    git_branch(&temp, "feature");
+    temp
+}
+
+/// Spec: docs/specs/checks/git.md#scope-inference
+///
+/// > With `infer_scope = "enforce"`, a commit whose scope does not name a
+/// > package it touches fails with `scope_mismatch`
+#[test]
+fn git_enforced_scope_must_match_touched_package() {
+    let temp = scope_inference_project("enforce");
+    temp.file("crates/cli/src/parser.rs", "pub fn parse() {}\n");
+    git_commit(&temp, "feat(core): add parser");
+
+    let git = check("git").pwd(temp.path()).args(&["--ci"]).json().fails();
+    let violations = git.require("violations").as_array().unwrap();
+    let mismatch = violations
+        .iter()
+        .find(|v| v.get("type").and_then(|t| t.as_str()) == Some("scope_mismatch"))
+        .expect("should have scope_mismatch violation");
+    assert_eq!(
+        mismatch.get("advice").and_then(|a| a.as_str()),
+        Some("Commit touches cli; use: feat(cli): add parser")
+    );
+}
+
+/// Spec: docs/specs/checks/git.md#scope-inference
+///
+/// > Any package the commit touches is an acceptable scope
+#[test]
+fn git_enforced_scope_passes_when_scope_matches() {
+    let temp = scope_inference_project("enforce");
+    temp.file("crates/cli/src/parser.rs", "pub fn parse() {}\n");
+    git_commit(&temp, "feat(cli): add parser");
+
+    check("git").pwd(temp.path()).args(&["--ci"]).passes();
+}
+
+/// Spec: docs/specs/checks/git.md#scope-inference
+///
+/// > With `infer_scope = "suggest"`, mismatches are reported as warnings
+#[test]
+fn git_suggested_scope_warns_without_failing() {
+    let temp = scope_inference_project("suggest");
+    temp.file("crates/core/src/empty.rs", "pub fn empty() {}\n");
+    git_commit(&temp, "fix: handle empty input");
+
+    let git = check("git")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .json()
+        .passes();
+    let violations = git.require("violations").as_array().unwrap();
+    assert_eq!(
+        violations[0].get("advice").and_then(|a| a.as_str()),
+        Some("Commit touches core; use: fix(core): handle empty input")
+    );
+}
+
 // =============================================================================
 // AGENT DOCUMENTATION SPECS
 // =============================================================================
@@ -376,7 +456,7 @@ template = true
 
 /// Spec: docs/specs/checks/git.md#json-output
 ///
-/// > Violation types: `invalid_format`, `invalid_type`, `invalid_scope`, `missing_docs`
+/// > Violation types: `invalid_format`, `invalid_type`, `invalid_scope`, `scope_mismatch`, `missing_docs`
 #[test]
 fn git_violation_type_is_one_of_expected_values() {
     let temp = Project::empty();
@@ -403,6 +483,7 @@ agents = false
         "invalid_format",
         "invalid_type",
         "invalid_scope",
+        "scope_mismatch",
         "missing_docs",
     ];
 
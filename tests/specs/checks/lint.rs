@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the lint check.
+//!
+//! Tests that quench correctly:
+//! - Stays off unless configured
+//! - Reports clippy diagnostics as violations with linter/rule patterns
+//! - Grandfathers diagnostics as warnings
+//! - Drops diagnostics in excluded files
+//! - Skips linters that aren't installed
+//!
+//! Reference: docs/specs/checks/lint.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+const CARGO_TOML: &str = r#"[package]
+name = "demo"
+version = "0.1.0"
+edition = "2021"
+"#;
+
+const NEEDLESS_RETURN: &str = "pub fn f() -> i32 {\n    return 1;\n}\n";
+
+fn rust_project(config: &str, lib: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(config);
+    temp.file("Cargo.toml", CARGO_TOML);
+    temp.file("src/lib.rs", lib);
+    temp
+}
+
+/// Spec: docs/specs/checks/lint.md#purpose
+///
+/// > **Disabled by default.**
+#[test]
+fn lint_disabled_by_default() {
+    let temp = rust_project("", NEEDLESS_RETURN);
+
+    let lint = check("lint").pwd(temp.path()).json().passes();
+
+    assert!(lint.violations().is_empty());
+}
+
+/// Spec: docs/specs/checks/lint.md#lint-diagnostic
+///
+/// > The violation's `pattern` is `<linter>/<rule>`
+#[test]
+fn clippy_diagnostic_generates_violation() {
+    let temp = rust_project("[check.lint]\ncheck = \"error\"\n", NEEDLESS_RETURN);
+
+    let lint = check("lint").pwd(temp.path()).json().fails();
+
+    let violation = lint.require_violation("lint");
+    assert_eq!(
+        violation.get("file").and_then(|v| v.as_str()),
+        Some("src/lib.rs")
+    );
+    assert_eq!(violation.get("line").and_then(|v| v.as_u64()), Some(2));
+    assert_eq!(
+        violation.get("pattern").and_then(|v| v.as_str()),
+        Some("clippy/needless_return")
+    );
+    assert_eq!(
+        lint.require("metrics")["rules"]["clippy/needless_return"],
+        1
+    );
+}
+
+/// Spec: docs/specs/checks/lint.md#grandfathering
+///
+/// > With `grandfather = true`, diagnostics are reported as warnings and the
+/// > check passes.
+#[test]
+fn grandfathered_diagnostics_pass_with_warnings() {
+    let temp = rust_project(
+        "[check.lint]\ncheck = \"error\"\ngrandfather = true\n",
+        NEEDLESS_RETURN,
+    );
+
+    let lint = check("lint").pwd(temp.path()).json().passes();
+
+    assert!(lint.has_violation("lint"));
+    assert_eq!(lint.require("metrics")["diagnostics"]["clippy"], 1);
+}
+
+/// Spec: docs/specs/checks/lint.md#linters
+///
+/// > quench's `exclude` patterns for the lint check drop diagnostics in
+/// > matching files after the linter runs.
+#[test]
+fn excluded_files_are_dropped() {
+    let temp = rust_project(
+        "[check.lint]\ncheck = \"error\"\nexclude = [\"src/lib.rs\"]\n",
+        NEEDLESS_RETURN,
+    );
+
+    let lint = check("lint").pwd(temp.path()).json().passes();
+
+    assert_eq!(lint.require("metrics")["diagnostics"]["clippy"], 0);
+}
+
+/// Spec: docs/specs/checks/lint.md#missing-linter
+///
+/// > If none could run, the check is skipped
+#[test]
+fn missing_linter_skips_check() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.lint]
+check = "error"
+linters = ["golangci-lint"]
+"#,
+    );
+    temp.file("main.go", "package main\n");
+    // Hide any installed golangci-lint
+    let lint = check("lint")
+        .pwd(temp.path())
+        .env("PATH", "/nonexistent")
+        .json()
+        .passes();
+
+    assert_eq!(lint.require("skipped"), true);
+    assert!(
+        lint.require("error")
+            .as_str()
+            .unwrap()
+            .contains("golangci-lint not found")
+    );
+}
@@ -51,6 +51,15 @@ fn git_commit(temp: &Project, message: &str) {
         .unwrap();
 }
 
+/// Record the current commit as a branch to diff against.
+fn git_branch(temp: &Project, name: &str) {
+    std::process::Command::new("git")
+        .args(["branch", name])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+}
+
 // =============================================================================
 // RUST POLICY CONFIG SPECS
 // =============================================================================
@@ -400,3 +409,142 @@ lint_changes = "standalone"
         .fails()
         .stdout_has("lint_policy");
 }
+
+// =============================================================================
+// CROSS-LANGUAGE SPECS
+// =============================================================================
+
+/// Spec: docs/specs/10-language-adapters.md#lint-config-policy
+///
+/// > Every language's policy applies, not just the detected language's.
+#[test]
+fn secondary_language_policy_applies_in_mixed_project() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[shell.policy]
+lint_changes = "standalone"
+"#,
+    );
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"test\"\nversion = \"0.1.0\"\n",
+    );
+
+    setup_git_repo(&temp);
+    temp.file("src/lib.rs", "pub fn f() {}");
+    temp.file("scripts/build.sh", "#!/bin/bash\necho build\n");
+    git_add_all(&temp);
+    git_commit(&temp, "initial");
+
+    temp.file(".shellcheckrc", "disable=SC2034\n");
+    temp.file("scripts/build.sh", "#!/bin/bash\necho build all\n");
+    git_add_all(&temp);
+
+    check("escapes")
+        .pwd(temp.path())
+        .args(&["--base", "HEAD"])
+        .fails()
+        .stdout_has("Changed shell lint config: .shellcheckrc");
+}
+
+/// Spec: docs/specs/10-language-adapters.md#lint-config-policy
+///
+/// > A lint config change is only paired with its own language's sources.
+#[test]
+fn lint_config_not_paired_with_other_language_sources() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[shell.policy]
+lint_changes = "standalone"
+"#,
+    );
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"test\"\nversion = \"0.1.0\"\n",
+    );
+
+    setup_git_repo(&temp);
+    temp.file("src/lib.rs", "pub fn f() {}");
+    git_add_all(&temp);
+    git_commit(&temp, "initial");
+
+    temp.file(".shellcheckrc", "disable=SC2034\n");
+    temp.file("src/lib.rs", "pub fn f() {}\npub fn g() {}");
+    git_add_all(&temp);
+
+    check("escapes")
+        .pwd(temp.path())
+        .args(&["--base", "HEAD"])
+        .passes();
+}
+
+// =============================================================================
+// CHORE(LINT) EXEMPTION SPECS
+// =============================================================================
+
+/// Spec: docs/specs/10-language-adapters.md#lint-config-policy
+///
+/// > Lint config changes made in `chore(lint)` commits may carry source fixes.
+#[test]
+fn chore_lint_commit_allows_paired_change() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[rust.policy]
+lint_changes = "standalone"
+"#,
+    );
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"test\"\nversion = \"0.1.0\"\n",
+    );
+
+    setup_git_repo(&temp);
+    temp.file("src/lib.rs", "pub fn f() {}");
+    git_add_all(&temp);
+    git_commit(&temp, "initial");
+    git_branch(&temp, "main");
+
+    temp.file("rustfmt.toml", "max_width = 100\n");
+    temp.file("src/lib.rs", "pub fn f() {}\npub fn g() {}");
+    git_add_all(&temp);
+    git_commit(&temp, "chore(lint): narrow rustfmt width");
+
+    check("escapes")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .passes();
+}
+
+/// Spec: docs/specs/10-language-adapters.md#lint-config-policy
+///
+/// > Other commit types still require a standalone change.
+#[test]
+fn other_commit_types_still_require_standalone() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[rust.policy]
+lint_changes = "standalone"
+"#,
+    );
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"test\"\nversion = \"0.1.0\"\n",
+    );
+
+    setup_git_repo(&temp);
+    temp.file("src/lib.rs", "pub fn f() {}");
+    git_add_all(&temp);
+    git_commit(&temp, "initial");
+    git_branch(&temp, "main");
+
+    temp.file("rustfmt.toml", "max_width = 100\n");
+    temp.file("src/lib.rs", "pub fn f() {}\npub fn g() {}");
+    git_add_all(&temp);
+    git_commit(&temp, "chore: narrow rustfmt width");
+
+    check("escapes")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .fails()
+        .stdout_has("lint_policy");
+}
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the release readiness check.
+//!
+//! Reference: docs/specs/checks/release.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Project with a Cargo.toml at `version` and a changelog for it.
+fn release_project(version: &str) -> Project {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file(
+        "Cargo.toml",
+        &format!("[package]\nname = \"app\"\nversion = \"{version}\"\n"),
+    );
+    temp.file(
+        "CHANGELOG.md",
+        &format!("# Changelog\n\n## [{version}] - 2026-01-01\n\n- Initial release\n"),
+    );
+    temp.file("src/lib.rs", "pub fn run() {}\n");
+    temp
+}
+
+/// The release check result (only `cloc` runs alongside it).
+fn release_result(json: &ChecksJson) -> serde_json::Value {
+    json.checks()
+        .iter()
+        .find(|c| c.get("name").and_then(|n| n.as_str()) == Some("release"))
+        .cloned()
+        .expect("release check should run")
+}
+
+fn violation_types(result: &serde_json::Value) -> Vec<String> {
+    result
+        .get("violations")
+        .and_then(|v| v.as_array())
+        .map(|vs| {
+            vs.iter()
+                .filter_map(|v| v.get("type").and_then(|t| t.as_str()))
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Spec: docs/specs/checks/release.md#enabling
+///
+/// > The release check only runs with `--ci --release-mode`
+#[test]
+fn release_check_runs_only_in_release_mode() {
+    let temp = release_project("1.0.0");
+    temp.file(
+        "src/todo.rs",
+        "// TODO(before-release): remove debug flag\n",
+    );
+
+    let json = cli().pwd(temp.path()).args(&["--cloc"]).json().passes();
+    assert!(
+        json.checks()
+            .iter()
+            .all(|c| c.get("name").and_then(|n| n.as_str()) != Some("release"))
+    );
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--cloc", "--release-mode"])
+        .exits(2);
+}
+
+/// Spec: docs/specs/checks/release.md#enabling
+///
+/// > A ready project passes
+#[test]
+fn release_ready_project_passes() {
+    let temp = release_project("1.0.0");
+    let json = cli()
+        .pwd(temp.path())
+        .args(&["--ci", "--release-mode", "--cloc"])
+        .json()
+        .passes();
+    let release = release_result(&json);
+    assert_eq!(release.get("passed"), Some(&serde_json::json!(true)));
+    assert_eq!(
+        release.pointer("/metrics/version").and_then(|v| v.as_str()),
+        Some("1.0.0")
+    );
+}
+
+/// Spec: docs/specs/checks/release.md#changelog
+///
+/// > The changelog must have a heading for the pending version
+#[test]
+fn release_requires_changelog_section() {
+    let temp = release_project("1.0.0");
+    temp.file("CHANGELOG.md", "# Changelog\n\n## [0.9.0]\n");
+
+    let json = cli()
+        .pwd(temp.path())
+        .args(&["--ci", "--release-mode", "--cloc"])
+        .json()
+        .fails();
+    assert_eq!(
+        violation_types(&release_result(&json)),
+        ["missing_changelog"]
+    );
+}
+
+/// Spec: docs/specs/checks/release.md#versions
+///
+/// > Every manifest and pinned doc version must match the pending version
+#[test]
+fn release_reports_inconsistent_versions() {
+    let temp = release_project("1.0.0");
+    temp.file("package.json", r#"{"name": "app", "version": "0.9.0"}"#);
+    temp.file("README.md", "# App\n\nInstall: `cargo install app@0.9.0`\n");
+
+    let json = cli()
+        .pwd(temp.path())
+        .args(&["--ci", "--release-mode", "--cloc"])
+        .json()
+        .fails();
+    assert_eq!(
+        violation_types(&release_result(&json)),
+        ["version_mismatch", "version_mismatch"]
+    );
+}
+
+/// Spec: docs/specs/checks/release.md#markers-and-placeholders
+///
+/// > Placeholder tests and `TODO(before-release)` markers block the release
+#[test]
+fn release_reports_markers_and_placeholder_tests() {
+    let temp = release_project("1.0.0");
+    temp.file(
+        "src/todo.rs",
+        "// TODO(before-release): remove debug flag\n",
+    );
+    temp.file(
+        "tests/parser.rs",
+        "#[test]\n#[ignore]\nfn parses_everything() {}\n",
+    );
+
+    let json = cli()
+        .pwd(temp.path())
+        .args(&["--ci", "--release-mode", "--cloc"])
+        .json()
+        .fails();
+    let mut types = violation_types(&release_result(&json));
+    types.sort();
+    assert_eq!(types, ["placeholder_test", "release_marker"]);
+}
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the snapshots check.
+//!
+//! Tests that quench correctly:
+//! - Stays off unless configured
+//! - Reports orphaned jest and insta snapshots
+//! - Reports snapshots over the size limit
+//! - Reports snapshot churn against --staged
+//!
+//! Reference: docs/specs/checks/snapshots.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+fn snapshots_project(config: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!("[check.snapshots]\ncheck = \"error\"\n{}", config));
+    temp
+}
+
+fn insta_snapshot(source: &str, body: &str) -> String {
+    format!("---\nsource: {source}\nexpression: value\n---\n{body}")
+}
+
+// =============================================================================
+// DEFAULT BEHAVIOR SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/snapshots.md#purpose
+///
+/// > **Disabled by default.**
+#[test]
+fn snapshots_disabled_by_default() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("src/__snapshots__/gone.test.ts.snap", "exports = {};\n");
+
+    let snapshots = check("snapshots").pwd(temp.path()).json().passes();
+
+    assert!(snapshots.violations().is_empty());
+}
+
+// =============================================================================
+// VALIDATION RULE SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/snapshots.md#orphaned-snapshots
+///
+/// > `dir/__snapshots__/x.test.ts.snap` → `dir/x.test.ts`
+#[test]
+fn jest_snapshot_without_test_is_orphaned() {
+    let temp = snapshots_project("");
+    temp.file("src/button.test.ts", "test('renders', () => {});\n");
+    temp.file("src/__snapshots__/button.test.ts.snap", "exports = {};\n");
+    temp.file("src/__snapshots__/gone.test.ts.snap", "exports = {};\n");
+
+    let snapshots = check("snapshots").pwd(temp.path()).json().fails();
+
+    let violation = snapshots.require_violation("orphaned_snapshot");
+    assert_eq!(
+        violation.get("file").and_then(|v| v.as_str()),
+        Some("src/__snapshots__/gone.test.ts.snap")
+    );
+    assert_eq!(
+        violation.get("target").and_then(|v| v.as_str()),
+        Some("src/gone.test.ts")
+    );
+    assert_eq!(snapshots.violations().len(), 1);
+}
+
+/// Spec: docs/specs/checks/snapshots.md#orphaned-snapshots
+///
+/// > The source file doesn't exist, or doesn't mention the snapshot name
+#[test]
+fn insta_snapshot_missing_from_source_is_orphaned() {
+    let temp = snapshots_project("");
+    temp.file(
+        "src/parser.rs",
+        "#[test]\nfn parse_empty() { insta::assert_snapshot!(1); }\n",
+    );
+    temp.file(
+        "src/snapshots/app__parser__parse_empty.snap",
+        &insta_snapshot("src/parser.rs", "1\n"),
+    );
+    temp.file(
+        "src/snapshots/app__parser__parse_removed.snap",
+        &insta_snapshot("src/parser.rs", "2\n"),
+    );
+
+    let snapshots = check("snapshots").pwd(temp.path()).json().fails();
+
+    assert!(snapshots.has_violation_for_file("src/snapshots/app__parser__parse_removed.snap"));
+    assert!(!snapshots.has_violation_for_file("src/snapshots/app__parser__parse_empty.snap"));
+}
+
+/// Spec: docs/specs/checks/snapshots.md#orphaned-snapshots
+///
+/// > Set `orphans = false` to skip this rule.
+#[test]
+fn orphans_can_be_disabled() {
+    let temp = snapshots_project("orphans = false\n");
+    temp.file("src/__snapshots__/gone.test.ts.snap", "exports = {};\n");
+
+    check("snapshots").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/snapshots.md#giant-snapshots
+///
+/// > Snapshot files larger than `max_size`
+#[test]
+fn snapshot_over_max_size_generates_violation() {
+    let temp = snapshots_project("max_size = \"1KB\"\norphans = false\n");
+    temp.file("src/snapshots/app__page.snap", &"x".repeat(2048));
+
+    check("snapshots")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("src/snapshots/app__page.snap: snapshot too large: 2.0 KB (max: 1.0 KB)");
+}
+
+/// Spec: docs/specs/checks/snapshots.md#snapshot-churn
+///
+/// > the lines added and deleted across changed snapshot files must not
+/// > exceed `max_churn`
+#[test]
+fn snapshot_churn_over_limit_generates_violation() {
+    let temp = snapshots_project("max_churn = 5\norphans = false\n");
+    temp.file("src/snapshots/app__page.snap", "a\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+
+    temp.file("src/snapshots/app__page.snap", &"b\n".repeat(10));
+    git_add_all(&temp);
+
+    let snapshots = check("snapshots")
+        .pwd(temp.path())
+        .args(&["--staged"])
+        .json()
+        .fails();
+
+    let violation = snapshots.require_violation("snapshot_churn");
+    assert_eq!(violation.get("value").and_then(|v| v.as_i64()), Some(11));
+    assert_eq!(violation.get("threshold").and_then(|v| v.as_i64()), Some(5));
+    let metrics = snapshots.require("metrics");
+    assert_eq!(metrics.get("churn_files").and_then(|v| v.as_u64()), Some(1));
+}
+
+/// Spec: docs/specs/checks/snapshots.md#configuration
+///
+/// > check = "error"              # error | warn | off (default: off)
+#[test]
+fn warn_level_reports_without_failing() {
+    let temp = Project::empty();
+    temp.config("[check.snapshots]\ncheck = \"warn\"\n");
+    temp.file("src/__snapshots__/gone.test.ts.snap", "exports = {};\n");
+
+    let snapshots = check("snapshots").pwd(temp.path()).json().passes();
+
+    assert!(snapshots.has_violation("orphaned_snapshot"));
+}
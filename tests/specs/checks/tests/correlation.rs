@@ -11,7 +11,7 @@ use crate::prelude::*;
 use std::process::Command;
 
 /// Initialize a git repo with user config and initial commit.
-fn init_git_repo(path: &std::path::Path) {
+pub(super) fn init_git_repo(path: &std::path::Path) {
     Command::new("git")
         .args(["init", "-b", "main"])
         .current_dir(path)
@@ -49,7 +49,7 @@ fn git_stage(path: &std::path::Path) {
 }
 
 /// Add and commit all changes.
-fn git_commit(path: &std::path::Path, msg: &str) {
+pub(super) fn git_commit(path: &std::path::Path, msg: &str) {
     Command::new("git")
         .args(["add", "."])
         .current_dir(path)
@@ -63,7 +63,7 @@ fn git_commit(path: &std::path::Path, msg: &str) {
 }
 
 /// Create a feature branch.
-fn git_branch(path: &std::path::Path, name: &str) {
+pub(super) fn git_branch(path: &std::path::Path, name: &str) {
     Command::new("git")
         .args(["checkout", "-b", name])
         .current_dir(path)
@@ -144,6 +144,86 @@ check = "error"
         .stdout_has("feature.rs");
 }
 
+/// Run a git command in `path`.
+fn git(path: &std::path::Path, args: &[&str]) {
+    Command::new("git")
+        .args(args)
+        .current_dir(path)
+        .output()
+        .unwrap();
+}
+
+/// Spec: docs/specs/checks/tests.md#base-commit
+///
+/// > `merge-base` (default) | Where the branch and the ref's tip diverged, so
+/// > commits the ref gained since don't count as changes
+#[test]
+fn base_flag_ignores_commits_base_gained_after_branching() {
+    let temp = Project::empty();
+    temp.config("[check.tests.commit]\ncheck = \"error\"\n");
+    temp.file("src/upstream.rs", "pub fn upstream() {}");
+    init_git_repo(temp.path());
+    git_branch(temp.path(), "feature/new-thing");
+    temp.file("src/feature.rs", "pub fn feature() {}");
+    git_commit(temp.path(), "feat: add feature");
+    git(temp.path(), &["checkout", "main"]);
+    temp.file("src/upstream.rs", "pub fn upstream() -> bool { true }");
+    git_commit(temp.path(), "feat: change upstream");
+    git(temp.path(), &["checkout", "feature/new-thing"]);
+
+    check("tests")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .fails()
+        .stdout_has("feature.rs")
+        .stdout_lacks("upstream.rs");
+
+    check("tests")
+        .pwd(temp.path())
+        .args(&["--base", "main", "--base-strategy", "exact"])
+        .fails()
+        .stdout_has("upstream.rs");
+}
+
+/// Spec: docs/specs/checks/tests.md#base-commit
+///
+/// > `fork-point` | Where the branch forked, per the ref's reflog, so commits
+/// > the ref dropped when it was rebased don't count either
+#[test]
+fn fork_point_ignores_rebased_base_commits() {
+    let temp = Project::empty();
+    temp.config(
+        "[git]\nbase_strategy = \"fork-point\"\n\n[check.tests.commit]\ncheck = \"error\"\n",
+    );
+    init_git_repo(temp.path());
+    temp.file("src/upstream.rs", "pub fn upstream() {}");
+    git_commit(temp.path(), "feat: add upstream");
+    git_branch(temp.path(), "feature/new-thing");
+    temp.file("src/feature.rs", "pub fn feature() {}");
+    git_commit(temp.path(), "feat: add feature");
+    // Rewrite main's last commit, as a rebase would
+    git(temp.path(), &["checkout", "main"]);
+    temp.file("src/upstream.rs", "pub fn upstream() -> bool { true }");
+    git(
+        temp.path(),
+        &["commit", "-a", "--amend", "-m", "feat: add upstream"],
+    );
+    git(temp.path(), &["checkout", "feature/new-thing"]);
+
+    check("tests")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .fails()
+        .stdout_has("feature.rs")
+        .stdout_lacks("upstream.rs");
+
+    check("tests")
+        .pwd(temp.path())
+        .args(&["--base", "main", "--base-strategy", "merge-base"])
+        .fails()
+        .stdout_has("upstream.rs");
+}
+
 // =============================================================================
 // SOURCE/TEST CORRELATION SPECS
 // =============================================================================
@@ -260,6 +340,79 @@ mod tests {
         .passes();
 }
 
+/// Spec: docs/specs/checks/tests.md#inline-test-changes-rust
+///
+/// > Adding or changing a doctest (a code block in `///` or `//!` doc
+/// > comments) counts too.
+#[test]
+fn doctest_change_satisfies_test_requirement() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.tests.commit]
+check = "error"
+"#,
+    );
+
+    init_git_repo(temp.path());
+    git_branch(temp.path(), "feature/doctests");
+
+    temp.file(
+        "src/parser.rs",
+        r#"/// Parse the input.
+///
+/// ```
+/// assert!(parser::parse());
+/// ```
+pub fn parse() -> bool {
+    true
+}
+"#,
+    );
+    git_commit(temp.path(), "feat: add parser with doctest");
+
+    check("tests")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .passes();
+}
+
+/// Spec: docs/specs/checks/tests.md#inline-test-changes-rust
+///
+/// > Blocks tagged `text`, another language, or `ignore` don't run as
+/// > doctests and don't count
+#[test]
+fn text_block_in_doc_comment_does_not_satisfy_test_requirement() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.tests.commit]
+check = "error"
+"#,
+    );
+
+    init_git_repo(temp.path());
+    git_branch(temp.path(), "feature/docs");
+
+    temp.file(
+        "src/parser.rs",
+        r#"/// Parse the input.
+///
+/// ```text
+/// parse: true
+/// ```
+pub fn parse() -> bool {
+    true
+}
+"#,
+    );
+    git_commit(temp.path(), "feat: add parser");
+
+    check("tests")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .fails()
+        .stdout_has("parser.rs");
+}
+
 // =============================================================================
 // PLACEHOLDER TEST SPECS
 // =============================================================================
@@ -565,252 +718,6 @@ check = "error"
     assert_eq!(v.get("lines_changed").and_then(|v| v.as_i64()), Some(10));
 }
 
-// =============================================================================
-// COMMIT SCOPE SPECS
-// =============================================================================
-
-/// Spec: docs/specs/checks/tests.md#commit-scope
-///
-/// > scope = "commit" # Per-commit with asymmetric rules
-/// > - Tests without code = **OK** (TDD recognized)
-/// > - Code without tests = **FAIL**
-#[test]
-fn commit_scope_fails_on_source_without_tests() {
-    let temp = Project::empty();
-    temp.config(
-        r#"[check.tests.commit]
-check = "error"
-scope = "commit"
-"#,
-    );
-
-    init_git_repo(temp.path());
-    git_branch(temp.path(), "feature/commit-scope");
-
-    // First commit: tests only (TDD) - OK
-    temp.file("tests/parser_tests.rs", "#[test] fn t() {}");
-    git_commit(temp.path(), "test: add parser tests");
-
-    // Second commit: source without tests - FAIL
-    temp.file("src/lexer.rs", "pub fn lex() {}");
-    git_commit(temp.path(), "feat: add lexer");
-
-    check("tests")
-        .pwd(temp.path())
-        .args(&["--base", "main"])
-        .fails()
-        .stdout_has("lexer.rs")
-        .stdout_lacks("parser"); // TDD commit should pass
-}
-
-/// Spec: docs/specs/checks/tests.md#commit-scope
-///
-/// > Tests without code = **OK** (TDD recognized)
-#[test]
-fn commit_scope_passes_test_only_commit_tdd() {
-    let temp = Project::empty();
-    temp.config(
-        r#"[check.tests.commit]
-check = "error"
-scope = "commit"
-"#,
-    );
-
-    init_git_repo(temp.path());
-    git_branch(temp.path(), "feature/tdd-commit");
-
-    // Commit with only test changes - TDD workflow
-    temp.file(
-        "tests/parser_tests.rs",
-        "#[test] fn test_parse() { assert!(true); }",
-    );
-    git_commit(temp.path(), "test: add parser tests first");
-
-    // Should pass - TDD commit is valid
-    check("tests")
-        .pwd(temp.path())
-        .args(&["--base", "main"])
-        .passes();
-}
-
-/// Spec: docs/specs/checks/tests.md#commit-scope
-///
-/// > Each commit checked independently
-#[test]
-fn commit_scope_passes_when_each_commit_has_tests() {
-    let temp = Project::empty();
-    temp.config(
-        r#"[check.tests.commit]
-check = "error"
-scope = "commit"
-"#,
-    );
-
-    init_git_repo(temp.path());
-    git_branch(temp.path(), "feature/proper-commits");
-
-    // First commit: source with tests
-    temp.file("src/parser.rs", "pub fn parse() {}");
-    temp.file("tests/parser_tests.rs", "#[test] fn test_parse() {}");
-    git_commit(temp.path(), "feat: add parser with tests");
-
-    // Second commit: source with tests
-    temp.file("src/lexer.rs", "pub fn lex() {}");
-    temp.file("tests/lexer_tests.rs", "#[test] fn test_lex() {}");
-    git_commit(temp.path(), "feat: add lexer with tests");
-
-    // Should pass - each commit has tests
-    check("tests")
-        .pwd(temp.path())
-        .args(&["--base", "main"])
-        .passes();
-}
-
-/// Spec: docs/specs/checks/tests.md#commit-scope
-///
-/// > Inline #[cfg(test)] changes count as test changes per commit
-#[test]
-fn commit_scope_inline_cfg_test_satisfies() {
-    let temp = Project::empty();
-    temp.config(
-        r#"[check.tests.commit]
-check = "error"
-scope = "commit"
-"#,
-    );
-
-    init_git_repo(temp.path());
-    git_branch(temp.path(), "feature/inline-tests");
-
-    // Commit with source and inline tests
-    temp.file(
-        "src/parser.rs",
-        r#"pub fn parse() -> bool { true }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse() {
-        assert!(parse());
-    }
-}
-"#,
-    );
-    git_commit(temp.path(), "feat: add parser with inline tests");
-
-    // Should pass - inline tests satisfy requirement
-    check("tests")
-        .pwd(temp.path())
-        .args(&["--base", "main"])
-        .passes();
-}
-
-/// Spec: docs/specs/checks/tests.md#commit-scope
-///
-/// > scope = "branch" aggregates all changes (default)
-#[test]
-fn branch_scope_aggregates_all_changes() {
-    let temp = Project::empty();
-    temp.config(
-        r#"[check.tests.commit]
-check = "error"
-scope = "branch"
-"#,
-    );
-
-    init_git_repo(temp.path());
-    git_branch(temp.path(), "feature/branch-scope");
-
-    // First commit: source only
-    temp.file("src/parser.rs", "pub fn parse() {}");
-    git_commit(temp.path(), "feat: add parser");
-
-    // Second commit: tests only
-    temp.file("tests/parser_tests.rs", "#[test] fn test_parse() {}");
-    git_commit(temp.path(), "test: add parser tests");
-
-    // Should pass in branch scope - tests exist somewhere in the branch
-    check("tests")
-        .pwd(temp.path())
-        .args(&["--base", "main"])
-        .passes();
-}
-
-/// Spec: docs/specs/checks/tests.md#commit-scope
-///
-/// > Commit scope with sibling test files
-#[test]
-fn commit_scope_sibling_test_file_satisfies() {
-    let temp = Project::empty();
-    temp.config(
-        r#"[check.tests.commit]
-check = "error"
-scope = "commit"
-"#,
-    );
-
-    init_git_repo(temp.path());
-    git_branch(temp.path(), "feature/sibling-tests");
-
-    // Commit with source and sibling test file
-    temp.file("src/parser.rs", "pub fn parse() {}");
-    temp.file("src/parser_tests.rs", "#[test] fn test_parse() {}");
-    git_commit(temp.path(), "feat: add parser with sibling tests");
-
-    // Should pass - sibling test file satisfies requirement
-    check("tests")
-        .pwd(temp.path())
-        .args(&["--base", "main"])
-        .passes();
-}
-
-/// Spec: docs/specs/checks/tests.md#json-output
-///
-/// > Commit scope includes commits_checked and commits_failing metrics
-#[test]
-fn commit_scope_json_includes_commit_metrics() {
-    let temp = Project::empty();
-    temp.config(
-        r#"[check.tests.commit]
-check = "error"
-scope = "commit"
-"#,
-    );
-
-    init_git_repo(temp.path());
-    git_branch(temp.path(), "feature/commit-metrics");
-
-    // Two commits: one passing, one failing
-    temp.file("src/good.rs", "pub fn good() {}");
-    temp.file("tests/good_tests.rs", "#[test] fn t() {}");
-    git_commit(temp.path(), "feat: add good with tests");
-
-    temp.file("src/bad.rs", "pub fn bad() {}");
-    git_commit(temp.path(), "feat: add bad without tests");
-
-    let result = check("tests")
-        .pwd(temp.path())
-        .args(&["--base", "main"])
-        .json()
-        .fails();
-
-    let metrics = result.require("metrics");
-    assert_eq!(
-        metrics.get("commits_checked").and_then(|v| v.as_u64()),
-        Some(2)
-    );
-    assert_eq!(
-        metrics.get("commits_failing").and_then(|v| v.as_u64()),
-        Some(1)
-    );
-    assert_eq!(
-        metrics.get("scope").and_then(|v| v.as_str()),
-        Some("commit")
-    );
-}
-
 // =============================================================================
 // JAVASCRIPT/TYPESCRIPT PLACEHOLDER SPECS
 // =============================================================================
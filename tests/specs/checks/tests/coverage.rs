@@ -78,6 +78,56 @@ fn test_covered() { assert_eq!(test_project::covered(), 42); }
     );
 }
 
+/// Spec: docs/specs/11-test-runners.md#cargo
+///
+/// > Coverage via `cargo llvm-cov --workspace`, which covers the same unit and
+/// > integration tests as `cargo test --all`
+#[test]
+fn cargo_runner_covers_workspace_member_integration_tests() {
+    let temp = Project::cargo("test_project");
+    temp.file(
+        "Cargo.toml",
+        r#"
+[package]
+name = "test_project"
+version = "0.1.0"
+edition = "2021"
+
+[workspace]
+members = ["crates/helper"]
+"#,
+    );
+    temp.file("src/lib.rs", "pub fn root() -> i32 { 1 }\n");
+    temp.file(
+        "crates/helper/Cargo.toml",
+        r#"
+[package]
+name = "helper"
+version = "0.1.0"
+edition = "2021"
+"#,
+    );
+    temp.file("crates/helper/src/lib.rs", "pub fn help() -> i32 { 2 }\n");
+    temp.file(
+        "crates/helper/tests/help.rs",
+        "#[test]\nfn helps() { assert_eq!(helper::help(), 2); }\n",
+    );
+
+    let result = check("tests")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .json()
+        .passes();
+    let metrics = result.require("metrics");
+
+    // The member's code is only run by its own integration test
+    let helper = metrics
+        .get("coverage_by_package")
+        .and_then(|v| v.get("helper"))
+        .and_then(|v| v.as_f64());
+    assert_eq!(helper, Some(100.0), "metrics: {metrics}");
+}
+
 // =============================================================================
 // SHELL COVERAGE (kcov)
 // =============================================================================
@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for mechanical changes in source/test correlation.
+//!
+//! Reference: docs/specs/checks/tests.md#mechanical-changes
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use super::correlation::{git_branch, git_commit, init_git_repo};
+use crate::prelude::*;
+
+/// Spec: docs/specs/checks/tests.md#mechanical-changes
+///
+/// > Files matching `mechanical` patterns are neither source nor test changes:
+/// > - They never require tests (no `missing_tests`), even under `src/`
+#[test]
+fn mechanical_changes_dont_require_tests() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.tests.commit]
+check = "error"
+"#,
+    );
+
+    init_git_repo(temp.path());
+    git_branch(temp.path(), "feature/deps");
+
+    temp.file("Cargo.lock", "# lockfile\n");
+    temp.file("src/api.generated.ts", "export const api = {};\n");
+    temp.file(
+        "src/__snapshots__/view.test.ts.snap",
+        "exports[`view`] = `ok`;\n",
+    );
+    git_commit(temp.path(), "chore: regenerate");
+
+    check("tests")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .passes();
+}
+
+/// Spec: docs/specs/checks/tests.md#mechanical-changes
+///
+/// > - They don't satisfy another file's test requirement, even under `tests/`
+#[test]
+fn mechanical_changes_dont_count_as_tests() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.tests.commit]
+check = "error"
+mechanical = ["tests/snapshots/**"]
+"#,
+    );
+
+    init_git_repo(temp.path());
+    git_branch(temp.path(), "feature/parser");
+
+    temp.file("src/parser.rs", "pub fn parse() {}");
+    temp.file("tests/snapshots/parser_tests.snap", "ok\n");
+    git_commit(temp.path(), "feat: add parser");
+
+    check("tests")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .fails()
+        .stdout_has("parser.rs");
+}
+
+/// Spec: docs/specs/checks/tests.md#mechanical-changes
+///
+/// > Their volume is reported separately in metrics (`mechanical_files_changed`,
+/// > `mechanical_lines_changed`, `commits_mechanical`)
+#[test]
+fn json_reports_mechanical_volume() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.tests.commit]
+check = "error"
+"#,
+    );
+
+    init_git_repo(temp.path());
+    git_branch(temp.path(), "feature/bump");
+
+    temp.file("package-lock.json", "{\n  \"lockfileVersion\": 3\n}\n");
+    temp.file("go.sum", "example.com/m v1.0.0 h1:abc=\n");
+    git_commit(temp.path(), "chore: bump deps");
+
+    let result = check("tests")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .json()
+        .passes();
+
+    let metrics = result.require("metrics");
+    assert_eq!(
+        metrics
+            .get("mechanical_files_changed")
+            .and_then(|v| v.as_u64()),
+        Some(2)
+    );
+    assert_eq!(
+        metrics
+            .get("mechanical_lines_changed")
+            .and_then(|v| v.as_u64()),
+        Some(4)
+    );
+    assert_eq!(
+        metrics.get("source_files_changed").and_then(|v| v.as_u64()),
+        Some(0)
+    );
+}
+
+/// Spec: docs/specs/checks/tests.md#mechanical-changes
+///
+/// > - A commit touching only mechanical files is skipped in commit scope
+#[test]
+fn commit_scope_counts_mechanical_commits() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.tests.commit]
+check = "error"
+scope = "commit"
+"#,
+    );
+
+    init_git_repo(temp.path());
+    git_branch(temp.path(), "feature/mechanical-commits");
+
+    temp.file("src/good.rs", "pub fn good() {}");
+    temp.file("tests/good_tests.rs", "#[test] fn t() {}");
+    git_commit(temp.path(), "feat: add good with tests");
+
+    temp.file("Cargo.lock", "# lockfile\n");
+    git_commit(temp.path(), "chore: update lockfile");
+
+    let result = check("tests")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .json()
+        .passes();
+
+    let metrics = result.require("metrics");
+    assert_eq!(
+        metrics.get("commits_checked").and_then(|v| v.as_u64()),
+        Some(2)
+    );
+    assert_eq!(
+        metrics.get("commits_mechanical").and_then(|v| v.as_u64()),
+        Some(1)
+    );
+}
@@ -7,9 +7,11 @@
 //! Reference: docs/specs/11-test-runners.md
 
 mod ci_metrics;
+mod commit_scope;
 mod correlation;
 mod coverage;
 mod js_runners;
+mod mechanical;
 mod output;
 mod runners;
 mod thresholds;
@@ -59,6 +59,49 @@ edition = "2021"
     assert_eq!(metrics.get("test_count").and_then(|v| v.as_i64()), Some(3));
 }
 
+/// Spec: docs/specs/11-test-runners.md#cargo
+///
+/// > Doctests run with the other tests and count toward `test_count`. They
+/// > are also reported as `doctest_count`
+#[test]
+fn cargo_runner_reports_doctest_count() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[[check.tests.suite]]
+runner = "cargo"
+"#,
+    );
+    temp.file(
+        "Cargo.toml",
+        r#"
+[package]
+name = "test_project"
+version = "0.1.0"
+edition = "2021"
+"#,
+    );
+    temp.file(
+        "src/lib.rs",
+        r#"
+/// ```
+/// assert_eq!(test_project::one(), 1);
+/// ```
+pub fn one() -> i32 { 1 }
+"#,
+    );
+    temp.file("tests/a.rs", "#[test] fn t1() {}");
+
+    let result = check("tests").pwd(temp.path()).json().passes();
+    let metrics = result.require("metrics");
+
+    assert_eq!(metrics.get("test_count").and_then(|v| v.as_i64()), Some(2));
+    assert_eq!(
+        metrics.get("doctest_count").and_then(|v| v.as_i64()),
+        Some(1)
+    );
+}
+
 /// Spec: Integration test on fixtures/rust-simple
 #[test]
 fn cargo_runner_on_rust_simple_fixture() {
@@ -304,3 +347,81 @@ fn auto_detection_runs_all_languages_in_polyglot_project() {
         .unwrap_or(false);
     assert!(cargo_passed, "Cargo suite should pass");
 }
+
+// =============================================================================
+// CONTAINER SPECS
+// =============================================================================
+
+/// A stand-in container engine: logs its arguments, then runs the command
+/// that follows the image.
+#[cfg(unix)]
+fn fake_engine(temp: &Project) -> String {
+    use std::os::unix::fs::PermissionsExt;
+
+    temp.file(
+        "engine.sh",
+        r#"#!/bin/sh
+echo "$@" >> "$(dirname "$0")/engine.log"
+while [ "$1" != "alpine:3" ]; do shift; done
+shift
+exec "$@"
+"#,
+    );
+    let engine = temp.path().join("engine.sh");
+    std::fs::set_permissions(&engine, std::fs::Permissions::from_mode(0o755)).unwrap();
+    engine.display().to_string()
+}
+
+/// Spec: docs/specs/11-test-runners.md#containers
+///
+/// > The setup command, test command, and coverage collection run via
+/// > `docker run` (or `podman run`) with the project root mounted at the same path
+#[cfg(unix)]
+#[test]
+fn container_suite_runs_setup_and_command_in_image() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[[check.tests.suite]]
+runner = "custom"
+command = "test -f built"
+setup = "touch built"
+container = "alpine:3"
+"#,
+    );
+    let engine = fake_engine(&temp);
+
+    check("tests")
+        .pwd(temp.path())
+        .env("QUENCH_CONTAINER_ENGINE", &engine)
+        .passes();
+
+    let log = std::fs::read_to_string(temp.path().join("engine.log")).unwrap();
+    assert!(log.contains("run --rm -v "), "log: {log}");
+    assert!(log.contains("alpine:3 sh -c touch built"), "log: {log}");
+    assert!(log.contains("alpine:3 sh -c test -f built"), "log: {log}");
+}
+
+/// Spec: docs/specs/11-test-runners.md#containers
+///
+/// > The setup command, test command, and coverage collection run via
+/// > `docker run` (or `podman run`)
+#[cfg(unix)]
+#[test]
+fn container_suite_fails_when_command_fails_in_image() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[[check.tests.suite]]
+runner = "custom"
+command = "exit 1"
+container = "alpine:3"
+"#,
+    );
+    let engine = fake_engine(&temp);
+
+    check("tests")
+        .pwd(temp.path())
+        .env("QUENCH_CONTAINER_ENGINE", &engine)
+        .fails();
+}
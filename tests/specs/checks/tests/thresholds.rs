@@ -231,6 +231,98 @@ fn test_covered() { assert_eq!(test_project::covered(), 42); }
     );
 }
 
+/// Spec: docs/specs/checks/tests.md#per-path-coverage
+///
+/// > Path thresholds apply to a glob over covered files
+#[test]
+fn path_coverage_violation_names_glob() {
+    let temp = Project::cargo("test_project");
+    temp.config(
+        r#"
+[[check.tests.suite]]
+runner = "cargo"
+
+[check.tests.coverage]
+check = "error"
+
+[check.tests.coverage.path."src/core/**"]
+min = 95
+"#,
+    );
+    temp.file("src/lib.rs", "pub mod core;\n");
+    temp.file(
+        "src/core/mod.rs",
+        r#"
+pub fn covered() -> i32 { 42 }
+pub fn uncovered() -> i32 { 0 }
+"#,
+    );
+    temp.file(
+        "tests/basic.rs",
+        r#"
+#[test]
+fn test_covered() { assert_eq!(test_project::core::covered(), 42); }
+"#,
+    );
+
+    let result = check("tests")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .json()
+        .fails();
+
+    let v = result.require_violation("coverage_below_min");
+    assert_eq!(v["file"], "<coverage:src/core/**>");
+    assert!(result.require("metrics")["coverage_by_path"]["src/core/**"].is_number());
+}
+
+/// Spec: docs/specs/checks/tests.md#patch-coverage
+///
+/// > Below `patch_min`, quench reports a `patch_coverage_below_min` violation
+/// > ... and a `patch_uncovered` violation per file
+#[test]
+fn patch_coverage_reports_uncovered_changed_lines() {
+    let temp = Project::cargo("test_project");
+    temp.config(
+        r#"
+[[check.tests.suite]]
+runner = "cargo"
+
+[check.tests.coverage]
+check = "error"
+patch_min = 80
+"#,
+    );
+    temp.file("src/lib.rs", "pub fn covered() -> i32 { 42 }\n");
+    temp.file(
+        "tests/basic.rs",
+        r#"
+#[test]
+fn test_covered() { assert_eq!(test_project::covered(), 42); }
+"#,
+    );
+    git_init(&temp);
+    git_initial_commit(&temp);
+
+    // The change adds only untested code
+    temp.file(
+        "src/lib.rs",
+        "pub fn covered() -> i32 { 42 }\n\npub fn added() -> i32 {\n    0\n}\n",
+    );
+
+    let result = check("tests")
+        .pwd(temp.path())
+        .args(&["--ci", "--base", "main"])
+        .json()
+        .fails();
+
+    let v = result.require_violation("patch_coverage_below_min");
+    assert_eq!(v["file"], "<coverage:patch>");
+    let v = result.require_violation("patch_uncovered");
+    assert_eq!(v["file"], "src/lib.rs");
+    assert!(result.require("metrics")["patch_coverage"].is_number());
+}
+
 /// Spec: docs/specs/checks/tests.md#test-time
 ///
 /// > Time violation includes test name for max_test exceeded.
@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the whitespace check.
+//!
+//! Tests that quench correctly:
+//! - Stays off unless configured
+//! - Reports wrong line endings, trailing whitespace, missing final newlines,
+//!   and wrong indentation
+//! - Normalizes files with --fix
+//!
+//! Reference: docs/specs/checks/whitespace.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+fn whitespace_project(config: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!(
+        "[check.whitespace]\ncheck = \"error\"\n{}",
+        config
+    ));
+    temp
+}
+
+// =============================================================================
+// DEFAULT BEHAVIOR SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/whitespace.md#purpose
+///
+/// > **Disabled by default.**
+#[test]
+fn whitespace_disabled_by_default() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("src/lib.rs", "pub fn f() {}   \r\n");
+
+    let whitespace = check("whitespace").pwd(temp.path()).json().passes();
+
+    assert!(whitespace.violations().is_empty());
+}
+
+// =============================================================================
+// VALIDATION RULE SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/whitespace.md#line-endings
+///
+/// > With `line_endings = "lf"` (default), lines ending in CRLF are violations.
+#[test]
+fn crlf_line_endings_generate_one_violation_per_file() {
+    let temp = whitespace_project("");
+    temp.file("src/lib.rs", "fn a() {}\r\nfn b() {}\nfn c() {}\r\n");
+
+    let whitespace = check("whitespace").pwd(temp.path()).json().fails();
+
+    let violation = whitespace.require_violation("line_ending");
+    assert_eq!(violation.get("line").and_then(|v| v.as_u64()), Some(1));
+    assert_eq!(violation.get("value").and_then(|v| v.as_i64()), Some(2));
+    assert_eq!(
+        violation.get("expected").and_then(|v| v.as_str()),
+        Some("lf")
+    );
+    assert_eq!(
+        violation.get("found").and_then(|v| v.as_str()),
+        Some("crlf")
+    );
+    assert_eq!(whitespace.violations().len(), 1);
+}
+
+/// Spec: docs/specs/checks/whitespace.md#trailing-whitespace
+///
+/// > Spaces or tabs before the line ending, including whitespace-only lines.
+#[test]
+fn trailing_whitespace_generates_violation() {
+    let temp = whitespace_project("");
+    temp.file("src/lib.rs", "fn a() {}\nfn b() {} \t\n");
+
+    let whitespace = check("whitespace").pwd(temp.path()).json().fails();
+
+    let violation = whitespace.require_violation("trailing_whitespace");
+    assert_eq!(violation.get("line").and_then(|v| v.as_u64()), Some(2));
+}
+
+/// Spec: docs/specs/checks/whitespace.md#final-newline
+///
+/// > Non-empty files must end with a newline.
+#[test]
+fn missing_final_newline_generates_violation() {
+    let temp = whitespace_project("");
+    temp.file("src/lib.rs", "fn a() {}\nfn b() {}");
+
+    check("whitespace")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("src/lib.rs:2: no newline at end of file");
+}
+
+/// Spec: docs/specs/checks/whitespace.md#indentation
+///
+/// > `[check.whitespace.languages]` overrides it per language
+#[test]
+fn indentation_policy_applies_per_language() {
+    let temp = whitespace_project(
+        r#"indent = "spaces"
+
+[check.whitespace.languages]
+go = "tabs"
+"#,
+    );
+    temp.file("main.go", "func main() {\n\tx()\n}\n");
+    temp.file("src/lib.rs", "fn a() {\n\tx();\n}\n");
+
+    let whitespace = check("whitespace").pwd(temp.path()).json().fails();
+
+    assert!(whitespace.has_violation_for_file("src/lib.rs"));
+    assert!(!whitespace.has_violation_for_file("main.go"));
+    let violation = whitespace.require_violation("wrong_indent");
+    assert_eq!(
+        violation.get("expected").and_then(|v| v.as_str()),
+        Some("spaces")
+    );
+}
+
+/// Spec: docs/specs/checks/whitespace.md#configuration
+///
+/// > check = "error"              # error | warn | off (default: off)
+#[test]
+fn warn_level_reports_without_failing() {
+    let temp = Project::empty();
+    temp.config("[check.whitespace]\ncheck = \"warn\"\n");
+    temp.file("src/lib.rs", "fn a() {}  \n");
+
+    let whitespace = check("whitespace").pwd(temp.path()).json().passes();
+
+    assert!(whitespace.has_violation("trailing_whitespace"));
+}
+
+// =============================================================================
+// FIX SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/whitespace.md#auto-fix---fix
+///
+/// > Rewrites each file with issues
+#[test]
+fn fix_normalizes_whitespace() {
+    let temp = whitespace_project("indent = \"spaces\"\ntab_width = 2\n");
+    temp.file("src/lib.rs", "fn a() {  \r\n\tx();\r\n}");
+
+    check("whitespace")
+        .pwd(temp.path())
+        .args(&["--fix"])
+        .passes()
+        .stdout_has("Normalized whitespace: 1 files");
+
+    let content = std::fs::read_to_string(temp.path().join("src/lib.rs")).unwrap();
+    assert_eq!(content, "fn a() {\n  x();\n}\n");
+}
+
+/// Spec: docs/specs/checks/whitespace.md#auto-fix---fix
+///
+/// > Use `--dry-run` to list the files that would be rewritten.
+#[test]
+fn fix_dry_run_leaves_files_unchanged() {
+    let temp = whitespace_project("");
+    temp.file("src/lib.rs", "fn a() {}  \n");
+
+    check("whitespace")
+        .pwd(temp.path())
+        .args(&["--fix", "--dry-run"])
+        .passes();
+
+    let content = std::fs::read_to_string(temp.path().join("src/lib.rs")).unwrap();
+    assert_eq!(content, "fn a() {}  \n");
+}
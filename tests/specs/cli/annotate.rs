@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench annotate` command.
+//!
+//! Reference: docs/specs/01-cli.md#quench-annotate
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Project with one committed escapes violation on line 1.
+fn project_with_committed_violation() -> Project {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[[check.escapes.patterns]]
+name = "panic"
+pattern = "panic!"
+action = "forbid"
+"#,
+    );
+    temp.file("src/lib.rs", "fn main() { panic!() }\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+    temp
+}
+
+/// Spec: docs/specs/01-cli.md#quench-annotate
+///
+/// > Attribute current violations to the author and commit that introduced
+/// > them, using `git blame` at HEAD
+#[test]
+fn annotate_attributes_last_run_violations() {
+    let temp = project_with_committed_violation();
+    quench_cmd()
+        .args(["check", "--no-cache", "--escapes"])
+        .current_dir(temp.path())
+        .assert()
+        .code(1);
+
+    quench_cmd()
+        .args(["annotate"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Attributed 1 of 1 violations (0 unattributed)",
+        ))
+        .stdout(predicates::str::contains(
+            "feat: initial commit: 1 violations",
+        ));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-annotate
+///
+/// > `--input <FILE>` | Check output JSON to annotate
+#[test]
+fn annotate_reads_input_json() {
+    let temp = project_with_committed_violation();
+    let output = quench_cmd()
+        .args(["check", "--no-cache", "--escapes", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    temp.file("out.json", &String::from_utf8(output.stdout).unwrap());
+
+    let output = quench_cmd()
+        .args(["annotate", "--input", "out.json", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["total"], 1);
+    assert_eq!(json["violations"][0]["file"], "src/lib.rs");
+    assert_eq!(json["violations"][0]["line"], 1);
+    assert_eq!(json["by_commit"][0]["summary"], "feat: initial commit");
+}
+
+/// Spec: docs/specs/01-cli.md#quench-annotate
+///
+/// > `-o, --output <FMT>` | `text` (default), `json`, `markdown`
+#[test]
+fn annotate_markdown_has_tables() {
+    let temp = project_with_committed_violation();
+    quench_cmd()
+        .args(["check", "--no-cache", "--escapes"])
+        .current_dir(temp.path())
+        .assert()
+        .code(1);
+
+    quench_cmd()
+        .args(["annotate", "-o", "markdown"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "| Author | Violations | Commits |",
+        ));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-annotate
+///
+/// > default: `.quench/latest.json`, written by every `quench check`
+#[test]
+fn annotate_without_recorded_run_explains() {
+    let temp = Project::empty();
+    temp.file("src/lib.rs", "fn main() {}\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+
+    quench_cmd()
+        .args(["annotate"])
+        .current_dir(temp.path())
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("run `quench check` first"));
+}
@@ -0,0 +1,318 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench baseline` command.
+//!
+//! Reference: docs/specs/01-cli.md#quench-baseline
+
+use crate::prelude::*;
+
+const FILE_CONFIG: &str = r#"
+[git]
+baseline = ".quench/baseline.json"
+"#;
+
+fn baseline_json(commit: &str) -> String {
+    format!(
+        r#"{{"version":1,"updated":"2026-01-20T00:00:00Z","commit":"{}","metrics":{{"escapes":{{"source":{{"unwrap":3}}}}}}}}"#,
+        commit
+    )
+}
+
+/// A repo whose `main` has committed a newer baseline than the checked-out
+/// `feature` branch.
+fn stale_feature_branch() -> Project {
+    let project = Project::empty();
+    project.config(FILE_CONFIG);
+    project.file(".quench/baseline.json", &baseline_json("old0000"));
+    git_init(&project);
+    git_initial_commit(&project);
+    project.file(".quench/baseline.json", &baseline_json("main123"));
+    git_add_all(&project);
+    git_commit(&project, "chore: update baseline");
+    std::process::Command::new("git")
+        .args(["checkout", "-b", "feature", "HEAD~1"])
+        .current_dir(project.path())
+        .output()
+        .unwrap();
+    project
+}
+
+/// Spec: docs/specs/01-cli.md#quench-baseline
+///
+/// > export reads the file as committed at REF
+#[test]
+fn baseline_export_reads_file_from_other_branch() {
+    let project = stale_feature_branch();
+
+    quench_cmd()
+        .args(["baseline", "export", "--ref", "main"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(r#""commit": "main123""#))
+        .stdout(predicates::str::contains(r#""unwrap": 3"#));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-baseline
+///
+/// > `export [--ref <REF>]` ... (default: `main`, else `master`)
+#[test]
+fn baseline_export_defaults_to_main() {
+    let project = stale_feature_branch();
+
+    quench_cmd()
+        .args(["baseline", "export"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(r#""commit": "main123""#));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-baseline
+///
+/// > import overwrites the working-tree file
+#[test]
+fn baseline_import_ref_overwrites_local_file() {
+    let project = stale_feature_branch();
+
+    quench_cmd()
+        .args(["baseline", "import", "--ref", "main"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Imported baseline from main (commit main123) into .quench/baseline.json",
+        ));
+
+    let local = std::fs::read_to_string(project.path().join(".quench/baseline.json")).unwrap();
+    assert!(local.contains("main123"), "got: {}", local);
+}
+
+/// Spec: docs/specs/01-cli.md#quench-baseline
+///
+/// > `import <FILE>` | Replace the local baseline with an exported file
+#[test]
+fn baseline_import_file_round_trips_export() {
+    let project = stale_feature_branch();
+    let exported = quench_cmd()
+        .args(["baseline", "export", "--ref", "main"])
+        .current_dir(project.path())
+        .output()
+        .unwrap();
+    project.file("main.json", &String::from_utf8_lossy(&exported.stdout));
+
+    quench_cmd()
+        .args(["baseline", "import", "main.json"])
+        .current_dir(project.path())
+        .assert()
+        .success();
+
+    let local = std::fs::read_to_string(project.path().join(".quench/baseline.json")).unwrap();
+    assert!(local.contains("main123"), "got: {}", local);
+}
+
+/// Spec: docs/specs/01-cli.md#quench-baseline
+///
+/// > With `baseline = "notes"`, export reads REF's `refs/notes/quench` note,
+/// > and import writes the note for HEAD.
+#[test]
+fn baseline_import_copies_notes_to_head() {
+    let project = Project::empty();
+    project.config("");
+    git_init(&project);
+    git_initial_commit(&project);
+    git_add_note(&project, &baseline_json("main123"));
+    git_branch(&project, "feature");
+    git_commit(&project, "feat: work");
+
+    quench_cmd()
+        .args(["baseline", "import", "--ref", "main"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("into git notes for HEAD"));
+
+    let note = git_read_note(&project).unwrap();
+    assert!(note.contains("main123"), "got: {}", note);
+}
+
+/// Spec: docs/specs/01-cli.md#quench-baseline
+///
+/// > Exits 2 if REF doesn't resolve or has no baseline
+#[test]
+fn baseline_export_fails_without_baseline_at_ref() {
+    let project = Project::empty();
+    project.config(FILE_CONFIG);
+    git_init(&project);
+    git_initial_commit(&project);
+
+    quench_cmd()
+        .args(["baseline", "export", "--ref", "main"])
+        .current_dir(project.path())
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains(
+            "no baseline at main (.quench/baseline.json)",
+        ));
+
+    quench_cmd()
+        .args(["baseline", "export", "--ref", "nope"])
+        .current_dir(project.path())
+        .assert()
+        .code(2);
+}
+
+/// Import `baseline_json` variants with `unwrap` counts, returning snapshot IDs.
+fn import_unwrap_counts(project: &Project, counts: &[u32]) -> Vec<String> {
+    for count in counts {
+        project.file(
+            "next.json",
+            &format!(
+                r#"{{"version":1,"updated":"2026-01-20T00:00:00Z","metrics":{{"escapes":{{"source":{{"unwrap":{}}}}}}}}}"#,
+                count
+            ),
+        );
+        quench_cmd()
+            .args(["baseline", "import", "next.json"])
+            .current_dir(project.path())
+            .assert()
+            .success();
+    }
+    let output = quench_cmd()
+        .args(["baseline", "history", "-o", "json"])
+        .current_dir(project.path())
+        .output()
+        .unwrap();
+    let history: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    history
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// Spec: docs/specs/01-cli.md#quench-baseline
+///
+/// > Every baseline save (`quench check --fix`, ...) also writes a snapshot to
+/// > `.quench/history/<id>.json`
+#[test]
+fn baseline_fix_records_history_snapshot() {
+    let project = Project::empty();
+    project.config(FILE_CONFIG);
+    project.file(
+        "CLAUDE.md",
+        "# Project\n\n## Directory Structure\n\nLayout.\n\n## Landing the Plane\n\n- Done\n",
+    );
+
+    quench_cmd()
+        .args(["check", "--fix"])
+        .current_dir(project.path())
+        .assert()
+        .success();
+
+    quench_cmd()
+        .args(["baseline", "history"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("first snapshot"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-baseline
+///
+/// > `--metric` ... lists only the snapshots that moved that metric
+#[test]
+fn baseline_history_shows_when_metric_moved() {
+    let project = Project::empty();
+    project.config(FILE_CONFIG);
+    import_unwrap_counts(&project, &[3, 3, 5]);
+
+    let output = quench_cmd()
+        .args(["baseline", "history", "--metric", "escapes.source.unwrap"])
+        .current_dir(project.path())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // The unchanged second import is left out
+    assert!(stdout.contains("escapes.source.unwrap: - -> 3"), "{stdout}");
+    assert!(stdout.contains("escapes.source.unwrap: 3 -> 5"), "{stdout}");
+    assert_eq!(
+        stdout.matches("escapes.source.unwrap").count(),
+        2,
+        "{stdout}"
+    );
+}
+
+/// Spec: docs/specs/01-cli.md#quench-baseline
+///
+/// > After an accidental `--fix`, `update --from` restores an earlier snapshot
+#[test]
+fn baseline_update_from_restores_snapshot() {
+    let project = Project::empty();
+    project.config(FILE_CONFIG);
+    let ids = import_unwrap_counts(&project, &[3, 9]);
+
+    quench_cmd()
+        .args(["baseline", "update", "--from", &ids[0]])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(format!(
+            "Restored baseline snapshot {} into .quench/baseline.json",
+            ids[0]
+        )));
+
+    quench_cmd()
+        .args(["baseline", "show"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("escapes.source.unwrap = 3"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-baseline
+///
+/// > Exits 2 ... if SNAPSHOT isn't in the history.
+#[test]
+fn baseline_show_unknown_snapshot_exits_2() {
+    let project = Project::empty();
+    project.config(FILE_CONFIG);
+
+    quench_cmd()
+        .args(["baseline", "show", "20260120T093000.000Z"])
+        .current_dir(project.path())
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains(
+            "no baseline snapshot 20260120T093000.000Z",
+        ));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-baseline
+///
+/// > `prune [--keep <N>] [--older-than <DAYS>]` | Delete all but the newest N
+/// > snapshots
+#[test]
+fn baseline_prune_keeps_newest_snapshots() {
+    let project = Project::empty();
+    project.config(FILE_CONFIG);
+    let ids = import_unwrap_counts(&project, &[1, 2, 3]);
+
+    quench_cmd()
+        .args(["baseline", "prune", "--keep", "1"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Deleted 2 baseline snapshots, kept 1",
+        ));
+
+    quench_cmd()
+        .args(["baseline", "show", &ids[2]])
+        .current_dir(project.path())
+        .assert()
+        .success();
+}
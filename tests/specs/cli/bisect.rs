@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench bisect` command.
+//!
+//! Reference: docs/specs/01-cli.md#quench-bisect
+
+use crate::prelude::*;
+
+const TODO_CONFIG: &str = r#"
+[[check.escapes.patterns]]
+name = "todo"
+pattern = "TODO"
+action = "count"
+
+[check.agents]
+check = "off"
+"#;
+
+fn short_hash(project: &Project, rev: &str) -> String {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "--short=7", rev])
+        .current_dir(project.path())
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn git_tag(project: &Project, name: &str) {
+    std::process::Command::new("git")
+        .args(["tag", name])
+        .current_dir(project.path())
+        .output()
+        .unwrap();
+}
+
+/// A repo tagged `v1` with one TODO, where `feat: add todo` adds a second
+/// between unrelated commits. Returns the short hash of that commit.
+fn todo_history(project: &Project) -> String {
+    project.config(TODO_CONFIG);
+    project.file("src/lib.rs", "// TODO: one\npub fn f() {}\n");
+    git_init(project);
+    git_initial_commit(project);
+    git_tag(project, "v1");
+    git_commit(project, "chore: one");
+    git_commit(project, "chore: two");
+    project.file("src/lib.rs", "// TODO: one\n// TODO: two\npub fn f() {}\n");
+    git_add_all(project);
+    std::process::Command::new("git")
+        .args(["commit", "-m", "feat: add todo"])
+        .current_dir(project.path())
+        .output()
+        .unwrap();
+    let culprit = short_hash(project, "HEAD");
+    git_commit(project, "chore: three");
+    culprit
+}
+
+/// Spec: docs/specs/01-cli.md#quench-bisect
+///
+/// > Without `--above` or `--below`, the threshold is the metric's value at
+/// > `--from` ... checking the commit out into a temporary `git worktree`
+#[test]
+fn bisect_finds_commit_where_metric_rose() {
+    let project = Project::empty();
+    let culprit = todo_history(&project);
+
+    quench_cmd()
+        .args(["bisect", "--metric", "escapes.source.todo", "--from", "v1"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(format!(
+            "escapes.source.todo went above 1 at {}: feat: add todo",
+            culprit
+        )))
+        .stdout(predicates::str::contains("1 -> 2"))
+        .stderr(predicates::str::contains("(measured)"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-bisect
+///
+/// > then from `.quench/bisect/` (results of earlier bisects)
+#[test]
+fn bisect_reuses_cached_results() {
+    let project = Project::empty();
+    todo_history(&project);
+    let bisect = || {
+        quench_cmd()
+            .args(["bisect", "--metric", "escapes.source.todo", "--from", "v1"])
+            .current_dir(project.path())
+            .assert()
+            .success()
+    };
+
+    bisect();
+    bisect()
+        .stderr(predicates::str::contains("(cache)"))
+        .stderr(predicates::str::contains("(measured)").not());
+}
+
+/// Spec: docs/specs/01-cli.md#quench-bisect
+///
+/// > Each probed commit's value comes from its `refs/notes/quench` baseline
+/// > when there is one
+#[test]
+fn bisect_reads_values_from_git_notes() {
+    let project = Project::empty();
+    project.config("");
+    git_init(&project);
+    git_initial_commit(&project);
+    for (i, count) in [0, 0, 3, 4].iter().enumerate() {
+        if i > 0 {
+            git_commit(&project, &format!("feat: step {}", i));
+        }
+        git_add_note(
+            &project,
+            &format!(
+                r#"{{"version":1,"updated":"2026-01-01T00:00:00Z","metrics":{{"escapes":{{"source":{{"unwrap":{}}}}}}}}}"#,
+                count
+            ),
+        );
+    }
+    let culprit = short_hash(&project, "HEAD~1");
+
+    quench_cmd()
+        .args(["bisect", "--metric", "escapes.source.unwrap"])
+        .args(["--from", "HEAD~3", "--above", "2"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(format!(
+            "escapes.source.unwrap went above 2 at {}: feat: step 2",
+            culprit
+        )))
+        .stderr(predicates::str::contains("(notes)"))
+        .stderr(predicates::str::contains("(measured)").not());
+}
+
+/// Spec: docs/specs/01-cli.md#quench-bisect
+///
+/// > Fails with exit 2 if the metric is already crossed at `--from`.
+#[test]
+fn bisect_rejects_threshold_already_crossed() {
+    let project = Project::empty();
+    todo_history(&project);
+
+    quench_cmd()
+        .args(["bisect", "--metric", "escapes.source.todo"])
+        .args(["--from", "v1", "--above", "0"])
+        .current_dir(project.path())
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("already above 0 at v1"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-bisect
+///
+/// > If it never crosses by `--to`, says so and exits 0.
+#[test]
+fn bisect_reports_metric_that_never_crossed() {
+    let project = Project::empty();
+    todo_history(&project);
+
+    quench_cmd()
+        .args(["bisect", "--metric", "escapes.source.todo"])
+        .args(["--from", "v1", "--above", "5"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "escapes.source.todo never went above 5 between v1 and HEAD",
+        ));
+}
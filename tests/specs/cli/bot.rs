@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench bot` command.
+//!
+//! Reference: docs/specs/01-cli.md#quench-bot
+
+use crate::prelude::*;
+
+fn rev_parse(project: &Project, rev: &str) -> String {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", rev])
+        .current_dir(project.path())
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// An origin repository with `main` and a `feature` branch whose head
+/// adds `feature_file`, plus a `pull_request` payload for it.
+fn pull_request(feature_file: &str, content: &str) -> (Project, String) {
+    let origin = Project::empty();
+    origin.config("[check.cloc]\nmax_lines = 5\n\n[check.agents]\ncheck = \"off\"\n");
+    origin.file("src/lib.rs", "pub fn f() {}\n");
+    git_init(&origin);
+    git_initial_commit(&origin);
+    git_branch(&origin, "feature");
+    origin.file(feature_file, content);
+    git_add_all(&origin);
+    git_commit(&origin, "feat: add feature");
+
+    let payload = format!(
+        r#"{{
+            "action": "opened",
+            "number": 7,
+            "pull_request": {{
+                "head": {{ "ref": "feature", "sha": "{head}", "repo": null }},
+                "base": {{ "ref": "main", "sha": "{base}", "repo": null }}
+            }},
+            "repository": {{ "full_name": "acme/app", "clone_url": "{url}" }}
+        }}"#,
+        head = rev_parse(&origin, "feature"),
+        base = rev_parse(&origin, "main"),
+        url = origin.path().display(),
+    );
+    origin.file("event.json", &payload);
+    (origin, payload)
+}
+
+/// Spec: docs/specs/01-cli.md#quench-bot
+///
+/// > runs `quench check --base <base sha>` on the head and posts a `quench`
+/// > commit status plus a markdown comment with the check results
+#[test]
+fn bot_reports_failing_pull_request() {
+    let (origin, _) = pull_request("src/big.rs", &"pub fn g() {}\n".repeat(10));
+    let work = tempfile::tempdir().unwrap();
+
+    quench_cmd()
+        .args(["bot", "--dry-run", "--event", "event.json", "--work-dir"])
+        .arg(work.path())
+        .current_dir(origin.path())
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains(
+            "status: pending - Running quench check",
+        ))
+        .stdout(predicates::str::contains("<!-- quench -->"))
+        .stdout(predicates::str::contains("### quench: FAIL"))
+        .stdout(predicates::str::contains("| cloc | FAIL | 1 |"))
+        .stdout(predicates::str::contains(
+            "- `src/big.rs` cloc **file_too_large**",
+        ))
+        .stdout(predicates::str::contains("status: failure - 1 of"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-bot
+///
+/// > The status is `success` when every check passes
+#[test]
+fn bot_reports_passing_pull_request() {
+    let (origin, _) = pull_request("src/small.rs", "pub fn g() {}\n");
+    let work = tempfile::tempdir().unwrap();
+
+    quench_cmd()
+        .args(["bot", "--dry-run", "--event", "-", "--work-dir"])
+        .arg(work.path())
+        .current_dir(origin.path())
+        .stdin(std::fs::File::open(origin.path().join("event.json")).unwrap())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("### quench: PASS"))
+        .stdout(predicates::str::contains(
+            "status: success - All checks passed",
+        ));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-bot
+///
+/// > Other pull request actions (closed, labeled, ...) are ignored
+#[test]
+fn bot_ignores_closed_pull_request() {
+    let (origin, payload) = pull_request("src/small.rs", "pub fn g() {}\n");
+    origin.file("event.json", &payload.replace("\"opened\"", "\"closed\""));
+
+    quench_cmd()
+        .args(["bot", "--dry-run", "--event", "event.json"])
+        .current_dir(origin.path())
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("ignoring 'closed' on acme/app#7"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-bot
+///
+/// > Serving webhooks requires `QUENCH_WEBHOOK_SECRET`
+#[test]
+fn bot_server_requires_webhook_secret() {
+    let temp = Project::empty();
+
+    quench_cmd()
+        .args(["bot", "--dry-run"])
+        .current_dir(temp.path())
+        .env_remove("QUENCH_WEBHOOK_SECRET")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "QUENCH_WEBHOOK_SECRET must be set",
+        ));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-bot
+///
+/// > Pull requests from forks are refused unless `--allow-fork` names
+/// > their owner
+#[test]
+fn bot_refuses_fork_pull_request() {
+    let (origin, payload) = pull_request("src/small.rs", "pub fn g() {}\n");
+    let fork = r#"{ "full_name": "stranger/app", "clone_url": "https://example.com/app.git" }"#;
+    origin.file(
+        "event.json",
+        &payload.replacen("\"repo\": null", &format!("\"repo\": {}", fork), 1),
+    );
+    let work = tempfile::tempdir().unwrap();
+
+    quench_cmd()
+        .args(["bot", "--dry-run", "--event", "event.json", "--work-dir"])
+        .arg(work.path())
+        .current_dir(origin.path())
+        .assert()
+        .code(3)
+        .stdout(predicates::str::contains(
+            "status: error - Not checked: stranger/app is not an allowed fork",
+        ))
+        .stdout(predicates::str::contains("Running quench check").not());
+}
+
+/// Spec: docs/specs/01-cli.md#quench-bot
+///
+/// > Checks run with the base commit's `quench.toml` files
+#[test]
+fn bot_checks_with_base_config() {
+    let (origin, _) = pull_request("src/big.rs", &"pub fn g() {}\n".repeat(10));
+    git_checkout(&origin, "feature");
+    origin.config("[check.cloc]\nmax_lines = 100\n\n[check.agents]\ncheck = \"off\"\n");
+    git_add_all(&origin);
+    git_commit(&origin, "chore: raise the limit");
+    let head = rev_parse(&origin, "feature");
+    let payload = std::fs::read_to_string(origin.path().join("event.json")).unwrap();
+    let old_head = payload.split("\"sha\": \"").nth(1).unwrap();
+    let old_head = &old_head[..old_head.find('"').unwrap()];
+    origin.file("event.json", &payload.replace(old_head, &head));
+
+    let run = |trust: bool| {
+        let work = tempfile::tempdir().unwrap();
+        let mut cmd = quench_cmd();
+        cmd.args(["bot", "--dry-run", "--event", "event.json", "--work-dir"])
+            .arg(work.path())
+            .current_dir(origin.path());
+        if trust {
+            cmd.arg("--trust-config");
+        }
+        cmd.assert()
+    };
+    run(false)
+        .code(1)
+        .stdout(predicates::str::contains("| cloc | FAIL | 1 |"));
+    run(true)
+        .success()
+        .stdout(predicates::str::contains("### quench: PASS"));
+}
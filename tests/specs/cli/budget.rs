@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for time-boxed checking with --budget.
+//!
+//! Reference: docs/specs/01-cli.md#budget
+//!
+//! NOTE: These tests use quench_cmd() directly because the CheckBuilder
+//! always adds --no-cache, and timing history lives in the cache.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use crate::prelude::*;
+
+fn slow_tests_project() -> Project {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[[check.tests.suite]]
+runner = "custom"
+command = "sleep 0.3"
+"#,
+    );
+    temp
+}
+
+/// Spec: docs/specs/01-cli.md#budget
+///
+/// > Checks without timing history always run.
+#[test]
+fn budget_without_history_runs_all_checks() {
+    let temp = slow_tests_project();
+    quench_cmd()
+        .args(["check", "--tests", "--ci", "--budget", "0ms"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("SKIP").not());
+}
+
+/// Spec: docs/specs/01-cli.md#budget
+///
+/// > Checks that don't fit are reported as skipped (`SKIP`) with their last cost
+#[test]
+fn budget_skips_checks_over_their_last_cost() {
+    let temp = slow_tests_project();
+    quench_cmd()
+        .args(["check", "--tests", "--ci"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    let output = quench_cmd()
+        .args([
+            "check", "--tests", "--ci", "--budget", "100ms", "-o", "json",
+        ])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let tests = &json["checks"][0];
+    assert_eq!(tests["name"], "tests");
+    assert_eq!(tests["skipped"], true);
+    assert!(
+        tests["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("over --budget 100ms (last run ")
+    );
+
+    quench_cmd()
+        .args(["check", "--tests", "--ci", "--budget", "10s"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("SKIP").not());
+}
+
+/// Spec: docs/specs/01-cli.md#budget
+///
+/// > Invalid durations exit with code 2.
+#[test]
+fn invalid_budget_is_config_error() {
+    let temp = default_project();
+    quench_cmd()
+        .args(["check", "--budget", "soon"])
+        .current_dir(temp.path())
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("invalid --budget"));
+}
+
+/// Spec: docs/specs/01-cli.md#budget
+///
+/// > `--budget` can't be combined with `--no-cache` or `--check-determinism`
+#[test]
+fn budget_without_cache_is_config_error() {
+    let temp = default_project();
+    for flag in ["--no-cache", "--check-determinism"] {
+        quench_cmd()
+            .args(["check", "--budget", "10s", flag])
+            .current_dir(temp.path())
+            .assert()
+            .code(2)
+            .stderr(predicates::str::contains("--budget"));
+    }
+}
@@ -8,6 +8,7 @@
 //! - --ci disables violation limit
 //! - --ci auto-detects base branch
 //! - --save writes metrics to file
+//! - CI environment variables turn on CI mode unless --no-ci
 //!
 //! Reference: docs/specs/01-cli.md#scope-flags
 
@@ -394,3 +395,61 @@ fn check_writes_latest_json_cache() {
     );
     assert!(json.get("output").is_some(), "should have output");
 }
+
+// =============================================================================
+// CI DETECTION
+// =============================================================================
+
+/// Spec: docs/specs/01-cli.md#ci-mode
+///
+/// > `quench check` turns on CI mode by itself when a standard CI variable is set
+#[test]
+fn ci_variable_turns_on_ci_mode() {
+    let temp = default_project();
+
+    let result = cli()
+        .pwd(temp.path())
+        .env("GITHUB_ACTIONS", "true")
+        .json()
+        .passes();
+    assert_eq!(result.value()["ci"], "inferred");
+
+    let result = cli().pwd(temp.path()).args(&["--ci"]).json().passes();
+    assert_eq!(result.value()["ci"], "explicit");
+
+    let result = cli().pwd(temp.path()).json().passes();
+    assert!(result.value().get("ci").is_none());
+}
+
+/// Spec: docs/specs/01-cli.md#ci-mode
+///
+/// > Values of `0`, `false`, or empty don't count. Pass `--no-ci` to stay in
+/// > fast mode.
+#[test]
+fn no_ci_and_false_values_keep_fast_mode() {
+    let temp = default_project();
+
+    let result = cli()
+        .pwd(temp.path())
+        .env("CI", "true")
+        .args(&["--no-ci"])
+        .json()
+        .passes();
+    assert!(result.value().get("ci").is_none());
+
+    let result = cli().pwd(temp.path()).env("CI", "false").json().passes();
+    assert!(result.value().get("ci").is_none());
+}
+
+/// Spec: docs/specs/01-cli.md#ci-mode
+///
+/// > Verbose output names the variable
+#[test]
+fn inferred_ci_mode_names_the_variable() {
+    let temp = default_project();
+    cli()
+        .pwd(temp.path())
+        .env("CI", "1")
+        .passes()
+        .stderr_has("CI mode: CI is set (--no-ci to opt out)");
+}
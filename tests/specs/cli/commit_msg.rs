@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for `quench check-commit-msg`.
+//!
+//! Reference: docs/specs/01-cli.md#quench-check-commit-msg
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use crate::prelude::*;
+
+fn check_commit_msg(temp: &Project, message: &str) -> assert_cmd::assert::Assert {
+    temp.file("COMMIT_EDITMSG", message);
+    quench_cmd()
+        .args(["check-commit-msg", "COMMIT_EDITMSG"])
+        .current_dir(temp.path())
+        .assert()
+}
+
+/// Spec: docs/specs/01-cli.md#quench-check-commit-msg
+///
+/// > Comment lines (`#`) and the `git commit -v` diff are ignored
+#[test]
+fn valid_message_passes() {
+    let temp = default_project();
+    check_commit_msg(
+        &temp,
+        "feat(api): add endpoint\n\n# Please enter the commit message\n",
+    )
+    .success();
+}
+
+/// Spec: docs/specs/01-cli.md#quench-check-commit-msg
+///
+/// > Problems are listed as `file:line: type` with what to fix, and the command exits 1
+#[test]
+fn invalid_message_fails_with_location() {
+    let temp = default_project();
+    temp.config("[git.commit]\ntypes = [\"feat\", \"fix\"]\n");
+    check_commit_msg(&temp, "# comment\nchore: tidy\n")
+        .code(1)
+        .stderr(predicates::str::contains(
+            "COMMIT_EDITMSG:2: invalid_type: \"chore: tidy\"",
+        ))
+        .stderr(predicates::str::contains("Allowed types: feat, fix"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-check-commit-msg
+///
+/// > With `check = "warn"` problems are printed but the commit proceeds
+#[test]
+fn warn_level_prints_but_passes() {
+    let temp = default_project();
+    temp.config("[git.commit]\ncheck = \"warn\"\n");
+    check_commit_msg(&temp, "update stuff\n")
+        .success()
+        .stderr(predicates::str::contains("invalid_format"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-check-commit-msg
+///
+/// > with `check = "off"` or `format = "none"` the command does nothing
+#[test]
+fn disabled_check_accepts_anything() {
+    let temp = default_project();
+    temp.config("[git.commit]\nformat = \"none\"\n");
+    check_commit_msg(&temp, "update stuff\n").success();
+}
+
+/// Spec: docs/specs/checks/git.md#scope-inference
+///
+/// > `quench check-commit-msg` applies the same rule to the staged files
+#[test]
+fn enforced_scope_checks_staged_files() {
+    let temp = default_project();
+    temp.config(
+        "[project]\npackages = [\"crates/cli\", \"crates/core\"]\n\n[git.commit]\ninfer_scope = \"enforce\"\n",
+    );
+    git_init(&temp);
+    git_initial_commit(&temp);
+    temp.file("crates/cli/src/parser.rs", "pub fn parse() {}\n");
+    git_add_all(&temp);
+    check_commit_msg(&temp, "feat: add parser\n")
+        .code(1)
+        .stderr(predicates::str::contains(
+            "COMMIT_EDITMSG:1: scope_mismatch: \"feat: add parser\"",
+        ))
+        .stderr(predicates::str::contains("use: feat(cli): add parser"));
+}
@@ -110,7 +110,20 @@ fn supports_language_aliases() {
 #[test]
 fn supports_check_names() {
     for check in &[
-        "agents", "build", "cloc", "docs", "escapes", "git", "license", "tests",
+        "agents",
+        "build",
+        "cloc",
+        "docs",
+        "escapes",
+        "git",
+        "license",
+        "tests",
+        "whitespace",
+        "format",
+        "lint",
+        "snapshots",
+        "deadcode",
+        "deps",
     ] {
         let output = quench_cmd().args(["config", check]).assert().success();
         let stdout = String::from_utf8_lossy(&output.get_output().stdout);
@@ -166,6 +179,71 @@ fn feature_names_are_case_insensitive() {
     );
 }
 
+// =============================================================================
+// Generated Reference
+// =============================================================================
+
+/// Spec: docs/specs/01-cli.md#reference
+///
+/// > `quench config docs --output md` prints the reference for every key in
+/// > `quench.toml`. The generated reference is committed as
+/// > docs/config-reference.md.
+#[test]
+fn docs_output_md_matches_committed_reference() {
+    let committed = std::fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../docs/config-reference.md"
+    ))
+    .expect("docs/config-reference.md should exist");
+
+    let output = quench_cmd()
+        .args(["config", "docs", "--output", "md"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8_lossy(&output.get_output().stdout);
+    assert!(
+        stdout == committed,
+        "docs/config-reference.md is stale; regenerate it with \
+         `quench config docs --output md > docs/config-reference.md`"
+    );
+}
+
+/// Spec: docs/specs/01-cli.md#reference
+///
+/// > `--output json` prints the JSON Schema it is built from.
+#[test]
+fn docs_output_json_prints_schema() {
+    let output = quench_cmd()
+        .args(["config", "docs", "-o", "json"])
+        .assert()
+        .success();
+    let schema: serde_json::Value =
+        serde_json::from_slice(&output.get_output().stdout).expect("valid JSON");
+    assert_eq!(schema["title"], "Config");
+    assert!(schema["properties"]["check"].is_object());
+}
+
+/// Spec: docs/specs/01-cli.md#reference
+///
+/// > Without `--output`, `quench config docs` shows the docs check guide.
+#[test]
+fn docs_without_output_shows_guide() {
+    let output = quench_cmd().args(["config", "docs"]).assert().success();
+    let stdout = String::from_utf8_lossy(&output.get_output().stdout);
+    assert!(!stdout.contains("# Configuration Reference"));
+}
+
+/// Spec: `--output` is only for `quench config docs`
+#[test]
+fn output_rejected_for_other_features() {
+    let output = quench_cmd()
+        .args(["config", "rust", "-o", "md"])
+        .assert()
+        .failure();
+    let stderr = String::from_utf8_lossy(&output.get_output().stderr);
+    assert!(stderr.contains("--output is only supported with `quench config docs`"));
+}
+
 // =============================================================================
 // Help Output
 // =============================================================================
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench doctor` command.
+//!
+//! Reference: docs/specs/01-cli.md#quench-doctor
+
+use crate::prelude::*;
+
+/// Spec: docs/specs/01-cli.md#quench-doctor
+///
+/// > Each pinned tool prints `ok` with its installed version, or `FAIL` with
+/// > the mismatch and an install command
+#[test]
+fn doctor_suggests_install_for_mismatched_tool() {
+    let temp = Project::empty();
+    temp.config("[tools]\ncargo = \"0.1\"\n");
+
+    quench_cmd()
+        .arg("doctor")
+        .current_dir(temp.path())
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("FAIL  cargo"))
+        .stdout(predicates::str::contains(
+            "install: rustup toolchain install 0.1 && rustup override set 0.1",
+        ));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-doctor
+///
+/// > Each pinned tool prints `ok` with its installed version
+#[test]
+fn doctor_passes_when_pins_match() {
+    let temp = Project::empty();
+    let version = std::process::Command::new("cargo")
+        .arg("--version")
+        .output()
+        .unwrap();
+    let version = String::from_utf8_lossy(&version.stdout);
+    let major = version
+        .split_whitespace()
+        .nth(1)
+        .and_then(|v| v.split('.').next())
+        .unwrap()
+        .to_string();
+    temp.config(&format!("[tools]\ncargo = \"{}\"\n", major));
+
+    quench_cmd()
+        .args(["doctor", "-o", "json"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("\"passed\": true"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-doctor
+///
+/// > Without pins, it suggests a `[tools]` section pinning the installed versions
+#[test]
+fn doctor_without_pins_suggests_tools_section() {
+    let temp = Project::empty();
+
+    quench_cmd()
+        .arg("doctor")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("No tool versions pinned."))
+        .stdout(predicates::str::contains("[tools]\n"));
+}
@@ -9,6 +9,7 @@
 //! - Shows diff of proposed changes
 //! - Exits 0 even when fixes needed
 //! - Does not modify any files
+//! - Previews baseline changes as a diff
 //!
 //! Reference: docs/specs/01-cli.md#output-flags
 
@@ -226,3 +227,93 @@ sections.required = []
         "preview should have source"
     );
 }
+
+// =============================================================================
+// BASELINE PREVIEW SPECS
+// =============================================================================
+
+const RATCHET_CONFIG: &str = r#"
+[git]
+baseline = ".quench/baseline.json"
+
+[ratchet]
+check = "error"
+escapes = true
+
+[[check.escapes.patterns]]
+name = "unsafe"
+pattern = "unsafe"
+action = "count"
+threshold = 100
+"#;
+
+const BASELINE: &str = r#"{
+  "version": 1,
+  "updated": "2026-01-20T00:00:00Z",
+  "metrics": {
+    "escapes": {
+      "source": { "unsafe": 5 }
+    }
+  }
+}"#;
+
+fn ratchet_project() -> Project {
+    let temp = default_project();
+    temp.config(RATCHET_CONFIG);
+    temp.file(".quench/baseline.json", BASELINE);
+    temp.file("src/lib.rs", "fn f() {\n    unsafe {}\n    unsafe {}\n}");
+    temp
+}
+
+/// Spec: docs/specs/04-ratcheting.md#previewing-baseline-updates
+///
+/// > `quench check --fix --dry-run` leaves the baseline untouched and prints,
+/// > to stderr, a unified diff of the metrics `--fix` would store.
+#[test]
+fn dry_run_previews_baseline_diff() {
+    let temp = ratchet_project();
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--fix", "--dry-run"])
+        .passes()
+        .stderr_has("ratchet: would update baseline (.quench/baseline.json)")
+        .stderr_has("  --- .quench/baseline.json (current)")
+        .stderr_has("  +++ .quench/baseline.json (after --fix)")
+        .stderr_has("  -escapes.source.unsafe: 5\n  +escapes.source.unsafe: 2 (-3)");
+}
+
+/// Spec: docs/specs/04-ratcheting.md#previewing-baseline-updates
+///
+/// > `quench check --fix --dry-run` leaves the baseline untouched.
+#[test]
+fn dry_run_does_not_update_baseline() {
+    let temp = ratchet_project();
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--fix", "--dry-run"])
+        .passes()
+        .stderr_lacks("updated baseline");
+
+    let content = std::fs::read_to_string(temp.path().join(".quench/baseline.json")).unwrap();
+    assert_eq!(content, BASELINE, "baseline should not be modified");
+}
+
+/// Spec: docs/specs/04-ratcheting.md#previewing-baseline-updates
+///
+/// > Metrics the baseline doesn't have yet show only a `+` line.
+#[test]
+fn dry_run_previews_initial_baseline() {
+    let temp = ratchet_project();
+    std::fs::remove_file(temp.path().join(".quench/baseline.json")).unwrap();
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--fix", "--dry-run"])
+        .passes()
+        .stderr_has("  +escapes.source.unsafe: 2\n")
+        .stderr_lacks("  -escapes");
+
+    assert!(!temp.path().join(".quench/baseline.json").exists());
+}
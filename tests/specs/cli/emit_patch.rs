@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the --emit-patch flag.
+//!
+//! Tests that `quench check --fix --emit-patch FILE`:
+//! - Requires --fix and rejects --dry-run
+//! - Writes agent syncs, license headers, and whitespace fixes as one diff
+//! - Leaves the working tree and baseline untouched
+//! - Produces a patch `git apply` accepts
+//!
+//! Reference: docs/specs/01-cli.md#output-flags
+
+use crate::prelude::*;
+
+const CONFIG: &str = r#"
+[check.agents]
+files = ["CLAUDE.md", ".cursorrules"]
+sync = true
+sync_from = "CLAUDE.md"
+
+[check.license]
+check = "error"
+license = "MIT"
+copyright = "Test Org"
+
+[check.license.patterns]
+rust = ["**/*.rs"]
+
+[check.whitespace]
+check = "error"
+"#;
+
+const SOURCE: &str =
+    "# Source\n\n## Directory Structure\n\nLayout.\n\n## Landing the Plane\n\n- Done\n";
+const TARGET: &str = "# Target\n\n## Different\n\nContent B\n";
+const LIB: &str = "pub fn hello() {}\n";
+const MAIN: &str =
+    "// SPDX-License-Identifier: MIT\n// Copyright (c) 2026 Test Org\n\nfn main() {}  \n";
+
+fn fixable_project() -> Project {
+    let temp = Project::empty();
+    temp.config(CONFIG);
+    temp.file("CLAUDE.md", SOURCE);
+    temp.file(".cursorrules", TARGET);
+    temp.file("src/lib.rs", LIB);
+    temp.file("src/main.rs", MAIN);
+    temp
+}
+
+fn read(temp: &Project, path: &str) -> String {
+    std::fs::read_to_string(temp.path().join(path)).unwrap()
+}
+
+// =============================================================================
+// ERROR HANDLING SPECS
+// =============================================================================
+
+/// Spec: docs/specs/01-cli.md#output-flags
+///
+/// > Using --emit-patch without --fix is an error.
+#[test]
+fn emit_patch_without_fix_is_error() {
+    let temp = fixable_project();
+    cli()
+        .pwd(temp.path())
+        .args(&["--emit-patch", "fixes.patch"])
+        .exits(2)
+        .stderr_has("--emit-patch only works with --fix");
+    assert!(!temp.path().join("fixes.patch").exists());
+}
+
+/// Spec: docs/specs/01-cli.md#output-flags
+///
+/// > --emit-patch can't be combined with --dry-run.
+#[test]
+fn emit_patch_with_dry_run_is_error() {
+    let temp = fixable_project();
+    cli()
+        .pwd(temp.path())
+        .args(&["--fix", "--dry-run", "--emit-patch", "fixes.patch"])
+        .exits(2)
+        .stderr_has("--emit-patch and --dry-run cannot be used together");
+}
+
+// =============================================================================
+// PATCH SPECS
+// =============================================================================
+
+/// Spec: docs/specs/01-cli.md#output-flags
+///
+/// > --emit-patch writes every fix as a unified diff instead of modifying
+/// > the working tree.
+#[test]
+fn emit_patch_writes_fixes_without_modifying_files() {
+    let temp = fixable_project();
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--ci", "--fix", "--emit-patch", "fixes.patch"])
+        .passes()
+        .stderr_has("Wrote fixes for 3 file(s) to fixes.patch");
+
+    let patch = read(&temp, "fixes.patch");
+    assert!(patch.contains("--- a/.cursorrules\n+++ b/.cursorrules\n"));
+    assert!(patch.contains("+# Source"));
+    assert!(patch.contains("--- a/src/lib.rs\n+++ b/src/lib.rs\n"));
+    assert!(patch.contains("+// SPDX-License-Identifier: MIT"));
+    assert!(patch.contains("--- a/src/main.rs\n+++ b/src/main.rs\n"));
+    assert!(patch.contains("-fn main() {}  \n+fn main() {}\n"));
+
+    assert_eq!(read(&temp, ".cursorrules"), TARGET);
+    assert_eq!(read(&temp, "src/lib.rs"), LIB);
+    assert_eq!(read(&temp, "src/main.rs"), MAIN);
+}
+
+/// Spec: docs/specs/01-cli.md#output-flags
+///
+/// > The patch applies with `git apply`.
+#[test]
+fn emit_patch_applies_with_git_apply() {
+    let temp = fixable_project();
+    git_init(&temp);
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--ci", "--fix", "--emit-patch", "fixes.patch"])
+        .passes();
+
+    let status = std::process::Command::new("git")
+        .args(["apply", "fixes.patch"])
+        .current_dir(temp.path())
+        .status()
+        .unwrap();
+    assert!(status.success(), "git apply should accept the patch");
+
+    assert_eq!(read(&temp, ".cursorrules"), SOURCE);
+    assert!(read(&temp, "src/lib.rs").starts_with("// SPDX-License-Identifier: MIT\n"));
+    assert!(read(&temp, "src/main.rs").ends_with("fn main() {}\n"));
+}
+
+/// Spec: docs/specs/01-cli.md#output-flags
+///
+/// > Nothing to fix writes an empty patch.
+#[test]
+fn emit_patch_with_nothing_to_fix_is_empty() {
+    let temp = Project::empty();
+    temp.config("[check.whitespace]\ncheck = \"error\"\n");
+    temp.file("src/lib.rs", "fn a() {}\n");
+
+    check("whitespace")
+        .pwd(temp.path())
+        .args(&["--fix", "--emit-patch", "fixes.patch"])
+        .passes()
+        .stderr_has("Wrote fixes for 0 file(s)");
+
+    assert_eq!(read(&temp, "fixes.patch"), "");
+}
+
+/// Spec: docs/specs/04-ratcheting.md#previewing-baseline-updates
+///
+/// > --emit-patch previews the baseline like --dry-run instead of saving it.
+#[test]
+fn emit_patch_does_not_update_baseline() {
+    let temp = fixable_project();
+    temp.config(&format!(
+        "{}\n[git]\nbaseline = \".quench/baseline.json\"\n",
+        CONFIG
+    ));
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--fix", "--emit-patch", "fixes.patch"])
+        .passes();
+
+    assert!(!temp.path().join(".quench/baseline.json").exists());
+}
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench explain` command.
+//!
+//! Reference: docs/specs/01-cli.md#quench-explain
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Spec: docs/specs/01-cli.md#quench-explain
+///
+/// > prints what it means, why quench reports it, the config keys that tune
+/// > it, and example fixes
+#[test]
+fn explain_prints_description_config_and_fixes() {
+    quench_cmd()
+        .args(["explain", "missing_comment"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "missing_comment (escapes/missing-comment)",
+        ))
+        .stdout(predicates::str::contains("Why: "))
+        .stdout(predicates::str::contains(
+            "check.escapes.patterns[].comment",
+        ))
+        .stdout(predicates::str::contains("Fixes:"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-explain
+///
+/// > Accepts a violation type or a rule ID; a rule ID limits the explanation
+/// > to that check
+#[test]
+fn explain_rule_id_limits_to_check() {
+    let output = quench_cmd()
+        .args(["explain", "agents/file-too-large", "-o", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let items = json.as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(
+        items[0]["rule_ids"],
+        serde_json::json!(["agents/file-too-large"])
+    );
+}
+
+/// Spec: docs/specs/01-cli.md#quench-explain
+///
+/// > An unknown violation type exits 2
+#[test]
+fn explain_unknown_type_exits_2() {
+    quench_cmd()
+        .args(["explain", "no_such_violation"])
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains(
+            "unknown violation type: no_such_violation",
+        ));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-explain
+///
+/// > Every violation type a check can report has an explanation
+#[test]
+fn explain_covers_documented_violation_types() {
+    let specs = concat!(env!("CARGO_MANIFEST_DIR"), "/../../docs/specs/checks");
+    let output = quench_cmd()
+        .args(["explain", "-o", "json"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let known: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|e| e["violation_type"].as_str())
+        .collect();
+
+    for entry in std::fs::read_dir(specs).unwrap() {
+        let path = entry.unwrap().path();
+        let content = std::fs::read_to_string(&path).unwrap();
+        for line in content.lines() {
+            let Some(types) = line.strip_prefix("**Violation types**:") else {
+                continue;
+            };
+            for ty in types.split(',') {
+                let ty = ty.trim().trim_matches('`');
+                assert!(
+                    known.contains(&ty),
+                    "{}: `{ty}` has no explanation",
+                    path.display()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench fix` command and check fixers.
+//!
+//! Reference: docs/specs/01-cli.md#quench-fix
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+const ESCAPES_CONFIG: &str = r#"
+[[check.escapes.patterns]]
+name = "unsafe"
+pattern = "unsafe\\s*\\{"
+action = "comment"
+comment = "// SAFETY:"
+"#;
+
+const LIB: &str = "pub fn f() {\n    unsafe { g() }\n}\n";
+
+fn escapes_project() -> Project {
+    let temp = Project::empty();
+    temp.config(ESCAPES_CONFIG);
+    temp.file("src/lib.rs", LIB);
+    temp
+}
+
+fn read(temp: &Project, path: &str) -> String {
+    std::fs::read_to_string(temp.path().join(path)).unwrap()
+}
+
+/// Spec: docs/specs/01-cli.md#quench-fix
+///
+/// > `escapes` | `missing_comment` | Inserts the required comment with a
+/// > `TODO` above the escape
+#[test]
+fn fix_inserts_justification_stub() {
+    let temp = escapes_project();
+
+    quench_cmd()
+        .args(["fix", "--escapes"])
+        .current_dir(temp.path())
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains("src/lib.rs:3: missing_comment"))
+        .stdout(predicates::str::contains(
+            "Replace the TODO in `// SAFETY: TODO` with the justification.",
+        ));
+
+    assert_eq!(
+        read(&temp, "src/lib.rs"),
+        "pub fn f() {\n    // SAFETY: TODO\n    unsafe { g() }\n}\n"
+    );
+}
+
+/// Spec: docs/specs/01-cli.md#quench-fix
+///
+/// > An escape that already has its stub doesn't get another, so running
+/// > `quench fix` again changes nothing
+#[test]
+fn fix_twice_leaves_file_unchanged() {
+    let temp = escapes_project();
+    let fix = || {
+        quench_cmd()
+            .args(["fix", "--escapes"])
+            .current_dir(temp.path())
+            .assert()
+            .code(1);
+    };
+
+    fix();
+    let stubbed = read(&temp, "src/lib.rs");
+    fix();
+
+    assert_eq!(read(&temp, "src/lib.rs"), stubbed);
+    assert_eq!(stubbed.matches("// SAFETY: TODO").count(), 1);
+}
+
+/// Spec: docs/specs/01-cli.md#quench-fix
+///
+/// > A `TODO` stub doesn't satisfy `missing_comment`
+#[test]
+fn todo_stub_does_not_satisfy_missing_comment() {
+    let temp = Project::empty();
+    temp.config(ESCAPES_CONFIG);
+    temp.file(
+        "src/lib.rs",
+        "pub fn f() {\n    // SAFETY: TODO\n    unsafe { g() }\n}\n",
+    );
+
+    check("escapes")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("src/lib.rs:3: missing_comment");
+}
+
+/// Spec: docs/specs/01-cli.md#quench-fix
+///
+/// > `check --fix` alone only makes the fixes checks make while running and
+/// > updates the baseline; it never runs fixers
+#[test]
+fn check_fix_does_not_run_fixers() {
+    let temp = escapes_project();
+
+    check("escapes")
+        .pwd(temp.path())
+        .args(&["--fix"])
+        .fails()
+        .stdout_has("src/lib.rs:2: missing_comment");
+
+    assert_eq!(read(&temp, "src/lib.rs"), LIB);
+}
+
+/// Spec: docs/specs/01-cli.md#output-flags
+///
+/// > `--fix --dry-run` prints every fix quench makes itself to stderr as a
+/// > unified diff
+#[test]
+fn fix_dry_run_prints_diff_and_changes_nothing() {
+    let temp = escapes_project();
+
+    quench_cmd()
+        .args(["fix", "--escapes", "--dry-run"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("fix: would change 1 file(s)"))
+        .stderr(predicates::str::contains("+++ b/src/lib.rs"))
+        .stderr(predicates::str::contains("+    // SAFETY: TODO\n"));
+
+    assert_eq!(read(&temp, "src/lib.rs"), LIB);
+}
+
+/// Spec: docs/specs/01-cli.md#fix-previews
+///
+/// > `--fix --dry-run` prints every fix quench makes itself, not just agent
+/// > syncs
+#[test]
+fn check_fix_dry_run_previews_whitespace_fixes() {
+    let temp = Project::empty();
+    temp.config("[check.whitespace]\ncheck = \"error\"\n");
+    temp.file("src/lib.rs", "fn a() {}  \n");
+
+    check("whitespace")
+        .pwd(temp.path())
+        .args(&["--fix", "--dry-run"])
+        .passes()
+        .stderr_has("-fn a() {}  \n+fn a() {}\n");
+
+    assert_eq!(read(&temp, "src/lib.rs"), "fn a() {}  \n");
+}
+
+/// Spec: docs/specs/checks/docs.md#output
+///
+/// > `quench fix` regenerates trees with missing files
+#[test]
+fn fix_regenerates_toc_without_missing_files() {
+    let temp = Project::empty();
+    temp.file("src/lib.rs", "");
+    temp.file(
+        "README.md",
+        "# Layout\n\n```\nsrc/\n├── lib.rs\n└── gone.rs\n```\n",
+    );
+
+    quench_cmd()
+        .args(["fix", "--docs"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    assert_eq!(
+        read(&temp, "README.md"),
+        "# Layout\n\n```\nsrc/\n└── lib.rs\n```\n"
+    );
+}
+
+/// Spec: docs/specs/checks/cloc.md#file-size-limits
+///
+/// > With `quench fix`, the advice also lists split points
+#[test]
+fn fix_suggests_split_points_for_large_files() {
+    let temp = Project::empty();
+    temp.config("[check.cloc]\nmax_lines = 10\n");
+    let mut content = String::new();
+    for name in ["a", "b", "c", "d"] {
+        content.push_str(&format!("fn {}() {{\n    body();\n}}\n\n", name));
+    }
+    temp.file("src/lib.rs", &content);
+
+    quench_cmd()
+        .args(["fix", "--cloc"])
+        .current_dir(temp.path())
+        .assert()
+        .code(1)
+        .stdout(predicates::str::contains(
+            "Split points: line 9 (`fn c() {`).",
+        ));
+
+    assert_eq!(
+        read(&temp, "src/lib.rs"),
+        content,
+        "splitting is left to a person"
+    );
+}
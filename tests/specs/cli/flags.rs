@@ -131,3 +131,48 @@ fn check_unknown_long_flag_fails() {
         .code(2)
         .stderr(predicates::str::is_match(r"(?i)(unexpected|unknown|unrecognized)").unwrap());
 }
+
+// =============================================================================
+// DEVELOPMENT FLAG SPECS
+// =============================================================================
+
+fn many_violations_project() -> Project {
+    let temp = Project::empty();
+    temp.config("[check.cloc]\nmax_lines = 2\n");
+    for i in 0..30 {
+        temp.file(
+            format!("src/file_{i:02}.rs"),
+            &"fn a() {}\nfn b() {}\nfn c() {}\n".repeat(i + 1),
+        );
+    }
+    temp
+}
+
+/// Spec: docs/specs/01-cli.md#development-flags
+///
+/// > `-j, --jobs <N>` sets worker threads; results and `--limit` don't change
+#[test]
+fn check_jobs_does_not_change_results() {
+    let temp = many_violations_project();
+    let violations = |jobs: &str| {
+        check("cloc")
+            .pwd(temp.path())
+            .args(&["--jobs", jobs, "--limit", "5"])
+            .json()
+            .fails()
+            .violations()
+            .to_vec()
+    };
+
+    let serial = violations("1");
+    assert_eq!(serial.len(), 5);
+    assert_eq!(violations("4"), serial);
+}
+
+/// Spec: docs/specs/01-cli.md#development-flags
+///
+/// > `--jobs` needs at least one thread
+#[test]
+fn check_jobs_rejects_zero() {
+    quench_cmd().args(["check", "--jobs", "0"]).assert().code(2);
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench graph` command.
+//!
+//! Reference: docs/specs/01-cli.md#quench-graph
+
+use crate::prelude::*;
+
+/// Cargo workspace where `app` depends on `core`.
+fn workspace() -> Project {
+    let temp = Project::empty();
+    temp.config("[project]\npackages = [\"crates/core\", \"crates/app\"]\n");
+    temp.file(
+        "crates/core/Cargo.toml",
+        "[package]\nname = \"core\"\nversion = \"0.1.0\"\n",
+    );
+    temp.file("crates/core/src/lib.rs", "pub fn a() {}\npub fn b() {}\n");
+    temp.file(
+        "crates/app/Cargo.toml",
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\ncore = { path = \"../core\" }\n",
+    );
+    temp.file("crates/app/src/lib.rs", "pub fn c() {}\n");
+    temp
+}
+
+/// Spec: docs/specs/01-cli.md#quench-graph
+///
+/// > An edge points from a package to each package it depends on
+#[test]
+fn graph_renders_dot_dependency_edges() {
+    let temp = workspace();
+
+    quench_cmd()
+        .arg("graph")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::starts_with("digraph workspace {"))
+        .stdout(predicates::str::contains("n0 [label=\"core\"];"))
+        .stdout(predicates::str::contains("n1 -> n0;"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-graph
+///
+/// > Each node shows the package's source LOC (cloc), line coverage (tests,
+/// > CI only), and escape hatch count in source files
+#[test]
+fn graph_annotates_nodes_with_last_check_metrics() {
+    let temp = workspace();
+    check("cloc").pwd(temp.path()).json().passes();
+
+    quench_cmd()
+        .args(["graph", "-o", "mermaid"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::starts_with("graph LR\n"))
+        .stdout(predicates::str::contains("n0[\"core<br/>loc: 2\"]"))
+        .stdout(predicates::str::contains("n1 --> n0"));
+}
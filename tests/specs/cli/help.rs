@@ -73,7 +73,20 @@ fn check_help_shows_consolidated_check_toggles() {
     let stdout = String::from_utf8_lossy(&output.stdout);
 
     for check in [
-        "cloc", "escapes", "agents", "docs", "tests", "git", "build", "license",
+        "cloc",
+        "escapes",
+        "agents",
+        "docs",
+        "tests",
+        "git",
+        "build",
+        "license",
+        "whitespace",
+        "format",
+        "lint",
+        "snapshots",
+        "deadcode",
+        "deps",
     ] {
         let consolidated = format!("--[no-]{check}");
         assert!(
@@ -96,7 +109,20 @@ fn report_help_shows_consolidated_check_toggles() {
     let stdout = String::from_utf8_lossy(&output.stdout);
 
     for check in [
-        "cloc", "escapes", "agents", "docs", "tests", "git", "build", "license",
+        "cloc",
+        "escapes",
+        "agents",
+        "docs",
+        "tests",
+        "git",
+        "build",
+        "license",
+        "whitespace",
+        "format",
+        "lint",
+        "snapshots",
+        "deadcode",
+        "deps",
     ] {
         let consolidated = format!("--[no-]{check}");
         assert!(
@@ -167,7 +193,20 @@ fn check_help_has_no_duplicate_entries() {
 
     // Count occurrences of each check toggle
     for check in [
-        "cloc", "escapes", "agents", "docs", "tests", "git", "build", "license",
+        "cloc",
+        "escapes",
+        "agents",
+        "docs",
+        "tests",
+        "git",
+        "build",
+        "license",
+        "whitespace",
+        "format",
+        "lint",
+        "snapshots",
+        "deadcode",
+        "deps",
     ] {
         // After consolidation, we should see exactly one line containing --[no-]<check>
         // and zero lines with standalone --<check> or --no-<check>
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! CI workflow scaffolding specs (`quench init --ci`).
+
+use crate::prelude::*;
+
+/// Spec: docs/specs/commands/quench-init.md#--ci-flag
+///
+/// > quench init --ci github       # .github/workflows/quench.yml
+#[test]
+fn init_ci_github_writes_workflow_for_detected_languages() {
+    let temp = Project::empty();
+    temp.file("Cargo.toml", "[package]\nname = \"app\"\n");
+
+    quench_cmd()
+        .args(["init", "--ci", "github"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Created .github/workflows/quench.yml",
+        ));
+
+    let workflow =
+        std::fs::read_to_string(temp.path().join(".github/workflows/quench.yml")).unwrap();
+    assert!(workflow.contains("dtolnay/rust-toolchain@stable"));
+    assert!(workflow.contains("quench check --ci --base"));
+    assert!(workflow.contains("git push origin refs/notes/quench"));
+    assert!(workflow.contains("actions/upload-artifact"));
+}
+
+/// Spec: docs/specs/commands/quench-init.md#--ci-flag
+///
+/// > Toolchain setup follows the detected languages (or the `--with` profiles)
+#[test]
+fn init_ci_uses_with_profiles() {
+    let temp = Project::empty();
+    temp.file("poetry.lock", "");
+
+    quench_cmd()
+        .args(["init", "--with", "python", "--ci", "gitlab"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Created .gitlab-ci.yml"));
+
+    let workflow = std::fs::read_to_string(temp.path().join(".gitlab-ci.yml")).unwrap();
+    assert!(workflow.contains("image: python:3"));
+    assert!(workflow.contains("- poetry install"));
+}
+
+/// Spec: docs/specs/commands/quench-init.md#--ci-flag
+///
+/// > A `.gitlab-ci.yml` that `quench init` didn't generate is never
+/// > overwritten: the jobs go into `.gitlab/ci/quench.yml` instead
+#[test]
+fn init_ci_gitlab_keeps_existing_pipeline() {
+    let temp = Project::empty();
+    temp.file(".gitlab-ci.yml", "build:\n  script: make\n");
+
+    quench_cmd()
+        .args(["init", "--ci", "gitlab", "--force"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Created .gitlab/ci/quench.yml (add `include: .gitlab/ci/quench.yml` to .gitlab-ci.yml)",
+        ));
+
+    let pipeline = std::fs::read_to_string(temp.path().join(".gitlab-ci.yml")).unwrap();
+    assert_eq!(pipeline, "build:\n  script: make\n");
+    assert!(temp.path().join(".gitlab/ci/quench.yml").exists());
+}
+
+/// Spec: docs/specs/commands/quench-init.md#--ci-flag
+///
+/// > An existing workflow file is kept unless `--force` is given.
+#[test]
+fn init_ci_keeps_existing_workflow_without_force() {
+    let temp = Project::empty();
+    temp.file(".circleci/config.yml", "version: 2.1\n");
+
+    quench_cmd()
+        .args(["init", "--ci", "circle"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicates::str::contains(
+            ".circleci/config.yml already exists",
+        ));
+
+    let config = std::fs::read_to_string(temp.path().join(".circleci/config.yml")).unwrap();
+    assert_eq!(config, "version: 2.1\n");
+}
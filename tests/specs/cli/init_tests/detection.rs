@@ -336,3 +336,30 @@ fn init_detects_cursor_from_mdc_rules() {
     assert!(config.contains("[check.agents]"));
     // Should detect cursor agent presence
 }
+
+/// Spec: docs/specs/commands/quench-init.md#agent-detection
+///
+/// > AGENTS.md -> codex, GEMINI.md -> gemini, .windsurfrules -> windsurf,
+/// > .github/copilot-instructions.md -> copilot
+#[test]
+fn init_detects_other_agents_from_marker_files() {
+    let temp = Project::empty();
+    temp.file("AGENTS.md", "# Project\n");
+    temp.file("GEMINI.md", "# Project\n");
+    temp.file(".windsurfrules", "# Rules\n");
+    temp.file(".github/copilot-instructions.md", "# Project\n");
+
+    quench_cmd()
+        .args(["init"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "detected: codex, gemini, windsurf, copilot",
+        ));
+
+    let config = std::fs::read_to_string(temp.path().join("quench.toml")).unwrap();
+    assert!(config.contains(
+        r#"required = ["AGENTS.md", "GEMINI.md", ".windsurfrules", ".github/copilot-instructions.md"]"#
+    ));
+}
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Git hook installation specs (`quench init --hooks`).
+
+use crate::prelude::*;
+
+/// Spec: docs/specs/commands/quench-init.md#--hooks-flag
+///
+/// > quench init --hooks installs pre-commit and commit-msg hooks
+#[test]
+fn init_hooks_installs_pre_commit_and_commit_msg() {
+    let temp = Project::empty();
+    git_init(&temp);
+
+    quench_cmd()
+        .args(["init", "--hooks"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Created quench.toml"))
+        .stdout(predicates::str::contains("Installed .git/hooks/pre-commit"))
+        .stdout(predicates::str::contains("Installed .git/hooks/commit-msg"));
+
+    let pre_commit = std::fs::read_to_string(temp.path().join(".git/hooks/pre-commit")).unwrap();
+    assert!(pre_commit.contains("exec quench check --staged"));
+    let commit_msg = std::fs::read_to_string(temp.path().join(".git/hooks/commit-msg")).unwrap();
+    assert!(commit_msg.contains("exec quench check-commit-msg \"$1\""));
+}
+
+/// Spec: docs/specs/commands/quench-init.md#--hooks-flag
+///
+/// > With an existing quench.toml, `--hooks` only installs the hooks
+#[test]
+fn init_hooks_keeps_existing_config() {
+    let temp = Project::empty();
+    git_init(&temp);
+    temp.file("quench.toml", "version = 1\n");
+
+    quench_cmd()
+        .args(["init", "--hooks"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Created quench.toml").not())
+        .stdout(predicates::str::contains("Installed .git/hooks/pre-commit"));
+
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("quench.toml")).unwrap(),
+        "version = 1\n"
+    );
+}
+
+/// Spec: docs/specs/commands/quench-init.md#--hooks-flag
+///
+/// > An existing hook moves to `<hook>.pre-quench` and runs first;
+/// > `--remove-hooks` puts it back
+#[test]
+fn init_hooks_chains_existing_hook_and_remove_restores_it() {
+    let temp = Project::empty();
+    git_init(&temp);
+    let existing = "#!/bin/sh\nmake lint\n";
+    temp.file(".git/hooks/pre-commit", existing);
+
+    quench_cmd()
+        .args(["init", "--hooks"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Installed .git/hooks/pre-commit (runs the existing hook first, moved to .git/hooks/pre-commit.pre-quench)",
+        ));
+    let backup =
+        std::fs::read_to_string(temp.path().join(".git/hooks/pre-commit.pre-quench")).unwrap();
+    assert_eq!(backup, existing);
+
+    quench_cmd()
+        .args(["init", "--remove-hooks"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Removed .git/hooks/pre-commit (restored the hook it replaced)",
+        ))
+        .stdout(predicates::str::contains("Removed .git/hooks/commit-msg\n"));
+
+    let restored = std::fs::read_to_string(temp.path().join(".git/hooks/pre-commit")).unwrap();
+    assert_eq!(restored, existing);
+    assert!(!temp.path().join(".git/hooks/commit-msg").exists());
+}
+
+/// Spec: docs/specs/commands/quench-init.md#--hooks-flag
+///
+/// > `--remove-hooks` leaves hooks quench didn't install
+#[test]
+fn init_remove_hooks_leaves_other_hooks() {
+    let temp = Project::empty();
+    git_init(&temp);
+    temp.file(".git/hooks/commit-msg", "#!/bin/sh\n");
+
+    quench_cmd()
+        .args(["init", "--remove-hooks"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicates::str::contains(
+            ".git/hooks/commit-msg was not installed by quench, leaving it",
+        ));
+    assert!(temp.path().join(".git/hooks/commit-msg").exists());
+}
+
+/// Spec: docs/specs/commands/quench-init.md#--hooks-flag
+///
+/// > Hooks need a git repository
+#[test]
+fn init_hooks_outside_git_repo_fails() {
+    let temp = Project::empty();
+
+    quench_cmd()
+        .args(["init", "--hooks"])
+        .current_dir(temp.path())
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("--hooks needs a git repository"));
+}
@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Tool config migration specs (`quench init --import`).
+
+use crate::prelude::*;
+
+/// Spec: docs/specs/commands/quench-init.md#--import-flag
+///
+/// > quench init --import .eslintrc,clippy.toml,codecov.yml
+#[test]
+fn init_import_translates_tool_configs() {
+    let temp = Project::empty();
+    temp.file(
+        "package.json",
+        r#"{ "scripts": { "lint": "eslint . --max-warnings 5" } }"#,
+    );
+    temp.file(".eslintrc", r#"{ "ignorePatterns": ["dist/"] }"#);
+    temp.file("clippy.toml", "too-many-lines-threshold = 80\n");
+    temp.file(
+        "codecov.yml",
+        "coverage:\n  status:\n    project:\n      default:\n        target: 80%\n        threshold: 1%\n",
+    );
+
+    quench_cmd()
+        .args(["init", "--import", ".eslintrc,clippy.toml,codecov.yml"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Imported settings from .eslintrc, clippy.toml, codecov.yml",
+        ));
+
+    let config = std::fs::read_to_string(temp.path().join("quench.toml")).unwrap();
+    assert!(config.contains("exclude = [\"dist/\"]"));
+    assert!(config.contains("linters = [\"eslint\", \"clippy\"]"));
+    assert!(config.contains("grandfather = true"));
+    assert!(config.contains("min = 80.0"));
+    assert!(config.contains("coverage_tolerance = 1.0"));
+    assert!(config.contains("#   clippy.toml: too-many-lines-threshold"));
+}
+
+/// Spec: docs/specs/commands/quench-init.md#--import-flag
+///
+/// > Imported sections go at the end of `quench.toml`
+#[test]
+fn init_import_generates_valid_config() {
+    let temp = Project::empty();
+    temp.file(".eslintignore", "vendor/\n");
+    temp.file(
+        "codecov.yml",
+        "coverage:\n  status:\n    project:\n      core:\n        target: 90\n        paths: [src/core]\n",
+    );
+    temp.file("vendor/lib.rs", "fn main() {}\n");
+
+    quench_cmd()
+        .args(["init", "--import", ".eslintignore,codecov.yml"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    let config = std::fs::read_to_string(temp.path().join("quench.toml")).unwrap();
+    assert!(config.contains("[check.tests.coverage.path.\"src/core/**\"]"));
+    quench_cmd()
+        .arg("check")
+        .current_dir(temp.path())
+        .assert()
+        .stderr(predicates::str::contains("config error").not());
+}
+
+/// Spec: docs/specs/commands/quench-init.md#--import-flag
+///
+/// > Unreadable or unsupported files are skipped with a warning
+#[test]
+fn init_import_warns_on_unsupported_file() {
+    let temp = Project::empty();
+    temp.file("tslint.json", "{}");
+
+    quench_cmd()
+        .args(["init", "--import", "tslint.json,codecov.yml"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicates::str::contains(
+            "cannot import tslint.json: unsupported config",
+        ))
+        .stderr(predicates::str::contains("cannot import codecov.yml"))
+        .stdout(predicates::str::contains("Imported settings").not());
+
+    assert!(temp.path().join("quench.toml").exists());
+}
@@ -4,6 +4,9 @@
 //! Behavioral specs for `quench init` command.
 
 mod basic;
+mod ci;
 mod detection;
+mod hooks;
+mod import;
 mod profiles;
 mod templates;
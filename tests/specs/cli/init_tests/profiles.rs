@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Profile-specific configuration specs for shell, ruby, and python.
+//! Profile-specific configuration specs for shell, ruby, python, and agents.
 
 use crate::prelude::*;
 
@@ -218,3 +218,28 @@ fn init_python_profile_includes_debugger_patterns() {
         "config should have [python.policy] section"
     );
 }
+
+// =============================================================================
+// Agent Profile Specs
+// =============================================================================
+
+/// Spec: docs/specs/01-cli.md#explicit-profiles
+///
+/// > `copilot` | .github/copilot-instructions.md required
+#[test]
+fn init_with_agent_profiles_requires_their_files() {
+    let temp = Project::empty();
+
+    quench_cmd()
+        .args(["init", "--with", "claude,gemini,copilot"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    let config = std::fs::read_to_string(temp.path().join("quench.toml")).unwrap();
+    assert!(
+        config.contains(
+            r#"required = ["CLAUDE.md", "GEMINI.md", ".github/copilot-instructions.md"]"#
+        )
+    );
+}
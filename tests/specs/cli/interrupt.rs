@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for interrupting commands that run checks with Ctrl-C.
+//!
+//! Reference: docs/specs/01-cli.md#interrupting
+//!
+//! NOTE: These tests spawn quench_cmd() directly to signal the running
+//! process, and keep the cache, which the CheckBuilder always disables.
+
+#![cfg(unix)]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::process::{Command, Output, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::prelude::*;
+
+/// Start `quench check` on a project whose test suite never finishes,
+/// interrupt it once the suite is running, and collect the output.
+fn interrupted_run(temp: &Project, args: &[&str]) -> (Output, Duration) {
+    let mut check = vec!["check", "--tests", "--ci"];
+    check.extend_from_slice(args);
+    interrupted(temp, &check)
+}
+
+/// Run quench with `args`, interrupt it once the suite is running, and
+/// collect the output.
+fn interrupted(temp: &Project, args: &[&str]) -> (Output, Duration) {
+    let child = quench_cmd()
+        .args(args)
+        .current_dir(temp.path())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // The suite writes a marker once it's running
+    let started = temp.path().join("started");
+    let deadline = Instant::now() + Duration::from_secs(20);
+    while !started.exists() {
+        assert!(Instant::now() < deadline, "test suite never started");
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    let interrupted_at = Instant::now();
+    let status = Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let output = child.wait_with_output().unwrap();
+    (output, interrupted_at.elapsed())
+}
+
+/// Whether the suite's process group, whose id it wrote to `pgid`, is gone.
+fn suite_killed(temp: &Project) -> bool {
+    let pgid = std::fs::read_to_string(temp.path().join("pgid")).unwrap();
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while Instant::now() < deadline {
+        let alive = Command::new("kill")
+            .args(["-0", "--", &format!("-{}", pgid.trim())])
+            .stderr(Stdio::null())
+            .status()
+            .unwrap()
+            .success();
+        if !alive {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    false
+}
+
+fn endless_suite_project() -> Project {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[[check.tests.suite]]
+runner = "custom"
+command = "sh -c 'echo $$ > pgid; touch started; sleep 60 & sleep 60'"
+"#,
+    );
+    temp.file("src/lib.rs", "fn main() {}\n");
+    temp
+}
+
+/// Spec: docs/specs/01-cli.md#interrupting
+///
+/// > Checks still running, or not yet started, are reported as skipped
+/// > (`SKIP`, `interrupted`). The summary ends with `INTERRUPTED: partial
+/// > results`, and the run exits with code 130.
+#[test]
+fn interrupt_prints_partial_summary_and_exits_130() {
+    let temp = endless_suite_project();
+
+    let (output, elapsed) = interrupted_run(&temp, &[]);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(130), "{stdout}");
+    assert!(stdout.contains("tests: SKIP\n  interrupted"), "{stdout}");
+    assert!(
+        stdout.trim_end().ends_with("INTERRUPTED: partial results"),
+        "{stdout}"
+    );
+    // The suite's processes were killed rather than waited on
+    assert!(elapsed < Duration::from_secs(30), "{elapsed:?}");
+}
+
+/// Spec: docs/specs/01-cli.md#interrupting
+///
+/// > Finished cache entries are kept.
+#[test]
+fn interrupt_persists_cache() {
+    let temp = endless_suite_project();
+
+    interrupted_run(&temp, &[]);
+
+    assert!(temp.path().join(".quench").is_dir());
+}
+
+/// Spec: docs/specs/03-output.md#json-format
+///
+/// > `interrupted` is `true` only when Ctrl-C cut the run short
+#[test]
+fn interrupt_marks_json_output() {
+    let temp = endless_suite_project();
+
+    let (output, _) = interrupted_run(&temp, &["-o", "json"]);
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["interrupted"], true);
+    let tests = json["checks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["name"] == "tests")
+        .unwrap();
+    assert_eq!(tests["skipped"], true);
+    assert_eq!(tests["error"], "interrupted");
+}
+
+/// Spec: docs/specs/01-cli.md#interrupting
+///
+/// > `quench watch` stops watching after the interrupted run
+#[test]
+fn interrupt_stops_watch_and_kills_suite() {
+    let temp = endless_suite_project();
+
+    let (output, elapsed) = interrupted(&temp, &["watch", "--tests"]);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(130), "{stdout}");
+    assert!(stdout.contains("INTERRUPTED: partial results"), "{stdout}");
+    assert!(suite_killed(&temp));
+    assert!(elapsed < Duration::from_secs(30), "{elapsed:?}");
+}
+
+/// Spec: docs/specs/01-cli.md#interrupting
+///
+/// > `quench ratchet refresh` exits 130 without refreshing the baseline.
+#[test]
+fn interrupt_stops_ratchet_refresh_without_saving() {
+    let temp = endless_suite_project();
+
+    let (output, _) = interrupted(&temp, &["ratchet", "refresh"]);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(130), "{stderr}");
+    assert!(stderr.contains("baseline not refreshed"), "{stderr}");
+    assert!(suite_killed(&temp));
+    assert!(!temp.path().join(".quench/baseline.json").exists());
+}
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `.quench/lock` run lock.
+//!
+//! Tests that concurrent `quench check` runs are kept apart:
+//! - A run fails while another live process holds the lock
+//! - --wait waits for the holder, --no-lock runs anyway
+//! - Locks left by exited processes are taken over
+//! - Runs that don't write the cache or baseline skip the lock
+//!
+//! Reference: docs/specs/01-cli.md#concurrent-runs
+
+use std::fs::File;
+use std::time::Duration;
+
+use crate::prelude::*;
+
+/// Hold `.quench/lock` from this process until the file is dropped.
+fn hold_lock(temp: &Project) -> File {
+    temp.file(".quench/lock", &format!("{}\n", std::process::id()));
+    let file = File::options()
+        .write(true)
+        .open(temp.path().join(".quench/lock"))
+        .unwrap();
+    file.lock().unwrap();
+    file
+}
+
+/// Spec: docs/specs/01-cli.md#concurrent-runs
+///
+/// > While another quench run holds the lock, `quench check` exits with
+/// > code 3 and names the holder.
+#[test]
+fn check_fails_while_lock_held() {
+    let temp = default_project();
+    let _lock = hold_lock(&temp);
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--fix"])
+        .exits(3)
+        .stderr_has(
+            format!(
+                "another quench run (pid {}) holds .quench/lock",
+                std::process::id()
+            )
+            .as_str(),
+        )
+        .stderr_has("--wait")
+        .stderr_has("--no-lock");
+}
+
+/// Spec: docs/specs/01-cli.md#concurrent-runs
+///
+/// > `--no-lock` runs without the lock.
+#[test]
+fn no_lock_runs_while_lock_held() {
+    let temp = default_project();
+    let _lock = hold_lock(&temp);
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--fix", "--no-lock"])
+        .passes();
+
+    // The holder's lock is left alone
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join(".quench/lock")).unwrap(),
+        format!("{}\n", std::process::id())
+    );
+}
+
+/// Spec: docs/specs/01-cli.md#concurrent-runs
+///
+/// > `--wait` waits for the holder to finish.
+#[test]
+fn wait_runs_after_holder_finishes() {
+    let temp = default_project();
+    let lock = hold_lock(&temp);
+    let releaser = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(500));
+        drop(lock);
+    });
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--fix", "--wait"])
+        .passes()
+        .stderr_has("waiting for another quench run");
+    releaser.join().unwrap();
+}
+
+/// Spec: docs/specs/01-cli.md#concurrent-runs
+///
+/// > A lock file left by a process that exited is taken over.
+#[test]
+fn stale_lock_is_taken_over() {
+    let temp = default_project();
+    temp.file(".quench/lock", "4194304\n");
+
+    cli().pwd(temp.path()).args(&["--fix"]).passes();
+
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join(".quench/lock")).unwrap(),
+        ""
+    );
+}
+
+/// Spec: docs/specs/01-cli.md#concurrent-runs
+///
+/// > The lock is released when the run finishes.
+#[test]
+fn lock_released_after_run() {
+    let temp = default_project();
+
+    quench_cmd()
+        .args(["check"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    let lock = File::options()
+        .write(true)
+        .open(temp.path().join(".quench/lock"))
+        .unwrap();
+    assert!(lock.try_lock().is_ok());
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join(".quench/lock")).unwrap(),
+        ""
+    );
+}
+
+/// Spec: docs/specs/01-cli.md#concurrent-runs
+///
+/// > Runs that write neither the cache nor the baseline (`--no-cache`
+/// > without `--fix`) don't take the lock.
+#[test]
+fn no_cache_run_skips_lock() {
+    let temp = default_project();
+    let _lock = hold_lock(&temp);
+
+    cli().pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/01-cli.md#concurrent-runs
+///
+/// > `--wait` and `--no-lock` can't be combined.
+#[test]
+fn wait_and_no_lock_conflict() {
+    let temp = default_project();
+    cli()
+        .pwd(temp.path())
+        .args(&["--wait", "--no-lock"])
+        .exits(2);
+}
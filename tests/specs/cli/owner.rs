@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for ownership-scoped runs.
+//!
+//! Reference: docs/specs/01-cli.md#ownership-scoped-runs
+
+use crate::prelude::*;
+
+const BIG_FILE: &str = "fn a() {}\nfn b() {}\nfn c() {}\n";
+
+fn team_project() -> Project {
+    let temp = default_project();
+    temp.config("[check.cloc]\nmax_lines = 2\n");
+    temp.file(
+        ".github/CODEOWNERS",
+        "* @org/platform\n/crates/api/ @org/api\n",
+    );
+    temp.file("crates/api/src/big.rs", BIG_FILE);
+    temp.file("crates/core/src/big.rs", BIG_FILE);
+    temp
+}
+
+/// Spec: docs/specs/01-cli.md#ownership-scoped-runs
+///
+/// > `--owner <OWNER>` checks only files CODEOWNERS assigns to that owner
+#[test]
+fn owner_restricts_check_to_owned_files() {
+    let temp = team_project();
+
+    let cloc = check("cloc")
+        .pwd(temp.path())
+        .args(&["--owner", "@org/api"])
+        .json()
+        .fails();
+    let files: Vec<&str> = cloc
+        .violations()
+        .iter()
+        .filter_map(|v| v.get("file").and_then(|f| f.as_str()))
+        .collect();
+    assert_eq!(files, vec!["crates/api/src/big.rs"]);
+}
+
+/// Spec: docs/specs/01-cli.md#ownership-scoped-runs
+///
+/// > The `@` is optional
+#[test]
+fn owner_accepts_name_without_at() {
+    let temp = team_project();
+    temp.file(".github/CODEOWNERS", "* @org/platform\n/crates/ @org/api\n");
+    temp.file("src/ok.rs", "fn a() {}\n");
+
+    check("cloc")
+        .pwd(temp.path())
+        .args(&["--owner", "org/platform"])
+        .passes();
+}
+
+/// Spec: docs/specs/01-cli.md#ownership-scoped-runs
+///
+/// > Without a CODEOWNERS file, `--owner` is a configuration error
+#[test]
+fn owner_without_codeowners_is_config_error() {
+    let temp = default_project();
+
+    check("cloc")
+        .pwd(temp.path())
+        .args(&["--owner", "@org/api"])
+        .exits(2)
+        .stderr_has("--owner requires a CODEOWNERS file");
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench query` command and `[store]` backend.
+//!
+//! Reference: docs/specs/01-cli.md#quench-query
+
+use crate::prelude::*;
+
+/// Spec: docs/specs/01-cli.md#quench-query
+///
+/// > Reads the `[store]` database; fails with exit 2 without one.
+#[test]
+fn query_requires_sqlite_store() {
+    let project = Project::empty();
+    project.config("");
+
+    quench_cmd()
+        .args(["query", "--history", "escapes.source.todo"])
+        .current_dir(project.path())
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("[store] backend = \"sqlite\""));
+}
+
+/// Spec: docs/specs/05-reporting.md#store
+///
+/// > Without the `sqlite` feature, `backend = "sqlite"` is a config error.
+#[cfg(not(feature = "sqlite"))]
+#[test]
+fn sqlite_backend_requires_feature() {
+    let project = Project::empty();
+    project.config("[store]\nbackend = \"sqlite\"\n");
+
+    quench_cmd()
+        .args(["check"])
+        .current_dir(project.path())
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("`sqlite` feature"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-query
+///
+/// > `--history <METRIC>` lists the metric's recorded values, oldest first.
+#[cfg(feature = "sqlite")]
+#[test]
+fn query_history_lists_recorded_runs() {
+    let project = Project::empty();
+    project.config(
+        r#"
+[store]
+backend = "sqlite"
+
+[[check.escapes.patterns]]
+name = "todo"
+pattern = "TODO"
+action = "count"
+
+[check.agents]
+check = "off"
+"#,
+    );
+    project.file("src/lib.rs", "// TODO: one\npub fn f() {}\n");
+    git_init(&project);
+    git_initial_commit(&project);
+    // The TODOs exceed the default count threshold; failing runs still record
+    let check = || {
+        quench_cmd()
+            .args(["check"])
+            .current_dir(project.path())
+            .assert()
+            .code(1);
+    };
+
+    check();
+    project.file("src/lib.rs", "// TODO: one\n// TODO: two\npub fn f() {}\n");
+    git_commit(&project, "feat: add todo");
+    check();
+
+    let output = quench_cmd()
+        .args(["query", "--history", "escapes.source.todo", "-o", "json"])
+        .current_dir(project.path())
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let values: Vec<f64> = json["history"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["value"].as_f64().unwrap())
+        .collect();
+    assert_eq!(values, [1.0, 2.0]);
+}
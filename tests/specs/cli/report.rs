@@ -260,3 +260,541 @@ baseline = ".quench/baseline.json"
     assert!(content.contains("<!DOCTYPE html>"), "should be HTML");
     assert!(content.contains("75.0"), "should include metrics");
 }
+
+// =============================================================================
+// SLOWEST TESTS
+// =============================================================================
+
+/// Spec: docs/specs/01-cli.md#slowest-tests
+///
+/// > Each test shows its latest time and the change since the previous run
+#[test]
+fn report_shows_slow_test_trend() {
+    let temp = Project::empty();
+    temp.file(
+        "quench.toml",
+        r#"
+version = 1
+
+[git]
+baseline = ".quench/baseline.json"
+"#,
+    );
+    temp.file(
+        ".quench/baseline.json",
+        r#"{
+        "version": 1,
+        "updated": "2026-01-20T12:00:00Z",
+        "metrics": {
+            "slow_tests": {
+                "unit": [
+                    {"name": "tests::big", "ms": 2100, "previous": [1200, 1400]},
+                    {"name": "tests::new", "ms": 300}
+                ]
+            }
+        }
+    }"#,
+    );
+
+    quench_cmd()
+        .args(["report"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "slow_tests.unit:\n  tests::big: 2100ms (+700ms)\n  tests::new: 300ms\n",
+        ));
+}
+
+// =============================================================================
+// AGENT CONTEXT
+// =============================================================================
+
+/// Spec: docs/specs/01-cli.md#agent-context
+///
+/// > reports show the combined total and which files consume it, largest first
+#[test]
+fn report_shows_agent_token_breakdown_from_check_fix() {
+    let temp = Project::empty();
+    temp.file(
+        "quench.toml",
+        r#"
+version = 1
+
+[git]
+baseline = ".quench/baseline.json"
+
+[check.agents]
+sync = false
+sections.required = []
+"#,
+    );
+    temp.file("CLAUDE.md", &format!("# Project\n\n{}\n", "a".repeat(400)));
+    temp.file("AGENTS.md", &format!("# Project\n\n{}\n", "b".repeat(200)));
+
+    quench_cmd()
+        .args(["check", "--agents", "--fix"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    quench_cmd()
+        .args(["report", "--agents"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "agents.total_tokens: 156\n  CLAUDE.md: 103 (66%)\n  AGENTS.md: 53 (34%)\n",
+        ));
+}
+
+// =============================================================================
+// HEALTH SCORE
+// =============================================================================
+
+/// Spec: docs/specs/01-cli.md#health-score
+///
+/// > Reports lead with the baseline's health score
+#[test]
+fn report_text_shows_health_score() {
+    // Coverage 85.5% is past the default good value (80)
+    report()
+        .on("report/with-baseline")
+        .runs()
+        .stdout_has("Health: 100/100 (A)");
+}
+
+/// Spec: docs/specs/05-reporting.md#health
+///
+/// > Each metric scores 100 at its `good` value and 0 at its `bad` value
+/// > Workspace packages get their own scores
+#[test]
+fn report_health_uses_configured_scale_and_packages() {
+    let temp = Project::empty();
+    temp.file(
+        "quench.toml",
+        r#"
+version = 1
+
+[git]
+baseline = ".quench/baseline.json"
+
+[health]
+coverage = { good = 90, bad = 50 }
+"#,
+    );
+    temp.file(
+        ".quench/baseline.json",
+        r#"{
+        "version": 1,
+        "updated": "2026-01-20T12:00:00Z",
+        "metrics": {
+            "coverage": {"total": 70.0, "by_package": {"api": 90.0, "web": 50.0}}
+        }
+    }"#,
+    );
+
+    quench_cmd()
+        .args(["report"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Health: 50/100 (F)"))
+        .stdout(predicates::str::contains("  api: 100/100 (A)"))
+        .stdout(predicates::str::contains("  web: 0/100 (F)"));
+}
+
+/// Spec: docs/specs/01-cli.md#check-toggles
+///
+/// > `--check` and `--package` scope the report to some checks and packages
+#[test]
+fn report_filters_by_check_and_package() {
+    let temp = Project::empty();
+    temp.file(
+        "quench.toml",
+        r#"
+version = 1
+
+[project]
+packages = ["crates/api", "crates/web"]
+
+[git]
+baseline = ".quench/baseline.json"
+"#,
+    );
+    temp.file(
+        ".quench/baseline.json",
+        r#"{
+        "version": 1,
+        "updated": "2026-01-20T12:00:00Z",
+        "metrics": {
+            "coverage": {"total": 70.0, "by_package": {"crates/api": 90.0, "crates/web": 50.0}},
+            "escapes": {
+                "source": {"unwrap": 5},
+                "by_package": {
+                    "crates/api": {"source": {"unwrap": 1}, "source_lines": 100},
+                    "crates/web": {"source": {"unwrap": 4}, "source_lines": 100}
+                }
+            },
+            "build_time": {"cold": 30.0, "hot": 2.0}
+        }
+    }"#,
+    );
+
+    quench_cmd()
+        .args([
+            "report",
+            "--check",
+            "escapes,tests",
+            "--package",
+            "crates/api",
+        ])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("  crates/api: 90.0%"))
+        .stdout(predicates::str::contains("crates/web").not())
+        .stdout(predicates::str::contains("escapes.unwrap: 1\n"))
+        .stdout(predicates::str::contains("build_time").not());
+}
+
+/// Spec: docs/specs/01-cli.md#comparing-baselines
+///
+/// > `--compare` marks metrics that regressed or improved and lists what
+/// > changed since the earlier baseline
+#[test]
+fn report_compare_marks_changes_since_earlier_baseline() {
+    let temp = Project::empty();
+    temp.file(
+        "quench.toml",
+        "version = 1\n\n[git]\nbaseline = \".quench/baseline.json\"\n",
+    );
+    temp.file(
+        ".quench/baseline.json",
+        r#"{
+        "version": 1,
+        "updated": "2026-02-01T12:00:00Z",
+        "commit": "bbb2222",
+        "metrics": {
+            "coverage": {"total": 70.0},
+            "escapes": {"source": {"unwrap": 3, "todo": 2}}
+        }
+    }"#,
+    );
+    temp.file(
+        "earlier.json",
+        r#"{
+        "version": 1,
+        "updated": "2026-01-01T12:00:00Z",
+        "commit": "aaa1111",
+        "metrics": {
+            "coverage": {"total": 75.0},
+            "escapes": {"source": {"unwrap": 5}}
+        }
+    }"#,
+    );
+
+    quench_cmd()
+        .args(["report", "--compare", "earlier.json", "-o", "md"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "**Compared to:** aaa1111 (2026-01-01)",
+        ))
+        .stdout(predicates::str::contains(
+            "| Coverage | 70.0% (regressed) |",
+        ))
+        .stdout(predicates::str::contains(
+            "| Escapes (unwrap) | 3 (improved) |",
+        ))
+        .stdout(predicates::str::contains(
+            "## Changes Since aaa1111\n\n- New violation: escapes.todo (2)",
+        ));
+
+    quench_cmd()
+        .args(["report", "--compare", "earlier.json", "-o", "html"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            r#"<td>coverage</td><td class="regressed">70.0%</td>"#,
+        ))
+        .stdout(predicates::str::contains("New violation: escapes.todo (2)"));
+}
+
+/// Spec: docs/specs/01-cli.md#health-score
+///
+/// > quench report -o badge.svg    # Health badge
+#[test]
+fn report_writes_health_badge() {
+    let temp = Project::empty();
+    temp.file(
+        "quench.toml",
+        "version = 1\n\n[git]\nbaseline = \".quench/baseline.json\"\n",
+    );
+    temp.file(
+        ".quench/baseline.json",
+        r#"{"version": 1, "updated": "2026-01-20T12:00:00Z", "metrics": {"coverage": {"total": 72.0}}}"#,
+    );
+
+    quench_cmd()
+        .args(["report", "-o", "badge.svg"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    let badge = std::fs::read_to_string(temp.path().join("badge.svg")).unwrap();
+    assert!(badge.starts_with("<svg"));
+    assert!(badge.contains(">health</text>"));
+    assert!(badge.contains(">80 B</text>"));
+}
+
+/// Spec: docs/specs/01-cli.md#health-score
+///
+/// > Reports lead with the baseline's health score (JSON includes the
+/// > per-metric scores)
+#[test]
+fn report_json_includes_health() {
+    let output = report().on("report/with-baseline").json().runs();
+    let output: serde_json::Value = serde_json::from_str(&output.stdout()).unwrap();
+    assert_eq!(output["health"]["score"], 100);
+    assert_eq!(output["health"]["grade"], "A");
+    assert_eq!(output["health"]["metrics"]["coverage"], 100.0);
+}
+
+// =============================================================================
+// GOALS
+// =============================================================================
+
+/// Project with coverage and unwrap goals over ten days of baseline history.
+fn goals_project() -> Project {
+    let temp = Project::empty();
+    temp.file(
+        "quench.toml",
+        r#"
+version = 1
+
+[git]
+baseline = ".quench/baseline.json"
+
+[goals]
+coverage.target = 85
+escapes.unwrap.target = 0
+"#,
+    );
+    temp.file(
+        ".quench/baseline.json",
+        r#"{
+        "version": 1,
+        "updated": "2026-01-11T00:00:00Z",
+        "metrics": {
+            "coverage": {"total": 75.0},
+            "escapes": {"source": {"unwrap": 12}},
+            "history": [
+                {"date": "2026-01-01T00:00:00Z", "coverage": 65.0, "escapes": {"unwrap": 12}},
+                {"date": "2026-01-11T00:00:00Z", "coverage": 75.0, "escapes": {"unwrap": 12}}
+            ]
+        }
+    }"#,
+    );
+    temp
+}
+
+/// Spec: docs/specs/01-cli.md#goals
+///
+/// > Each goal shows its current and target values, a progress bar, and the
+/// > estimated time to reach it at the recent pace
+#[test]
+fn report_shows_goal_progress_and_eta() {
+    let temp = goals_project();
+
+    quench_cmd()
+        .args(["report"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "goal.coverage: 75.0% -> 85.0% [##########----------] 50% (~10 days)",
+        ))
+        .stdout(predicates::str::contains(
+            "goal.escapes.unwrap: 12 -> 0 [--------------------] 0% (no progress)",
+        ));
+}
+
+/// Spec: docs/specs/01-cli.md#goals
+///
+/// > JSON reports include them under `goals`
+#[test]
+fn report_json_includes_goals() {
+    let temp = goals_project();
+
+    let output = quench_cmd()
+        .args(["report", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["goals"][0]["metric"], "coverage");
+    assert_eq!(json["goals"][0]["velocity"], 1.0);
+    assert_eq!(json["goals"][0]["eta_days"], 10);
+    assert_eq!(json["goals"][1]["metric"], "escapes.unwrap");
+}
+
+/// Spec: docs/specs/01-cli.md#goals
+///
+/// > Reports end with progress toward the targets in `[goals]`
+#[test]
+fn report_without_goals_has_no_goal_lines() {
+    report()
+        .on("report/with-baseline")
+        .runs()
+        .stdout_lacks("goal.");
+}
+
+// =============================================================================
+// DEBT
+// =============================================================================
+
+/// Rust project with one untested file full of escapes and one tested file.
+fn debt_project() -> Project {
+    let temp = Project::empty();
+    temp.file("quench.toml", "version = 1\n");
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"debt\"\nversion = \"0.1.0\"\n",
+    );
+    temp.file(
+        "src/parser.rs",
+        "pub fn parse(p: *const u8) -> u8 {\n    unsafe { *p }\n}\n",
+    );
+    temp.file("src/lexer.rs", "pub fn lex() {}\n");
+    temp.file("tests/lexer_tests.rs", "#[test]\nfn lexes() {}\n");
+    temp
+}
+
+/// Spec: docs/specs/01-cli.md#debt-report
+///
+/// > `--debt` ranks source files by a 0-100 debt score instead of reporting metrics
+#[test]
+fn report_debt_ranks_files_as_markdown() {
+    let temp = debt_project();
+
+    quench_cmd()
+        .args(["report", "--debt", "-o", "md"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("# Debt Report"))
+        .stdout(predicates::str::contains(
+            "| 1 | `src/parser.rs` | 40.0 | 3 | 1 | no | - | 0 |",
+        ))
+        .stdout(predicates::str::contains("src/lexer.rs").not());
+}
+
+/// Spec: docs/specs/01-cli.md#debt-report
+///
+/// > JSON lists the files under `files` with their factor scores
+#[test]
+fn report_debt_json_lists_factors() {
+    let temp = debt_project();
+
+    let output = quench_cmd()
+        .args(["report", "--debt", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["files"][0]["path"], "src/parser.rs");
+    assert_eq!(json["files"][0]["factors"]["escapes"], 1.0);
+    assert_eq!(json["files"][0]["factors"]["tests"], 1.0);
+}
+
+/// Spec: docs/specs/01-cli.md#debt-report
+///
+/// > `quench report --debt --top 50 -o debt.html`
+#[test]
+fn report_debt_writes_html_file() {
+    let temp = debt_project();
+
+    quench_cmd()
+        .args(["report", "--debt", "--top", "1", "-o", "debt.html"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    let html = std::fs::read_to_string(temp.path().join("debt.html")).unwrap();
+    assert!(html.contains("Quench Debt Report"));
+    assert!(html.contains("<td>src/parser.rs</td>"));
+}
+
+/// Spec: docs/specs/01-cli.md#debt-report
+///
+/// > `--top N` ... only applies with `--debt`
+#[test]
+fn report_top_requires_debt() {
+    quench_cmd()
+        .args(["report", "--top", "5"])
+        .assert()
+        .failure();
+}
+
+// =============================================================================
+// HOTSPOTS
+// =============================================================================
+
+/// Git project where `src/parser.rs` is large and committed three times.
+fn hotspot_project() -> Project {
+    let temp = Project::empty();
+    temp.config("[hotspots]\nchurn = 3\nlines = 5\n");
+    temp.file(
+        "src/quiet.rs",
+        "fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\nfn e() {}\n",
+    );
+    git_init(&temp);
+    for n in 1..=3 {
+        let body: String = (0..5 + n).map(|i| format!("fn f{i}() {{}}\n")).collect();
+        temp.file("src/parser.rs", &body);
+        git_add_all(&temp);
+        git_commit(&temp, &format!("feat: parser {n}"));
+    }
+    temp
+}
+
+/// Spec: docs/specs/01-cli.md#hotspots
+///
+/// > `--hotspots` lists files that are both large and changed often, hottest first
+#[test]
+fn report_hotspots_lists_large_churning_files() {
+    let temp = hotspot_project();
+
+    quench_cmd()
+        .args(["report", "--hotspots"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Hotspots: files with 3+ commits in 90 days and 5+ lines",
+        ))
+        .stdout(predicates::str::contains(
+            "src/parser.rs  (3 commits, 8 lines)",
+        ))
+        .stdout(predicates::str::contains("src/quiet.rs").not());
+}
+
+/// Spec: docs/specs/01-cli.md#hotspots
+///
+/// > JSON lists them under `hotspots`, with the `window` in days
+#[test]
+fn report_hotspots_json() {
+    let temp = hotspot_project();
+
+    let output = quench_cmd()
+        .args(["report", "--hotspots", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["window"], 90);
+    assert_eq!(json["hotspots"][0]["path"], "src/parser.rs");
+    assert_eq!(json["hotspots"][0]["churn"], 3);
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for rule IDs and per-rule enablement.
+//!
+//! Reference: docs/specs/01-cli.md#rule-filters
+
+use crate::prelude::*;
+
+fn oversized_project() -> Project {
+    let temp = default_project();
+    temp.config("[check.cloc]\nmax_lines = 2\n");
+    temp.file("src/big.rs", "fn a() {}\nfn b() {}\nfn c() {}\n");
+    temp
+}
+
+/// Spec: docs/specs/01-cli.md#rule-filters
+///
+/// > A check whose violations are all filtered out passes.
+#[test]
+fn skip_rule_filters_violations() {
+    let temp = oversized_project();
+    check("cloc").pwd(temp.path()).fails();
+
+    let cloc = check("cloc")
+        .pwd(temp.path())
+        .args(&["--skip-rule", "cloc/file-too-large"])
+        .json()
+        .passes();
+    assert!(cloc.violations().is_empty());
+    assert!(cloc.require("metrics").get("source_lines").is_some());
+}
+
+/// Spec: docs/specs/01-cli.md#rule-filters
+///
+/// > `--only-rule <RULE>` | Report only these rules
+#[test]
+fn only_rule_keeps_listed_rules() {
+    let temp = oversized_project();
+    let cloc = check("cloc")
+        .pwd(temp.path())
+        .args(&["--only-rule", "cloc/file-too-large"])
+        .json()
+        .fails();
+    assert!(cloc.has_violation("file_too_large"));
+
+    check("cloc")
+        .pwd(temp.path())
+        .args(&["--only-rule", "escapes/forbidden"])
+        .passes();
+}
+
+/// Spec: docs/specs/01-cli.md#rule-filters
+///
+/// > Violations the filter drops don't count toward `--limit`
+#[test]
+fn only_rule_finds_violations_past_the_limit() {
+    let temp = default_project();
+    temp.config(
+        r#"[[check.escapes.patterns]]
+name = "note"
+pattern = "note!"
+action = "comment"
+comment = "// OK:"
+
+[[check.escapes.patterns]]
+name = "hack"
+pattern = "hack!"
+action = "forbid"
+"#,
+    );
+    temp.file(
+        "src/a.rs",
+        &format!("fn a() {{\n{}}}\n", "    note!();\n".repeat(20)),
+    );
+    temp.file("src/z.rs", "fn z() {\n    hack!();\n}\n");
+
+    let escapes = check("escapes")
+        .pwd(temp.path())
+        .args(&["--only-rule", "escapes/forbidden"])
+        .json()
+        .fails();
+    assert_eq!(escapes.violations().len(), 1);
+    assert!(escapes.has_violation("forbidden"));
+}
+
+/// Spec: docs/specs/02-config.md#rules
+///
+/// > Enable or disable individual rules by ID
+#[test]
+fn config_disables_rule() {
+    let temp = oversized_project();
+    temp.config("[check.cloc]\nmax_lines = 2\n\n[rules]\n\"cloc/file-too-large\" = false\n");
+    check("cloc").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/01-cli.md#rule-filters
+///
+/// > Unknown checks or malformed IDs exit with code 2.
+#[test]
+fn invalid_rule_id_is_config_error() {
+    let temp = default_project();
+    cli()
+        .pwd(temp.path())
+        .args(&["--skip-rule", "file-too-large"])
+        .exits(2)
+        .stderr_has("invalid rule ID 'file-too-large'");
+    cli()
+        .pwd(temp.path())
+        .args(&["--only-rule", "style/thing"])
+        .exits(2)
+        .stderr_has("unknown check 'style'");
+}
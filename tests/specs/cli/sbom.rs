@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench sbom` command.
+//!
+//! Reference: docs/specs/01-cli.md#quench-sbom
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Cargo project with one registry package in its lockfile.
+fn cargo_project() -> Project {
+    let temp = Project::empty();
+    temp.config("[project]\nname = \"app\"\n");
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\nserde = \"1\"\n",
+    );
+    temp.file(
+        "Cargo.lock",
+        "version = 3\n\n[[package]]\nname = \"app\"\nversion = \"0.1.0\"\n\n[[package]]\nname = \"serde\"\nversion = \"1.0.197\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\n",
+    );
+    temp
+}
+
+fn sbom_json(temp: &Project, args: &[&str]) -> serde_json::Value {
+    let output = quench_cmd()
+        .arg("sbom")
+        .args(args)
+        .current_dir(temp.path())
+        .assert()
+        .success();
+    serde_json::from_slice(&output.get_output().stdout).unwrap()
+}
+
+/// Spec: docs/specs/01-cli.md#quench-sbom
+///
+/// > Components are the packages resolved in `Cargo.lock` and `package-lock.json`
+#[test]
+fn sbom_defaults_to_cyclonedx() {
+    let temp = cargo_project();
+
+    let bom = sbom_json(&temp, &[]);
+
+    assert_eq!(bom["bomFormat"], "CycloneDX");
+    assert_eq!(bom["metadata"]["component"]["name"], "app");
+    let components = bom["components"].as_array().unwrap();
+    assert_eq!(components.len(), 1, "workspace members are not components");
+    assert_eq!(components[0]["purl"], "pkg:cargo/serde@1.0.197");
+}
+
+/// Spec: docs/specs/01-cli.md#quench-sbom
+///
+/// > `-o, --output <FMT>` | `cyclonedx` (default), `spdx`
+#[test]
+fn sbom_spdx_output() {
+    let temp = cargo_project();
+
+    let doc = sbom_json(&temp, &["-o", "spdx"]);
+
+    assert_eq!(doc["spdxVersion"], "SPDX-2.3");
+    assert_eq!(doc["packages"][1]["name"], "serde");
+    assert_eq!(doc["packages"][1]["versionInfo"], "1.0.197");
+}
+
+/// Spec: docs/specs/01-cli.md#quench-sbom
+///
+/// > An ecosystem with a manifest but no lockfile is reported on stderr
+#[test]
+fn sbom_warns_about_manifest_without_lockfile() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file(
+        "package.json",
+        r#"{ "name": "web", "dependencies": { "react": "^18.2.0" } }"#,
+    );
+
+    quench_cmd()
+        .arg("sbom")
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("npm manifests have no lockfile"));
+}
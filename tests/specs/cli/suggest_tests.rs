@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench suggest-tests` command.
+//!
+//! Reference: docs/specs/01-cli.md#quench-suggest-tests
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::collections::BTreeSet;
+
+use quench::impact::ImpactMap;
+
+use crate::prelude::*;
+
+/// Committed Python project with recorded impact for two tests.
+fn project_with_impact() -> Project {
+    let temp = Project::empty();
+    temp.file("src/api.py", "def get(): pass\n");
+    temp.file("src/db.py", "def save(): pass\n");
+    temp.file("README.md", "# Project\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+
+    let mut map = ImpactMap::new();
+    let files = |paths: &[&str]| paths.iter().map(|p| p.to_string()).collect::<BTreeSet<_>>();
+    map.record(
+        "tests/test_api.py::test_get",
+        files(&["src/api.py", "src/db.py"]),
+    );
+    map.record("tests/test_db.py::test_save", files(&["src/db.py"]));
+    map.save(&ImpactMap::path(temp.path())).unwrap();
+    temp
+}
+
+/// Spec: docs/specs/01-cli.md#quench-suggest-tests
+///
+/// > Lists the tests that executed any file changed since the base ref
+#[test]
+fn suggest_tests_lists_tests_for_changed_files() {
+    let temp = project_with_impact();
+    temp.file("src/api.py", "def get(): return 1\n");
+
+    quench_cmd()
+        .args(["suggest-tests", "--base", "main"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("tests/test_api.py::test_get\n");
+}
+
+/// Spec: docs/specs/01-cli.md#quench-suggest-tests
+///
+/// > Changed files no recorded test touches are reported on stderr
+#[test]
+fn suggest_tests_reports_unmapped_files() {
+    let temp = project_with_impact();
+    temp.file("README.md", "# Changed\n");
+
+    quench_cmd()
+        .args(["suggest-tests", "--base", "main"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout("")
+        .stderr(predicates::str::contains(
+            "1 changed files have no recorded tests",
+        ))
+        .stderr(predicates::str::contains("README.md"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-suggest-tests
+///
+/// > JSON output includes the changed files, tests, and unmapped files
+#[test]
+fn suggest_tests_json_output() {
+    let temp = project_with_impact();
+    temp.file("src/db.py", "def save(): return 1\n");
+
+    let output = quench_cmd()
+        .args(["suggest-tests", "--base", "main", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(json["base"], "main");
+    assert_eq!(json["changed"], serde_json::json!(["src/db.py"]));
+    assert_eq!(
+        json["tests"],
+        serde_json::json!(["tests/test_api.py::test_get", "tests/test_db.py::test_save"])
+    );
+}
+
+/// Spec: docs/specs/01-cli.md#quench-suggest-tests
+///
+/// > Without recorded impact, exits 2 and explains how to record it
+#[test]
+fn suggest_tests_without_impact_fails() {
+    let temp = Project::empty();
+    temp.file("src/api.py", "def get(): pass\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+
+    quench_cmd()
+        .args(["suggest-tests", "--base", "main"])
+        .current_dir(temp.path())
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("no test impact recorded"));
+}
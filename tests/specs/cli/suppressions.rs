@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench suppressions` command.
+//!
+//! Reference: docs/specs/01-cli.md#quench-suppressions
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// A project with an expired inline suppression, a far-off allow-list entry,
+/// and one suppression without an expiry.
+fn project_with_suppressions() -> Project {
+    let project = Project::empty();
+    project.config(
+        r#"
+[rust.suppress.source]
+allow = [
+  "dead_code",  # generated bindings, expires 2999-12-31
+]
+
+[git]
+baseline = ".quench/baseline.json"
+"#,
+    );
+    project.file(
+        "src/lib.rs",
+        "// KEEP: old FFI shim, expires 2020-01-01\n#[allow(unused)]\nfn a() {}\n\n#[allow(dead_code)]\nfn b() {}\n",
+    );
+    project
+}
+
+/// Spec: docs/specs/01-cli.md#quench-suppressions
+///
+/// > List everything that silences findings
+#[test]
+fn suppressions_list_shows_kind_and_expiry() {
+    let project = project_with_suppressions();
+
+    quench_cmd()
+        .args(["suppressions", "list"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "src/lib.rs:2: #[allow(unused)]\n  inline, uncommitted, expired 2020-01-01\n",
+        ))
+        .stdout(predicates::str::contains(
+            "quench.toml:5: rust.suppress.source.allow: dead_code\n  allow_list, uncommitted, expires 2999-12-31\n",
+        ))
+        .stdout(predicates::str::contains(
+            "3 suppressions (1 expired, 0 expiring within 30 days)",
+        ));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-suppressions
+///
+/// > `--expiring [DAYS]` | Only expired ones and those expiring within DAYS
+#[test]
+fn suppressions_list_expiring_filters_to_due_entries() {
+    let project = project_with_suppressions();
+
+    quench_cmd()
+        .args(["suppressions", "list", "--expiring"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("#[allow(unused)]"))
+        .stdout(predicates::str::contains("dead_code").not());
+}
+
+/// Spec: docs/specs/01-cli.md#quench-suppressions
+///
+/// > Each suppression's age comes from `git blame` of its line
+#[test]
+fn suppressions_list_json_includes_age() {
+    let project = project_with_suppressions();
+    git_init(&project);
+    git_initial_commit(&project);
+
+    let output = quench_cmd()
+        .args(["suppressions", "list", "-o", "json"])
+        .current_dir(project.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let first = &json["suppressions"][0];
+    assert_eq!(first["kind"], "allow_list");
+    assert_eq!(first["age_days"], 0);
+    assert_eq!(first["expired"], false);
+    assert_eq!(json["summary"]["total"], 3);
+    assert_eq!(json["summary"]["expired"], 1);
+}
+
+/// Spec: docs/specs/01-cli.md#suppressions
+///
+/// > Reports count the working tree's suppressions by kind
+#[test]
+fn report_counts_suppressions() {
+    let project = project_with_suppressions();
+    project.file(
+        ".quench/baseline.json",
+        r#"{"version": 1, "updated": "2026-01-20T12:00:00Z", "metrics": {}}"#,
+    );
+
+    quench_cmd()
+        .args(["report"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "suppressions: 3 (1 expired, 0 expiring)\n  allow_list: 1\n  inline: 2\n",
+        ));
+
+    quench_cmd()
+        .args(["report", "--no-escapes"])
+        .current_dir(project.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("suppressions:").not());
+}
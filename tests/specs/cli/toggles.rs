@@ -23,9 +23,9 @@ use yare::parameterized;
 
 /// Spec: docs/specs/00-overview.md#built-in-checks
 ///
-/// > Built-in checks: cloc, escapes, agents, docs, tests, git, build, license
+/// > Built-in checks: cloc, escapes, agents, docs, tests, git, build, license, whitespace, format, lint, snapshots, deadcode, deps
 #[test]
-fn check_names_are_exactly_8_known_checks() {
+fn check_names_are_exactly_14_known_checks() {
     let temp = default_project();
     let result = cli().pwd(temp.path()).json().passes();
     let checks = result.checks();
@@ -35,7 +35,7 @@ fn check_names_are_exactly_8_known_checks() {
         .filter_map(|c| c.get("name").and_then(|n| n.as_str()))
         .collect();
 
-    // All 8 checks should be present
+    // All 14 checks should be present
     assert!(names.contains(&"cloc"), "should have cloc check");
     assert!(names.contains(&"escapes"), "should have escapes check");
     assert!(names.contains(&"agents"), "should have agents check");
@@ -44,9 +44,18 @@ fn check_names_are_exactly_8_known_checks() {
     assert!(names.contains(&"git"), "should have git check");
     assert!(names.contains(&"build"), "should have build check");
     assert!(names.contains(&"license"), "should have license check");
+    assert!(
+        names.contains(&"whitespace"),
+        "should have whitespace check"
+    );
+    assert!(names.contains(&"format"), "should have format check");
+    assert!(names.contains(&"lint"), "should have lint check");
+    assert!(names.contains(&"snapshots"), "should have snapshots check");
+    assert!(names.contains(&"deadcode"), "should have deadcode check");
+    assert!(names.contains(&"deps"), "should have deps check");
 
     // No other checks should be present
-    assert_eq!(names.len(), 8, "should have exactly 8 checks");
+    assert_eq!(names.len(), 14, "should have exactly 14 checks");
 }
 
 /// Spec: docs/specs/01-cli.md#check-toggles
@@ -66,7 +75,12 @@ fn check_toggles_shown_in_help() {
         .stdout(predicates::str::contains("--[no-]tests"))
         .stdout(predicates::str::contains("--[no-]git"))
         .stdout(predicates::str::contains("--[no-]build"))
-        .stdout(predicates::str::contains("--[no-]license"));
+        .stdout(predicates::str::contains("--[no-]license"))
+        .stdout(predicates::str::contains("--[no-]whitespace"))
+        .stdout(predicates::str::contains("--[no-]format"))
+        .stdout(predicates::str::contains("--[no-]lint"))
+        .stdout(predicates::str::contains("--[no-]snapshots"))
+        .stdout(predicates::str::contains("--[no-]deadcode"));
 }
 
 // =============================================================================
@@ -85,6 +99,12 @@ fn check_toggles_shown_in_help() {
     git = { "git" },
     build = { "build" },
     license = { "license" },
+    whitespace = { "whitespace" },
+    format = { "format" },
+    lint = { "lint" },
+    snapshots = { "snapshots" },
+    deadcode = { "deadcode" },
+    deps = { "deps" },
 )]
 fn enable_flag_runs_only_that_check(check_name: &str) {
     let temp = default_project();
@@ -115,6 +135,12 @@ fn enable_flag_runs_only_that_check(check_name: &str) {
     git = { "git" },
     build = { "build" },
     license = { "license" },
+    whitespace = { "whitespace" },
+    format = { "format" },
+    lint = { "lint" },
+    snapshots = { "snapshots" },
+    deadcode = { "deadcode" },
+    deps = { "deps" },
 )]
 fn disable_flag_skips_that_check(check_name: &str) {
     let temp = default_project();
@@ -132,8 +158,8 @@ fn disable_flag_skips_that_check(check_name: &str) {
     );
     assert_eq!(
         names.len(),
-        7,
-        "7 checks should run (all except {})",
+        13,
+        "13 checks should run (all except {})",
         check_name
     );
 }
@@ -175,7 +201,7 @@ fn multiple_disable_flags_skip_multiple_checks() {
 
     assert!(!names.contains(&"docs"), "docs should not be present");
     assert!(!names.contains(&"tests"), "tests should not be present");
-    assert_eq!(names.len(), 6, "6 checks should run");
+    assert_eq!(names.len(), 12, "12 checks should run");
 }
 
 /// Spec: docs/specs/01-cli.md#examples
@@ -211,6 +237,12 @@ fn all_checks_disabled_except_one() {
             "--no-tests",
             "--no-git",
             "--no-build",
+            "--no-whitespace",
+            "--no-format",
+            "--no-lint",
+            "--no-snapshots",
+            "--no-deadcode",
+            "--no-deps",
             // license is the only one NOT disabled
         ])
         .json()
@@ -237,8 +269,8 @@ fn check_failure_doesnt_block_other_checks() {
     let result = cli().on("check-framework").json().fails();
     let checks = result.checks();
 
-    // All 8 checks should have run, even though cloc failed
-    assert_eq!(checks.len(), 8, "all checks should have run");
+    // All 14 checks should have run, even though cloc failed
+    assert_eq!(checks.len(), 14, "all checks should have run");
 
     // Find cloc check - it should have failed
     let cloc = checks
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `quench watch` command.
+//!
+//! Reference: docs/specs/01-cli.md#quench-watch
+
+use crate::prelude::*;
+
+/// Spec: docs/specs/01-cli.md#quench-watch
+///
+/// > Takes the same flags as quench check.
+#[test]
+fn watch_help_lists_check_flags() {
+    quench_cmd()
+        .args(["watch", "--help"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("--debounce"))
+        .stdout(predicates::str::contains("--[no-]tests"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-watch
+///
+/// > `--staged`, `--save`, and `--check-determinism` aren't supported and exit 2.
+#[test]
+fn watch_rejects_staged() {
+    let project = Project::empty();
+    project.config("");
+
+    quench_cmd()
+        .args(["watch", "--staged"])
+        .current_dir(project.path())
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains(
+            "--staged cannot be used with quench watch",
+        ));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-watch
+///
+/// > `--debounce <DURATION>`
+#[test]
+fn watch_rejects_invalid_debounce() {
+    let project = Project::empty();
+    project.config("");
+
+    quench_cmd()
+        .args(["watch", "--debounce", "soon"])
+        .current_dir(project.path())
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains("invalid --debounce"));
+}
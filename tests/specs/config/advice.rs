@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for advice overrides.
+//!
+//! Reference: docs/specs/02-config.md#advice
+
+use crate::prelude::*;
+
+const BIG_FILE: &str = "fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\nfn e() {}\nfn f() {}\n";
+
+/// Spec: docs/specs/02-config.md#advice
+///
+/// > Override built-in advice per rule ID
+#[test]
+fn inline_message_replaces_advice() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.cloc]
+max_lines = 5
+
+[advice.messages]
+"cloc/file-too-large" = "Split {file}; see https://wiki.example.com/modules"
+"#,
+    );
+    temp.file("src/big.rs", BIG_FILE);
+
+    let cloc = check("cloc").pwd(temp.path()).json().fails();
+    let violation = &cloc.violations()[0];
+    assert_eq!(
+        violation["advice"],
+        "Split src/big.rs; see https://wiki.example.com/modules"
+    );
+}
+
+/// Spec: docs/specs/02-config.md#advice
+///
+/// > The catalog file is a flat table of the same entries
+#[test]
+fn catalog_file_replaces_advice_in_text_output() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.cloc]
+max_lines = 5
+
+[advice]
+catalog = ".quench/advice.toml"
+"#,
+    );
+    temp.file(
+        ".quench/advice.toml",
+        "\"cloc/file-too-large\" = \"Datei aufteilen.\"\n",
+    );
+    temp.file("src/big.rs", BIG_FILE);
+
+    check("cloc")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("Datei aufteilen.");
+}
+
+/// Spec: docs/specs/02-config.md#advice
+///
+/// > An unknown check in a key or an unreadable catalog is a configuration error
+#[test]
+fn missing_catalog_is_config_error() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[advice]
+catalog = "missing.toml"
+"#,
+    );
+
+    check("cloc")
+        .pwd(temp.path())
+        .exits(2)
+        .stderr_has("cannot read advice catalog missing.toml");
+}
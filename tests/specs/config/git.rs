@@ -108,3 +108,95 @@ threshold = 100
     cli().pwd(temp.path()).args(&["--no-git"]).passes();
     // Assert reads from file, not notes (file baseline has 0 unsafe, notes has 100)
 }
+
+/// Spec: docs/specs/02-config.md#git
+///
+/// > allow_dirty = false fails CI runs and baseline updates on uncommitted
+/// > changes, listing the dirty files
+#[test]
+fn allow_dirty_false_fails_ci_on_dirty_tree() {
+    let temp = Project::empty();
+    temp.config("[git]\nallow_dirty = false\n");
+    temp.file("src/lib.rs", "fn main() {}\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+    temp.file("src/lib.rs", "fn main() { }\n");
+
+    check("cloc")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .fails()
+        .stdout_has("git: FAIL")
+        .stdout_has("dirty_workdir")
+        .stdout_has("src/lib.rs");
+}
+
+/// Spec: docs/specs/02-config.md#git
+///
+/// > allow_dirty = false fails CI runs and baseline updates on uncommitted
+/// > changes, listing the dirty files
+#[test]
+fn allow_dirty_false_passes_ci_on_clean_tree() {
+    let temp = Project::empty();
+    temp.config("[git]\nallow_dirty = false\n");
+    temp.file("src/lib.rs", "fn main() {}\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+
+    check("cloc").pwd(temp.path()).args(&["--ci"]).passes();
+}
+
+/// Spec: docs/specs/02-config.md#git
+///
+/// > allow_dirty = "warn" reports uncommitted changes without failing
+#[test]
+fn allow_dirty_warn_reports_without_failing() {
+    let temp = Project::empty();
+    temp.config("[git]\nallow_dirty = \"warn\"\n");
+    temp.file("src/lib.rs", "fn main() {}\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+    temp.file("src/new.rs", "fn new() {}\n");
+
+    check("cloc")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .passes()
+        .stdout_has("dirty_workdir")
+        .stdout_has("src/new.rs");
+}
+
+/// Spec: docs/specs/02-config.md#git
+///
+/// > allow_dirty = false fails CI runs and baseline updates on uncommitted
+/// > changes, listing the dirty files
+#[test]
+fn allow_dirty_false_skips_baseline_update() {
+    let temp = Project::empty();
+    temp.config("[git]\nbaseline = \".quench/baseline.json\"\nallow_dirty = false\n");
+    temp.file("src/lib.rs", "fn main() {}\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+    temp.file("src/lib.rs", "fn main() { }\n");
+
+    check("cloc")
+        .pwd(temp.path())
+        .args(&["--fix"])
+        .fails()
+        .stderr_has("not updating baseline");
+    assert!(!temp.path().join(".quench/baseline.json").exists());
+}
+
+/// Spec: docs/specs/02-config.md#git
+///
+/// > allow_dirty = true (default)
+#[test]
+fn allow_dirty_defaults_to_true() {
+    let temp = Project::empty();
+    temp.file("src/lib.rs", "fn main() {}\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+    temp.file("src/lib.rs", "fn main() { }\n");
+
+    check("cloc").pwd(temp.path()).args(&["--ci"]).passes();
+}
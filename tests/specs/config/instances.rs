@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for named check instances.
+//!
+//! Reference: docs/specs/02-config.md#check-instances
+
+use crate::prelude::*;
+
+const SIX_LINES: &str = "fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\nfn e() {}\nfn f() {}\n";
+
+fn scripts_project() -> Project {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.cloc]
+max_lines = 10
+exclude = ["scripts/**"]
+
+[check."cloc:scripts"]
+paths = ["scripts/**"]
+max_lines = 5
+exclude = []
+"#,
+    );
+    temp.file("src/lib.rs", SIX_LINES);
+    temp.file("scripts/gen.rs", SIX_LINES);
+    temp
+}
+
+fn find<'a>(result: &'a ChecksJson, name: &str) -> &'a serde_json::Value {
+    result
+        .checks()
+        .iter()
+        .find(|c| c.get("name").and_then(|n| n.as_str()) == Some(name))
+        .unwrap_or_else(|| panic!("no {name} check in output"))
+}
+
+/// Spec: docs/specs/02-config.md#check-instances
+///
+/// > An instance runs the check again, reported under its own name, with its
+/// > own settings and only the files its `paths` match.
+#[test]
+fn instance_applies_its_own_limits_to_its_paths() {
+    let temp = scripts_project();
+
+    let result = cli().pwd(temp.path()).args(&["--cloc"]).json().fails();
+
+    assert_eq!(check_names(result.value()), ["cloc", "cloc:scripts"]);
+    let cloc = find(&result, "cloc");
+    assert_eq!(cloc["passed"], true);
+    let scripts = find(&result, "cloc:scripts");
+    assert_eq!(scripts["passed"], false);
+    let violations = scripts["violations"].as_array().unwrap();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0]["file"], "scripts/gen.rs");
+}
+
+/// Spec: docs/specs/02-config.md#check-instances
+///
+/// > Instances follow their check's toggles
+#[test]
+fn disabling_check_disables_its_instances() {
+    let temp = scripts_project();
+
+    let result = cli()
+        .pwd(temp.path())
+        .args(&["--no-cloc", "--no-agents"])
+        .json()
+        .passes();
+
+    let names = check_names(result.value());
+    assert!(!names.contains(&"cloc:scripts"));
+}
+
+/// Spec: docs/specs/02-config.md#check-instances
+///
+/// > A rule of the check covers its instances
+#[test]
+fn check_rule_filters_instance_violations() {
+    let temp = scripts_project();
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--cloc", "--skip-rule", "cloc/file-too-large"])
+        .passes();
+}
+
+/// Spec: docs/specs/02-config.md#check-instances
+///
+/// > The part before the colon must be a built-in check.
+#[test]
+fn instance_of_unknown_check_is_config_error() {
+    let temp = Project::empty();
+    temp.config("[check.\"style:scripts\"]\npaths = [\"scripts/**\"]\n");
+
+    cli()
+        .pwd(temp.path())
+        .exits(2)
+        .stderr_has("unknown check 'style' in instance 'style:scripts'");
+}
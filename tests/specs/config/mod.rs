@@ -7,6 +7,8 @@
 //! - Config file validation
 //! - Environment variables
 //! - Git configuration
+//! - Named check instances
+//! - Path-scoped check policies
 //!
 //! Reference: docs/specs/02-config.md
 
@@ -20,3 +22,24 @@ mod env;
 
 #[path = "git.rs"]
 mod git;
+
+#[path = "quarantine.rs"]
+mod quarantine;
+
+#[path = "new_code.rs"]
+mod new_code;
+
+#[path = "tools.rs"]
+mod tools;
+
+#[path = "advice.rs"]
+mod advice;
+
+#[path = "telemetry.rs"]
+mod telemetry;
+
+#[path = "instances.rs"]
+mod instances;
+
+#[path = "scopes.rs"]
+mod scopes;
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the new-code enforcement window.
+//!
+//! Reference: docs/specs/02-config.md#new_code
+
+use crate::prelude::*;
+
+const BIG_FILE: &str = "fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\nfn e() {}\nfn f() {}\n";
+
+/// A committed over-limit file, with `[new_code] since` set to `since`.
+fn legacy_project(since: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!(
+        "[check.cloc]\nmax_lines = 5\n\n[new_code]\nsince = {}\n",
+        since
+    ));
+    temp.file("src/legacy.rs", BIG_FILE);
+    git_init(&temp);
+    git_initial_commit(&temp);
+    temp
+}
+
+/// Spec: docs/specs/02-config.md#new_code
+///
+/// > Files first committed after `since` get the full rules; older (legacy)
+/// > files only warn
+#[test]
+fn legacy_file_violations_only_warn() {
+    let temp = legacy_project("2999-12-31");
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    assert_eq!(cloc.violations().len(), 1);
+}
+
+/// Spec: docs/specs/02-config.md#new_code
+///
+/// > Files first committed after `since` get the full rules
+#[test]
+fn files_committed_after_since_fail() {
+    let temp = legacy_project("2020-01-01");
+
+    check("cloc").pwd(temp.path()).fails();
+}
+
+/// Spec: docs/specs/02-config.md#new_code
+///
+/// > Uncommitted files are new
+#[test]
+fn uncommitted_files_are_new() {
+    let temp = legacy_project("2999-12-31");
+    temp.file("src/draft.rs", BIG_FILE);
+
+    let cloc = check("cloc").pwd(temp.path()).json().fails();
+    assert!(cloc.has_violation_for_file("src/draft.rs"));
+}
+
+/// Spec: docs/specs/02-config.md#new_code
+///
+/// > With `since = "baseline"`, the window opens when the baseline file was
+/// > first committed
+#[test]
+fn baseline_window_opens_when_baseline_committed() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[git]
+baseline = "quench-baseline.json"
+
+[check.cloc]
+max_lines = 5
+
+[new_code]
+since = "baseline"
+"#,
+    );
+    temp.file("src/legacy.rs", BIG_FILE);
+    temp.file(
+        "quench-baseline.json",
+        r#"{"version": 1, "updated": "2026-01-20T00:00:00Z", "metrics": {}}"#,
+    );
+    git_init(&temp);
+    git_initial_commit(&temp);
+
+    check("cloc").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/02-config.md#new_code
+///
+/// > Until a baseline exists, every file is checked.
+#[test]
+fn baseline_window_without_baseline_checks_every_file() {
+    let temp = legacy_project("\"baseline\"");
+
+    check("cloc").pwd(temp.path()).fails();
+}
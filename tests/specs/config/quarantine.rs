@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for quarantined directories.
+//!
+//! Reference: docs/specs/02-config.md#quarantine
+
+use crate::prelude::*;
+
+const BIG_FILE: &str = "fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\nfn e() {}\nfn f() {}\n";
+
+fn quarantined_project(until: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!(
+        r#"[check.cloc]
+max_lines = 5
+
+[[quarantine]]
+path = "spikes/"
+until = {until}
+reason = "parser prototype"
+"#
+    ));
+    temp.file("spikes/big.rs", BIG_FILE);
+    temp
+}
+
+/// Spec: docs/specs/02-config.md#quarantine
+///
+/// > Violations in a quarantined directory are reported as warnings
+#[test]
+fn quarantined_violations_only_warn() {
+    let temp = quarantined_project("2999-12-31");
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    assert_eq!(cloc.require("violations").as_array().unwrap().len(), 1);
+}
+
+/// Spec: docs/specs/02-config.md#quarantine
+///
+/// > A check still fails if any violation is outside a quarantine
+#[test]
+fn violations_outside_quarantine_still_fail() {
+    let temp = quarantined_project("2999-12-31");
+    temp.file("src/big.rs", BIG_FILE);
+
+    check("cloc").pwd(temp.path()).fails();
+}
+
+/// Spec: docs/specs/02-config.md#quarantine
+///
+/// > After the `until` date, violations are errors again and quench warns
+/// > that the quarantine expired
+#[test]
+fn expired_quarantine_fails_with_warning() {
+    let temp = quarantined_project("2020-01-01");
+
+    check("cloc").pwd(temp.path()).fails().stderr_has(
+        "quarantine for spikes/ expired on 2020-01-01; its violations are errors again (parser prototype)",
+    );
+}
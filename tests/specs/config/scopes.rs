@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for path-scoped check policies.
+//!
+//! Reference: docs/specs/02-config.md#scope
+
+use crate::prelude::*;
+
+const SIX_LINES: &str = "fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\nfn e() {}\nfn f() {}\n";
+
+/// Spec: docs/specs/02-config.md#scope
+///
+/// > A scope that overrides or enables a check reruns it on its files with
+/// > the scope's settings, replacing the project-wide findings there
+#[test]
+fn scope_overrides_thresholds_for_its_files() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.cloc]
+max_lines = 10
+
+[scope."services/*".check.cloc]
+max_lines = 5
+"#,
+    );
+    temp.file("src/lib.rs", SIX_LINES);
+    temp.file("services/api.rs", SIX_LINES);
+
+    let cloc = check("cloc").pwd(temp.path()).json().fails();
+
+    assert_eq!(cloc.violations().len(), 1);
+    assert!(cloc.has_violation_for_file("services/api.rs"));
+}
+
+/// Spec: docs/specs/02-config.md#scope
+///
+/// > disable = ["docs"]                     # Drop these checks' findings here
+#[test]
+fn scope_disables_check_for_its_files() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.cloc]
+max_lines = 5
+
+[scope."infra/**"]
+disable = ["cloc"]
+"#,
+    );
+    temp.file("infra/deploy/main.rs", SIX_LINES);
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+
+    assert!(cloc.violations().is_empty());
+}
+
+/// Spec: docs/specs/02-config.md#scope
+///
+/// > enable = ["whitespace"]                # Turn on checks that are off by default
+#[test]
+fn scope_enables_check_only_for_its_files() {
+    let temp = Project::empty();
+    temp.config("[scope.\"services/*\"]\nenable = [\"whitespace\"]\n");
+    temp.file("src/lib.rs", "pub fn f() {}   \n");
+    temp.file("services/api.rs", "pub fn f() {}   \n");
+
+    let whitespace = check("whitespace").pwd(temp.path()).json().fails();
+
+    assert_eq!(whitespace.violations().len(), 1);
+    assert!(whitespace.has_violation_for_file("services/api.rs"));
+}
+
+/// Spec: docs/specs/02-config.md#scope
+///
+/// > A file follows the most specific scope that matches it
+#[test]
+fn most_specific_scope_applies() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[scope."services/**".check.cloc]
+max_lines = 5
+
+[scope."services/legacy/**"]
+disable = ["cloc"]
+"#,
+    );
+    temp.file("services/api.rs", SIX_LINES);
+    temp.file("services/legacy/old.rs", SIX_LINES);
+
+    let cloc = check("cloc").pwd(temp.path()).json().fails();
+
+    assert_eq!(cloc.violations().len(), 1);
+    assert!(cloc.has_violation_for_file("services/api.rs"));
+}
+
+/// Spec: docs/specs/02-config.md#scope
+///
+/// > Only checks that scan files can be overridden or enabled
+#[test]
+fn scope_override_of_unscoped_check_is_config_error() {
+    let temp = Project::empty();
+    temp.config("[scope.\"services/*\"]\nenable = [\"build\"]\n");
+
+    cli()
+        .pwd(temp.path())
+        .exits(2)
+        .stderr_has("check 'build' can't be scoped");
+}
+
+// =============================================================================
+// NESTED CONFIG FILES
+// =============================================================================
+
+/// Spec: docs/specs/02-config.md#nested-config-files
+///
+/// > A `quench.override.toml`, or a `quench.toml` without `version`, in a
+/// > subdirectory holds the keys of a scope section for the files under that
+/// > directory
+#[test]
+fn nested_config_overrides_thresholds_for_its_directory() {
+    let temp = Project::empty();
+    temp.config("[check.cloc]\nmax_lines = 5\n");
+    temp.file("packages/api/quench.toml", "[check.cloc]\nmax_lines = 10\n");
+    temp.file("packages/api/src/lib.rs", SIX_LINES);
+    temp.file("packages/web/src/lib.rs", SIX_LINES);
+
+    let cloc = check("cloc").pwd(temp.path()).json().fails();
+
+    assert_eq!(cloc.violations().len(), 1);
+    assert!(cloc.has_violation_for_file("packages/web/src/lib.rs"));
+}
+
+/// Spec: docs/specs/02-config.md#nested-config-files
+///
+/// > Nested files cascade: each inherits the overrides of the nested files
+/// > above it
+#[test]
+fn nested_config_cascades_from_parent_directories() {
+    let temp = Project::empty();
+    temp.config("[check.cloc]\nmax_lines = 5\n");
+    temp.file(
+        "packages/quench.override.toml",
+        "[check.cloc]\ncheck = \"off\"\n",
+    );
+    temp.file(
+        "packages/api/quench.override.toml",
+        "[check.whitespace]\ncheck = \"off\"\n",
+    );
+    temp.file("packages/api/src/lib.rs", SIX_LINES);
+
+    check("cloc").pwd(temp.path()).json().passes();
+}
+
+/// Spec: docs/specs/02-config.md#nested-config-files
+///
+/// > A nested `quench.toml` with `version` is a separate project
+#[test]
+fn versioned_nested_config_is_not_merged() {
+    let temp = Project::empty();
+    temp.config("[check.cloc]\nmax_lines = 5\n");
+    temp.file(
+        "vendor/quench.toml",
+        "version = 1\n[check.cloc]\nmax_lines = 10\n",
+    );
+    temp.file("vendor/src/lib.rs", SIX_LINES);
+
+    let cloc = check("cloc").pwd(temp.path()).json().fails();
+
+    assert!(cloc.has_violation_for_file("vendor/src/lib.rs"));
+}
@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for opt-in telemetry.
+//!
+//! Reference: docs/specs/05-reporting.md#telemetry
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::prelude::*;
+
+/// Accept one POST on a local port and return its body.
+fn collector() -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/runs", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(10)))
+            .unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if line.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':')
+                && name.eq_ignore_ascii_case("content-length")
+            {
+                length = value.trim().parse().unwrap();
+            }
+        }
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        reader
+            .get_mut()
+            .write_all(b"HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n")
+            .unwrap();
+        String::from_utf8(body).unwrap()
+    });
+    (url, handle)
+}
+
+fn project(endpoint: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!(
+        r#"[check.cloc]
+max_lines = 2
+
+[telemetry]
+endpoint = "{endpoint}"
+"#
+    ));
+    temp.file("src/private_module.rs", "fn a() {}\nfn b() {}\nfn c() {}\n");
+    temp
+}
+
+/// Spec: docs/specs/05-reporting.md#telemetry
+///
+/// > POSTs an anonymized run summary: check durations, a repository size
+/// > bucket, and pass/fail counts
+#[test]
+fn telemetry_posts_anonymized_summary() {
+    let (url, server) = collector();
+    let temp = project(&url);
+
+    check("cloc").pwd(temp.path()).fails();
+
+    let body = server.join().unwrap();
+    let summary: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(summary["repo_size"], "<100");
+    assert_eq!(summary["passed"], false);
+    assert_eq!(summary["counts"]["failed"], 1);
+    assert_eq!(summary["checks"][0]["name"], "cloc");
+    assert!(summary["checks"][0]["duration_ms"].is_u64());
+    assert!(!body.contains("private_module"));
+}
+
+/// Spec: docs/specs/05-reporting.md#telemetry
+///
+/// > A failed upload never changes the run's outcome
+#[test]
+fn unreachable_endpoint_does_not_affect_exit_code() {
+    // Bind and drop to get a port nobody listens on
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let temp = project(&format!("http://127.0.0.1:{port}/runs"));
+    temp.file("src/private_module.rs", "fn a() {}\n");
+
+    check("cloc").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/05-reporting.md#telemetry
+///
+/// > Set `QUENCH_NO_TELEMETRY` to opt a machine or CI job out
+#[test]
+fn no_telemetry_env_skips_upload() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.set_nonblocking(true).unwrap();
+    let temp = project(&format!("http://{}/runs", listener.local_addr().unwrap()));
+
+    check("cloc")
+        .pwd(temp.path())
+        .env("QUENCH_NO_TELEMETRY", "1")
+        .fails();
+
+    assert!(listener.accept().is_err(), "no upload expected");
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for pinned tool versions.
+//!
+//! Reference: docs/specs/02-config.md#tools
+
+use crate::prelude::*;
+
+/// Spec: docs/specs/02-config.md#tools
+///
+/// > `on_mismatch = "error"` (default) runs those checks and fails them with a
+/// > `tool_version` violation naming the expected and found versions
+#[test]
+fn mismatched_tool_fails_dependent_checks() {
+    let temp = Project::empty();
+    temp.config("[tools]\ncargo = \"0.1\"\n");
+
+    let tests = check("tests").pwd(temp.path()).json().fails();
+
+    let v = tests.require_violation("tool_version");
+    assert_eq!(v["expected"], "cargo 0.1");
+    assert!(v["found"].as_str().unwrap().starts_with("cargo "));
+}
+
+/// Spec: docs/specs/02-config.md#tools
+///
+/// > `on_mismatch = "skip"` skips those checks, with the mismatch as the reason
+#[test]
+fn mismatched_tool_skips_dependent_checks() {
+    let temp = Project::empty();
+    temp.config("[tools]\ncargo = \"0.1\"\non_mismatch = \"skip\"\n");
+    temp.file("src/lib.rs", "pub fn f() {}\n");
+
+    let output = cli()
+        .pwd(temp.path())
+        .args(&["--tests", "--cloc"])
+        .json()
+        .passes();
+
+    let tests = output
+        .checks()
+        .iter()
+        .find(|c| c["name"] == "tests")
+        .unwrap();
+    assert_eq!(tests["skipped"], true);
+    assert!(
+        tests["error"]
+            .as_str()
+            .unwrap()
+            .contains("doesn't match pinned version 0.1")
+    );
+    let cloc = output
+        .checks()
+        .iter()
+        .find(|c| c["name"] == "cloc")
+        .unwrap();
+    assert!(cloc.get("skipped").is_none_or(|s| s == false));
+}
+
+/// Spec: docs/specs/02-config.md#tools
+///
+/// > Known tools: `bun`, `cargo`, `go`, `node`, `pytest`, `python`, `ruby`, `rustc`
+#[test]
+fn unknown_tool_is_config_error() {
+    let temp = Project::empty();
+    temp.config("[tools]\nmake = \"4\"\n");
+
+    cli()
+        .pwd(temp.path())
+        .exits(2)
+        .stderr_has("unknown tool 'make'");
+}
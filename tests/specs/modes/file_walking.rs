@@ -108,6 +108,64 @@ fn file_walking_respects_nested_gitignore() {
         .stdout_lacks("vendor/");
 }
 
+/// Spec: docs/specs/02-config.md#project
+///
+/// > Patterns only quench should skip can go in a `.quenchignore`
+#[test]
+fn file_walking_respects_quenchignore() {
+    let temp = Project::empty();
+    temp.file("src/lib.rs", "pub fn f() {}\n");
+    temp.file("src/gen/api.rs", "pub fn generated() {}\n");
+    temp.file("src/gen/.quenchignore", "*\n");
+    temp.file(".quenchignore", "*.snap\n");
+    temp.file("src/lib.snap", "snapshot\n");
+
+    cli()
+        .pwd(temp.path())
+        .env("QUENCH_DEBUG_FILES", "1")
+        .passes()
+        .stdout_has("src/lib.rs")
+        .stdout_lacks("gen/")
+        .stdout_lacks(".snap");
+}
+
+/// Spec: docs/specs/02-config.md#project
+///
+/// > ignore_files = [".ignore", ".quenchignore"]  # Default
+#[test]
+fn file_walking_ignore_files_can_be_cleared() {
+    let temp = Project::empty();
+    temp.config("[project]\nignore_files = []\n");
+    temp.file("src/lib.rs", "pub fn f() {}\n");
+    temp.file("src/lib.snap", "snapshot\n");
+    temp.file(".quenchignore", "*.snap\n");
+
+    cli()
+        .pwd(temp.path())
+        .env("QUENCH_DEBUG_FILES", "1")
+        .passes()
+        .stdout_has("src/lib.snap");
+}
+
+/// Spec: docs/specs/02-config.md#project
+///
+/// > gitignore = true  # Default: skip files ignored by .gitignore
+#[test]
+fn file_walking_gitignore_can_be_disabled() {
+    let temp = Project::empty();
+    temp.config("[project]\ngitignore = false\n");
+    temp.file("src/lib.rs", "pub fn f() {}\n");
+    temp.file("gen/api.rs", "pub fn generated() {}\n");
+    temp.file(".gitignore", "gen/\n");
+    git_init(&temp);
+
+    cli()
+        .pwd(temp.path())
+        .env("QUENCH_DEBUG_FILES", "1")
+        .passes()
+        .stdout_has("gen/api.rs");
+}
+
 // =============================================================================
 // Custom Ignore Patterns
 // =============================================================================
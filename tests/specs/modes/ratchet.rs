@@ -277,6 +277,42 @@ fn fix_updates_baseline_on_improvement() {
         baseline_content.contains("\"unsafe\": 2"),
         "baseline should be updated to new value"
     );
+    // The baseline it replaced is kept
+    let backup = fs::read_to_string(temp.path().join(".quench/baseline.json.bak")).unwrap();
+    assert!(backup.contains("\"unsafe\": 5"));
+}
+
+/// Spec: docs/specs/04-ratcheting.md#baseline-backup
+///
+/// > A baseline that can't be read falls back to the backup from the last save.
+#[test]
+fn damaged_baseline_falls_back_to_backup() {
+    let temp = Project::empty();
+    temp.config(RATCHET_FILE_CONFIG);
+    temp.file("CLAUDE.md", CLAUDE_MD);
+    temp.file("Cargo.toml", CARGO_TOML);
+
+    // Truncated by an interrupted write from an older quench
+    temp.file(".quench/baseline.json", "{\n  \"version\": 1,\n  \"upd");
+    temp.file(
+        ".quench/baseline.json.bak",
+        r#"{
+  "version": 1,
+  "updated": "2026-01-20T00:00:00Z",
+  "metrics": {
+    "escapes": {
+      "source": { "unsafe": 1 }
+    }
+  }
+}"#,
+    );
+    temp.file("src/lib.rs", "fn f() {\n    unsafe {}\n    unsafe {}\n}");
+
+    cli()
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("escapes.unsafe: 2 (max: 1 from baseline)")
+        .stderr_has("using backup .quench/baseline.json.bak");
 }
 
 /// Spec: docs/specs/04-ratcheting.md#fix-message-variants
@@ -514,68 +550,6 @@ fn test_covered() { assert_eq!(cov_tol::covered(), 42); }
     cli().pwd(temp.path()).args(&["--ci"]).passes();
 }
 
-// =============================================================================
-// Binary Size Ratcheting Specs
-// =============================================================================
-
-const BINARY_SIZE_RATCHET_CONFIG: &str = r#"
-version = 1
-
-[git]
-baseline = ".quench/baseline.json"
-
-[ratchet]
-check = "error"
-binary_size = true
-
-[check.build]
-targets = ["binsize_test"]
-"#;
-
-/// Spec: docs/specs/04-ratcheting.md#binary-size
-///
-/// > Binary size can't exceed baseline plus tolerance.
-#[test]
-fn binary_size_regression_fails() {
-    let temp = Project::empty();
-    temp.config(BINARY_SIZE_RATCHET_CONFIG);
-    temp.file("CLAUDE.md", CLAUDE_MD);
-    temp.file(
-        "Cargo.toml",
-        "[package]\nname = \"binsize_test\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
-    );
-    temp.file("src/main.rs", "fn main() { println!(\"hello\"); }");
-
-    // Pre-build the release binary so the build check can measure its size
-    std::process::Command::new("cargo")
-        .args(["build", "--release"])
-        .current_dir(temp.path())
-        .output()
-        .expect("cargo build should succeed");
-
-    // Baseline claims binary is 1 byte — real binary will be much larger
-    fs::create_dir_all(temp.path().join(".quench")).unwrap();
-    fs::write(
-        temp.path().join(".quench/baseline.json"),
-        r#"{
-  "version": 1,
-  "updated": "2026-01-20T00:00:00Z",
-  "metrics": {
-    "binary_size": { "binsize_test": 1 }
-  }
-}"#,
-    )
-    .unwrap();
-
-    cli()
-        .pwd(temp.path())
-        .args(&["--ci", "--build"])
-        .fails()
-        .stdout_has("binary_size.binsize_test:")
-        .stdout_has("(max:")
-        .stdout_has("from baseline)");
-}
-
 // =============================================================================
 // Stale Baseline Specs
 // =============================================================================
@@ -720,6 +694,99 @@ fn per_package_coverage_ratchet() {
     // - Only ratcheted packages fail on regression
 }
 
+const PACKAGE_RATCHET_CONFIG: &str = r#"
+version = 1
+
+[git]
+baseline = ".quench/baseline.json"
+
+[project]
+packages = ["crates/core", "crates/cli"]
+
+[ratchet]
+check = "error"
+escapes = true
+
+[[check.escapes.patterns]]
+name = "unsafe"
+pattern = "unsafe"
+action = "count"
+threshold = 100
+"#;
+
+/// Two `unsafe` in core and one in cli.
+const PACKAGE_BASELINE: &str = r#"{
+  "version": 1,
+  "updated": "2026-01-20T00:00:00Z",
+  "metrics": {
+    "escapes": {
+      "source": { "unsafe": 3 },
+      "by_package": {
+        "core": { "source": { "unsafe": 2 }, "source_lines": 2 },
+        "cli": { "source": { "unsafe": 1 }, "source_lines": 1 }
+      }
+    }
+  }
+}"#;
+
+/// A workspace with `core` and `cli` unsafe counts, ratcheted per package.
+fn package_project(config: &str, core: usize, cli: usize) -> Project {
+    let temp = Project::empty();
+    temp.config(config);
+    temp.file("CLAUDE.md", CLAUDE_MD);
+    temp.file(".quench/baseline.json", PACKAGE_BASELINE);
+    temp.file("crates/core/src/lib.rs", &"unsafe {}\n".repeat(core));
+    temp.file("crates/cli/src/lib.rs", &"unsafe {}\n".repeat(cli));
+    temp
+}
+
+/// Spec: docs/specs/04-ratcheting.md#per-package
+///
+/// > a regression confined to one package fails even when another package's
+/// > improvement keeps the totals flat
+#[test]
+fn per_package_escapes_regression_fails() {
+    let temp = package_project(PACKAGE_RATCHET_CONFIG, 3, 0);
+
+    cli()
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("escapes.core/unsafe: 3 (max: 2 from baseline)")
+        .stdout_lacks("escapes.unsafe:");
+}
+
+/// Spec: docs/specs/04-ratcheting.md#per-package
+///
+/// > Package settings override the global `escapes` and `coverage` switches
+#[test]
+fn per_package_escapes_override_disables_package() {
+    let config = format!(
+        "{}\n[ratchet.package.core]\nescapes = false\n",
+        PACKAGE_RATCHET_CONFIG
+    );
+    let temp = package_project(&config, 3, 0);
+
+    cli().pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/04-ratcheting.md#per-package
+///
+/// > JSON output reports each package's verdict under `ratchet.packages`.
+#[test]
+fn per_package_verdicts_in_json() {
+    let temp = package_project(PACKAGE_RATCHET_CONFIG, 3, 0);
+
+    let output = quench_cmd()
+        .args(["check", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(json["ratchet"]["packages"]["core"], false);
+    assert_eq!(json["ratchet"]["packages"]["cli"], true);
+}
+
 // =============================================================================
 // Git Notes Baseline Specs
 // =============================================================================
@@ -879,3 +946,125 @@ fn base_ref_uses_baseline_from_that_commit() {
         .args(&["--base", "HEAD~1", "--no-git"])
         .passes();
 }
+
+/// Spec: docs/specs/03-output.md#run-tags
+///
+/// > Tags are stored with the baseline written by `--fix`, and each
+/// > history entry keeps the tags of its own run
+#[test]
+fn fix_stores_run_tags_in_baseline() {
+    let temp = Project::empty();
+    temp.config(RATCHET_FILE_CONFIG);
+    temp.file("CLAUDE.md", CLAUDE_MD);
+    temp.file("Cargo.toml", CARGO_TOML);
+    temp.file("src/lib.rs", "fn f() { unsafe {} }");
+
+    for env in ["env=ci", "env=nightly"] {
+        quench_cmd()
+            .args(["check", "--fix", "--tag", env])
+            .current_dir(temp.path())
+            .assert()
+            .success();
+    }
+
+    let baseline: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(temp.path().join(".quench/baseline.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(baseline["tags"]["env"], "nightly");
+    // Each history entry keeps the tags of its own run
+    assert_eq!(baseline["metrics"]["history"][0]["tags"]["env"], "ci");
+    assert_eq!(baseline["metrics"]["history"][1]["tags"]["env"], "nightly");
+}
+
+// =============================================================================
+// DELTA OWNERS
+// =============================================================================
+
+/// Baseline at the `main` commit, then a branch commit by "ann" adding an
+/// unsafe block.
+fn branch_with_regression() -> Project {
+    let temp = Project::empty();
+    temp.config(RATCHET_FILE_CONFIG);
+    temp.file("CLAUDE.md", CLAUDE_MD);
+    temp.file("Cargo.toml", CARGO_TOML);
+    temp.file("src/lib.rs", "fn f() { unsafe {} }\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+
+    let head = std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    let head = String::from_utf8_lossy(&head.stdout).trim().to_string();
+    temp.file(
+        ".quench/baseline.json",
+        &format!(
+            r#"{{"version":1,"updated":"2026-01-20T00:00:00Z","commit":"{}","metrics":{{"escapes":{{"source":{{"unsafe":1}}}}}}}}"#,
+            head
+        ),
+    );
+
+    git_branch(&temp, "feature");
+    temp.file("src/lib.rs", "fn f() { unsafe {} }\nfn g() { unsafe {} }\n");
+    std::process::Command::new("git")
+        .args(["-c", "user.name=ann", "-c", "user.email=ann@example.com"])
+        .args(["commit", "-am", "feat: add g"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    temp
+}
+
+/// Spec: docs/specs/04-ratcheting.md#delta-owners
+///
+/// > In CI, each regressed metric lists the branch commits that moved it.
+#[test]
+fn ci_regression_names_commits_that_added_escapes() {
+    let temp = branch_with_regression();
+
+    check("escapes")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .fails()
+        .stdout_has("escapes.unsafe: 2 (max: 1 from baseline)")
+        .stdout_has("introduced by")
+        .stdout_has("ann <ann@example.com>: +1 in src/lib.rs");
+}
+
+/// Spec: docs/specs/04-ratcheting.md#delta-owners
+///
+/// > JSON output includes the owners of each regressed comparison.
+#[test]
+fn ci_regression_json_includes_owners() {
+    let temp = branch_with_regression();
+
+    let result = check("escapes")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .json()
+        .fails();
+    let comparison = result.value()["ratchet"]["comparisons"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["name"] == "escapes.unsafe")
+        .unwrap();
+    assert_eq!(comparison["owners"][0]["author"], "ann");
+    assert_eq!(comparison["owners"][0]["summary"], "feat: add g");
+    assert_eq!(comparison["owners"][0]["added"], 1);
+}
+
+/// Spec: docs/specs/04-ratcheting.md#delta-owners
+///
+/// > Attribution only runs in CI.
+#[test]
+fn local_regression_skips_attribution() {
+    let temp = branch_with_regression();
+
+    check("escapes")
+        .pwd(temp.path())
+        .fails()
+        .stdout_lacks("introduced by");
+}
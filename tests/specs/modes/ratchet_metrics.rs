@@ -0,0 +1,299 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Ratchet specs for binary size, escape density, unsafe ratio, and
+//! quench's own run time.
+//!
+//! Reference: docs/specs/04-ratcheting.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use crate::prelude::*;
+use std::fs;
+
+const CLAUDE_MD: &str =
+    "# Project\n\n## Directory Structure\n\nLayout.\n\n## Landing the Plane\n\n- Done\n";
+const CARGO_TOML: &str = "[package]\nname = \"test\"\nversion = \"0.1.0\"\nedition = \"2024\"\n";
+
+const RATCHET_FILE_CONFIG: &str = r#"
+version = 1
+
+[git]
+baseline = ".quench/baseline.json"
+
+[ratchet]
+check = "error"
+escapes = true
+
+[[check.escapes.patterns]]
+name = "unsafe"
+pattern = "unsafe"
+action = "count"
+threshold = 100
+"#;
+
+// =============================================================================
+// Binary Size Ratcheting Specs
+// =============================================================================
+
+const BINARY_SIZE_RATCHET_CONFIG: &str = r#"
+version = 1
+
+[git]
+baseline = ".quench/baseline.json"
+
+[ratchet]
+check = "error"
+binary_size = true
+
+[check.build]
+targets = ["binsize_test"]
+"#;
+
+/// Spec: docs/specs/04-ratcheting.md#binary-size
+///
+/// > Binary size can't exceed baseline plus tolerance.
+#[test]
+fn binary_size_regression_fails() {
+    let temp = Project::empty();
+    temp.config(BINARY_SIZE_RATCHET_CONFIG);
+    temp.file("CLAUDE.md", CLAUDE_MD);
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"binsize_test\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+    );
+    temp.file("src/main.rs", "fn main() { println!(\"hello\"); }");
+
+    // Pre-build the release binary so the build check can measure its size
+    std::process::Command::new("cargo")
+        .args(["build", "--release"])
+        .current_dir(temp.path())
+        .output()
+        .expect("cargo build should succeed");
+
+    // Baseline claims binary is 1 byte — real binary will be much larger
+    fs::create_dir_all(temp.path().join(".quench")).unwrap();
+    fs::write(
+        temp.path().join(".quench/baseline.json"),
+        r#"{
+  "version": 1,
+  "updated": "2026-01-20T00:00:00Z",
+  "metrics": {
+    "binary_size": { "binsize_test": 1 }
+  }
+}"#,
+    )
+    .unwrap();
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--ci", "--build"])
+        .fails()
+        .stdout_has("binary_size.binsize_test:")
+        .stdout_has("(max:")
+        .stdout_has("from baseline)");
+}
+
+// =============================================================================
+// Escape Density Specs
+// =============================================================================
+
+const DENSITY_RATCHET_CONFIG: &str = r#"
+version = 1
+
+[git]
+baseline = ".quench/baseline.json"
+
+[ratchet]
+check = "error"
+escapes = true
+escapes_density = true
+
+[[check.escapes.patterns]]
+name = "unsafe"
+pattern = "unsafe"
+action = "count"
+threshold = 100
+"#;
+
+/// Baseline of one `unsafe` in four source lines (250 per 1000).
+const DENSITY_BASELINE: &str = r#"{
+  "version": 1,
+  "updated": "2026-01-20T00:00:00Z",
+  "metrics": {
+    "escapes": {
+      "source": { "unsafe": 1 },
+      "source_lines": 4
+    }
+  }
+}"#;
+
+fn density_project(source: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(DENSITY_RATCHET_CONFIG);
+    temp.file("CLAUDE.md", CLAUDE_MD);
+    temp.file("Cargo.toml", CARGO_TOML);
+    temp.file(".quench/baseline.json", DENSITY_BASELINE);
+    temp.file("src/lib.rs", source);
+    temp
+}
+
+/// Spec: docs/specs/04-ratcheting.md#escape-density
+///
+/// > Density mode ratchets escapes per 1000 non-blank source lines instead of
+/// > raw counts, so adding code at the same escape rate passes
+#[test]
+fn escapes_density_passes_when_code_grows_at_same_rate() {
+    let temp = density_project(
+        "fn f() {\n    unsafe {}\n}\nfn g() {}\nfn h() {\n    unsafe {}\n}\nfn i() {}\n",
+    );
+
+    cli().pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/04-ratcheting.md#escape-density
+///
+/// > Each pattern is compared as `escapes.<pattern>.density`, at two decimals
+#[test]
+fn escapes_density_regression_fails() {
+    let temp = density_project("fn f() {\n    unsafe {}\n    unsafe {}\n}\n");
+
+    cli()
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("escapes.unsafe.density: 500.00/kloc (max: 250.00/kloc from baseline)");
+}
+
+// =============================================================================
+// Unsafe Ratio Specs
+// =============================================================================
+
+const UNSAFE_RATIO_CONFIG: &str = r#"
+version = 1
+
+[git]
+baseline = ".quench/baseline.json"
+
+[ratchet]
+check = "error"
+unsafe_ratio = true
+
+[[check.escapes.patterns]]
+name = "unsafe"
+pattern = "unsafe"
+action = "count"
+threshold = 100
+"#;
+
+/// Baseline of one unsafe line in four source lines (25%).
+const UNSAFE_RATIO_BASELINE: &str = r#"{
+  "version": 1,
+  "updated": "2026-01-20T00:00:00Z",
+  "metrics": {
+    "unsafe": {
+      "total": { "blocks": 1, "fns": 0, "impls": 0, "lines": 1, "source_lines": 4, "ratio": 0.25 }
+    }
+  }
+}"#;
+
+fn unsafe_ratio_project(source: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(UNSAFE_RATIO_CONFIG);
+    temp.file("CLAUDE.md", CLAUDE_MD);
+    temp.file("Cargo.toml", CARGO_TOML);
+    temp.file(".quench/baseline.json", UNSAFE_RATIO_BASELINE);
+    temp.file("src/lib.rs", source);
+    temp
+}
+
+/// Spec: docs/specs/04-ratcheting.md#unsafe-ratio
+///
+/// > Adding safe code lowers the ratio, so it passes even as unsafe blocks
+/// > are added
+#[test]
+fn unsafe_ratio_passes_when_code_grows_at_same_rate() {
+    let temp = unsafe_ratio_project(
+        "fn f() {\n    unsafe {}\n}\nfn g() {}\nfn h() {\n    unsafe {}\n}\nfn i() {}\n",
+    );
+
+    cli().pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/04-ratcheting.md#unsafe-ratio
+///
+/// > The share of Rust source lines inside unsafe blocks is compared as
+/// > `unsafe.ratio`
+#[test]
+fn unsafe_ratio_regression_fails() {
+    let temp = unsafe_ratio_project("fn f() {\n    unsafe {\n        g();\n    }\n}\n");
+
+    cli()
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("unsafe.ratio: 60.00% (max: 25.00% from baseline)");
+}
+
+// =============================================================================
+// Self-Time Specs
+// =============================================================================
+
+/// Spec: docs/specs/04-ratcheting.md#quench-self-time
+///
+/// > Quench records its own run time in the baseline on every `--fix`,
+/// > separately for fast runs and CI runs.
+#[test]
+fn fix_records_self_time_for_mode() {
+    let temp = Project::empty();
+    temp.config(RATCHET_FILE_CONFIG);
+    temp.file("CLAUDE.md", CLAUDE_MD);
+    temp.file("Cargo.toml", CARGO_TOML);
+    temp.file("src/lib.rs", "fn f() { unsafe {} }");
+
+    quench_cmd()
+        .args(["check", "--fix"])
+        .current_dir(temp.path())
+        .assert()
+        .success();
+
+    let baseline: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(temp.path().join(".quench/baseline.json")).unwrap(),
+    )
+    .unwrap();
+    let self_time = &baseline["metrics"]["quench"];
+    assert!(self_time["self_time_fast"].as_f64().unwrap() > 0.0);
+    assert!(self_time.get("self_time_ci").is_none());
+}
+
+/// Spec: docs/specs/04-ratcheting.md#quench-self-time
+///
+/// > Runs under one second never warn, since their timing is mostly noise
+#[test]
+fn fast_run_never_warns_on_self_time() {
+    let temp = Project::empty();
+    temp.config(RATCHET_FILE_CONFIG);
+    temp.file("CLAUDE.md", CLAUDE_MD);
+    temp.file("Cargo.toml", CARGO_TOML);
+    temp.file("src/lib.rs", "fn f() { unsafe {} }");
+    fs::create_dir_all(temp.path().join(".quench")).unwrap();
+    fs::write(
+        temp.path().join(".quench/baseline.json"),
+        r#"{
+  "version": 1,
+  "updated": "2026-01-20T00:00:00Z",
+  "metrics": {
+    "escapes": {
+      "source": { "unsafe": 1 }
+    },
+    "quench": { "self_time_fast": 0.001 }
+  }
+}"#,
+    )
+    .unwrap();
+
+    quench_cmd()
+        .args(["check"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("self_time").not());
+}
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Scheduled baseline refresh specifications.
+//!
+//! Reference: docs/specs/04-ratcheting.md#scheduled-refresh
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use crate::prelude::*;
+use std::fs;
+
+const REFRESH_CONFIG: &str = r#"
+version = 1
+
+[git]
+baseline = ".quench/baseline.json"
+
+[ratchet]
+check = "error"
+escapes = true
+
+[[check.escapes.patterns]]
+name = "unsafe"
+pattern = "unsafe"
+action = "count"
+threshold = 100
+"#;
+
+fn refresh_project(source: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(REFRESH_CONFIG);
+    temp.file("src/lib.rs", source);
+    temp
+}
+
+fn write_baseline(temp: &Project, updated: &str, unsafe_count: usize) {
+    fs::create_dir_all(temp.path().join(".quench")).unwrap();
+    fs::write(
+        temp.path().join(".quench/baseline.json"),
+        format!(
+            r#"{{
+  "version": 1,
+  "updated": "{updated}",
+  "metrics": {{ "escapes": {{ "source": {{ "unsafe": {unsafe_count} }} }} }}
+}}"#
+        ),
+    )
+    .unwrap();
+}
+
+fn refresh_json(temp: &Project, code: i32) -> serde_json::Value {
+    let output = quench_cmd()
+        .args(["ratchet", "refresh", "--if-stale", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(code), "{:?}", output);
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+/// Spec: docs/specs/04-ratcheting.md#scheduled-refresh
+///
+/// > A missing baseline is created.
+#[test]
+fn refresh_creates_missing_baseline() {
+    let temp = refresh_project("fn f() { unsafe {} }");
+
+    let summary = refresh_json(&temp, 0);
+    assert_eq!(summary["updated"], true);
+    assert_eq!(summary["reason"], "missing");
+    assert!(temp.path().join(".quench/baseline.json").exists());
+}
+
+/// Spec: docs/specs/04-ratcheting.md#scheduled-refresh
+///
+/// > With `--if-stale`, a current baseline with no improvements is left alone.
+#[test]
+fn refresh_if_stale_skips_current_baseline() {
+    let temp = refresh_project("fn f() { unsafe {} }");
+    refresh_json(&temp, 0);
+    let before = fs::read_to_string(temp.path().join(".quench/baseline.json")).unwrap();
+
+    let summary = refresh_json(&temp, 0);
+    assert_eq!(summary["updated"], false);
+    assert!(summary.get("title").is_none());
+    let after = fs::read_to_string(temp.path().join(".quench/baseline.json")).unwrap();
+    assert_eq!(before, after);
+}
+
+/// Spec: docs/specs/04-ratcheting.md#scheduled-refresh
+///
+/// > The baseline is updated when metrics improved or it is older than `stale_days`.
+#[test]
+fn refresh_updates_improved_or_stale_baseline() {
+    let temp = refresh_project("fn f() { unsafe {} }");
+
+    write_baseline(&temp, "2020-01-01T00:00:00Z", 1);
+    let summary = refresh_json(&temp, 0);
+    assert_eq!(summary["reason"], "stale");
+    assert!(summary["title"].as_str().is_some());
+
+    write_baseline(&temp, "2099-01-01T00:00:00Z", 3);
+    let summary = refresh_json(&temp, 0);
+    assert_eq!(summary["reason"], "improved");
+    assert_eq!(summary["improvements"][0]["name"], "escapes.unsafe");
+    let baseline = fs::read_to_string(temp.path().join(".quench/baseline.json")).unwrap();
+    assert!(baseline.contains("\"unsafe\": 1"));
+}
+
+/// Spec: docs/specs/04-ratcheting.md#scheduled-refresh
+///
+/// > Regressions block the refresh and exit with code 1.
+#[test]
+fn refresh_regression_blocks_update() {
+    let temp = refresh_project("fn f() {\n    unsafe {}\n    unsafe {}\n}");
+    write_baseline(&temp, "2020-01-01T00:00:00Z", 1);
+
+    let summary = refresh_json(&temp, 1);
+    assert_eq!(summary["updated"], false);
+    assert_eq!(summary["regressions"][0]["name"], "escapes.unsafe");
+    let baseline = fs::read_to_string(temp.path().join(".quench/baseline.json")).unwrap();
+    assert!(baseline.contains("2020-01-01"));
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Sparse-checkout and partial-clone specifications.
+//!
+//! Reference: docs/specs/checks/tests.md#sparse-checkouts
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use crate::prelude::*;
+
+fn git_config(project: &Project, key: &str, value: &str) {
+    std::process::Command::new("git")
+        .args(["config", key, value])
+        .current_dir(project.path())
+        .output()
+        .expect("git config should succeed");
+}
+
+fn project_with_untested_change() -> Project {
+    let temp = Project::empty();
+    temp.config("[check.tests.commit]\ncheck = \"error\"\n");
+    git_init(&temp);
+    temp.file("README.md", "# Project\n");
+    git_initial_commit(&temp);
+    git_branch(&temp, "feature/no-tests");
+    temp.file("src/parser.rs", "pub fn parse() {}");
+    git_add_all(&temp);
+    git_commit(&temp, "feat: add parser");
+    temp
+}
+
+/// Spec: docs/specs/checks/tests.md#sparse-checkouts
+///
+/// > In a sparse checkout, the tests and docs checks are skipped with
+/// > "skipped (sparse)" instead of reporting violations.
+#[test]
+fn sparse_checkout_skips_tests_check() {
+    let temp = project_with_untested_change();
+    check("tests")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .fails()
+        .stdout_has("missing_tests");
+
+    git_config(&temp, "core.sparseCheckout", "true");
+    check("tests")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .passes()
+        .stdout_has("skipped (sparse)")
+        .stdout_has("git sparse-checkout disable")
+        .stdout_lacks("missing_tests");
+}
+
+/// Spec: docs/specs/checks/tests.md#sparse-checkouts
+///
+/// > In a partial clone, only the tests check is skipped.
+#[test]
+fn partial_clone_skips_tests_check_only() {
+    let temp = project_with_untested_change();
+    git_config(&temp, "remote.origin.promisor", "true");
+
+    let output = cli()
+        .pwd(temp.path())
+        .args(&["--tests", "--docs", "--base", "main"])
+        .json()
+        .passes();
+    let tests = output
+        .checks()
+        .iter()
+        .find(|c| c["name"] == "tests")
+        .unwrap();
+    assert_eq!(tests["skipped"], true);
+    assert!(tests["error"].as_str().unwrap().contains("partial clone"));
+    let docs = output
+        .checks()
+        .iter()
+        .find(|c| c["name"] == "docs")
+        .unwrap();
+    assert!(docs.get("skipped").is_none_or(|s| s == false));
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Staged content specifications.
+//!
+//! Reference: docs/specs/01-cli.md#staged-content
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use crate::prelude::*;
+
+/// Project with a committed `src/lib.rs` and cloc limited to 5 lines.
+fn staged_project() -> Project {
+    let temp = Project::empty();
+    temp.config("[check.cloc]\nmax_lines = 5\n");
+    temp.file("src/lib.rs", "fn a() {}\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+    temp
+}
+
+const LONG: &str = "fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\nfn e() {}\nfn f() {}\n";
+
+/// Spec: docs/specs/01-cli.md#staged-content
+///
+/// > A file with unstaged changes on top of its staged ones is read from the
+/// > git index, so a violation only in the unstaged part doesn't fail the hook
+#[test]
+fn staged_ignores_violation_in_unstaged_changes() {
+    let temp = staged_project();
+    temp.file("src/lib.rs", "fn a() {}\nfn b() {}\n");
+    git_add_all(&temp);
+    temp.file("src/lib.rs", LONG);
+
+    check("cloc").pwd(temp.path()).args(&["--staged"]).passes();
+}
+
+/// Spec: docs/specs/01-cli.md#staged-content
+///
+/// > and one in the staged part isn't hidden by an unstaged fix
+#[test]
+fn staged_reports_violation_fixed_only_in_working_tree() {
+    let temp = staged_project();
+    temp.file("src/lib.rs", LONG);
+    git_add_all(&temp);
+    temp.file("src/lib.rs", "fn a() {}\n");
+
+    check("cloc")
+        .pwd(temp.path())
+        .args(&["--staged"])
+        .fails()
+        .stdout_has("src/lib.rs");
+}
+
+/// Spec: docs/specs/01-cli.md#staged-content
+///
+/// > `--fix` leaves such files alone, since rewriting them would drop their
+/// > unstaged changes.
+#[test]
+fn staged_fix_keeps_unstaged_changes() {
+    let temp = Project::empty();
+    temp.config("[check.whitespace]\ncheck = \"error\"\n");
+    temp.file("src/lib.rs", "fn a() {}\n");
+    git_init(&temp);
+    git_initial_commit(&temp);
+    temp.file("src/lib.rs", "fn a() {}  \n");
+    git_add_all(&temp);
+    temp.file("src/lib.rs", "fn a() {}  \nfn b() {}\n");
+
+    check("whitespace")
+        .pwd(temp.path())
+        .args(&["--staged", "--fix"])
+        .fails()
+        .stdout_has("src/lib.rs:1: trailing whitespace");
+
+    let content = std::fs::read_to_string(temp.path().join("src/lib.rs")).unwrap();
+    assert_eq!(content, "fn a() {}  \nfn b() {}\n");
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for deterministic output ordering.
+//!
+//! Reference: docs/specs/03-output.md#deterministic-ordering
+
+use crate::prelude::*;
+
+/// Spec: docs/specs/03-output.md#deterministic-ordering
+///
+/// > Violations within a check are sorted by file path, then line, then type
+#[test]
+fn violations_sorted_by_path() {
+    let temp = Project::empty();
+    temp.config("[check.cloc]\nmax_lines = 2\n");
+    temp.file("src/zeta.rs", "fn a() {}\nfn b() {}\nfn c() {}\n");
+    temp.file("src/alpha.rs", "fn a() {}\nfn b() {}\nfn c() {}\n");
+    temp.file("src/mid.rs", "fn a() {}\nfn b() {}\nfn c() {}\n");
+
+    let result = check("cloc").pwd(temp.path()).json().fails();
+    let files: Vec<&str> = result
+        .violations()
+        .iter()
+        .filter_map(|v| v.get("file").and_then(|f| f.as_str()))
+        .collect();
+    assert_eq!(files, vec!["src/alpha.rs", "src/mid.rs", "src/zeta.rs"]);
+}
+
+/// Spec: docs/specs/03-output.md#determinism-check---check-determinism
+///
+/// > Runs every check twice on the same files and compares the results
+#[test]
+fn check_determinism_passes_on_stable_project() {
+    cli()
+        .on("violations")
+        .args(&["--check-determinism"])
+        .exits(1)
+        .stderr_lacks("nondeterministic");
+}
+
+/// Spec: docs/specs/03-output.md#determinism-check---check-determinism
+///
+/// > cannot be combined with `--fix`
+#[test]
+fn check_determinism_rejects_fix() {
+    let temp = default_project();
+    cli()
+        .pwd(temp.path())
+        .args(&["--check-determinism", "--fix"])
+        .exits(2)
+        .stderr_has("--check-determinism");
+}
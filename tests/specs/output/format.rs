@@ -75,12 +75,81 @@ fn text_output_summary_lists_checks_by_status() {
 #[test]
 fn text_output_passing_summary_only() {
     let temp = default_project();
-    // Only non-stub checks appear; currently cloc, escapes, agents, docs, tests, license are implemented
+    // Only non-stub checks appear; currently cloc, escapes, agents, docs, tests, license, whitespace, format, lint, snapshots, deadcode, deps are implemented
     cli()
         .pwd(temp.path())
         .args(&["--no-git"])
         .passes()
-        .stdout_has("PASS: cloc, escapes, agents, docs, tests, license\n");
+        .stdout_has(
+            "PASS: cloc, escapes, agents, docs, tests, license, whitespace, format, lint, snapshots, deadcode, deps\n",
+        );
+}
+
+/// Spec: docs/specs/03-output.md#default-failures--summary
+///
+/// > With `--health`, when the run measured metrics that feed the health
+/// > score, a Health line follows the summary
+#[test]
+fn text_output_summary_includes_health_score_with_flag() {
+    let temp = default_project();
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"health\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+    );
+    temp.file(
+        "src/lib.rs",
+        "pub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n",
+    );
+    cli()
+        .pwd(temp.path())
+        .args(&["--no-git", "--health"])
+        .passes()
+        .stdout_has(predicates::str::is_match(r"(?m)^Health: \d+/100 \([A-F]\)$").unwrap());
+    cli()
+        .pwd(temp.path())
+        .args(&["--no-git"])
+        .passes()
+        .stdout_lacks("Health:");
+}
+
+/// Spec: docs/specs/03-output.md#default-failures--summary
+///
+/// > The score is capped at the share of checks that passed
+#[test]
+fn text_output_health_score_capped_by_failing_checks() {
+    let temp = default_project();
+    temp.config(
+        r#"
+[check.cloc]
+max_lines = 2
+"#,
+    );
+    temp.file(
+        "Cargo.toml",
+        "[package]\nname = \"health\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+    );
+    temp.file(
+        "src/lib.rs",
+        "pub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}\n",
+    );
+    cli()
+        .pwd(temp.path())
+        .args(&["--no-git", "--health", "--cloc", "--escapes"])
+        .fails()
+        .stdout_has("Health: 50/100 (F)");
+}
+
+/// Spec: docs/specs/03-output.md#default-failures--summary
+///
+/// > The badge format is only for `quench report`
+#[test]
+fn check_rejects_svg_output() {
+    let temp = default_project();
+    quench_cmd()
+        .args(["check", "-o", "svg"])
+        .current_dir(temp.path())
+        .assert()
+        .code(2);
 }
 
 // =============================================================================
@@ -183,6 +252,98 @@ fn json_output_violation_has_required_fields() {
     }
 }
 
+// =============================================================================
+// NDJSON Output Format
+// =============================================================================
+
+fn ndjson_events(stdout: &str) -> Vec<serde_json::Value> {
+    stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line should be JSON"))
+        .collect()
+}
+
+fn of_kind<'a>(events: &'a [serde_json::Value], kind: &str) -> Vec<&'a serde_json::Value> {
+    events.iter().filter(|e| e["event"] == kind).collect()
+}
+
+/// Spec: docs/specs/03-output.md#ndjson-format
+///
+/// > Each line is one event, tagged by `event`: `run_started` first, then
+/// > `check_started`, `violation`, and `check_finished` for each check, and
+/// > `summary` last.
+#[test]
+fn ndjson_streams_events_from_start_to_summary() {
+    let result = cli().on("output-test").args(&["-o", "ndjson"]).exits(1);
+    let events = ndjson_events(&result.stdout());
+
+    assert_eq!(events.first().unwrap()["event"], "run_started");
+    let summary = events.last().unwrap();
+    assert_eq!(summary["event"], "summary");
+    assert_eq!(summary["passed"], false);
+
+    let finished = of_kind(&events, "check_finished");
+    assert_eq!(summary["checks"], finished.len());
+    assert_eq!(of_kind(&events, "check_started").len(), finished.len());
+    assert_eq!(
+        events[0]["checks"].as_array().unwrap().len(),
+        finished.len()
+    );
+
+    let violations = of_kind(&events, "violation");
+    assert!(!violations.is_empty());
+    assert_eq!(summary["violations"], violations.len());
+    assert!(
+        violations
+            .iter()
+            .all(|v| v["check"].is_string() && v["type"].is_string())
+    );
+}
+
+/// Spec: docs/specs/03-output.md#ndjson-format
+///
+/// > A check's `violation` events come right before its `check_finished`,
+/// > which has the check object with `violations` as a count.
+#[test]
+fn ndjson_violations_precede_their_check_finished() {
+    let result = cli().on("output-test").args(&["-o", "ndjson"]).exits(1);
+    let events = ndjson_events(&result.stdout());
+
+    let mut pending = 0;
+    for event in &events {
+        match event["event"].as_str().unwrap() {
+            "violation" => pending += 1,
+            "check_finished" => {
+                assert_eq!(event["violations"], pending, "{}", event["check"]);
+                pending = 0;
+            }
+            _ => assert_eq!(pending, 0),
+        }
+    }
+}
+
+/// Spec: docs/specs/03-output.md#streaming-vs-buffered
+///
+/// > Streamed checks still print in canonical order
+#[test]
+fn ndjson_check_order_matches_json_output() {
+    let json = cli().on("output-test").json().fails();
+    let ndjson = cli().on("output-test").args(&["-o", "ndjson"]).exits(1);
+
+    let events = ndjson_events(&ndjson.stdout());
+    let names: Vec<&serde_json::Value> = of_kind(&events, "check_finished")
+        .into_iter()
+        .map(|e| &e["check"])
+        .collect();
+    let expected: Vec<&serde_json::Value> = json.value()["checks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|check| &check["name"])
+        .collect();
+    assert_eq!(names, expected);
+}
+
 // =============================================================================
 // Exit Codes
 // =============================================================================
@@ -383,7 +544,7 @@ cloc: FAIL
 
     Avoid removing individual lines to satisfy the linter; prefer extracting testable code blocks.
 
-PASS: escapes, agents, docs, tests, git, license
+PASS: escapes, agents, docs, tests, git, license, whitespace, format, lint, snapshots, deadcode, deps
 FAIL: cloc
 ";
 
@@ -405,7 +566,7 @@ FAIL: cloc
 fn text_output_deduplicates_consecutive_identical_advice() {
     cli().on("dedup-advice").exits(1).stdout_eq(
         "cloc: FAIL
-  src/file_c.rs: file_too_large (lines: 7 vs 5)
+  src/file_a.rs: file_too_large (lines: 7 vs 5)
     First, look for repetitive patterns that could be extracted into helper functions, or refactor to be more unit testable and concise.
 
     Then split into sibling modules or submodules in a folder by semantic concern (target 1\u{2013}1 lines each).
@@ -413,8 +574,8 @@ fn text_output_deduplicates_consecutive_identical_advice() {
     Avoid removing individual lines to satisfy the linter; prefer extracting testable code blocks.
 
   src/file_b.rs: file_too_large (lines: 7 vs 5)
-  src/file_a.rs: file_too_large (lines: 7 vs 5)
-PASS: escapes, agents, docs, tests, git, license
+  src/file_c.rs: file_too_large (lines: 7 vs 5)
+PASS: escapes, agents, docs, tests, git, license, whitespace, format, lint, snapshots, deadcode, deps
 FAIL: cloc
 ",
     );
@@ -465,3 +626,187 @@ fn json_output_never_deduplicates_advice() {
         );
     }
 }
+
+/// Spec: docs/specs/03-output.md#run-tags
+///
+/// > `--tag KEY=VALUE` (repeatable) attaches arbitrary dimensions to a run
+#[test]
+fn json_output_includes_run_tags() {
+    let temp = default_project();
+    let result = cli()
+        .pwd(temp.path())
+        .args(&["--no-git", "--tag", "env=ci", "--tag", "branch=main"])
+        .json()
+        .passes();
+    assert_eq!(
+        result.value().get("tags"),
+        Some(&serde_json::json!({ "branch": "main", "env": "ci" }))
+    );
+}
+
+/// Spec: docs/specs/03-output.md#run-tags
+///
+/// > Keys may contain letters, digits, `_`, `-`, and `.`
+#[test]
+fn invalid_tag_is_rejected() {
+    let temp = default_project();
+    cli()
+        .pwd(temp.path())
+        .args(&["--tag", "no-equals"])
+        .exits(2)
+        .stderr_has("expected KEY=VALUE");
+}
+
+// =============================================================================
+// VIOLATION GROUPING SPECS
+// =============================================================================
+
+/// Spec: docs/specs/03-output.md#grouped-violations
+///
+/// > Later violations on the same `file:line` are listed on one line
+/// > pointing at the primary, without repeating advice.
+#[test]
+fn same_line_violations_are_grouped_under_primary() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[golang.suppress]
+check = "comment"
+
+[[check.escapes.patterns]]
+name = "discard"
+pattern = "_ = "
+action = "forbid"
+advice = "Handle the result instead of discarding it."
+"#,
+    );
+    temp.file("go.mod", "module test\ngo 1.21\n");
+    temp.file(
+        "main.go",
+        "package main\n\nfunc test() {\n\t_ = doSomething() //nolint:errcheck\n}\n",
+    );
+
+    check("escapes").pwd(temp.path()).fails().stdout_eq(
+        r#"escapes: FAIL
+  main.go:4: forbidden: discard
+    Handle the result instead of discarding it.
+  main.go:4: suppress_missing_comment: //nolint:errcheck (see escapes: forbidden)
+FAIL: escapes
+"#,
+    );
+}
+
+/// Spec: docs/specs/03-output.md#grouped-violations
+///
+/// > In JSON, grouped violations keep their own entry and name their primary.
+#[test]
+fn grouped_violation_json_names_primary() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[golang.suppress]
+check = "comment"
+
+[[check.escapes.patterns]]
+name = "discard"
+pattern = "_ = "
+action = "forbid"
+"#,
+    );
+    temp.file("go.mod", "module test\ngo 1.21\n");
+    temp.file(
+        "main.go",
+        "package main\n\nfunc test() {\n\t_ = doSomething() //nolint:errcheck\n}\n",
+    );
+
+    let result = check("escapes").pwd(temp.path()).json().fails();
+    let violations = result.violations();
+    assert_eq!(violations.len(), 2);
+    assert!(violations[0].get("primary").is_none());
+    assert_eq!(
+        violations[1]["primary"],
+        serde_json::json!({"check": "escapes", "type": "forbidden"})
+    );
+}
+
+// =============================================================================
+// DIAGNOSTICS SPECS
+// =============================================================================
+
+/// Spec: docs/specs/03-output.md#diagnostics
+///
+/// > Each is printed to stderr when it happens, colored like `WARN` or
+/// > `FAIL`, with a hint below
+#[test]
+fn diagnostic_prints_code_and_hint_to_stderr() {
+    let temp = default_project();
+    cli()
+        .pwd(temp.path())
+        .args(&["--staged"])
+        .passes()
+        .stderr_has("warning[staged_files_unavailable]: could not get staged files")
+        .stderr_has("  hint: Run inside a git repository, or drop --staged.");
+}
+
+/// Spec: docs/specs/03-output.md#diagnostics
+///
+/// > JSON output (and the NDJSON `summary`) lists them in report order, so
+/// > automation can react by code
+#[test]
+fn json_output_lists_diagnostics() {
+    let temp = default_project();
+    let result = cli().pwd(temp.path()).args(&["--staged"]).json().passes();
+    let diagnostics = result.value()["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["code"], "staged_files_unavailable");
+    assert_eq!(diagnostics[0]["severity"], "warning");
+    assert!(
+        diagnostics[0]["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("could not get staged files")
+    );
+    assert_eq!(
+        diagnostics[0]["hint"],
+        "Run inside a git repository, or drop --staged."
+    );
+}
+
+/// Spec: docs/specs/03-output.md#diagnostics
+///
+/// > JSON output (and the NDJSON `summary`) lists them in report order
+#[test]
+fn ndjson_summary_lists_diagnostics() {
+    let temp = default_project();
+    let result = cli()
+        .pwd(temp.path())
+        .args(&["--staged", "-o", "ndjson"])
+        .passes();
+    let events = ndjson_events(&result.stdout());
+    let summary = of_kind(&events, "summary")[0];
+    assert_eq!(
+        summary["diagnostics"][0]["code"],
+        "staged_files_unavailable"
+    );
+}
+
+/// Spec: docs/specs/03-output.md#json-format
+///
+/// > `diagnostics` is present only when some were reported.
+#[test]
+fn json_output_omits_diagnostics_when_none() {
+    let temp = default_project();
+    let result = cli().pwd(temp.path()).json().passes();
+    assert!(result.value().get("diagnostics").is_none());
+}
+
+/// Spec: docs/specs/03-output.md#diagnostics
+///
+/// > `coverage_without_suites` | warning | Coverage thresholds are set, but
+/// > no test suite is configured and `auto` is off
+#[test]
+fn incoherent_config_reports_diagnostic() {
+    let temp = default_project();
+    temp.config("[check.tests.coverage]\nmin = 80\n");
+    let result = cli().pwd(temp.path()).json().passes();
+    let diagnostics = result.value()["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics[0]["code"], "coverage_without_suites");
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Golden-file specs using the public `quench::testkit` harness.
+//!
+//! Reference: docs/specs/03-output.md#golden-testing
+//!
+//! Regenerate goldens with `QUENCH_BLESS=1 cargo test --test specs golden`.
+
+use quench::testkit::GoldenHarness;
+
+use crate::prelude::*;
+
+fn harness() -> GoldenHarness {
+    GoldenHarness::new(
+        assert_cmd::cargo::cargo_bin!("quench"),
+        fixture(""),
+        fixture("").join("../golden"),
+    )
+    .args(["--no-git"])
+}
+
+/// Spec: docs/specs/03-output.md#golden-testing
+///
+/// > JSON output of a fixture run matches `<goldens>/<case>.json`
+/// > with timestamps and durations removed.
+#[test]
+fn passing_fixture_matches_golden() {
+    harness().check("minimal").unwrap();
+}
+
+/// Spec: docs/specs/03-output.md#golden-testing
+///
+/// > Failing runs are compared the same way; violations are part of the golden.
+#[test]
+fn failing_fixture_matches_golden() {
+    harness().check("cloc").unwrap();
+}
@@ -36,7 +36,12 @@ impl<P: Predicate<str>> IntoStrPredicate<P> for P {
 
 /// Returns a Command configured to run the quench binary
 pub fn quench_cmd() -> Command {
-    Command::new(assert_cmd::cargo::cargo_bin!("quench"))
+    let mut cmd = Command::new(assert_cmd::cargo::cargo_bin!("quench"));
+    // Specs opt into CI mode explicitly, even when the suite runs in CI
+    for var in quench::env::CI_VARS {
+        cmd.env_remove(var);
+    }
+    cmd
 }
 
 /// Create a check builder for the named check (runs only that check)
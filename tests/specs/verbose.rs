@@ -206,6 +206,11 @@ Discovery:
   Max depth limit: 100
   Scanned 3 files (0 errors, 0 symlink loops, 0 skipped >10MB)
 
+Scheduling:
+  Files: 3 (95 B total), largest first
+  Largest: CLAUDE.md (71 B); top 1% of files hold 75% of bytes
+  Check order: cloc, escapes, agents, docs, tests, git, build, license, whitespace, format, lint, snapshots, deadcode, deps
+
 Ratchet:
   Mode: file
   Ratchet check: off (not in git repo with notes mode)
@@ -260,6 +265,11 @@ Discovery:
   Max depth limit: 100
   Scanned 3 files (0 errors, 0 symlink loops, 0 skipped >10MB)
 
+Scheduling:
+  Files: 3 (186 B total), largest first
+  Largest: quench.toml (103 B); top 1% of files hold 55% of bytes
+  Check order: cloc, escapes, agents, docs, tests, git, build, license, whitespace, format, lint, snapshots, deadcode, deps
+
 Ratchet:
   Mode: file
   Ratchet check: off (not in git repo with notes mode)